@@ -4,7 +4,7 @@ use criterion::{criterion_group, criterion_main, Criterion};
 mod finl_test {
     use finl_unicode::grapheme_clusters::Graphemes;
 
-    pub fn read_clusters(input: &String) -> usize {
+    pub fn read_clusters(input: &str) -> usize {
         let mut cnt = 0;
         Graphemes::new(input).for_each(
             |c| {
@@ -17,10 +17,26 @@ mod finl_test {
     }
 }
 
+mod finl_bytes_test {
+    use finl_unicode::grapheme_clusters::ByteGraphemes;
+
+    pub fn read_clusters(input: &str) -> usize {
+        let mut cnt = 0;
+        ByteGraphemes::new(input.as_bytes()).for_each(
+            |c| {
+                if c.len() == 1 {
+                    cnt += 1;
+                }
+            }
+        );
+        cnt
+    }
+}
+
 mod unicode_rs {
    use unicode_segmentation::UnicodeSegmentation;
 
-    pub fn read_clusters(input: &String) -> usize {
+    pub fn read_clusters(input: &str) -> usize {
         let mut cnt = 0;
         input.graphemes(true).for_each(
             |c| {
@@ -36,7 +52,7 @@ mod unicode_rs {
 mod bstr {
     use bstr::ByteSlice;
 
-    pub fn read_clusters(input: &String) -> usize {
+    pub fn read_clusters(input: &str) -> usize {
         let mut cnt = 0;
         input.as_bytes().graphemes().for_each(
             |c| {
@@ -62,6 +78,12 @@ fn text_benchmark(c: &mut Criterion, source_file_name: &str, group_name: &str) {
                          })
     );
 
+    group.bench_function("finl_unicode (bytes)",
+                         |b| b.iter(|| {
+                             finl_bytes_test::read_clusters(&input_text);
+                         })
+    );
+
     group.bench_function("unicode-rs",
                          |b| b.iter(|| {
                              unicode_rs::read_clusters(&input_text);
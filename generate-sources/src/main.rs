@@ -1,50 +1,435 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::env;
 use std::ffi::{OsStr, OsString};
 use std::fs::File;
 use std::io::{BufRead, BufReader,Write};
 use std::ops::RangeInclusive;
 use std::path::{Path, PathBuf};
-use reqwest::blocking::Client;
+use anyhow::bail;
 use itertools::Itertools;
+use crate::source::UnicodeSource;
+
+mod source;
 
 fn main() -> anyhow::Result<()> {
-    let unicode_version = "15.0.0";
-    let mut out_dir = env::var_os("CARGO_MANIFEST_DIR").unwrap();
-    out_dir.push("/target/tmp/");
-    if !Path::new(&out_dir).try_exists()? {
-        std::fs::create_dir(&out_dir)?;
-    }
-    let mut code_dir = env::var_os("CARGO_MANIFEST_DIR").unwrap();
-    code_dir.push("/../src/data/");
-    if !Path::new(&code_dir).try_exists()? {
-        std::fs::create_dir(&code_dir)?;
-    }
-    let data_dir = Path::new(&out_dir).join("data").join(unicode_version);
-    std::fs::create_dir_all(&data_dir)?;
-    let unicode_data_txt = data_dir.join("UnicodeData.txt");
-    let grapheme_break_test_txt = data_dir.join("GraphemeBreakTest.txt");
-    let grapheme_break_property_txt = data_dir.join("GraphemeBreakProperty.txt");
-    let emoji_data_txt = data_dir.join("emoji-data.txt");
-
-
-    eprintln!("Downloading Unicode data...");
-    download_unicode_data(&unicode_data_txt, "ucd/UnicodeData.txt", unicode_version)?;
+    let options = parse_options()?;
+    let out_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("target").join("tmp");
+    std::fs::create_dir_all(&out_dir)?;
+    let code_dir = match &options.out {
+        Some(out) => OsString::from(out),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join("src").join("data").into_os_string(),
+    };
+    std::fs::create_dir_all(&code_dir)?;
+    let mut source = match &options.ucd {
+        Some(ucd) => UnicodeSource::local(&options.version, ucd, &out_dir)?,
+        None => UnicodeSource::remote(&options.version, &out_dir.join("data"))?,
+    };
+    // Local data must be checked against a manifest unless we're being asked to write one.
+    match options.manifest.clone().or_else(|| source.default_manifest()) {
+        Some(manifest) => source.verify_with(&manifest)?,
+        None if options.ucd.is_some() && options.write_manifest.is_none() => {
+            bail!("No checksum manifest for {}: use --manifest or create one with --write-manifest", options.ucd.unwrap().display())
+        }
+        None => {}
+    }
+
+    if options.pinned {
+        generate_pinned_tables(&code_dir, &mut source, &options.version)?;
+    } else {
+        generate_all_tables(&code_dir, &mut source, &options.version)?;
+    }
+    if let Some(manifest) = &options.write_manifest {
+        source.write_manifest(manifest)?;
+    }
+    Ok(())
+}
+
+fn generate_all_tables(code_dir: &OsString, source: &mut UnicodeSource, unicode_version: &str) -> anyhow::Result<()> {
+    build_unicode_version(code_dir, unicode_version)?;
+    build_data_module(code_dir)?;
+    eprintln!("Fetching Unicode data...");
+    let unicode_data_txt = source.fetch("ucd/UnicodeData.txt")?;
     eprintln!("Generating category data...");
-    build_character_tables(&code_dir, &unicode_data_txt)?;
-    eprintln!("Downloading grapheme test data...");
-    download_unicode_data(&grapheme_break_test_txt, "ucd/auxiliary/GraphemeBreakTest.txt", unicode_version)?;
+    build_character_tables(code_dir, source, &unicode_data_txt)?;
+    eprintln!("Fetching grapheme test data...");
+    let grapheme_break_test_txt = source.fetch("ucd/auxiliary/GraphemeBreakTest.txt")?;
     eprintln!("Generating grapheme tests...");
-    build_grapheme_break_test(&code_dir, &grapheme_break_test_txt)?;
-    eprintln!("Downloading grapheme break properties...");
-    download_unicode_data(&grapheme_break_property_txt, "ucd/auxiliary/GraphemeBreakProperty.txt", unicode_version)?;
-    eprintln!("Downloading emoji data...");
-    download_unicode_data(&emoji_data_txt, "ucd/emoji/emoji-data.txt", unicode_version)?;
+    build_grapheme_break_test(code_dir, source, &grapheme_break_test_txt)?;
+    eprintln!("Fetching grapheme break properties...");
+    let grapheme_break_property_txt = source.fetch("ucd/auxiliary/GraphemeBreakProperty.txt")?;
+    eprintln!("Fetching emoji data...");
+    let emoji_data_txt = source.fetch("ucd/emoji/emoji-data.txt")?;
+    eprintln!("Fetching derived core properties...");
+    let derived_core_properties_txt = source.fetch("ucd/DerivedCoreProperties.txt")?;
+    eprintln!("Generating grapheme break data...");
+    build_grapheme_break_property(code_dir, source, &grapheme_break_property_txt, &emoji_data_txt, &derived_core_properties_txt)?;
+    eprintln!("Fetching word break test data...");
+    let word_break_test_txt = source.fetch("ucd/auxiliary/WordBreakTest.txt")?;
+    eprintln!("Generating word break tests...");
+    build_break_test(code_dir, source, &word_break_test_txt, "word_boundaries", "word", None)?;
+    eprintln!("Fetching word break properties...");
+    let word_break_property_txt = source.fetch("ucd/auxiliary/WordBreakProperty.txt")?;
+    eprintln!("Generating word break data...");
+    build_word_break_property(code_dir, source, &word_break_property_txt, &emoji_data_txt)?;
+    eprintln!("Fetching sentence break test data...");
+    let sentence_break_test_txt = source.fetch("ucd/auxiliary/SentenceBreakTest.txt")?;
+    eprintln!("Generating sentence break tests...");
+    build_break_test(code_dir, source, &sentence_break_test_txt, "sentences", "sentence", None)?;
+    eprintln!("Fetching sentence break properties...");
+    let sentence_break_property_txt = source.fetch("ucd/auxiliary/SentenceBreakProperty.txt")?;
+    eprintln!("Generating sentence break data...");
+    build_sentence_break_property(code_dir, source, &sentence_break_property_txt)?;
+    eprintln!("Fetching line break test data...");
+    let line_break_test_txt = source.fetch("ucd/auxiliary/LineBreakTest.txt")?;
+    eprintln!("Generating line break tests...");
+    build_break_test(code_dir, source, &line_break_test_txt, "line_break", "line", None)?;
+    eprintln!("Fetching line break properties...");
+    let line_break_txt = source.fetch("ucd/LineBreak.txt")?;
+    let east_asian_width_txt = source.fetch("ucd/EastAsianWidth.txt")?;
+    eprintln!("Generating line break data...");
+    build_line_break_property(code_dir, source, &line_break_txt, &east_asian_width_txt, &unicode_data_txt, &emoji_data_txt)?;
+    eprintln!("Fetching composition exclusions...");
+    let composition_exclusions_txt = source.fetch("ucd/CompositionExclusions.txt")?;
+    eprintln!("Generating normalization data...");
+    build_normalization_tables(code_dir, source, &unicode_data_txt, &composition_exclusions_txt)?;
+    eprintln!("Fetching normalization test data...");
+    let normalization_test_txt = source.fetch("ucd/NormalizationTest.txt")?;
+    eprintln!("Generating normalization tests...");
+    build_normalization_test(code_dir, source, &normalization_test_txt)?;
+    eprintln!("Fetching derived bidi class...");
+    let derived_bidi_class_txt = source.fetch("ucd/extracted/DerivedBidiClass.txt")?;
+    eprintln!("Generating character property data...");
+    build_unicode_data_properties(code_dir, source, &unicode_data_txt, &derived_bidi_class_txt)?;
+    eprintln!("Fetching script data...");
+    let scripts_txt = source.fetch("ucd/Scripts.txt")?;
+    let script_extensions_txt = source.fetch("ucd/ScriptExtensions.txt")?;
+    let property_value_aliases_txt = source.fetch("ucd/PropertyValueAliases.txt")?;
+    eprintln!("Generating script data...");
+    build_script_property(code_dir, source, &scripts_txt, &script_extensions_txt, &property_value_aliases_txt)?;
+    eprintln!("Fetching block data...");
+    let blocks_txt = source.fetch("ucd/Blocks.txt")?;
+    eprintln!("Generating block data...");
+    build_block_list(code_dir, source, &blocks_txt)?;
+    eprintln!("Generating east asian width data...");
+    build_east_asian_width_property(code_dir, source, &east_asian_width_txt)?;
+    eprintln!("Fetching case mapping data...");
+    let special_casing_txt = source.fetch("ucd/SpecialCasing.txt")?;
+    let case_folding_txt = source.fetch("ucd/CaseFolding.txt")?;
+    let prop_list_txt = source.fetch("ucd/PropList.txt")?;
+    eprintln!("Generating case mapping data...");
+    build_case_tables(code_dir, source, &unicode_data_txt, &special_casing_txt, &case_folding_txt, &derived_core_properties_txt, &prop_list_txt)?;
+    eprintln!("Fetching bidi bracket and mirroring data...");
+    let bidi_brackets_txt = source.fetch("ucd/BidiBrackets.txt")?;
+    let bidi_mirroring_txt = source.fetch("ucd/BidiMirroring.txt")?;
+    eprintln!("Generating bidi bracket and mirroring data...");
+    build_bidi_tables(code_dir, source, &bidi_brackets_txt, &bidi_mirroring_txt)?;
+    eprintln!("Fetching bidi test data...");
+    let bidi_test_txt = source.fetch("ucd/BidiTest.txt")?;
+    let bidi_character_test_txt = source.fetch("ucd/BidiCharacterTest.txt")?;
+    eprintln!("Generating bidi tests...");
+    build_bidi_test(code_dir, source, &bidi_test_txt)?;
+    build_bidi_character_test(code_dir, source, &bidi_character_test_txt)?;
+    eprintln!("Generating identifier data...");
+    build_identifier_property(code_dir, source, &derived_core_properties_txt, &prop_list_txt)?;
+    eprintln!("Generating binary property data...");
+    build_binary_properties(code_dir, source, &prop_list_txt, &derived_core_properties_txt)?;
+    eprintln!("Generating emoji property data...");
+    build_emoji_property(code_dir, source, &emoji_data_txt)?;
+    eprintln!("Fetching emoji sequences...");
+    let emoji_sequences_txt = source.fetch("emoji/emoji-sequences.txt")?;
+    let emoji_zwj_sequences_txt = source.fetch("emoji/emoji-zwj-sequences.txt")?;
+    eprintln!("Generating emoji sequence data...");
+    build_emoji_sequences(code_dir, source, &emoji_sequences_txt, &emoji_zwj_sequences_txt)?;
+    eprintln!("Fetching name aliases...");
+    let name_aliases_txt = source.fetch("ucd/NameAliases.txt")?;
+    eprintln!("Generating name data...");
+    build_names(code_dir, source, &unicode_data_txt, &name_aliases_txt)?;
+    Ok(())
+}
+
+// Only the category and grapheme cluster tables can be pinned to an older version of Unicode.
+// They go in a module of their own under `code_dir` which is selected with a cargo feature of the
+// same name, e.g., `unicode_14` for 14.0.0.
+fn generate_pinned_tables(code_dir: &OsString, source: &mut UnicodeSource, unicode_version: &str) -> anyhow::Result<()> {
+    let module = format!("unicode_{}", unicode_version.trim_end_matches(".0").replace('.', "_"));
+    let module_dir = Path::new(code_dir).join(&module);
+    std::fs::create_dir_all(&module_dir)?;
+    let module_dir = module_dir.into_os_string();
+
+    eprintln!("Fetching Unicode data...");
+    let unicode_data_txt = source.fetch("ucd/UnicodeData.txt")?;
+    eprintln!("Generating category data...");
+    build_character_tables(&module_dir, source, &unicode_data_txt)?;
+    eprintln!("Fetching grapheme break properties...");
+    let grapheme_break_property_txt = source.fetch("ucd/auxiliary/GraphemeBreakProperty.txt")?;
+    let emoji_data_txt = source.fetch("ucd/emoji/emoji-data.txt")?;
+    let derived_core_properties_txt = source.fetch("ucd/DerivedCoreProperties.txt")?;
     eprintln!("Generating grapheme break data...");
-    build_grapheme_break_property(&code_dir, &grapheme_break_property_txt, &emoji_data_txt)?;
+    build_grapheme_break_property(&module_dir, source, &grapheme_break_property_txt, &emoji_data_txt, &derived_core_properties_txt)?;
+    eprintln!("Fetching grapheme test data...");
+    let grapheme_break_test_txt = source.fetch("ucd/auxiliary/GraphemeBreakTest.txt")?;
+    eprintln!("Generating grapheme tests...");
+    build_break_test(&module_dir, source, &grapheme_break_test_txt, "grapheme_clusters", "grapheme", None)?;
+
+    let mut mod_rs = File::create(Path::new(&module_dir).join("mod.rs"))?;
+    writeln!(mod_rs, "// GENERATED CODE DO NOT MANUALLY EDIT")?;
+    writeln!(mod_rs, "// Category and grapheme cluster tables for Unicode {unicode_version}")?;
+    let (major, minor, update) = parse_unicode_version(unicode_version)?;
+    write!(mod_rs, "{}", cfg_attribute(vec![any_of(&PINNED_FEATURES.map(feature_condition))]))?;
+    writeln!(mod_rs, "pub const UNICODE_VERSION: (u8, u8, u8) = ({major}, {minor}, {update});")?;
+    for module in &PINNED_MODULES {
+        write!(mod_rs, "{}", module.declaration(&[]))?;
+    }
+    build_data_module(code_dir)?;
+    Ok(())
+}
+
+// A module of generated code in `code_dir`, the cargo features which include it (it's always
+// compiled if there are none) and the lint which its tables need allowed. Test data is only
+// compiled for tests.
+struct DataModule {
+    name: &'static str,
+    features: &'static [&'static str],
+    test: bool,
+    allow: Option<&'static str>,
+}
+
+impl DataModule {
+    // The `pub mod` declaration of the module, with `extra` conditions for compiling it
+    fn declaration(&self, extra: &[String]) -> String {
+        let lint = self.allow.map(|lint| format!("#[allow({lint})]\n")).unwrap_or_default();
+        format!("{}{lint}pub mod {};\n", cfg_attribute(self.conditions(extra)), self.name)
+    }
+
+    fn conditions(&self, extra: &[String]) -> Vec<String> {
+        let mut conditions = Vec::new();
+        if self.test {
+            conditions.push("test".to_owned());
+        }
+        if !self.features.is_empty() {
+            conditions.push(any_of(&self.features.iter().copied().map(feature_condition).collect_vec()));
+        }
+        conditions.extend_from_slice(extra);
+        conditions
+    }
+}
+
+fn feature_condition(feature: &str) -> String {
+    format!("feature = \"{feature}\"")
+}
+
+fn any_of(conditions: &[String]) -> String {
+    match conditions {
+        [condition] => condition.clone(),
+        _ => format!("any({})", conditions.join(", ")),
+    }
+}
+
+// A `cfg` attribute which requires all of `conditions`
+fn cfg_attribute(conditions: Vec<String>) -> String {
+    match conditions.as_slice() {
+        [] => String::new(),
+        [condition] => format!("#[cfg({condition})]\n"),
+        _ => format!("#[cfg(all({}))]\n", conditions.join(", ")),
+    }
+}
+
+const LARGE_ARRAYS: Option<&str> = Some("clippy::large_const_arrays");
+
+// The tables which can be pinned to an older version of Unicode. These come from the module for
+// the pinned version when its feature is enabled.
+const PINNED_MODULES: [DataModule; 3] = [
+    DataModule { name: "characters", features: &["categories"], test: false, allow: LARGE_ARRAYS },
+    DataModule { name: "grapheme_property", features: &["grapheme_clusters"], test: false, allow: LARGE_ARRAYS },
+    DataModule { name: "grapheme_test", features: &["grapheme_clusters"], test: true, allow: None },
+];
+const PINNED_FEATURES: [&str; 2] = ["categories", "grapheme_clusters"];
+
+const DATA_MODULES: [DataModule; 29] = [
+    DataModule { name: "word_property", features: &["word_boundaries"], test: false, allow: LARGE_ARRAYS },
+    DataModule { name: "word_test", features: &["word_boundaries"], test: true, allow: None },
+    DataModule { name: "sentence_property", features: &["sentences"], test: false, allow: LARGE_ARRAYS },
+    DataModule { name: "sentence_test", features: &["sentences"], test: true, allow: None },
+    DataModule { name: "line_property", features: &["line_break"], test: false, allow: LARGE_ARRAYS },
+    DataModule { name: "line_test", features: &["line_break"], test: true, allow: None },
+    DataModule { name: "combining_class", features: &["normalization", "properties"], test: false, allow: LARGE_ARRAYS },
+    DataModule { name: "normalization_property", features: &["normalization"], test: false, allow: LARGE_ARRAYS },
+    DataModule { name: "decompositions", features: &["normalization"], test: false, allow: LARGE_ARRAYS },
+    DataModule { name: "normalization_test", features: &["normalization"], test: true, allow: None },
+    DataModule { name: "bidi_class", features: &["properties"], test: false, allow: LARGE_ARRAYS },
+    DataModule { name: "decomposition_type", features: &["properties"], test: false, allow: LARGE_ARRAYS },
+    DataModule { name: "numeric_value", features: &["properties"], test: false, allow: LARGE_ARRAYS },
+    DataModule { name: "scripts", features: &["scripts"], test: false, allow: None },
+    DataModule { name: "script_property", features: &["scripts"], test: false, allow: LARGE_ARRAYS },
+    DataModule { name: "script_extensions", features: &["scripts"], test: false, allow: LARGE_ARRAYS },
+    DataModule { name: "blocks", features: &["blocks"], test: false, allow: None },
+    DataModule { name: "east_asian_width", features: &["east_asian_width"], test: false, allow: LARGE_ARRAYS },
+    DataModule { name: "case_mapping", features: &["case"], test: false, allow: Some("clippy::type_complexity") },
+    DataModule { name: "case_property", features: &["case"], test: false, allow: LARGE_ARRAYS },
+    DataModule { name: "bidi_brackets", features: &["bidi"], test: false, allow: None },
+    DataModule { name: "bidi_test", features: &["bidi"], test: true, allow: None },
+    DataModule { name: "bidi_character_test", features: &["bidi"], test: true, allow: None },
+    DataModule { name: "identifier_property", features: &["identifiers"], test: false, allow: LARGE_ARRAYS },
+    DataModule { name: "binary_properties", features: &["binary_properties"], test: false, allow: None },
+    DataModule { name: "binary_property", features: &["binary_properties"], test: false, allow: LARGE_ARRAYS },
+    DataModule { name: "emoji_property", features: &["emoji"], test: false, allow: LARGE_ARRAYS },
+    DataModule { name: "emoji_sequences", features: &["emoji"], test: false, allow: LARGE_ARRAYS },
+    DataModule { name: "names", features: &["names"], test: false, allow: None },
+];
+
+// `src/data/mod.rs` declares the generated modules and lists the tables which are compiled in for
+// `finl_unicode::COMPILED_TABLES`. The pinned versions are the `unicode_*` modules which have been
+// generated in `code_dir`. The features for them are additive: if more than one is enabled, the
+// oldest version wins.
+fn build_data_module(code_dir: &OsString) -> anyhow::Result<()> {
+    let mut pinned = Vec::new();
+    for entry in std::fs::read_dir(code_dir)? {
+        let path = entry?.path();
+        let Some(name) = path.file_name().and_then(OsStr::to_str) else { continue };
+        if let Some(version) = name.strip_prefix("unicode_") {
+            if path.join("mod.rs").is_file() {
+                let version = version.split('_').map(str::parse::<u8>).collect::<Result<Vec<u8>, _>>()?;
+                pinned.push((version, name.to_owned()));
+            }
+        }
+    }
+    pinned.sort();
+    let pinned = pinned.into_iter().map(|(_, name)| name).collect_vec();
+    let pinned_features = pinned.iter().map(|name| feature_condition(name)).collect_vec();
+
+    let data_mod_rs = Path::new(code_dir).join("mod.rs");
+    let mut data_mod_rs = File::create(data_mod_rs)?;
+    writeln!(data_mod_rs, "// GENERATED CODE DO NOT MANUALLY EDIT")?;
+    if !pinned.is_empty() {
+        writeln!(data_mod_rs, "// Pinned tables: {}", pinned.join(", "))?;
+    }
+    // The pinned version is only needed to list the tables which use it.
+    let versioned = any_of(&PINNED_FEATURES.map(feature_condition));
+    let current = (!pinned.is_empty()).then(|| format!("not({})", any_of(&pinned_features))).into_iter().collect_vec();
+    for module in &PINNED_MODULES {
+        write!(data_mod_rs, "{}", module.declaration(&current))?;
+    }
+    write!(data_mod_rs, "{}", cfg_attribute([versioned.clone()].into_iter().chain(current).collect()))?;
+    writeln!(data_mod_rs, "pub use unicode_version::UNICODE_VERSION as PINNED_UNICODE_VERSION;")?;
+    for (index, module) in pinned.iter().enumerate() {
+        let mut conditions = vec![pinned_features[index].clone()];
+        if index > 0 {
+            conditions.push(format!("not({})", any_of(&pinned_features[..index])));
+        }
+        write!(data_mod_rs, "{}", cfg_attribute(conditions.clone()))?;
+        writeln!(data_mod_rs, "mod {module};")?;
+        // The test for the pinned version is compiled in its module, so only the tables are used
+        // from here.
+        for table in PINNED_MODULES.iter().filter(|table| !table.test) {
+            write!(data_mod_rs, "{}", cfg_attribute(table.conditions(&conditions)))?;
+            writeln!(data_mod_rs, "pub use {module}::{};", table.name)?;
+        }
+        write!(data_mod_rs, "{}", cfg_attribute([versioned.clone()].into_iter().chain(conditions).collect()))?;
+        writeln!(data_mod_rs, "pub use {module}::UNICODE_VERSION as PINNED_UNICODE_VERSION;")?;
+    }
+    writeln!(data_mod_rs, "pub mod unicode_version;")?;
+    for module in &DATA_MODULES {
+        write!(data_mod_rs, "{}", module.declaration(&[]))?;
+    }
+
+    writeln!(data_mod_rs)?;
+    writeln!(data_mod_rs, "pub const COMPILED_TABLES: &[crate::DataTable] = &[")?;
+    let features = DATA_MODULES.iter()
+        .filter(|module| !module.test)
+        .flat_map(|module| module.features.iter().copied())
+        .unique();
+    for feature in PINNED_FEATURES.into_iter().chain(features) {
+        let version = if PINNED_FEATURES.contains(&feature) { "PINNED_UNICODE_VERSION" } else { "unicode_version::UNICODE_VERSION" };
+        writeln!(data_mod_rs, "\t#[cfg(feature = \"{feature}\")]")?;
+        writeln!(data_mod_rs, "\tcrate::DataTable {{ feature: \"{feature}\", unicode_version: {version} }},")?;
+    }
+    writeln!(data_mod_rs, "];")?;
     Ok(())
 }
 
+// The version of Unicode the tables were generated from is recorded in the code so that it can be
+// checked by users of the crate.
+fn build_unicode_version(out_dir: &OsString, unicode_version: &str) -> anyhow::Result<()> {
+    let (major, minor, update) = parse_unicode_version(unicode_version)?;
+    let unicode_version_rs = Path::new(out_dir).join("unicode_version.rs");
+    let mut unicode_version_rs = File::create(unicode_version_rs)?;
+    writeln!(unicode_version_rs, "// GENERATED CODE DO NOT MANUALLY EDIT")?;
+    writeln!(unicode_version_rs, "pub const UNICODE_VERSION: (u8, u8, u8) = ({major}, {minor}, {update});")?;
+    Ok(())
+}
+
+fn parse_unicode_version(unicode_version: &str) -> anyhow::Result<(u8, u8, u8)> {
+    match unicode_version.split('.').map(str::parse::<u8>).collect::<Result<Vec<u8>, _>>() {
+        Ok(parts) if parts.len() == 3 => Ok((parts[0], parts[1], parts[2])),
+        _ => bail!("{unicode_version} is not a Unicode version number of the form 16.0.0"),
+    }
+}
+
+const USAGE: &str = "\
+Usage: generate-sources [OPTIONS]
+
+Options:
+    --version <VERSION>        The Unicode version to generate tables for (default: 16.0.0)
+    --ucd <DIR|ZIP>            Read the data files from a local copy of
+                               https://www.unicode.org/Public/<VERSION>/ rather than downloading them;
+                               either an extracted directory or a zip file (such as UCD.zip), which
+                               is unpacked under target/tmp
+    --manifest <FILE>          A checksum manifest (as written by sha256sum) to verify the data files
+                               against (default: SHA256SUMS in the local copy if there is one)
+    --write-manifest <FILE>    Write a checksum manifest of the data files which were used
+    --out <DIR>                Where to write the generated code (default: ../src/data)
+    --pinned                   Only generate the category and grapheme cluster tables, in a module
+                               named for the version (e.g., unicode_14) for use with the cargo
+                               feature of the same name. Either way, mod.rs is rewritten to list
+                               the generated modules and tables, including the pinned ones
+    --help                     Print this message
+";
+
+struct Options {
+    version: String,
+    ucd: Option<PathBuf>,
+    manifest: Option<PathBuf>,
+    write_manifest: Option<PathBuf>,
+    out: Option<PathBuf>,
+    pinned: bool,
+}
+
+fn parse_options() -> anyhow::Result<Options> {
+    let mut options = Options {
+        version: "16.0.0".to_owned(),
+        ucd: None,
+        manifest: None,
+        write_manifest: None,
+        out: None,
+        pinned: false,
+    };
+    let mut args = env::args_os().skip(1);
+    while let Some(arg) = args.next() {
+        let arg = arg.to_string_lossy().into_owned();
+        if arg == "--help" {
+            print!("{USAGE}");
+            std::process::exit(0);
+        }
+        if arg == "--pinned" {
+            options.pinned = true;
+            continue;
+        }
+        let Some(value) = args.next() else {
+            bail!("Missing value for {arg}\n\n{USAGE}");
+        };
+        match arg.as_str() {
+            "--version" => options.version = value.to_string_lossy().into_owned(),
+            "--ucd" => options.ucd = Some(value.into()),
+            "--manifest" => options.manifest = Some(value.into()),
+            "--write-manifest" => options.write_manifest = Some(value.into()),
+            "--out" => options.out = Some(value.into()),
+            _ => bail!("Unknown option {arg}\n\n{USAGE}"),
+        }
+    }
+    parse_unicode_version(&options.version)?;
+    Ok(options)
+}
+
 
 // We store the category code as a u8 value with the following meaning:
 // High nibble:
@@ -65,8 +450,8 @@ fn main() -> anyhow::Result<()> {
 // Lu	Uppercase_Letter	x90
 // Ll	Lowercase_Letter	x91
 // Lt	Titlecase_Letter	x92
-// Lm	Modifier_Letter	    x80
-// Lo	Other_Letter	    x81
+// Lm	Modifier_Letter	    x83
+// Lo	Other_Letter	    x84
 // Mn	Nonspacing_Mark	    x10
 // Mc	Spacing_Mark	    x11
 // Me	Enclosing_Mark	    x12
@@ -87,11 +472,11 @@ fn main() -> anyhow::Result<()> {
 // Zs	Space_Separator	    x50
 // Zl	Line_Separator	    x51
 // Zp	Paragraph_Separator	x52
-// Cc	Control	            x60
-// Cf	Format	            x61
-// Cs	Surrogate	        x62
-// Co	Private_Use	        x63
-// Cn	Unassigned	        x64
+// Cc	Control	            x61
+// Cf	Format	            x62
+// Cs	Surrogate	        x63
+// Co	Private_Use	        x64
+// Cn	Unassigned	        x60
 fn cat_to_u8(cat: &str) -> u8 {
     match cat {
         "Lu" => 0x90,
@@ -129,15 +514,23 @@ fn cat_to_u8(cat: &str) -> u8 {
 
 // Credit to https://here-be-braces.com/fast-lookup-of-unicode-properties/ for the broad outline of
 // how this would work. The coding of categories into bytes is my own.
-fn build_character_tables(out_dir: &OsStr, unicode_data_txt: &PathBuf) -> anyhow::Result<()> {
+fn build_character_tables(out_dir: &OsStr, source: &UnicodeSource, unicode_data_txt: &PathBuf) -> anyhow::Result<()> {
+    let header = source.header(&[unicode_data_txt]);
     let characters_rs = Path::new(out_dir).join("characters.rs");
     let characters_rs = File::create(characters_rs)?;
+    let raw_categories = read_categories(unicode_data_txt)?;
+
+    write_data_tables(characters_rs, &header, &raw_categories, "CAT_TABLE", "CAT_PAGES")
+}
+
+// Read the general category of every code point from UnicodeData.txt, coded as by `cat_to_u8`.
+fn read_categories(unicode_data_txt: &PathBuf) -> anyhow::Result<Vec<u8>> {
     let unicode_data = File::open(unicode_data_txt)?;
     let unicode_data = BufReader::new(unicode_data);
 
     // First we build a raw (large) index of all the character codes in numeric format
     // Note that we actually allocate an array slightly larger than Unicode uses
-    let mut raw_categories = [0x60u8;0x110000];
+    let mut raw_categories = vec![0x60u8;0x110000];
     let mut range_start = 0;
     for line in unicode_data.lines() {
         let line = line.unwrap();
@@ -153,9 +546,7 @@ fn build_character_tables(out_dir: &OsStr, unicode_data_txt: &PathBuf) -> anyhow
                 }
                 else if char_name.ends_with(", Last>") {
                     let cat_code = cat_to_u8(category);
-                    for i in range_start..=char_code {
-                        raw_categories[i] = cat_code;
-                    }
+                    raw_categories[range_start..=char_code].fill(cat_code);
                 }
                 else {
                     raw_categories[char_code] = cat_to_u8(category);
@@ -163,15 +554,151 @@ fn build_character_tables(out_dir: &OsStr, unicode_data_txt: &PathBuf) -> anyhow
             }
         }
     }
+    Ok(raw_categories)
+}
+
+// The Bidi_Class is stored in the low five bits with Bidi_Mirrored in the high bit. L is 0 since
+// it's the default for unassigned code points outside of the right-to-left blocks.
+fn encode_bidi_class(property: &str) -> u8 {
+    match property {
+        "R" | "Right_To_Left" => 0x01,
+        "AL" | "Arabic_Letter" => 0x02,
+        "EN" | "European_Number" => 0x03,
+        "ES" | "European_Separator" => 0x04,
+        "ET" | "European_Terminator" => 0x05,
+        "AN" | "Arabic_Number" => 0x06,
+        "CS" | "Common_Separator" => 0x07,
+        "NSM" | "Nonspacing_Mark" => 0x08,
+        "BN" | "Boundary_Neutral" => 0x09,
+        "B" | "Paragraph_Separator" => 0x0a,
+        "S" | "Segment_Separator" => 0x0b,
+        "WS" | "White_Space" => 0x0c,
+        "ON" | "Other_Neutral" => 0x0d,
+        "LRE" | "Left_To_Right_Embedding" => 0x0e,
+        "LRO" | "Left_To_Right_Override" => 0x0f,
+        "RLE" | "Right_To_Left_Embedding" => 0x10,
+        "RLO" | "Right_To_Left_Override" => 0x11,
+        "PDF" | "Pop_Directional_Format" => 0x12,
+        "LRI" | "Left_To_Right_Isolate" => 0x13,
+        "RLI" | "Right_To_Left_Isolate" => 0x14,
+        "FSI" | "First_Strong_Isolate" => 0x15,
+        "PDI" | "Pop_Directional_Isolate" => 0x16,
+        _ => 0x00,
+    }
+}
+
+// 0 means that the character has no decomposition mapping.
+fn encode_decomposition_type(decomposition: &str) -> u8 {
+    match decomposition.split_once('>') {
+        None if decomposition.is_empty() => 0x00,
+        None => 0x01,
+        Some((tag, _)) => match tag {
+            "<font" => 0x02,
+            "<noBreak" => 0x03,
+            "<initial" => 0x04,
+            "<medial" => 0x05,
+            "<final" => 0x06,
+            "<isolated" => 0x07,
+            "<circle" => 0x08,
+            "<super" => 0x09,
+            "<sub" => 0x0a,
+            "<vertical" => 0x0b,
+            "<wide" => 0x0c,
+            "<narrow" => 0x0d,
+            "<small" => 0x0e,
+            "<square" => 0x0f,
+            "<fraction" => 0x10,
+            _ => 0x11, // <compat>
+        }
+    }
+}
+
+// The numeric type is stored as 1 for Decimal, 2 for Digit and 3 for Numeric.
+fn encode_numeric_type(fields: &[&str]) -> u8 {
+    if !fields[6].is_empty() { 1 }
+    else if !fields[7].is_empty() { 2 }
+    else { 3 }
+}
+
+// The remaining fields of UnicodeData.txt are kept in three tables:
+// - bidi_class.rs has the Bidi_Class and Bidi_Mirrored as coded by `encode_bidi_class`. The
+//   defaults for unassigned code points are taken from the @missing lines of DerivedBidiClass.txt.
+// - decomposition_type.rs has the Decomposition_Type as coded by `encode_decomposition_type`.
+// - numeric_value.rs has an index into `NUMERIC_VALUES` (offset by one so that 0 can mean that
+//   there is no numeric value), which lists each distinct numeric type and value.
+// The canonical combining class table is written by `build_normalization_tables`.
+fn build_unicode_data_properties(out_dir: &OsString, source: &UnicodeSource, unicode_data_txt: &PathBuf, derived_bidi_class_txt: &PathBuf) -> anyhow::Result<()> {
+    let header = source.header(&[unicode_data_txt, derived_bidi_class_txt]);
+    let bidi_class_rs = Path::new(out_dir).join("bidi_class.rs");
+    let bidi_class_rs = File::create(bidi_class_rs)?;
+    let decomposition_type_rs = Path::new(out_dir).join("decomposition_type.rs");
+    let decomposition_type_rs = File::create(decomposition_type_rs)?;
+    let numeric_value_rs = Path::new(out_dir).join("numeric_value.rs");
+    let mut numeric_value_rs = File::create(numeric_value_rs)?;
+    let unicode_data = File::open(unicode_data_txt)?;
+    let unicode_data = BufReader::new(unicode_data);
+    let derived_bidi_class = File::open(derived_bidi_class_txt)?;
+    let derived_bidi_class = BufReader::new(derived_bidi_class);
+
+    let mut bidi_classes = [0u8;0x110000];
+    for line in derived_bidi_class.lines() {
+        let line = line.unwrap();
+        if line.starts_with("# @missing:") {
+            if let Some((range, property)) = split_property_line(&line) {
+                bidi_classes.get_mut(str_to_range(range)).unwrap().fill(encode_bidi_class(property));
+            }
+        }
+    }
 
-    write_data_tables(characters_rs, &raw_categories, "CAT_TABLE", "CAT_PAGES")
+    let mut decomposition_types = [0u8;0x110000];
+    let mut numeric_indices = [0u8;0x110000];
+    let mut numeric_values = vec!();
+    let mut range_start = 0;
+    for line in unicode_data.lines() {
+        let line = line.unwrap();
+        let fields = line.split(';').collect_vec();
+        let char_code = usize::from_str_radix(fields[0], 16)?;
+        let range = if fields[1].ends_with(", First>") {
+            range_start = char_code;
+            continue;
+        }
+        else if fields[1].ends_with(", Last>") {
+            range_start..=char_code
+        }
+        else {
+            char_code..=char_code
+        };
+        let mirrored = if fields[9] == "Y" { 0x80 } else { 0x00 };
+        bidi_classes.get_mut(range.clone()).unwrap().fill(encode_bidi_class(fields[4]) | mirrored);
+        decomposition_types.get_mut(range.clone()).unwrap().fill(encode_decomposition_type(fields[5]));
+        if !fields[8].is_empty() {
+            let (numerator, denominator) = fields[8].split_once('/').unwrap_or((fields[8], "1"));
+            let value = (encode_numeric_type(&fields), numerator.parse::<i64>()?, denominator.parse::<u32>()?);
+            let index = match numeric_values.iter().position(|&v| v == value) {
+                Some(index) => index,
+                None => {
+                    numeric_values.push(value);
+                    numeric_values.len() - 1
+                }
+            };
+            numeric_indices.get_mut(range).unwrap().fill(u8::try_from(index + 1)?);
+        }
+    }
+    // Hangul syllables are decomposed algorithmically so they have no decomposition in UnicodeData.txt
+    decomposition_types[HANGUL_SYLLABLES].fill(0x01);
+
+    write_data_tables(bidi_class_rs, &header, &bidi_classes, "BIDI_TABLE", "BIDI_PAGES")?;
+    write_data_tables(decomposition_type_rs, &header, &decomposition_types, "DT_TABLE", "DT_PAGES")?;
+    write_data_tables(numeric_value_rs.try_clone()?, &header, &numeric_indices, "NV_TABLE", "NV_PAGES")?;
+    writeln!(numeric_value_rs, "pub const NUMERIC_VALUES: [(u8, i64, u32);{}] = [", numeric_values.len())?;
+    for (numeric_type, numerator, denominator) in numeric_values {
+        writeln!(numeric_value_rs, "\t({numeric_type}, {numerator}, {denominator}),")?;
+    }
+    writeln!(numeric_value_rs, "];")?;
+    Ok(())
 }
 
-fn build_grapheme_break_test(out_dir: &OsString, grapheme_break_test_txt: &PathBuf) -> anyhow::Result<()>  {
-    let grapheme_test_rs = Path::new(out_dir).join("grapheme_test.rs");
-    let mut grapheme_test_rs = File::create(grapheme_test_rs)?;
-    let grapheme_break_test = File::open(grapheme_break_test_txt)?;
-    let grapheme_break_test = BufReader::new(grapheme_break_test);
+fn build_grapheme_break_test(out_dir: &OsString, source: &UnicodeSource, grapheme_break_test_txt: &PathBuf) -> anyhow::Result<()>  {
     let mut grapheme_bench_txt = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
     grapheme_bench_txt.push("..");
     grapheme_bench_txt.push("resources");
@@ -180,45 +707,64 @@ fn build_grapheme_break_test(out_dir: &OsString, grapheme_break_test_txt: &PathB
 
     writeln!(grapheme_bench_txt, "Automatically generated data file DO NOT EDIT MANUALLY")?;
 
-    writeln!(grapheme_test_rs, "// GENERATED CODE DO NOT MANUALLY EDIT")?;
-    writeln!(grapheme_test_rs)?;
-    writeln!(grapheme_test_rs, "use crate::grapheme_clusters::tests::grapheme_test;")?;
-    writeln!(grapheme_test_rs)?;
-    writeln!(grapheme_test_rs, "#[test]")?;
-    writeln!(grapheme_test_rs, "fn standard_grapheme_test() {{")?;
-    for line in grapheme_break_test.lines() {
+    build_break_test(out_dir, source, grapheme_break_test_txt, "grapheme_clusters", "grapheme", Some(grapheme_bench_txt))
+}
+
+// The break test files (GraphemeBreakTest.txt, WordBreakTest.txt, etc.) all share the same format
+// so we generate a test from each in the same way. The generated test calls `{kind}_test` in the
+// `tests` module of `module` for each line of the file.
+fn build_break_test(out_dir: &OsString, source: &UnicodeSource, break_test_txt: &PathBuf, module: &str, kind: &str, mut bench_txt: Option<File>) -> anyhow::Result<()>  {
+    let header = source.header(&[break_test_txt]);
+    let test_rs = Path::new(out_dir).join(format!("{kind}_test.rs"));
+    let mut test_rs = File::create(test_rs)?;
+    let break_test = File::open(break_test_txt)?;
+    let break_test = BufReader::new(break_test);
+
+    write!(test_rs, "{header}")?;
+    writeln!(test_rs)?;
+    writeln!(test_rs, "use crate::{module}::tests::{kind}_test;")?;
+    writeln!(test_rs)?;
+    writeln!(test_rs, "#[test]")?;
+    writeln!(test_rs, "fn standard_{kind}_test() {{")?;
+    for line in break_test.lines() {
         let line = line.unwrap();
         if let Some((map, comment)) = line.split_once('#') {
-            if map.len() > 0 {
+            if !map.is_empty() {
                 let mut input_string = String::new();
                 let mut output_string:Vec<String> = vec!();
-                let mut current_grapheme = String::new();
-                writeln!(grapheme_bench_txt, "{}", map)?;
+                let mut current_segment = String::new();
+                if let Some(bench_txt) = bench_txt.as_mut() {
+                    writeln!(bench_txt, "{}", map)?;
+                }
                 for token in map.split_whitespace() {
                     match token {
                         "÷" => {
-                            if current_grapheme.len() > 0 {
-                                output_string.push(current_grapheme);
-                                current_grapheme = String::new();
+                            if !current_segment.is_empty() {
+                                output_string.push(current_segment);
+                                current_segment = String::new();
                             }
                         }
                         "×" => {} // No action necessary here (!)
                         hex_code => {
-                            write!(grapheme_bench_txt, "{}", char::from_u32(u32::from_str_radix(hex_code, 16).unwrap()).unwrap())?;
+                            if let Some(bench_txt) = bench_txt.as_mut() {
+                                write!(bench_txt, "{}", char::from_u32(u32::from_str_radix(hex_code, 16).unwrap()).unwrap())?;
+                            }
                             let hex_code = "\\u{".to_string() + hex_code + "}";
                             input_string.push_str(&hex_code);
-                            current_grapheme.push_str(&hex_code);
+                            current_segment.push_str(&hex_code);
                         }
                     }
                 }
-                writeln!(grapheme_bench_txt)?;
+                if let Some(bench_txt) = bench_txt.as_mut() {
+                    writeln!(bench_txt)?;
+                }
                 let output_string = output_string.join("\", \"");
 
-                writeln!(grapheme_test_rs, "\tgrapheme_test(\"{input_string}\",\n\t\t&[\"{output_string}\"],\n\t\t\"{comment}\"\n\t);")?;
+                writeln!(test_rs, "\t{kind}_test(\"{input_string}\",\n\t\t&[\"{output_string}\"],\n\t\t\"{comment}\"\n\t);")?;
             }
         }
     }
-    writeln!(grapheme_test_rs, "}}")?;
+    writeln!(test_rs, "}}")?;
     Ok(())
 }
 
@@ -243,6 +789,19 @@ fn encode_property(property: &str) -> u8 {
     }
 }
 
+// The Indic_Conjunct_Break property (used by GB9c) is stored in the high nibble of the grapheme
+// property so that it can be looked up along with the Grapheme_Cluster_Break value. Every
+// character with InCB=Linker or InCB=Extend is also Extend or ZWJ for Grapheme_Cluster_Break and
+// every InCB=Consonant is Other, so the low nibble is unaffected.
+fn encode_indic_conjunct_break(property: &str) -> u8 {
+    match property {
+        "Consonant" => 0x10,
+        "Linker" => 0x20,
+        "Extend" => 0x40,
+        _ => 0x00,
+    }
+}
+
 fn str_to_range(range: &str) -> RangeInclusive<usize> {
     if let Some((first, last)) = range.split_once("..") {
         u32::from_str_radix(first, 16).unwrap() as usize ..=
@@ -254,13 +813,16 @@ fn str_to_range(range: &str) -> RangeInclusive<usize> {
     }
 }
 
-fn build_grapheme_break_property(out_dir: &OsString, grapheme_break_property_txt: &PathBuf, emoji_data_txt: &PathBuf) -> anyhow::Result<()> {
+fn build_grapheme_break_property(out_dir: &OsString, source: &UnicodeSource, grapheme_break_property_txt: &PathBuf, emoji_data_txt: &PathBuf, derived_core_properties_txt: &PathBuf) -> anyhow::Result<()> {
+    let header = source.header(&[grapheme_break_property_txt, emoji_data_txt, derived_core_properties_txt]);
     let grapheme_property_rs = Path::new(out_dir).join("grapheme_property.rs");
     let grapheme_property_rs = File::create(grapheme_property_rs)?;
     let grapheme_break_property = File::open(grapheme_break_property_txt)?;
     let grapheme_break_property = BufReader::new(grapheme_break_property);
     let emoji_data = File::open(emoji_data_txt)?;
     let emoji_data = BufReader::new(emoji_data);
+    let derived_core_properties = File::open(derived_core_properties_txt)?;
+    let derived_core_properties = BufReader::new(derived_core_properties);
 
     // first pass: build an array of all the properties
     let mut raw_grapheme_properties = [0u8;0x110000];
@@ -289,7 +851,22 @@ fn build_grapheme_break_property(out_dir: &OsString, grapheme_break_property_txt
         }
     }
 
-    write_data_tables(grapheme_property_rs, &raw_grapheme_properties, "GP_TABLE", "GP_PAGES")
+    // add the Indic conjunct break values from the derived core properties
+    for line in derived_core_properties.lines() {
+        let line = line.unwrap();
+        if let Some((line, _)) = line.split_once('#') {
+            if let Some((range, property)) = line.split_once(';') {
+                if let Some(("InCB", value)) = property.split_once(';').map(|(p, v)| (p.trim(), v.trim())) {
+                    let incb = encode_indic_conjunct_break(value);
+                    raw_grapheme_properties.get_mut(str_to_range(range.trim())).unwrap()
+                        .iter_mut()
+                        .for_each(|property| *property |= incb);
+                }
+            }
+        }
+    }
+
+    write_data_tables(grapheme_property_rs, &header, &raw_grapheme_properties, "GP_TABLE", "GP_PAGES")
     // Then we break it down into pages (wrapping the result with a bit of Rust boilerplate)
     // writeln!(grapheme_property_rs, "// GENERATED CODE DO NOT MANUALLY EDIT")?;
     // writeln!(grapheme_property_rs, "pub const GP_TABLE: [u8;0x1100] = [")?;
@@ -316,8 +893,1394 @@ fn build_grapheme_break_property(out_dir: &OsString, grapheme_break_property_txt
     // Ok(())
 }
 
-fn write_data_tables(mut rust_file : File, raw_data: &[u8], table_name: &str, pages_name: &str) -> anyhow::Result<()> {
-    writeln!(rust_file, "// GENERATED CODE DO NOT MANUALLY EDIT")?;
+// The Word_Break property is stored in the low five bits. Since WB3c needs to know whether a
+// character is Extended_Pictographic and there are characters which are both Extended_Pictographic
+// and ALetter, that is stored separately in the high bit.
+fn encode_word_break_property(property: &str) -> u8 {
+    match property {
+        "CR" => 0x01,
+        "LF" => 0x02,
+        "Newline" => 0x03,
+        "Extend" => 0x04,
+        "ZWJ" => 0x05,
+        "Regional_Indicator" => 0x06,
+        "Format" => 0x07,
+        "Katakana" => 0x08,
+        "Hebrew_Letter" => 0x09,
+        "ALetter" => 0x0a,
+        "Single_Quote" => 0x0b,
+        "Double_Quote" => 0x0c,
+        "MidNumLet" => 0x0d,
+        "MidLetter" => 0x0e,
+        "MidNum" => 0x0f,
+        "Numeric" => 0x10,
+        "ExtendNumLet" => 0x11,
+        "WSegSpace" => 0x12,
+        _ => 0x00,
+    }
+}
+
+fn build_word_break_property(out_dir: &OsString, source: &UnicodeSource, word_break_property_txt: &PathBuf, emoji_data_txt: &PathBuf) -> anyhow::Result<()> {
+    let header = source.header(&[word_break_property_txt, emoji_data_txt]);
+    let word_property_rs = Path::new(out_dir).join("word_property.rs");
+    let word_property_rs = File::create(word_property_rs)?;
+    let word_break_property = File::open(word_break_property_txt)?;
+    let word_break_property = BufReader::new(word_break_property);
+    let emoji_data = File::open(emoji_data_txt)?;
+    let emoji_data = BufReader::new(emoji_data);
+
+    let mut raw_word_properties = [0u8;0x110000];
+    for line in word_break_property.lines() {
+        let line = line.unwrap();
+        if let Some((line, _)) = line.split_once('#') {
+            if let Some((range, property)) = line.split_once(';') {
+                let range = range.trim();
+                let property = property.trim();
+                raw_word_properties.get_mut(str_to_range(range)).unwrap().fill(encode_word_break_property(property));
+            }
+        }
+    }
+
+    for line in emoji_data.lines() {
+        let line = line.unwrap();
+        if let Some((line, _)) = line.split_once('#') {
+            if let Some((range, property)) = line.split_once(';') {
+                if property.trim() == "Extended_Pictographic" {
+                    raw_word_properties.get_mut(str_to_range(range.trim())).unwrap()
+                        .iter_mut()
+                        .for_each(|property| *property |= 0x80);
+                }
+            }
+        }
+    }
+
+    write_data_tables(word_property_rs, &header, &raw_word_properties, "WB_TABLE", "WB_PAGES")
+}
+
+fn encode_sentence_break_property(property: &str) -> u8 {
+    match property {
+        "CR" => 0x01,
+        "LF" => 0x02,
+        "Extend" => 0x03,
+        "Sep" => 0x04,
+        "Format" => 0x05,
+        "Sp" => 0x06,
+        "Lower" => 0x07,
+        "Upper" => 0x08,
+        "OLetter" => 0x09,
+        "Numeric" => 0x0a,
+        "ATerm" => 0x0b,
+        "SContinue" => 0x0c,
+        "STerm" => 0x0d,
+        "Close" => 0x0e,
+        _ => 0x00,
+    }
+}
+
+fn build_sentence_break_property(out_dir: &OsString, source: &UnicodeSource, sentence_break_property_txt: &PathBuf) -> anyhow::Result<()> {
+    let header = source.header(&[sentence_break_property_txt]);
+    let sentence_property_rs = Path::new(out_dir).join("sentence_property.rs");
+    let sentence_property_rs = File::create(sentence_property_rs)?;
+    let sentence_break_property = File::open(sentence_break_property_txt)?;
+    let sentence_break_property = BufReader::new(sentence_break_property);
+
+    let mut raw_sentence_properties = [0u8;0x110000];
+    for line in sentence_break_property.lines() {
+        let line = line.unwrap();
+        if let Some((line, _)) = line.split_once('#') {
+            if let Some((range, property)) = line.split_once(';') {
+                let range = range.trim();
+                let property = property.trim();
+                raw_sentence_properties.get_mut(str_to_range(range)).unwrap().fill(encode_sentence_break_property(property));
+            }
+        }
+    }
+
+    write_data_tables(sentence_property_rs, &header, &raw_sentence_properties, "SB_TABLE", "SB_PAGES")
+}
+
+// The Line_Break property is stored in the low six bits, already resolved according to LB1: AI,
+// SG and XX become AL (which is 0 so that unlisted code points get it), SA becomes CM for
+// combining marks and AL otherwise, and CJ becomes NS. Quotation marks which are also initial or
+// final punctuation get their own values since LB15a, LB15b and LB19 treat them differently. Bit
+// 0x40 is set for East Asian characters (East_Asian_Width F, W or H) and bit 0x80 is set for
+// unassigned Extended_Pictographic code points for LB30b.
+fn encode_line_break_property(property: &str, category: u8) -> u8 {
+    match property {
+        "BK" => 0x01,
+        "CR" => 0x02,
+        "LF" => 0x03,
+        "NL" => 0x04,
+        "SP" => 0x05,
+        "ZW" => 0x06,
+        "ZWJ" => 0x07,
+        "CM" => 0x08,
+        "WJ" => 0x09,
+        "GL" => 0x0a,
+        "BA" => 0x0b,
+        "HY" => 0x0c,
+        "BB" => 0x0d,
+        "B2" => 0x0e,
+        "CB" => 0x0f,
+        "CL" => 0x10,
+        "CP" => 0x11,
+        "EX" => 0x12,
+        "IN" => 0x13,
+        "IS" => 0x14,
+        "NS" | "CJ" => 0x15,
+        "OP" => 0x16,
+        "PO" => 0x17,
+        "PR" => 0x18,
+        "QU" => match category {
+            0x34 => 0x1a,
+            0x35 => 0x1b,
+            _ => 0x19,
+        },
+        "SY" => 0x1c,
+        "HL" => 0x1d,
+        "ID" => 0x1e,
+        "EB" => 0x1f,
+        "EM" => 0x20,
+        "H2" => 0x21,
+        "H3" => 0x22,
+        "JL" => 0x23,
+        "JV" => 0x24,
+        "JT" => 0x25,
+        "RI" => 0x26,
+        "NU" => 0x27,
+        "AK" => 0x28,
+        "AP" => 0x29,
+        "AS" => 0x2a,
+        "VF" => 0x2b,
+        "VI" => 0x2c,
+        "SA" if category == 0x10 || category == 0x11 => 0x08,
+        _ => 0x00,
+    }
+}
+
+// Split a line of a UCD property file into its range and value. The defaults for unlisted code
+// points are given in `# @missing` comment lines which we read as well.
+fn split_property_line(line: &str) -> Option<(&str, &str)> {
+    let line = line.strip_prefix("# @missing:").unwrap_or(line);
+    let (line, _) = line.split_once('#').unwrap_or((line, ""));
+    let (range, property) = line.split_once(';')?;
+    Some((range.trim(), property.trim()))
+}
+
+fn build_line_break_property(out_dir: &OsString, source: &UnicodeSource, line_break_txt: &PathBuf, east_asian_width_txt: &PathBuf, unicode_data_txt: &PathBuf, emoji_data_txt: &PathBuf) -> anyhow::Result<()> {
+    let header = source.header(&[line_break_txt, east_asian_width_txt, unicode_data_txt, emoji_data_txt]);
+    let line_property_rs = Path::new(out_dir).join("line_property.rs");
+    let line_property_rs = File::create(line_property_rs)?;
+    let line_break = File::open(line_break_txt)?;
+    let line_break = BufReader::new(line_break);
+    let east_asian_width = File::open(east_asian_width_txt)?;
+    let east_asian_width = BufReader::new(east_asian_width);
+    let emoji_data = File::open(emoji_data_txt)?;
+    let emoji_data = BufReader::new(emoji_data);
+    let categories = read_categories(unicode_data_txt)?;
+
+    let mut raw_line_properties = [0u8;0x110000];
+    for line in line_break.lines() {
+        let line = line.unwrap();
+        if let Some((range, property)) = split_property_line(&line) {
+            for code in str_to_range(range) {
+                raw_line_properties[code] = encode_line_break_property(property, categories[code]);
+            }
+        }
+    }
+
+    for line in east_asian_width.lines() {
+        let line = line.unwrap();
+        if let Some((range, property)) = split_property_line(&line) {
+            let east_asian = if matches!(property, "F" | "W" | "H") { 0x40 } else { 0x00 };
+            raw_line_properties.get_mut(str_to_range(range)).unwrap()
+                .iter_mut()
+                .for_each(|property| *property = (*property & 0xbf) | east_asian);
+        }
+    }
+
+    for line in emoji_data.lines() {
+        let line = line.unwrap();
+        if let Some((line, _)) = line.split_once('#') {
+            if let Some((range, property)) = line.split_once(';') {
+                if property.trim() == "Extended_Pictographic" {
+                    for code in str_to_range(range.trim()) {
+                        if categories[code] == 0x60 {
+                            raw_line_properties[code] |= 0x80;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    write_data_tables(line_property_rs, &header, &raw_line_properties, "LB_TABLE", "LB_PAGES")
+}
+
+// Read the values of a property from PropertyValueAliases.txt as (short name, long name) pairs.
+fn read_property_value_aliases(property_value_aliases_txt: &PathBuf, property: &str) -> anyhow::Result<Vec<(String, String)>> {
+    let property_value_aliases = File::open(property_value_aliases_txt)?;
+    let property_value_aliases = BufReader::new(property_value_aliases);
+
+    let mut values = vec!();
+    for line in property_value_aliases.lines() {
+        let line = line.unwrap();
+        let (line, _) = line.split_once('#').unwrap_or((&line, ""));
+        let fields = line.split(';').map(|field| field.trim()).collect_vec();
+        if fields.len() >= 3 && fields[0] == property {
+            values.push((fields[1].to_string(), fields[2].to_string()));
+        }
+    }
+    Ok(values)
+}
+
+// The Script enum is generated from the script values in PropertyValueAliases.txt with Unknown
+// first so that it can be the default of 0 for unlisted code points. The script tables hold the
+// index of the script in `SCRIPTS` and the script extensions tables hold an index into
+// `SCRIPT_EXTENSIONS` (offset by one so that 0 means that the Script_Extensions value is just
+// the Script value). The enum and the lists of scripts are written to scripts.rs.
+fn build_script_property(out_dir: &OsString, source: &UnicodeSource, scripts_txt: &PathBuf, script_extensions_txt: &PathBuf, property_value_aliases_txt: &PathBuf) -> anyhow::Result<()> {
+    let header = source.header(&[scripts_txt, script_extensions_txt, property_value_aliases_txt]);
+    let scripts_rs = Path::new(out_dir).join("scripts.rs");
+    let mut scripts_rs = File::create(scripts_rs)?;
+    let script_property_rs = Path::new(out_dir).join("script_property.rs");
+    let script_property_rs = File::create(script_property_rs)?;
+    let script_extensions_rs = Path::new(out_dir).join("script_extensions.rs");
+    let script_extensions_rs = File::create(script_extensions_rs)?;
+    let scripts = File::open(scripts_txt)?;
+    let scripts = BufReader::new(scripts);
+    let script_extensions = File::open(script_extensions_txt)?;
+    let script_extensions = BufReader::new(script_extensions);
+
+    let mut script_values = read_property_value_aliases(property_value_aliases_txt, "sc")?;
+    script_values.sort_by_key(|(short_name, _)| short_name != "Zzzz");
+    let variant = |long_name: &str| long_name.replace('_', "");
+    let script_index = |name: &str| script_values.iter()
+        .position(|(short_name, long_name)| name == short_name || name == long_name)
+        .map(|index| index as u8)
+        .ok_or_else(|| anyhow::anyhow!("Unknown script {name}"));
+
+    let mut raw_scripts = [0u8;0x110000];
+    for line in scripts.lines() {
+        let line = line.unwrap();
+        if let Some((range, property)) = split_property_line(&line) {
+            raw_scripts.get_mut(str_to_range(range)).unwrap().fill(script_index(property)?);
+        }
+    }
+
+    let mut raw_script_extensions = [0u8;0x110000];
+    let mut extension_sets: Vec<Vec<u8>> = vec!();
+    for line in script_extensions.lines() {
+        let line = line.unwrap();
+        if line.starts_with('#') {
+            continue;
+        }
+        if let Some((range, property)) = split_property_line(&line) {
+            let set = property.split_whitespace().map(script_index).collect::<Result<Vec<_>, _>>()?;
+            let index = match extension_sets.iter().position(|s| *s == set) {
+                Some(index) => index,
+                None => {
+                    extension_sets.push(set);
+                    extension_sets.len() - 1
+                }
+            };
+            raw_script_extensions.get_mut(str_to_range(range)).unwrap().fill(u8::try_from(index + 1)?);
+        }
+    }
+
+    write!(scripts_rs, "{header}")?;
+    writeln!(scripts_rs, "/// enum for the Unicode scripts. The variants are the long names of the scripts with the")?;
+    writeln!(scripts_rs, "/// underscores removed and are documented with their ISO 15924 codes.")?;
+    writeln!(scripts_rs, "/// Each version of Unicode adds scripts, so a `match` on a `Script` needs a wildcard arm.")?;
+    writeln!(scripts_rs, "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]")?;
+    writeln!(scripts_rs, "#[non_exhaustive]")?;
+    writeln!(scripts_rs, "pub enum Script {{")?;
+    for (short_name, long_name) in &script_values {
+        writeln!(scripts_rs, "\t/// {short_name}")?;
+        writeln!(scripts_rs, "\t{},", variant(long_name))?;
+    }
+    writeln!(scripts_rs, "}}")?;
+    writeln!(scripts_rs, "pub static SCRIPTS: [Script;{}] = [", script_values.len())?;
+    for (_, long_name) in &script_values {
+        writeln!(scripts_rs, "\tScript::{},", variant(long_name))?;
+    }
+    writeln!(scripts_rs, "];")?;
+    writeln!(scripts_rs, "pub const SCRIPT_CODES: [&str;{}] = [", script_values.len())?;
+    for (short_name, _) in &script_values {
+        writeln!(scripts_rs, "\t\"{short_name}\",")?;
+    }
+    writeln!(scripts_rs, "];")?;
+    writeln!(scripts_rs, "pub const SCRIPT_NAMES: [&str;{}] = [", script_values.len())?;
+    for (_, long_name) in &script_values {
+        writeln!(scripts_rs, "\t\"{long_name}\",")?;
+    }
+    writeln!(scripts_rs, "];")?;
+    writeln!(scripts_rs, "pub const SCRIPT_EXTENSIONS: [&[Script];{}] = [", extension_sets.len())?;
+    for set in extension_sets {
+        let set = set.iter().map(|&index| format!("Script::{}", variant(&script_values[usize::from(index)].1))).join(", ");
+        writeln!(scripts_rs, "\t&[{set}],")?;
+    }
+    writeln!(scripts_rs, "];")?;
+
+    write_data_tables(script_property_rs, &header, &raw_scripts, "SC_TABLE", "SC_PAGES")?;
+    write_data_tables(script_extensions_rs, &header, &raw_script_extensions, "SCX_TABLE", "SCX_PAGES")
+}
+
+// The East_Asian_Width values are coded as:
+// - x00 Neutral (N)
+// - x01 Ambiguous (A)
+// - x02 Halfwidth (H)
+// - x03 Wide (W)
+// - x04 Fullwidth (F)
+// - x05 Narrow (Na)
+fn encode_east_asian_width(property: &str) -> u8 {
+    match property {
+        "A" => 0x01,
+        "H" => 0x02,
+        "W" => 0x03,
+        "F" => 0x04,
+        "Na" => 0x05,
+        _ => 0x00
+    }
+}
+
+// EastAsianWidth.txt gives the defaults for unassigned code points in @missing lines, so those
+// lines are read along with the others. They come first in the file so that the more specific
+// values override them.
+fn build_east_asian_width_property(out_dir: &OsString, source: &UnicodeSource, east_asian_width_txt: &PathBuf) -> anyhow::Result<()> {
+    let header = source.header(&[east_asian_width_txt]);
+    let east_asian_width_rs = Path::new(out_dir).join("east_asian_width.rs");
+    let east_asian_width_rs = File::create(east_asian_width_rs)?;
+    let east_asian_width = File::open(east_asian_width_txt)?;
+    let east_asian_width = BufReader::new(east_asian_width);
+
+    let mut raw_east_asian_widths = [0u8;0x110000];
+    for line in east_asian_width.lines() {
+        let line = line.unwrap();
+        if let Some((range, property)) = split_property_line(&line) {
+            raw_east_asian_widths.get_mut(str_to_range(range)).unwrap().fill(encode_east_asian_width(property));
+        }
+    }
+
+    write_data_tables(east_asian_width_rs, &header, &raw_east_asian_widths, "EAW_TABLE", "EAW_PAGES")
+}
+
+// The identifier properties are stored as flags:
+// - x01 XID_Start
+// - x02 XID_Continue
+// - x04 Pattern_Syntax
+// - x08 Pattern_White_Space
+fn build_identifier_property(out_dir: &OsString, source: &UnicodeSource, derived_core_properties_txt: &PathBuf, prop_list_txt: &PathBuf) -> anyhow::Result<()> {
+    let header = source.header(&[derived_core_properties_txt, prop_list_txt]);
+    let identifier_property_rs = Path::new(out_dir).join("identifier_property.rs");
+    let identifier_property_rs = File::create(identifier_property_rs)?;
+    let derived_core_properties = File::open(derived_core_properties_txt)?;
+    let derived_core_properties = BufReader::new(derived_core_properties);
+    let prop_list = File::open(prop_list_txt)?;
+    let prop_list = BufReader::new(prop_list);
+
+    let mut identifier_properties = [0u8;0x110000];
+    for line in derived_core_properties.lines().chain(prop_list.lines()) {
+        let line = line.unwrap();
+        if let Some((range, property)) = split_property_line(&line) {
+            let flag = match property {
+                "XID_Start" => 0x01,
+                "XID_Continue" => 0x02,
+                "Pattern_Syntax" => 0x04,
+                "Pattern_White_Space" => 0x08,
+                _ => continue
+            };
+            for char_code in str_to_range(range) {
+                identifier_properties[char_code] |= flag;
+            }
+        }
+    }
+
+    write_data_tables(identifier_property_rs, &header, &identifier_properties, "ID_TABLE", "ID_PAGES")
+}
+
+// There are too many binary properties to store as flags in the u8 data tables, so each character
+// gets the set of its properties as a u64 with one bit per property. Only a few hundred different
+// sets occur, so the data tables hold an index into the list of sets, with 0 for the empty set.
+// The contributory Other_* properties are left out since they are only used to derive the others.
+fn build_binary_properties(out_dir: &OsString, source: &UnicodeSource, prop_list_txt: &PathBuf, derived_core_properties_txt: &PathBuf) -> anyhow::Result<()> {
+    let header = source.header(&[prop_list_txt, derived_core_properties_txt]);
+    let binary_properties_rs = Path::new(out_dir).join("binary_properties.rs");
+    let mut binary_properties_rs = File::create(binary_properties_rs)?;
+    let binary_property_rs = Path::new(out_dir).join("binary_property.rs");
+    let binary_property_rs = File::create(binary_property_rs)?;
+    let prop_list = File::open(prop_list_txt)?;
+    let prop_list = BufReader::new(prop_list);
+    let derived_core_properties = File::open(derived_core_properties_txt)?;
+    let derived_core_properties = BufReader::new(derived_core_properties);
+
+    let mut ranges = vec!();
+    for line in prop_list.lines().chain(derived_core_properties.lines()) {
+        let line = line.unwrap();
+        if let Some((range, property)) = split_property_line(&line) {
+            // Properties with values other than Yes/No like InCB are skipped
+            if property.contains(';') || property.starts_with("Other_") {
+                continue;
+            }
+            ranges.push((str_to_range(range), property.to_string()));
+        }
+    }
+    let names = ranges.iter().map(|(_, property)| property.clone()).sorted().dedup().collect_vec();
+    anyhow::ensure!(names.len() <= 64, "Too many binary properties to fit in a u64");
+
+    let mut property_sets = vec![0u64;0x110000];
+    for (range, property) in ranges {
+        let bit = 1u64 << names.iter().position(|name| *name == property).unwrap();
+        for char_code in range {
+            property_sets[char_code] |= bit;
+        }
+    }
+    let mut sets = vec![0u64];
+    let mut set_indices = HashMap::from([(0u64, 0u8)]);
+    let mut raw_binary_properties = vec![0u8;0x110000];
+    for (char_code, set) in property_sets.into_iter().enumerate() {
+        let next_index = u8::try_from(sets.len());
+        let index = match set_indices.get(&set) {
+            Some(&index) => index,
+            None => {
+                let index = next_index?;
+                set_indices.insert(set, index);
+                sets.push(set);
+                index
+            }
+        };
+        raw_binary_properties[char_code] = index;
+    }
+    let variant = |name: &str| name.split('_')
+        .map(|word| word[..1].to_uppercase() + &word[1..])
+        .join("");
+
+    write_data_tables(binary_property_rs, &header, &raw_binary_properties, "BP_TABLE", "BP_PAGES")?;
+    write!(binary_properties_rs, "{header}")?;
+    writeln!(binary_properties_rs, "/// enum for the binary properties in `PropList.txt` and `DerivedCoreProperties.txt`. The")?;
+    writeln!(binary_properties_rs, "/// variants are the names of the properties in camel case and are documented with the name.")?;
+    writeln!(binary_properties_rs, "/// Properties are added to the UCD from time to time, so a `match` on a `BinaryProperty` needs a")?;
+    writeln!(binary_properties_rs, "/// wildcard arm.")?;
+    writeln!(binary_properties_rs, "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]")?;
+    writeln!(binary_properties_rs, "#[non_exhaustive]")?;
+    writeln!(binary_properties_rs, "pub enum BinaryProperty {{")?;
+    for name in &names {
+        writeln!(binary_properties_rs, "\t/// {name}")?;
+        writeln!(binary_properties_rs, "\t{},", variant(name))?;
+    }
+    writeln!(binary_properties_rs, "}}")?;
+    writeln!(binary_properties_rs, "pub const BINARY_PROPERTIES: [BinaryProperty;{}] = [", names.len())?;
+    for name in &names {
+        writeln!(binary_properties_rs, "\tBinaryProperty::{},", variant(name))?;
+    }
+    writeln!(binary_properties_rs, "];")?;
+    writeln!(binary_properties_rs, "pub const BINARY_PROPERTY_NAMES: [&str;{}] = [", names.len())?;
+    for name in &names {
+        writeln!(binary_properties_rs, "\t\"{name}\",")?;
+    }
+    writeln!(binary_properties_rs, "];")?;
+    writeln!(binary_properties_rs, "pub const BINARY_PROPERTY_SETS: [u64;{}] = [", sets.len())?;
+    for set in sets {
+        writeln!(binary_properties_rs, "\t{set:#018x},")?;
+    }
+    writeln!(binary_properties_rs, "];")?;
+    Ok(())
+}
+
+fn build_emoji_property(out_dir: &OsString, source: &UnicodeSource, emoji_data_txt: &PathBuf) -> anyhow::Result<()> {
+    let header = source.header(&[emoji_data_txt]);
+    let emoji_property_rs = Path::new(out_dir).join("emoji_property.rs");
+    let emoji_property_rs = File::create(emoji_property_rs)?;
+    let emoji_data = File::open(emoji_data_txt)?;
+    let emoji_data = BufReader::new(emoji_data);
+
+    let mut emoji_properties = [0u8;0x110000];
+    for line in emoji_data.lines() {
+        let line = line.unwrap();
+        if let Some((range, property)) = split_property_line(&line) {
+            let flag = match property {
+                "Emoji" => 0x01,
+                "Emoji_Presentation" => 0x02,
+                "Emoji_Modifier" => 0x04,
+                "Emoji_Modifier_Base" => 0x08,
+                "Emoji_Component" => 0x10,
+                "Extended_Pictographic" => 0x20,
+                _ => continue
+            };
+            for char_code in str_to_range(range) {
+                emoji_properties[char_code] |= flag;
+            }
+        }
+    }
+
+    write_data_tables(emoji_property_rs, &header, &emoji_properties, "EMOJI_TABLE", "EMOJI_PAGES")
+}
+
+// The RGI emoji sequences are written out as a list of strings sorted so that they can be binary
+// searched, each with its type encoded as:
+// - x01 Basic_Emoji
+// - x02 Emoji_Keycap_Sequence
+// - x03 RGI_Emoji_Flag_Sequence
+// - x04 RGI_Emoji_Modifier_Sequence
+// - x05 RGI_Emoji_ZWJ_Sequence
+// - x06 RGI_Emoji_Tag_Sequence
+// Basic_Emoji lines can give a range of single code points which we expand.
+fn build_emoji_sequences(out_dir: &OsString, source: &UnicodeSource, emoji_sequences_txt: &PathBuf, emoji_zwj_sequences_txt: &PathBuf) -> anyhow::Result<()> {
+    let header = source.header(&[emoji_sequences_txt, emoji_zwj_sequences_txt]);
+    let emoji_sequences_rs = Path::new(out_dir).join("emoji_sequences.rs");
+    let mut emoji_sequences_rs = File::create(emoji_sequences_rs)?;
+    let emoji_sequences = File::open(emoji_sequences_txt)?;
+    let emoji_sequences = BufReader::new(emoji_sequences);
+    let emoji_zwj_sequences = File::open(emoji_zwj_sequences_txt)?;
+    let emoji_zwj_sequences = BufReader::new(emoji_zwj_sequences);
+
+    // Strings are ordered by their UTF-8 bytes, which matches the ordering of `str` in Rust
+    let mut sequences = BTreeMap::new();
+    for line in emoji_sequences.lines().chain(emoji_zwj_sequences.lines()) {
+        let line = line.unwrap();
+        let (line, _) = line.split_once('#').unwrap_or((&line, ""));
+        let fields = line.split(';').map(|field| field.trim()).collect_vec();
+        if fields.len() < 2 {
+            continue;
+        }
+        let sequence_type = match fields[1] {
+            "Basic_Emoji" => 0x01u8,
+            "Emoji_Keycap_Sequence" => 0x02,
+            "RGI_Emoji_Flag_Sequence" => 0x03,
+            "RGI_Emoji_Modifier_Sequence" => 0x04,
+            "RGI_Emoji_ZWJ_Sequence" => 0x05,
+            "RGI_Emoji_Tag_Sequence" => 0x06,
+            sequence_type => anyhow::bail!("Unknown emoji sequence type {sequence_type}")
+        };
+        if fields[0].contains("..") {
+            for char_code in str_to_range(fields[0]) {
+                sequences.insert(char::from_u32(char_code as u32).unwrap().to_string(), sequence_type);
+            }
+        }
+        else {
+            let sequence = fields[0].split_whitespace()
+                .map(|code| u32::from_str_radix(code, 16).map(|code| char::from_u32(code).unwrap()))
+                .collect::<Result<String, _>>()?;
+            sequences.insert(sequence, sequence_type);
+        }
+    }
+
+    write!(emoji_sequences_rs, "{header}")?;
+    writeln!(emoji_sequences_rs, "pub const EMOJI_SEQUENCES: [(&str, u8);{}] = [", sequences.len())?;
+    for (sequence, sequence_type) in sequences {
+        let sequence = sequence.chars().map(|c| format!("\\u{{{:x}}}", c as u32)).join("");
+        writeln!(emoji_sequences_rs, "\t(\"{sequence}\", {sequence_type:#04x}),")?;
+    }
+    writeln!(emoji_sequences_rs, "];")?;
+    Ok(())
+}
+
+// Names are compressed by splitting them into words. The words are listed once in NAME_WORDS,
+// most frequent first, and each name is stored in NAME_DATA as a record:
+// - the difference between its code point and that of the previous record (0xff followed by
+//   three bytes giving the code point for differences which don't fit)
+// - the number of words in the name
+// - the index of each word, as a single byte for the first 0xc0 words and as two bytes
+//   starting with 0xc0 + the high bits of the index less 0xc0 for the rest
+// NAME_INDEX gives the first code point and the offset of every NAMES_PER_BLOCK records. Names
+// which are derived from the code point like CJK UNIFIED IDEOGRAPH-4E00 are left out of the
+// records and given as ranges with their prefix instead. For lookup by name, the record numbers
+// are listed in the order of the names under the loose matching rule UAX44-LM2.
+const NAMES_PER_BLOCK: usize = 32;
+
+fn build_names(out_dir: &OsString, source: &UnicodeSource, unicode_data_txt: &PathBuf, name_aliases_txt: &PathBuf) -> anyhow::Result<()> {
+    let header = source.header(&[unicode_data_txt, name_aliases_txt]);
+    let names_rs = Path::new(out_dir).join("names.rs");
+    let mut names_rs = File::create(names_rs)?;
+    let unicode_data = File::open(unicode_data_txt)?;
+    let unicode_data = BufReader::new(unicode_data);
+    let name_aliases = File::open(name_aliases_txt)?;
+    let name_aliases = BufReader::new(name_aliases);
+
+    let mut names = vec!();
+    let mut algorithmic_names: Vec<(u32, u32, String)> = vec!();
+    let mut range_start = None;
+    for line in unicode_data.lines() {
+        let line = line.unwrap();
+        let fields = line.split(';').collect_vec();
+        if fields.len() < 2 {
+            continue;
+        }
+        let char_code = u32::from_str_radix(fields[0], 16)?;
+        let name = fields[1];
+        let prefix = if let Some(label) = name.strip_prefix('<') {
+            // Ranges are given by a pair of lines with the labels <…, First> and <…, Last>. Only
+            // the ideographs among them have names (Hangul syllables are handled in code).
+            if label.ends_with(", First>") {
+                range_start = Some(char_code);
+                continue;
+            }
+            let prefix = if label.starts_with("CJK Ideograph") {
+                "CJK UNIFIED IDEOGRAPH-"
+            }
+            else if label.starts_with("Tangut Ideograph") {
+                "TANGUT IDEOGRAPH-"
+            }
+            else {
+                continue;
+            };
+            if let Some(range_start) = range_start.take() {
+                algorithmic_names.push((range_start, char_code, prefix.to_string()));
+            }
+            continue;
+        }
+        else {
+            name.strip_suffix(&format!("{char_code:04X}")).filter(|prefix| prefix.ends_with('-'))
+        };
+        match prefix {
+            Some(prefix) => {
+                match algorithmic_names.last_mut() {
+                    Some((_, end, last_prefix)) if *end + 1 == char_code && last_prefix == prefix => *end = char_code,
+                    _ => algorithmic_names.push((char_code, char_code, prefix.to_string()))
+                }
+            }
+            None => names.push((char_code, name.to_string()))
+        }
+    }
+    algorithmic_names.sort();
+
+    let word_counts = names.iter().flat_map(|(_, name)| name.split(' ')).counts();
+    let words = word_counts.into_iter()
+        .sorted_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then(a.cmp(b)))
+        .map(|(word, _)| word)
+        .collect_vec();
+    anyhow::ensure!(words.len() <= 0xc0 + 0x40 * 0x100, "Too many words in the names to encode");
+    let word_indices: HashMap<_, _> = words.iter().enumerate().map(|(index, &word)| (word, index)).collect();
+
+    let mut name_data = vec!();
+    let mut name_index = vec!();
+    let mut previous = 0;
+    for (record, (char_code, name)) in names.iter().enumerate() {
+        if record % NAMES_PER_BLOCK == 0 {
+            name_index.push((*char_code, name_data.len()));
+            previous = *char_code;
+        }
+        let delta = char_code - previous;
+        if delta < 0xff {
+            name_data.push(delta as u8);
+        }
+        else {
+            name_data.push(0xff);
+            name_data.extend_from_slice(&char_code.to_be_bytes()[1..]);
+        }
+        previous = *char_code;
+        let name_words = name.split(' ').collect_vec();
+        name_data.push(u8::try_from(name_words.len())?);
+        for word in name_words {
+            let index = word_indices[word];
+            if index < 0xc0 {
+                name_data.push(index as u8);
+            }
+            else {
+                name_data.push((0xc0 + ((index - 0xc0) >> 8)) as u8);
+                name_data.push(((index - 0xc0) & 0xff) as u8);
+            }
+        }
+    }
+
+    // U+1180 HANGUL JUNGSEONG O-E is the only name which keeps its medial hyphen under loose
+    // matching, so it is matched separately rather than listed.
+    let names_by_loose_name = names.iter().enumerate()
+        .filter(|(_, (char_code, _))| *char_code != 0x1180)
+        .map(|(record, (_, name))| (loose_name(name), record))
+        .sorted()
+        .collect_vec();
+    for ((a, _), (b, _)) in names_by_loose_name.iter().tuple_windows() {
+        anyhow::ensure!(a != b, "Duplicate loose name {a}");
+    }
+
+    let mut aliases = vec!();
+    for line in name_aliases.lines() {
+        let line = line.unwrap();
+        let (line, _) = line.split_once('#').unwrap_or((&line, ""));
+        let fields = line.split(';').map(|field| field.trim()).collect_vec();
+        if fields.len() < 3 {
+            continue;
+        }
+        let alias_type = match fields[2] {
+            "correction" => 0x01u8,
+            "control" => 0x02,
+            "alternate" => 0x03,
+            "figment" => 0x04,
+            "abbreviation" => 0x05,
+            alias_type => anyhow::bail!("Unknown name alias type {alias_type}")
+        };
+        aliases.push((u32::from_str_radix(fields[0], 16)?, fields[1].to_string(), alias_type));
+    }
+    // Keep the aliases of each character in the order given in the file
+    aliases.sort_by_key(|(char_code, _, _)| *char_code);
+    let aliases_by_loose_name = aliases.iter()
+        .map(|(char_code, alias, _)| (loose_name(alias), *char_code))
+        .sorted()
+        .dedup()
+        .collect_vec();
+    for ((a, _), (b, _)) in aliases_by_loose_name.iter().tuple_windows() {
+        anyhow::ensure!(a != b, "Duplicate loose alias {a}");
+    }
+
+    write!(names_rs, "{header}")?;
+    writeln!(names_rs, "pub const NAMES_PER_BLOCK: usize = {NAMES_PER_BLOCK};")?;
+    writeln!(names_rs, "pub const NAME_WORDS: &str = \"\\")?;
+    for line in words.chunks(12) {
+        writeln!(names_rs, "{} \\", line.join(" "))?;
+    }
+    writeln!(names_rs, "\";")?;
+    let mut offset = 0;
+    writeln!(names_rs, "pub static NAME_WORD_OFFSETS: [u32;{}] = [", words.len() + 1)?;
+    for word in &words {
+        writeln!(names_rs, "\t{offset},")?;
+        offset += word.len() + 1;
+    }
+    writeln!(names_rs, "\t{offset},")?;
+    writeln!(names_rs, "];")?;
+    writeln!(names_rs, "pub static NAME_DATA: [u8;{}] = [", name_data.len())?;
+    for line in name_data.chunks(16) {
+        writeln!(names_rs, "\t{},", line.iter().map(|byte| format!("{byte:#04x}")).join(", "))?;
+    }
+    writeln!(names_rs, "];")?;
+    writeln!(names_rs, "pub static NAME_INDEX: [(char, u32);{}] = [", name_index.len())?;
+    for (char_code, offset) in name_index {
+        writeln!(names_rs, "\t('\\u{{{char_code:x}}}', {offset}),")?;
+    }
+    writeln!(names_rs, "];")?;
+    writeln!(names_rs, "pub static NAMES_BY_LOOSE_NAME: [u16;{}] = [", names_by_loose_name.len())?;
+    for (_, record) in names_by_loose_name {
+        writeln!(names_rs, "\t{},", u16::try_from(record)?)?;
+    }
+    writeln!(names_rs, "];")?;
+    writeln!(names_rs, "pub static ALGORITHMIC_NAMES: [(char, char, &str);{}] = [", algorithmic_names.len())?;
+    for (start, end, prefix) in algorithmic_names {
+        writeln!(names_rs, "\t('\\u{{{start:x}}}', '\\u{{{end:x}}}', \"{prefix}\"),")?;
+    }
+    writeln!(names_rs, "];")?;
+    writeln!(names_rs, "pub static NAME_ALIASES: [(char, &str, u8);{}] = [", aliases.len())?;
+    for (char_code, alias, alias_type) in aliases {
+        writeln!(names_rs, "\t('\\u{{{char_code:x}}}', \"{alias}\", {alias_type:#04x}),")?;
+    }
+    writeln!(names_rs, "];")?;
+    writeln!(names_rs, "pub static NAME_ALIASES_BY_LOOSE_NAME: [(&str, char);{}] = [", aliases_by_loose_name.len())?;
+    for (loose_alias, char_code) in aliases_by_loose_name {
+        writeln!(names_rs, "\t(\"{loose_alias}\", '\\u{{{char_code:x}}}'),")?;
+    }
+    writeln!(names_rs, "];")?;
+    Ok(())
+}
+
+// The loose matching rule UAX44-LM2 ignores case, whitespace, underscores and hyphens between two
+// letters or digits. This must match `loose_chars` in names.rs.
+fn loose_name(name: &str) -> String {
+    let chars = name.chars().collect_vec();
+    chars.iter().enumerate()
+        .filter(|&(i, &c)| match c {
+            '_' => false,
+            c if c.is_whitespace() => false,
+            '-' => !(i > 0 && i + 1 < chars.len() && chars[i - 1].is_ascii_alphanumeric() && chars[i + 1].is_ascii_alphanumeric()),
+            _ => true
+        })
+        .map(|(_, c)| c.to_ascii_uppercase())
+        .collect()
+}
+
+// Encode the condition of a conditional mapping in SpecialCasing.txt as:
+// - x01 Final_Sigma
+// - x02 After_Soft_Dotted
+// - x03 More_Above
+// - x04 Before_Dot
+// - x05 After_I
+// with x80 added for a negated condition (e.g., Not_Before_Dot).
+fn encode_casing_condition(condition: &str) -> anyhow::Result<u8> {
+    let (negated, condition) = match condition.strip_prefix("Not_") {
+        Some(condition) => (0x80, condition),
+        None => (0x00, condition),
+    };
+    let code = match condition {
+        "Final_Sigma" => 0x01,
+        "After_Soft_Dotted" => 0x02,
+        "More_Above" => 0x03,
+        "Before_Dot" => 0x04,
+        "After_I" => 0x05,
+        _ => anyhow::bail!("Unknown casing condition {condition}")
+    };
+    Ok(negated | code)
+}
+
+// The simple mappings from UnicodeData.txt, the unconditional full mappings from
+// SpecialCasing.txt and the case foldings are written to case_mapping.rs as sorted lists which
+// can be binary searched. Only the full mappings which differ from the simple mappings are
+// listed, and titlecase mappings are only listed where they differ from the uppercase mapping.
+// The conditional mappings are listed with their language (or "" for none) and condition.
+//
+// The context needed to test the conditions is written to the case property table:
+// - x01 Cased
+// - x02 Case_Ignorable
+// - x04 Soft_Dotted
+// - x08 canonical combining class is 230 (Above)
+// - x10 canonical combining class is 0
+// - x20 has a conditional mapping, so CONDITIONAL_CASE_MAPPINGS only needs to be searched for
+//   characters with this bit set
+fn build_case_tables(out_dir: &OsString, source: &UnicodeSource, unicode_data_txt: &PathBuf, special_casing_txt: &PathBuf, case_folding_txt: &PathBuf, derived_core_properties_txt: &PathBuf, prop_list_txt: &PathBuf) -> anyhow::Result<()> {
+    let header = source.header(&[unicode_data_txt, special_casing_txt, case_folding_txt, derived_core_properties_txt, prop_list_txt]);
+    let case_mapping_rs = Path::new(out_dir).join("case_mapping.rs");
+    let mut case_mapping_rs = File::create(case_mapping_rs)?;
+    let case_property_rs = Path::new(out_dir).join("case_property.rs");
+    let case_property_rs = File::create(case_property_rs)?;
+    let unicode_data = File::open(unicode_data_txt)?;
+    let unicode_data = BufReader::new(unicode_data);
+    let special_casing = File::open(special_casing_txt)?;
+    let special_casing = BufReader::new(special_casing);
+    let case_folding = File::open(case_folding_txt)?;
+    let case_folding = BufReader::new(case_folding);
+    let derived_core_properties = File::open(derived_core_properties_txt)?;
+    let derived_core_properties = BufReader::new(derived_core_properties);
+    let prop_list = File::open(prop_list_txt)?;
+    let prop_list = BufReader::new(prop_list);
+
+    let parse_chars = |chars: &str| chars.split_whitespace()
+        .map(|code| usize::from_str_radix(code, 16))
+        .collect::<Result<Vec<_>, _>>();
+
+    // Unassigned code points and the characters in ranges have a combining class of 0 and no case
+    // mappings, so they only need the initial value.
+    let mut case_properties = [0x10u8;0x110000];
+    let mut simple_uppercase = BTreeMap::new();
+    let mut simple_lowercase = BTreeMap::new();
+    let mut simple_titlecase = BTreeMap::new();
+    for line in unicode_data.lines() {
+        let line = line.unwrap();
+        let fields = line.split(';').collect_vec();
+        let char_code = usize::from_str_radix(fields[0], 16)?;
+        case_properties[char_code] = match fields[3] {
+            "0" => 0x10,
+            "230" => 0x08,
+            _ => 0x00
+        };
+        if let Ok(upper) = usize::from_str_radix(fields[12], 16) {
+            simple_uppercase.insert(char_code, upper);
+        }
+        if let Ok(lower) = usize::from_str_radix(fields[13], 16) {
+            simple_lowercase.insert(char_code, lower);
+        }
+        if let Ok(title) = usize::from_str_radix(fields[14], 16) {
+            if Some(&title) != simple_uppercase.get(&char_code) {
+                simple_titlecase.insert(char_code, title);
+            }
+        }
+    }
+
+    for line in derived_core_properties.lines().chain(prop_list.lines()) {
+        let line = line.unwrap();
+        if let Some((range, property)) = split_property_line(&line) {
+            let flag = match property {
+                "Cased" => 0x01,
+                "Case_Ignorable" => 0x02,
+                "Soft_Dotted" => 0x04,
+                _ => continue
+            };
+            for char_code in str_to_range(range) {
+                case_properties[char_code] |= flag;
+            }
+        }
+    }
+
+    let simple_mapping = |mapping: &BTreeMap<usize, usize>, char_code: usize| vec![*mapping.get(&char_code).unwrap_or(&char_code)];
+    let mut full_uppercase = BTreeMap::new();
+    let mut full_lowercase = BTreeMap::new();
+    let mut full_titlecase = BTreeMap::new();
+    let mut conditional_mappings = vec!();
+    for line in special_casing.lines() {
+        let line = line.unwrap();
+        let (line, _) = line.split_once('#').unwrap_or((&line, ""));
+        let fields = line.split(';').map(|field| field.trim()).collect_vec();
+        if fields.len() < 5 {
+            continue;
+        }
+        let char_code = usize::from_str_radix(fields[0], 16)?;
+        let (lower, title, upper) = (parse_chars(fields[1])?, parse_chars(fields[2])?, parse_chars(fields[3])?);
+        if fields[4].is_empty() {
+            if lower != simple_mapping(&simple_lowercase, char_code) {
+                full_lowercase.insert(char_code, lower);
+            }
+            let simple_title = simple_titlecase.get(&char_code).copied()
+                .unwrap_or_else(|| simple_mapping(&simple_uppercase, char_code)[0]);
+            if title != [simple_title] {
+                full_titlecase.insert(char_code, title);
+            }
+            if upper != simple_mapping(&simple_uppercase, char_code) {
+                full_uppercase.insert(char_code, upper);
+            }
+        }
+        else {
+            let (language, condition) = match fields[4].split_once(' ') {
+                Some((language, condition)) => (language, encode_casing_condition(condition)?),
+                None if fields[4].chars().next().is_some_and(char::is_lowercase) => (fields[4], 0),
+                None => ("", encode_casing_condition(fields[4])?),
+            };
+            case_properties[char_code] |= 0x20;
+            conditional_mappings.push((char_code, language.to_string(), condition, lower, title, upper));
+        }
+    }
+
+    let mut simple_case_folding = BTreeMap::new();
+    let mut full_case_folding = BTreeMap::new();
+    let mut turkic_case_folding = BTreeMap::new();
+    for line in case_folding.lines() {
+        let line = line.unwrap();
+        let (line, _) = line.split_once('#').unwrap_or((&line, ""));
+        let fields = line.split(';').map(|field| field.trim()).collect_vec();
+        if fields.len() < 3 {
+            continue;
+        }
+        let char_code = usize::from_str_radix(fields[0], 16)?;
+        let mapping = parse_chars(fields[2])?;
+        match fields[1] {
+            "C" | "S" => { simple_case_folding.insert(char_code, mapping[0]); }
+            "F" => { full_case_folding.insert(char_code, mapping); }
+            "T" => { turkic_case_folding.insert(char_code, mapping[0]); }
+            status => anyhow::bail!("Unknown case folding status {status}")
+        }
+    }
+
+    write_data_tables(case_property_rs, &header, &case_properties, "CASE_TABLE", "CASE_PAGES")?;
+
+    let format_char = |c: &usize| format!("'\\u{{{c:x}}}'");
+    let format_chars = |chars: &[usize]| chars.iter().map(format_char).join(", ");
+    write!(case_mapping_rs, "{header}")?;
+    for (name, mapping) in [("SIMPLE_UPPERCASE", &simple_uppercase), ("SIMPLE_LOWERCASE", &simple_lowercase), ("SIMPLE_TITLECASE", &simple_titlecase), ("SIMPLE_CASE_FOLDING", &simple_case_folding), ("TURKIC_CASE_FOLDING", &turkic_case_folding)] {
+        writeln!(case_mapping_rs, "pub const {name}: [(char, char);{}] = [", mapping.len())?;
+        for (char_code, mapped) in mapping {
+            writeln!(case_mapping_rs, "\t({}, {}),", format_char(char_code), format_char(mapped))?;
+        }
+        writeln!(case_mapping_rs, "];")?;
+    }
+    for (name, mapping) in [("FULL_UPPERCASE", &full_uppercase), ("FULL_LOWERCASE", &full_lowercase), ("FULL_TITLECASE", &full_titlecase), ("FULL_CASE_FOLDING", &full_case_folding)] {
+        writeln!(case_mapping_rs, "pub const {name}: [(char, &[char]);{}] = [", mapping.len())?;
+        for (char_code, mapped) in mapping {
+            writeln!(case_mapping_rs, "\t({}, &[{}]),", format_char(char_code), format_chars(mapped))?;
+        }
+        writeln!(case_mapping_rs, "];")?;
+    }
+    writeln!(case_mapping_rs, "pub const CONDITIONAL_CASE_MAPPINGS: [(char, &str, u8, &[char], &[char], &[char]);{}] = [", conditional_mappings.len())?;
+    for (char_code, language, condition, lower, title, upper) in conditional_mappings {
+        writeln!(case_mapping_rs, "\t({}, \"{language}\", {condition:#04x}, &[{}], &[{}], &[{}]),", format_char(&char_code), format_chars(&lower), format_chars(&title), format_chars(&upper))?;
+    }
+    writeln!(case_mapping_rs, "];")?;
+    Ok(())
+}
+
+// Only a few hundred characters have a paired bracket or a mirroring glyph, so we write them out
+// as sorted lists which can be binary searched rather than as page tables.
+fn build_bidi_tables(out_dir: &OsString, source: &UnicodeSource, bidi_brackets_txt: &PathBuf, bidi_mirroring_txt: &PathBuf) -> anyhow::Result<()> {
+    let header = source.header(&[bidi_brackets_txt, bidi_mirroring_txt]);
+    let bidi_brackets_rs = Path::new(out_dir).join("bidi_brackets.rs");
+    let mut bidi_brackets_rs = File::create(bidi_brackets_rs)?;
+    let bidi_brackets = File::open(bidi_brackets_txt)?;
+    let bidi_brackets = BufReader::new(bidi_brackets);
+    let bidi_mirroring = File::open(bidi_mirroring_txt)?;
+    let bidi_mirroring = BufReader::new(bidi_mirroring);
+
+    let mut brackets = BTreeMap::new();
+    for line in bidi_brackets.lines() {
+        let line = line.unwrap();
+        let (line, _) = line.split_once('#').unwrap_or((&line, ""));
+        let fields = line.split(';').map(|field| field.trim()).collect_vec();
+        if fields.len() < 3 {
+            continue;
+        }
+        let opening = match fields[2] {
+            "o" => true,
+            "c" => false,
+            bracket_type => anyhow::bail!("Unknown paired bracket type {bracket_type}")
+        };
+        brackets.insert(u32::from_str_radix(fields[0], 16)?, (u32::from_str_radix(fields[1], 16)?, opening));
+    }
+
+    let mut mirroring = BTreeMap::new();
+    for line in bidi_mirroring.lines() {
+        let line = line.unwrap();
+        let (line, _) = line.split_once('#').unwrap_or((&line, ""));
+        let fields = line.split(';').map(|field| field.trim()).collect_vec();
+        if fields.len() < 2 {
+            continue;
+        }
+        mirroring.insert(u32::from_str_radix(fields[0], 16)?, u32::from_str_radix(fields[1], 16)?);
+    }
+
+    write!(bidi_brackets_rs, "{header}")?;
+    writeln!(bidi_brackets_rs, "pub const BIDI_PAIRED_BRACKETS: [(char, char, bool);{}] = [", brackets.len())?;
+    for (char_code, (paired, opening)) in brackets {
+        writeln!(bidi_brackets_rs, "\t('\\u{{{char_code:x}}}', '\\u{{{paired:x}}}', {opening}),")?;
+    }
+    writeln!(bidi_brackets_rs, "];")?;
+    writeln!(bidi_brackets_rs, "pub const BIDI_MIRRORING_GLYPHS: [(char, char);{}] = [", mirroring.len())?;
+    for (char_code, mirrored) in mirroring {
+        writeln!(bidi_brackets_rs, "\t('\\u{{{char_code:x}}}', '\\u{{{mirrored:x}}}'),")?;
+    }
+    writeln!(bidi_brackets_rs, "];")?;
+    Ok(())
+}
+
+// BidiTest.txt gives the inputs as lists of bidi classes, grouped under @Levels and @Reorder lines
+// which give the expected results. There are far too many lines to generate a function call for
+// each one, so we write them out as a table which the test iterates over.
+fn build_bidi_test(out_dir: &OsString, source: &UnicodeSource, bidi_test_txt: &PathBuf) -> anyhow::Result<()> {
+    let header = source.header(&[bidi_test_txt]);
+    let test_rs = Path::new(out_dir).join("bidi_test.rs");
+    let mut test_rs = File::create(test_rs)?;
+    let bidi_test = File::open(bidi_test_txt)?;
+    let bidi_test = BufReader::new(bidi_test);
+
+    let mut levels = String::new();
+    let mut reorder = String::new();
+    let mut cases = vec!();
+    for line in bidi_test.lines() {
+        let line = line.unwrap();
+        let (line, _) = line.split_once('#').unwrap_or((&line, ""));
+        if let Some(value) = line.strip_prefix("@Levels:") {
+            levels = value.trim().to_string();
+        }
+        else if let Some(value) = line.strip_prefix("@Reorder:") {
+            reorder = value.trim().to_string();
+        }
+        else if let Some((classes, paragraph_levels)) = line.split_once(';') {
+            cases.push((classes.trim().to_string(), paragraph_levels.trim().parse::<u8>()?, levels.clone(), reorder.clone()));
+        }
+    }
+
+    write!(test_rs, "{header}")?;
+    writeln!(test_rs)?;
+    writeln!(test_rs, "use crate::bidi::tests::bidi_test;")?;
+    writeln!(test_rs)?;
+    writeln!(test_rs, "#[test]")?;
+    writeln!(test_rs, "fn standard_bidi_test() {{")?;
+    writeln!(test_rs, "\tfor &(classes, paragraph_levels, levels, reorder) in &BIDI_TEST {{")?;
+    writeln!(test_rs, "\t\tbidi_test(classes, paragraph_levels, levels, reorder);")?;
+    writeln!(test_rs, "\t}}")?;
+    writeln!(test_rs, "}}")?;
+    writeln!(test_rs)?;
+    writeln!(test_rs, "static BIDI_TEST: [(&str, u8, &str, &str);{}] = [", cases.len())?;
+    for (classes, paragraph_levels, levels, reorder) in cases {
+        writeln!(test_rs, "\t({classes:?}, {paragraph_levels}, {levels:?}, {reorder:?}),")?;
+    }
+    writeln!(test_rs, "];")?;
+    Ok(())
+}
+
+// Each line of BidiCharacterTest.txt has the input as code points along with the paragraph
+// direction and the expected paragraph level, resolved levels and visual order.
+fn build_bidi_character_test(out_dir: &OsString, source: &UnicodeSource, bidi_character_test_txt: &PathBuf) -> anyhow::Result<()> {
+    let header = source.header(&[bidi_character_test_txt]);
+    let test_rs = Path::new(out_dir).join("bidi_character_test.rs");
+    let mut test_rs = File::create(test_rs)?;
+    let bidi_character_test = File::open(bidi_character_test_txt)?;
+    let bidi_character_test = BufReader::new(bidi_character_test);
+
+    let mut cases = vec!();
+    for line in bidi_character_test.lines() {
+        let line = line.unwrap();
+        let (line, _) = line.split_once('#').unwrap_or((&line, ""));
+        let fields = line.split(';').map(|field| field.trim()).collect_vec();
+        if fields.len() < 5 {
+            continue;
+        }
+        let input = fields[0].split_whitespace().map(|code| "\\u{".to_string() + code + "}").join("");
+        cases.push((input, fields[1].parse::<u8>()?, fields[2].parse::<u8>()?, fields[3].to_string(), fields[4].to_string()));
+    }
+
+    write!(test_rs, "{header}")?;
+    writeln!(test_rs)?;
+    writeln!(test_rs, "use crate::bidi::tests::bidi_character_test;")?;
+    writeln!(test_rs)?;
+    writeln!(test_rs, "#[test]")?;
+    writeln!(test_rs, "fn standard_bidi_character_test() {{")?;
+    writeln!(test_rs, "\tfor &(input, direction, paragraph_level, levels, reorder) in &BIDI_CHARACTER_TEST {{")?;
+    writeln!(test_rs, "\t\tbidi_character_test(input, direction, paragraph_level, levels, reorder);")?;
+    writeln!(test_rs, "\t}}")?;
+    writeln!(test_rs, "}}")?;
+    writeln!(test_rs)?;
+    writeln!(test_rs, "static BIDI_CHARACTER_TEST: [(&str, u8, u8, &str, &str);{}] = [", cases.len())?;
+    for (input, direction, paragraph_level, levels, reorder) in cases {
+        writeln!(test_rs, "\t(\"{input}\", {direction}, {paragraph_level}, {levels:?}, {reorder:?}),")?;
+    }
+    writeln!(test_rs, "];")?;
+    Ok(())
+}
+
+// There are too many blocks to fit a block index into the u8 data tables, but since blocks are
+// contiguous ranges which are listed in order, we write them out as a sorted list which can be
+// binary searched. The Block enum is generated along with the list in blocks.rs.
+fn build_block_list(out_dir: &OsString, source: &UnicodeSource, blocks_txt: &PathBuf) -> anyhow::Result<()> {
+    let header = source.header(&[blocks_txt]);
+    let blocks_rs = Path::new(out_dir).join("blocks.rs");
+    let mut blocks_rs = File::create(blocks_rs)?;
+    let blocks = File::open(blocks_txt)?;
+    let blocks = BufReader::new(blocks);
+
+    let mut block_values = vec!();
+    for line in blocks.lines() {
+        let line = line.unwrap();
+        if line.starts_with('#') {
+            continue;
+        }
+        if let Some((range, name)) = split_property_line(&line) {
+            let range = str_to_range(range);
+            block_values.push((*range.start(), *range.end(), name.to_string()));
+        }
+    }
+    block_values.sort();
+    let variant = |name: &str| name.split([' ', '-', '_'])
+        .map(|word| word[..1].to_uppercase() + &word[1..])
+        .join("");
+
+    write!(blocks_rs, "{header}")?;
+    writeln!(blocks_rs, "/// enum for the Unicode blocks. The variants are the names of the blocks in camel case and")?;
+    writeln!(blocks_rs, "/// are documented with the range of the block.")?;
+    writeln!(blocks_rs, "/// New versions of Unicode add blocks, so a `match` on a `Block` needs a wildcard arm.")?;
+    writeln!(blocks_rs, "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]")?;
+    writeln!(blocks_rs, "#[non_exhaustive]")?;
+    writeln!(blocks_rs, "pub enum Block {{")?;
+    for (start, end, name) in &block_values {
+        writeln!(blocks_rs, "\t/// {start:04X}..{end:04X}")?;
+        writeln!(blocks_rs, "\t{},", variant(name))?;
+    }
+    writeln!(blocks_rs, "}}")?;
+    writeln!(blocks_rs, "pub const BLOCKS: [(u32, u32, Block);{}] = [", block_values.len())?;
+    for (start, end, name) in &block_values {
+        writeln!(blocks_rs, "\t({start:#x}, {end:#x}, Block::{}),", variant(name))?;
+    }
+    writeln!(blocks_rs, "];")?;
+    writeln!(blocks_rs, "pub const BLOCK_NAMES: [&str;{}] = [", block_values.len())?;
+    for (_, _, name) in &block_values {
+        writeln!(blocks_rs, "\t\"{name}\",")?;
+    }
+    writeln!(blocks_rs, "];")?;
+    Ok(())
+}
+
+// Hangul syllables are decomposed and composed algorithmically so they don't appear in the tables
+// except for their quick check values.
+const HANGUL_SYLLABLES: RangeInclusive<usize> = 0xac00..=0xd7a3;
+const HANGUL_VOWELS: RangeInclusive<usize> = 0x1161..=0x1175;
+const HANGUL_TRAILING_CONSONANTS: RangeInclusive<usize> = 0x11a8..=0x11c2;
+
+// The normalization flags are stored as follows:
+// - x01 NFD_Quick_Check is No
+// - x02 NFKD_Quick_Check is No
+// - x04 NFC_Quick_Check is No
+// - x08 NFC_Quick_Check is Maybe
+// - x10 NFKC_Quick_Check is No
+// - x20 NFKC_Quick_Check is Maybe
+// These are derived from the decompositions in UnicodeData.txt and CompositionExclusions.txt
+// in the same way as DerivedNormalizationProps.txt.
+fn build_normalization_tables(out_dir: &OsString, source: &UnicodeSource, unicode_data_txt: &PathBuf, composition_exclusions_txt: &PathBuf) -> anyhow::Result<()> {
+    let header = source.header(&[unicode_data_txt, composition_exclusions_txt]);
+    let combining_class_rs = Path::new(out_dir).join("combining_class.rs");
+    let combining_class_rs = File::create(combining_class_rs)?;
+    let normalization_property_rs = Path::new(out_dir).join("normalization_property.rs");
+    let normalization_property_rs = File::create(normalization_property_rs)?;
+    let decompositions_rs = Path::new(out_dir).join("decompositions.rs");
+    let mut decompositions_rs = File::create(decompositions_rs)?;
+    let unicode_data = File::open(unicode_data_txt)?;
+    let unicode_data = BufReader::new(unicode_data);
+    let composition_exclusions = File::open(composition_exclusions_txt)?;
+    let composition_exclusions = BufReader::new(composition_exclusions);
+
+    // Characters in ranges all have a combining class of 0 and no decomposition so we can ignore
+    // the ranges here.
+    let mut combining_classes = [0u8;0x110000];
+    let mut decomposition_mappings = BTreeMap::new();
+    for line in unicode_data.lines() {
+        let line = line.unwrap();
+        let fields = line.split(';').collect_vec();
+        let char_code = usize::from_str_radix(fields[0], 16)?;
+        combining_classes[char_code] = fields[3].parse()?;
+        if !fields[5].is_empty() {
+            let (compatibility, mapping) = match fields[5].split_once('>') {
+                Some((_, mapping)) => (true, mapping),
+                None => (false, fields[5]),
+            };
+            let mapping = mapping.split_whitespace()
+                .map(|code| usize::from_str_radix(code, 16))
+                .collect::<Result<Vec<_>, _>>()?;
+            decomposition_mappings.insert(char_code, (compatibility, mapping));
+        }
+    }
+
+    let mut exclusions = HashSet::new();
+    for line in composition_exclusions.lines() {
+        let line = line.unwrap();
+        if let Some((code, _)) = line.split_once('#') {
+            let code = code.trim();
+            if !code.is_empty() {
+                exclusions.extend(str_to_range(code));
+            }
+        }
+    }
+
+    let mut flags = [0u8;0x110000];
+    let mut compositions = BTreeMap::new();
+    for (&char_code, (compatibility, mapping)) in &decomposition_mappings {
+        if *compatibility {
+            flags[char_code] |= 0x12;
+        }
+        else {
+            flags[char_code] |= 0x03;
+            let singleton = mapping.len() == 1;
+            let non_starter = combining_classes[char_code] != 0 || combining_classes[mapping[0]] != 0;
+            if singleton || non_starter || exclusions.contains(&char_code) {
+                flags[char_code] |= 0x14;
+            }
+            else {
+                compositions.insert((mapping[0], mapping[1]), char_code);
+            }
+        }
+    }
+    for &(_, second) in compositions.keys() {
+        flags[second] |= 0x08;
+        if flags[second] & 0x10 == 0 {
+            flags[second] |= 0x20;
+        }
+    }
+    flags[HANGUL_SYLLABLES].fill(0x03);
+    flags[HANGUL_VOWELS].fill(0x28);
+    flags[HANGUL_TRAILING_CONSONANTS].fill(0x28);
+
+    // The decomposition tables have the full decomposition of each character, in canonical order.
+    let full_decomposition = |char_code: usize, compatibility: bool| {
+        let mut decomposition = vec!();
+        let mut pending = vec![char_code];
+        while let Some(char_code) = pending.pop() {
+            match decomposition_mappings.get(&char_code) {
+                Some((is_compatibility, mapping)) if compatibility || !is_compatibility => {
+                    pending.extend(mapping.iter().rev());
+                }
+                _ => decomposition.push(char_code),
+            }
+        }
+        let mut start = 0;
+        while start < decomposition.len() {
+            let end = (start..decomposition.len())
+                .find(|&i| combining_classes[decomposition[i]] == 0 && i > start)
+                .unwrap_or(decomposition.len());
+            decomposition[start..end].sort_by_key(|&c| combining_classes[c]);
+            start = end;
+        }
+        decomposition
+    };
+    // Only the characters whose compatibility decomposition differs from the canonical
+    // decomposition are included in the compatibility table. A canonical decomposition can contain
+    // characters with compatibility decompositions, so these are not always the characters with
+    // compatibility mappings in UnicodeData.txt.
+    let compatibility = decomposition_mappings.keys()
+        .map(|&char_code| (char_code, full_decomposition(char_code, true)))
+        .filter(|(char_code, decomposition)| *decomposition != full_decomposition(*char_code, false))
+        .collect_vec();
+    for (char_code, _) in &compatibility {
+        flags[*char_code] |= 0x10;
+        flags[*char_code] &= !0x20;
+    }
+    // A character whose decomposition starts with a character that can combine with the character
+    // before it is also Maybe, e.g., U+16D68 KIRAT RAI VOWEL SIGN AI. DerivedNormalizationProps.txt
+    // lists these as well.
+    for &char_code in decomposition_mappings.keys() {
+        let first = full_decomposition(char_code, false)[0];
+        if flags[char_code] & 0x04 == 0 && flags[first] & 0x08 != 0 {
+            flags[char_code] |= 0x08;
+        }
+        if flags[char_code] & 0x10 == 0 && flags[first] & 0x20 != 0 {
+            flags[char_code] |= 0x20;
+        }
+    }
+
+    write_data_tables(combining_class_rs, &header, &combining_classes, "CCC_TABLE", "CCC_PAGES")?;
+    write_data_tables(normalization_property_rs, &header, &flags, "NORM_TABLE", "NORM_PAGES")?;
+
+    let format_chars = |chars: &[usize]| chars.iter().map(|c| format!("'\\u{{{c:x}}}'")).join(", ");
+
+    write!(decompositions_rs, "{header}")?;
+    let canonical = decomposition_mappings.iter()
+        .filter(|(_, (compatibility, _))| !compatibility)
+        .map(|(&char_code, _)| (char_code, full_decomposition(char_code, false)))
+        .collect_vec();
+    writeln!(decompositions_rs, "pub const CANONICAL_DECOMPOSITIONS: [(char, &[char]);{}] = [", canonical.len())?;
+    for (char_code, decomposition) in canonical {
+        writeln!(decompositions_rs, "\t('\\u{{{char_code:x}}}', &[{}]),", format_chars(&decomposition))?;
+    }
+    writeln!(decompositions_rs, "];")?;
+    writeln!(decompositions_rs, "pub const COMPATIBILITY_DECOMPOSITIONS: [(char, &[char]);{}] = [", compatibility.len())?;
+    for (char_code, decomposition) in compatibility {
+        writeln!(decompositions_rs, "\t('\\u{{{char_code:x}}}', &[{}]),", format_chars(&decomposition))?;
+    }
+    writeln!(decompositions_rs, "];")?;
+    writeln!(decompositions_rs, "pub const COMPOSITIONS: [((char, char), char);{}] = [", compositions.len())?;
+    for ((first, second), composite) in compositions {
+        writeln!(decompositions_rs, "\t(('\\u{{{first:x}}}', '\\u{{{second:x}}}'), '\\u{{{composite:x}}}'),")?;
+    }
+    writeln!(decompositions_rs, "];")?;
+    Ok(())
+}
+
+// Each line of NormalizationTest.txt becomes a call to `normalization_test` with the source and
+// its four normalization forms. Characters which don't appear in part 1 of the file must be
+// unchanged by all of the normalization forms, so we also write the list of characters from
+// part 1 for a test of that.
+fn build_normalization_test(out_dir: &OsString, source: &UnicodeSource, normalization_test_txt: &PathBuf) -> anyhow::Result<()> {
+    let header = source.header(&[normalization_test_txt]);
+    let test_rs = Path::new(out_dir).join("normalization_test.rs");
+    let mut test_rs = File::create(test_rs)?;
+    let normalization_test = File::open(normalization_test_txt)?;
+    let normalization_test = BufReader::new(normalization_test);
+
+    write!(test_rs, "{header}")?;
+    writeln!(test_rs)?;
+    writeln!(test_rs, "use crate::normalization::tests::{{normalization_test, unchanged_test}};")?;
+    writeln!(test_rs)?;
+    writeln!(test_rs, "#[test]")?;
+    writeln!(test_rs, "fn standard_normalization_test() {{")?;
+    let mut part = "";
+    let mut part_one = vec!();
+    for line in normalization_test.lines() {
+        let line = line.unwrap();
+        if let Some(header) = line.strip_prefix('@') {
+            part = if header.starts_with("Part1") { "Part1" } else { "" };
+            continue;
+        }
+        if let Some((columns, comment)) = line.split_once('#') {
+            let columns = columns.split(';').map(|column| column.trim()).collect_vec();
+            if columns.len() > 5 {
+                let strings = columns[..5].iter()
+                    .map(|column| column.split_whitespace().map(|code| "\\u{".to_string() + code + "}").join(""))
+                    .collect_vec();
+                if part == "Part1" {
+                    part_one.push(columns[0].to_string());
+                }
+                writeln!(test_rs, "\tnormalization_test(\"{}\", \"{}\", \"{}\", \"{}\", \"{}\",\n\t\t{:?}\n\t);",
+                    strings[0], strings[1], strings[2], strings[3], strings[4], comment.trim())?;
+            }
+        }
+    }
+    writeln!(test_rs, "}}")?;
+    writeln!(test_rs)?;
+    part_one.sort_by_key(|code| u32::from_str_radix(code, 16).unwrap());
+    writeln!(test_rs, "static PART_ONE: [char;{}] = [", part_one.len())?;
+    for code in part_one {
+        writeln!(test_rs, "\t'\\u{{{code}}}',")?;
+    }
+    writeln!(test_rs, "];")?;
+    writeln!(test_rs)?;
+    writeln!(test_rs, "#[test]")?;
+    writeln!(test_rs, "fn standard_unchanged_test() {{")?;
+    writeln!(test_rs, "\tunchanged_test(&PART_ONE);")?;
+    writeln!(test_rs, "}}")?;
+    Ok(())
+}
+
+fn write_data_tables(mut rust_file : File, header: &str, raw_data: &[u8], table_name: &str, pages_name: &str) -> anyhow::Result<()> {
+    write!(rust_file, "{header}")?;
     writeln!(rust_file, "pub const {table_name}: [u8;0x1100] = [")?;
     let mut page_index = HashMap::new();
     let mut page_number = 0u8;
@@ -339,14 +2302,3 @@ fn write_data_tables(mut rust_file : File, raw_data: &[u8], table_name: &str, pa
     writeln!(rust_file, "pub const {pages_name}: [[u8;256];{}] = {pages:#x?};", pages.len())?;
     Ok(())
 }
-
-fn download_unicode_data(local_txt_data_file: &PathBuf, remote_txt_data_file: &str, unicode_version: &str) -> anyhow::Result<()> {
-    let url_base = "https://www.unicode.org/Public/".to_owned() + unicode_version + "/";
-    let client = Client::new();
-    if !local_txt_data_file.exists() {
-        let mut remote_data = client.get(url_base.clone() + remote_txt_data_file).send()?;
-        let mut file = File::create(local_txt_data_file)?;
-        std::io::copy(&mut remote_data, &mut file)?;
-    }
-    Ok(())
-}
\ No newline at end of file
@@ -0,0 +1,173 @@
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use anyhow::{bail, Context};
+use itertools::Itertools;
+use sha2::{Digest, Sha256};
+use zip::ZipArchive;
+
+/// Where the Unicode data files come from.
+enum Location {
+    /// Downloaded from unicode.org into a cache directory (if they're not already there)
+    Remote(PathBuf),
+    /// A local copy of `https://www.unicode.org/Public/{version}/`. For a copy of just the `ucd`
+    /// directory (e.g., an unpacked UCD.zip), the `ucd/` prefix of the file names can be left off.
+    Local(PathBuf),
+}
+
+/// The Unicode data files used to generate the tables. Every file is hashed as it is fetched so
+/// that it can be checked against a checksum manifest and recorded in the generated code.
+pub struct UnicodeSource {
+    version: String,
+    location: Location,
+    manifest: Option<BTreeMap<String, String>>,
+    fetched: BTreeMap<PathBuf, (String, String)>,
+}
+
+impl UnicodeSource {
+    /// Download the data files for `version` from unicode.org, keeping them in `cache_dir`. Without
+    /// the `download` feature, the files must already be in `cache_dir`.
+    pub fn remote(version: &str, cache_dir: &Path) -> anyhow::Result<UnicodeSource> {
+        let cache_dir = cache_dir.join(version);
+        std::fs::create_dir_all(&cache_dir)?;
+        Ok(UnicodeSource::new(version, Location::Remote(cache_dir)))
+    }
+
+    /// Read the data files from `ucd`, which is either a directory or a zip file. Zip files are
+    /// unpacked into `scratch_dir`.
+    pub fn local(version: &str, ucd: &Path, scratch_dir: &Path) -> anyhow::Result<UnicodeSource> {
+        if ucd.is_dir() {
+            return Ok(UnicodeSource::new(version, Location::Local(ucd.to_path_buf())));
+        }
+        if ucd.extension().is_some_and(|extension| extension.eq_ignore_ascii_case("zip")) {
+            let unpacked = scratch_dir.join("unzipped").join(version);
+            if unpacked.exists() {
+                std::fs::remove_dir_all(&unpacked)?;
+            }
+            std::fs::create_dir_all(&unpacked)?;
+            let zip = File::open(ucd).with_context(|| format!("Could not open {}", ucd.display()))?;
+            ZipArchive::new(zip)
+                .and_then(|mut archive| archive.extract(&unpacked))
+                .with_context(|| format!("Could not unpack {}", ucd.display()))?;
+            return Ok(UnicodeSource::new(version, Location::Local(unpacked)));
+        }
+        bail!("{} is not a directory or a zip file", ucd.display())
+    }
+
+    fn new(version: &str, location: Location) -> UnicodeSource {
+        UnicodeSource {
+            version: version.to_owned(),
+            location,
+            manifest: None,
+            fetched: BTreeMap::new(),
+        }
+    }
+
+    /// The checksum manifest which comes with a local copy of the data files, if there is one.
+    pub fn default_manifest(&self) -> Option<PathBuf> {
+        match &self.location {
+            Location::Local(root) => Some(root.join("SHA256SUMS")).filter(|manifest| manifest.is_file()),
+            Location::Remote(_) => None,
+        }
+    }
+
+    /// Check every file fetched from now on against `manifest_file`, which lists a SHA-256 digest
+    /// and a file name on each line in the format written by `sha256sum`.
+    pub fn verify_with(&mut self, manifest_file: &Path) -> anyhow::Result<()> {
+        let manifest = File::open(manifest_file)
+            .with_context(|| format!("Could not open checksum manifest {}", manifest_file.display()))?;
+        let mut entries = BTreeMap::new();
+        for line in BufReader::new(manifest).lines() {
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            match line.split_once(char::is_whitespace) {
+                Some((hash, name)) => {
+                    let name = name.trim_start().trim_start_matches('*');
+                    entries.insert(name.to_owned(), hash.to_ascii_lowercase());
+                }
+                None => bail!("Malformed line in {}: {line}", manifest_file.display())
+            }
+        }
+        self.manifest = Some(entries);
+        Ok(())
+    }
+
+    /// Get the data file `name` (its path relative to `https://www.unicode.org/Public/{version}/`),
+    /// returning the path of the local copy.
+    pub fn fetch(&mut self, name: &str) -> anyhow::Result<PathBuf> {
+        let (local_txt_data_file, name) = match &self.location {
+            Location::Remote(cache_dir) => {
+                let local_txt_data_file = cache_dir.join(name.rsplit('/').next().unwrap());
+                download_unicode_data(&local_txt_data_file, name, &self.version)?;
+                (local_txt_data_file, name)
+            }
+            Location::Local(root) => {
+                let short_name = name.strip_prefix("ucd/").unwrap_or(name);
+                if root.join(name).is_file() {
+                    (root.join(name), name)
+                } else if root.join(short_name).is_file() {
+                    (root.join(short_name), short_name)
+                } else {
+                    bail!("{name} not found in {}", root.display())
+                }
+            }
+        };
+        let hash = format!("{:x}", Sha256::digest(std::fs::read(&local_txt_data_file)?));
+        if let Some(manifest) = &self.manifest {
+            match manifest.get(name) {
+                Some(expected) if *expected == hash => {}
+                Some(expected) => bail!("Checksum mismatch for {name}: expected {expected}, found {hash}"),
+                None => bail!("{name} is not listed in the checksum manifest"),
+            }
+        }
+        self.fetched.insert(local_txt_data_file.clone(), (name.to_owned(), hash));
+        Ok(local_txt_data_file)
+    }
+
+    /// The comment block which begins each generated file, recording the Unicode version and the
+    /// digests of the data files it was generated from.
+    pub fn header(&self, inputs: &[&PathBuf]) -> String {
+        let mut header = String::from("// GENERATED CODE DO NOT MANUALLY EDIT\n");
+        header += &format!("// Generated from Unicode {} data files:\n", self.version);
+        for input in inputs {
+            let (name, hash) = &self.fetched[*input];
+            header += &format!("//   {hash}  {name}\n");
+        }
+        header
+    }
+
+    /// Write the digests of all the files fetched so far in the format read by `verify_with`.
+    pub fn write_manifest(&self, manifest_file: &Path) -> anyhow::Result<()> {
+        let mut manifest = File::create(manifest_file)?;
+        for (name, hash) in self.fetched.values().sorted() {
+            writeln!(manifest, "{hash}  {name}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "download")]
+fn download_unicode_data(local_txt_data_file: &PathBuf, remote_txt_data_file: &str, unicode_version: &str) -> anyhow::Result<()> {
+    use reqwest::blocking::Client;
+    let url_base = "https://www.unicode.org/Public/".to_owned() + unicode_version + "/";
+    let client = Client::new();
+    if !local_txt_data_file.exists() {
+        let mut remote_data = client.get(url_base.clone() + remote_txt_data_file).send()?.error_for_status()?;
+        let mut file = File::create(local_txt_data_file)?;
+        std::io::copy(&mut remote_data, &mut file)?;
+    }
+    Ok(())
+}
+
+// Without the `download` feature, only files which are already in the cache can be used.
+#[cfg(not(feature = "download"))]
+fn download_unicode_data(local_txt_data_file: &Path, remote_txt_data_file: &str, _unicode_version: &str) -> anyhow::Result<()> {
+    if !local_txt_data_file.exists() {
+        bail!("{remote_txt_data_file} has not been downloaded and generate-sources was built without the download feature: use --ucd to read a local copy of the data files")
+    }
+    Ok(())
+}
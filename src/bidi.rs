@@ -0,0 +1,732 @@
+//! This module implements the Unicode bidirectional algorithm described in
+//! [UAX #9](https://www.unicode.org/reports/tr9/). A `BidiParagraph` resolves the embedding level
+//! of each character of a paragraph, which can then be used to find the visual order of each line
+//! of the paragraph once it has been broken into lines.
+//! ```
+//! # use crate::finl_unicode::bidi::{BidiParagraph, Direction};
+//! let text = "car means אוטו.";
+//! let paragraph = BidiParagraph::new(text, None);
+//! assert_eq!(paragraph.direction(), Direction::LeftToRight);
+//! assert_eq!(paragraph.visual_runs(0..text.len()), [(0..10, 0), (10..18, 1), (18..19, 0)]);
+//! assert_eq!(paragraph.reorder_line(0..text.len()), "car means וטוא.");
+//! ```
+//!
+//! The paired brackets and mirroring glyphs used by the algorithm are also available as methods on
+//! `char`.
+//! ```
+//! # use crate::finl_unicode::bidi::{BracketType, CharacterBidi};
+//! assert_eq!('('.bidi_paired_bracket(), Some((')', BracketType::Open)));
+//! assert_eq!('≤'.bidi_mirroring_glyph(), Some('≥'));
+//! ```
+//!
+//! Only the algorithm for plain text is implemented, so the higher-level protocols of HL1–HL6 are
+//! not supported. The text given to `BidiParagraph::new` is treated as a single paragraph, so text
+//! made up of several paragraphs should first be split after each paragraph separator.
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
+use crate::data::bidi_brackets::{BIDI_MIRRORING_GLYPHS, BIDI_PAIRED_BRACKETS};
+use crate::properties::{BidiClass, CharacterProperties};
+use crate::properties::BidiClass::*;
+
+/// Trait to look up the properties used for bidi brackets and mirroring. Importing the trait will
+/// provide the methods on the `char` type.
+pub trait CharacterBidi {
+    /// Returns the bracket which is paired with a character along with whether the character is an
+    /// opening or closing bracket, or `None` if the character is not a paired bracket.
+    fn bidi_paired_bracket(self) -> Option<(char, BracketType)>;
+    /// Returns the character whose glyph is the mirror image of this character's glyph, which can
+    /// be displayed in its place in right-to-left text. Some mirrored characters, like ∛, have no
+    /// mirroring glyph and return `None`.
+    fn bidi_mirroring_glyph(self) -> Option<char>;
+}
+
+/// enum for the Bidi_Paired_Bracket_Type of a paired bracket.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BracketType {
+    Open,
+    Close,
+}
+
+/// enum for the direction of a paragraph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    LeftToRight,
+    RightToLeft,
+}
+
+impl CharacterBidi for char {
+    #[inline]
+    fn bidi_paired_bracket(self) -> Option<(char, BracketType)> {
+        BIDI_PAIRED_BRACKETS.binary_search_by_key(&self, |&(key, _, _)| key)
+            .ok()
+            .map(|index| {
+                let (_, paired, opening) = BIDI_PAIRED_BRACKETS[index];
+                (paired, if opening { BracketType::Open } else { BracketType::Close })
+            })
+    }
+
+    #[inline]
+    fn bidi_mirroring_glyph(self) -> Option<char> {
+        BIDI_MIRRORING_GLYPHS.binary_search_by_key(&self, |&(key, _)| key)
+            .ok()
+            .map(|index| BIDI_MIRRORING_GLYPHS[index].1)
+    }
+}
+
+/// The maximum explicit embedding level (BD2).
+const MAX_DEPTH: u8 = 125;
+
+/// The maximum number of nested brackets which are paired (BD16).
+const MAX_BRACKET_DEPTH: usize = 63;
+
+/// A paragraph of text with the embedding levels of its characters resolved by the bidirectional
+/// algorithm. The rules which apply to lines (L1 and L2) are applied by `line_levels`,
+/// `visual_runs` and `reorder_line`, which take the byte range of a line within the paragraph.
+pub struct BidiParagraph<'a> {
+    text: &'a str,
+    offsets: Vec<usize>,
+    classes: Vec<BidiClass>,
+    levels: Vec<u8>,
+    level: u8,
+}
+
+impl<'a> BidiParagraph<'a> {
+    /// Resolves the embedding levels of a paragraph. If `direction` is `None`, the direction of the
+    /// paragraph is taken from its first strong character (rules P2 and P3), so a paragraph with no
+    /// strong characters is left-to-right.
+    /// ```
+    /// # use crate::finl_unicode::bidi::{BidiParagraph, Direction};
+    /// assert_eq!(BidiParagraph::new("שלום!", None).level(), 1);
+    /// assert_eq!(BidiParagraph::new("שלום!", Some(Direction::LeftToRight)).level(), 0);
+    /// ```
+    pub fn new(text: &'a str, direction: Option<Direction>) -> BidiParagraph<'a> {
+        let (offsets, classes): (Vec<usize>, Vec<BidiClass>) = text.char_indices()
+            .map(|(offset, c)| (offset, c.bidi_class()))
+            .unzip();
+        let level = match direction.or_else(|| first_strong(&classes, false)) {
+            Some(Direction::RightToLeft) => 1,
+            _ => 0,
+        };
+        let mut paragraph = BidiParagraph {
+            text,
+            offsets,
+            levels: vec![level; classes.len()],
+            classes,
+            level,
+        };
+        paragraph.resolve();
+        paragraph
+    }
+
+    /// Returns the paragraph embedding level, which is 0 for a left-to-right paragraph and 1 for a
+    /// right-to-left paragraph.
+    #[inline]
+    pub fn level(&self) -> u8 {
+        self.level
+    }
+
+    /// Returns the direction of the paragraph.
+    #[inline]
+    pub fn direction(&self) -> Direction {
+        if self.level & 1 == 0 { Direction::LeftToRight } else { Direction::RightToLeft }
+    }
+
+    /// Returns the resolved embedding levels of the paragraph, one for each `char` of the text,
+    /// before the line-based rule L1 is applied. Characters which are removed by rule X9 (embedding
+    /// and override controls and boundary neutrals) are given the level of the preceding character.
+    #[inline]
+    pub fn levels(&self) -> &[u8] {
+        &self.levels
+    }
+
+    /// Returns the embedding levels of a line of the paragraph, one for each `char` of the line,
+    /// with trailing whitespace and separators reset to the paragraph level (rule L1). The line is
+    /// given by its byte range in the paragraph, which must fall on `char` boundaries.
+    pub fn line_levels(&self, line: Range<usize>) -> Vec<u8> {
+        let start = self.char_index(line.start);
+        let end = self.char_index(line.end);
+        let mut levels = self.levels[start..end].to_vec();
+        // Trailing whitespace is reset up to the end of the line or the next separator
+        let mut reset = true;
+        for index in (start..end).rev() {
+            match self.classes[index] {
+                S | B => {
+                    reset = true;
+                    levels[index - start] = self.level;
+                }
+                WS | LRI | RLI | FSI | PDI | LRE | RLE | LRO | RLO | PDF | BN if reset => {
+                    levels[index - start] = self.level;
+                }
+                _ => {
+                    reset = false;
+                }
+            }
+        }
+        levels
+    }
+
+    /// Returns the runs of a line of the paragraph in visual order as byte ranges in the paragraph
+    /// with their embedding levels (rule L2). The characters of a run with an odd level are
+    /// displayed from right to left.
+    pub fn visual_runs(&self, line: Range<usize>) -> Vec<(Range<usize>, u8)> {
+        let start = self.char_index(line.start);
+        let levels = self.line_levels(line.clone());
+        let mut runs: Vec<(Range<usize>, u8)> = vec!();
+        for (index, &level) in (start..).zip(levels.iter()) {
+            let end = self.offsets.get(index + 1).copied().unwrap_or(self.text.len());
+            match runs.last_mut() {
+                Some((range, run_level)) if *run_level == level => range.end = end,
+                _ => runs.push((self.offsets[index]..end, level)),
+            }
+        }
+        let highest = runs.iter().map(|&(_, level)| level).max().unwrap_or(0);
+        let lowest_odd = runs.iter().map(|&(_, level)| level | 1).min().unwrap_or(1);
+        for level in (lowest_odd..=highest).rev() {
+            let mut index = 0;
+            while index < runs.len() {
+                if runs[index].1 >= level {
+                    let run_start = index;
+                    while index < runs.len() && runs[index].1 >= level {
+                        index += 1;
+                    }
+                    runs[run_start..index].reverse();
+                }
+                else {
+                    index += 1;
+                }
+            }
+        }
+        runs
+    }
+
+    /// Returns a line of the paragraph in visual order, with characters in right-to-left runs
+    /// replaced by their mirroring glyphs (rule L4).
+    pub fn reorder_line(&self, line: Range<usize>) -> String {
+        let mut reordered = String::with_capacity(line.len());
+        for (range, level) in self.visual_runs(line) {
+            let run = &self.text[range];
+            if level & 1 == 0 {
+                reordered.push_str(run);
+            }
+            else {
+                reordered.extend(run.chars().rev().map(|c| c.bidi_mirroring_glyph().unwrap_or(c)));
+            }
+        }
+        reordered
+    }
+
+    #[inline]
+    fn char_index(&self, offset: usize) -> usize {
+        self.offsets.partition_point(|&char_offset| char_offset < offset)
+    }
+
+    // Rules X1–I2
+    fn resolve(&mut self) {
+        let mut classes = self.classes.clone();
+        self.resolve_explicit_levels(&mut classes);
+        let explicit_levels = self.levels.clone();
+        for sequence in self.isolating_run_sequences() {
+            self.resolve_sequence(&sequence, &explicit_levels, &mut classes);
+        }
+        // Characters removed by X9 take the level of the character before them
+        let mut previous = self.level;
+        for (index, level) in self.levels.iter_mut().enumerate() {
+            if is_removed(self.classes[index]) {
+                *level = previous;
+            }
+            previous = *level;
+        }
+    }
+
+    // Rules X1–X8. Character types are changed to L or R by directional overrides.
+    fn resolve_explicit_levels(&mut self, classes: &mut [BidiClass]) {
+        struct Status {
+            level: u8,
+            override_class: Option<BidiClass>,
+            isolate: bool,
+        }
+        let mut stack = vec![Status { level: self.level, override_class: None, isolate: false }];
+        let mut overflow_isolates = 0usize;
+        let mut overflow_embeddings = 0usize;
+        let mut valid_isolates = 0usize;
+        let levels = &mut self.levels;
+        for (index, &class) in self.classes.iter().enumerate() {
+            let current = stack.last().unwrap();
+            let (current_level, current_override) = (current.level, current.override_class);
+            match class {
+                class @ (RLE | LRE | RLO | LRO) => {
+                    levels[index] = current_level;
+                    let level = if matches!(class, RLE | RLO) { next_odd(current_level) } else { next_even(current_level) };
+                    if level <= MAX_DEPTH && overflow_isolates == 0 && overflow_embeddings == 0 {
+                        let override_class = match class {
+                            RLO => Some(R),
+                            LRO => Some(L),
+                            _ => None,
+                        };
+                        stack.push(Status { level, override_class, isolate: false });
+                    }
+                    else if overflow_isolates == 0 {
+                        overflow_embeddings += 1;
+                    }
+                }
+                class @ (RLI | LRI | FSI) => {
+                    levels[index] = current_level;
+                    if let Some(override_class) = current_override {
+                        classes[index] = override_class;
+                    }
+                    let rtl = match class {
+                        RLI => true,
+                        LRI => false,
+                        _ => first_strong(&self.classes[index + 1..], true) == Some(Direction::RightToLeft),
+                    };
+                    let level = if rtl { next_odd(current_level) } else { next_even(current_level) };
+                    if level <= MAX_DEPTH && overflow_isolates == 0 && overflow_embeddings == 0 {
+                        valid_isolates += 1;
+                        stack.push(Status { level, override_class: None, isolate: true });
+                    }
+                    else {
+                        overflow_isolates += 1;
+                    }
+                }
+                PDI => {
+                    if overflow_isolates > 0 {
+                        overflow_isolates -= 1;
+                    }
+                    else if valid_isolates > 0 {
+                        overflow_embeddings = 0;
+                        while stack.last().is_some_and(|status| !status.isolate) {
+                            stack.pop();
+                        }
+                        stack.pop();
+                        valid_isolates -= 1;
+                    }
+                    let current = stack.last().unwrap();
+                    levels[index] = current.level;
+                    if let Some(override_class) = current.override_class {
+                        classes[index] = override_class;
+                    }
+                }
+                PDF => {
+                    levels[index] = current_level;
+                    if overflow_isolates > 0 {
+                    }
+                    else if overflow_embeddings > 0 {
+                        overflow_embeddings -= 1;
+                    }
+                    else if !stack.last().unwrap().isolate && stack.len() >= 2 {
+                        stack.pop();
+                    }
+                }
+                B => {
+                    levels[index] = self.level;
+                }
+                BN => {
+                    levels[index] = current_level;
+                }
+                _ => {
+                    levels[index] = current_level;
+                    if let Some(override_class) = current_override {
+                        classes[index] = override_class;
+                    }
+                }
+            }
+        }
+    }
+
+    // Rules X9 and X10. Each isolating run sequence is returned as the list of the indices of its
+    // characters, leaving out the characters removed by X9.
+    fn isolating_run_sequences(&self) -> Vec<Vec<usize>> {
+        // Match isolate initiators with their PDIs (BD9)
+        let mut matching_pdi = vec![None; self.classes.len()];
+        let mut has_initiator = vec![false; self.classes.len()];
+        let mut open_isolates = vec!();
+        for (index, &class) in self.classes.iter().enumerate() {
+            match class {
+                LRI | RLI | FSI => open_isolates.push(index),
+                PDI => {
+                    if let Some(initiator) = open_isolates.pop() {
+                        matching_pdi[initiator] = Some(index);
+                        has_initiator[index] = true;
+                    }
+                }
+                B => open_isolates.clear(),
+                _ => {}
+            }
+        }
+
+        let mut level_runs: Vec<Vec<usize>> = vec!();
+        let mut previous_level = None;
+        for index in (0..self.classes.len()).filter(|&index| !is_removed(self.classes[index])) {
+            let level = self.levels[index];
+            match level_runs.last_mut() {
+                Some(run) if previous_level == Some(level) => run.push(index),
+                _ => level_runs.push(vec![index]),
+            }
+            previous_level = Some(level);
+        }
+
+        let mut run_starting_at = vec![None; self.classes.len()];
+        for (run_index, run) in level_runs.iter().enumerate() {
+            run_starting_at[run[0]] = Some(run_index);
+        }
+        let mut sequences = vec!();
+        for run in &level_runs {
+            if has_initiator[run[0]] {
+                continue;
+            }
+            let mut sequence = run.clone();
+            while let Some(pdi) = sequence.last().and_then(|&last| matching_pdi[last]) {
+                match run_starting_at[pdi] {
+                    Some(run_index) => sequence.extend_from_slice(&level_runs[run_index]),
+                    None => break,
+                }
+            }
+            sequences.push(sequence);
+        }
+        sequences
+    }
+
+    // Rules W1–I2 for a single isolating run sequence. The levels on either side of the sequence
+    // which determine sos and eos are the explicit levels from X1–X8.
+    fn resolve_sequence(&mut self, sequence: &[usize], explicit_levels: &[u8], classes: &mut [BidiClass]) {
+        let level = explicit_levels[sequence[0]];
+        let first = sequence[0];
+        let last = sequence[sequence.len() - 1];
+        let before = (0..first).rev()
+            .find(|&index| !is_removed(self.classes[index]))
+            .map_or(self.level, |index| explicit_levels[index]);
+        let after = if matches!(self.classes[last], LRI | RLI | FSI) {
+            self.level
+        }
+        else {
+            (last + 1..self.classes.len())
+                .find(|&index| !is_removed(self.classes[index]))
+                .map_or(self.level, |index| explicit_levels[index])
+        };
+        let sos = direction_class(level.max(before));
+        let eos = direction_class(level.max(after));
+        let embedding = direction_class(level);
+
+        let original = sequence.iter().map(|&index| classes[index]).collect::<Vec<_>>();
+        let mut types = original.clone();
+        let len = types.len();
+
+        // W1
+        for i in 0..len {
+            if types[i] == NSM {
+                types[i] = match i.checked_sub(1).map(|previous| types[previous]) {
+                    None => sos,
+                    Some(LRI | RLI | FSI | PDI) => ON,
+                    Some(previous) => previous,
+                };
+            }
+        }
+        // W2 and W3
+        let mut last_strong = sos;
+        for class in types.iter_mut() {
+            match *class {
+                L | R => last_strong = *class,
+                AL => {
+                    last_strong = AL;
+                    *class = R;
+                }
+                EN if last_strong == AL => *class = AN,
+                _ => {}
+            }
+        }
+        // W4
+        for i in 1..len.saturating_sub(1) {
+            match (types[i - 1], types[i], types[i + 1]) {
+                (EN, ES | CS, EN) => types[i] = EN,
+                (AN, CS, AN) => types[i] = AN,
+                _ => {}
+            }
+        }
+        // W5
+        let mut i = 0;
+        while i < len {
+            if types[i] == ET {
+                let start = i;
+                while i < len && types[i] == ET {
+                    i += 1;
+                }
+                if (start > 0 && types[start - 1] == EN) || (i < len && types[i] == EN) {
+                    types[start..i].fill(EN);
+                }
+            }
+            else {
+                i += 1;
+            }
+        }
+        // W6 and W7
+        let mut last_strong = sos;
+        for class in types.iter_mut() {
+            match *class {
+                ES | ET | CS => *class = ON,
+                L | R => last_strong = *class,
+                EN if last_strong == L => *class = L,
+                _ => {}
+            }
+        }
+
+        // N0
+        for (open, close) in self.bracket_pairs(sequence, &types) {
+            let mut found_embedding = false;
+            let mut found_opposite = false;
+            for &class in &types[open + 1..close] {
+                match strong_direction(class) {
+                    Some(direction) if direction == embedding => found_embedding = true,
+                    Some(_) => found_opposite = true,
+                    None => {}
+                }
+            }
+            let resolved = if found_embedding {
+                embedding
+            }
+            else if found_opposite {
+                let context = types[..open].iter().rev()
+                    .find_map(|&class| strong_direction(class))
+                    .unwrap_or(sos);
+                if context != embedding { context } else { embedding }
+            }
+            else {
+                continue;
+            };
+            for bracket in [open, close] {
+                types[bracket] = resolved;
+                for i in bracket + 1..len {
+                    if original[i] != NSM {
+                        break;
+                    }
+                    types[i] = resolved;
+                }
+            }
+        }
+
+        // N1 and N2
+        let mut i = 0;
+        while i < len {
+            if is_neutral(types[i]) {
+                let start = i;
+                while i < len && is_neutral(types[i]) {
+                    i += 1;
+                }
+                let leading = if start == 0 { sos } else { strong_direction(types[start - 1]).unwrap_or(embedding) };
+                let trailing = if i == len { eos } else { strong_direction(types[i]).unwrap_or(embedding) };
+                let resolved = if leading == trailing { leading } else { embedding };
+                types[start..i].fill(resolved);
+            }
+            else {
+                i += 1;
+            }
+        }
+
+        // I1 and I2
+        for (&index, &class) in sequence.iter().zip(types.iter()) {
+            let level = &mut self.levels[index];
+            *level += match (*level & 1, class) {
+                (0, R) => 1,
+                (0, AN | EN) => 2,
+                (1, L | EN | AN) => 1,
+                _ => 0,
+            };
+        }
+    }
+
+    // BD16: the bracket pairs of an isolating run sequence as positions within the sequence,
+    // sorted by the position of the opening bracket.
+    fn bracket_pairs(&self, sequence: &[usize], types: &[BidiClass]) -> Vec<(usize, usize)> {
+        let mut pairs = vec!();
+        let mut stack: Vec<(char, usize)> = vec!();
+        for (position, &index) in sequence.iter().enumerate() {
+            if types[position] != ON {
+                continue;
+            }
+            let c = self.text[self.offsets[index]..].chars().next().unwrap_or_default();
+            match c.bidi_paired_bracket() {
+                Some((paired, BracketType::Open)) => {
+                    if stack.len() == MAX_BRACKET_DEPTH {
+                        break;
+                    }
+                    stack.push((canonical_bracket(paired), position));
+                }
+                Some((_, BracketType::Close)) => {
+                    let c = canonical_bracket(c);
+                    if let Some(depth) = stack.iter().rposition(|&(closing, _)| closing == c) {
+                        pairs.push((stack[depth].1, position));
+                        stack.truncate(depth);
+                    }
+                }
+                None => {}
+            }
+        }
+        pairs.sort_unstable();
+        pairs
+    }
+}
+
+// P2 and P3: the direction of the first strong character, skipping over isolates. When finding the
+// direction of an FSI, the search stops at the PDI which matches it.
+fn first_strong(classes: &[BidiClass], stop_at_pdi: bool) -> Option<Direction> {
+    let mut isolates = 0usize;
+    for &class in classes {
+        match class {
+            L if isolates == 0 => return Some(Direction::LeftToRight),
+            R | AL if isolates == 0 => return Some(Direction::RightToLeft),
+            LRI | RLI | FSI => isolates += 1,
+            PDI if isolates > 0 => isolates -= 1,
+            PDI if stop_at_pdi => return None,
+            B => return None,
+            _ => {}
+        }
+    }
+    None
+}
+
+#[inline]
+fn next_odd(level: u8) -> u8 {
+    (level + 1) | 1
+}
+
+#[inline]
+fn next_even(level: u8) -> u8 {
+    (level + 2) & !1
+}
+
+#[inline]
+fn direction_class(level: u8) -> BidiClass {
+    if level & 1 == 0 { L } else { R }
+}
+
+// European and Arabic numbers count as R for rules N0 and N1.
+#[inline]
+fn strong_direction(class: BidiClass) -> Option<BidiClass> {
+    match class {
+        L => Some(L),
+        R | AL | EN | AN => Some(R),
+        _ => None,
+    }
+}
+
+#[inline]
+fn is_neutral(class: BidiClass) -> bool {
+    matches!(class, B | S | WS | ON | LRI | RLI | FSI | PDI)
+}
+
+#[inline]
+fn is_removed(class: BidiClass) -> bool {
+    matches!(class, RLE | LRE | RLO | LRO | PDF | BN)
+}
+
+// U+2329 and U+232A are canonically equivalent to U+3008 and U+3009, so they pair with them.
+#[inline]
+fn canonical_bracket(c: char) -> char {
+    match c {
+        '\u{2329}' => '\u{3008}',
+        '\u{232a}' => '\u{3009}',
+        c => c,
+    }
+}
+
+#[cfg(test)]
+pub (crate) mod tests {
+    use crate::bidi::*;
+
+    #[test]
+    fn can_get_brackets_and_mirroring() {
+        assert_eq!('['.bidi_paired_bracket(), Some((']', BracketType::Open)));
+        assert_eq!('»'.bidi_paired_bracket(), None);
+        assert_eq!('\u{2329}'.bidi_paired_bracket(), Some(('\u{232a}', BracketType::Open)));
+        assert_eq!('}'.bidi_paired_bracket(), Some(('{', BracketType::Close)));
+        assert_eq!('»'.bidi_mirroring_glyph(), Some('«'));
+        assert_eq!('a'.bidi_mirroring_glyph(), None);
+    }
+
+    #[test]
+    fn can_reorder_paragraphs() {
+        let paragraph = BidiParagraph::new("", None);
+        assert_eq!(paragraph.level(), 0);
+        assert!(paragraph.visual_runs(0..0).is_empty());
+        // Arabic numbers and isolates raise the level even when the text is all left-to-right
+        assert_eq!(BidiParagraph::new("\u{661}", Some(Direction::LeftToRight)).levels(), [2]);
+        assert_eq!(BidiParagraph::new("\u{2066}!\u{2069}", None).levels(), [0, 2, 0]);
+
+        let text = "אבג (def) הוז";
+        let paragraph = BidiParagraph::new(text, None);
+        assert_eq!(paragraph.direction(), Direction::RightToLeft);
+        assert_eq!(paragraph.reorder_line(0..text.len()), "זוה (def) גבא");
+
+        let text = "he said “ما اسمك؟” twice";
+        let paragraph = BidiParagraph::new(text, Some(Direction::LeftToRight));
+        assert_eq!(paragraph.reorder_line(0..text.len()), "he said “؟كمسا ام” twice");
+
+        // Each line is reordered separately, with trailing whitespace at the paragraph level
+        let text = "abc אבג דהו xyz";
+        let paragraph = BidiParagraph::new(text, None);
+        assert_eq!(paragraph.line_levels(0..11), [0, 0, 0, 0, 1, 1, 1, 0]);
+        assert_eq!(paragraph.reorder_line(0..11), "abc גבא ");
+        assert_eq!(paragraph.reorder_line(11..text.len()), "והד xyz");
+    }
+
+    const CLASS_CHARS: [(&str, char); 23] = [
+        ("L", 'a'), ("R", '\u{5d0}'), ("AL", '\u{627}'), ("EN", '1'), ("ES", '+'), ("ET", '#'),
+        ("AN", '\u{661}'), ("CS", ','), ("NSM", '\u{300}'), ("BN", '\u{ad}'), ("B", '\u{2029}'),
+        ("S", '\t'), ("WS", ' '), ("ON", '!'), ("LRE", '\u{202a}'), ("LRO", '\u{202d}'),
+        ("RLE", '\u{202b}'), ("RLO", '\u{202e}'), ("PDF", '\u{202c}'), ("LRI", '\u{2066}'),
+        ("RLI", '\u{2067}'), ("FSI", '\u{2068}'), ("PDI", '\u{2069}'),
+    ];
+
+    pub (crate) fn bidi_test(classes: &str, paragraph_levels: u8, levels: &str, reorder: &str) {
+        let input = classes.split_whitespace()
+            .map(|class| CLASS_CHARS.iter().find(|&&(name, _)| name == class).unwrap().1)
+            .collect::<String>();
+        for (bit, direction) in [(1, None), (2, Some(Direction::LeftToRight)), (4, Some(Direction::RightToLeft))] {
+            if paragraph_levels & bit != 0 {
+                check_paragraph(&input, direction, None, levels, reorder, classes);
+            }
+        }
+    }
+
+    pub (crate) fn bidi_character_test(input: &str, direction: u8, paragraph_level: u8, levels: &str, reorder: &str) {
+        let direction = match direction {
+            0 => Some(Direction::LeftToRight),
+            1 => Some(Direction::RightToLeft),
+            _ => None,
+        };
+        check_paragraph(input, direction, Some(paragraph_level), levels, reorder, input);
+    }
+
+    fn check_paragraph(input: &str, direction: Option<Direction>, paragraph_level: Option<u8>, levels: &str, reorder: &str, message: &str) {
+        let paragraph = BidiParagraph::new(input, direction);
+        if let Some(paragraph_level) = paragraph_level {
+            assert_eq!(paragraph.level(), paragraph_level, "Paragraph level mismatch: {message} ({direction:?})");
+        }
+        let actual_levels = paragraph.line_levels(0..input.len());
+        let expected_levels = levels.split_whitespace().collect::<Vec<_>>();
+        assert_eq!(actual_levels.len(), expected_levels.len(), "Length mismatch: {message} ({direction:?})");
+        for (actual, expected) in actual_levels.iter().zip(expected_levels) {
+            if expected != "x" {
+                assert_eq!(actual.to_string(), expected, "Levels mismatch: {message} ({direction:?}) {actual_levels:?}");
+            }
+        }
+        // The visual order leaves out the characters removed by X9
+        let char_indices = input.char_indices().map(|(offset, _)| offset).collect::<Vec<_>>();
+        let mut order = vec!();
+        for (range, level) in paragraph.visual_runs(0..input.len()) {
+            let run = char_indices.iter().enumerate()
+                .filter(|&(index, offset)| range.contains(offset) && !is_removed(paragraph.classes[index]))
+                .map(|(index, _)| index);
+            if level & 1 == 0 {
+                order.extend(run);
+            }
+            else {
+                order.extend(run.rev());
+            }
+        }
+        let order = order.iter().map(usize::to_string).collect::<Vec<_>>().join(" ");
+        assert_eq!(order, reorder, "Reorder mismatch: {message} ({direction:?})");
+    }
+}
@@ -0,0 +1,76 @@
+//! This module provides the binary properties listed in `PropList.txt` and
+//! `DerivedCoreProperties.txt`, such as White_Space, Alphabetic, Math and Dash.
+//! ```
+//! # use crate::finl_unicode::binary_properties::{BinaryProperty, CharacterBinaryProperties};
+//! assert!(' '.has_property(BinaryProperty::WhiteSpace));
+//! assert!(!'ǅ'.has_property(BinaryProperty::Uppercase));
+//! assert!('ǅ'.has_property(BinaryProperty::Cased));
+//! assert!('−'.has_property(BinaryProperty::Math));
+//! assert!('\u{fdd0}'.has_property(BinaryProperty::NoncharacterCodePoint));
+//! ```
+//!
+//! All of the properties of a character are found with a single lookup in a multistage table, so
+//! `has_property` takes constant time no matter which property is asked for.
+
+use crate::data::binary_properties::{BINARY_PROPERTIES, BINARY_PROPERTY_NAMES, BINARY_PROPERTY_SETS};
+use crate::data::binary_property::{BP_PAGES, BP_TABLE};
+pub use crate::data::binary_properties::BinaryProperty;
+
+/// Trait to look up the binary properties of a character. Importing the trait will provide the
+/// method on the `char` type.
+pub trait CharacterBinaryProperties {
+    /// Determines whether a character has a binary property.
+    fn has_property(self, property: BinaryProperty) -> bool;
+}
+
+impl CharacterBinaryProperties for char {
+    #[inline]
+    fn has_property(self, property: BinaryProperty) -> bool {
+        let index = BP_PAGES[usize::from(BP_TABLE[(self as usize) >> 8])][(self as usize) & 0xff];
+        BINARY_PROPERTY_SETS[usize::from(index)] & (1 << property as u32) != 0
+    }
+}
+
+impl BinaryProperty {
+    /// Returns the name of the property as given in the Unicode character database, e.g.,
+    /// `White_Space` for `BinaryProperty::WhiteSpace`.
+    #[inline]
+    pub fn name(self) -> &'static str {
+        BINARY_PROPERTY_NAMES[self as usize]
+    }
+
+    /// Returns an iterator over all of the binary properties in alphabetical order.
+    pub fn all() -> impl ExactSizeIterator<Item = BinaryProperty> + DoubleEndedIterator {
+        BINARY_PROPERTIES.iter().copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::binary_properties::*;
+
+    #[test]
+    fn can_get_binary_properties() {
+        assert!('\t'.has_property(BinaryProperty::WhiteSpace));
+        assert!('\u{3000}'.has_property(BinaryProperty::WhiteSpace));
+        assert!(!'\u{200b}'.has_property(BinaryProperty::WhiteSpace));
+        assert!('\u{200b}'.has_property(BinaryProperty::DefaultIgnorableCodePoint));
+        assert!('ª'.has_property(BinaryProperty::Lowercase));
+        assert!('Ⓐ'.has_property(BinaryProperty::Uppercase));
+        assert!(!'1'.has_property(BinaryProperty::Alphabetic));
+        assert!('\u{345}'.has_property(BinaryProperty::Alphabetic));
+        assert!('—'.has_property(BinaryProperty::Dash));
+        assert!('«'.has_property(BinaryProperty::QuotationMark));
+        assert!('f'.has_property(BinaryProperty::ASCIIHexDigit));
+        assert!('ｆ'.has_property(BinaryProperty::HexDigit));
+        assert!(!'ｆ'.has_property(BinaryProperty::ASCIIHexDigit));
+        assert!('\u{10ffff}'.has_property(BinaryProperty::NoncharacterCodePoint));
+        assert!(!'a'.has_property(BinaryProperty::NoncharacterCodePoint));
+        assert!('漢'.has_property(BinaryProperty::UnifiedIdeograph));
+        assert!(!'\u{378}'.has_property(BinaryProperty::Alphabetic));
+
+        assert_eq!(BinaryProperty::DefaultIgnorableCodePoint.name(), "Default_Ignorable_Code_Point");
+        assert!(BinaryProperty::all().any(|property| property == BinaryProperty::Math));
+        assert!(BinaryProperty::all().all(|property| !property.name().starts_with("Other_")));
+    }
+}
@@ -0,0 +1,105 @@
+//! This module provides the Block property, which divides the code space into the named,
+//! contiguous ranges listed in `Blocks.txt`.
+//! ```
+//! # use crate::finl_unicode::blocks::{Block, CharacterBlock};
+//! assert_eq!('a'.block(), Some(Block::BasicLatin));
+//! assert_eq!('é'.block(), Some(Block::Latin1Supplement));
+//! assert_eq!('\u{2fe0}'.block(), None);
+//! assert_eq!(Block::GreekAndCoptic.name(), "Greek and Coptic");
+//! assert_eq!(Block::GreekAndCoptic.range(), 0x370..=0x3ff);
+//! ```
+//!
+//! All of the blocks can be iterated over in code point order with `Block::all`.
+//! ```
+//! # use crate::finl_unicode::blocks::Block;
+//! let supplementary = Block::all().filter(|block| *block.range().start() >= 0x10000).count();
+//! assert!(supplementary > 100);
+//! ```
+
+use core::cmp::Ordering;
+use core::ops::RangeInclusive;
+use crate::data::blocks::{BLOCKS, BLOCK_NAMES};
+pub use crate::data::blocks::Block;
+
+/// Trait to look up the block of a character. Importing the trait will provide the method on
+/// the `char` type.
+pub trait CharacterBlock {
+    /// Returns the block that a character is in, or `None` if the character is not in any block
+    /// (i.e., its Block property is No_Block).
+    fn block(self) -> Option<Block>;
+}
+
+impl CharacterBlock for char {
+    #[inline]
+    fn block(self) -> Option<Block> {
+        let c = self as u32;
+        BLOCKS.binary_search_by(|&(start, end, _)| {
+            if end < c {
+                Ordering::Less
+            } else if start > c {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        }).ok().map(|index| BLOCKS[index].2)
+    }
+}
+
+impl Block {
+    /// Returns the name of the block as given in `Blocks.txt`, e.g., `Latin-1 Supplement` for
+    /// `Block::Latin1Supplement`.
+    #[inline]
+    pub fn name(self) -> &'static str {
+        BLOCK_NAMES[self as usize]
+    }
+
+    /// Returns the range of code points in the block. This is given as `u32` values rather than
+    /// `char` since the surrogate blocks are not made up of valid `char` values.
+    #[inline]
+    pub fn range(self) -> RangeInclusive<u32> {
+        let (start, end, _) = BLOCKS[self as usize];
+        start..=end
+    }
+
+    /// Returns an iterator over all of the blocks in code point order.
+    pub fn all() -> impl ExactSizeIterator<Item = Block> + DoubleEndedIterator {
+        BLOCKS.iter().map(|&(_, _, block)| block)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::blocks::*;
+
+    #[test]
+    fn can_get_blocks() {
+        assert_eq!('Z'.block(), Some(Block::BasicLatin));
+        assert_eq!('\u{7f}'.block(), Some(Block::BasicLatin));
+        assert_eq!('\u{80}'.block(), Some(Block::Latin1Supplement));
+        assert_eq!('ж'.block(), Some(Block::Cyrillic));
+        assert_eq!('漢'.block(), Some(Block::CJKUnifiedIdeographs));
+        assert_eq!('😀'.block(), Some(Block::Emoticons));
+        assert_eq!('\u{10ffff}'.block(), Some(Block::SupplementaryPrivateUseAreaB));
+        assert_eq!('\u{e0080}'.block(), None);
+        assert_eq!('\u{30000}'.block(), Some(Block::CJKUnifiedIdeographsExtensionG));
+
+        assert_eq!(Block::Latin1Supplement.name(), "Latin-1 Supplement");
+        assert_eq!(Block::HighSurrogates.range(), 0xd800..=0xdb7f);
+    }
+
+    #[test]
+    fn blocks_are_in_order() {
+        let mut last_end = None;
+        for block in Block::all() {
+            let range = block.range();
+            if let Some(end) = last_end {
+                assert!(end < *range.start());
+            }
+            if let Some(c) = char::from_u32(*range.start()) {
+                assert_eq!(c.block(), Some(block));
+            }
+            last_end = Some(*range.end());
+        }
+        assert_eq!(Block::all().count(), BLOCKS.len());
+    }
+}
@@ -0,0 +1,455 @@
+//! This module provides the case mappings and case folding described in chapter 3 of the Unicode
+//! standard. The `CharacterCase` trait provides the simple (single character) and full mappings
+//! of a character on its own.
+//! ```
+//! # use crate::finl_unicode::case::CharacterCase;
+//! assert_eq!('a'.to_simple_uppercase(), 'A');
+//! assert_eq!('ǆ'.to_simple_titlecase(), 'ǅ');
+//! assert_eq!('ß'.to_simple_uppercase(), 'ß');
+//! assert_eq!('ß'.to_full_uppercase().to_string(), "SS");
+//! assert_eq!('ﬁ'.to_full_titlecase().to_string(), "Fi");
+//! assert_eq!('ẞ'.to_full_case_fold().to_string(), "ss");
+//! ```
+//!
+//! Converting the case of a string uses the full mappings along with the context-sensitive
+//! mappings in `SpecialCasing.txt`, such as the final form of Greek sigma, and the
+//! language-sensitive mappings for Turkish, Azeri and Lithuanian.
+//! ```
+//! # use crate::finl_unicode::case::{case_fold, eq_ignore_case, to_lowercase, to_uppercase, Language};
+//! assert_eq!(to_uppercase("straße", Language::Default), "STRASSE");
+//! assert_eq!(to_lowercase("ΟΔΥΣΣΕΥΣ", Language::Default), "οδυσσευς");
+//! assert_eq!(to_uppercase("istanbul", Language::Turkish), "İSTANBUL");
+//! assert_eq!(to_lowercase("DİYARBAKIR", Language::Turkish), "diyarbakır");
+//! assert_eq!(case_fold("Maße", Language::Default), "masse");
+//! assert!(eq_ignore_case("STRASSE", "straße"));
+//! ```
+//!
+//! `to_titlecase` finds the words of a string with the word boundaries from the `word_boundaries`
+//! module and titlecases the first cased character of each one.
+//! ```
+//! # use crate::finl_unicode::case::{to_titlecase, Language};
+//! assert_eq!(to_titlecase("ǆemal ﬁsh", Language::Default), "ǅemal Fish");
+//! assert_eq!(to_titlecase("iSTANBUL'DA (“ıspanak”)", Language::Turkish), "İstanbul'da (“Ispanak”)");
+//! ```
+
+use alloc::string::String;
+use core::fmt;
+use core::iter::FusedIterator;
+use crate::data::case_mapping::{CONDITIONAL_CASE_MAPPINGS, FULL_CASE_FOLDING, FULL_LOWERCASE, FULL_TITLECASE, FULL_UPPERCASE,
+                                SIMPLE_CASE_FOLDING, SIMPLE_LOWERCASE, SIMPLE_TITLECASE, SIMPLE_UPPERCASE, TURKIC_CASE_FOLDING};
+use crate::data::case_property::{CASE_PAGES, CASE_TABLE};
+use crate::word_boundaries::Words;
+
+/// Trait to look up the case mappings of a character. Importing the trait will provide the
+/// methods on the `char` type.
+///
+/// The full mappings don't take the surrounding text or language into account. Use the string
+/// functions in this module for that.
+pub trait CharacterCase {
+    /// Returns the simple uppercase mapping of a character, or the character itself if it has none.
+    fn to_simple_uppercase(self) -> char;
+    /// Returns the simple lowercase mapping of a character, or the character itself if it has none.
+    fn to_simple_lowercase(self) -> char;
+    /// Returns the simple titlecase mapping of a character, or the character itself if it has none.
+    /// This is the same as the uppercase mapping except for characters like ǆ which have distinct
+    /// titlecase forms.
+    fn to_simple_titlecase(self) -> char;
+    /// Returns the simple case folding of a character, or the character itself if it has none.
+    fn to_simple_case_fold(self) -> char;
+    /// Returns the full uppercase mapping of a character, which may be more than one character
+    /// (e.g., ß becomes SS).
+    fn to_full_uppercase(self) -> CaseMapping;
+    /// Returns the full lowercase mapping of a character, which may be more than one character.
+    fn to_full_lowercase(self) -> CaseMapping;
+    /// Returns the full titlecase mapping of a character, which may be more than one character
+    /// (e.g., ﬁ becomes Fi).
+    fn to_full_titlecase(self) -> CaseMapping;
+    /// Returns the full case folding of a character, which may be more than one character.
+    fn to_full_case_fold(self) -> CaseMapping;
+}
+
+/// The languages with tailored case mappings in `SpecialCasing.txt` and `CaseFolding.txt`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Language {
+    /// The mappings used for all other languages
+    #[default]
+    Default,
+    /// Turkish, where i and ı are distinct letters with the uppercase forms İ and I
+    Turkish,
+    /// Azeri, which has the same mappings as Turkish
+    Azeri,
+    /// Lithuanian, where a dot above is kept on i and j when lowercasing them with accents
+    Lithuanian,
+}
+
+impl Language {
+    /// The language code used in `SpecialCasing.txt`.
+    fn code(self) -> &'static str {
+        match self {
+            Language::Default => "",
+            Language::Turkish => "tr",
+            Language::Azeri => "az",
+            Language::Lithuanian => "lt",
+        }
+    }
+}
+
+/// An iterator over the characters that a character maps to. Most mappings are a single character
+/// but full mappings can be up to three. It implements `Display` so the mapping can be written
+/// straight into a string.
+#[derive(Debug, Clone)]
+pub struct CaseMapping {
+    chars: [char; 3],
+    start: usize,
+    end: usize,
+}
+
+impl CaseMapping {
+    fn single(c: char) -> CaseMapping {
+        CaseMapping { chars: [c, '\0', '\0'], start: 0, end: 1 }
+    }
+
+    fn from_slice(mapping: &[char]) -> CaseMapping {
+        let mut chars = ['\0'; 3];
+        chars[..mapping.len()].copy_from_slice(mapping);
+        CaseMapping { chars, start: 0, end: mapping.len() }
+    }
+}
+
+impl Iterator for CaseMapping {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        (self.start < self.end).then(|| {
+            self.start += 1;
+            self.chars[self.start - 1]
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.end - self.start, Some(self.end - self.start))
+    }
+}
+
+impl DoubleEndedIterator for CaseMapping {
+    #[inline]
+    fn next_back(&mut self) -> Option<char> {
+        (self.start < self.end).then(|| {
+            self.end -= 1;
+            self.chars[self.end]
+        })
+    }
+}
+
+impl ExactSizeIterator for CaseMapping {}
+
+impl FusedIterator for CaseMapping {}
+
+impl fmt::Display for CaseMapping {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.chars[self.start..self.end].iter().try_for_each(|c| fmt::Write::write_char(f, *c))
+    }
+}
+
+impl CharacterCase for char {
+    #[inline]
+    fn to_simple_uppercase(self) -> char {
+        lookup(&SIMPLE_UPPERCASE, self).unwrap_or(self)
+    }
+
+    #[inline]
+    fn to_simple_lowercase(self) -> char {
+        lookup(&SIMPLE_LOWERCASE, self).unwrap_or(self)
+    }
+
+    #[inline]
+    fn to_simple_titlecase(self) -> char {
+        lookup(&SIMPLE_TITLECASE, self).unwrap_or_else(|| self.to_simple_uppercase())
+    }
+
+    #[inline]
+    fn to_simple_case_fold(self) -> char {
+        lookup(&SIMPLE_CASE_FOLDING, self).unwrap_or(self)
+    }
+
+    #[inline]
+    fn to_full_uppercase(self) -> CaseMapping {
+        lookup_full(&FULL_UPPERCASE, self).unwrap_or_else(|| CaseMapping::single(self.to_simple_uppercase()))
+    }
+
+    #[inline]
+    fn to_full_lowercase(self) -> CaseMapping {
+        lookup_full(&FULL_LOWERCASE, self).unwrap_or_else(|| CaseMapping::single(self.to_simple_lowercase()))
+    }
+
+    #[inline]
+    fn to_full_titlecase(self) -> CaseMapping {
+        lookup_full(&FULL_TITLECASE, self).unwrap_or_else(|| CaseMapping::single(self.to_simple_titlecase()))
+    }
+
+    #[inline]
+    fn to_full_case_fold(self) -> CaseMapping {
+        lookup_full(&FULL_CASE_FOLDING, self).unwrap_or_else(|| CaseMapping::single(self.to_simple_case_fold()))
+    }
+}
+
+/// Converts a string to uppercase using the full case mappings for the given language.
+pub fn to_uppercase(s: &str, language: Language) -> String {
+    map_string(s, language, CaseKind::Upper)
+}
+
+/// Converts a string to lowercase using the full case mappings for the given language. A Greek
+/// capital sigma becomes ς at the end of a word and σ elsewhere.
+pub fn to_lowercase(s: &str, language: Language) -> String {
+    map_string(s, language, CaseKind::Lower)
+}
+
+/// Converts a string to title case using the full case mappings for the given language. The first
+/// cased character (one with the `Cased` property, so ª or Ⓐ as well as letters like a or A) of
+/// each word is mapped to titlecase and the rest of the word to lowercase. Words are found with
+/// the word boundaries from UAX #29, so, e.g., an apostrophe in the middle of a word does not
+/// start a new one.
+pub fn to_titlecase(s: &str, language: Language) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut offset = 0;
+    for word in Words::new(s) {
+        let mut found_cased = false;
+        for (index, c) in word.char_indices() {
+            let kind = if !found_cased && get_property(c) & CaseProperty::CASED != 0 {
+                found_cased = true;
+                CaseKind::Title
+            } else {
+                CaseKind::Lower
+            };
+            result.extend(map_char_in_context(s, offset + index, c, language, kind));
+        }
+        offset += word.len();
+    }
+    result
+}
+
+/// Returns the full case folding of a string. Turkish and Azeri fold I to ı and İ to i rather than
+/// I to i and İ to i̇. Strings which differ only in case will have the same case folding.
+pub fn case_fold(s: &str, language: Language) -> String {
+    s.chars().flat_map(|c| case_fold_char(c, language)).collect()
+}
+
+/// Determines whether two strings are the same when case differences are ignored, i.e., whether
+/// they have the same full case folding. The strings are compared without allocating.
+/// ```
+/// # use crate::finl_unicode::case::eq_ignore_case;
+/// assert!(eq_ignore_case("ΣΊΣΥΦΟΣ", "σίσυφος"));
+/// assert!(!eq_ignore_case("finl", "final"));
+/// ```
+pub fn eq_ignore_case(a: &str, b: &str) -> bool {
+    a.chars().flat_map(CharacterCase::to_full_case_fold)
+        .eq(b.chars().flat_map(CharacterCase::to_full_case_fold))
+}
+
+// ------------------------
+// Private implementation details follow
+
+#[derive(Clone, Copy)]
+enum CaseKind {
+    Lower,
+    Title,
+    Upper,
+}
+
+fn map_string(s: &str, language: Language, kind: CaseKind) -> String {
+    let mut result = String::with_capacity(s.len());
+    for (index, c) in s.char_indices() {
+        result.extend(map_char_in_context(s, index, c, language, kind));
+    }
+    result
+}
+
+/// Map a single character found at `index` in `s`, using the conditional mappings in
+/// `SpecialCasing.txt` when they apply.
+fn map_char_in_context(s: &str, index: usize, c: char, language: Language, kind: CaseKind) -> CaseMapping {
+    let conditional = if get_property(c) & CaseProperty::CONDITIONAL == 0 {
+        None
+    } else {
+        CONDITIONAL_CASE_MAPPINGS.iter()
+            .filter(|&&(key, code, _, _, _, _)| key == c && (code.is_empty() || code == language.code()))
+            .find(|&&(_, _, condition, _, _, _)| condition_holds(s, index, c, condition))
+    };
+    match (conditional, kind) {
+        (Some(&(_, _, _, lower, _, _)), CaseKind::Lower) => CaseMapping::from_slice(lower),
+        (Some(&(_, _, _, _, title, _)), CaseKind::Title) => CaseMapping::from_slice(title),
+        (Some(&(_, _, _, _, _, upper)), CaseKind::Upper) => CaseMapping::from_slice(upper),
+        (None, CaseKind::Lower) => c.to_full_lowercase(),
+        (None, CaseKind::Title) => c.to_full_titlecase(),
+        (None, CaseKind::Upper) => c.to_full_uppercase(),
+    }
+}
+
+fn case_fold_char(c: char, language: Language) -> CaseMapping {
+    match language {
+        Language::Turkish | Language::Azeri => lookup(&TURKIC_CASE_FOLDING, c)
+            .map(CaseMapping::single)
+            .unwrap_or_else(|| c.to_full_case_fold()),
+        _ => c.to_full_case_fold()
+    }
+}
+
+struct CaseProperty;
+
+impl CaseProperty {
+    const CASED: u8 = 0x01;
+    const CASE_IGNORABLE: u8 = 0x02;
+    const SOFT_DOTTED: u8 = 0x04;
+    const ABOVE: u8 = 0x08;
+    const NOT_REORDERED: u8 = 0x10;
+    /// Combining class 0 or 230, which ends the search for the context of a combining dot above
+    const CONTEXT_BOUNDARY: u8 = CaseProperty::ABOVE | CaseProperty::NOT_REORDERED;
+    /// The character has an entry in `CONDITIONAL_CASE_MAPPINGS`
+    const CONDITIONAL: u8 = 0x20;
+}
+
+#[inline]
+fn get_property(c: char) -> u8 {
+    CASE_PAGES[usize::from(CASE_TABLE[(c as usize) >> 8])][(c as usize) & 0xff]
+}
+
+/// Test the casing contexts defined in table 3-17 of the Unicode standard.
+fn condition_holds(s: &str, index: usize, c: char, condition: u8) -> bool {
+    let before = s[..index].chars().rev();
+    let after = s[index + c.len_utf8()..].chars();
+    let holds = match condition & 0x7f {
+        0x00 => true,
+        // Final_Sigma: preceded by a cased letter and not followed by one, ignoring any
+        // case-ignorable characters in between
+        0x01 => next_is_cased(before) && !next_is_cased(after),
+        // After_Soft_Dotted
+        0x02 => find_in_context(before, |c| get_property(c) & CaseProperty::SOFT_DOTTED != 0),
+        // More_Above
+        0x03 => find_in_context(after, |c| get_property(c) & CaseProperty::ABOVE != 0),
+        // Before_Dot
+        0x04 => find_in_context(after, |c| c == '\u{307}'),
+        // After_I
+        0x05 => find_in_context(before, |c| c == 'I'),
+        _ => {
+            panic!("Corrupt casing condition data")
+        }
+    };
+    holds != (condition & 0x80 != 0)
+}
+
+/// Whether the first character which is not case-ignorable is cased.
+fn next_is_cased(mut chars: impl Iterator<Item = char>) -> bool {
+    chars.find(|&c| get_property(c) & CaseProperty::CASE_IGNORABLE == 0)
+        .is_some_and(|c| get_property(c) & CaseProperty::CASED != 0)
+}
+
+/// Look through the characters until the first one with a combining class of 0 or 230 and check
+/// whether any of the characters up to and including it match.
+fn find_in_context(mut chars: impl Iterator<Item = char>, matches: impl Fn(char) -> bool) -> bool {
+    chars.find(|&c| matches(c) || get_property(c) & CaseProperty::CONTEXT_BOUNDARY != 0)
+        .is_some_and(matches)
+}
+
+#[inline]
+fn lookup(table: &'static [(char, char)], c: char) -> Option<char> {
+    table.binary_search_by_key(&c, |&(key, _)| key)
+        .ok()
+        .map(|index| table[index].1)
+}
+
+#[inline]
+fn lookup_full(table: &'static [(char, &'static [char])], c: char) -> Option<CaseMapping> {
+    table.binary_search_by_key(&c, |&(key, _)| key)
+        .ok()
+        .map(|index| CaseMapping::from_slice(table[index].1))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::case::*;
+
+    #[test]
+    fn simple_mappings() {
+        assert_eq!('z'.to_simple_uppercase(), 'Z');
+        assert_eq!('Z'.to_simple_uppercase(), 'Z');
+        assert_eq!('1'.to_simple_lowercase(), '1');
+        assert_eq!('Ω'.to_simple_lowercase(), 'ω');
+        assert_eq!('\u{2126}'.to_simple_lowercase(), 'ω');
+        assert_eq!('Ǆ'.to_simple_titlecase(), 'ǅ');
+        assert_eq!('ǅ'.to_simple_uppercase(), 'Ǆ');
+        assert_eq!('ǅ'.to_simple_lowercase(), 'ǆ');
+        assert_eq!('ა'.to_simple_uppercase(), 'Ა');
+        assert_eq!('ა'.to_simple_titlecase(), 'ა');
+        assert_eq!('ꭰ'.to_simple_case_fold(), 'Ꭰ');
+        assert_eq!('ς'.to_simple_case_fold(), 'σ');
+        assert_eq!('K'.to_simple_case_fold(), 'k');
+    }
+
+    #[test]
+    fn full_mappings() {
+        assert_eq!('ŉ'.to_full_uppercase().collect::<String>(), "ʼN");
+        assert_eq!('ᾳ'.to_full_uppercase().collect::<String>(), "ΑΙ");
+        assert_eq!('ᾳ'.to_full_titlecase().collect::<String>(), "ᾼ");
+        assert_eq!('İ'.to_full_lowercase().collect::<String>(), "i\u{307}");
+        assert_eq!('ﬃ'.to_full_case_fold().collect::<String>(), "ffi");
+        assert_eq!('a'.to_full_uppercase().len(), 1);
+        assert_eq!('ΐ'.to_full_uppercase().rev().collect::<String>(), "\u{301}\u{308}Ι");
+    }
+
+    #[test]
+    fn string_mappings() {
+        assert_eq!(to_uppercase("", Language::Default), "");
+        assert_eq!(to_uppercase("ﬁnl ǆ", Language::Default), "FINL Ǆ");
+        assert_eq!(to_lowercase("ΣΑΣ ΣΑΣ.", Language::Default), "σας σας.");
+        // A sigma on its own or at the start of a word is not final
+        assert_eq!(to_lowercase("Σ ΑΣΑ", Language::Default), "σ ασα");
+        assert_eq!(to_lowercase("ΑΣ'Α", Language::Default), "ασ'α");
+        assert_eq!(to_lowercase("IJ İ", Language::Default), "ij i\u{307}");
+        // Turkish and Azeri
+        assert_eq!(to_lowercase("I İ", Language::Turkish), "ı i");
+        assert_eq!(to_lowercase("I\u{307}", Language::Azeri), "i");
+        assert_eq!(to_uppercase("iı", Language::Azeri), "İI");
+        assert_eq!(case_fold("Iİ", Language::Turkish), "ıi");
+        assert_eq!(case_fold("Iİ", Language::Default), "ii\u{307}");
+        // Lithuanian keeps the dot above i when there is another accent above it
+        assert_eq!(to_lowercase("I\u{300} Ì J", Language::Lithuanian), "i\u{307}\u{300} i\u{307}\u{300} j");
+        assert_eq!(to_uppercase("i\u{307}\u{301} i\u{323}\u{307}", Language::Lithuanian), "I\u{301} I\u{323}");
+        assert_eq!(to_uppercase("i\u{307}", Language::Default), "I\u{307}");
+    }
+
+    #[test]
+    fn conditional_mappings_are_flagged() {
+        for &(c, _, _, _, _, _) in CONDITIONAL_CASE_MAPPINGS.iter() {
+            assert_ne!(get_property(c) & CaseProperty::CONDITIONAL, 0, "{c:?} is not flagged");
+        }
+    }
+
+    #[test]
+    fn titlecase_strings() {
+        assert_eq!(to_titlecase("", Language::Default), "");
+        assert_eq!(to_titlecase("the QUICK brown fox", Language::Default), "The Quick Brown Fox");
+        assert_eq!(to_titlecase("ǄEMAL", Language::Default), "ǅemal");
+        assert_eq!(to_titlecase("ﬂoor-ﬁller", Language::Default), "Floor-Filler");
+        assert_eq!(to_titlecase("can't won't", Language::Default), "Can't Won't");
+        assert_eq!(to_titlecase("ΌΣΟΣ ΟΔΥΣΣΕΥΣ", Language::Default), "Όσος Οδυσσευς");
+        // The first cased letter is titlecased even if the word starts with something else
+        assert_eq!(to_titlecase("3rd 'ᾳ'", Language::Default), "3Rd 'ᾼ'");
+        assert_eq!(to_titlecase("ijssel", Language::Turkish), "İjssel");
+        assert_eq!(to_titlecase("日本 tokyo", Language::Default), "日本 Tokyo");
+        // Cased characters which aren't cased letters still count as the first cased character
+        assert_eq!(to_titlecase("ªBC", Language::Default), "ªbc");
+        assert_eq!(to_titlecase("\u{2b0}ELLO", Language::Default), "\u{2b0}ello");
+        assert_eq!(to_titlecase("ⓐbc", Language::Default), "Ⓐbc");
+        assert_eq!(to_titlecase("ⒶⒷⒸ", Language::Default), "Ⓐⓑⓒ");
+    }
+
+    #[test]
+    fn caseless_matching() {
+        assert!(eq_ignore_case("", ""));
+        assert!(eq_ignore_case("ﬀ", "FF"));
+        assert!(eq_ignore_case("Ꭰꭰ", "ꭰᎠ"));
+        assert!(!eq_ignore_case("ß", "s"));
+        assert!(!eq_ignore_case("İ", "i"));
+    }
+}
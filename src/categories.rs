@@ -1,11 +1,19 @@
 //! The code in this module provides a trait that is implemented against `char` that allows testing
 //! or retrieving the Unicode category for the character as well as two `enum`s for identifying
 //! character classes.
+//!
+//! Text which has come from WTF-8 or unvalidated UTF-16 may contain lone surrogates, which cannot
+//! be represented as a `char`. For these, [`category_of_u32`] and the [`CodePointCategories`] trait
+//! work directly on `u32` code points, reporting surrogates as `Cs` and returning an
+//! [`InvalidCodePoint`] error for values beyond U+10FFFF.
+
+use core::fmt;
+use crate::data::characters::{CAT_PAGES, CAT_TABLE};
 
 /// Trait to provide methods that provide boolean tests on most Unicode character categories.
 ///
 /// There is no `is_surrogate()` method since surrogate character codes are not valid values
-/// for a Rust `char`.
+/// for a Rust `char`. Use [`CodePointCategories`] to examine code points which may be surrogates.
 ///
 /// Importing the trait will provide the methods on the `char` type.
 ///
@@ -13,10 +21,7 @@
 /// determination of character codes. Some special tricks are employed to enable fast determination
 /// of composite classes (L, LC, M, N, P, S, Z, C) without requiring a check for each individual
 /// sub-class.
-///
-
-use crate::data::characters::{CAT_PAGES, CAT_TABLE};
-
+#[allow(clippy::wrong_self_convention)]
 pub trait CharacterCategories {
     /// Determines whether a character is class L, letter (Lu, Ll, Lt, Lm, Lo). This includes all
     /// characters used for word formation, both phonetic and ideograms. It does not include symbols
@@ -146,7 +151,7 @@ pub trait CharacterCategories {
     fn is_separator_paragraph(self) -> bool;
     /// Determines whether a character is an other character (C). These consist of control characters,
     /// format characters, surrogates (no test is provided for these since they will not appear in
-    /// valid UTF-8 text), unassigned characters and private use characters.
+    /// valid UTF-8 text, but see [`CodePointCategories`]), unassigned characters and private use characters.
     fn is_other(self) -> bool;
     /// Determines whether a character is a control character (Cc). These are the 65 characters in the
     /// ranges 0x00–0x1f and 0x7f–0x9f.
@@ -175,7 +180,11 @@ pub trait CharacterCategories {
 }
 
 /// enum for distinguishing Unicode minor categories of characters
+///
+/// The enum is non-exhaustive so that categories can be added in future versions (as `Cs` was in
+/// 2.0.0), so a `match` on it needs a wildcard arm.
 #[derive(PartialEq, Debug)]
+#[non_exhaustive]
 pub enum MinorCategory {
     /// Uppercase letter
     Lu,
@@ -231,6 +240,9 @@ pub enum MinorCategory {
     Cc,
     /// Format character
     Cf,
+    /// Surrogate code point. This is never returned for a `char`, only for a `u32` code point
+    /// examined with [`CodePointCategories`] or [`category_of_u32`].
+    Cs,
     /// Private use character
     Co,
     /// Unassigned character
@@ -296,7 +308,7 @@ impl Cat {
     const Z: u8 = 0x50;
     const Cc: u8 = 0x61;
     const Cf: u8 = 0x62;
-    //    const Cs: u8 = 0x03;
+    const Cs: u8 = 0x63;
     const Co: u8 = 0x64;
     const Cn: u8 = 0x60;
     const C: u8 = 0x60;
@@ -307,61 +319,81 @@ fn get_code(c: char) -> u8 {
     CAT_PAGES[usize::from(CAT_TABLE[(c as usize) >> 8])][(c as usize) & 0xff]
 }
 
+/// Nonspacing and enclosing marks along with control and format characters, which take up no
+/// columns on their own.
+#[cfg(feature = "east_asian_width")]
+#[inline]
+pub(crate) fn is_zero_width_category(c: char) -> bool {
+    matches!(get_code(c), Cat::Mn | Cat::Me | Cat::Cc | Cat::Cf)
+}
+
+#[inline]
+fn major_category(code: u8) -> MajorCategory {
+    match code & 0xf0 {
+        Cat::L => MajorCategory::L,
+        Cat::LC => MajorCategory::L,
+        Cat::M => MajorCategory::M,
+        Cat::N => MajorCategory::N,
+        Cat::P => MajorCategory::P,
+        Cat::S => MajorCategory::S,
+        Cat::Z => MajorCategory::Z,
+        Cat::C => MajorCategory::C,
+        _ => {
+            panic!("Corrupt character data")
+        }
+    }
+}
+
+#[inline]
+fn minor_category(code: u8) -> MinorCategory {
+    match code {
+        Cat::Lu => MinorCategory::Lu,
+        Cat::Ll => MinorCategory::Ll,
+        Cat::Lt => MinorCategory::Lt,
+        Cat::Lm => MinorCategory::Lm,
+        Cat::Lo => MinorCategory::Lo,
+        Cat::Mn => MinorCategory::Mn,
+        Cat::Mc => MinorCategory::Mc,
+        Cat::Me => MinorCategory::Me,
+        Cat::Nd => MinorCategory::Nd,
+        Cat::Nl => MinorCategory::Nl,
+        Cat::No => MinorCategory::No,
+        Cat::Pc => MinorCategory::Pc,
+        Cat::Pd => MinorCategory::Pd,
+        Cat::Ps => MinorCategory::Ps,
+        Cat::Pe => MinorCategory::Pe,
+        Cat::Pi => MinorCategory::Pi,
+        Cat::Pf => MinorCategory::Pf,
+        Cat::Po => MinorCategory::Po,
+        Cat::Sm => MinorCategory::Sm,
+        Cat::Sc => MinorCategory::Sc,
+        Cat::Sk => MinorCategory::Sk,
+        Cat::So => MinorCategory::So,
+        Cat::Zs => MinorCategory::Zs,
+        Cat::Zl => MinorCategory::Zl,
+        Cat::Zp => MinorCategory::Zp,
+        Cat::Cc => MinorCategory::Cc,
+        Cat::Cf => MinorCategory::Cf,
+        Cat::Cs => MinorCategory::Cs,
+        Cat::Co => MinorCategory::Co,
+        Cat::Cn => MinorCategory::Cn,
+        _ => {
+            panic!("Corrupt character data")
+        }
+    }
+}
+
 impl CharacterCategories for char {
     #[inline]
     fn get_major_category(self) -> MajorCategory {
-        match get_code(self) & 0xf0 {
-            Cat::L => MajorCategory::L,
-            Cat::LC => MajorCategory::L,
-            Cat::M => MajorCategory::M,
-            Cat::N => MajorCategory::N,
-            Cat::P => MajorCategory::P,
-            Cat::S => MajorCategory::S,
-            Cat::Z => MajorCategory::Z,
-            Cat::C => MajorCategory::C,
-            _ => {
-                panic!("Corrupt character data")
-            }
-        }
+        major_category(get_code(self))
     }
 
     #[inline]
     fn get_minor_category(self) -> MinorCategory {
-        match get_code(self) {
-            Cat::Lu => MinorCategory::Lu,
-            Cat::Ll => MinorCategory::Ll,
-            Cat::Lt => MinorCategory::Lt,
-            Cat::Lm => MinorCategory::Lm,
-            Cat::Lo => MinorCategory::Lo,
-            Cat::Mn => MinorCategory::Mn,
-            Cat::Mc => MinorCategory::Mc,
-            Cat::Me => MinorCategory::Me,
-            Cat::Nd => MinorCategory::Nd,
-            Cat::Nl => MinorCategory::Nl,
-            Cat::No => MinorCategory::No,
-            Cat::Pc => MinorCategory::Pc,
-            Cat::Pd => MinorCategory::Pd,
-            Cat::Ps => MinorCategory::Ps,
-            Cat::Pe => MinorCategory::Pe,
-            Cat::Pi => MinorCategory::Pi,
-            Cat::Pf => MinorCategory::Pf,
-            Cat::Po => MinorCategory::Po,
-            Cat::Sm => MinorCategory::Sm,
-            Cat::Sc => MinorCategory::Sc,
-            Cat::Sk => MinorCategory::Sk,
-            Cat::So => MinorCategory::So,
-            Cat::Zs => MinorCategory::Zs,
-            Cat::Zl => MinorCategory::Zl,
-            Cat::Zp => MinorCategory::Zp,
-            Cat::Cc => MinorCategory::Cc,
-            Cat::Cf => MinorCategory::Cf,
-            Cat::Co => MinorCategory::Co,
-            Cat::Cn => MinorCategory::Cn,
-            _ => {
-                panic!("Corrupt character data")
-            }
-        }
+        minor_category(get_code(self))
     }
+
     #[inline]
     fn is_letter(self) -> bool {
         get_code(self) & Cat::L == Cat::L
@@ -419,7 +451,7 @@ impl CharacterCategories for char {
 
     #[inline]
     fn is_letter_or_mark(self) -> bool {
-        return get_code(self) & 0x60 == 0x00
+        get_code(self) & 0x60 == 0x00
     }
 
     #[inline]
@@ -553,6 +585,76 @@ impl CharacterCategories for char {
     }
 }
 
+/// Error returned when a `u32` is not a Unicode code point, i.e., it is greater than U+10FFFF.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct InvalidCodePoint(pub u32);
+
+impl fmt::Display for InvalidCodePoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#X} is not a Unicode code point", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidCodePoint {}
+
+#[inline]
+fn get_code_u32(code_point: u32) -> Result<u8, InvalidCodePoint> {
+    if code_point > 0x10FFFF {
+        return Err(InvalidCodePoint(code_point));
+    }
+    let code_point = code_point as usize;
+    Ok(CAT_PAGES[usize::from(CAT_TABLE[code_point >> 8])][code_point & 0xff])
+}
+
+/// Get the minor category of a code point which may not be a valid `char`. Surrogates
+/// (U+D800–U+DFFF) report [`MinorCategory::Cs`] and values beyond U+10FFFF are rejected.
+///
+/// ```
+/// # use finl_unicode::categories::{category_of_u32, InvalidCodePoint, MinorCategory};
+/// assert_eq!(category_of_u32(0x41), Ok(MinorCategory::Lu));
+/// assert_eq!(category_of_u32(0xD800), Ok(MinorCategory::Cs));
+/// assert_eq!(category_of_u32(0x110000), Err(InvalidCodePoint(0x110000)));
+/// ```
+#[inline]
+pub fn category_of_u32(code_point: u32) -> Result<MinorCategory, InvalidCodePoint> {
+    get_code_u32(code_point).map(minor_category)
+}
+
+/// Trait to provide category lookups on `u32` code points, for text such as WTF-8 or unvalidated
+/// UTF-16 which may contain lone surrogates. Each method returns an [`InvalidCodePoint`] error
+/// for values greater than U+10FFFF. Tests on individual categories beyond those provided here can
+/// be made by matching on the result of `get_minor_category()`.
+///
+/// Importing the trait will provide the methods on the `u32` type.
+#[allow(clippy::wrong_self_convention)]
+pub trait CodePointCategories {
+    /// Determines whether a code point is a surrogate (Cs), i.e., in the range U+D800–U+DFFF.
+    fn is_surrogate(self) -> Result<bool, InvalidCodePoint>;
+    /// Get the major category for a code point (L, M, N, P, S, Z or C). Surrogates are in C.
+    fn get_major_category(self) -> Result<MajorCategory, InvalidCodePoint>;
+    /// Get the minor category for a code point. Unlike [`CharacterCategories::get_minor_category`]
+    /// this may return `Cs` for surrogates.
+    fn get_minor_category(self) -> Result<MinorCategory, InvalidCodePoint>;
+}
+
+impl CodePointCategories for u32 {
+    #[inline]
+    fn is_surrogate(self) -> Result<bool, InvalidCodePoint> {
+        get_code_u32(self).map(|code| code == Cat::Cs)
+    }
+
+    #[inline]
+    fn get_major_category(self) -> Result<MajorCategory, InvalidCodePoint> {
+        get_code_u32(self).map(major_category)
+    }
+
+    #[inline]
+    fn get_minor_category(self) -> Result<MinorCategory, InvalidCodePoint> {
+        category_of_u32(self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::categories::*;
@@ -619,4 +721,27 @@ mod tests {
         assert_eq!(']'.get_minor_category(), MinorCategory::Pe);
         assert_eq!(']'.get_major_category(), MajorCategory::P);
     }
+
+    #[test]
+    fn code_point_categories() {
+        assert_eq!(category_of_u32('a' as u32), Ok(MinorCategory::Ll));
+        assert_eq!(0xD800.get_minor_category(), Ok(MinorCategory::Cs));
+        assert_eq!(0xDFFF.get_major_category(), Ok(MajorCategory::C));
+        assert_eq!(0xDBFF.is_surrogate(), Ok(true));
+        assert_eq!(0xE000.is_surrogate(), Ok(false));
+        assert_eq!(0x10FFFF.get_minor_category(), Ok(MinorCategory::Cn));
+        assert_eq!(0x110000.get_minor_category(), Err(InvalidCodePoint(0x110000)));
+        assert_eq!(u32::MAX.is_surrogate(), Err(InvalidCodePoint(u32::MAX)));
+        for c in ['\t', '\u{AD}', ']', 'ᾮ', '\u{100000}', '\u{FFFF}'] {
+            assert_eq!(category_of_u32(c as u32), Ok(c.get_minor_category()));
+        }
+    }
+
+    #[test]
+    fn categories_follow_the_unicode_version() {
+        // MELTING FACE (14.0), SHAKING FACE (15.0) and FACE WITH BAGS UNDER EYES (16.0)
+        assert!('\u{1fae0}'.is_symbol_other());
+        assert_eq!('\u{1fae8}'.is_unassigned(), cfg!(feature = "unicode_14"));
+        assert_eq!('\u{1fae9}'.is_unassigned(), cfg!(any(feature = "unicode_14", feature = "unicode_15")));
+    }
 }
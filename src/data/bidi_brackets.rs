@@ -0,0 +1,561 @@
+// GENERATED CODE DO NOT MANUALLY EDIT
+pub const BIDI_PAIRED_BRACKETS: [(char, char, bool);128] = [
+	('\u{28}', '\u{29}', true),
+	('\u{29}', '\u{28}', false),
+	('\u{5b}', '\u{5d}', true),
+	('\u{5d}', '\u{5b}', false),
+	('\u{7b}', '\u{7d}', true),
+	('\u{7d}', '\u{7b}', false),
+	('\u{f3a}', '\u{f3b}', true),
+	('\u{f3b}', '\u{f3a}', false),
+	('\u{f3c}', '\u{f3d}', true),
+	('\u{f3d}', '\u{f3c}', false),
+	('\u{169b}', '\u{169c}', true),
+	('\u{169c}', '\u{169b}', false),
+	('\u{2045}', '\u{2046}', true),
+	('\u{2046}', '\u{2045}', false),
+	('\u{207d}', '\u{207e}', true),
+	('\u{207e}', '\u{207d}', false),
+	('\u{208d}', '\u{208e}', true),
+	('\u{208e}', '\u{208d}', false),
+	('\u{2308}', '\u{2309}', true),
+	('\u{2309}', '\u{2308}', false),
+	('\u{230a}', '\u{230b}', true),
+	('\u{230b}', '\u{230a}', false),
+	('\u{2329}', '\u{232a}', true),
+	('\u{232a}', '\u{2329}', false),
+	('\u{2768}', '\u{2769}', true),
+	('\u{2769}', '\u{2768}', false),
+	('\u{276a}', '\u{276b}', true),
+	('\u{276b}', '\u{276a}', false),
+	('\u{276c}', '\u{276d}', true),
+	('\u{276d}', '\u{276c}', false),
+	('\u{276e}', '\u{276f}', true),
+	('\u{276f}', '\u{276e}', false),
+	('\u{2770}', '\u{2771}', true),
+	('\u{2771}', '\u{2770}', false),
+	('\u{2772}', '\u{2773}', true),
+	('\u{2773}', '\u{2772}', false),
+	('\u{2774}', '\u{2775}', true),
+	('\u{2775}', '\u{2774}', false),
+	('\u{27c5}', '\u{27c6}', true),
+	('\u{27c6}', '\u{27c5}', false),
+	('\u{27e6}', '\u{27e7}', true),
+	('\u{27e7}', '\u{27e6}', false),
+	('\u{27e8}', '\u{27e9}', true),
+	('\u{27e9}', '\u{27e8}', false),
+	('\u{27ea}', '\u{27eb}', true),
+	('\u{27eb}', '\u{27ea}', false),
+	('\u{27ec}', '\u{27ed}', true),
+	('\u{27ed}', '\u{27ec}', false),
+	('\u{27ee}', '\u{27ef}', true),
+	('\u{27ef}', '\u{27ee}', false),
+	('\u{2983}', '\u{2984}', true),
+	('\u{2984}', '\u{2983}', false),
+	('\u{2985}', '\u{2986}', true),
+	('\u{2986}', '\u{2985}', false),
+	('\u{2987}', '\u{2988}', true),
+	('\u{2988}', '\u{2987}', false),
+	('\u{2989}', '\u{298a}', true),
+	('\u{298a}', '\u{2989}', false),
+	('\u{298b}', '\u{298c}', true),
+	('\u{298c}', '\u{298b}', false),
+	('\u{298d}', '\u{2990}', true),
+	('\u{298e}', '\u{298f}', false),
+	('\u{298f}', '\u{298e}', true),
+	('\u{2990}', '\u{298d}', false),
+	('\u{2991}', '\u{2992}', true),
+	('\u{2992}', '\u{2991}', false),
+	('\u{2993}', '\u{2994}', true),
+	('\u{2994}', '\u{2993}', false),
+	('\u{2995}', '\u{2996}', true),
+	('\u{2996}', '\u{2995}', false),
+	('\u{2997}', '\u{2998}', true),
+	('\u{2998}', '\u{2997}', false),
+	('\u{29d8}', '\u{29d9}', true),
+	('\u{29d9}', '\u{29d8}', false),
+	('\u{29da}', '\u{29db}', true),
+	('\u{29db}', '\u{29da}', false),
+	('\u{29fc}', '\u{29fd}', true),
+	('\u{29fd}', '\u{29fc}', false),
+	('\u{2e22}', '\u{2e23}', true),
+	('\u{2e23}', '\u{2e22}', false),
+	('\u{2e24}', '\u{2e25}', true),
+	('\u{2e25}', '\u{2e24}', false),
+	('\u{2e26}', '\u{2e27}', true),
+	('\u{2e27}', '\u{2e26}', false),
+	('\u{2e28}', '\u{2e29}', true),
+	('\u{2e29}', '\u{2e28}', false),
+	('\u{2e55}', '\u{2e56}', true),
+	('\u{2e56}', '\u{2e55}', false),
+	('\u{2e57}', '\u{2e58}', true),
+	('\u{2e58}', '\u{2e57}', false),
+	('\u{2e59}', '\u{2e5a}', true),
+	('\u{2e5a}', '\u{2e59}', false),
+	('\u{2e5b}', '\u{2e5c}', true),
+	('\u{2e5c}', '\u{2e5b}', false),
+	('\u{3008}', '\u{3009}', true),
+	('\u{3009}', '\u{3008}', false),
+	('\u{300a}', '\u{300b}', true),
+	('\u{300b}', '\u{300a}', false),
+	('\u{300c}', '\u{300d}', true),
+	('\u{300d}', '\u{300c}', false),
+	('\u{300e}', '\u{300f}', true),
+	('\u{300f}', '\u{300e}', false),
+	('\u{3010}', '\u{3011}', true),
+	('\u{3011}', '\u{3010}', false),
+	('\u{3014}', '\u{3015}', true),
+	('\u{3015}', '\u{3014}', false),
+	('\u{3016}', '\u{3017}', true),
+	('\u{3017}', '\u{3016}', false),
+	('\u{3018}', '\u{3019}', true),
+	('\u{3019}', '\u{3018}', false),
+	('\u{301a}', '\u{301b}', true),
+	('\u{301b}', '\u{301a}', false),
+	('\u{fe59}', '\u{fe5a}', true),
+	('\u{fe5a}', '\u{fe59}', false),
+	('\u{fe5b}', '\u{fe5c}', true),
+	('\u{fe5c}', '\u{fe5b}', false),
+	('\u{fe5d}', '\u{fe5e}', true),
+	('\u{fe5e}', '\u{fe5d}', false),
+	('\u{ff08}', '\u{ff09}', true),
+	('\u{ff09}', '\u{ff08}', false),
+	('\u{ff3b}', '\u{ff3d}', true),
+	('\u{ff3d}', '\u{ff3b}', false),
+	('\u{ff5b}', '\u{ff5d}', true),
+	('\u{ff5d}', '\u{ff5b}', false),
+	('\u{ff5f}', '\u{ff60}', true),
+	('\u{ff60}', '\u{ff5f}', false),
+	('\u{ff62}', '\u{ff63}', true),
+	('\u{ff63}', '\u{ff62}', false),
+];
+pub const BIDI_MIRRORING_GLYPHS: [(char, char);428] = [
+	('\u{28}', '\u{29}'),
+	('\u{29}', '\u{28}'),
+	('\u{3c}', '\u{3e}'),
+	('\u{3e}', '\u{3c}'),
+	('\u{5b}', '\u{5d}'),
+	('\u{5d}', '\u{5b}'),
+	('\u{7b}', '\u{7d}'),
+	('\u{7d}', '\u{7b}'),
+	('\u{ab}', '\u{bb}'),
+	('\u{bb}', '\u{ab}'),
+	('\u{f3a}', '\u{f3b}'),
+	('\u{f3b}', '\u{f3a}'),
+	('\u{f3c}', '\u{f3d}'),
+	('\u{f3d}', '\u{f3c}'),
+	('\u{169b}', '\u{169c}'),
+	('\u{169c}', '\u{169b}'),
+	('\u{2039}', '\u{203a}'),
+	('\u{203a}', '\u{2039}'),
+	('\u{2045}', '\u{2046}'),
+	('\u{2046}', '\u{2045}'),
+	('\u{207d}', '\u{207e}'),
+	('\u{207e}', '\u{207d}'),
+	('\u{208d}', '\u{208e}'),
+	('\u{208e}', '\u{208d}'),
+	('\u{2208}', '\u{220b}'),
+	('\u{2209}', '\u{220c}'),
+	('\u{220a}', '\u{220d}'),
+	('\u{220b}', '\u{2208}'),
+	('\u{220c}', '\u{2209}'),
+	('\u{220d}', '\u{220a}'),
+	('\u{2215}', '\u{29f5}'),
+	('\u{221f}', '\u{2bfe}'),
+	('\u{2220}', '\u{29a3}'),
+	('\u{2221}', '\u{299b}'),
+	('\u{2222}', '\u{29a0}'),
+	('\u{2224}', '\u{2aee}'),
+	('\u{223c}', '\u{223d}'),
+	('\u{223d}', '\u{223c}'),
+	('\u{2243}', '\u{22cd}'),
+	('\u{2245}', '\u{224c}'),
+	('\u{224c}', '\u{2245}'),
+	('\u{2252}', '\u{2253}'),
+	('\u{2253}', '\u{2252}'),
+	('\u{2254}', '\u{2255}'),
+	('\u{2255}', '\u{2254}'),
+	('\u{2264}', '\u{2265}'),
+	('\u{2265}', '\u{2264}'),
+	('\u{2266}', '\u{2267}'),
+	('\u{2267}', '\u{2266}'),
+	('\u{2268}', '\u{2269}'),
+	('\u{2269}', '\u{2268}'),
+	('\u{226a}', '\u{226b}'),
+	('\u{226b}', '\u{226a}'),
+	('\u{226e}', '\u{226f}'),
+	('\u{226f}', '\u{226e}'),
+	('\u{2270}', '\u{2271}'),
+	('\u{2271}', '\u{2270}'),
+	('\u{2272}', '\u{2273}'),
+	('\u{2273}', '\u{2272}'),
+	('\u{2274}', '\u{2275}'),
+	('\u{2275}', '\u{2274}'),
+	('\u{2276}', '\u{2277}'),
+	('\u{2277}', '\u{2276}'),
+	('\u{2278}', '\u{2279}'),
+	('\u{2279}', '\u{2278}'),
+	('\u{227a}', '\u{227b}'),
+	('\u{227b}', '\u{227a}'),
+	('\u{227c}', '\u{227d}'),
+	('\u{227d}', '\u{227c}'),
+	('\u{227e}', '\u{227f}'),
+	('\u{227f}', '\u{227e}'),
+	('\u{2280}', '\u{2281}'),
+	('\u{2281}', '\u{2280}'),
+	('\u{2282}', '\u{2283}'),
+	('\u{2283}', '\u{2282}'),
+	('\u{2284}', '\u{2285}'),
+	('\u{2285}', '\u{2284}'),
+	('\u{2286}', '\u{2287}'),
+	('\u{2287}', '\u{2286}'),
+	('\u{2288}', '\u{2289}'),
+	('\u{2289}', '\u{2288}'),
+	('\u{228a}', '\u{228b}'),
+	('\u{228b}', '\u{228a}'),
+	('\u{228f}', '\u{2290}'),
+	('\u{2290}', '\u{228f}'),
+	('\u{2291}', '\u{2292}'),
+	('\u{2292}', '\u{2291}'),
+	('\u{2298}', '\u{29b8}'),
+	('\u{22a2}', '\u{22a3}'),
+	('\u{22a3}', '\u{22a2}'),
+	('\u{22a6}', '\u{2ade}'),
+	('\u{22a8}', '\u{2ae4}'),
+	('\u{22a9}', '\u{2ae3}'),
+	('\u{22ab}', '\u{2ae5}'),
+	('\u{22b0}', '\u{22b1}'),
+	('\u{22b1}', '\u{22b0}'),
+	('\u{22b2}', '\u{22b3}'),
+	('\u{22b3}', '\u{22b2}'),
+	('\u{22b4}', '\u{22b5}'),
+	('\u{22b5}', '\u{22b4}'),
+	('\u{22b6}', '\u{22b7}'),
+	('\u{22b7}', '\u{22b6}'),
+	('\u{22b8}', '\u{27dc}'),
+	('\u{22c9}', '\u{22ca}'),
+	('\u{22ca}', '\u{22c9}'),
+	('\u{22cb}', '\u{22cc}'),
+	('\u{22cc}', '\u{22cb}'),
+	('\u{22cd}', '\u{2243}'),
+	('\u{22d0}', '\u{22d1}'),
+	('\u{22d1}', '\u{22d0}'),
+	('\u{22d6}', '\u{22d7}'),
+	('\u{22d7}', '\u{22d6}'),
+	('\u{22d8}', '\u{22d9}'),
+	('\u{22d9}', '\u{22d8}'),
+	('\u{22da}', '\u{22db}'),
+	('\u{22db}', '\u{22da}'),
+	('\u{22dc}', '\u{22dd}'),
+	('\u{22dd}', '\u{22dc}'),
+	('\u{22de}', '\u{22df}'),
+	('\u{22df}', '\u{22de}'),
+	('\u{22e0}', '\u{22e1}'),
+	('\u{22e1}', '\u{22e0}'),
+	('\u{22e2}', '\u{22e3}'),
+	('\u{22e3}', '\u{22e2}'),
+	('\u{22e4}', '\u{22e5}'),
+	('\u{22e5}', '\u{22e4}'),
+	('\u{22e6}', '\u{22e7}'),
+	('\u{22e7}', '\u{22e6}'),
+	('\u{22e8}', '\u{22e9}'),
+	('\u{22e9}', '\u{22e8}'),
+	('\u{22ea}', '\u{22eb}'),
+	('\u{22eb}', '\u{22ea}'),
+	('\u{22ec}', '\u{22ed}'),
+	('\u{22ed}', '\u{22ec}'),
+	('\u{22f0}', '\u{22f1}'),
+	('\u{22f1}', '\u{22f0}'),
+	('\u{22f2}', '\u{22fa}'),
+	('\u{22f3}', '\u{22fb}'),
+	('\u{22f4}', '\u{22fc}'),
+	('\u{22f6}', '\u{22fd}'),
+	('\u{22f7}', '\u{22fe}'),
+	('\u{22fa}', '\u{22f2}'),
+	('\u{22fb}', '\u{22f3}'),
+	('\u{22fc}', '\u{22f4}'),
+	('\u{22fd}', '\u{22f6}'),
+	('\u{22fe}', '\u{22f7}'),
+	('\u{2308}', '\u{2309}'),
+	('\u{2309}', '\u{2308}'),
+	('\u{230a}', '\u{230b}'),
+	('\u{230b}', '\u{230a}'),
+	('\u{2329}', '\u{232a}'),
+	('\u{232a}', '\u{2329}'),
+	('\u{2768}', '\u{2769}'),
+	('\u{2769}', '\u{2768}'),
+	('\u{276a}', '\u{276b}'),
+	('\u{276b}', '\u{276a}'),
+	('\u{276c}', '\u{276d}'),
+	('\u{276d}', '\u{276c}'),
+	('\u{276e}', '\u{276f}'),
+	('\u{276f}', '\u{276e}'),
+	('\u{2770}', '\u{2771}'),
+	('\u{2771}', '\u{2770}'),
+	('\u{2772}', '\u{2773}'),
+	('\u{2773}', '\u{2772}'),
+	('\u{2774}', '\u{2775}'),
+	('\u{2775}', '\u{2774}'),
+	('\u{27c3}', '\u{27c4}'),
+	('\u{27c4}', '\u{27c3}'),
+	('\u{27c5}', '\u{27c6}'),
+	('\u{27c6}', '\u{27c5}'),
+	('\u{27c8}', '\u{27c9}'),
+	('\u{27c9}', '\u{27c8}'),
+	('\u{27cb}', '\u{27cd}'),
+	('\u{27cd}', '\u{27cb}'),
+	('\u{27d5}', '\u{27d6}'),
+	('\u{27d6}', '\u{27d5}'),
+	('\u{27dc}', '\u{22b8}'),
+	('\u{27dd}', '\u{27de}'),
+	('\u{27de}', '\u{27dd}'),
+	('\u{27e2}', '\u{27e3}'),
+	('\u{27e3}', '\u{27e2}'),
+	('\u{27e4}', '\u{27e5}'),
+	('\u{27e5}', '\u{27e4}'),
+	('\u{27e6}', '\u{27e7}'),
+	('\u{27e7}', '\u{27e6}'),
+	('\u{27e8}', '\u{27e9}'),
+	('\u{27e9}', '\u{27e8}'),
+	('\u{27ea}', '\u{27eb}'),
+	('\u{27eb}', '\u{27ea}'),
+	('\u{27ec}', '\u{27ed}'),
+	('\u{27ed}', '\u{27ec}'),
+	('\u{27ee}', '\u{27ef}'),
+	('\u{27ef}', '\u{27ee}'),
+	('\u{2983}', '\u{2984}'),
+	('\u{2984}', '\u{2983}'),
+	('\u{2985}', '\u{2986}'),
+	('\u{2986}', '\u{2985}'),
+	('\u{2987}', '\u{2988}'),
+	('\u{2988}', '\u{2987}'),
+	('\u{2989}', '\u{298a}'),
+	('\u{298a}', '\u{2989}'),
+	('\u{298b}', '\u{298c}'),
+	('\u{298c}', '\u{298b}'),
+	('\u{298d}', '\u{2990}'),
+	('\u{298e}', '\u{298f}'),
+	('\u{298f}', '\u{298e}'),
+	('\u{2990}', '\u{298d}'),
+	('\u{2991}', '\u{2992}'),
+	('\u{2992}', '\u{2991}'),
+	('\u{2993}', '\u{2994}'),
+	('\u{2994}', '\u{2993}'),
+	('\u{2995}', '\u{2996}'),
+	('\u{2996}', '\u{2995}'),
+	('\u{2997}', '\u{2998}'),
+	('\u{2998}', '\u{2997}'),
+	('\u{299b}', '\u{2221}'),
+	('\u{29a0}', '\u{2222}'),
+	('\u{29a3}', '\u{2220}'),
+	('\u{29a4}', '\u{29a5}'),
+	('\u{29a5}', '\u{29a4}'),
+	('\u{29a8}', '\u{29a9}'),
+	('\u{29a9}', '\u{29a8}'),
+	('\u{29aa}', '\u{29ab}'),
+	('\u{29ab}', '\u{29aa}'),
+	('\u{29ac}', '\u{29ad}'),
+	('\u{29ad}', '\u{29ac}'),
+	('\u{29ae}', '\u{29af}'),
+	('\u{29af}', '\u{29ae}'),
+	('\u{29b8}', '\u{2298}'),
+	('\u{29c0}', '\u{29c1}'),
+	('\u{29c1}', '\u{29c0}'),
+	('\u{29c4}', '\u{29c5}'),
+	('\u{29c5}', '\u{29c4}'),
+	('\u{29cf}', '\u{29d0}'),
+	('\u{29d0}', '\u{29cf}'),
+	('\u{29d1}', '\u{29d2}'),
+	('\u{29d2}', '\u{29d1}'),
+	('\u{29d4}', '\u{29d5}'),
+	('\u{29d5}', '\u{29d4}'),
+	('\u{29d8}', '\u{29d9}'),
+	('\u{29d9}', '\u{29d8}'),
+	('\u{29da}', '\u{29db}'),
+	('\u{29db}', '\u{29da}'),
+	('\u{29e8}', '\u{29e9}'),
+	('\u{29e9}', '\u{29e8}'),
+	('\u{29f5}', '\u{2215}'),
+	('\u{29f8}', '\u{29f9}'),
+	('\u{29f9}', '\u{29f8}'),
+	('\u{29fc}', '\u{29fd}'),
+	('\u{29fd}', '\u{29fc}'),
+	('\u{2a2b}', '\u{2a2c}'),
+	('\u{2a2c}', '\u{2a2b}'),
+	('\u{2a2d}', '\u{2a2e}'),
+	('\u{2a2e}', '\u{2a2d}'),
+	('\u{2a34}', '\u{2a35}'),
+	('\u{2a35}', '\u{2a34}'),
+	('\u{2a3c}', '\u{2a3d}'),
+	('\u{2a3d}', '\u{2a3c}'),
+	('\u{2a64}', '\u{2a65}'),
+	('\u{2a65}', '\u{2a64}'),
+	('\u{2a79}', '\u{2a7a}'),
+	('\u{2a7a}', '\u{2a79}'),
+	('\u{2a7b}', '\u{2a7c}'),
+	('\u{2a7c}', '\u{2a7b}'),
+	('\u{2a7d}', '\u{2a7e}'),
+	('\u{2a7e}', '\u{2a7d}'),
+	('\u{2a7f}', '\u{2a80}'),
+	('\u{2a80}', '\u{2a7f}'),
+	('\u{2a81}', '\u{2a82}'),
+	('\u{2a82}', '\u{2a81}'),
+	('\u{2a83}', '\u{2a84}'),
+	('\u{2a84}', '\u{2a83}'),
+	('\u{2a85}', '\u{2a86}'),
+	('\u{2a86}', '\u{2a85}'),
+	('\u{2a87}', '\u{2a88}'),
+	('\u{2a88}', '\u{2a87}'),
+	('\u{2a89}', '\u{2a8a}'),
+	('\u{2a8a}', '\u{2a89}'),
+	('\u{2a8b}', '\u{2a8c}'),
+	('\u{2a8c}', '\u{2a8b}'),
+	('\u{2a8d}', '\u{2a8e}'),
+	('\u{2a8e}', '\u{2a8d}'),
+	('\u{2a8f}', '\u{2a90}'),
+	('\u{2a90}', '\u{2a8f}'),
+	('\u{2a91}', '\u{2a92}'),
+	('\u{2a92}', '\u{2a91}'),
+	('\u{2a93}', '\u{2a94}'),
+	('\u{2a94}', '\u{2a93}'),
+	('\u{2a95}', '\u{2a96}'),
+	('\u{2a96}', '\u{2a95}'),
+	('\u{2a97}', '\u{2a98}'),
+	('\u{2a98}', '\u{2a97}'),
+	('\u{2a99}', '\u{2a9a}'),
+	('\u{2a9a}', '\u{2a99}'),
+	('\u{2a9b}', '\u{2a9c}'),
+	('\u{2a9c}', '\u{2a9b}'),
+	('\u{2a9d}', '\u{2a9e}'),
+	('\u{2a9e}', '\u{2a9d}'),
+	('\u{2a9f}', '\u{2aa0}'),
+	('\u{2aa0}', '\u{2a9f}'),
+	('\u{2aa1}', '\u{2aa2}'),
+	('\u{2aa2}', '\u{2aa1}'),
+	('\u{2aa6}', '\u{2aa7}'),
+	('\u{2aa7}', '\u{2aa6}'),
+	('\u{2aa8}', '\u{2aa9}'),
+	('\u{2aa9}', '\u{2aa8}'),
+	('\u{2aaa}', '\u{2aab}'),
+	('\u{2aab}', '\u{2aaa}'),
+	('\u{2aac}', '\u{2aad}'),
+	('\u{2aad}', '\u{2aac}'),
+	('\u{2aaf}', '\u{2ab0}'),
+	('\u{2ab0}', '\u{2aaf}'),
+	('\u{2ab1}', '\u{2ab2}'),
+	('\u{2ab2}', '\u{2ab1}'),
+	('\u{2ab3}', '\u{2ab4}'),
+	('\u{2ab4}', '\u{2ab3}'),
+	('\u{2ab5}', '\u{2ab6}'),
+	('\u{2ab6}', '\u{2ab5}'),
+	('\u{2ab7}', '\u{2ab8}'),
+	('\u{2ab8}', '\u{2ab7}'),
+	('\u{2ab9}', '\u{2aba}'),
+	('\u{2aba}', '\u{2ab9}'),
+	('\u{2abb}', '\u{2abc}'),
+	('\u{2abc}', '\u{2abb}'),
+	('\u{2abd}', '\u{2abe}'),
+	('\u{2abe}', '\u{2abd}'),
+	('\u{2abf}', '\u{2ac0}'),
+	('\u{2ac0}', '\u{2abf}'),
+	('\u{2ac1}', '\u{2ac2}'),
+	('\u{2ac2}', '\u{2ac1}'),
+	('\u{2ac3}', '\u{2ac4}'),
+	('\u{2ac4}', '\u{2ac3}'),
+	('\u{2ac5}', '\u{2ac6}'),
+	('\u{2ac6}', '\u{2ac5}'),
+	('\u{2ac7}', '\u{2ac8}'),
+	('\u{2ac8}', '\u{2ac7}'),
+	('\u{2ac9}', '\u{2aca}'),
+	('\u{2aca}', '\u{2ac9}'),
+	('\u{2acb}', '\u{2acc}'),
+	('\u{2acc}', '\u{2acb}'),
+	('\u{2acd}', '\u{2ace}'),
+	('\u{2ace}', '\u{2acd}'),
+	('\u{2acf}', '\u{2ad0}'),
+	('\u{2ad0}', '\u{2acf}'),
+	('\u{2ad1}', '\u{2ad2}'),
+	('\u{2ad2}', '\u{2ad1}'),
+	('\u{2ad3}', '\u{2ad4}'),
+	('\u{2ad4}', '\u{2ad3}'),
+	('\u{2ad5}', '\u{2ad6}'),
+	('\u{2ad6}', '\u{2ad5}'),
+	('\u{2ade}', '\u{22a6}'),
+	('\u{2ae3}', '\u{22a9}'),
+	('\u{2ae4}', '\u{22a8}'),
+	('\u{2ae5}', '\u{22ab}'),
+	('\u{2aec}', '\u{2aed}'),
+	('\u{2aed}', '\u{2aec}'),
+	('\u{2aee}', '\u{2224}'),
+	('\u{2af7}', '\u{2af8}'),
+	('\u{2af8}', '\u{2af7}'),
+	('\u{2af9}', '\u{2afa}'),
+	('\u{2afa}', '\u{2af9}'),
+	('\u{2bfe}', '\u{221f}'),
+	('\u{2e02}', '\u{2e03}'),
+	('\u{2e03}', '\u{2e02}'),
+	('\u{2e04}', '\u{2e05}'),
+	('\u{2e05}', '\u{2e04}'),
+	('\u{2e09}', '\u{2e0a}'),
+	('\u{2e0a}', '\u{2e09}'),
+	('\u{2e0c}', '\u{2e0d}'),
+	('\u{2e0d}', '\u{2e0c}'),
+	('\u{2e1c}', '\u{2e1d}'),
+	('\u{2e1d}', '\u{2e1c}'),
+	('\u{2e20}', '\u{2e21}'),
+	('\u{2e21}', '\u{2e20}'),
+	('\u{2e22}', '\u{2e23}'),
+	('\u{2e23}', '\u{2e22}'),
+	('\u{2e24}', '\u{2e25}'),
+	('\u{2e25}', '\u{2e24}'),
+	('\u{2e26}', '\u{2e27}'),
+	('\u{2e27}', '\u{2e26}'),
+	('\u{2e28}', '\u{2e29}'),
+	('\u{2e29}', '\u{2e28}'),
+	('\u{2e55}', '\u{2e56}'),
+	('\u{2e56}', '\u{2e55}'),
+	('\u{2e57}', '\u{2e58}'),
+	('\u{2e58}', '\u{2e57}'),
+	('\u{2e59}', '\u{2e5a}'),
+	('\u{2e5a}', '\u{2e59}'),
+	('\u{2e5b}', '\u{2e5c}'),
+	('\u{2e5c}', '\u{2e5b}'),
+	('\u{3008}', '\u{3009}'),
+	('\u{3009}', '\u{3008}'),
+	('\u{300a}', '\u{300b}'),
+	('\u{300b}', '\u{300a}'),
+	('\u{300c}', '\u{300d}'),
+	('\u{300d}', '\u{300c}'),
+	('\u{300e}', '\u{300f}'),
+	('\u{300f}', '\u{300e}'),
+	('\u{3010}', '\u{3011}'),
+	('\u{3011}', '\u{3010}'),
+	('\u{3014}', '\u{3015}'),
+	('\u{3015}', '\u{3014}'),
+	('\u{3016}', '\u{3017}'),
+	('\u{3017}', '\u{3016}'),
+	('\u{3018}', '\u{3019}'),
+	('\u{3019}', '\u{3018}'),
+	('\u{301a}', '\u{301b}'),
+	('\u{301b}', '\u{301a}'),
+	('\u{fe59}', '\u{fe5a}'),
+	('\u{fe5a}', '\u{fe59}'),
+	('\u{fe5b}', '\u{fe5c}'),
+	('\u{fe5c}', '\u{fe5b}'),
+	('\u{fe5d}', '\u{fe5e}'),
+	('\u{fe5e}', '\u{fe5d}'),
+	('\u{fe64}', '\u{fe65}'),
+	('\u{fe65}', '\u{fe64}'),
+	('\u{ff08}', '\u{ff09}'),
+	('\u{ff09}', '\u{ff08}'),
+	('\u{ff1c}', '\u{ff1e}'),
+	('\u{ff1e}', '\u{ff1c}'),
+	('\u{ff3b}', '\u{ff3d}'),
+	('\u{ff3d}', '\u{ff3b}'),
+	('\u{ff5b}', '\u{ff5d}'),
+	('\u{ff5d}', '\u{ff5b}'),
+	('\u{ff5f}', '\u{ff60}'),
+	('\u{ff60}', '\u{ff5f}'),
+	('\u{ff62}', '\u{ff63}'),
+	('\u{ff63}', '\u{ff62}'),
+];
@@ -0,0 +1,351 @@
+// GENERATED CODE DO NOT MANUALLY EDIT
+/// enum for the binary properties in `PropList.txt` and `DerivedCoreProperties.txt`. The
+/// variants are the names of the properties in camel case and are documented with the name.
+/// Properties are added to the UCD from time to time, so a `match` on a `BinaryProperty` needs a
+/// wildcard arm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum BinaryProperty {
+	/// ASCII_Hex_Digit
+	ASCIIHexDigit,
+	/// Alphabetic
+	Alphabetic,
+	/// Bidi_Control
+	BidiControl,
+	/// Case_Ignorable
+	CaseIgnorable,
+	/// Cased
+	Cased,
+	/// Changes_When_Casefolded
+	ChangesWhenCasefolded,
+	/// Changes_When_Casemapped
+	ChangesWhenCasemapped,
+	/// Changes_When_Lowercased
+	ChangesWhenLowercased,
+	/// Changes_When_Titlecased
+	ChangesWhenTitlecased,
+	/// Changes_When_Uppercased
+	ChangesWhenUppercased,
+	/// Dash
+	Dash,
+	/// Default_Ignorable_Code_Point
+	DefaultIgnorableCodePoint,
+	/// Deprecated
+	Deprecated,
+	/// Diacritic
+	Diacritic,
+	/// Extender
+	Extender,
+	/// Grapheme_Base
+	GraphemeBase,
+	/// Grapheme_Extend
+	GraphemeExtend,
+	/// Hex_Digit
+	HexDigit,
+	/// Hyphen
+	Hyphen,
+	/// IDS_Binary_Operator
+	IDSBinaryOperator,
+	/// IDS_Trinary_Operator
+	IDSTrinaryOperator,
+	/// IDS_Unary_Operator
+	IDSUnaryOperator,
+	/// ID_Compat_Math_Continue
+	IDCompatMathContinue,
+	/// ID_Compat_Math_Start
+	IDCompatMathStart,
+	/// ID_Continue
+	IDContinue,
+	/// ID_Start
+	IDStart,
+	/// Ideographic
+	Ideographic,
+	/// Join_Control
+	JoinControl,
+	/// Logical_Order_Exception
+	LogicalOrderException,
+	/// Lowercase
+	Lowercase,
+	/// Math
+	Math,
+	/// Modifier_Combining_Mark
+	ModifierCombiningMark,
+	/// Noncharacter_Code_Point
+	NoncharacterCodePoint,
+	/// Pattern_Syntax
+	PatternSyntax,
+	/// Pattern_White_Space
+	PatternWhiteSpace,
+	/// Prepended_Concatenation_Mark
+	PrependedConcatenationMark,
+	/// Quotation_Mark
+	QuotationMark,
+	/// Radical
+	Radical,
+	/// Regional_Indicator
+	RegionalIndicator,
+	/// Sentence_Terminal
+	SentenceTerminal,
+	/// Soft_Dotted
+	SoftDotted,
+	/// Terminal_Punctuation
+	TerminalPunctuation,
+	/// Unified_Ideograph
+	UnifiedIdeograph,
+	/// Uppercase
+	Uppercase,
+	/// Variation_Selector
+	VariationSelector,
+	/// White_Space
+	WhiteSpace,
+	/// XID_Continue
+	XIDContinue,
+	/// XID_Start
+	XIDStart,
+}
+pub const BINARY_PROPERTIES: [BinaryProperty;48] = [
+	BinaryProperty::ASCIIHexDigit,
+	BinaryProperty::Alphabetic,
+	BinaryProperty::BidiControl,
+	BinaryProperty::CaseIgnorable,
+	BinaryProperty::Cased,
+	BinaryProperty::ChangesWhenCasefolded,
+	BinaryProperty::ChangesWhenCasemapped,
+	BinaryProperty::ChangesWhenLowercased,
+	BinaryProperty::ChangesWhenTitlecased,
+	BinaryProperty::ChangesWhenUppercased,
+	BinaryProperty::Dash,
+	BinaryProperty::DefaultIgnorableCodePoint,
+	BinaryProperty::Deprecated,
+	BinaryProperty::Diacritic,
+	BinaryProperty::Extender,
+	BinaryProperty::GraphemeBase,
+	BinaryProperty::GraphemeExtend,
+	BinaryProperty::HexDigit,
+	BinaryProperty::Hyphen,
+	BinaryProperty::IDSBinaryOperator,
+	BinaryProperty::IDSTrinaryOperator,
+	BinaryProperty::IDSUnaryOperator,
+	BinaryProperty::IDCompatMathContinue,
+	BinaryProperty::IDCompatMathStart,
+	BinaryProperty::IDContinue,
+	BinaryProperty::IDStart,
+	BinaryProperty::Ideographic,
+	BinaryProperty::JoinControl,
+	BinaryProperty::LogicalOrderException,
+	BinaryProperty::Lowercase,
+	BinaryProperty::Math,
+	BinaryProperty::ModifierCombiningMark,
+	BinaryProperty::NoncharacterCodePoint,
+	BinaryProperty::PatternSyntax,
+	BinaryProperty::PatternWhiteSpace,
+	BinaryProperty::PrependedConcatenationMark,
+	BinaryProperty::QuotationMark,
+	BinaryProperty::Radical,
+	BinaryProperty::RegionalIndicator,
+	BinaryProperty::SentenceTerminal,
+	BinaryProperty::SoftDotted,
+	BinaryProperty::TerminalPunctuation,
+	BinaryProperty::UnifiedIdeograph,
+	BinaryProperty::Uppercase,
+	BinaryProperty::VariationSelector,
+	BinaryProperty::WhiteSpace,
+	BinaryProperty::XIDContinue,
+	BinaryProperty::XIDStart,
+];
+pub const BINARY_PROPERTY_NAMES: [&str;48] = [
+	"ASCII_Hex_Digit",
+	"Alphabetic",
+	"Bidi_Control",
+	"Case_Ignorable",
+	"Cased",
+	"Changes_When_Casefolded",
+	"Changes_When_Casemapped",
+	"Changes_When_Lowercased",
+	"Changes_When_Titlecased",
+	"Changes_When_Uppercased",
+	"Dash",
+	"Default_Ignorable_Code_Point",
+	"Deprecated",
+	"Diacritic",
+	"Extender",
+	"Grapheme_Base",
+	"Grapheme_Extend",
+	"Hex_Digit",
+	"Hyphen",
+	"IDS_Binary_Operator",
+	"IDS_Trinary_Operator",
+	"IDS_Unary_Operator",
+	"ID_Compat_Math_Continue",
+	"ID_Compat_Math_Start",
+	"ID_Continue",
+	"ID_Start",
+	"Ideographic",
+	"Join_Control",
+	"Logical_Order_Exception",
+	"Lowercase",
+	"Math",
+	"Modifier_Combining_Mark",
+	"Noncharacter_Code_Point",
+	"Pattern_Syntax",
+	"Pattern_White_Space",
+	"Prepended_Concatenation_Mark",
+	"Quotation_Mark",
+	"Radical",
+	"Regional_Indicator",
+	"Sentence_Terminal",
+	"Soft_Dotted",
+	"Terminal_Punctuation",
+	"Unified_Ideograph",
+	"Uppercase",
+	"Variation_Selector",
+	"White_Space",
+	"XID_Continue",
+	"XID_Start",
+];
+pub const BINARY_PROPERTY_SETS: [u64;144] = [
+	0x0000000000000000,
+	0x0000200400000000,
+	0x0000200400008000,
+	0x0000028200008000,
+	0x0000001200008000,
+	0x0000000200008000,
+	0x0000001200008008,
+	0x0000000240008000,
+	0x0000020200008000,
+	0x0000000200048400,
+	0x0000028200008008,
+	0x0000400001028001,
+	0x0000020200008008,
+	0x0000c800030280f3,
+	0x0000c800030080f2,
+	0x000000024000a008,
+	0x0000400001008000,
+	0x000000020000a008,
+	0x0000c00023028353,
+	0x0000c00023008352,
+	0x0000c10023008352,
+	0x0000200000008000,
+	0x000000000000a008,
+	0x0000c00023008012,
+	0x0000000000040808,
+	0x0000000000408000,
+	0x0000c00023008372,
+	0x000040000100e008,
+	0x0000000000008000,
+	0x0000c00023009372,
+	0x0000c00003008002,
+	0x0000c800030081f2,
+	0x0000c000030082f2,
+	0x0000c0002300a01a,
+	0x0000c1002300a01a,
+	0x0000c0000300a00a,
+	0x0000c0000300e00a,
+	0x0000400001012008,
+	0x000040002101237a,
+	0x0000400001010808,
+	0x0000400001010008,
+	0x000040000101000a,
+	0x000000002300a01a,
+	0x0000020000008000,
+	0x0000420001008008,
+	0x0000c00063008372,
+	0x0000c80043008012,
+	0x0000c80003008012,
+	0x0000c800430080f2,
+	0x0000000040008000,
+	0x0000000000010008,
+	0x0000000000008008,
+	0x0000028000008000,
+	0x0000000000048400,
+	0x000040000101200a,
+	0x0000000000008400,
+	0x0000000800000008,
+	0x000000000000080c,
+	0x0000c0000300c00a,
+	0x000040008101000a,
+	0x0000400081012008,
+	0x0000c00003009002,
+	0x0000c0000300800a,
+	0x0000400081010008,
+	0x000040008101200a,
+	0x0000400001008002,
+	0x0000400001010002,
+	0x000040000101400a,
+	0x0000400001016008,
+	0x0000400003008002,
+	0x0000c00013008002,
+	0x000040000100a000,
+	0x000040000101100a,
+	0x000040000100a002,
+	0x0000c00023008252,
+	0x0000c0002300801a,
+	0x0000c00003008802,
+	0x0000c800030080d2,
+	0x0000400001012000,
+	0x000000000000c000,
+	0x0000500001010808,
+	0x0000000000000808,
+	0x0000c0000301000a,
+	0x0000000000012008,
+	0x000040000101600a,
+	0x000000000000a000,
+	0x0000c10023008012,
+	0x0000400009010808,
+	0x0000400009000808,
+	0x000000040000080c,
+	0x0000000200008400,
+	0x0000000200008008,
+	0x0000400041008000,
+	0x0000000040000808,
+	0x0000000000000800,
+	0x0000000000001808,
+	0x0000c1002300801a,
+	0x0000000040408000,
+	0x0000000040408400,
+	0x0000400041010008,
+	0x0000c00063008012,
+	0x0000c00043008000,
+	0x0000c00003008000,
+	0x0000c00043008002,
+	0x0000c10063008012,
+	0x0000000240c08000,
+	0x0000000240008400,
+	0x0000000200009000,
+	0x0000000200000000,
+	0x00000800000080f2,
+	0x0000000020008352,
+	0x000000020000a00a,
+	0x0000002000008000,
+	0x0000000000088000,
+	0x0000000000108000,
+	0x0000000000208000,
+	0x0000c00007008002,
+	0x000000000300a008,
+	0x0000400001048000,
+	0x0000c40007008002,
+	0x0000c0000300a002,
+	0x0000000003008002,
+	0x0000000100000000,
+	0x0000001000008000,
+	0x0000028000008008,
+	0x0000020000008008,
+	0x0000000040048400,
+	0x0000001000008008,
+	0x0000400001028000,
+	0x0000c800030280f2,
+	0x000000004000a008,
+	0x0000c00023028352,
+	0x000040000301200a,
+	0x0000000000000008,
+	0x0000c0000300c002,
+	0x0000c0000300e002,
+	0x0000400001014008,
+	0x0000400005010008,
+	0x0000400001012002,
+	0x0000400001010000,
+	0x0000000040c08000,
+	0x0000080000008012,
+	0x0000004000008000,
+	0x0000000000010808,
+];
@@ -0,0 +1,47443 @@
+// GENERATED CODE DO NOT MANUALLY EDIT
+pub const BP_TABLE: [u8;0x1100] = [
+	 0, // 0x0
+	 1, // 0x1
+	 2, // 0x2
+	 3, // 0x3
+	 4, // 0x4
+	 5, // 0x5
+	 6, // 0x6
+	 7, // 0x7
+	 8, // 0x8
+	 9, // 0x9
+	 10, // 0xa
+	 11, // 0xb
+	 12, // 0xc
+	 13, // 0xd
+	 14, // 0xe
+	 15, // 0xf
+	 16, // 0x10
+	 17, // 0x11
+	 18, // 0x12
+	 19, // 0x13
+	 20, // 0x14
+	 21, // 0x15
+	 22, // 0x16
+	 23, // 0x17
+	 24, // 0x18
+	 25, // 0x19
+	 26, // 0x1a
+	 27, // 0x1b
+	 28, // 0x1c
+	 29, // 0x1d
+	 30, // 0x1e
+	 31, // 0x1f
+	 32, // 0x20
+	 33, // 0x21
+	 34, // 0x22
+	 35, // 0x23
+	 36, // 0x24
+	 37, // 0x25
+	 38, // 0x26
+	 39, // 0x27
+	 40, // 0x28
+	 41, // 0x29
+	 41, // 0x2a
+	 42, // 0x2b
+	 43, // 0x2c
+	 44, // 0x2d
+	 45, // 0x2e
+	 46, // 0x2f
+	 47, // 0x30
+	 48, // 0x31
+	 49, // 0x32
+	 50, // 0x33
+	 51, // 0x34
+	 51, // 0x35
+	 51, // 0x36
+	 51, // 0x37
+	 51, // 0x38
+	 51, // 0x39
+	 51, // 0x3a
+	 51, // 0x3b
+	 51, // 0x3c
+	 51, // 0x3d
+	 51, // 0x3e
+	 51, // 0x3f
+	 51, // 0x40
+	 51, // 0x41
+	 51, // 0x42
+	 51, // 0x43
+	 51, // 0x44
+	 51, // 0x45
+	 51, // 0x46
+	 51, // 0x47
+	 51, // 0x48
+	 51, // 0x49
+	 51, // 0x4a
+	 51, // 0x4b
+	 51, // 0x4c
+	 52, // 0x4d
+	 51, // 0x4e
+	 51, // 0x4f
+	 51, // 0x50
+	 51, // 0x51
+	 51, // 0x52
+	 51, // 0x53
+	 51, // 0x54
+	 51, // 0x55
+	 51, // 0x56
+	 51, // 0x57
+	 51, // 0x58
+	 51, // 0x59
+	 51, // 0x5a
+	 51, // 0x5b
+	 51, // 0x5c
+	 51, // 0x5d
+	 51, // 0x5e
+	 51, // 0x5f
+	 51, // 0x60
+	 51, // 0x61
+	 51, // 0x62
+	 51, // 0x63
+	 51, // 0x64
+	 51, // 0x65
+	 51, // 0x66
+	 51, // 0x67
+	 51, // 0x68
+	 51, // 0x69
+	 51, // 0x6a
+	 51, // 0x6b
+	 51, // 0x6c
+	 51, // 0x6d
+	 51, // 0x6e
+	 51, // 0x6f
+	 51, // 0x70
+	 51, // 0x71
+	 51, // 0x72
+	 51, // 0x73
+	 51, // 0x74
+	 51, // 0x75
+	 51, // 0x76
+	 51, // 0x77
+	 51, // 0x78
+	 51, // 0x79
+	 51, // 0x7a
+	 51, // 0x7b
+	 51, // 0x7c
+	 51, // 0x7d
+	 51, // 0x7e
+	 51, // 0x7f
+	 51, // 0x80
+	 51, // 0x81
+	 51, // 0x82
+	 51, // 0x83
+	 51, // 0x84
+	 51, // 0x85
+	 51, // 0x86
+	 51, // 0x87
+	 51, // 0x88
+	 51, // 0x89
+	 51, // 0x8a
+	 51, // 0x8b
+	 51, // 0x8c
+	 51, // 0x8d
+	 51, // 0x8e
+	 51, // 0x8f
+	 51, // 0x90
+	 51, // 0x91
+	 51, // 0x92
+	 51, // 0x93
+	 51, // 0x94
+	 51, // 0x95
+	 51, // 0x96
+	 51, // 0x97
+	 51, // 0x98
+	 51, // 0x99
+	 51, // 0x9a
+	 51, // 0x9b
+	 51, // 0x9c
+	 51, // 0x9d
+	 51, // 0x9e
+	 51, // 0x9f
+	 53, // 0xa0
+	 21, // 0xa1
+	 21, // 0xa2
+	 21, // 0xa3
+	 54, // 0xa4
+	 21, // 0xa5
+	 55, // 0xa6
+	 56, // 0xa7
+	 57, // 0xa8
+	 58, // 0xa9
+	 59, // 0xaa
+	 60, // 0xab
+	 21, // 0xac
+	 21, // 0xad
+	 21, // 0xae
+	 21, // 0xaf
+	 21, // 0xb0
+	 21, // 0xb1
+	 21, // 0xb2
+	 21, // 0xb3
+	 21, // 0xb4
+	 21, // 0xb5
+	 21, // 0xb6
+	 21, // 0xb7
+	 21, // 0xb8
+	 21, // 0xb9
+	 21, // 0xba
+	 21, // 0xbb
+	 21, // 0xbc
+	 21, // 0xbd
+	 21, // 0xbe
+	 21, // 0xbf
+	 21, // 0xc0
+	 21, // 0xc1
+	 21, // 0xc2
+	 21, // 0xc3
+	 21, // 0xc4
+	 21, // 0xc5
+	 21, // 0xc6
+	 21, // 0xc7
+	 21, // 0xc8
+	 21, // 0xc9
+	 21, // 0xca
+	 21, // 0xcb
+	 21, // 0xcc
+	 21, // 0xcd
+	 21, // 0xce
+	 21, // 0xcf
+	 21, // 0xd0
+	 21, // 0xd1
+	 21, // 0xd2
+	 21, // 0xd3
+	 21, // 0xd4
+	 21, // 0xd5
+	 21, // 0xd6
+	 61, // 0xd7
+	 62, // 0xd8
+	 62, // 0xd9
+	 62, // 0xda
+	 62, // 0xdb
+	 62, // 0xdc
+	 62, // 0xdd
+	 62, // 0xde
+	 62, // 0xdf
+	 62, // 0xe0
+	 62, // 0xe1
+	 62, // 0xe2
+	 62, // 0xe3
+	 62, // 0xe4
+	 62, // 0xe5
+	 62, // 0xe6
+	 62, // 0xe7
+	 62, // 0xe8
+	 62, // 0xe9
+	 62, // 0xea
+	 62, // 0xeb
+	 62, // 0xec
+	 62, // 0xed
+	 62, // 0xee
+	 62, // 0xef
+	 62, // 0xf0
+	 62, // 0xf1
+	 62, // 0xf2
+	 62, // 0xf3
+	 62, // 0xf4
+	 62, // 0xf5
+	 62, // 0xf6
+	 62, // 0xf7
+	 62, // 0xf8
+	 63, // 0xf9
+	 64, // 0xfa
+	 65, // 0xfb
+	 66, // 0xfc
+	 67, // 0xfd
+	 68, // 0xfe
+	 69, // 0xff
+	 70, // 0x100
+	 71, // 0x101
+	 72, // 0x102
+	 73, // 0x103
+	 74, // 0x104
+	 75, // 0x105
+	 21, // 0x106
+	 76, // 0x107
+	 77, // 0x108
+	 78, // 0x109
+	 79, // 0x10a
+	 80, // 0x10b
+	 81, // 0x10c
+	 82, // 0x10d
+	 83, // 0x10e
+	 84, // 0x10f
+	 85, // 0x110
+	 86, // 0x111
+	 87, // 0x112
+	 88, // 0x113
+	 89, // 0x114
+	 90, // 0x115
+	 91, // 0x116
+	 92, // 0x117
+	 93, // 0x118
+	 94, // 0x119
+	 95, // 0x11a
+	 96, // 0x11b
+	 97, // 0x11c
+	 98, // 0x11d
+	 99, // 0x11e
+	 100, // 0x11f
+	 21, // 0x120
+	 21, // 0x121
+	 21, // 0x122
+	 101, // 0x123
+	 102, // 0x124
+	 103, // 0x125
+	 62, // 0x126
+	 62, // 0x127
+	 62, // 0x128
+	 62, // 0x129
+	 62, // 0x12a
+	 62, // 0x12b
+	 62, // 0x12c
+	 62, // 0x12d
+	 62, // 0x12e
+	 104, // 0x12f
+	 21, // 0x130
+	 21, // 0x131
+	 21, // 0x132
+	 21, // 0x133
+	 105, // 0x134
+	 21, // 0x135
+	 21, // 0x136
+	 21, // 0x137
+	 21, // 0x138
+	 21, // 0x139
+	 21, // 0x13a
+	 21, // 0x13b
+	 21, // 0x13c
+	 21, // 0x13d
+	 21, // 0x13e
+	 21, // 0x13f
+	 21, // 0x140
+	 21, // 0x141
+	 21, // 0x142
+	 106, // 0x143
+	 21, // 0x144
+	 21, // 0x145
+	 107, // 0x146
+	 62, // 0x147
+	 62, // 0x148
+	 62, // 0x149
+	 62, // 0x14a
+	 62, // 0x14b
+	 62, // 0x14c
+	 62, // 0x14d
+	 62, // 0x14e
+	 62, // 0x14f
+	 62, // 0x150
+	 62, // 0x151
+	 62, // 0x152
+	 62, // 0x153
+	 62, // 0x154
+	 62, // 0x155
+	 62, // 0x156
+	 62, // 0x157
+	 62, // 0x158
+	 62, // 0x159
+	 62, // 0x15a
+	 62, // 0x15b
+	 62, // 0x15c
+	 62, // 0x15d
+	 62, // 0x15e
+	 62, // 0x15f
+	 62, // 0x160
+	 108, // 0x161
+	 62, // 0x162
+	 62, // 0x163
+	 62, // 0x164
+	 62, // 0x165
+	 62, // 0x166
+	 62, // 0x167
+	 21, // 0x168
+	 21, // 0x169
+	 109, // 0x16a
+	 110, // 0x16b
+	 62, // 0x16c
+	 111, // 0x16d
+	 112, // 0x16e
+	 113, // 0x16f
+	 63, // 0x170
+	 63, // 0x171
+	 63, // 0x172
+	 63, // 0x173
+	 63, // 0x174
+	 63, // 0x175
+	 63, // 0x176
+	 63, // 0x177
+	 63, // 0x178
+	 63, // 0x179
+	 63, // 0x17a
+	 63, // 0x17b
+	 63, // 0x17c
+	 63, // 0x17d
+	 63, // 0x17e
+	 63, // 0x17f
+	 63, // 0x180
+	 63, // 0x181
+	 63, // 0x182
+	 63, // 0x183
+	 63, // 0x184
+	 63, // 0x185
+	 63, // 0x186
+	 114, // 0x187
+	 63, // 0x188
+	 63, // 0x189
+	 63, // 0x18a
+	 63, // 0x18b
+	 115, // 0x18c
+	 116, // 0x18d
+	 62, // 0x18e
+	 62, // 0x18f
+	 62, // 0x190
+	 62, // 0x191
+	 62, // 0x192
+	 62, // 0x193
+	 62, // 0x194
+	 62, // 0x195
+	 62, // 0x196
+	 62, // 0x197
+	 62, // 0x198
+	 62, // 0x199
+	 62, // 0x19a
+	 62, // 0x19b
+	 62, // 0x19c
+	 62, // 0x19d
+	 62, // 0x19e
+	 62, // 0x19f
+	 62, // 0x1a0
+	 62, // 0x1a1
+	 62, // 0x1a2
+	 62, // 0x1a3
+	 62, // 0x1a4
+	 62, // 0x1a5
+	 62, // 0x1a6
+	 62, // 0x1a7
+	 62, // 0x1a8
+	 62, // 0x1a9
+	 62, // 0x1aa
+	 62, // 0x1ab
+	 62, // 0x1ac
+	 62, // 0x1ad
+	 62, // 0x1ae
+	 117, // 0x1af
+	 21, // 0x1b0
+	 118, // 0x1b1
+	 119, // 0x1b2
+	 62, // 0x1b3
+	 62, // 0x1b4
+	 62, // 0x1b5
+	 62, // 0x1b6
+	 62, // 0x1b7
+	 62, // 0x1b8
+	 62, // 0x1b9
+	 62, // 0x1ba
+	 62, // 0x1bb
+	 120, // 0x1bc
+	 62, // 0x1bd
+	 62, // 0x1be
+	 62, // 0x1bf
+	 62, // 0x1c0
+	 62, // 0x1c1
+	 62, // 0x1c2
+	 62, // 0x1c3
+	 62, // 0x1c4
+	 62, // 0x1c5
+	 62, // 0x1c6
+	 62, // 0x1c7
+	 62, // 0x1c8
+	 62, // 0x1c9
+	 62, // 0x1ca
+	 62, // 0x1cb
+	 121, // 0x1cc
+	 50, // 0x1cd
+	 122, // 0x1ce
+	 123, // 0x1cf
+	 124, // 0x1d0
+	 125, // 0x1d1
+	 126, // 0x1d2
+	 127, // 0x1d3
+	 128, // 0x1d4
+	 129, // 0x1d5
+	 130, // 0x1d6
+	 131, // 0x1d7
+	 50, // 0x1d8
+	 50, // 0x1d9
+	 132, // 0x1da
+	 62, // 0x1db
+	 62, // 0x1dc
+	 62, // 0x1dd
+	 62, // 0x1de
+	 133, // 0x1df
+	 134, // 0x1e0
+	 135, // 0x1e1
+	 136, // 0x1e2
+	 62, // 0x1e3
+	 137, // 0x1e4
+	 138, // 0x1e5
+	 62, // 0x1e6
+	 139, // 0x1e7
+	 140, // 0x1e8
+	 141, // 0x1e9
+	 62, // 0x1ea
+	 62, // 0x1eb
+	 142, // 0x1ec
+	 143, // 0x1ed
+	 144, // 0x1ee
+	 62, // 0x1ef
+	 145, // 0x1f0
+	 146, // 0x1f1
+	 147, // 0x1f2
+	 148, // 0x1f3
+	 50, // 0x1f4
+	 50, // 0x1f5
+	 149, // 0x1f6
+	 150, // 0x1f7
+	 151, // 0x1f8
+	 50, // 0x1f9
+	 152, // 0x1fa
+	 153, // 0x1fb
+	 62, // 0x1fc
+	 62, // 0x1fd
+	 62, // 0x1fe
+	 154, // 0x1ff
+	 51, // 0x200
+	 51, // 0x201
+	 51, // 0x202
+	 51, // 0x203
+	 51, // 0x204
+	 51, // 0x205
+	 51, // 0x206
+	 51, // 0x207
+	 51, // 0x208
+	 51, // 0x209
+	 51, // 0x20a
+	 51, // 0x20b
+	 51, // 0x20c
+	 51, // 0x20d
+	 51, // 0x20e
+	 51, // 0x20f
+	 51, // 0x210
+	 51, // 0x211
+	 51, // 0x212
+	 51, // 0x213
+	 51, // 0x214
+	 51, // 0x215
+	 51, // 0x216
+	 51, // 0x217
+	 51, // 0x218
+	 51, // 0x219
+	 51, // 0x21a
+	 51, // 0x21b
+	 51, // 0x21c
+	 51, // 0x21d
+	 51, // 0x21e
+	 51, // 0x21f
+	 51, // 0x220
+	 51, // 0x221
+	 51, // 0x222
+	 51, // 0x223
+	 51, // 0x224
+	 51, // 0x225
+	 51, // 0x226
+	 51, // 0x227
+	 51, // 0x228
+	 51, // 0x229
+	 51, // 0x22a
+	 51, // 0x22b
+	 51, // 0x22c
+	 51, // 0x22d
+	 51, // 0x22e
+	 51, // 0x22f
+	 51, // 0x230
+	 51, // 0x231
+	 51, // 0x232
+	 51, // 0x233
+	 51, // 0x234
+	 51, // 0x235
+	 51, // 0x236
+	 51, // 0x237
+	 51, // 0x238
+	 51, // 0x239
+	 51, // 0x23a
+	 51, // 0x23b
+	 51, // 0x23c
+	 51, // 0x23d
+	 51, // 0x23e
+	 51, // 0x23f
+	 51, // 0x240
+	 51, // 0x241
+	 51, // 0x242
+	 51, // 0x243
+	 51, // 0x244
+	 51, // 0x245
+	 51, // 0x246
+	 51, // 0x247
+	 51, // 0x248
+	 51, // 0x249
+	 51, // 0x24a
+	 51, // 0x24b
+	 51, // 0x24c
+	 51, // 0x24d
+	 51, // 0x24e
+	 51, // 0x24f
+	 51, // 0x250
+	 51, // 0x251
+	 51, // 0x252
+	 51, // 0x253
+	 51, // 0x254
+	 51, // 0x255
+	 51, // 0x256
+	 51, // 0x257
+	 51, // 0x258
+	 51, // 0x259
+	 51, // 0x25a
+	 51, // 0x25b
+	 51, // 0x25c
+	 51, // 0x25d
+	 51, // 0x25e
+	 51, // 0x25f
+	 51, // 0x260
+	 51, // 0x261
+	 51, // 0x262
+	 51, // 0x263
+	 51, // 0x264
+	 51, // 0x265
+	 51, // 0x266
+	 51, // 0x267
+	 51, // 0x268
+	 51, // 0x269
+	 51, // 0x26a
+	 51, // 0x26b
+	 51, // 0x26c
+	 51, // 0x26d
+	 51, // 0x26e
+	 51, // 0x26f
+	 51, // 0x270
+	 51, // 0x271
+	 51, // 0x272
+	 51, // 0x273
+	 51, // 0x274
+	 51, // 0x275
+	 51, // 0x276
+	 51, // 0x277
+	 51, // 0x278
+	 51, // 0x279
+	 51, // 0x27a
+	 51, // 0x27b
+	 51, // 0x27c
+	 51, // 0x27d
+	 51, // 0x27e
+	 51, // 0x27f
+	 51, // 0x280
+	 51, // 0x281
+	 51, // 0x282
+	 51, // 0x283
+	 51, // 0x284
+	 51, // 0x285
+	 51, // 0x286
+	 51, // 0x287
+	 51, // 0x288
+	 51, // 0x289
+	 51, // 0x28a
+	 51, // 0x28b
+	 51, // 0x28c
+	 51, // 0x28d
+	 51, // 0x28e
+	 51, // 0x28f
+	 51, // 0x290
+	 51, // 0x291
+	 51, // 0x292
+	 51, // 0x293
+	 51, // 0x294
+	 51, // 0x295
+	 51, // 0x296
+	 51, // 0x297
+	 51, // 0x298
+	 51, // 0x299
+	 51, // 0x29a
+	 51, // 0x29b
+	 51, // 0x29c
+	 51, // 0x29d
+	 51, // 0x29e
+	 51, // 0x29f
+	 51, // 0x2a0
+	 51, // 0x2a1
+	 51, // 0x2a2
+	 51, // 0x2a3
+	 51, // 0x2a4
+	 51, // 0x2a5
+	 155, // 0x2a6
+	 51, // 0x2a7
+	 51, // 0x2a8
+	 51, // 0x2a9
+	 51, // 0x2aa
+	 51, // 0x2ab
+	 51, // 0x2ac
+	 51, // 0x2ad
+	 51, // 0x2ae
+	 51, // 0x2af
+	 51, // 0x2b0
+	 51, // 0x2b1
+	 51, // 0x2b2
+	 51, // 0x2b3
+	 51, // 0x2b4
+	 51, // 0x2b5
+	 51, // 0x2b6
+	 156, // 0x2b7
+	 157, // 0x2b8
+	 51, // 0x2b9
+	 51, // 0x2ba
+	 51, // 0x2bb
+	 51, // 0x2bc
+	 51, // 0x2bd
+	 51, // 0x2be
+	 51, // 0x2bf
+	 51, // 0x2c0
+	 51, // 0x2c1
+	 51, // 0x2c2
+	 51, // 0x2c3
+	 51, // 0x2c4
+	 51, // 0x2c5
+	 51, // 0x2c6
+	 51, // 0x2c7
+	 51, // 0x2c8
+	 51, // 0x2c9
+	 51, // 0x2ca
+	 51, // 0x2cb
+	 51, // 0x2cc
+	 51, // 0x2cd
+	 158, // 0x2ce
+	 51, // 0x2cf
+	 51, // 0x2d0
+	 51, // 0x2d1
+	 51, // 0x2d2
+	 51, // 0x2d3
+	 51, // 0x2d4
+	 51, // 0x2d5
+	 51, // 0x2d6
+	 51, // 0x2d7
+	 51, // 0x2d8
+	 51, // 0x2d9
+	 51, // 0x2da
+	 51, // 0x2db
+	 51, // 0x2dc
+	 51, // 0x2dd
+	 51, // 0x2de
+	 51, // 0x2df
+	 51, // 0x2e0
+	 51, // 0x2e1
+	 51, // 0x2e2
+	 51, // 0x2e3
+	 51, // 0x2e4
+	 51, // 0x2e5
+	 51, // 0x2e6
+	 51, // 0x2e7
+	 51, // 0x2e8
+	 51, // 0x2e9
+	 51, // 0x2ea
+	 159, // 0x2eb
+	 51, // 0x2ec
+	 51, // 0x2ed
+	 160, // 0x2ee
+	 62, // 0x2ef
+	 62, // 0x2f0
+	 62, // 0x2f1
+	 62, // 0x2f2
+	 62, // 0x2f3
+	 62, // 0x2f4
+	 62, // 0x2f5
+	 62, // 0x2f6
+	 62, // 0x2f7
+	 63, // 0x2f8
+	 63, // 0x2f9
+	 161, // 0x2fa
+	 62, // 0x2fb
+	 62, // 0x2fc
+	 62, // 0x2fd
+	 62, // 0x2fe
+	 154, // 0x2ff
+	 51, // 0x300
+	 51, // 0x301
+	 51, // 0x302
+	 51, // 0x303
+	 51, // 0x304
+	 51, // 0x305
+	 51, // 0x306
+	 51, // 0x307
+	 51, // 0x308
+	 51, // 0x309
+	 51, // 0x30a
+	 51, // 0x30b
+	 51, // 0x30c
+	 51, // 0x30d
+	 51, // 0x30e
+	 51, // 0x30f
+	 51, // 0x310
+	 51, // 0x311
+	 51, // 0x312
+	 162, // 0x313
+	 51, // 0x314
+	 51, // 0x315
+	 51, // 0x316
+	 51, // 0x317
+	 51, // 0x318
+	 51, // 0x319
+	 51, // 0x31a
+	 51, // 0x31b
+	 51, // 0x31c
+	 51, // 0x31d
+	 51, // 0x31e
+	 51, // 0x31f
+	 51, // 0x320
+	 51, // 0x321
+	 51, // 0x322
+	 163, // 0x323
+	 62, // 0x324
+	 62, // 0x325
+	 62, // 0x326
+	 62, // 0x327
+	 62, // 0x328
+	 62, // 0x329
+	 62, // 0x32a
+	 62, // 0x32b
+	 62, // 0x32c
+	 62, // 0x32d
+	 62, // 0x32e
+	 62, // 0x32f
+	 62, // 0x330
+	 62, // 0x331
+	 62, // 0x332
+	 62, // 0x333
+	 62, // 0x334
+	 62, // 0x335
+	 62, // 0x336
+	 62, // 0x337
+	 62, // 0x338
+	 62, // 0x339
+	 62, // 0x33a
+	 62, // 0x33b
+	 62, // 0x33c
+	 62, // 0x33d
+	 62, // 0x33e
+	 62, // 0x33f
+	 62, // 0x340
+	 62, // 0x341
+	 62, // 0x342
+	 62, // 0x343
+	 62, // 0x344
+	 62, // 0x345
+	 62, // 0x346
+	 62, // 0x347
+	 62, // 0x348
+	 62, // 0x349
+	 62, // 0x34a
+	 62, // 0x34b
+	 62, // 0x34c
+	 62, // 0x34d
+	 62, // 0x34e
+	 62, // 0x34f
+	 62, // 0x350
+	 62, // 0x351
+	 62, // 0x352
+	 62, // 0x353
+	 62, // 0x354
+	 62, // 0x355
+	 62, // 0x356
+	 62, // 0x357
+	 62, // 0x358
+	 62, // 0x359
+	 62, // 0x35a
+	 62, // 0x35b
+	 62, // 0x35c
+	 62, // 0x35d
+	 62, // 0x35e
+	 62, // 0x35f
+	 62, // 0x360
+	 62, // 0x361
+	 62, // 0x362
+	 62, // 0x363
+	 62, // 0x364
+	 62, // 0x365
+	 62, // 0x366
+	 62, // 0x367
+	 62, // 0x368
+	 62, // 0x369
+	 62, // 0x36a
+	 62, // 0x36b
+	 62, // 0x36c
+	 62, // 0x36d
+	 62, // 0x36e
+	 62, // 0x36f
+	 62, // 0x370
+	 62, // 0x371
+	 62, // 0x372
+	 62, // 0x373
+	 62, // 0x374
+	 62, // 0x375
+	 62, // 0x376
+	 62, // 0x377
+	 62, // 0x378
+	 62, // 0x379
+	 62, // 0x37a
+	 62, // 0x37b
+	 62, // 0x37c
+	 62, // 0x37d
+	 62, // 0x37e
+	 62, // 0x37f
+	 62, // 0x380
+	 62, // 0x381
+	 62, // 0x382
+	 62, // 0x383
+	 62, // 0x384
+	 62, // 0x385
+	 62, // 0x386
+	 62, // 0x387
+	 62, // 0x388
+	 62, // 0x389
+	 62, // 0x38a
+	 62, // 0x38b
+	 62, // 0x38c
+	 62, // 0x38d
+	 62, // 0x38e
+	 62, // 0x38f
+	 62, // 0x390
+	 62, // 0x391
+	 62, // 0x392
+	 62, // 0x393
+	 62, // 0x394
+	 62, // 0x395
+	 62, // 0x396
+	 62, // 0x397
+	 62, // 0x398
+	 62, // 0x399
+	 62, // 0x39a
+	 62, // 0x39b
+	 62, // 0x39c
+	 62, // 0x39d
+	 62, // 0x39e
+	 62, // 0x39f
+	 62, // 0x3a0
+	 62, // 0x3a1
+	 62, // 0x3a2
+	 62, // 0x3a3
+	 62, // 0x3a4
+	 62, // 0x3a5
+	 62, // 0x3a6
+	 62, // 0x3a7
+	 62, // 0x3a8
+	 62, // 0x3a9
+	 62, // 0x3aa
+	 62, // 0x3ab
+	 62, // 0x3ac
+	 62, // 0x3ad
+	 62, // 0x3ae
+	 62, // 0x3af
+	 62, // 0x3b0
+	 62, // 0x3b1
+	 62, // 0x3b2
+	 62, // 0x3b3
+	 62, // 0x3b4
+	 62, // 0x3b5
+	 62, // 0x3b6
+	 62, // 0x3b7
+	 62, // 0x3b8
+	 62, // 0x3b9
+	 62, // 0x3ba
+	 62, // 0x3bb
+	 62, // 0x3bc
+	 62, // 0x3bd
+	 62, // 0x3be
+	 62, // 0x3bf
+	 62, // 0x3c0
+	 62, // 0x3c1
+	 62, // 0x3c2
+	 62, // 0x3c3
+	 62, // 0x3c4
+	 62, // 0x3c5
+	 62, // 0x3c6
+	 62, // 0x3c7
+	 62, // 0x3c8
+	 62, // 0x3c9
+	 62, // 0x3ca
+	 62, // 0x3cb
+	 62, // 0x3cc
+	 62, // 0x3cd
+	 62, // 0x3ce
+	 62, // 0x3cf
+	 62, // 0x3d0
+	 62, // 0x3d1
+	 62, // 0x3d2
+	 62, // 0x3d3
+	 62, // 0x3d4
+	 62, // 0x3d5
+	 62, // 0x3d6
+	 62, // 0x3d7
+	 62, // 0x3d8
+	 62, // 0x3d9
+	 62, // 0x3da
+	 62, // 0x3db
+	 62, // 0x3dc
+	 62, // 0x3dd
+	 62, // 0x3de
+	 62, // 0x3df
+	 62, // 0x3e0
+	 62, // 0x3e1
+	 62, // 0x3e2
+	 62, // 0x3e3
+	 62, // 0x3e4
+	 62, // 0x3e5
+	 62, // 0x3e6
+	 62, // 0x3e7
+	 62, // 0x3e8
+	 62, // 0x3e9
+	 62, // 0x3ea
+	 62, // 0x3eb
+	 62, // 0x3ec
+	 62, // 0x3ed
+	 62, // 0x3ee
+	 62, // 0x3ef
+	 62, // 0x3f0
+	 62, // 0x3f1
+	 62, // 0x3f2
+	 62, // 0x3f3
+	 62, // 0x3f4
+	 62, // 0x3f5
+	 62, // 0x3f6
+	 62, // 0x3f7
+	 62, // 0x3f8
+	 62, // 0x3f9
+	 62, // 0x3fa
+	 62, // 0x3fb
+	 62, // 0x3fc
+	 62, // 0x3fd
+	 62, // 0x3fe
+	 154, // 0x3ff
+	 62, // 0x400
+	 62, // 0x401
+	 62, // 0x402
+	 62, // 0x403
+	 62, // 0x404
+	 62, // 0x405
+	 62, // 0x406
+	 62, // 0x407
+	 62, // 0x408
+	 62, // 0x409
+	 62, // 0x40a
+	 62, // 0x40b
+	 62, // 0x40c
+	 62, // 0x40d
+	 62, // 0x40e
+	 62, // 0x40f
+	 62, // 0x410
+	 62, // 0x411
+	 62, // 0x412
+	 62, // 0x413
+	 62, // 0x414
+	 62, // 0x415
+	 62, // 0x416
+	 62, // 0x417
+	 62, // 0x418
+	 62, // 0x419
+	 62, // 0x41a
+	 62, // 0x41b
+	 62, // 0x41c
+	 62, // 0x41d
+	 62, // 0x41e
+	 62, // 0x41f
+	 62, // 0x420
+	 62, // 0x421
+	 62, // 0x422
+	 62, // 0x423
+	 62, // 0x424
+	 62, // 0x425
+	 62, // 0x426
+	 62, // 0x427
+	 62, // 0x428
+	 62, // 0x429
+	 62, // 0x42a
+	 62, // 0x42b
+	 62, // 0x42c
+	 62, // 0x42d
+	 62, // 0x42e
+	 62, // 0x42f
+	 62, // 0x430
+	 62, // 0x431
+	 62, // 0x432
+	 62, // 0x433
+	 62, // 0x434
+	 62, // 0x435
+	 62, // 0x436
+	 62, // 0x437
+	 62, // 0x438
+	 62, // 0x439
+	 62, // 0x43a
+	 62, // 0x43b
+	 62, // 0x43c
+	 62, // 0x43d
+	 62, // 0x43e
+	 62, // 0x43f
+	 62, // 0x440
+	 62, // 0x441
+	 62, // 0x442
+	 62, // 0x443
+	 62, // 0x444
+	 62, // 0x445
+	 62, // 0x446
+	 62, // 0x447
+	 62, // 0x448
+	 62, // 0x449
+	 62, // 0x44a
+	 62, // 0x44b
+	 62, // 0x44c
+	 62, // 0x44d
+	 62, // 0x44e
+	 62, // 0x44f
+	 62, // 0x450
+	 62, // 0x451
+	 62, // 0x452
+	 62, // 0x453
+	 62, // 0x454
+	 62, // 0x455
+	 62, // 0x456
+	 62, // 0x457
+	 62, // 0x458
+	 62, // 0x459
+	 62, // 0x45a
+	 62, // 0x45b
+	 62, // 0x45c
+	 62, // 0x45d
+	 62, // 0x45e
+	 62, // 0x45f
+	 62, // 0x460
+	 62, // 0x461
+	 62, // 0x462
+	 62, // 0x463
+	 62, // 0x464
+	 62, // 0x465
+	 62, // 0x466
+	 62, // 0x467
+	 62, // 0x468
+	 62, // 0x469
+	 62, // 0x46a
+	 62, // 0x46b
+	 62, // 0x46c
+	 62, // 0x46d
+	 62, // 0x46e
+	 62, // 0x46f
+	 62, // 0x470
+	 62, // 0x471
+	 62, // 0x472
+	 62, // 0x473
+	 62, // 0x474
+	 62, // 0x475
+	 62, // 0x476
+	 62, // 0x477
+	 62, // 0x478
+	 62, // 0x479
+	 62, // 0x47a
+	 62, // 0x47b
+	 62, // 0x47c
+	 62, // 0x47d
+	 62, // 0x47e
+	 62, // 0x47f
+	 62, // 0x480
+	 62, // 0x481
+	 62, // 0x482
+	 62, // 0x483
+	 62, // 0x484
+	 62, // 0x485
+	 62, // 0x486
+	 62, // 0x487
+	 62, // 0x488
+	 62, // 0x489
+	 62, // 0x48a
+	 62, // 0x48b
+	 62, // 0x48c
+	 62, // 0x48d
+	 62, // 0x48e
+	 62, // 0x48f
+	 62, // 0x490
+	 62, // 0x491
+	 62, // 0x492
+	 62, // 0x493
+	 62, // 0x494
+	 62, // 0x495
+	 62, // 0x496
+	 62, // 0x497
+	 62, // 0x498
+	 62, // 0x499
+	 62, // 0x49a
+	 62, // 0x49b
+	 62, // 0x49c
+	 62, // 0x49d
+	 62, // 0x49e
+	 62, // 0x49f
+	 62, // 0x4a0
+	 62, // 0x4a1
+	 62, // 0x4a2
+	 62, // 0x4a3
+	 62, // 0x4a4
+	 62, // 0x4a5
+	 62, // 0x4a6
+	 62, // 0x4a7
+	 62, // 0x4a8
+	 62, // 0x4a9
+	 62, // 0x4aa
+	 62, // 0x4ab
+	 62, // 0x4ac
+	 62, // 0x4ad
+	 62, // 0x4ae
+	 62, // 0x4af
+	 62, // 0x4b0
+	 62, // 0x4b1
+	 62, // 0x4b2
+	 62, // 0x4b3
+	 62, // 0x4b4
+	 62, // 0x4b5
+	 62, // 0x4b6
+	 62, // 0x4b7
+	 62, // 0x4b8
+	 62, // 0x4b9
+	 62, // 0x4ba
+	 62, // 0x4bb
+	 62, // 0x4bc
+	 62, // 0x4bd
+	 62, // 0x4be
+	 62, // 0x4bf
+	 62, // 0x4c0
+	 62, // 0x4c1
+	 62, // 0x4c2
+	 62, // 0x4c3
+	 62, // 0x4c4
+	 62, // 0x4c5
+	 62, // 0x4c6
+	 62, // 0x4c7
+	 62, // 0x4c8
+	 62, // 0x4c9
+	 62, // 0x4ca
+	 62, // 0x4cb
+	 62, // 0x4cc
+	 62, // 0x4cd
+	 62, // 0x4ce
+	 62, // 0x4cf
+	 62, // 0x4d0
+	 62, // 0x4d1
+	 62, // 0x4d2
+	 62, // 0x4d3
+	 62, // 0x4d4
+	 62, // 0x4d5
+	 62, // 0x4d6
+	 62, // 0x4d7
+	 62, // 0x4d8
+	 62, // 0x4d9
+	 62, // 0x4da
+	 62, // 0x4db
+	 62, // 0x4dc
+	 62, // 0x4dd
+	 62, // 0x4de
+	 62, // 0x4df
+	 62, // 0x4e0
+	 62, // 0x4e1
+	 62, // 0x4e2
+	 62, // 0x4e3
+	 62, // 0x4e4
+	 62, // 0x4e5
+	 62, // 0x4e6
+	 62, // 0x4e7
+	 62, // 0x4e8
+	 62, // 0x4e9
+	 62, // 0x4ea
+	 62, // 0x4eb
+	 62, // 0x4ec
+	 62, // 0x4ed
+	 62, // 0x4ee
+	 62, // 0x4ef
+	 62, // 0x4f0
+	 62, // 0x4f1
+	 62, // 0x4f2
+	 62, // 0x4f3
+	 62, // 0x4f4
+	 62, // 0x4f5
+	 62, // 0x4f6
+	 62, // 0x4f7
+	 62, // 0x4f8
+	 62, // 0x4f9
+	 62, // 0x4fa
+	 62, // 0x4fb
+	 62, // 0x4fc
+	 62, // 0x4fd
+	 62, // 0x4fe
+	 154, // 0x4ff
+	 62, // 0x500
+	 62, // 0x501
+	 62, // 0x502
+	 62, // 0x503
+	 62, // 0x504
+	 62, // 0x505
+	 62, // 0x506
+	 62, // 0x507
+	 62, // 0x508
+	 62, // 0x509
+	 62, // 0x50a
+	 62, // 0x50b
+	 62, // 0x50c
+	 62, // 0x50d
+	 62, // 0x50e
+	 62, // 0x50f
+	 62, // 0x510
+	 62, // 0x511
+	 62, // 0x512
+	 62, // 0x513
+	 62, // 0x514
+	 62, // 0x515
+	 62, // 0x516
+	 62, // 0x517
+	 62, // 0x518
+	 62, // 0x519
+	 62, // 0x51a
+	 62, // 0x51b
+	 62, // 0x51c
+	 62, // 0x51d
+	 62, // 0x51e
+	 62, // 0x51f
+	 62, // 0x520
+	 62, // 0x521
+	 62, // 0x522
+	 62, // 0x523
+	 62, // 0x524
+	 62, // 0x525
+	 62, // 0x526
+	 62, // 0x527
+	 62, // 0x528
+	 62, // 0x529
+	 62, // 0x52a
+	 62, // 0x52b
+	 62, // 0x52c
+	 62, // 0x52d
+	 62, // 0x52e
+	 62, // 0x52f
+	 62, // 0x530
+	 62, // 0x531
+	 62, // 0x532
+	 62, // 0x533
+	 62, // 0x534
+	 62, // 0x535
+	 62, // 0x536
+	 62, // 0x537
+	 62, // 0x538
+	 62, // 0x539
+	 62, // 0x53a
+	 62, // 0x53b
+	 62, // 0x53c
+	 62, // 0x53d
+	 62, // 0x53e
+	 62, // 0x53f
+	 62, // 0x540
+	 62, // 0x541
+	 62, // 0x542
+	 62, // 0x543
+	 62, // 0x544
+	 62, // 0x545
+	 62, // 0x546
+	 62, // 0x547
+	 62, // 0x548
+	 62, // 0x549
+	 62, // 0x54a
+	 62, // 0x54b
+	 62, // 0x54c
+	 62, // 0x54d
+	 62, // 0x54e
+	 62, // 0x54f
+	 62, // 0x550
+	 62, // 0x551
+	 62, // 0x552
+	 62, // 0x553
+	 62, // 0x554
+	 62, // 0x555
+	 62, // 0x556
+	 62, // 0x557
+	 62, // 0x558
+	 62, // 0x559
+	 62, // 0x55a
+	 62, // 0x55b
+	 62, // 0x55c
+	 62, // 0x55d
+	 62, // 0x55e
+	 62, // 0x55f
+	 62, // 0x560
+	 62, // 0x561
+	 62, // 0x562
+	 62, // 0x563
+	 62, // 0x564
+	 62, // 0x565
+	 62, // 0x566
+	 62, // 0x567
+	 62, // 0x568
+	 62, // 0x569
+	 62, // 0x56a
+	 62, // 0x56b
+	 62, // 0x56c
+	 62, // 0x56d
+	 62, // 0x56e
+	 62, // 0x56f
+	 62, // 0x570
+	 62, // 0x571
+	 62, // 0x572
+	 62, // 0x573
+	 62, // 0x574
+	 62, // 0x575
+	 62, // 0x576
+	 62, // 0x577
+	 62, // 0x578
+	 62, // 0x579
+	 62, // 0x57a
+	 62, // 0x57b
+	 62, // 0x57c
+	 62, // 0x57d
+	 62, // 0x57e
+	 62, // 0x57f
+	 62, // 0x580
+	 62, // 0x581
+	 62, // 0x582
+	 62, // 0x583
+	 62, // 0x584
+	 62, // 0x585
+	 62, // 0x586
+	 62, // 0x587
+	 62, // 0x588
+	 62, // 0x589
+	 62, // 0x58a
+	 62, // 0x58b
+	 62, // 0x58c
+	 62, // 0x58d
+	 62, // 0x58e
+	 62, // 0x58f
+	 62, // 0x590
+	 62, // 0x591
+	 62, // 0x592
+	 62, // 0x593
+	 62, // 0x594
+	 62, // 0x595
+	 62, // 0x596
+	 62, // 0x597
+	 62, // 0x598
+	 62, // 0x599
+	 62, // 0x59a
+	 62, // 0x59b
+	 62, // 0x59c
+	 62, // 0x59d
+	 62, // 0x59e
+	 62, // 0x59f
+	 62, // 0x5a0
+	 62, // 0x5a1
+	 62, // 0x5a2
+	 62, // 0x5a3
+	 62, // 0x5a4
+	 62, // 0x5a5
+	 62, // 0x5a6
+	 62, // 0x5a7
+	 62, // 0x5a8
+	 62, // 0x5a9
+	 62, // 0x5aa
+	 62, // 0x5ab
+	 62, // 0x5ac
+	 62, // 0x5ad
+	 62, // 0x5ae
+	 62, // 0x5af
+	 62, // 0x5b0
+	 62, // 0x5b1
+	 62, // 0x5b2
+	 62, // 0x5b3
+	 62, // 0x5b4
+	 62, // 0x5b5
+	 62, // 0x5b6
+	 62, // 0x5b7
+	 62, // 0x5b8
+	 62, // 0x5b9
+	 62, // 0x5ba
+	 62, // 0x5bb
+	 62, // 0x5bc
+	 62, // 0x5bd
+	 62, // 0x5be
+	 62, // 0x5bf
+	 62, // 0x5c0
+	 62, // 0x5c1
+	 62, // 0x5c2
+	 62, // 0x5c3
+	 62, // 0x5c4
+	 62, // 0x5c5
+	 62, // 0x5c6
+	 62, // 0x5c7
+	 62, // 0x5c8
+	 62, // 0x5c9
+	 62, // 0x5ca
+	 62, // 0x5cb
+	 62, // 0x5cc
+	 62, // 0x5cd
+	 62, // 0x5ce
+	 62, // 0x5cf
+	 62, // 0x5d0
+	 62, // 0x5d1
+	 62, // 0x5d2
+	 62, // 0x5d3
+	 62, // 0x5d4
+	 62, // 0x5d5
+	 62, // 0x5d6
+	 62, // 0x5d7
+	 62, // 0x5d8
+	 62, // 0x5d9
+	 62, // 0x5da
+	 62, // 0x5db
+	 62, // 0x5dc
+	 62, // 0x5dd
+	 62, // 0x5de
+	 62, // 0x5df
+	 62, // 0x5e0
+	 62, // 0x5e1
+	 62, // 0x5e2
+	 62, // 0x5e3
+	 62, // 0x5e4
+	 62, // 0x5e5
+	 62, // 0x5e6
+	 62, // 0x5e7
+	 62, // 0x5e8
+	 62, // 0x5e9
+	 62, // 0x5ea
+	 62, // 0x5eb
+	 62, // 0x5ec
+	 62, // 0x5ed
+	 62, // 0x5ee
+	 62, // 0x5ef
+	 62, // 0x5f0
+	 62, // 0x5f1
+	 62, // 0x5f2
+	 62, // 0x5f3
+	 62, // 0x5f4
+	 62, // 0x5f5
+	 62, // 0x5f6
+	 62, // 0x5f7
+	 62, // 0x5f8
+	 62, // 0x5f9
+	 62, // 0x5fa
+	 62, // 0x5fb
+	 62, // 0x5fc
+	 62, // 0x5fd
+	 62, // 0x5fe
+	 154, // 0x5ff
+	 62, // 0x600
+	 62, // 0x601
+	 62, // 0x602
+	 62, // 0x603
+	 62, // 0x604
+	 62, // 0x605
+	 62, // 0x606
+	 62, // 0x607
+	 62, // 0x608
+	 62, // 0x609
+	 62, // 0x60a
+	 62, // 0x60b
+	 62, // 0x60c
+	 62, // 0x60d
+	 62, // 0x60e
+	 62, // 0x60f
+	 62, // 0x610
+	 62, // 0x611
+	 62, // 0x612
+	 62, // 0x613
+	 62, // 0x614
+	 62, // 0x615
+	 62, // 0x616
+	 62, // 0x617
+	 62, // 0x618
+	 62, // 0x619
+	 62, // 0x61a
+	 62, // 0x61b
+	 62, // 0x61c
+	 62, // 0x61d
+	 62, // 0x61e
+	 62, // 0x61f
+	 62, // 0x620
+	 62, // 0x621
+	 62, // 0x622
+	 62, // 0x623
+	 62, // 0x624
+	 62, // 0x625
+	 62, // 0x626
+	 62, // 0x627
+	 62, // 0x628
+	 62, // 0x629
+	 62, // 0x62a
+	 62, // 0x62b
+	 62, // 0x62c
+	 62, // 0x62d
+	 62, // 0x62e
+	 62, // 0x62f
+	 62, // 0x630
+	 62, // 0x631
+	 62, // 0x632
+	 62, // 0x633
+	 62, // 0x634
+	 62, // 0x635
+	 62, // 0x636
+	 62, // 0x637
+	 62, // 0x638
+	 62, // 0x639
+	 62, // 0x63a
+	 62, // 0x63b
+	 62, // 0x63c
+	 62, // 0x63d
+	 62, // 0x63e
+	 62, // 0x63f
+	 62, // 0x640
+	 62, // 0x641
+	 62, // 0x642
+	 62, // 0x643
+	 62, // 0x644
+	 62, // 0x645
+	 62, // 0x646
+	 62, // 0x647
+	 62, // 0x648
+	 62, // 0x649
+	 62, // 0x64a
+	 62, // 0x64b
+	 62, // 0x64c
+	 62, // 0x64d
+	 62, // 0x64e
+	 62, // 0x64f
+	 62, // 0x650
+	 62, // 0x651
+	 62, // 0x652
+	 62, // 0x653
+	 62, // 0x654
+	 62, // 0x655
+	 62, // 0x656
+	 62, // 0x657
+	 62, // 0x658
+	 62, // 0x659
+	 62, // 0x65a
+	 62, // 0x65b
+	 62, // 0x65c
+	 62, // 0x65d
+	 62, // 0x65e
+	 62, // 0x65f
+	 62, // 0x660
+	 62, // 0x661
+	 62, // 0x662
+	 62, // 0x663
+	 62, // 0x664
+	 62, // 0x665
+	 62, // 0x666
+	 62, // 0x667
+	 62, // 0x668
+	 62, // 0x669
+	 62, // 0x66a
+	 62, // 0x66b
+	 62, // 0x66c
+	 62, // 0x66d
+	 62, // 0x66e
+	 62, // 0x66f
+	 62, // 0x670
+	 62, // 0x671
+	 62, // 0x672
+	 62, // 0x673
+	 62, // 0x674
+	 62, // 0x675
+	 62, // 0x676
+	 62, // 0x677
+	 62, // 0x678
+	 62, // 0x679
+	 62, // 0x67a
+	 62, // 0x67b
+	 62, // 0x67c
+	 62, // 0x67d
+	 62, // 0x67e
+	 62, // 0x67f
+	 62, // 0x680
+	 62, // 0x681
+	 62, // 0x682
+	 62, // 0x683
+	 62, // 0x684
+	 62, // 0x685
+	 62, // 0x686
+	 62, // 0x687
+	 62, // 0x688
+	 62, // 0x689
+	 62, // 0x68a
+	 62, // 0x68b
+	 62, // 0x68c
+	 62, // 0x68d
+	 62, // 0x68e
+	 62, // 0x68f
+	 62, // 0x690
+	 62, // 0x691
+	 62, // 0x692
+	 62, // 0x693
+	 62, // 0x694
+	 62, // 0x695
+	 62, // 0x696
+	 62, // 0x697
+	 62, // 0x698
+	 62, // 0x699
+	 62, // 0x69a
+	 62, // 0x69b
+	 62, // 0x69c
+	 62, // 0x69d
+	 62, // 0x69e
+	 62, // 0x69f
+	 62, // 0x6a0
+	 62, // 0x6a1
+	 62, // 0x6a2
+	 62, // 0x6a3
+	 62, // 0x6a4
+	 62, // 0x6a5
+	 62, // 0x6a6
+	 62, // 0x6a7
+	 62, // 0x6a8
+	 62, // 0x6a9
+	 62, // 0x6aa
+	 62, // 0x6ab
+	 62, // 0x6ac
+	 62, // 0x6ad
+	 62, // 0x6ae
+	 62, // 0x6af
+	 62, // 0x6b0
+	 62, // 0x6b1
+	 62, // 0x6b2
+	 62, // 0x6b3
+	 62, // 0x6b4
+	 62, // 0x6b5
+	 62, // 0x6b6
+	 62, // 0x6b7
+	 62, // 0x6b8
+	 62, // 0x6b9
+	 62, // 0x6ba
+	 62, // 0x6bb
+	 62, // 0x6bc
+	 62, // 0x6bd
+	 62, // 0x6be
+	 62, // 0x6bf
+	 62, // 0x6c0
+	 62, // 0x6c1
+	 62, // 0x6c2
+	 62, // 0x6c3
+	 62, // 0x6c4
+	 62, // 0x6c5
+	 62, // 0x6c6
+	 62, // 0x6c7
+	 62, // 0x6c8
+	 62, // 0x6c9
+	 62, // 0x6ca
+	 62, // 0x6cb
+	 62, // 0x6cc
+	 62, // 0x6cd
+	 62, // 0x6ce
+	 62, // 0x6cf
+	 62, // 0x6d0
+	 62, // 0x6d1
+	 62, // 0x6d2
+	 62, // 0x6d3
+	 62, // 0x6d4
+	 62, // 0x6d5
+	 62, // 0x6d6
+	 62, // 0x6d7
+	 62, // 0x6d8
+	 62, // 0x6d9
+	 62, // 0x6da
+	 62, // 0x6db
+	 62, // 0x6dc
+	 62, // 0x6dd
+	 62, // 0x6de
+	 62, // 0x6df
+	 62, // 0x6e0
+	 62, // 0x6e1
+	 62, // 0x6e2
+	 62, // 0x6e3
+	 62, // 0x6e4
+	 62, // 0x6e5
+	 62, // 0x6e6
+	 62, // 0x6e7
+	 62, // 0x6e8
+	 62, // 0x6e9
+	 62, // 0x6ea
+	 62, // 0x6eb
+	 62, // 0x6ec
+	 62, // 0x6ed
+	 62, // 0x6ee
+	 62, // 0x6ef
+	 62, // 0x6f0
+	 62, // 0x6f1
+	 62, // 0x6f2
+	 62, // 0x6f3
+	 62, // 0x6f4
+	 62, // 0x6f5
+	 62, // 0x6f6
+	 62, // 0x6f7
+	 62, // 0x6f8
+	 62, // 0x6f9
+	 62, // 0x6fa
+	 62, // 0x6fb
+	 62, // 0x6fc
+	 62, // 0x6fd
+	 62, // 0x6fe
+	 154, // 0x6ff
+	 62, // 0x700
+	 62, // 0x701
+	 62, // 0x702
+	 62, // 0x703
+	 62, // 0x704
+	 62, // 0x705
+	 62, // 0x706
+	 62, // 0x707
+	 62, // 0x708
+	 62, // 0x709
+	 62, // 0x70a
+	 62, // 0x70b
+	 62, // 0x70c
+	 62, // 0x70d
+	 62, // 0x70e
+	 62, // 0x70f
+	 62, // 0x710
+	 62, // 0x711
+	 62, // 0x712
+	 62, // 0x713
+	 62, // 0x714
+	 62, // 0x715
+	 62, // 0x716
+	 62, // 0x717
+	 62, // 0x718
+	 62, // 0x719
+	 62, // 0x71a
+	 62, // 0x71b
+	 62, // 0x71c
+	 62, // 0x71d
+	 62, // 0x71e
+	 62, // 0x71f
+	 62, // 0x720
+	 62, // 0x721
+	 62, // 0x722
+	 62, // 0x723
+	 62, // 0x724
+	 62, // 0x725
+	 62, // 0x726
+	 62, // 0x727
+	 62, // 0x728
+	 62, // 0x729
+	 62, // 0x72a
+	 62, // 0x72b
+	 62, // 0x72c
+	 62, // 0x72d
+	 62, // 0x72e
+	 62, // 0x72f
+	 62, // 0x730
+	 62, // 0x731
+	 62, // 0x732
+	 62, // 0x733
+	 62, // 0x734
+	 62, // 0x735
+	 62, // 0x736
+	 62, // 0x737
+	 62, // 0x738
+	 62, // 0x739
+	 62, // 0x73a
+	 62, // 0x73b
+	 62, // 0x73c
+	 62, // 0x73d
+	 62, // 0x73e
+	 62, // 0x73f
+	 62, // 0x740
+	 62, // 0x741
+	 62, // 0x742
+	 62, // 0x743
+	 62, // 0x744
+	 62, // 0x745
+	 62, // 0x746
+	 62, // 0x747
+	 62, // 0x748
+	 62, // 0x749
+	 62, // 0x74a
+	 62, // 0x74b
+	 62, // 0x74c
+	 62, // 0x74d
+	 62, // 0x74e
+	 62, // 0x74f
+	 62, // 0x750
+	 62, // 0x751
+	 62, // 0x752
+	 62, // 0x753
+	 62, // 0x754
+	 62, // 0x755
+	 62, // 0x756
+	 62, // 0x757
+	 62, // 0x758
+	 62, // 0x759
+	 62, // 0x75a
+	 62, // 0x75b
+	 62, // 0x75c
+	 62, // 0x75d
+	 62, // 0x75e
+	 62, // 0x75f
+	 62, // 0x760
+	 62, // 0x761
+	 62, // 0x762
+	 62, // 0x763
+	 62, // 0x764
+	 62, // 0x765
+	 62, // 0x766
+	 62, // 0x767
+	 62, // 0x768
+	 62, // 0x769
+	 62, // 0x76a
+	 62, // 0x76b
+	 62, // 0x76c
+	 62, // 0x76d
+	 62, // 0x76e
+	 62, // 0x76f
+	 62, // 0x770
+	 62, // 0x771
+	 62, // 0x772
+	 62, // 0x773
+	 62, // 0x774
+	 62, // 0x775
+	 62, // 0x776
+	 62, // 0x777
+	 62, // 0x778
+	 62, // 0x779
+	 62, // 0x77a
+	 62, // 0x77b
+	 62, // 0x77c
+	 62, // 0x77d
+	 62, // 0x77e
+	 62, // 0x77f
+	 62, // 0x780
+	 62, // 0x781
+	 62, // 0x782
+	 62, // 0x783
+	 62, // 0x784
+	 62, // 0x785
+	 62, // 0x786
+	 62, // 0x787
+	 62, // 0x788
+	 62, // 0x789
+	 62, // 0x78a
+	 62, // 0x78b
+	 62, // 0x78c
+	 62, // 0x78d
+	 62, // 0x78e
+	 62, // 0x78f
+	 62, // 0x790
+	 62, // 0x791
+	 62, // 0x792
+	 62, // 0x793
+	 62, // 0x794
+	 62, // 0x795
+	 62, // 0x796
+	 62, // 0x797
+	 62, // 0x798
+	 62, // 0x799
+	 62, // 0x79a
+	 62, // 0x79b
+	 62, // 0x79c
+	 62, // 0x79d
+	 62, // 0x79e
+	 62, // 0x79f
+	 62, // 0x7a0
+	 62, // 0x7a1
+	 62, // 0x7a2
+	 62, // 0x7a3
+	 62, // 0x7a4
+	 62, // 0x7a5
+	 62, // 0x7a6
+	 62, // 0x7a7
+	 62, // 0x7a8
+	 62, // 0x7a9
+	 62, // 0x7aa
+	 62, // 0x7ab
+	 62, // 0x7ac
+	 62, // 0x7ad
+	 62, // 0x7ae
+	 62, // 0x7af
+	 62, // 0x7b0
+	 62, // 0x7b1
+	 62, // 0x7b2
+	 62, // 0x7b3
+	 62, // 0x7b4
+	 62, // 0x7b5
+	 62, // 0x7b6
+	 62, // 0x7b7
+	 62, // 0x7b8
+	 62, // 0x7b9
+	 62, // 0x7ba
+	 62, // 0x7bb
+	 62, // 0x7bc
+	 62, // 0x7bd
+	 62, // 0x7be
+	 62, // 0x7bf
+	 62, // 0x7c0
+	 62, // 0x7c1
+	 62, // 0x7c2
+	 62, // 0x7c3
+	 62, // 0x7c4
+	 62, // 0x7c5
+	 62, // 0x7c6
+	 62, // 0x7c7
+	 62, // 0x7c8
+	 62, // 0x7c9
+	 62, // 0x7ca
+	 62, // 0x7cb
+	 62, // 0x7cc
+	 62, // 0x7cd
+	 62, // 0x7ce
+	 62, // 0x7cf
+	 62, // 0x7d0
+	 62, // 0x7d1
+	 62, // 0x7d2
+	 62, // 0x7d3
+	 62, // 0x7d4
+	 62, // 0x7d5
+	 62, // 0x7d6
+	 62, // 0x7d7
+	 62, // 0x7d8
+	 62, // 0x7d9
+	 62, // 0x7da
+	 62, // 0x7db
+	 62, // 0x7dc
+	 62, // 0x7dd
+	 62, // 0x7de
+	 62, // 0x7df
+	 62, // 0x7e0
+	 62, // 0x7e1
+	 62, // 0x7e2
+	 62, // 0x7e3
+	 62, // 0x7e4
+	 62, // 0x7e5
+	 62, // 0x7e6
+	 62, // 0x7e7
+	 62, // 0x7e8
+	 62, // 0x7e9
+	 62, // 0x7ea
+	 62, // 0x7eb
+	 62, // 0x7ec
+	 62, // 0x7ed
+	 62, // 0x7ee
+	 62, // 0x7ef
+	 62, // 0x7f0
+	 62, // 0x7f1
+	 62, // 0x7f2
+	 62, // 0x7f3
+	 62, // 0x7f4
+	 62, // 0x7f5
+	 62, // 0x7f6
+	 62, // 0x7f7
+	 62, // 0x7f8
+	 62, // 0x7f9
+	 62, // 0x7fa
+	 62, // 0x7fb
+	 62, // 0x7fc
+	 62, // 0x7fd
+	 62, // 0x7fe
+	 154, // 0x7ff
+	 62, // 0x800
+	 62, // 0x801
+	 62, // 0x802
+	 62, // 0x803
+	 62, // 0x804
+	 62, // 0x805
+	 62, // 0x806
+	 62, // 0x807
+	 62, // 0x808
+	 62, // 0x809
+	 62, // 0x80a
+	 62, // 0x80b
+	 62, // 0x80c
+	 62, // 0x80d
+	 62, // 0x80e
+	 62, // 0x80f
+	 62, // 0x810
+	 62, // 0x811
+	 62, // 0x812
+	 62, // 0x813
+	 62, // 0x814
+	 62, // 0x815
+	 62, // 0x816
+	 62, // 0x817
+	 62, // 0x818
+	 62, // 0x819
+	 62, // 0x81a
+	 62, // 0x81b
+	 62, // 0x81c
+	 62, // 0x81d
+	 62, // 0x81e
+	 62, // 0x81f
+	 62, // 0x820
+	 62, // 0x821
+	 62, // 0x822
+	 62, // 0x823
+	 62, // 0x824
+	 62, // 0x825
+	 62, // 0x826
+	 62, // 0x827
+	 62, // 0x828
+	 62, // 0x829
+	 62, // 0x82a
+	 62, // 0x82b
+	 62, // 0x82c
+	 62, // 0x82d
+	 62, // 0x82e
+	 62, // 0x82f
+	 62, // 0x830
+	 62, // 0x831
+	 62, // 0x832
+	 62, // 0x833
+	 62, // 0x834
+	 62, // 0x835
+	 62, // 0x836
+	 62, // 0x837
+	 62, // 0x838
+	 62, // 0x839
+	 62, // 0x83a
+	 62, // 0x83b
+	 62, // 0x83c
+	 62, // 0x83d
+	 62, // 0x83e
+	 62, // 0x83f
+	 62, // 0x840
+	 62, // 0x841
+	 62, // 0x842
+	 62, // 0x843
+	 62, // 0x844
+	 62, // 0x845
+	 62, // 0x846
+	 62, // 0x847
+	 62, // 0x848
+	 62, // 0x849
+	 62, // 0x84a
+	 62, // 0x84b
+	 62, // 0x84c
+	 62, // 0x84d
+	 62, // 0x84e
+	 62, // 0x84f
+	 62, // 0x850
+	 62, // 0x851
+	 62, // 0x852
+	 62, // 0x853
+	 62, // 0x854
+	 62, // 0x855
+	 62, // 0x856
+	 62, // 0x857
+	 62, // 0x858
+	 62, // 0x859
+	 62, // 0x85a
+	 62, // 0x85b
+	 62, // 0x85c
+	 62, // 0x85d
+	 62, // 0x85e
+	 62, // 0x85f
+	 62, // 0x860
+	 62, // 0x861
+	 62, // 0x862
+	 62, // 0x863
+	 62, // 0x864
+	 62, // 0x865
+	 62, // 0x866
+	 62, // 0x867
+	 62, // 0x868
+	 62, // 0x869
+	 62, // 0x86a
+	 62, // 0x86b
+	 62, // 0x86c
+	 62, // 0x86d
+	 62, // 0x86e
+	 62, // 0x86f
+	 62, // 0x870
+	 62, // 0x871
+	 62, // 0x872
+	 62, // 0x873
+	 62, // 0x874
+	 62, // 0x875
+	 62, // 0x876
+	 62, // 0x877
+	 62, // 0x878
+	 62, // 0x879
+	 62, // 0x87a
+	 62, // 0x87b
+	 62, // 0x87c
+	 62, // 0x87d
+	 62, // 0x87e
+	 62, // 0x87f
+	 62, // 0x880
+	 62, // 0x881
+	 62, // 0x882
+	 62, // 0x883
+	 62, // 0x884
+	 62, // 0x885
+	 62, // 0x886
+	 62, // 0x887
+	 62, // 0x888
+	 62, // 0x889
+	 62, // 0x88a
+	 62, // 0x88b
+	 62, // 0x88c
+	 62, // 0x88d
+	 62, // 0x88e
+	 62, // 0x88f
+	 62, // 0x890
+	 62, // 0x891
+	 62, // 0x892
+	 62, // 0x893
+	 62, // 0x894
+	 62, // 0x895
+	 62, // 0x896
+	 62, // 0x897
+	 62, // 0x898
+	 62, // 0x899
+	 62, // 0x89a
+	 62, // 0x89b
+	 62, // 0x89c
+	 62, // 0x89d
+	 62, // 0x89e
+	 62, // 0x89f
+	 62, // 0x8a0
+	 62, // 0x8a1
+	 62, // 0x8a2
+	 62, // 0x8a3
+	 62, // 0x8a4
+	 62, // 0x8a5
+	 62, // 0x8a6
+	 62, // 0x8a7
+	 62, // 0x8a8
+	 62, // 0x8a9
+	 62, // 0x8aa
+	 62, // 0x8ab
+	 62, // 0x8ac
+	 62, // 0x8ad
+	 62, // 0x8ae
+	 62, // 0x8af
+	 62, // 0x8b0
+	 62, // 0x8b1
+	 62, // 0x8b2
+	 62, // 0x8b3
+	 62, // 0x8b4
+	 62, // 0x8b5
+	 62, // 0x8b6
+	 62, // 0x8b7
+	 62, // 0x8b8
+	 62, // 0x8b9
+	 62, // 0x8ba
+	 62, // 0x8bb
+	 62, // 0x8bc
+	 62, // 0x8bd
+	 62, // 0x8be
+	 62, // 0x8bf
+	 62, // 0x8c0
+	 62, // 0x8c1
+	 62, // 0x8c2
+	 62, // 0x8c3
+	 62, // 0x8c4
+	 62, // 0x8c5
+	 62, // 0x8c6
+	 62, // 0x8c7
+	 62, // 0x8c8
+	 62, // 0x8c9
+	 62, // 0x8ca
+	 62, // 0x8cb
+	 62, // 0x8cc
+	 62, // 0x8cd
+	 62, // 0x8ce
+	 62, // 0x8cf
+	 62, // 0x8d0
+	 62, // 0x8d1
+	 62, // 0x8d2
+	 62, // 0x8d3
+	 62, // 0x8d4
+	 62, // 0x8d5
+	 62, // 0x8d6
+	 62, // 0x8d7
+	 62, // 0x8d8
+	 62, // 0x8d9
+	 62, // 0x8da
+	 62, // 0x8db
+	 62, // 0x8dc
+	 62, // 0x8dd
+	 62, // 0x8de
+	 62, // 0x8df
+	 62, // 0x8e0
+	 62, // 0x8e1
+	 62, // 0x8e2
+	 62, // 0x8e3
+	 62, // 0x8e4
+	 62, // 0x8e5
+	 62, // 0x8e6
+	 62, // 0x8e7
+	 62, // 0x8e8
+	 62, // 0x8e9
+	 62, // 0x8ea
+	 62, // 0x8eb
+	 62, // 0x8ec
+	 62, // 0x8ed
+	 62, // 0x8ee
+	 62, // 0x8ef
+	 62, // 0x8f0
+	 62, // 0x8f1
+	 62, // 0x8f2
+	 62, // 0x8f3
+	 62, // 0x8f4
+	 62, // 0x8f5
+	 62, // 0x8f6
+	 62, // 0x8f7
+	 62, // 0x8f8
+	 62, // 0x8f9
+	 62, // 0x8fa
+	 62, // 0x8fb
+	 62, // 0x8fc
+	 62, // 0x8fd
+	 62, // 0x8fe
+	 154, // 0x8ff
+	 62, // 0x900
+	 62, // 0x901
+	 62, // 0x902
+	 62, // 0x903
+	 62, // 0x904
+	 62, // 0x905
+	 62, // 0x906
+	 62, // 0x907
+	 62, // 0x908
+	 62, // 0x909
+	 62, // 0x90a
+	 62, // 0x90b
+	 62, // 0x90c
+	 62, // 0x90d
+	 62, // 0x90e
+	 62, // 0x90f
+	 62, // 0x910
+	 62, // 0x911
+	 62, // 0x912
+	 62, // 0x913
+	 62, // 0x914
+	 62, // 0x915
+	 62, // 0x916
+	 62, // 0x917
+	 62, // 0x918
+	 62, // 0x919
+	 62, // 0x91a
+	 62, // 0x91b
+	 62, // 0x91c
+	 62, // 0x91d
+	 62, // 0x91e
+	 62, // 0x91f
+	 62, // 0x920
+	 62, // 0x921
+	 62, // 0x922
+	 62, // 0x923
+	 62, // 0x924
+	 62, // 0x925
+	 62, // 0x926
+	 62, // 0x927
+	 62, // 0x928
+	 62, // 0x929
+	 62, // 0x92a
+	 62, // 0x92b
+	 62, // 0x92c
+	 62, // 0x92d
+	 62, // 0x92e
+	 62, // 0x92f
+	 62, // 0x930
+	 62, // 0x931
+	 62, // 0x932
+	 62, // 0x933
+	 62, // 0x934
+	 62, // 0x935
+	 62, // 0x936
+	 62, // 0x937
+	 62, // 0x938
+	 62, // 0x939
+	 62, // 0x93a
+	 62, // 0x93b
+	 62, // 0x93c
+	 62, // 0x93d
+	 62, // 0x93e
+	 62, // 0x93f
+	 62, // 0x940
+	 62, // 0x941
+	 62, // 0x942
+	 62, // 0x943
+	 62, // 0x944
+	 62, // 0x945
+	 62, // 0x946
+	 62, // 0x947
+	 62, // 0x948
+	 62, // 0x949
+	 62, // 0x94a
+	 62, // 0x94b
+	 62, // 0x94c
+	 62, // 0x94d
+	 62, // 0x94e
+	 62, // 0x94f
+	 62, // 0x950
+	 62, // 0x951
+	 62, // 0x952
+	 62, // 0x953
+	 62, // 0x954
+	 62, // 0x955
+	 62, // 0x956
+	 62, // 0x957
+	 62, // 0x958
+	 62, // 0x959
+	 62, // 0x95a
+	 62, // 0x95b
+	 62, // 0x95c
+	 62, // 0x95d
+	 62, // 0x95e
+	 62, // 0x95f
+	 62, // 0x960
+	 62, // 0x961
+	 62, // 0x962
+	 62, // 0x963
+	 62, // 0x964
+	 62, // 0x965
+	 62, // 0x966
+	 62, // 0x967
+	 62, // 0x968
+	 62, // 0x969
+	 62, // 0x96a
+	 62, // 0x96b
+	 62, // 0x96c
+	 62, // 0x96d
+	 62, // 0x96e
+	 62, // 0x96f
+	 62, // 0x970
+	 62, // 0x971
+	 62, // 0x972
+	 62, // 0x973
+	 62, // 0x974
+	 62, // 0x975
+	 62, // 0x976
+	 62, // 0x977
+	 62, // 0x978
+	 62, // 0x979
+	 62, // 0x97a
+	 62, // 0x97b
+	 62, // 0x97c
+	 62, // 0x97d
+	 62, // 0x97e
+	 62, // 0x97f
+	 62, // 0x980
+	 62, // 0x981
+	 62, // 0x982
+	 62, // 0x983
+	 62, // 0x984
+	 62, // 0x985
+	 62, // 0x986
+	 62, // 0x987
+	 62, // 0x988
+	 62, // 0x989
+	 62, // 0x98a
+	 62, // 0x98b
+	 62, // 0x98c
+	 62, // 0x98d
+	 62, // 0x98e
+	 62, // 0x98f
+	 62, // 0x990
+	 62, // 0x991
+	 62, // 0x992
+	 62, // 0x993
+	 62, // 0x994
+	 62, // 0x995
+	 62, // 0x996
+	 62, // 0x997
+	 62, // 0x998
+	 62, // 0x999
+	 62, // 0x99a
+	 62, // 0x99b
+	 62, // 0x99c
+	 62, // 0x99d
+	 62, // 0x99e
+	 62, // 0x99f
+	 62, // 0x9a0
+	 62, // 0x9a1
+	 62, // 0x9a2
+	 62, // 0x9a3
+	 62, // 0x9a4
+	 62, // 0x9a5
+	 62, // 0x9a6
+	 62, // 0x9a7
+	 62, // 0x9a8
+	 62, // 0x9a9
+	 62, // 0x9aa
+	 62, // 0x9ab
+	 62, // 0x9ac
+	 62, // 0x9ad
+	 62, // 0x9ae
+	 62, // 0x9af
+	 62, // 0x9b0
+	 62, // 0x9b1
+	 62, // 0x9b2
+	 62, // 0x9b3
+	 62, // 0x9b4
+	 62, // 0x9b5
+	 62, // 0x9b6
+	 62, // 0x9b7
+	 62, // 0x9b8
+	 62, // 0x9b9
+	 62, // 0x9ba
+	 62, // 0x9bb
+	 62, // 0x9bc
+	 62, // 0x9bd
+	 62, // 0x9be
+	 62, // 0x9bf
+	 62, // 0x9c0
+	 62, // 0x9c1
+	 62, // 0x9c2
+	 62, // 0x9c3
+	 62, // 0x9c4
+	 62, // 0x9c5
+	 62, // 0x9c6
+	 62, // 0x9c7
+	 62, // 0x9c8
+	 62, // 0x9c9
+	 62, // 0x9ca
+	 62, // 0x9cb
+	 62, // 0x9cc
+	 62, // 0x9cd
+	 62, // 0x9ce
+	 62, // 0x9cf
+	 62, // 0x9d0
+	 62, // 0x9d1
+	 62, // 0x9d2
+	 62, // 0x9d3
+	 62, // 0x9d4
+	 62, // 0x9d5
+	 62, // 0x9d6
+	 62, // 0x9d7
+	 62, // 0x9d8
+	 62, // 0x9d9
+	 62, // 0x9da
+	 62, // 0x9db
+	 62, // 0x9dc
+	 62, // 0x9dd
+	 62, // 0x9de
+	 62, // 0x9df
+	 62, // 0x9e0
+	 62, // 0x9e1
+	 62, // 0x9e2
+	 62, // 0x9e3
+	 62, // 0x9e4
+	 62, // 0x9e5
+	 62, // 0x9e6
+	 62, // 0x9e7
+	 62, // 0x9e8
+	 62, // 0x9e9
+	 62, // 0x9ea
+	 62, // 0x9eb
+	 62, // 0x9ec
+	 62, // 0x9ed
+	 62, // 0x9ee
+	 62, // 0x9ef
+	 62, // 0x9f0
+	 62, // 0x9f1
+	 62, // 0x9f2
+	 62, // 0x9f3
+	 62, // 0x9f4
+	 62, // 0x9f5
+	 62, // 0x9f6
+	 62, // 0x9f7
+	 62, // 0x9f8
+	 62, // 0x9f9
+	 62, // 0x9fa
+	 62, // 0x9fb
+	 62, // 0x9fc
+	 62, // 0x9fd
+	 62, // 0x9fe
+	 154, // 0x9ff
+	 62, // 0xa00
+	 62, // 0xa01
+	 62, // 0xa02
+	 62, // 0xa03
+	 62, // 0xa04
+	 62, // 0xa05
+	 62, // 0xa06
+	 62, // 0xa07
+	 62, // 0xa08
+	 62, // 0xa09
+	 62, // 0xa0a
+	 62, // 0xa0b
+	 62, // 0xa0c
+	 62, // 0xa0d
+	 62, // 0xa0e
+	 62, // 0xa0f
+	 62, // 0xa10
+	 62, // 0xa11
+	 62, // 0xa12
+	 62, // 0xa13
+	 62, // 0xa14
+	 62, // 0xa15
+	 62, // 0xa16
+	 62, // 0xa17
+	 62, // 0xa18
+	 62, // 0xa19
+	 62, // 0xa1a
+	 62, // 0xa1b
+	 62, // 0xa1c
+	 62, // 0xa1d
+	 62, // 0xa1e
+	 62, // 0xa1f
+	 62, // 0xa20
+	 62, // 0xa21
+	 62, // 0xa22
+	 62, // 0xa23
+	 62, // 0xa24
+	 62, // 0xa25
+	 62, // 0xa26
+	 62, // 0xa27
+	 62, // 0xa28
+	 62, // 0xa29
+	 62, // 0xa2a
+	 62, // 0xa2b
+	 62, // 0xa2c
+	 62, // 0xa2d
+	 62, // 0xa2e
+	 62, // 0xa2f
+	 62, // 0xa30
+	 62, // 0xa31
+	 62, // 0xa32
+	 62, // 0xa33
+	 62, // 0xa34
+	 62, // 0xa35
+	 62, // 0xa36
+	 62, // 0xa37
+	 62, // 0xa38
+	 62, // 0xa39
+	 62, // 0xa3a
+	 62, // 0xa3b
+	 62, // 0xa3c
+	 62, // 0xa3d
+	 62, // 0xa3e
+	 62, // 0xa3f
+	 62, // 0xa40
+	 62, // 0xa41
+	 62, // 0xa42
+	 62, // 0xa43
+	 62, // 0xa44
+	 62, // 0xa45
+	 62, // 0xa46
+	 62, // 0xa47
+	 62, // 0xa48
+	 62, // 0xa49
+	 62, // 0xa4a
+	 62, // 0xa4b
+	 62, // 0xa4c
+	 62, // 0xa4d
+	 62, // 0xa4e
+	 62, // 0xa4f
+	 62, // 0xa50
+	 62, // 0xa51
+	 62, // 0xa52
+	 62, // 0xa53
+	 62, // 0xa54
+	 62, // 0xa55
+	 62, // 0xa56
+	 62, // 0xa57
+	 62, // 0xa58
+	 62, // 0xa59
+	 62, // 0xa5a
+	 62, // 0xa5b
+	 62, // 0xa5c
+	 62, // 0xa5d
+	 62, // 0xa5e
+	 62, // 0xa5f
+	 62, // 0xa60
+	 62, // 0xa61
+	 62, // 0xa62
+	 62, // 0xa63
+	 62, // 0xa64
+	 62, // 0xa65
+	 62, // 0xa66
+	 62, // 0xa67
+	 62, // 0xa68
+	 62, // 0xa69
+	 62, // 0xa6a
+	 62, // 0xa6b
+	 62, // 0xa6c
+	 62, // 0xa6d
+	 62, // 0xa6e
+	 62, // 0xa6f
+	 62, // 0xa70
+	 62, // 0xa71
+	 62, // 0xa72
+	 62, // 0xa73
+	 62, // 0xa74
+	 62, // 0xa75
+	 62, // 0xa76
+	 62, // 0xa77
+	 62, // 0xa78
+	 62, // 0xa79
+	 62, // 0xa7a
+	 62, // 0xa7b
+	 62, // 0xa7c
+	 62, // 0xa7d
+	 62, // 0xa7e
+	 62, // 0xa7f
+	 62, // 0xa80
+	 62, // 0xa81
+	 62, // 0xa82
+	 62, // 0xa83
+	 62, // 0xa84
+	 62, // 0xa85
+	 62, // 0xa86
+	 62, // 0xa87
+	 62, // 0xa88
+	 62, // 0xa89
+	 62, // 0xa8a
+	 62, // 0xa8b
+	 62, // 0xa8c
+	 62, // 0xa8d
+	 62, // 0xa8e
+	 62, // 0xa8f
+	 62, // 0xa90
+	 62, // 0xa91
+	 62, // 0xa92
+	 62, // 0xa93
+	 62, // 0xa94
+	 62, // 0xa95
+	 62, // 0xa96
+	 62, // 0xa97
+	 62, // 0xa98
+	 62, // 0xa99
+	 62, // 0xa9a
+	 62, // 0xa9b
+	 62, // 0xa9c
+	 62, // 0xa9d
+	 62, // 0xa9e
+	 62, // 0xa9f
+	 62, // 0xaa0
+	 62, // 0xaa1
+	 62, // 0xaa2
+	 62, // 0xaa3
+	 62, // 0xaa4
+	 62, // 0xaa5
+	 62, // 0xaa6
+	 62, // 0xaa7
+	 62, // 0xaa8
+	 62, // 0xaa9
+	 62, // 0xaaa
+	 62, // 0xaab
+	 62, // 0xaac
+	 62, // 0xaad
+	 62, // 0xaae
+	 62, // 0xaaf
+	 62, // 0xab0
+	 62, // 0xab1
+	 62, // 0xab2
+	 62, // 0xab3
+	 62, // 0xab4
+	 62, // 0xab5
+	 62, // 0xab6
+	 62, // 0xab7
+	 62, // 0xab8
+	 62, // 0xab9
+	 62, // 0xaba
+	 62, // 0xabb
+	 62, // 0xabc
+	 62, // 0xabd
+	 62, // 0xabe
+	 62, // 0xabf
+	 62, // 0xac0
+	 62, // 0xac1
+	 62, // 0xac2
+	 62, // 0xac3
+	 62, // 0xac4
+	 62, // 0xac5
+	 62, // 0xac6
+	 62, // 0xac7
+	 62, // 0xac8
+	 62, // 0xac9
+	 62, // 0xaca
+	 62, // 0xacb
+	 62, // 0xacc
+	 62, // 0xacd
+	 62, // 0xace
+	 62, // 0xacf
+	 62, // 0xad0
+	 62, // 0xad1
+	 62, // 0xad2
+	 62, // 0xad3
+	 62, // 0xad4
+	 62, // 0xad5
+	 62, // 0xad6
+	 62, // 0xad7
+	 62, // 0xad8
+	 62, // 0xad9
+	 62, // 0xada
+	 62, // 0xadb
+	 62, // 0xadc
+	 62, // 0xadd
+	 62, // 0xade
+	 62, // 0xadf
+	 62, // 0xae0
+	 62, // 0xae1
+	 62, // 0xae2
+	 62, // 0xae3
+	 62, // 0xae4
+	 62, // 0xae5
+	 62, // 0xae6
+	 62, // 0xae7
+	 62, // 0xae8
+	 62, // 0xae9
+	 62, // 0xaea
+	 62, // 0xaeb
+	 62, // 0xaec
+	 62, // 0xaed
+	 62, // 0xaee
+	 62, // 0xaef
+	 62, // 0xaf0
+	 62, // 0xaf1
+	 62, // 0xaf2
+	 62, // 0xaf3
+	 62, // 0xaf4
+	 62, // 0xaf5
+	 62, // 0xaf6
+	 62, // 0xaf7
+	 62, // 0xaf8
+	 62, // 0xaf9
+	 62, // 0xafa
+	 62, // 0xafb
+	 62, // 0xafc
+	 62, // 0xafd
+	 62, // 0xafe
+	 154, // 0xaff
+	 62, // 0xb00
+	 62, // 0xb01
+	 62, // 0xb02
+	 62, // 0xb03
+	 62, // 0xb04
+	 62, // 0xb05
+	 62, // 0xb06
+	 62, // 0xb07
+	 62, // 0xb08
+	 62, // 0xb09
+	 62, // 0xb0a
+	 62, // 0xb0b
+	 62, // 0xb0c
+	 62, // 0xb0d
+	 62, // 0xb0e
+	 62, // 0xb0f
+	 62, // 0xb10
+	 62, // 0xb11
+	 62, // 0xb12
+	 62, // 0xb13
+	 62, // 0xb14
+	 62, // 0xb15
+	 62, // 0xb16
+	 62, // 0xb17
+	 62, // 0xb18
+	 62, // 0xb19
+	 62, // 0xb1a
+	 62, // 0xb1b
+	 62, // 0xb1c
+	 62, // 0xb1d
+	 62, // 0xb1e
+	 62, // 0xb1f
+	 62, // 0xb20
+	 62, // 0xb21
+	 62, // 0xb22
+	 62, // 0xb23
+	 62, // 0xb24
+	 62, // 0xb25
+	 62, // 0xb26
+	 62, // 0xb27
+	 62, // 0xb28
+	 62, // 0xb29
+	 62, // 0xb2a
+	 62, // 0xb2b
+	 62, // 0xb2c
+	 62, // 0xb2d
+	 62, // 0xb2e
+	 62, // 0xb2f
+	 62, // 0xb30
+	 62, // 0xb31
+	 62, // 0xb32
+	 62, // 0xb33
+	 62, // 0xb34
+	 62, // 0xb35
+	 62, // 0xb36
+	 62, // 0xb37
+	 62, // 0xb38
+	 62, // 0xb39
+	 62, // 0xb3a
+	 62, // 0xb3b
+	 62, // 0xb3c
+	 62, // 0xb3d
+	 62, // 0xb3e
+	 62, // 0xb3f
+	 62, // 0xb40
+	 62, // 0xb41
+	 62, // 0xb42
+	 62, // 0xb43
+	 62, // 0xb44
+	 62, // 0xb45
+	 62, // 0xb46
+	 62, // 0xb47
+	 62, // 0xb48
+	 62, // 0xb49
+	 62, // 0xb4a
+	 62, // 0xb4b
+	 62, // 0xb4c
+	 62, // 0xb4d
+	 62, // 0xb4e
+	 62, // 0xb4f
+	 62, // 0xb50
+	 62, // 0xb51
+	 62, // 0xb52
+	 62, // 0xb53
+	 62, // 0xb54
+	 62, // 0xb55
+	 62, // 0xb56
+	 62, // 0xb57
+	 62, // 0xb58
+	 62, // 0xb59
+	 62, // 0xb5a
+	 62, // 0xb5b
+	 62, // 0xb5c
+	 62, // 0xb5d
+	 62, // 0xb5e
+	 62, // 0xb5f
+	 62, // 0xb60
+	 62, // 0xb61
+	 62, // 0xb62
+	 62, // 0xb63
+	 62, // 0xb64
+	 62, // 0xb65
+	 62, // 0xb66
+	 62, // 0xb67
+	 62, // 0xb68
+	 62, // 0xb69
+	 62, // 0xb6a
+	 62, // 0xb6b
+	 62, // 0xb6c
+	 62, // 0xb6d
+	 62, // 0xb6e
+	 62, // 0xb6f
+	 62, // 0xb70
+	 62, // 0xb71
+	 62, // 0xb72
+	 62, // 0xb73
+	 62, // 0xb74
+	 62, // 0xb75
+	 62, // 0xb76
+	 62, // 0xb77
+	 62, // 0xb78
+	 62, // 0xb79
+	 62, // 0xb7a
+	 62, // 0xb7b
+	 62, // 0xb7c
+	 62, // 0xb7d
+	 62, // 0xb7e
+	 62, // 0xb7f
+	 62, // 0xb80
+	 62, // 0xb81
+	 62, // 0xb82
+	 62, // 0xb83
+	 62, // 0xb84
+	 62, // 0xb85
+	 62, // 0xb86
+	 62, // 0xb87
+	 62, // 0xb88
+	 62, // 0xb89
+	 62, // 0xb8a
+	 62, // 0xb8b
+	 62, // 0xb8c
+	 62, // 0xb8d
+	 62, // 0xb8e
+	 62, // 0xb8f
+	 62, // 0xb90
+	 62, // 0xb91
+	 62, // 0xb92
+	 62, // 0xb93
+	 62, // 0xb94
+	 62, // 0xb95
+	 62, // 0xb96
+	 62, // 0xb97
+	 62, // 0xb98
+	 62, // 0xb99
+	 62, // 0xb9a
+	 62, // 0xb9b
+	 62, // 0xb9c
+	 62, // 0xb9d
+	 62, // 0xb9e
+	 62, // 0xb9f
+	 62, // 0xba0
+	 62, // 0xba1
+	 62, // 0xba2
+	 62, // 0xba3
+	 62, // 0xba4
+	 62, // 0xba5
+	 62, // 0xba6
+	 62, // 0xba7
+	 62, // 0xba8
+	 62, // 0xba9
+	 62, // 0xbaa
+	 62, // 0xbab
+	 62, // 0xbac
+	 62, // 0xbad
+	 62, // 0xbae
+	 62, // 0xbaf
+	 62, // 0xbb0
+	 62, // 0xbb1
+	 62, // 0xbb2
+	 62, // 0xbb3
+	 62, // 0xbb4
+	 62, // 0xbb5
+	 62, // 0xbb6
+	 62, // 0xbb7
+	 62, // 0xbb8
+	 62, // 0xbb9
+	 62, // 0xbba
+	 62, // 0xbbb
+	 62, // 0xbbc
+	 62, // 0xbbd
+	 62, // 0xbbe
+	 62, // 0xbbf
+	 62, // 0xbc0
+	 62, // 0xbc1
+	 62, // 0xbc2
+	 62, // 0xbc3
+	 62, // 0xbc4
+	 62, // 0xbc5
+	 62, // 0xbc6
+	 62, // 0xbc7
+	 62, // 0xbc8
+	 62, // 0xbc9
+	 62, // 0xbca
+	 62, // 0xbcb
+	 62, // 0xbcc
+	 62, // 0xbcd
+	 62, // 0xbce
+	 62, // 0xbcf
+	 62, // 0xbd0
+	 62, // 0xbd1
+	 62, // 0xbd2
+	 62, // 0xbd3
+	 62, // 0xbd4
+	 62, // 0xbd5
+	 62, // 0xbd6
+	 62, // 0xbd7
+	 62, // 0xbd8
+	 62, // 0xbd9
+	 62, // 0xbda
+	 62, // 0xbdb
+	 62, // 0xbdc
+	 62, // 0xbdd
+	 62, // 0xbde
+	 62, // 0xbdf
+	 62, // 0xbe0
+	 62, // 0xbe1
+	 62, // 0xbe2
+	 62, // 0xbe3
+	 62, // 0xbe4
+	 62, // 0xbe5
+	 62, // 0xbe6
+	 62, // 0xbe7
+	 62, // 0xbe8
+	 62, // 0xbe9
+	 62, // 0xbea
+	 62, // 0xbeb
+	 62, // 0xbec
+	 62, // 0xbed
+	 62, // 0xbee
+	 62, // 0xbef
+	 62, // 0xbf0
+	 62, // 0xbf1
+	 62, // 0xbf2
+	 62, // 0xbf3
+	 62, // 0xbf4
+	 62, // 0xbf5
+	 62, // 0xbf6
+	 62, // 0xbf7
+	 62, // 0xbf8
+	 62, // 0xbf9
+	 62, // 0xbfa
+	 62, // 0xbfb
+	 62, // 0xbfc
+	 62, // 0xbfd
+	 62, // 0xbfe
+	 154, // 0xbff
+	 62, // 0xc00
+	 62, // 0xc01
+	 62, // 0xc02
+	 62, // 0xc03
+	 62, // 0xc04
+	 62, // 0xc05
+	 62, // 0xc06
+	 62, // 0xc07
+	 62, // 0xc08
+	 62, // 0xc09
+	 62, // 0xc0a
+	 62, // 0xc0b
+	 62, // 0xc0c
+	 62, // 0xc0d
+	 62, // 0xc0e
+	 62, // 0xc0f
+	 62, // 0xc10
+	 62, // 0xc11
+	 62, // 0xc12
+	 62, // 0xc13
+	 62, // 0xc14
+	 62, // 0xc15
+	 62, // 0xc16
+	 62, // 0xc17
+	 62, // 0xc18
+	 62, // 0xc19
+	 62, // 0xc1a
+	 62, // 0xc1b
+	 62, // 0xc1c
+	 62, // 0xc1d
+	 62, // 0xc1e
+	 62, // 0xc1f
+	 62, // 0xc20
+	 62, // 0xc21
+	 62, // 0xc22
+	 62, // 0xc23
+	 62, // 0xc24
+	 62, // 0xc25
+	 62, // 0xc26
+	 62, // 0xc27
+	 62, // 0xc28
+	 62, // 0xc29
+	 62, // 0xc2a
+	 62, // 0xc2b
+	 62, // 0xc2c
+	 62, // 0xc2d
+	 62, // 0xc2e
+	 62, // 0xc2f
+	 62, // 0xc30
+	 62, // 0xc31
+	 62, // 0xc32
+	 62, // 0xc33
+	 62, // 0xc34
+	 62, // 0xc35
+	 62, // 0xc36
+	 62, // 0xc37
+	 62, // 0xc38
+	 62, // 0xc39
+	 62, // 0xc3a
+	 62, // 0xc3b
+	 62, // 0xc3c
+	 62, // 0xc3d
+	 62, // 0xc3e
+	 62, // 0xc3f
+	 62, // 0xc40
+	 62, // 0xc41
+	 62, // 0xc42
+	 62, // 0xc43
+	 62, // 0xc44
+	 62, // 0xc45
+	 62, // 0xc46
+	 62, // 0xc47
+	 62, // 0xc48
+	 62, // 0xc49
+	 62, // 0xc4a
+	 62, // 0xc4b
+	 62, // 0xc4c
+	 62, // 0xc4d
+	 62, // 0xc4e
+	 62, // 0xc4f
+	 62, // 0xc50
+	 62, // 0xc51
+	 62, // 0xc52
+	 62, // 0xc53
+	 62, // 0xc54
+	 62, // 0xc55
+	 62, // 0xc56
+	 62, // 0xc57
+	 62, // 0xc58
+	 62, // 0xc59
+	 62, // 0xc5a
+	 62, // 0xc5b
+	 62, // 0xc5c
+	 62, // 0xc5d
+	 62, // 0xc5e
+	 62, // 0xc5f
+	 62, // 0xc60
+	 62, // 0xc61
+	 62, // 0xc62
+	 62, // 0xc63
+	 62, // 0xc64
+	 62, // 0xc65
+	 62, // 0xc66
+	 62, // 0xc67
+	 62, // 0xc68
+	 62, // 0xc69
+	 62, // 0xc6a
+	 62, // 0xc6b
+	 62, // 0xc6c
+	 62, // 0xc6d
+	 62, // 0xc6e
+	 62, // 0xc6f
+	 62, // 0xc70
+	 62, // 0xc71
+	 62, // 0xc72
+	 62, // 0xc73
+	 62, // 0xc74
+	 62, // 0xc75
+	 62, // 0xc76
+	 62, // 0xc77
+	 62, // 0xc78
+	 62, // 0xc79
+	 62, // 0xc7a
+	 62, // 0xc7b
+	 62, // 0xc7c
+	 62, // 0xc7d
+	 62, // 0xc7e
+	 62, // 0xc7f
+	 62, // 0xc80
+	 62, // 0xc81
+	 62, // 0xc82
+	 62, // 0xc83
+	 62, // 0xc84
+	 62, // 0xc85
+	 62, // 0xc86
+	 62, // 0xc87
+	 62, // 0xc88
+	 62, // 0xc89
+	 62, // 0xc8a
+	 62, // 0xc8b
+	 62, // 0xc8c
+	 62, // 0xc8d
+	 62, // 0xc8e
+	 62, // 0xc8f
+	 62, // 0xc90
+	 62, // 0xc91
+	 62, // 0xc92
+	 62, // 0xc93
+	 62, // 0xc94
+	 62, // 0xc95
+	 62, // 0xc96
+	 62, // 0xc97
+	 62, // 0xc98
+	 62, // 0xc99
+	 62, // 0xc9a
+	 62, // 0xc9b
+	 62, // 0xc9c
+	 62, // 0xc9d
+	 62, // 0xc9e
+	 62, // 0xc9f
+	 62, // 0xca0
+	 62, // 0xca1
+	 62, // 0xca2
+	 62, // 0xca3
+	 62, // 0xca4
+	 62, // 0xca5
+	 62, // 0xca6
+	 62, // 0xca7
+	 62, // 0xca8
+	 62, // 0xca9
+	 62, // 0xcaa
+	 62, // 0xcab
+	 62, // 0xcac
+	 62, // 0xcad
+	 62, // 0xcae
+	 62, // 0xcaf
+	 62, // 0xcb0
+	 62, // 0xcb1
+	 62, // 0xcb2
+	 62, // 0xcb3
+	 62, // 0xcb4
+	 62, // 0xcb5
+	 62, // 0xcb6
+	 62, // 0xcb7
+	 62, // 0xcb8
+	 62, // 0xcb9
+	 62, // 0xcba
+	 62, // 0xcbb
+	 62, // 0xcbc
+	 62, // 0xcbd
+	 62, // 0xcbe
+	 62, // 0xcbf
+	 62, // 0xcc0
+	 62, // 0xcc1
+	 62, // 0xcc2
+	 62, // 0xcc3
+	 62, // 0xcc4
+	 62, // 0xcc5
+	 62, // 0xcc6
+	 62, // 0xcc7
+	 62, // 0xcc8
+	 62, // 0xcc9
+	 62, // 0xcca
+	 62, // 0xccb
+	 62, // 0xccc
+	 62, // 0xccd
+	 62, // 0xcce
+	 62, // 0xccf
+	 62, // 0xcd0
+	 62, // 0xcd1
+	 62, // 0xcd2
+	 62, // 0xcd3
+	 62, // 0xcd4
+	 62, // 0xcd5
+	 62, // 0xcd6
+	 62, // 0xcd7
+	 62, // 0xcd8
+	 62, // 0xcd9
+	 62, // 0xcda
+	 62, // 0xcdb
+	 62, // 0xcdc
+	 62, // 0xcdd
+	 62, // 0xcde
+	 62, // 0xcdf
+	 62, // 0xce0
+	 62, // 0xce1
+	 62, // 0xce2
+	 62, // 0xce3
+	 62, // 0xce4
+	 62, // 0xce5
+	 62, // 0xce6
+	 62, // 0xce7
+	 62, // 0xce8
+	 62, // 0xce9
+	 62, // 0xcea
+	 62, // 0xceb
+	 62, // 0xcec
+	 62, // 0xced
+	 62, // 0xcee
+	 62, // 0xcef
+	 62, // 0xcf0
+	 62, // 0xcf1
+	 62, // 0xcf2
+	 62, // 0xcf3
+	 62, // 0xcf4
+	 62, // 0xcf5
+	 62, // 0xcf6
+	 62, // 0xcf7
+	 62, // 0xcf8
+	 62, // 0xcf9
+	 62, // 0xcfa
+	 62, // 0xcfb
+	 62, // 0xcfc
+	 62, // 0xcfd
+	 62, // 0xcfe
+	 154, // 0xcff
+	 62, // 0xd00
+	 62, // 0xd01
+	 62, // 0xd02
+	 62, // 0xd03
+	 62, // 0xd04
+	 62, // 0xd05
+	 62, // 0xd06
+	 62, // 0xd07
+	 62, // 0xd08
+	 62, // 0xd09
+	 62, // 0xd0a
+	 62, // 0xd0b
+	 62, // 0xd0c
+	 62, // 0xd0d
+	 62, // 0xd0e
+	 62, // 0xd0f
+	 62, // 0xd10
+	 62, // 0xd11
+	 62, // 0xd12
+	 62, // 0xd13
+	 62, // 0xd14
+	 62, // 0xd15
+	 62, // 0xd16
+	 62, // 0xd17
+	 62, // 0xd18
+	 62, // 0xd19
+	 62, // 0xd1a
+	 62, // 0xd1b
+	 62, // 0xd1c
+	 62, // 0xd1d
+	 62, // 0xd1e
+	 62, // 0xd1f
+	 62, // 0xd20
+	 62, // 0xd21
+	 62, // 0xd22
+	 62, // 0xd23
+	 62, // 0xd24
+	 62, // 0xd25
+	 62, // 0xd26
+	 62, // 0xd27
+	 62, // 0xd28
+	 62, // 0xd29
+	 62, // 0xd2a
+	 62, // 0xd2b
+	 62, // 0xd2c
+	 62, // 0xd2d
+	 62, // 0xd2e
+	 62, // 0xd2f
+	 62, // 0xd30
+	 62, // 0xd31
+	 62, // 0xd32
+	 62, // 0xd33
+	 62, // 0xd34
+	 62, // 0xd35
+	 62, // 0xd36
+	 62, // 0xd37
+	 62, // 0xd38
+	 62, // 0xd39
+	 62, // 0xd3a
+	 62, // 0xd3b
+	 62, // 0xd3c
+	 62, // 0xd3d
+	 62, // 0xd3e
+	 62, // 0xd3f
+	 62, // 0xd40
+	 62, // 0xd41
+	 62, // 0xd42
+	 62, // 0xd43
+	 62, // 0xd44
+	 62, // 0xd45
+	 62, // 0xd46
+	 62, // 0xd47
+	 62, // 0xd48
+	 62, // 0xd49
+	 62, // 0xd4a
+	 62, // 0xd4b
+	 62, // 0xd4c
+	 62, // 0xd4d
+	 62, // 0xd4e
+	 62, // 0xd4f
+	 62, // 0xd50
+	 62, // 0xd51
+	 62, // 0xd52
+	 62, // 0xd53
+	 62, // 0xd54
+	 62, // 0xd55
+	 62, // 0xd56
+	 62, // 0xd57
+	 62, // 0xd58
+	 62, // 0xd59
+	 62, // 0xd5a
+	 62, // 0xd5b
+	 62, // 0xd5c
+	 62, // 0xd5d
+	 62, // 0xd5e
+	 62, // 0xd5f
+	 62, // 0xd60
+	 62, // 0xd61
+	 62, // 0xd62
+	 62, // 0xd63
+	 62, // 0xd64
+	 62, // 0xd65
+	 62, // 0xd66
+	 62, // 0xd67
+	 62, // 0xd68
+	 62, // 0xd69
+	 62, // 0xd6a
+	 62, // 0xd6b
+	 62, // 0xd6c
+	 62, // 0xd6d
+	 62, // 0xd6e
+	 62, // 0xd6f
+	 62, // 0xd70
+	 62, // 0xd71
+	 62, // 0xd72
+	 62, // 0xd73
+	 62, // 0xd74
+	 62, // 0xd75
+	 62, // 0xd76
+	 62, // 0xd77
+	 62, // 0xd78
+	 62, // 0xd79
+	 62, // 0xd7a
+	 62, // 0xd7b
+	 62, // 0xd7c
+	 62, // 0xd7d
+	 62, // 0xd7e
+	 62, // 0xd7f
+	 62, // 0xd80
+	 62, // 0xd81
+	 62, // 0xd82
+	 62, // 0xd83
+	 62, // 0xd84
+	 62, // 0xd85
+	 62, // 0xd86
+	 62, // 0xd87
+	 62, // 0xd88
+	 62, // 0xd89
+	 62, // 0xd8a
+	 62, // 0xd8b
+	 62, // 0xd8c
+	 62, // 0xd8d
+	 62, // 0xd8e
+	 62, // 0xd8f
+	 62, // 0xd90
+	 62, // 0xd91
+	 62, // 0xd92
+	 62, // 0xd93
+	 62, // 0xd94
+	 62, // 0xd95
+	 62, // 0xd96
+	 62, // 0xd97
+	 62, // 0xd98
+	 62, // 0xd99
+	 62, // 0xd9a
+	 62, // 0xd9b
+	 62, // 0xd9c
+	 62, // 0xd9d
+	 62, // 0xd9e
+	 62, // 0xd9f
+	 62, // 0xda0
+	 62, // 0xda1
+	 62, // 0xda2
+	 62, // 0xda3
+	 62, // 0xda4
+	 62, // 0xda5
+	 62, // 0xda6
+	 62, // 0xda7
+	 62, // 0xda8
+	 62, // 0xda9
+	 62, // 0xdaa
+	 62, // 0xdab
+	 62, // 0xdac
+	 62, // 0xdad
+	 62, // 0xdae
+	 62, // 0xdaf
+	 62, // 0xdb0
+	 62, // 0xdb1
+	 62, // 0xdb2
+	 62, // 0xdb3
+	 62, // 0xdb4
+	 62, // 0xdb5
+	 62, // 0xdb6
+	 62, // 0xdb7
+	 62, // 0xdb8
+	 62, // 0xdb9
+	 62, // 0xdba
+	 62, // 0xdbb
+	 62, // 0xdbc
+	 62, // 0xdbd
+	 62, // 0xdbe
+	 62, // 0xdbf
+	 62, // 0xdc0
+	 62, // 0xdc1
+	 62, // 0xdc2
+	 62, // 0xdc3
+	 62, // 0xdc4
+	 62, // 0xdc5
+	 62, // 0xdc6
+	 62, // 0xdc7
+	 62, // 0xdc8
+	 62, // 0xdc9
+	 62, // 0xdca
+	 62, // 0xdcb
+	 62, // 0xdcc
+	 62, // 0xdcd
+	 62, // 0xdce
+	 62, // 0xdcf
+	 62, // 0xdd0
+	 62, // 0xdd1
+	 62, // 0xdd2
+	 62, // 0xdd3
+	 62, // 0xdd4
+	 62, // 0xdd5
+	 62, // 0xdd6
+	 62, // 0xdd7
+	 62, // 0xdd8
+	 62, // 0xdd9
+	 62, // 0xdda
+	 62, // 0xddb
+	 62, // 0xddc
+	 62, // 0xddd
+	 62, // 0xdde
+	 62, // 0xddf
+	 62, // 0xde0
+	 62, // 0xde1
+	 62, // 0xde2
+	 62, // 0xde3
+	 62, // 0xde4
+	 62, // 0xde5
+	 62, // 0xde6
+	 62, // 0xde7
+	 62, // 0xde8
+	 62, // 0xde9
+	 62, // 0xdea
+	 62, // 0xdeb
+	 62, // 0xdec
+	 62, // 0xded
+	 62, // 0xdee
+	 62, // 0xdef
+	 62, // 0xdf0
+	 62, // 0xdf1
+	 62, // 0xdf2
+	 62, // 0xdf3
+	 62, // 0xdf4
+	 62, // 0xdf5
+	 62, // 0xdf6
+	 62, // 0xdf7
+	 62, // 0xdf8
+	 62, // 0xdf9
+	 62, // 0xdfa
+	 62, // 0xdfb
+	 62, // 0xdfc
+	 62, // 0xdfd
+	 62, // 0xdfe
+	 154, // 0xdff
+	 164, // 0xe00
+	 165, // 0xe01
+	 166, // 0xe02
+	 166, // 0xe03
+	 166, // 0xe04
+	 166, // 0xe05
+	 166, // 0xe06
+	 166, // 0xe07
+	 166, // 0xe08
+	 166, // 0xe09
+	 166, // 0xe0a
+	 166, // 0xe0b
+	 166, // 0xe0c
+	 166, // 0xe0d
+	 166, // 0xe0e
+	 166, // 0xe0f
+	 62, // 0xe10
+	 62, // 0xe11
+	 62, // 0xe12
+	 62, // 0xe13
+	 62, // 0xe14
+	 62, // 0xe15
+	 62, // 0xe16
+	 62, // 0xe17
+	 62, // 0xe18
+	 62, // 0xe19
+	 62, // 0xe1a
+	 62, // 0xe1b
+	 62, // 0xe1c
+	 62, // 0xe1d
+	 62, // 0xe1e
+	 62, // 0xe1f
+	 62, // 0xe20
+	 62, // 0xe21
+	 62, // 0xe22
+	 62, // 0xe23
+	 62, // 0xe24
+	 62, // 0xe25
+	 62, // 0xe26
+	 62, // 0xe27
+	 62, // 0xe28
+	 62, // 0xe29
+	 62, // 0xe2a
+	 62, // 0xe2b
+	 62, // 0xe2c
+	 62, // 0xe2d
+	 62, // 0xe2e
+	 62, // 0xe2f
+	 62, // 0xe30
+	 62, // 0xe31
+	 62, // 0xe32
+	 62, // 0xe33
+	 62, // 0xe34
+	 62, // 0xe35
+	 62, // 0xe36
+	 62, // 0xe37
+	 62, // 0xe38
+	 62, // 0xe39
+	 62, // 0xe3a
+	 62, // 0xe3b
+	 62, // 0xe3c
+	 62, // 0xe3d
+	 62, // 0xe3e
+	 62, // 0xe3f
+	 62, // 0xe40
+	 62, // 0xe41
+	 62, // 0xe42
+	 62, // 0xe43
+	 62, // 0xe44
+	 62, // 0xe45
+	 62, // 0xe46
+	 62, // 0xe47
+	 62, // 0xe48
+	 62, // 0xe49
+	 62, // 0xe4a
+	 62, // 0xe4b
+	 62, // 0xe4c
+	 62, // 0xe4d
+	 62, // 0xe4e
+	 62, // 0xe4f
+	 62, // 0xe50
+	 62, // 0xe51
+	 62, // 0xe52
+	 62, // 0xe53
+	 62, // 0xe54
+	 62, // 0xe55
+	 62, // 0xe56
+	 62, // 0xe57
+	 62, // 0xe58
+	 62, // 0xe59
+	 62, // 0xe5a
+	 62, // 0xe5b
+	 62, // 0xe5c
+	 62, // 0xe5d
+	 62, // 0xe5e
+	 62, // 0xe5f
+	 62, // 0xe60
+	 62, // 0xe61
+	 62, // 0xe62
+	 62, // 0xe63
+	 62, // 0xe64
+	 62, // 0xe65
+	 62, // 0xe66
+	 62, // 0xe67
+	 62, // 0xe68
+	 62, // 0xe69
+	 62, // 0xe6a
+	 62, // 0xe6b
+	 62, // 0xe6c
+	 62, // 0xe6d
+	 62, // 0xe6e
+	 62, // 0xe6f
+	 62, // 0xe70
+	 62, // 0xe71
+	 62, // 0xe72
+	 62, // 0xe73
+	 62, // 0xe74
+	 62, // 0xe75
+	 62, // 0xe76
+	 62, // 0xe77
+	 62, // 0xe78
+	 62, // 0xe79
+	 62, // 0xe7a
+	 62, // 0xe7b
+	 62, // 0xe7c
+	 62, // 0xe7d
+	 62, // 0xe7e
+	 62, // 0xe7f
+	 62, // 0xe80
+	 62, // 0xe81
+	 62, // 0xe82
+	 62, // 0xe83
+	 62, // 0xe84
+	 62, // 0xe85
+	 62, // 0xe86
+	 62, // 0xe87
+	 62, // 0xe88
+	 62, // 0xe89
+	 62, // 0xe8a
+	 62, // 0xe8b
+	 62, // 0xe8c
+	 62, // 0xe8d
+	 62, // 0xe8e
+	 62, // 0xe8f
+	 62, // 0xe90
+	 62, // 0xe91
+	 62, // 0xe92
+	 62, // 0xe93
+	 62, // 0xe94
+	 62, // 0xe95
+	 62, // 0xe96
+	 62, // 0xe97
+	 62, // 0xe98
+	 62, // 0xe99
+	 62, // 0xe9a
+	 62, // 0xe9b
+	 62, // 0xe9c
+	 62, // 0xe9d
+	 62, // 0xe9e
+	 62, // 0xe9f
+	 62, // 0xea0
+	 62, // 0xea1
+	 62, // 0xea2
+	 62, // 0xea3
+	 62, // 0xea4
+	 62, // 0xea5
+	 62, // 0xea6
+	 62, // 0xea7
+	 62, // 0xea8
+	 62, // 0xea9
+	 62, // 0xeaa
+	 62, // 0xeab
+	 62, // 0xeac
+	 62, // 0xead
+	 62, // 0xeae
+	 62, // 0xeaf
+	 62, // 0xeb0
+	 62, // 0xeb1
+	 62, // 0xeb2
+	 62, // 0xeb3
+	 62, // 0xeb4
+	 62, // 0xeb5
+	 62, // 0xeb6
+	 62, // 0xeb7
+	 62, // 0xeb8
+	 62, // 0xeb9
+	 62, // 0xeba
+	 62, // 0xebb
+	 62, // 0xebc
+	 62, // 0xebd
+	 62, // 0xebe
+	 62, // 0xebf
+	 62, // 0xec0
+	 62, // 0xec1
+	 62, // 0xec2
+	 62, // 0xec3
+	 62, // 0xec4
+	 62, // 0xec5
+	 62, // 0xec6
+	 62, // 0xec7
+	 62, // 0xec8
+	 62, // 0xec9
+	 62, // 0xeca
+	 62, // 0xecb
+	 62, // 0xecc
+	 62, // 0xecd
+	 62, // 0xece
+	 62, // 0xecf
+	 62, // 0xed0
+	 62, // 0xed1
+	 62, // 0xed2
+	 62, // 0xed3
+	 62, // 0xed4
+	 62, // 0xed5
+	 62, // 0xed6
+	 62, // 0xed7
+	 62, // 0xed8
+	 62, // 0xed9
+	 62, // 0xeda
+	 62, // 0xedb
+	 62, // 0xedc
+	 62, // 0xedd
+	 62, // 0xede
+	 62, // 0xedf
+	 62, // 0xee0
+	 62, // 0xee1
+	 62, // 0xee2
+	 62, // 0xee3
+	 62, // 0xee4
+	 62, // 0xee5
+	 62, // 0xee6
+	 62, // 0xee7
+	 62, // 0xee8
+	 62, // 0xee9
+	 62, // 0xeea
+	 62, // 0xeeb
+	 62, // 0xeec
+	 62, // 0xeed
+	 62, // 0xeee
+	 62, // 0xeef
+	 62, // 0xef0
+	 62, // 0xef1
+	 62, // 0xef2
+	 62, // 0xef3
+	 62, // 0xef4
+	 62, // 0xef5
+	 62, // 0xef6
+	 62, // 0xef7
+	 62, // 0xef8
+	 62, // 0xef9
+	 62, // 0xefa
+	 62, // 0xefb
+	 62, // 0xefc
+	 62, // 0xefd
+	 62, // 0xefe
+	 154, // 0xeff
+	 62, // 0xf00
+	 62, // 0xf01
+	 62, // 0xf02
+	 62, // 0xf03
+	 62, // 0xf04
+	 62, // 0xf05
+	 62, // 0xf06
+	 62, // 0xf07
+	 62, // 0xf08
+	 62, // 0xf09
+	 62, // 0xf0a
+	 62, // 0xf0b
+	 62, // 0xf0c
+	 62, // 0xf0d
+	 62, // 0xf0e
+	 62, // 0xf0f
+	 62, // 0xf10
+	 62, // 0xf11
+	 62, // 0xf12
+	 62, // 0xf13
+	 62, // 0xf14
+	 62, // 0xf15
+	 62, // 0xf16
+	 62, // 0xf17
+	 62, // 0xf18
+	 62, // 0xf19
+	 62, // 0xf1a
+	 62, // 0xf1b
+	 62, // 0xf1c
+	 62, // 0xf1d
+	 62, // 0xf1e
+	 62, // 0xf1f
+	 62, // 0xf20
+	 62, // 0xf21
+	 62, // 0xf22
+	 62, // 0xf23
+	 62, // 0xf24
+	 62, // 0xf25
+	 62, // 0xf26
+	 62, // 0xf27
+	 62, // 0xf28
+	 62, // 0xf29
+	 62, // 0xf2a
+	 62, // 0xf2b
+	 62, // 0xf2c
+	 62, // 0xf2d
+	 62, // 0xf2e
+	 62, // 0xf2f
+	 62, // 0xf30
+	 62, // 0xf31
+	 62, // 0xf32
+	 62, // 0xf33
+	 62, // 0xf34
+	 62, // 0xf35
+	 62, // 0xf36
+	 62, // 0xf37
+	 62, // 0xf38
+	 62, // 0xf39
+	 62, // 0xf3a
+	 62, // 0xf3b
+	 62, // 0xf3c
+	 62, // 0xf3d
+	 62, // 0xf3e
+	 62, // 0xf3f
+	 62, // 0xf40
+	 62, // 0xf41
+	 62, // 0xf42
+	 62, // 0xf43
+	 62, // 0xf44
+	 62, // 0xf45
+	 62, // 0xf46
+	 62, // 0xf47
+	 62, // 0xf48
+	 62, // 0xf49
+	 62, // 0xf4a
+	 62, // 0xf4b
+	 62, // 0xf4c
+	 62, // 0xf4d
+	 62, // 0xf4e
+	 62, // 0xf4f
+	 62, // 0xf50
+	 62, // 0xf51
+	 62, // 0xf52
+	 62, // 0xf53
+	 62, // 0xf54
+	 62, // 0xf55
+	 62, // 0xf56
+	 62, // 0xf57
+	 62, // 0xf58
+	 62, // 0xf59
+	 62, // 0xf5a
+	 62, // 0xf5b
+	 62, // 0xf5c
+	 62, // 0xf5d
+	 62, // 0xf5e
+	 62, // 0xf5f
+	 62, // 0xf60
+	 62, // 0xf61
+	 62, // 0xf62
+	 62, // 0xf63
+	 62, // 0xf64
+	 62, // 0xf65
+	 62, // 0xf66
+	 62, // 0xf67
+	 62, // 0xf68
+	 62, // 0xf69
+	 62, // 0xf6a
+	 62, // 0xf6b
+	 62, // 0xf6c
+	 62, // 0xf6d
+	 62, // 0xf6e
+	 62, // 0xf6f
+	 62, // 0xf70
+	 62, // 0xf71
+	 62, // 0xf72
+	 62, // 0xf73
+	 62, // 0xf74
+	 62, // 0xf75
+	 62, // 0xf76
+	 62, // 0xf77
+	 62, // 0xf78
+	 62, // 0xf79
+	 62, // 0xf7a
+	 62, // 0xf7b
+	 62, // 0xf7c
+	 62, // 0xf7d
+	 62, // 0xf7e
+	 62, // 0xf7f
+	 62, // 0xf80
+	 62, // 0xf81
+	 62, // 0xf82
+	 62, // 0xf83
+	 62, // 0xf84
+	 62, // 0xf85
+	 62, // 0xf86
+	 62, // 0xf87
+	 62, // 0xf88
+	 62, // 0xf89
+	 62, // 0xf8a
+	 62, // 0xf8b
+	 62, // 0xf8c
+	 62, // 0xf8d
+	 62, // 0xf8e
+	 62, // 0xf8f
+	 62, // 0xf90
+	 62, // 0xf91
+	 62, // 0xf92
+	 62, // 0xf93
+	 62, // 0xf94
+	 62, // 0xf95
+	 62, // 0xf96
+	 62, // 0xf97
+	 62, // 0xf98
+	 62, // 0xf99
+	 62, // 0xf9a
+	 62, // 0xf9b
+	 62, // 0xf9c
+	 62, // 0xf9d
+	 62, // 0xf9e
+	 62, // 0xf9f
+	 62, // 0xfa0
+	 62, // 0xfa1
+	 62, // 0xfa2
+	 62, // 0xfa3
+	 62, // 0xfa4
+	 62, // 0xfa5
+	 62, // 0xfa6
+	 62, // 0xfa7
+	 62, // 0xfa8
+	 62, // 0xfa9
+	 62, // 0xfaa
+	 62, // 0xfab
+	 62, // 0xfac
+	 62, // 0xfad
+	 62, // 0xfae
+	 62, // 0xfaf
+	 62, // 0xfb0
+	 62, // 0xfb1
+	 62, // 0xfb2
+	 62, // 0xfb3
+	 62, // 0xfb4
+	 62, // 0xfb5
+	 62, // 0xfb6
+	 62, // 0xfb7
+	 62, // 0xfb8
+	 62, // 0xfb9
+	 62, // 0xfba
+	 62, // 0xfbb
+	 62, // 0xfbc
+	 62, // 0xfbd
+	 62, // 0xfbe
+	 62, // 0xfbf
+	 62, // 0xfc0
+	 62, // 0xfc1
+	 62, // 0xfc2
+	 62, // 0xfc3
+	 62, // 0xfc4
+	 62, // 0xfc5
+	 62, // 0xfc6
+	 62, // 0xfc7
+	 62, // 0xfc8
+	 62, // 0xfc9
+	 62, // 0xfca
+	 62, // 0xfcb
+	 62, // 0xfcc
+	 62, // 0xfcd
+	 62, // 0xfce
+	 62, // 0xfcf
+	 62, // 0xfd0
+	 62, // 0xfd1
+	 62, // 0xfd2
+	 62, // 0xfd3
+	 62, // 0xfd4
+	 62, // 0xfd5
+	 62, // 0xfd6
+	 62, // 0xfd7
+	 62, // 0xfd8
+	 62, // 0xfd9
+	 62, // 0xfda
+	 62, // 0xfdb
+	 62, // 0xfdc
+	 62, // 0xfdd
+	 62, // 0xfde
+	 62, // 0xfdf
+	 62, // 0xfe0
+	 62, // 0xfe1
+	 62, // 0xfe2
+	 62, // 0xfe3
+	 62, // 0xfe4
+	 62, // 0xfe5
+	 62, // 0xfe6
+	 62, // 0xfe7
+	 62, // 0xfe8
+	 62, // 0xfe9
+	 62, // 0xfea
+	 62, // 0xfeb
+	 62, // 0xfec
+	 62, // 0xfed
+	 62, // 0xfee
+	 62, // 0xfef
+	 62, // 0xff0
+	 62, // 0xff1
+	 62, // 0xff2
+	 62, // 0xff3
+	 62, // 0xff4
+	 62, // 0xff5
+	 62, // 0xff6
+	 62, // 0xff7
+	 62, // 0xff8
+	 62, // 0xff9
+	 62, // 0xffa
+	 62, // 0xffb
+	 62, // 0xffc
+	 62, // 0xffd
+	 62, // 0xffe
+	 154, // 0xfff
+	 62, // 0x1000
+	 62, // 0x1001
+	 62, // 0x1002
+	 62, // 0x1003
+	 62, // 0x1004
+	 62, // 0x1005
+	 62, // 0x1006
+	 62, // 0x1007
+	 62, // 0x1008
+	 62, // 0x1009
+	 62, // 0x100a
+	 62, // 0x100b
+	 62, // 0x100c
+	 62, // 0x100d
+	 62, // 0x100e
+	 62, // 0x100f
+	 62, // 0x1010
+	 62, // 0x1011
+	 62, // 0x1012
+	 62, // 0x1013
+	 62, // 0x1014
+	 62, // 0x1015
+	 62, // 0x1016
+	 62, // 0x1017
+	 62, // 0x1018
+	 62, // 0x1019
+	 62, // 0x101a
+	 62, // 0x101b
+	 62, // 0x101c
+	 62, // 0x101d
+	 62, // 0x101e
+	 62, // 0x101f
+	 62, // 0x1020
+	 62, // 0x1021
+	 62, // 0x1022
+	 62, // 0x1023
+	 62, // 0x1024
+	 62, // 0x1025
+	 62, // 0x1026
+	 62, // 0x1027
+	 62, // 0x1028
+	 62, // 0x1029
+	 62, // 0x102a
+	 62, // 0x102b
+	 62, // 0x102c
+	 62, // 0x102d
+	 62, // 0x102e
+	 62, // 0x102f
+	 62, // 0x1030
+	 62, // 0x1031
+	 62, // 0x1032
+	 62, // 0x1033
+	 62, // 0x1034
+	 62, // 0x1035
+	 62, // 0x1036
+	 62, // 0x1037
+	 62, // 0x1038
+	 62, // 0x1039
+	 62, // 0x103a
+	 62, // 0x103b
+	 62, // 0x103c
+	 62, // 0x103d
+	 62, // 0x103e
+	 62, // 0x103f
+	 62, // 0x1040
+	 62, // 0x1041
+	 62, // 0x1042
+	 62, // 0x1043
+	 62, // 0x1044
+	 62, // 0x1045
+	 62, // 0x1046
+	 62, // 0x1047
+	 62, // 0x1048
+	 62, // 0x1049
+	 62, // 0x104a
+	 62, // 0x104b
+	 62, // 0x104c
+	 62, // 0x104d
+	 62, // 0x104e
+	 62, // 0x104f
+	 62, // 0x1050
+	 62, // 0x1051
+	 62, // 0x1052
+	 62, // 0x1053
+	 62, // 0x1054
+	 62, // 0x1055
+	 62, // 0x1056
+	 62, // 0x1057
+	 62, // 0x1058
+	 62, // 0x1059
+	 62, // 0x105a
+	 62, // 0x105b
+	 62, // 0x105c
+	 62, // 0x105d
+	 62, // 0x105e
+	 62, // 0x105f
+	 62, // 0x1060
+	 62, // 0x1061
+	 62, // 0x1062
+	 62, // 0x1063
+	 62, // 0x1064
+	 62, // 0x1065
+	 62, // 0x1066
+	 62, // 0x1067
+	 62, // 0x1068
+	 62, // 0x1069
+	 62, // 0x106a
+	 62, // 0x106b
+	 62, // 0x106c
+	 62, // 0x106d
+	 62, // 0x106e
+	 62, // 0x106f
+	 62, // 0x1070
+	 62, // 0x1071
+	 62, // 0x1072
+	 62, // 0x1073
+	 62, // 0x1074
+	 62, // 0x1075
+	 62, // 0x1076
+	 62, // 0x1077
+	 62, // 0x1078
+	 62, // 0x1079
+	 62, // 0x107a
+	 62, // 0x107b
+	 62, // 0x107c
+	 62, // 0x107d
+	 62, // 0x107e
+	 62, // 0x107f
+	 62, // 0x1080
+	 62, // 0x1081
+	 62, // 0x1082
+	 62, // 0x1083
+	 62, // 0x1084
+	 62, // 0x1085
+	 62, // 0x1086
+	 62, // 0x1087
+	 62, // 0x1088
+	 62, // 0x1089
+	 62, // 0x108a
+	 62, // 0x108b
+	 62, // 0x108c
+	 62, // 0x108d
+	 62, // 0x108e
+	 62, // 0x108f
+	 62, // 0x1090
+	 62, // 0x1091
+	 62, // 0x1092
+	 62, // 0x1093
+	 62, // 0x1094
+	 62, // 0x1095
+	 62, // 0x1096
+	 62, // 0x1097
+	 62, // 0x1098
+	 62, // 0x1099
+	 62, // 0x109a
+	 62, // 0x109b
+	 62, // 0x109c
+	 62, // 0x109d
+	 62, // 0x109e
+	 62, // 0x109f
+	 62, // 0x10a0
+	 62, // 0x10a1
+	 62, // 0x10a2
+	 62, // 0x10a3
+	 62, // 0x10a4
+	 62, // 0x10a5
+	 62, // 0x10a6
+	 62, // 0x10a7
+	 62, // 0x10a8
+	 62, // 0x10a9
+	 62, // 0x10aa
+	 62, // 0x10ab
+	 62, // 0x10ac
+	 62, // 0x10ad
+	 62, // 0x10ae
+	 62, // 0x10af
+	 62, // 0x10b0
+	 62, // 0x10b1
+	 62, // 0x10b2
+	 62, // 0x10b3
+	 62, // 0x10b4
+	 62, // 0x10b5
+	 62, // 0x10b6
+	 62, // 0x10b7
+	 62, // 0x10b8
+	 62, // 0x10b9
+	 62, // 0x10ba
+	 62, // 0x10bb
+	 62, // 0x10bc
+	 62, // 0x10bd
+	 62, // 0x10be
+	 62, // 0x10bf
+	 62, // 0x10c0
+	 62, // 0x10c1
+	 62, // 0x10c2
+	 62, // 0x10c3
+	 62, // 0x10c4
+	 62, // 0x10c5
+	 62, // 0x10c6
+	 62, // 0x10c7
+	 62, // 0x10c8
+	 62, // 0x10c9
+	 62, // 0x10ca
+	 62, // 0x10cb
+	 62, // 0x10cc
+	 62, // 0x10cd
+	 62, // 0x10ce
+	 62, // 0x10cf
+	 62, // 0x10d0
+	 62, // 0x10d1
+	 62, // 0x10d2
+	 62, // 0x10d3
+	 62, // 0x10d4
+	 62, // 0x10d5
+	 62, // 0x10d6
+	 62, // 0x10d7
+	 62, // 0x10d8
+	 62, // 0x10d9
+	 62, // 0x10da
+	 62, // 0x10db
+	 62, // 0x10dc
+	 62, // 0x10dd
+	 62, // 0x10de
+	 62, // 0x10df
+	 62, // 0x10e0
+	 62, // 0x10e1
+	 62, // 0x10e2
+	 62, // 0x10e3
+	 62, // 0x10e4
+	 62, // 0x10e5
+	 62, // 0x10e6
+	 62, // 0x10e7
+	 62, // 0x10e8
+	 62, // 0x10e9
+	 62, // 0x10ea
+	 62, // 0x10eb
+	 62, // 0x10ec
+	 62, // 0x10ed
+	 62, // 0x10ee
+	 62, // 0x10ef
+	 62, // 0x10f0
+	 62, // 0x10f1
+	 62, // 0x10f2
+	 62, // 0x10f3
+	 62, // 0x10f4
+	 62, // 0x10f5
+	 62, // 0x10f6
+	 62, // 0x10f7
+	 62, // 0x10f8
+	 62, // 0x10f9
+	 62, // 0x10fa
+	 62, // 0x10fb
+	 62, // 0x10fc
+	 62, // 0x10fd
+	 62, // 0x10fe
+	 154, // 0x10ff
+];
+pub const BP_PAGES: [[u8;256];167] = [
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x3,
+        0x4,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x6,
+        0x5,
+        0x5,
+        0x5,
+        0x7,
+        0x8,
+        0x9,
+        0xa,
+        0x5,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xc,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x3,
+        0x5,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x5,
+        0x5,
+        0x5,
+        0xf,
+        0x10,
+        0x11,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x13,
+        0x13,
+        0x14,
+        0x14,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x5,
+        0x7,
+        0x5,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x15,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x16,
+        0x5,
+        0x17,
+        0x4,
+        0x7,
+        0x18,
+        0x5,
+        0x16,
+        0x5,
+        0x7,
+        0x19,
+        0x19,
+        0x16,
+        0x1a,
+        0x5,
+        0x1b,
+        0x16,
+        0x19,
+        0x17,
+        0x4,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x5,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x7,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x1a,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x7,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+    ],
+    [
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x14,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0x17,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0x1d,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0x1a,
+        0x13,
+        0xe,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0xe,
+        0x13,
+        0xe,
+        0xe,
+        0xe,
+        0x13,
+        0x17,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x13,
+        0xe,
+        0xe,
+        0x13,
+        0xe,
+        0xe,
+        0xe,
+        0x13,
+        0x13,
+        0x13,
+        0xe,
+        0xe,
+        0x13,
+        0xe,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0xe,
+        0x13,
+        0xe,
+        0x17,
+        0x17,
+        0xe,
+        0x13,
+        0xe,
+        0xe,
+        0x13,
+        0xe,
+        0xe,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0xe,
+        0x13,
+        0x17,
+        0x1e,
+        0xe,
+        0x13,
+        0x17,
+        0x13,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1f,
+        0x20,
+        0x13,
+        0x1f,
+        0x20,
+        0x13,
+        0x1f,
+        0x20,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0x13,
+        0x1f,
+        0x20,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0xe,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+    ],
+    [
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x17,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0xe,
+        0xe,
+        0x13,
+        0xe,
+        0xe,
+        0x13,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x13,
+        0xe,
+        0x14,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x17,
+        0x13,
+        0x13,
+        0x17,
+        0x13,
+        0x17,
+        0x13,
+        0x13,
+        0x17,
+        0x17,
+        0x17,
+        0x13,
+        0x13,
+        0x17,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x17,
+        0x14,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x17,
+        0x17,
+        0x13,
+        0x17,
+        0x13,
+        0x13,
+        0x17,
+        0x17,
+        0x13,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x13,
+        0x17,
+        0x17,
+        0x13,
+        0x17,
+        0x13,
+        0x13,
+        0x17,
+        0x17,
+        0x17,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x13,
+        0x17,
+        0x1e,
+        0x1e,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x14,
+        0x13,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x21,
+        0x21,
+        0x22,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x21,
+        0x21,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x24,
+        0x24,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x23,
+        0x16,
+        0x23,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+    ],
+    [
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x26,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x27,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0x23,
+        0x16,
+        0xe,
+        0x13,
+        0x0,
+        0x0,
+        0x2a,
+        0x13,
+        0x13,
+        0x13,
+        0x2b,
+        0xe,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x16,
+        0x16,
+        0xe,
+        0x2c,
+        0xe,
+        0xe,
+        0xe,
+        0x0,
+        0xe,
+        0x0,
+        0xe,
+        0xe,
+        0x13,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x0,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x1a,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0xe,
+        0x2d,
+        0x2d,
+        0x2e,
+        0x2f,
+        0x2f,
+        0x2d,
+        0x1a,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0x2d,
+        0x2d,
+        0x13,
+        0x14,
+        0x30,
+        0x2d,
+        0x31,
+        0xe,
+        0x13,
+        0xe,
+        0xe,
+        0x13,
+        0x17,
+        0xe,
+        0xe,
+        0xe,
+    ],
+    [
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x14,
+        0x13,
+        0x14,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0x1c,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x32,
+        0x32,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+    ],
+    [
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0x0,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x0,
+        0x0,
+        0x23,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x33,
+        0x17,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x1a,
+        0x17,
+        0x34,
+        0x35,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x37,
+        0x36,
+        0x1c,
+        0x36,
+        0x36,
+        0x2b,
+        0x36,
+        0x36,
+        0x1c,
+        0x36,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1c,
+        0x33,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x31,
+        0x31,
+        0x31,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x2b,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x2b,
+        0x39,
+        0x34,
+        0x34,
+        0x34,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x3a,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x29,
+        0x3b,
+        0x3b,
+        0x29,
+        0x36,
+        0x3c,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1e,
+        0x1e,
+        0x29,
+        0x1e,
+        0x1e,
+        0x3d,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x34,
+        0x1e,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x3b,
+        0x38,
+        0x1c,
+        0x25,
+        0x25,
+        0x29,
+        0x29,
+        0x3b,
+        0x29,
+        0x23,
+        0x23,
+        0x3b,
+        0x3b,
+        0x1c,
+        0x25,
+        0x25,
+        0x25,
+        0x29,
+        0x1e,
+        0x1e,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1c,
+        0x1c,
+        0x1e,
+    ],
+    [
+        0x34,
+        0x34,
+        0x34,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x1c,
+        0x2b,
+        0x1c,
+        0x0,
+        0x38,
+        0x1e,
+        0x29,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x23,
+        0x23,
+        0x1c,
+        0x1c,
+        0x2b,
+        0x34,
+        0x3a,
+        0x0,
+        0x0,
+        0x28,
+        0x1c,
+        0x1c,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x29,
+        0x29,
+        0x25,
+        0x25,
+        0x3e,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x3e,
+        0x29,
+        0x29,
+        0x29,
+        0x3e,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x28,
+        0x0,
+        0x0,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x1c,
+        0x34,
+        0x2b,
+        0x34,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x34,
+        0x34,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x28,
+        0x28,
+        0x28,
+        0x0,
+        0x0,
+        0x2b,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x33,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x38,
+        0x38,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x29,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x23,
+        0x3c,
+        0x3c,
+        0x25,
+        0x3c,
+        0x3c,
+        0x3c,
+        0x25,
+        0x25,
+        0x25,
+        0x3f,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x28,
+        0x28,
+        0x38,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x36,
+        0x36,
+        0x36,
+        0x40,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x29,
+    ],
+    [
+        0x29,
+        0x29,
+        0x29,
+        0x41,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x29,
+        0x41,
+        0x25,
+        0x1e,
+        0x41,
+        0x41,
+        0x41,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x25,
+        0x41,
+        0x41,
+        0x1e,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x29,
+        0x29,
+        0x29,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x29,
+        0x29,
+        0x34,
+        0x34,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x1c,
+        0x23,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x29,
+        0x41,
+        0x41,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x25,
+        0x1e,
+        0x42,
+        0x41,
+        0x41,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x0,
+        0x0,
+        0x41,
+        0x41,
+        0x0,
+        0x0,
+        0x41,
+        0x41,
+        0x25,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x42,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x29,
+        0x29,
+        0x0,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x1e,
+        0x1e,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1e,
+        0x1c,
+        0x28,
+        0x0,
+    ],
+    [
+        0x0,
+        0x29,
+        0x29,
+        0x41,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x25,
+        0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x29,
+        0x29,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x29,
+        0x29,
+        0x0,
+        0x0,
+        0x29,
+        0x29,
+        0x25,
+        0x0,
+        0x0,
+        0x0,
+        0x29,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x29,
+        0x43,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x29,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x29,
+        0x29,
+        0x41,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x25,
+        0x1e,
+        0x41,
+        0x41,
+        0x41,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x0,
+        0x29,
+        0x29,
+        0x41,
+        0x0,
+        0x41,
+        0x41,
+        0x25,
+        0x0,
+        0x0,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x29,
+        0x29,
+        0x0,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x29,
+        0x43,
+        0x29,
+        0x25,
+        0x25,
+        0x25,
+    ],
+    [
+        0x0,
+        0x29,
+        0x41,
+        0x41,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x25,
+        0x1e,
+        0x42,
+        0x29,
+        0x41,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x0,
+        0x0,
+        0x41,
+        0x41,
+        0x0,
+        0x0,
+        0x41,
+        0x41,
+        0x25,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x44,
+        0x29,
+        0x42,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x29,
+        0x29,
+        0x0,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x1c,
+        0x1e,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x29,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x42,
+        0x41,
+        0x29,
+        0x41,
+        0x41,
+        0x0,
+        0x0,
+        0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x25,
+        0x0,
+        0x0,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x42,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x29,
+        0x41,
+        0x41,
+        0x41,
+        0x29,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x25,
+        0x1e,
+        0x29,
+        0x29,
+        0x29,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x0,
+        0x29,
+        0x29,
+        0x29,
+        0x0,
+        0x29,
+        0x29,
+        0x29,
+        0x25,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x29,
+        0x29,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x1e,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x29,
+        0x29,
+        0x0,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1e,
+        0x29,
+        0x41,
+        0x41,
+        0x1c,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x25,
+        0x1e,
+        0x41,
+        0x29,
+        0x42,
+        0x41,
+        0x42,
+        0x41,
+        0x41,
+        0x0,
+        0x29,
+        0x42,
+        0x42,
+        0x0,
+        0x42,
+        0x42,
+        0x29,
+        0x25,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x42,
+        0x42,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x29,
+        0x29,
+        0x0,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x1e,
+        0x1e,
+        0x41,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x29,
+        0x29,
+        0x41,
+        0x41,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x25,
+        0x25,
+        0x1e,
+        0x42,
+        0x41,
+        0x41,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x25,
+        0x1e,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x42,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x29,
+        0x29,
+        0x0,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x29,
+        0x41,
+        0x41,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x25,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x42,
+        0x41,
+        0x41,
+        0x29,
+        0x29,
+        0x29,
+        0x0,
+        0x29,
+        0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x42,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x0,
+        0x41,
+        0x41,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x29,
+        0x1e,
+        0x45,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x36,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x1e,
+        0x3a,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x29,
+        0x25,
+        0x1c,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2b,
+        0x2b,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x29,
+        0x1e,
+        0x45,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x25,
+        0x29,
+        0x29,
+        0x1e,
+        0x0,
+        0x0,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x0,
+        0x3a,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x29,
+        0x28,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1e,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x2b,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x25,
+        0x25,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x25,
+        0x1c,
+        0x25,
+        0x1c,
+        0x25,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x47,
+        0x47,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x48,
+        0x29,
+        0x48,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x41,
+        0x29,
+        0x29,
+        0x36,
+        0x36,
+        0x25,
+        0x1c,
+        0x25,
+        0x25,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x0,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x25,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x41,
+        0x41,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x41,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x25,
+        0x41,
+        0x25,
+        0x25,
+        0x41,
+        0x41,
+        0x29,
+        0x29,
+        0x1e,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x34,
+        0x34,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x41,
+        0x41,
+        0x29,
+        0x29,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x29,
+        0x29,
+        0x29,
+        0x1e,
+        0x41,
+        0x49,
+        0x49,
+        0x1e,
+        0x1e,
+        0x41,
+        0x41,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x29,
+        0x41,
+        0x41,
+        0x29,
+        0x29,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x36,
+        0x1e,
+        0x49,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x49,
+        0x49,
+        0x41,
+        0x29,
+        0x1c,
+        0x1c,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x0,
+        0xe,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe,
+        0x0,
+        0x0,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x1c,
+        0x4b,
+        0x4a,
+        0x4a,
+        0x4a,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x4c,
+        0x4c,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x1c,
+        0x2b,
+        0x34,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x34,
+        0x34,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x0,
+        0x0,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x0,
+        0x0,
+    ],
+    [
+        0x37,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1c,
+        0x34,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x15,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x29,
+        0x29,
+        0x25,
+        0x4e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x29,
+        0x29,
+        0x4e,
+        0x34,
+        0x34,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x29,
+        0x29,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x29,
+        0x29,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x3d,
+        0x3d,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x27,
+        0x27,
+        0x41,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x29,
+        0x41,
+        0x41,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x34,
+        0x34,
+        0x2b,
+        0x3e,
+        0x1c,
+        0x1c,
+        0x2b,
+        0x1c,
+        0x1e,
+        0x25,
+        0x0,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1c,
+        0x1c,
+        0x2b,
+        0x34,
+        0x2b,
+        0x2b,
+        0x35,
+        0x1c,
+        0x2b,
+        0x34,
+        0x4f,
+        0x50,
+        0x50,
+        0x50,
+        0x51,
+        0x50,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x3a,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x52,
+        0x52,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x29,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x29,
+        0x29,
+        0x29,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x29,
+        0x29,
+        0x41,
+        0x41,
+        0x41,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x41,
+        0x41,
+        0x29,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x34,
+        0x34,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x46,
+        0x46,
+        0x46,
+        0x1e,
+        0x1e,
+        0x46,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x29,
+        0x29,
+        0x41,
+        0x41,
+        0x29,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x41,
+        0x29,
+        0x41,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x0,
+        0x25,
+        0x41,
+        0x29,
+        0x41,
+        0x41,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x29,
+        0x29,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x0,
+        0x25,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x3a,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x53,
+        0x29,
+        0x29,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x29,
+        0x29,
+        0x29,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x41,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x25,
+        0x42,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x42,
+        0x29,
+        0x42,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x29,
+        0x42,
+        0x4e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x34,
+        0x34,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x34,
+        0x34,
+        0x1c,
+        0x2b,
+        0x34,
+        0x34,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x34,
+        0x34,
+        0x34,
+        0x29,
+        0x29,
+        0x41,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x41,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x41,
+        0x41,
+        0x29,
+        0x29,
+        0x4e,
+        0x25,
+        0x29,
+        0x29,
+        0x1e,
+        0x1e,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x25,
+        0x41,
+        0x29,
+        0x29,
+        0x41,
+        0x41,
+        0x41,
+        0x29,
+        0x41,
+        0x29,
+        0x29,
+        0x29,
+        0x4e,
+        0x4e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x41,
+        0x41,
+        0x54,
+        0x25,
+        0x0,
+        0x0,
+        0x0,
+        0x34,
+        0x34,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x23,
+        0x23,
+        0x23,
+        0x24,
+        0x23,
+        0x23,
+        0x34,
+        0x34,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0xe,
+        0x13,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x0,
+        0x0,
+        0xe,
+        0xe,
+        0xe,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x55,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x47,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x25,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x25,
+        0x1e,
+        0x1e,
+        0x47,
+        0x25,
+        0x25,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x22,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x4b,
+        0x13,
+        0x17,
+        0x17,
+        0x17,
+        0x13,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x13,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x56,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x22,
+        0x21,
+        0x21,
+        0x21,
+        0x22,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x4b,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x28,
+        0x28,
+        0x28,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+    ],
+    [
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x14,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x1a,
+        0x1a,
+        0x17,
+        0x17,
+        0xe,
+        0x17,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x14,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+    ],
+    [
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x0,
+        0x0,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x0,
+        0x0,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x0,
+        0x0,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x0,
+        0x0,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x0,
+        0xe,
+        0x0,
+        0xe,
+        0x0,
+        0xe,
+        0x0,
+        0xe,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x0,
+        0x0,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x13,
+        0x13,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x0,
+        0x13,
+        0x1a,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x20,
+        0x16,
+        0x13,
+        0x16,
+        0x16,
+        0x16,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x0,
+        0x13,
+        0x1a,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x20,
+        0x16,
+        0x16,
+        0x16,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x0,
+        0x0,
+        0x13,
+        0x13,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x0,
+        0x16,
+        0x16,
+        0x16,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x16,
+        0x16,
+        0x16,
+        0x0,
+        0x0,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x0,
+        0x13,
+        0x1a,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x20,
+        0x16,
+        0x16,
+        0x0,
+    ],
+    [
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x51,
+        0x57,
+        0x58,
+        0x59,
+        0x59,
+        0x9,
+        0x9,
+        0x5a,
+        0x5a,
+        0x5a,
+        0x5a,
+        0x7,
+        0x5,
+        0x6,
+        0x6,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0xa,
+        0x5,
+        0x5,
+        0x5b,
+        0x1,
+        0x1,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x15,
+        0x5,
+        0x5,
+        0x7,
+        0x7,
+        0x7,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x4,
+        0x4,
+        0x5,
+        0x3,
+        0x3,
+        0x5,
+        0x10,
+        0x5c,
+        0x5,
+        0x5,
+        0x5,
+        0x7,
+        0x5,
+        0x5,
+        0x3,
+        0x3,
+        0x3,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x7,
+        0x5a,
+        0x10,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x15,
+        0x51,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5e,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x19,
+        0x60,
+        0x0,
+        0x0,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x61,
+        0x62,
+        0x61,
+        0x61,
+        0x61,
+        0x4b,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x61,
+        0x62,
+        0x61,
+        0x61,
+        0x61,
+        0x0,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x63,
+        0x32,
+        0x32,
+        0x32,
+        0x63,
+        0x63,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x28,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1c,
+        0x1c,
+        0x2e,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x2e,
+        0x1c,
+        0x1c,
+        0x64,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x64,
+        0x64,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x64,
+        0x1c,
+        0x2e,
+        0x1c,
+        0x1c,
+        0x65,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x2e,
+        0x1c,
+        0xe,
+        0x1c,
+        0x2e,
+        0x31,
+        0xe,
+        0xe,
+        0x2e,
+        0x2e,
+        0x66,
+        0x64,
+        0x2e,
+        0x2e,
+        0xe,
+        0x2e,
+        0x64,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x17,
+        0x1c,
+        0x1c,
+        0x64,
+        0x64,
+        0x2e,
+        0x2e,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x2e,
+        0x64,
+        0x64,
+        0x68,
+        0x68,
+        0x1c,
+        0x31,
+        0x1c,
+        0x1c,
+        0x13,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x1e,
+        0x1e,
+        0x1e,
+        0xe,
+        0x13,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x5,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x5,
+        0x7,
+        0x7,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x7,
+        0x7,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x5,
+        0x7,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x7,
+        0x7,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+    ],
+    [
+        0x7,
+        0x7,
+        0x69,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x69,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x6a,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x69,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+    ],
+    [
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x7,
+        0x7,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x6b,
+        0x6b,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x7,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x5,
+        0x7,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x7,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+    ],
+    [
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+    ],
+    [
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x7,
+        0x7,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x7,
+        0x7,
+        0x5,
+        0x5,
+        0x7,
+        0x7,
+        0x5,
+        0x5,
+        0x5,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x7,
+        0x5,
+        0x7,
+        0x5,
+        0x5,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+    ],
+    [
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x7,
+        0x7,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x7,
+        0x5,
+        0x7,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x7,
+        0x7,
+        0x7,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+    ],
+    [
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+    ],
+    [
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+    ],
+    [
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+    ],
+    [
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x5,
+        0x5,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x6c,
+        0x6c,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x0,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+    ],
+    [
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0xe,
+        0xe,
+        0x13,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x17,
+        0xe,
+        0x13,
+        0x17,
+        0xe,
+        0x13,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x60,
+        0x4b,
+        0xe,
+        0xe,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0x17,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0x25,
+        0x25,
+        0x25,
+        0xe,
+        0x13,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x34,
+        0x34,
+        0x34,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+    ],
+    [
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x0,
+        0x13,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x13,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3e,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x28,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+    ],
+    [
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x9,
+        0x5,
+        0x5,
+        0x5a,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x3,
+        0x6f,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5a,
+        0x5a,
+        0x3,
+        0x5,
+        0x5,
+        0x5,
+        0x5a,
+        0x8,
+        0x4,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x8,
+        0x5,
+        0x8,
+        0x8,
+        0x5,
+        0x5,
+        0x5,
+        0x3,
+        0x3,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5a,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x0,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x71,
+        0x71,
+        0x72,
+        0x72,
+        0x71,
+        0x71,
+        0x71,
+        0x71,
+        0x71,
+        0x71,
+        0x71,
+        0x71,
+        0x71,
+        0x71,
+        0x73,
+        0x73,
+    ],
+    [
+        0x15,
+        0x8,
+        0x3,
+        0x5,
+        0x1c,
+        0x3a,
+        0x74,
+        0x74,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5a,
+        0x4,
+        0x4,
+        0x4,
+        0x5,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x4e,
+        0x4e,
+        0x5a,
+        0x3a,
+        0x3a,
+        0x3a,
+        0x3a,
+        0x3a,
+        0x1c,
+        0x1c,
+        0x74,
+        0x74,
+        0x74,
+        0x3e,
+        0x1e,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x25,
+        0x25,
+        0x75,
+        0x75,
+        0x3a,
+        0x3a,
+        0x1e,
+        0x37,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x76,
+        0x24,
+        0x3a,
+        0x3a,
+        0x1e,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x4c,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x71,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+    ],
+    [
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+    ],
+    [
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+    ],
+    [
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+    ],
+    [
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x3a,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x2b,
+        0x34,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x3a,
+        0x2b,
+        0x34,
+        0x34,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0x1e,
+        0x25,
+        0x32,
+        0x32,
+        0x32,
+        0x1c,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x25,
+        0x25,
+        0x1c,
+        0x23,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0x21,
+        0x21,
+        0x29,
+        0x29,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x25,
+        0x25,
+        0x1c,
+        0x34,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x34,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x16,
+        0x16,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0x17,
+        0x17,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0x4b,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0x23,
+        0x16,
+        0x16,
+        0xe,
+        0x13,
+        0xe,
+        0x17,
+        0x1e,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0x13,
+        0x17,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x17,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0xe,
+        0x13,
+        0x0,
+        0x0,
+        0xe,
+        0x13,
+        0x0,
+        0x13,
+        0x0,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x13,
+        0xe,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x4b,
+        0x4b,
+        0x4b,
+        0xe,
+        0x13,
+        0x1e,
+        0x21,
+        0x21,
+        0x17,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x29,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x25,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x29,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x41,
+        0x41,
+        0x29,
+        0x29,
+        0x41,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x25,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1c,
+        0x1c,
+        0x34,
+        0x34,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x41,
+        0x41,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x25,
+        0x29,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x34,
+        0x34,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1e,
+        0x1c,
+        0x1e,
+        0x1e,
+        0x29,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x25,
+        0x25,
+        0x25,
+        0x55,
+        0x34,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x41,
+        0x4e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x29,
+        0x29,
+        0x29,
+        0x41,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x25,
+        0x41,
+        0x41,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x41,
+        0x41,
+        0x29,
+        0x29,
+        0x41,
+        0x41,
+        0x4e,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x2b,
+        0x34,
+        0x34,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x3a,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x36,
+        0x3a,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x41,
+        0x41,
+        0x29,
+        0x29,
+        0x41,
+        0x41,
+        0x29,
+        0x29,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x29,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x29,
+        0x41,
+        0x0,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x0,
+        0x1c,
+        0x34,
+        0x34,
+        0x34,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x3a,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1e,
+        0x49,
+        0x36,
+        0x49,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x29,
+        0x1e,
+        0x29,
+        0x29,
+        0x29,
+        0x46,
+        0x46,
+        0x29,
+        0x29,
+        0x46,
+        0x1e,
+        0x46,
+        0x46,
+        0x1e,
+        0x29,
+        0x25,
+        0x78,
+        0x25,
+        0x78,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x3a,
+        0x1c,
+        0x2b,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x41,
+        0x29,
+        0x29,
+        0x41,
+        0x41,
+        0x34,
+        0x34,
+        0x1e,
+        0x3a,
+        0x3a,
+        0x41,
+        0x25,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x13,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x16,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x21,
+        0x16,
+        0x16,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x41,
+        0x41,
+        0x29,
+        0x41,
+        0x41,
+        0x29,
+        0x41,
+        0x41,
+        0x34,
+        0x47,
+        0x25,
+        0x0,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+    ],
+    [
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x77,
+        0x77,
+        0x74,
+        0x77,
+        0x74,
+        0x77,
+        0x77,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x77,
+        0x74,
+        0x77,
+        0x74,
+        0x77,
+        0x77,
+        0x74,
+        0x74,
+        0x77,
+        0x77,
+        0x77,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x0,
+        0x0,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x36,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x31,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x79,
+        0x79,
+        0x79,
+        0x79,
+        0x79,
+        0x79,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x5,
+        0x5,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x79,
+        0x79,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+    ],
+    [
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x1c,
+        0x1c,
+        0x34,
+        0x33,
+        0x1c,
+        0x34,
+        0x34,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x1c,
+        0x37,
+        0x37,
+        0x10,
+        0x10,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x5,
+        0x5,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x10,
+        0x10,
+        0x10,
+        0x2b,
+        0x2b,
+        0x7c,
+        0x0,
+        0x2b,
+        0x7d,
+        0x34,
+        0x34,
+        0x37,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x31,
+        0x31,
+        0x7e,
+        0x31,
+        0x31,
+        0x31,
+        0x0,
+        0x31,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x79,
+        0x1e,
+        0x79,
+        0x1e,
+        0x79,
+        0x0,
+        0x79,
+        0x1e,
+        0x79,
+        0x1e,
+        0x79,
+        0x1e,
+        0x79,
+        0x1e,
+        0x79,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x51,
+    ],
+    [
+        0x0,
+        0x34,
+        0x7b,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x7f,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x31,
+        0x2b,
+        0x35,
+        0x7c,
+        0x1c,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x7d,
+        0x2b,
+        0x31,
+        0x31,
+        0x31,
+        0x34,
+        0x1c,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x1c,
+        0x31,
+        0x1c,
+        0x82,
+        0x10,
+        0x16,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x1c,
+        0x31,
+        0x1c,
+        0x31,
+        0x1c,
+        0x1c,
+        0x34,
+        0x7b,
+        0x7b,
+        0x2b,
+        0x76,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x24,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x84,
+        0x84,
+        0x4c,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x31,
+        0x16,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x1c,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x1c,
+        0x1c,
+        0x0,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x85,
+        0x85,
+        0x85,
+        0x1c,
+        0x1c,
+        0x7a,
+        0x7a,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x28,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x25,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x2b,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x2b,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x0,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x0,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x0,
+        0xe,
+        0xe,
+        0x0,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x0,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x0,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x0,
+        0x13,
+        0x13,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x21,
+        0x24,
+        0x24,
+        0x21,
+        0x21,
+        0x21,
+        0x0,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x0,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x2b,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x2b,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1e,
+        0x1e,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+    ],
+    [
+        0x1e,
+        0x29,
+        0x29,
+        0x29,
+        0x0,
+        0x29,
+        0x29,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x25,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x34,
+        0x34,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1c,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x25,
+        0x25,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x78,
+        0x78,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x24,
+        0x1e,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x0,
+        0x0,
+        0x0,
+        0x36,
+        0x44,
+        0x25,
+        0x25,
+        0x25,
+        0x37,
+        0x3a,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x31,
+        0x31,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x29,
+        0x29,
+        0x37,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x29,
+        0x25,
+        0x25,
+        0x25,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x41,
+        0x29,
+        0x41,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x25,
+        0x34,
+        0x34,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x25,
+        0x1e,
+        0x1e,
+        0x29,
+        0x29,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x28,
+        0x29,
+        0x29,
+        0x41,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x41,
+        0x41,
+        0x41,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x41,
+        0x41,
+        0x25,
+        0x25,
+        0x1c,
+        0x1c,
+        0x38,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x29,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x38,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x29,
+        0x29,
+        0x29,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x41,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x25,
+        0x25,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x1c,
+        0x34,
+        0x34,
+        0x34,
+        0x1e,
+        0x41,
+        0x41,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x25,
+        0x1c,
+        0x1c,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x29,
+        0x29,
+        0x41,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x41,
+        0x41,
+        0x41,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x41,
+        0x4e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x34,
+        0x34,
+        0x1c,
+        0x1c,
+        0x28,
+        0x25,
+        0x25,
+        0x25,
+        0x34,
+        0x41,
+        0x29,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x1e,
+        0x1c,
+        0x1e,
+        0x1c,
+        0x34,
+        0x34,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x41,
+        0x41,
+        0x41,
+        0x29,
+        0x29,
+        0x29,
+        0x41,
+        0x41,
+        0x29,
+        0x4e,
+        0x25,
+        0x43,
+        0x34,
+        0x34,
+        0x2b,
+        0x34,
+        0x34,
+        0x1c,
+        0x29,
+        0x1e,
+        0x1e,
+        0x29,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x34,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x29,
+        0x41,
+        0x41,
+        0x41,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x25,
+        0x25,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x29,
+        0x29,
+        0x41,
+        0x41,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x25,
+        0x25,
+        0x1e,
+        0x42,
+        0x41,
+        0x29,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x0,
+        0x0,
+        0x41,
+        0x41,
+        0x0,
+        0x0,
+        0x41,
+        0x41,
+        0x4e,
+        0x0,
+        0x0,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x42,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x86,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x41,
+        0x41,
+        0x0,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x0,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x0,
+        0x0,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x42,
+        0x41,
+        0x41,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x0,
+        0x42,
+        0x0,
+        0x0,
+        0x42,
+        0x0,
+        0x42,
+        0x42,
+        0x42,
+        0x41,
+        0x0,
+        0x41,
+        0x41,
+        0x25,
+        0x4e,
+        0x25,
+        0x1e,
+        0x44,
+        0x87,
+        0x34,
+        0x34,
+        0x0,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x25,
+        0x25,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x41,
+        0x41,
+        0x41,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x41,
+        0x41,
+        0x25,
+        0x29,
+        0x29,
+        0x41,
+        0x25,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x34,
+        0x34,
+        0x2b,
+        0x1c,
+        0x1c,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2b,
+        0x2b,
+        0x0,
+        0x1c,
+        0x28,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x42,
+        0x41,
+        0x41,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x41,
+        0x29,
+        0x41,
+        0x41,
+        0x42,
+        0x41,
+        0x29,
+        0x29,
+        0x41,
+        0x25,
+        0x25,
+        0x1e,
+        0x1e,
+        0x1c,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x42,
+        0x41,
+        0x41,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x0,
+        0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x29,
+        0x29,
+        0x41,
+        0x25,
+        0x25,
+        0x1c,
+        0x34,
+        0x34,
+        0x2b,
+        0x2b,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x29,
+        0x29,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x41,
+        0x41,
+        0x41,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x41,
+        0x41,
+        0x29,
+        0x41,
+        0x25,
+        0x29,
+        0x34,
+        0x34,
+        0x1c,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x29,
+        0x41,
+        0x29,
+        0x41,
+        0x41,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x4e,
+        0x25,
+        0x1e,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x29,
+        0x41,
+        0x29,
+        0x41,
+        0x41,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x41,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x25,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x1c,
+        0x1c,
+        0x34,
+        0x34,
+        0x34,
+        0x1c,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x41,
+        0x41,
+        0x41,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x41,
+        0x25,
+        0x25,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x1e,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x42,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x0,
+        0x41,
+        0x41,
+        0x0,
+        0x0,
+        0x29,
+        0x29,
+        0x4e,
+        0x25,
+        0x1e,
+        0x41,
+        0x1e,
+        0x41,
+        0x25,
+        0x34,
+        0x1c,
+        0x34,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x41,
+        0x41,
+        0x41,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x0,
+        0x0,
+        0x29,
+        0x29,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x25,
+        0x1e,
+        0x1c,
+        0x1e,
+        0x41,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1e,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x28,
+        0x25,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x41,
+        0x1e,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x34,
+        0x34,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x25,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x41,
+        0x41,
+        0x29,
+        0x29,
+        0x29,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x41,
+        0x88,
+        0x25,
+        0x1c,
+        0x34,
+        0x34,
+        0x1e,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x2b,
+        0x2b,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x41,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x0,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x41,
+        0x25,
+        0x1e,
+        0x34,
+        0x34,
+        0x2b,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x2b,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x0,
+        0x41,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x41,
+        0x29,
+        0x29,
+        0x41,
+        0x29,
+        0x29,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x0,
+        0x0,
+        0x0,
+        0x29,
+        0x0,
+        0x29,
+        0x29,
+        0x0,
+        0x29,
+        0x29,
+        0x29,
+        0x25,
+        0x29,
+        0x25,
+        0x25,
+        0x1e,
+        0x29,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x0,
+        0x29,
+        0x29,
+        0x0,
+        0x41,
+        0x41,
+        0x29,
+        0x41,
+        0x25,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x29,
+        0x29,
+        0x41,
+        0x41,
+        0x34,
+        0x34,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x29,
+        0x29,
+        0x1e,
+        0x41,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x41,
+        0x41,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x0,
+        0x0,
+        0x0,
+        0x41,
+        0x41,
+        0x29,
+        0x4e,
+        0x25,
+        0x34,
+        0x34,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x25,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x28,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x41,
+        0x41,
+        0x41,
+        0x29,
+        0x29,
+        0x25,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x34,
+        0x34,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x34,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x34,
+        0x34,
+        0x2b,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x3e,
+        0x3e,
+        0x3a,
+        0x3a,
+        0x34,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x23,
+        0x23,
+        0x1c,
+        0x34,
+        0x34,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x2b,
+        0x34,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x29,
+        0x1e,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3a,
+        0x3a,
+        0x1c,
+        0x3a,
+        0x89,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x8a,
+        0x8a,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x74,
+    ],
+    [
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x0,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x0,
+        0x23,
+        0x23,
+        0x0,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+    ],
+    [
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x1c,
+        0x28,
+        0x29,
+        0x34,
+        0x51,
+        0x51,
+        0x51,
+        0x51,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x8b,
+        0x8b,
+        0x25,
+        0x25,
+        0x25,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x51,
+        0x51,
+        0x51,
+        0x51,
+        0x51,
+        0x51,
+        0x51,
+        0x51,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x1c,
+        0x1c,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x28,
+        0x28,
+        0x28,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x68,
+        0x68,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x0,
+        0x68,
+        0x68,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x68,
+        0x68,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x2e,
+        0x0,
+        0x2e,
+        0x2e,
+        0x0,
+        0x0,
+        0x2e,
+        0x0,
+        0x0,
+        0x2e,
+        0x2e,
+        0x0,
+        0x0,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x0,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x0,
+        0x64,
+        0x0,
+        0x64,
+        0x68,
+        0x68,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x0,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x68,
+        0x68,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+    ],
+    [
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x2e,
+        0x2e,
+        0x0,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x0,
+        0x0,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x0,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x0,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x68,
+        0x68,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x2e,
+        0x2e,
+        0x0,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x0,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x0,
+        0x2e,
+        0x0,
+        0x0,
+        0x0,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x0,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x68,
+        0x68,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x68,
+        0x68,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x68,
+        0x68,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x68,
+        0x68,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+    ],
+    [
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x68,
+        0x68,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x68,
+        0x68,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x68,
+        0x68,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x0,
+        0x0,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x8c,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x8c,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x8c,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+    ],
+    [
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x8c,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x8c,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x8c,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x8c,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x8c,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x8c,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x8c,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x2e,
+        0x64,
+        0x0,
+        0x0,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+    ],
+    [
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x28,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x28,
+        0x1c,
+        0x1c,
+        0x2b,
+        0x34,
+        0x2b,
+        0x2b,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x0,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x1e,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x56,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x0,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x0,
+        0x0,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x0,
+        0x29,
+        0x29,
+        0x0,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x22,
+        0x22,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x22,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x29,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3a,
+        0x3a,
+        0x0,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x25,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x3e,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x25,
+        0x44,
+        0x1e,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x44,
+        0x44,
+        0x44,
+        0x29,
+        0x25,
+        0x25,
+        0x25,
+        0x3e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x0,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x0,
+        0x67,
+        0x67,
+        0x0,
+        0x67,
+        0x0,
+        0x0,
+        0x67,
+        0x0,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x0,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x0,
+        0x67,
+        0x0,
+        0x67,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x67,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x67,
+        0x0,
+        0x67,
+        0x0,
+        0x67,
+        0x0,
+        0x67,
+        0x67,
+        0x67,
+        0x0,
+        0x67,
+        0x67,
+        0x0,
+        0x67,
+        0x0,
+        0x0,
+        0x67,
+        0x0,
+        0x67,
+        0x0,
+        0x67,
+        0x0,
+        0x67,
+        0x0,
+        0x67,
+        0x0,
+        0x67,
+        0x67,
+        0x0,
+        0x67,
+        0x0,
+        0x0,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x0,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x0,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x0,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x0,
+        0x67,
+        0x0,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x0,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x67,
+        0x67,
+        0x67,
+        0x0,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x0,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x31,
+        0x31,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x8e,
+        0x8e,
+        0x8e,
+        0x8e,
+        0x8e,
+        0x8e,
+        0x8e,
+        0x8e,
+        0x8e,
+        0x8e,
+        0x8e,
+        0x8e,
+        0x8e,
+        0x8e,
+        0x8e,
+        0x8e,
+        0x8e,
+        0x8e,
+        0x8e,
+        0x8e,
+        0x8e,
+        0x8e,
+        0x8e,
+        0x8e,
+        0x8e,
+        0x8e,
+    ],
+    [
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+    ],
+    [
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7a,
+        0x7a,
+    ],
+    [
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+    ],
+    [
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x0,
+        0x0,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+    ],
+    [
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+    ],
+    [
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+    ],
+    [
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+    ],
+    [
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x5e,
+        0x5f,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+    ],
+    [
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+    ],
+    [
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+    ],
+];
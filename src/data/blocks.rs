@@ -0,0 +1,1364 @@
+// GENERATED CODE DO NOT MANUALLY EDIT
+/// enum for the Unicode blocks. The variants are the names of the blocks in camel case and
+/// are documented with the range of the block.
+/// New versions of Unicode add blocks, so a `match` on a `Block` needs a wildcard arm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum Block {
+	/// 0000..007F
+	BasicLatin,
+	/// 0080..00FF
+	Latin1Supplement,
+	/// 0100..017F
+	LatinExtendedA,
+	/// 0180..024F
+	LatinExtendedB,
+	/// 0250..02AF
+	IPAExtensions,
+	/// 02B0..02FF
+	SpacingModifierLetters,
+	/// 0300..036F
+	CombiningDiacriticalMarks,
+	/// 0370..03FF
+	GreekAndCoptic,
+	/// 0400..04FF
+	Cyrillic,
+	/// 0500..052F
+	CyrillicSupplement,
+	/// 0530..058F
+	Armenian,
+	/// 0590..05FF
+	Hebrew,
+	/// 0600..06FF
+	Arabic,
+	/// 0700..074F
+	Syriac,
+	/// 0750..077F
+	ArabicSupplement,
+	/// 0780..07BF
+	Thaana,
+	/// 07C0..07FF
+	NKo,
+	/// 0800..083F
+	Samaritan,
+	/// 0840..085F
+	Mandaic,
+	/// 0860..086F
+	SyriacSupplement,
+	/// 0870..089F
+	ArabicExtendedB,
+	/// 08A0..08FF
+	ArabicExtendedA,
+	/// 0900..097F
+	Devanagari,
+	/// 0980..09FF
+	Bengali,
+	/// 0A00..0A7F
+	Gurmukhi,
+	/// 0A80..0AFF
+	Gujarati,
+	/// 0B00..0B7F
+	Oriya,
+	/// 0B80..0BFF
+	Tamil,
+	/// 0C00..0C7F
+	Telugu,
+	/// 0C80..0CFF
+	Kannada,
+	/// 0D00..0D7F
+	Malayalam,
+	/// 0D80..0DFF
+	Sinhala,
+	/// 0E00..0E7F
+	Thai,
+	/// 0E80..0EFF
+	Lao,
+	/// 0F00..0FFF
+	Tibetan,
+	/// 1000..109F
+	Myanmar,
+	/// 10A0..10FF
+	Georgian,
+	/// 1100..11FF
+	HangulJamo,
+	/// 1200..137F
+	Ethiopic,
+	/// 1380..139F
+	EthiopicSupplement,
+	/// 13A0..13FF
+	Cherokee,
+	/// 1400..167F
+	UnifiedCanadianAboriginalSyllabics,
+	/// 1680..169F
+	Ogham,
+	/// 16A0..16FF
+	Runic,
+	/// 1700..171F
+	Tagalog,
+	/// 1720..173F
+	Hanunoo,
+	/// 1740..175F
+	Buhid,
+	/// 1760..177F
+	Tagbanwa,
+	/// 1780..17FF
+	Khmer,
+	/// 1800..18AF
+	Mongolian,
+	/// 18B0..18FF
+	UnifiedCanadianAboriginalSyllabicsExtended,
+	/// 1900..194F
+	Limbu,
+	/// 1950..197F
+	TaiLe,
+	/// 1980..19DF
+	NewTaiLue,
+	/// 19E0..19FF
+	KhmerSymbols,
+	/// 1A00..1A1F
+	Buginese,
+	/// 1A20..1AAF
+	TaiTham,
+	/// 1AB0..1AFF
+	CombiningDiacriticalMarksExtended,
+	/// 1B00..1B7F
+	Balinese,
+	/// 1B80..1BBF
+	Sundanese,
+	/// 1BC0..1BFF
+	Batak,
+	/// 1C00..1C4F
+	Lepcha,
+	/// 1C50..1C7F
+	OlChiki,
+	/// 1C80..1C8F
+	CyrillicExtendedC,
+	/// 1C90..1CBF
+	GeorgianExtended,
+	/// 1CC0..1CCF
+	SundaneseSupplement,
+	/// 1CD0..1CFF
+	VedicExtensions,
+	/// 1D00..1D7F
+	PhoneticExtensions,
+	/// 1D80..1DBF
+	PhoneticExtensionsSupplement,
+	/// 1DC0..1DFF
+	CombiningDiacriticalMarksSupplement,
+	/// 1E00..1EFF
+	LatinExtendedAdditional,
+	/// 1F00..1FFF
+	GreekExtended,
+	/// 2000..206F
+	GeneralPunctuation,
+	/// 2070..209F
+	SuperscriptsAndSubscripts,
+	/// 20A0..20CF
+	CurrencySymbols,
+	/// 20D0..20FF
+	CombiningDiacriticalMarksForSymbols,
+	/// 2100..214F
+	LetterlikeSymbols,
+	/// 2150..218F
+	NumberForms,
+	/// 2190..21FF
+	Arrows,
+	/// 2200..22FF
+	MathematicalOperators,
+	/// 2300..23FF
+	MiscellaneousTechnical,
+	/// 2400..243F
+	ControlPictures,
+	/// 2440..245F
+	OpticalCharacterRecognition,
+	/// 2460..24FF
+	EnclosedAlphanumerics,
+	/// 2500..257F
+	BoxDrawing,
+	/// 2580..259F
+	BlockElements,
+	/// 25A0..25FF
+	GeometricShapes,
+	/// 2600..26FF
+	MiscellaneousSymbols,
+	/// 2700..27BF
+	Dingbats,
+	/// 27C0..27EF
+	MiscellaneousMathematicalSymbolsA,
+	/// 27F0..27FF
+	SupplementalArrowsA,
+	/// 2800..28FF
+	BraillePatterns,
+	/// 2900..297F
+	SupplementalArrowsB,
+	/// 2980..29FF
+	MiscellaneousMathematicalSymbolsB,
+	/// 2A00..2AFF
+	SupplementalMathematicalOperators,
+	/// 2B00..2BFF
+	MiscellaneousSymbolsAndArrows,
+	/// 2C00..2C5F
+	Glagolitic,
+	/// 2C60..2C7F
+	LatinExtendedC,
+	/// 2C80..2CFF
+	Coptic,
+	/// 2D00..2D2F
+	GeorgianSupplement,
+	/// 2D30..2D7F
+	Tifinagh,
+	/// 2D80..2DDF
+	EthiopicExtended,
+	/// 2DE0..2DFF
+	CyrillicExtendedA,
+	/// 2E00..2E7F
+	SupplementalPunctuation,
+	/// 2E80..2EFF
+	CJKRadicalsSupplement,
+	/// 2F00..2FDF
+	KangxiRadicals,
+	/// 2FF0..2FFF
+	IdeographicDescriptionCharacters,
+	/// 3000..303F
+	CJKSymbolsAndPunctuation,
+	/// 3040..309F
+	Hiragana,
+	/// 30A0..30FF
+	Katakana,
+	/// 3100..312F
+	Bopomofo,
+	/// 3130..318F
+	HangulCompatibilityJamo,
+	/// 3190..319F
+	Kanbun,
+	/// 31A0..31BF
+	BopomofoExtended,
+	/// 31C0..31EF
+	CJKStrokes,
+	/// 31F0..31FF
+	KatakanaPhoneticExtensions,
+	/// 3200..32FF
+	EnclosedCJKLettersAndMonths,
+	/// 3300..33FF
+	CJKCompatibility,
+	/// 3400..4DBF
+	CJKUnifiedIdeographsExtensionA,
+	/// 4DC0..4DFF
+	YijingHexagramSymbols,
+	/// 4E00..9FFF
+	CJKUnifiedIdeographs,
+	/// A000..A48F
+	YiSyllables,
+	/// A490..A4CF
+	YiRadicals,
+	/// A4D0..A4FF
+	Lisu,
+	/// A500..A63F
+	Vai,
+	/// A640..A69F
+	CyrillicExtendedB,
+	/// A6A0..A6FF
+	Bamum,
+	/// A700..A71F
+	ModifierToneLetters,
+	/// A720..A7FF
+	LatinExtendedD,
+	/// A800..A82F
+	SylotiNagri,
+	/// A830..A83F
+	CommonIndicNumberForms,
+	/// A840..A87F
+	PhagsPa,
+	/// A880..A8DF
+	Saurashtra,
+	/// A8E0..A8FF
+	DevanagariExtended,
+	/// A900..A92F
+	KayahLi,
+	/// A930..A95F
+	Rejang,
+	/// A960..A97F
+	HangulJamoExtendedA,
+	/// A980..A9DF
+	Javanese,
+	/// A9E0..A9FF
+	MyanmarExtendedB,
+	/// AA00..AA5F
+	Cham,
+	/// AA60..AA7F
+	MyanmarExtendedA,
+	/// AA80..AADF
+	TaiViet,
+	/// AAE0..AAFF
+	MeeteiMayekExtensions,
+	/// AB00..AB2F
+	EthiopicExtendedA,
+	/// AB30..AB6F
+	LatinExtendedE,
+	/// AB70..ABBF
+	CherokeeSupplement,
+	/// ABC0..ABFF
+	MeeteiMayek,
+	/// AC00..D7AF
+	HangulSyllables,
+	/// D7B0..D7FF
+	HangulJamoExtendedB,
+	/// D800..DB7F
+	HighSurrogates,
+	/// DB80..DBFF
+	HighPrivateUseSurrogates,
+	/// DC00..DFFF
+	LowSurrogates,
+	/// E000..F8FF
+	PrivateUseArea,
+	/// F900..FAFF
+	CJKCompatibilityIdeographs,
+	/// FB00..FB4F
+	AlphabeticPresentationForms,
+	/// FB50..FDFF
+	ArabicPresentationFormsA,
+	/// FE00..FE0F
+	VariationSelectors,
+	/// FE10..FE1F
+	VerticalForms,
+	/// FE20..FE2F
+	CombiningHalfMarks,
+	/// FE30..FE4F
+	CJKCompatibilityForms,
+	/// FE50..FE6F
+	SmallFormVariants,
+	/// FE70..FEFF
+	ArabicPresentationFormsB,
+	/// FF00..FFEF
+	HalfwidthAndFullwidthForms,
+	/// FFF0..FFFF
+	Specials,
+	/// 10000..1007F
+	LinearBSyllabary,
+	/// 10080..100FF
+	LinearBIdeograms,
+	/// 10100..1013F
+	AegeanNumbers,
+	/// 10140..1018F
+	AncientGreekNumbers,
+	/// 10190..101CF
+	AncientSymbols,
+	/// 101D0..101FF
+	PhaistosDisc,
+	/// 10280..1029F
+	Lycian,
+	/// 102A0..102DF
+	Carian,
+	/// 102E0..102FF
+	CopticEpactNumbers,
+	/// 10300..1032F
+	OldItalic,
+	/// 10330..1034F
+	Gothic,
+	/// 10350..1037F
+	OldPermic,
+	/// 10380..1039F
+	Ugaritic,
+	/// 103A0..103DF
+	OldPersian,
+	/// 10400..1044F
+	Deseret,
+	/// 10450..1047F
+	Shavian,
+	/// 10480..104AF
+	Osmanya,
+	/// 104B0..104FF
+	Osage,
+	/// 10500..1052F
+	Elbasan,
+	/// 10530..1056F
+	CaucasianAlbanian,
+	/// 10570..105BF
+	Vithkuqi,
+	/// 105C0..105FF
+	Todhri,
+	/// 10600..1077F
+	LinearA,
+	/// 10780..107BF
+	LatinExtendedF,
+	/// 10800..1083F
+	CypriotSyllabary,
+	/// 10840..1085F
+	ImperialAramaic,
+	/// 10860..1087F
+	Palmyrene,
+	/// 10880..108AF
+	Nabataean,
+	/// 108E0..108FF
+	Hatran,
+	/// 10900..1091F
+	Phoenician,
+	/// 10920..1093F
+	Lydian,
+	/// 10980..1099F
+	MeroiticHieroglyphs,
+	/// 109A0..109FF
+	MeroiticCursive,
+	/// 10A00..10A5F
+	Kharoshthi,
+	/// 10A60..10A7F
+	OldSouthArabian,
+	/// 10A80..10A9F
+	OldNorthArabian,
+	/// 10AC0..10AFF
+	Manichaean,
+	/// 10B00..10B3F
+	Avestan,
+	/// 10B40..10B5F
+	InscriptionalParthian,
+	/// 10B60..10B7F
+	InscriptionalPahlavi,
+	/// 10B80..10BAF
+	PsalterPahlavi,
+	/// 10C00..10C4F
+	OldTurkic,
+	/// 10C80..10CFF
+	OldHungarian,
+	/// 10D00..10D3F
+	HanifiRohingya,
+	/// 10D40..10D8F
+	Garay,
+	/// 10E60..10E7F
+	RumiNumeralSymbols,
+	/// 10E80..10EBF
+	Yezidi,
+	/// 10EC0..10EFF
+	ArabicExtendedC,
+	/// 10F00..10F2F
+	OldSogdian,
+	/// 10F30..10F6F
+	Sogdian,
+	/// 10F70..10FAF
+	OldUyghur,
+	/// 10FB0..10FDF
+	Chorasmian,
+	/// 10FE0..10FFF
+	Elymaic,
+	/// 11000..1107F
+	Brahmi,
+	/// 11080..110CF
+	Kaithi,
+	/// 110D0..110FF
+	SoraSompeng,
+	/// 11100..1114F
+	Chakma,
+	/// 11150..1117F
+	Mahajani,
+	/// 11180..111DF
+	Sharada,
+	/// 111E0..111FF
+	SinhalaArchaicNumbers,
+	/// 11200..1124F
+	Khojki,
+	/// 11280..112AF
+	Multani,
+	/// 112B0..112FF
+	Khudawadi,
+	/// 11300..1137F
+	Grantha,
+	/// 11380..113FF
+	TuluTigalari,
+	/// 11400..1147F
+	Newa,
+	/// 11480..114DF
+	Tirhuta,
+	/// 11580..115FF
+	Siddham,
+	/// 11600..1165F
+	Modi,
+	/// 11660..1167F
+	MongolianSupplement,
+	/// 11680..116CF
+	Takri,
+	/// 116D0..116FF
+	MyanmarExtendedC,
+	/// 11700..1174F
+	Ahom,
+	/// 11800..1184F
+	Dogra,
+	/// 118A0..118FF
+	WarangCiti,
+	/// 11900..1195F
+	DivesAkuru,
+	/// 119A0..119FF
+	Nandinagari,
+	/// 11A00..11A4F
+	ZanabazarSquare,
+	/// 11A50..11AAF
+	Soyombo,
+	/// 11AB0..11ABF
+	UnifiedCanadianAboriginalSyllabicsExtendedA,
+	/// 11AC0..11AFF
+	PauCinHau,
+	/// 11B00..11B5F
+	DevanagariExtendedA,
+	/// 11BC0..11BFF
+	Sunuwar,
+	/// 11C00..11C6F
+	Bhaiksuki,
+	/// 11C70..11CBF
+	Marchen,
+	/// 11D00..11D5F
+	MasaramGondi,
+	/// 11D60..11DAF
+	GunjalaGondi,
+	/// 11EE0..11EFF
+	Makasar,
+	/// 11F00..11F5F
+	Kawi,
+	/// 11FB0..11FBF
+	LisuSupplement,
+	/// 11FC0..11FFF
+	TamilSupplement,
+	/// 12000..123FF
+	Cuneiform,
+	/// 12400..1247F
+	CuneiformNumbersAndPunctuation,
+	/// 12480..1254F
+	EarlyDynasticCuneiform,
+	/// 12F90..12FFF
+	CyproMinoan,
+	/// 13000..1342F
+	EgyptianHieroglyphs,
+	/// 13430..1345F
+	EgyptianHieroglyphFormatControls,
+	/// 13460..143FF
+	EgyptianHieroglyphsExtendedA,
+	/// 14400..1467F
+	AnatolianHieroglyphs,
+	/// 16100..1613F
+	GurungKhema,
+	/// 16800..16A3F
+	BamumSupplement,
+	/// 16A40..16A6F
+	Mro,
+	/// 16A70..16ACF
+	Tangsa,
+	/// 16AD0..16AFF
+	BassaVah,
+	/// 16B00..16B8F
+	PahawhHmong,
+	/// 16D40..16D7F
+	KiratRai,
+	/// 16E40..16E9F
+	Medefaidrin,
+	/// 16F00..16F9F
+	Miao,
+	/// 16FE0..16FFF
+	IdeographicSymbolsAndPunctuation,
+	/// 17000..187FF
+	Tangut,
+	/// 18800..18AFF
+	TangutComponents,
+	/// 18B00..18CFF
+	KhitanSmallScript,
+	/// 18D00..18D7F
+	TangutSupplement,
+	/// 1AFF0..1AFFF
+	KanaExtendedB,
+	/// 1B000..1B0FF
+	KanaSupplement,
+	/// 1B100..1B12F
+	KanaExtendedA,
+	/// 1B130..1B16F
+	SmallKanaExtension,
+	/// 1B170..1B2FF
+	Nushu,
+	/// 1BC00..1BC9F
+	Duployan,
+	/// 1BCA0..1BCAF
+	ShorthandFormatControls,
+	/// 1CC00..1CEBF
+	SymbolsForLegacyComputingSupplement,
+	/// 1CF00..1CFCF
+	ZnamennyMusicalNotation,
+	/// 1D000..1D0FF
+	ByzantineMusicalSymbols,
+	/// 1D100..1D1FF
+	MusicalSymbols,
+	/// 1D200..1D24F
+	AncientGreekMusicalNotation,
+	/// 1D2C0..1D2DF
+	KaktovikNumerals,
+	/// 1D2E0..1D2FF
+	MayanNumerals,
+	/// 1D300..1D35F
+	TaiXuanJingSymbols,
+	/// 1D360..1D37F
+	CountingRodNumerals,
+	/// 1D400..1D7FF
+	MathematicalAlphanumericSymbols,
+	/// 1D800..1DAAF
+	SuttonSignWriting,
+	/// 1DF00..1DFFF
+	LatinExtendedG,
+	/// 1E000..1E02F
+	GlagoliticSupplement,
+	/// 1E030..1E08F
+	CyrillicExtendedD,
+	/// 1E100..1E14F
+	NyiakengPuachueHmong,
+	/// 1E290..1E2BF
+	Toto,
+	/// 1E2C0..1E2FF
+	Wancho,
+	/// 1E4D0..1E4FF
+	NagMundari,
+	/// 1E5D0..1E5FF
+	OlOnal,
+	/// 1E7E0..1E7FF
+	EthiopicExtendedB,
+	/// 1E800..1E8DF
+	MendeKikakui,
+	/// 1E900..1E95F
+	Adlam,
+	/// 1EC70..1ECBF
+	IndicSiyaqNumbers,
+	/// 1ED00..1ED4F
+	OttomanSiyaqNumbers,
+	/// 1EE00..1EEFF
+	ArabicMathematicalAlphabeticSymbols,
+	/// 1F000..1F02F
+	MahjongTiles,
+	/// 1F030..1F09F
+	DominoTiles,
+	/// 1F0A0..1F0FF
+	PlayingCards,
+	/// 1F100..1F1FF
+	EnclosedAlphanumericSupplement,
+	/// 1F200..1F2FF
+	EnclosedIdeographicSupplement,
+	/// 1F300..1F5FF
+	MiscellaneousSymbolsAndPictographs,
+	/// 1F600..1F64F
+	Emoticons,
+	/// 1F650..1F67F
+	OrnamentalDingbats,
+	/// 1F680..1F6FF
+	TransportAndMapSymbols,
+	/// 1F700..1F77F
+	AlchemicalSymbols,
+	/// 1F780..1F7FF
+	GeometricShapesExtended,
+	/// 1F800..1F8FF
+	SupplementalArrowsC,
+	/// 1F900..1F9FF
+	SupplementalSymbolsAndPictographs,
+	/// 1FA00..1FA6F
+	ChessSymbols,
+	/// 1FA70..1FAFF
+	SymbolsAndPictographsExtendedA,
+	/// 1FB00..1FBFF
+	SymbolsForLegacyComputing,
+	/// 20000..2A6DF
+	CJKUnifiedIdeographsExtensionB,
+	/// 2A700..2B73F
+	CJKUnifiedIdeographsExtensionC,
+	/// 2B740..2B81F
+	CJKUnifiedIdeographsExtensionD,
+	/// 2B820..2CEAF
+	CJKUnifiedIdeographsExtensionE,
+	/// 2CEB0..2EBEF
+	CJKUnifiedIdeographsExtensionF,
+	/// 2EBF0..2EE5F
+	CJKUnifiedIdeographsExtensionI,
+	/// 2F800..2FA1F
+	CJKCompatibilityIdeographsSupplement,
+	/// 30000..3134F
+	CJKUnifiedIdeographsExtensionG,
+	/// 31350..323AF
+	CJKUnifiedIdeographsExtensionH,
+	/// E0000..E007F
+	Tags,
+	/// E0100..E01EF
+	VariationSelectorsSupplement,
+	/// F0000..FFFFF
+	SupplementaryPrivateUseAreaA,
+	/// 100000..10FFFF
+	SupplementaryPrivateUseAreaB,
+}
+pub const BLOCKS: [(u32, u32, Block);338] = [
+	(0x0, 0x7f, Block::BasicLatin),
+	(0x80, 0xff, Block::Latin1Supplement),
+	(0x100, 0x17f, Block::LatinExtendedA),
+	(0x180, 0x24f, Block::LatinExtendedB),
+	(0x250, 0x2af, Block::IPAExtensions),
+	(0x2b0, 0x2ff, Block::SpacingModifierLetters),
+	(0x300, 0x36f, Block::CombiningDiacriticalMarks),
+	(0x370, 0x3ff, Block::GreekAndCoptic),
+	(0x400, 0x4ff, Block::Cyrillic),
+	(0x500, 0x52f, Block::CyrillicSupplement),
+	(0x530, 0x58f, Block::Armenian),
+	(0x590, 0x5ff, Block::Hebrew),
+	(0x600, 0x6ff, Block::Arabic),
+	(0x700, 0x74f, Block::Syriac),
+	(0x750, 0x77f, Block::ArabicSupplement),
+	(0x780, 0x7bf, Block::Thaana),
+	(0x7c0, 0x7ff, Block::NKo),
+	(0x800, 0x83f, Block::Samaritan),
+	(0x840, 0x85f, Block::Mandaic),
+	(0x860, 0x86f, Block::SyriacSupplement),
+	(0x870, 0x89f, Block::ArabicExtendedB),
+	(0x8a0, 0x8ff, Block::ArabicExtendedA),
+	(0x900, 0x97f, Block::Devanagari),
+	(0x980, 0x9ff, Block::Bengali),
+	(0xa00, 0xa7f, Block::Gurmukhi),
+	(0xa80, 0xaff, Block::Gujarati),
+	(0xb00, 0xb7f, Block::Oriya),
+	(0xb80, 0xbff, Block::Tamil),
+	(0xc00, 0xc7f, Block::Telugu),
+	(0xc80, 0xcff, Block::Kannada),
+	(0xd00, 0xd7f, Block::Malayalam),
+	(0xd80, 0xdff, Block::Sinhala),
+	(0xe00, 0xe7f, Block::Thai),
+	(0xe80, 0xeff, Block::Lao),
+	(0xf00, 0xfff, Block::Tibetan),
+	(0x1000, 0x109f, Block::Myanmar),
+	(0x10a0, 0x10ff, Block::Georgian),
+	(0x1100, 0x11ff, Block::HangulJamo),
+	(0x1200, 0x137f, Block::Ethiopic),
+	(0x1380, 0x139f, Block::EthiopicSupplement),
+	(0x13a0, 0x13ff, Block::Cherokee),
+	(0x1400, 0x167f, Block::UnifiedCanadianAboriginalSyllabics),
+	(0x1680, 0x169f, Block::Ogham),
+	(0x16a0, 0x16ff, Block::Runic),
+	(0x1700, 0x171f, Block::Tagalog),
+	(0x1720, 0x173f, Block::Hanunoo),
+	(0x1740, 0x175f, Block::Buhid),
+	(0x1760, 0x177f, Block::Tagbanwa),
+	(0x1780, 0x17ff, Block::Khmer),
+	(0x1800, 0x18af, Block::Mongolian),
+	(0x18b0, 0x18ff, Block::UnifiedCanadianAboriginalSyllabicsExtended),
+	(0x1900, 0x194f, Block::Limbu),
+	(0x1950, 0x197f, Block::TaiLe),
+	(0x1980, 0x19df, Block::NewTaiLue),
+	(0x19e0, 0x19ff, Block::KhmerSymbols),
+	(0x1a00, 0x1a1f, Block::Buginese),
+	(0x1a20, 0x1aaf, Block::TaiTham),
+	(0x1ab0, 0x1aff, Block::CombiningDiacriticalMarksExtended),
+	(0x1b00, 0x1b7f, Block::Balinese),
+	(0x1b80, 0x1bbf, Block::Sundanese),
+	(0x1bc0, 0x1bff, Block::Batak),
+	(0x1c00, 0x1c4f, Block::Lepcha),
+	(0x1c50, 0x1c7f, Block::OlChiki),
+	(0x1c80, 0x1c8f, Block::CyrillicExtendedC),
+	(0x1c90, 0x1cbf, Block::GeorgianExtended),
+	(0x1cc0, 0x1ccf, Block::SundaneseSupplement),
+	(0x1cd0, 0x1cff, Block::VedicExtensions),
+	(0x1d00, 0x1d7f, Block::PhoneticExtensions),
+	(0x1d80, 0x1dbf, Block::PhoneticExtensionsSupplement),
+	(0x1dc0, 0x1dff, Block::CombiningDiacriticalMarksSupplement),
+	(0x1e00, 0x1eff, Block::LatinExtendedAdditional),
+	(0x1f00, 0x1fff, Block::GreekExtended),
+	(0x2000, 0x206f, Block::GeneralPunctuation),
+	(0x2070, 0x209f, Block::SuperscriptsAndSubscripts),
+	(0x20a0, 0x20cf, Block::CurrencySymbols),
+	(0x20d0, 0x20ff, Block::CombiningDiacriticalMarksForSymbols),
+	(0x2100, 0x214f, Block::LetterlikeSymbols),
+	(0x2150, 0x218f, Block::NumberForms),
+	(0x2190, 0x21ff, Block::Arrows),
+	(0x2200, 0x22ff, Block::MathematicalOperators),
+	(0x2300, 0x23ff, Block::MiscellaneousTechnical),
+	(0x2400, 0x243f, Block::ControlPictures),
+	(0x2440, 0x245f, Block::OpticalCharacterRecognition),
+	(0x2460, 0x24ff, Block::EnclosedAlphanumerics),
+	(0x2500, 0x257f, Block::BoxDrawing),
+	(0x2580, 0x259f, Block::BlockElements),
+	(0x25a0, 0x25ff, Block::GeometricShapes),
+	(0x2600, 0x26ff, Block::MiscellaneousSymbols),
+	(0x2700, 0x27bf, Block::Dingbats),
+	(0x27c0, 0x27ef, Block::MiscellaneousMathematicalSymbolsA),
+	(0x27f0, 0x27ff, Block::SupplementalArrowsA),
+	(0x2800, 0x28ff, Block::BraillePatterns),
+	(0x2900, 0x297f, Block::SupplementalArrowsB),
+	(0x2980, 0x29ff, Block::MiscellaneousMathematicalSymbolsB),
+	(0x2a00, 0x2aff, Block::SupplementalMathematicalOperators),
+	(0x2b00, 0x2bff, Block::MiscellaneousSymbolsAndArrows),
+	(0x2c00, 0x2c5f, Block::Glagolitic),
+	(0x2c60, 0x2c7f, Block::LatinExtendedC),
+	(0x2c80, 0x2cff, Block::Coptic),
+	(0x2d00, 0x2d2f, Block::GeorgianSupplement),
+	(0x2d30, 0x2d7f, Block::Tifinagh),
+	(0x2d80, 0x2ddf, Block::EthiopicExtended),
+	(0x2de0, 0x2dff, Block::CyrillicExtendedA),
+	(0x2e00, 0x2e7f, Block::SupplementalPunctuation),
+	(0x2e80, 0x2eff, Block::CJKRadicalsSupplement),
+	(0x2f00, 0x2fdf, Block::KangxiRadicals),
+	(0x2ff0, 0x2fff, Block::IdeographicDescriptionCharacters),
+	(0x3000, 0x303f, Block::CJKSymbolsAndPunctuation),
+	(0x3040, 0x309f, Block::Hiragana),
+	(0x30a0, 0x30ff, Block::Katakana),
+	(0x3100, 0x312f, Block::Bopomofo),
+	(0x3130, 0x318f, Block::HangulCompatibilityJamo),
+	(0x3190, 0x319f, Block::Kanbun),
+	(0x31a0, 0x31bf, Block::BopomofoExtended),
+	(0x31c0, 0x31ef, Block::CJKStrokes),
+	(0x31f0, 0x31ff, Block::KatakanaPhoneticExtensions),
+	(0x3200, 0x32ff, Block::EnclosedCJKLettersAndMonths),
+	(0x3300, 0x33ff, Block::CJKCompatibility),
+	(0x3400, 0x4dbf, Block::CJKUnifiedIdeographsExtensionA),
+	(0x4dc0, 0x4dff, Block::YijingHexagramSymbols),
+	(0x4e00, 0x9fff, Block::CJKUnifiedIdeographs),
+	(0xa000, 0xa48f, Block::YiSyllables),
+	(0xa490, 0xa4cf, Block::YiRadicals),
+	(0xa4d0, 0xa4ff, Block::Lisu),
+	(0xa500, 0xa63f, Block::Vai),
+	(0xa640, 0xa69f, Block::CyrillicExtendedB),
+	(0xa6a0, 0xa6ff, Block::Bamum),
+	(0xa700, 0xa71f, Block::ModifierToneLetters),
+	(0xa720, 0xa7ff, Block::LatinExtendedD),
+	(0xa800, 0xa82f, Block::SylotiNagri),
+	(0xa830, 0xa83f, Block::CommonIndicNumberForms),
+	(0xa840, 0xa87f, Block::PhagsPa),
+	(0xa880, 0xa8df, Block::Saurashtra),
+	(0xa8e0, 0xa8ff, Block::DevanagariExtended),
+	(0xa900, 0xa92f, Block::KayahLi),
+	(0xa930, 0xa95f, Block::Rejang),
+	(0xa960, 0xa97f, Block::HangulJamoExtendedA),
+	(0xa980, 0xa9df, Block::Javanese),
+	(0xa9e0, 0xa9ff, Block::MyanmarExtendedB),
+	(0xaa00, 0xaa5f, Block::Cham),
+	(0xaa60, 0xaa7f, Block::MyanmarExtendedA),
+	(0xaa80, 0xaadf, Block::TaiViet),
+	(0xaae0, 0xaaff, Block::MeeteiMayekExtensions),
+	(0xab00, 0xab2f, Block::EthiopicExtendedA),
+	(0xab30, 0xab6f, Block::LatinExtendedE),
+	(0xab70, 0xabbf, Block::CherokeeSupplement),
+	(0xabc0, 0xabff, Block::MeeteiMayek),
+	(0xac00, 0xd7af, Block::HangulSyllables),
+	(0xd7b0, 0xd7ff, Block::HangulJamoExtendedB),
+	(0xd800, 0xdb7f, Block::HighSurrogates),
+	(0xdb80, 0xdbff, Block::HighPrivateUseSurrogates),
+	(0xdc00, 0xdfff, Block::LowSurrogates),
+	(0xe000, 0xf8ff, Block::PrivateUseArea),
+	(0xf900, 0xfaff, Block::CJKCompatibilityIdeographs),
+	(0xfb00, 0xfb4f, Block::AlphabeticPresentationForms),
+	(0xfb50, 0xfdff, Block::ArabicPresentationFormsA),
+	(0xfe00, 0xfe0f, Block::VariationSelectors),
+	(0xfe10, 0xfe1f, Block::VerticalForms),
+	(0xfe20, 0xfe2f, Block::CombiningHalfMarks),
+	(0xfe30, 0xfe4f, Block::CJKCompatibilityForms),
+	(0xfe50, 0xfe6f, Block::SmallFormVariants),
+	(0xfe70, 0xfeff, Block::ArabicPresentationFormsB),
+	(0xff00, 0xffef, Block::HalfwidthAndFullwidthForms),
+	(0xfff0, 0xffff, Block::Specials),
+	(0x10000, 0x1007f, Block::LinearBSyllabary),
+	(0x10080, 0x100ff, Block::LinearBIdeograms),
+	(0x10100, 0x1013f, Block::AegeanNumbers),
+	(0x10140, 0x1018f, Block::AncientGreekNumbers),
+	(0x10190, 0x101cf, Block::AncientSymbols),
+	(0x101d0, 0x101ff, Block::PhaistosDisc),
+	(0x10280, 0x1029f, Block::Lycian),
+	(0x102a0, 0x102df, Block::Carian),
+	(0x102e0, 0x102ff, Block::CopticEpactNumbers),
+	(0x10300, 0x1032f, Block::OldItalic),
+	(0x10330, 0x1034f, Block::Gothic),
+	(0x10350, 0x1037f, Block::OldPermic),
+	(0x10380, 0x1039f, Block::Ugaritic),
+	(0x103a0, 0x103df, Block::OldPersian),
+	(0x10400, 0x1044f, Block::Deseret),
+	(0x10450, 0x1047f, Block::Shavian),
+	(0x10480, 0x104af, Block::Osmanya),
+	(0x104b0, 0x104ff, Block::Osage),
+	(0x10500, 0x1052f, Block::Elbasan),
+	(0x10530, 0x1056f, Block::CaucasianAlbanian),
+	(0x10570, 0x105bf, Block::Vithkuqi),
+	(0x105c0, 0x105ff, Block::Todhri),
+	(0x10600, 0x1077f, Block::LinearA),
+	(0x10780, 0x107bf, Block::LatinExtendedF),
+	(0x10800, 0x1083f, Block::CypriotSyllabary),
+	(0x10840, 0x1085f, Block::ImperialAramaic),
+	(0x10860, 0x1087f, Block::Palmyrene),
+	(0x10880, 0x108af, Block::Nabataean),
+	(0x108e0, 0x108ff, Block::Hatran),
+	(0x10900, 0x1091f, Block::Phoenician),
+	(0x10920, 0x1093f, Block::Lydian),
+	(0x10980, 0x1099f, Block::MeroiticHieroglyphs),
+	(0x109a0, 0x109ff, Block::MeroiticCursive),
+	(0x10a00, 0x10a5f, Block::Kharoshthi),
+	(0x10a60, 0x10a7f, Block::OldSouthArabian),
+	(0x10a80, 0x10a9f, Block::OldNorthArabian),
+	(0x10ac0, 0x10aff, Block::Manichaean),
+	(0x10b00, 0x10b3f, Block::Avestan),
+	(0x10b40, 0x10b5f, Block::InscriptionalParthian),
+	(0x10b60, 0x10b7f, Block::InscriptionalPahlavi),
+	(0x10b80, 0x10baf, Block::PsalterPahlavi),
+	(0x10c00, 0x10c4f, Block::OldTurkic),
+	(0x10c80, 0x10cff, Block::OldHungarian),
+	(0x10d00, 0x10d3f, Block::HanifiRohingya),
+	(0x10d40, 0x10d8f, Block::Garay),
+	(0x10e60, 0x10e7f, Block::RumiNumeralSymbols),
+	(0x10e80, 0x10ebf, Block::Yezidi),
+	(0x10ec0, 0x10eff, Block::ArabicExtendedC),
+	(0x10f00, 0x10f2f, Block::OldSogdian),
+	(0x10f30, 0x10f6f, Block::Sogdian),
+	(0x10f70, 0x10faf, Block::OldUyghur),
+	(0x10fb0, 0x10fdf, Block::Chorasmian),
+	(0x10fe0, 0x10fff, Block::Elymaic),
+	(0x11000, 0x1107f, Block::Brahmi),
+	(0x11080, 0x110cf, Block::Kaithi),
+	(0x110d0, 0x110ff, Block::SoraSompeng),
+	(0x11100, 0x1114f, Block::Chakma),
+	(0x11150, 0x1117f, Block::Mahajani),
+	(0x11180, 0x111df, Block::Sharada),
+	(0x111e0, 0x111ff, Block::SinhalaArchaicNumbers),
+	(0x11200, 0x1124f, Block::Khojki),
+	(0x11280, 0x112af, Block::Multani),
+	(0x112b0, 0x112ff, Block::Khudawadi),
+	(0x11300, 0x1137f, Block::Grantha),
+	(0x11380, 0x113ff, Block::TuluTigalari),
+	(0x11400, 0x1147f, Block::Newa),
+	(0x11480, 0x114df, Block::Tirhuta),
+	(0x11580, 0x115ff, Block::Siddham),
+	(0x11600, 0x1165f, Block::Modi),
+	(0x11660, 0x1167f, Block::MongolianSupplement),
+	(0x11680, 0x116cf, Block::Takri),
+	(0x116d0, 0x116ff, Block::MyanmarExtendedC),
+	(0x11700, 0x1174f, Block::Ahom),
+	(0x11800, 0x1184f, Block::Dogra),
+	(0x118a0, 0x118ff, Block::WarangCiti),
+	(0x11900, 0x1195f, Block::DivesAkuru),
+	(0x119a0, 0x119ff, Block::Nandinagari),
+	(0x11a00, 0x11a4f, Block::ZanabazarSquare),
+	(0x11a50, 0x11aaf, Block::Soyombo),
+	(0x11ab0, 0x11abf, Block::UnifiedCanadianAboriginalSyllabicsExtendedA),
+	(0x11ac0, 0x11aff, Block::PauCinHau),
+	(0x11b00, 0x11b5f, Block::DevanagariExtendedA),
+	(0x11bc0, 0x11bff, Block::Sunuwar),
+	(0x11c00, 0x11c6f, Block::Bhaiksuki),
+	(0x11c70, 0x11cbf, Block::Marchen),
+	(0x11d00, 0x11d5f, Block::MasaramGondi),
+	(0x11d60, 0x11daf, Block::GunjalaGondi),
+	(0x11ee0, 0x11eff, Block::Makasar),
+	(0x11f00, 0x11f5f, Block::Kawi),
+	(0x11fb0, 0x11fbf, Block::LisuSupplement),
+	(0x11fc0, 0x11fff, Block::TamilSupplement),
+	(0x12000, 0x123ff, Block::Cuneiform),
+	(0x12400, 0x1247f, Block::CuneiformNumbersAndPunctuation),
+	(0x12480, 0x1254f, Block::EarlyDynasticCuneiform),
+	(0x12f90, 0x12fff, Block::CyproMinoan),
+	(0x13000, 0x1342f, Block::EgyptianHieroglyphs),
+	(0x13430, 0x1345f, Block::EgyptianHieroglyphFormatControls),
+	(0x13460, 0x143ff, Block::EgyptianHieroglyphsExtendedA),
+	(0x14400, 0x1467f, Block::AnatolianHieroglyphs),
+	(0x16100, 0x1613f, Block::GurungKhema),
+	(0x16800, 0x16a3f, Block::BamumSupplement),
+	(0x16a40, 0x16a6f, Block::Mro),
+	(0x16a70, 0x16acf, Block::Tangsa),
+	(0x16ad0, 0x16aff, Block::BassaVah),
+	(0x16b00, 0x16b8f, Block::PahawhHmong),
+	(0x16d40, 0x16d7f, Block::KiratRai),
+	(0x16e40, 0x16e9f, Block::Medefaidrin),
+	(0x16f00, 0x16f9f, Block::Miao),
+	(0x16fe0, 0x16fff, Block::IdeographicSymbolsAndPunctuation),
+	(0x17000, 0x187ff, Block::Tangut),
+	(0x18800, 0x18aff, Block::TangutComponents),
+	(0x18b00, 0x18cff, Block::KhitanSmallScript),
+	(0x18d00, 0x18d7f, Block::TangutSupplement),
+	(0x1aff0, 0x1afff, Block::KanaExtendedB),
+	(0x1b000, 0x1b0ff, Block::KanaSupplement),
+	(0x1b100, 0x1b12f, Block::KanaExtendedA),
+	(0x1b130, 0x1b16f, Block::SmallKanaExtension),
+	(0x1b170, 0x1b2ff, Block::Nushu),
+	(0x1bc00, 0x1bc9f, Block::Duployan),
+	(0x1bca0, 0x1bcaf, Block::ShorthandFormatControls),
+	(0x1cc00, 0x1cebf, Block::SymbolsForLegacyComputingSupplement),
+	(0x1cf00, 0x1cfcf, Block::ZnamennyMusicalNotation),
+	(0x1d000, 0x1d0ff, Block::ByzantineMusicalSymbols),
+	(0x1d100, 0x1d1ff, Block::MusicalSymbols),
+	(0x1d200, 0x1d24f, Block::AncientGreekMusicalNotation),
+	(0x1d2c0, 0x1d2df, Block::KaktovikNumerals),
+	(0x1d2e0, 0x1d2ff, Block::MayanNumerals),
+	(0x1d300, 0x1d35f, Block::TaiXuanJingSymbols),
+	(0x1d360, 0x1d37f, Block::CountingRodNumerals),
+	(0x1d400, 0x1d7ff, Block::MathematicalAlphanumericSymbols),
+	(0x1d800, 0x1daaf, Block::SuttonSignWriting),
+	(0x1df00, 0x1dfff, Block::LatinExtendedG),
+	(0x1e000, 0x1e02f, Block::GlagoliticSupplement),
+	(0x1e030, 0x1e08f, Block::CyrillicExtendedD),
+	(0x1e100, 0x1e14f, Block::NyiakengPuachueHmong),
+	(0x1e290, 0x1e2bf, Block::Toto),
+	(0x1e2c0, 0x1e2ff, Block::Wancho),
+	(0x1e4d0, 0x1e4ff, Block::NagMundari),
+	(0x1e5d0, 0x1e5ff, Block::OlOnal),
+	(0x1e7e0, 0x1e7ff, Block::EthiopicExtendedB),
+	(0x1e800, 0x1e8df, Block::MendeKikakui),
+	(0x1e900, 0x1e95f, Block::Adlam),
+	(0x1ec70, 0x1ecbf, Block::IndicSiyaqNumbers),
+	(0x1ed00, 0x1ed4f, Block::OttomanSiyaqNumbers),
+	(0x1ee00, 0x1eeff, Block::ArabicMathematicalAlphabeticSymbols),
+	(0x1f000, 0x1f02f, Block::MahjongTiles),
+	(0x1f030, 0x1f09f, Block::DominoTiles),
+	(0x1f0a0, 0x1f0ff, Block::PlayingCards),
+	(0x1f100, 0x1f1ff, Block::EnclosedAlphanumericSupplement),
+	(0x1f200, 0x1f2ff, Block::EnclosedIdeographicSupplement),
+	(0x1f300, 0x1f5ff, Block::MiscellaneousSymbolsAndPictographs),
+	(0x1f600, 0x1f64f, Block::Emoticons),
+	(0x1f650, 0x1f67f, Block::OrnamentalDingbats),
+	(0x1f680, 0x1f6ff, Block::TransportAndMapSymbols),
+	(0x1f700, 0x1f77f, Block::AlchemicalSymbols),
+	(0x1f780, 0x1f7ff, Block::GeometricShapesExtended),
+	(0x1f800, 0x1f8ff, Block::SupplementalArrowsC),
+	(0x1f900, 0x1f9ff, Block::SupplementalSymbolsAndPictographs),
+	(0x1fa00, 0x1fa6f, Block::ChessSymbols),
+	(0x1fa70, 0x1faff, Block::SymbolsAndPictographsExtendedA),
+	(0x1fb00, 0x1fbff, Block::SymbolsForLegacyComputing),
+	(0x20000, 0x2a6df, Block::CJKUnifiedIdeographsExtensionB),
+	(0x2a700, 0x2b73f, Block::CJKUnifiedIdeographsExtensionC),
+	(0x2b740, 0x2b81f, Block::CJKUnifiedIdeographsExtensionD),
+	(0x2b820, 0x2ceaf, Block::CJKUnifiedIdeographsExtensionE),
+	(0x2ceb0, 0x2ebef, Block::CJKUnifiedIdeographsExtensionF),
+	(0x2ebf0, 0x2ee5f, Block::CJKUnifiedIdeographsExtensionI),
+	(0x2f800, 0x2fa1f, Block::CJKCompatibilityIdeographsSupplement),
+	(0x30000, 0x3134f, Block::CJKUnifiedIdeographsExtensionG),
+	(0x31350, 0x323af, Block::CJKUnifiedIdeographsExtensionH),
+	(0xe0000, 0xe007f, Block::Tags),
+	(0xe0100, 0xe01ef, Block::VariationSelectorsSupplement),
+	(0xf0000, 0xfffff, Block::SupplementaryPrivateUseAreaA),
+	(0x100000, 0x10ffff, Block::SupplementaryPrivateUseAreaB),
+];
+pub const BLOCK_NAMES: [&str;338] = [
+	"Basic Latin",
+	"Latin-1 Supplement",
+	"Latin Extended-A",
+	"Latin Extended-B",
+	"IPA Extensions",
+	"Spacing Modifier Letters",
+	"Combining Diacritical Marks",
+	"Greek and Coptic",
+	"Cyrillic",
+	"Cyrillic Supplement",
+	"Armenian",
+	"Hebrew",
+	"Arabic",
+	"Syriac",
+	"Arabic Supplement",
+	"Thaana",
+	"NKo",
+	"Samaritan",
+	"Mandaic",
+	"Syriac Supplement",
+	"Arabic Extended-B",
+	"Arabic Extended-A",
+	"Devanagari",
+	"Bengali",
+	"Gurmukhi",
+	"Gujarati",
+	"Oriya",
+	"Tamil",
+	"Telugu",
+	"Kannada",
+	"Malayalam",
+	"Sinhala",
+	"Thai",
+	"Lao",
+	"Tibetan",
+	"Myanmar",
+	"Georgian",
+	"Hangul Jamo",
+	"Ethiopic",
+	"Ethiopic Supplement",
+	"Cherokee",
+	"Unified Canadian Aboriginal Syllabics",
+	"Ogham",
+	"Runic",
+	"Tagalog",
+	"Hanunoo",
+	"Buhid",
+	"Tagbanwa",
+	"Khmer",
+	"Mongolian",
+	"Unified Canadian Aboriginal Syllabics Extended",
+	"Limbu",
+	"Tai Le",
+	"New Tai Lue",
+	"Khmer Symbols",
+	"Buginese",
+	"Tai Tham",
+	"Combining Diacritical Marks Extended",
+	"Balinese",
+	"Sundanese",
+	"Batak",
+	"Lepcha",
+	"Ol Chiki",
+	"Cyrillic Extended-C",
+	"Georgian Extended",
+	"Sundanese Supplement",
+	"Vedic Extensions",
+	"Phonetic Extensions",
+	"Phonetic Extensions Supplement",
+	"Combining Diacritical Marks Supplement",
+	"Latin Extended Additional",
+	"Greek Extended",
+	"General Punctuation",
+	"Superscripts and Subscripts",
+	"Currency Symbols",
+	"Combining Diacritical Marks for Symbols",
+	"Letterlike Symbols",
+	"Number Forms",
+	"Arrows",
+	"Mathematical Operators",
+	"Miscellaneous Technical",
+	"Control Pictures",
+	"Optical Character Recognition",
+	"Enclosed Alphanumerics",
+	"Box Drawing",
+	"Block Elements",
+	"Geometric Shapes",
+	"Miscellaneous Symbols",
+	"Dingbats",
+	"Miscellaneous Mathematical Symbols-A",
+	"Supplemental Arrows-A",
+	"Braille Patterns",
+	"Supplemental Arrows-B",
+	"Miscellaneous Mathematical Symbols-B",
+	"Supplemental Mathematical Operators",
+	"Miscellaneous Symbols and Arrows",
+	"Glagolitic",
+	"Latin Extended-C",
+	"Coptic",
+	"Georgian Supplement",
+	"Tifinagh",
+	"Ethiopic Extended",
+	"Cyrillic Extended-A",
+	"Supplemental Punctuation",
+	"CJK Radicals Supplement",
+	"Kangxi Radicals",
+	"Ideographic Description Characters",
+	"CJK Symbols and Punctuation",
+	"Hiragana",
+	"Katakana",
+	"Bopomofo",
+	"Hangul Compatibility Jamo",
+	"Kanbun",
+	"Bopomofo Extended",
+	"CJK Strokes",
+	"Katakana Phonetic Extensions",
+	"Enclosed CJK Letters and Months",
+	"CJK Compatibility",
+	"CJK Unified Ideographs Extension A",
+	"Yijing Hexagram Symbols",
+	"CJK Unified Ideographs",
+	"Yi Syllables",
+	"Yi Radicals",
+	"Lisu",
+	"Vai",
+	"Cyrillic Extended-B",
+	"Bamum",
+	"Modifier Tone Letters",
+	"Latin Extended-D",
+	"Syloti Nagri",
+	"Common Indic Number Forms",
+	"Phags-pa",
+	"Saurashtra",
+	"Devanagari Extended",
+	"Kayah Li",
+	"Rejang",
+	"Hangul Jamo Extended-A",
+	"Javanese",
+	"Myanmar Extended-B",
+	"Cham",
+	"Myanmar Extended-A",
+	"Tai Viet",
+	"Meetei Mayek Extensions",
+	"Ethiopic Extended-A",
+	"Latin Extended-E",
+	"Cherokee Supplement",
+	"Meetei Mayek",
+	"Hangul Syllables",
+	"Hangul Jamo Extended-B",
+	"High Surrogates",
+	"High Private Use Surrogates",
+	"Low Surrogates",
+	"Private Use Area",
+	"CJK Compatibility Ideographs",
+	"Alphabetic Presentation Forms",
+	"Arabic Presentation Forms-A",
+	"Variation Selectors",
+	"Vertical Forms",
+	"Combining Half Marks",
+	"CJK Compatibility Forms",
+	"Small Form Variants",
+	"Arabic Presentation Forms-B",
+	"Halfwidth and Fullwidth Forms",
+	"Specials",
+	"Linear B Syllabary",
+	"Linear B Ideograms",
+	"Aegean Numbers",
+	"Ancient Greek Numbers",
+	"Ancient Symbols",
+	"Phaistos Disc",
+	"Lycian",
+	"Carian",
+	"Coptic Epact Numbers",
+	"Old Italic",
+	"Gothic",
+	"Old Permic",
+	"Ugaritic",
+	"Old Persian",
+	"Deseret",
+	"Shavian",
+	"Osmanya",
+	"Osage",
+	"Elbasan",
+	"Caucasian Albanian",
+	"Vithkuqi",
+	"Todhri",
+	"Linear A",
+	"Latin Extended-F",
+	"Cypriot Syllabary",
+	"Imperial Aramaic",
+	"Palmyrene",
+	"Nabataean",
+	"Hatran",
+	"Phoenician",
+	"Lydian",
+	"Meroitic Hieroglyphs",
+	"Meroitic Cursive",
+	"Kharoshthi",
+	"Old South Arabian",
+	"Old North Arabian",
+	"Manichaean",
+	"Avestan",
+	"Inscriptional Parthian",
+	"Inscriptional Pahlavi",
+	"Psalter Pahlavi",
+	"Old Turkic",
+	"Old Hungarian",
+	"Hanifi Rohingya",
+	"Garay",
+	"Rumi Numeral Symbols",
+	"Yezidi",
+	"Arabic Extended-C",
+	"Old Sogdian",
+	"Sogdian",
+	"Old Uyghur",
+	"Chorasmian",
+	"Elymaic",
+	"Brahmi",
+	"Kaithi",
+	"Sora Sompeng",
+	"Chakma",
+	"Mahajani",
+	"Sharada",
+	"Sinhala Archaic Numbers",
+	"Khojki",
+	"Multani",
+	"Khudawadi",
+	"Grantha",
+	"Tulu-Tigalari",
+	"Newa",
+	"Tirhuta",
+	"Siddham",
+	"Modi",
+	"Mongolian Supplement",
+	"Takri",
+	"Myanmar Extended-C",
+	"Ahom",
+	"Dogra",
+	"Warang Citi",
+	"Dives Akuru",
+	"Nandinagari",
+	"Zanabazar Square",
+	"Soyombo",
+	"Unified Canadian Aboriginal Syllabics Extended-A",
+	"Pau Cin Hau",
+	"Devanagari Extended-A",
+	"Sunuwar",
+	"Bhaiksuki",
+	"Marchen",
+	"Masaram Gondi",
+	"Gunjala Gondi",
+	"Makasar",
+	"Kawi",
+	"Lisu Supplement",
+	"Tamil Supplement",
+	"Cuneiform",
+	"Cuneiform Numbers and Punctuation",
+	"Early Dynastic Cuneiform",
+	"Cypro-Minoan",
+	"Egyptian Hieroglyphs",
+	"Egyptian Hieroglyph Format Controls",
+	"Egyptian Hieroglyphs Extended-A",
+	"Anatolian Hieroglyphs",
+	"Gurung Khema",
+	"Bamum Supplement",
+	"Mro",
+	"Tangsa",
+	"Bassa Vah",
+	"Pahawh Hmong",
+	"Kirat Rai",
+	"Medefaidrin",
+	"Miao",
+	"Ideographic Symbols and Punctuation",
+	"Tangut",
+	"Tangut Components",
+	"Khitan Small Script",
+	"Tangut Supplement",
+	"Kana Extended-B",
+	"Kana Supplement",
+	"Kana Extended-A",
+	"Small Kana Extension",
+	"Nushu",
+	"Duployan",
+	"Shorthand Format Controls",
+	"Symbols for Legacy Computing Supplement",
+	"Znamenny Musical Notation",
+	"Byzantine Musical Symbols",
+	"Musical Symbols",
+	"Ancient Greek Musical Notation",
+	"Kaktovik Numerals",
+	"Mayan Numerals",
+	"Tai Xuan Jing Symbols",
+	"Counting Rod Numerals",
+	"Mathematical Alphanumeric Symbols",
+	"Sutton SignWriting",
+	"Latin Extended-G",
+	"Glagolitic Supplement",
+	"Cyrillic Extended-D",
+	"Nyiakeng Puachue Hmong",
+	"Toto",
+	"Wancho",
+	"Nag Mundari",
+	"Ol Onal",
+	"Ethiopic Extended-B",
+	"Mende Kikakui",
+	"Adlam",
+	"Indic Siyaq Numbers",
+	"Ottoman Siyaq Numbers",
+	"Arabic Mathematical Alphabetic Symbols",
+	"Mahjong Tiles",
+	"Domino Tiles",
+	"Playing Cards",
+	"Enclosed Alphanumeric Supplement",
+	"Enclosed Ideographic Supplement",
+	"Miscellaneous Symbols and Pictographs",
+	"Emoticons",
+	"Ornamental Dingbats",
+	"Transport and Map Symbols",
+	"Alchemical Symbols",
+	"Geometric Shapes Extended",
+	"Supplemental Arrows-C",
+	"Supplemental Symbols and Pictographs",
+	"Chess Symbols",
+	"Symbols and Pictographs Extended-A",
+	"Symbols for Legacy Computing",
+	"CJK Unified Ideographs Extension B",
+	"CJK Unified Ideographs Extension C",
+	"CJK Unified Ideographs Extension D",
+	"CJK Unified Ideographs Extension E",
+	"CJK Unified Ideographs Extension F",
+	"CJK Unified Ideographs Extension I",
+	"CJK Compatibility Ideographs Supplement",
+	"CJK Unified Ideographs Extension G",
+	"CJK Unified Ideographs Extension H",
+	"Tags",
+	"Variation Selectors Supplement",
+	"Supplementary Private Use Area-A",
+	"Supplementary Private Use Area-B",
+];
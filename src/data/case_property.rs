@@ -0,0 +1,30415 @@
+// GENERATED CODE DO NOT MANUALLY EDIT
+pub const CASE_TABLE: [u8;0x1100] = [
+	 0, // 0x0
+	 1, // 0x1
+	 2, // 0x2
+	 3, // 0x3
+	 4, // 0x4
+	 5, // 0x5
+	 6, // 0x6
+	 7, // 0x7
+	 8, // 0x8
+	 9, // 0x9
+	 10, // 0xa
+	 11, // 0xb
+	 12, // 0xc
+	 13, // 0xd
+	 14, // 0xe
+	 15, // 0xf
+	 16, // 0x10
+	 17, // 0x11
+	 17, // 0x12
+	 18, // 0x13
+	 17, // 0x14
+	 17, // 0x15
+	 17, // 0x16
+	 19, // 0x17
+	 20, // 0x18
+	 21, // 0x19
+	 22, // 0x1a
+	 23, // 0x1b
+	 24, // 0x1c
+	 25, // 0x1d
+	 26, // 0x1e
+	 27, // 0x1f
+	 28, // 0x20
+	 29, // 0x21
+	 17, // 0x22
+	 17, // 0x23
+	 30, // 0x24
+	 17, // 0x25
+	 17, // 0x26
+	 17, // 0x27
+	 17, // 0x28
+	 17, // 0x29
+	 17, // 0x2a
+	 17, // 0x2b
+	 31, // 0x2c
+	 32, // 0x2d
+	 33, // 0x2e
+	 17, // 0x2f
+	 34, // 0x30
+	 17, // 0x31
+	 17, // 0x32
+	 17, // 0x33
+	 17, // 0x34
+	 17, // 0x35
+	 17, // 0x36
+	 17, // 0x37
+	 17, // 0x38
+	 17, // 0x39
+	 17, // 0x3a
+	 17, // 0x3b
+	 17, // 0x3c
+	 17, // 0x3d
+	 17, // 0x3e
+	 17, // 0x3f
+	 17, // 0x40
+	 17, // 0x41
+	 17, // 0x42
+	 17, // 0x43
+	 17, // 0x44
+	 17, // 0x45
+	 17, // 0x46
+	 17, // 0x47
+	 17, // 0x48
+	 17, // 0x49
+	 17, // 0x4a
+	 17, // 0x4b
+	 17, // 0x4c
+	 17, // 0x4d
+	 17, // 0x4e
+	 17, // 0x4f
+	 17, // 0x50
+	 17, // 0x51
+	 17, // 0x52
+	 17, // 0x53
+	 17, // 0x54
+	 17, // 0x55
+	 17, // 0x56
+	 17, // 0x57
+	 17, // 0x58
+	 17, // 0x59
+	 17, // 0x5a
+	 17, // 0x5b
+	 17, // 0x5c
+	 17, // 0x5d
+	 17, // 0x5e
+	 17, // 0x5f
+	 17, // 0x60
+	 17, // 0x61
+	 17, // 0x62
+	 17, // 0x63
+	 17, // 0x64
+	 17, // 0x65
+	 17, // 0x66
+	 17, // 0x67
+	 17, // 0x68
+	 17, // 0x69
+	 17, // 0x6a
+	 17, // 0x6b
+	 17, // 0x6c
+	 17, // 0x6d
+	 17, // 0x6e
+	 17, // 0x6f
+	 17, // 0x70
+	 17, // 0x71
+	 17, // 0x72
+	 17, // 0x73
+	 17, // 0x74
+	 17, // 0x75
+	 17, // 0x76
+	 17, // 0x77
+	 17, // 0x78
+	 17, // 0x79
+	 17, // 0x7a
+	 17, // 0x7b
+	 17, // 0x7c
+	 17, // 0x7d
+	 17, // 0x7e
+	 17, // 0x7f
+	 17, // 0x80
+	 17, // 0x81
+	 17, // 0x82
+	 17, // 0x83
+	 17, // 0x84
+	 17, // 0x85
+	 17, // 0x86
+	 17, // 0x87
+	 17, // 0x88
+	 17, // 0x89
+	 17, // 0x8a
+	 17, // 0x8b
+	 17, // 0x8c
+	 17, // 0x8d
+	 17, // 0x8e
+	 17, // 0x8f
+	 17, // 0x90
+	 17, // 0x91
+	 17, // 0x92
+	 17, // 0x93
+	 17, // 0x94
+	 17, // 0x95
+	 17, // 0x96
+	 17, // 0x97
+	 17, // 0x98
+	 17, // 0x99
+	 17, // 0x9a
+	 17, // 0x9b
+	 17, // 0x9c
+	 17, // 0x9d
+	 17, // 0x9e
+	 17, // 0x9f
+	 35, // 0xa0
+	 17, // 0xa1
+	 17, // 0xa2
+	 17, // 0xa3
+	 36, // 0xa4
+	 17, // 0xa5
+	 37, // 0xa6
+	 38, // 0xa7
+	 39, // 0xa8
+	 40, // 0xa9
+	 41, // 0xaa
+	 42, // 0xab
+	 17, // 0xac
+	 17, // 0xad
+	 17, // 0xae
+	 17, // 0xaf
+	 17, // 0xb0
+	 17, // 0xb1
+	 17, // 0xb2
+	 17, // 0xb3
+	 17, // 0xb4
+	 17, // 0xb5
+	 17, // 0xb6
+	 17, // 0xb7
+	 17, // 0xb8
+	 17, // 0xb9
+	 17, // 0xba
+	 17, // 0xbb
+	 17, // 0xbc
+	 17, // 0xbd
+	 17, // 0xbe
+	 17, // 0xbf
+	 17, // 0xc0
+	 17, // 0xc1
+	 17, // 0xc2
+	 17, // 0xc3
+	 17, // 0xc4
+	 17, // 0xc5
+	 17, // 0xc6
+	 17, // 0xc7
+	 17, // 0xc8
+	 17, // 0xc9
+	 17, // 0xca
+	 17, // 0xcb
+	 17, // 0xcc
+	 17, // 0xcd
+	 17, // 0xce
+	 17, // 0xcf
+	 17, // 0xd0
+	 17, // 0xd1
+	 17, // 0xd2
+	 17, // 0xd3
+	 17, // 0xd4
+	 17, // 0xd5
+	 17, // 0xd6
+	 17, // 0xd7
+	 17, // 0xd8
+	 17, // 0xd9
+	 17, // 0xda
+	 17, // 0xdb
+	 17, // 0xdc
+	 17, // 0xdd
+	 17, // 0xde
+	 17, // 0xdf
+	 17, // 0xe0
+	 17, // 0xe1
+	 17, // 0xe2
+	 17, // 0xe3
+	 17, // 0xe4
+	 17, // 0xe5
+	 17, // 0xe6
+	 17, // 0xe7
+	 17, // 0xe8
+	 17, // 0xe9
+	 17, // 0xea
+	 17, // 0xeb
+	 17, // 0xec
+	 17, // 0xed
+	 17, // 0xee
+	 17, // 0xef
+	 17, // 0xf0
+	 17, // 0xf1
+	 17, // 0xf2
+	 17, // 0xf3
+	 17, // 0xf4
+	 17, // 0xf5
+	 17, // 0xf6
+	 17, // 0xf7
+	 17, // 0xf8
+	 17, // 0xf9
+	 17, // 0xfa
+	 43, // 0xfb
+	 17, // 0xfc
+	 17, // 0xfd
+	 44, // 0xfe
+	 45, // 0xff
+	 17, // 0x100
+	 46, // 0x101
+	 47, // 0x102
+	 48, // 0x103
+	 49, // 0x104
+	 50, // 0x105
+	 17, // 0x106
+	 51, // 0x107
+	 17, // 0x108
+	 17, // 0x109
+	 52, // 0x10a
+	 17, // 0x10b
+	 53, // 0x10c
+	 54, // 0x10d
+	 55, // 0x10e
+	 56, // 0x10f
+	 57, // 0x110
+	 58, // 0x111
+	 59, // 0x112
+	 60, // 0x113
+	 61, // 0x114
+	 62, // 0x115
+	 63, // 0x116
+	 64, // 0x117
+	 65, // 0x118
+	 66, // 0x119
+	 67, // 0x11a
+	 17, // 0x11b
+	 68, // 0x11c
+	 69, // 0x11d
+	 70, // 0x11e
+	 71, // 0x11f
+	 17, // 0x120
+	 17, // 0x121
+	 17, // 0x122
+	 17, // 0x123
+	 17, // 0x124
+	 17, // 0x125
+	 17, // 0x126
+	 17, // 0x127
+	 17, // 0x128
+	 17, // 0x129
+	 17, // 0x12a
+	 17, // 0x12b
+	 17, // 0x12c
+	 17, // 0x12d
+	 17, // 0x12e
+	 17, // 0x12f
+	 17, // 0x130
+	 17, // 0x131
+	 17, // 0x132
+	 17, // 0x133
+	 72, // 0x134
+	 17, // 0x135
+	 17, // 0x136
+	 17, // 0x137
+	 17, // 0x138
+	 17, // 0x139
+	 17, // 0x13a
+	 17, // 0x13b
+	 17, // 0x13c
+	 17, // 0x13d
+	 17, // 0x13e
+	 17, // 0x13f
+	 17, // 0x140
+	 17, // 0x141
+	 17, // 0x142
+	 17, // 0x143
+	 17, // 0x144
+	 17, // 0x145
+	 17, // 0x146
+	 17, // 0x147
+	 17, // 0x148
+	 17, // 0x149
+	 17, // 0x14a
+	 17, // 0x14b
+	 17, // 0x14c
+	 17, // 0x14d
+	 17, // 0x14e
+	 17, // 0x14f
+	 17, // 0x150
+	 17, // 0x151
+	 17, // 0x152
+	 17, // 0x153
+	 17, // 0x154
+	 17, // 0x155
+	 17, // 0x156
+	 17, // 0x157
+	 17, // 0x158
+	 17, // 0x159
+	 17, // 0x15a
+	 17, // 0x15b
+	 17, // 0x15c
+	 17, // 0x15d
+	 17, // 0x15e
+	 17, // 0x15f
+	 17, // 0x160
+	 73, // 0x161
+	 17, // 0x162
+	 17, // 0x163
+	 17, // 0x164
+	 17, // 0x165
+	 17, // 0x166
+	 17, // 0x167
+	 17, // 0x168
+	 17, // 0x169
+	 74, // 0x16a
+	 75, // 0x16b
+	 17, // 0x16c
+	 76, // 0x16d
+	 77, // 0x16e
+	 78, // 0x16f
+	 17, // 0x170
+	 17, // 0x171
+	 17, // 0x172
+	 17, // 0x173
+	 17, // 0x174
+	 17, // 0x175
+	 17, // 0x176
+	 17, // 0x177
+	 17, // 0x178
+	 17, // 0x179
+	 17, // 0x17a
+	 17, // 0x17b
+	 17, // 0x17c
+	 17, // 0x17d
+	 17, // 0x17e
+	 17, // 0x17f
+	 17, // 0x180
+	 17, // 0x181
+	 17, // 0x182
+	 17, // 0x183
+	 17, // 0x184
+	 17, // 0x185
+	 17, // 0x186
+	 17, // 0x187
+	 17, // 0x188
+	 17, // 0x189
+	 17, // 0x18a
+	 17, // 0x18b
+	 17, // 0x18c
+	 17, // 0x18d
+	 17, // 0x18e
+	 17, // 0x18f
+	 17, // 0x190
+	 17, // 0x191
+	 17, // 0x192
+	 17, // 0x193
+	 17, // 0x194
+	 17, // 0x195
+	 17, // 0x196
+	 17, // 0x197
+	 17, // 0x198
+	 17, // 0x199
+	 17, // 0x19a
+	 17, // 0x19b
+	 17, // 0x19c
+	 17, // 0x19d
+	 17, // 0x19e
+	 17, // 0x19f
+	 17, // 0x1a0
+	 17, // 0x1a1
+	 17, // 0x1a2
+	 17, // 0x1a3
+	 17, // 0x1a4
+	 17, // 0x1a5
+	 17, // 0x1a6
+	 17, // 0x1a7
+	 17, // 0x1a8
+	 17, // 0x1a9
+	 17, // 0x1aa
+	 17, // 0x1ab
+	 17, // 0x1ac
+	 17, // 0x1ad
+	 17, // 0x1ae
+	 79, // 0x1af
+	 17, // 0x1b0
+	 17, // 0x1b1
+	 17, // 0x1b2
+	 17, // 0x1b3
+	 17, // 0x1b4
+	 17, // 0x1b5
+	 17, // 0x1b6
+	 17, // 0x1b7
+	 17, // 0x1b8
+	 17, // 0x1b9
+	 17, // 0x1ba
+	 17, // 0x1bb
+	 80, // 0x1bc
+	 17, // 0x1bd
+	 17, // 0x1be
+	 17, // 0x1bf
+	 17, // 0x1c0
+	 17, // 0x1c1
+	 17, // 0x1c2
+	 17, // 0x1c3
+	 17, // 0x1c4
+	 17, // 0x1c5
+	 17, // 0x1c6
+	 17, // 0x1c7
+	 17, // 0x1c8
+	 17, // 0x1c9
+	 17, // 0x1ca
+	 17, // 0x1cb
+	 17, // 0x1cc
+	 17, // 0x1cd
+	 17, // 0x1ce
+	 81, // 0x1cf
+	 17, // 0x1d0
+	 82, // 0x1d1
+	 83, // 0x1d2
+	 17, // 0x1d3
+	 84, // 0x1d4
+	 85, // 0x1d5
+	 86, // 0x1d6
+	 87, // 0x1d7
+	 17, // 0x1d8
+	 17, // 0x1d9
+	 88, // 0x1da
+	 17, // 0x1db
+	 17, // 0x1dc
+	 17, // 0x1dd
+	 17, // 0x1de
+	 89, // 0x1df
+	 90, // 0x1e0
+	 91, // 0x1e1
+	 92, // 0x1e2
+	 17, // 0x1e3
+	 93, // 0x1e4
+	 94, // 0x1e5
+	 17, // 0x1e6
+	 17, // 0x1e7
+	 95, // 0x1e8
+	 96, // 0x1e9
+	 17, // 0x1ea
+	 17, // 0x1eb
+	 17, // 0x1ec
+	 17, // 0x1ed
+	 17, // 0x1ee
+	 17, // 0x1ef
+	 17, // 0x1f0
+	 97, // 0x1f1
+	 17, // 0x1f2
+	 98, // 0x1f3
+	 17, // 0x1f4
+	 17, // 0x1f5
+	 17, // 0x1f6
+	 17, // 0x1f7
+	 17, // 0x1f8
+	 17, // 0x1f9
+	 17, // 0x1fa
+	 17, // 0x1fb
+	 17, // 0x1fc
+	 17, // 0x1fd
+	 17, // 0x1fe
+	 17, // 0x1ff
+	 17, // 0x200
+	 17, // 0x201
+	 17, // 0x202
+	 17, // 0x203
+	 17, // 0x204
+	 17, // 0x205
+	 17, // 0x206
+	 17, // 0x207
+	 17, // 0x208
+	 17, // 0x209
+	 17, // 0x20a
+	 17, // 0x20b
+	 17, // 0x20c
+	 17, // 0x20d
+	 17, // 0x20e
+	 17, // 0x20f
+	 17, // 0x210
+	 17, // 0x211
+	 17, // 0x212
+	 17, // 0x213
+	 17, // 0x214
+	 17, // 0x215
+	 17, // 0x216
+	 17, // 0x217
+	 17, // 0x218
+	 17, // 0x219
+	 17, // 0x21a
+	 17, // 0x21b
+	 17, // 0x21c
+	 17, // 0x21d
+	 17, // 0x21e
+	 17, // 0x21f
+	 17, // 0x220
+	 17, // 0x221
+	 17, // 0x222
+	 17, // 0x223
+	 17, // 0x224
+	 17, // 0x225
+	 17, // 0x226
+	 17, // 0x227
+	 17, // 0x228
+	 17, // 0x229
+	 17, // 0x22a
+	 17, // 0x22b
+	 17, // 0x22c
+	 17, // 0x22d
+	 17, // 0x22e
+	 17, // 0x22f
+	 17, // 0x230
+	 17, // 0x231
+	 17, // 0x232
+	 17, // 0x233
+	 17, // 0x234
+	 17, // 0x235
+	 17, // 0x236
+	 17, // 0x237
+	 17, // 0x238
+	 17, // 0x239
+	 17, // 0x23a
+	 17, // 0x23b
+	 17, // 0x23c
+	 17, // 0x23d
+	 17, // 0x23e
+	 17, // 0x23f
+	 17, // 0x240
+	 17, // 0x241
+	 17, // 0x242
+	 17, // 0x243
+	 17, // 0x244
+	 17, // 0x245
+	 17, // 0x246
+	 17, // 0x247
+	 17, // 0x248
+	 17, // 0x249
+	 17, // 0x24a
+	 17, // 0x24b
+	 17, // 0x24c
+	 17, // 0x24d
+	 17, // 0x24e
+	 17, // 0x24f
+	 17, // 0x250
+	 17, // 0x251
+	 17, // 0x252
+	 17, // 0x253
+	 17, // 0x254
+	 17, // 0x255
+	 17, // 0x256
+	 17, // 0x257
+	 17, // 0x258
+	 17, // 0x259
+	 17, // 0x25a
+	 17, // 0x25b
+	 17, // 0x25c
+	 17, // 0x25d
+	 17, // 0x25e
+	 17, // 0x25f
+	 17, // 0x260
+	 17, // 0x261
+	 17, // 0x262
+	 17, // 0x263
+	 17, // 0x264
+	 17, // 0x265
+	 17, // 0x266
+	 17, // 0x267
+	 17, // 0x268
+	 17, // 0x269
+	 17, // 0x26a
+	 17, // 0x26b
+	 17, // 0x26c
+	 17, // 0x26d
+	 17, // 0x26e
+	 17, // 0x26f
+	 17, // 0x270
+	 17, // 0x271
+	 17, // 0x272
+	 17, // 0x273
+	 17, // 0x274
+	 17, // 0x275
+	 17, // 0x276
+	 17, // 0x277
+	 17, // 0x278
+	 17, // 0x279
+	 17, // 0x27a
+	 17, // 0x27b
+	 17, // 0x27c
+	 17, // 0x27d
+	 17, // 0x27e
+	 17, // 0x27f
+	 17, // 0x280
+	 17, // 0x281
+	 17, // 0x282
+	 17, // 0x283
+	 17, // 0x284
+	 17, // 0x285
+	 17, // 0x286
+	 17, // 0x287
+	 17, // 0x288
+	 17, // 0x289
+	 17, // 0x28a
+	 17, // 0x28b
+	 17, // 0x28c
+	 17, // 0x28d
+	 17, // 0x28e
+	 17, // 0x28f
+	 17, // 0x290
+	 17, // 0x291
+	 17, // 0x292
+	 17, // 0x293
+	 17, // 0x294
+	 17, // 0x295
+	 17, // 0x296
+	 17, // 0x297
+	 17, // 0x298
+	 17, // 0x299
+	 17, // 0x29a
+	 17, // 0x29b
+	 17, // 0x29c
+	 17, // 0x29d
+	 17, // 0x29e
+	 17, // 0x29f
+	 17, // 0x2a0
+	 17, // 0x2a1
+	 17, // 0x2a2
+	 17, // 0x2a3
+	 17, // 0x2a4
+	 17, // 0x2a5
+	 17, // 0x2a6
+	 17, // 0x2a7
+	 17, // 0x2a8
+	 17, // 0x2a9
+	 17, // 0x2aa
+	 17, // 0x2ab
+	 17, // 0x2ac
+	 17, // 0x2ad
+	 17, // 0x2ae
+	 17, // 0x2af
+	 17, // 0x2b0
+	 17, // 0x2b1
+	 17, // 0x2b2
+	 17, // 0x2b3
+	 17, // 0x2b4
+	 17, // 0x2b5
+	 17, // 0x2b6
+	 17, // 0x2b7
+	 17, // 0x2b8
+	 17, // 0x2b9
+	 17, // 0x2ba
+	 17, // 0x2bb
+	 17, // 0x2bc
+	 17, // 0x2bd
+	 17, // 0x2be
+	 17, // 0x2bf
+	 17, // 0x2c0
+	 17, // 0x2c1
+	 17, // 0x2c2
+	 17, // 0x2c3
+	 17, // 0x2c4
+	 17, // 0x2c5
+	 17, // 0x2c6
+	 17, // 0x2c7
+	 17, // 0x2c8
+	 17, // 0x2c9
+	 17, // 0x2ca
+	 17, // 0x2cb
+	 17, // 0x2cc
+	 17, // 0x2cd
+	 17, // 0x2ce
+	 17, // 0x2cf
+	 17, // 0x2d0
+	 17, // 0x2d1
+	 17, // 0x2d2
+	 17, // 0x2d3
+	 17, // 0x2d4
+	 17, // 0x2d5
+	 17, // 0x2d6
+	 17, // 0x2d7
+	 17, // 0x2d8
+	 17, // 0x2d9
+	 17, // 0x2da
+	 17, // 0x2db
+	 17, // 0x2dc
+	 17, // 0x2dd
+	 17, // 0x2de
+	 17, // 0x2df
+	 17, // 0x2e0
+	 17, // 0x2e1
+	 17, // 0x2e2
+	 17, // 0x2e3
+	 17, // 0x2e4
+	 17, // 0x2e5
+	 17, // 0x2e6
+	 17, // 0x2e7
+	 17, // 0x2e8
+	 17, // 0x2e9
+	 17, // 0x2ea
+	 17, // 0x2eb
+	 17, // 0x2ec
+	 17, // 0x2ed
+	 17, // 0x2ee
+	 17, // 0x2ef
+	 17, // 0x2f0
+	 17, // 0x2f1
+	 17, // 0x2f2
+	 17, // 0x2f3
+	 17, // 0x2f4
+	 17, // 0x2f5
+	 17, // 0x2f6
+	 17, // 0x2f7
+	 17, // 0x2f8
+	 17, // 0x2f9
+	 17, // 0x2fa
+	 17, // 0x2fb
+	 17, // 0x2fc
+	 17, // 0x2fd
+	 17, // 0x2fe
+	 17, // 0x2ff
+	 17, // 0x300
+	 17, // 0x301
+	 17, // 0x302
+	 17, // 0x303
+	 17, // 0x304
+	 17, // 0x305
+	 17, // 0x306
+	 17, // 0x307
+	 17, // 0x308
+	 17, // 0x309
+	 17, // 0x30a
+	 17, // 0x30b
+	 17, // 0x30c
+	 17, // 0x30d
+	 17, // 0x30e
+	 17, // 0x30f
+	 17, // 0x310
+	 17, // 0x311
+	 17, // 0x312
+	 17, // 0x313
+	 17, // 0x314
+	 17, // 0x315
+	 17, // 0x316
+	 17, // 0x317
+	 17, // 0x318
+	 17, // 0x319
+	 17, // 0x31a
+	 17, // 0x31b
+	 17, // 0x31c
+	 17, // 0x31d
+	 17, // 0x31e
+	 17, // 0x31f
+	 17, // 0x320
+	 17, // 0x321
+	 17, // 0x322
+	 17, // 0x323
+	 17, // 0x324
+	 17, // 0x325
+	 17, // 0x326
+	 17, // 0x327
+	 17, // 0x328
+	 17, // 0x329
+	 17, // 0x32a
+	 17, // 0x32b
+	 17, // 0x32c
+	 17, // 0x32d
+	 17, // 0x32e
+	 17, // 0x32f
+	 17, // 0x330
+	 17, // 0x331
+	 17, // 0x332
+	 17, // 0x333
+	 17, // 0x334
+	 17, // 0x335
+	 17, // 0x336
+	 17, // 0x337
+	 17, // 0x338
+	 17, // 0x339
+	 17, // 0x33a
+	 17, // 0x33b
+	 17, // 0x33c
+	 17, // 0x33d
+	 17, // 0x33e
+	 17, // 0x33f
+	 17, // 0x340
+	 17, // 0x341
+	 17, // 0x342
+	 17, // 0x343
+	 17, // 0x344
+	 17, // 0x345
+	 17, // 0x346
+	 17, // 0x347
+	 17, // 0x348
+	 17, // 0x349
+	 17, // 0x34a
+	 17, // 0x34b
+	 17, // 0x34c
+	 17, // 0x34d
+	 17, // 0x34e
+	 17, // 0x34f
+	 17, // 0x350
+	 17, // 0x351
+	 17, // 0x352
+	 17, // 0x353
+	 17, // 0x354
+	 17, // 0x355
+	 17, // 0x356
+	 17, // 0x357
+	 17, // 0x358
+	 17, // 0x359
+	 17, // 0x35a
+	 17, // 0x35b
+	 17, // 0x35c
+	 17, // 0x35d
+	 17, // 0x35e
+	 17, // 0x35f
+	 17, // 0x360
+	 17, // 0x361
+	 17, // 0x362
+	 17, // 0x363
+	 17, // 0x364
+	 17, // 0x365
+	 17, // 0x366
+	 17, // 0x367
+	 17, // 0x368
+	 17, // 0x369
+	 17, // 0x36a
+	 17, // 0x36b
+	 17, // 0x36c
+	 17, // 0x36d
+	 17, // 0x36e
+	 17, // 0x36f
+	 17, // 0x370
+	 17, // 0x371
+	 17, // 0x372
+	 17, // 0x373
+	 17, // 0x374
+	 17, // 0x375
+	 17, // 0x376
+	 17, // 0x377
+	 17, // 0x378
+	 17, // 0x379
+	 17, // 0x37a
+	 17, // 0x37b
+	 17, // 0x37c
+	 17, // 0x37d
+	 17, // 0x37e
+	 17, // 0x37f
+	 17, // 0x380
+	 17, // 0x381
+	 17, // 0x382
+	 17, // 0x383
+	 17, // 0x384
+	 17, // 0x385
+	 17, // 0x386
+	 17, // 0x387
+	 17, // 0x388
+	 17, // 0x389
+	 17, // 0x38a
+	 17, // 0x38b
+	 17, // 0x38c
+	 17, // 0x38d
+	 17, // 0x38e
+	 17, // 0x38f
+	 17, // 0x390
+	 17, // 0x391
+	 17, // 0x392
+	 17, // 0x393
+	 17, // 0x394
+	 17, // 0x395
+	 17, // 0x396
+	 17, // 0x397
+	 17, // 0x398
+	 17, // 0x399
+	 17, // 0x39a
+	 17, // 0x39b
+	 17, // 0x39c
+	 17, // 0x39d
+	 17, // 0x39e
+	 17, // 0x39f
+	 17, // 0x3a0
+	 17, // 0x3a1
+	 17, // 0x3a2
+	 17, // 0x3a3
+	 17, // 0x3a4
+	 17, // 0x3a5
+	 17, // 0x3a6
+	 17, // 0x3a7
+	 17, // 0x3a8
+	 17, // 0x3a9
+	 17, // 0x3aa
+	 17, // 0x3ab
+	 17, // 0x3ac
+	 17, // 0x3ad
+	 17, // 0x3ae
+	 17, // 0x3af
+	 17, // 0x3b0
+	 17, // 0x3b1
+	 17, // 0x3b2
+	 17, // 0x3b3
+	 17, // 0x3b4
+	 17, // 0x3b5
+	 17, // 0x3b6
+	 17, // 0x3b7
+	 17, // 0x3b8
+	 17, // 0x3b9
+	 17, // 0x3ba
+	 17, // 0x3bb
+	 17, // 0x3bc
+	 17, // 0x3bd
+	 17, // 0x3be
+	 17, // 0x3bf
+	 17, // 0x3c0
+	 17, // 0x3c1
+	 17, // 0x3c2
+	 17, // 0x3c3
+	 17, // 0x3c4
+	 17, // 0x3c5
+	 17, // 0x3c6
+	 17, // 0x3c7
+	 17, // 0x3c8
+	 17, // 0x3c9
+	 17, // 0x3ca
+	 17, // 0x3cb
+	 17, // 0x3cc
+	 17, // 0x3cd
+	 17, // 0x3ce
+	 17, // 0x3cf
+	 17, // 0x3d0
+	 17, // 0x3d1
+	 17, // 0x3d2
+	 17, // 0x3d3
+	 17, // 0x3d4
+	 17, // 0x3d5
+	 17, // 0x3d6
+	 17, // 0x3d7
+	 17, // 0x3d8
+	 17, // 0x3d9
+	 17, // 0x3da
+	 17, // 0x3db
+	 17, // 0x3dc
+	 17, // 0x3dd
+	 17, // 0x3de
+	 17, // 0x3df
+	 17, // 0x3e0
+	 17, // 0x3e1
+	 17, // 0x3e2
+	 17, // 0x3e3
+	 17, // 0x3e4
+	 17, // 0x3e5
+	 17, // 0x3e6
+	 17, // 0x3e7
+	 17, // 0x3e8
+	 17, // 0x3e9
+	 17, // 0x3ea
+	 17, // 0x3eb
+	 17, // 0x3ec
+	 17, // 0x3ed
+	 17, // 0x3ee
+	 17, // 0x3ef
+	 17, // 0x3f0
+	 17, // 0x3f1
+	 17, // 0x3f2
+	 17, // 0x3f3
+	 17, // 0x3f4
+	 17, // 0x3f5
+	 17, // 0x3f6
+	 17, // 0x3f7
+	 17, // 0x3f8
+	 17, // 0x3f9
+	 17, // 0x3fa
+	 17, // 0x3fb
+	 17, // 0x3fc
+	 17, // 0x3fd
+	 17, // 0x3fe
+	 17, // 0x3ff
+	 17, // 0x400
+	 17, // 0x401
+	 17, // 0x402
+	 17, // 0x403
+	 17, // 0x404
+	 17, // 0x405
+	 17, // 0x406
+	 17, // 0x407
+	 17, // 0x408
+	 17, // 0x409
+	 17, // 0x40a
+	 17, // 0x40b
+	 17, // 0x40c
+	 17, // 0x40d
+	 17, // 0x40e
+	 17, // 0x40f
+	 17, // 0x410
+	 17, // 0x411
+	 17, // 0x412
+	 17, // 0x413
+	 17, // 0x414
+	 17, // 0x415
+	 17, // 0x416
+	 17, // 0x417
+	 17, // 0x418
+	 17, // 0x419
+	 17, // 0x41a
+	 17, // 0x41b
+	 17, // 0x41c
+	 17, // 0x41d
+	 17, // 0x41e
+	 17, // 0x41f
+	 17, // 0x420
+	 17, // 0x421
+	 17, // 0x422
+	 17, // 0x423
+	 17, // 0x424
+	 17, // 0x425
+	 17, // 0x426
+	 17, // 0x427
+	 17, // 0x428
+	 17, // 0x429
+	 17, // 0x42a
+	 17, // 0x42b
+	 17, // 0x42c
+	 17, // 0x42d
+	 17, // 0x42e
+	 17, // 0x42f
+	 17, // 0x430
+	 17, // 0x431
+	 17, // 0x432
+	 17, // 0x433
+	 17, // 0x434
+	 17, // 0x435
+	 17, // 0x436
+	 17, // 0x437
+	 17, // 0x438
+	 17, // 0x439
+	 17, // 0x43a
+	 17, // 0x43b
+	 17, // 0x43c
+	 17, // 0x43d
+	 17, // 0x43e
+	 17, // 0x43f
+	 17, // 0x440
+	 17, // 0x441
+	 17, // 0x442
+	 17, // 0x443
+	 17, // 0x444
+	 17, // 0x445
+	 17, // 0x446
+	 17, // 0x447
+	 17, // 0x448
+	 17, // 0x449
+	 17, // 0x44a
+	 17, // 0x44b
+	 17, // 0x44c
+	 17, // 0x44d
+	 17, // 0x44e
+	 17, // 0x44f
+	 17, // 0x450
+	 17, // 0x451
+	 17, // 0x452
+	 17, // 0x453
+	 17, // 0x454
+	 17, // 0x455
+	 17, // 0x456
+	 17, // 0x457
+	 17, // 0x458
+	 17, // 0x459
+	 17, // 0x45a
+	 17, // 0x45b
+	 17, // 0x45c
+	 17, // 0x45d
+	 17, // 0x45e
+	 17, // 0x45f
+	 17, // 0x460
+	 17, // 0x461
+	 17, // 0x462
+	 17, // 0x463
+	 17, // 0x464
+	 17, // 0x465
+	 17, // 0x466
+	 17, // 0x467
+	 17, // 0x468
+	 17, // 0x469
+	 17, // 0x46a
+	 17, // 0x46b
+	 17, // 0x46c
+	 17, // 0x46d
+	 17, // 0x46e
+	 17, // 0x46f
+	 17, // 0x470
+	 17, // 0x471
+	 17, // 0x472
+	 17, // 0x473
+	 17, // 0x474
+	 17, // 0x475
+	 17, // 0x476
+	 17, // 0x477
+	 17, // 0x478
+	 17, // 0x479
+	 17, // 0x47a
+	 17, // 0x47b
+	 17, // 0x47c
+	 17, // 0x47d
+	 17, // 0x47e
+	 17, // 0x47f
+	 17, // 0x480
+	 17, // 0x481
+	 17, // 0x482
+	 17, // 0x483
+	 17, // 0x484
+	 17, // 0x485
+	 17, // 0x486
+	 17, // 0x487
+	 17, // 0x488
+	 17, // 0x489
+	 17, // 0x48a
+	 17, // 0x48b
+	 17, // 0x48c
+	 17, // 0x48d
+	 17, // 0x48e
+	 17, // 0x48f
+	 17, // 0x490
+	 17, // 0x491
+	 17, // 0x492
+	 17, // 0x493
+	 17, // 0x494
+	 17, // 0x495
+	 17, // 0x496
+	 17, // 0x497
+	 17, // 0x498
+	 17, // 0x499
+	 17, // 0x49a
+	 17, // 0x49b
+	 17, // 0x49c
+	 17, // 0x49d
+	 17, // 0x49e
+	 17, // 0x49f
+	 17, // 0x4a0
+	 17, // 0x4a1
+	 17, // 0x4a2
+	 17, // 0x4a3
+	 17, // 0x4a4
+	 17, // 0x4a5
+	 17, // 0x4a6
+	 17, // 0x4a7
+	 17, // 0x4a8
+	 17, // 0x4a9
+	 17, // 0x4aa
+	 17, // 0x4ab
+	 17, // 0x4ac
+	 17, // 0x4ad
+	 17, // 0x4ae
+	 17, // 0x4af
+	 17, // 0x4b0
+	 17, // 0x4b1
+	 17, // 0x4b2
+	 17, // 0x4b3
+	 17, // 0x4b4
+	 17, // 0x4b5
+	 17, // 0x4b6
+	 17, // 0x4b7
+	 17, // 0x4b8
+	 17, // 0x4b9
+	 17, // 0x4ba
+	 17, // 0x4bb
+	 17, // 0x4bc
+	 17, // 0x4bd
+	 17, // 0x4be
+	 17, // 0x4bf
+	 17, // 0x4c0
+	 17, // 0x4c1
+	 17, // 0x4c2
+	 17, // 0x4c3
+	 17, // 0x4c4
+	 17, // 0x4c5
+	 17, // 0x4c6
+	 17, // 0x4c7
+	 17, // 0x4c8
+	 17, // 0x4c9
+	 17, // 0x4ca
+	 17, // 0x4cb
+	 17, // 0x4cc
+	 17, // 0x4cd
+	 17, // 0x4ce
+	 17, // 0x4cf
+	 17, // 0x4d0
+	 17, // 0x4d1
+	 17, // 0x4d2
+	 17, // 0x4d3
+	 17, // 0x4d4
+	 17, // 0x4d5
+	 17, // 0x4d6
+	 17, // 0x4d7
+	 17, // 0x4d8
+	 17, // 0x4d9
+	 17, // 0x4da
+	 17, // 0x4db
+	 17, // 0x4dc
+	 17, // 0x4dd
+	 17, // 0x4de
+	 17, // 0x4df
+	 17, // 0x4e0
+	 17, // 0x4e1
+	 17, // 0x4e2
+	 17, // 0x4e3
+	 17, // 0x4e4
+	 17, // 0x4e5
+	 17, // 0x4e6
+	 17, // 0x4e7
+	 17, // 0x4e8
+	 17, // 0x4e9
+	 17, // 0x4ea
+	 17, // 0x4eb
+	 17, // 0x4ec
+	 17, // 0x4ed
+	 17, // 0x4ee
+	 17, // 0x4ef
+	 17, // 0x4f0
+	 17, // 0x4f1
+	 17, // 0x4f2
+	 17, // 0x4f3
+	 17, // 0x4f4
+	 17, // 0x4f5
+	 17, // 0x4f6
+	 17, // 0x4f7
+	 17, // 0x4f8
+	 17, // 0x4f9
+	 17, // 0x4fa
+	 17, // 0x4fb
+	 17, // 0x4fc
+	 17, // 0x4fd
+	 17, // 0x4fe
+	 17, // 0x4ff
+	 17, // 0x500
+	 17, // 0x501
+	 17, // 0x502
+	 17, // 0x503
+	 17, // 0x504
+	 17, // 0x505
+	 17, // 0x506
+	 17, // 0x507
+	 17, // 0x508
+	 17, // 0x509
+	 17, // 0x50a
+	 17, // 0x50b
+	 17, // 0x50c
+	 17, // 0x50d
+	 17, // 0x50e
+	 17, // 0x50f
+	 17, // 0x510
+	 17, // 0x511
+	 17, // 0x512
+	 17, // 0x513
+	 17, // 0x514
+	 17, // 0x515
+	 17, // 0x516
+	 17, // 0x517
+	 17, // 0x518
+	 17, // 0x519
+	 17, // 0x51a
+	 17, // 0x51b
+	 17, // 0x51c
+	 17, // 0x51d
+	 17, // 0x51e
+	 17, // 0x51f
+	 17, // 0x520
+	 17, // 0x521
+	 17, // 0x522
+	 17, // 0x523
+	 17, // 0x524
+	 17, // 0x525
+	 17, // 0x526
+	 17, // 0x527
+	 17, // 0x528
+	 17, // 0x529
+	 17, // 0x52a
+	 17, // 0x52b
+	 17, // 0x52c
+	 17, // 0x52d
+	 17, // 0x52e
+	 17, // 0x52f
+	 17, // 0x530
+	 17, // 0x531
+	 17, // 0x532
+	 17, // 0x533
+	 17, // 0x534
+	 17, // 0x535
+	 17, // 0x536
+	 17, // 0x537
+	 17, // 0x538
+	 17, // 0x539
+	 17, // 0x53a
+	 17, // 0x53b
+	 17, // 0x53c
+	 17, // 0x53d
+	 17, // 0x53e
+	 17, // 0x53f
+	 17, // 0x540
+	 17, // 0x541
+	 17, // 0x542
+	 17, // 0x543
+	 17, // 0x544
+	 17, // 0x545
+	 17, // 0x546
+	 17, // 0x547
+	 17, // 0x548
+	 17, // 0x549
+	 17, // 0x54a
+	 17, // 0x54b
+	 17, // 0x54c
+	 17, // 0x54d
+	 17, // 0x54e
+	 17, // 0x54f
+	 17, // 0x550
+	 17, // 0x551
+	 17, // 0x552
+	 17, // 0x553
+	 17, // 0x554
+	 17, // 0x555
+	 17, // 0x556
+	 17, // 0x557
+	 17, // 0x558
+	 17, // 0x559
+	 17, // 0x55a
+	 17, // 0x55b
+	 17, // 0x55c
+	 17, // 0x55d
+	 17, // 0x55e
+	 17, // 0x55f
+	 17, // 0x560
+	 17, // 0x561
+	 17, // 0x562
+	 17, // 0x563
+	 17, // 0x564
+	 17, // 0x565
+	 17, // 0x566
+	 17, // 0x567
+	 17, // 0x568
+	 17, // 0x569
+	 17, // 0x56a
+	 17, // 0x56b
+	 17, // 0x56c
+	 17, // 0x56d
+	 17, // 0x56e
+	 17, // 0x56f
+	 17, // 0x570
+	 17, // 0x571
+	 17, // 0x572
+	 17, // 0x573
+	 17, // 0x574
+	 17, // 0x575
+	 17, // 0x576
+	 17, // 0x577
+	 17, // 0x578
+	 17, // 0x579
+	 17, // 0x57a
+	 17, // 0x57b
+	 17, // 0x57c
+	 17, // 0x57d
+	 17, // 0x57e
+	 17, // 0x57f
+	 17, // 0x580
+	 17, // 0x581
+	 17, // 0x582
+	 17, // 0x583
+	 17, // 0x584
+	 17, // 0x585
+	 17, // 0x586
+	 17, // 0x587
+	 17, // 0x588
+	 17, // 0x589
+	 17, // 0x58a
+	 17, // 0x58b
+	 17, // 0x58c
+	 17, // 0x58d
+	 17, // 0x58e
+	 17, // 0x58f
+	 17, // 0x590
+	 17, // 0x591
+	 17, // 0x592
+	 17, // 0x593
+	 17, // 0x594
+	 17, // 0x595
+	 17, // 0x596
+	 17, // 0x597
+	 17, // 0x598
+	 17, // 0x599
+	 17, // 0x59a
+	 17, // 0x59b
+	 17, // 0x59c
+	 17, // 0x59d
+	 17, // 0x59e
+	 17, // 0x59f
+	 17, // 0x5a0
+	 17, // 0x5a1
+	 17, // 0x5a2
+	 17, // 0x5a3
+	 17, // 0x5a4
+	 17, // 0x5a5
+	 17, // 0x5a6
+	 17, // 0x5a7
+	 17, // 0x5a8
+	 17, // 0x5a9
+	 17, // 0x5aa
+	 17, // 0x5ab
+	 17, // 0x5ac
+	 17, // 0x5ad
+	 17, // 0x5ae
+	 17, // 0x5af
+	 17, // 0x5b0
+	 17, // 0x5b1
+	 17, // 0x5b2
+	 17, // 0x5b3
+	 17, // 0x5b4
+	 17, // 0x5b5
+	 17, // 0x5b6
+	 17, // 0x5b7
+	 17, // 0x5b8
+	 17, // 0x5b9
+	 17, // 0x5ba
+	 17, // 0x5bb
+	 17, // 0x5bc
+	 17, // 0x5bd
+	 17, // 0x5be
+	 17, // 0x5bf
+	 17, // 0x5c0
+	 17, // 0x5c1
+	 17, // 0x5c2
+	 17, // 0x5c3
+	 17, // 0x5c4
+	 17, // 0x5c5
+	 17, // 0x5c6
+	 17, // 0x5c7
+	 17, // 0x5c8
+	 17, // 0x5c9
+	 17, // 0x5ca
+	 17, // 0x5cb
+	 17, // 0x5cc
+	 17, // 0x5cd
+	 17, // 0x5ce
+	 17, // 0x5cf
+	 17, // 0x5d0
+	 17, // 0x5d1
+	 17, // 0x5d2
+	 17, // 0x5d3
+	 17, // 0x5d4
+	 17, // 0x5d5
+	 17, // 0x5d6
+	 17, // 0x5d7
+	 17, // 0x5d8
+	 17, // 0x5d9
+	 17, // 0x5da
+	 17, // 0x5db
+	 17, // 0x5dc
+	 17, // 0x5dd
+	 17, // 0x5de
+	 17, // 0x5df
+	 17, // 0x5e0
+	 17, // 0x5e1
+	 17, // 0x5e2
+	 17, // 0x5e3
+	 17, // 0x5e4
+	 17, // 0x5e5
+	 17, // 0x5e6
+	 17, // 0x5e7
+	 17, // 0x5e8
+	 17, // 0x5e9
+	 17, // 0x5ea
+	 17, // 0x5eb
+	 17, // 0x5ec
+	 17, // 0x5ed
+	 17, // 0x5ee
+	 17, // 0x5ef
+	 17, // 0x5f0
+	 17, // 0x5f1
+	 17, // 0x5f2
+	 17, // 0x5f3
+	 17, // 0x5f4
+	 17, // 0x5f5
+	 17, // 0x5f6
+	 17, // 0x5f7
+	 17, // 0x5f8
+	 17, // 0x5f9
+	 17, // 0x5fa
+	 17, // 0x5fb
+	 17, // 0x5fc
+	 17, // 0x5fd
+	 17, // 0x5fe
+	 17, // 0x5ff
+	 17, // 0x600
+	 17, // 0x601
+	 17, // 0x602
+	 17, // 0x603
+	 17, // 0x604
+	 17, // 0x605
+	 17, // 0x606
+	 17, // 0x607
+	 17, // 0x608
+	 17, // 0x609
+	 17, // 0x60a
+	 17, // 0x60b
+	 17, // 0x60c
+	 17, // 0x60d
+	 17, // 0x60e
+	 17, // 0x60f
+	 17, // 0x610
+	 17, // 0x611
+	 17, // 0x612
+	 17, // 0x613
+	 17, // 0x614
+	 17, // 0x615
+	 17, // 0x616
+	 17, // 0x617
+	 17, // 0x618
+	 17, // 0x619
+	 17, // 0x61a
+	 17, // 0x61b
+	 17, // 0x61c
+	 17, // 0x61d
+	 17, // 0x61e
+	 17, // 0x61f
+	 17, // 0x620
+	 17, // 0x621
+	 17, // 0x622
+	 17, // 0x623
+	 17, // 0x624
+	 17, // 0x625
+	 17, // 0x626
+	 17, // 0x627
+	 17, // 0x628
+	 17, // 0x629
+	 17, // 0x62a
+	 17, // 0x62b
+	 17, // 0x62c
+	 17, // 0x62d
+	 17, // 0x62e
+	 17, // 0x62f
+	 17, // 0x630
+	 17, // 0x631
+	 17, // 0x632
+	 17, // 0x633
+	 17, // 0x634
+	 17, // 0x635
+	 17, // 0x636
+	 17, // 0x637
+	 17, // 0x638
+	 17, // 0x639
+	 17, // 0x63a
+	 17, // 0x63b
+	 17, // 0x63c
+	 17, // 0x63d
+	 17, // 0x63e
+	 17, // 0x63f
+	 17, // 0x640
+	 17, // 0x641
+	 17, // 0x642
+	 17, // 0x643
+	 17, // 0x644
+	 17, // 0x645
+	 17, // 0x646
+	 17, // 0x647
+	 17, // 0x648
+	 17, // 0x649
+	 17, // 0x64a
+	 17, // 0x64b
+	 17, // 0x64c
+	 17, // 0x64d
+	 17, // 0x64e
+	 17, // 0x64f
+	 17, // 0x650
+	 17, // 0x651
+	 17, // 0x652
+	 17, // 0x653
+	 17, // 0x654
+	 17, // 0x655
+	 17, // 0x656
+	 17, // 0x657
+	 17, // 0x658
+	 17, // 0x659
+	 17, // 0x65a
+	 17, // 0x65b
+	 17, // 0x65c
+	 17, // 0x65d
+	 17, // 0x65e
+	 17, // 0x65f
+	 17, // 0x660
+	 17, // 0x661
+	 17, // 0x662
+	 17, // 0x663
+	 17, // 0x664
+	 17, // 0x665
+	 17, // 0x666
+	 17, // 0x667
+	 17, // 0x668
+	 17, // 0x669
+	 17, // 0x66a
+	 17, // 0x66b
+	 17, // 0x66c
+	 17, // 0x66d
+	 17, // 0x66e
+	 17, // 0x66f
+	 17, // 0x670
+	 17, // 0x671
+	 17, // 0x672
+	 17, // 0x673
+	 17, // 0x674
+	 17, // 0x675
+	 17, // 0x676
+	 17, // 0x677
+	 17, // 0x678
+	 17, // 0x679
+	 17, // 0x67a
+	 17, // 0x67b
+	 17, // 0x67c
+	 17, // 0x67d
+	 17, // 0x67e
+	 17, // 0x67f
+	 17, // 0x680
+	 17, // 0x681
+	 17, // 0x682
+	 17, // 0x683
+	 17, // 0x684
+	 17, // 0x685
+	 17, // 0x686
+	 17, // 0x687
+	 17, // 0x688
+	 17, // 0x689
+	 17, // 0x68a
+	 17, // 0x68b
+	 17, // 0x68c
+	 17, // 0x68d
+	 17, // 0x68e
+	 17, // 0x68f
+	 17, // 0x690
+	 17, // 0x691
+	 17, // 0x692
+	 17, // 0x693
+	 17, // 0x694
+	 17, // 0x695
+	 17, // 0x696
+	 17, // 0x697
+	 17, // 0x698
+	 17, // 0x699
+	 17, // 0x69a
+	 17, // 0x69b
+	 17, // 0x69c
+	 17, // 0x69d
+	 17, // 0x69e
+	 17, // 0x69f
+	 17, // 0x6a0
+	 17, // 0x6a1
+	 17, // 0x6a2
+	 17, // 0x6a3
+	 17, // 0x6a4
+	 17, // 0x6a5
+	 17, // 0x6a6
+	 17, // 0x6a7
+	 17, // 0x6a8
+	 17, // 0x6a9
+	 17, // 0x6aa
+	 17, // 0x6ab
+	 17, // 0x6ac
+	 17, // 0x6ad
+	 17, // 0x6ae
+	 17, // 0x6af
+	 17, // 0x6b0
+	 17, // 0x6b1
+	 17, // 0x6b2
+	 17, // 0x6b3
+	 17, // 0x6b4
+	 17, // 0x6b5
+	 17, // 0x6b6
+	 17, // 0x6b7
+	 17, // 0x6b8
+	 17, // 0x6b9
+	 17, // 0x6ba
+	 17, // 0x6bb
+	 17, // 0x6bc
+	 17, // 0x6bd
+	 17, // 0x6be
+	 17, // 0x6bf
+	 17, // 0x6c0
+	 17, // 0x6c1
+	 17, // 0x6c2
+	 17, // 0x6c3
+	 17, // 0x6c4
+	 17, // 0x6c5
+	 17, // 0x6c6
+	 17, // 0x6c7
+	 17, // 0x6c8
+	 17, // 0x6c9
+	 17, // 0x6ca
+	 17, // 0x6cb
+	 17, // 0x6cc
+	 17, // 0x6cd
+	 17, // 0x6ce
+	 17, // 0x6cf
+	 17, // 0x6d0
+	 17, // 0x6d1
+	 17, // 0x6d2
+	 17, // 0x6d3
+	 17, // 0x6d4
+	 17, // 0x6d5
+	 17, // 0x6d6
+	 17, // 0x6d7
+	 17, // 0x6d8
+	 17, // 0x6d9
+	 17, // 0x6da
+	 17, // 0x6db
+	 17, // 0x6dc
+	 17, // 0x6dd
+	 17, // 0x6de
+	 17, // 0x6df
+	 17, // 0x6e0
+	 17, // 0x6e1
+	 17, // 0x6e2
+	 17, // 0x6e3
+	 17, // 0x6e4
+	 17, // 0x6e5
+	 17, // 0x6e6
+	 17, // 0x6e7
+	 17, // 0x6e8
+	 17, // 0x6e9
+	 17, // 0x6ea
+	 17, // 0x6eb
+	 17, // 0x6ec
+	 17, // 0x6ed
+	 17, // 0x6ee
+	 17, // 0x6ef
+	 17, // 0x6f0
+	 17, // 0x6f1
+	 17, // 0x6f2
+	 17, // 0x6f3
+	 17, // 0x6f4
+	 17, // 0x6f5
+	 17, // 0x6f6
+	 17, // 0x6f7
+	 17, // 0x6f8
+	 17, // 0x6f9
+	 17, // 0x6fa
+	 17, // 0x6fb
+	 17, // 0x6fc
+	 17, // 0x6fd
+	 17, // 0x6fe
+	 17, // 0x6ff
+	 17, // 0x700
+	 17, // 0x701
+	 17, // 0x702
+	 17, // 0x703
+	 17, // 0x704
+	 17, // 0x705
+	 17, // 0x706
+	 17, // 0x707
+	 17, // 0x708
+	 17, // 0x709
+	 17, // 0x70a
+	 17, // 0x70b
+	 17, // 0x70c
+	 17, // 0x70d
+	 17, // 0x70e
+	 17, // 0x70f
+	 17, // 0x710
+	 17, // 0x711
+	 17, // 0x712
+	 17, // 0x713
+	 17, // 0x714
+	 17, // 0x715
+	 17, // 0x716
+	 17, // 0x717
+	 17, // 0x718
+	 17, // 0x719
+	 17, // 0x71a
+	 17, // 0x71b
+	 17, // 0x71c
+	 17, // 0x71d
+	 17, // 0x71e
+	 17, // 0x71f
+	 17, // 0x720
+	 17, // 0x721
+	 17, // 0x722
+	 17, // 0x723
+	 17, // 0x724
+	 17, // 0x725
+	 17, // 0x726
+	 17, // 0x727
+	 17, // 0x728
+	 17, // 0x729
+	 17, // 0x72a
+	 17, // 0x72b
+	 17, // 0x72c
+	 17, // 0x72d
+	 17, // 0x72e
+	 17, // 0x72f
+	 17, // 0x730
+	 17, // 0x731
+	 17, // 0x732
+	 17, // 0x733
+	 17, // 0x734
+	 17, // 0x735
+	 17, // 0x736
+	 17, // 0x737
+	 17, // 0x738
+	 17, // 0x739
+	 17, // 0x73a
+	 17, // 0x73b
+	 17, // 0x73c
+	 17, // 0x73d
+	 17, // 0x73e
+	 17, // 0x73f
+	 17, // 0x740
+	 17, // 0x741
+	 17, // 0x742
+	 17, // 0x743
+	 17, // 0x744
+	 17, // 0x745
+	 17, // 0x746
+	 17, // 0x747
+	 17, // 0x748
+	 17, // 0x749
+	 17, // 0x74a
+	 17, // 0x74b
+	 17, // 0x74c
+	 17, // 0x74d
+	 17, // 0x74e
+	 17, // 0x74f
+	 17, // 0x750
+	 17, // 0x751
+	 17, // 0x752
+	 17, // 0x753
+	 17, // 0x754
+	 17, // 0x755
+	 17, // 0x756
+	 17, // 0x757
+	 17, // 0x758
+	 17, // 0x759
+	 17, // 0x75a
+	 17, // 0x75b
+	 17, // 0x75c
+	 17, // 0x75d
+	 17, // 0x75e
+	 17, // 0x75f
+	 17, // 0x760
+	 17, // 0x761
+	 17, // 0x762
+	 17, // 0x763
+	 17, // 0x764
+	 17, // 0x765
+	 17, // 0x766
+	 17, // 0x767
+	 17, // 0x768
+	 17, // 0x769
+	 17, // 0x76a
+	 17, // 0x76b
+	 17, // 0x76c
+	 17, // 0x76d
+	 17, // 0x76e
+	 17, // 0x76f
+	 17, // 0x770
+	 17, // 0x771
+	 17, // 0x772
+	 17, // 0x773
+	 17, // 0x774
+	 17, // 0x775
+	 17, // 0x776
+	 17, // 0x777
+	 17, // 0x778
+	 17, // 0x779
+	 17, // 0x77a
+	 17, // 0x77b
+	 17, // 0x77c
+	 17, // 0x77d
+	 17, // 0x77e
+	 17, // 0x77f
+	 17, // 0x780
+	 17, // 0x781
+	 17, // 0x782
+	 17, // 0x783
+	 17, // 0x784
+	 17, // 0x785
+	 17, // 0x786
+	 17, // 0x787
+	 17, // 0x788
+	 17, // 0x789
+	 17, // 0x78a
+	 17, // 0x78b
+	 17, // 0x78c
+	 17, // 0x78d
+	 17, // 0x78e
+	 17, // 0x78f
+	 17, // 0x790
+	 17, // 0x791
+	 17, // 0x792
+	 17, // 0x793
+	 17, // 0x794
+	 17, // 0x795
+	 17, // 0x796
+	 17, // 0x797
+	 17, // 0x798
+	 17, // 0x799
+	 17, // 0x79a
+	 17, // 0x79b
+	 17, // 0x79c
+	 17, // 0x79d
+	 17, // 0x79e
+	 17, // 0x79f
+	 17, // 0x7a0
+	 17, // 0x7a1
+	 17, // 0x7a2
+	 17, // 0x7a3
+	 17, // 0x7a4
+	 17, // 0x7a5
+	 17, // 0x7a6
+	 17, // 0x7a7
+	 17, // 0x7a8
+	 17, // 0x7a9
+	 17, // 0x7aa
+	 17, // 0x7ab
+	 17, // 0x7ac
+	 17, // 0x7ad
+	 17, // 0x7ae
+	 17, // 0x7af
+	 17, // 0x7b0
+	 17, // 0x7b1
+	 17, // 0x7b2
+	 17, // 0x7b3
+	 17, // 0x7b4
+	 17, // 0x7b5
+	 17, // 0x7b6
+	 17, // 0x7b7
+	 17, // 0x7b8
+	 17, // 0x7b9
+	 17, // 0x7ba
+	 17, // 0x7bb
+	 17, // 0x7bc
+	 17, // 0x7bd
+	 17, // 0x7be
+	 17, // 0x7bf
+	 17, // 0x7c0
+	 17, // 0x7c1
+	 17, // 0x7c2
+	 17, // 0x7c3
+	 17, // 0x7c4
+	 17, // 0x7c5
+	 17, // 0x7c6
+	 17, // 0x7c7
+	 17, // 0x7c8
+	 17, // 0x7c9
+	 17, // 0x7ca
+	 17, // 0x7cb
+	 17, // 0x7cc
+	 17, // 0x7cd
+	 17, // 0x7ce
+	 17, // 0x7cf
+	 17, // 0x7d0
+	 17, // 0x7d1
+	 17, // 0x7d2
+	 17, // 0x7d3
+	 17, // 0x7d4
+	 17, // 0x7d5
+	 17, // 0x7d6
+	 17, // 0x7d7
+	 17, // 0x7d8
+	 17, // 0x7d9
+	 17, // 0x7da
+	 17, // 0x7db
+	 17, // 0x7dc
+	 17, // 0x7dd
+	 17, // 0x7de
+	 17, // 0x7df
+	 17, // 0x7e0
+	 17, // 0x7e1
+	 17, // 0x7e2
+	 17, // 0x7e3
+	 17, // 0x7e4
+	 17, // 0x7e5
+	 17, // 0x7e6
+	 17, // 0x7e7
+	 17, // 0x7e8
+	 17, // 0x7e9
+	 17, // 0x7ea
+	 17, // 0x7eb
+	 17, // 0x7ec
+	 17, // 0x7ed
+	 17, // 0x7ee
+	 17, // 0x7ef
+	 17, // 0x7f0
+	 17, // 0x7f1
+	 17, // 0x7f2
+	 17, // 0x7f3
+	 17, // 0x7f4
+	 17, // 0x7f5
+	 17, // 0x7f6
+	 17, // 0x7f7
+	 17, // 0x7f8
+	 17, // 0x7f9
+	 17, // 0x7fa
+	 17, // 0x7fb
+	 17, // 0x7fc
+	 17, // 0x7fd
+	 17, // 0x7fe
+	 17, // 0x7ff
+	 17, // 0x800
+	 17, // 0x801
+	 17, // 0x802
+	 17, // 0x803
+	 17, // 0x804
+	 17, // 0x805
+	 17, // 0x806
+	 17, // 0x807
+	 17, // 0x808
+	 17, // 0x809
+	 17, // 0x80a
+	 17, // 0x80b
+	 17, // 0x80c
+	 17, // 0x80d
+	 17, // 0x80e
+	 17, // 0x80f
+	 17, // 0x810
+	 17, // 0x811
+	 17, // 0x812
+	 17, // 0x813
+	 17, // 0x814
+	 17, // 0x815
+	 17, // 0x816
+	 17, // 0x817
+	 17, // 0x818
+	 17, // 0x819
+	 17, // 0x81a
+	 17, // 0x81b
+	 17, // 0x81c
+	 17, // 0x81d
+	 17, // 0x81e
+	 17, // 0x81f
+	 17, // 0x820
+	 17, // 0x821
+	 17, // 0x822
+	 17, // 0x823
+	 17, // 0x824
+	 17, // 0x825
+	 17, // 0x826
+	 17, // 0x827
+	 17, // 0x828
+	 17, // 0x829
+	 17, // 0x82a
+	 17, // 0x82b
+	 17, // 0x82c
+	 17, // 0x82d
+	 17, // 0x82e
+	 17, // 0x82f
+	 17, // 0x830
+	 17, // 0x831
+	 17, // 0x832
+	 17, // 0x833
+	 17, // 0x834
+	 17, // 0x835
+	 17, // 0x836
+	 17, // 0x837
+	 17, // 0x838
+	 17, // 0x839
+	 17, // 0x83a
+	 17, // 0x83b
+	 17, // 0x83c
+	 17, // 0x83d
+	 17, // 0x83e
+	 17, // 0x83f
+	 17, // 0x840
+	 17, // 0x841
+	 17, // 0x842
+	 17, // 0x843
+	 17, // 0x844
+	 17, // 0x845
+	 17, // 0x846
+	 17, // 0x847
+	 17, // 0x848
+	 17, // 0x849
+	 17, // 0x84a
+	 17, // 0x84b
+	 17, // 0x84c
+	 17, // 0x84d
+	 17, // 0x84e
+	 17, // 0x84f
+	 17, // 0x850
+	 17, // 0x851
+	 17, // 0x852
+	 17, // 0x853
+	 17, // 0x854
+	 17, // 0x855
+	 17, // 0x856
+	 17, // 0x857
+	 17, // 0x858
+	 17, // 0x859
+	 17, // 0x85a
+	 17, // 0x85b
+	 17, // 0x85c
+	 17, // 0x85d
+	 17, // 0x85e
+	 17, // 0x85f
+	 17, // 0x860
+	 17, // 0x861
+	 17, // 0x862
+	 17, // 0x863
+	 17, // 0x864
+	 17, // 0x865
+	 17, // 0x866
+	 17, // 0x867
+	 17, // 0x868
+	 17, // 0x869
+	 17, // 0x86a
+	 17, // 0x86b
+	 17, // 0x86c
+	 17, // 0x86d
+	 17, // 0x86e
+	 17, // 0x86f
+	 17, // 0x870
+	 17, // 0x871
+	 17, // 0x872
+	 17, // 0x873
+	 17, // 0x874
+	 17, // 0x875
+	 17, // 0x876
+	 17, // 0x877
+	 17, // 0x878
+	 17, // 0x879
+	 17, // 0x87a
+	 17, // 0x87b
+	 17, // 0x87c
+	 17, // 0x87d
+	 17, // 0x87e
+	 17, // 0x87f
+	 17, // 0x880
+	 17, // 0x881
+	 17, // 0x882
+	 17, // 0x883
+	 17, // 0x884
+	 17, // 0x885
+	 17, // 0x886
+	 17, // 0x887
+	 17, // 0x888
+	 17, // 0x889
+	 17, // 0x88a
+	 17, // 0x88b
+	 17, // 0x88c
+	 17, // 0x88d
+	 17, // 0x88e
+	 17, // 0x88f
+	 17, // 0x890
+	 17, // 0x891
+	 17, // 0x892
+	 17, // 0x893
+	 17, // 0x894
+	 17, // 0x895
+	 17, // 0x896
+	 17, // 0x897
+	 17, // 0x898
+	 17, // 0x899
+	 17, // 0x89a
+	 17, // 0x89b
+	 17, // 0x89c
+	 17, // 0x89d
+	 17, // 0x89e
+	 17, // 0x89f
+	 17, // 0x8a0
+	 17, // 0x8a1
+	 17, // 0x8a2
+	 17, // 0x8a3
+	 17, // 0x8a4
+	 17, // 0x8a5
+	 17, // 0x8a6
+	 17, // 0x8a7
+	 17, // 0x8a8
+	 17, // 0x8a9
+	 17, // 0x8aa
+	 17, // 0x8ab
+	 17, // 0x8ac
+	 17, // 0x8ad
+	 17, // 0x8ae
+	 17, // 0x8af
+	 17, // 0x8b0
+	 17, // 0x8b1
+	 17, // 0x8b2
+	 17, // 0x8b3
+	 17, // 0x8b4
+	 17, // 0x8b5
+	 17, // 0x8b6
+	 17, // 0x8b7
+	 17, // 0x8b8
+	 17, // 0x8b9
+	 17, // 0x8ba
+	 17, // 0x8bb
+	 17, // 0x8bc
+	 17, // 0x8bd
+	 17, // 0x8be
+	 17, // 0x8bf
+	 17, // 0x8c0
+	 17, // 0x8c1
+	 17, // 0x8c2
+	 17, // 0x8c3
+	 17, // 0x8c4
+	 17, // 0x8c5
+	 17, // 0x8c6
+	 17, // 0x8c7
+	 17, // 0x8c8
+	 17, // 0x8c9
+	 17, // 0x8ca
+	 17, // 0x8cb
+	 17, // 0x8cc
+	 17, // 0x8cd
+	 17, // 0x8ce
+	 17, // 0x8cf
+	 17, // 0x8d0
+	 17, // 0x8d1
+	 17, // 0x8d2
+	 17, // 0x8d3
+	 17, // 0x8d4
+	 17, // 0x8d5
+	 17, // 0x8d6
+	 17, // 0x8d7
+	 17, // 0x8d8
+	 17, // 0x8d9
+	 17, // 0x8da
+	 17, // 0x8db
+	 17, // 0x8dc
+	 17, // 0x8dd
+	 17, // 0x8de
+	 17, // 0x8df
+	 17, // 0x8e0
+	 17, // 0x8e1
+	 17, // 0x8e2
+	 17, // 0x8e3
+	 17, // 0x8e4
+	 17, // 0x8e5
+	 17, // 0x8e6
+	 17, // 0x8e7
+	 17, // 0x8e8
+	 17, // 0x8e9
+	 17, // 0x8ea
+	 17, // 0x8eb
+	 17, // 0x8ec
+	 17, // 0x8ed
+	 17, // 0x8ee
+	 17, // 0x8ef
+	 17, // 0x8f0
+	 17, // 0x8f1
+	 17, // 0x8f2
+	 17, // 0x8f3
+	 17, // 0x8f4
+	 17, // 0x8f5
+	 17, // 0x8f6
+	 17, // 0x8f7
+	 17, // 0x8f8
+	 17, // 0x8f9
+	 17, // 0x8fa
+	 17, // 0x8fb
+	 17, // 0x8fc
+	 17, // 0x8fd
+	 17, // 0x8fe
+	 17, // 0x8ff
+	 17, // 0x900
+	 17, // 0x901
+	 17, // 0x902
+	 17, // 0x903
+	 17, // 0x904
+	 17, // 0x905
+	 17, // 0x906
+	 17, // 0x907
+	 17, // 0x908
+	 17, // 0x909
+	 17, // 0x90a
+	 17, // 0x90b
+	 17, // 0x90c
+	 17, // 0x90d
+	 17, // 0x90e
+	 17, // 0x90f
+	 17, // 0x910
+	 17, // 0x911
+	 17, // 0x912
+	 17, // 0x913
+	 17, // 0x914
+	 17, // 0x915
+	 17, // 0x916
+	 17, // 0x917
+	 17, // 0x918
+	 17, // 0x919
+	 17, // 0x91a
+	 17, // 0x91b
+	 17, // 0x91c
+	 17, // 0x91d
+	 17, // 0x91e
+	 17, // 0x91f
+	 17, // 0x920
+	 17, // 0x921
+	 17, // 0x922
+	 17, // 0x923
+	 17, // 0x924
+	 17, // 0x925
+	 17, // 0x926
+	 17, // 0x927
+	 17, // 0x928
+	 17, // 0x929
+	 17, // 0x92a
+	 17, // 0x92b
+	 17, // 0x92c
+	 17, // 0x92d
+	 17, // 0x92e
+	 17, // 0x92f
+	 17, // 0x930
+	 17, // 0x931
+	 17, // 0x932
+	 17, // 0x933
+	 17, // 0x934
+	 17, // 0x935
+	 17, // 0x936
+	 17, // 0x937
+	 17, // 0x938
+	 17, // 0x939
+	 17, // 0x93a
+	 17, // 0x93b
+	 17, // 0x93c
+	 17, // 0x93d
+	 17, // 0x93e
+	 17, // 0x93f
+	 17, // 0x940
+	 17, // 0x941
+	 17, // 0x942
+	 17, // 0x943
+	 17, // 0x944
+	 17, // 0x945
+	 17, // 0x946
+	 17, // 0x947
+	 17, // 0x948
+	 17, // 0x949
+	 17, // 0x94a
+	 17, // 0x94b
+	 17, // 0x94c
+	 17, // 0x94d
+	 17, // 0x94e
+	 17, // 0x94f
+	 17, // 0x950
+	 17, // 0x951
+	 17, // 0x952
+	 17, // 0x953
+	 17, // 0x954
+	 17, // 0x955
+	 17, // 0x956
+	 17, // 0x957
+	 17, // 0x958
+	 17, // 0x959
+	 17, // 0x95a
+	 17, // 0x95b
+	 17, // 0x95c
+	 17, // 0x95d
+	 17, // 0x95e
+	 17, // 0x95f
+	 17, // 0x960
+	 17, // 0x961
+	 17, // 0x962
+	 17, // 0x963
+	 17, // 0x964
+	 17, // 0x965
+	 17, // 0x966
+	 17, // 0x967
+	 17, // 0x968
+	 17, // 0x969
+	 17, // 0x96a
+	 17, // 0x96b
+	 17, // 0x96c
+	 17, // 0x96d
+	 17, // 0x96e
+	 17, // 0x96f
+	 17, // 0x970
+	 17, // 0x971
+	 17, // 0x972
+	 17, // 0x973
+	 17, // 0x974
+	 17, // 0x975
+	 17, // 0x976
+	 17, // 0x977
+	 17, // 0x978
+	 17, // 0x979
+	 17, // 0x97a
+	 17, // 0x97b
+	 17, // 0x97c
+	 17, // 0x97d
+	 17, // 0x97e
+	 17, // 0x97f
+	 17, // 0x980
+	 17, // 0x981
+	 17, // 0x982
+	 17, // 0x983
+	 17, // 0x984
+	 17, // 0x985
+	 17, // 0x986
+	 17, // 0x987
+	 17, // 0x988
+	 17, // 0x989
+	 17, // 0x98a
+	 17, // 0x98b
+	 17, // 0x98c
+	 17, // 0x98d
+	 17, // 0x98e
+	 17, // 0x98f
+	 17, // 0x990
+	 17, // 0x991
+	 17, // 0x992
+	 17, // 0x993
+	 17, // 0x994
+	 17, // 0x995
+	 17, // 0x996
+	 17, // 0x997
+	 17, // 0x998
+	 17, // 0x999
+	 17, // 0x99a
+	 17, // 0x99b
+	 17, // 0x99c
+	 17, // 0x99d
+	 17, // 0x99e
+	 17, // 0x99f
+	 17, // 0x9a0
+	 17, // 0x9a1
+	 17, // 0x9a2
+	 17, // 0x9a3
+	 17, // 0x9a4
+	 17, // 0x9a5
+	 17, // 0x9a6
+	 17, // 0x9a7
+	 17, // 0x9a8
+	 17, // 0x9a9
+	 17, // 0x9aa
+	 17, // 0x9ab
+	 17, // 0x9ac
+	 17, // 0x9ad
+	 17, // 0x9ae
+	 17, // 0x9af
+	 17, // 0x9b0
+	 17, // 0x9b1
+	 17, // 0x9b2
+	 17, // 0x9b3
+	 17, // 0x9b4
+	 17, // 0x9b5
+	 17, // 0x9b6
+	 17, // 0x9b7
+	 17, // 0x9b8
+	 17, // 0x9b9
+	 17, // 0x9ba
+	 17, // 0x9bb
+	 17, // 0x9bc
+	 17, // 0x9bd
+	 17, // 0x9be
+	 17, // 0x9bf
+	 17, // 0x9c0
+	 17, // 0x9c1
+	 17, // 0x9c2
+	 17, // 0x9c3
+	 17, // 0x9c4
+	 17, // 0x9c5
+	 17, // 0x9c6
+	 17, // 0x9c7
+	 17, // 0x9c8
+	 17, // 0x9c9
+	 17, // 0x9ca
+	 17, // 0x9cb
+	 17, // 0x9cc
+	 17, // 0x9cd
+	 17, // 0x9ce
+	 17, // 0x9cf
+	 17, // 0x9d0
+	 17, // 0x9d1
+	 17, // 0x9d2
+	 17, // 0x9d3
+	 17, // 0x9d4
+	 17, // 0x9d5
+	 17, // 0x9d6
+	 17, // 0x9d7
+	 17, // 0x9d8
+	 17, // 0x9d9
+	 17, // 0x9da
+	 17, // 0x9db
+	 17, // 0x9dc
+	 17, // 0x9dd
+	 17, // 0x9de
+	 17, // 0x9df
+	 17, // 0x9e0
+	 17, // 0x9e1
+	 17, // 0x9e2
+	 17, // 0x9e3
+	 17, // 0x9e4
+	 17, // 0x9e5
+	 17, // 0x9e6
+	 17, // 0x9e7
+	 17, // 0x9e8
+	 17, // 0x9e9
+	 17, // 0x9ea
+	 17, // 0x9eb
+	 17, // 0x9ec
+	 17, // 0x9ed
+	 17, // 0x9ee
+	 17, // 0x9ef
+	 17, // 0x9f0
+	 17, // 0x9f1
+	 17, // 0x9f2
+	 17, // 0x9f3
+	 17, // 0x9f4
+	 17, // 0x9f5
+	 17, // 0x9f6
+	 17, // 0x9f7
+	 17, // 0x9f8
+	 17, // 0x9f9
+	 17, // 0x9fa
+	 17, // 0x9fb
+	 17, // 0x9fc
+	 17, // 0x9fd
+	 17, // 0x9fe
+	 17, // 0x9ff
+	 17, // 0xa00
+	 17, // 0xa01
+	 17, // 0xa02
+	 17, // 0xa03
+	 17, // 0xa04
+	 17, // 0xa05
+	 17, // 0xa06
+	 17, // 0xa07
+	 17, // 0xa08
+	 17, // 0xa09
+	 17, // 0xa0a
+	 17, // 0xa0b
+	 17, // 0xa0c
+	 17, // 0xa0d
+	 17, // 0xa0e
+	 17, // 0xa0f
+	 17, // 0xa10
+	 17, // 0xa11
+	 17, // 0xa12
+	 17, // 0xa13
+	 17, // 0xa14
+	 17, // 0xa15
+	 17, // 0xa16
+	 17, // 0xa17
+	 17, // 0xa18
+	 17, // 0xa19
+	 17, // 0xa1a
+	 17, // 0xa1b
+	 17, // 0xa1c
+	 17, // 0xa1d
+	 17, // 0xa1e
+	 17, // 0xa1f
+	 17, // 0xa20
+	 17, // 0xa21
+	 17, // 0xa22
+	 17, // 0xa23
+	 17, // 0xa24
+	 17, // 0xa25
+	 17, // 0xa26
+	 17, // 0xa27
+	 17, // 0xa28
+	 17, // 0xa29
+	 17, // 0xa2a
+	 17, // 0xa2b
+	 17, // 0xa2c
+	 17, // 0xa2d
+	 17, // 0xa2e
+	 17, // 0xa2f
+	 17, // 0xa30
+	 17, // 0xa31
+	 17, // 0xa32
+	 17, // 0xa33
+	 17, // 0xa34
+	 17, // 0xa35
+	 17, // 0xa36
+	 17, // 0xa37
+	 17, // 0xa38
+	 17, // 0xa39
+	 17, // 0xa3a
+	 17, // 0xa3b
+	 17, // 0xa3c
+	 17, // 0xa3d
+	 17, // 0xa3e
+	 17, // 0xa3f
+	 17, // 0xa40
+	 17, // 0xa41
+	 17, // 0xa42
+	 17, // 0xa43
+	 17, // 0xa44
+	 17, // 0xa45
+	 17, // 0xa46
+	 17, // 0xa47
+	 17, // 0xa48
+	 17, // 0xa49
+	 17, // 0xa4a
+	 17, // 0xa4b
+	 17, // 0xa4c
+	 17, // 0xa4d
+	 17, // 0xa4e
+	 17, // 0xa4f
+	 17, // 0xa50
+	 17, // 0xa51
+	 17, // 0xa52
+	 17, // 0xa53
+	 17, // 0xa54
+	 17, // 0xa55
+	 17, // 0xa56
+	 17, // 0xa57
+	 17, // 0xa58
+	 17, // 0xa59
+	 17, // 0xa5a
+	 17, // 0xa5b
+	 17, // 0xa5c
+	 17, // 0xa5d
+	 17, // 0xa5e
+	 17, // 0xa5f
+	 17, // 0xa60
+	 17, // 0xa61
+	 17, // 0xa62
+	 17, // 0xa63
+	 17, // 0xa64
+	 17, // 0xa65
+	 17, // 0xa66
+	 17, // 0xa67
+	 17, // 0xa68
+	 17, // 0xa69
+	 17, // 0xa6a
+	 17, // 0xa6b
+	 17, // 0xa6c
+	 17, // 0xa6d
+	 17, // 0xa6e
+	 17, // 0xa6f
+	 17, // 0xa70
+	 17, // 0xa71
+	 17, // 0xa72
+	 17, // 0xa73
+	 17, // 0xa74
+	 17, // 0xa75
+	 17, // 0xa76
+	 17, // 0xa77
+	 17, // 0xa78
+	 17, // 0xa79
+	 17, // 0xa7a
+	 17, // 0xa7b
+	 17, // 0xa7c
+	 17, // 0xa7d
+	 17, // 0xa7e
+	 17, // 0xa7f
+	 17, // 0xa80
+	 17, // 0xa81
+	 17, // 0xa82
+	 17, // 0xa83
+	 17, // 0xa84
+	 17, // 0xa85
+	 17, // 0xa86
+	 17, // 0xa87
+	 17, // 0xa88
+	 17, // 0xa89
+	 17, // 0xa8a
+	 17, // 0xa8b
+	 17, // 0xa8c
+	 17, // 0xa8d
+	 17, // 0xa8e
+	 17, // 0xa8f
+	 17, // 0xa90
+	 17, // 0xa91
+	 17, // 0xa92
+	 17, // 0xa93
+	 17, // 0xa94
+	 17, // 0xa95
+	 17, // 0xa96
+	 17, // 0xa97
+	 17, // 0xa98
+	 17, // 0xa99
+	 17, // 0xa9a
+	 17, // 0xa9b
+	 17, // 0xa9c
+	 17, // 0xa9d
+	 17, // 0xa9e
+	 17, // 0xa9f
+	 17, // 0xaa0
+	 17, // 0xaa1
+	 17, // 0xaa2
+	 17, // 0xaa3
+	 17, // 0xaa4
+	 17, // 0xaa5
+	 17, // 0xaa6
+	 17, // 0xaa7
+	 17, // 0xaa8
+	 17, // 0xaa9
+	 17, // 0xaaa
+	 17, // 0xaab
+	 17, // 0xaac
+	 17, // 0xaad
+	 17, // 0xaae
+	 17, // 0xaaf
+	 17, // 0xab0
+	 17, // 0xab1
+	 17, // 0xab2
+	 17, // 0xab3
+	 17, // 0xab4
+	 17, // 0xab5
+	 17, // 0xab6
+	 17, // 0xab7
+	 17, // 0xab8
+	 17, // 0xab9
+	 17, // 0xaba
+	 17, // 0xabb
+	 17, // 0xabc
+	 17, // 0xabd
+	 17, // 0xabe
+	 17, // 0xabf
+	 17, // 0xac0
+	 17, // 0xac1
+	 17, // 0xac2
+	 17, // 0xac3
+	 17, // 0xac4
+	 17, // 0xac5
+	 17, // 0xac6
+	 17, // 0xac7
+	 17, // 0xac8
+	 17, // 0xac9
+	 17, // 0xaca
+	 17, // 0xacb
+	 17, // 0xacc
+	 17, // 0xacd
+	 17, // 0xace
+	 17, // 0xacf
+	 17, // 0xad0
+	 17, // 0xad1
+	 17, // 0xad2
+	 17, // 0xad3
+	 17, // 0xad4
+	 17, // 0xad5
+	 17, // 0xad6
+	 17, // 0xad7
+	 17, // 0xad8
+	 17, // 0xad9
+	 17, // 0xada
+	 17, // 0xadb
+	 17, // 0xadc
+	 17, // 0xadd
+	 17, // 0xade
+	 17, // 0xadf
+	 17, // 0xae0
+	 17, // 0xae1
+	 17, // 0xae2
+	 17, // 0xae3
+	 17, // 0xae4
+	 17, // 0xae5
+	 17, // 0xae6
+	 17, // 0xae7
+	 17, // 0xae8
+	 17, // 0xae9
+	 17, // 0xaea
+	 17, // 0xaeb
+	 17, // 0xaec
+	 17, // 0xaed
+	 17, // 0xaee
+	 17, // 0xaef
+	 17, // 0xaf0
+	 17, // 0xaf1
+	 17, // 0xaf2
+	 17, // 0xaf3
+	 17, // 0xaf4
+	 17, // 0xaf5
+	 17, // 0xaf6
+	 17, // 0xaf7
+	 17, // 0xaf8
+	 17, // 0xaf9
+	 17, // 0xafa
+	 17, // 0xafb
+	 17, // 0xafc
+	 17, // 0xafd
+	 17, // 0xafe
+	 17, // 0xaff
+	 17, // 0xb00
+	 17, // 0xb01
+	 17, // 0xb02
+	 17, // 0xb03
+	 17, // 0xb04
+	 17, // 0xb05
+	 17, // 0xb06
+	 17, // 0xb07
+	 17, // 0xb08
+	 17, // 0xb09
+	 17, // 0xb0a
+	 17, // 0xb0b
+	 17, // 0xb0c
+	 17, // 0xb0d
+	 17, // 0xb0e
+	 17, // 0xb0f
+	 17, // 0xb10
+	 17, // 0xb11
+	 17, // 0xb12
+	 17, // 0xb13
+	 17, // 0xb14
+	 17, // 0xb15
+	 17, // 0xb16
+	 17, // 0xb17
+	 17, // 0xb18
+	 17, // 0xb19
+	 17, // 0xb1a
+	 17, // 0xb1b
+	 17, // 0xb1c
+	 17, // 0xb1d
+	 17, // 0xb1e
+	 17, // 0xb1f
+	 17, // 0xb20
+	 17, // 0xb21
+	 17, // 0xb22
+	 17, // 0xb23
+	 17, // 0xb24
+	 17, // 0xb25
+	 17, // 0xb26
+	 17, // 0xb27
+	 17, // 0xb28
+	 17, // 0xb29
+	 17, // 0xb2a
+	 17, // 0xb2b
+	 17, // 0xb2c
+	 17, // 0xb2d
+	 17, // 0xb2e
+	 17, // 0xb2f
+	 17, // 0xb30
+	 17, // 0xb31
+	 17, // 0xb32
+	 17, // 0xb33
+	 17, // 0xb34
+	 17, // 0xb35
+	 17, // 0xb36
+	 17, // 0xb37
+	 17, // 0xb38
+	 17, // 0xb39
+	 17, // 0xb3a
+	 17, // 0xb3b
+	 17, // 0xb3c
+	 17, // 0xb3d
+	 17, // 0xb3e
+	 17, // 0xb3f
+	 17, // 0xb40
+	 17, // 0xb41
+	 17, // 0xb42
+	 17, // 0xb43
+	 17, // 0xb44
+	 17, // 0xb45
+	 17, // 0xb46
+	 17, // 0xb47
+	 17, // 0xb48
+	 17, // 0xb49
+	 17, // 0xb4a
+	 17, // 0xb4b
+	 17, // 0xb4c
+	 17, // 0xb4d
+	 17, // 0xb4e
+	 17, // 0xb4f
+	 17, // 0xb50
+	 17, // 0xb51
+	 17, // 0xb52
+	 17, // 0xb53
+	 17, // 0xb54
+	 17, // 0xb55
+	 17, // 0xb56
+	 17, // 0xb57
+	 17, // 0xb58
+	 17, // 0xb59
+	 17, // 0xb5a
+	 17, // 0xb5b
+	 17, // 0xb5c
+	 17, // 0xb5d
+	 17, // 0xb5e
+	 17, // 0xb5f
+	 17, // 0xb60
+	 17, // 0xb61
+	 17, // 0xb62
+	 17, // 0xb63
+	 17, // 0xb64
+	 17, // 0xb65
+	 17, // 0xb66
+	 17, // 0xb67
+	 17, // 0xb68
+	 17, // 0xb69
+	 17, // 0xb6a
+	 17, // 0xb6b
+	 17, // 0xb6c
+	 17, // 0xb6d
+	 17, // 0xb6e
+	 17, // 0xb6f
+	 17, // 0xb70
+	 17, // 0xb71
+	 17, // 0xb72
+	 17, // 0xb73
+	 17, // 0xb74
+	 17, // 0xb75
+	 17, // 0xb76
+	 17, // 0xb77
+	 17, // 0xb78
+	 17, // 0xb79
+	 17, // 0xb7a
+	 17, // 0xb7b
+	 17, // 0xb7c
+	 17, // 0xb7d
+	 17, // 0xb7e
+	 17, // 0xb7f
+	 17, // 0xb80
+	 17, // 0xb81
+	 17, // 0xb82
+	 17, // 0xb83
+	 17, // 0xb84
+	 17, // 0xb85
+	 17, // 0xb86
+	 17, // 0xb87
+	 17, // 0xb88
+	 17, // 0xb89
+	 17, // 0xb8a
+	 17, // 0xb8b
+	 17, // 0xb8c
+	 17, // 0xb8d
+	 17, // 0xb8e
+	 17, // 0xb8f
+	 17, // 0xb90
+	 17, // 0xb91
+	 17, // 0xb92
+	 17, // 0xb93
+	 17, // 0xb94
+	 17, // 0xb95
+	 17, // 0xb96
+	 17, // 0xb97
+	 17, // 0xb98
+	 17, // 0xb99
+	 17, // 0xb9a
+	 17, // 0xb9b
+	 17, // 0xb9c
+	 17, // 0xb9d
+	 17, // 0xb9e
+	 17, // 0xb9f
+	 17, // 0xba0
+	 17, // 0xba1
+	 17, // 0xba2
+	 17, // 0xba3
+	 17, // 0xba4
+	 17, // 0xba5
+	 17, // 0xba6
+	 17, // 0xba7
+	 17, // 0xba8
+	 17, // 0xba9
+	 17, // 0xbaa
+	 17, // 0xbab
+	 17, // 0xbac
+	 17, // 0xbad
+	 17, // 0xbae
+	 17, // 0xbaf
+	 17, // 0xbb0
+	 17, // 0xbb1
+	 17, // 0xbb2
+	 17, // 0xbb3
+	 17, // 0xbb4
+	 17, // 0xbb5
+	 17, // 0xbb6
+	 17, // 0xbb7
+	 17, // 0xbb8
+	 17, // 0xbb9
+	 17, // 0xbba
+	 17, // 0xbbb
+	 17, // 0xbbc
+	 17, // 0xbbd
+	 17, // 0xbbe
+	 17, // 0xbbf
+	 17, // 0xbc0
+	 17, // 0xbc1
+	 17, // 0xbc2
+	 17, // 0xbc3
+	 17, // 0xbc4
+	 17, // 0xbc5
+	 17, // 0xbc6
+	 17, // 0xbc7
+	 17, // 0xbc8
+	 17, // 0xbc9
+	 17, // 0xbca
+	 17, // 0xbcb
+	 17, // 0xbcc
+	 17, // 0xbcd
+	 17, // 0xbce
+	 17, // 0xbcf
+	 17, // 0xbd0
+	 17, // 0xbd1
+	 17, // 0xbd2
+	 17, // 0xbd3
+	 17, // 0xbd4
+	 17, // 0xbd5
+	 17, // 0xbd6
+	 17, // 0xbd7
+	 17, // 0xbd8
+	 17, // 0xbd9
+	 17, // 0xbda
+	 17, // 0xbdb
+	 17, // 0xbdc
+	 17, // 0xbdd
+	 17, // 0xbde
+	 17, // 0xbdf
+	 17, // 0xbe0
+	 17, // 0xbe1
+	 17, // 0xbe2
+	 17, // 0xbe3
+	 17, // 0xbe4
+	 17, // 0xbe5
+	 17, // 0xbe6
+	 17, // 0xbe7
+	 17, // 0xbe8
+	 17, // 0xbe9
+	 17, // 0xbea
+	 17, // 0xbeb
+	 17, // 0xbec
+	 17, // 0xbed
+	 17, // 0xbee
+	 17, // 0xbef
+	 17, // 0xbf0
+	 17, // 0xbf1
+	 17, // 0xbf2
+	 17, // 0xbf3
+	 17, // 0xbf4
+	 17, // 0xbf5
+	 17, // 0xbf6
+	 17, // 0xbf7
+	 17, // 0xbf8
+	 17, // 0xbf9
+	 17, // 0xbfa
+	 17, // 0xbfb
+	 17, // 0xbfc
+	 17, // 0xbfd
+	 17, // 0xbfe
+	 17, // 0xbff
+	 17, // 0xc00
+	 17, // 0xc01
+	 17, // 0xc02
+	 17, // 0xc03
+	 17, // 0xc04
+	 17, // 0xc05
+	 17, // 0xc06
+	 17, // 0xc07
+	 17, // 0xc08
+	 17, // 0xc09
+	 17, // 0xc0a
+	 17, // 0xc0b
+	 17, // 0xc0c
+	 17, // 0xc0d
+	 17, // 0xc0e
+	 17, // 0xc0f
+	 17, // 0xc10
+	 17, // 0xc11
+	 17, // 0xc12
+	 17, // 0xc13
+	 17, // 0xc14
+	 17, // 0xc15
+	 17, // 0xc16
+	 17, // 0xc17
+	 17, // 0xc18
+	 17, // 0xc19
+	 17, // 0xc1a
+	 17, // 0xc1b
+	 17, // 0xc1c
+	 17, // 0xc1d
+	 17, // 0xc1e
+	 17, // 0xc1f
+	 17, // 0xc20
+	 17, // 0xc21
+	 17, // 0xc22
+	 17, // 0xc23
+	 17, // 0xc24
+	 17, // 0xc25
+	 17, // 0xc26
+	 17, // 0xc27
+	 17, // 0xc28
+	 17, // 0xc29
+	 17, // 0xc2a
+	 17, // 0xc2b
+	 17, // 0xc2c
+	 17, // 0xc2d
+	 17, // 0xc2e
+	 17, // 0xc2f
+	 17, // 0xc30
+	 17, // 0xc31
+	 17, // 0xc32
+	 17, // 0xc33
+	 17, // 0xc34
+	 17, // 0xc35
+	 17, // 0xc36
+	 17, // 0xc37
+	 17, // 0xc38
+	 17, // 0xc39
+	 17, // 0xc3a
+	 17, // 0xc3b
+	 17, // 0xc3c
+	 17, // 0xc3d
+	 17, // 0xc3e
+	 17, // 0xc3f
+	 17, // 0xc40
+	 17, // 0xc41
+	 17, // 0xc42
+	 17, // 0xc43
+	 17, // 0xc44
+	 17, // 0xc45
+	 17, // 0xc46
+	 17, // 0xc47
+	 17, // 0xc48
+	 17, // 0xc49
+	 17, // 0xc4a
+	 17, // 0xc4b
+	 17, // 0xc4c
+	 17, // 0xc4d
+	 17, // 0xc4e
+	 17, // 0xc4f
+	 17, // 0xc50
+	 17, // 0xc51
+	 17, // 0xc52
+	 17, // 0xc53
+	 17, // 0xc54
+	 17, // 0xc55
+	 17, // 0xc56
+	 17, // 0xc57
+	 17, // 0xc58
+	 17, // 0xc59
+	 17, // 0xc5a
+	 17, // 0xc5b
+	 17, // 0xc5c
+	 17, // 0xc5d
+	 17, // 0xc5e
+	 17, // 0xc5f
+	 17, // 0xc60
+	 17, // 0xc61
+	 17, // 0xc62
+	 17, // 0xc63
+	 17, // 0xc64
+	 17, // 0xc65
+	 17, // 0xc66
+	 17, // 0xc67
+	 17, // 0xc68
+	 17, // 0xc69
+	 17, // 0xc6a
+	 17, // 0xc6b
+	 17, // 0xc6c
+	 17, // 0xc6d
+	 17, // 0xc6e
+	 17, // 0xc6f
+	 17, // 0xc70
+	 17, // 0xc71
+	 17, // 0xc72
+	 17, // 0xc73
+	 17, // 0xc74
+	 17, // 0xc75
+	 17, // 0xc76
+	 17, // 0xc77
+	 17, // 0xc78
+	 17, // 0xc79
+	 17, // 0xc7a
+	 17, // 0xc7b
+	 17, // 0xc7c
+	 17, // 0xc7d
+	 17, // 0xc7e
+	 17, // 0xc7f
+	 17, // 0xc80
+	 17, // 0xc81
+	 17, // 0xc82
+	 17, // 0xc83
+	 17, // 0xc84
+	 17, // 0xc85
+	 17, // 0xc86
+	 17, // 0xc87
+	 17, // 0xc88
+	 17, // 0xc89
+	 17, // 0xc8a
+	 17, // 0xc8b
+	 17, // 0xc8c
+	 17, // 0xc8d
+	 17, // 0xc8e
+	 17, // 0xc8f
+	 17, // 0xc90
+	 17, // 0xc91
+	 17, // 0xc92
+	 17, // 0xc93
+	 17, // 0xc94
+	 17, // 0xc95
+	 17, // 0xc96
+	 17, // 0xc97
+	 17, // 0xc98
+	 17, // 0xc99
+	 17, // 0xc9a
+	 17, // 0xc9b
+	 17, // 0xc9c
+	 17, // 0xc9d
+	 17, // 0xc9e
+	 17, // 0xc9f
+	 17, // 0xca0
+	 17, // 0xca1
+	 17, // 0xca2
+	 17, // 0xca3
+	 17, // 0xca4
+	 17, // 0xca5
+	 17, // 0xca6
+	 17, // 0xca7
+	 17, // 0xca8
+	 17, // 0xca9
+	 17, // 0xcaa
+	 17, // 0xcab
+	 17, // 0xcac
+	 17, // 0xcad
+	 17, // 0xcae
+	 17, // 0xcaf
+	 17, // 0xcb0
+	 17, // 0xcb1
+	 17, // 0xcb2
+	 17, // 0xcb3
+	 17, // 0xcb4
+	 17, // 0xcb5
+	 17, // 0xcb6
+	 17, // 0xcb7
+	 17, // 0xcb8
+	 17, // 0xcb9
+	 17, // 0xcba
+	 17, // 0xcbb
+	 17, // 0xcbc
+	 17, // 0xcbd
+	 17, // 0xcbe
+	 17, // 0xcbf
+	 17, // 0xcc0
+	 17, // 0xcc1
+	 17, // 0xcc2
+	 17, // 0xcc3
+	 17, // 0xcc4
+	 17, // 0xcc5
+	 17, // 0xcc6
+	 17, // 0xcc7
+	 17, // 0xcc8
+	 17, // 0xcc9
+	 17, // 0xcca
+	 17, // 0xccb
+	 17, // 0xccc
+	 17, // 0xccd
+	 17, // 0xcce
+	 17, // 0xccf
+	 17, // 0xcd0
+	 17, // 0xcd1
+	 17, // 0xcd2
+	 17, // 0xcd3
+	 17, // 0xcd4
+	 17, // 0xcd5
+	 17, // 0xcd6
+	 17, // 0xcd7
+	 17, // 0xcd8
+	 17, // 0xcd9
+	 17, // 0xcda
+	 17, // 0xcdb
+	 17, // 0xcdc
+	 17, // 0xcdd
+	 17, // 0xcde
+	 17, // 0xcdf
+	 17, // 0xce0
+	 17, // 0xce1
+	 17, // 0xce2
+	 17, // 0xce3
+	 17, // 0xce4
+	 17, // 0xce5
+	 17, // 0xce6
+	 17, // 0xce7
+	 17, // 0xce8
+	 17, // 0xce9
+	 17, // 0xcea
+	 17, // 0xceb
+	 17, // 0xcec
+	 17, // 0xced
+	 17, // 0xcee
+	 17, // 0xcef
+	 17, // 0xcf0
+	 17, // 0xcf1
+	 17, // 0xcf2
+	 17, // 0xcf3
+	 17, // 0xcf4
+	 17, // 0xcf5
+	 17, // 0xcf6
+	 17, // 0xcf7
+	 17, // 0xcf8
+	 17, // 0xcf9
+	 17, // 0xcfa
+	 17, // 0xcfb
+	 17, // 0xcfc
+	 17, // 0xcfd
+	 17, // 0xcfe
+	 17, // 0xcff
+	 17, // 0xd00
+	 17, // 0xd01
+	 17, // 0xd02
+	 17, // 0xd03
+	 17, // 0xd04
+	 17, // 0xd05
+	 17, // 0xd06
+	 17, // 0xd07
+	 17, // 0xd08
+	 17, // 0xd09
+	 17, // 0xd0a
+	 17, // 0xd0b
+	 17, // 0xd0c
+	 17, // 0xd0d
+	 17, // 0xd0e
+	 17, // 0xd0f
+	 17, // 0xd10
+	 17, // 0xd11
+	 17, // 0xd12
+	 17, // 0xd13
+	 17, // 0xd14
+	 17, // 0xd15
+	 17, // 0xd16
+	 17, // 0xd17
+	 17, // 0xd18
+	 17, // 0xd19
+	 17, // 0xd1a
+	 17, // 0xd1b
+	 17, // 0xd1c
+	 17, // 0xd1d
+	 17, // 0xd1e
+	 17, // 0xd1f
+	 17, // 0xd20
+	 17, // 0xd21
+	 17, // 0xd22
+	 17, // 0xd23
+	 17, // 0xd24
+	 17, // 0xd25
+	 17, // 0xd26
+	 17, // 0xd27
+	 17, // 0xd28
+	 17, // 0xd29
+	 17, // 0xd2a
+	 17, // 0xd2b
+	 17, // 0xd2c
+	 17, // 0xd2d
+	 17, // 0xd2e
+	 17, // 0xd2f
+	 17, // 0xd30
+	 17, // 0xd31
+	 17, // 0xd32
+	 17, // 0xd33
+	 17, // 0xd34
+	 17, // 0xd35
+	 17, // 0xd36
+	 17, // 0xd37
+	 17, // 0xd38
+	 17, // 0xd39
+	 17, // 0xd3a
+	 17, // 0xd3b
+	 17, // 0xd3c
+	 17, // 0xd3d
+	 17, // 0xd3e
+	 17, // 0xd3f
+	 17, // 0xd40
+	 17, // 0xd41
+	 17, // 0xd42
+	 17, // 0xd43
+	 17, // 0xd44
+	 17, // 0xd45
+	 17, // 0xd46
+	 17, // 0xd47
+	 17, // 0xd48
+	 17, // 0xd49
+	 17, // 0xd4a
+	 17, // 0xd4b
+	 17, // 0xd4c
+	 17, // 0xd4d
+	 17, // 0xd4e
+	 17, // 0xd4f
+	 17, // 0xd50
+	 17, // 0xd51
+	 17, // 0xd52
+	 17, // 0xd53
+	 17, // 0xd54
+	 17, // 0xd55
+	 17, // 0xd56
+	 17, // 0xd57
+	 17, // 0xd58
+	 17, // 0xd59
+	 17, // 0xd5a
+	 17, // 0xd5b
+	 17, // 0xd5c
+	 17, // 0xd5d
+	 17, // 0xd5e
+	 17, // 0xd5f
+	 17, // 0xd60
+	 17, // 0xd61
+	 17, // 0xd62
+	 17, // 0xd63
+	 17, // 0xd64
+	 17, // 0xd65
+	 17, // 0xd66
+	 17, // 0xd67
+	 17, // 0xd68
+	 17, // 0xd69
+	 17, // 0xd6a
+	 17, // 0xd6b
+	 17, // 0xd6c
+	 17, // 0xd6d
+	 17, // 0xd6e
+	 17, // 0xd6f
+	 17, // 0xd70
+	 17, // 0xd71
+	 17, // 0xd72
+	 17, // 0xd73
+	 17, // 0xd74
+	 17, // 0xd75
+	 17, // 0xd76
+	 17, // 0xd77
+	 17, // 0xd78
+	 17, // 0xd79
+	 17, // 0xd7a
+	 17, // 0xd7b
+	 17, // 0xd7c
+	 17, // 0xd7d
+	 17, // 0xd7e
+	 17, // 0xd7f
+	 17, // 0xd80
+	 17, // 0xd81
+	 17, // 0xd82
+	 17, // 0xd83
+	 17, // 0xd84
+	 17, // 0xd85
+	 17, // 0xd86
+	 17, // 0xd87
+	 17, // 0xd88
+	 17, // 0xd89
+	 17, // 0xd8a
+	 17, // 0xd8b
+	 17, // 0xd8c
+	 17, // 0xd8d
+	 17, // 0xd8e
+	 17, // 0xd8f
+	 17, // 0xd90
+	 17, // 0xd91
+	 17, // 0xd92
+	 17, // 0xd93
+	 17, // 0xd94
+	 17, // 0xd95
+	 17, // 0xd96
+	 17, // 0xd97
+	 17, // 0xd98
+	 17, // 0xd99
+	 17, // 0xd9a
+	 17, // 0xd9b
+	 17, // 0xd9c
+	 17, // 0xd9d
+	 17, // 0xd9e
+	 17, // 0xd9f
+	 17, // 0xda0
+	 17, // 0xda1
+	 17, // 0xda2
+	 17, // 0xda3
+	 17, // 0xda4
+	 17, // 0xda5
+	 17, // 0xda6
+	 17, // 0xda7
+	 17, // 0xda8
+	 17, // 0xda9
+	 17, // 0xdaa
+	 17, // 0xdab
+	 17, // 0xdac
+	 17, // 0xdad
+	 17, // 0xdae
+	 17, // 0xdaf
+	 17, // 0xdb0
+	 17, // 0xdb1
+	 17, // 0xdb2
+	 17, // 0xdb3
+	 17, // 0xdb4
+	 17, // 0xdb5
+	 17, // 0xdb6
+	 17, // 0xdb7
+	 17, // 0xdb8
+	 17, // 0xdb9
+	 17, // 0xdba
+	 17, // 0xdbb
+	 17, // 0xdbc
+	 17, // 0xdbd
+	 17, // 0xdbe
+	 17, // 0xdbf
+	 17, // 0xdc0
+	 17, // 0xdc1
+	 17, // 0xdc2
+	 17, // 0xdc3
+	 17, // 0xdc4
+	 17, // 0xdc5
+	 17, // 0xdc6
+	 17, // 0xdc7
+	 17, // 0xdc8
+	 17, // 0xdc9
+	 17, // 0xdca
+	 17, // 0xdcb
+	 17, // 0xdcc
+	 17, // 0xdcd
+	 17, // 0xdce
+	 17, // 0xdcf
+	 17, // 0xdd0
+	 17, // 0xdd1
+	 17, // 0xdd2
+	 17, // 0xdd3
+	 17, // 0xdd4
+	 17, // 0xdd5
+	 17, // 0xdd6
+	 17, // 0xdd7
+	 17, // 0xdd8
+	 17, // 0xdd9
+	 17, // 0xdda
+	 17, // 0xddb
+	 17, // 0xddc
+	 17, // 0xddd
+	 17, // 0xdde
+	 17, // 0xddf
+	 17, // 0xde0
+	 17, // 0xde1
+	 17, // 0xde2
+	 17, // 0xde3
+	 17, // 0xde4
+	 17, // 0xde5
+	 17, // 0xde6
+	 17, // 0xde7
+	 17, // 0xde8
+	 17, // 0xde9
+	 17, // 0xdea
+	 17, // 0xdeb
+	 17, // 0xdec
+	 17, // 0xded
+	 17, // 0xdee
+	 17, // 0xdef
+	 17, // 0xdf0
+	 17, // 0xdf1
+	 17, // 0xdf2
+	 17, // 0xdf3
+	 17, // 0xdf4
+	 17, // 0xdf5
+	 17, // 0xdf6
+	 17, // 0xdf7
+	 17, // 0xdf8
+	 17, // 0xdf9
+	 17, // 0xdfa
+	 17, // 0xdfb
+	 17, // 0xdfc
+	 17, // 0xdfd
+	 17, // 0xdfe
+	 17, // 0xdff
+	 99, // 0xe00
+	 100, // 0xe01
+	 17, // 0xe02
+	 17, // 0xe03
+	 17, // 0xe04
+	 17, // 0xe05
+	 17, // 0xe06
+	 17, // 0xe07
+	 17, // 0xe08
+	 17, // 0xe09
+	 17, // 0xe0a
+	 17, // 0xe0b
+	 17, // 0xe0c
+	 17, // 0xe0d
+	 17, // 0xe0e
+	 17, // 0xe0f
+	 17, // 0xe10
+	 17, // 0xe11
+	 17, // 0xe12
+	 17, // 0xe13
+	 17, // 0xe14
+	 17, // 0xe15
+	 17, // 0xe16
+	 17, // 0xe17
+	 17, // 0xe18
+	 17, // 0xe19
+	 17, // 0xe1a
+	 17, // 0xe1b
+	 17, // 0xe1c
+	 17, // 0xe1d
+	 17, // 0xe1e
+	 17, // 0xe1f
+	 17, // 0xe20
+	 17, // 0xe21
+	 17, // 0xe22
+	 17, // 0xe23
+	 17, // 0xe24
+	 17, // 0xe25
+	 17, // 0xe26
+	 17, // 0xe27
+	 17, // 0xe28
+	 17, // 0xe29
+	 17, // 0xe2a
+	 17, // 0xe2b
+	 17, // 0xe2c
+	 17, // 0xe2d
+	 17, // 0xe2e
+	 17, // 0xe2f
+	 17, // 0xe30
+	 17, // 0xe31
+	 17, // 0xe32
+	 17, // 0xe33
+	 17, // 0xe34
+	 17, // 0xe35
+	 17, // 0xe36
+	 17, // 0xe37
+	 17, // 0xe38
+	 17, // 0xe39
+	 17, // 0xe3a
+	 17, // 0xe3b
+	 17, // 0xe3c
+	 17, // 0xe3d
+	 17, // 0xe3e
+	 17, // 0xe3f
+	 17, // 0xe40
+	 17, // 0xe41
+	 17, // 0xe42
+	 17, // 0xe43
+	 17, // 0xe44
+	 17, // 0xe45
+	 17, // 0xe46
+	 17, // 0xe47
+	 17, // 0xe48
+	 17, // 0xe49
+	 17, // 0xe4a
+	 17, // 0xe4b
+	 17, // 0xe4c
+	 17, // 0xe4d
+	 17, // 0xe4e
+	 17, // 0xe4f
+	 17, // 0xe50
+	 17, // 0xe51
+	 17, // 0xe52
+	 17, // 0xe53
+	 17, // 0xe54
+	 17, // 0xe55
+	 17, // 0xe56
+	 17, // 0xe57
+	 17, // 0xe58
+	 17, // 0xe59
+	 17, // 0xe5a
+	 17, // 0xe5b
+	 17, // 0xe5c
+	 17, // 0xe5d
+	 17, // 0xe5e
+	 17, // 0xe5f
+	 17, // 0xe60
+	 17, // 0xe61
+	 17, // 0xe62
+	 17, // 0xe63
+	 17, // 0xe64
+	 17, // 0xe65
+	 17, // 0xe66
+	 17, // 0xe67
+	 17, // 0xe68
+	 17, // 0xe69
+	 17, // 0xe6a
+	 17, // 0xe6b
+	 17, // 0xe6c
+	 17, // 0xe6d
+	 17, // 0xe6e
+	 17, // 0xe6f
+	 17, // 0xe70
+	 17, // 0xe71
+	 17, // 0xe72
+	 17, // 0xe73
+	 17, // 0xe74
+	 17, // 0xe75
+	 17, // 0xe76
+	 17, // 0xe77
+	 17, // 0xe78
+	 17, // 0xe79
+	 17, // 0xe7a
+	 17, // 0xe7b
+	 17, // 0xe7c
+	 17, // 0xe7d
+	 17, // 0xe7e
+	 17, // 0xe7f
+	 17, // 0xe80
+	 17, // 0xe81
+	 17, // 0xe82
+	 17, // 0xe83
+	 17, // 0xe84
+	 17, // 0xe85
+	 17, // 0xe86
+	 17, // 0xe87
+	 17, // 0xe88
+	 17, // 0xe89
+	 17, // 0xe8a
+	 17, // 0xe8b
+	 17, // 0xe8c
+	 17, // 0xe8d
+	 17, // 0xe8e
+	 17, // 0xe8f
+	 17, // 0xe90
+	 17, // 0xe91
+	 17, // 0xe92
+	 17, // 0xe93
+	 17, // 0xe94
+	 17, // 0xe95
+	 17, // 0xe96
+	 17, // 0xe97
+	 17, // 0xe98
+	 17, // 0xe99
+	 17, // 0xe9a
+	 17, // 0xe9b
+	 17, // 0xe9c
+	 17, // 0xe9d
+	 17, // 0xe9e
+	 17, // 0xe9f
+	 17, // 0xea0
+	 17, // 0xea1
+	 17, // 0xea2
+	 17, // 0xea3
+	 17, // 0xea4
+	 17, // 0xea5
+	 17, // 0xea6
+	 17, // 0xea7
+	 17, // 0xea8
+	 17, // 0xea9
+	 17, // 0xeaa
+	 17, // 0xeab
+	 17, // 0xeac
+	 17, // 0xead
+	 17, // 0xeae
+	 17, // 0xeaf
+	 17, // 0xeb0
+	 17, // 0xeb1
+	 17, // 0xeb2
+	 17, // 0xeb3
+	 17, // 0xeb4
+	 17, // 0xeb5
+	 17, // 0xeb6
+	 17, // 0xeb7
+	 17, // 0xeb8
+	 17, // 0xeb9
+	 17, // 0xeba
+	 17, // 0xebb
+	 17, // 0xebc
+	 17, // 0xebd
+	 17, // 0xebe
+	 17, // 0xebf
+	 17, // 0xec0
+	 17, // 0xec1
+	 17, // 0xec2
+	 17, // 0xec3
+	 17, // 0xec4
+	 17, // 0xec5
+	 17, // 0xec6
+	 17, // 0xec7
+	 17, // 0xec8
+	 17, // 0xec9
+	 17, // 0xeca
+	 17, // 0xecb
+	 17, // 0xecc
+	 17, // 0xecd
+	 17, // 0xece
+	 17, // 0xecf
+	 17, // 0xed0
+	 17, // 0xed1
+	 17, // 0xed2
+	 17, // 0xed3
+	 17, // 0xed4
+	 17, // 0xed5
+	 17, // 0xed6
+	 17, // 0xed7
+	 17, // 0xed8
+	 17, // 0xed9
+	 17, // 0xeda
+	 17, // 0xedb
+	 17, // 0xedc
+	 17, // 0xedd
+	 17, // 0xede
+	 17, // 0xedf
+	 17, // 0xee0
+	 17, // 0xee1
+	 17, // 0xee2
+	 17, // 0xee3
+	 17, // 0xee4
+	 17, // 0xee5
+	 17, // 0xee6
+	 17, // 0xee7
+	 17, // 0xee8
+	 17, // 0xee9
+	 17, // 0xeea
+	 17, // 0xeeb
+	 17, // 0xeec
+	 17, // 0xeed
+	 17, // 0xeee
+	 17, // 0xeef
+	 17, // 0xef0
+	 17, // 0xef1
+	 17, // 0xef2
+	 17, // 0xef3
+	 17, // 0xef4
+	 17, // 0xef5
+	 17, // 0xef6
+	 17, // 0xef7
+	 17, // 0xef8
+	 17, // 0xef9
+	 17, // 0xefa
+	 17, // 0xefb
+	 17, // 0xefc
+	 17, // 0xefd
+	 17, // 0xefe
+	 17, // 0xeff
+	 17, // 0xf00
+	 17, // 0xf01
+	 17, // 0xf02
+	 17, // 0xf03
+	 17, // 0xf04
+	 17, // 0xf05
+	 17, // 0xf06
+	 17, // 0xf07
+	 17, // 0xf08
+	 17, // 0xf09
+	 17, // 0xf0a
+	 17, // 0xf0b
+	 17, // 0xf0c
+	 17, // 0xf0d
+	 17, // 0xf0e
+	 17, // 0xf0f
+	 17, // 0xf10
+	 17, // 0xf11
+	 17, // 0xf12
+	 17, // 0xf13
+	 17, // 0xf14
+	 17, // 0xf15
+	 17, // 0xf16
+	 17, // 0xf17
+	 17, // 0xf18
+	 17, // 0xf19
+	 17, // 0xf1a
+	 17, // 0xf1b
+	 17, // 0xf1c
+	 17, // 0xf1d
+	 17, // 0xf1e
+	 17, // 0xf1f
+	 17, // 0xf20
+	 17, // 0xf21
+	 17, // 0xf22
+	 17, // 0xf23
+	 17, // 0xf24
+	 17, // 0xf25
+	 17, // 0xf26
+	 17, // 0xf27
+	 17, // 0xf28
+	 17, // 0xf29
+	 17, // 0xf2a
+	 17, // 0xf2b
+	 17, // 0xf2c
+	 17, // 0xf2d
+	 17, // 0xf2e
+	 17, // 0xf2f
+	 17, // 0xf30
+	 17, // 0xf31
+	 17, // 0xf32
+	 17, // 0xf33
+	 17, // 0xf34
+	 17, // 0xf35
+	 17, // 0xf36
+	 17, // 0xf37
+	 17, // 0xf38
+	 17, // 0xf39
+	 17, // 0xf3a
+	 17, // 0xf3b
+	 17, // 0xf3c
+	 17, // 0xf3d
+	 17, // 0xf3e
+	 17, // 0xf3f
+	 17, // 0xf40
+	 17, // 0xf41
+	 17, // 0xf42
+	 17, // 0xf43
+	 17, // 0xf44
+	 17, // 0xf45
+	 17, // 0xf46
+	 17, // 0xf47
+	 17, // 0xf48
+	 17, // 0xf49
+	 17, // 0xf4a
+	 17, // 0xf4b
+	 17, // 0xf4c
+	 17, // 0xf4d
+	 17, // 0xf4e
+	 17, // 0xf4f
+	 17, // 0xf50
+	 17, // 0xf51
+	 17, // 0xf52
+	 17, // 0xf53
+	 17, // 0xf54
+	 17, // 0xf55
+	 17, // 0xf56
+	 17, // 0xf57
+	 17, // 0xf58
+	 17, // 0xf59
+	 17, // 0xf5a
+	 17, // 0xf5b
+	 17, // 0xf5c
+	 17, // 0xf5d
+	 17, // 0xf5e
+	 17, // 0xf5f
+	 17, // 0xf60
+	 17, // 0xf61
+	 17, // 0xf62
+	 17, // 0xf63
+	 17, // 0xf64
+	 17, // 0xf65
+	 17, // 0xf66
+	 17, // 0xf67
+	 17, // 0xf68
+	 17, // 0xf69
+	 17, // 0xf6a
+	 17, // 0xf6b
+	 17, // 0xf6c
+	 17, // 0xf6d
+	 17, // 0xf6e
+	 17, // 0xf6f
+	 17, // 0xf70
+	 17, // 0xf71
+	 17, // 0xf72
+	 17, // 0xf73
+	 17, // 0xf74
+	 17, // 0xf75
+	 17, // 0xf76
+	 17, // 0xf77
+	 17, // 0xf78
+	 17, // 0xf79
+	 17, // 0xf7a
+	 17, // 0xf7b
+	 17, // 0xf7c
+	 17, // 0xf7d
+	 17, // 0xf7e
+	 17, // 0xf7f
+	 17, // 0xf80
+	 17, // 0xf81
+	 17, // 0xf82
+	 17, // 0xf83
+	 17, // 0xf84
+	 17, // 0xf85
+	 17, // 0xf86
+	 17, // 0xf87
+	 17, // 0xf88
+	 17, // 0xf89
+	 17, // 0xf8a
+	 17, // 0xf8b
+	 17, // 0xf8c
+	 17, // 0xf8d
+	 17, // 0xf8e
+	 17, // 0xf8f
+	 17, // 0xf90
+	 17, // 0xf91
+	 17, // 0xf92
+	 17, // 0xf93
+	 17, // 0xf94
+	 17, // 0xf95
+	 17, // 0xf96
+	 17, // 0xf97
+	 17, // 0xf98
+	 17, // 0xf99
+	 17, // 0xf9a
+	 17, // 0xf9b
+	 17, // 0xf9c
+	 17, // 0xf9d
+	 17, // 0xf9e
+	 17, // 0xf9f
+	 17, // 0xfa0
+	 17, // 0xfa1
+	 17, // 0xfa2
+	 17, // 0xfa3
+	 17, // 0xfa4
+	 17, // 0xfa5
+	 17, // 0xfa6
+	 17, // 0xfa7
+	 17, // 0xfa8
+	 17, // 0xfa9
+	 17, // 0xfaa
+	 17, // 0xfab
+	 17, // 0xfac
+	 17, // 0xfad
+	 17, // 0xfae
+	 17, // 0xfaf
+	 17, // 0xfb0
+	 17, // 0xfb1
+	 17, // 0xfb2
+	 17, // 0xfb3
+	 17, // 0xfb4
+	 17, // 0xfb5
+	 17, // 0xfb6
+	 17, // 0xfb7
+	 17, // 0xfb8
+	 17, // 0xfb9
+	 17, // 0xfba
+	 17, // 0xfbb
+	 17, // 0xfbc
+	 17, // 0xfbd
+	 17, // 0xfbe
+	 17, // 0xfbf
+	 17, // 0xfc0
+	 17, // 0xfc1
+	 17, // 0xfc2
+	 17, // 0xfc3
+	 17, // 0xfc4
+	 17, // 0xfc5
+	 17, // 0xfc6
+	 17, // 0xfc7
+	 17, // 0xfc8
+	 17, // 0xfc9
+	 17, // 0xfca
+	 17, // 0xfcb
+	 17, // 0xfcc
+	 17, // 0xfcd
+	 17, // 0xfce
+	 17, // 0xfcf
+	 17, // 0xfd0
+	 17, // 0xfd1
+	 17, // 0xfd2
+	 17, // 0xfd3
+	 17, // 0xfd4
+	 17, // 0xfd5
+	 17, // 0xfd6
+	 17, // 0xfd7
+	 17, // 0xfd8
+	 17, // 0xfd9
+	 17, // 0xfda
+	 17, // 0xfdb
+	 17, // 0xfdc
+	 17, // 0xfdd
+	 17, // 0xfde
+	 17, // 0xfdf
+	 17, // 0xfe0
+	 17, // 0xfe1
+	 17, // 0xfe2
+	 17, // 0xfe3
+	 17, // 0xfe4
+	 17, // 0xfe5
+	 17, // 0xfe6
+	 17, // 0xfe7
+	 17, // 0xfe8
+	 17, // 0xfe9
+	 17, // 0xfea
+	 17, // 0xfeb
+	 17, // 0xfec
+	 17, // 0xfed
+	 17, // 0xfee
+	 17, // 0xfef
+	 17, // 0xff0
+	 17, // 0xff1
+	 17, // 0xff2
+	 17, // 0xff3
+	 17, // 0xff4
+	 17, // 0xff5
+	 17, // 0xff6
+	 17, // 0xff7
+	 17, // 0xff8
+	 17, // 0xff9
+	 17, // 0xffa
+	 17, // 0xffb
+	 17, // 0xffc
+	 17, // 0xffd
+	 17, // 0xffe
+	 17, // 0xfff
+	 17, // 0x1000
+	 17, // 0x1001
+	 17, // 0x1002
+	 17, // 0x1003
+	 17, // 0x1004
+	 17, // 0x1005
+	 17, // 0x1006
+	 17, // 0x1007
+	 17, // 0x1008
+	 17, // 0x1009
+	 17, // 0x100a
+	 17, // 0x100b
+	 17, // 0x100c
+	 17, // 0x100d
+	 17, // 0x100e
+	 17, // 0x100f
+	 17, // 0x1010
+	 17, // 0x1011
+	 17, // 0x1012
+	 17, // 0x1013
+	 17, // 0x1014
+	 17, // 0x1015
+	 17, // 0x1016
+	 17, // 0x1017
+	 17, // 0x1018
+	 17, // 0x1019
+	 17, // 0x101a
+	 17, // 0x101b
+	 17, // 0x101c
+	 17, // 0x101d
+	 17, // 0x101e
+	 17, // 0x101f
+	 17, // 0x1020
+	 17, // 0x1021
+	 17, // 0x1022
+	 17, // 0x1023
+	 17, // 0x1024
+	 17, // 0x1025
+	 17, // 0x1026
+	 17, // 0x1027
+	 17, // 0x1028
+	 17, // 0x1029
+	 17, // 0x102a
+	 17, // 0x102b
+	 17, // 0x102c
+	 17, // 0x102d
+	 17, // 0x102e
+	 17, // 0x102f
+	 17, // 0x1030
+	 17, // 0x1031
+	 17, // 0x1032
+	 17, // 0x1033
+	 17, // 0x1034
+	 17, // 0x1035
+	 17, // 0x1036
+	 17, // 0x1037
+	 17, // 0x1038
+	 17, // 0x1039
+	 17, // 0x103a
+	 17, // 0x103b
+	 17, // 0x103c
+	 17, // 0x103d
+	 17, // 0x103e
+	 17, // 0x103f
+	 17, // 0x1040
+	 17, // 0x1041
+	 17, // 0x1042
+	 17, // 0x1043
+	 17, // 0x1044
+	 17, // 0x1045
+	 17, // 0x1046
+	 17, // 0x1047
+	 17, // 0x1048
+	 17, // 0x1049
+	 17, // 0x104a
+	 17, // 0x104b
+	 17, // 0x104c
+	 17, // 0x104d
+	 17, // 0x104e
+	 17, // 0x104f
+	 17, // 0x1050
+	 17, // 0x1051
+	 17, // 0x1052
+	 17, // 0x1053
+	 17, // 0x1054
+	 17, // 0x1055
+	 17, // 0x1056
+	 17, // 0x1057
+	 17, // 0x1058
+	 17, // 0x1059
+	 17, // 0x105a
+	 17, // 0x105b
+	 17, // 0x105c
+	 17, // 0x105d
+	 17, // 0x105e
+	 17, // 0x105f
+	 17, // 0x1060
+	 17, // 0x1061
+	 17, // 0x1062
+	 17, // 0x1063
+	 17, // 0x1064
+	 17, // 0x1065
+	 17, // 0x1066
+	 17, // 0x1067
+	 17, // 0x1068
+	 17, // 0x1069
+	 17, // 0x106a
+	 17, // 0x106b
+	 17, // 0x106c
+	 17, // 0x106d
+	 17, // 0x106e
+	 17, // 0x106f
+	 17, // 0x1070
+	 17, // 0x1071
+	 17, // 0x1072
+	 17, // 0x1073
+	 17, // 0x1074
+	 17, // 0x1075
+	 17, // 0x1076
+	 17, // 0x1077
+	 17, // 0x1078
+	 17, // 0x1079
+	 17, // 0x107a
+	 17, // 0x107b
+	 17, // 0x107c
+	 17, // 0x107d
+	 17, // 0x107e
+	 17, // 0x107f
+	 17, // 0x1080
+	 17, // 0x1081
+	 17, // 0x1082
+	 17, // 0x1083
+	 17, // 0x1084
+	 17, // 0x1085
+	 17, // 0x1086
+	 17, // 0x1087
+	 17, // 0x1088
+	 17, // 0x1089
+	 17, // 0x108a
+	 17, // 0x108b
+	 17, // 0x108c
+	 17, // 0x108d
+	 17, // 0x108e
+	 17, // 0x108f
+	 17, // 0x1090
+	 17, // 0x1091
+	 17, // 0x1092
+	 17, // 0x1093
+	 17, // 0x1094
+	 17, // 0x1095
+	 17, // 0x1096
+	 17, // 0x1097
+	 17, // 0x1098
+	 17, // 0x1099
+	 17, // 0x109a
+	 17, // 0x109b
+	 17, // 0x109c
+	 17, // 0x109d
+	 17, // 0x109e
+	 17, // 0x109f
+	 17, // 0x10a0
+	 17, // 0x10a1
+	 17, // 0x10a2
+	 17, // 0x10a3
+	 17, // 0x10a4
+	 17, // 0x10a5
+	 17, // 0x10a6
+	 17, // 0x10a7
+	 17, // 0x10a8
+	 17, // 0x10a9
+	 17, // 0x10aa
+	 17, // 0x10ab
+	 17, // 0x10ac
+	 17, // 0x10ad
+	 17, // 0x10ae
+	 17, // 0x10af
+	 17, // 0x10b0
+	 17, // 0x10b1
+	 17, // 0x10b2
+	 17, // 0x10b3
+	 17, // 0x10b4
+	 17, // 0x10b5
+	 17, // 0x10b6
+	 17, // 0x10b7
+	 17, // 0x10b8
+	 17, // 0x10b9
+	 17, // 0x10ba
+	 17, // 0x10bb
+	 17, // 0x10bc
+	 17, // 0x10bd
+	 17, // 0x10be
+	 17, // 0x10bf
+	 17, // 0x10c0
+	 17, // 0x10c1
+	 17, // 0x10c2
+	 17, // 0x10c3
+	 17, // 0x10c4
+	 17, // 0x10c5
+	 17, // 0x10c6
+	 17, // 0x10c7
+	 17, // 0x10c8
+	 17, // 0x10c9
+	 17, // 0x10ca
+	 17, // 0x10cb
+	 17, // 0x10cc
+	 17, // 0x10cd
+	 17, // 0x10ce
+	 17, // 0x10cf
+	 17, // 0x10d0
+	 17, // 0x10d1
+	 17, // 0x10d2
+	 17, // 0x10d3
+	 17, // 0x10d4
+	 17, // 0x10d5
+	 17, // 0x10d6
+	 17, // 0x10d7
+	 17, // 0x10d8
+	 17, // 0x10d9
+	 17, // 0x10da
+	 17, // 0x10db
+	 17, // 0x10dc
+	 17, // 0x10dd
+	 17, // 0x10de
+	 17, // 0x10df
+	 17, // 0x10e0
+	 17, // 0x10e1
+	 17, // 0x10e2
+	 17, // 0x10e3
+	 17, // 0x10e4
+	 17, // 0x10e5
+	 17, // 0x10e6
+	 17, // 0x10e7
+	 17, // 0x10e8
+	 17, // 0x10e9
+	 17, // 0x10ea
+	 17, // 0x10eb
+	 17, // 0x10ec
+	 17, // 0x10ed
+	 17, // 0x10ee
+	 17, // 0x10ef
+	 17, // 0x10f0
+	 17, // 0x10f1
+	 17, // 0x10f2
+	 17, // 0x10f3
+	 17, // 0x10f4
+	 17, // 0x10f5
+	 17, // 0x10f6
+	 17, // 0x10f7
+	 17, // 0x10f8
+	 17, // 0x10f9
+	 17, // 0x10fa
+	 17, // 0x10fb
+	 17, // 0x10fc
+	 17, // 0x10fd
+	 17, // 0x10fe
+	 17, // 0x10ff
+];
+pub const CASE_PAGES: [[u8;256];101] = [
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x31,
+        0x31,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x12,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x35,
+        0x15,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x11,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x31,
+        0x31,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+    ],
+    [
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x31,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x31,
+        0x15,
+        0x31,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+    ],
+    [
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x15,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x15,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x15,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x13,
+        0x13,
+        0x17,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x13,
+        0x13,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+    ],
+    [
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x2a,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x3,
+        0xa,
+        0x2,
+        0x2,
+        0x2,
+        0xa,
+        0xa,
+        0xa,
+        0x2,
+        0x2,
+        0x12,
+        0xa,
+        0xa,
+        0xa,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0xa,
+        0x2,
+        0x2,
+        0x2,
+        0xa,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x12,
+        0x12,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x13,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x11,
+        0x12,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x31,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x15,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+    ],
+    [
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x15,
+        0x11,
+        0x15,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x12,
+        0x12,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+    ],
+    [
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x2,
+        0xa,
+        0xa,
+        0xa,
+        0x2,
+        0x2,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0xa,
+        0xa,
+        0x2,
+        0xa,
+        0xa,
+        0x2,
+        0x2,
+        0xa,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x10,
+        0x2,
+        0x10,
+        0x2,
+        0x2,
+        0x10,
+        0xa,
+        0x2,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x2,
+        0x2,
+        0x2,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0xa,
+        0xa,
+        0x2,
+        0x2,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x2,
+        0xa,
+        0xa,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x12,
+        0x10,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x2,
+        0xa,
+        0x12,
+        0x12,
+        0xa,
+        0xa,
+        0x10,
+        0x2,
+        0xa,
+        0xa,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0x2,
+        0xa,
+        0xa,
+        0x2,
+        0xa,
+        0xa,
+        0x2,
+        0x2,
+        0x2,
+        0xa,
+        0x2,
+        0x2,
+        0xa,
+        0x2,
+        0xa,
+        0xa,
+        0xa,
+        0x2,
+        0xa,
+        0x2,
+        0xa,
+        0x2,
+        0xa,
+        0x2,
+        0xa,
+        0xa,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x2,
+        0xa,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x12,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x12,
+        0xa,
+        0xa,
+        0xa,
+        0x12,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x2,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0xa,
+        0x2,
+        0x2,
+        0x2,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x12,
+        0x2,
+        0xa,
+        0xa,
+        0x2,
+        0xa,
+        0xa,
+        0x2,
+        0xa,
+        0xa,
+        0xa,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0xa,
+        0xa,
+        0xa,
+        0x2,
+        0xa,
+        0xa,
+        0x2,
+        0x2,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+    ],
+    [
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0x2,
+        0xa,
+        0xa,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0x10,
+    ],
+    [
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+    ],
+    [
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x2,
+        0x2,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x2,
+        0x2,
+        0x2,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x10,
+        0x2,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x2,
+        0x12,
+        0x2,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x12,
+        0x10,
+        0x2,
+        0x12,
+        0xa,
+        0xa,
+        0x2,
+        0x10,
+        0xa,
+        0xa,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x2,
+        0x10,
+        0x2,
+        0x2,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x13,
+        0x11,
+        0x11,
+        0x11,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0xa,
+        0xa,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x2,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x2,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0xa,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0x2,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x2,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x10,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0xa,
+        0xa,
+        0x2,
+        0x12,
+        0x2,
+        0x2,
+        0xa,
+        0xa,
+        0x2,
+        0x2,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x2,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0x2,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x0,
+        0x2,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x12,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0xa,
+        0xa,
+        0x10,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0xa,
+        0xa,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0xa,
+        0x10,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0xa,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x17,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x13,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x15,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x17,
+        0x13,
+        0x13,
+        0x13,
+        0x17,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0xa,
+        0xa,
+        0x2,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x2,
+        0xa,
+        0xa,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0xa,
+        0x2,
+        0x2,
+        0xa,
+        0x2,
+    ],
+    [
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x15,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x15,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+    ],
+    [
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x10,
+        0x11,
+        0x10,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x12,
+        0x11,
+        0x12,
+        0x12,
+        0x12,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x12,
+        0x12,
+        0x12,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x12,
+        0x12,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x17,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x13,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0xa,
+        0x2,
+        0x2,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x2,
+        0x2,
+        0x2,
+        0xa,
+        0xa,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0xa,
+        0x12,
+        0x12,
+        0x12,
+        0x2,
+        0x2,
+        0xa,
+        0x2,
+        0xa,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0xa,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x11,
+        0x10,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x15,
+        0x15,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x17,
+        0x13,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0xa,
+        0xa,
+        0xa,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x2,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0xa,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x10,
+        0x12,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x13,
+        0x13,
+        0xa,
+        0xa,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0xa,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x13,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x12,
+        0x12,
+        0x12,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x13,
+        0x13,
+        0x13,
+        0x11,
+        0x11,
+        0x10,
+        0x13,
+        0x13,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x2,
+        0x2,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0x10,
+        0xa,
+        0xa,
+        0x2,
+        0x10,
+        0x10,
+        0xa,
+        0xa,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0xa,
+        0x10,
+        0xa,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x12,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x13,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0xa,
+        0xa,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x12,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x13,
+        0x12,
+        0x12,
+        0x13,
+        0x13,
+        0x13,
+        0x10,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x10,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x2,
+        0x12,
+        0xa,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0x2,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x10,
+        0x12,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0xa,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x2,
+        0x2,
+        0x2,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x2,
+        0xa,
+        0xa,
+        0xa,
+        0x2,
+        0xa,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0x2,
+        0xa,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x2,
+        0x2,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0xa,
+        0xa,
+        0xa,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x2,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x2,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x12,
+        0x0,
+        0x2,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x2,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x0,
+        0x2,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x2,
+        0x12,
+        0x12,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x2,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x2,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x2,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x2,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x0,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x2,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x12,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x2,
+        0x12,
+        0x2,
+        0x2,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x0,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x12,
+        0x12,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x2,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x10,
+        0x10,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x2,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0xa,
+        0xa,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x15,
+        0x15,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x15,
+        0x15,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x15,
+        0x15,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x10,
+        0x11,
+        0x15,
+        0x15,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x15,
+        0x15,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+    ],
+    [
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x15,
+        0x15,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x15,
+        0x15,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x15,
+        0x15,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x15,
+        0x15,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x15,
+        0x15,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+    ],
+    [
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x15,
+        0x15,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x15,
+        0x15,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x15,
+        0x15,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+    ],
+    [
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x15,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x10,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x10,
+        0x10,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x10,
+        0xa,
+        0xa,
+        0x10,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x17,
+        0x17,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x17,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x2,
+        0x2,
+        0x2,
+        0xa,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0xa,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x2,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+    ],
+    [
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+];
@@ -309,24 +309,24 @@ pub const CAT_TABLE: [u8;0x1100] = [
 	 17, // 0x132
 	 17, // 0x133
 	 102, // 0x134
-	 100, // 0x135
-	 100, // 0x136
-	 100, // 0x137
-	 100, // 0x138
-	 100, // 0x139
-	 100, // 0x13a
-	 100, // 0x13b
-	 100, // 0x13c
-	 100, // 0x13d
-	 100, // 0x13e
-	 100, // 0x13f
-	 100, // 0x140
-	 100, // 0x141
-	 100, // 0x142
-	 100, // 0x143
+	 17, // 0x135
+	 17, // 0x136
+	 17, // 0x137
+	 17, // 0x138
+	 17, // 0x139
+	 17, // 0x13a
+	 17, // 0x13b
+	 17, // 0x13c
+	 17, // 0x13d
+	 17, // 0x13e
+	 17, // 0x13f
+	 17, // 0x140
+	 17, // 0x141
+	 17, // 0x142
+	 103, // 0x143
 	 17, // 0x144
 	 17, // 0x145
-	 103, // 0x146
+	 104, // 0x146
 	 100, // 0x147
 	 100, // 0x148
 	 100, // 0x149
@@ -353,7 +353,7 @@ pub const CAT_TABLE: [u8;0x1100] = [
 	 100, // 0x15e
 	 100, // 0x15f
 	 100, // 0x160
-	 100, // 0x161
+	 105, // 0x161
 	 100, // 0x162
 	 100, // 0x163
 	 100, // 0x164
@@ -362,12 +362,12 @@ pub const CAT_TABLE: [u8;0x1100] = [
 	 100, // 0x167
 	 17, // 0x168
 	 17, // 0x169
-	 104, // 0x16a
-	 105, // 0x16b
+	 106, // 0x16a
+	 107, // 0x16b
 	 100, // 0x16c
-	 100, // 0x16d
-	 106, // 0x16e
-	 107, // 0x16f
+	 108, // 0x16d
+	 109, // 0x16e
+	 110, // 0x16f
 	 17, // 0x170
 	 17, // 0x171
 	 17, // 0x172
@@ -391,13 +391,13 @@ pub const CAT_TABLE: [u8;0x1100] = [
 	 17, // 0x184
 	 17, // 0x185
 	 17, // 0x186
-	 108, // 0x187
+	 111, // 0x187
 	 17, // 0x188
 	 17, // 0x189
 	 17, // 0x18a
 	 17, // 0x18b
-	 109, // 0x18c
-	 110, // 0x18d
+	 112, // 0x18c
+	 113, // 0x18d
 	 100, // 0x18e
 	 100, // 0x18f
 	 100, // 0x190
@@ -431,10 +431,10 @@ pub const CAT_TABLE: [u8;0x1100] = [
 	 100, // 0x1ac
 	 100, // 0x1ad
 	 100, // 0x1ae
-	 111, // 0x1af
+	 114, // 0x1af
 	 17, // 0x1b0
-	 112, // 0x1b1
-	 113, // 0x1b2
+	 115, // 0x1b1
+	 116, // 0x1b2
 	 100, // 0x1b3
 	 100, // 0x1b4
 	 100, // 0x1b5
@@ -444,7 +444,7 @@ pub const CAT_TABLE: [u8;0x1100] = [
 	 100, // 0x1b9
 	 100, // 0x1ba
 	 100, // 0x1bb
-	 114, // 0x1bc
+	 117, // 0x1bc
 	 100, // 0x1bd
 	 100, // 0x1be
 	 100, // 0x1bf
@@ -460,54 +460,54 @@ pub const CAT_TABLE: [u8;0x1100] = [
 	 100, // 0x1c9
 	 100, // 0x1ca
 	 100, // 0x1cb
-	 100, // 0x1cc
-	 100, // 0x1cd
-	 100, // 0x1ce
-	 115, // 0x1cf
-	 116, // 0x1d0
-	 117, // 0x1d1
-	 118, // 0x1d2
-	 119, // 0x1d3
-	 120, // 0x1d4
-	 121, // 0x1d5
-	 122, // 0x1d6
-	 123, // 0x1d7
+	 118, // 0x1cc
+	 39, // 0x1cd
+	 119, // 0x1ce
+	 120, // 0x1cf
+	 121, // 0x1d0
+	 122, // 0x1d1
+	 123, // 0x1d2
+	 124, // 0x1d3
+	 125, // 0x1d4
+	 126, // 0x1d5
+	 127, // 0x1d6
+	 128, // 0x1d7
 	 39, // 0x1d8
 	 39, // 0x1d9
-	 124, // 0x1da
+	 129, // 0x1da
 	 100, // 0x1db
 	 100, // 0x1dc
 	 100, // 0x1dd
 	 100, // 0x1de
-	 125, // 0x1df
-	 126, // 0x1e0
-	 127, // 0x1e1
-	 128, // 0x1e2
+	 130, // 0x1df
+	 131, // 0x1e0
+	 132, // 0x1e1
+	 133, // 0x1e2
 	 100, // 0x1e3
-	 129, // 0x1e4
-	 100, // 0x1e5
+	 134, // 0x1e4
+	 135, // 0x1e5
 	 100, // 0x1e6
-	 130, // 0x1e7
-	 131, // 0x1e8
-	 132, // 0x1e9
+	 136, // 0x1e7
+	 137, // 0x1e8
+	 138, // 0x1e9
 	 100, // 0x1ea
 	 100, // 0x1eb
-	 133, // 0x1ec
-	 134, // 0x1ed
-	 135, // 0x1ee
+	 139, // 0x1ec
+	 140, // 0x1ed
+	 141, // 0x1ee
 	 100, // 0x1ef
-	 136, // 0x1f0
-	 137, // 0x1f1
-	 138, // 0x1f2
-	 139, // 0x1f3
+	 142, // 0x1f0
+	 143, // 0x1f1
+	 144, // 0x1f2
+	 145, // 0x1f3
 	 39, // 0x1f4
 	 39, // 0x1f5
-	 140, // 0x1f6
-	 141, // 0x1f7
-	 142, // 0x1f8
+	 146, // 0x1f6
+	 147, // 0x1f7
+	 148, // 0x1f8
 	 39, // 0x1f9
-	 143, // 0x1fa
-	 144, // 0x1fb
+	 149, // 0x1fa
+	 150, // 0x1fb
 	 100, // 0x1fc
 	 100, // 0x1fd
 	 100, // 0x1fe
@@ -678,7 +678,7 @@ pub const CAT_TABLE: [u8;0x1100] = [
 	 17, // 0x2a3
 	 17, // 0x2a4
 	 17, // 0x2a5
-	 145, // 0x2a6
+	 151, // 0x2a6
 	 17, // 0x2a7
 	 17, // 0x2a8
 	 17, // 0x2a9
@@ -695,8 +695,8 @@ pub const CAT_TABLE: [u8;0x1100] = [
 	 17, // 0x2b4
 	 17, // 0x2b5
 	 17, // 0x2b6
-	 146, // 0x2b7
-	 147, // 0x2b8
+	 152, // 0x2b7
+	 153, // 0x2b8
 	 17, // 0x2b9
 	 17, // 0x2ba
 	 17, // 0x2bb
@@ -718,7 +718,7 @@ pub const CAT_TABLE: [u8;0x1100] = [
 	 17, // 0x2cb
 	 17, // 0x2cc
 	 17, // 0x2cd
-	 148, // 0x2ce
+	 154, // 0x2ce
 	 17, // 0x2cf
 	 17, // 0x2d0
 	 17, // 0x2d1
@@ -747,10 +747,10 @@ pub const CAT_TABLE: [u8;0x1100] = [
 	 17, // 0x2e8
 	 17, // 0x2e9
 	 17, // 0x2ea
-	 149, // 0x2eb
-	 100, // 0x2ec
-	 100, // 0x2ed
-	 100, // 0x2ee
+	 155, // 0x2eb
+	 17, // 0x2ec
+	 17, // 0x2ed
+	 156, // 0x2ee
 	 100, // 0x2ef
 	 100, // 0x2f0
 	 100, // 0x2f1
@@ -762,7 +762,7 @@ pub const CAT_TABLE: [u8;0x1100] = [
 	 100, // 0x2f7
 	 17, // 0x2f8
 	 17, // 0x2f9
-	 150, // 0x2fa
+	 157, // 0x2fa
 	 100, // 0x2fb
 	 100, // 0x2fc
 	 100, // 0x2fd
@@ -787,7 +787,7 @@ pub const CAT_TABLE: [u8;0x1100] = [
 	 17, // 0x310
 	 17, // 0x311
 	 17, // 0x312
-	 151, // 0x313
+	 158, // 0x313
 	 17, // 0x314
 	 17, // 0x315
 	 17, // 0x316
@@ -803,7 +803,7 @@ pub const CAT_TABLE: [u8;0x1100] = [
 	 17, // 0x320
 	 17, // 0x321
 	 17, // 0x322
-	 152, // 0x323
+	 159, // 0x323
 	 100, // 0x324
 	 100, // 0x325
 	 100, // 0x326
@@ -3584,8 +3584,8 @@ pub const CAT_TABLE: [u8;0x1100] = [
 	 100, // 0xdfd
 	 100, // 0xdfe
 	 100, // 0xdff
-	 153, // 0xe00
-	 154, // 0xe01
+	 160, // 0xe00
+	 161, // 0xe01
 	 100, // 0xe02
 	 100, // 0xe03
 	 100, // 0xe04
@@ -4095,7 +4095,7 @@ pub const CAT_TABLE: [u8;0x1100] = [
 	 60, // 0xffc
 	 60, // 0xffd
 	 60, // 0xffe
-	 155, // 0xfff
+	 162, // 0xfff
 	 60, // 0x1000
 	 60, // 0x1001
 	 60, // 0x1002
@@ -4351,9 +4351,9 @@ pub const CAT_TABLE: [u8;0x1100] = [
 	 60, // 0x10fc
 	 60, // 0x10fd
 	 60, // 0x10fe
-	 155, // 0x10ff
+	 162, // 0x10ff
 ];
-pub const CAT_PAGES: [[u8;256];156] = [
+pub const CAT_PAGES: [[u8;256];163] = [
     [
         0x61,
         0x61,
@@ -6570,7 +6570,7 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x60,
         0x60,
-        0x60,
+        0x10,
         0x10,
         0x10,
         0x10,
@@ -11141,8 +11141,8 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x84,
         0x84,
         0x60,
-        0x60,
-        0x60,
+        0x36,
+        0x36,
         0x20,
         0x20,
         0x20,
@@ -11190,7 +11190,7 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x43,
         0x36,
         0x36,
-        0x60,
+        0x36,
         0x10,
         0x10,
         0x11,
@@ -11458,8 +11458,8 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x91,
         0x91,
         0x91,
-        0x60,
-        0x60,
+        0x90,
+        0x91,
         0x60,
         0x60,
         0x60,
@@ -13424,9 +13424,9 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x43,
         0x43,
         0x43,
-        0x60,
-        0x60,
-        0x60,
+        0x43,
+        0x43,
+        0x43,
         0x60,
         0x60,
         0x60,
@@ -16217,10 +16217,10 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x43,
         0x43,
         0x43,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
     ],
     [
         0x50,
@@ -16709,6 +16709,8 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x43,
         0x43,
         0x43,
+        0x43,
+        0x43,
         0x60,
         0x60,
         0x60,
@@ -16718,9 +16720,7 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x60,
         0x60,
-        0x60,
-        0x60,
-        0x60,
+        0x43,
         0x84,
         0x84,
         0x84,
@@ -18232,9 +18232,9 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x91,
         0x90,
         0x91,
-        0x60,
-        0x60,
-        0x60,
+        0x90,
+        0x90,
+        0x91,
         0x60,
         0x60,
         0x90,
@@ -18247,9 +18247,9 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x91,
         0x90,
         0x91,
-        0x60,
-        0x60,
-        0x60,
+        0x90,
+        0x91,
+        0x90,
         0x60,
         0x60,
         0x60,
@@ -22865,58 +22865,58 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x60,
         0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
         0x60,
         0x60,
         0x60,
@@ -24543,9 +24543,76 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x60,
         0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x83,
+        0x84,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
         0x60,
         0x60,
         0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x31,
+        0x83,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
         0x60,
         0x60,
         0x60,
@@ -24554,6 +24621,8 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x60,
         0x60,
+        0x40,
+        0x40,
         0x60,
         0x60,
         0x60,
@@ -24666,77 +24735,8 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x60,
         0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-    ],
-    [
+    ],
+    [
         0x60,
         0x60,
         0x60,
@@ -24931,10 +24931,9 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x60,
         0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
+        0x84,
+        0x84,
+        0x84,
         0x60,
         0x60,
         0x60,
@@ -24993,6 +24992,7 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x10,
         0x10,
         0x10,
+        0x10,
     ],
     [
         0x84,
@@ -26155,17 +26155,95 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x60,
         0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
         0x60,
+        0x84,
         0x60,
         0x60,
+        0x84,
         0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
         0x60,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
         0x60,
+        0x11,
         0x60,
         0x60,
+        0x11,
         0x60,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
         0x60,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x10,
+        0x84,
+        0x10,
+        0x84,
+        0x36,
+        0x36,
         0x60,
+        0x36,
+        0x36,
         0x60,
         0x60,
         0x60,
@@ -26174,86 +26252,8 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x60,
         0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
+        0x10,
+        0x10,
         0x60,
         0x60,
         0x60,
@@ -27009,26 +27009,26 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x60,
         0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
         0x60,
         0x60,
         0x60,
@@ -27089,7 +27089,7 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x60,
         0x10,
-        0x10,
+        0x11,
         0x10,
         0x11,
         0x11,
@@ -28283,6 +28283,40 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x60,
         0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x36,
         0x60,
         0x60,
         0x60,
@@ -28297,50 +28331,16 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x60,
         0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
         0x60,
         0x60,
         0x60,
@@ -29213,7 +29213,7 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x20,
         0x20,
         0x20,
-        0x60,
+        0x10,
         0x60,
         0x60,
         0x60,
@@ -30767,168 +30767,6 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x60,
         0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-    ],
-    [
         0x84,
         0x84,
         0x84,
@@ -31000,191 +30838,95 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x84,
         0x84,
         0x84,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
     ],
     [
         0x84,
@@ -31244,13 +30986,6 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x84,
         0x84,
         0x84,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
         0x84,
         0x84,
         0x84,
@@ -31282,23 +31017,6 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x84,
         0x84,
         0x84,
-        0x60,
-        0x20,
-        0x20,
-        0x20,
-        0x20,
-        0x20,
-        0x20,
-        0x20,
-        0x20,
-        0x20,
-        0x20,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x36,
-        0x36,
         0x84,
         0x84,
         0x84,
@@ -31378,23 +31096,6 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x84,
         0x84,
         0x84,
-        0x60,
-        0x20,
-        0x20,
-        0x20,
-        0x20,
-        0x20,
-        0x20,
-        0x20,
-        0x20,
-        0x20,
-        0x20,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
         0x84,
         0x84,
         0x84,
@@ -31425,26 +31126,6 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x84,
         0x84,
         0x84,
-        0x60,
-        0x60,
-        0x10,
-        0x10,
-        0x10,
-        0x10,
-        0x10,
-        0x36,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-    ],
-    [
         0x84,
         0x84,
         0x84,
@@ -31493,57 +31174,50 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x84,
         0x84,
         0x84,
-        0x10,
-        0x10,
-        0x10,
-        0x10,
-        0x10,
-        0x10,
-        0x10,
-        0x36,
-        0x36,
-        0x36,
-        0x36,
-        0x36,
-        0x43,
-        0x43,
-        0x43,
-        0x43,
-        0x83,
-        0x83,
-        0x83,
-        0x83,
-        0x36,
-        0x43,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
         0x60,
         0x60,
         0x60,
-        0x20,
-        0x20,
-        0x20,
-        0x20,
-        0x20,
-        0x20,
-        0x20,
-        0x20,
-        0x20,
-        0x20,
         0x60,
-        0x22,
-        0x22,
-        0x22,
-        0x22,
-        0x22,
-        0x22,
-        0x22,
         0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
         0x84,
         0x84,
         0x84,
@@ -31565,11 +31239,6 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x84,
         0x84,
         0x84,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
         0x84,
         0x84,
         0x84,
@@ -31701,23 +31370,6 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x60,
         0x60,
-    ],
-    [
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
         0x60,
         0x60,
         0x60,
@@ -31767,97 +31419,6 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x60,
         0x60,
-        0x90,
-        0x90,
-        0x90,
-        0x90,
-        0x90,
-        0x90,
-        0x90,
-        0x90,
-        0x90,
-        0x90,
-        0x90,
-        0x90,
-        0x90,
-        0x90,
-        0x90,
-        0x90,
-        0x90,
-        0x90,
-        0x90,
-        0x90,
-        0x90,
-        0x90,
-        0x90,
-        0x90,
-        0x90,
-        0x90,
-        0x90,
-        0x90,
-        0x90,
-        0x90,
-        0x90,
-        0x90,
-        0x91,
-        0x91,
-        0x91,
-        0x91,
-        0x91,
-        0x91,
-        0x91,
-        0x91,
-        0x91,
-        0x91,
-        0x91,
-        0x91,
-        0x91,
-        0x91,
-        0x91,
-        0x91,
-        0x91,
-        0x91,
-        0x91,
-        0x91,
-        0x91,
-        0x91,
-        0x91,
-        0x91,
-        0x91,
-        0x91,
-        0x91,
-        0x91,
-        0x91,
-        0x91,
-        0x91,
-        0x91,
-        0x22,
-        0x22,
-        0x22,
-        0x22,
-        0x22,
-        0x22,
-        0x22,
-        0x22,
-        0x22,
-        0x22,
-        0x22,
-        0x22,
-        0x22,
-        0x22,
-        0x22,
-        0x22,
-        0x22,
-        0x22,
-        0x22,
-        0x22,
-        0x22,
-        0x22,
-        0x22,
-        0x36,
-        0x36,
-        0x36,
-        0x36,
         0x60,
         0x60,
         0x60,
@@ -31882,6 +31443,87 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x60,
         0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
         0x60,
         0x60,
         0x60,
@@ -31959,144 +31601,10 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x60,
         0x60,
-    ],
-    [
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
         0x60,
         0x60,
         0x60,
         0x60,
-        0x10,
-        0x84,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
-        0x11,
         0x60,
         0x60,
         0x60,
@@ -32104,23 +31612,6 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x60,
         0x60,
-        0x10,
-        0x10,
-        0x10,
-        0x10,
-        0x83,
-        0x83,
-        0x83,
-        0x83,
-        0x83,
-        0x83,
-        0x83,
-        0x83,
-        0x83,
-        0x83,
-        0x83,
-        0x83,
-        0x83,
         0x60,
         0x60,
         0x60,
@@ -32185,11 +31676,6 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x60,
         0x60,
-        0x83,
-        0x83,
-        0x36,
-        0x83,
-        0x10,
         0x60,
         0x60,
         0x60,
@@ -32201,8 +31687,6 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x60,
         0x60,
-        0x11,
-        0x11,
         0x60,
         0x60,
         0x60,
@@ -32276,6 +31760,13 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x84,
         0x84,
         0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
         0x84,
         0x84,
         0x84,
@@ -32307,6 +31798,23 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x84,
         0x84,
         0x84,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+        0x36,
         0x84,
         0x84,
         0x84,
@@ -32386,6 +31894,23 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x84,
         0x84,
         0x84,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
         0x84,
         0x84,
         0x84,
@@ -32416,57 +31941,16 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x84,
         0x84,
         0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x36,
+        0x60,
+        0x60,
         0x60,
         0x60,
         0x60,
@@ -32525,6 +32009,57 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x84,
         0x84,
         0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x36,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
         0x84,
         0x84,
         0x84,
@@ -32546,6 +32081,11 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x84,
         0x84,
         0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
         0x84,
         0x84,
         0x84,
@@ -32565,200 +32105,6 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x84,
         0x84,
         0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-    ],
-    [
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
         0x60,
         0x60,
         0x60,
@@ -32871,6 +32217,8 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x60,
         0x60,
+    ],
+    [
         0x60,
         0x60,
         0x60,
@@ -32935,6 +32283,64 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x60,
         0x60,
+        0x83,
+        0x83,
+        0x83,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x83,
+        0x83,
+        0x36,
+        0x36,
+        0x36,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
         0x60,
         0x60,
         0x60,
@@ -32991,8 +32397,6 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x60,
         0x60,
-    ],
-    [
         0x60,
         0x60,
         0x60,
@@ -33071,6 +32475,8 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x60,
         0x60,
+    ],
+    [
         0x60,
         0x60,
         0x60,
@@ -33135,6 +32541,97 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x60,
         0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
         0x60,
         0x60,
         0x60,
@@ -33233,24 +32730,51 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x60,
         0x60,
-        0x83,
-        0x83,
-        0x83,
-        0x83,
         0x60,
-        0x83,
-        0x83,
-        0x83,
-        0x83,
-        0x83,
-        0x83,
-        0x83,
         0x60,
-        0x83,
-        0x83,
         0x60,
     ],
     [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
         0x84,
         0x84,
         0x84,
@@ -33290,6 +32814,63 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x60,
         0x60,
+        0x10,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
         0x60,
         0x60,
         0x60,
@@ -33297,11 +32878,27 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x60,
         0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
         0x60,
         0x60,
         0x60,
         0x60,
-        0x84,
         0x60,
         0x60,
         0x60,
@@ -33331,12 +32928,8 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x60,
         0x60,
-        0x84,
-        0x84,
-        0x84,
         0x60,
         0x60,
-        0x84,
         0x60,
         0x60,
         0x60,
@@ -33351,10 +32944,6 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x60,
         0x60,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
         0x60,
         0x60,
         0x60,
@@ -33363,6 +32952,47 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x60,
         0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x83,
+        0x83,
+        0x36,
+        0x83,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x11,
+        0x11,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
         0x84,
         0x84,
         0x84,
@@ -33507,49 +33137,6 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x84,
         0x84,
         0x84,
-    ],
-    [
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
         0x84,
         0x84,
         0x84,
@@ -33654,6 +33241,16 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x84,
         0x84,
         0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
         0x84,
         0x84,
         0x84,
@@ -33761,12 +33358,6 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x84,
         0x84,
         0x84,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-    ],
-    [
         0x84,
         0x84,
         0x84,
@@ -33879,31 +33470,35 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x60,
         0x60,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
         0x60,
         0x60,
         0x60,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
         0x60,
         0x60,
         0x60,
@@ -33912,6 +33507,8 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x60,
         0x84,
+    ],
+    [
         0x84,
         0x84,
         0x84,
@@ -33923,14 +33520,6 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x84,
         0x60,
         0x60,
-        0x43,
-        0x10,
-        0x10,
-        0x36,
-        0x62,
-        0x62,
-        0x62,
-        0x62,
         0x60,
         0x60,
         0x60,
@@ -34023,6 +33612,1707 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x60,
         0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x60,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x60,
+        0x83,
+        0x83,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x43,
+        0x10,
+        0x10,
+        0x36,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
     ],
     [
         0x10,
@@ -37377,71 +38667,398 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x60,
         0x60,
-    ],
-    [
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
+    ],
+    [
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x41,
+    ],
+    [
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
         0x60,
         0x60,
         0x60,
@@ -37518,76 +39135,6 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x60,
         0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x10,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
-        0x84,
         0x84,
         0x84,
         0x84,
@@ -37615,6 +39162,7 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x84,
         0x84,
         0x84,
+        0x83,
         0x10,
         0x10,
         0x10,
@@ -37634,7 +39182,7 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x60,
         0x60,
-        0x41,
+        0x60,
     ],
     [
         0x60,
@@ -37872,11 +39420,12 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x84,
         0x84,
         0x84,
-        0x83,
-        0x10,
-        0x10,
+        0x84,
+        0x84,
+        0x84,
         0x10,
         0x10,
+        0x84,
         0x20,
         0x20,
         0x20,
@@ -37891,8 +39440,7 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x60,
         0x60,
-        0x60,
-        0x60,
+        0x36,
     ],
     [
         0x60,
@@ -41169,22 +42717,22 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x43,
         0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
         0x60,
         0x60,
         0x60,
         0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
+        0x43,
+        0x43,
         0x60,
         0x60,
         0x60,
@@ -41386,8 +42934,7 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x43,
         0x43,
         0x43,
-        0x60,
-        0x60,
+        0x43,
         0x60,
         0x60,
         0x60,
@@ -41439,7 +42986,6 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x43,
         0x43,
         0x43,
-        0x60,
         0x43,
         0x43,
         0x43,
@@ -41447,7 +42993,9 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x43,
         0x43,
         0x43,
-        0x60,
+        0x43,
+        0x43,
+        0x43,
         0x60,
         0x60,
         0x60,
@@ -41469,8 +43017,7 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x43,
         0x43,
         0x43,
-        0x60,
-        0x60,
+        0x43,
         0x60,
         0x60,
         0x43,
@@ -41482,7 +43029,8 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x43,
         0x43,
         0x43,
-        0x60,
+        0x43,
+        0x43,
         0x60,
         0x60,
         0x60,
@@ -41710,43 +43258,43 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x43,
         0x43,
         0x43,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
-        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
         0x20,
         0x20,
         0x20,
@@ -43037,6 +44585,264 @@ pub const CAT_PAGES: [[u8;256];156] = [
         0x60,
         0x60,
         0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
         0x60,
         0x60,
         0x60,
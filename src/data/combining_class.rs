@@ -0,0 +1,21901 @@
+// GENERATED CODE DO NOT MANUALLY EDIT
+pub const CCC_TABLE: [u8;0x1100] = [
+	 0, // 0x0
+	 0, // 0x1
+	 0, // 0x2
+	 1, // 0x3
+	 2, // 0x4
+	 3, // 0x5
+	 4, // 0x6
+	 5, // 0x7
+	 6, // 0x8
+	 7, // 0x9
+	 8, // 0xa
+	 9, // 0xb
+	 10, // 0xc
+	 11, // 0xd
+	 12, // 0xe
+	 13, // 0xf
+	 14, // 0x10
+	 0, // 0x11
+	 0, // 0x12
+	 15, // 0x13
+	 0, // 0x14
+	 0, // 0x15
+	 0, // 0x16
+	 16, // 0x17
+	 17, // 0x18
+	 18, // 0x19
+	 19, // 0x1a
+	 20, // 0x1b
+	 21, // 0x1c
+	 22, // 0x1d
+	 0, // 0x1e
+	 0, // 0x1f
+	 23, // 0x20
+	 0, // 0x21
+	 0, // 0x22
+	 0, // 0x23
+	 0, // 0x24
+	 0, // 0x25
+	 0, // 0x26
+	 0, // 0x27
+	 0, // 0x28
+	 0, // 0x29
+	 0, // 0x2a
+	 0, // 0x2b
+	 24, // 0x2c
+	 25, // 0x2d
+	 0, // 0x2e
+	 0, // 0x2f
+	 26, // 0x30
+	 0, // 0x31
+	 0, // 0x32
+	 0, // 0x33
+	 0, // 0x34
+	 0, // 0x35
+	 0, // 0x36
+	 0, // 0x37
+	 0, // 0x38
+	 0, // 0x39
+	 0, // 0x3a
+	 0, // 0x3b
+	 0, // 0x3c
+	 0, // 0x3d
+	 0, // 0x3e
+	 0, // 0x3f
+	 0, // 0x40
+	 0, // 0x41
+	 0, // 0x42
+	 0, // 0x43
+	 0, // 0x44
+	 0, // 0x45
+	 0, // 0x46
+	 0, // 0x47
+	 0, // 0x48
+	 0, // 0x49
+	 0, // 0x4a
+	 0, // 0x4b
+	 0, // 0x4c
+	 0, // 0x4d
+	 0, // 0x4e
+	 0, // 0x4f
+	 0, // 0x50
+	 0, // 0x51
+	 0, // 0x52
+	 0, // 0x53
+	 0, // 0x54
+	 0, // 0x55
+	 0, // 0x56
+	 0, // 0x57
+	 0, // 0x58
+	 0, // 0x59
+	 0, // 0x5a
+	 0, // 0x5b
+	 0, // 0x5c
+	 0, // 0x5d
+	 0, // 0x5e
+	 0, // 0x5f
+	 0, // 0x60
+	 0, // 0x61
+	 0, // 0x62
+	 0, // 0x63
+	 0, // 0x64
+	 0, // 0x65
+	 0, // 0x66
+	 0, // 0x67
+	 0, // 0x68
+	 0, // 0x69
+	 0, // 0x6a
+	 0, // 0x6b
+	 0, // 0x6c
+	 0, // 0x6d
+	 0, // 0x6e
+	 0, // 0x6f
+	 0, // 0x70
+	 0, // 0x71
+	 0, // 0x72
+	 0, // 0x73
+	 0, // 0x74
+	 0, // 0x75
+	 0, // 0x76
+	 0, // 0x77
+	 0, // 0x78
+	 0, // 0x79
+	 0, // 0x7a
+	 0, // 0x7b
+	 0, // 0x7c
+	 0, // 0x7d
+	 0, // 0x7e
+	 0, // 0x7f
+	 0, // 0x80
+	 0, // 0x81
+	 0, // 0x82
+	 0, // 0x83
+	 0, // 0x84
+	 0, // 0x85
+	 0, // 0x86
+	 0, // 0x87
+	 0, // 0x88
+	 0, // 0x89
+	 0, // 0x8a
+	 0, // 0x8b
+	 0, // 0x8c
+	 0, // 0x8d
+	 0, // 0x8e
+	 0, // 0x8f
+	 0, // 0x90
+	 0, // 0x91
+	 0, // 0x92
+	 0, // 0x93
+	 0, // 0x94
+	 0, // 0x95
+	 0, // 0x96
+	 0, // 0x97
+	 0, // 0x98
+	 0, // 0x99
+	 0, // 0x9a
+	 0, // 0x9b
+	 0, // 0x9c
+	 0, // 0x9d
+	 0, // 0x9e
+	 0, // 0x9f
+	 0, // 0xa0
+	 0, // 0xa1
+	 0, // 0xa2
+	 0, // 0xa3
+	 0, // 0xa4
+	 0, // 0xa5
+	 27, // 0xa6
+	 0, // 0xa7
+	 28, // 0xa8
+	 29, // 0xa9
+	 30, // 0xaa
+	 31, // 0xab
+	 0, // 0xac
+	 0, // 0xad
+	 0, // 0xae
+	 0, // 0xaf
+	 0, // 0xb0
+	 0, // 0xb1
+	 0, // 0xb2
+	 0, // 0xb3
+	 0, // 0xb4
+	 0, // 0xb5
+	 0, // 0xb6
+	 0, // 0xb7
+	 0, // 0xb8
+	 0, // 0xb9
+	 0, // 0xba
+	 0, // 0xbb
+	 0, // 0xbc
+	 0, // 0xbd
+	 0, // 0xbe
+	 0, // 0xbf
+	 0, // 0xc0
+	 0, // 0xc1
+	 0, // 0xc2
+	 0, // 0xc3
+	 0, // 0xc4
+	 0, // 0xc5
+	 0, // 0xc6
+	 0, // 0xc7
+	 0, // 0xc8
+	 0, // 0xc9
+	 0, // 0xca
+	 0, // 0xcb
+	 0, // 0xcc
+	 0, // 0xcd
+	 0, // 0xce
+	 0, // 0xcf
+	 0, // 0xd0
+	 0, // 0xd1
+	 0, // 0xd2
+	 0, // 0xd3
+	 0, // 0xd4
+	 0, // 0xd5
+	 0, // 0xd6
+	 0, // 0xd7
+	 0, // 0xd8
+	 0, // 0xd9
+	 0, // 0xda
+	 0, // 0xdb
+	 0, // 0xdc
+	 0, // 0xdd
+	 0, // 0xde
+	 0, // 0xdf
+	 0, // 0xe0
+	 0, // 0xe1
+	 0, // 0xe2
+	 0, // 0xe3
+	 0, // 0xe4
+	 0, // 0xe5
+	 0, // 0xe6
+	 0, // 0xe7
+	 0, // 0xe8
+	 0, // 0xe9
+	 0, // 0xea
+	 0, // 0xeb
+	 0, // 0xec
+	 0, // 0xed
+	 0, // 0xee
+	 0, // 0xef
+	 0, // 0xf0
+	 0, // 0xf1
+	 0, // 0xf2
+	 0, // 0xf3
+	 0, // 0xf4
+	 0, // 0xf5
+	 0, // 0xf6
+	 0, // 0xf7
+	 0, // 0xf8
+	 0, // 0xf9
+	 0, // 0xfa
+	 32, // 0xfb
+	 0, // 0xfc
+	 0, // 0xfd
+	 33, // 0xfe
+	 0, // 0xff
+	 0, // 0x100
+	 34, // 0x101
+	 35, // 0x102
+	 36, // 0x103
+	 0, // 0x104
+	 0, // 0x105
+	 0, // 0x106
+	 0, // 0x107
+	 0, // 0x108
+	 0, // 0x109
+	 37, // 0x10a
+	 0, // 0x10b
+	 0, // 0x10c
+	 38, // 0x10d
+	 39, // 0x10e
+	 40, // 0x10f
+	 41, // 0x110
+	 42, // 0x111
+	 43, // 0x112
+	 44, // 0x113
+	 45, // 0x114
+	 46, // 0x115
+	 47, // 0x116
+	 48, // 0x117
+	 49, // 0x118
+	 50, // 0x119
+	 51, // 0x11a
+	 0, // 0x11b
+	 52, // 0x11c
+	 53, // 0x11d
+	 0, // 0x11e
+	 54, // 0x11f
+	 0, // 0x120
+	 0, // 0x121
+	 0, // 0x122
+	 0, // 0x123
+	 0, // 0x124
+	 0, // 0x125
+	 0, // 0x126
+	 0, // 0x127
+	 0, // 0x128
+	 0, // 0x129
+	 0, // 0x12a
+	 0, // 0x12b
+	 0, // 0x12c
+	 0, // 0x12d
+	 0, // 0x12e
+	 0, // 0x12f
+	 0, // 0x130
+	 0, // 0x131
+	 0, // 0x132
+	 0, // 0x133
+	 0, // 0x134
+	 0, // 0x135
+	 0, // 0x136
+	 0, // 0x137
+	 0, // 0x138
+	 0, // 0x139
+	 0, // 0x13a
+	 0, // 0x13b
+	 0, // 0x13c
+	 0, // 0x13d
+	 0, // 0x13e
+	 0, // 0x13f
+	 0, // 0x140
+	 0, // 0x141
+	 0, // 0x142
+	 0, // 0x143
+	 0, // 0x144
+	 0, // 0x145
+	 0, // 0x146
+	 0, // 0x147
+	 0, // 0x148
+	 0, // 0x149
+	 0, // 0x14a
+	 0, // 0x14b
+	 0, // 0x14c
+	 0, // 0x14d
+	 0, // 0x14e
+	 0, // 0x14f
+	 0, // 0x150
+	 0, // 0x151
+	 0, // 0x152
+	 0, // 0x153
+	 0, // 0x154
+	 0, // 0x155
+	 0, // 0x156
+	 0, // 0x157
+	 0, // 0x158
+	 0, // 0x159
+	 0, // 0x15a
+	 0, // 0x15b
+	 0, // 0x15c
+	 0, // 0x15d
+	 0, // 0x15e
+	 0, // 0x15f
+	 0, // 0x160
+	 55, // 0x161
+	 0, // 0x162
+	 0, // 0x163
+	 0, // 0x164
+	 0, // 0x165
+	 0, // 0x166
+	 0, // 0x167
+	 0, // 0x168
+	 0, // 0x169
+	 56, // 0x16a
+	 57, // 0x16b
+	 0, // 0x16c
+	 0, // 0x16d
+	 0, // 0x16e
+	 58, // 0x16f
+	 0, // 0x170
+	 0, // 0x171
+	 0, // 0x172
+	 0, // 0x173
+	 0, // 0x174
+	 0, // 0x175
+	 0, // 0x176
+	 0, // 0x177
+	 0, // 0x178
+	 0, // 0x179
+	 0, // 0x17a
+	 0, // 0x17b
+	 0, // 0x17c
+	 0, // 0x17d
+	 0, // 0x17e
+	 0, // 0x17f
+	 0, // 0x180
+	 0, // 0x181
+	 0, // 0x182
+	 0, // 0x183
+	 0, // 0x184
+	 0, // 0x185
+	 0, // 0x186
+	 0, // 0x187
+	 0, // 0x188
+	 0, // 0x189
+	 0, // 0x18a
+	 0, // 0x18b
+	 0, // 0x18c
+	 0, // 0x18d
+	 0, // 0x18e
+	 0, // 0x18f
+	 0, // 0x190
+	 0, // 0x191
+	 0, // 0x192
+	 0, // 0x193
+	 0, // 0x194
+	 0, // 0x195
+	 0, // 0x196
+	 0, // 0x197
+	 0, // 0x198
+	 0, // 0x199
+	 0, // 0x19a
+	 0, // 0x19b
+	 0, // 0x19c
+	 0, // 0x19d
+	 0, // 0x19e
+	 0, // 0x19f
+	 0, // 0x1a0
+	 0, // 0x1a1
+	 0, // 0x1a2
+	 0, // 0x1a3
+	 0, // 0x1a4
+	 0, // 0x1a5
+	 0, // 0x1a6
+	 0, // 0x1a7
+	 0, // 0x1a8
+	 0, // 0x1a9
+	 0, // 0x1aa
+	 0, // 0x1ab
+	 0, // 0x1ac
+	 0, // 0x1ad
+	 0, // 0x1ae
+	 0, // 0x1af
+	 0, // 0x1b0
+	 0, // 0x1b1
+	 0, // 0x1b2
+	 0, // 0x1b3
+	 0, // 0x1b4
+	 0, // 0x1b5
+	 0, // 0x1b6
+	 0, // 0x1b7
+	 0, // 0x1b8
+	 0, // 0x1b9
+	 0, // 0x1ba
+	 0, // 0x1bb
+	 59, // 0x1bc
+	 0, // 0x1bd
+	 0, // 0x1be
+	 0, // 0x1bf
+	 0, // 0x1c0
+	 0, // 0x1c1
+	 0, // 0x1c2
+	 0, // 0x1c3
+	 0, // 0x1c4
+	 0, // 0x1c5
+	 0, // 0x1c6
+	 0, // 0x1c7
+	 0, // 0x1c8
+	 0, // 0x1c9
+	 0, // 0x1ca
+	 0, // 0x1cb
+	 0, // 0x1cc
+	 0, // 0x1cd
+	 0, // 0x1ce
+	 0, // 0x1cf
+	 0, // 0x1d0
+	 60, // 0x1d1
+	 61, // 0x1d2
+	 0, // 0x1d3
+	 0, // 0x1d4
+	 0, // 0x1d5
+	 0, // 0x1d6
+	 0, // 0x1d7
+	 0, // 0x1d8
+	 0, // 0x1d9
+	 0, // 0x1da
+	 0, // 0x1db
+	 0, // 0x1dc
+	 0, // 0x1dd
+	 0, // 0x1de
+	 0, // 0x1df
+	 62, // 0x1e0
+	 57, // 0x1e1
+	 63, // 0x1e2
+	 0, // 0x1e3
+	 64, // 0x1e4
+	 65, // 0x1e5
+	 0, // 0x1e6
+	 0, // 0x1e7
+	 66, // 0x1e8
+	 67, // 0x1e9
+	 0, // 0x1ea
+	 0, // 0x1eb
+	 0, // 0x1ec
+	 0, // 0x1ed
+	 0, // 0x1ee
+	 0, // 0x1ef
+	 0, // 0x1f0
+	 0, // 0x1f1
+	 0, // 0x1f2
+	 0, // 0x1f3
+	 0, // 0x1f4
+	 0, // 0x1f5
+	 0, // 0x1f6
+	 0, // 0x1f7
+	 0, // 0x1f8
+	 0, // 0x1f9
+	 0, // 0x1fa
+	 0, // 0x1fb
+	 0, // 0x1fc
+	 0, // 0x1fd
+	 0, // 0x1fe
+	 0, // 0x1ff
+	 0, // 0x200
+	 0, // 0x201
+	 0, // 0x202
+	 0, // 0x203
+	 0, // 0x204
+	 0, // 0x205
+	 0, // 0x206
+	 0, // 0x207
+	 0, // 0x208
+	 0, // 0x209
+	 0, // 0x20a
+	 0, // 0x20b
+	 0, // 0x20c
+	 0, // 0x20d
+	 0, // 0x20e
+	 0, // 0x20f
+	 0, // 0x210
+	 0, // 0x211
+	 0, // 0x212
+	 0, // 0x213
+	 0, // 0x214
+	 0, // 0x215
+	 0, // 0x216
+	 0, // 0x217
+	 0, // 0x218
+	 0, // 0x219
+	 0, // 0x21a
+	 0, // 0x21b
+	 0, // 0x21c
+	 0, // 0x21d
+	 0, // 0x21e
+	 0, // 0x21f
+	 0, // 0x220
+	 0, // 0x221
+	 0, // 0x222
+	 0, // 0x223
+	 0, // 0x224
+	 0, // 0x225
+	 0, // 0x226
+	 0, // 0x227
+	 0, // 0x228
+	 0, // 0x229
+	 0, // 0x22a
+	 0, // 0x22b
+	 0, // 0x22c
+	 0, // 0x22d
+	 0, // 0x22e
+	 0, // 0x22f
+	 0, // 0x230
+	 0, // 0x231
+	 0, // 0x232
+	 0, // 0x233
+	 0, // 0x234
+	 0, // 0x235
+	 0, // 0x236
+	 0, // 0x237
+	 0, // 0x238
+	 0, // 0x239
+	 0, // 0x23a
+	 0, // 0x23b
+	 0, // 0x23c
+	 0, // 0x23d
+	 0, // 0x23e
+	 0, // 0x23f
+	 0, // 0x240
+	 0, // 0x241
+	 0, // 0x242
+	 0, // 0x243
+	 0, // 0x244
+	 0, // 0x245
+	 0, // 0x246
+	 0, // 0x247
+	 0, // 0x248
+	 0, // 0x249
+	 0, // 0x24a
+	 0, // 0x24b
+	 0, // 0x24c
+	 0, // 0x24d
+	 0, // 0x24e
+	 0, // 0x24f
+	 0, // 0x250
+	 0, // 0x251
+	 0, // 0x252
+	 0, // 0x253
+	 0, // 0x254
+	 0, // 0x255
+	 0, // 0x256
+	 0, // 0x257
+	 0, // 0x258
+	 0, // 0x259
+	 0, // 0x25a
+	 0, // 0x25b
+	 0, // 0x25c
+	 0, // 0x25d
+	 0, // 0x25e
+	 0, // 0x25f
+	 0, // 0x260
+	 0, // 0x261
+	 0, // 0x262
+	 0, // 0x263
+	 0, // 0x264
+	 0, // 0x265
+	 0, // 0x266
+	 0, // 0x267
+	 0, // 0x268
+	 0, // 0x269
+	 0, // 0x26a
+	 0, // 0x26b
+	 0, // 0x26c
+	 0, // 0x26d
+	 0, // 0x26e
+	 0, // 0x26f
+	 0, // 0x270
+	 0, // 0x271
+	 0, // 0x272
+	 0, // 0x273
+	 0, // 0x274
+	 0, // 0x275
+	 0, // 0x276
+	 0, // 0x277
+	 0, // 0x278
+	 0, // 0x279
+	 0, // 0x27a
+	 0, // 0x27b
+	 0, // 0x27c
+	 0, // 0x27d
+	 0, // 0x27e
+	 0, // 0x27f
+	 0, // 0x280
+	 0, // 0x281
+	 0, // 0x282
+	 0, // 0x283
+	 0, // 0x284
+	 0, // 0x285
+	 0, // 0x286
+	 0, // 0x287
+	 0, // 0x288
+	 0, // 0x289
+	 0, // 0x28a
+	 0, // 0x28b
+	 0, // 0x28c
+	 0, // 0x28d
+	 0, // 0x28e
+	 0, // 0x28f
+	 0, // 0x290
+	 0, // 0x291
+	 0, // 0x292
+	 0, // 0x293
+	 0, // 0x294
+	 0, // 0x295
+	 0, // 0x296
+	 0, // 0x297
+	 0, // 0x298
+	 0, // 0x299
+	 0, // 0x29a
+	 0, // 0x29b
+	 0, // 0x29c
+	 0, // 0x29d
+	 0, // 0x29e
+	 0, // 0x29f
+	 0, // 0x2a0
+	 0, // 0x2a1
+	 0, // 0x2a2
+	 0, // 0x2a3
+	 0, // 0x2a4
+	 0, // 0x2a5
+	 0, // 0x2a6
+	 0, // 0x2a7
+	 0, // 0x2a8
+	 0, // 0x2a9
+	 0, // 0x2aa
+	 0, // 0x2ab
+	 0, // 0x2ac
+	 0, // 0x2ad
+	 0, // 0x2ae
+	 0, // 0x2af
+	 0, // 0x2b0
+	 0, // 0x2b1
+	 0, // 0x2b2
+	 0, // 0x2b3
+	 0, // 0x2b4
+	 0, // 0x2b5
+	 0, // 0x2b6
+	 0, // 0x2b7
+	 0, // 0x2b8
+	 0, // 0x2b9
+	 0, // 0x2ba
+	 0, // 0x2bb
+	 0, // 0x2bc
+	 0, // 0x2bd
+	 0, // 0x2be
+	 0, // 0x2bf
+	 0, // 0x2c0
+	 0, // 0x2c1
+	 0, // 0x2c2
+	 0, // 0x2c3
+	 0, // 0x2c4
+	 0, // 0x2c5
+	 0, // 0x2c6
+	 0, // 0x2c7
+	 0, // 0x2c8
+	 0, // 0x2c9
+	 0, // 0x2ca
+	 0, // 0x2cb
+	 0, // 0x2cc
+	 0, // 0x2cd
+	 0, // 0x2ce
+	 0, // 0x2cf
+	 0, // 0x2d0
+	 0, // 0x2d1
+	 0, // 0x2d2
+	 0, // 0x2d3
+	 0, // 0x2d4
+	 0, // 0x2d5
+	 0, // 0x2d6
+	 0, // 0x2d7
+	 0, // 0x2d8
+	 0, // 0x2d9
+	 0, // 0x2da
+	 0, // 0x2db
+	 0, // 0x2dc
+	 0, // 0x2dd
+	 0, // 0x2de
+	 0, // 0x2df
+	 0, // 0x2e0
+	 0, // 0x2e1
+	 0, // 0x2e2
+	 0, // 0x2e3
+	 0, // 0x2e4
+	 0, // 0x2e5
+	 0, // 0x2e6
+	 0, // 0x2e7
+	 0, // 0x2e8
+	 0, // 0x2e9
+	 0, // 0x2ea
+	 0, // 0x2eb
+	 0, // 0x2ec
+	 0, // 0x2ed
+	 0, // 0x2ee
+	 0, // 0x2ef
+	 0, // 0x2f0
+	 0, // 0x2f1
+	 0, // 0x2f2
+	 0, // 0x2f3
+	 0, // 0x2f4
+	 0, // 0x2f5
+	 0, // 0x2f6
+	 0, // 0x2f7
+	 0, // 0x2f8
+	 0, // 0x2f9
+	 0, // 0x2fa
+	 0, // 0x2fb
+	 0, // 0x2fc
+	 0, // 0x2fd
+	 0, // 0x2fe
+	 0, // 0x2ff
+	 0, // 0x300
+	 0, // 0x301
+	 0, // 0x302
+	 0, // 0x303
+	 0, // 0x304
+	 0, // 0x305
+	 0, // 0x306
+	 0, // 0x307
+	 0, // 0x308
+	 0, // 0x309
+	 0, // 0x30a
+	 0, // 0x30b
+	 0, // 0x30c
+	 0, // 0x30d
+	 0, // 0x30e
+	 0, // 0x30f
+	 0, // 0x310
+	 0, // 0x311
+	 0, // 0x312
+	 0, // 0x313
+	 0, // 0x314
+	 0, // 0x315
+	 0, // 0x316
+	 0, // 0x317
+	 0, // 0x318
+	 0, // 0x319
+	 0, // 0x31a
+	 0, // 0x31b
+	 0, // 0x31c
+	 0, // 0x31d
+	 0, // 0x31e
+	 0, // 0x31f
+	 0, // 0x320
+	 0, // 0x321
+	 0, // 0x322
+	 0, // 0x323
+	 0, // 0x324
+	 0, // 0x325
+	 0, // 0x326
+	 0, // 0x327
+	 0, // 0x328
+	 0, // 0x329
+	 0, // 0x32a
+	 0, // 0x32b
+	 0, // 0x32c
+	 0, // 0x32d
+	 0, // 0x32e
+	 0, // 0x32f
+	 0, // 0x330
+	 0, // 0x331
+	 0, // 0x332
+	 0, // 0x333
+	 0, // 0x334
+	 0, // 0x335
+	 0, // 0x336
+	 0, // 0x337
+	 0, // 0x338
+	 0, // 0x339
+	 0, // 0x33a
+	 0, // 0x33b
+	 0, // 0x33c
+	 0, // 0x33d
+	 0, // 0x33e
+	 0, // 0x33f
+	 0, // 0x340
+	 0, // 0x341
+	 0, // 0x342
+	 0, // 0x343
+	 0, // 0x344
+	 0, // 0x345
+	 0, // 0x346
+	 0, // 0x347
+	 0, // 0x348
+	 0, // 0x349
+	 0, // 0x34a
+	 0, // 0x34b
+	 0, // 0x34c
+	 0, // 0x34d
+	 0, // 0x34e
+	 0, // 0x34f
+	 0, // 0x350
+	 0, // 0x351
+	 0, // 0x352
+	 0, // 0x353
+	 0, // 0x354
+	 0, // 0x355
+	 0, // 0x356
+	 0, // 0x357
+	 0, // 0x358
+	 0, // 0x359
+	 0, // 0x35a
+	 0, // 0x35b
+	 0, // 0x35c
+	 0, // 0x35d
+	 0, // 0x35e
+	 0, // 0x35f
+	 0, // 0x360
+	 0, // 0x361
+	 0, // 0x362
+	 0, // 0x363
+	 0, // 0x364
+	 0, // 0x365
+	 0, // 0x366
+	 0, // 0x367
+	 0, // 0x368
+	 0, // 0x369
+	 0, // 0x36a
+	 0, // 0x36b
+	 0, // 0x36c
+	 0, // 0x36d
+	 0, // 0x36e
+	 0, // 0x36f
+	 0, // 0x370
+	 0, // 0x371
+	 0, // 0x372
+	 0, // 0x373
+	 0, // 0x374
+	 0, // 0x375
+	 0, // 0x376
+	 0, // 0x377
+	 0, // 0x378
+	 0, // 0x379
+	 0, // 0x37a
+	 0, // 0x37b
+	 0, // 0x37c
+	 0, // 0x37d
+	 0, // 0x37e
+	 0, // 0x37f
+	 0, // 0x380
+	 0, // 0x381
+	 0, // 0x382
+	 0, // 0x383
+	 0, // 0x384
+	 0, // 0x385
+	 0, // 0x386
+	 0, // 0x387
+	 0, // 0x388
+	 0, // 0x389
+	 0, // 0x38a
+	 0, // 0x38b
+	 0, // 0x38c
+	 0, // 0x38d
+	 0, // 0x38e
+	 0, // 0x38f
+	 0, // 0x390
+	 0, // 0x391
+	 0, // 0x392
+	 0, // 0x393
+	 0, // 0x394
+	 0, // 0x395
+	 0, // 0x396
+	 0, // 0x397
+	 0, // 0x398
+	 0, // 0x399
+	 0, // 0x39a
+	 0, // 0x39b
+	 0, // 0x39c
+	 0, // 0x39d
+	 0, // 0x39e
+	 0, // 0x39f
+	 0, // 0x3a0
+	 0, // 0x3a1
+	 0, // 0x3a2
+	 0, // 0x3a3
+	 0, // 0x3a4
+	 0, // 0x3a5
+	 0, // 0x3a6
+	 0, // 0x3a7
+	 0, // 0x3a8
+	 0, // 0x3a9
+	 0, // 0x3aa
+	 0, // 0x3ab
+	 0, // 0x3ac
+	 0, // 0x3ad
+	 0, // 0x3ae
+	 0, // 0x3af
+	 0, // 0x3b0
+	 0, // 0x3b1
+	 0, // 0x3b2
+	 0, // 0x3b3
+	 0, // 0x3b4
+	 0, // 0x3b5
+	 0, // 0x3b6
+	 0, // 0x3b7
+	 0, // 0x3b8
+	 0, // 0x3b9
+	 0, // 0x3ba
+	 0, // 0x3bb
+	 0, // 0x3bc
+	 0, // 0x3bd
+	 0, // 0x3be
+	 0, // 0x3bf
+	 0, // 0x3c0
+	 0, // 0x3c1
+	 0, // 0x3c2
+	 0, // 0x3c3
+	 0, // 0x3c4
+	 0, // 0x3c5
+	 0, // 0x3c6
+	 0, // 0x3c7
+	 0, // 0x3c8
+	 0, // 0x3c9
+	 0, // 0x3ca
+	 0, // 0x3cb
+	 0, // 0x3cc
+	 0, // 0x3cd
+	 0, // 0x3ce
+	 0, // 0x3cf
+	 0, // 0x3d0
+	 0, // 0x3d1
+	 0, // 0x3d2
+	 0, // 0x3d3
+	 0, // 0x3d4
+	 0, // 0x3d5
+	 0, // 0x3d6
+	 0, // 0x3d7
+	 0, // 0x3d8
+	 0, // 0x3d9
+	 0, // 0x3da
+	 0, // 0x3db
+	 0, // 0x3dc
+	 0, // 0x3dd
+	 0, // 0x3de
+	 0, // 0x3df
+	 0, // 0x3e0
+	 0, // 0x3e1
+	 0, // 0x3e2
+	 0, // 0x3e3
+	 0, // 0x3e4
+	 0, // 0x3e5
+	 0, // 0x3e6
+	 0, // 0x3e7
+	 0, // 0x3e8
+	 0, // 0x3e9
+	 0, // 0x3ea
+	 0, // 0x3eb
+	 0, // 0x3ec
+	 0, // 0x3ed
+	 0, // 0x3ee
+	 0, // 0x3ef
+	 0, // 0x3f0
+	 0, // 0x3f1
+	 0, // 0x3f2
+	 0, // 0x3f3
+	 0, // 0x3f4
+	 0, // 0x3f5
+	 0, // 0x3f6
+	 0, // 0x3f7
+	 0, // 0x3f8
+	 0, // 0x3f9
+	 0, // 0x3fa
+	 0, // 0x3fb
+	 0, // 0x3fc
+	 0, // 0x3fd
+	 0, // 0x3fe
+	 0, // 0x3ff
+	 0, // 0x400
+	 0, // 0x401
+	 0, // 0x402
+	 0, // 0x403
+	 0, // 0x404
+	 0, // 0x405
+	 0, // 0x406
+	 0, // 0x407
+	 0, // 0x408
+	 0, // 0x409
+	 0, // 0x40a
+	 0, // 0x40b
+	 0, // 0x40c
+	 0, // 0x40d
+	 0, // 0x40e
+	 0, // 0x40f
+	 0, // 0x410
+	 0, // 0x411
+	 0, // 0x412
+	 0, // 0x413
+	 0, // 0x414
+	 0, // 0x415
+	 0, // 0x416
+	 0, // 0x417
+	 0, // 0x418
+	 0, // 0x419
+	 0, // 0x41a
+	 0, // 0x41b
+	 0, // 0x41c
+	 0, // 0x41d
+	 0, // 0x41e
+	 0, // 0x41f
+	 0, // 0x420
+	 0, // 0x421
+	 0, // 0x422
+	 0, // 0x423
+	 0, // 0x424
+	 0, // 0x425
+	 0, // 0x426
+	 0, // 0x427
+	 0, // 0x428
+	 0, // 0x429
+	 0, // 0x42a
+	 0, // 0x42b
+	 0, // 0x42c
+	 0, // 0x42d
+	 0, // 0x42e
+	 0, // 0x42f
+	 0, // 0x430
+	 0, // 0x431
+	 0, // 0x432
+	 0, // 0x433
+	 0, // 0x434
+	 0, // 0x435
+	 0, // 0x436
+	 0, // 0x437
+	 0, // 0x438
+	 0, // 0x439
+	 0, // 0x43a
+	 0, // 0x43b
+	 0, // 0x43c
+	 0, // 0x43d
+	 0, // 0x43e
+	 0, // 0x43f
+	 0, // 0x440
+	 0, // 0x441
+	 0, // 0x442
+	 0, // 0x443
+	 0, // 0x444
+	 0, // 0x445
+	 0, // 0x446
+	 0, // 0x447
+	 0, // 0x448
+	 0, // 0x449
+	 0, // 0x44a
+	 0, // 0x44b
+	 0, // 0x44c
+	 0, // 0x44d
+	 0, // 0x44e
+	 0, // 0x44f
+	 0, // 0x450
+	 0, // 0x451
+	 0, // 0x452
+	 0, // 0x453
+	 0, // 0x454
+	 0, // 0x455
+	 0, // 0x456
+	 0, // 0x457
+	 0, // 0x458
+	 0, // 0x459
+	 0, // 0x45a
+	 0, // 0x45b
+	 0, // 0x45c
+	 0, // 0x45d
+	 0, // 0x45e
+	 0, // 0x45f
+	 0, // 0x460
+	 0, // 0x461
+	 0, // 0x462
+	 0, // 0x463
+	 0, // 0x464
+	 0, // 0x465
+	 0, // 0x466
+	 0, // 0x467
+	 0, // 0x468
+	 0, // 0x469
+	 0, // 0x46a
+	 0, // 0x46b
+	 0, // 0x46c
+	 0, // 0x46d
+	 0, // 0x46e
+	 0, // 0x46f
+	 0, // 0x470
+	 0, // 0x471
+	 0, // 0x472
+	 0, // 0x473
+	 0, // 0x474
+	 0, // 0x475
+	 0, // 0x476
+	 0, // 0x477
+	 0, // 0x478
+	 0, // 0x479
+	 0, // 0x47a
+	 0, // 0x47b
+	 0, // 0x47c
+	 0, // 0x47d
+	 0, // 0x47e
+	 0, // 0x47f
+	 0, // 0x480
+	 0, // 0x481
+	 0, // 0x482
+	 0, // 0x483
+	 0, // 0x484
+	 0, // 0x485
+	 0, // 0x486
+	 0, // 0x487
+	 0, // 0x488
+	 0, // 0x489
+	 0, // 0x48a
+	 0, // 0x48b
+	 0, // 0x48c
+	 0, // 0x48d
+	 0, // 0x48e
+	 0, // 0x48f
+	 0, // 0x490
+	 0, // 0x491
+	 0, // 0x492
+	 0, // 0x493
+	 0, // 0x494
+	 0, // 0x495
+	 0, // 0x496
+	 0, // 0x497
+	 0, // 0x498
+	 0, // 0x499
+	 0, // 0x49a
+	 0, // 0x49b
+	 0, // 0x49c
+	 0, // 0x49d
+	 0, // 0x49e
+	 0, // 0x49f
+	 0, // 0x4a0
+	 0, // 0x4a1
+	 0, // 0x4a2
+	 0, // 0x4a3
+	 0, // 0x4a4
+	 0, // 0x4a5
+	 0, // 0x4a6
+	 0, // 0x4a7
+	 0, // 0x4a8
+	 0, // 0x4a9
+	 0, // 0x4aa
+	 0, // 0x4ab
+	 0, // 0x4ac
+	 0, // 0x4ad
+	 0, // 0x4ae
+	 0, // 0x4af
+	 0, // 0x4b0
+	 0, // 0x4b1
+	 0, // 0x4b2
+	 0, // 0x4b3
+	 0, // 0x4b4
+	 0, // 0x4b5
+	 0, // 0x4b6
+	 0, // 0x4b7
+	 0, // 0x4b8
+	 0, // 0x4b9
+	 0, // 0x4ba
+	 0, // 0x4bb
+	 0, // 0x4bc
+	 0, // 0x4bd
+	 0, // 0x4be
+	 0, // 0x4bf
+	 0, // 0x4c0
+	 0, // 0x4c1
+	 0, // 0x4c2
+	 0, // 0x4c3
+	 0, // 0x4c4
+	 0, // 0x4c5
+	 0, // 0x4c6
+	 0, // 0x4c7
+	 0, // 0x4c8
+	 0, // 0x4c9
+	 0, // 0x4ca
+	 0, // 0x4cb
+	 0, // 0x4cc
+	 0, // 0x4cd
+	 0, // 0x4ce
+	 0, // 0x4cf
+	 0, // 0x4d0
+	 0, // 0x4d1
+	 0, // 0x4d2
+	 0, // 0x4d3
+	 0, // 0x4d4
+	 0, // 0x4d5
+	 0, // 0x4d6
+	 0, // 0x4d7
+	 0, // 0x4d8
+	 0, // 0x4d9
+	 0, // 0x4da
+	 0, // 0x4db
+	 0, // 0x4dc
+	 0, // 0x4dd
+	 0, // 0x4de
+	 0, // 0x4df
+	 0, // 0x4e0
+	 0, // 0x4e1
+	 0, // 0x4e2
+	 0, // 0x4e3
+	 0, // 0x4e4
+	 0, // 0x4e5
+	 0, // 0x4e6
+	 0, // 0x4e7
+	 0, // 0x4e8
+	 0, // 0x4e9
+	 0, // 0x4ea
+	 0, // 0x4eb
+	 0, // 0x4ec
+	 0, // 0x4ed
+	 0, // 0x4ee
+	 0, // 0x4ef
+	 0, // 0x4f0
+	 0, // 0x4f1
+	 0, // 0x4f2
+	 0, // 0x4f3
+	 0, // 0x4f4
+	 0, // 0x4f5
+	 0, // 0x4f6
+	 0, // 0x4f7
+	 0, // 0x4f8
+	 0, // 0x4f9
+	 0, // 0x4fa
+	 0, // 0x4fb
+	 0, // 0x4fc
+	 0, // 0x4fd
+	 0, // 0x4fe
+	 0, // 0x4ff
+	 0, // 0x500
+	 0, // 0x501
+	 0, // 0x502
+	 0, // 0x503
+	 0, // 0x504
+	 0, // 0x505
+	 0, // 0x506
+	 0, // 0x507
+	 0, // 0x508
+	 0, // 0x509
+	 0, // 0x50a
+	 0, // 0x50b
+	 0, // 0x50c
+	 0, // 0x50d
+	 0, // 0x50e
+	 0, // 0x50f
+	 0, // 0x510
+	 0, // 0x511
+	 0, // 0x512
+	 0, // 0x513
+	 0, // 0x514
+	 0, // 0x515
+	 0, // 0x516
+	 0, // 0x517
+	 0, // 0x518
+	 0, // 0x519
+	 0, // 0x51a
+	 0, // 0x51b
+	 0, // 0x51c
+	 0, // 0x51d
+	 0, // 0x51e
+	 0, // 0x51f
+	 0, // 0x520
+	 0, // 0x521
+	 0, // 0x522
+	 0, // 0x523
+	 0, // 0x524
+	 0, // 0x525
+	 0, // 0x526
+	 0, // 0x527
+	 0, // 0x528
+	 0, // 0x529
+	 0, // 0x52a
+	 0, // 0x52b
+	 0, // 0x52c
+	 0, // 0x52d
+	 0, // 0x52e
+	 0, // 0x52f
+	 0, // 0x530
+	 0, // 0x531
+	 0, // 0x532
+	 0, // 0x533
+	 0, // 0x534
+	 0, // 0x535
+	 0, // 0x536
+	 0, // 0x537
+	 0, // 0x538
+	 0, // 0x539
+	 0, // 0x53a
+	 0, // 0x53b
+	 0, // 0x53c
+	 0, // 0x53d
+	 0, // 0x53e
+	 0, // 0x53f
+	 0, // 0x540
+	 0, // 0x541
+	 0, // 0x542
+	 0, // 0x543
+	 0, // 0x544
+	 0, // 0x545
+	 0, // 0x546
+	 0, // 0x547
+	 0, // 0x548
+	 0, // 0x549
+	 0, // 0x54a
+	 0, // 0x54b
+	 0, // 0x54c
+	 0, // 0x54d
+	 0, // 0x54e
+	 0, // 0x54f
+	 0, // 0x550
+	 0, // 0x551
+	 0, // 0x552
+	 0, // 0x553
+	 0, // 0x554
+	 0, // 0x555
+	 0, // 0x556
+	 0, // 0x557
+	 0, // 0x558
+	 0, // 0x559
+	 0, // 0x55a
+	 0, // 0x55b
+	 0, // 0x55c
+	 0, // 0x55d
+	 0, // 0x55e
+	 0, // 0x55f
+	 0, // 0x560
+	 0, // 0x561
+	 0, // 0x562
+	 0, // 0x563
+	 0, // 0x564
+	 0, // 0x565
+	 0, // 0x566
+	 0, // 0x567
+	 0, // 0x568
+	 0, // 0x569
+	 0, // 0x56a
+	 0, // 0x56b
+	 0, // 0x56c
+	 0, // 0x56d
+	 0, // 0x56e
+	 0, // 0x56f
+	 0, // 0x570
+	 0, // 0x571
+	 0, // 0x572
+	 0, // 0x573
+	 0, // 0x574
+	 0, // 0x575
+	 0, // 0x576
+	 0, // 0x577
+	 0, // 0x578
+	 0, // 0x579
+	 0, // 0x57a
+	 0, // 0x57b
+	 0, // 0x57c
+	 0, // 0x57d
+	 0, // 0x57e
+	 0, // 0x57f
+	 0, // 0x580
+	 0, // 0x581
+	 0, // 0x582
+	 0, // 0x583
+	 0, // 0x584
+	 0, // 0x585
+	 0, // 0x586
+	 0, // 0x587
+	 0, // 0x588
+	 0, // 0x589
+	 0, // 0x58a
+	 0, // 0x58b
+	 0, // 0x58c
+	 0, // 0x58d
+	 0, // 0x58e
+	 0, // 0x58f
+	 0, // 0x590
+	 0, // 0x591
+	 0, // 0x592
+	 0, // 0x593
+	 0, // 0x594
+	 0, // 0x595
+	 0, // 0x596
+	 0, // 0x597
+	 0, // 0x598
+	 0, // 0x599
+	 0, // 0x59a
+	 0, // 0x59b
+	 0, // 0x59c
+	 0, // 0x59d
+	 0, // 0x59e
+	 0, // 0x59f
+	 0, // 0x5a0
+	 0, // 0x5a1
+	 0, // 0x5a2
+	 0, // 0x5a3
+	 0, // 0x5a4
+	 0, // 0x5a5
+	 0, // 0x5a6
+	 0, // 0x5a7
+	 0, // 0x5a8
+	 0, // 0x5a9
+	 0, // 0x5aa
+	 0, // 0x5ab
+	 0, // 0x5ac
+	 0, // 0x5ad
+	 0, // 0x5ae
+	 0, // 0x5af
+	 0, // 0x5b0
+	 0, // 0x5b1
+	 0, // 0x5b2
+	 0, // 0x5b3
+	 0, // 0x5b4
+	 0, // 0x5b5
+	 0, // 0x5b6
+	 0, // 0x5b7
+	 0, // 0x5b8
+	 0, // 0x5b9
+	 0, // 0x5ba
+	 0, // 0x5bb
+	 0, // 0x5bc
+	 0, // 0x5bd
+	 0, // 0x5be
+	 0, // 0x5bf
+	 0, // 0x5c0
+	 0, // 0x5c1
+	 0, // 0x5c2
+	 0, // 0x5c3
+	 0, // 0x5c4
+	 0, // 0x5c5
+	 0, // 0x5c6
+	 0, // 0x5c7
+	 0, // 0x5c8
+	 0, // 0x5c9
+	 0, // 0x5ca
+	 0, // 0x5cb
+	 0, // 0x5cc
+	 0, // 0x5cd
+	 0, // 0x5ce
+	 0, // 0x5cf
+	 0, // 0x5d0
+	 0, // 0x5d1
+	 0, // 0x5d2
+	 0, // 0x5d3
+	 0, // 0x5d4
+	 0, // 0x5d5
+	 0, // 0x5d6
+	 0, // 0x5d7
+	 0, // 0x5d8
+	 0, // 0x5d9
+	 0, // 0x5da
+	 0, // 0x5db
+	 0, // 0x5dc
+	 0, // 0x5dd
+	 0, // 0x5de
+	 0, // 0x5df
+	 0, // 0x5e0
+	 0, // 0x5e1
+	 0, // 0x5e2
+	 0, // 0x5e3
+	 0, // 0x5e4
+	 0, // 0x5e5
+	 0, // 0x5e6
+	 0, // 0x5e7
+	 0, // 0x5e8
+	 0, // 0x5e9
+	 0, // 0x5ea
+	 0, // 0x5eb
+	 0, // 0x5ec
+	 0, // 0x5ed
+	 0, // 0x5ee
+	 0, // 0x5ef
+	 0, // 0x5f0
+	 0, // 0x5f1
+	 0, // 0x5f2
+	 0, // 0x5f3
+	 0, // 0x5f4
+	 0, // 0x5f5
+	 0, // 0x5f6
+	 0, // 0x5f7
+	 0, // 0x5f8
+	 0, // 0x5f9
+	 0, // 0x5fa
+	 0, // 0x5fb
+	 0, // 0x5fc
+	 0, // 0x5fd
+	 0, // 0x5fe
+	 0, // 0x5ff
+	 0, // 0x600
+	 0, // 0x601
+	 0, // 0x602
+	 0, // 0x603
+	 0, // 0x604
+	 0, // 0x605
+	 0, // 0x606
+	 0, // 0x607
+	 0, // 0x608
+	 0, // 0x609
+	 0, // 0x60a
+	 0, // 0x60b
+	 0, // 0x60c
+	 0, // 0x60d
+	 0, // 0x60e
+	 0, // 0x60f
+	 0, // 0x610
+	 0, // 0x611
+	 0, // 0x612
+	 0, // 0x613
+	 0, // 0x614
+	 0, // 0x615
+	 0, // 0x616
+	 0, // 0x617
+	 0, // 0x618
+	 0, // 0x619
+	 0, // 0x61a
+	 0, // 0x61b
+	 0, // 0x61c
+	 0, // 0x61d
+	 0, // 0x61e
+	 0, // 0x61f
+	 0, // 0x620
+	 0, // 0x621
+	 0, // 0x622
+	 0, // 0x623
+	 0, // 0x624
+	 0, // 0x625
+	 0, // 0x626
+	 0, // 0x627
+	 0, // 0x628
+	 0, // 0x629
+	 0, // 0x62a
+	 0, // 0x62b
+	 0, // 0x62c
+	 0, // 0x62d
+	 0, // 0x62e
+	 0, // 0x62f
+	 0, // 0x630
+	 0, // 0x631
+	 0, // 0x632
+	 0, // 0x633
+	 0, // 0x634
+	 0, // 0x635
+	 0, // 0x636
+	 0, // 0x637
+	 0, // 0x638
+	 0, // 0x639
+	 0, // 0x63a
+	 0, // 0x63b
+	 0, // 0x63c
+	 0, // 0x63d
+	 0, // 0x63e
+	 0, // 0x63f
+	 0, // 0x640
+	 0, // 0x641
+	 0, // 0x642
+	 0, // 0x643
+	 0, // 0x644
+	 0, // 0x645
+	 0, // 0x646
+	 0, // 0x647
+	 0, // 0x648
+	 0, // 0x649
+	 0, // 0x64a
+	 0, // 0x64b
+	 0, // 0x64c
+	 0, // 0x64d
+	 0, // 0x64e
+	 0, // 0x64f
+	 0, // 0x650
+	 0, // 0x651
+	 0, // 0x652
+	 0, // 0x653
+	 0, // 0x654
+	 0, // 0x655
+	 0, // 0x656
+	 0, // 0x657
+	 0, // 0x658
+	 0, // 0x659
+	 0, // 0x65a
+	 0, // 0x65b
+	 0, // 0x65c
+	 0, // 0x65d
+	 0, // 0x65e
+	 0, // 0x65f
+	 0, // 0x660
+	 0, // 0x661
+	 0, // 0x662
+	 0, // 0x663
+	 0, // 0x664
+	 0, // 0x665
+	 0, // 0x666
+	 0, // 0x667
+	 0, // 0x668
+	 0, // 0x669
+	 0, // 0x66a
+	 0, // 0x66b
+	 0, // 0x66c
+	 0, // 0x66d
+	 0, // 0x66e
+	 0, // 0x66f
+	 0, // 0x670
+	 0, // 0x671
+	 0, // 0x672
+	 0, // 0x673
+	 0, // 0x674
+	 0, // 0x675
+	 0, // 0x676
+	 0, // 0x677
+	 0, // 0x678
+	 0, // 0x679
+	 0, // 0x67a
+	 0, // 0x67b
+	 0, // 0x67c
+	 0, // 0x67d
+	 0, // 0x67e
+	 0, // 0x67f
+	 0, // 0x680
+	 0, // 0x681
+	 0, // 0x682
+	 0, // 0x683
+	 0, // 0x684
+	 0, // 0x685
+	 0, // 0x686
+	 0, // 0x687
+	 0, // 0x688
+	 0, // 0x689
+	 0, // 0x68a
+	 0, // 0x68b
+	 0, // 0x68c
+	 0, // 0x68d
+	 0, // 0x68e
+	 0, // 0x68f
+	 0, // 0x690
+	 0, // 0x691
+	 0, // 0x692
+	 0, // 0x693
+	 0, // 0x694
+	 0, // 0x695
+	 0, // 0x696
+	 0, // 0x697
+	 0, // 0x698
+	 0, // 0x699
+	 0, // 0x69a
+	 0, // 0x69b
+	 0, // 0x69c
+	 0, // 0x69d
+	 0, // 0x69e
+	 0, // 0x69f
+	 0, // 0x6a0
+	 0, // 0x6a1
+	 0, // 0x6a2
+	 0, // 0x6a3
+	 0, // 0x6a4
+	 0, // 0x6a5
+	 0, // 0x6a6
+	 0, // 0x6a7
+	 0, // 0x6a8
+	 0, // 0x6a9
+	 0, // 0x6aa
+	 0, // 0x6ab
+	 0, // 0x6ac
+	 0, // 0x6ad
+	 0, // 0x6ae
+	 0, // 0x6af
+	 0, // 0x6b0
+	 0, // 0x6b1
+	 0, // 0x6b2
+	 0, // 0x6b3
+	 0, // 0x6b4
+	 0, // 0x6b5
+	 0, // 0x6b6
+	 0, // 0x6b7
+	 0, // 0x6b8
+	 0, // 0x6b9
+	 0, // 0x6ba
+	 0, // 0x6bb
+	 0, // 0x6bc
+	 0, // 0x6bd
+	 0, // 0x6be
+	 0, // 0x6bf
+	 0, // 0x6c0
+	 0, // 0x6c1
+	 0, // 0x6c2
+	 0, // 0x6c3
+	 0, // 0x6c4
+	 0, // 0x6c5
+	 0, // 0x6c6
+	 0, // 0x6c7
+	 0, // 0x6c8
+	 0, // 0x6c9
+	 0, // 0x6ca
+	 0, // 0x6cb
+	 0, // 0x6cc
+	 0, // 0x6cd
+	 0, // 0x6ce
+	 0, // 0x6cf
+	 0, // 0x6d0
+	 0, // 0x6d1
+	 0, // 0x6d2
+	 0, // 0x6d3
+	 0, // 0x6d4
+	 0, // 0x6d5
+	 0, // 0x6d6
+	 0, // 0x6d7
+	 0, // 0x6d8
+	 0, // 0x6d9
+	 0, // 0x6da
+	 0, // 0x6db
+	 0, // 0x6dc
+	 0, // 0x6dd
+	 0, // 0x6de
+	 0, // 0x6df
+	 0, // 0x6e0
+	 0, // 0x6e1
+	 0, // 0x6e2
+	 0, // 0x6e3
+	 0, // 0x6e4
+	 0, // 0x6e5
+	 0, // 0x6e6
+	 0, // 0x6e7
+	 0, // 0x6e8
+	 0, // 0x6e9
+	 0, // 0x6ea
+	 0, // 0x6eb
+	 0, // 0x6ec
+	 0, // 0x6ed
+	 0, // 0x6ee
+	 0, // 0x6ef
+	 0, // 0x6f0
+	 0, // 0x6f1
+	 0, // 0x6f2
+	 0, // 0x6f3
+	 0, // 0x6f4
+	 0, // 0x6f5
+	 0, // 0x6f6
+	 0, // 0x6f7
+	 0, // 0x6f8
+	 0, // 0x6f9
+	 0, // 0x6fa
+	 0, // 0x6fb
+	 0, // 0x6fc
+	 0, // 0x6fd
+	 0, // 0x6fe
+	 0, // 0x6ff
+	 0, // 0x700
+	 0, // 0x701
+	 0, // 0x702
+	 0, // 0x703
+	 0, // 0x704
+	 0, // 0x705
+	 0, // 0x706
+	 0, // 0x707
+	 0, // 0x708
+	 0, // 0x709
+	 0, // 0x70a
+	 0, // 0x70b
+	 0, // 0x70c
+	 0, // 0x70d
+	 0, // 0x70e
+	 0, // 0x70f
+	 0, // 0x710
+	 0, // 0x711
+	 0, // 0x712
+	 0, // 0x713
+	 0, // 0x714
+	 0, // 0x715
+	 0, // 0x716
+	 0, // 0x717
+	 0, // 0x718
+	 0, // 0x719
+	 0, // 0x71a
+	 0, // 0x71b
+	 0, // 0x71c
+	 0, // 0x71d
+	 0, // 0x71e
+	 0, // 0x71f
+	 0, // 0x720
+	 0, // 0x721
+	 0, // 0x722
+	 0, // 0x723
+	 0, // 0x724
+	 0, // 0x725
+	 0, // 0x726
+	 0, // 0x727
+	 0, // 0x728
+	 0, // 0x729
+	 0, // 0x72a
+	 0, // 0x72b
+	 0, // 0x72c
+	 0, // 0x72d
+	 0, // 0x72e
+	 0, // 0x72f
+	 0, // 0x730
+	 0, // 0x731
+	 0, // 0x732
+	 0, // 0x733
+	 0, // 0x734
+	 0, // 0x735
+	 0, // 0x736
+	 0, // 0x737
+	 0, // 0x738
+	 0, // 0x739
+	 0, // 0x73a
+	 0, // 0x73b
+	 0, // 0x73c
+	 0, // 0x73d
+	 0, // 0x73e
+	 0, // 0x73f
+	 0, // 0x740
+	 0, // 0x741
+	 0, // 0x742
+	 0, // 0x743
+	 0, // 0x744
+	 0, // 0x745
+	 0, // 0x746
+	 0, // 0x747
+	 0, // 0x748
+	 0, // 0x749
+	 0, // 0x74a
+	 0, // 0x74b
+	 0, // 0x74c
+	 0, // 0x74d
+	 0, // 0x74e
+	 0, // 0x74f
+	 0, // 0x750
+	 0, // 0x751
+	 0, // 0x752
+	 0, // 0x753
+	 0, // 0x754
+	 0, // 0x755
+	 0, // 0x756
+	 0, // 0x757
+	 0, // 0x758
+	 0, // 0x759
+	 0, // 0x75a
+	 0, // 0x75b
+	 0, // 0x75c
+	 0, // 0x75d
+	 0, // 0x75e
+	 0, // 0x75f
+	 0, // 0x760
+	 0, // 0x761
+	 0, // 0x762
+	 0, // 0x763
+	 0, // 0x764
+	 0, // 0x765
+	 0, // 0x766
+	 0, // 0x767
+	 0, // 0x768
+	 0, // 0x769
+	 0, // 0x76a
+	 0, // 0x76b
+	 0, // 0x76c
+	 0, // 0x76d
+	 0, // 0x76e
+	 0, // 0x76f
+	 0, // 0x770
+	 0, // 0x771
+	 0, // 0x772
+	 0, // 0x773
+	 0, // 0x774
+	 0, // 0x775
+	 0, // 0x776
+	 0, // 0x777
+	 0, // 0x778
+	 0, // 0x779
+	 0, // 0x77a
+	 0, // 0x77b
+	 0, // 0x77c
+	 0, // 0x77d
+	 0, // 0x77e
+	 0, // 0x77f
+	 0, // 0x780
+	 0, // 0x781
+	 0, // 0x782
+	 0, // 0x783
+	 0, // 0x784
+	 0, // 0x785
+	 0, // 0x786
+	 0, // 0x787
+	 0, // 0x788
+	 0, // 0x789
+	 0, // 0x78a
+	 0, // 0x78b
+	 0, // 0x78c
+	 0, // 0x78d
+	 0, // 0x78e
+	 0, // 0x78f
+	 0, // 0x790
+	 0, // 0x791
+	 0, // 0x792
+	 0, // 0x793
+	 0, // 0x794
+	 0, // 0x795
+	 0, // 0x796
+	 0, // 0x797
+	 0, // 0x798
+	 0, // 0x799
+	 0, // 0x79a
+	 0, // 0x79b
+	 0, // 0x79c
+	 0, // 0x79d
+	 0, // 0x79e
+	 0, // 0x79f
+	 0, // 0x7a0
+	 0, // 0x7a1
+	 0, // 0x7a2
+	 0, // 0x7a3
+	 0, // 0x7a4
+	 0, // 0x7a5
+	 0, // 0x7a6
+	 0, // 0x7a7
+	 0, // 0x7a8
+	 0, // 0x7a9
+	 0, // 0x7aa
+	 0, // 0x7ab
+	 0, // 0x7ac
+	 0, // 0x7ad
+	 0, // 0x7ae
+	 0, // 0x7af
+	 0, // 0x7b0
+	 0, // 0x7b1
+	 0, // 0x7b2
+	 0, // 0x7b3
+	 0, // 0x7b4
+	 0, // 0x7b5
+	 0, // 0x7b6
+	 0, // 0x7b7
+	 0, // 0x7b8
+	 0, // 0x7b9
+	 0, // 0x7ba
+	 0, // 0x7bb
+	 0, // 0x7bc
+	 0, // 0x7bd
+	 0, // 0x7be
+	 0, // 0x7bf
+	 0, // 0x7c0
+	 0, // 0x7c1
+	 0, // 0x7c2
+	 0, // 0x7c3
+	 0, // 0x7c4
+	 0, // 0x7c5
+	 0, // 0x7c6
+	 0, // 0x7c7
+	 0, // 0x7c8
+	 0, // 0x7c9
+	 0, // 0x7ca
+	 0, // 0x7cb
+	 0, // 0x7cc
+	 0, // 0x7cd
+	 0, // 0x7ce
+	 0, // 0x7cf
+	 0, // 0x7d0
+	 0, // 0x7d1
+	 0, // 0x7d2
+	 0, // 0x7d3
+	 0, // 0x7d4
+	 0, // 0x7d5
+	 0, // 0x7d6
+	 0, // 0x7d7
+	 0, // 0x7d8
+	 0, // 0x7d9
+	 0, // 0x7da
+	 0, // 0x7db
+	 0, // 0x7dc
+	 0, // 0x7dd
+	 0, // 0x7de
+	 0, // 0x7df
+	 0, // 0x7e0
+	 0, // 0x7e1
+	 0, // 0x7e2
+	 0, // 0x7e3
+	 0, // 0x7e4
+	 0, // 0x7e5
+	 0, // 0x7e6
+	 0, // 0x7e7
+	 0, // 0x7e8
+	 0, // 0x7e9
+	 0, // 0x7ea
+	 0, // 0x7eb
+	 0, // 0x7ec
+	 0, // 0x7ed
+	 0, // 0x7ee
+	 0, // 0x7ef
+	 0, // 0x7f0
+	 0, // 0x7f1
+	 0, // 0x7f2
+	 0, // 0x7f3
+	 0, // 0x7f4
+	 0, // 0x7f5
+	 0, // 0x7f6
+	 0, // 0x7f7
+	 0, // 0x7f8
+	 0, // 0x7f9
+	 0, // 0x7fa
+	 0, // 0x7fb
+	 0, // 0x7fc
+	 0, // 0x7fd
+	 0, // 0x7fe
+	 0, // 0x7ff
+	 0, // 0x800
+	 0, // 0x801
+	 0, // 0x802
+	 0, // 0x803
+	 0, // 0x804
+	 0, // 0x805
+	 0, // 0x806
+	 0, // 0x807
+	 0, // 0x808
+	 0, // 0x809
+	 0, // 0x80a
+	 0, // 0x80b
+	 0, // 0x80c
+	 0, // 0x80d
+	 0, // 0x80e
+	 0, // 0x80f
+	 0, // 0x810
+	 0, // 0x811
+	 0, // 0x812
+	 0, // 0x813
+	 0, // 0x814
+	 0, // 0x815
+	 0, // 0x816
+	 0, // 0x817
+	 0, // 0x818
+	 0, // 0x819
+	 0, // 0x81a
+	 0, // 0x81b
+	 0, // 0x81c
+	 0, // 0x81d
+	 0, // 0x81e
+	 0, // 0x81f
+	 0, // 0x820
+	 0, // 0x821
+	 0, // 0x822
+	 0, // 0x823
+	 0, // 0x824
+	 0, // 0x825
+	 0, // 0x826
+	 0, // 0x827
+	 0, // 0x828
+	 0, // 0x829
+	 0, // 0x82a
+	 0, // 0x82b
+	 0, // 0x82c
+	 0, // 0x82d
+	 0, // 0x82e
+	 0, // 0x82f
+	 0, // 0x830
+	 0, // 0x831
+	 0, // 0x832
+	 0, // 0x833
+	 0, // 0x834
+	 0, // 0x835
+	 0, // 0x836
+	 0, // 0x837
+	 0, // 0x838
+	 0, // 0x839
+	 0, // 0x83a
+	 0, // 0x83b
+	 0, // 0x83c
+	 0, // 0x83d
+	 0, // 0x83e
+	 0, // 0x83f
+	 0, // 0x840
+	 0, // 0x841
+	 0, // 0x842
+	 0, // 0x843
+	 0, // 0x844
+	 0, // 0x845
+	 0, // 0x846
+	 0, // 0x847
+	 0, // 0x848
+	 0, // 0x849
+	 0, // 0x84a
+	 0, // 0x84b
+	 0, // 0x84c
+	 0, // 0x84d
+	 0, // 0x84e
+	 0, // 0x84f
+	 0, // 0x850
+	 0, // 0x851
+	 0, // 0x852
+	 0, // 0x853
+	 0, // 0x854
+	 0, // 0x855
+	 0, // 0x856
+	 0, // 0x857
+	 0, // 0x858
+	 0, // 0x859
+	 0, // 0x85a
+	 0, // 0x85b
+	 0, // 0x85c
+	 0, // 0x85d
+	 0, // 0x85e
+	 0, // 0x85f
+	 0, // 0x860
+	 0, // 0x861
+	 0, // 0x862
+	 0, // 0x863
+	 0, // 0x864
+	 0, // 0x865
+	 0, // 0x866
+	 0, // 0x867
+	 0, // 0x868
+	 0, // 0x869
+	 0, // 0x86a
+	 0, // 0x86b
+	 0, // 0x86c
+	 0, // 0x86d
+	 0, // 0x86e
+	 0, // 0x86f
+	 0, // 0x870
+	 0, // 0x871
+	 0, // 0x872
+	 0, // 0x873
+	 0, // 0x874
+	 0, // 0x875
+	 0, // 0x876
+	 0, // 0x877
+	 0, // 0x878
+	 0, // 0x879
+	 0, // 0x87a
+	 0, // 0x87b
+	 0, // 0x87c
+	 0, // 0x87d
+	 0, // 0x87e
+	 0, // 0x87f
+	 0, // 0x880
+	 0, // 0x881
+	 0, // 0x882
+	 0, // 0x883
+	 0, // 0x884
+	 0, // 0x885
+	 0, // 0x886
+	 0, // 0x887
+	 0, // 0x888
+	 0, // 0x889
+	 0, // 0x88a
+	 0, // 0x88b
+	 0, // 0x88c
+	 0, // 0x88d
+	 0, // 0x88e
+	 0, // 0x88f
+	 0, // 0x890
+	 0, // 0x891
+	 0, // 0x892
+	 0, // 0x893
+	 0, // 0x894
+	 0, // 0x895
+	 0, // 0x896
+	 0, // 0x897
+	 0, // 0x898
+	 0, // 0x899
+	 0, // 0x89a
+	 0, // 0x89b
+	 0, // 0x89c
+	 0, // 0x89d
+	 0, // 0x89e
+	 0, // 0x89f
+	 0, // 0x8a0
+	 0, // 0x8a1
+	 0, // 0x8a2
+	 0, // 0x8a3
+	 0, // 0x8a4
+	 0, // 0x8a5
+	 0, // 0x8a6
+	 0, // 0x8a7
+	 0, // 0x8a8
+	 0, // 0x8a9
+	 0, // 0x8aa
+	 0, // 0x8ab
+	 0, // 0x8ac
+	 0, // 0x8ad
+	 0, // 0x8ae
+	 0, // 0x8af
+	 0, // 0x8b0
+	 0, // 0x8b1
+	 0, // 0x8b2
+	 0, // 0x8b3
+	 0, // 0x8b4
+	 0, // 0x8b5
+	 0, // 0x8b6
+	 0, // 0x8b7
+	 0, // 0x8b8
+	 0, // 0x8b9
+	 0, // 0x8ba
+	 0, // 0x8bb
+	 0, // 0x8bc
+	 0, // 0x8bd
+	 0, // 0x8be
+	 0, // 0x8bf
+	 0, // 0x8c0
+	 0, // 0x8c1
+	 0, // 0x8c2
+	 0, // 0x8c3
+	 0, // 0x8c4
+	 0, // 0x8c5
+	 0, // 0x8c6
+	 0, // 0x8c7
+	 0, // 0x8c8
+	 0, // 0x8c9
+	 0, // 0x8ca
+	 0, // 0x8cb
+	 0, // 0x8cc
+	 0, // 0x8cd
+	 0, // 0x8ce
+	 0, // 0x8cf
+	 0, // 0x8d0
+	 0, // 0x8d1
+	 0, // 0x8d2
+	 0, // 0x8d3
+	 0, // 0x8d4
+	 0, // 0x8d5
+	 0, // 0x8d6
+	 0, // 0x8d7
+	 0, // 0x8d8
+	 0, // 0x8d9
+	 0, // 0x8da
+	 0, // 0x8db
+	 0, // 0x8dc
+	 0, // 0x8dd
+	 0, // 0x8de
+	 0, // 0x8df
+	 0, // 0x8e0
+	 0, // 0x8e1
+	 0, // 0x8e2
+	 0, // 0x8e3
+	 0, // 0x8e4
+	 0, // 0x8e5
+	 0, // 0x8e6
+	 0, // 0x8e7
+	 0, // 0x8e8
+	 0, // 0x8e9
+	 0, // 0x8ea
+	 0, // 0x8eb
+	 0, // 0x8ec
+	 0, // 0x8ed
+	 0, // 0x8ee
+	 0, // 0x8ef
+	 0, // 0x8f0
+	 0, // 0x8f1
+	 0, // 0x8f2
+	 0, // 0x8f3
+	 0, // 0x8f4
+	 0, // 0x8f5
+	 0, // 0x8f6
+	 0, // 0x8f7
+	 0, // 0x8f8
+	 0, // 0x8f9
+	 0, // 0x8fa
+	 0, // 0x8fb
+	 0, // 0x8fc
+	 0, // 0x8fd
+	 0, // 0x8fe
+	 0, // 0x8ff
+	 0, // 0x900
+	 0, // 0x901
+	 0, // 0x902
+	 0, // 0x903
+	 0, // 0x904
+	 0, // 0x905
+	 0, // 0x906
+	 0, // 0x907
+	 0, // 0x908
+	 0, // 0x909
+	 0, // 0x90a
+	 0, // 0x90b
+	 0, // 0x90c
+	 0, // 0x90d
+	 0, // 0x90e
+	 0, // 0x90f
+	 0, // 0x910
+	 0, // 0x911
+	 0, // 0x912
+	 0, // 0x913
+	 0, // 0x914
+	 0, // 0x915
+	 0, // 0x916
+	 0, // 0x917
+	 0, // 0x918
+	 0, // 0x919
+	 0, // 0x91a
+	 0, // 0x91b
+	 0, // 0x91c
+	 0, // 0x91d
+	 0, // 0x91e
+	 0, // 0x91f
+	 0, // 0x920
+	 0, // 0x921
+	 0, // 0x922
+	 0, // 0x923
+	 0, // 0x924
+	 0, // 0x925
+	 0, // 0x926
+	 0, // 0x927
+	 0, // 0x928
+	 0, // 0x929
+	 0, // 0x92a
+	 0, // 0x92b
+	 0, // 0x92c
+	 0, // 0x92d
+	 0, // 0x92e
+	 0, // 0x92f
+	 0, // 0x930
+	 0, // 0x931
+	 0, // 0x932
+	 0, // 0x933
+	 0, // 0x934
+	 0, // 0x935
+	 0, // 0x936
+	 0, // 0x937
+	 0, // 0x938
+	 0, // 0x939
+	 0, // 0x93a
+	 0, // 0x93b
+	 0, // 0x93c
+	 0, // 0x93d
+	 0, // 0x93e
+	 0, // 0x93f
+	 0, // 0x940
+	 0, // 0x941
+	 0, // 0x942
+	 0, // 0x943
+	 0, // 0x944
+	 0, // 0x945
+	 0, // 0x946
+	 0, // 0x947
+	 0, // 0x948
+	 0, // 0x949
+	 0, // 0x94a
+	 0, // 0x94b
+	 0, // 0x94c
+	 0, // 0x94d
+	 0, // 0x94e
+	 0, // 0x94f
+	 0, // 0x950
+	 0, // 0x951
+	 0, // 0x952
+	 0, // 0x953
+	 0, // 0x954
+	 0, // 0x955
+	 0, // 0x956
+	 0, // 0x957
+	 0, // 0x958
+	 0, // 0x959
+	 0, // 0x95a
+	 0, // 0x95b
+	 0, // 0x95c
+	 0, // 0x95d
+	 0, // 0x95e
+	 0, // 0x95f
+	 0, // 0x960
+	 0, // 0x961
+	 0, // 0x962
+	 0, // 0x963
+	 0, // 0x964
+	 0, // 0x965
+	 0, // 0x966
+	 0, // 0x967
+	 0, // 0x968
+	 0, // 0x969
+	 0, // 0x96a
+	 0, // 0x96b
+	 0, // 0x96c
+	 0, // 0x96d
+	 0, // 0x96e
+	 0, // 0x96f
+	 0, // 0x970
+	 0, // 0x971
+	 0, // 0x972
+	 0, // 0x973
+	 0, // 0x974
+	 0, // 0x975
+	 0, // 0x976
+	 0, // 0x977
+	 0, // 0x978
+	 0, // 0x979
+	 0, // 0x97a
+	 0, // 0x97b
+	 0, // 0x97c
+	 0, // 0x97d
+	 0, // 0x97e
+	 0, // 0x97f
+	 0, // 0x980
+	 0, // 0x981
+	 0, // 0x982
+	 0, // 0x983
+	 0, // 0x984
+	 0, // 0x985
+	 0, // 0x986
+	 0, // 0x987
+	 0, // 0x988
+	 0, // 0x989
+	 0, // 0x98a
+	 0, // 0x98b
+	 0, // 0x98c
+	 0, // 0x98d
+	 0, // 0x98e
+	 0, // 0x98f
+	 0, // 0x990
+	 0, // 0x991
+	 0, // 0x992
+	 0, // 0x993
+	 0, // 0x994
+	 0, // 0x995
+	 0, // 0x996
+	 0, // 0x997
+	 0, // 0x998
+	 0, // 0x999
+	 0, // 0x99a
+	 0, // 0x99b
+	 0, // 0x99c
+	 0, // 0x99d
+	 0, // 0x99e
+	 0, // 0x99f
+	 0, // 0x9a0
+	 0, // 0x9a1
+	 0, // 0x9a2
+	 0, // 0x9a3
+	 0, // 0x9a4
+	 0, // 0x9a5
+	 0, // 0x9a6
+	 0, // 0x9a7
+	 0, // 0x9a8
+	 0, // 0x9a9
+	 0, // 0x9aa
+	 0, // 0x9ab
+	 0, // 0x9ac
+	 0, // 0x9ad
+	 0, // 0x9ae
+	 0, // 0x9af
+	 0, // 0x9b0
+	 0, // 0x9b1
+	 0, // 0x9b2
+	 0, // 0x9b3
+	 0, // 0x9b4
+	 0, // 0x9b5
+	 0, // 0x9b6
+	 0, // 0x9b7
+	 0, // 0x9b8
+	 0, // 0x9b9
+	 0, // 0x9ba
+	 0, // 0x9bb
+	 0, // 0x9bc
+	 0, // 0x9bd
+	 0, // 0x9be
+	 0, // 0x9bf
+	 0, // 0x9c0
+	 0, // 0x9c1
+	 0, // 0x9c2
+	 0, // 0x9c3
+	 0, // 0x9c4
+	 0, // 0x9c5
+	 0, // 0x9c6
+	 0, // 0x9c7
+	 0, // 0x9c8
+	 0, // 0x9c9
+	 0, // 0x9ca
+	 0, // 0x9cb
+	 0, // 0x9cc
+	 0, // 0x9cd
+	 0, // 0x9ce
+	 0, // 0x9cf
+	 0, // 0x9d0
+	 0, // 0x9d1
+	 0, // 0x9d2
+	 0, // 0x9d3
+	 0, // 0x9d4
+	 0, // 0x9d5
+	 0, // 0x9d6
+	 0, // 0x9d7
+	 0, // 0x9d8
+	 0, // 0x9d9
+	 0, // 0x9da
+	 0, // 0x9db
+	 0, // 0x9dc
+	 0, // 0x9dd
+	 0, // 0x9de
+	 0, // 0x9df
+	 0, // 0x9e0
+	 0, // 0x9e1
+	 0, // 0x9e2
+	 0, // 0x9e3
+	 0, // 0x9e4
+	 0, // 0x9e5
+	 0, // 0x9e6
+	 0, // 0x9e7
+	 0, // 0x9e8
+	 0, // 0x9e9
+	 0, // 0x9ea
+	 0, // 0x9eb
+	 0, // 0x9ec
+	 0, // 0x9ed
+	 0, // 0x9ee
+	 0, // 0x9ef
+	 0, // 0x9f0
+	 0, // 0x9f1
+	 0, // 0x9f2
+	 0, // 0x9f3
+	 0, // 0x9f4
+	 0, // 0x9f5
+	 0, // 0x9f6
+	 0, // 0x9f7
+	 0, // 0x9f8
+	 0, // 0x9f9
+	 0, // 0x9fa
+	 0, // 0x9fb
+	 0, // 0x9fc
+	 0, // 0x9fd
+	 0, // 0x9fe
+	 0, // 0x9ff
+	 0, // 0xa00
+	 0, // 0xa01
+	 0, // 0xa02
+	 0, // 0xa03
+	 0, // 0xa04
+	 0, // 0xa05
+	 0, // 0xa06
+	 0, // 0xa07
+	 0, // 0xa08
+	 0, // 0xa09
+	 0, // 0xa0a
+	 0, // 0xa0b
+	 0, // 0xa0c
+	 0, // 0xa0d
+	 0, // 0xa0e
+	 0, // 0xa0f
+	 0, // 0xa10
+	 0, // 0xa11
+	 0, // 0xa12
+	 0, // 0xa13
+	 0, // 0xa14
+	 0, // 0xa15
+	 0, // 0xa16
+	 0, // 0xa17
+	 0, // 0xa18
+	 0, // 0xa19
+	 0, // 0xa1a
+	 0, // 0xa1b
+	 0, // 0xa1c
+	 0, // 0xa1d
+	 0, // 0xa1e
+	 0, // 0xa1f
+	 0, // 0xa20
+	 0, // 0xa21
+	 0, // 0xa22
+	 0, // 0xa23
+	 0, // 0xa24
+	 0, // 0xa25
+	 0, // 0xa26
+	 0, // 0xa27
+	 0, // 0xa28
+	 0, // 0xa29
+	 0, // 0xa2a
+	 0, // 0xa2b
+	 0, // 0xa2c
+	 0, // 0xa2d
+	 0, // 0xa2e
+	 0, // 0xa2f
+	 0, // 0xa30
+	 0, // 0xa31
+	 0, // 0xa32
+	 0, // 0xa33
+	 0, // 0xa34
+	 0, // 0xa35
+	 0, // 0xa36
+	 0, // 0xa37
+	 0, // 0xa38
+	 0, // 0xa39
+	 0, // 0xa3a
+	 0, // 0xa3b
+	 0, // 0xa3c
+	 0, // 0xa3d
+	 0, // 0xa3e
+	 0, // 0xa3f
+	 0, // 0xa40
+	 0, // 0xa41
+	 0, // 0xa42
+	 0, // 0xa43
+	 0, // 0xa44
+	 0, // 0xa45
+	 0, // 0xa46
+	 0, // 0xa47
+	 0, // 0xa48
+	 0, // 0xa49
+	 0, // 0xa4a
+	 0, // 0xa4b
+	 0, // 0xa4c
+	 0, // 0xa4d
+	 0, // 0xa4e
+	 0, // 0xa4f
+	 0, // 0xa50
+	 0, // 0xa51
+	 0, // 0xa52
+	 0, // 0xa53
+	 0, // 0xa54
+	 0, // 0xa55
+	 0, // 0xa56
+	 0, // 0xa57
+	 0, // 0xa58
+	 0, // 0xa59
+	 0, // 0xa5a
+	 0, // 0xa5b
+	 0, // 0xa5c
+	 0, // 0xa5d
+	 0, // 0xa5e
+	 0, // 0xa5f
+	 0, // 0xa60
+	 0, // 0xa61
+	 0, // 0xa62
+	 0, // 0xa63
+	 0, // 0xa64
+	 0, // 0xa65
+	 0, // 0xa66
+	 0, // 0xa67
+	 0, // 0xa68
+	 0, // 0xa69
+	 0, // 0xa6a
+	 0, // 0xa6b
+	 0, // 0xa6c
+	 0, // 0xa6d
+	 0, // 0xa6e
+	 0, // 0xa6f
+	 0, // 0xa70
+	 0, // 0xa71
+	 0, // 0xa72
+	 0, // 0xa73
+	 0, // 0xa74
+	 0, // 0xa75
+	 0, // 0xa76
+	 0, // 0xa77
+	 0, // 0xa78
+	 0, // 0xa79
+	 0, // 0xa7a
+	 0, // 0xa7b
+	 0, // 0xa7c
+	 0, // 0xa7d
+	 0, // 0xa7e
+	 0, // 0xa7f
+	 0, // 0xa80
+	 0, // 0xa81
+	 0, // 0xa82
+	 0, // 0xa83
+	 0, // 0xa84
+	 0, // 0xa85
+	 0, // 0xa86
+	 0, // 0xa87
+	 0, // 0xa88
+	 0, // 0xa89
+	 0, // 0xa8a
+	 0, // 0xa8b
+	 0, // 0xa8c
+	 0, // 0xa8d
+	 0, // 0xa8e
+	 0, // 0xa8f
+	 0, // 0xa90
+	 0, // 0xa91
+	 0, // 0xa92
+	 0, // 0xa93
+	 0, // 0xa94
+	 0, // 0xa95
+	 0, // 0xa96
+	 0, // 0xa97
+	 0, // 0xa98
+	 0, // 0xa99
+	 0, // 0xa9a
+	 0, // 0xa9b
+	 0, // 0xa9c
+	 0, // 0xa9d
+	 0, // 0xa9e
+	 0, // 0xa9f
+	 0, // 0xaa0
+	 0, // 0xaa1
+	 0, // 0xaa2
+	 0, // 0xaa3
+	 0, // 0xaa4
+	 0, // 0xaa5
+	 0, // 0xaa6
+	 0, // 0xaa7
+	 0, // 0xaa8
+	 0, // 0xaa9
+	 0, // 0xaaa
+	 0, // 0xaab
+	 0, // 0xaac
+	 0, // 0xaad
+	 0, // 0xaae
+	 0, // 0xaaf
+	 0, // 0xab0
+	 0, // 0xab1
+	 0, // 0xab2
+	 0, // 0xab3
+	 0, // 0xab4
+	 0, // 0xab5
+	 0, // 0xab6
+	 0, // 0xab7
+	 0, // 0xab8
+	 0, // 0xab9
+	 0, // 0xaba
+	 0, // 0xabb
+	 0, // 0xabc
+	 0, // 0xabd
+	 0, // 0xabe
+	 0, // 0xabf
+	 0, // 0xac0
+	 0, // 0xac1
+	 0, // 0xac2
+	 0, // 0xac3
+	 0, // 0xac4
+	 0, // 0xac5
+	 0, // 0xac6
+	 0, // 0xac7
+	 0, // 0xac8
+	 0, // 0xac9
+	 0, // 0xaca
+	 0, // 0xacb
+	 0, // 0xacc
+	 0, // 0xacd
+	 0, // 0xace
+	 0, // 0xacf
+	 0, // 0xad0
+	 0, // 0xad1
+	 0, // 0xad2
+	 0, // 0xad3
+	 0, // 0xad4
+	 0, // 0xad5
+	 0, // 0xad6
+	 0, // 0xad7
+	 0, // 0xad8
+	 0, // 0xad9
+	 0, // 0xada
+	 0, // 0xadb
+	 0, // 0xadc
+	 0, // 0xadd
+	 0, // 0xade
+	 0, // 0xadf
+	 0, // 0xae0
+	 0, // 0xae1
+	 0, // 0xae2
+	 0, // 0xae3
+	 0, // 0xae4
+	 0, // 0xae5
+	 0, // 0xae6
+	 0, // 0xae7
+	 0, // 0xae8
+	 0, // 0xae9
+	 0, // 0xaea
+	 0, // 0xaeb
+	 0, // 0xaec
+	 0, // 0xaed
+	 0, // 0xaee
+	 0, // 0xaef
+	 0, // 0xaf0
+	 0, // 0xaf1
+	 0, // 0xaf2
+	 0, // 0xaf3
+	 0, // 0xaf4
+	 0, // 0xaf5
+	 0, // 0xaf6
+	 0, // 0xaf7
+	 0, // 0xaf8
+	 0, // 0xaf9
+	 0, // 0xafa
+	 0, // 0xafb
+	 0, // 0xafc
+	 0, // 0xafd
+	 0, // 0xafe
+	 0, // 0xaff
+	 0, // 0xb00
+	 0, // 0xb01
+	 0, // 0xb02
+	 0, // 0xb03
+	 0, // 0xb04
+	 0, // 0xb05
+	 0, // 0xb06
+	 0, // 0xb07
+	 0, // 0xb08
+	 0, // 0xb09
+	 0, // 0xb0a
+	 0, // 0xb0b
+	 0, // 0xb0c
+	 0, // 0xb0d
+	 0, // 0xb0e
+	 0, // 0xb0f
+	 0, // 0xb10
+	 0, // 0xb11
+	 0, // 0xb12
+	 0, // 0xb13
+	 0, // 0xb14
+	 0, // 0xb15
+	 0, // 0xb16
+	 0, // 0xb17
+	 0, // 0xb18
+	 0, // 0xb19
+	 0, // 0xb1a
+	 0, // 0xb1b
+	 0, // 0xb1c
+	 0, // 0xb1d
+	 0, // 0xb1e
+	 0, // 0xb1f
+	 0, // 0xb20
+	 0, // 0xb21
+	 0, // 0xb22
+	 0, // 0xb23
+	 0, // 0xb24
+	 0, // 0xb25
+	 0, // 0xb26
+	 0, // 0xb27
+	 0, // 0xb28
+	 0, // 0xb29
+	 0, // 0xb2a
+	 0, // 0xb2b
+	 0, // 0xb2c
+	 0, // 0xb2d
+	 0, // 0xb2e
+	 0, // 0xb2f
+	 0, // 0xb30
+	 0, // 0xb31
+	 0, // 0xb32
+	 0, // 0xb33
+	 0, // 0xb34
+	 0, // 0xb35
+	 0, // 0xb36
+	 0, // 0xb37
+	 0, // 0xb38
+	 0, // 0xb39
+	 0, // 0xb3a
+	 0, // 0xb3b
+	 0, // 0xb3c
+	 0, // 0xb3d
+	 0, // 0xb3e
+	 0, // 0xb3f
+	 0, // 0xb40
+	 0, // 0xb41
+	 0, // 0xb42
+	 0, // 0xb43
+	 0, // 0xb44
+	 0, // 0xb45
+	 0, // 0xb46
+	 0, // 0xb47
+	 0, // 0xb48
+	 0, // 0xb49
+	 0, // 0xb4a
+	 0, // 0xb4b
+	 0, // 0xb4c
+	 0, // 0xb4d
+	 0, // 0xb4e
+	 0, // 0xb4f
+	 0, // 0xb50
+	 0, // 0xb51
+	 0, // 0xb52
+	 0, // 0xb53
+	 0, // 0xb54
+	 0, // 0xb55
+	 0, // 0xb56
+	 0, // 0xb57
+	 0, // 0xb58
+	 0, // 0xb59
+	 0, // 0xb5a
+	 0, // 0xb5b
+	 0, // 0xb5c
+	 0, // 0xb5d
+	 0, // 0xb5e
+	 0, // 0xb5f
+	 0, // 0xb60
+	 0, // 0xb61
+	 0, // 0xb62
+	 0, // 0xb63
+	 0, // 0xb64
+	 0, // 0xb65
+	 0, // 0xb66
+	 0, // 0xb67
+	 0, // 0xb68
+	 0, // 0xb69
+	 0, // 0xb6a
+	 0, // 0xb6b
+	 0, // 0xb6c
+	 0, // 0xb6d
+	 0, // 0xb6e
+	 0, // 0xb6f
+	 0, // 0xb70
+	 0, // 0xb71
+	 0, // 0xb72
+	 0, // 0xb73
+	 0, // 0xb74
+	 0, // 0xb75
+	 0, // 0xb76
+	 0, // 0xb77
+	 0, // 0xb78
+	 0, // 0xb79
+	 0, // 0xb7a
+	 0, // 0xb7b
+	 0, // 0xb7c
+	 0, // 0xb7d
+	 0, // 0xb7e
+	 0, // 0xb7f
+	 0, // 0xb80
+	 0, // 0xb81
+	 0, // 0xb82
+	 0, // 0xb83
+	 0, // 0xb84
+	 0, // 0xb85
+	 0, // 0xb86
+	 0, // 0xb87
+	 0, // 0xb88
+	 0, // 0xb89
+	 0, // 0xb8a
+	 0, // 0xb8b
+	 0, // 0xb8c
+	 0, // 0xb8d
+	 0, // 0xb8e
+	 0, // 0xb8f
+	 0, // 0xb90
+	 0, // 0xb91
+	 0, // 0xb92
+	 0, // 0xb93
+	 0, // 0xb94
+	 0, // 0xb95
+	 0, // 0xb96
+	 0, // 0xb97
+	 0, // 0xb98
+	 0, // 0xb99
+	 0, // 0xb9a
+	 0, // 0xb9b
+	 0, // 0xb9c
+	 0, // 0xb9d
+	 0, // 0xb9e
+	 0, // 0xb9f
+	 0, // 0xba0
+	 0, // 0xba1
+	 0, // 0xba2
+	 0, // 0xba3
+	 0, // 0xba4
+	 0, // 0xba5
+	 0, // 0xba6
+	 0, // 0xba7
+	 0, // 0xba8
+	 0, // 0xba9
+	 0, // 0xbaa
+	 0, // 0xbab
+	 0, // 0xbac
+	 0, // 0xbad
+	 0, // 0xbae
+	 0, // 0xbaf
+	 0, // 0xbb0
+	 0, // 0xbb1
+	 0, // 0xbb2
+	 0, // 0xbb3
+	 0, // 0xbb4
+	 0, // 0xbb5
+	 0, // 0xbb6
+	 0, // 0xbb7
+	 0, // 0xbb8
+	 0, // 0xbb9
+	 0, // 0xbba
+	 0, // 0xbbb
+	 0, // 0xbbc
+	 0, // 0xbbd
+	 0, // 0xbbe
+	 0, // 0xbbf
+	 0, // 0xbc0
+	 0, // 0xbc1
+	 0, // 0xbc2
+	 0, // 0xbc3
+	 0, // 0xbc4
+	 0, // 0xbc5
+	 0, // 0xbc6
+	 0, // 0xbc7
+	 0, // 0xbc8
+	 0, // 0xbc9
+	 0, // 0xbca
+	 0, // 0xbcb
+	 0, // 0xbcc
+	 0, // 0xbcd
+	 0, // 0xbce
+	 0, // 0xbcf
+	 0, // 0xbd0
+	 0, // 0xbd1
+	 0, // 0xbd2
+	 0, // 0xbd3
+	 0, // 0xbd4
+	 0, // 0xbd5
+	 0, // 0xbd6
+	 0, // 0xbd7
+	 0, // 0xbd8
+	 0, // 0xbd9
+	 0, // 0xbda
+	 0, // 0xbdb
+	 0, // 0xbdc
+	 0, // 0xbdd
+	 0, // 0xbde
+	 0, // 0xbdf
+	 0, // 0xbe0
+	 0, // 0xbe1
+	 0, // 0xbe2
+	 0, // 0xbe3
+	 0, // 0xbe4
+	 0, // 0xbe5
+	 0, // 0xbe6
+	 0, // 0xbe7
+	 0, // 0xbe8
+	 0, // 0xbe9
+	 0, // 0xbea
+	 0, // 0xbeb
+	 0, // 0xbec
+	 0, // 0xbed
+	 0, // 0xbee
+	 0, // 0xbef
+	 0, // 0xbf0
+	 0, // 0xbf1
+	 0, // 0xbf2
+	 0, // 0xbf3
+	 0, // 0xbf4
+	 0, // 0xbf5
+	 0, // 0xbf6
+	 0, // 0xbf7
+	 0, // 0xbf8
+	 0, // 0xbf9
+	 0, // 0xbfa
+	 0, // 0xbfb
+	 0, // 0xbfc
+	 0, // 0xbfd
+	 0, // 0xbfe
+	 0, // 0xbff
+	 0, // 0xc00
+	 0, // 0xc01
+	 0, // 0xc02
+	 0, // 0xc03
+	 0, // 0xc04
+	 0, // 0xc05
+	 0, // 0xc06
+	 0, // 0xc07
+	 0, // 0xc08
+	 0, // 0xc09
+	 0, // 0xc0a
+	 0, // 0xc0b
+	 0, // 0xc0c
+	 0, // 0xc0d
+	 0, // 0xc0e
+	 0, // 0xc0f
+	 0, // 0xc10
+	 0, // 0xc11
+	 0, // 0xc12
+	 0, // 0xc13
+	 0, // 0xc14
+	 0, // 0xc15
+	 0, // 0xc16
+	 0, // 0xc17
+	 0, // 0xc18
+	 0, // 0xc19
+	 0, // 0xc1a
+	 0, // 0xc1b
+	 0, // 0xc1c
+	 0, // 0xc1d
+	 0, // 0xc1e
+	 0, // 0xc1f
+	 0, // 0xc20
+	 0, // 0xc21
+	 0, // 0xc22
+	 0, // 0xc23
+	 0, // 0xc24
+	 0, // 0xc25
+	 0, // 0xc26
+	 0, // 0xc27
+	 0, // 0xc28
+	 0, // 0xc29
+	 0, // 0xc2a
+	 0, // 0xc2b
+	 0, // 0xc2c
+	 0, // 0xc2d
+	 0, // 0xc2e
+	 0, // 0xc2f
+	 0, // 0xc30
+	 0, // 0xc31
+	 0, // 0xc32
+	 0, // 0xc33
+	 0, // 0xc34
+	 0, // 0xc35
+	 0, // 0xc36
+	 0, // 0xc37
+	 0, // 0xc38
+	 0, // 0xc39
+	 0, // 0xc3a
+	 0, // 0xc3b
+	 0, // 0xc3c
+	 0, // 0xc3d
+	 0, // 0xc3e
+	 0, // 0xc3f
+	 0, // 0xc40
+	 0, // 0xc41
+	 0, // 0xc42
+	 0, // 0xc43
+	 0, // 0xc44
+	 0, // 0xc45
+	 0, // 0xc46
+	 0, // 0xc47
+	 0, // 0xc48
+	 0, // 0xc49
+	 0, // 0xc4a
+	 0, // 0xc4b
+	 0, // 0xc4c
+	 0, // 0xc4d
+	 0, // 0xc4e
+	 0, // 0xc4f
+	 0, // 0xc50
+	 0, // 0xc51
+	 0, // 0xc52
+	 0, // 0xc53
+	 0, // 0xc54
+	 0, // 0xc55
+	 0, // 0xc56
+	 0, // 0xc57
+	 0, // 0xc58
+	 0, // 0xc59
+	 0, // 0xc5a
+	 0, // 0xc5b
+	 0, // 0xc5c
+	 0, // 0xc5d
+	 0, // 0xc5e
+	 0, // 0xc5f
+	 0, // 0xc60
+	 0, // 0xc61
+	 0, // 0xc62
+	 0, // 0xc63
+	 0, // 0xc64
+	 0, // 0xc65
+	 0, // 0xc66
+	 0, // 0xc67
+	 0, // 0xc68
+	 0, // 0xc69
+	 0, // 0xc6a
+	 0, // 0xc6b
+	 0, // 0xc6c
+	 0, // 0xc6d
+	 0, // 0xc6e
+	 0, // 0xc6f
+	 0, // 0xc70
+	 0, // 0xc71
+	 0, // 0xc72
+	 0, // 0xc73
+	 0, // 0xc74
+	 0, // 0xc75
+	 0, // 0xc76
+	 0, // 0xc77
+	 0, // 0xc78
+	 0, // 0xc79
+	 0, // 0xc7a
+	 0, // 0xc7b
+	 0, // 0xc7c
+	 0, // 0xc7d
+	 0, // 0xc7e
+	 0, // 0xc7f
+	 0, // 0xc80
+	 0, // 0xc81
+	 0, // 0xc82
+	 0, // 0xc83
+	 0, // 0xc84
+	 0, // 0xc85
+	 0, // 0xc86
+	 0, // 0xc87
+	 0, // 0xc88
+	 0, // 0xc89
+	 0, // 0xc8a
+	 0, // 0xc8b
+	 0, // 0xc8c
+	 0, // 0xc8d
+	 0, // 0xc8e
+	 0, // 0xc8f
+	 0, // 0xc90
+	 0, // 0xc91
+	 0, // 0xc92
+	 0, // 0xc93
+	 0, // 0xc94
+	 0, // 0xc95
+	 0, // 0xc96
+	 0, // 0xc97
+	 0, // 0xc98
+	 0, // 0xc99
+	 0, // 0xc9a
+	 0, // 0xc9b
+	 0, // 0xc9c
+	 0, // 0xc9d
+	 0, // 0xc9e
+	 0, // 0xc9f
+	 0, // 0xca0
+	 0, // 0xca1
+	 0, // 0xca2
+	 0, // 0xca3
+	 0, // 0xca4
+	 0, // 0xca5
+	 0, // 0xca6
+	 0, // 0xca7
+	 0, // 0xca8
+	 0, // 0xca9
+	 0, // 0xcaa
+	 0, // 0xcab
+	 0, // 0xcac
+	 0, // 0xcad
+	 0, // 0xcae
+	 0, // 0xcaf
+	 0, // 0xcb0
+	 0, // 0xcb1
+	 0, // 0xcb2
+	 0, // 0xcb3
+	 0, // 0xcb4
+	 0, // 0xcb5
+	 0, // 0xcb6
+	 0, // 0xcb7
+	 0, // 0xcb8
+	 0, // 0xcb9
+	 0, // 0xcba
+	 0, // 0xcbb
+	 0, // 0xcbc
+	 0, // 0xcbd
+	 0, // 0xcbe
+	 0, // 0xcbf
+	 0, // 0xcc0
+	 0, // 0xcc1
+	 0, // 0xcc2
+	 0, // 0xcc3
+	 0, // 0xcc4
+	 0, // 0xcc5
+	 0, // 0xcc6
+	 0, // 0xcc7
+	 0, // 0xcc8
+	 0, // 0xcc9
+	 0, // 0xcca
+	 0, // 0xccb
+	 0, // 0xccc
+	 0, // 0xccd
+	 0, // 0xcce
+	 0, // 0xccf
+	 0, // 0xcd0
+	 0, // 0xcd1
+	 0, // 0xcd2
+	 0, // 0xcd3
+	 0, // 0xcd4
+	 0, // 0xcd5
+	 0, // 0xcd6
+	 0, // 0xcd7
+	 0, // 0xcd8
+	 0, // 0xcd9
+	 0, // 0xcda
+	 0, // 0xcdb
+	 0, // 0xcdc
+	 0, // 0xcdd
+	 0, // 0xcde
+	 0, // 0xcdf
+	 0, // 0xce0
+	 0, // 0xce1
+	 0, // 0xce2
+	 0, // 0xce3
+	 0, // 0xce4
+	 0, // 0xce5
+	 0, // 0xce6
+	 0, // 0xce7
+	 0, // 0xce8
+	 0, // 0xce9
+	 0, // 0xcea
+	 0, // 0xceb
+	 0, // 0xcec
+	 0, // 0xced
+	 0, // 0xcee
+	 0, // 0xcef
+	 0, // 0xcf0
+	 0, // 0xcf1
+	 0, // 0xcf2
+	 0, // 0xcf3
+	 0, // 0xcf4
+	 0, // 0xcf5
+	 0, // 0xcf6
+	 0, // 0xcf7
+	 0, // 0xcf8
+	 0, // 0xcf9
+	 0, // 0xcfa
+	 0, // 0xcfb
+	 0, // 0xcfc
+	 0, // 0xcfd
+	 0, // 0xcfe
+	 0, // 0xcff
+	 0, // 0xd00
+	 0, // 0xd01
+	 0, // 0xd02
+	 0, // 0xd03
+	 0, // 0xd04
+	 0, // 0xd05
+	 0, // 0xd06
+	 0, // 0xd07
+	 0, // 0xd08
+	 0, // 0xd09
+	 0, // 0xd0a
+	 0, // 0xd0b
+	 0, // 0xd0c
+	 0, // 0xd0d
+	 0, // 0xd0e
+	 0, // 0xd0f
+	 0, // 0xd10
+	 0, // 0xd11
+	 0, // 0xd12
+	 0, // 0xd13
+	 0, // 0xd14
+	 0, // 0xd15
+	 0, // 0xd16
+	 0, // 0xd17
+	 0, // 0xd18
+	 0, // 0xd19
+	 0, // 0xd1a
+	 0, // 0xd1b
+	 0, // 0xd1c
+	 0, // 0xd1d
+	 0, // 0xd1e
+	 0, // 0xd1f
+	 0, // 0xd20
+	 0, // 0xd21
+	 0, // 0xd22
+	 0, // 0xd23
+	 0, // 0xd24
+	 0, // 0xd25
+	 0, // 0xd26
+	 0, // 0xd27
+	 0, // 0xd28
+	 0, // 0xd29
+	 0, // 0xd2a
+	 0, // 0xd2b
+	 0, // 0xd2c
+	 0, // 0xd2d
+	 0, // 0xd2e
+	 0, // 0xd2f
+	 0, // 0xd30
+	 0, // 0xd31
+	 0, // 0xd32
+	 0, // 0xd33
+	 0, // 0xd34
+	 0, // 0xd35
+	 0, // 0xd36
+	 0, // 0xd37
+	 0, // 0xd38
+	 0, // 0xd39
+	 0, // 0xd3a
+	 0, // 0xd3b
+	 0, // 0xd3c
+	 0, // 0xd3d
+	 0, // 0xd3e
+	 0, // 0xd3f
+	 0, // 0xd40
+	 0, // 0xd41
+	 0, // 0xd42
+	 0, // 0xd43
+	 0, // 0xd44
+	 0, // 0xd45
+	 0, // 0xd46
+	 0, // 0xd47
+	 0, // 0xd48
+	 0, // 0xd49
+	 0, // 0xd4a
+	 0, // 0xd4b
+	 0, // 0xd4c
+	 0, // 0xd4d
+	 0, // 0xd4e
+	 0, // 0xd4f
+	 0, // 0xd50
+	 0, // 0xd51
+	 0, // 0xd52
+	 0, // 0xd53
+	 0, // 0xd54
+	 0, // 0xd55
+	 0, // 0xd56
+	 0, // 0xd57
+	 0, // 0xd58
+	 0, // 0xd59
+	 0, // 0xd5a
+	 0, // 0xd5b
+	 0, // 0xd5c
+	 0, // 0xd5d
+	 0, // 0xd5e
+	 0, // 0xd5f
+	 0, // 0xd60
+	 0, // 0xd61
+	 0, // 0xd62
+	 0, // 0xd63
+	 0, // 0xd64
+	 0, // 0xd65
+	 0, // 0xd66
+	 0, // 0xd67
+	 0, // 0xd68
+	 0, // 0xd69
+	 0, // 0xd6a
+	 0, // 0xd6b
+	 0, // 0xd6c
+	 0, // 0xd6d
+	 0, // 0xd6e
+	 0, // 0xd6f
+	 0, // 0xd70
+	 0, // 0xd71
+	 0, // 0xd72
+	 0, // 0xd73
+	 0, // 0xd74
+	 0, // 0xd75
+	 0, // 0xd76
+	 0, // 0xd77
+	 0, // 0xd78
+	 0, // 0xd79
+	 0, // 0xd7a
+	 0, // 0xd7b
+	 0, // 0xd7c
+	 0, // 0xd7d
+	 0, // 0xd7e
+	 0, // 0xd7f
+	 0, // 0xd80
+	 0, // 0xd81
+	 0, // 0xd82
+	 0, // 0xd83
+	 0, // 0xd84
+	 0, // 0xd85
+	 0, // 0xd86
+	 0, // 0xd87
+	 0, // 0xd88
+	 0, // 0xd89
+	 0, // 0xd8a
+	 0, // 0xd8b
+	 0, // 0xd8c
+	 0, // 0xd8d
+	 0, // 0xd8e
+	 0, // 0xd8f
+	 0, // 0xd90
+	 0, // 0xd91
+	 0, // 0xd92
+	 0, // 0xd93
+	 0, // 0xd94
+	 0, // 0xd95
+	 0, // 0xd96
+	 0, // 0xd97
+	 0, // 0xd98
+	 0, // 0xd99
+	 0, // 0xd9a
+	 0, // 0xd9b
+	 0, // 0xd9c
+	 0, // 0xd9d
+	 0, // 0xd9e
+	 0, // 0xd9f
+	 0, // 0xda0
+	 0, // 0xda1
+	 0, // 0xda2
+	 0, // 0xda3
+	 0, // 0xda4
+	 0, // 0xda5
+	 0, // 0xda6
+	 0, // 0xda7
+	 0, // 0xda8
+	 0, // 0xda9
+	 0, // 0xdaa
+	 0, // 0xdab
+	 0, // 0xdac
+	 0, // 0xdad
+	 0, // 0xdae
+	 0, // 0xdaf
+	 0, // 0xdb0
+	 0, // 0xdb1
+	 0, // 0xdb2
+	 0, // 0xdb3
+	 0, // 0xdb4
+	 0, // 0xdb5
+	 0, // 0xdb6
+	 0, // 0xdb7
+	 0, // 0xdb8
+	 0, // 0xdb9
+	 0, // 0xdba
+	 0, // 0xdbb
+	 0, // 0xdbc
+	 0, // 0xdbd
+	 0, // 0xdbe
+	 0, // 0xdbf
+	 0, // 0xdc0
+	 0, // 0xdc1
+	 0, // 0xdc2
+	 0, // 0xdc3
+	 0, // 0xdc4
+	 0, // 0xdc5
+	 0, // 0xdc6
+	 0, // 0xdc7
+	 0, // 0xdc8
+	 0, // 0xdc9
+	 0, // 0xdca
+	 0, // 0xdcb
+	 0, // 0xdcc
+	 0, // 0xdcd
+	 0, // 0xdce
+	 0, // 0xdcf
+	 0, // 0xdd0
+	 0, // 0xdd1
+	 0, // 0xdd2
+	 0, // 0xdd3
+	 0, // 0xdd4
+	 0, // 0xdd5
+	 0, // 0xdd6
+	 0, // 0xdd7
+	 0, // 0xdd8
+	 0, // 0xdd9
+	 0, // 0xdda
+	 0, // 0xddb
+	 0, // 0xddc
+	 0, // 0xddd
+	 0, // 0xdde
+	 0, // 0xddf
+	 0, // 0xde0
+	 0, // 0xde1
+	 0, // 0xde2
+	 0, // 0xde3
+	 0, // 0xde4
+	 0, // 0xde5
+	 0, // 0xde6
+	 0, // 0xde7
+	 0, // 0xde8
+	 0, // 0xde9
+	 0, // 0xdea
+	 0, // 0xdeb
+	 0, // 0xdec
+	 0, // 0xded
+	 0, // 0xdee
+	 0, // 0xdef
+	 0, // 0xdf0
+	 0, // 0xdf1
+	 0, // 0xdf2
+	 0, // 0xdf3
+	 0, // 0xdf4
+	 0, // 0xdf5
+	 0, // 0xdf6
+	 0, // 0xdf7
+	 0, // 0xdf8
+	 0, // 0xdf9
+	 0, // 0xdfa
+	 0, // 0xdfb
+	 0, // 0xdfc
+	 0, // 0xdfd
+	 0, // 0xdfe
+	 0, // 0xdff
+	 0, // 0xe00
+	 0, // 0xe01
+	 0, // 0xe02
+	 0, // 0xe03
+	 0, // 0xe04
+	 0, // 0xe05
+	 0, // 0xe06
+	 0, // 0xe07
+	 0, // 0xe08
+	 0, // 0xe09
+	 0, // 0xe0a
+	 0, // 0xe0b
+	 0, // 0xe0c
+	 0, // 0xe0d
+	 0, // 0xe0e
+	 0, // 0xe0f
+	 0, // 0xe10
+	 0, // 0xe11
+	 0, // 0xe12
+	 0, // 0xe13
+	 0, // 0xe14
+	 0, // 0xe15
+	 0, // 0xe16
+	 0, // 0xe17
+	 0, // 0xe18
+	 0, // 0xe19
+	 0, // 0xe1a
+	 0, // 0xe1b
+	 0, // 0xe1c
+	 0, // 0xe1d
+	 0, // 0xe1e
+	 0, // 0xe1f
+	 0, // 0xe20
+	 0, // 0xe21
+	 0, // 0xe22
+	 0, // 0xe23
+	 0, // 0xe24
+	 0, // 0xe25
+	 0, // 0xe26
+	 0, // 0xe27
+	 0, // 0xe28
+	 0, // 0xe29
+	 0, // 0xe2a
+	 0, // 0xe2b
+	 0, // 0xe2c
+	 0, // 0xe2d
+	 0, // 0xe2e
+	 0, // 0xe2f
+	 0, // 0xe30
+	 0, // 0xe31
+	 0, // 0xe32
+	 0, // 0xe33
+	 0, // 0xe34
+	 0, // 0xe35
+	 0, // 0xe36
+	 0, // 0xe37
+	 0, // 0xe38
+	 0, // 0xe39
+	 0, // 0xe3a
+	 0, // 0xe3b
+	 0, // 0xe3c
+	 0, // 0xe3d
+	 0, // 0xe3e
+	 0, // 0xe3f
+	 0, // 0xe40
+	 0, // 0xe41
+	 0, // 0xe42
+	 0, // 0xe43
+	 0, // 0xe44
+	 0, // 0xe45
+	 0, // 0xe46
+	 0, // 0xe47
+	 0, // 0xe48
+	 0, // 0xe49
+	 0, // 0xe4a
+	 0, // 0xe4b
+	 0, // 0xe4c
+	 0, // 0xe4d
+	 0, // 0xe4e
+	 0, // 0xe4f
+	 0, // 0xe50
+	 0, // 0xe51
+	 0, // 0xe52
+	 0, // 0xe53
+	 0, // 0xe54
+	 0, // 0xe55
+	 0, // 0xe56
+	 0, // 0xe57
+	 0, // 0xe58
+	 0, // 0xe59
+	 0, // 0xe5a
+	 0, // 0xe5b
+	 0, // 0xe5c
+	 0, // 0xe5d
+	 0, // 0xe5e
+	 0, // 0xe5f
+	 0, // 0xe60
+	 0, // 0xe61
+	 0, // 0xe62
+	 0, // 0xe63
+	 0, // 0xe64
+	 0, // 0xe65
+	 0, // 0xe66
+	 0, // 0xe67
+	 0, // 0xe68
+	 0, // 0xe69
+	 0, // 0xe6a
+	 0, // 0xe6b
+	 0, // 0xe6c
+	 0, // 0xe6d
+	 0, // 0xe6e
+	 0, // 0xe6f
+	 0, // 0xe70
+	 0, // 0xe71
+	 0, // 0xe72
+	 0, // 0xe73
+	 0, // 0xe74
+	 0, // 0xe75
+	 0, // 0xe76
+	 0, // 0xe77
+	 0, // 0xe78
+	 0, // 0xe79
+	 0, // 0xe7a
+	 0, // 0xe7b
+	 0, // 0xe7c
+	 0, // 0xe7d
+	 0, // 0xe7e
+	 0, // 0xe7f
+	 0, // 0xe80
+	 0, // 0xe81
+	 0, // 0xe82
+	 0, // 0xe83
+	 0, // 0xe84
+	 0, // 0xe85
+	 0, // 0xe86
+	 0, // 0xe87
+	 0, // 0xe88
+	 0, // 0xe89
+	 0, // 0xe8a
+	 0, // 0xe8b
+	 0, // 0xe8c
+	 0, // 0xe8d
+	 0, // 0xe8e
+	 0, // 0xe8f
+	 0, // 0xe90
+	 0, // 0xe91
+	 0, // 0xe92
+	 0, // 0xe93
+	 0, // 0xe94
+	 0, // 0xe95
+	 0, // 0xe96
+	 0, // 0xe97
+	 0, // 0xe98
+	 0, // 0xe99
+	 0, // 0xe9a
+	 0, // 0xe9b
+	 0, // 0xe9c
+	 0, // 0xe9d
+	 0, // 0xe9e
+	 0, // 0xe9f
+	 0, // 0xea0
+	 0, // 0xea1
+	 0, // 0xea2
+	 0, // 0xea3
+	 0, // 0xea4
+	 0, // 0xea5
+	 0, // 0xea6
+	 0, // 0xea7
+	 0, // 0xea8
+	 0, // 0xea9
+	 0, // 0xeaa
+	 0, // 0xeab
+	 0, // 0xeac
+	 0, // 0xead
+	 0, // 0xeae
+	 0, // 0xeaf
+	 0, // 0xeb0
+	 0, // 0xeb1
+	 0, // 0xeb2
+	 0, // 0xeb3
+	 0, // 0xeb4
+	 0, // 0xeb5
+	 0, // 0xeb6
+	 0, // 0xeb7
+	 0, // 0xeb8
+	 0, // 0xeb9
+	 0, // 0xeba
+	 0, // 0xebb
+	 0, // 0xebc
+	 0, // 0xebd
+	 0, // 0xebe
+	 0, // 0xebf
+	 0, // 0xec0
+	 0, // 0xec1
+	 0, // 0xec2
+	 0, // 0xec3
+	 0, // 0xec4
+	 0, // 0xec5
+	 0, // 0xec6
+	 0, // 0xec7
+	 0, // 0xec8
+	 0, // 0xec9
+	 0, // 0xeca
+	 0, // 0xecb
+	 0, // 0xecc
+	 0, // 0xecd
+	 0, // 0xece
+	 0, // 0xecf
+	 0, // 0xed0
+	 0, // 0xed1
+	 0, // 0xed2
+	 0, // 0xed3
+	 0, // 0xed4
+	 0, // 0xed5
+	 0, // 0xed6
+	 0, // 0xed7
+	 0, // 0xed8
+	 0, // 0xed9
+	 0, // 0xeda
+	 0, // 0xedb
+	 0, // 0xedc
+	 0, // 0xedd
+	 0, // 0xede
+	 0, // 0xedf
+	 0, // 0xee0
+	 0, // 0xee1
+	 0, // 0xee2
+	 0, // 0xee3
+	 0, // 0xee4
+	 0, // 0xee5
+	 0, // 0xee6
+	 0, // 0xee7
+	 0, // 0xee8
+	 0, // 0xee9
+	 0, // 0xeea
+	 0, // 0xeeb
+	 0, // 0xeec
+	 0, // 0xeed
+	 0, // 0xeee
+	 0, // 0xeef
+	 0, // 0xef0
+	 0, // 0xef1
+	 0, // 0xef2
+	 0, // 0xef3
+	 0, // 0xef4
+	 0, // 0xef5
+	 0, // 0xef6
+	 0, // 0xef7
+	 0, // 0xef8
+	 0, // 0xef9
+	 0, // 0xefa
+	 0, // 0xefb
+	 0, // 0xefc
+	 0, // 0xefd
+	 0, // 0xefe
+	 0, // 0xeff
+	 0, // 0xf00
+	 0, // 0xf01
+	 0, // 0xf02
+	 0, // 0xf03
+	 0, // 0xf04
+	 0, // 0xf05
+	 0, // 0xf06
+	 0, // 0xf07
+	 0, // 0xf08
+	 0, // 0xf09
+	 0, // 0xf0a
+	 0, // 0xf0b
+	 0, // 0xf0c
+	 0, // 0xf0d
+	 0, // 0xf0e
+	 0, // 0xf0f
+	 0, // 0xf10
+	 0, // 0xf11
+	 0, // 0xf12
+	 0, // 0xf13
+	 0, // 0xf14
+	 0, // 0xf15
+	 0, // 0xf16
+	 0, // 0xf17
+	 0, // 0xf18
+	 0, // 0xf19
+	 0, // 0xf1a
+	 0, // 0xf1b
+	 0, // 0xf1c
+	 0, // 0xf1d
+	 0, // 0xf1e
+	 0, // 0xf1f
+	 0, // 0xf20
+	 0, // 0xf21
+	 0, // 0xf22
+	 0, // 0xf23
+	 0, // 0xf24
+	 0, // 0xf25
+	 0, // 0xf26
+	 0, // 0xf27
+	 0, // 0xf28
+	 0, // 0xf29
+	 0, // 0xf2a
+	 0, // 0xf2b
+	 0, // 0xf2c
+	 0, // 0xf2d
+	 0, // 0xf2e
+	 0, // 0xf2f
+	 0, // 0xf30
+	 0, // 0xf31
+	 0, // 0xf32
+	 0, // 0xf33
+	 0, // 0xf34
+	 0, // 0xf35
+	 0, // 0xf36
+	 0, // 0xf37
+	 0, // 0xf38
+	 0, // 0xf39
+	 0, // 0xf3a
+	 0, // 0xf3b
+	 0, // 0xf3c
+	 0, // 0xf3d
+	 0, // 0xf3e
+	 0, // 0xf3f
+	 0, // 0xf40
+	 0, // 0xf41
+	 0, // 0xf42
+	 0, // 0xf43
+	 0, // 0xf44
+	 0, // 0xf45
+	 0, // 0xf46
+	 0, // 0xf47
+	 0, // 0xf48
+	 0, // 0xf49
+	 0, // 0xf4a
+	 0, // 0xf4b
+	 0, // 0xf4c
+	 0, // 0xf4d
+	 0, // 0xf4e
+	 0, // 0xf4f
+	 0, // 0xf50
+	 0, // 0xf51
+	 0, // 0xf52
+	 0, // 0xf53
+	 0, // 0xf54
+	 0, // 0xf55
+	 0, // 0xf56
+	 0, // 0xf57
+	 0, // 0xf58
+	 0, // 0xf59
+	 0, // 0xf5a
+	 0, // 0xf5b
+	 0, // 0xf5c
+	 0, // 0xf5d
+	 0, // 0xf5e
+	 0, // 0xf5f
+	 0, // 0xf60
+	 0, // 0xf61
+	 0, // 0xf62
+	 0, // 0xf63
+	 0, // 0xf64
+	 0, // 0xf65
+	 0, // 0xf66
+	 0, // 0xf67
+	 0, // 0xf68
+	 0, // 0xf69
+	 0, // 0xf6a
+	 0, // 0xf6b
+	 0, // 0xf6c
+	 0, // 0xf6d
+	 0, // 0xf6e
+	 0, // 0xf6f
+	 0, // 0xf70
+	 0, // 0xf71
+	 0, // 0xf72
+	 0, // 0xf73
+	 0, // 0xf74
+	 0, // 0xf75
+	 0, // 0xf76
+	 0, // 0xf77
+	 0, // 0xf78
+	 0, // 0xf79
+	 0, // 0xf7a
+	 0, // 0xf7b
+	 0, // 0xf7c
+	 0, // 0xf7d
+	 0, // 0xf7e
+	 0, // 0xf7f
+	 0, // 0xf80
+	 0, // 0xf81
+	 0, // 0xf82
+	 0, // 0xf83
+	 0, // 0xf84
+	 0, // 0xf85
+	 0, // 0xf86
+	 0, // 0xf87
+	 0, // 0xf88
+	 0, // 0xf89
+	 0, // 0xf8a
+	 0, // 0xf8b
+	 0, // 0xf8c
+	 0, // 0xf8d
+	 0, // 0xf8e
+	 0, // 0xf8f
+	 0, // 0xf90
+	 0, // 0xf91
+	 0, // 0xf92
+	 0, // 0xf93
+	 0, // 0xf94
+	 0, // 0xf95
+	 0, // 0xf96
+	 0, // 0xf97
+	 0, // 0xf98
+	 0, // 0xf99
+	 0, // 0xf9a
+	 0, // 0xf9b
+	 0, // 0xf9c
+	 0, // 0xf9d
+	 0, // 0xf9e
+	 0, // 0xf9f
+	 0, // 0xfa0
+	 0, // 0xfa1
+	 0, // 0xfa2
+	 0, // 0xfa3
+	 0, // 0xfa4
+	 0, // 0xfa5
+	 0, // 0xfa6
+	 0, // 0xfa7
+	 0, // 0xfa8
+	 0, // 0xfa9
+	 0, // 0xfaa
+	 0, // 0xfab
+	 0, // 0xfac
+	 0, // 0xfad
+	 0, // 0xfae
+	 0, // 0xfaf
+	 0, // 0xfb0
+	 0, // 0xfb1
+	 0, // 0xfb2
+	 0, // 0xfb3
+	 0, // 0xfb4
+	 0, // 0xfb5
+	 0, // 0xfb6
+	 0, // 0xfb7
+	 0, // 0xfb8
+	 0, // 0xfb9
+	 0, // 0xfba
+	 0, // 0xfbb
+	 0, // 0xfbc
+	 0, // 0xfbd
+	 0, // 0xfbe
+	 0, // 0xfbf
+	 0, // 0xfc0
+	 0, // 0xfc1
+	 0, // 0xfc2
+	 0, // 0xfc3
+	 0, // 0xfc4
+	 0, // 0xfc5
+	 0, // 0xfc6
+	 0, // 0xfc7
+	 0, // 0xfc8
+	 0, // 0xfc9
+	 0, // 0xfca
+	 0, // 0xfcb
+	 0, // 0xfcc
+	 0, // 0xfcd
+	 0, // 0xfce
+	 0, // 0xfcf
+	 0, // 0xfd0
+	 0, // 0xfd1
+	 0, // 0xfd2
+	 0, // 0xfd3
+	 0, // 0xfd4
+	 0, // 0xfd5
+	 0, // 0xfd6
+	 0, // 0xfd7
+	 0, // 0xfd8
+	 0, // 0xfd9
+	 0, // 0xfda
+	 0, // 0xfdb
+	 0, // 0xfdc
+	 0, // 0xfdd
+	 0, // 0xfde
+	 0, // 0xfdf
+	 0, // 0xfe0
+	 0, // 0xfe1
+	 0, // 0xfe2
+	 0, // 0xfe3
+	 0, // 0xfe4
+	 0, // 0xfe5
+	 0, // 0xfe6
+	 0, // 0xfe7
+	 0, // 0xfe8
+	 0, // 0xfe9
+	 0, // 0xfea
+	 0, // 0xfeb
+	 0, // 0xfec
+	 0, // 0xfed
+	 0, // 0xfee
+	 0, // 0xfef
+	 0, // 0xff0
+	 0, // 0xff1
+	 0, // 0xff2
+	 0, // 0xff3
+	 0, // 0xff4
+	 0, // 0xff5
+	 0, // 0xff6
+	 0, // 0xff7
+	 0, // 0xff8
+	 0, // 0xff9
+	 0, // 0xffa
+	 0, // 0xffb
+	 0, // 0xffc
+	 0, // 0xffd
+	 0, // 0xffe
+	 0, // 0xfff
+	 0, // 0x1000
+	 0, // 0x1001
+	 0, // 0x1002
+	 0, // 0x1003
+	 0, // 0x1004
+	 0, // 0x1005
+	 0, // 0x1006
+	 0, // 0x1007
+	 0, // 0x1008
+	 0, // 0x1009
+	 0, // 0x100a
+	 0, // 0x100b
+	 0, // 0x100c
+	 0, // 0x100d
+	 0, // 0x100e
+	 0, // 0x100f
+	 0, // 0x1010
+	 0, // 0x1011
+	 0, // 0x1012
+	 0, // 0x1013
+	 0, // 0x1014
+	 0, // 0x1015
+	 0, // 0x1016
+	 0, // 0x1017
+	 0, // 0x1018
+	 0, // 0x1019
+	 0, // 0x101a
+	 0, // 0x101b
+	 0, // 0x101c
+	 0, // 0x101d
+	 0, // 0x101e
+	 0, // 0x101f
+	 0, // 0x1020
+	 0, // 0x1021
+	 0, // 0x1022
+	 0, // 0x1023
+	 0, // 0x1024
+	 0, // 0x1025
+	 0, // 0x1026
+	 0, // 0x1027
+	 0, // 0x1028
+	 0, // 0x1029
+	 0, // 0x102a
+	 0, // 0x102b
+	 0, // 0x102c
+	 0, // 0x102d
+	 0, // 0x102e
+	 0, // 0x102f
+	 0, // 0x1030
+	 0, // 0x1031
+	 0, // 0x1032
+	 0, // 0x1033
+	 0, // 0x1034
+	 0, // 0x1035
+	 0, // 0x1036
+	 0, // 0x1037
+	 0, // 0x1038
+	 0, // 0x1039
+	 0, // 0x103a
+	 0, // 0x103b
+	 0, // 0x103c
+	 0, // 0x103d
+	 0, // 0x103e
+	 0, // 0x103f
+	 0, // 0x1040
+	 0, // 0x1041
+	 0, // 0x1042
+	 0, // 0x1043
+	 0, // 0x1044
+	 0, // 0x1045
+	 0, // 0x1046
+	 0, // 0x1047
+	 0, // 0x1048
+	 0, // 0x1049
+	 0, // 0x104a
+	 0, // 0x104b
+	 0, // 0x104c
+	 0, // 0x104d
+	 0, // 0x104e
+	 0, // 0x104f
+	 0, // 0x1050
+	 0, // 0x1051
+	 0, // 0x1052
+	 0, // 0x1053
+	 0, // 0x1054
+	 0, // 0x1055
+	 0, // 0x1056
+	 0, // 0x1057
+	 0, // 0x1058
+	 0, // 0x1059
+	 0, // 0x105a
+	 0, // 0x105b
+	 0, // 0x105c
+	 0, // 0x105d
+	 0, // 0x105e
+	 0, // 0x105f
+	 0, // 0x1060
+	 0, // 0x1061
+	 0, // 0x1062
+	 0, // 0x1063
+	 0, // 0x1064
+	 0, // 0x1065
+	 0, // 0x1066
+	 0, // 0x1067
+	 0, // 0x1068
+	 0, // 0x1069
+	 0, // 0x106a
+	 0, // 0x106b
+	 0, // 0x106c
+	 0, // 0x106d
+	 0, // 0x106e
+	 0, // 0x106f
+	 0, // 0x1070
+	 0, // 0x1071
+	 0, // 0x1072
+	 0, // 0x1073
+	 0, // 0x1074
+	 0, // 0x1075
+	 0, // 0x1076
+	 0, // 0x1077
+	 0, // 0x1078
+	 0, // 0x1079
+	 0, // 0x107a
+	 0, // 0x107b
+	 0, // 0x107c
+	 0, // 0x107d
+	 0, // 0x107e
+	 0, // 0x107f
+	 0, // 0x1080
+	 0, // 0x1081
+	 0, // 0x1082
+	 0, // 0x1083
+	 0, // 0x1084
+	 0, // 0x1085
+	 0, // 0x1086
+	 0, // 0x1087
+	 0, // 0x1088
+	 0, // 0x1089
+	 0, // 0x108a
+	 0, // 0x108b
+	 0, // 0x108c
+	 0, // 0x108d
+	 0, // 0x108e
+	 0, // 0x108f
+	 0, // 0x1090
+	 0, // 0x1091
+	 0, // 0x1092
+	 0, // 0x1093
+	 0, // 0x1094
+	 0, // 0x1095
+	 0, // 0x1096
+	 0, // 0x1097
+	 0, // 0x1098
+	 0, // 0x1099
+	 0, // 0x109a
+	 0, // 0x109b
+	 0, // 0x109c
+	 0, // 0x109d
+	 0, // 0x109e
+	 0, // 0x109f
+	 0, // 0x10a0
+	 0, // 0x10a1
+	 0, // 0x10a2
+	 0, // 0x10a3
+	 0, // 0x10a4
+	 0, // 0x10a5
+	 0, // 0x10a6
+	 0, // 0x10a7
+	 0, // 0x10a8
+	 0, // 0x10a9
+	 0, // 0x10aa
+	 0, // 0x10ab
+	 0, // 0x10ac
+	 0, // 0x10ad
+	 0, // 0x10ae
+	 0, // 0x10af
+	 0, // 0x10b0
+	 0, // 0x10b1
+	 0, // 0x10b2
+	 0, // 0x10b3
+	 0, // 0x10b4
+	 0, // 0x10b5
+	 0, // 0x10b6
+	 0, // 0x10b7
+	 0, // 0x10b8
+	 0, // 0x10b9
+	 0, // 0x10ba
+	 0, // 0x10bb
+	 0, // 0x10bc
+	 0, // 0x10bd
+	 0, // 0x10be
+	 0, // 0x10bf
+	 0, // 0x10c0
+	 0, // 0x10c1
+	 0, // 0x10c2
+	 0, // 0x10c3
+	 0, // 0x10c4
+	 0, // 0x10c5
+	 0, // 0x10c6
+	 0, // 0x10c7
+	 0, // 0x10c8
+	 0, // 0x10c9
+	 0, // 0x10ca
+	 0, // 0x10cb
+	 0, // 0x10cc
+	 0, // 0x10cd
+	 0, // 0x10ce
+	 0, // 0x10cf
+	 0, // 0x10d0
+	 0, // 0x10d1
+	 0, // 0x10d2
+	 0, // 0x10d3
+	 0, // 0x10d4
+	 0, // 0x10d5
+	 0, // 0x10d6
+	 0, // 0x10d7
+	 0, // 0x10d8
+	 0, // 0x10d9
+	 0, // 0x10da
+	 0, // 0x10db
+	 0, // 0x10dc
+	 0, // 0x10dd
+	 0, // 0x10de
+	 0, // 0x10df
+	 0, // 0x10e0
+	 0, // 0x10e1
+	 0, // 0x10e2
+	 0, // 0x10e3
+	 0, // 0x10e4
+	 0, // 0x10e5
+	 0, // 0x10e6
+	 0, // 0x10e7
+	 0, // 0x10e8
+	 0, // 0x10e9
+	 0, // 0x10ea
+	 0, // 0x10eb
+	 0, // 0x10ec
+	 0, // 0x10ed
+	 0, // 0x10ee
+	 0, // 0x10ef
+	 0, // 0x10f0
+	 0, // 0x10f1
+	 0, // 0x10f2
+	 0, // 0x10f3
+	 0, // 0x10f4
+	 0, // 0x10f5
+	 0, // 0x10f6
+	 0, // 0x10f7
+	 0, // 0x10f8
+	 0, // 0x10f9
+	 0, // 0x10fa
+	 0, // 0x10fb
+	 0, // 0x10fc
+	 0, // 0x10fd
+	 0, // 0x10fe
+	 0, // 0x10ff
+];
+pub const CCC_PAGES: [[u8;256];68] = [
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe8,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xe8,
+        0xd8,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xca,
+        0xca,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xca,
+        0xca,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xdc,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xf0,
+        0xe6,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xdc,
+        0xdc,
+        0x0,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xe6,
+        0xe8,
+        0xdc,
+        0xdc,
+        0xe6,
+        0xe9,
+        0xea,
+        0xea,
+        0xe9,
+        0xea,
+        0xea,
+        0xe9,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xdc,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xdc,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xde,
+        0xdc,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xe6,
+        0xe6,
+        0xdc,
+        0xe6,
+        0xe6,
+        0xde,
+        0xe4,
+        0xe6,
+        0xa,
+        0xb,
+        0xc,
+        0xd,
+        0xe,
+        0xf,
+        0x10,
+        0x11,
+        0x12,
+        0x13,
+        0x13,
+        0x14,
+        0x15,
+        0x16,
+        0x0,
+        0x17,
+        0x0,
+        0x18,
+        0x19,
+        0x0,
+        0xe6,
+        0xdc,
+        0x0,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0x1e,
+        0x1f,
+        0x20,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1b,
+        0x1c,
+        0x1d,
+        0x1e,
+        0x1f,
+        0x20,
+        0x21,
+        0x22,
+        0xe6,
+        0xe6,
+        0xdc,
+        0xdc,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xdc,
+        0xe6,
+        0xe6,
+        0xdc,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x23,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0x0,
+        0x0,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xdc,
+        0xe6,
+        0x0,
+        0x0,
+        0xe6,
+        0xe6,
+        0x0,
+        0xdc,
+        0xe6,
+        0xe6,
+        0xdc,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x24,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0xdc,
+        0xe6,
+        0xe6,
+        0xdc,
+        0xe6,
+        0xe6,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xe6,
+        0xdc,
+        0xdc,
+        0xe6,
+        0xdc,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xdc,
+        0xe6,
+        0xdc,
+        0xe6,
+        0xdc,
+        0xe6,
+        0xdc,
+        0xe6,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xdc,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xdc,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0x0,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0x0,
+        0xe6,
+        0xe6,
+        0xe6,
+        0x0,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xdc,
+        0xdc,
+        0xdc,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0xe6,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0x0,
+        0xdc,
+        0xe6,
+        0xe6,
+        0xdc,
+        0xe6,
+        0xe6,
+        0xdc,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xdc,
+        0xdc,
+        0xdc,
+        0x1b,
+        0x1c,
+        0x1d,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xdc,
+        0xe6,
+        0xe6,
+        0xdc,
+        0xdc,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0xdc,
+        0xe6,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x54,
+        0x5b,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x67,
+        0x67,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x76,
+        0x76,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xdc,
+        0xdc,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xdc,
+        0x0,
+        0xdc,
+        0x0,
+        0xd8,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x81,
+        0x82,
+        0x0,
+        0x84,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x0,
+        0x0,
+        0x82,
+        0x0,
+        0xe6,
+        0xe6,
+        0x9,
+        0x0,
+        0xe6,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xdc,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xdc,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0xe6,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe4,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xde,
+        0xe6,
+        0xdc,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0xdc,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0x0,
+        0x0,
+        0xdc,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xe6,
+        0xe6,
+        0xdc,
+        0x0,
+        0xdc,
+        0xdc,
+        0xe6,
+        0xe6,
+        0xdc,
+        0xdc,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xdc,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0xdc,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0xe6,
+        0xe6,
+        0x0,
+        0x1,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xe6,
+        0xe6,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xe6,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xdc,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0xe6,
+        0xdc,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xdc,
+        0xe6,
+        0xe6,
+        0xea,
+        0xd6,
+        0xdc,
+        0xca,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe8,
+        0xe4,
+        0xe4,
+        0xdc,
+        0xda,
+        0xe6,
+        0xe9,
+        0xdc,
+        0xe6,
+        0xdc,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0xe6,
+        0x1,
+        0x1,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0x1,
+        0x1,
+        0x1,
+        0xe6,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0xe6,
+        0xdc,
+        0xe6,
+        0x1,
+        0x1,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0xe6,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xda,
+        0xe4,
+        0xe8,
+        0xde,
+        0xe0,
+        0xe0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x8,
+        0x8,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xdc,
+        0xdc,
+        0xdc,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0x0,
+        0xe6,
+        0xe6,
+        0xdc,
+        0x0,
+        0x0,
+        0xe6,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0xe6,
+        0x0,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1a,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xe6,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xdc,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xdc,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xdc,
+        0x0,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0x1,
+        0xdc,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0xdc,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xdc,
+        0xdc,
+        0xdc,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xdc,
+        0xdc,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xdc,
+        0xe6,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xdc,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0xdc,
+        0xe6,
+        0xdc,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0xe6,
+        0xe6,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x6,
+        0x6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xd8,
+        0xd8,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0xe2,
+        0xd8,
+        0xd8,
+        0xd8,
+        0xd8,
+        0xd8,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xdc,
+        0x0,
+        0x0,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xdc,
+        0xdc,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0xe6,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0x0,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0x0,
+        0x0,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0x0,
+        0xe6,
+        0xe6,
+        0x0,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe8,
+        0xe8,
+        0xdc,
+        0xe6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0xdc,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xdc,
+        0xdc,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0xe6,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+];
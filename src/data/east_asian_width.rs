@@ -0,0 +1,17257 @@
+// GENERATED CODE DO NOT MANUALLY EDIT
+pub const EAW_TABLE: [u8;0x1100] = [
+	 0, // 0x0
+	 1, // 0x1
+	 2, // 0x2
+	 3, // 0x3
+	 4, // 0x4
+	 5, // 0x5
+	 5, // 0x6
+	 5, // 0x7
+	 5, // 0x8
+	 5, // 0x9
+	 5, // 0xa
+	 5, // 0xb
+	 5, // 0xc
+	 5, // 0xd
+	 5, // 0xe
+	 5, // 0xf
+	 5, // 0x10
+	 6, // 0x11
+	 5, // 0x12
+	 5, // 0x13
+	 5, // 0x14
+	 5, // 0x15
+	 5, // 0x16
+	 5, // 0x17
+	 5, // 0x18
+	 5, // 0x19
+	 5, // 0x1a
+	 5, // 0x1b
+	 5, // 0x1c
+	 5, // 0x1d
+	 5, // 0x1e
+	 5, // 0x1f
+	 7, // 0x20
+	 8, // 0x21
+	 9, // 0x22
+	 10, // 0x23
+	 11, // 0x24
+	 12, // 0x25
+	 13, // 0x26
+	 14, // 0x27
+	 5, // 0x28
+	 15, // 0x29
+	 5, // 0x2a
+	 16, // 0x2b
+	 5, // 0x2c
+	 5, // 0x2d
+	 17, // 0x2e
+	 18, // 0x2f
+	 19, // 0x30
+	 20, // 0x31
+	 21, // 0x32
+	 22, // 0x33
+	 22, // 0x34
+	 22, // 0x35
+	 22, // 0x36
+	 22, // 0x37
+	 22, // 0x38
+	 22, // 0x39
+	 22, // 0x3a
+	 22, // 0x3b
+	 22, // 0x3c
+	 22, // 0x3d
+	 22, // 0x3e
+	 22, // 0x3f
+	 22, // 0x40
+	 22, // 0x41
+	 22, // 0x42
+	 22, // 0x43
+	 22, // 0x44
+	 22, // 0x45
+	 22, // 0x46
+	 22, // 0x47
+	 22, // 0x48
+	 22, // 0x49
+	 22, // 0x4a
+	 22, // 0x4b
+	 22, // 0x4c
+	 22, // 0x4d
+	 22, // 0x4e
+	 22, // 0x4f
+	 22, // 0x50
+	 22, // 0x51
+	 22, // 0x52
+	 22, // 0x53
+	 22, // 0x54
+	 22, // 0x55
+	 22, // 0x56
+	 22, // 0x57
+	 22, // 0x58
+	 22, // 0x59
+	 22, // 0x5a
+	 22, // 0x5b
+	 22, // 0x5c
+	 22, // 0x5d
+	 22, // 0x5e
+	 22, // 0x5f
+	 22, // 0x60
+	 22, // 0x61
+	 22, // 0x62
+	 22, // 0x63
+	 22, // 0x64
+	 22, // 0x65
+	 22, // 0x66
+	 22, // 0x67
+	 22, // 0x68
+	 22, // 0x69
+	 22, // 0x6a
+	 22, // 0x6b
+	 22, // 0x6c
+	 22, // 0x6d
+	 22, // 0x6e
+	 22, // 0x6f
+	 22, // 0x70
+	 22, // 0x71
+	 22, // 0x72
+	 22, // 0x73
+	 22, // 0x74
+	 22, // 0x75
+	 22, // 0x76
+	 22, // 0x77
+	 22, // 0x78
+	 22, // 0x79
+	 22, // 0x7a
+	 22, // 0x7b
+	 22, // 0x7c
+	 22, // 0x7d
+	 22, // 0x7e
+	 22, // 0x7f
+	 22, // 0x80
+	 22, // 0x81
+	 22, // 0x82
+	 22, // 0x83
+	 22, // 0x84
+	 22, // 0x85
+	 22, // 0x86
+	 22, // 0x87
+	 22, // 0x88
+	 22, // 0x89
+	 22, // 0x8a
+	 22, // 0x8b
+	 22, // 0x8c
+	 22, // 0x8d
+	 22, // 0x8e
+	 22, // 0x8f
+	 22, // 0x90
+	 22, // 0x91
+	 22, // 0x92
+	 22, // 0x93
+	 22, // 0x94
+	 22, // 0x95
+	 22, // 0x96
+	 22, // 0x97
+	 22, // 0x98
+	 22, // 0x99
+	 22, // 0x9a
+	 22, // 0x9b
+	 22, // 0x9c
+	 22, // 0x9d
+	 22, // 0x9e
+	 22, // 0x9f
+	 22, // 0xa0
+	 22, // 0xa1
+	 22, // 0xa2
+	 22, // 0xa3
+	 23, // 0xa4
+	 5, // 0xa5
+	 5, // 0xa6
+	 5, // 0xa7
+	 5, // 0xa8
+	 24, // 0xa9
+	 5, // 0xaa
+	 5, // 0xab
+	 22, // 0xac
+	 22, // 0xad
+	 22, // 0xae
+	 22, // 0xaf
+	 22, // 0xb0
+	 22, // 0xb1
+	 22, // 0xb2
+	 22, // 0xb3
+	 22, // 0xb4
+	 22, // 0xb5
+	 22, // 0xb6
+	 22, // 0xb7
+	 22, // 0xb8
+	 22, // 0xb9
+	 22, // 0xba
+	 22, // 0xbb
+	 22, // 0xbc
+	 22, // 0xbd
+	 22, // 0xbe
+	 22, // 0xbf
+	 22, // 0xc0
+	 22, // 0xc1
+	 22, // 0xc2
+	 22, // 0xc3
+	 22, // 0xc4
+	 22, // 0xc5
+	 22, // 0xc6
+	 22, // 0xc7
+	 22, // 0xc8
+	 22, // 0xc9
+	 22, // 0xca
+	 22, // 0xcb
+	 22, // 0xcc
+	 22, // 0xcd
+	 22, // 0xce
+	 22, // 0xcf
+	 22, // 0xd0
+	 22, // 0xd1
+	 22, // 0xd2
+	 22, // 0xd3
+	 22, // 0xd4
+	 22, // 0xd5
+	 22, // 0xd6
+	 25, // 0xd7
+	 5, // 0xd8
+	 5, // 0xd9
+	 5, // 0xda
+	 5, // 0xdb
+	 5, // 0xdc
+	 5, // 0xdd
+	 5, // 0xde
+	 5, // 0xdf
+	 26, // 0xe0
+	 26, // 0xe1
+	 26, // 0xe2
+	 26, // 0xe3
+	 26, // 0xe4
+	 26, // 0xe5
+	 26, // 0xe6
+	 26, // 0xe7
+	 26, // 0xe8
+	 26, // 0xe9
+	 26, // 0xea
+	 26, // 0xeb
+	 26, // 0xec
+	 26, // 0xed
+	 26, // 0xee
+	 26, // 0xef
+	 26, // 0xf0
+	 26, // 0xf1
+	 26, // 0xf2
+	 26, // 0xf3
+	 26, // 0xf4
+	 26, // 0xf5
+	 26, // 0xf6
+	 26, // 0xf7
+	 26, // 0xf8
+	 22, // 0xf9
+	 22, // 0xfa
+	 5, // 0xfb
+	 5, // 0xfc
+	 5, // 0xfd
+	 27, // 0xfe
+	 28, // 0xff
+	 5, // 0x100
+	 5, // 0x101
+	 5, // 0x102
+	 5, // 0x103
+	 5, // 0x104
+	 5, // 0x105
+	 5, // 0x106
+	 5, // 0x107
+	 5, // 0x108
+	 5, // 0x109
+	 5, // 0x10a
+	 5, // 0x10b
+	 5, // 0x10c
+	 5, // 0x10d
+	 5, // 0x10e
+	 5, // 0x10f
+	 5, // 0x110
+	 5, // 0x111
+	 5, // 0x112
+	 5, // 0x113
+	 5, // 0x114
+	 5, // 0x115
+	 5, // 0x116
+	 5, // 0x117
+	 5, // 0x118
+	 5, // 0x119
+	 5, // 0x11a
+	 5, // 0x11b
+	 5, // 0x11c
+	 5, // 0x11d
+	 5, // 0x11e
+	 5, // 0x11f
+	 5, // 0x120
+	 5, // 0x121
+	 5, // 0x122
+	 5, // 0x123
+	 5, // 0x124
+	 5, // 0x125
+	 5, // 0x126
+	 5, // 0x127
+	 5, // 0x128
+	 5, // 0x129
+	 5, // 0x12a
+	 5, // 0x12b
+	 5, // 0x12c
+	 5, // 0x12d
+	 5, // 0x12e
+	 5, // 0x12f
+	 5, // 0x130
+	 5, // 0x131
+	 5, // 0x132
+	 5, // 0x133
+	 5, // 0x134
+	 5, // 0x135
+	 5, // 0x136
+	 5, // 0x137
+	 5, // 0x138
+	 5, // 0x139
+	 5, // 0x13a
+	 5, // 0x13b
+	 5, // 0x13c
+	 5, // 0x13d
+	 5, // 0x13e
+	 5, // 0x13f
+	 5, // 0x140
+	 5, // 0x141
+	 5, // 0x142
+	 5, // 0x143
+	 5, // 0x144
+	 5, // 0x145
+	 5, // 0x146
+	 5, // 0x147
+	 5, // 0x148
+	 5, // 0x149
+	 5, // 0x14a
+	 5, // 0x14b
+	 5, // 0x14c
+	 5, // 0x14d
+	 5, // 0x14e
+	 5, // 0x14f
+	 5, // 0x150
+	 5, // 0x151
+	 5, // 0x152
+	 5, // 0x153
+	 5, // 0x154
+	 5, // 0x155
+	 5, // 0x156
+	 5, // 0x157
+	 5, // 0x158
+	 5, // 0x159
+	 5, // 0x15a
+	 5, // 0x15b
+	 5, // 0x15c
+	 5, // 0x15d
+	 5, // 0x15e
+	 5, // 0x15f
+	 5, // 0x160
+	 5, // 0x161
+	 5, // 0x162
+	 5, // 0x163
+	 5, // 0x164
+	 5, // 0x165
+	 5, // 0x166
+	 5, // 0x167
+	 5, // 0x168
+	 5, // 0x169
+	 5, // 0x16a
+	 5, // 0x16b
+	 5, // 0x16c
+	 5, // 0x16d
+	 5, // 0x16e
+	 29, // 0x16f
+	 22, // 0x170
+	 22, // 0x171
+	 22, // 0x172
+	 22, // 0x173
+	 22, // 0x174
+	 22, // 0x175
+	 22, // 0x176
+	 22, // 0x177
+	 22, // 0x178
+	 22, // 0x179
+	 22, // 0x17a
+	 22, // 0x17b
+	 22, // 0x17c
+	 22, // 0x17d
+	 22, // 0x17e
+	 22, // 0x17f
+	 22, // 0x180
+	 22, // 0x181
+	 22, // 0x182
+	 22, // 0x183
+	 22, // 0x184
+	 22, // 0x185
+	 22, // 0x186
+	 30, // 0x187
+	 22, // 0x188
+	 22, // 0x189
+	 22, // 0x18a
+	 22, // 0x18b
+	 31, // 0x18c
+	 32, // 0x18d
+	 5, // 0x18e
+	 5, // 0x18f
+	 5, // 0x190
+	 5, // 0x191
+	 5, // 0x192
+	 5, // 0x193
+	 5, // 0x194
+	 5, // 0x195
+	 5, // 0x196
+	 5, // 0x197
+	 5, // 0x198
+	 5, // 0x199
+	 5, // 0x19a
+	 5, // 0x19b
+	 5, // 0x19c
+	 5, // 0x19d
+	 5, // 0x19e
+	 5, // 0x19f
+	 5, // 0x1a0
+	 5, // 0x1a1
+	 5, // 0x1a2
+	 5, // 0x1a3
+	 5, // 0x1a4
+	 5, // 0x1a5
+	 5, // 0x1a6
+	 5, // 0x1a7
+	 5, // 0x1a8
+	 5, // 0x1a9
+	 5, // 0x1aa
+	 5, // 0x1ab
+	 5, // 0x1ac
+	 5, // 0x1ad
+	 5, // 0x1ae
+	 33, // 0x1af
+	 22, // 0x1b0
+	 34, // 0x1b1
+	 35, // 0x1b2
+	 5, // 0x1b3
+	 5, // 0x1b4
+	 5, // 0x1b5
+	 5, // 0x1b6
+	 5, // 0x1b7
+	 5, // 0x1b8
+	 5, // 0x1b9
+	 5, // 0x1ba
+	 5, // 0x1bb
+	 5, // 0x1bc
+	 5, // 0x1bd
+	 5, // 0x1be
+	 5, // 0x1bf
+	 5, // 0x1c0
+	 5, // 0x1c1
+	 5, // 0x1c2
+	 5, // 0x1c3
+	 5, // 0x1c4
+	 5, // 0x1c5
+	 5, // 0x1c6
+	 5, // 0x1c7
+	 5, // 0x1c8
+	 5, // 0x1c9
+	 5, // 0x1ca
+	 5, // 0x1cb
+	 5, // 0x1cc
+	 5, // 0x1cd
+	 5, // 0x1ce
+	 5, // 0x1cf
+	 5, // 0x1d0
+	 5, // 0x1d1
+	 5, // 0x1d2
+	 36, // 0x1d3
+	 5, // 0x1d4
+	 5, // 0x1d5
+	 5, // 0x1d6
+	 5, // 0x1d7
+	 5, // 0x1d8
+	 5, // 0x1d9
+	 5, // 0x1da
+	 5, // 0x1db
+	 5, // 0x1dc
+	 5, // 0x1dd
+	 5, // 0x1de
+	 5, // 0x1df
+	 5, // 0x1e0
+	 5, // 0x1e1
+	 5, // 0x1e2
+	 5, // 0x1e3
+	 5, // 0x1e4
+	 5, // 0x1e5
+	 5, // 0x1e6
+	 5, // 0x1e7
+	 5, // 0x1e8
+	 5, // 0x1e9
+	 5, // 0x1ea
+	 5, // 0x1eb
+	 5, // 0x1ec
+	 5, // 0x1ed
+	 5, // 0x1ee
+	 5, // 0x1ef
+	 37, // 0x1f0
+	 38, // 0x1f1
+	 39, // 0x1f2
+	 40, // 0x1f3
+	 41, // 0x1f4
+	 42, // 0x1f5
+	 43, // 0x1f6
+	 44, // 0x1f7
+	 5, // 0x1f8
+	 45, // 0x1f9
+	 46, // 0x1fa
+	 5, // 0x1fb
+	 5, // 0x1fc
+	 5, // 0x1fd
+	 5, // 0x1fe
+	 5, // 0x1ff
+	 22, // 0x200
+	 22, // 0x201
+	 22, // 0x202
+	 22, // 0x203
+	 22, // 0x204
+	 22, // 0x205
+	 22, // 0x206
+	 22, // 0x207
+	 22, // 0x208
+	 22, // 0x209
+	 22, // 0x20a
+	 22, // 0x20b
+	 22, // 0x20c
+	 22, // 0x20d
+	 22, // 0x20e
+	 22, // 0x20f
+	 22, // 0x210
+	 22, // 0x211
+	 22, // 0x212
+	 22, // 0x213
+	 22, // 0x214
+	 22, // 0x215
+	 22, // 0x216
+	 22, // 0x217
+	 22, // 0x218
+	 22, // 0x219
+	 22, // 0x21a
+	 22, // 0x21b
+	 22, // 0x21c
+	 22, // 0x21d
+	 22, // 0x21e
+	 22, // 0x21f
+	 22, // 0x220
+	 22, // 0x221
+	 22, // 0x222
+	 22, // 0x223
+	 22, // 0x224
+	 22, // 0x225
+	 22, // 0x226
+	 22, // 0x227
+	 22, // 0x228
+	 22, // 0x229
+	 22, // 0x22a
+	 22, // 0x22b
+	 22, // 0x22c
+	 22, // 0x22d
+	 22, // 0x22e
+	 22, // 0x22f
+	 22, // 0x230
+	 22, // 0x231
+	 22, // 0x232
+	 22, // 0x233
+	 22, // 0x234
+	 22, // 0x235
+	 22, // 0x236
+	 22, // 0x237
+	 22, // 0x238
+	 22, // 0x239
+	 22, // 0x23a
+	 22, // 0x23b
+	 22, // 0x23c
+	 22, // 0x23d
+	 22, // 0x23e
+	 22, // 0x23f
+	 22, // 0x240
+	 22, // 0x241
+	 22, // 0x242
+	 22, // 0x243
+	 22, // 0x244
+	 22, // 0x245
+	 22, // 0x246
+	 22, // 0x247
+	 22, // 0x248
+	 22, // 0x249
+	 22, // 0x24a
+	 22, // 0x24b
+	 22, // 0x24c
+	 22, // 0x24d
+	 22, // 0x24e
+	 22, // 0x24f
+	 22, // 0x250
+	 22, // 0x251
+	 22, // 0x252
+	 22, // 0x253
+	 22, // 0x254
+	 22, // 0x255
+	 22, // 0x256
+	 22, // 0x257
+	 22, // 0x258
+	 22, // 0x259
+	 22, // 0x25a
+	 22, // 0x25b
+	 22, // 0x25c
+	 22, // 0x25d
+	 22, // 0x25e
+	 22, // 0x25f
+	 22, // 0x260
+	 22, // 0x261
+	 22, // 0x262
+	 22, // 0x263
+	 22, // 0x264
+	 22, // 0x265
+	 22, // 0x266
+	 22, // 0x267
+	 22, // 0x268
+	 22, // 0x269
+	 22, // 0x26a
+	 22, // 0x26b
+	 22, // 0x26c
+	 22, // 0x26d
+	 22, // 0x26e
+	 22, // 0x26f
+	 22, // 0x270
+	 22, // 0x271
+	 22, // 0x272
+	 22, // 0x273
+	 22, // 0x274
+	 22, // 0x275
+	 22, // 0x276
+	 22, // 0x277
+	 22, // 0x278
+	 22, // 0x279
+	 22, // 0x27a
+	 22, // 0x27b
+	 22, // 0x27c
+	 22, // 0x27d
+	 22, // 0x27e
+	 22, // 0x27f
+	 22, // 0x280
+	 22, // 0x281
+	 22, // 0x282
+	 22, // 0x283
+	 22, // 0x284
+	 22, // 0x285
+	 22, // 0x286
+	 22, // 0x287
+	 22, // 0x288
+	 22, // 0x289
+	 22, // 0x28a
+	 22, // 0x28b
+	 22, // 0x28c
+	 22, // 0x28d
+	 22, // 0x28e
+	 22, // 0x28f
+	 22, // 0x290
+	 22, // 0x291
+	 22, // 0x292
+	 22, // 0x293
+	 22, // 0x294
+	 22, // 0x295
+	 22, // 0x296
+	 22, // 0x297
+	 22, // 0x298
+	 22, // 0x299
+	 22, // 0x29a
+	 22, // 0x29b
+	 22, // 0x29c
+	 22, // 0x29d
+	 22, // 0x29e
+	 22, // 0x29f
+	 22, // 0x2a0
+	 22, // 0x2a1
+	 22, // 0x2a2
+	 22, // 0x2a3
+	 22, // 0x2a4
+	 22, // 0x2a5
+	 22, // 0x2a6
+	 22, // 0x2a7
+	 22, // 0x2a8
+	 22, // 0x2a9
+	 22, // 0x2aa
+	 22, // 0x2ab
+	 22, // 0x2ac
+	 22, // 0x2ad
+	 22, // 0x2ae
+	 22, // 0x2af
+	 22, // 0x2b0
+	 22, // 0x2b1
+	 22, // 0x2b2
+	 22, // 0x2b3
+	 22, // 0x2b4
+	 22, // 0x2b5
+	 22, // 0x2b6
+	 22, // 0x2b7
+	 22, // 0x2b8
+	 22, // 0x2b9
+	 22, // 0x2ba
+	 22, // 0x2bb
+	 22, // 0x2bc
+	 22, // 0x2bd
+	 22, // 0x2be
+	 22, // 0x2bf
+	 22, // 0x2c0
+	 22, // 0x2c1
+	 22, // 0x2c2
+	 22, // 0x2c3
+	 22, // 0x2c4
+	 22, // 0x2c5
+	 22, // 0x2c6
+	 22, // 0x2c7
+	 22, // 0x2c8
+	 22, // 0x2c9
+	 22, // 0x2ca
+	 22, // 0x2cb
+	 22, // 0x2cc
+	 22, // 0x2cd
+	 22, // 0x2ce
+	 22, // 0x2cf
+	 22, // 0x2d0
+	 22, // 0x2d1
+	 22, // 0x2d2
+	 22, // 0x2d3
+	 22, // 0x2d4
+	 22, // 0x2d5
+	 22, // 0x2d6
+	 22, // 0x2d7
+	 22, // 0x2d8
+	 22, // 0x2d9
+	 22, // 0x2da
+	 22, // 0x2db
+	 22, // 0x2dc
+	 22, // 0x2dd
+	 22, // 0x2de
+	 22, // 0x2df
+	 22, // 0x2e0
+	 22, // 0x2e1
+	 22, // 0x2e2
+	 22, // 0x2e3
+	 22, // 0x2e4
+	 22, // 0x2e5
+	 22, // 0x2e6
+	 22, // 0x2e7
+	 22, // 0x2e8
+	 22, // 0x2e9
+	 22, // 0x2ea
+	 22, // 0x2eb
+	 22, // 0x2ec
+	 22, // 0x2ed
+	 22, // 0x2ee
+	 22, // 0x2ef
+	 22, // 0x2f0
+	 22, // 0x2f1
+	 22, // 0x2f2
+	 22, // 0x2f3
+	 22, // 0x2f4
+	 22, // 0x2f5
+	 22, // 0x2f6
+	 22, // 0x2f7
+	 22, // 0x2f8
+	 22, // 0x2f9
+	 22, // 0x2fa
+	 22, // 0x2fb
+	 22, // 0x2fc
+	 22, // 0x2fd
+	 22, // 0x2fe
+	 47, // 0x2ff
+	 22, // 0x300
+	 22, // 0x301
+	 22, // 0x302
+	 22, // 0x303
+	 22, // 0x304
+	 22, // 0x305
+	 22, // 0x306
+	 22, // 0x307
+	 22, // 0x308
+	 22, // 0x309
+	 22, // 0x30a
+	 22, // 0x30b
+	 22, // 0x30c
+	 22, // 0x30d
+	 22, // 0x30e
+	 22, // 0x30f
+	 22, // 0x310
+	 22, // 0x311
+	 22, // 0x312
+	 22, // 0x313
+	 22, // 0x314
+	 22, // 0x315
+	 22, // 0x316
+	 22, // 0x317
+	 22, // 0x318
+	 22, // 0x319
+	 22, // 0x31a
+	 22, // 0x31b
+	 22, // 0x31c
+	 22, // 0x31d
+	 22, // 0x31e
+	 22, // 0x31f
+	 22, // 0x320
+	 22, // 0x321
+	 22, // 0x322
+	 22, // 0x323
+	 22, // 0x324
+	 22, // 0x325
+	 22, // 0x326
+	 22, // 0x327
+	 22, // 0x328
+	 22, // 0x329
+	 22, // 0x32a
+	 22, // 0x32b
+	 22, // 0x32c
+	 22, // 0x32d
+	 22, // 0x32e
+	 22, // 0x32f
+	 22, // 0x330
+	 22, // 0x331
+	 22, // 0x332
+	 22, // 0x333
+	 22, // 0x334
+	 22, // 0x335
+	 22, // 0x336
+	 22, // 0x337
+	 22, // 0x338
+	 22, // 0x339
+	 22, // 0x33a
+	 22, // 0x33b
+	 22, // 0x33c
+	 22, // 0x33d
+	 22, // 0x33e
+	 22, // 0x33f
+	 22, // 0x340
+	 22, // 0x341
+	 22, // 0x342
+	 22, // 0x343
+	 22, // 0x344
+	 22, // 0x345
+	 22, // 0x346
+	 22, // 0x347
+	 22, // 0x348
+	 22, // 0x349
+	 22, // 0x34a
+	 22, // 0x34b
+	 22, // 0x34c
+	 22, // 0x34d
+	 22, // 0x34e
+	 22, // 0x34f
+	 22, // 0x350
+	 22, // 0x351
+	 22, // 0x352
+	 22, // 0x353
+	 22, // 0x354
+	 22, // 0x355
+	 22, // 0x356
+	 22, // 0x357
+	 22, // 0x358
+	 22, // 0x359
+	 22, // 0x35a
+	 22, // 0x35b
+	 22, // 0x35c
+	 22, // 0x35d
+	 22, // 0x35e
+	 22, // 0x35f
+	 22, // 0x360
+	 22, // 0x361
+	 22, // 0x362
+	 22, // 0x363
+	 22, // 0x364
+	 22, // 0x365
+	 22, // 0x366
+	 22, // 0x367
+	 22, // 0x368
+	 22, // 0x369
+	 22, // 0x36a
+	 22, // 0x36b
+	 22, // 0x36c
+	 22, // 0x36d
+	 22, // 0x36e
+	 22, // 0x36f
+	 22, // 0x370
+	 22, // 0x371
+	 22, // 0x372
+	 22, // 0x373
+	 22, // 0x374
+	 22, // 0x375
+	 22, // 0x376
+	 22, // 0x377
+	 22, // 0x378
+	 22, // 0x379
+	 22, // 0x37a
+	 22, // 0x37b
+	 22, // 0x37c
+	 22, // 0x37d
+	 22, // 0x37e
+	 22, // 0x37f
+	 22, // 0x380
+	 22, // 0x381
+	 22, // 0x382
+	 22, // 0x383
+	 22, // 0x384
+	 22, // 0x385
+	 22, // 0x386
+	 22, // 0x387
+	 22, // 0x388
+	 22, // 0x389
+	 22, // 0x38a
+	 22, // 0x38b
+	 22, // 0x38c
+	 22, // 0x38d
+	 22, // 0x38e
+	 22, // 0x38f
+	 22, // 0x390
+	 22, // 0x391
+	 22, // 0x392
+	 22, // 0x393
+	 22, // 0x394
+	 22, // 0x395
+	 22, // 0x396
+	 22, // 0x397
+	 22, // 0x398
+	 22, // 0x399
+	 22, // 0x39a
+	 22, // 0x39b
+	 22, // 0x39c
+	 22, // 0x39d
+	 22, // 0x39e
+	 22, // 0x39f
+	 22, // 0x3a0
+	 22, // 0x3a1
+	 22, // 0x3a2
+	 22, // 0x3a3
+	 22, // 0x3a4
+	 22, // 0x3a5
+	 22, // 0x3a6
+	 22, // 0x3a7
+	 22, // 0x3a8
+	 22, // 0x3a9
+	 22, // 0x3aa
+	 22, // 0x3ab
+	 22, // 0x3ac
+	 22, // 0x3ad
+	 22, // 0x3ae
+	 22, // 0x3af
+	 22, // 0x3b0
+	 22, // 0x3b1
+	 22, // 0x3b2
+	 22, // 0x3b3
+	 22, // 0x3b4
+	 22, // 0x3b5
+	 22, // 0x3b6
+	 22, // 0x3b7
+	 22, // 0x3b8
+	 22, // 0x3b9
+	 22, // 0x3ba
+	 22, // 0x3bb
+	 22, // 0x3bc
+	 22, // 0x3bd
+	 22, // 0x3be
+	 22, // 0x3bf
+	 22, // 0x3c0
+	 22, // 0x3c1
+	 22, // 0x3c2
+	 22, // 0x3c3
+	 22, // 0x3c4
+	 22, // 0x3c5
+	 22, // 0x3c6
+	 22, // 0x3c7
+	 22, // 0x3c8
+	 22, // 0x3c9
+	 22, // 0x3ca
+	 22, // 0x3cb
+	 22, // 0x3cc
+	 22, // 0x3cd
+	 22, // 0x3ce
+	 22, // 0x3cf
+	 22, // 0x3d0
+	 22, // 0x3d1
+	 22, // 0x3d2
+	 22, // 0x3d3
+	 22, // 0x3d4
+	 22, // 0x3d5
+	 22, // 0x3d6
+	 22, // 0x3d7
+	 22, // 0x3d8
+	 22, // 0x3d9
+	 22, // 0x3da
+	 22, // 0x3db
+	 22, // 0x3dc
+	 22, // 0x3dd
+	 22, // 0x3de
+	 22, // 0x3df
+	 22, // 0x3e0
+	 22, // 0x3e1
+	 22, // 0x3e2
+	 22, // 0x3e3
+	 22, // 0x3e4
+	 22, // 0x3e5
+	 22, // 0x3e6
+	 22, // 0x3e7
+	 22, // 0x3e8
+	 22, // 0x3e9
+	 22, // 0x3ea
+	 22, // 0x3eb
+	 22, // 0x3ec
+	 22, // 0x3ed
+	 22, // 0x3ee
+	 22, // 0x3ef
+	 22, // 0x3f0
+	 22, // 0x3f1
+	 22, // 0x3f2
+	 22, // 0x3f3
+	 22, // 0x3f4
+	 22, // 0x3f5
+	 22, // 0x3f6
+	 22, // 0x3f7
+	 22, // 0x3f8
+	 22, // 0x3f9
+	 22, // 0x3fa
+	 22, // 0x3fb
+	 22, // 0x3fc
+	 22, // 0x3fd
+	 22, // 0x3fe
+	 47, // 0x3ff
+	 5, // 0x400
+	 5, // 0x401
+	 5, // 0x402
+	 5, // 0x403
+	 5, // 0x404
+	 5, // 0x405
+	 5, // 0x406
+	 5, // 0x407
+	 5, // 0x408
+	 5, // 0x409
+	 5, // 0x40a
+	 5, // 0x40b
+	 5, // 0x40c
+	 5, // 0x40d
+	 5, // 0x40e
+	 5, // 0x40f
+	 5, // 0x410
+	 5, // 0x411
+	 5, // 0x412
+	 5, // 0x413
+	 5, // 0x414
+	 5, // 0x415
+	 5, // 0x416
+	 5, // 0x417
+	 5, // 0x418
+	 5, // 0x419
+	 5, // 0x41a
+	 5, // 0x41b
+	 5, // 0x41c
+	 5, // 0x41d
+	 5, // 0x41e
+	 5, // 0x41f
+	 5, // 0x420
+	 5, // 0x421
+	 5, // 0x422
+	 5, // 0x423
+	 5, // 0x424
+	 5, // 0x425
+	 5, // 0x426
+	 5, // 0x427
+	 5, // 0x428
+	 5, // 0x429
+	 5, // 0x42a
+	 5, // 0x42b
+	 5, // 0x42c
+	 5, // 0x42d
+	 5, // 0x42e
+	 5, // 0x42f
+	 5, // 0x430
+	 5, // 0x431
+	 5, // 0x432
+	 5, // 0x433
+	 5, // 0x434
+	 5, // 0x435
+	 5, // 0x436
+	 5, // 0x437
+	 5, // 0x438
+	 5, // 0x439
+	 5, // 0x43a
+	 5, // 0x43b
+	 5, // 0x43c
+	 5, // 0x43d
+	 5, // 0x43e
+	 5, // 0x43f
+	 5, // 0x440
+	 5, // 0x441
+	 5, // 0x442
+	 5, // 0x443
+	 5, // 0x444
+	 5, // 0x445
+	 5, // 0x446
+	 5, // 0x447
+	 5, // 0x448
+	 5, // 0x449
+	 5, // 0x44a
+	 5, // 0x44b
+	 5, // 0x44c
+	 5, // 0x44d
+	 5, // 0x44e
+	 5, // 0x44f
+	 5, // 0x450
+	 5, // 0x451
+	 5, // 0x452
+	 5, // 0x453
+	 5, // 0x454
+	 5, // 0x455
+	 5, // 0x456
+	 5, // 0x457
+	 5, // 0x458
+	 5, // 0x459
+	 5, // 0x45a
+	 5, // 0x45b
+	 5, // 0x45c
+	 5, // 0x45d
+	 5, // 0x45e
+	 5, // 0x45f
+	 5, // 0x460
+	 5, // 0x461
+	 5, // 0x462
+	 5, // 0x463
+	 5, // 0x464
+	 5, // 0x465
+	 5, // 0x466
+	 5, // 0x467
+	 5, // 0x468
+	 5, // 0x469
+	 5, // 0x46a
+	 5, // 0x46b
+	 5, // 0x46c
+	 5, // 0x46d
+	 5, // 0x46e
+	 5, // 0x46f
+	 5, // 0x470
+	 5, // 0x471
+	 5, // 0x472
+	 5, // 0x473
+	 5, // 0x474
+	 5, // 0x475
+	 5, // 0x476
+	 5, // 0x477
+	 5, // 0x478
+	 5, // 0x479
+	 5, // 0x47a
+	 5, // 0x47b
+	 5, // 0x47c
+	 5, // 0x47d
+	 5, // 0x47e
+	 5, // 0x47f
+	 5, // 0x480
+	 5, // 0x481
+	 5, // 0x482
+	 5, // 0x483
+	 5, // 0x484
+	 5, // 0x485
+	 5, // 0x486
+	 5, // 0x487
+	 5, // 0x488
+	 5, // 0x489
+	 5, // 0x48a
+	 5, // 0x48b
+	 5, // 0x48c
+	 5, // 0x48d
+	 5, // 0x48e
+	 5, // 0x48f
+	 5, // 0x490
+	 5, // 0x491
+	 5, // 0x492
+	 5, // 0x493
+	 5, // 0x494
+	 5, // 0x495
+	 5, // 0x496
+	 5, // 0x497
+	 5, // 0x498
+	 5, // 0x499
+	 5, // 0x49a
+	 5, // 0x49b
+	 5, // 0x49c
+	 5, // 0x49d
+	 5, // 0x49e
+	 5, // 0x49f
+	 5, // 0x4a0
+	 5, // 0x4a1
+	 5, // 0x4a2
+	 5, // 0x4a3
+	 5, // 0x4a4
+	 5, // 0x4a5
+	 5, // 0x4a6
+	 5, // 0x4a7
+	 5, // 0x4a8
+	 5, // 0x4a9
+	 5, // 0x4aa
+	 5, // 0x4ab
+	 5, // 0x4ac
+	 5, // 0x4ad
+	 5, // 0x4ae
+	 5, // 0x4af
+	 5, // 0x4b0
+	 5, // 0x4b1
+	 5, // 0x4b2
+	 5, // 0x4b3
+	 5, // 0x4b4
+	 5, // 0x4b5
+	 5, // 0x4b6
+	 5, // 0x4b7
+	 5, // 0x4b8
+	 5, // 0x4b9
+	 5, // 0x4ba
+	 5, // 0x4bb
+	 5, // 0x4bc
+	 5, // 0x4bd
+	 5, // 0x4be
+	 5, // 0x4bf
+	 5, // 0x4c0
+	 5, // 0x4c1
+	 5, // 0x4c2
+	 5, // 0x4c3
+	 5, // 0x4c4
+	 5, // 0x4c5
+	 5, // 0x4c6
+	 5, // 0x4c7
+	 5, // 0x4c8
+	 5, // 0x4c9
+	 5, // 0x4ca
+	 5, // 0x4cb
+	 5, // 0x4cc
+	 5, // 0x4cd
+	 5, // 0x4ce
+	 5, // 0x4cf
+	 5, // 0x4d0
+	 5, // 0x4d1
+	 5, // 0x4d2
+	 5, // 0x4d3
+	 5, // 0x4d4
+	 5, // 0x4d5
+	 5, // 0x4d6
+	 5, // 0x4d7
+	 5, // 0x4d8
+	 5, // 0x4d9
+	 5, // 0x4da
+	 5, // 0x4db
+	 5, // 0x4dc
+	 5, // 0x4dd
+	 5, // 0x4de
+	 5, // 0x4df
+	 5, // 0x4e0
+	 5, // 0x4e1
+	 5, // 0x4e2
+	 5, // 0x4e3
+	 5, // 0x4e4
+	 5, // 0x4e5
+	 5, // 0x4e6
+	 5, // 0x4e7
+	 5, // 0x4e8
+	 5, // 0x4e9
+	 5, // 0x4ea
+	 5, // 0x4eb
+	 5, // 0x4ec
+	 5, // 0x4ed
+	 5, // 0x4ee
+	 5, // 0x4ef
+	 5, // 0x4f0
+	 5, // 0x4f1
+	 5, // 0x4f2
+	 5, // 0x4f3
+	 5, // 0x4f4
+	 5, // 0x4f5
+	 5, // 0x4f6
+	 5, // 0x4f7
+	 5, // 0x4f8
+	 5, // 0x4f9
+	 5, // 0x4fa
+	 5, // 0x4fb
+	 5, // 0x4fc
+	 5, // 0x4fd
+	 5, // 0x4fe
+	 5, // 0x4ff
+	 5, // 0x500
+	 5, // 0x501
+	 5, // 0x502
+	 5, // 0x503
+	 5, // 0x504
+	 5, // 0x505
+	 5, // 0x506
+	 5, // 0x507
+	 5, // 0x508
+	 5, // 0x509
+	 5, // 0x50a
+	 5, // 0x50b
+	 5, // 0x50c
+	 5, // 0x50d
+	 5, // 0x50e
+	 5, // 0x50f
+	 5, // 0x510
+	 5, // 0x511
+	 5, // 0x512
+	 5, // 0x513
+	 5, // 0x514
+	 5, // 0x515
+	 5, // 0x516
+	 5, // 0x517
+	 5, // 0x518
+	 5, // 0x519
+	 5, // 0x51a
+	 5, // 0x51b
+	 5, // 0x51c
+	 5, // 0x51d
+	 5, // 0x51e
+	 5, // 0x51f
+	 5, // 0x520
+	 5, // 0x521
+	 5, // 0x522
+	 5, // 0x523
+	 5, // 0x524
+	 5, // 0x525
+	 5, // 0x526
+	 5, // 0x527
+	 5, // 0x528
+	 5, // 0x529
+	 5, // 0x52a
+	 5, // 0x52b
+	 5, // 0x52c
+	 5, // 0x52d
+	 5, // 0x52e
+	 5, // 0x52f
+	 5, // 0x530
+	 5, // 0x531
+	 5, // 0x532
+	 5, // 0x533
+	 5, // 0x534
+	 5, // 0x535
+	 5, // 0x536
+	 5, // 0x537
+	 5, // 0x538
+	 5, // 0x539
+	 5, // 0x53a
+	 5, // 0x53b
+	 5, // 0x53c
+	 5, // 0x53d
+	 5, // 0x53e
+	 5, // 0x53f
+	 5, // 0x540
+	 5, // 0x541
+	 5, // 0x542
+	 5, // 0x543
+	 5, // 0x544
+	 5, // 0x545
+	 5, // 0x546
+	 5, // 0x547
+	 5, // 0x548
+	 5, // 0x549
+	 5, // 0x54a
+	 5, // 0x54b
+	 5, // 0x54c
+	 5, // 0x54d
+	 5, // 0x54e
+	 5, // 0x54f
+	 5, // 0x550
+	 5, // 0x551
+	 5, // 0x552
+	 5, // 0x553
+	 5, // 0x554
+	 5, // 0x555
+	 5, // 0x556
+	 5, // 0x557
+	 5, // 0x558
+	 5, // 0x559
+	 5, // 0x55a
+	 5, // 0x55b
+	 5, // 0x55c
+	 5, // 0x55d
+	 5, // 0x55e
+	 5, // 0x55f
+	 5, // 0x560
+	 5, // 0x561
+	 5, // 0x562
+	 5, // 0x563
+	 5, // 0x564
+	 5, // 0x565
+	 5, // 0x566
+	 5, // 0x567
+	 5, // 0x568
+	 5, // 0x569
+	 5, // 0x56a
+	 5, // 0x56b
+	 5, // 0x56c
+	 5, // 0x56d
+	 5, // 0x56e
+	 5, // 0x56f
+	 5, // 0x570
+	 5, // 0x571
+	 5, // 0x572
+	 5, // 0x573
+	 5, // 0x574
+	 5, // 0x575
+	 5, // 0x576
+	 5, // 0x577
+	 5, // 0x578
+	 5, // 0x579
+	 5, // 0x57a
+	 5, // 0x57b
+	 5, // 0x57c
+	 5, // 0x57d
+	 5, // 0x57e
+	 5, // 0x57f
+	 5, // 0x580
+	 5, // 0x581
+	 5, // 0x582
+	 5, // 0x583
+	 5, // 0x584
+	 5, // 0x585
+	 5, // 0x586
+	 5, // 0x587
+	 5, // 0x588
+	 5, // 0x589
+	 5, // 0x58a
+	 5, // 0x58b
+	 5, // 0x58c
+	 5, // 0x58d
+	 5, // 0x58e
+	 5, // 0x58f
+	 5, // 0x590
+	 5, // 0x591
+	 5, // 0x592
+	 5, // 0x593
+	 5, // 0x594
+	 5, // 0x595
+	 5, // 0x596
+	 5, // 0x597
+	 5, // 0x598
+	 5, // 0x599
+	 5, // 0x59a
+	 5, // 0x59b
+	 5, // 0x59c
+	 5, // 0x59d
+	 5, // 0x59e
+	 5, // 0x59f
+	 5, // 0x5a0
+	 5, // 0x5a1
+	 5, // 0x5a2
+	 5, // 0x5a3
+	 5, // 0x5a4
+	 5, // 0x5a5
+	 5, // 0x5a6
+	 5, // 0x5a7
+	 5, // 0x5a8
+	 5, // 0x5a9
+	 5, // 0x5aa
+	 5, // 0x5ab
+	 5, // 0x5ac
+	 5, // 0x5ad
+	 5, // 0x5ae
+	 5, // 0x5af
+	 5, // 0x5b0
+	 5, // 0x5b1
+	 5, // 0x5b2
+	 5, // 0x5b3
+	 5, // 0x5b4
+	 5, // 0x5b5
+	 5, // 0x5b6
+	 5, // 0x5b7
+	 5, // 0x5b8
+	 5, // 0x5b9
+	 5, // 0x5ba
+	 5, // 0x5bb
+	 5, // 0x5bc
+	 5, // 0x5bd
+	 5, // 0x5be
+	 5, // 0x5bf
+	 5, // 0x5c0
+	 5, // 0x5c1
+	 5, // 0x5c2
+	 5, // 0x5c3
+	 5, // 0x5c4
+	 5, // 0x5c5
+	 5, // 0x5c6
+	 5, // 0x5c7
+	 5, // 0x5c8
+	 5, // 0x5c9
+	 5, // 0x5ca
+	 5, // 0x5cb
+	 5, // 0x5cc
+	 5, // 0x5cd
+	 5, // 0x5ce
+	 5, // 0x5cf
+	 5, // 0x5d0
+	 5, // 0x5d1
+	 5, // 0x5d2
+	 5, // 0x5d3
+	 5, // 0x5d4
+	 5, // 0x5d5
+	 5, // 0x5d6
+	 5, // 0x5d7
+	 5, // 0x5d8
+	 5, // 0x5d9
+	 5, // 0x5da
+	 5, // 0x5db
+	 5, // 0x5dc
+	 5, // 0x5dd
+	 5, // 0x5de
+	 5, // 0x5df
+	 5, // 0x5e0
+	 5, // 0x5e1
+	 5, // 0x5e2
+	 5, // 0x5e3
+	 5, // 0x5e4
+	 5, // 0x5e5
+	 5, // 0x5e6
+	 5, // 0x5e7
+	 5, // 0x5e8
+	 5, // 0x5e9
+	 5, // 0x5ea
+	 5, // 0x5eb
+	 5, // 0x5ec
+	 5, // 0x5ed
+	 5, // 0x5ee
+	 5, // 0x5ef
+	 5, // 0x5f0
+	 5, // 0x5f1
+	 5, // 0x5f2
+	 5, // 0x5f3
+	 5, // 0x5f4
+	 5, // 0x5f5
+	 5, // 0x5f6
+	 5, // 0x5f7
+	 5, // 0x5f8
+	 5, // 0x5f9
+	 5, // 0x5fa
+	 5, // 0x5fb
+	 5, // 0x5fc
+	 5, // 0x5fd
+	 5, // 0x5fe
+	 5, // 0x5ff
+	 5, // 0x600
+	 5, // 0x601
+	 5, // 0x602
+	 5, // 0x603
+	 5, // 0x604
+	 5, // 0x605
+	 5, // 0x606
+	 5, // 0x607
+	 5, // 0x608
+	 5, // 0x609
+	 5, // 0x60a
+	 5, // 0x60b
+	 5, // 0x60c
+	 5, // 0x60d
+	 5, // 0x60e
+	 5, // 0x60f
+	 5, // 0x610
+	 5, // 0x611
+	 5, // 0x612
+	 5, // 0x613
+	 5, // 0x614
+	 5, // 0x615
+	 5, // 0x616
+	 5, // 0x617
+	 5, // 0x618
+	 5, // 0x619
+	 5, // 0x61a
+	 5, // 0x61b
+	 5, // 0x61c
+	 5, // 0x61d
+	 5, // 0x61e
+	 5, // 0x61f
+	 5, // 0x620
+	 5, // 0x621
+	 5, // 0x622
+	 5, // 0x623
+	 5, // 0x624
+	 5, // 0x625
+	 5, // 0x626
+	 5, // 0x627
+	 5, // 0x628
+	 5, // 0x629
+	 5, // 0x62a
+	 5, // 0x62b
+	 5, // 0x62c
+	 5, // 0x62d
+	 5, // 0x62e
+	 5, // 0x62f
+	 5, // 0x630
+	 5, // 0x631
+	 5, // 0x632
+	 5, // 0x633
+	 5, // 0x634
+	 5, // 0x635
+	 5, // 0x636
+	 5, // 0x637
+	 5, // 0x638
+	 5, // 0x639
+	 5, // 0x63a
+	 5, // 0x63b
+	 5, // 0x63c
+	 5, // 0x63d
+	 5, // 0x63e
+	 5, // 0x63f
+	 5, // 0x640
+	 5, // 0x641
+	 5, // 0x642
+	 5, // 0x643
+	 5, // 0x644
+	 5, // 0x645
+	 5, // 0x646
+	 5, // 0x647
+	 5, // 0x648
+	 5, // 0x649
+	 5, // 0x64a
+	 5, // 0x64b
+	 5, // 0x64c
+	 5, // 0x64d
+	 5, // 0x64e
+	 5, // 0x64f
+	 5, // 0x650
+	 5, // 0x651
+	 5, // 0x652
+	 5, // 0x653
+	 5, // 0x654
+	 5, // 0x655
+	 5, // 0x656
+	 5, // 0x657
+	 5, // 0x658
+	 5, // 0x659
+	 5, // 0x65a
+	 5, // 0x65b
+	 5, // 0x65c
+	 5, // 0x65d
+	 5, // 0x65e
+	 5, // 0x65f
+	 5, // 0x660
+	 5, // 0x661
+	 5, // 0x662
+	 5, // 0x663
+	 5, // 0x664
+	 5, // 0x665
+	 5, // 0x666
+	 5, // 0x667
+	 5, // 0x668
+	 5, // 0x669
+	 5, // 0x66a
+	 5, // 0x66b
+	 5, // 0x66c
+	 5, // 0x66d
+	 5, // 0x66e
+	 5, // 0x66f
+	 5, // 0x670
+	 5, // 0x671
+	 5, // 0x672
+	 5, // 0x673
+	 5, // 0x674
+	 5, // 0x675
+	 5, // 0x676
+	 5, // 0x677
+	 5, // 0x678
+	 5, // 0x679
+	 5, // 0x67a
+	 5, // 0x67b
+	 5, // 0x67c
+	 5, // 0x67d
+	 5, // 0x67e
+	 5, // 0x67f
+	 5, // 0x680
+	 5, // 0x681
+	 5, // 0x682
+	 5, // 0x683
+	 5, // 0x684
+	 5, // 0x685
+	 5, // 0x686
+	 5, // 0x687
+	 5, // 0x688
+	 5, // 0x689
+	 5, // 0x68a
+	 5, // 0x68b
+	 5, // 0x68c
+	 5, // 0x68d
+	 5, // 0x68e
+	 5, // 0x68f
+	 5, // 0x690
+	 5, // 0x691
+	 5, // 0x692
+	 5, // 0x693
+	 5, // 0x694
+	 5, // 0x695
+	 5, // 0x696
+	 5, // 0x697
+	 5, // 0x698
+	 5, // 0x699
+	 5, // 0x69a
+	 5, // 0x69b
+	 5, // 0x69c
+	 5, // 0x69d
+	 5, // 0x69e
+	 5, // 0x69f
+	 5, // 0x6a0
+	 5, // 0x6a1
+	 5, // 0x6a2
+	 5, // 0x6a3
+	 5, // 0x6a4
+	 5, // 0x6a5
+	 5, // 0x6a6
+	 5, // 0x6a7
+	 5, // 0x6a8
+	 5, // 0x6a9
+	 5, // 0x6aa
+	 5, // 0x6ab
+	 5, // 0x6ac
+	 5, // 0x6ad
+	 5, // 0x6ae
+	 5, // 0x6af
+	 5, // 0x6b0
+	 5, // 0x6b1
+	 5, // 0x6b2
+	 5, // 0x6b3
+	 5, // 0x6b4
+	 5, // 0x6b5
+	 5, // 0x6b6
+	 5, // 0x6b7
+	 5, // 0x6b8
+	 5, // 0x6b9
+	 5, // 0x6ba
+	 5, // 0x6bb
+	 5, // 0x6bc
+	 5, // 0x6bd
+	 5, // 0x6be
+	 5, // 0x6bf
+	 5, // 0x6c0
+	 5, // 0x6c1
+	 5, // 0x6c2
+	 5, // 0x6c3
+	 5, // 0x6c4
+	 5, // 0x6c5
+	 5, // 0x6c6
+	 5, // 0x6c7
+	 5, // 0x6c8
+	 5, // 0x6c9
+	 5, // 0x6ca
+	 5, // 0x6cb
+	 5, // 0x6cc
+	 5, // 0x6cd
+	 5, // 0x6ce
+	 5, // 0x6cf
+	 5, // 0x6d0
+	 5, // 0x6d1
+	 5, // 0x6d2
+	 5, // 0x6d3
+	 5, // 0x6d4
+	 5, // 0x6d5
+	 5, // 0x6d6
+	 5, // 0x6d7
+	 5, // 0x6d8
+	 5, // 0x6d9
+	 5, // 0x6da
+	 5, // 0x6db
+	 5, // 0x6dc
+	 5, // 0x6dd
+	 5, // 0x6de
+	 5, // 0x6df
+	 5, // 0x6e0
+	 5, // 0x6e1
+	 5, // 0x6e2
+	 5, // 0x6e3
+	 5, // 0x6e4
+	 5, // 0x6e5
+	 5, // 0x6e6
+	 5, // 0x6e7
+	 5, // 0x6e8
+	 5, // 0x6e9
+	 5, // 0x6ea
+	 5, // 0x6eb
+	 5, // 0x6ec
+	 5, // 0x6ed
+	 5, // 0x6ee
+	 5, // 0x6ef
+	 5, // 0x6f0
+	 5, // 0x6f1
+	 5, // 0x6f2
+	 5, // 0x6f3
+	 5, // 0x6f4
+	 5, // 0x6f5
+	 5, // 0x6f6
+	 5, // 0x6f7
+	 5, // 0x6f8
+	 5, // 0x6f9
+	 5, // 0x6fa
+	 5, // 0x6fb
+	 5, // 0x6fc
+	 5, // 0x6fd
+	 5, // 0x6fe
+	 5, // 0x6ff
+	 5, // 0x700
+	 5, // 0x701
+	 5, // 0x702
+	 5, // 0x703
+	 5, // 0x704
+	 5, // 0x705
+	 5, // 0x706
+	 5, // 0x707
+	 5, // 0x708
+	 5, // 0x709
+	 5, // 0x70a
+	 5, // 0x70b
+	 5, // 0x70c
+	 5, // 0x70d
+	 5, // 0x70e
+	 5, // 0x70f
+	 5, // 0x710
+	 5, // 0x711
+	 5, // 0x712
+	 5, // 0x713
+	 5, // 0x714
+	 5, // 0x715
+	 5, // 0x716
+	 5, // 0x717
+	 5, // 0x718
+	 5, // 0x719
+	 5, // 0x71a
+	 5, // 0x71b
+	 5, // 0x71c
+	 5, // 0x71d
+	 5, // 0x71e
+	 5, // 0x71f
+	 5, // 0x720
+	 5, // 0x721
+	 5, // 0x722
+	 5, // 0x723
+	 5, // 0x724
+	 5, // 0x725
+	 5, // 0x726
+	 5, // 0x727
+	 5, // 0x728
+	 5, // 0x729
+	 5, // 0x72a
+	 5, // 0x72b
+	 5, // 0x72c
+	 5, // 0x72d
+	 5, // 0x72e
+	 5, // 0x72f
+	 5, // 0x730
+	 5, // 0x731
+	 5, // 0x732
+	 5, // 0x733
+	 5, // 0x734
+	 5, // 0x735
+	 5, // 0x736
+	 5, // 0x737
+	 5, // 0x738
+	 5, // 0x739
+	 5, // 0x73a
+	 5, // 0x73b
+	 5, // 0x73c
+	 5, // 0x73d
+	 5, // 0x73e
+	 5, // 0x73f
+	 5, // 0x740
+	 5, // 0x741
+	 5, // 0x742
+	 5, // 0x743
+	 5, // 0x744
+	 5, // 0x745
+	 5, // 0x746
+	 5, // 0x747
+	 5, // 0x748
+	 5, // 0x749
+	 5, // 0x74a
+	 5, // 0x74b
+	 5, // 0x74c
+	 5, // 0x74d
+	 5, // 0x74e
+	 5, // 0x74f
+	 5, // 0x750
+	 5, // 0x751
+	 5, // 0x752
+	 5, // 0x753
+	 5, // 0x754
+	 5, // 0x755
+	 5, // 0x756
+	 5, // 0x757
+	 5, // 0x758
+	 5, // 0x759
+	 5, // 0x75a
+	 5, // 0x75b
+	 5, // 0x75c
+	 5, // 0x75d
+	 5, // 0x75e
+	 5, // 0x75f
+	 5, // 0x760
+	 5, // 0x761
+	 5, // 0x762
+	 5, // 0x763
+	 5, // 0x764
+	 5, // 0x765
+	 5, // 0x766
+	 5, // 0x767
+	 5, // 0x768
+	 5, // 0x769
+	 5, // 0x76a
+	 5, // 0x76b
+	 5, // 0x76c
+	 5, // 0x76d
+	 5, // 0x76e
+	 5, // 0x76f
+	 5, // 0x770
+	 5, // 0x771
+	 5, // 0x772
+	 5, // 0x773
+	 5, // 0x774
+	 5, // 0x775
+	 5, // 0x776
+	 5, // 0x777
+	 5, // 0x778
+	 5, // 0x779
+	 5, // 0x77a
+	 5, // 0x77b
+	 5, // 0x77c
+	 5, // 0x77d
+	 5, // 0x77e
+	 5, // 0x77f
+	 5, // 0x780
+	 5, // 0x781
+	 5, // 0x782
+	 5, // 0x783
+	 5, // 0x784
+	 5, // 0x785
+	 5, // 0x786
+	 5, // 0x787
+	 5, // 0x788
+	 5, // 0x789
+	 5, // 0x78a
+	 5, // 0x78b
+	 5, // 0x78c
+	 5, // 0x78d
+	 5, // 0x78e
+	 5, // 0x78f
+	 5, // 0x790
+	 5, // 0x791
+	 5, // 0x792
+	 5, // 0x793
+	 5, // 0x794
+	 5, // 0x795
+	 5, // 0x796
+	 5, // 0x797
+	 5, // 0x798
+	 5, // 0x799
+	 5, // 0x79a
+	 5, // 0x79b
+	 5, // 0x79c
+	 5, // 0x79d
+	 5, // 0x79e
+	 5, // 0x79f
+	 5, // 0x7a0
+	 5, // 0x7a1
+	 5, // 0x7a2
+	 5, // 0x7a3
+	 5, // 0x7a4
+	 5, // 0x7a5
+	 5, // 0x7a6
+	 5, // 0x7a7
+	 5, // 0x7a8
+	 5, // 0x7a9
+	 5, // 0x7aa
+	 5, // 0x7ab
+	 5, // 0x7ac
+	 5, // 0x7ad
+	 5, // 0x7ae
+	 5, // 0x7af
+	 5, // 0x7b0
+	 5, // 0x7b1
+	 5, // 0x7b2
+	 5, // 0x7b3
+	 5, // 0x7b4
+	 5, // 0x7b5
+	 5, // 0x7b6
+	 5, // 0x7b7
+	 5, // 0x7b8
+	 5, // 0x7b9
+	 5, // 0x7ba
+	 5, // 0x7bb
+	 5, // 0x7bc
+	 5, // 0x7bd
+	 5, // 0x7be
+	 5, // 0x7bf
+	 5, // 0x7c0
+	 5, // 0x7c1
+	 5, // 0x7c2
+	 5, // 0x7c3
+	 5, // 0x7c4
+	 5, // 0x7c5
+	 5, // 0x7c6
+	 5, // 0x7c7
+	 5, // 0x7c8
+	 5, // 0x7c9
+	 5, // 0x7ca
+	 5, // 0x7cb
+	 5, // 0x7cc
+	 5, // 0x7cd
+	 5, // 0x7ce
+	 5, // 0x7cf
+	 5, // 0x7d0
+	 5, // 0x7d1
+	 5, // 0x7d2
+	 5, // 0x7d3
+	 5, // 0x7d4
+	 5, // 0x7d5
+	 5, // 0x7d6
+	 5, // 0x7d7
+	 5, // 0x7d8
+	 5, // 0x7d9
+	 5, // 0x7da
+	 5, // 0x7db
+	 5, // 0x7dc
+	 5, // 0x7dd
+	 5, // 0x7de
+	 5, // 0x7df
+	 5, // 0x7e0
+	 5, // 0x7e1
+	 5, // 0x7e2
+	 5, // 0x7e3
+	 5, // 0x7e4
+	 5, // 0x7e5
+	 5, // 0x7e6
+	 5, // 0x7e7
+	 5, // 0x7e8
+	 5, // 0x7e9
+	 5, // 0x7ea
+	 5, // 0x7eb
+	 5, // 0x7ec
+	 5, // 0x7ed
+	 5, // 0x7ee
+	 5, // 0x7ef
+	 5, // 0x7f0
+	 5, // 0x7f1
+	 5, // 0x7f2
+	 5, // 0x7f3
+	 5, // 0x7f4
+	 5, // 0x7f5
+	 5, // 0x7f6
+	 5, // 0x7f7
+	 5, // 0x7f8
+	 5, // 0x7f9
+	 5, // 0x7fa
+	 5, // 0x7fb
+	 5, // 0x7fc
+	 5, // 0x7fd
+	 5, // 0x7fe
+	 5, // 0x7ff
+	 5, // 0x800
+	 5, // 0x801
+	 5, // 0x802
+	 5, // 0x803
+	 5, // 0x804
+	 5, // 0x805
+	 5, // 0x806
+	 5, // 0x807
+	 5, // 0x808
+	 5, // 0x809
+	 5, // 0x80a
+	 5, // 0x80b
+	 5, // 0x80c
+	 5, // 0x80d
+	 5, // 0x80e
+	 5, // 0x80f
+	 5, // 0x810
+	 5, // 0x811
+	 5, // 0x812
+	 5, // 0x813
+	 5, // 0x814
+	 5, // 0x815
+	 5, // 0x816
+	 5, // 0x817
+	 5, // 0x818
+	 5, // 0x819
+	 5, // 0x81a
+	 5, // 0x81b
+	 5, // 0x81c
+	 5, // 0x81d
+	 5, // 0x81e
+	 5, // 0x81f
+	 5, // 0x820
+	 5, // 0x821
+	 5, // 0x822
+	 5, // 0x823
+	 5, // 0x824
+	 5, // 0x825
+	 5, // 0x826
+	 5, // 0x827
+	 5, // 0x828
+	 5, // 0x829
+	 5, // 0x82a
+	 5, // 0x82b
+	 5, // 0x82c
+	 5, // 0x82d
+	 5, // 0x82e
+	 5, // 0x82f
+	 5, // 0x830
+	 5, // 0x831
+	 5, // 0x832
+	 5, // 0x833
+	 5, // 0x834
+	 5, // 0x835
+	 5, // 0x836
+	 5, // 0x837
+	 5, // 0x838
+	 5, // 0x839
+	 5, // 0x83a
+	 5, // 0x83b
+	 5, // 0x83c
+	 5, // 0x83d
+	 5, // 0x83e
+	 5, // 0x83f
+	 5, // 0x840
+	 5, // 0x841
+	 5, // 0x842
+	 5, // 0x843
+	 5, // 0x844
+	 5, // 0x845
+	 5, // 0x846
+	 5, // 0x847
+	 5, // 0x848
+	 5, // 0x849
+	 5, // 0x84a
+	 5, // 0x84b
+	 5, // 0x84c
+	 5, // 0x84d
+	 5, // 0x84e
+	 5, // 0x84f
+	 5, // 0x850
+	 5, // 0x851
+	 5, // 0x852
+	 5, // 0x853
+	 5, // 0x854
+	 5, // 0x855
+	 5, // 0x856
+	 5, // 0x857
+	 5, // 0x858
+	 5, // 0x859
+	 5, // 0x85a
+	 5, // 0x85b
+	 5, // 0x85c
+	 5, // 0x85d
+	 5, // 0x85e
+	 5, // 0x85f
+	 5, // 0x860
+	 5, // 0x861
+	 5, // 0x862
+	 5, // 0x863
+	 5, // 0x864
+	 5, // 0x865
+	 5, // 0x866
+	 5, // 0x867
+	 5, // 0x868
+	 5, // 0x869
+	 5, // 0x86a
+	 5, // 0x86b
+	 5, // 0x86c
+	 5, // 0x86d
+	 5, // 0x86e
+	 5, // 0x86f
+	 5, // 0x870
+	 5, // 0x871
+	 5, // 0x872
+	 5, // 0x873
+	 5, // 0x874
+	 5, // 0x875
+	 5, // 0x876
+	 5, // 0x877
+	 5, // 0x878
+	 5, // 0x879
+	 5, // 0x87a
+	 5, // 0x87b
+	 5, // 0x87c
+	 5, // 0x87d
+	 5, // 0x87e
+	 5, // 0x87f
+	 5, // 0x880
+	 5, // 0x881
+	 5, // 0x882
+	 5, // 0x883
+	 5, // 0x884
+	 5, // 0x885
+	 5, // 0x886
+	 5, // 0x887
+	 5, // 0x888
+	 5, // 0x889
+	 5, // 0x88a
+	 5, // 0x88b
+	 5, // 0x88c
+	 5, // 0x88d
+	 5, // 0x88e
+	 5, // 0x88f
+	 5, // 0x890
+	 5, // 0x891
+	 5, // 0x892
+	 5, // 0x893
+	 5, // 0x894
+	 5, // 0x895
+	 5, // 0x896
+	 5, // 0x897
+	 5, // 0x898
+	 5, // 0x899
+	 5, // 0x89a
+	 5, // 0x89b
+	 5, // 0x89c
+	 5, // 0x89d
+	 5, // 0x89e
+	 5, // 0x89f
+	 5, // 0x8a0
+	 5, // 0x8a1
+	 5, // 0x8a2
+	 5, // 0x8a3
+	 5, // 0x8a4
+	 5, // 0x8a5
+	 5, // 0x8a6
+	 5, // 0x8a7
+	 5, // 0x8a8
+	 5, // 0x8a9
+	 5, // 0x8aa
+	 5, // 0x8ab
+	 5, // 0x8ac
+	 5, // 0x8ad
+	 5, // 0x8ae
+	 5, // 0x8af
+	 5, // 0x8b0
+	 5, // 0x8b1
+	 5, // 0x8b2
+	 5, // 0x8b3
+	 5, // 0x8b4
+	 5, // 0x8b5
+	 5, // 0x8b6
+	 5, // 0x8b7
+	 5, // 0x8b8
+	 5, // 0x8b9
+	 5, // 0x8ba
+	 5, // 0x8bb
+	 5, // 0x8bc
+	 5, // 0x8bd
+	 5, // 0x8be
+	 5, // 0x8bf
+	 5, // 0x8c0
+	 5, // 0x8c1
+	 5, // 0x8c2
+	 5, // 0x8c3
+	 5, // 0x8c4
+	 5, // 0x8c5
+	 5, // 0x8c6
+	 5, // 0x8c7
+	 5, // 0x8c8
+	 5, // 0x8c9
+	 5, // 0x8ca
+	 5, // 0x8cb
+	 5, // 0x8cc
+	 5, // 0x8cd
+	 5, // 0x8ce
+	 5, // 0x8cf
+	 5, // 0x8d0
+	 5, // 0x8d1
+	 5, // 0x8d2
+	 5, // 0x8d3
+	 5, // 0x8d4
+	 5, // 0x8d5
+	 5, // 0x8d6
+	 5, // 0x8d7
+	 5, // 0x8d8
+	 5, // 0x8d9
+	 5, // 0x8da
+	 5, // 0x8db
+	 5, // 0x8dc
+	 5, // 0x8dd
+	 5, // 0x8de
+	 5, // 0x8df
+	 5, // 0x8e0
+	 5, // 0x8e1
+	 5, // 0x8e2
+	 5, // 0x8e3
+	 5, // 0x8e4
+	 5, // 0x8e5
+	 5, // 0x8e6
+	 5, // 0x8e7
+	 5, // 0x8e8
+	 5, // 0x8e9
+	 5, // 0x8ea
+	 5, // 0x8eb
+	 5, // 0x8ec
+	 5, // 0x8ed
+	 5, // 0x8ee
+	 5, // 0x8ef
+	 5, // 0x8f0
+	 5, // 0x8f1
+	 5, // 0x8f2
+	 5, // 0x8f3
+	 5, // 0x8f4
+	 5, // 0x8f5
+	 5, // 0x8f6
+	 5, // 0x8f7
+	 5, // 0x8f8
+	 5, // 0x8f9
+	 5, // 0x8fa
+	 5, // 0x8fb
+	 5, // 0x8fc
+	 5, // 0x8fd
+	 5, // 0x8fe
+	 5, // 0x8ff
+	 5, // 0x900
+	 5, // 0x901
+	 5, // 0x902
+	 5, // 0x903
+	 5, // 0x904
+	 5, // 0x905
+	 5, // 0x906
+	 5, // 0x907
+	 5, // 0x908
+	 5, // 0x909
+	 5, // 0x90a
+	 5, // 0x90b
+	 5, // 0x90c
+	 5, // 0x90d
+	 5, // 0x90e
+	 5, // 0x90f
+	 5, // 0x910
+	 5, // 0x911
+	 5, // 0x912
+	 5, // 0x913
+	 5, // 0x914
+	 5, // 0x915
+	 5, // 0x916
+	 5, // 0x917
+	 5, // 0x918
+	 5, // 0x919
+	 5, // 0x91a
+	 5, // 0x91b
+	 5, // 0x91c
+	 5, // 0x91d
+	 5, // 0x91e
+	 5, // 0x91f
+	 5, // 0x920
+	 5, // 0x921
+	 5, // 0x922
+	 5, // 0x923
+	 5, // 0x924
+	 5, // 0x925
+	 5, // 0x926
+	 5, // 0x927
+	 5, // 0x928
+	 5, // 0x929
+	 5, // 0x92a
+	 5, // 0x92b
+	 5, // 0x92c
+	 5, // 0x92d
+	 5, // 0x92e
+	 5, // 0x92f
+	 5, // 0x930
+	 5, // 0x931
+	 5, // 0x932
+	 5, // 0x933
+	 5, // 0x934
+	 5, // 0x935
+	 5, // 0x936
+	 5, // 0x937
+	 5, // 0x938
+	 5, // 0x939
+	 5, // 0x93a
+	 5, // 0x93b
+	 5, // 0x93c
+	 5, // 0x93d
+	 5, // 0x93e
+	 5, // 0x93f
+	 5, // 0x940
+	 5, // 0x941
+	 5, // 0x942
+	 5, // 0x943
+	 5, // 0x944
+	 5, // 0x945
+	 5, // 0x946
+	 5, // 0x947
+	 5, // 0x948
+	 5, // 0x949
+	 5, // 0x94a
+	 5, // 0x94b
+	 5, // 0x94c
+	 5, // 0x94d
+	 5, // 0x94e
+	 5, // 0x94f
+	 5, // 0x950
+	 5, // 0x951
+	 5, // 0x952
+	 5, // 0x953
+	 5, // 0x954
+	 5, // 0x955
+	 5, // 0x956
+	 5, // 0x957
+	 5, // 0x958
+	 5, // 0x959
+	 5, // 0x95a
+	 5, // 0x95b
+	 5, // 0x95c
+	 5, // 0x95d
+	 5, // 0x95e
+	 5, // 0x95f
+	 5, // 0x960
+	 5, // 0x961
+	 5, // 0x962
+	 5, // 0x963
+	 5, // 0x964
+	 5, // 0x965
+	 5, // 0x966
+	 5, // 0x967
+	 5, // 0x968
+	 5, // 0x969
+	 5, // 0x96a
+	 5, // 0x96b
+	 5, // 0x96c
+	 5, // 0x96d
+	 5, // 0x96e
+	 5, // 0x96f
+	 5, // 0x970
+	 5, // 0x971
+	 5, // 0x972
+	 5, // 0x973
+	 5, // 0x974
+	 5, // 0x975
+	 5, // 0x976
+	 5, // 0x977
+	 5, // 0x978
+	 5, // 0x979
+	 5, // 0x97a
+	 5, // 0x97b
+	 5, // 0x97c
+	 5, // 0x97d
+	 5, // 0x97e
+	 5, // 0x97f
+	 5, // 0x980
+	 5, // 0x981
+	 5, // 0x982
+	 5, // 0x983
+	 5, // 0x984
+	 5, // 0x985
+	 5, // 0x986
+	 5, // 0x987
+	 5, // 0x988
+	 5, // 0x989
+	 5, // 0x98a
+	 5, // 0x98b
+	 5, // 0x98c
+	 5, // 0x98d
+	 5, // 0x98e
+	 5, // 0x98f
+	 5, // 0x990
+	 5, // 0x991
+	 5, // 0x992
+	 5, // 0x993
+	 5, // 0x994
+	 5, // 0x995
+	 5, // 0x996
+	 5, // 0x997
+	 5, // 0x998
+	 5, // 0x999
+	 5, // 0x99a
+	 5, // 0x99b
+	 5, // 0x99c
+	 5, // 0x99d
+	 5, // 0x99e
+	 5, // 0x99f
+	 5, // 0x9a0
+	 5, // 0x9a1
+	 5, // 0x9a2
+	 5, // 0x9a3
+	 5, // 0x9a4
+	 5, // 0x9a5
+	 5, // 0x9a6
+	 5, // 0x9a7
+	 5, // 0x9a8
+	 5, // 0x9a9
+	 5, // 0x9aa
+	 5, // 0x9ab
+	 5, // 0x9ac
+	 5, // 0x9ad
+	 5, // 0x9ae
+	 5, // 0x9af
+	 5, // 0x9b0
+	 5, // 0x9b1
+	 5, // 0x9b2
+	 5, // 0x9b3
+	 5, // 0x9b4
+	 5, // 0x9b5
+	 5, // 0x9b6
+	 5, // 0x9b7
+	 5, // 0x9b8
+	 5, // 0x9b9
+	 5, // 0x9ba
+	 5, // 0x9bb
+	 5, // 0x9bc
+	 5, // 0x9bd
+	 5, // 0x9be
+	 5, // 0x9bf
+	 5, // 0x9c0
+	 5, // 0x9c1
+	 5, // 0x9c2
+	 5, // 0x9c3
+	 5, // 0x9c4
+	 5, // 0x9c5
+	 5, // 0x9c6
+	 5, // 0x9c7
+	 5, // 0x9c8
+	 5, // 0x9c9
+	 5, // 0x9ca
+	 5, // 0x9cb
+	 5, // 0x9cc
+	 5, // 0x9cd
+	 5, // 0x9ce
+	 5, // 0x9cf
+	 5, // 0x9d0
+	 5, // 0x9d1
+	 5, // 0x9d2
+	 5, // 0x9d3
+	 5, // 0x9d4
+	 5, // 0x9d5
+	 5, // 0x9d6
+	 5, // 0x9d7
+	 5, // 0x9d8
+	 5, // 0x9d9
+	 5, // 0x9da
+	 5, // 0x9db
+	 5, // 0x9dc
+	 5, // 0x9dd
+	 5, // 0x9de
+	 5, // 0x9df
+	 5, // 0x9e0
+	 5, // 0x9e1
+	 5, // 0x9e2
+	 5, // 0x9e3
+	 5, // 0x9e4
+	 5, // 0x9e5
+	 5, // 0x9e6
+	 5, // 0x9e7
+	 5, // 0x9e8
+	 5, // 0x9e9
+	 5, // 0x9ea
+	 5, // 0x9eb
+	 5, // 0x9ec
+	 5, // 0x9ed
+	 5, // 0x9ee
+	 5, // 0x9ef
+	 5, // 0x9f0
+	 5, // 0x9f1
+	 5, // 0x9f2
+	 5, // 0x9f3
+	 5, // 0x9f4
+	 5, // 0x9f5
+	 5, // 0x9f6
+	 5, // 0x9f7
+	 5, // 0x9f8
+	 5, // 0x9f9
+	 5, // 0x9fa
+	 5, // 0x9fb
+	 5, // 0x9fc
+	 5, // 0x9fd
+	 5, // 0x9fe
+	 5, // 0x9ff
+	 5, // 0xa00
+	 5, // 0xa01
+	 5, // 0xa02
+	 5, // 0xa03
+	 5, // 0xa04
+	 5, // 0xa05
+	 5, // 0xa06
+	 5, // 0xa07
+	 5, // 0xa08
+	 5, // 0xa09
+	 5, // 0xa0a
+	 5, // 0xa0b
+	 5, // 0xa0c
+	 5, // 0xa0d
+	 5, // 0xa0e
+	 5, // 0xa0f
+	 5, // 0xa10
+	 5, // 0xa11
+	 5, // 0xa12
+	 5, // 0xa13
+	 5, // 0xa14
+	 5, // 0xa15
+	 5, // 0xa16
+	 5, // 0xa17
+	 5, // 0xa18
+	 5, // 0xa19
+	 5, // 0xa1a
+	 5, // 0xa1b
+	 5, // 0xa1c
+	 5, // 0xa1d
+	 5, // 0xa1e
+	 5, // 0xa1f
+	 5, // 0xa20
+	 5, // 0xa21
+	 5, // 0xa22
+	 5, // 0xa23
+	 5, // 0xa24
+	 5, // 0xa25
+	 5, // 0xa26
+	 5, // 0xa27
+	 5, // 0xa28
+	 5, // 0xa29
+	 5, // 0xa2a
+	 5, // 0xa2b
+	 5, // 0xa2c
+	 5, // 0xa2d
+	 5, // 0xa2e
+	 5, // 0xa2f
+	 5, // 0xa30
+	 5, // 0xa31
+	 5, // 0xa32
+	 5, // 0xa33
+	 5, // 0xa34
+	 5, // 0xa35
+	 5, // 0xa36
+	 5, // 0xa37
+	 5, // 0xa38
+	 5, // 0xa39
+	 5, // 0xa3a
+	 5, // 0xa3b
+	 5, // 0xa3c
+	 5, // 0xa3d
+	 5, // 0xa3e
+	 5, // 0xa3f
+	 5, // 0xa40
+	 5, // 0xa41
+	 5, // 0xa42
+	 5, // 0xa43
+	 5, // 0xa44
+	 5, // 0xa45
+	 5, // 0xa46
+	 5, // 0xa47
+	 5, // 0xa48
+	 5, // 0xa49
+	 5, // 0xa4a
+	 5, // 0xa4b
+	 5, // 0xa4c
+	 5, // 0xa4d
+	 5, // 0xa4e
+	 5, // 0xa4f
+	 5, // 0xa50
+	 5, // 0xa51
+	 5, // 0xa52
+	 5, // 0xa53
+	 5, // 0xa54
+	 5, // 0xa55
+	 5, // 0xa56
+	 5, // 0xa57
+	 5, // 0xa58
+	 5, // 0xa59
+	 5, // 0xa5a
+	 5, // 0xa5b
+	 5, // 0xa5c
+	 5, // 0xa5d
+	 5, // 0xa5e
+	 5, // 0xa5f
+	 5, // 0xa60
+	 5, // 0xa61
+	 5, // 0xa62
+	 5, // 0xa63
+	 5, // 0xa64
+	 5, // 0xa65
+	 5, // 0xa66
+	 5, // 0xa67
+	 5, // 0xa68
+	 5, // 0xa69
+	 5, // 0xa6a
+	 5, // 0xa6b
+	 5, // 0xa6c
+	 5, // 0xa6d
+	 5, // 0xa6e
+	 5, // 0xa6f
+	 5, // 0xa70
+	 5, // 0xa71
+	 5, // 0xa72
+	 5, // 0xa73
+	 5, // 0xa74
+	 5, // 0xa75
+	 5, // 0xa76
+	 5, // 0xa77
+	 5, // 0xa78
+	 5, // 0xa79
+	 5, // 0xa7a
+	 5, // 0xa7b
+	 5, // 0xa7c
+	 5, // 0xa7d
+	 5, // 0xa7e
+	 5, // 0xa7f
+	 5, // 0xa80
+	 5, // 0xa81
+	 5, // 0xa82
+	 5, // 0xa83
+	 5, // 0xa84
+	 5, // 0xa85
+	 5, // 0xa86
+	 5, // 0xa87
+	 5, // 0xa88
+	 5, // 0xa89
+	 5, // 0xa8a
+	 5, // 0xa8b
+	 5, // 0xa8c
+	 5, // 0xa8d
+	 5, // 0xa8e
+	 5, // 0xa8f
+	 5, // 0xa90
+	 5, // 0xa91
+	 5, // 0xa92
+	 5, // 0xa93
+	 5, // 0xa94
+	 5, // 0xa95
+	 5, // 0xa96
+	 5, // 0xa97
+	 5, // 0xa98
+	 5, // 0xa99
+	 5, // 0xa9a
+	 5, // 0xa9b
+	 5, // 0xa9c
+	 5, // 0xa9d
+	 5, // 0xa9e
+	 5, // 0xa9f
+	 5, // 0xaa0
+	 5, // 0xaa1
+	 5, // 0xaa2
+	 5, // 0xaa3
+	 5, // 0xaa4
+	 5, // 0xaa5
+	 5, // 0xaa6
+	 5, // 0xaa7
+	 5, // 0xaa8
+	 5, // 0xaa9
+	 5, // 0xaaa
+	 5, // 0xaab
+	 5, // 0xaac
+	 5, // 0xaad
+	 5, // 0xaae
+	 5, // 0xaaf
+	 5, // 0xab0
+	 5, // 0xab1
+	 5, // 0xab2
+	 5, // 0xab3
+	 5, // 0xab4
+	 5, // 0xab5
+	 5, // 0xab6
+	 5, // 0xab7
+	 5, // 0xab8
+	 5, // 0xab9
+	 5, // 0xaba
+	 5, // 0xabb
+	 5, // 0xabc
+	 5, // 0xabd
+	 5, // 0xabe
+	 5, // 0xabf
+	 5, // 0xac0
+	 5, // 0xac1
+	 5, // 0xac2
+	 5, // 0xac3
+	 5, // 0xac4
+	 5, // 0xac5
+	 5, // 0xac6
+	 5, // 0xac7
+	 5, // 0xac8
+	 5, // 0xac9
+	 5, // 0xaca
+	 5, // 0xacb
+	 5, // 0xacc
+	 5, // 0xacd
+	 5, // 0xace
+	 5, // 0xacf
+	 5, // 0xad0
+	 5, // 0xad1
+	 5, // 0xad2
+	 5, // 0xad3
+	 5, // 0xad4
+	 5, // 0xad5
+	 5, // 0xad6
+	 5, // 0xad7
+	 5, // 0xad8
+	 5, // 0xad9
+	 5, // 0xada
+	 5, // 0xadb
+	 5, // 0xadc
+	 5, // 0xadd
+	 5, // 0xade
+	 5, // 0xadf
+	 5, // 0xae0
+	 5, // 0xae1
+	 5, // 0xae2
+	 5, // 0xae3
+	 5, // 0xae4
+	 5, // 0xae5
+	 5, // 0xae6
+	 5, // 0xae7
+	 5, // 0xae8
+	 5, // 0xae9
+	 5, // 0xaea
+	 5, // 0xaeb
+	 5, // 0xaec
+	 5, // 0xaed
+	 5, // 0xaee
+	 5, // 0xaef
+	 5, // 0xaf0
+	 5, // 0xaf1
+	 5, // 0xaf2
+	 5, // 0xaf3
+	 5, // 0xaf4
+	 5, // 0xaf5
+	 5, // 0xaf6
+	 5, // 0xaf7
+	 5, // 0xaf8
+	 5, // 0xaf9
+	 5, // 0xafa
+	 5, // 0xafb
+	 5, // 0xafc
+	 5, // 0xafd
+	 5, // 0xafe
+	 5, // 0xaff
+	 5, // 0xb00
+	 5, // 0xb01
+	 5, // 0xb02
+	 5, // 0xb03
+	 5, // 0xb04
+	 5, // 0xb05
+	 5, // 0xb06
+	 5, // 0xb07
+	 5, // 0xb08
+	 5, // 0xb09
+	 5, // 0xb0a
+	 5, // 0xb0b
+	 5, // 0xb0c
+	 5, // 0xb0d
+	 5, // 0xb0e
+	 5, // 0xb0f
+	 5, // 0xb10
+	 5, // 0xb11
+	 5, // 0xb12
+	 5, // 0xb13
+	 5, // 0xb14
+	 5, // 0xb15
+	 5, // 0xb16
+	 5, // 0xb17
+	 5, // 0xb18
+	 5, // 0xb19
+	 5, // 0xb1a
+	 5, // 0xb1b
+	 5, // 0xb1c
+	 5, // 0xb1d
+	 5, // 0xb1e
+	 5, // 0xb1f
+	 5, // 0xb20
+	 5, // 0xb21
+	 5, // 0xb22
+	 5, // 0xb23
+	 5, // 0xb24
+	 5, // 0xb25
+	 5, // 0xb26
+	 5, // 0xb27
+	 5, // 0xb28
+	 5, // 0xb29
+	 5, // 0xb2a
+	 5, // 0xb2b
+	 5, // 0xb2c
+	 5, // 0xb2d
+	 5, // 0xb2e
+	 5, // 0xb2f
+	 5, // 0xb30
+	 5, // 0xb31
+	 5, // 0xb32
+	 5, // 0xb33
+	 5, // 0xb34
+	 5, // 0xb35
+	 5, // 0xb36
+	 5, // 0xb37
+	 5, // 0xb38
+	 5, // 0xb39
+	 5, // 0xb3a
+	 5, // 0xb3b
+	 5, // 0xb3c
+	 5, // 0xb3d
+	 5, // 0xb3e
+	 5, // 0xb3f
+	 5, // 0xb40
+	 5, // 0xb41
+	 5, // 0xb42
+	 5, // 0xb43
+	 5, // 0xb44
+	 5, // 0xb45
+	 5, // 0xb46
+	 5, // 0xb47
+	 5, // 0xb48
+	 5, // 0xb49
+	 5, // 0xb4a
+	 5, // 0xb4b
+	 5, // 0xb4c
+	 5, // 0xb4d
+	 5, // 0xb4e
+	 5, // 0xb4f
+	 5, // 0xb50
+	 5, // 0xb51
+	 5, // 0xb52
+	 5, // 0xb53
+	 5, // 0xb54
+	 5, // 0xb55
+	 5, // 0xb56
+	 5, // 0xb57
+	 5, // 0xb58
+	 5, // 0xb59
+	 5, // 0xb5a
+	 5, // 0xb5b
+	 5, // 0xb5c
+	 5, // 0xb5d
+	 5, // 0xb5e
+	 5, // 0xb5f
+	 5, // 0xb60
+	 5, // 0xb61
+	 5, // 0xb62
+	 5, // 0xb63
+	 5, // 0xb64
+	 5, // 0xb65
+	 5, // 0xb66
+	 5, // 0xb67
+	 5, // 0xb68
+	 5, // 0xb69
+	 5, // 0xb6a
+	 5, // 0xb6b
+	 5, // 0xb6c
+	 5, // 0xb6d
+	 5, // 0xb6e
+	 5, // 0xb6f
+	 5, // 0xb70
+	 5, // 0xb71
+	 5, // 0xb72
+	 5, // 0xb73
+	 5, // 0xb74
+	 5, // 0xb75
+	 5, // 0xb76
+	 5, // 0xb77
+	 5, // 0xb78
+	 5, // 0xb79
+	 5, // 0xb7a
+	 5, // 0xb7b
+	 5, // 0xb7c
+	 5, // 0xb7d
+	 5, // 0xb7e
+	 5, // 0xb7f
+	 5, // 0xb80
+	 5, // 0xb81
+	 5, // 0xb82
+	 5, // 0xb83
+	 5, // 0xb84
+	 5, // 0xb85
+	 5, // 0xb86
+	 5, // 0xb87
+	 5, // 0xb88
+	 5, // 0xb89
+	 5, // 0xb8a
+	 5, // 0xb8b
+	 5, // 0xb8c
+	 5, // 0xb8d
+	 5, // 0xb8e
+	 5, // 0xb8f
+	 5, // 0xb90
+	 5, // 0xb91
+	 5, // 0xb92
+	 5, // 0xb93
+	 5, // 0xb94
+	 5, // 0xb95
+	 5, // 0xb96
+	 5, // 0xb97
+	 5, // 0xb98
+	 5, // 0xb99
+	 5, // 0xb9a
+	 5, // 0xb9b
+	 5, // 0xb9c
+	 5, // 0xb9d
+	 5, // 0xb9e
+	 5, // 0xb9f
+	 5, // 0xba0
+	 5, // 0xba1
+	 5, // 0xba2
+	 5, // 0xba3
+	 5, // 0xba4
+	 5, // 0xba5
+	 5, // 0xba6
+	 5, // 0xba7
+	 5, // 0xba8
+	 5, // 0xba9
+	 5, // 0xbaa
+	 5, // 0xbab
+	 5, // 0xbac
+	 5, // 0xbad
+	 5, // 0xbae
+	 5, // 0xbaf
+	 5, // 0xbb0
+	 5, // 0xbb1
+	 5, // 0xbb2
+	 5, // 0xbb3
+	 5, // 0xbb4
+	 5, // 0xbb5
+	 5, // 0xbb6
+	 5, // 0xbb7
+	 5, // 0xbb8
+	 5, // 0xbb9
+	 5, // 0xbba
+	 5, // 0xbbb
+	 5, // 0xbbc
+	 5, // 0xbbd
+	 5, // 0xbbe
+	 5, // 0xbbf
+	 5, // 0xbc0
+	 5, // 0xbc1
+	 5, // 0xbc2
+	 5, // 0xbc3
+	 5, // 0xbc4
+	 5, // 0xbc5
+	 5, // 0xbc6
+	 5, // 0xbc7
+	 5, // 0xbc8
+	 5, // 0xbc9
+	 5, // 0xbca
+	 5, // 0xbcb
+	 5, // 0xbcc
+	 5, // 0xbcd
+	 5, // 0xbce
+	 5, // 0xbcf
+	 5, // 0xbd0
+	 5, // 0xbd1
+	 5, // 0xbd2
+	 5, // 0xbd3
+	 5, // 0xbd4
+	 5, // 0xbd5
+	 5, // 0xbd6
+	 5, // 0xbd7
+	 5, // 0xbd8
+	 5, // 0xbd9
+	 5, // 0xbda
+	 5, // 0xbdb
+	 5, // 0xbdc
+	 5, // 0xbdd
+	 5, // 0xbde
+	 5, // 0xbdf
+	 5, // 0xbe0
+	 5, // 0xbe1
+	 5, // 0xbe2
+	 5, // 0xbe3
+	 5, // 0xbe4
+	 5, // 0xbe5
+	 5, // 0xbe6
+	 5, // 0xbe7
+	 5, // 0xbe8
+	 5, // 0xbe9
+	 5, // 0xbea
+	 5, // 0xbeb
+	 5, // 0xbec
+	 5, // 0xbed
+	 5, // 0xbee
+	 5, // 0xbef
+	 5, // 0xbf0
+	 5, // 0xbf1
+	 5, // 0xbf2
+	 5, // 0xbf3
+	 5, // 0xbf4
+	 5, // 0xbf5
+	 5, // 0xbf6
+	 5, // 0xbf7
+	 5, // 0xbf8
+	 5, // 0xbf9
+	 5, // 0xbfa
+	 5, // 0xbfb
+	 5, // 0xbfc
+	 5, // 0xbfd
+	 5, // 0xbfe
+	 5, // 0xbff
+	 5, // 0xc00
+	 5, // 0xc01
+	 5, // 0xc02
+	 5, // 0xc03
+	 5, // 0xc04
+	 5, // 0xc05
+	 5, // 0xc06
+	 5, // 0xc07
+	 5, // 0xc08
+	 5, // 0xc09
+	 5, // 0xc0a
+	 5, // 0xc0b
+	 5, // 0xc0c
+	 5, // 0xc0d
+	 5, // 0xc0e
+	 5, // 0xc0f
+	 5, // 0xc10
+	 5, // 0xc11
+	 5, // 0xc12
+	 5, // 0xc13
+	 5, // 0xc14
+	 5, // 0xc15
+	 5, // 0xc16
+	 5, // 0xc17
+	 5, // 0xc18
+	 5, // 0xc19
+	 5, // 0xc1a
+	 5, // 0xc1b
+	 5, // 0xc1c
+	 5, // 0xc1d
+	 5, // 0xc1e
+	 5, // 0xc1f
+	 5, // 0xc20
+	 5, // 0xc21
+	 5, // 0xc22
+	 5, // 0xc23
+	 5, // 0xc24
+	 5, // 0xc25
+	 5, // 0xc26
+	 5, // 0xc27
+	 5, // 0xc28
+	 5, // 0xc29
+	 5, // 0xc2a
+	 5, // 0xc2b
+	 5, // 0xc2c
+	 5, // 0xc2d
+	 5, // 0xc2e
+	 5, // 0xc2f
+	 5, // 0xc30
+	 5, // 0xc31
+	 5, // 0xc32
+	 5, // 0xc33
+	 5, // 0xc34
+	 5, // 0xc35
+	 5, // 0xc36
+	 5, // 0xc37
+	 5, // 0xc38
+	 5, // 0xc39
+	 5, // 0xc3a
+	 5, // 0xc3b
+	 5, // 0xc3c
+	 5, // 0xc3d
+	 5, // 0xc3e
+	 5, // 0xc3f
+	 5, // 0xc40
+	 5, // 0xc41
+	 5, // 0xc42
+	 5, // 0xc43
+	 5, // 0xc44
+	 5, // 0xc45
+	 5, // 0xc46
+	 5, // 0xc47
+	 5, // 0xc48
+	 5, // 0xc49
+	 5, // 0xc4a
+	 5, // 0xc4b
+	 5, // 0xc4c
+	 5, // 0xc4d
+	 5, // 0xc4e
+	 5, // 0xc4f
+	 5, // 0xc50
+	 5, // 0xc51
+	 5, // 0xc52
+	 5, // 0xc53
+	 5, // 0xc54
+	 5, // 0xc55
+	 5, // 0xc56
+	 5, // 0xc57
+	 5, // 0xc58
+	 5, // 0xc59
+	 5, // 0xc5a
+	 5, // 0xc5b
+	 5, // 0xc5c
+	 5, // 0xc5d
+	 5, // 0xc5e
+	 5, // 0xc5f
+	 5, // 0xc60
+	 5, // 0xc61
+	 5, // 0xc62
+	 5, // 0xc63
+	 5, // 0xc64
+	 5, // 0xc65
+	 5, // 0xc66
+	 5, // 0xc67
+	 5, // 0xc68
+	 5, // 0xc69
+	 5, // 0xc6a
+	 5, // 0xc6b
+	 5, // 0xc6c
+	 5, // 0xc6d
+	 5, // 0xc6e
+	 5, // 0xc6f
+	 5, // 0xc70
+	 5, // 0xc71
+	 5, // 0xc72
+	 5, // 0xc73
+	 5, // 0xc74
+	 5, // 0xc75
+	 5, // 0xc76
+	 5, // 0xc77
+	 5, // 0xc78
+	 5, // 0xc79
+	 5, // 0xc7a
+	 5, // 0xc7b
+	 5, // 0xc7c
+	 5, // 0xc7d
+	 5, // 0xc7e
+	 5, // 0xc7f
+	 5, // 0xc80
+	 5, // 0xc81
+	 5, // 0xc82
+	 5, // 0xc83
+	 5, // 0xc84
+	 5, // 0xc85
+	 5, // 0xc86
+	 5, // 0xc87
+	 5, // 0xc88
+	 5, // 0xc89
+	 5, // 0xc8a
+	 5, // 0xc8b
+	 5, // 0xc8c
+	 5, // 0xc8d
+	 5, // 0xc8e
+	 5, // 0xc8f
+	 5, // 0xc90
+	 5, // 0xc91
+	 5, // 0xc92
+	 5, // 0xc93
+	 5, // 0xc94
+	 5, // 0xc95
+	 5, // 0xc96
+	 5, // 0xc97
+	 5, // 0xc98
+	 5, // 0xc99
+	 5, // 0xc9a
+	 5, // 0xc9b
+	 5, // 0xc9c
+	 5, // 0xc9d
+	 5, // 0xc9e
+	 5, // 0xc9f
+	 5, // 0xca0
+	 5, // 0xca1
+	 5, // 0xca2
+	 5, // 0xca3
+	 5, // 0xca4
+	 5, // 0xca5
+	 5, // 0xca6
+	 5, // 0xca7
+	 5, // 0xca8
+	 5, // 0xca9
+	 5, // 0xcaa
+	 5, // 0xcab
+	 5, // 0xcac
+	 5, // 0xcad
+	 5, // 0xcae
+	 5, // 0xcaf
+	 5, // 0xcb0
+	 5, // 0xcb1
+	 5, // 0xcb2
+	 5, // 0xcb3
+	 5, // 0xcb4
+	 5, // 0xcb5
+	 5, // 0xcb6
+	 5, // 0xcb7
+	 5, // 0xcb8
+	 5, // 0xcb9
+	 5, // 0xcba
+	 5, // 0xcbb
+	 5, // 0xcbc
+	 5, // 0xcbd
+	 5, // 0xcbe
+	 5, // 0xcbf
+	 5, // 0xcc0
+	 5, // 0xcc1
+	 5, // 0xcc2
+	 5, // 0xcc3
+	 5, // 0xcc4
+	 5, // 0xcc5
+	 5, // 0xcc6
+	 5, // 0xcc7
+	 5, // 0xcc8
+	 5, // 0xcc9
+	 5, // 0xcca
+	 5, // 0xccb
+	 5, // 0xccc
+	 5, // 0xccd
+	 5, // 0xcce
+	 5, // 0xccf
+	 5, // 0xcd0
+	 5, // 0xcd1
+	 5, // 0xcd2
+	 5, // 0xcd3
+	 5, // 0xcd4
+	 5, // 0xcd5
+	 5, // 0xcd6
+	 5, // 0xcd7
+	 5, // 0xcd8
+	 5, // 0xcd9
+	 5, // 0xcda
+	 5, // 0xcdb
+	 5, // 0xcdc
+	 5, // 0xcdd
+	 5, // 0xcde
+	 5, // 0xcdf
+	 5, // 0xce0
+	 5, // 0xce1
+	 5, // 0xce2
+	 5, // 0xce3
+	 5, // 0xce4
+	 5, // 0xce5
+	 5, // 0xce6
+	 5, // 0xce7
+	 5, // 0xce8
+	 5, // 0xce9
+	 5, // 0xcea
+	 5, // 0xceb
+	 5, // 0xcec
+	 5, // 0xced
+	 5, // 0xcee
+	 5, // 0xcef
+	 5, // 0xcf0
+	 5, // 0xcf1
+	 5, // 0xcf2
+	 5, // 0xcf3
+	 5, // 0xcf4
+	 5, // 0xcf5
+	 5, // 0xcf6
+	 5, // 0xcf7
+	 5, // 0xcf8
+	 5, // 0xcf9
+	 5, // 0xcfa
+	 5, // 0xcfb
+	 5, // 0xcfc
+	 5, // 0xcfd
+	 5, // 0xcfe
+	 5, // 0xcff
+	 5, // 0xd00
+	 5, // 0xd01
+	 5, // 0xd02
+	 5, // 0xd03
+	 5, // 0xd04
+	 5, // 0xd05
+	 5, // 0xd06
+	 5, // 0xd07
+	 5, // 0xd08
+	 5, // 0xd09
+	 5, // 0xd0a
+	 5, // 0xd0b
+	 5, // 0xd0c
+	 5, // 0xd0d
+	 5, // 0xd0e
+	 5, // 0xd0f
+	 5, // 0xd10
+	 5, // 0xd11
+	 5, // 0xd12
+	 5, // 0xd13
+	 5, // 0xd14
+	 5, // 0xd15
+	 5, // 0xd16
+	 5, // 0xd17
+	 5, // 0xd18
+	 5, // 0xd19
+	 5, // 0xd1a
+	 5, // 0xd1b
+	 5, // 0xd1c
+	 5, // 0xd1d
+	 5, // 0xd1e
+	 5, // 0xd1f
+	 5, // 0xd20
+	 5, // 0xd21
+	 5, // 0xd22
+	 5, // 0xd23
+	 5, // 0xd24
+	 5, // 0xd25
+	 5, // 0xd26
+	 5, // 0xd27
+	 5, // 0xd28
+	 5, // 0xd29
+	 5, // 0xd2a
+	 5, // 0xd2b
+	 5, // 0xd2c
+	 5, // 0xd2d
+	 5, // 0xd2e
+	 5, // 0xd2f
+	 5, // 0xd30
+	 5, // 0xd31
+	 5, // 0xd32
+	 5, // 0xd33
+	 5, // 0xd34
+	 5, // 0xd35
+	 5, // 0xd36
+	 5, // 0xd37
+	 5, // 0xd38
+	 5, // 0xd39
+	 5, // 0xd3a
+	 5, // 0xd3b
+	 5, // 0xd3c
+	 5, // 0xd3d
+	 5, // 0xd3e
+	 5, // 0xd3f
+	 5, // 0xd40
+	 5, // 0xd41
+	 5, // 0xd42
+	 5, // 0xd43
+	 5, // 0xd44
+	 5, // 0xd45
+	 5, // 0xd46
+	 5, // 0xd47
+	 5, // 0xd48
+	 5, // 0xd49
+	 5, // 0xd4a
+	 5, // 0xd4b
+	 5, // 0xd4c
+	 5, // 0xd4d
+	 5, // 0xd4e
+	 5, // 0xd4f
+	 5, // 0xd50
+	 5, // 0xd51
+	 5, // 0xd52
+	 5, // 0xd53
+	 5, // 0xd54
+	 5, // 0xd55
+	 5, // 0xd56
+	 5, // 0xd57
+	 5, // 0xd58
+	 5, // 0xd59
+	 5, // 0xd5a
+	 5, // 0xd5b
+	 5, // 0xd5c
+	 5, // 0xd5d
+	 5, // 0xd5e
+	 5, // 0xd5f
+	 5, // 0xd60
+	 5, // 0xd61
+	 5, // 0xd62
+	 5, // 0xd63
+	 5, // 0xd64
+	 5, // 0xd65
+	 5, // 0xd66
+	 5, // 0xd67
+	 5, // 0xd68
+	 5, // 0xd69
+	 5, // 0xd6a
+	 5, // 0xd6b
+	 5, // 0xd6c
+	 5, // 0xd6d
+	 5, // 0xd6e
+	 5, // 0xd6f
+	 5, // 0xd70
+	 5, // 0xd71
+	 5, // 0xd72
+	 5, // 0xd73
+	 5, // 0xd74
+	 5, // 0xd75
+	 5, // 0xd76
+	 5, // 0xd77
+	 5, // 0xd78
+	 5, // 0xd79
+	 5, // 0xd7a
+	 5, // 0xd7b
+	 5, // 0xd7c
+	 5, // 0xd7d
+	 5, // 0xd7e
+	 5, // 0xd7f
+	 5, // 0xd80
+	 5, // 0xd81
+	 5, // 0xd82
+	 5, // 0xd83
+	 5, // 0xd84
+	 5, // 0xd85
+	 5, // 0xd86
+	 5, // 0xd87
+	 5, // 0xd88
+	 5, // 0xd89
+	 5, // 0xd8a
+	 5, // 0xd8b
+	 5, // 0xd8c
+	 5, // 0xd8d
+	 5, // 0xd8e
+	 5, // 0xd8f
+	 5, // 0xd90
+	 5, // 0xd91
+	 5, // 0xd92
+	 5, // 0xd93
+	 5, // 0xd94
+	 5, // 0xd95
+	 5, // 0xd96
+	 5, // 0xd97
+	 5, // 0xd98
+	 5, // 0xd99
+	 5, // 0xd9a
+	 5, // 0xd9b
+	 5, // 0xd9c
+	 5, // 0xd9d
+	 5, // 0xd9e
+	 5, // 0xd9f
+	 5, // 0xda0
+	 5, // 0xda1
+	 5, // 0xda2
+	 5, // 0xda3
+	 5, // 0xda4
+	 5, // 0xda5
+	 5, // 0xda6
+	 5, // 0xda7
+	 5, // 0xda8
+	 5, // 0xda9
+	 5, // 0xdaa
+	 5, // 0xdab
+	 5, // 0xdac
+	 5, // 0xdad
+	 5, // 0xdae
+	 5, // 0xdaf
+	 5, // 0xdb0
+	 5, // 0xdb1
+	 5, // 0xdb2
+	 5, // 0xdb3
+	 5, // 0xdb4
+	 5, // 0xdb5
+	 5, // 0xdb6
+	 5, // 0xdb7
+	 5, // 0xdb8
+	 5, // 0xdb9
+	 5, // 0xdba
+	 5, // 0xdbb
+	 5, // 0xdbc
+	 5, // 0xdbd
+	 5, // 0xdbe
+	 5, // 0xdbf
+	 5, // 0xdc0
+	 5, // 0xdc1
+	 5, // 0xdc2
+	 5, // 0xdc3
+	 5, // 0xdc4
+	 5, // 0xdc5
+	 5, // 0xdc6
+	 5, // 0xdc7
+	 5, // 0xdc8
+	 5, // 0xdc9
+	 5, // 0xdca
+	 5, // 0xdcb
+	 5, // 0xdcc
+	 5, // 0xdcd
+	 5, // 0xdce
+	 5, // 0xdcf
+	 5, // 0xdd0
+	 5, // 0xdd1
+	 5, // 0xdd2
+	 5, // 0xdd3
+	 5, // 0xdd4
+	 5, // 0xdd5
+	 5, // 0xdd6
+	 5, // 0xdd7
+	 5, // 0xdd8
+	 5, // 0xdd9
+	 5, // 0xdda
+	 5, // 0xddb
+	 5, // 0xddc
+	 5, // 0xddd
+	 5, // 0xdde
+	 5, // 0xddf
+	 5, // 0xde0
+	 5, // 0xde1
+	 5, // 0xde2
+	 5, // 0xde3
+	 5, // 0xde4
+	 5, // 0xde5
+	 5, // 0xde6
+	 5, // 0xde7
+	 5, // 0xde8
+	 5, // 0xde9
+	 5, // 0xdea
+	 5, // 0xdeb
+	 5, // 0xdec
+	 5, // 0xded
+	 5, // 0xdee
+	 5, // 0xdef
+	 5, // 0xdf0
+	 5, // 0xdf1
+	 5, // 0xdf2
+	 5, // 0xdf3
+	 5, // 0xdf4
+	 5, // 0xdf5
+	 5, // 0xdf6
+	 5, // 0xdf7
+	 5, // 0xdf8
+	 5, // 0xdf9
+	 5, // 0xdfa
+	 5, // 0xdfb
+	 5, // 0xdfc
+	 5, // 0xdfd
+	 5, // 0xdfe
+	 5, // 0xdff
+	 5, // 0xe00
+	 48, // 0xe01
+	 5, // 0xe02
+	 5, // 0xe03
+	 5, // 0xe04
+	 5, // 0xe05
+	 5, // 0xe06
+	 5, // 0xe07
+	 5, // 0xe08
+	 5, // 0xe09
+	 5, // 0xe0a
+	 5, // 0xe0b
+	 5, // 0xe0c
+	 5, // 0xe0d
+	 5, // 0xe0e
+	 5, // 0xe0f
+	 5, // 0xe10
+	 5, // 0xe11
+	 5, // 0xe12
+	 5, // 0xe13
+	 5, // 0xe14
+	 5, // 0xe15
+	 5, // 0xe16
+	 5, // 0xe17
+	 5, // 0xe18
+	 5, // 0xe19
+	 5, // 0xe1a
+	 5, // 0xe1b
+	 5, // 0xe1c
+	 5, // 0xe1d
+	 5, // 0xe1e
+	 5, // 0xe1f
+	 5, // 0xe20
+	 5, // 0xe21
+	 5, // 0xe22
+	 5, // 0xe23
+	 5, // 0xe24
+	 5, // 0xe25
+	 5, // 0xe26
+	 5, // 0xe27
+	 5, // 0xe28
+	 5, // 0xe29
+	 5, // 0xe2a
+	 5, // 0xe2b
+	 5, // 0xe2c
+	 5, // 0xe2d
+	 5, // 0xe2e
+	 5, // 0xe2f
+	 5, // 0xe30
+	 5, // 0xe31
+	 5, // 0xe32
+	 5, // 0xe33
+	 5, // 0xe34
+	 5, // 0xe35
+	 5, // 0xe36
+	 5, // 0xe37
+	 5, // 0xe38
+	 5, // 0xe39
+	 5, // 0xe3a
+	 5, // 0xe3b
+	 5, // 0xe3c
+	 5, // 0xe3d
+	 5, // 0xe3e
+	 5, // 0xe3f
+	 5, // 0xe40
+	 5, // 0xe41
+	 5, // 0xe42
+	 5, // 0xe43
+	 5, // 0xe44
+	 5, // 0xe45
+	 5, // 0xe46
+	 5, // 0xe47
+	 5, // 0xe48
+	 5, // 0xe49
+	 5, // 0xe4a
+	 5, // 0xe4b
+	 5, // 0xe4c
+	 5, // 0xe4d
+	 5, // 0xe4e
+	 5, // 0xe4f
+	 5, // 0xe50
+	 5, // 0xe51
+	 5, // 0xe52
+	 5, // 0xe53
+	 5, // 0xe54
+	 5, // 0xe55
+	 5, // 0xe56
+	 5, // 0xe57
+	 5, // 0xe58
+	 5, // 0xe59
+	 5, // 0xe5a
+	 5, // 0xe5b
+	 5, // 0xe5c
+	 5, // 0xe5d
+	 5, // 0xe5e
+	 5, // 0xe5f
+	 5, // 0xe60
+	 5, // 0xe61
+	 5, // 0xe62
+	 5, // 0xe63
+	 5, // 0xe64
+	 5, // 0xe65
+	 5, // 0xe66
+	 5, // 0xe67
+	 5, // 0xe68
+	 5, // 0xe69
+	 5, // 0xe6a
+	 5, // 0xe6b
+	 5, // 0xe6c
+	 5, // 0xe6d
+	 5, // 0xe6e
+	 5, // 0xe6f
+	 5, // 0xe70
+	 5, // 0xe71
+	 5, // 0xe72
+	 5, // 0xe73
+	 5, // 0xe74
+	 5, // 0xe75
+	 5, // 0xe76
+	 5, // 0xe77
+	 5, // 0xe78
+	 5, // 0xe79
+	 5, // 0xe7a
+	 5, // 0xe7b
+	 5, // 0xe7c
+	 5, // 0xe7d
+	 5, // 0xe7e
+	 5, // 0xe7f
+	 5, // 0xe80
+	 5, // 0xe81
+	 5, // 0xe82
+	 5, // 0xe83
+	 5, // 0xe84
+	 5, // 0xe85
+	 5, // 0xe86
+	 5, // 0xe87
+	 5, // 0xe88
+	 5, // 0xe89
+	 5, // 0xe8a
+	 5, // 0xe8b
+	 5, // 0xe8c
+	 5, // 0xe8d
+	 5, // 0xe8e
+	 5, // 0xe8f
+	 5, // 0xe90
+	 5, // 0xe91
+	 5, // 0xe92
+	 5, // 0xe93
+	 5, // 0xe94
+	 5, // 0xe95
+	 5, // 0xe96
+	 5, // 0xe97
+	 5, // 0xe98
+	 5, // 0xe99
+	 5, // 0xe9a
+	 5, // 0xe9b
+	 5, // 0xe9c
+	 5, // 0xe9d
+	 5, // 0xe9e
+	 5, // 0xe9f
+	 5, // 0xea0
+	 5, // 0xea1
+	 5, // 0xea2
+	 5, // 0xea3
+	 5, // 0xea4
+	 5, // 0xea5
+	 5, // 0xea6
+	 5, // 0xea7
+	 5, // 0xea8
+	 5, // 0xea9
+	 5, // 0xeaa
+	 5, // 0xeab
+	 5, // 0xeac
+	 5, // 0xead
+	 5, // 0xeae
+	 5, // 0xeaf
+	 5, // 0xeb0
+	 5, // 0xeb1
+	 5, // 0xeb2
+	 5, // 0xeb3
+	 5, // 0xeb4
+	 5, // 0xeb5
+	 5, // 0xeb6
+	 5, // 0xeb7
+	 5, // 0xeb8
+	 5, // 0xeb9
+	 5, // 0xeba
+	 5, // 0xebb
+	 5, // 0xebc
+	 5, // 0xebd
+	 5, // 0xebe
+	 5, // 0xebf
+	 5, // 0xec0
+	 5, // 0xec1
+	 5, // 0xec2
+	 5, // 0xec3
+	 5, // 0xec4
+	 5, // 0xec5
+	 5, // 0xec6
+	 5, // 0xec7
+	 5, // 0xec8
+	 5, // 0xec9
+	 5, // 0xeca
+	 5, // 0xecb
+	 5, // 0xecc
+	 5, // 0xecd
+	 5, // 0xece
+	 5, // 0xecf
+	 5, // 0xed0
+	 5, // 0xed1
+	 5, // 0xed2
+	 5, // 0xed3
+	 5, // 0xed4
+	 5, // 0xed5
+	 5, // 0xed6
+	 5, // 0xed7
+	 5, // 0xed8
+	 5, // 0xed9
+	 5, // 0xeda
+	 5, // 0xedb
+	 5, // 0xedc
+	 5, // 0xedd
+	 5, // 0xede
+	 5, // 0xedf
+	 5, // 0xee0
+	 5, // 0xee1
+	 5, // 0xee2
+	 5, // 0xee3
+	 5, // 0xee4
+	 5, // 0xee5
+	 5, // 0xee6
+	 5, // 0xee7
+	 5, // 0xee8
+	 5, // 0xee9
+	 5, // 0xeea
+	 5, // 0xeeb
+	 5, // 0xeec
+	 5, // 0xeed
+	 5, // 0xeee
+	 5, // 0xeef
+	 5, // 0xef0
+	 5, // 0xef1
+	 5, // 0xef2
+	 5, // 0xef3
+	 5, // 0xef4
+	 5, // 0xef5
+	 5, // 0xef6
+	 5, // 0xef7
+	 5, // 0xef8
+	 5, // 0xef9
+	 5, // 0xefa
+	 5, // 0xefb
+	 5, // 0xefc
+	 5, // 0xefd
+	 5, // 0xefe
+	 5, // 0xeff
+	 26, // 0xf00
+	 26, // 0xf01
+	 26, // 0xf02
+	 26, // 0xf03
+	 26, // 0xf04
+	 26, // 0xf05
+	 26, // 0xf06
+	 26, // 0xf07
+	 26, // 0xf08
+	 26, // 0xf09
+	 26, // 0xf0a
+	 26, // 0xf0b
+	 26, // 0xf0c
+	 26, // 0xf0d
+	 26, // 0xf0e
+	 26, // 0xf0f
+	 26, // 0xf10
+	 26, // 0xf11
+	 26, // 0xf12
+	 26, // 0xf13
+	 26, // 0xf14
+	 26, // 0xf15
+	 26, // 0xf16
+	 26, // 0xf17
+	 26, // 0xf18
+	 26, // 0xf19
+	 26, // 0xf1a
+	 26, // 0xf1b
+	 26, // 0xf1c
+	 26, // 0xf1d
+	 26, // 0xf1e
+	 26, // 0xf1f
+	 26, // 0xf20
+	 26, // 0xf21
+	 26, // 0xf22
+	 26, // 0xf23
+	 26, // 0xf24
+	 26, // 0xf25
+	 26, // 0xf26
+	 26, // 0xf27
+	 26, // 0xf28
+	 26, // 0xf29
+	 26, // 0xf2a
+	 26, // 0xf2b
+	 26, // 0xf2c
+	 26, // 0xf2d
+	 26, // 0xf2e
+	 26, // 0xf2f
+	 26, // 0xf30
+	 26, // 0xf31
+	 26, // 0xf32
+	 26, // 0xf33
+	 26, // 0xf34
+	 26, // 0xf35
+	 26, // 0xf36
+	 26, // 0xf37
+	 26, // 0xf38
+	 26, // 0xf39
+	 26, // 0xf3a
+	 26, // 0xf3b
+	 26, // 0xf3c
+	 26, // 0xf3d
+	 26, // 0xf3e
+	 26, // 0xf3f
+	 26, // 0xf40
+	 26, // 0xf41
+	 26, // 0xf42
+	 26, // 0xf43
+	 26, // 0xf44
+	 26, // 0xf45
+	 26, // 0xf46
+	 26, // 0xf47
+	 26, // 0xf48
+	 26, // 0xf49
+	 26, // 0xf4a
+	 26, // 0xf4b
+	 26, // 0xf4c
+	 26, // 0xf4d
+	 26, // 0xf4e
+	 26, // 0xf4f
+	 26, // 0xf50
+	 26, // 0xf51
+	 26, // 0xf52
+	 26, // 0xf53
+	 26, // 0xf54
+	 26, // 0xf55
+	 26, // 0xf56
+	 26, // 0xf57
+	 26, // 0xf58
+	 26, // 0xf59
+	 26, // 0xf5a
+	 26, // 0xf5b
+	 26, // 0xf5c
+	 26, // 0xf5d
+	 26, // 0xf5e
+	 26, // 0xf5f
+	 26, // 0xf60
+	 26, // 0xf61
+	 26, // 0xf62
+	 26, // 0xf63
+	 26, // 0xf64
+	 26, // 0xf65
+	 26, // 0xf66
+	 26, // 0xf67
+	 26, // 0xf68
+	 26, // 0xf69
+	 26, // 0xf6a
+	 26, // 0xf6b
+	 26, // 0xf6c
+	 26, // 0xf6d
+	 26, // 0xf6e
+	 26, // 0xf6f
+	 26, // 0xf70
+	 26, // 0xf71
+	 26, // 0xf72
+	 26, // 0xf73
+	 26, // 0xf74
+	 26, // 0xf75
+	 26, // 0xf76
+	 26, // 0xf77
+	 26, // 0xf78
+	 26, // 0xf79
+	 26, // 0xf7a
+	 26, // 0xf7b
+	 26, // 0xf7c
+	 26, // 0xf7d
+	 26, // 0xf7e
+	 26, // 0xf7f
+	 26, // 0xf80
+	 26, // 0xf81
+	 26, // 0xf82
+	 26, // 0xf83
+	 26, // 0xf84
+	 26, // 0xf85
+	 26, // 0xf86
+	 26, // 0xf87
+	 26, // 0xf88
+	 26, // 0xf89
+	 26, // 0xf8a
+	 26, // 0xf8b
+	 26, // 0xf8c
+	 26, // 0xf8d
+	 26, // 0xf8e
+	 26, // 0xf8f
+	 26, // 0xf90
+	 26, // 0xf91
+	 26, // 0xf92
+	 26, // 0xf93
+	 26, // 0xf94
+	 26, // 0xf95
+	 26, // 0xf96
+	 26, // 0xf97
+	 26, // 0xf98
+	 26, // 0xf99
+	 26, // 0xf9a
+	 26, // 0xf9b
+	 26, // 0xf9c
+	 26, // 0xf9d
+	 26, // 0xf9e
+	 26, // 0xf9f
+	 26, // 0xfa0
+	 26, // 0xfa1
+	 26, // 0xfa2
+	 26, // 0xfa3
+	 26, // 0xfa4
+	 26, // 0xfa5
+	 26, // 0xfa6
+	 26, // 0xfa7
+	 26, // 0xfa8
+	 26, // 0xfa9
+	 26, // 0xfaa
+	 26, // 0xfab
+	 26, // 0xfac
+	 26, // 0xfad
+	 26, // 0xfae
+	 26, // 0xfaf
+	 26, // 0xfb0
+	 26, // 0xfb1
+	 26, // 0xfb2
+	 26, // 0xfb3
+	 26, // 0xfb4
+	 26, // 0xfb5
+	 26, // 0xfb6
+	 26, // 0xfb7
+	 26, // 0xfb8
+	 26, // 0xfb9
+	 26, // 0xfba
+	 26, // 0xfbb
+	 26, // 0xfbc
+	 26, // 0xfbd
+	 26, // 0xfbe
+	 26, // 0xfbf
+	 26, // 0xfc0
+	 26, // 0xfc1
+	 26, // 0xfc2
+	 26, // 0xfc3
+	 26, // 0xfc4
+	 26, // 0xfc5
+	 26, // 0xfc6
+	 26, // 0xfc7
+	 26, // 0xfc8
+	 26, // 0xfc9
+	 26, // 0xfca
+	 26, // 0xfcb
+	 26, // 0xfcc
+	 26, // 0xfcd
+	 26, // 0xfce
+	 26, // 0xfcf
+	 26, // 0xfd0
+	 26, // 0xfd1
+	 26, // 0xfd2
+	 26, // 0xfd3
+	 26, // 0xfd4
+	 26, // 0xfd5
+	 26, // 0xfd6
+	 26, // 0xfd7
+	 26, // 0xfd8
+	 26, // 0xfd9
+	 26, // 0xfda
+	 26, // 0xfdb
+	 26, // 0xfdc
+	 26, // 0xfdd
+	 26, // 0xfde
+	 26, // 0xfdf
+	 26, // 0xfe0
+	 26, // 0xfe1
+	 26, // 0xfe2
+	 26, // 0xfe3
+	 26, // 0xfe4
+	 26, // 0xfe5
+	 26, // 0xfe6
+	 26, // 0xfe7
+	 26, // 0xfe8
+	 26, // 0xfe9
+	 26, // 0xfea
+	 26, // 0xfeb
+	 26, // 0xfec
+	 26, // 0xfed
+	 26, // 0xfee
+	 26, // 0xfef
+	 26, // 0xff0
+	 26, // 0xff1
+	 26, // 0xff2
+	 26, // 0xff3
+	 26, // 0xff4
+	 26, // 0xff5
+	 26, // 0xff6
+	 26, // 0xff7
+	 26, // 0xff8
+	 26, // 0xff9
+	 26, // 0xffa
+	 26, // 0xffb
+	 26, // 0xffc
+	 26, // 0xffd
+	 26, // 0xffe
+	 49, // 0xfff
+	 26, // 0x1000
+	 26, // 0x1001
+	 26, // 0x1002
+	 26, // 0x1003
+	 26, // 0x1004
+	 26, // 0x1005
+	 26, // 0x1006
+	 26, // 0x1007
+	 26, // 0x1008
+	 26, // 0x1009
+	 26, // 0x100a
+	 26, // 0x100b
+	 26, // 0x100c
+	 26, // 0x100d
+	 26, // 0x100e
+	 26, // 0x100f
+	 26, // 0x1010
+	 26, // 0x1011
+	 26, // 0x1012
+	 26, // 0x1013
+	 26, // 0x1014
+	 26, // 0x1015
+	 26, // 0x1016
+	 26, // 0x1017
+	 26, // 0x1018
+	 26, // 0x1019
+	 26, // 0x101a
+	 26, // 0x101b
+	 26, // 0x101c
+	 26, // 0x101d
+	 26, // 0x101e
+	 26, // 0x101f
+	 26, // 0x1020
+	 26, // 0x1021
+	 26, // 0x1022
+	 26, // 0x1023
+	 26, // 0x1024
+	 26, // 0x1025
+	 26, // 0x1026
+	 26, // 0x1027
+	 26, // 0x1028
+	 26, // 0x1029
+	 26, // 0x102a
+	 26, // 0x102b
+	 26, // 0x102c
+	 26, // 0x102d
+	 26, // 0x102e
+	 26, // 0x102f
+	 26, // 0x1030
+	 26, // 0x1031
+	 26, // 0x1032
+	 26, // 0x1033
+	 26, // 0x1034
+	 26, // 0x1035
+	 26, // 0x1036
+	 26, // 0x1037
+	 26, // 0x1038
+	 26, // 0x1039
+	 26, // 0x103a
+	 26, // 0x103b
+	 26, // 0x103c
+	 26, // 0x103d
+	 26, // 0x103e
+	 26, // 0x103f
+	 26, // 0x1040
+	 26, // 0x1041
+	 26, // 0x1042
+	 26, // 0x1043
+	 26, // 0x1044
+	 26, // 0x1045
+	 26, // 0x1046
+	 26, // 0x1047
+	 26, // 0x1048
+	 26, // 0x1049
+	 26, // 0x104a
+	 26, // 0x104b
+	 26, // 0x104c
+	 26, // 0x104d
+	 26, // 0x104e
+	 26, // 0x104f
+	 26, // 0x1050
+	 26, // 0x1051
+	 26, // 0x1052
+	 26, // 0x1053
+	 26, // 0x1054
+	 26, // 0x1055
+	 26, // 0x1056
+	 26, // 0x1057
+	 26, // 0x1058
+	 26, // 0x1059
+	 26, // 0x105a
+	 26, // 0x105b
+	 26, // 0x105c
+	 26, // 0x105d
+	 26, // 0x105e
+	 26, // 0x105f
+	 26, // 0x1060
+	 26, // 0x1061
+	 26, // 0x1062
+	 26, // 0x1063
+	 26, // 0x1064
+	 26, // 0x1065
+	 26, // 0x1066
+	 26, // 0x1067
+	 26, // 0x1068
+	 26, // 0x1069
+	 26, // 0x106a
+	 26, // 0x106b
+	 26, // 0x106c
+	 26, // 0x106d
+	 26, // 0x106e
+	 26, // 0x106f
+	 26, // 0x1070
+	 26, // 0x1071
+	 26, // 0x1072
+	 26, // 0x1073
+	 26, // 0x1074
+	 26, // 0x1075
+	 26, // 0x1076
+	 26, // 0x1077
+	 26, // 0x1078
+	 26, // 0x1079
+	 26, // 0x107a
+	 26, // 0x107b
+	 26, // 0x107c
+	 26, // 0x107d
+	 26, // 0x107e
+	 26, // 0x107f
+	 26, // 0x1080
+	 26, // 0x1081
+	 26, // 0x1082
+	 26, // 0x1083
+	 26, // 0x1084
+	 26, // 0x1085
+	 26, // 0x1086
+	 26, // 0x1087
+	 26, // 0x1088
+	 26, // 0x1089
+	 26, // 0x108a
+	 26, // 0x108b
+	 26, // 0x108c
+	 26, // 0x108d
+	 26, // 0x108e
+	 26, // 0x108f
+	 26, // 0x1090
+	 26, // 0x1091
+	 26, // 0x1092
+	 26, // 0x1093
+	 26, // 0x1094
+	 26, // 0x1095
+	 26, // 0x1096
+	 26, // 0x1097
+	 26, // 0x1098
+	 26, // 0x1099
+	 26, // 0x109a
+	 26, // 0x109b
+	 26, // 0x109c
+	 26, // 0x109d
+	 26, // 0x109e
+	 26, // 0x109f
+	 26, // 0x10a0
+	 26, // 0x10a1
+	 26, // 0x10a2
+	 26, // 0x10a3
+	 26, // 0x10a4
+	 26, // 0x10a5
+	 26, // 0x10a6
+	 26, // 0x10a7
+	 26, // 0x10a8
+	 26, // 0x10a9
+	 26, // 0x10aa
+	 26, // 0x10ab
+	 26, // 0x10ac
+	 26, // 0x10ad
+	 26, // 0x10ae
+	 26, // 0x10af
+	 26, // 0x10b0
+	 26, // 0x10b1
+	 26, // 0x10b2
+	 26, // 0x10b3
+	 26, // 0x10b4
+	 26, // 0x10b5
+	 26, // 0x10b6
+	 26, // 0x10b7
+	 26, // 0x10b8
+	 26, // 0x10b9
+	 26, // 0x10ba
+	 26, // 0x10bb
+	 26, // 0x10bc
+	 26, // 0x10bd
+	 26, // 0x10be
+	 26, // 0x10bf
+	 26, // 0x10c0
+	 26, // 0x10c1
+	 26, // 0x10c2
+	 26, // 0x10c3
+	 26, // 0x10c4
+	 26, // 0x10c5
+	 26, // 0x10c6
+	 26, // 0x10c7
+	 26, // 0x10c8
+	 26, // 0x10c9
+	 26, // 0x10ca
+	 26, // 0x10cb
+	 26, // 0x10cc
+	 26, // 0x10cd
+	 26, // 0x10ce
+	 26, // 0x10cf
+	 26, // 0x10d0
+	 26, // 0x10d1
+	 26, // 0x10d2
+	 26, // 0x10d3
+	 26, // 0x10d4
+	 26, // 0x10d5
+	 26, // 0x10d6
+	 26, // 0x10d7
+	 26, // 0x10d8
+	 26, // 0x10d9
+	 26, // 0x10da
+	 26, // 0x10db
+	 26, // 0x10dc
+	 26, // 0x10dd
+	 26, // 0x10de
+	 26, // 0x10df
+	 26, // 0x10e0
+	 26, // 0x10e1
+	 26, // 0x10e2
+	 26, // 0x10e3
+	 26, // 0x10e4
+	 26, // 0x10e5
+	 26, // 0x10e6
+	 26, // 0x10e7
+	 26, // 0x10e8
+	 26, // 0x10e9
+	 26, // 0x10ea
+	 26, // 0x10eb
+	 26, // 0x10ec
+	 26, // 0x10ed
+	 26, // 0x10ee
+	 26, // 0x10ef
+	 26, // 0x10f0
+	 26, // 0x10f1
+	 26, // 0x10f2
+	 26, // 0x10f3
+	 26, // 0x10f4
+	 26, // 0x10f5
+	 26, // 0x10f6
+	 26, // 0x10f7
+	 26, // 0x10f8
+	 26, // 0x10f9
+	 26, // 0x10fa
+	 26, // 0x10fb
+	 26, // 0x10fc
+	 26, // 0x10fd
+	 26, // 0x10fe
+	 49, // 0x10ff
+];
+pub const EAW_PAGES: [[u8;256];50] = [
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x5,
+        0x5,
+        0x1,
+        0x5,
+        0x5,
+        0x1,
+        0x1,
+        0x0,
+        0x1,
+        0x0,
+        0x5,
+        0x1,
+        0x1,
+        0x5,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x1,
+        0x0,
+        0x1,
+        0x0,
+    ],
+    [
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x1,
+        0x0,
+        0x1,
+        0x0,
+        0x1,
+        0x0,
+        0x1,
+        0x0,
+        0x1,
+        0x0,
+        0x1,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x1,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x1,
+        0x1,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1,
+        0x0,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x1,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+    ],
+    [
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x1,
+        0x1,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x3,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x3,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x3,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x3,
+        0x3,
+        0x1,
+        0x3,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x3,
+        0x1,
+        0x1,
+        0x3,
+        0x1,
+        0x1,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x5,
+        0x5,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+    ],
+    [
+        0x4,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+    ],
+    [
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x3,
+        0x1,
+        0x1,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+    ],
+];
@@ -0,0 +1,3793 @@
+// GENERATED CODE DO NOT MANUALLY EDIT
+pub const EMOJI_SEQUENCES: [(&str, u8);3790] = [
+	("\u{23}\u{fe0f}\u{20e3}", 0x02),
+	("\u{2a}\u{fe0f}\u{20e3}", 0x02),
+	("\u{30}\u{fe0f}\u{20e3}", 0x02),
+	("\u{31}\u{fe0f}\u{20e3}", 0x02),
+	("\u{32}\u{fe0f}\u{20e3}", 0x02),
+	("\u{33}\u{fe0f}\u{20e3}", 0x02),
+	("\u{34}\u{fe0f}\u{20e3}", 0x02),
+	("\u{35}\u{fe0f}\u{20e3}", 0x02),
+	("\u{36}\u{fe0f}\u{20e3}", 0x02),
+	("\u{37}\u{fe0f}\u{20e3}", 0x02),
+	("\u{38}\u{fe0f}\u{20e3}", 0x02),
+	("\u{39}\u{fe0f}\u{20e3}", 0x02),
+	("\u{a9}\u{fe0f}", 0x01),
+	("\u{ae}\u{fe0f}", 0x01),
+	("\u{203c}\u{fe0f}", 0x01),
+	("\u{2049}\u{fe0f}", 0x01),
+	("\u{2122}\u{fe0f}", 0x01),
+	("\u{2139}\u{fe0f}", 0x01),
+	("\u{2194}\u{fe0f}", 0x01),
+	("\u{2195}\u{fe0f}", 0x01),
+	("\u{2196}\u{fe0f}", 0x01),
+	("\u{2197}\u{fe0f}", 0x01),
+	("\u{2198}\u{fe0f}", 0x01),
+	("\u{2199}\u{fe0f}", 0x01),
+	("\u{21a9}\u{fe0f}", 0x01),
+	("\u{21aa}\u{fe0f}", 0x01),
+	("\u{231a}", 0x01),
+	("\u{231b}", 0x01),
+	("\u{2328}\u{fe0f}", 0x01),
+	("\u{23cf}\u{fe0f}", 0x01),
+	("\u{23e9}", 0x01),
+	("\u{23ea}", 0x01),
+	("\u{23eb}", 0x01),
+	("\u{23ec}", 0x01),
+	("\u{23ed}\u{fe0f}", 0x01),
+	("\u{23ee}\u{fe0f}", 0x01),
+	("\u{23ef}\u{fe0f}", 0x01),
+	("\u{23f0}", 0x01),
+	("\u{23f1}\u{fe0f}", 0x01),
+	("\u{23f2}\u{fe0f}", 0x01),
+	("\u{23f3}", 0x01),
+	("\u{23f8}\u{fe0f}", 0x01),
+	("\u{23f9}\u{fe0f}", 0x01),
+	("\u{23fa}\u{fe0f}", 0x01),
+	("\u{24c2}\u{fe0f}", 0x01),
+	("\u{25aa}\u{fe0f}", 0x01),
+	("\u{25ab}\u{fe0f}", 0x01),
+	("\u{25b6}\u{fe0f}", 0x01),
+	("\u{25c0}\u{fe0f}", 0x01),
+	("\u{25fb}\u{fe0f}", 0x01),
+	("\u{25fc}\u{fe0f}", 0x01),
+	("\u{25fd}", 0x01),
+	("\u{25fe}", 0x01),
+	("\u{2600}\u{fe0f}", 0x01),
+	("\u{2601}\u{fe0f}", 0x01),
+	("\u{2602}\u{fe0f}", 0x01),
+	("\u{2603}\u{fe0f}", 0x01),
+	("\u{2604}\u{fe0f}", 0x01),
+	("\u{260e}\u{fe0f}", 0x01),
+	("\u{2611}\u{fe0f}", 0x01),
+	("\u{2614}", 0x01),
+	("\u{2615}", 0x01),
+	("\u{2618}\u{fe0f}", 0x01),
+	("\u{261d}\u{fe0f}", 0x01),
+	("\u{261d}\u{1f3fb}", 0x04),
+	("\u{261d}\u{1f3fc}", 0x04),
+	("\u{261d}\u{1f3fd}", 0x04),
+	("\u{261d}\u{1f3fe}", 0x04),
+	("\u{261d}\u{1f3ff}", 0x04),
+	("\u{2620}\u{fe0f}", 0x01),
+	("\u{2622}\u{fe0f}", 0x01),
+	("\u{2623}\u{fe0f}", 0x01),
+	("\u{2626}\u{fe0f}", 0x01),
+	("\u{262a}\u{fe0f}", 0x01),
+	("\u{262e}\u{fe0f}", 0x01),
+	("\u{262f}\u{fe0f}", 0x01),
+	("\u{2638}\u{fe0f}", 0x01),
+	("\u{2639}\u{fe0f}", 0x01),
+	("\u{263a}\u{fe0f}", 0x01),
+	("\u{2640}\u{fe0f}", 0x01),
+	("\u{2642}\u{fe0f}", 0x01),
+	("\u{2648}", 0x01),
+	("\u{2649}", 0x01),
+	("\u{264a}", 0x01),
+	("\u{264b}", 0x01),
+	("\u{264c}", 0x01),
+	("\u{264d}", 0x01),
+	("\u{264e}", 0x01),
+	("\u{264f}", 0x01),
+	("\u{2650}", 0x01),
+	("\u{2651}", 0x01),
+	("\u{2652}", 0x01),
+	("\u{2653}", 0x01),
+	("\u{265f}\u{fe0f}", 0x01),
+	("\u{2660}\u{fe0f}", 0x01),
+	("\u{2663}\u{fe0f}", 0x01),
+	("\u{2665}\u{fe0f}", 0x01),
+	("\u{2666}\u{fe0f}", 0x01),
+	("\u{2668}\u{fe0f}", 0x01),
+	("\u{267b}\u{fe0f}", 0x01),
+	("\u{267e}\u{fe0f}", 0x01),
+	("\u{267f}", 0x01),
+	("\u{2692}\u{fe0f}", 0x01),
+	("\u{2693}", 0x01),
+	("\u{2694}\u{fe0f}", 0x01),
+	("\u{2695}\u{fe0f}", 0x01),
+	("\u{2696}\u{fe0f}", 0x01),
+	("\u{2697}\u{fe0f}", 0x01),
+	("\u{2699}\u{fe0f}", 0x01),
+	("\u{269b}\u{fe0f}", 0x01),
+	("\u{269c}\u{fe0f}", 0x01),
+	("\u{26a0}\u{fe0f}", 0x01),
+	("\u{26a1}", 0x01),
+	("\u{26a7}\u{fe0f}", 0x01),
+	("\u{26aa}", 0x01),
+	("\u{26ab}", 0x01),
+	("\u{26b0}\u{fe0f}", 0x01),
+	("\u{26b1}\u{fe0f}", 0x01),
+	("\u{26bd}", 0x01),
+	("\u{26be}", 0x01),
+	("\u{26c4}", 0x01),
+	("\u{26c5}", 0x01),
+	("\u{26c8}\u{fe0f}", 0x01),
+	("\u{26ce}", 0x01),
+	("\u{26cf}\u{fe0f}", 0x01),
+	("\u{26d1}\u{fe0f}", 0x01),
+	("\u{26d3}\u{fe0f}", 0x01),
+	("\u{26d3}\u{fe0f}\u{200d}\u{1f4a5}", 0x05),
+	("\u{26d4}", 0x01),
+	("\u{26e9}\u{fe0f}", 0x01),
+	("\u{26ea}", 0x01),
+	("\u{26f0}\u{fe0f}", 0x01),
+	("\u{26f1}\u{fe0f}", 0x01),
+	("\u{26f2}", 0x01),
+	("\u{26f3}", 0x01),
+	("\u{26f4}\u{fe0f}", 0x01),
+	("\u{26f5}", 0x01),
+	("\u{26f7}\u{fe0f}", 0x01),
+	("\u{26f8}\u{fe0f}", 0x01),
+	("\u{26f9}\u{fe0f}", 0x01),
+	("\u{26f9}\u{fe0f}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{26f9}\u{fe0f}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{26f9}\u{1f3fb}", 0x04),
+	("\u{26f9}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{26f9}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{26f9}\u{1f3fc}", 0x04),
+	("\u{26f9}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{26f9}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{26f9}\u{1f3fd}", 0x04),
+	("\u{26f9}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{26f9}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{26f9}\u{1f3fe}", 0x04),
+	("\u{26f9}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{26f9}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{26f9}\u{1f3ff}", 0x04),
+	("\u{26f9}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{26f9}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{26fa}", 0x01),
+	("\u{26fd}", 0x01),
+	("\u{2702}\u{fe0f}", 0x01),
+	("\u{2705}", 0x01),
+	("\u{2708}\u{fe0f}", 0x01),
+	("\u{2709}\u{fe0f}", 0x01),
+	("\u{270a}", 0x01),
+	("\u{270a}\u{1f3fb}", 0x04),
+	("\u{270a}\u{1f3fc}", 0x04),
+	("\u{270a}\u{1f3fd}", 0x04),
+	("\u{270a}\u{1f3fe}", 0x04),
+	("\u{270a}\u{1f3ff}", 0x04),
+	("\u{270b}", 0x01),
+	("\u{270b}\u{1f3fb}", 0x04),
+	("\u{270b}\u{1f3fc}", 0x04),
+	("\u{270b}\u{1f3fd}", 0x04),
+	("\u{270b}\u{1f3fe}", 0x04),
+	("\u{270b}\u{1f3ff}", 0x04),
+	("\u{270c}\u{fe0f}", 0x01),
+	("\u{270c}\u{1f3fb}", 0x04),
+	("\u{270c}\u{1f3fc}", 0x04),
+	("\u{270c}\u{1f3fd}", 0x04),
+	("\u{270c}\u{1f3fe}", 0x04),
+	("\u{270c}\u{1f3ff}", 0x04),
+	("\u{270d}\u{fe0f}", 0x01),
+	("\u{270d}\u{1f3fb}", 0x04),
+	("\u{270d}\u{1f3fc}", 0x04),
+	("\u{270d}\u{1f3fd}", 0x04),
+	("\u{270d}\u{1f3fe}", 0x04),
+	("\u{270d}\u{1f3ff}", 0x04),
+	("\u{270f}\u{fe0f}", 0x01),
+	("\u{2712}\u{fe0f}", 0x01),
+	("\u{2714}\u{fe0f}", 0x01),
+	("\u{2716}\u{fe0f}", 0x01),
+	("\u{271d}\u{fe0f}", 0x01),
+	("\u{2721}\u{fe0f}", 0x01),
+	("\u{2728}", 0x01),
+	("\u{2733}\u{fe0f}", 0x01),
+	("\u{2734}\u{fe0f}", 0x01),
+	("\u{2744}\u{fe0f}", 0x01),
+	("\u{2747}\u{fe0f}", 0x01),
+	("\u{274c}", 0x01),
+	("\u{274e}", 0x01),
+	("\u{2753}", 0x01),
+	("\u{2754}", 0x01),
+	("\u{2755}", 0x01),
+	("\u{2757}", 0x01),
+	("\u{2763}\u{fe0f}", 0x01),
+	("\u{2764}\u{fe0f}", 0x01),
+	("\u{2764}\u{fe0f}\u{200d}\u{1f525}", 0x05),
+	("\u{2764}\u{fe0f}\u{200d}\u{1fa79}", 0x05),
+	("\u{2795}", 0x01),
+	("\u{2796}", 0x01),
+	("\u{2797}", 0x01),
+	("\u{27a1}\u{fe0f}", 0x01),
+	("\u{27b0}", 0x01),
+	("\u{27bf}", 0x01),
+	("\u{2934}\u{fe0f}", 0x01),
+	("\u{2935}\u{fe0f}", 0x01),
+	("\u{2b05}\u{fe0f}", 0x01),
+	("\u{2b06}\u{fe0f}", 0x01),
+	("\u{2b07}\u{fe0f}", 0x01),
+	("\u{2b1b}", 0x01),
+	("\u{2b1c}", 0x01),
+	("\u{2b50}", 0x01),
+	("\u{2b55}", 0x01),
+	("\u{3030}\u{fe0f}", 0x01),
+	("\u{303d}\u{fe0f}", 0x01),
+	("\u{3297}\u{fe0f}", 0x01),
+	("\u{3299}\u{fe0f}", 0x01),
+	("\u{1f004}", 0x01),
+	("\u{1f0cf}", 0x01),
+	("\u{1f170}\u{fe0f}", 0x01),
+	("\u{1f171}\u{fe0f}", 0x01),
+	("\u{1f17e}\u{fe0f}", 0x01),
+	("\u{1f17f}\u{fe0f}", 0x01),
+	("\u{1f18e}", 0x01),
+	("\u{1f191}", 0x01),
+	("\u{1f192}", 0x01),
+	("\u{1f193}", 0x01),
+	("\u{1f194}", 0x01),
+	("\u{1f195}", 0x01),
+	("\u{1f196}", 0x01),
+	("\u{1f197}", 0x01),
+	("\u{1f198}", 0x01),
+	("\u{1f199}", 0x01),
+	("\u{1f19a}", 0x01),
+	("\u{1f1e6}\u{1f1e8}", 0x03),
+	("\u{1f1e6}\u{1f1e9}", 0x03),
+	("\u{1f1e6}\u{1f1ea}", 0x03),
+	("\u{1f1e6}\u{1f1eb}", 0x03),
+	("\u{1f1e6}\u{1f1ec}", 0x03),
+	("\u{1f1e6}\u{1f1ee}", 0x03),
+	("\u{1f1e6}\u{1f1f1}", 0x03),
+	("\u{1f1e6}\u{1f1f2}", 0x03),
+	("\u{1f1e6}\u{1f1f4}", 0x03),
+	("\u{1f1e6}\u{1f1f6}", 0x03),
+	("\u{1f1e6}\u{1f1f7}", 0x03),
+	("\u{1f1e6}\u{1f1f8}", 0x03),
+	("\u{1f1e6}\u{1f1f9}", 0x03),
+	("\u{1f1e6}\u{1f1fa}", 0x03),
+	("\u{1f1e6}\u{1f1fc}", 0x03),
+	("\u{1f1e6}\u{1f1fd}", 0x03),
+	("\u{1f1e6}\u{1f1ff}", 0x03),
+	("\u{1f1e7}\u{1f1e6}", 0x03),
+	("\u{1f1e7}\u{1f1e7}", 0x03),
+	("\u{1f1e7}\u{1f1e9}", 0x03),
+	("\u{1f1e7}\u{1f1ea}", 0x03),
+	("\u{1f1e7}\u{1f1eb}", 0x03),
+	("\u{1f1e7}\u{1f1ec}", 0x03),
+	("\u{1f1e7}\u{1f1ed}", 0x03),
+	("\u{1f1e7}\u{1f1ee}", 0x03),
+	("\u{1f1e7}\u{1f1ef}", 0x03),
+	("\u{1f1e7}\u{1f1f1}", 0x03),
+	("\u{1f1e7}\u{1f1f2}", 0x03),
+	("\u{1f1e7}\u{1f1f3}", 0x03),
+	("\u{1f1e7}\u{1f1f4}", 0x03),
+	("\u{1f1e7}\u{1f1f6}", 0x03),
+	("\u{1f1e7}\u{1f1f7}", 0x03),
+	("\u{1f1e7}\u{1f1f8}", 0x03),
+	("\u{1f1e7}\u{1f1f9}", 0x03),
+	("\u{1f1e7}\u{1f1fb}", 0x03),
+	("\u{1f1e7}\u{1f1fc}", 0x03),
+	("\u{1f1e7}\u{1f1fe}", 0x03),
+	("\u{1f1e7}\u{1f1ff}", 0x03),
+	("\u{1f1e8}\u{1f1e6}", 0x03),
+	("\u{1f1e8}\u{1f1e8}", 0x03),
+	("\u{1f1e8}\u{1f1e9}", 0x03),
+	("\u{1f1e8}\u{1f1eb}", 0x03),
+	("\u{1f1e8}\u{1f1ec}", 0x03),
+	("\u{1f1e8}\u{1f1ed}", 0x03),
+	("\u{1f1e8}\u{1f1ee}", 0x03),
+	("\u{1f1e8}\u{1f1f0}", 0x03),
+	("\u{1f1e8}\u{1f1f1}", 0x03),
+	("\u{1f1e8}\u{1f1f2}", 0x03),
+	("\u{1f1e8}\u{1f1f3}", 0x03),
+	("\u{1f1e8}\u{1f1f4}", 0x03),
+	("\u{1f1e8}\u{1f1f5}", 0x03),
+	("\u{1f1e8}\u{1f1f6}", 0x03),
+	("\u{1f1e8}\u{1f1f7}", 0x03),
+	("\u{1f1e8}\u{1f1fa}", 0x03),
+	("\u{1f1e8}\u{1f1fb}", 0x03),
+	("\u{1f1e8}\u{1f1fc}", 0x03),
+	("\u{1f1e8}\u{1f1fd}", 0x03),
+	("\u{1f1e8}\u{1f1fe}", 0x03),
+	("\u{1f1e8}\u{1f1ff}", 0x03),
+	("\u{1f1e9}\u{1f1ea}", 0x03),
+	("\u{1f1e9}\u{1f1ec}", 0x03),
+	("\u{1f1e9}\u{1f1ef}", 0x03),
+	("\u{1f1e9}\u{1f1f0}", 0x03),
+	("\u{1f1e9}\u{1f1f2}", 0x03),
+	("\u{1f1e9}\u{1f1f4}", 0x03),
+	("\u{1f1e9}\u{1f1ff}", 0x03),
+	("\u{1f1ea}\u{1f1e6}", 0x03),
+	("\u{1f1ea}\u{1f1e8}", 0x03),
+	("\u{1f1ea}\u{1f1ea}", 0x03),
+	("\u{1f1ea}\u{1f1ec}", 0x03),
+	("\u{1f1ea}\u{1f1ed}", 0x03),
+	("\u{1f1ea}\u{1f1f7}", 0x03),
+	("\u{1f1ea}\u{1f1f8}", 0x03),
+	("\u{1f1ea}\u{1f1f9}", 0x03),
+	("\u{1f1ea}\u{1f1fa}", 0x03),
+	("\u{1f1eb}\u{1f1ee}", 0x03),
+	("\u{1f1eb}\u{1f1ef}", 0x03),
+	("\u{1f1eb}\u{1f1f0}", 0x03),
+	("\u{1f1eb}\u{1f1f2}", 0x03),
+	("\u{1f1eb}\u{1f1f4}", 0x03),
+	("\u{1f1eb}\u{1f1f7}", 0x03),
+	("\u{1f1ec}\u{1f1e6}", 0x03),
+	("\u{1f1ec}\u{1f1e7}", 0x03),
+	("\u{1f1ec}\u{1f1e9}", 0x03),
+	("\u{1f1ec}\u{1f1ea}", 0x03),
+	("\u{1f1ec}\u{1f1eb}", 0x03),
+	("\u{1f1ec}\u{1f1ec}", 0x03),
+	("\u{1f1ec}\u{1f1ed}", 0x03),
+	("\u{1f1ec}\u{1f1ee}", 0x03),
+	("\u{1f1ec}\u{1f1f1}", 0x03),
+	("\u{1f1ec}\u{1f1f2}", 0x03),
+	("\u{1f1ec}\u{1f1f3}", 0x03),
+	("\u{1f1ec}\u{1f1f5}", 0x03),
+	("\u{1f1ec}\u{1f1f6}", 0x03),
+	("\u{1f1ec}\u{1f1f7}", 0x03),
+	("\u{1f1ec}\u{1f1f8}", 0x03),
+	("\u{1f1ec}\u{1f1f9}", 0x03),
+	("\u{1f1ec}\u{1f1fa}", 0x03),
+	("\u{1f1ec}\u{1f1fc}", 0x03),
+	("\u{1f1ec}\u{1f1fe}", 0x03),
+	("\u{1f1ed}\u{1f1f0}", 0x03),
+	("\u{1f1ed}\u{1f1f2}", 0x03),
+	("\u{1f1ed}\u{1f1f3}", 0x03),
+	("\u{1f1ed}\u{1f1f7}", 0x03),
+	("\u{1f1ed}\u{1f1f9}", 0x03),
+	("\u{1f1ed}\u{1f1fa}", 0x03),
+	("\u{1f1ee}\u{1f1e8}", 0x03),
+	("\u{1f1ee}\u{1f1e9}", 0x03),
+	("\u{1f1ee}\u{1f1ea}", 0x03),
+	("\u{1f1ee}\u{1f1f1}", 0x03),
+	("\u{1f1ee}\u{1f1f2}", 0x03),
+	("\u{1f1ee}\u{1f1f3}", 0x03),
+	("\u{1f1ee}\u{1f1f4}", 0x03),
+	("\u{1f1ee}\u{1f1f6}", 0x03),
+	("\u{1f1ee}\u{1f1f7}", 0x03),
+	("\u{1f1ee}\u{1f1f8}", 0x03),
+	("\u{1f1ee}\u{1f1f9}", 0x03),
+	("\u{1f1ef}\u{1f1ea}", 0x03),
+	("\u{1f1ef}\u{1f1f2}", 0x03),
+	("\u{1f1ef}\u{1f1f4}", 0x03),
+	("\u{1f1ef}\u{1f1f5}", 0x03),
+	("\u{1f1f0}\u{1f1ea}", 0x03),
+	("\u{1f1f0}\u{1f1ec}", 0x03),
+	("\u{1f1f0}\u{1f1ed}", 0x03),
+	("\u{1f1f0}\u{1f1ee}", 0x03),
+	("\u{1f1f0}\u{1f1f2}", 0x03),
+	("\u{1f1f0}\u{1f1f3}", 0x03),
+	("\u{1f1f0}\u{1f1f5}", 0x03),
+	("\u{1f1f0}\u{1f1f7}", 0x03),
+	("\u{1f1f0}\u{1f1fc}", 0x03),
+	("\u{1f1f0}\u{1f1fe}", 0x03),
+	("\u{1f1f0}\u{1f1ff}", 0x03),
+	("\u{1f1f1}\u{1f1e6}", 0x03),
+	("\u{1f1f1}\u{1f1e7}", 0x03),
+	("\u{1f1f1}\u{1f1e8}", 0x03),
+	("\u{1f1f1}\u{1f1ee}", 0x03),
+	("\u{1f1f1}\u{1f1f0}", 0x03),
+	("\u{1f1f1}\u{1f1f7}", 0x03),
+	("\u{1f1f1}\u{1f1f8}", 0x03),
+	("\u{1f1f1}\u{1f1f9}", 0x03),
+	("\u{1f1f1}\u{1f1fa}", 0x03),
+	("\u{1f1f1}\u{1f1fb}", 0x03),
+	("\u{1f1f1}\u{1f1fe}", 0x03),
+	("\u{1f1f2}\u{1f1e6}", 0x03),
+	("\u{1f1f2}\u{1f1e8}", 0x03),
+	("\u{1f1f2}\u{1f1e9}", 0x03),
+	("\u{1f1f2}\u{1f1ea}", 0x03),
+	("\u{1f1f2}\u{1f1eb}", 0x03),
+	("\u{1f1f2}\u{1f1ec}", 0x03),
+	("\u{1f1f2}\u{1f1ed}", 0x03),
+	("\u{1f1f2}\u{1f1f0}", 0x03),
+	("\u{1f1f2}\u{1f1f1}", 0x03),
+	("\u{1f1f2}\u{1f1f2}", 0x03),
+	("\u{1f1f2}\u{1f1f3}", 0x03),
+	("\u{1f1f2}\u{1f1f4}", 0x03),
+	("\u{1f1f2}\u{1f1f5}", 0x03),
+	("\u{1f1f2}\u{1f1f6}", 0x03),
+	("\u{1f1f2}\u{1f1f7}", 0x03),
+	("\u{1f1f2}\u{1f1f8}", 0x03),
+	("\u{1f1f2}\u{1f1f9}", 0x03),
+	("\u{1f1f2}\u{1f1fa}", 0x03),
+	("\u{1f1f2}\u{1f1fb}", 0x03),
+	("\u{1f1f2}\u{1f1fc}", 0x03),
+	("\u{1f1f2}\u{1f1fd}", 0x03),
+	("\u{1f1f2}\u{1f1fe}", 0x03),
+	("\u{1f1f2}\u{1f1ff}", 0x03),
+	("\u{1f1f3}\u{1f1e6}", 0x03),
+	("\u{1f1f3}\u{1f1e8}", 0x03),
+	("\u{1f1f3}\u{1f1ea}", 0x03),
+	("\u{1f1f3}\u{1f1eb}", 0x03),
+	("\u{1f1f3}\u{1f1ec}", 0x03),
+	("\u{1f1f3}\u{1f1ee}", 0x03),
+	("\u{1f1f3}\u{1f1f1}", 0x03),
+	("\u{1f1f3}\u{1f1f4}", 0x03),
+	("\u{1f1f3}\u{1f1f5}", 0x03),
+	("\u{1f1f3}\u{1f1f7}", 0x03),
+	("\u{1f1f3}\u{1f1fa}", 0x03),
+	("\u{1f1f3}\u{1f1ff}", 0x03),
+	("\u{1f1f4}\u{1f1f2}", 0x03),
+	("\u{1f1f5}\u{1f1e6}", 0x03),
+	("\u{1f1f5}\u{1f1ea}", 0x03),
+	("\u{1f1f5}\u{1f1eb}", 0x03),
+	("\u{1f1f5}\u{1f1ec}", 0x03),
+	("\u{1f1f5}\u{1f1ed}", 0x03),
+	("\u{1f1f5}\u{1f1f0}", 0x03),
+	("\u{1f1f5}\u{1f1f1}", 0x03),
+	("\u{1f1f5}\u{1f1f2}", 0x03),
+	("\u{1f1f5}\u{1f1f3}", 0x03),
+	("\u{1f1f5}\u{1f1f7}", 0x03),
+	("\u{1f1f5}\u{1f1f8}", 0x03),
+	("\u{1f1f5}\u{1f1f9}", 0x03),
+	("\u{1f1f5}\u{1f1fc}", 0x03),
+	("\u{1f1f5}\u{1f1fe}", 0x03),
+	("\u{1f1f6}\u{1f1e6}", 0x03),
+	("\u{1f1f7}\u{1f1ea}", 0x03),
+	("\u{1f1f7}\u{1f1f4}", 0x03),
+	("\u{1f1f7}\u{1f1f8}", 0x03),
+	("\u{1f1f7}\u{1f1fa}", 0x03),
+	("\u{1f1f7}\u{1f1fc}", 0x03),
+	("\u{1f1f8}\u{1f1e6}", 0x03),
+	("\u{1f1f8}\u{1f1e7}", 0x03),
+	("\u{1f1f8}\u{1f1e8}", 0x03),
+	("\u{1f1f8}\u{1f1e9}", 0x03),
+	("\u{1f1f8}\u{1f1ea}", 0x03),
+	("\u{1f1f8}\u{1f1ec}", 0x03),
+	("\u{1f1f8}\u{1f1ed}", 0x03),
+	("\u{1f1f8}\u{1f1ee}", 0x03),
+	("\u{1f1f8}\u{1f1ef}", 0x03),
+	("\u{1f1f8}\u{1f1f0}", 0x03),
+	("\u{1f1f8}\u{1f1f1}", 0x03),
+	("\u{1f1f8}\u{1f1f2}", 0x03),
+	("\u{1f1f8}\u{1f1f3}", 0x03),
+	("\u{1f1f8}\u{1f1f4}", 0x03),
+	("\u{1f1f8}\u{1f1f7}", 0x03),
+	("\u{1f1f8}\u{1f1f8}", 0x03),
+	("\u{1f1f8}\u{1f1f9}", 0x03),
+	("\u{1f1f8}\u{1f1fb}", 0x03),
+	("\u{1f1f8}\u{1f1fd}", 0x03),
+	("\u{1f1f8}\u{1f1fe}", 0x03),
+	("\u{1f1f8}\u{1f1ff}", 0x03),
+	("\u{1f1f9}\u{1f1e6}", 0x03),
+	("\u{1f1f9}\u{1f1e8}", 0x03),
+	("\u{1f1f9}\u{1f1e9}", 0x03),
+	("\u{1f1f9}\u{1f1eb}", 0x03),
+	("\u{1f1f9}\u{1f1ec}", 0x03),
+	("\u{1f1f9}\u{1f1ed}", 0x03),
+	("\u{1f1f9}\u{1f1ef}", 0x03),
+	("\u{1f1f9}\u{1f1f0}", 0x03),
+	("\u{1f1f9}\u{1f1f1}", 0x03),
+	("\u{1f1f9}\u{1f1f2}", 0x03),
+	("\u{1f1f9}\u{1f1f3}", 0x03),
+	("\u{1f1f9}\u{1f1f4}", 0x03),
+	("\u{1f1f9}\u{1f1f7}", 0x03),
+	("\u{1f1f9}\u{1f1f9}", 0x03),
+	("\u{1f1f9}\u{1f1fb}", 0x03),
+	("\u{1f1f9}\u{1f1fc}", 0x03),
+	("\u{1f1f9}\u{1f1ff}", 0x03),
+	("\u{1f1fa}\u{1f1e6}", 0x03),
+	("\u{1f1fa}\u{1f1ec}", 0x03),
+	("\u{1f1fa}\u{1f1f2}", 0x03),
+	("\u{1f1fa}\u{1f1f3}", 0x03),
+	("\u{1f1fa}\u{1f1f8}", 0x03),
+	("\u{1f1fa}\u{1f1fe}", 0x03),
+	("\u{1f1fa}\u{1f1ff}", 0x03),
+	("\u{1f1fb}\u{1f1e6}", 0x03),
+	("\u{1f1fb}\u{1f1e8}", 0x03),
+	("\u{1f1fb}\u{1f1ea}", 0x03),
+	("\u{1f1fb}\u{1f1ec}", 0x03),
+	("\u{1f1fb}\u{1f1ee}", 0x03),
+	("\u{1f1fb}\u{1f1f3}", 0x03),
+	("\u{1f1fb}\u{1f1fa}", 0x03),
+	("\u{1f1fc}\u{1f1eb}", 0x03),
+	("\u{1f1fc}\u{1f1f8}", 0x03),
+	("\u{1f1fd}\u{1f1f0}", 0x03),
+	("\u{1f1fe}\u{1f1ea}", 0x03),
+	("\u{1f1fe}\u{1f1f9}", 0x03),
+	("\u{1f1ff}\u{1f1e6}", 0x03),
+	("\u{1f1ff}\u{1f1f2}", 0x03),
+	("\u{1f1ff}\u{1f1fc}", 0x03),
+	("\u{1f201}", 0x01),
+	("\u{1f202}\u{fe0f}", 0x01),
+	("\u{1f21a}", 0x01),
+	("\u{1f22f}", 0x01),
+	("\u{1f232}", 0x01),
+	("\u{1f233}", 0x01),
+	("\u{1f234}", 0x01),
+	("\u{1f235}", 0x01),
+	("\u{1f236}", 0x01),
+	("\u{1f237}\u{fe0f}", 0x01),
+	("\u{1f238}", 0x01),
+	("\u{1f239}", 0x01),
+	("\u{1f23a}", 0x01),
+	("\u{1f250}", 0x01),
+	("\u{1f251}", 0x01),
+	("\u{1f300}", 0x01),
+	("\u{1f301}", 0x01),
+	("\u{1f302}", 0x01),
+	("\u{1f303}", 0x01),
+	("\u{1f304}", 0x01),
+	("\u{1f305}", 0x01),
+	("\u{1f306}", 0x01),
+	("\u{1f307}", 0x01),
+	("\u{1f308}", 0x01),
+	("\u{1f309}", 0x01),
+	("\u{1f30a}", 0x01),
+	("\u{1f30b}", 0x01),
+	("\u{1f30c}", 0x01),
+	("\u{1f30d}", 0x01),
+	("\u{1f30e}", 0x01),
+	("\u{1f30f}", 0x01),
+	("\u{1f310}", 0x01),
+	("\u{1f311}", 0x01),
+	("\u{1f312}", 0x01),
+	("\u{1f313}", 0x01),
+	("\u{1f314}", 0x01),
+	("\u{1f315}", 0x01),
+	("\u{1f316}", 0x01),
+	("\u{1f317}", 0x01),
+	("\u{1f318}", 0x01),
+	("\u{1f319}", 0x01),
+	("\u{1f31a}", 0x01),
+	("\u{1f31b}", 0x01),
+	("\u{1f31c}", 0x01),
+	("\u{1f31d}", 0x01),
+	("\u{1f31e}", 0x01),
+	("\u{1f31f}", 0x01),
+	("\u{1f320}", 0x01),
+	("\u{1f321}\u{fe0f}", 0x01),
+	("\u{1f324}\u{fe0f}", 0x01),
+	("\u{1f325}\u{fe0f}", 0x01),
+	("\u{1f326}\u{fe0f}", 0x01),
+	("\u{1f327}\u{fe0f}", 0x01),
+	("\u{1f328}\u{fe0f}", 0x01),
+	("\u{1f329}\u{fe0f}", 0x01),
+	("\u{1f32a}\u{fe0f}", 0x01),
+	("\u{1f32b}\u{fe0f}", 0x01),
+	("\u{1f32c}\u{fe0f}", 0x01),
+	("\u{1f32d}", 0x01),
+	("\u{1f32e}", 0x01),
+	("\u{1f32f}", 0x01),
+	("\u{1f330}", 0x01),
+	("\u{1f331}", 0x01),
+	("\u{1f332}", 0x01),
+	("\u{1f333}", 0x01),
+	("\u{1f334}", 0x01),
+	("\u{1f335}", 0x01),
+	("\u{1f336}\u{fe0f}", 0x01),
+	("\u{1f337}", 0x01),
+	("\u{1f338}", 0x01),
+	("\u{1f339}", 0x01),
+	("\u{1f33a}", 0x01),
+	("\u{1f33b}", 0x01),
+	("\u{1f33c}", 0x01),
+	("\u{1f33d}", 0x01),
+	("\u{1f33e}", 0x01),
+	("\u{1f33f}", 0x01),
+	("\u{1f340}", 0x01),
+	("\u{1f341}", 0x01),
+	("\u{1f342}", 0x01),
+	("\u{1f343}", 0x01),
+	("\u{1f344}", 0x01),
+	("\u{1f344}\u{200d}\u{1f7eb}", 0x05),
+	("\u{1f345}", 0x01),
+	("\u{1f346}", 0x01),
+	("\u{1f347}", 0x01),
+	("\u{1f348}", 0x01),
+	("\u{1f349}", 0x01),
+	("\u{1f34a}", 0x01),
+	("\u{1f34b}", 0x01),
+	("\u{1f34b}\u{200d}\u{1f7e9}", 0x05),
+	("\u{1f34c}", 0x01),
+	("\u{1f34d}", 0x01),
+	("\u{1f34e}", 0x01),
+	("\u{1f34f}", 0x01),
+	("\u{1f350}", 0x01),
+	("\u{1f351}", 0x01),
+	("\u{1f352}", 0x01),
+	("\u{1f353}", 0x01),
+	("\u{1f354}", 0x01),
+	("\u{1f355}", 0x01),
+	("\u{1f356}", 0x01),
+	("\u{1f357}", 0x01),
+	("\u{1f358}", 0x01),
+	("\u{1f359}", 0x01),
+	("\u{1f35a}", 0x01),
+	("\u{1f35b}", 0x01),
+	("\u{1f35c}", 0x01),
+	("\u{1f35d}", 0x01),
+	("\u{1f35e}", 0x01),
+	("\u{1f35f}", 0x01),
+	("\u{1f360}", 0x01),
+	("\u{1f361}", 0x01),
+	("\u{1f362}", 0x01),
+	("\u{1f363}", 0x01),
+	("\u{1f364}", 0x01),
+	("\u{1f365}", 0x01),
+	("\u{1f366}", 0x01),
+	("\u{1f367}", 0x01),
+	("\u{1f368}", 0x01),
+	("\u{1f369}", 0x01),
+	("\u{1f36a}", 0x01),
+	("\u{1f36b}", 0x01),
+	("\u{1f36c}", 0x01),
+	("\u{1f36d}", 0x01),
+	("\u{1f36e}", 0x01),
+	("\u{1f36f}", 0x01),
+	("\u{1f370}", 0x01),
+	("\u{1f371}", 0x01),
+	("\u{1f372}", 0x01),
+	("\u{1f373}", 0x01),
+	("\u{1f374}", 0x01),
+	("\u{1f375}", 0x01),
+	("\u{1f376}", 0x01),
+	("\u{1f377}", 0x01),
+	("\u{1f378}", 0x01),
+	("\u{1f379}", 0x01),
+	("\u{1f37a}", 0x01),
+	("\u{1f37b}", 0x01),
+	("\u{1f37c}", 0x01),
+	("\u{1f37d}\u{fe0f}", 0x01),
+	("\u{1f37e}", 0x01),
+	("\u{1f37f}", 0x01),
+	("\u{1f380}", 0x01),
+	("\u{1f381}", 0x01),
+	("\u{1f382}", 0x01),
+	("\u{1f383}", 0x01),
+	("\u{1f384}", 0x01),
+	("\u{1f385}", 0x01),
+	("\u{1f385}\u{1f3fb}", 0x04),
+	("\u{1f385}\u{1f3fc}", 0x04),
+	("\u{1f385}\u{1f3fd}", 0x04),
+	("\u{1f385}\u{1f3fe}", 0x04),
+	("\u{1f385}\u{1f3ff}", 0x04),
+	("\u{1f386}", 0x01),
+	("\u{1f387}", 0x01),
+	("\u{1f388}", 0x01),
+	("\u{1f389}", 0x01),
+	("\u{1f38a}", 0x01),
+	("\u{1f38b}", 0x01),
+	("\u{1f38c}", 0x01),
+	("\u{1f38d}", 0x01),
+	("\u{1f38e}", 0x01),
+	("\u{1f38f}", 0x01),
+	("\u{1f390}", 0x01),
+	("\u{1f391}", 0x01),
+	("\u{1f392}", 0x01),
+	("\u{1f393}", 0x01),
+	("\u{1f396}\u{fe0f}", 0x01),
+	("\u{1f397}\u{fe0f}", 0x01),
+	("\u{1f399}\u{fe0f}", 0x01),
+	("\u{1f39a}\u{fe0f}", 0x01),
+	("\u{1f39b}\u{fe0f}", 0x01),
+	("\u{1f39e}\u{fe0f}", 0x01),
+	("\u{1f39f}\u{fe0f}", 0x01),
+	("\u{1f3a0}", 0x01),
+	("\u{1f3a1}", 0x01),
+	("\u{1f3a2}", 0x01),
+	("\u{1f3a3}", 0x01),
+	("\u{1f3a4}", 0x01),
+	("\u{1f3a5}", 0x01),
+	("\u{1f3a6}", 0x01),
+	("\u{1f3a7}", 0x01),
+	("\u{1f3a8}", 0x01),
+	("\u{1f3a9}", 0x01),
+	("\u{1f3aa}", 0x01),
+	("\u{1f3ab}", 0x01),
+	("\u{1f3ac}", 0x01),
+	("\u{1f3ad}", 0x01),
+	("\u{1f3ae}", 0x01),
+	("\u{1f3af}", 0x01),
+	("\u{1f3b0}", 0x01),
+	("\u{1f3b1}", 0x01),
+	("\u{1f3b2}", 0x01),
+	("\u{1f3b3}", 0x01),
+	("\u{1f3b4}", 0x01),
+	("\u{1f3b5}", 0x01),
+	("\u{1f3b6}", 0x01),
+	("\u{1f3b7}", 0x01),
+	("\u{1f3b8}", 0x01),
+	("\u{1f3b9}", 0x01),
+	("\u{1f3ba}", 0x01),
+	("\u{1f3bb}", 0x01),
+	("\u{1f3bc}", 0x01),
+	("\u{1f3bd}", 0x01),
+	("\u{1f3be}", 0x01),
+	("\u{1f3bf}", 0x01),
+	("\u{1f3c0}", 0x01),
+	("\u{1f3c1}", 0x01),
+	("\u{1f3c2}", 0x01),
+	("\u{1f3c2}\u{1f3fb}", 0x04),
+	("\u{1f3c2}\u{1f3fc}", 0x04),
+	("\u{1f3c2}\u{1f3fd}", 0x04),
+	("\u{1f3c2}\u{1f3fe}", 0x04),
+	("\u{1f3c2}\u{1f3ff}", 0x04),
+	("\u{1f3c3}", 0x01),
+	("\u{1f3c3}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f3c3}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f3c3}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f3c3}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f3c3}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f3c3}\u{1f3fb}", 0x04),
+	("\u{1f3c3}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f3c3}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f3c3}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f3c3}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f3c3}\u{1f3fb}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f3c3}\u{1f3fc}", 0x04),
+	("\u{1f3c3}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f3c3}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f3c3}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f3c3}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f3c3}\u{1f3fc}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f3c3}\u{1f3fd}", 0x04),
+	("\u{1f3c3}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f3c3}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f3c3}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f3c3}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f3c3}\u{1f3fd}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f3c3}\u{1f3fe}", 0x04),
+	("\u{1f3c3}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f3c3}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f3c3}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f3c3}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f3c3}\u{1f3fe}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f3c3}\u{1f3ff}", 0x04),
+	("\u{1f3c3}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f3c3}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f3c3}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f3c3}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f3c3}\u{1f3ff}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f3c4}", 0x01),
+	("\u{1f3c4}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f3c4}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f3c4}\u{1f3fb}", 0x04),
+	("\u{1f3c4}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f3c4}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f3c4}\u{1f3fc}", 0x04),
+	("\u{1f3c4}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f3c4}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f3c4}\u{1f3fd}", 0x04),
+	("\u{1f3c4}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f3c4}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f3c4}\u{1f3fe}", 0x04),
+	("\u{1f3c4}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f3c4}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f3c4}\u{1f3ff}", 0x04),
+	("\u{1f3c4}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f3c4}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f3c5}", 0x01),
+	("\u{1f3c6}", 0x01),
+	("\u{1f3c7}", 0x01),
+	("\u{1f3c7}\u{1f3fb}", 0x04),
+	("\u{1f3c7}\u{1f3fc}", 0x04),
+	("\u{1f3c7}\u{1f3fd}", 0x04),
+	("\u{1f3c7}\u{1f3fe}", 0x04),
+	("\u{1f3c7}\u{1f3ff}", 0x04),
+	("\u{1f3c8}", 0x01),
+	("\u{1f3c9}", 0x01),
+	("\u{1f3ca}", 0x01),
+	("\u{1f3ca}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f3ca}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f3ca}\u{1f3fb}", 0x04),
+	("\u{1f3ca}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f3ca}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f3ca}\u{1f3fc}", 0x04),
+	("\u{1f3ca}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f3ca}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f3ca}\u{1f3fd}", 0x04),
+	("\u{1f3ca}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f3ca}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f3ca}\u{1f3fe}", 0x04),
+	("\u{1f3ca}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f3ca}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f3ca}\u{1f3ff}", 0x04),
+	("\u{1f3ca}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f3ca}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f3cb}\u{fe0f}", 0x01),
+	("\u{1f3cb}\u{fe0f}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f3cb}\u{fe0f}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f3cb}\u{1f3fb}", 0x04),
+	("\u{1f3cb}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f3cb}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f3cb}\u{1f3fc}", 0x04),
+	("\u{1f3cb}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f3cb}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f3cb}\u{1f3fd}", 0x04),
+	("\u{1f3cb}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f3cb}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f3cb}\u{1f3fe}", 0x04),
+	("\u{1f3cb}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f3cb}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f3cb}\u{1f3ff}", 0x04),
+	("\u{1f3cb}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f3cb}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f3cc}\u{fe0f}", 0x01),
+	("\u{1f3cc}\u{fe0f}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f3cc}\u{fe0f}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f3cc}\u{1f3fb}", 0x04),
+	("\u{1f3cc}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f3cc}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f3cc}\u{1f3fc}", 0x04),
+	("\u{1f3cc}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f3cc}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f3cc}\u{1f3fd}", 0x04),
+	("\u{1f3cc}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f3cc}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f3cc}\u{1f3fe}", 0x04),
+	("\u{1f3cc}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f3cc}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f3cc}\u{1f3ff}", 0x04),
+	("\u{1f3cc}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f3cc}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f3cd}\u{fe0f}", 0x01),
+	("\u{1f3ce}\u{fe0f}", 0x01),
+	("\u{1f3cf}", 0x01),
+	("\u{1f3d0}", 0x01),
+	("\u{1f3d1}", 0x01),
+	("\u{1f3d2}", 0x01),
+	("\u{1f3d3}", 0x01),
+	("\u{1f3d4}\u{fe0f}", 0x01),
+	("\u{1f3d5}\u{fe0f}", 0x01),
+	("\u{1f3d6}\u{fe0f}", 0x01),
+	("\u{1f3d7}\u{fe0f}", 0x01),
+	("\u{1f3d8}\u{fe0f}", 0x01),
+	("\u{1f3d9}\u{fe0f}", 0x01),
+	("\u{1f3da}\u{fe0f}", 0x01),
+	("\u{1f3db}\u{fe0f}", 0x01),
+	("\u{1f3dc}\u{fe0f}", 0x01),
+	("\u{1f3dd}\u{fe0f}", 0x01),
+	("\u{1f3de}\u{fe0f}", 0x01),
+	("\u{1f3df}\u{fe0f}", 0x01),
+	("\u{1f3e0}", 0x01),
+	("\u{1f3e1}", 0x01),
+	("\u{1f3e2}", 0x01),
+	("\u{1f3e3}", 0x01),
+	("\u{1f3e4}", 0x01),
+	("\u{1f3e5}", 0x01),
+	("\u{1f3e6}", 0x01),
+	("\u{1f3e7}", 0x01),
+	("\u{1f3e8}", 0x01),
+	("\u{1f3e9}", 0x01),
+	("\u{1f3ea}", 0x01),
+	("\u{1f3eb}", 0x01),
+	("\u{1f3ec}", 0x01),
+	("\u{1f3ed}", 0x01),
+	("\u{1f3ee}", 0x01),
+	("\u{1f3ef}", 0x01),
+	("\u{1f3f0}", 0x01),
+	("\u{1f3f3}\u{fe0f}", 0x01),
+	("\u{1f3f3}\u{fe0f}\u{200d}\u{26a7}\u{fe0f}", 0x05),
+	("\u{1f3f3}\u{fe0f}\u{200d}\u{1f308}", 0x05),
+	("\u{1f3f4}", 0x01),
+	("\u{1f3f4}\u{200d}\u{2620}\u{fe0f}", 0x05),
+	("\u{1f3f4}\u{e0067}\u{e0062}\u{e0065}\u{e006e}\u{e0067}\u{e007f}", 0x06),
+	("\u{1f3f4}\u{e0067}\u{e0062}\u{e0073}\u{e0063}\u{e0074}\u{e007f}", 0x06),
+	("\u{1f3f4}\u{e0067}\u{e0062}\u{e0077}\u{e006c}\u{e0073}\u{e007f}", 0x06),
+	("\u{1f3f5}\u{fe0f}", 0x01),
+	("\u{1f3f7}\u{fe0f}", 0x01),
+	("\u{1f3f8}", 0x01),
+	("\u{1f3f9}", 0x01),
+	("\u{1f3fa}", 0x01),
+	("\u{1f3fb}", 0x01),
+	("\u{1f3fc}", 0x01),
+	("\u{1f3fd}", 0x01),
+	("\u{1f3fe}", 0x01),
+	("\u{1f3ff}", 0x01),
+	("\u{1f400}", 0x01),
+	("\u{1f401}", 0x01),
+	("\u{1f402}", 0x01),
+	("\u{1f403}", 0x01),
+	("\u{1f404}", 0x01),
+	("\u{1f405}", 0x01),
+	("\u{1f406}", 0x01),
+	("\u{1f407}", 0x01),
+	("\u{1f408}", 0x01),
+	("\u{1f408}\u{200d}\u{2b1b}", 0x05),
+	("\u{1f409}", 0x01),
+	("\u{1f40a}", 0x01),
+	("\u{1f40b}", 0x01),
+	("\u{1f40c}", 0x01),
+	("\u{1f40d}", 0x01),
+	("\u{1f40e}", 0x01),
+	("\u{1f40f}", 0x01),
+	("\u{1f410}", 0x01),
+	("\u{1f411}", 0x01),
+	("\u{1f412}", 0x01),
+	("\u{1f413}", 0x01),
+	("\u{1f414}", 0x01),
+	("\u{1f415}", 0x01),
+	("\u{1f415}\u{200d}\u{1f9ba}", 0x05),
+	("\u{1f416}", 0x01),
+	("\u{1f417}", 0x01),
+	("\u{1f418}", 0x01),
+	("\u{1f419}", 0x01),
+	("\u{1f41a}", 0x01),
+	("\u{1f41b}", 0x01),
+	("\u{1f41c}", 0x01),
+	("\u{1f41d}", 0x01),
+	("\u{1f41e}", 0x01),
+	("\u{1f41f}", 0x01),
+	("\u{1f420}", 0x01),
+	("\u{1f421}", 0x01),
+	("\u{1f422}", 0x01),
+	("\u{1f423}", 0x01),
+	("\u{1f424}", 0x01),
+	("\u{1f425}", 0x01),
+	("\u{1f426}", 0x01),
+	("\u{1f426}\u{200d}\u{2b1b}", 0x05),
+	("\u{1f426}\u{200d}\u{1f525}", 0x05),
+	("\u{1f427}", 0x01),
+	("\u{1f428}", 0x01),
+	("\u{1f429}", 0x01),
+	("\u{1f42a}", 0x01),
+	("\u{1f42b}", 0x01),
+	("\u{1f42c}", 0x01),
+	("\u{1f42d}", 0x01),
+	("\u{1f42e}", 0x01),
+	("\u{1f42f}", 0x01),
+	("\u{1f430}", 0x01),
+	("\u{1f431}", 0x01),
+	("\u{1f432}", 0x01),
+	("\u{1f433}", 0x01),
+	("\u{1f434}", 0x01),
+	("\u{1f435}", 0x01),
+	("\u{1f436}", 0x01),
+	("\u{1f437}", 0x01),
+	("\u{1f438}", 0x01),
+	("\u{1f439}", 0x01),
+	("\u{1f43a}", 0x01),
+	("\u{1f43b}", 0x01),
+	("\u{1f43b}\u{200d}\u{2744}\u{fe0f}", 0x05),
+	("\u{1f43c}", 0x01),
+	("\u{1f43d}", 0x01),
+	("\u{1f43e}", 0x01),
+	("\u{1f43f}\u{fe0f}", 0x01),
+	("\u{1f440}", 0x01),
+	("\u{1f441}\u{fe0f}", 0x01),
+	("\u{1f441}\u{fe0f}\u{200d}\u{1f5e8}\u{fe0f}", 0x05),
+	("\u{1f442}", 0x01),
+	("\u{1f442}\u{1f3fb}", 0x04),
+	("\u{1f442}\u{1f3fc}", 0x04),
+	("\u{1f442}\u{1f3fd}", 0x04),
+	("\u{1f442}\u{1f3fe}", 0x04),
+	("\u{1f442}\u{1f3ff}", 0x04),
+	("\u{1f443}", 0x01),
+	("\u{1f443}\u{1f3fb}", 0x04),
+	("\u{1f443}\u{1f3fc}", 0x04),
+	("\u{1f443}\u{1f3fd}", 0x04),
+	("\u{1f443}\u{1f3fe}", 0x04),
+	("\u{1f443}\u{1f3ff}", 0x04),
+	("\u{1f444}", 0x01),
+	("\u{1f445}", 0x01),
+	("\u{1f446}", 0x01),
+	("\u{1f446}\u{1f3fb}", 0x04),
+	("\u{1f446}\u{1f3fc}", 0x04),
+	("\u{1f446}\u{1f3fd}", 0x04),
+	("\u{1f446}\u{1f3fe}", 0x04),
+	("\u{1f446}\u{1f3ff}", 0x04),
+	("\u{1f447}", 0x01),
+	("\u{1f447}\u{1f3fb}", 0x04),
+	("\u{1f447}\u{1f3fc}", 0x04),
+	("\u{1f447}\u{1f3fd}", 0x04),
+	("\u{1f447}\u{1f3fe}", 0x04),
+	("\u{1f447}\u{1f3ff}", 0x04),
+	("\u{1f448}", 0x01),
+	("\u{1f448}\u{1f3fb}", 0x04),
+	("\u{1f448}\u{1f3fc}", 0x04),
+	("\u{1f448}\u{1f3fd}", 0x04),
+	("\u{1f448}\u{1f3fe}", 0x04),
+	("\u{1f448}\u{1f3ff}", 0x04),
+	("\u{1f449}", 0x01),
+	("\u{1f449}\u{1f3fb}", 0x04),
+	("\u{1f449}\u{1f3fc}", 0x04),
+	("\u{1f449}\u{1f3fd}", 0x04),
+	("\u{1f449}\u{1f3fe}", 0x04),
+	("\u{1f449}\u{1f3ff}", 0x04),
+	("\u{1f44a}", 0x01),
+	("\u{1f44a}\u{1f3fb}", 0x04),
+	("\u{1f44a}\u{1f3fc}", 0x04),
+	("\u{1f44a}\u{1f3fd}", 0x04),
+	("\u{1f44a}\u{1f3fe}", 0x04),
+	("\u{1f44a}\u{1f3ff}", 0x04),
+	("\u{1f44b}", 0x01),
+	("\u{1f44b}\u{1f3fb}", 0x04),
+	("\u{1f44b}\u{1f3fc}", 0x04),
+	("\u{1f44b}\u{1f3fd}", 0x04),
+	("\u{1f44b}\u{1f3fe}", 0x04),
+	("\u{1f44b}\u{1f3ff}", 0x04),
+	("\u{1f44c}", 0x01),
+	("\u{1f44c}\u{1f3fb}", 0x04),
+	("\u{1f44c}\u{1f3fc}", 0x04),
+	("\u{1f44c}\u{1f3fd}", 0x04),
+	("\u{1f44c}\u{1f3fe}", 0x04),
+	("\u{1f44c}\u{1f3ff}", 0x04),
+	("\u{1f44d}", 0x01),
+	("\u{1f44d}\u{1f3fb}", 0x04),
+	("\u{1f44d}\u{1f3fc}", 0x04),
+	("\u{1f44d}\u{1f3fd}", 0x04),
+	("\u{1f44d}\u{1f3fe}", 0x04),
+	("\u{1f44d}\u{1f3ff}", 0x04),
+	("\u{1f44e}", 0x01),
+	("\u{1f44e}\u{1f3fb}", 0x04),
+	("\u{1f44e}\u{1f3fc}", 0x04),
+	("\u{1f44e}\u{1f3fd}", 0x04),
+	("\u{1f44e}\u{1f3fe}", 0x04),
+	("\u{1f44e}\u{1f3ff}", 0x04),
+	("\u{1f44f}", 0x01),
+	("\u{1f44f}\u{1f3fb}", 0x04),
+	("\u{1f44f}\u{1f3fc}", 0x04),
+	("\u{1f44f}\u{1f3fd}", 0x04),
+	("\u{1f44f}\u{1f3fe}", 0x04),
+	("\u{1f44f}\u{1f3ff}", 0x04),
+	("\u{1f450}", 0x01),
+	("\u{1f450}\u{1f3fb}", 0x04),
+	("\u{1f450}\u{1f3fc}", 0x04),
+	("\u{1f450}\u{1f3fd}", 0x04),
+	("\u{1f450}\u{1f3fe}", 0x04),
+	("\u{1f450}\u{1f3ff}", 0x04),
+	("\u{1f451}", 0x01),
+	("\u{1f452}", 0x01),
+	("\u{1f453}", 0x01),
+	("\u{1f454}", 0x01),
+	("\u{1f455}", 0x01),
+	("\u{1f456}", 0x01),
+	("\u{1f457}", 0x01),
+	("\u{1f458}", 0x01),
+	("\u{1f459}", 0x01),
+	("\u{1f45a}", 0x01),
+	("\u{1f45b}", 0x01),
+	("\u{1f45c}", 0x01),
+	("\u{1f45d}", 0x01),
+	("\u{1f45e}", 0x01),
+	("\u{1f45f}", 0x01),
+	("\u{1f460}", 0x01),
+	("\u{1f461}", 0x01),
+	("\u{1f462}", 0x01),
+	("\u{1f463}", 0x01),
+	("\u{1f464}", 0x01),
+	("\u{1f465}", 0x01),
+	("\u{1f466}", 0x01),
+	("\u{1f466}\u{1f3fb}", 0x04),
+	("\u{1f466}\u{1f3fc}", 0x04),
+	("\u{1f466}\u{1f3fd}", 0x04),
+	("\u{1f466}\u{1f3fe}", 0x04),
+	("\u{1f466}\u{1f3ff}", 0x04),
+	("\u{1f467}", 0x01),
+	("\u{1f467}\u{1f3fb}", 0x04),
+	("\u{1f467}\u{1f3fc}", 0x04),
+	("\u{1f467}\u{1f3fd}", 0x04),
+	("\u{1f467}\u{1f3fe}", 0x04),
+	("\u{1f467}\u{1f3ff}", 0x04),
+	("\u{1f468}", 0x01),
+	("\u{1f468}\u{200d}\u{2695}\u{fe0f}", 0x05),
+	("\u{1f468}\u{200d}\u{2696}\u{fe0f}", 0x05),
+	("\u{1f468}\u{200d}\u{2708}\u{fe0f}", 0x05),
+	("\u{1f468}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}", 0x05),
+	("\u{1f468}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}", 0x05),
+	("\u{1f468}\u{200d}\u{1f33e}", 0x05),
+	("\u{1f468}\u{200d}\u{1f373}", 0x05),
+	("\u{1f468}\u{200d}\u{1f37c}", 0x05),
+	("\u{1f468}\u{200d}\u{1f393}", 0x05),
+	("\u{1f468}\u{200d}\u{1f3a4}", 0x05),
+	("\u{1f468}\u{200d}\u{1f3a8}", 0x05),
+	("\u{1f468}\u{200d}\u{1f3eb}", 0x05),
+	("\u{1f468}\u{200d}\u{1f3ed}", 0x05),
+	("\u{1f468}\u{200d}\u{1f466}", 0x05),
+	("\u{1f468}\u{200d}\u{1f466}\u{200d}\u{1f466}", 0x05),
+	("\u{1f468}\u{200d}\u{1f467}", 0x05),
+	("\u{1f468}\u{200d}\u{1f467}\u{200d}\u{1f466}", 0x05),
+	("\u{1f468}\u{200d}\u{1f467}\u{200d}\u{1f467}", 0x05),
+	("\u{1f468}\u{200d}\u{1f468}\u{200d}\u{1f466}", 0x05),
+	("\u{1f468}\u{200d}\u{1f468}\u{200d}\u{1f466}\u{200d}\u{1f466}", 0x05),
+	("\u{1f468}\u{200d}\u{1f468}\u{200d}\u{1f467}", 0x05),
+	("\u{1f468}\u{200d}\u{1f468}\u{200d}\u{1f467}\u{200d}\u{1f466}", 0x05),
+	("\u{1f468}\u{200d}\u{1f468}\u{200d}\u{1f467}\u{200d}\u{1f467}", 0x05),
+	("\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f466}", 0x05),
+	("\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f466}\u{200d}\u{1f466}", 0x05),
+	("\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}", 0x05),
+	("\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f466}", 0x05),
+	("\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f467}", 0x05),
+	("\u{1f468}\u{200d}\u{1f4bb}", 0x05),
+	("\u{1f468}\u{200d}\u{1f4bc}", 0x05),
+	("\u{1f468}\u{200d}\u{1f527}", 0x05),
+	("\u{1f468}\u{200d}\u{1f52c}", 0x05),
+	("\u{1f468}\u{200d}\u{1f680}", 0x05),
+	("\u{1f468}\u{200d}\u{1f692}", 0x05),
+	("\u{1f468}\u{200d}\u{1f9af}", 0x05),
+	("\u{1f468}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f468}\u{200d}\u{1f9b0}", 0x05),
+	("\u{1f468}\u{200d}\u{1f9b1}", 0x05),
+	("\u{1f468}\u{200d}\u{1f9b2}", 0x05),
+	("\u{1f468}\u{200d}\u{1f9b3}", 0x05),
+	("\u{1f468}\u{200d}\u{1f9bc}", 0x05),
+	("\u{1f468}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f468}\u{200d}\u{1f9bd}", 0x05),
+	("\u{1f468}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f468}\u{1f3fb}", 0x04),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{2695}\u{fe0f}", 0x05),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{2696}\u{fe0f}", 0x05),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{2708}\u{fe0f}", 0x05),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", 0x05),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", 0x05),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", 0x05),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", 0x05),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}", 0x05),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", 0x05),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", 0x05),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", 0x05),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", 0x05),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", 0x05),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{1f33e}", 0x05),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{1f373}", 0x05),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{1f37c}", 0x05),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{1f393}", 0x05),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{1f3a4}", 0x05),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{1f3a8}", 0x05),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{1f3eb}", 0x05),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{1f3ed}", 0x05),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{1f4bb}", 0x05),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{1f4bc}", 0x05),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{1f527}", 0x05),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{1f52c}", 0x05),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{1f680}", 0x05),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{1f692}", 0x05),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fc}", 0x05),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fd}", 0x05),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fe}", 0x05),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3ff}", 0x05),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{1f9af}", 0x05),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{1f9b0}", 0x05),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{1f9b1}", 0x05),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{1f9b2}", 0x05),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{1f9b3}", 0x05),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{1f9bc}", 0x05),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{1f9bd}", 0x05),
+	("\u{1f468}\u{1f3fb}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f468}\u{1f3fc}", 0x04),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{2695}\u{fe0f}", 0x05),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{2696}\u{fe0f}", 0x05),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{2708}\u{fe0f}", 0x05),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", 0x05),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", 0x05),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", 0x05),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", 0x05),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}", 0x05),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", 0x05),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", 0x05),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", 0x05),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", 0x05),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", 0x05),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{1f33e}", 0x05),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{1f373}", 0x05),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{1f37c}", 0x05),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{1f393}", 0x05),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{1f3a4}", 0x05),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{1f3a8}", 0x05),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{1f3eb}", 0x05),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{1f3ed}", 0x05),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{1f4bb}", 0x05),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{1f4bc}", 0x05),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{1f527}", 0x05),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{1f52c}", 0x05),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{1f680}", 0x05),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{1f692}", 0x05),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fb}", 0x05),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fd}", 0x05),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fe}", 0x05),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3ff}", 0x05),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{1f9af}", 0x05),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{1f9b0}", 0x05),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{1f9b1}", 0x05),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{1f9b2}", 0x05),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{1f9b3}", 0x05),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{1f9bc}", 0x05),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{1f9bd}", 0x05),
+	("\u{1f468}\u{1f3fc}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f468}\u{1f3fd}", 0x04),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{2695}\u{fe0f}", 0x05),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{2696}\u{fe0f}", 0x05),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{2708}\u{fe0f}", 0x05),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", 0x05),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", 0x05),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", 0x05),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", 0x05),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}", 0x05),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", 0x05),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", 0x05),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", 0x05),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", 0x05),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", 0x05),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{1f33e}", 0x05),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{1f373}", 0x05),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{1f37c}", 0x05),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{1f393}", 0x05),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{1f3a4}", 0x05),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{1f3a8}", 0x05),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{1f3eb}", 0x05),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{1f3ed}", 0x05),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{1f4bb}", 0x05),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{1f4bc}", 0x05),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{1f527}", 0x05),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{1f52c}", 0x05),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{1f680}", 0x05),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{1f692}", 0x05),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fb}", 0x05),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fc}", 0x05),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fe}", 0x05),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3ff}", 0x05),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{1f9af}", 0x05),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{1f9b0}", 0x05),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{1f9b1}", 0x05),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{1f9b2}", 0x05),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{1f9b3}", 0x05),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{1f9bc}", 0x05),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{1f9bd}", 0x05),
+	("\u{1f468}\u{1f3fd}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f468}\u{1f3fe}", 0x04),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{2695}\u{fe0f}", 0x05),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{2696}\u{fe0f}", 0x05),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{2708}\u{fe0f}", 0x05),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", 0x05),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", 0x05),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", 0x05),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", 0x05),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}", 0x05),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", 0x05),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", 0x05),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", 0x05),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", 0x05),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", 0x05),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{1f33e}", 0x05),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{1f373}", 0x05),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{1f37c}", 0x05),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{1f393}", 0x05),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{1f3a4}", 0x05),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{1f3a8}", 0x05),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{1f3eb}", 0x05),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{1f3ed}", 0x05),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{1f4bb}", 0x05),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{1f4bc}", 0x05),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{1f527}", 0x05),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{1f52c}", 0x05),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{1f680}", 0x05),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{1f692}", 0x05),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fb}", 0x05),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fc}", 0x05),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fd}", 0x05),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3ff}", 0x05),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{1f9af}", 0x05),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{1f9b0}", 0x05),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{1f9b1}", 0x05),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{1f9b2}", 0x05),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{1f9b3}", 0x05),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{1f9bc}", 0x05),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{1f9bd}", 0x05),
+	("\u{1f468}\u{1f3fe}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f468}\u{1f3ff}", 0x04),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{2695}\u{fe0f}", 0x05),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{2696}\u{fe0f}", 0x05),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{2708}\u{fe0f}", 0x05),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", 0x05),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", 0x05),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", 0x05),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", 0x05),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}", 0x05),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", 0x05),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", 0x05),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", 0x05),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", 0x05),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", 0x05),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{1f33e}", 0x05),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{1f373}", 0x05),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{1f37c}", 0x05),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{1f393}", 0x05),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{1f3a4}", 0x05),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{1f3a8}", 0x05),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{1f3eb}", 0x05),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{1f3ed}", 0x05),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{1f4bb}", 0x05),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{1f4bc}", 0x05),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{1f527}", 0x05),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{1f52c}", 0x05),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{1f680}", 0x05),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{1f692}", 0x05),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fb}", 0x05),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fc}", 0x05),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fd}", 0x05),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fe}", 0x05),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{1f9af}", 0x05),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{1f9b0}", 0x05),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{1f9b1}", 0x05),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{1f9b2}", 0x05),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{1f9b3}", 0x05),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{1f9bc}", 0x05),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{1f9bd}", 0x05),
+	("\u{1f468}\u{1f3ff}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f469}", 0x01),
+	("\u{1f469}\u{200d}\u{2695}\u{fe0f}", 0x05),
+	("\u{1f469}\u{200d}\u{2696}\u{fe0f}", 0x05),
+	("\u{1f469}\u{200d}\u{2708}\u{fe0f}", 0x05),
+	("\u{1f469}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}", 0x05),
+	("\u{1f469}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}", 0x05),
+	("\u{1f469}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}", 0x05),
+	("\u{1f469}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}", 0x05),
+	("\u{1f469}\u{200d}\u{1f33e}", 0x05),
+	("\u{1f469}\u{200d}\u{1f373}", 0x05),
+	("\u{1f469}\u{200d}\u{1f37c}", 0x05),
+	("\u{1f469}\u{200d}\u{1f393}", 0x05),
+	("\u{1f469}\u{200d}\u{1f3a4}", 0x05),
+	("\u{1f469}\u{200d}\u{1f3a8}", 0x05),
+	("\u{1f469}\u{200d}\u{1f3eb}", 0x05),
+	("\u{1f469}\u{200d}\u{1f3ed}", 0x05),
+	("\u{1f469}\u{200d}\u{1f466}", 0x05),
+	("\u{1f469}\u{200d}\u{1f466}\u{200d}\u{1f466}", 0x05),
+	("\u{1f469}\u{200d}\u{1f467}", 0x05),
+	("\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f466}", 0x05),
+	("\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f467}", 0x05),
+	("\u{1f469}\u{200d}\u{1f469}\u{200d}\u{1f466}", 0x05),
+	("\u{1f469}\u{200d}\u{1f469}\u{200d}\u{1f466}\u{200d}\u{1f466}", 0x05),
+	("\u{1f469}\u{200d}\u{1f469}\u{200d}\u{1f467}", 0x05),
+	("\u{1f469}\u{200d}\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f466}", 0x05),
+	("\u{1f469}\u{200d}\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f467}", 0x05),
+	("\u{1f469}\u{200d}\u{1f4bb}", 0x05),
+	("\u{1f469}\u{200d}\u{1f4bc}", 0x05),
+	("\u{1f469}\u{200d}\u{1f527}", 0x05),
+	("\u{1f469}\u{200d}\u{1f52c}", 0x05),
+	("\u{1f469}\u{200d}\u{1f680}", 0x05),
+	("\u{1f469}\u{200d}\u{1f692}", 0x05),
+	("\u{1f469}\u{200d}\u{1f9af}", 0x05),
+	("\u{1f469}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f469}\u{200d}\u{1f9b0}", 0x05),
+	("\u{1f469}\u{200d}\u{1f9b1}", 0x05),
+	("\u{1f469}\u{200d}\u{1f9b2}", 0x05),
+	("\u{1f469}\u{200d}\u{1f9b3}", 0x05),
+	("\u{1f469}\u{200d}\u{1f9bc}", 0x05),
+	("\u{1f469}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f469}\u{200d}\u{1f9bd}", 0x05),
+	("\u{1f469}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f469}\u{1f3fb}", 0x04),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{2695}\u{fe0f}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{2696}\u{fe0f}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{2708}\u{fe0f}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fb}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fc}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fd}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fe}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3ff}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{1f33e}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{1f373}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{1f37c}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{1f393}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{1f3a4}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{1f3a8}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{1f3eb}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{1f3ed}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{1f4bb}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{1f4bc}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{1f527}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{1f52c}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{1f680}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{1f692}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fc}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fd}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fe}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3ff}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fc}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fd}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fe}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3ff}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{1f9af}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{1f9b0}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{1f9b1}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{1f9b2}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{1f9b3}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{1f9bc}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{1f9bd}", 0x05),
+	("\u{1f469}\u{1f3fb}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f469}\u{1f3fc}", 0x04),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{2695}\u{fe0f}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{2696}\u{fe0f}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{2708}\u{fe0f}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fb}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fc}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fd}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fe}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3ff}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{1f33e}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{1f373}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{1f37c}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{1f393}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{1f3a4}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{1f3a8}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{1f3eb}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{1f3ed}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{1f4bb}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{1f4bc}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{1f527}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{1f52c}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{1f680}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{1f692}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fb}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fd}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fe}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3ff}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fb}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fd}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fe}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3ff}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{1f9af}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{1f9b0}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{1f9b1}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{1f9b2}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{1f9b3}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{1f9bc}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{1f9bd}", 0x05),
+	("\u{1f469}\u{1f3fc}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f469}\u{1f3fd}", 0x04),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{2695}\u{fe0f}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{2696}\u{fe0f}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{2708}\u{fe0f}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fb}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fc}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fd}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fe}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3ff}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{1f33e}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{1f373}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{1f37c}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{1f393}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{1f3a4}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{1f3a8}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{1f3eb}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{1f3ed}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{1f4bb}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{1f4bc}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{1f527}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{1f52c}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{1f680}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{1f692}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fb}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fc}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fe}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3ff}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fb}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fc}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fe}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3ff}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{1f9af}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{1f9b0}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{1f9b1}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{1f9b2}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{1f9b3}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{1f9bc}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{1f9bd}", 0x05),
+	("\u{1f469}\u{1f3fd}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f469}\u{1f3fe}", 0x04),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{2695}\u{fe0f}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{2696}\u{fe0f}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{2708}\u{fe0f}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fb}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fc}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fd}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fe}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3ff}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{1f33e}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{1f373}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{1f37c}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{1f393}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{1f3a4}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{1f3a8}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{1f3eb}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{1f3ed}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{1f4bb}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{1f4bc}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{1f527}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{1f52c}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{1f680}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{1f692}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fb}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fc}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fd}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3ff}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fb}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fc}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fd}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3ff}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{1f9af}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{1f9b0}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{1f9b1}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{1f9b2}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{1f9b3}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{1f9bc}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{1f9bd}", 0x05),
+	("\u{1f469}\u{1f3fe}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f469}\u{1f3ff}", 0x04),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{2695}\u{fe0f}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{2696}\u{fe0f}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{2708}\u{fe0f}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fb}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fc}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fd}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fe}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3ff}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{1f33e}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{1f373}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{1f37c}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{1f393}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{1f3a4}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{1f3a8}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{1f3eb}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{1f3ed}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{1f4bb}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{1f4bc}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{1f527}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{1f52c}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{1f680}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{1f692}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fb}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fc}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fd}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fe}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fb}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fc}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fd}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fe}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{1f9af}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{1f9b0}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{1f9b1}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{1f9b2}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{1f9b3}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{1f9bc}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{1f9bd}", 0x05),
+	("\u{1f469}\u{1f3ff}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f46a}", 0x01),
+	("\u{1f46b}", 0x01),
+	("\u{1f46b}\u{1f3fb}", 0x04),
+	("\u{1f46b}\u{1f3fc}", 0x04),
+	("\u{1f46b}\u{1f3fd}", 0x04),
+	("\u{1f46b}\u{1f3fe}", 0x04),
+	("\u{1f46b}\u{1f3ff}", 0x04),
+	("\u{1f46c}", 0x01),
+	("\u{1f46c}\u{1f3fb}", 0x04),
+	("\u{1f46c}\u{1f3fc}", 0x04),
+	("\u{1f46c}\u{1f3fd}", 0x04),
+	("\u{1f46c}\u{1f3fe}", 0x04),
+	("\u{1f46c}\u{1f3ff}", 0x04),
+	("\u{1f46d}", 0x01),
+	("\u{1f46d}\u{1f3fb}", 0x04),
+	("\u{1f46d}\u{1f3fc}", 0x04),
+	("\u{1f46d}\u{1f3fd}", 0x04),
+	("\u{1f46d}\u{1f3fe}", 0x04),
+	("\u{1f46d}\u{1f3ff}", 0x04),
+	("\u{1f46e}", 0x01),
+	("\u{1f46e}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f46e}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f46e}\u{1f3fb}", 0x04),
+	("\u{1f46e}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f46e}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f46e}\u{1f3fc}", 0x04),
+	("\u{1f46e}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f46e}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f46e}\u{1f3fd}", 0x04),
+	("\u{1f46e}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f46e}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f46e}\u{1f3fe}", 0x04),
+	("\u{1f46e}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f46e}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f46e}\u{1f3ff}", 0x04),
+	("\u{1f46e}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f46e}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f46f}", 0x01),
+	("\u{1f46f}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f46f}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f470}", 0x01),
+	("\u{1f470}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f470}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f470}\u{1f3fb}", 0x04),
+	("\u{1f470}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f470}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f470}\u{1f3fc}", 0x04),
+	("\u{1f470}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f470}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f470}\u{1f3fd}", 0x04),
+	("\u{1f470}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f470}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f470}\u{1f3fe}", 0x04),
+	("\u{1f470}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f470}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f470}\u{1f3ff}", 0x04),
+	("\u{1f470}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f470}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f471}", 0x01),
+	("\u{1f471}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f471}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f471}\u{1f3fb}", 0x04),
+	("\u{1f471}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f471}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f471}\u{1f3fc}", 0x04),
+	("\u{1f471}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f471}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f471}\u{1f3fd}", 0x04),
+	("\u{1f471}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f471}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f471}\u{1f3fe}", 0x04),
+	("\u{1f471}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f471}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f471}\u{1f3ff}", 0x04),
+	("\u{1f471}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f471}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f472}", 0x01),
+	("\u{1f472}\u{1f3fb}", 0x04),
+	("\u{1f472}\u{1f3fc}", 0x04),
+	("\u{1f472}\u{1f3fd}", 0x04),
+	("\u{1f472}\u{1f3fe}", 0x04),
+	("\u{1f472}\u{1f3ff}", 0x04),
+	("\u{1f473}", 0x01),
+	("\u{1f473}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f473}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f473}\u{1f3fb}", 0x04),
+	("\u{1f473}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f473}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f473}\u{1f3fc}", 0x04),
+	("\u{1f473}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f473}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f473}\u{1f3fd}", 0x04),
+	("\u{1f473}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f473}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f473}\u{1f3fe}", 0x04),
+	("\u{1f473}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f473}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f473}\u{1f3ff}", 0x04),
+	("\u{1f473}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f473}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f474}", 0x01),
+	("\u{1f474}\u{1f3fb}", 0x04),
+	("\u{1f474}\u{1f3fc}", 0x04),
+	("\u{1f474}\u{1f3fd}", 0x04),
+	("\u{1f474}\u{1f3fe}", 0x04),
+	("\u{1f474}\u{1f3ff}", 0x04),
+	("\u{1f475}", 0x01),
+	("\u{1f475}\u{1f3fb}", 0x04),
+	("\u{1f475}\u{1f3fc}", 0x04),
+	("\u{1f475}\u{1f3fd}", 0x04),
+	("\u{1f475}\u{1f3fe}", 0x04),
+	("\u{1f475}\u{1f3ff}", 0x04),
+	("\u{1f476}", 0x01),
+	("\u{1f476}\u{1f3fb}", 0x04),
+	("\u{1f476}\u{1f3fc}", 0x04),
+	("\u{1f476}\u{1f3fd}", 0x04),
+	("\u{1f476}\u{1f3fe}", 0x04),
+	("\u{1f476}\u{1f3ff}", 0x04),
+	("\u{1f477}", 0x01),
+	("\u{1f477}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f477}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f477}\u{1f3fb}", 0x04),
+	("\u{1f477}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f477}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f477}\u{1f3fc}", 0x04),
+	("\u{1f477}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f477}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f477}\u{1f3fd}", 0x04),
+	("\u{1f477}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f477}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f477}\u{1f3fe}", 0x04),
+	("\u{1f477}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f477}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f477}\u{1f3ff}", 0x04),
+	("\u{1f477}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f477}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f478}", 0x01),
+	("\u{1f478}\u{1f3fb}", 0x04),
+	("\u{1f478}\u{1f3fc}", 0x04),
+	("\u{1f478}\u{1f3fd}", 0x04),
+	("\u{1f478}\u{1f3fe}", 0x04),
+	("\u{1f478}\u{1f3ff}", 0x04),
+	("\u{1f479}", 0x01),
+	("\u{1f47a}", 0x01),
+	("\u{1f47b}", 0x01),
+	("\u{1f47c}", 0x01),
+	("\u{1f47c}\u{1f3fb}", 0x04),
+	("\u{1f47c}\u{1f3fc}", 0x04),
+	("\u{1f47c}\u{1f3fd}", 0x04),
+	("\u{1f47c}\u{1f3fe}", 0x04),
+	("\u{1f47c}\u{1f3ff}", 0x04),
+	("\u{1f47d}", 0x01),
+	("\u{1f47e}", 0x01),
+	("\u{1f47f}", 0x01),
+	("\u{1f480}", 0x01),
+	("\u{1f481}", 0x01),
+	("\u{1f481}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f481}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f481}\u{1f3fb}", 0x04),
+	("\u{1f481}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f481}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f481}\u{1f3fc}", 0x04),
+	("\u{1f481}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f481}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f481}\u{1f3fd}", 0x04),
+	("\u{1f481}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f481}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f481}\u{1f3fe}", 0x04),
+	("\u{1f481}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f481}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f481}\u{1f3ff}", 0x04),
+	("\u{1f481}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f481}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f482}", 0x01),
+	("\u{1f482}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f482}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f482}\u{1f3fb}", 0x04),
+	("\u{1f482}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f482}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f482}\u{1f3fc}", 0x04),
+	("\u{1f482}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f482}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f482}\u{1f3fd}", 0x04),
+	("\u{1f482}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f482}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f482}\u{1f3fe}", 0x04),
+	("\u{1f482}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f482}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f482}\u{1f3ff}", 0x04),
+	("\u{1f482}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f482}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f483}", 0x01),
+	("\u{1f483}\u{1f3fb}", 0x04),
+	("\u{1f483}\u{1f3fc}", 0x04),
+	("\u{1f483}\u{1f3fd}", 0x04),
+	("\u{1f483}\u{1f3fe}", 0x04),
+	("\u{1f483}\u{1f3ff}", 0x04),
+	("\u{1f484}", 0x01),
+	("\u{1f485}", 0x01),
+	("\u{1f485}\u{1f3fb}", 0x04),
+	("\u{1f485}\u{1f3fc}", 0x04),
+	("\u{1f485}\u{1f3fd}", 0x04),
+	("\u{1f485}\u{1f3fe}", 0x04),
+	("\u{1f485}\u{1f3ff}", 0x04),
+	("\u{1f486}", 0x01),
+	("\u{1f486}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f486}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f486}\u{1f3fb}", 0x04),
+	("\u{1f486}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f486}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f486}\u{1f3fc}", 0x04),
+	("\u{1f486}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f486}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f486}\u{1f3fd}", 0x04),
+	("\u{1f486}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f486}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f486}\u{1f3fe}", 0x04),
+	("\u{1f486}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f486}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f486}\u{1f3ff}", 0x04),
+	("\u{1f486}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f486}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f487}", 0x01),
+	("\u{1f487}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f487}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f487}\u{1f3fb}", 0x04),
+	("\u{1f487}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f487}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f487}\u{1f3fc}", 0x04),
+	("\u{1f487}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f487}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f487}\u{1f3fd}", 0x04),
+	("\u{1f487}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f487}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f487}\u{1f3fe}", 0x04),
+	("\u{1f487}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f487}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f487}\u{1f3ff}", 0x04),
+	("\u{1f487}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f487}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f488}", 0x01),
+	("\u{1f489}", 0x01),
+	("\u{1f48a}", 0x01),
+	("\u{1f48b}", 0x01),
+	("\u{1f48c}", 0x01),
+	("\u{1f48d}", 0x01),
+	("\u{1f48e}", 0x01),
+	("\u{1f48f}", 0x01),
+	("\u{1f48f}\u{1f3fb}", 0x04),
+	("\u{1f48f}\u{1f3fc}", 0x04),
+	("\u{1f48f}\u{1f3fd}", 0x04),
+	("\u{1f48f}\u{1f3fe}", 0x04),
+	("\u{1f48f}\u{1f3ff}", 0x04),
+	("\u{1f490}", 0x01),
+	("\u{1f491}", 0x01),
+	("\u{1f491}\u{1f3fb}", 0x04),
+	("\u{1f491}\u{1f3fc}", 0x04),
+	("\u{1f491}\u{1f3fd}", 0x04),
+	("\u{1f491}\u{1f3fe}", 0x04),
+	("\u{1f491}\u{1f3ff}", 0x04),
+	("\u{1f492}", 0x01),
+	("\u{1f493}", 0x01),
+	("\u{1f494}", 0x01),
+	("\u{1f495}", 0x01),
+	("\u{1f496}", 0x01),
+	("\u{1f497}", 0x01),
+	("\u{1f498}", 0x01),
+	("\u{1f499}", 0x01),
+	("\u{1f49a}", 0x01),
+	("\u{1f49b}", 0x01),
+	("\u{1f49c}", 0x01),
+	("\u{1f49d}", 0x01),
+	("\u{1f49e}", 0x01),
+	("\u{1f49f}", 0x01),
+	("\u{1f4a0}", 0x01),
+	("\u{1f4a1}", 0x01),
+	("\u{1f4a2}", 0x01),
+	("\u{1f4a3}", 0x01),
+	("\u{1f4a4}", 0x01),
+	("\u{1f4a5}", 0x01),
+	("\u{1f4a6}", 0x01),
+	("\u{1f4a7}", 0x01),
+	("\u{1f4a8}", 0x01),
+	("\u{1f4a9}", 0x01),
+	("\u{1f4aa}", 0x01),
+	("\u{1f4aa}\u{1f3fb}", 0x04),
+	("\u{1f4aa}\u{1f3fc}", 0x04),
+	("\u{1f4aa}\u{1f3fd}", 0x04),
+	("\u{1f4aa}\u{1f3fe}", 0x04),
+	("\u{1f4aa}\u{1f3ff}", 0x04),
+	("\u{1f4ab}", 0x01),
+	("\u{1f4ac}", 0x01),
+	("\u{1f4ad}", 0x01),
+	("\u{1f4ae}", 0x01),
+	("\u{1f4af}", 0x01),
+	("\u{1f4b0}", 0x01),
+	("\u{1f4b1}", 0x01),
+	("\u{1f4b2}", 0x01),
+	("\u{1f4b3}", 0x01),
+	("\u{1f4b4}", 0x01),
+	("\u{1f4b5}", 0x01),
+	("\u{1f4b6}", 0x01),
+	("\u{1f4b7}", 0x01),
+	("\u{1f4b8}", 0x01),
+	("\u{1f4b9}", 0x01),
+	("\u{1f4ba}", 0x01),
+	("\u{1f4bb}", 0x01),
+	("\u{1f4bc}", 0x01),
+	("\u{1f4bd}", 0x01),
+	("\u{1f4be}", 0x01),
+	("\u{1f4bf}", 0x01),
+	("\u{1f4c0}", 0x01),
+	("\u{1f4c1}", 0x01),
+	("\u{1f4c2}", 0x01),
+	("\u{1f4c3}", 0x01),
+	("\u{1f4c4}", 0x01),
+	("\u{1f4c5}", 0x01),
+	("\u{1f4c6}", 0x01),
+	("\u{1f4c7}", 0x01),
+	("\u{1f4c8}", 0x01),
+	("\u{1f4c9}", 0x01),
+	("\u{1f4ca}", 0x01),
+	("\u{1f4cb}", 0x01),
+	("\u{1f4cc}", 0x01),
+	("\u{1f4cd}", 0x01),
+	("\u{1f4ce}", 0x01),
+	("\u{1f4cf}", 0x01),
+	("\u{1f4d0}", 0x01),
+	("\u{1f4d1}", 0x01),
+	("\u{1f4d2}", 0x01),
+	("\u{1f4d3}", 0x01),
+	("\u{1f4d4}", 0x01),
+	("\u{1f4d5}", 0x01),
+	("\u{1f4d6}", 0x01),
+	("\u{1f4d7}", 0x01),
+	("\u{1f4d8}", 0x01),
+	("\u{1f4d9}", 0x01),
+	("\u{1f4da}", 0x01),
+	("\u{1f4db}", 0x01),
+	("\u{1f4dc}", 0x01),
+	("\u{1f4dd}", 0x01),
+	("\u{1f4de}", 0x01),
+	("\u{1f4df}", 0x01),
+	("\u{1f4e0}", 0x01),
+	("\u{1f4e1}", 0x01),
+	("\u{1f4e2}", 0x01),
+	("\u{1f4e3}", 0x01),
+	("\u{1f4e4}", 0x01),
+	("\u{1f4e5}", 0x01),
+	("\u{1f4e6}", 0x01),
+	("\u{1f4e7}", 0x01),
+	("\u{1f4e8}", 0x01),
+	("\u{1f4e9}", 0x01),
+	("\u{1f4ea}", 0x01),
+	("\u{1f4eb}", 0x01),
+	("\u{1f4ec}", 0x01),
+	("\u{1f4ed}", 0x01),
+	("\u{1f4ee}", 0x01),
+	("\u{1f4ef}", 0x01),
+	("\u{1f4f0}", 0x01),
+	("\u{1f4f1}", 0x01),
+	("\u{1f4f2}", 0x01),
+	("\u{1f4f3}", 0x01),
+	("\u{1f4f4}", 0x01),
+	("\u{1f4f5}", 0x01),
+	("\u{1f4f6}", 0x01),
+	("\u{1f4f7}", 0x01),
+	("\u{1f4f8}", 0x01),
+	("\u{1f4f9}", 0x01),
+	("\u{1f4fa}", 0x01),
+	("\u{1f4fb}", 0x01),
+	("\u{1f4fc}", 0x01),
+	("\u{1f4fd}\u{fe0f}", 0x01),
+	("\u{1f4ff}", 0x01),
+	("\u{1f500}", 0x01),
+	("\u{1f501}", 0x01),
+	("\u{1f502}", 0x01),
+	("\u{1f503}", 0x01),
+	("\u{1f504}", 0x01),
+	("\u{1f505}", 0x01),
+	("\u{1f506}", 0x01),
+	("\u{1f507}", 0x01),
+	("\u{1f508}", 0x01),
+	("\u{1f509}", 0x01),
+	("\u{1f50a}", 0x01),
+	("\u{1f50b}", 0x01),
+	("\u{1f50c}", 0x01),
+	("\u{1f50d}", 0x01),
+	("\u{1f50e}", 0x01),
+	("\u{1f50f}", 0x01),
+	("\u{1f510}", 0x01),
+	("\u{1f511}", 0x01),
+	("\u{1f512}", 0x01),
+	("\u{1f513}", 0x01),
+	("\u{1f514}", 0x01),
+	("\u{1f515}", 0x01),
+	("\u{1f516}", 0x01),
+	("\u{1f517}", 0x01),
+	("\u{1f518}", 0x01),
+	("\u{1f519}", 0x01),
+	("\u{1f51a}", 0x01),
+	("\u{1f51b}", 0x01),
+	("\u{1f51c}", 0x01),
+	("\u{1f51d}", 0x01),
+	("\u{1f51e}", 0x01),
+	("\u{1f51f}", 0x01),
+	("\u{1f520}", 0x01),
+	("\u{1f521}", 0x01),
+	("\u{1f522}", 0x01),
+	("\u{1f523}", 0x01),
+	("\u{1f524}", 0x01),
+	("\u{1f525}", 0x01),
+	("\u{1f526}", 0x01),
+	("\u{1f527}", 0x01),
+	("\u{1f528}", 0x01),
+	("\u{1f529}", 0x01),
+	("\u{1f52a}", 0x01),
+	("\u{1f52b}", 0x01),
+	("\u{1f52c}", 0x01),
+	("\u{1f52d}", 0x01),
+	("\u{1f52e}", 0x01),
+	("\u{1f52f}", 0x01),
+	("\u{1f530}", 0x01),
+	("\u{1f531}", 0x01),
+	("\u{1f532}", 0x01),
+	("\u{1f533}", 0x01),
+	("\u{1f534}", 0x01),
+	("\u{1f535}", 0x01),
+	("\u{1f536}", 0x01),
+	("\u{1f537}", 0x01),
+	("\u{1f538}", 0x01),
+	("\u{1f539}", 0x01),
+	("\u{1f53a}", 0x01),
+	("\u{1f53b}", 0x01),
+	("\u{1f53c}", 0x01),
+	("\u{1f53d}", 0x01),
+	("\u{1f549}\u{fe0f}", 0x01),
+	("\u{1f54a}\u{fe0f}", 0x01),
+	("\u{1f54b}", 0x01),
+	("\u{1f54c}", 0x01),
+	("\u{1f54d}", 0x01),
+	("\u{1f54e}", 0x01),
+	("\u{1f550}", 0x01),
+	("\u{1f551}", 0x01),
+	("\u{1f552}", 0x01),
+	("\u{1f553}", 0x01),
+	("\u{1f554}", 0x01),
+	("\u{1f555}", 0x01),
+	("\u{1f556}", 0x01),
+	("\u{1f557}", 0x01),
+	("\u{1f558}", 0x01),
+	("\u{1f559}", 0x01),
+	("\u{1f55a}", 0x01),
+	("\u{1f55b}", 0x01),
+	("\u{1f55c}", 0x01),
+	("\u{1f55d}", 0x01),
+	("\u{1f55e}", 0x01),
+	("\u{1f55f}", 0x01),
+	("\u{1f560}", 0x01),
+	("\u{1f561}", 0x01),
+	("\u{1f562}", 0x01),
+	("\u{1f563}", 0x01),
+	("\u{1f564}", 0x01),
+	("\u{1f565}", 0x01),
+	("\u{1f566}", 0x01),
+	("\u{1f567}", 0x01),
+	("\u{1f56f}\u{fe0f}", 0x01),
+	("\u{1f570}\u{fe0f}", 0x01),
+	("\u{1f573}\u{fe0f}", 0x01),
+	("\u{1f574}\u{fe0f}", 0x01),
+	("\u{1f574}\u{1f3fb}", 0x04),
+	("\u{1f574}\u{1f3fc}", 0x04),
+	("\u{1f574}\u{1f3fd}", 0x04),
+	("\u{1f574}\u{1f3fe}", 0x04),
+	("\u{1f574}\u{1f3ff}", 0x04),
+	("\u{1f575}\u{fe0f}", 0x01),
+	("\u{1f575}\u{fe0f}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f575}\u{fe0f}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f575}\u{1f3fb}", 0x04),
+	("\u{1f575}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f575}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f575}\u{1f3fc}", 0x04),
+	("\u{1f575}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f575}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f575}\u{1f3fd}", 0x04),
+	("\u{1f575}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f575}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f575}\u{1f3fe}", 0x04),
+	("\u{1f575}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f575}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f575}\u{1f3ff}", 0x04),
+	("\u{1f575}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f575}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f576}\u{fe0f}", 0x01),
+	("\u{1f577}\u{fe0f}", 0x01),
+	("\u{1f578}\u{fe0f}", 0x01),
+	("\u{1f579}\u{fe0f}", 0x01),
+	("\u{1f57a}", 0x01),
+	("\u{1f57a}\u{1f3fb}", 0x04),
+	("\u{1f57a}\u{1f3fc}", 0x04),
+	("\u{1f57a}\u{1f3fd}", 0x04),
+	("\u{1f57a}\u{1f3fe}", 0x04),
+	("\u{1f57a}\u{1f3ff}", 0x04),
+	("\u{1f587}\u{fe0f}", 0x01),
+	("\u{1f58a}\u{fe0f}", 0x01),
+	("\u{1f58b}\u{fe0f}", 0x01),
+	("\u{1f58c}\u{fe0f}", 0x01),
+	("\u{1f58d}\u{fe0f}", 0x01),
+	("\u{1f590}\u{fe0f}", 0x01),
+	("\u{1f590}\u{1f3fb}", 0x04),
+	("\u{1f590}\u{1f3fc}", 0x04),
+	("\u{1f590}\u{1f3fd}", 0x04),
+	("\u{1f590}\u{1f3fe}", 0x04),
+	("\u{1f590}\u{1f3ff}", 0x04),
+	("\u{1f595}", 0x01),
+	("\u{1f595}\u{1f3fb}", 0x04),
+	("\u{1f595}\u{1f3fc}", 0x04),
+	("\u{1f595}\u{1f3fd}", 0x04),
+	("\u{1f595}\u{1f3fe}", 0x04),
+	("\u{1f595}\u{1f3ff}", 0x04),
+	("\u{1f596}", 0x01),
+	("\u{1f596}\u{1f3fb}", 0x04),
+	("\u{1f596}\u{1f3fc}", 0x04),
+	("\u{1f596}\u{1f3fd}", 0x04),
+	("\u{1f596}\u{1f3fe}", 0x04),
+	("\u{1f596}\u{1f3ff}", 0x04),
+	("\u{1f5a4}", 0x01),
+	("\u{1f5a5}\u{fe0f}", 0x01),
+	("\u{1f5a8}\u{fe0f}", 0x01),
+	("\u{1f5b1}\u{fe0f}", 0x01),
+	("\u{1f5b2}\u{fe0f}", 0x01),
+	("\u{1f5bc}\u{fe0f}", 0x01),
+	("\u{1f5c2}\u{fe0f}", 0x01),
+	("\u{1f5c3}\u{fe0f}", 0x01),
+	("\u{1f5c4}\u{fe0f}", 0x01),
+	("\u{1f5d1}\u{fe0f}", 0x01),
+	("\u{1f5d2}\u{fe0f}", 0x01),
+	("\u{1f5d3}\u{fe0f}", 0x01),
+	("\u{1f5dc}\u{fe0f}", 0x01),
+	("\u{1f5dd}\u{fe0f}", 0x01),
+	("\u{1f5de}\u{fe0f}", 0x01),
+	("\u{1f5e1}\u{fe0f}", 0x01),
+	("\u{1f5e3}\u{fe0f}", 0x01),
+	("\u{1f5e8}\u{fe0f}", 0x01),
+	("\u{1f5ef}\u{fe0f}", 0x01),
+	("\u{1f5f3}\u{fe0f}", 0x01),
+	("\u{1f5fa}\u{fe0f}", 0x01),
+	("\u{1f5fb}", 0x01),
+	("\u{1f5fc}", 0x01),
+	("\u{1f5fd}", 0x01),
+	("\u{1f5fe}", 0x01),
+	("\u{1f5ff}", 0x01),
+	("\u{1f600}", 0x01),
+	("\u{1f601}", 0x01),
+	("\u{1f602}", 0x01),
+	("\u{1f603}", 0x01),
+	("\u{1f604}", 0x01),
+	("\u{1f605}", 0x01),
+	("\u{1f606}", 0x01),
+	("\u{1f607}", 0x01),
+	("\u{1f608}", 0x01),
+	("\u{1f609}", 0x01),
+	("\u{1f60a}", 0x01),
+	("\u{1f60b}", 0x01),
+	("\u{1f60c}", 0x01),
+	("\u{1f60d}", 0x01),
+	("\u{1f60e}", 0x01),
+	("\u{1f60f}", 0x01),
+	("\u{1f610}", 0x01),
+	("\u{1f611}", 0x01),
+	("\u{1f612}", 0x01),
+	("\u{1f613}", 0x01),
+	("\u{1f614}", 0x01),
+	("\u{1f615}", 0x01),
+	("\u{1f616}", 0x01),
+	("\u{1f617}", 0x01),
+	("\u{1f618}", 0x01),
+	("\u{1f619}", 0x01),
+	("\u{1f61a}", 0x01),
+	("\u{1f61b}", 0x01),
+	("\u{1f61c}", 0x01),
+	("\u{1f61d}", 0x01),
+	("\u{1f61e}", 0x01),
+	("\u{1f61f}", 0x01),
+	("\u{1f620}", 0x01),
+	("\u{1f621}", 0x01),
+	("\u{1f622}", 0x01),
+	("\u{1f623}", 0x01),
+	("\u{1f624}", 0x01),
+	("\u{1f625}", 0x01),
+	("\u{1f626}", 0x01),
+	("\u{1f627}", 0x01),
+	("\u{1f628}", 0x01),
+	("\u{1f629}", 0x01),
+	("\u{1f62a}", 0x01),
+	("\u{1f62b}", 0x01),
+	("\u{1f62c}", 0x01),
+	("\u{1f62d}", 0x01),
+	("\u{1f62e}", 0x01),
+	("\u{1f62e}\u{200d}\u{1f4a8}", 0x05),
+	("\u{1f62f}", 0x01),
+	("\u{1f630}", 0x01),
+	("\u{1f631}", 0x01),
+	("\u{1f632}", 0x01),
+	("\u{1f633}", 0x01),
+	("\u{1f634}", 0x01),
+	("\u{1f635}", 0x01),
+	("\u{1f635}\u{200d}\u{1f4ab}", 0x05),
+	("\u{1f636}", 0x01),
+	("\u{1f636}\u{200d}\u{1f32b}\u{fe0f}", 0x05),
+	("\u{1f637}", 0x01),
+	("\u{1f638}", 0x01),
+	("\u{1f639}", 0x01),
+	("\u{1f63a}", 0x01),
+	("\u{1f63b}", 0x01),
+	("\u{1f63c}", 0x01),
+	("\u{1f63d}", 0x01),
+	("\u{1f63e}", 0x01),
+	("\u{1f63f}", 0x01),
+	("\u{1f640}", 0x01),
+	("\u{1f641}", 0x01),
+	("\u{1f642}", 0x01),
+	("\u{1f642}\u{200d}\u{2194}\u{fe0f}", 0x05),
+	("\u{1f642}\u{200d}\u{2195}\u{fe0f}", 0x05),
+	("\u{1f643}", 0x01),
+	("\u{1f644}", 0x01),
+	("\u{1f645}", 0x01),
+	("\u{1f645}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f645}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f645}\u{1f3fb}", 0x04),
+	("\u{1f645}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f645}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f645}\u{1f3fc}", 0x04),
+	("\u{1f645}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f645}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f645}\u{1f3fd}", 0x04),
+	("\u{1f645}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f645}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f645}\u{1f3fe}", 0x04),
+	("\u{1f645}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f645}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f645}\u{1f3ff}", 0x04),
+	("\u{1f645}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f645}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f646}", 0x01),
+	("\u{1f646}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f646}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f646}\u{1f3fb}", 0x04),
+	("\u{1f646}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f646}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f646}\u{1f3fc}", 0x04),
+	("\u{1f646}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f646}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f646}\u{1f3fd}", 0x04),
+	("\u{1f646}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f646}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f646}\u{1f3fe}", 0x04),
+	("\u{1f646}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f646}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f646}\u{1f3ff}", 0x04),
+	("\u{1f646}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f646}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f647}", 0x01),
+	("\u{1f647}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f647}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f647}\u{1f3fb}", 0x04),
+	("\u{1f647}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f647}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f647}\u{1f3fc}", 0x04),
+	("\u{1f647}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f647}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f647}\u{1f3fd}", 0x04),
+	("\u{1f647}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f647}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f647}\u{1f3fe}", 0x04),
+	("\u{1f647}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f647}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f647}\u{1f3ff}", 0x04),
+	("\u{1f647}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f647}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f648}", 0x01),
+	("\u{1f649}", 0x01),
+	("\u{1f64a}", 0x01),
+	("\u{1f64b}", 0x01),
+	("\u{1f64b}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f64b}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f64b}\u{1f3fb}", 0x04),
+	("\u{1f64b}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f64b}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f64b}\u{1f3fc}", 0x04),
+	("\u{1f64b}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f64b}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f64b}\u{1f3fd}", 0x04),
+	("\u{1f64b}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f64b}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f64b}\u{1f3fe}", 0x04),
+	("\u{1f64b}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f64b}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f64b}\u{1f3ff}", 0x04),
+	("\u{1f64b}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f64b}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f64c}", 0x01),
+	("\u{1f64c}\u{1f3fb}", 0x04),
+	("\u{1f64c}\u{1f3fc}", 0x04),
+	("\u{1f64c}\u{1f3fd}", 0x04),
+	("\u{1f64c}\u{1f3fe}", 0x04),
+	("\u{1f64c}\u{1f3ff}", 0x04),
+	("\u{1f64d}", 0x01),
+	("\u{1f64d}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f64d}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f64d}\u{1f3fb}", 0x04),
+	("\u{1f64d}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f64d}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f64d}\u{1f3fc}", 0x04),
+	("\u{1f64d}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f64d}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f64d}\u{1f3fd}", 0x04),
+	("\u{1f64d}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f64d}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f64d}\u{1f3fe}", 0x04),
+	("\u{1f64d}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f64d}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f64d}\u{1f3ff}", 0x04),
+	("\u{1f64d}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f64d}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f64e}", 0x01),
+	("\u{1f64e}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f64e}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f64e}\u{1f3fb}", 0x04),
+	("\u{1f64e}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f64e}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f64e}\u{1f3fc}", 0x04),
+	("\u{1f64e}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f64e}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f64e}\u{1f3fd}", 0x04),
+	("\u{1f64e}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f64e}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f64e}\u{1f3fe}", 0x04),
+	("\u{1f64e}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f64e}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f64e}\u{1f3ff}", 0x04),
+	("\u{1f64e}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f64e}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f64f}", 0x01),
+	("\u{1f64f}\u{1f3fb}", 0x04),
+	("\u{1f64f}\u{1f3fc}", 0x04),
+	("\u{1f64f}\u{1f3fd}", 0x04),
+	("\u{1f64f}\u{1f3fe}", 0x04),
+	("\u{1f64f}\u{1f3ff}", 0x04),
+	("\u{1f680}", 0x01),
+	("\u{1f681}", 0x01),
+	("\u{1f682}", 0x01),
+	("\u{1f683}", 0x01),
+	("\u{1f684}", 0x01),
+	("\u{1f685}", 0x01),
+	("\u{1f686}", 0x01),
+	("\u{1f687}", 0x01),
+	("\u{1f688}", 0x01),
+	("\u{1f689}", 0x01),
+	("\u{1f68a}", 0x01),
+	("\u{1f68b}", 0x01),
+	("\u{1f68c}", 0x01),
+	("\u{1f68d}", 0x01),
+	("\u{1f68e}", 0x01),
+	("\u{1f68f}", 0x01),
+	("\u{1f690}", 0x01),
+	("\u{1f691}", 0x01),
+	("\u{1f692}", 0x01),
+	("\u{1f693}", 0x01),
+	("\u{1f694}", 0x01),
+	("\u{1f695}", 0x01),
+	("\u{1f696}", 0x01),
+	("\u{1f697}", 0x01),
+	("\u{1f698}", 0x01),
+	("\u{1f699}", 0x01),
+	("\u{1f69a}", 0x01),
+	("\u{1f69b}", 0x01),
+	("\u{1f69c}", 0x01),
+	("\u{1f69d}", 0x01),
+	("\u{1f69e}", 0x01),
+	("\u{1f69f}", 0x01),
+	("\u{1f6a0}", 0x01),
+	("\u{1f6a1}", 0x01),
+	("\u{1f6a2}", 0x01),
+	("\u{1f6a3}", 0x01),
+	("\u{1f6a3}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f6a3}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f6a3}\u{1f3fb}", 0x04),
+	("\u{1f6a3}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f6a3}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f6a3}\u{1f3fc}", 0x04),
+	("\u{1f6a3}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f6a3}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f6a3}\u{1f3fd}", 0x04),
+	("\u{1f6a3}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f6a3}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f6a3}\u{1f3fe}", 0x04),
+	("\u{1f6a3}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f6a3}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f6a3}\u{1f3ff}", 0x04),
+	("\u{1f6a3}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f6a3}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f6a4}", 0x01),
+	("\u{1f6a5}", 0x01),
+	("\u{1f6a6}", 0x01),
+	("\u{1f6a7}", 0x01),
+	("\u{1f6a8}", 0x01),
+	("\u{1f6a9}", 0x01),
+	("\u{1f6aa}", 0x01),
+	("\u{1f6ab}", 0x01),
+	("\u{1f6ac}", 0x01),
+	("\u{1f6ad}", 0x01),
+	("\u{1f6ae}", 0x01),
+	("\u{1f6af}", 0x01),
+	("\u{1f6b0}", 0x01),
+	("\u{1f6b1}", 0x01),
+	("\u{1f6b2}", 0x01),
+	("\u{1f6b3}", 0x01),
+	("\u{1f6b4}", 0x01),
+	("\u{1f6b4}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f6b4}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f6b4}\u{1f3fb}", 0x04),
+	("\u{1f6b4}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f6b4}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f6b4}\u{1f3fc}", 0x04),
+	("\u{1f6b4}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f6b4}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f6b4}\u{1f3fd}", 0x04),
+	("\u{1f6b4}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f6b4}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f6b4}\u{1f3fe}", 0x04),
+	("\u{1f6b4}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f6b4}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f6b4}\u{1f3ff}", 0x04),
+	("\u{1f6b4}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f6b4}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f6b5}", 0x01),
+	("\u{1f6b5}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f6b5}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f6b5}\u{1f3fb}", 0x04),
+	("\u{1f6b5}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f6b5}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f6b5}\u{1f3fc}", 0x04),
+	("\u{1f6b5}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f6b5}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f6b5}\u{1f3fd}", 0x04),
+	("\u{1f6b5}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f6b5}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f6b5}\u{1f3fe}", 0x04),
+	("\u{1f6b5}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f6b5}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f6b5}\u{1f3ff}", 0x04),
+	("\u{1f6b5}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f6b5}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f6b6}", 0x01),
+	("\u{1f6b6}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f6b6}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f6b6}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f6b6}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f6b6}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f6b6}\u{1f3fb}", 0x04),
+	("\u{1f6b6}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f6b6}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f6b6}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f6b6}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f6b6}\u{1f3fb}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f6b6}\u{1f3fc}", 0x04),
+	("\u{1f6b6}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f6b6}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f6b6}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f6b6}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f6b6}\u{1f3fc}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f6b6}\u{1f3fd}", 0x04),
+	("\u{1f6b6}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f6b6}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f6b6}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f6b6}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f6b6}\u{1f3fd}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f6b6}\u{1f3fe}", 0x04),
+	("\u{1f6b6}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f6b6}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f6b6}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f6b6}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f6b6}\u{1f3fe}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f6b6}\u{1f3ff}", 0x04),
+	("\u{1f6b6}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f6b6}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f6b6}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f6b6}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f6b6}\u{1f3ff}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f6b7}", 0x01),
+	("\u{1f6b8}", 0x01),
+	("\u{1f6b9}", 0x01),
+	("\u{1f6ba}", 0x01),
+	("\u{1f6bb}", 0x01),
+	("\u{1f6bc}", 0x01),
+	("\u{1f6bd}", 0x01),
+	("\u{1f6be}", 0x01),
+	("\u{1f6bf}", 0x01),
+	("\u{1f6c0}", 0x01),
+	("\u{1f6c0}\u{1f3fb}", 0x04),
+	("\u{1f6c0}\u{1f3fc}", 0x04),
+	("\u{1f6c0}\u{1f3fd}", 0x04),
+	("\u{1f6c0}\u{1f3fe}", 0x04),
+	("\u{1f6c0}\u{1f3ff}", 0x04),
+	("\u{1f6c1}", 0x01),
+	("\u{1f6c2}", 0x01),
+	("\u{1f6c3}", 0x01),
+	("\u{1f6c4}", 0x01),
+	("\u{1f6c5}", 0x01),
+	("\u{1f6cb}\u{fe0f}", 0x01),
+	("\u{1f6cc}", 0x01),
+	("\u{1f6cc}\u{1f3fb}", 0x04),
+	("\u{1f6cc}\u{1f3fc}", 0x04),
+	("\u{1f6cc}\u{1f3fd}", 0x04),
+	("\u{1f6cc}\u{1f3fe}", 0x04),
+	("\u{1f6cc}\u{1f3ff}", 0x04),
+	("\u{1f6cd}\u{fe0f}", 0x01),
+	("\u{1f6ce}\u{fe0f}", 0x01),
+	("\u{1f6cf}\u{fe0f}", 0x01),
+	("\u{1f6d0}", 0x01),
+	("\u{1f6d1}", 0x01),
+	("\u{1f6d2}", 0x01),
+	("\u{1f6d5}", 0x01),
+	("\u{1f6d6}", 0x01),
+	("\u{1f6d7}", 0x01),
+	("\u{1f6dc}", 0x01),
+	("\u{1f6dd}", 0x01),
+	("\u{1f6de}", 0x01),
+	("\u{1f6df}", 0x01),
+	("\u{1f6e0}\u{fe0f}", 0x01),
+	("\u{1f6e1}\u{fe0f}", 0x01),
+	("\u{1f6e2}\u{fe0f}", 0x01),
+	("\u{1f6e3}\u{fe0f}", 0x01),
+	("\u{1f6e4}\u{fe0f}", 0x01),
+	("\u{1f6e5}\u{fe0f}", 0x01),
+	("\u{1f6e9}\u{fe0f}", 0x01),
+	("\u{1f6eb}", 0x01),
+	("\u{1f6ec}", 0x01),
+	("\u{1f6f0}\u{fe0f}", 0x01),
+	("\u{1f6f3}\u{fe0f}", 0x01),
+	("\u{1f6f4}", 0x01),
+	("\u{1f6f5}", 0x01),
+	("\u{1f6f6}", 0x01),
+	("\u{1f6f7}", 0x01),
+	("\u{1f6f8}", 0x01),
+	("\u{1f6f9}", 0x01),
+	("\u{1f6fa}", 0x01),
+	("\u{1f6fb}", 0x01),
+	("\u{1f6fc}", 0x01),
+	("\u{1f7e0}", 0x01),
+	("\u{1f7e1}", 0x01),
+	("\u{1f7e2}", 0x01),
+	("\u{1f7e3}", 0x01),
+	("\u{1f7e4}", 0x01),
+	("\u{1f7e5}", 0x01),
+	("\u{1f7e6}", 0x01),
+	("\u{1f7e7}", 0x01),
+	("\u{1f7e8}", 0x01),
+	("\u{1f7e9}", 0x01),
+	("\u{1f7ea}", 0x01),
+	("\u{1f7eb}", 0x01),
+	("\u{1f7f0}", 0x01),
+	("\u{1f90c}", 0x01),
+	("\u{1f90c}\u{1f3fb}", 0x04),
+	("\u{1f90c}\u{1f3fc}", 0x04),
+	("\u{1f90c}\u{1f3fd}", 0x04),
+	("\u{1f90c}\u{1f3fe}", 0x04),
+	("\u{1f90c}\u{1f3ff}", 0x04),
+	("\u{1f90d}", 0x01),
+	("\u{1f90e}", 0x01),
+	("\u{1f90f}", 0x01),
+	("\u{1f90f}\u{1f3fb}", 0x04),
+	("\u{1f90f}\u{1f3fc}", 0x04),
+	("\u{1f90f}\u{1f3fd}", 0x04),
+	("\u{1f90f}\u{1f3fe}", 0x04),
+	("\u{1f90f}\u{1f3ff}", 0x04),
+	("\u{1f910}", 0x01),
+	("\u{1f911}", 0x01),
+	("\u{1f912}", 0x01),
+	("\u{1f913}", 0x01),
+	("\u{1f914}", 0x01),
+	("\u{1f915}", 0x01),
+	("\u{1f916}", 0x01),
+	("\u{1f917}", 0x01),
+	("\u{1f918}", 0x01),
+	("\u{1f918}\u{1f3fb}", 0x04),
+	("\u{1f918}\u{1f3fc}", 0x04),
+	("\u{1f918}\u{1f3fd}", 0x04),
+	("\u{1f918}\u{1f3fe}", 0x04),
+	("\u{1f918}\u{1f3ff}", 0x04),
+	("\u{1f919}", 0x01),
+	("\u{1f919}\u{1f3fb}", 0x04),
+	("\u{1f919}\u{1f3fc}", 0x04),
+	("\u{1f919}\u{1f3fd}", 0x04),
+	("\u{1f919}\u{1f3fe}", 0x04),
+	("\u{1f919}\u{1f3ff}", 0x04),
+	("\u{1f91a}", 0x01),
+	("\u{1f91a}\u{1f3fb}", 0x04),
+	("\u{1f91a}\u{1f3fc}", 0x04),
+	("\u{1f91a}\u{1f3fd}", 0x04),
+	("\u{1f91a}\u{1f3fe}", 0x04),
+	("\u{1f91a}\u{1f3ff}", 0x04),
+	("\u{1f91b}", 0x01),
+	("\u{1f91b}\u{1f3fb}", 0x04),
+	("\u{1f91b}\u{1f3fc}", 0x04),
+	("\u{1f91b}\u{1f3fd}", 0x04),
+	("\u{1f91b}\u{1f3fe}", 0x04),
+	("\u{1f91b}\u{1f3ff}", 0x04),
+	("\u{1f91c}", 0x01),
+	("\u{1f91c}\u{1f3fb}", 0x04),
+	("\u{1f91c}\u{1f3fc}", 0x04),
+	("\u{1f91c}\u{1f3fd}", 0x04),
+	("\u{1f91c}\u{1f3fe}", 0x04),
+	("\u{1f91c}\u{1f3ff}", 0x04),
+	("\u{1f91d}", 0x01),
+	("\u{1f91d}\u{1f3fb}", 0x04),
+	("\u{1f91d}\u{1f3fc}", 0x04),
+	("\u{1f91d}\u{1f3fd}", 0x04),
+	("\u{1f91d}\u{1f3fe}", 0x04),
+	("\u{1f91d}\u{1f3ff}", 0x04),
+	("\u{1f91e}", 0x01),
+	("\u{1f91e}\u{1f3fb}", 0x04),
+	("\u{1f91e}\u{1f3fc}", 0x04),
+	("\u{1f91e}\u{1f3fd}", 0x04),
+	("\u{1f91e}\u{1f3fe}", 0x04),
+	("\u{1f91e}\u{1f3ff}", 0x04),
+	("\u{1f91f}", 0x01),
+	("\u{1f91f}\u{1f3fb}", 0x04),
+	("\u{1f91f}\u{1f3fc}", 0x04),
+	("\u{1f91f}\u{1f3fd}", 0x04),
+	("\u{1f91f}\u{1f3fe}", 0x04),
+	("\u{1f91f}\u{1f3ff}", 0x04),
+	("\u{1f920}", 0x01),
+	("\u{1f921}", 0x01),
+	("\u{1f922}", 0x01),
+	("\u{1f923}", 0x01),
+	("\u{1f924}", 0x01),
+	("\u{1f925}", 0x01),
+	("\u{1f926}", 0x01),
+	("\u{1f926}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f926}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f926}\u{1f3fb}", 0x04),
+	("\u{1f926}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f926}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f926}\u{1f3fc}", 0x04),
+	("\u{1f926}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f926}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f926}\u{1f3fd}", 0x04),
+	("\u{1f926}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f926}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f926}\u{1f3fe}", 0x04),
+	("\u{1f926}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f926}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f926}\u{1f3ff}", 0x04),
+	("\u{1f926}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f926}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f927}", 0x01),
+	("\u{1f928}", 0x01),
+	("\u{1f929}", 0x01),
+	("\u{1f92a}", 0x01),
+	("\u{1f92b}", 0x01),
+	("\u{1f92c}", 0x01),
+	("\u{1f92d}", 0x01),
+	("\u{1f92e}", 0x01),
+	("\u{1f92f}", 0x01),
+	("\u{1f930}", 0x01),
+	("\u{1f930}\u{1f3fb}", 0x04),
+	("\u{1f930}\u{1f3fc}", 0x04),
+	("\u{1f930}\u{1f3fd}", 0x04),
+	("\u{1f930}\u{1f3fe}", 0x04),
+	("\u{1f930}\u{1f3ff}", 0x04),
+	("\u{1f931}", 0x01),
+	("\u{1f931}\u{1f3fb}", 0x04),
+	("\u{1f931}\u{1f3fc}", 0x04),
+	("\u{1f931}\u{1f3fd}", 0x04),
+	("\u{1f931}\u{1f3fe}", 0x04),
+	("\u{1f931}\u{1f3ff}", 0x04),
+	("\u{1f932}", 0x01),
+	("\u{1f932}\u{1f3fb}", 0x04),
+	("\u{1f932}\u{1f3fc}", 0x04),
+	("\u{1f932}\u{1f3fd}", 0x04),
+	("\u{1f932}\u{1f3fe}", 0x04),
+	("\u{1f932}\u{1f3ff}", 0x04),
+	("\u{1f933}", 0x01),
+	("\u{1f933}\u{1f3fb}", 0x04),
+	("\u{1f933}\u{1f3fc}", 0x04),
+	("\u{1f933}\u{1f3fd}", 0x04),
+	("\u{1f933}\u{1f3fe}", 0x04),
+	("\u{1f933}\u{1f3ff}", 0x04),
+	("\u{1f934}", 0x01),
+	("\u{1f934}\u{1f3fb}", 0x04),
+	("\u{1f934}\u{1f3fc}", 0x04),
+	("\u{1f934}\u{1f3fd}", 0x04),
+	("\u{1f934}\u{1f3fe}", 0x04),
+	("\u{1f934}\u{1f3ff}", 0x04),
+	("\u{1f935}", 0x01),
+	("\u{1f935}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f935}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f935}\u{1f3fb}", 0x04),
+	("\u{1f935}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f935}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f935}\u{1f3fc}", 0x04),
+	("\u{1f935}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f935}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f935}\u{1f3fd}", 0x04),
+	("\u{1f935}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f935}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f935}\u{1f3fe}", 0x04),
+	("\u{1f935}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f935}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f935}\u{1f3ff}", 0x04),
+	("\u{1f935}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f935}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f936}", 0x01),
+	("\u{1f936}\u{1f3fb}", 0x04),
+	("\u{1f936}\u{1f3fc}", 0x04),
+	("\u{1f936}\u{1f3fd}", 0x04),
+	("\u{1f936}\u{1f3fe}", 0x04),
+	("\u{1f936}\u{1f3ff}", 0x04),
+	("\u{1f937}", 0x01),
+	("\u{1f937}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f937}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f937}\u{1f3fb}", 0x04),
+	("\u{1f937}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f937}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f937}\u{1f3fc}", 0x04),
+	("\u{1f937}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f937}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f937}\u{1f3fd}", 0x04),
+	("\u{1f937}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f937}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f937}\u{1f3fe}", 0x04),
+	("\u{1f937}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f937}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f937}\u{1f3ff}", 0x04),
+	("\u{1f937}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f937}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f938}", 0x01),
+	("\u{1f938}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f938}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f938}\u{1f3fb}", 0x04),
+	("\u{1f938}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f938}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f938}\u{1f3fc}", 0x04),
+	("\u{1f938}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f938}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f938}\u{1f3fd}", 0x04),
+	("\u{1f938}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f938}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f938}\u{1f3fe}", 0x04),
+	("\u{1f938}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f938}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f938}\u{1f3ff}", 0x04),
+	("\u{1f938}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f938}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f939}", 0x01),
+	("\u{1f939}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f939}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f939}\u{1f3fb}", 0x04),
+	("\u{1f939}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f939}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f939}\u{1f3fc}", 0x04),
+	("\u{1f939}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f939}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f939}\u{1f3fd}", 0x04),
+	("\u{1f939}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f939}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f939}\u{1f3fe}", 0x04),
+	("\u{1f939}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f939}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f939}\u{1f3ff}", 0x04),
+	("\u{1f939}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f939}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f93a}", 0x01),
+	("\u{1f93c}", 0x01),
+	("\u{1f93c}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f93c}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f93d}", 0x01),
+	("\u{1f93d}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f93d}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f93d}\u{1f3fb}", 0x04),
+	("\u{1f93d}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f93d}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f93d}\u{1f3fc}", 0x04),
+	("\u{1f93d}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f93d}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f93d}\u{1f3fd}", 0x04),
+	("\u{1f93d}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f93d}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f93d}\u{1f3fe}", 0x04),
+	("\u{1f93d}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f93d}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f93d}\u{1f3ff}", 0x04),
+	("\u{1f93d}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f93d}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f93e}", 0x01),
+	("\u{1f93e}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f93e}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f93e}\u{1f3fb}", 0x04),
+	("\u{1f93e}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f93e}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f93e}\u{1f3fc}", 0x04),
+	("\u{1f93e}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f93e}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f93e}\u{1f3fd}", 0x04),
+	("\u{1f93e}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f93e}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f93e}\u{1f3fe}", 0x04),
+	("\u{1f93e}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f93e}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f93e}\u{1f3ff}", 0x04),
+	("\u{1f93e}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f93e}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f93f}", 0x01),
+	("\u{1f940}", 0x01),
+	("\u{1f941}", 0x01),
+	("\u{1f942}", 0x01),
+	("\u{1f943}", 0x01),
+	("\u{1f944}", 0x01),
+	("\u{1f945}", 0x01),
+	("\u{1f947}", 0x01),
+	("\u{1f948}", 0x01),
+	("\u{1f949}", 0x01),
+	("\u{1f94a}", 0x01),
+	("\u{1f94b}", 0x01),
+	("\u{1f94c}", 0x01),
+	("\u{1f94d}", 0x01),
+	("\u{1f94e}", 0x01),
+	("\u{1f94f}", 0x01),
+	("\u{1f950}", 0x01),
+	("\u{1f951}", 0x01),
+	("\u{1f952}", 0x01),
+	("\u{1f953}", 0x01),
+	("\u{1f954}", 0x01),
+	("\u{1f955}", 0x01),
+	("\u{1f956}", 0x01),
+	("\u{1f957}", 0x01),
+	("\u{1f958}", 0x01),
+	("\u{1f959}", 0x01),
+	("\u{1f95a}", 0x01),
+	("\u{1f95b}", 0x01),
+	("\u{1f95c}", 0x01),
+	("\u{1f95d}", 0x01),
+	("\u{1f95e}", 0x01),
+	("\u{1f95f}", 0x01),
+	("\u{1f960}", 0x01),
+	("\u{1f961}", 0x01),
+	("\u{1f962}", 0x01),
+	("\u{1f963}", 0x01),
+	("\u{1f964}", 0x01),
+	("\u{1f965}", 0x01),
+	("\u{1f966}", 0x01),
+	("\u{1f967}", 0x01),
+	("\u{1f968}", 0x01),
+	("\u{1f969}", 0x01),
+	("\u{1f96a}", 0x01),
+	("\u{1f96b}", 0x01),
+	("\u{1f96c}", 0x01),
+	("\u{1f96d}", 0x01),
+	("\u{1f96e}", 0x01),
+	("\u{1f96f}", 0x01),
+	("\u{1f970}", 0x01),
+	("\u{1f971}", 0x01),
+	("\u{1f972}", 0x01),
+	("\u{1f973}", 0x01),
+	("\u{1f974}", 0x01),
+	("\u{1f975}", 0x01),
+	("\u{1f976}", 0x01),
+	("\u{1f977}", 0x01),
+	("\u{1f977}\u{1f3fb}", 0x04),
+	("\u{1f977}\u{1f3fc}", 0x04),
+	("\u{1f977}\u{1f3fd}", 0x04),
+	("\u{1f977}\u{1f3fe}", 0x04),
+	("\u{1f977}\u{1f3ff}", 0x04),
+	("\u{1f978}", 0x01),
+	("\u{1f979}", 0x01),
+	("\u{1f97a}", 0x01),
+	("\u{1f97b}", 0x01),
+	("\u{1f97c}", 0x01),
+	("\u{1f97d}", 0x01),
+	("\u{1f97e}", 0x01),
+	("\u{1f97f}", 0x01),
+	("\u{1f980}", 0x01),
+	("\u{1f981}", 0x01),
+	("\u{1f982}", 0x01),
+	("\u{1f983}", 0x01),
+	("\u{1f984}", 0x01),
+	("\u{1f985}", 0x01),
+	("\u{1f986}", 0x01),
+	("\u{1f987}", 0x01),
+	("\u{1f988}", 0x01),
+	("\u{1f989}", 0x01),
+	("\u{1f98a}", 0x01),
+	("\u{1f98b}", 0x01),
+	("\u{1f98c}", 0x01),
+	("\u{1f98d}", 0x01),
+	("\u{1f98e}", 0x01),
+	("\u{1f98f}", 0x01),
+	("\u{1f990}", 0x01),
+	("\u{1f991}", 0x01),
+	("\u{1f992}", 0x01),
+	("\u{1f993}", 0x01),
+	("\u{1f994}", 0x01),
+	("\u{1f995}", 0x01),
+	("\u{1f996}", 0x01),
+	("\u{1f997}", 0x01),
+	("\u{1f998}", 0x01),
+	("\u{1f999}", 0x01),
+	("\u{1f99a}", 0x01),
+	("\u{1f99b}", 0x01),
+	("\u{1f99c}", 0x01),
+	("\u{1f99d}", 0x01),
+	("\u{1f99e}", 0x01),
+	("\u{1f99f}", 0x01),
+	("\u{1f9a0}", 0x01),
+	("\u{1f9a1}", 0x01),
+	("\u{1f9a2}", 0x01),
+	("\u{1f9a3}", 0x01),
+	("\u{1f9a4}", 0x01),
+	("\u{1f9a5}", 0x01),
+	("\u{1f9a6}", 0x01),
+	("\u{1f9a7}", 0x01),
+	("\u{1f9a8}", 0x01),
+	("\u{1f9a9}", 0x01),
+	("\u{1f9aa}", 0x01),
+	("\u{1f9ab}", 0x01),
+	("\u{1f9ac}", 0x01),
+	("\u{1f9ad}", 0x01),
+	("\u{1f9ae}", 0x01),
+	("\u{1f9af}", 0x01),
+	("\u{1f9b0}", 0x01),
+	("\u{1f9b1}", 0x01),
+	("\u{1f9b2}", 0x01),
+	("\u{1f9b3}", 0x01),
+	("\u{1f9b4}", 0x01),
+	("\u{1f9b5}", 0x01),
+	("\u{1f9b5}\u{1f3fb}", 0x04),
+	("\u{1f9b5}\u{1f3fc}", 0x04),
+	("\u{1f9b5}\u{1f3fd}", 0x04),
+	("\u{1f9b5}\u{1f3fe}", 0x04),
+	("\u{1f9b5}\u{1f3ff}", 0x04),
+	("\u{1f9b6}", 0x01),
+	("\u{1f9b6}\u{1f3fb}", 0x04),
+	("\u{1f9b6}\u{1f3fc}", 0x04),
+	("\u{1f9b6}\u{1f3fd}", 0x04),
+	("\u{1f9b6}\u{1f3fe}", 0x04),
+	("\u{1f9b6}\u{1f3ff}", 0x04),
+	("\u{1f9b7}", 0x01),
+	("\u{1f9b8}", 0x01),
+	("\u{1f9b8}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9b8}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9b8}\u{1f3fb}", 0x04),
+	("\u{1f9b8}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9b8}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9b8}\u{1f3fc}", 0x04),
+	("\u{1f9b8}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9b8}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9b8}\u{1f3fd}", 0x04),
+	("\u{1f9b8}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9b8}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9b8}\u{1f3fe}", 0x04),
+	("\u{1f9b8}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9b8}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9b8}\u{1f3ff}", 0x04),
+	("\u{1f9b8}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9b8}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9b9}", 0x01),
+	("\u{1f9b9}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9b9}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9b9}\u{1f3fb}", 0x04),
+	("\u{1f9b9}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9b9}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9b9}\u{1f3fc}", 0x04),
+	("\u{1f9b9}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9b9}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9b9}\u{1f3fd}", 0x04),
+	("\u{1f9b9}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9b9}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9b9}\u{1f3fe}", 0x04),
+	("\u{1f9b9}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9b9}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9b9}\u{1f3ff}", 0x04),
+	("\u{1f9b9}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9b9}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9ba}", 0x01),
+	("\u{1f9bb}", 0x01),
+	("\u{1f9bb}\u{1f3fb}", 0x04),
+	("\u{1f9bb}\u{1f3fc}", 0x04),
+	("\u{1f9bb}\u{1f3fd}", 0x04),
+	("\u{1f9bb}\u{1f3fe}", 0x04),
+	("\u{1f9bb}\u{1f3ff}", 0x04),
+	("\u{1f9bc}", 0x01),
+	("\u{1f9bd}", 0x01),
+	("\u{1f9be}", 0x01),
+	("\u{1f9bf}", 0x01),
+	("\u{1f9c0}", 0x01),
+	("\u{1f9c1}", 0x01),
+	("\u{1f9c2}", 0x01),
+	("\u{1f9c3}", 0x01),
+	("\u{1f9c4}", 0x01),
+	("\u{1f9c5}", 0x01),
+	("\u{1f9c6}", 0x01),
+	("\u{1f9c7}", 0x01),
+	("\u{1f9c8}", 0x01),
+	("\u{1f9c9}", 0x01),
+	("\u{1f9ca}", 0x01),
+	("\u{1f9cb}", 0x01),
+	("\u{1f9cc}", 0x01),
+	("\u{1f9cd}", 0x01),
+	("\u{1f9cd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9cd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9cd}\u{1f3fb}", 0x04),
+	("\u{1f9cd}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9cd}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9cd}\u{1f3fc}", 0x04),
+	("\u{1f9cd}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9cd}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9cd}\u{1f3fd}", 0x04),
+	("\u{1f9cd}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9cd}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9cd}\u{1f3fe}", 0x04),
+	("\u{1f9cd}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9cd}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9cd}\u{1f3ff}", 0x04),
+	("\u{1f9cd}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9cd}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9ce}", 0x01),
+	("\u{1f9ce}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9ce}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f9ce}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9ce}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f9ce}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f9ce}\u{1f3fb}", 0x04),
+	("\u{1f9ce}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9ce}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f9ce}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9ce}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f9ce}\u{1f3fb}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f9ce}\u{1f3fc}", 0x04),
+	("\u{1f9ce}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9ce}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f9ce}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9ce}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f9ce}\u{1f3fc}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f9ce}\u{1f3fd}", 0x04),
+	("\u{1f9ce}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9ce}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f9ce}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9ce}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f9ce}\u{1f3fd}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f9ce}\u{1f3fe}", 0x04),
+	("\u{1f9ce}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9ce}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f9ce}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9ce}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f9ce}\u{1f3fe}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f9ce}\u{1f3ff}", 0x04),
+	("\u{1f9ce}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9ce}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f9ce}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9ce}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f9ce}\u{1f3ff}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f9cf}", 0x01),
+	("\u{1f9cf}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9cf}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9cf}\u{1f3fb}", 0x04),
+	("\u{1f9cf}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9cf}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9cf}\u{1f3fc}", 0x04),
+	("\u{1f9cf}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9cf}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9cf}\u{1f3fd}", 0x04),
+	("\u{1f9cf}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9cf}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9cf}\u{1f3fe}", 0x04),
+	("\u{1f9cf}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9cf}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9cf}\u{1f3ff}", 0x04),
+	("\u{1f9cf}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9cf}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9d0}", 0x01),
+	("\u{1f9d1}", 0x01),
+	("\u{1f9d1}\u{200d}\u{2695}\u{fe0f}", 0x05),
+	("\u{1f9d1}\u{200d}\u{2696}\u{fe0f}", 0x05),
+	("\u{1f9d1}\u{200d}\u{2708}\u{fe0f}", 0x05),
+	("\u{1f9d1}\u{200d}\u{1f33e}", 0x05),
+	("\u{1f9d1}\u{200d}\u{1f373}", 0x05),
+	("\u{1f9d1}\u{200d}\u{1f37c}", 0x05),
+	("\u{1f9d1}\u{200d}\u{1f384}", 0x05),
+	("\u{1f9d1}\u{200d}\u{1f393}", 0x05),
+	("\u{1f9d1}\u{200d}\u{1f3a4}", 0x05),
+	("\u{1f9d1}\u{200d}\u{1f3a8}", 0x05),
+	("\u{1f9d1}\u{200d}\u{1f3eb}", 0x05),
+	("\u{1f9d1}\u{200d}\u{1f3ed}", 0x05),
+	("\u{1f9d1}\u{200d}\u{1f4bb}", 0x05),
+	("\u{1f9d1}\u{200d}\u{1f4bc}", 0x05),
+	("\u{1f9d1}\u{200d}\u{1f527}", 0x05),
+	("\u{1f9d1}\u{200d}\u{1f52c}", 0x05),
+	("\u{1f9d1}\u{200d}\u{1f680}", 0x05),
+	("\u{1f9d1}\u{200d}\u{1f692}", 0x05),
+	("\u{1f9d1}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}", 0x05),
+	("\u{1f9d1}\u{200d}\u{1f9af}", 0x05),
+	("\u{1f9d1}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f9d1}\u{200d}\u{1f9b0}", 0x05),
+	("\u{1f9d1}\u{200d}\u{1f9b1}", 0x05),
+	("\u{1f9d1}\u{200d}\u{1f9b2}", 0x05),
+	("\u{1f9d1}\u{200d}\u{1f9b3}", 0x05),
+	("\u{1f9d1}\u{200d}\u{1f9bc}", 0x05),
+	("\u{1f9d1}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f9d1}\u{200d}\u{1f9bd}", 0x05),
+	("\u{1f9d1}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f9d1}\u{200d}\u{1f9d1}\u{200d}\u{1f9d2}", 0x05),
+	("\u{1f9d1}\u{200d}\u{1f9d1}\u{200d}\u{1f9d2}\u{200d}\u{1f9d2}", 0x05),
+	("\u{1f9d1}\u{200d}\u{1f9d2}", 0x05),
+	("\u{1f9d1}\u{200d}\u{1f9d2}\u{200d}\u{1f9d2}", 0x05),
+	("\u{1f9d1}\u{1f3fb}", 0x04),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{2695}\u{fe0f}", 0x05),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{2696}\u{fe0f}", 0x05),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{2708}\u{fe0f}", 0x05),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fc}", 0x05),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fd}", 0x05),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fe}", 0x05),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3ff}", 0x05),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fc}", 0x05),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fd}", 0x05),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fe}", 0x05),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3ff}", 0x05),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f33e}", 0x05),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f373}", 0x05),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f37c}", 0x05),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f384}", 0x05),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f393}", 0x05),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f3a4}", 0x05),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f3a8}", 0x05),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f3eb}", 0x05),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f3ed}", 0x05),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f4bb}", 0x05),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f4bc}", 0x05),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f527}", 0x05),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f52c}", 0x05),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f680}", 0x05),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f692}", 0x05),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fb}", 0x05),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fc}", 0x05),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fd}", 0x05),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fe}", 0x05),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3ff}", 0x05),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9af}", 0x05),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9b0}", 0x05),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9b1}", 0x05),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9b2}", 0x05),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9b3}", 0x05),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9bc}", 0x05),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9bd}", 0x05),
+	("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f9d1}\u{1f3fc}", 0x04),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{2695}\u{fe0f}", 0x05),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{2696}\u{fe0f}", 0x05),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{2708}\u{fe0f}", 0x05),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fb}", 0x05),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fd}", 0x05),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fe}", 0x05),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3ff}", 0x05),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fb}", 0x05),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fd}", 0x05),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fe}", 0x05),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3ff}", 0x05),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f33e}", 0x05),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f373}", 0x05),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f37c}", 0x05),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f384}", 0x05),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f393}", 0x05),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f3a4}", 0x05),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f3a8}", 0x05),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f3eb}", 0x05),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f3ed}", 0x05),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f4bb}", 0x05),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f4bc}", 0x05),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f527}", 0x05),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f52c}", 0x05),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f680}", 0x05),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f692}", 0x05),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fb}", 0x05),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fc}", 0x05),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fd}", 0x05),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fe}", 0x05),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3ff}", 0x05),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9af}", 0x05),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9b0}", 0x05),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9b1}", 0x05),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9b2}", 0x05),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9b3}", 0x05),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9bc}", 0x05),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9bd}", 0x05),
+	("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f9d1}\u{1f3fd}", 0x04),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{2695}\u{fe0f}", 0x05),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{2696}\u{fe0f}", 0x05),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{2708}\u{fe0f}", 0x05),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fb}", 0x05),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fc}", 0x05),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fe}", 0x05),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3ff}", 0x05),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fb}", 0x05),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fc}", 0x05),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fe}", 0x05),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3ff}", 0x05),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f33e}", 0x05),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f373}", 0x05),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f37c}", 0x05),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f384}", 0x05),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f393}", 0x05),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f3a4}", 0x05),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f3a8}", 0x05),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f3eb}", 0x05),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f3ed}", 0x05),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f4bb}", 0x05),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f4bc}", 0x05),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f527}", 0x05),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f52c}", 0x05),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f680}", 0x05),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f692}", 0x05),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fb}", 0x05),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fc}", 0x05),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fd}", 0x05),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fe}", 0x05),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3ff}", 0x05),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9af}", 0x05),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9b0}", 0x05),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9b1}", 0x05),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9b2}", 0x05),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9b3}", 0x05),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9bc}", 0x05),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9bd}", 0x05),
+	("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f9d1}\u{1f3fe}", 0x04),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{2695}\u{fe0f}", 0x05),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{2696}\u{fe0f}", 0x05),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{2708}\u{fe0f}", 0x05),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fb}", 0x05),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fc}", 0x05),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fd}", 0x05),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3ff}", 0x05),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fb}", 0x05),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fc}", 0x05),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fd}", 0x05),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3ff}", 0x05),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f33e}", 0x05),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f373}", 0x05),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f37c}", 0x05),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f384}", 0x05),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f393}", 0x05),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f3a4}", 0x05),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f3a8}", 0x05),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f3eb}", 0x05),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f3ed}", 0x05),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f4bb}", 0x05),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f4bc}", 0x05),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f527}", 0x05),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f52c}", 0x05),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f680}", 0x05),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f692}", 0x05),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fb}", 0x05),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fc}", 0x05),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fd}", 0x05),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fe}", 0x05),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3ff}", 0x05),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9af}", 0x05),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9b0}", 0x05),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9b1}", 0x05),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9b2}", 0x05),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9b3}", 0x05),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9bc}", 0x05),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9bd}", 0x05),
+	("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f9d1}\u{1f3ff}", 0x04),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{2695}\u{fe0f}", 0x05),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{2696}\u{fe0f}", 0x05),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{2708}\u{fe0f}", 0x05),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fb}", 0x05),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fc}", 0x05),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fd}", 0x05),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fe}", 0x05),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fb}", 0x05),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fc}", 0x05),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fd}", 0x05),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fe}", 0x05),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f33e}", 0x05),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f373}", 0x05),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f37c}", 0x05),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f384}", 0x05),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f393}", 0x05),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f3a4}", 0x05),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f3a8}", 0x05),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f3eb}", 0x05),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f3ed}", 0x05),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f4bb}", 0x05),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f4bc}", 0x05),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f527}", 0x05),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f52c}", 0x05),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f680}", 0x05),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f692}", 0x05),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fb}", 0x05),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fc}", 0x05),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fd}", 0x05),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fe}", 0x05),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3ff}", 0x05),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9af}", 0x05),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9b0}", 0x05),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9b1}", 0x05),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9b2}", 0x05),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9b3}", 0x05),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9bc}", 0x05),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9bd}", 0x05),
+	("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", 0x05),
+	("\u{1f9d2}", 0x01),
+	("\u{1f9d2}\u{1f3fb}", 0x04),
+	("\u{1f9d2}\u{1f3fc}", 0x04),
+	("\u{1f9d2}\u{1f3fd}", 0x04),
+	("\u{1f9d2}\u{1f3fe}", 0x04),
+	("\u{1f9d2}\u{1f3ff}", 0x04),
+	("\u{1f9d3}", 0x01),
+	("\u{1f9d3}\u{1f3fb}", 0x04),
+	("\u{1f9d3}\u{1f3fc}", 0x04),
+	("\u{1f9d3}\u{1f3fd}", 0x04),
+	("\u{1f9d3}\u{1f3fe}", 0x04),
+	("\u{1f9d3}\u{1f3ff}", 0x04),
+	("\u{1f9d4}", 0x01),
+	("\u{1f9d4}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9d4}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9d4}\u{1f3fb}", 0x04),
+	("\u{1f9d4}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9d4}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9d4}\u{1f3fc}", 0x04),
+	("\u{1f9d4}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9d4}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9d4}\u{1f3fd}", 0x04),
+	("\u{1f9d4}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9d4}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9d4}\u{1f3fe}", 0x04),
+	("\u{1f9d4}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9d4}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9d4}\u{1f3ff}", 0x04),
+	("\u{1f9d4}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9d4}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9d5}", 0x01),
+	("\u{1f9d5}\u{1f3fb}", 0x04),
+	("\u{1f9d5}\u{1f3fc}", 0x04),
+	("\u{1f9d5}\u{1f3fd}", 0x04),
+	("\u{1f9d5}\u{1f3fe}", 0x04),
+	("\u{1f9d5}\u{1f3ff}", 0x04),
+	("\u{1f9d6}", 0x01),
+	("\u{1f9d6}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9d6}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9d6}\u{1f3fb}", 0x04),
+	("\u{1f9d6}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9d6}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9d6}\u{1f3fc}", 0x04),
+	("\u{1f9d6}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9d6}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9d6}\u{1f3fd}", 0x04),
+	("\u{1f9d6}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9d6}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9d6}\u{1f3fe}", 0x04),
+	("\u{1f9d6}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9d6}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9d6}\u{1f3ff}", 0x04),
+	("\u{1f9d6}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9d6}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9d7}", 0x01),
+	("\u{1f9d7}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9d7}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9d7}\u{1f3fb}", 0x04),
+	("\u{1f9d7}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9d7}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9d7}\u{1f3fc}", 0x04),
+	("\u{1f9d7}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9d7}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9d7}\u{1f3fd}", 0x04),
+	("\u{1f9d7}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9d7}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9d7}\u{1f3fe}", 0x04),
+	("\u{1f9d7}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9d7}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9d7}\u{1f3ff}", 0x04),
+	("\u{1f9d7}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9d7}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9d8}", 0x01),
+	("\u{1f9d8}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9d8}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9d8}\u{1f3fb}", 0x04),
+	("\u{1f9d8}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9d8}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9d8}\u{1f3fc}", 0x04),
+	("\u{1f9d8}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9d8}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9d8}\u{1f3fd}", 0x04),
+	("\u{1f9d8}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9d8}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9d8}\u{1f3fe}", 0x04),
+	("\u{1f9d8}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9d8}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9d8}\u{1f3ff}", 0x04),
+	("\u{1f9d8}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9d8}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9d9}", 0x01),
+	("\u{1f9d9}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9d9}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9d9}\u{1f3fb}", 0x04),
+	("\u{1f9d9}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9d9}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9d9}\u{1f3fc}", 0x04),
+	("\u{1f9d9}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9d9}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9d9}\u{1f3fd}", 0x04),
+	("\u{1f9d9}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9d9}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9d9}\u{1f3fe}", 0x04),
+	("\u{1f9d9}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9d9}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9d9}\u{1f3ff}", 0x04),
+	("\u{1f9d9}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9d9}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9da}", 0x01),
+	("\u{1f9da}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9da}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9da}\u{1f3fb}", 0x04),
+	("\u{1f9da}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9da}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9da}\u{1f3fc}", 0x04),
+	("\u{1f9da}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9da}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9da}\u{1f3fd}", 0x04),
+	("\u{1f9da}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9da}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9da}\u{1f3fe}", 0x04),
+	("\u{1f9da}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9da}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9da}\u{1f3ff}", 0x04),
+	("\u{1f9da}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9da}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9db}", 0x01),
+	("\u{1f9db}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9db}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9db}\u{1f3fb}", 0x04),
+	("\u{1f9db}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9db}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9db}\u{1f3fc}", 0x04),
+	("\u{1f9db}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9db}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9db}\u{1f3fd}", 0x04),
+	("\u{1f9db}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9db}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9db}\u{1f3fe}", 0x04),
+	("\u{1f9db}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9db}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9db}\u{1f3ff}", 0x04),
+	("\u{1f9db}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9db}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9dc}", 0x01),
+	("\u{1f9dc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9dc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9dc}\u{1f3fb}", 0x04),
+	("\u{1f9dc}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9dc}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9dc}\u{1f3fc}", 0x04),
+	("\u{1f9dc}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9dc}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9dc}\u{1f3fd}", 0x04),
+	("\u{1f9dc}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9dc}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9dc}\u{1f3fe}", 0x04),
+	("\u{1f9dc}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9dc}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9dc}\u{1f3ff}", 0x04),
+	("\u{1f9dc}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9dc}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9dd}", 0x01),
+	("\u{1f9dd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9dd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9dd}\u{1f3fb}", 0x04),
+	("\u{1f9dd}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9dd}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9dd}\u{1f3fc}", 0x04),
+	("\u{1f9dd}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9dd}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9dd}\u{1f3fd}", 0x04),
+	("\u{1f9dd}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9dd}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9dd}\u{1f3fe}", 0x04),
+	("\u{1f9dd}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9dd}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9dd}\u{1f3ff}", 0x04),
+	("\u{1f9dd}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9dd}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9de}", 0x01),
+	("\u{1f9de}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9de}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9df}", 0x01),
+	("\u{1f9df}\u{200d}\u{2640}\u{fe0f}", 0x05),
+	("\u{1f9df}\u{200d}\u{2642}\u{fe0f}", 0x05),
+	("\u{1f9e0}", 0x01),
+	("\u{1f9e1}", 0x01),
+	("\u{1f9e2}", 0x01),
+	("\u{1f9e3}", 0x01),
+	("\u{1f9e4}", 0x01),
+	("\u{1f9e5}", 0x01),
+	("\u{1f9e6}", 0x01),
+	("\u{1f9e7}", 0x01),
+	("\u{1f9e8}", 0x01),
+	("\u{1f9e9}", 0x01),
+	("\u{1f9ea}", 0x01),
+	("\u{1f9eb}", 0x01),
+	("\u{1f9ec}", 0x01),
+	("\u{1f9ed}", 0x01),
+	("\u{1f9ee}", 0x01),
+	("\u{1f9ef}", 0x01),
+	("\u{1f9f0}", 0x01),
+	("\u{1f9f1}", 0x01),
+	("\u{1f9f2}", 0x01),
+	("\u{1f9f3}", 0x01),
+	("\u{1f9f4}", 0x01),
+	("\u{1f9f5}", 0x01),
+	("\u{1f9f6}", 0x01),
+	("\u{1f9f7}", 0x01),
+	("\u{1f9f8}", 0x01),
+	("\u{1f9f9}", 0x01),
+	("\u{1f9fa}", 0x01),
+	("\u{1f9fb}", 0x01),
+	("\u{1f9fc}", 0x01),
+	("\u{1f9fd}", 0x01),
+	("\u{1f9fe}", 0x01),
+	("\u{1f9ff}", 0x01),
+	("\u{1fa70}", 0x01),
+	("\u{1fa71}", 0x01),
+	("\u{1fa72}", 0x01),
+	("\u{1fa73}", 0x01),
+	("\u{1fa74}", 0x01),
+	("\u{1fa75}", 0x01),
+	("\u{1fa76}", 0x01),
+	("\u{1fa77}", 0x01),
+	("\u{1fa78}", 0x01),
+	("\u{1fa79}", 0x01),
+	("\u{1fa7a}", 0x01),
+	("\u{1fa7b}", 0x01),
+	("\u{1fa7c}", 0x01),
+	("\u{1fa80}", 0x01),
+	("\u{1fa81}", 0x01),
+	("\u{1fa82}", 0x01),
+	("\u{1fa83}", 0x01),
+	("\u{1fa84}", 0x01),
+	("\u{1fa85}", 0x01),
+	("\u{1fa86}", 0x01),
+	("\u{1fa87}", 0x01),
+	("\u{1fa88}", 0x01),
+	("\u{1fa89}", 0x01),
+	("\u{1fa8f}", 0x01),
+	("\u{1fa90}", 0x01),
+	("\u{1fa91}", 0x01),
+	("\u{1fa92}", 0x01),
+	("\u{1fa93}", 0x01),
+	("\u{1fa94}", 0x01),
+	("\u{1fa95}", 0x01),
+	("\u{1fa96}", 0x01),
+	("\u{1fa97}", 0x01),
+	("\u{1fa98}", 0x01),
+	("\u{1fa99}", 0x01),
+	("\u{1fa9a}", 0x01),
+	("\u{1fa9b}", 0x01),
+	("\u{1fa9c}", 0x01),
+	("\u{1fa9d}", 0x01),
+	("\u{1fa9e}", 0x01),
+	("\u{1fa9f}", 0x01),
+	("\u{1faa0}", 0x01),
+	("\u{1faa1}", 0x01),
+	("\u{1faa2}", 0x01),
+	("\u{1faa3}", 0x01),
+	("\u{1faa4}", 0x01),
+	("\u{1faa5}", 0x01),
+	("\u{1faa6}", 0x01),
+	("\u{1faa7}", 0x01),
+	("\u{1faa8}", 0x01),
+	("\u{1faa9}", 0x01),
+	("\u{1faaa}", 0x01),
+	("\u{1faab}", 0x01),
+	("\u{1faac}", 0x01),
+	("\u{1faad}", 0x01),
+	("\u{1faae}", 0x01),
+	("\u{1faaf}", 0x01),
+	("\u{1fab0}", 0x01),
+	("\u{1fab1}", 0x01),
+	("\u{1fab2}", 0x01),
+	("\u{1fab3}", 0x01),
+	("\u{1fab4}", 0x01),
+	("\u{1fab5}", 0x01),
+	("\u{1fab6}", 0x01),
+	("\u{1fab7}", 0x01),
+	("\u{1fab8}", 0x01),
+	("\u{1fab9}", 0x01),
+	("\u{1faba}", 0x01),
+	("\u{1fabb}", 0x01),
+	("\u{1fabc}", 0x01),
+	("\u{1fabd}", 0x01),
+	("\u{1fabe}", 0x01),
+	("\u{1fabf}", 0x01),
+	("\u{1fac0}", 0x01),
+	("\u{1fac1}", 0x01),
+	("\u{1fac2}", 0x01),
+	("\u{1fac3}", 0x01),
+	("\u{1fac3}\u{1f3fb}", 0x04),
+	("\u{1fac3}\u{1f3fc}", 0x04),
+	("\u{1fac3}\u{1f3fd}", 0x04),
+	("\u{1fac3}\u{1f3fe}", 0x04),
+	("\u{1fac3}\u{1f3ff}", 0x04),
+	("\u{1fac4}", 0x01),
+	("\u{1fac4}\u{1f3fb}", 0x04),
+	("\u{1fac4}\u{1f3fc}", 0x04),
+	("\u{1fac4}\u{1f3fd}", 0x04),
+	("\u{1fac4}\u{1f3fe}", 0x04),
+	("\u{1fac4}\u{1f3ff}", 0x04),
+	("\u{1fac5}", 0x01),
+	("\u{1fac5}\u{1f3fb}", 0x04),
+	("\u{1fac5}\u{1f3fc}", 0x04),
+	("\u{1fac5}\u{1f3fd}", 0x04),
+	("\u{1fac5}\u{1f3fe}", 0x04),
+	("\u{1fac5}\u{1f3ff}", 0x04),
+	("\u{1fac6}", 0x01),
+	("\u{1face}", 0x01),
+	("\u{1facf}", 0x01),
+	("\u{1fad0}", 0x01),
+	("\u{1fad1}", 0x01),
+	("\u{1fad2}", 0x01),
+	("\u{1fad3}", 0x01),
+	("\u{1fad4}", 0x01),
+	("\u{1fad5}", 0x01),
+	("\u{1fad6}", 0x01),
+	("\u{1fad7}", 0x01),
+	("\u{1fad8}", 0x01),
+	("\u{1fad9}", 0x01),
+	("\u{1fada}", 0x01),
+	("\u{1fadb}", 0x01),
+	("\u{1fadc}", 0x01),
+	("\u{1fadf}", 0x01),
+	("\u{1fae0}", 0x01),
+	("\u{1fae1}", 0x01),
+	("\u{1fae2}", 0x01),
+	("\u{1fae3}", 0x01),
+	("\u{1fae4}", 0x01),
+	("\u{1fae5}", 0x01),
+	("\u{1fae6}", 0x01),
+	("\u{1fae7}", 0x01),
+	("\u{1fae8}", 0x01),
+	("\u{1fae9}", 0x01),
+	("\u{1faf0}", 0x01),
+	("\u{1faf0}\u{1f3fb}", 0x04),
+	("\u{1faf0}\u{1f3fc}", 0x04),
+	("\u{1faf0}\u{1f3fd}", 0x04),
+	("\u{1faf0}\u{1f3fe}", 0x04),
+	("\u{1faf0}\u{1f3ff}", 0x04),
+	("\u{1faf1}", 0x01),
+	("\u{1faf1}\u{1f3fb}", 0x04),
+	("\u{1faf1}\u{1f3fb}\u{200d}\u{1faf2}\u{1f3fc}", 0x05),
+	("\u{1faf1}\u{1f3fb}\u{200d}\u{1faf2}\u{1f3fd}", 0x05),
+	("\u{1faf1}\u{1f3fb}\u{200d}\u{1faf2}\u{1f3fe}", 0x05),
+	("\u{1faf1}\u{1f3fb}\u{200d}\u{1faf2}\u{1f3ff}", 0x05),
+	("\u{1faf1}\u{1f3fc}", 0x04),
+	("\u{1faf1}\u{1f3fc}\u{200d}\u{1faf2}\u{1f3fb}", 0x05),
+	("\u{1faf1}\u{1f3fc}\u{200d}\u{1faf2}\u{1f3fd}", 0x05),
+	("\u{1faf1}\u{1f3fc}\u{200d}\u{1faf2}\u{1f3fe}", 0x05),
+	("\u{1faf1}\u{1f3fc}\u{200d}\u{1faf2}\u{1f3ff}", 0x05),
+	("\u{1faf1}\u{1f3fd}", 0x04),
+	("\u{1faf1}\u{1f3fd}\u{200d}\u{1faf2}\u{1f3fb}", 0x05),
+	("\u{1faf1}\u{1f3fd}\u{200d}\u{1faf2}\u{1f3fc}", 0x05),
+	("\u{1faf1}\u{1f3fd}\u{200d}\u{1faf2}\u{1f3fe}", 0x05),
+	("\u{1faf1}\u{1f3fd}\u{200d}\u{1faf2}\u{1f3ff}", 0x05),
+	("\u{1faf1}\u{1f3fe}", 0x04),
+	("\u{1faf1}\u{1f3fe}\u{200d}\u{1faf2}\u{1f3fb}", 0x05),
+	("\u{1faf1}\u{1f3fe}\u{200d}\u{1faf2}\u{1f3fc}", 0x05),
+	("\u{1faf1}\u{1f3fe}\u{200d}\u{1faf2}\u{1f3fd}", 0x05),
+	("\u{1faf1}\u{1f3fe}\u{200d}\u{1faf2}\u{1f3ff}", 0x05),
+	("\u{1faf1}\u{1f3ff}", 0x04),
+	("\u{1faf1}\u{1f3ff}\u{200d}\u{1faf2}\u{1f3fb}", 0x05),
+	("\u{1faf1}\u{1f3ff}\u{200d}\u{1faf2}\u{1f3fc}", 0x05),
+	("\u{1faf1}\u{1f3ff}\u{200d}\u{1faf2}\u{1f3fd}", 0x05),
+	("\u{1faf1}\u{1f3ff}\u{200d}\u{1faf2}\u{1f3fe}", 0x05),
+	("\u{1faf2}", 0x01),
+	("\u{1faf2}\u{1f3fb}", 0x04),
+	("\u{1faf2}\u{1f3fc}", 0x04),
+	("\u{1faf2}\u{1f3fd}", 0x04),
+	("\u{1faf2}\u{1f3fe}", 0x04),
+	("\u{1faf2}\u{1f3ff}", 0x04),
+	("\u{1faf3}", 0x01),
+	("\u{1faf3}\u{1f3fb}", 0x04),
+	("\u{1faf3}\u{1f3fc}", 0x04),
+	("\u{1faf3}\u{1f3fd}", 0x04),
+	("\u{1faf3}\u{1f3fe}", 0x04),
+	("\u{1faf3}\u{1f3ff}", 0x04),
+	("\u{1faf4}", 0x01),
+	("\u{1faf4}\u{1f3fb}", 0x04),
+	("\u{1faf4}\u{1f3fc}", 0x04),
+	("\u{1faf4}\u{1f3fd}", 0x04),
+	("\u{1faf4}\u{1f3fe}", 0x04),
+	("\u{1faf4}\u{1f3ff}", 0x04),
+	("\u{1faf5}", 0x01),
+	("\u{1faf5}\u{1f3fb}", 0x04),
+	("\u{1faf5}\u{1f3fc}", 0x04),
+	("\u{1faf5}\u{1f3fd}", 0x04),
+	("\u{1faf5}\u{1f3fe}", 0x04),
+	("\u{1faf5}\u{1f3ff}", 0x04),
+	("\u{1faf6}", 0x01),
+	("\u{1faf6}\u{1f3fb}", 0x04),
+	("\u{1faf6}\u{1f3fc}", 0x04),
+	("\u{1faf6}\u{1f3fd}", 0x04),
+	("\u{1faf6}\u{1f3fe}", 0x04),
+	("\u{1faf6}\u{1f3ff}", 0x04),
+	("\u{1faf7}", 0x01),
+	("\u{1faf7}\u{1f3fb}", 0x04),
+	("\u{1faf7}\u{1f3fc}", 0x04),
+	("\u{1faf7}\u{1f3fd}", 0x04),
+	("\u{1faf7}\u{1f3fe}", 0x04),
+	("\u{1faf7}\u{1f3ff}", 0x04),
+	("\u{1faf8}", 0x01),
+	("\u{1faf8}\u{1f3fb}", 0x04),
+	("\u{1faf8}\u{1f3fc}", 0x04),
+	("\u{1faf8}\u{1f3fd}", 0x04),
+	("\u{1faf8}\u{1f3fe}", 0x04),
+	("\u{1faf8}\u{1f3ff}", 0x04),
+];
@@ -353,7 +353,7 @@ pub const GP_TABLE: [u8;0x1100] = [
 	 1, // 0x15e
 	 1, // 0x15f
 	 1, // 0x160
-	 1, // 0x161
+	 77, // 0x161
 	 1, // 0x162
 	 1, // 0x163
 	 1, // 0x164
@@ -362,12 +362,12 @@ pub const GP_TABLE: [u8;0x1100] = [
 	 1, // 0x167
 	 1, // 0x168
 	 1, // 0x169
-	 77, // 0x16a
-	 78, // 0x16b
+	 78, // 0x16a
+	 79, // 0x16b
 	 1, // 0x16c
-	 1, // 0x16d
+	 80, // 0x16d
 	 1, // 0x16e
-	 79, // 0x16f
+	 81, // 0x16f
 	 1, // 0x170
 	 1, // 0x171
 	 1, // 0x172
@@ -444,7 +444,7 @@ pub const GP_TABLE: [u8;0x1100] = [
 	 1, // 0x1b9
 	 1, // 0x1ba
 	 1, // 0x1bb
-	 80, // 0x1bc
+	 82, // 0x1bc
 	 1, // 0x1bd
 	 1, // 0x1be
 	 1, // 0x1bf
@@ -463,10 +463,10 @@ pub const GP_TABLE: [u8;0x1100] = [
 	 1, // 0x1cc
 	 1, // 0x1cd
 	 1, // 0x1ce
-	 81, // 0x1cf
+	 83, // 0x1cf
 	 1, // 0x1d0
-	 82, // 0x1d1
-	 83, // 0x1d2
+	 84, // 0x1d1
+	 85, // 0x1d2
 	 1, // 0x1d3
 	 1, // 0x1d4
 	 1, // 0x1d5
@@ -474,44 +474,44 @@ pub const GP_TABLE: [u8;0x1100] = [
 	 1, // 0x1d7
 	 1, // 0x1d8
 	 1, // 0x1d9
-	 84, // 0x1da
+	 86, // 0x1da
 	 1, // 0x1db
 	 1, // 0x1dc
 	 1, // 0x1dd
 	 1, // 0x1de
 	 1, // 0x1df
-	 85, // 0x1e0
-	 78, // 0x1e1
-	 86, // 0x1e2
+	 87, // 0x1e0
+	 79, // 0x1e1
+	 88, // 0x1e2
 	 1, // 0x1e3
-	 87, // 0x1e4
-	 1, // 0x1e5
+	 89, // 0x1e4
+	 90, // 0x1e5
 	 1, // 0x1e6
 	 1, // 0x1e7
-	 88, // 0x1e8
-	 89, // 0x1e9
+	 91, // 0x1e8
+	 92, // 0x1e9
 	 1, // 0x1ea
 	 1, // 0x1eb
 	 1, // 0x1ec
 	 1, // 0x1ed
 	 1, // 0x1ee
 	 1, // 0x1ef
-	 90, // 0x1f0
-	 91, // 0x1f1
-	 92, // 0x1f2
-	 93, // 0x1f3
-	 90, // 0x1f4
-	 94, // 0x1f5
-	 95, // 0x1f6
-	 96, // 0x1f7
-	 97, // 0x1f8
-	 98, // 0x1f9
-	 90, // 0x1fa
+	 93, // 0x1f0
+	 94, // 0x1f1
+	 95, // 0x1f2
+	 96, // 0x1f3
+	 93, // 0x1f4
+	 97, // 0x1f5
+	 98, // 0x1f6
+	 99, // 0x1f7
+	 100, // 0x1f8
+	 101, // 0x1f9
+	 93, // 0x1fa
 	 1, // 0x1fb
-	 90, // 0x1fc
-	 90, // 0x1fd
-	 90, // 0x1fe
-	 99, // 0x1ff
+	 93, // 0x1fc
+	 93, // 0x1fd
+	 93, // 0x1fe
+	 102, // 0x1ff
 	 1, // 0x200
 	 1, // 0x201
 	 1, // 0x202
@@ -3584,22 +3584,22 @@ pub const GP_TABLE: [u8;0x1100] = [
 	 1, // 0xdfd
 	 1, // 0xdfe
 	 1, // 0xdff
-	 100, // 0xe00
-	 101, // 0xe01
-	 102, // 0xe02
-	 102, // 0xe03
-	 102, // 0xe04
-	 102, // 0xe05
-	 102, // 0xe06
-	 102, // 0xe07
-	 102, // 0xe08
-	 102, // 0xe09
-	 102, // 0xe0a
-	 102, // 0xe0b
-	 102, // 0xe0c
-	 102, // 0xe0d
-	 102, // 0xe0e
-	 102, // 0xe0f
+	 103, // 0xe00
+	 104, // 0xe01
+	 105, // 0xe02
+	 105, // 0xe03
+	 105, // 0xe04
+	 105, // 0xe05
+	 105, // 0xe06
+	 105, // 0xe07
+	 105, // 0xe08
+	 105, // 0xe09
+	 105, // 0xe0a
+	 105, // 0xe0b
+	 105, // 0xe0c
+	 105, // 0xe0d
+	 105, // 0xe0e
+	 105, // 0xe0f
 	 1, // 0xe10
 	 1, // 0xe11
 	 1, // 0xe12
@@ -4353,7 +4353,7 @@ pub const GP_TABLE: [u8;0x1100] = [
 	 1, // 0x10fe
 	 1, // 0x10ff
 ];
-pub const GP_PAGES: [[u8;256];103] = [
+pub const GP_PAGES: [[u8;256];106] = [
     [
         0x4,
         0x4,
@@ -4871,118 +4871,118 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
     ],
     [
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -5260,13 +5260,13 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -5532,61 +5532,61 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
-        0x1,
+        0x41,
         0x0,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
         0x0,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
         0x0,
-        0x1,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -5661,17 +5661,17 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x4,
         0x0,
@@ -5720,27 +5720,27 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -5757,7 +5757,7 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -5859,30 +5859,30 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x5,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -5920,7 +5920,7 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x5,
         0x0,
-        0x1,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -5951,33 +5951,33 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -6069,17 +6069,17 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -6138,15 +6138,15 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -6156,7 +6156,7 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
+        0x41,
         0x0,
         0x0,
     ],
@@ -6183,30 +6183,30 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -6250,9 +6250,9 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -6312,15 +6312,15 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -6363,65 +6363,65 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x5,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
     ],
     [
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
         0x2,
         0x0,
         0x0,
@@ -6440,10 +6440,85 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x41,
+        0x2,
+        0x41,
         0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x21,
+        0x2,
+        0x2,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
         0x0,
         0x0,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -6464,7 +6539,18 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
         0x0,
+        0x41,
+        0x2,
+        0x2,
         0x0,
         0x0,
         0x0,
@@ -6477,53 +6563,68 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x2,
-        0x1,
         0x0,
-        0x2,
-        0x2,
-        0x2,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x1,
-        0x2,
-        0x2,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
         0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
         0x0,
+        0x10,
         0x0,
         0x0,
         0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
         0x0,
         0x0,
-        0x1,
-        0x1,
+        0x41,
         0x0,
+        0x41,
+        0x2,
+        0x2,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
+        0x2,
+        0x2,
         0x0,
         0x0,
+        0x2,
+        0x2,
+        0x21,
         0x0,
         0x0,
         0x0,
@@ -6533,13 +6634,19 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
         0x0,
         0x0,
         0x0,
         0x0,
+        0x10,
+        0x10,
         0x0,
+        0x10,
         0x0,
         0x0,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -6548,13 +6655,12 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x2,
-        0x2,
         0x0,
         0x0,
         0x0,
         0x0,
+        0x10,
+        0x10,
         0x0,
         0x0,
         0x0,
@@ -6567,8 +6673,14 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
         0x0,
+    ],
+    [
         0x0,
+        0x41,
+        0x41,
+        0x2,
         0x0,
         0x0,
         0x0,
@@ -6607,24 +6719,11 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
         0x0,
-        0x1,
-        0x2,
-        0x2,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
-        0x2,
-        0x2,
         0x0,
         0x0,
-        0x2,
-        0x2,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -6634,23 +6733,32 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
         0x0,
         0x0,
         0x0,
         0x0,
+        0x41,
         0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
         0x0,
-        0x1,
-        0x1,
         0x0,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -6673,23 +6781,20 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
         0x0,
-    ],
-    [
         0x0,
-        0x1,
-        0x1,
-        0x2,
         0x0,
         0x0,
         0x0,
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -6701,6 +6806,9 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x2,
         0x0,
         0x0,
         0x0,
@@ -6718,14 +6826,63 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
         0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
         0x0,
+        0x10,
+        0x10,
         0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
         0x0,
         0x0,
+        0x41,
         0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
+        0x41,
+        0x41,
+        0x2,
         0x0,
+        0x2,
+        0x2,
+        0x21,
         0x0,
         0x0,
         0x0,
@@ -6737,28 +6894,18 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
         0x0,
-        0x2,
-        0x2,
-        0x2,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
+        0x41,
+        0x41,
         0x0,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -6779,7 +6926,19 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x10,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+    ],
+    [
         0x0,
+        0x41,
+        0x2,
+        0x2,
         0x0,
         0x0,
         0x0,
@@ -6789,30 +6948,71 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
-        0x1,
         0x0,
         0x0,
         0x0,
         0x0,
         0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
         0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
         0x0,
+        0x10,
+        0x10,
         0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
         0x0,
         0x0,
+        0x41,
         0x0,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
         0x2,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
+        0x2,
+        0x2,
         0x0,
         0x0,
+        0x2,
+        0x2,
+        0x21,
         0x0,
         0x0,
         0x0,
@@ -6820,13 +7020,21 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
         0x0,
+        0x10,
+        0x10,
         0x0,
+        0x10,
         0x0,
         0x0,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -6840,6 +7048,7 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x10,
         0x0,
         0x0,
         0x0,
@@ -6856,6 +7065,7 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -6865,24 +7075,9 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
         0x0,
-        0x2,
-        0x2,
-        0x2,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
-        0x1,
-        0x1,
-        0x2,
         0x0,
-        0x2,
-        0x2,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -6903,8 +7098,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -6927,26 +7120,27 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-    ],
-    [
         0x0,
-        0x1,
-        0x2,
-        0x2,
         0x0,
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x2,
+        0x41,
+        0x2,
+        0x2,
         0x0,
         0x0,
         0x0,
+        0x2,
+        0x2,
+        0x2,
         0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -6956,6 +7150,7 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -6995,24 +7190,16 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x0,
-        0x1,
-        0x1,
-        0x2,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x0,
         0x0,
+    ],
+    [
+        0x41,
         0x2,
         0x2,
+        0x2,
+        0x41,
         0x0,
         0x0,
-        0x2,
-        0x2,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -7020,9 +7207,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -7030,14 +7214,63 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
         0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
         0x0,
         0x0,
-        0x1,
-        0x1,
+        0x41,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
         0x0,
+        0x41,
+        0x41,
+        0x41,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x21,
         0x0,
         0x0,
         0x0,
@@ -7045,7 +7278,12 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
         0x0,
+        0x10,
+        0x10,
+        0x10,
         0x0,
         0x0,
         0x0,
@@ -7053,6 +7291,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -7065,7 +7305,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -7083,6 +7322,9 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x2,
+        0x2,
         0x0,
         0x0,
         0x0,
@@ -7125,34 +7367,47 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x2,
-        0x1,
-        0x2,
-        0x2,
         0x0,
         0x0,
         0x0,
-        0x2,
-        0x2,
-        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x41,
         0x0,
         0x2,
+        0x41,
+        0x41,
+        0x2,
+        0x41,
         0x2,
         0x2,
-        0x1,
-        0x0,
-        0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
         0x0,
         0x0,
-        0x1,
         0x0,
         0x0,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -7164,6 +7419,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -7179,6 +7436,7 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x2,
         0x0,
         0x0,
         0x0,
@@ -7193,11 +7451,10 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
     ],
     [
-        0x1,
+        0x41,
+        0x41,
         0x2,
         0x2,
-        0x2,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -7215,9 +7472,64 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x41,
+        0x41,
         0x0,
+        0x41,
+        0x2,
+        0x2,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
+        0x2,
+        0x2,
+        0x2,
         0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x21,
+        0x5,
         0x0,
         0x0,
         0x0,
@@ -7226,6 +7538,7 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -7236,6 +7549,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -7253,24 +7568,23 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x2,
-        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x41,
         0x2,
         0x2,
         0x0,
-        0x1,
-        0x1,
-        0x1,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -7278,8 +7592,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -7291,8 +7603,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -7322,9 +7632,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x2,
-        0x2,
         0x0,
         0x0,
         0x0,
@@ -7346,12 +7653,28 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
         0x0,
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x2,
+        0x2,
+        0x41,
+        0x41,
+        0x41,
         0x0,
+        0x41,
         0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -7370,6 +7693,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x2,
+        0x2,
         0x0,
         0x0,
         0x0,
@@ -7381,24 +7706,11 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
         0x0,
-        0x2,
-        0x1,
-        0x2,
-        0x2,
-        0x1,
-        0x2,
-        0x2,
+    ],
+    [
         0x0,
-        0x1,
-        0x2,
-        0x2,
         0x0,
-        0x2,
-        0x2,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -7406,8 +7718,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -7419,8 +7729,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -7436,7 +7744,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x2,
         0x0,
         0x0,
         0x0,
@@ -7449,12 +7756,18 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-    ],
-    [
-        0x1,
-        0x1,
-        0x2,
+        0x0,
+        0x0,
+        0x41,
+        0x0,
         0x2,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -7467,6 +7780,14 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -7510,26 +7831,9 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
         0x0,
-        0x1,
-        0x2,
-        0x2,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
-        0x2,
-        0x2,
-        0x2,
         0x0,
-        0x2,
-        0x2,
-        0x2,
-        0x1,
-        0x5,
         0x0,
         0x0,
         0x0,
@@ -7538,7 +7842,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -7549,8 +7852,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -7580,15 +7881,23 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x2,
-        0x2,
         0x0,
         0x0,
         0x0,
         0x0,
         0x0,
+        0x41,
         0x0,
+        0x2,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -7600,6 +7909,13 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -7649,32 +7965,18 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+    ],
+    [
         0x0,
         0x0,
         0x0,
         0x0,
-        0x1,
         0x0,
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x2,
-        0x2,
-        0x1,
-        0x1,
-        0x1,
         0x0,
-        0x1,
         0x0,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -7689,12 +7991,12 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
         0x0,
-        0x2,
-        0x2,
         0x0,
         0x0,
         0x0,
@@ -7707,8 +8009,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-    ],
-    [
         0x0,
         0x0,
         0x0,
@@ -7720,12 +8020,17 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
         0x0,
+        0x41,
         0x0,
+        0x41,
         0x0,
         0x0,
         0x0,
         0x0,
+        0x2,
+        0x2,
         0x0,
         0x0,
         0x0,
@@ -7758,16 +8063,7 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
         0x0,
-        0x2,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -7780,25 +8076,86 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x2,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -7808,6 +8165,7 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -7865,6 +8223,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+    ],
+    [
         0x0,
         0x0,
         0x0,
@@ -7886,18 +8246,7 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
         0x0,
-        0x2,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -7909,13 +8258,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -7928,7 +8270,24 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x2,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
+        0x41,
+        0x41,
+        0x2,
+        0x2,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -7952,10 +8311,17 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x2,
+        0x2,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -7965,8 +8331,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-    ],
-    [
         0x0,
         0x0,
         0x0,
@@ -7974,6 +8338,10 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -7987,15 +8355,18 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
         0x0,
+        0x2,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -8011,378 +8382,7 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x1,
-        0x0,
-        0x1,
-        0x0,
-        0x1,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x2,
-        0x2,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x2,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x0,
-        0x1,
-        0x1,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x1,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-    ],
-    [
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x2,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x0,
-        0x1,
-        0x1,
-        0x2,
-        0x2,
-        0x1,
-        0x1,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x2,
-        0x2,
-        0x1,
-        0x1,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x1,
-        0x0,
-        0x2,
-        0x1,
-        0x1,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x1,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x1,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -8834,9 +8834,9 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -9017,10 +9017,10 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x2,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -9049,9 +9049,9 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x2,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -9081,8 +9081,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -9113,8 +9113,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -9179,16 +9179,16 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
         0x2,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x2,
         0x2,
         0x2,
@@ -9197,20 +9197,20 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x2,
         0x2,
         0x2,
-        0x1,
+        0x41,
         0x2,
         0x2,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -9220,7 +9220,7 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -9268,11 +9268,11 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
         0x4,
-        0x1,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -9390,8 +9390,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -9426,7 +9426,7 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -9547,15 +9547,15 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
         0x2,
         0x2,
         0x2,
         0x2,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
         0x2,
         0x2,
         0x2,
@@ -9565,16 +9565,16 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x2,
         0x2,
-        0x1,
+        0x41,
         0x2,
         0x2,
         0x2,
         0x2,
         0x2,
         0x2,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -9796,11 +9796,11 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
         0x2,
         0x2,
-        0x1,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -9859,48 +9859,48 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x2,
-        0x1,
+        0x41,
         0x2,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
-        0x1,
+        0x41,
         0x0,
-        0x1,
+        0x41,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x2,
         0x2,
         0x2,
         0x2,
         0x2,
         0x2,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
-        0x1,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -9949,37 +9949,37 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -10031,10 +10031,10 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
     ],
     [
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x2,
         0x0,
         0x0,
@@ -10083,23 +10083,23 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x2,
-        0x1,
-        0x2,
-        0x2,
-        0x2,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x2,
         0x2,
-        0x1,
         0x2,
         0x2,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -10138,15 +10138,15 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -10159,8 +10159,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
         0x2,
         0x0,
         0x0,
@@ -10193,18 +10193,18 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x2,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x2,
         0x2,
-        0x1,
-        0x1,
-        0x2,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -10261,20 +10261,20 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x2,
-        0x1,
-        0x1,
+        0x41,
         0x2,
+        0x41,
+        0x41,
         0x2,
         0x2,
-        0x1,
-        0x2,
-        0x1,
-        0x1,
-        0x1,
         0x2,
+        0x41,
         0x2,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -10333,18 +10333,18 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x2,
         0x2,
         0x2,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x2,
         0x2,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -10497,48 +10497,48 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x2,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
         0x0,
-        0x1,
+        0x41,
         0x0,
         0x0,
         0x0,
         0x0,
         0x0,
         0x0,
-        0x1,
+        0x41,
         0x0,
         0x0,
         0x2,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -10739,70 +10739,70 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
     ],
     [
         0x0,
@@ -10818,7 +10818,7 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x4,
         0x1,
-        0x3,
+        0x43,
         0x4,
         0x4,
         0x0,
@@ -11013,39 +11013,39 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -13366,9 +13366,9 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -13512,7 +13512,7 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -13609,38 +13609,38 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
     ],
     [
         0x0,
@@ -13685,12 +13685,12 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x6,
         0x0,
         0x0,
@@ -13796,8 +13796,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -14270,21 +14270,21 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -14317,8 +14317,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -14399,8 +14399,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -14419,16 +14419,16 @@ pub const GP_PAGES: [[u8;256];103] = [
     [
         0x0,
         0x0,
-        0x1,
+        0x41,
         0x0,
         0x0,
         0x0,
-        0x1,
+        0x41,
         0x0,
         0x0,
         0x0,
         0x0,
-        0x1,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -14454,14 +14454,14 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x2,
         0x2,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
         0x2,
         0x0,
         0x0,
         0x0,
         0x0,
-        0x1,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -14613,8 +14613,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x2,
         0x2,
         0x2,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -14641,24 +14641,24 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -14672,7 +14672,7 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
+        0x41,
     ],
     [
         0x0,
@@ -14713,14 +14713,14 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -14746,19 +14746,19 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x2,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x2,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -14803,9 +14803,9 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
         0x2,
         0x0,
         0x0,
@@ -14854,20 +14854,20 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
+        0x41,
         0x2,
         0x2,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x2,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x2,
-        0x1,
-        0x1,
         0x2,
+        0x41,
+        0x41,
         0x2,
         0x2,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -14904,7 +14904,7 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -14974,20 +14974,20 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x2,
         0x2,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
         0x2,
         0x2,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -15000,7 +15000,7 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -15009,7 +15009,7 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
+        0x41,
         0x2,
         0x0,
         0x0,
@@ -15057,7 +15057,7 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -15109,24 +15109,24 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
+        0x41,
         0x0,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
         0x0,
-        0x1,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -15169,8 +15169,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x2,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
         0x2,
         0x2,
         0x0,
@@ -15179,7 +15179,7 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x2,
-        0x1,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -15420,15 +15420,15 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x2,
         0x2,
-        0x1,
+        0x41,
         0x2,
         0x2,
-        0x1,
+        0x41,
         0x2,
         0x2,
         0x0,
         0x2,
-        0x1,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -17511,8 +17511,689 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-    ],
-    [
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x41,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x4,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x41,
+        0x41,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
         0x0,
         0x0,
         0x0,
@@ -17543,7 +18224,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -17589,10 +18269,24 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
         0x0,
         0x0,
         0x0,
         0x0,
+    ],
+    [
         0x0,
         0x0,
         0x0,
@@ -17769,24 +18463,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-    ],
-    [
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -17803,22 +18479,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -17880,8 +18540,11 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
         0x0,
         0x0,
+    ],
+    [
         0x0,
         0x0,
         0x0,
@@ -18026,9 +18689,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x4,
-    ],
-    [
         0x0,
         0x0,
         0x0,
@@ -18109,6 +18769,7 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -18140,6 +18801,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+    ],
+    [
         0x0,
         0x0,
         0x0,
@@ -18187,8 +18850,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -18260,6 +18921,11 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -18269,24 +18935,10 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
         0x0,
         0x0,
         0x0,
         0x0,
-    ],
-    [
         0x0,
         0x0,
         0x0,
@@ -18407,13 +19059,24 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+    ],
+    [
         0x0,
+        0x41,
+        0x41,
+        0x41,
         0x0,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -18454,10 +19117,14 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
         0x0,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -18540,11 +19207,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
         0x0,
         0x0,
-    ],
-    [
         0x0,
         0x0,
         0x0,
@@ -18626,6 +19290,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -18651,6 +19317,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+    ],
+    [
         0x0,
         0x0,
         0x0,
@@ -18687,6 +19355,10 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -18752,6 +19424,11 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -18769,7 +19446,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -18801,8 +19477,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-    ],
-    [
         0x0,
         0x0,
         0x0,
@@ -18901,6 +19575,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+    ],
+    [
         0x0,
         0x0,
         0x0,
@@ -18921,11 +19597,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -19059,24 +19730,13 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-    ],
-    [
         0x0,
-        0x1,
-        0x1,
-        0x1,
         0x0,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -19088,6 +19748,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -19117,14 +19779,10 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
         0x0,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -19171,6 +19829,12 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+    ],
+    [
         0x0,
         0x0,
         0x0,
@@ -19241,6 +19905,17 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -19290,8 +19965,10 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -19317,8 +19994,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-    ],
-    [
         0x0,
         0x0,
         0x0,
@@ -19355,10 +20030,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -19420,6 +20091,11 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+    ],
+    [
+        0x2,
+        0x41,
+        0x2,
         0x0,
         0x0,
         0x0,
@@ -19473,6 +20149,21 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -19514,8 +20205,11 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
         0x0,
         0x0,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -19526,6 +20220,10 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x2,
         0x0,
         0x0,
         0x0,
@@ -19571,14 +20269,25 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x2,
+        0x2,
+        0x41,
+        0x41,
         0x0,
         0x0,
+        0x5,
         0x0,
         0x0,
-    ],
-    [
         0x0,
         0x0,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -19589,6 +20298,7 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x5,
         0x0,
         0x0,
         0x0,
@@ -19639,6 +20349,11 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+    ],
+    [
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -19675,6 +20390,20 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x2,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -19691,6 +20420,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x2,
+        0x2,
         0x0,
         0x0,
         0x0,
@@ -19735,6 +20466,7 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -19747,9 +20479,10 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x2,
         0x0,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -19797,13 +20530,35 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x2,
+        0x41,
         0x0,
+        0x5,
+        0x5,
         0x0,
         0x0,
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
+        0x2,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -19830,11 +20585,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-    ],
-    [
         0x0,
         0x0,
         0x0,
@@ -19857,6 +20607,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+    ],
+    [
         0x0,
         0x0,
         0x0,
@@ -19901,21 +20653,28 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x41,
+        0x41,
+        0x41,
+        0x2,
+        0x2,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x0,
+        0x0,
+        0x41,
+        0x0,
+        0x0,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -19965,10 +20724,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -20077,6 +20832,25 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x2,
+        0x2,
+        0x2,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
         0x0,
         0x0,
         0x0,
@@ -20093,8 +20867,9 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
     ],
     [
+        0x41,
+        0x41,
         0x2,
-        0x1,
         0x2,
         0x0,
         0x0,
@@ -20149,24 +20924,27 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x0,
+        0x41,
+        0x2,
+        0x41,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x0,
         0x0,
+        0x2,
+        0x2,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -20176,6 +20954,7 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -20186,11 +20965,25 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x2,
+        0x2,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -20205,11 +20998,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
         0x0,
         0x0,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -20220,10 +21010,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x2,
         0x0,
         0x0,
         0x0,
@@ -20265,29 +21051,67 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x2,
+        0x2,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x0,
+        0x41,
+        0x0,
+        0x0,
+        0x41,
+        0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x41,
+        0x41,
+        0x41,
+        0x5,
+        0x41,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x41,
+        0x41,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
         0x0,
         0x0,
         0x0,
         0x0,
-        0x2,
-        0x2,
-        0x2,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x2,
-        0x2,
-        0x1,
-        0x1,
         0x0,
         0x0,
-        0x5,
         0x0,
         0x0,
         0x0,
         0x0,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -20298,8 +21122,9 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x5,
         0x0,
+    ],
+    [
         0x0,
         0x0,
         0x0,
@@ -20349,15 +21174,28 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-    ],
-    [
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
         0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x2,
+        0x2,
+        0x41,
+        0x41,
+        0x41,
+        0x2,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -20381,6 +21219,7 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -20390,20 +21229,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x2,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -20420,8 +21245,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x2,
-        0x2,
         0x0,
         0x0,
         0x0,
@@ -20466,7 +21289,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -20479,9 +21301,26 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
+        0x41,
         0x2,
+        0x2,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x2,
+        0x41,
+        0x2,
+        0x2,
+        0x41,
+        0x2,
+        0x41,
+        0x41,
+        0x2,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -20530,35 +21369,10 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x2,
-        0x2,
-        0x2,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x2,
-        0x2,
-        0x0,
-        0x5,
-        0x5,
-        0x0,
-        0x0,
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
-        0x2,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -20567,6 +21381,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+    ],
+    [
         0x0,
         0x0,
         0x0,
@@ -20607,8 +21423,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-    ],
-    [
         0x0,
         0x0,
         0x0,
@@ -20653,28 +21467,14 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x2,
-        0x2,
-        0x2,
-        0x1,
-        0x1,
-        0x1,
-        0x2,
-        0x2,
-        0x1,
-        0x2,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
         0x0,
         0x0,
         0x0,
-        0x1,
         0x0,
         0x0,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -20758,8 +21558,24 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x2,
+        0x2,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x41,
+        0x41,
+        0x2,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -20787,6 +21603,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -20821,6 +21639,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+    ],
+    [
         0x0,
         0x0,
         0x0,
@@ -20832,18 +21652,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x2,
-        0x2,
-        0x2,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -20865,12 +21673,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-    ],
-    [
-        0x1,
-        0x1,
-        0x2,
-        0x2,
         0x0,
         0x0,
         0x0,
@@ -20887,6 +21689,23 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x2,
+        0x2,
+        0x41,
+        0x2,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -20926,25 +21745,11 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
         0x0,
-        0x1,
-        0x2,
-        0x1,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
         0x0,
         0x0,
-        0x2,
-        0x2,
         0x0,
         0x0,
-        0x2,
-        0x2,
-        0x2,
         0x0,
         0x0,
         0x0,
@@ -20954,7 +21759,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -20965,25 +21769,11 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x2,
-        0x2,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -21022,6 +21812,19 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x2,
+        0x41,
+        0x2,
+        0x2,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -21094,6 +21897,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+    ],
+    [
         0x0,
         0x0,
         0x0,
@@ -21123,10 +21928,21 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-    ],
-    [
+        0x41,
+        0x2,
+        0x41,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x2,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -21178,24 +21994,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x2,
-        0x2,
-        0x2,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x2,
-        0x2,
-        0x1,
-        0x1,
-        0x1,
-        0x2,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -21219,7 +22017,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -21301,26 +22098,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x2,
-        0x2,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x2,
-        0x1,
-        0x2,
-        0x2,
-        0x1,
-        0x2,
-        0x1,
-        0x1,
-        0x2,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -21378,11 +22155,11 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+    ],
+    [
         0x0,
         0x0,
         0x0,
-    ],
-    [
         0x0,
         0x0,
         0x0,
@@ -21424,6 +22201,21 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x2,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -21558,24 +22350,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x2,
-        0x2,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x1,
-        0x1,
-        0x2,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -21603,8 +22379,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -21689,36 +22463,26 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
         0x2,
         0x2,
         0x2,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x2,
         0x2,
-        0x1,
         0x2,
-        0x1,
-        0x1,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
         0x0,
+        0x2,
+        0x2,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x5,
+        0x2,
+        0x5,
+        0x2,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -21812,19 +22576,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x2,
-        0x1,
-        0x2,
-        0x2,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x2,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -21873,11 +22624,26 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x41,
         0x0,
         0x0,
         0x0,
+        0x2,
         0x0,
         0x0,
         0x0,
@@ -21897,8 +22663,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-    ],
-    [
         0x0,
         0x0,
         0x0,
@@ -21907,7 +22671,19 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+    ],
+    [
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -21928,21 +22704,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x2,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -21961,6 +22724,18 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x2,
+        0x5,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -21969,6 +22744,7 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -21978,6 +22754,17 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x2,
+        0x2,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -22018,6 +22805,28 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x2,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -22120,6 +22929,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+    ],
+    [
         0x0,
         0x0,
         0x0,
@@ -22155,8 +22966,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-    ],
-    [
         0x0,
         0x0,
         0x0,
@@ -22169,7 +22978,23 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x2,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x2,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -22201,21 +23026,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x2,
-        0x2,
-        0x2,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x2,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -22267,7 +23077,43 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
+        0x2,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x2,
+        0x41,
+        0x41,
+        0x2,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -22341,6 +23187,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+    ],
+    [
         0x0,
         0x0,
         0x0,
@@ -22390,11 +23238,29 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
+        0x41,
         0x0,
+        0x41,
+        0x41,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x5,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -22413,8 +23279,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-    ],
-    [
         0x0,
         0x0,
         0x0,
@@ -22463,43 +23327,20 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x2,
         0x2,
         0x2,
         0x2,
         0x2,
-        0x0,
-        0x2,
         0x2,
         0x0,
+        0x41,
+        0x41,
         0x0,
-        0x1,
-        0x1,
         0x2,
-        0x1,
-        0x5,
         0x2,
-        0x5,
+        0x41,
         0x2,
-        0x1,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -22604,6 +23445,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+    ],
+    [
         0x0,
         0x0,
         0x0,
@@ -22624,26 +23467,11 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x2,
-        0x2,
-        0x2,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x1,
         0x0,
         0x0,
         0x0,
-        0x2,
         0x0,
         0x0,
         0x0,
@@ -22671,19 +23499,7 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-    ],
-    [
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -22724,18 +23540,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x2,
-        0x5,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -22744,7 +23548,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -22754,17 +23557,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x2,
-        0x2,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -22805,28 +23597,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x5,
-        0x5,
-        0x5,
-        0x5,
-        0x5,
-        0x5,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x2,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -22920,6 +23690,10 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x2,
+        0x2,
         0x0,
         0x0,
         0x0,
@@ -22931,6 +23705,10 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
     ],
     [
+        0x41,
+        0x41,
+        0x5,
+        0x2,
         0x0,
         0x0,
         0x0,
@@ -22978,30 +23756,22 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x2,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x2,
-        0x1,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
+        0x2,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
+        0x2,
+        0x2,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -23025,6 +23795,7 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -23077,43 +23848,7 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
-        0x2,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x2,
-        0x1,
-        0x1,
-        0x2,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -23187,8 +23922,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-    ],
-    [
         0x0,
         0x0,
         0x0,
@@ -23228,6 +23961,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+    ],
+    [
         0x0,
         0x0,
         0x0,
@@ -23238,29 +23973,11 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
-        0x1,
         0x0,
-        0x1,
-        0x1,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x5,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -23294,12 +24011,44 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x41,
         0x0,
         0x0,
         0x0,
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -23327,20 +24076,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
         0x0,
-        0x1,
-        0x1,
         0x0,
-        0x2,
-        0x2,
-        0x1,
-        0x2,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -23445,8 +24182,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-    ],
-    [
         0x0,
         0x0,
         0x0,
@@ -23484,6 +24219,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+    ],
+    [
         0x0,
         0x0,
         0x0,
@@ -23514,6 +24251,24 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x2,
+        0x2,
+        0x2,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -23690,10 +24445,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x2,
-        0x2,
         0x0,
         0x0,
         0x0,
@@ -23703,12 +24454,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-    ],
-    [
-        0x1,
-        0x1,
-        0x5,
-        0x2,
         0x0,
         0x0,
         0x0,
@@ -23732,6 +24477,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+    ],
+    [
         0x0,
         0x0,
         0x0,
@@ -23757,21 +24504,9 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x2,
-        0x2,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
-        0x2,
-        0x2,
-        0x1,
-        0x2,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -23961,8 +24696,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-    ],
-    [
         0x0,
         0x0,
         0x0,
@@ -23986,6 +24719,11 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -23997,6 +24735,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+    ],
+    [
         0x0,
         0x0,
         0x0,
@@ -24011,44 +24751,12 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
-        0x1,
         0x0,
         0x0,
         0x0,
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -24077,6 +24785,13 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -24219,8 +24934,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-    ],
-    [
         0x0,
         0x0,
         0x0,
@@ -24280,6 +24993,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+    ],
+    [
         0x0,
         0x0,
         0x0,
@@ -24379,9 +25094,16 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x8,
         0x0,
         0x0,
         0x0,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x0,
+        0x0,
         0x0,
         0x0,
         0x0,
@@ -24461,11 +25183,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -24477,8 +25194,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-    ],
-    [
         0x0,
         0x0,
         0x0,
@@ -24527,13 +25242,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -24543,6 +25251,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+    ],
+    [
         0x0,
         0x0,
         0x0,
@@ -24622,6 +25332,63 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
         0x0,
         0x0,
         0x0,
@@ -24629,6 +25396,10 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -24710,6 +25481,7 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -24721,6 +25493,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -24816,63 +25590,7 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
         0x0,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
-        0x2,
         0x0,
         0x0,
         0x0,
@@ -24880,10 +25598,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -24954,6 +25668,18 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x0,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
         0x0,
         0x0,
         0x0,
@@ -24965,7 +25691,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -24977,8 +25702,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x2,
-        0x2,
         0x0,
         0x0,
         0x0,
@@ -24993,8 +25716,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-    ],
-    [
         0x0,
         0x0,
         0x0,
@@ -25046,8 +25767,79 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+    ],
+    [
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -25152,13 +25944,7 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
         0x0,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
         0x0,
         0x0,
         0x0,
@@ -25239,6 +26025,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+    ],
+    [
         0x0,
         0x0,
         0x0,
@@ -25251,79 +26039,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-    ],
-    [
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -25411,11 +26128,45 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -25446,6 +26197,10 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -25509,8 +26264,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-    ],
-    [
         0x0,
         0x0,
         0x0,
@@ -25530,6 +26283,8 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+    ],
+    [
         0x0,
         0x0,
         0x0,
@@ -25596,6 +26351,9 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -25612,45 +26370,11 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x2,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
-        0x2,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -25681,10 +26405,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -25767,8 +26487,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-    ],
-    [
         0x0,
         0x0,
         0x0,
@@ -25823,10 +26541,117 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+    ],
+    [
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -25835,9 +26660,7 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -25852,6 +26675,7 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -25874,7 +26698,27 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -25955,11 +26799,51 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+    ],
+    [
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
+        0x41,
+        0x41,
         0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -26025,117 +26909,10 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-    ],
-    [
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -26144,7 +26921,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -26159,7 +26935,25 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x41,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
         0x0,
         0x0,
         0x0,
@@ -26182,27 +26976,7 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -26285,49 +27059,11 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
     ],
     [
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
-        0x1,
-        0x1,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -26428,7 +27164,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -26498,6 +27233,24 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x41,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
         0x0,
         0x0,
         0x0,
@@ -26541,6 +27294,27 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
+        0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
     ],
     [
         0x0,
@@ -26717,7 +27491,6 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
         0x0,
         0x0,
         0x0,
@@ -26779,10 +27552,11 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -27037,10 +27811,10 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x0,
+        0x0,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -27267,13 +28041,13 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -27385,13 +28159,13 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x0,
         0x0,
         0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x0,
         0x0,
         0x0,
@@ -28600,11 +29374,11 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x6,
         0x6,
         0x6,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
     ],
     [
         0x6,
@@ -30187,118 +30961,118 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x4,
         0x4,
         0x4,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
-        0x4,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
         0x4,
         0x4,
         0x4,
@@ -30413,246 +31187,246 @@ pub const GP_PAGES: [[u8;256];103] = [
         0x4,
     ],
     [
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
         0x4,
         0x4,
         0x4,
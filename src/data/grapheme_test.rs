@@ -36,13 +36,13 @@ fn standard_grapheme_test() {
 		&["\u{0020}\u{0308}", "\u{0001}"],
 		"  ÷ [0.2] SPACE (Other) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <START OF HEADING> (Control) ÷ [0.3]"
 	);
-	grapheme_test("\u{0020}\u{034F}",
-		&["\u{0020}\u{034F}"],
-		"  ÷ [0.2] SPACE (Other) × [9.0] COMBINING GRAPHEME JOINER (Extend) ÷ [0.3]"
+	grapheme_test("\u{0020}\u{200C}",
+		&["\u{0020}\u{200C}"],
+		"  ÷ [0.2] SPACE (Other) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
 	);
-	grapheme_test("\u{0020}\u{0308}\u{034F}",
-		&["\u{0020}\u{0308}\u{034F}"],
-		"  ÷ [0.2] SPACE (Other) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAPHEME JOINER (Extend) ÷ [0.3]"
+	grapheme_test("\u{0020}\u{0308}\u{200C}",
+		&["\u{0020}\u{0308}\u{200C}"],
+		"  ÷ [0.2] SPACE (Other) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
 	);
 	grapheme_test("\u{0020}\u{1F1E6}",
 		&["\u{0020}", "\u{1F1E6}"],
@@ -60,13 +60,13 @@ fn standard_grapheme_test() {
 		&["\u{0020}\u{0308}", "\u{0600}"],
 		"  ÷ [0.2] SPACE (Other) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] ARABIC NUMBER SIGN (Prepend) ÷ [0.3]"
 	);
-	grapheme_test("\u{0020}\u{0903}",
-		&["\u{0020}\u{0903}"],
-		"  ÷ [0.2] SPACE (Other) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	grapheme_test("\u{0020}\u{0A03}",
+		&["\u{0020}\u{0A03}"],
+		"  ÷ [0.2] SPACE (Other) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
 	);
-	grapheme_test("\u{0020}\u{0308}\u{0903}",
-		&["\u{0020}\u{0308}\u{0903}"],
-		"  ÷ [0.2] SPACE (Other) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	grapheme_test("\u{0020}\u{0308}\u{0A03}",
+		&["\u{0020}\u{0308}\u{0A03}"],
+		"  ÷ [0.2] SPACE (Other) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
 	);
 	grapheme_test("\u{0020}\u{1100}",
 		&["\u{0020}", "\u{1100}"],
@@ -108,6 +108,38 @@ fn standard_grapheme_test() {
 		&["\u{0020}\u{0308}", "\u{AC01}"],
 		"  ÷ [0.2] SPACE (Other) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL SYLLABLE GAG (LVT) ÷ [0.3]"
 	);
+	grapheme_test("\u{0020}\u{0903}",
+		&["\u{0020}\u{0903}"],
+		"  ÷ [0.2] SPACE (Other) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0020}\u{0308}\u{0903}",
+		&["\u{0020}\u{0308}\u{0903}"],
+		"  ÷ [0.2] SPACE (Other) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0020}\u{0904}",
+		&["\u{0020}", "\u{0904}"],
+		"  ÷ [0.2] SPACE (Other) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0020}\u{0308}\u{0904}",
+		&["\u{0020}\u{0308}", "\u{0904}"],
+		"  ÷ [0.2] SPACE (Other) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0020}\u{0D4E}",
+		&["\u{0020}", "\u{0D4E}"],
+		"  ÷ [0.2] SPACE (Other) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0020}\u{0308}\u{0D4E}",
+		&["\u{0020}\u{0308}", "\u{0D4E}"],
+		"  ÷ [0.2] SPACE (Other) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0020}\u{0915}",
+		&["\u{0020}", "\u{0915}"],
+		"  ÷ [0.2] SPACE (Other) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
+	grapheme_test("\u{0020}\u{0308}\u{0915}",
+		&["\u{0020}\u{0308}", "\u{0915}"],
+		"  ÷ [0.2] SPACE (Other) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
 	grapheme_test("\u{0020}\u{231A}",
 		&["\u{0020}", "\u{231A}"],
 		"  ÷ [0.2] SPACE (Other) ÷ [999.0] WATCH (ExtPict) ÷ [0.3]"
@@ -124,6 +156,22 @@ fn standard_grapheme_test() {
 		&["\u{0020}\u{0308}\u{0300}"],
 		"  ÷ [0.2] SPACE (Other) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) ÷ [0.3]"
 	);
+	grapheme_test("\u{0020}\u{0900}",
+		&["\u{0020}\u{0900}"],
+		"  ÷ [0.2] SPACE (Other) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0020}\u{0308}\u{0900}",
+		&["\u{0020}\u{0308}\u{0900}"],
+		"  ÷ [0.2] SPACE (Other) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0020}\u{094D}",
+		&["\u{0020}\u{094D}"],
+		"  ÷ [0.2] SPACE (Other) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0020}\u{0308}\u{094D}",
+		&["\u{0020}\u{0308}\u{094D}"],
+		"  ÷ [0.2] SPACE (Other) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
 	grapheme_test("\u{0020}\u{200D}",
 		&["\u{0020}\u{200D}"],
 		"  ÷ [0.2] SPACE (Other) × [9.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [0.3]"
@@ -172,13 +220,13 @@ fn standard_grapheme_test() {
 		&["\u{000D}", "\u{0308}", "\u{0001}"],
 		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <START OF HEADING> (Control) ÷ [0.3]"
 	);
-	grapheme_test("\u{000D}\u{034F}",
-		&["\u{000D}", "\u{034F}"],
-		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] COMBINING GRAPHEME JOINER (Extend) ÷ [0.3]"
+	grapheme_test("\u{000D}\u{200C}",
+		&["\u{000D}", "\u{200C}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
 	);
-	grapheme_test("\u{000D}\u{0308}\u{034F}",
-		&["\u{000D}", "\u{0308}\u{034F}"],
-		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAPHEME JOINER (Extend) ÷ [0.3]"
+	grapheme_test("\u{000D}\u{0308}\u{200C}",
+		&["\u{000D}", "\u{0308}\u{200C}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
 	);
 	grapheme_test("\u{000D}\u{1F1E6}",
 		&["\u{000D}", "\u{1F1E6}"],
@@ -196,13 +244,13 @@ fn standard_grapheme_test() {
 		&["\u{000D}", "\u{0308}", "\u{0600}"],
 		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] ARABIC NUMBER SIGN (Prepend) ÷ [0.3]"
 	);
-	grapheme_test("\u{000D}\u{0903}",
-		&["\u{000D}", "\u{0903}"],
-		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	grapheme_test("\u{000D}\u{0A03}",
+		&["\u{000D}", "\u{0A03}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
 	);
-	grapheme_test("\u{000D}\u{0308}\u{0903}",
-		&["\u{000D}", "\u{0308}\u{0903}"],
-		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	grapheme_test("\u{000D}\u{0308}\u{0A03}",
+		&["\u{000D}", "\u{0308}\u{0A03}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
 	);
 	grapheme_test("\u{000D}\u{1100}",
 		&["\u{000D}", "\u{1100}"],
@@ -244,6 +292,38 @@ fn standard_grapheme_test() {
 		&["\u{000D}", "\u{0308}", "\u{AC01}"],
 		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL SYLLABLE GAG (LVT) ÷ [0.3]"
 	);
+	grapheme_test("\u{000D}\u{0903}",
+		&["\u{000D}", "\u{0903}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{000D}\u{0308}\u{0903}",
+		&["\u{000D}", "\u{0308}\u{0903}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{000D}\u{0904}",
+		&["\u{000D}", "\u{0904}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{000D}\u{0308}\u{0904}",
+		&["\u{000D}", "\u{0308}", "\u{0904}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{000D}\u{0D4E}",
+		&["\u{000D}", "\u{0D4E}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{000D}\u{0308}\u{0D4E}",
+		&["\u{000D}", "\u{0308}", "\u{0D4E}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{000D}\u{0915}",
+		&["\u{000D}", "\u{0915}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
+	grapheme_test("\u{000D}\u{0308}\u{0915}",
+		&["\u{000D}", "\u{0308}", "\u{0915}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
 	grapheme_test("\u{000D}\u{231A}",
 		&["\u{000D}", "\u{231A}"],
 		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] WATCH (ExtPict) ÷ [0.3]"
@@ -260,6 +340,22 @@ fn standard_grapheme_test() {
 		&["\u{000D}", "\u{0308}\u{0300}"],
 		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) ÷ [0.3]"
 	);
+	grapheme_test("\u{000D}\u{0900}",
+		&["\u{000D}", "\u{0900}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{000D}\u{0308}\u{0900}",
+		&["\u{000D}", "\u{0308}\u{0900}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{000D}\u{094D}",
+		&["\u{000D}", "\u{094D}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{000D}\u{0308}\u{094D}",
+		&["\u{000D}", "\u{0308}\u{094D}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
 	grapheme_test("\u{000D}\u{200D}",
 		&["\u{000D}", "\u{200D}"],
 		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [0.3]"
@@ -308,13 +404,13 @@ fn standard_grapheme_test() {
 		&["\u{000A}", "\u{0308}", "\u{0001}"],
 		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <START OF HEADING> (Control) ÷ [0.3]"
 	);
-	grapheme_test("\u{000A}\u{034F}",
-		&["\u{000A}", "\u{034F}"],
-		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] COMBINING GRAPHEME JOINER (Extend) ÷ [0.3]"
+	grapheme_test("\u{000A}\u{200C}",
+		&["\u{000A}", "\u{200C}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
 	);
-	grapheme_test("\u{000A}\u{0308}\u{034F}",
-		&["\u{000A}", "\u{0308}\u{034F}"],
-		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAPHEME JOINER (Extend) ÷ [0.3]"
+	grapheme_test("\u{000A}\u{0308}\u{200C}",
+		&["\u{000A}", "\u{0308}\u{200C}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
 	);
 	grapheme_test("\u{000A}\u{1F1E6}",
 		&["\u{000A}", "\u{1F1E6}"],
@@ -332,13 +428,13 @@ fn standard_grapheme_test() {
 		&["\u{000A}", "\u{0308}", "\u{0600}"],
 		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] ARABIC NUMBER SIGN (Prepend) ÷ [0.3]"
 	);
-	grapheme_test("\u{000A}\u{0903}",
-		&["\u{000A}", "\u{0903}"],
-		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	grapheme_test("\u{000A}\u{0A03}",
+		&["\u{000A}", "\u{0A03}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
 	);
-	grapheme_test("\u{000A}\u{0308}\u{0903}",
-		&["\u{000A}", "\u{0308}\u{0903}"],
-		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	grapheme_test("\u{000A}\u{0308}\u{0A03}",
+		&["\u{000A}", "\u{0308}\u{0A03}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
 	);
 	grapheme_test("\u{000A}\u{1100}",
 		&["\u{000A}", "\u{1100}"],
@@ -380,6 +476,38 @@ fn standard_grapheme_test() {
 		&["\u{000A}", "\u{0308}", "\u{AC01}"],
 		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL SYLLABLE GAG (LVT) ÷ [0.3]"
 	);
+	grapheme_test("\u{000A}\u{0903}",
+		&["\u{000A}", "\u{0903}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{000A}\u{0308}\u{0903}",
+		&["\u{000A}", "\u{0308}\u{0903}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{000A}\u{0904}",
+		&["\u{000A}", "\u{0904}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{000A}\u{0308}\u{0904}",
+		&["\u{000A}", "\u{0308}", "\u{0904}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{000A}\u{0D4E}",
+		&["\u{000A}", "\u{0D4E}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{000A}\u{0308}\u{0D4E}",
+		&["\u{000A}", "\u{0308}", "\u{0D4E}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{000A}\u{0915}",
+		&["\u{000A}", "\u{0915}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
+	grapheme_test("\u{000A}\u{0308}\u{0915}",
+		&["\u{000A}", "\u{0308}", "\u{0915}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
 	grapheme_test("\u{000A}\u{231A}",
 		&["\u{000A}", "\u{231A}"],
 		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] WATCH (ExtPict) ÷ [0.3]"
@@ -396,6 +524,22 @@ fn standard_grapheme_test() {
 		&["\u{000A}", "\u{0308}\u{0300}"],
 		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) ÷ [0.3]"
 	);
+	grapheme_test("\u{000A}\u{0900}",
+		&["\u{000A}", "\u{0900}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{000A}\u{0308}\u{0900}",
+		&["\u{000A}", "\u{0308}\u{0900}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{000A}\u{094D}",
+		&["\u{000A}", "\u{094D}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{000A}\u{0308}\u{094D}",
+		&["\u{000A}", "\u{0308}\u{094D}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
 	grapheme_test("\u{000A}\u{200D}",
 		&["\u{000A}", "\u{200D}"],
 		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [0.3]"
@@ -444,13 +588,13 @@ fn standard_grapheme_test() {
 		&["\u{0001}", "\u{0308}", "\u{0001}"],
 		"  ÷ [0.2] <START OF HEADING> (Control) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <START OF HEADING> (Control) ÷ [0.3]"
 	);
-	grapheme_test("\u{0001}\u{034F}",
-		&["\u{0001}", "\u{034F}"],
-		"  ÷ [0.2] <START OF HEADING> (Control) ÷ [4.0] COMBINING GRAPHEME JOINER (Extend) ÷ [0.3]"
+	grapheme_test("\u{0001}\u{200C}",
+		&["\u{0001}", "\u{200C}"],
+		"  ÷ [0.2] <START OF HEADING> (Control) ÷ [4.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
 	);
-	grapheme_test("\u{0001}\u{0308}\u{034F}",
-		&["\u{0001}", "\u{0308}\u{034F}"],
-		"  ÷ [0.2] <START OF HEADING> (Control) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAPHEME JOINER (Extend) ÷ [0.3]"
+	grapheme_test("\u{0001}\u{0308}\u{200C}",
+		&["\u{0001}", "\u{0308}\u{200C}"],
+		"  ÷ [0.2] <START OF HEADING> (Control) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
 	);
 	grapheme_test("\u{0001}\u{1F1E6}",
 		&["\u{0001}", "\u{1F1E6}"],
@@ -468,13 +612,13 @@ fn standard_grapheme_test() {
 		&["\u{0001}", "\u{0308}", "\u{0600}"],
 		"  ÷ [0.2] <START OF HEADING> (Control) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] ARABIC NUMBER SIGN (Prepend) ÷ [0.3]"
 	);
-	grapheme_test("\u{0001}\u{0903}",
-		&["\u{0001}", "\u{0903}"],
-		"  ÷ [0.2] <START OF HEADING> (Control) ÷ [4.0] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	grapheme_test("\u{0001}\u{0A03}",
+		&["\u{0001}", "\u{0A03}"],
+		"  ÷ [0.2] <START OF HEADING> (Control) ÷ [4.0] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
 	);
-	grapheme_test("\u{0001}\u{0308}\u{0903}",
-		&["\u{0001}", "\u{0308}\u{0903}"],
-		"  ÷ [0.2] <START OF HEADING> (Control) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	grapheme_test("\u{0001}\u{0308}\u{0A03}",
+		&["\u{0001}", "\u{0308}\u{0A03}"],
+		"  ÷ [0.2] <START OF HEADING> (Control) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
 	);
 	grapheme_test("\u{0001}\u{1100}",
 		&["\u{0001}", "\u{1100}"],
@@ -516,6 +660,38 @@ fn standard_grapheme_test() {
 		&["\u{0001}", "\u{0308}", "\u{AC01}"],
 		"  ÷ [0.2] <START OF HEADING> (Control) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL SYLLABLE GAG (LVT) ÷ [0.3]"
 	);
+	grapheme_test("\u{0001}\u{0903}",
+		&["\u{0001}", "\u{0903}"],
+		"  ÷ [0.2] <START OF HEADING> (Control) ÷ [4.0] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0001}\u{0308}\u{0903}",
+		&["\u{0001}", "\u{0308}\u{0903}"],
+		"  ÷ [0.2] <START OF HEADING> (Control) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0001}\u{0904}",
+		&["\u{0001}", "\u{0904}"],
+		"  ÷ [0.2] <START OF HEADING> (Control) ÷ [4.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0001}\u{0308}\u{0904}",
+		&["\u{0001}", "\u{0308}", "\u{0904}"],
+		"  ÷ [0.2] <START OF HEADING> (Control) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0001}\u{0D4E}",
+		&["\u{0001}", "\u{0D4E}"],
+		"  ÷ [0.2] <START OF HEADING> (Control) ÷ [4.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0001}\u{0308}\u{0D4E}",
+		&["\u{0001}", "\u{0308}", "\u{0D4E}"],
+		"  ÷ [0.2] <START OF HEADING> (Control) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0001}\u{0915}",
+		&["\u{0001}", "\u{0915}"],
+		"  ÷ [0.2] <START OF HEADING> (Control) ÷ [4.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
+	grapheme_test("\u{0001}\u{0308}\u{0915}",
+		&["\u{0001}", "\u{0308}", "\u{0915}"],
+		"  ÷ [0.2] <START OF HEADING> (Control) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
 	grapheme_test("\u{0001}\u{231A}",
 		&["\u{0001}", "\u{231A}"],
 		"  ÷ [0.2] <START OF HEADING> (Control) ÷ [4.0] WATCH (ExtPict) ÷ [0.3]"
@@ -532,6 +708,22 @@ fn standard_grapheme_test() {
 		&["\u{0001}", "\u{0308}\u{0300}"],
 		"  ÷ [0.2] <START OF HEADING> (Control) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) ÷ [0.3]"
 	);
+	grapheme_test("\u{0001}\u{0900}",
+		&["\u{0001}", "\u{0900}"],
+		"  ÷ [0.2] <START OF HEADING> (Control) ÷ [4.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0001}\u{0308}\u{0900}",
+		&["\u{0001}", "\u{0308}\u{0900}"],
+		"  ÷ [0.2] <START OF HEADING> (Control) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0001}\u{094D}",
+		&["\u{0001}", "\u{094D}"],
+		"  ÷ [0.2] <START OF HEADING> (Control) ÷ [4.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0001}\u{0308}\u{094D}",
+		&["\u{0001}", "\u{0308}\u{094D}"],
+		"  ÷ [0.2] <START OF HEADING> (Control) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
 	grapheme_test("\u{0001}\u{200D}",
 		&["\u{0001}", "\u{200D}"],
 		"  ÷ [0.2] <START OF HEADING> (Control) ÷ [4.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [0.3]"
@@ -548,141 +740,189 @@ fn standard_grapheme_test() {
 		&["\u{0001}", "\u{0308}", "\u{0378}"],
 		"  ÷ [0.2] <START OF HEADING> (Control) ÷ [4.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] <reserved-0378> (Other) ÷ [0.3]"
 	);
-	grapheme_test("\u{034F}\u{0020}",
-		&["\u{034F}", "\u{0020}"],
-		"  ÷ [0.2] COMBINING GRAPHEME JOINER (Extend) ÷ [999.0] SPACE (Other) ÷ [0.3]"
+	grapheme_test("\u{200C}\u{0020}",
+		&["\u{200C}", "\u{0020}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) ÷ [999.0] SPACE (Other) ÷ [0.3]"
 	);
-	grapheme_test("\u{034F}\u{0308}\u{0020}",
-		&["\u{034F}\u{0308}", "\u{0020}"],
-		"  ÷ [0.2] COMBINING GRAPHEME JOINER (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] SPACE (Other) ÷ [0.3]"
+	grapheme_test("\u{200C}\u{0308}\u{0020}",
+		&["\u{200C}\u{0308}", "\u{0020}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] SPACE (Other) ÷ [0.3]"
 	);
-	grapheme_test("\u{034F}\u{000D}",
-		&["\u{034F}", "\u{000D}"],
-		"  ÷ [0.2] COMBINING GRAPHEME JOINER (Extend) ÷ [5.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	grapheme_test("\u{200C}\u{000D}",
+		&["\u{200C}", "\u{000D}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) ÷ [5.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
 	);
-	grapheme_test("\u{034F}\u{0308}\u{000D}",
-		&["\u{034F}\u{0308}", "\u{000D}"],
-		"  ÷ [0.2] COMBINING GRAPHEME JOINER (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	grapheme_test("\u{200C}\u{0308}\u{000D}",
+		&["\u{200C}\u{0308}", "\u{000D}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
 	);
-	grapheme_test("\u{034F}\u{000A}",
-		&["\u{034F}", "\u{000A}"],
-		"  ÷ [0.2] COMBINING GRAPHEME JOINER (Extend) ÷ [5.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	grapheme_test("\u{200C}\u{000A}",
+		&["\u{200C}", "\u{000A}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) ÷ [5.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
 	);
-	grapheme_test("\u{034F}\u{0308}\u{000A}",
-		&["\u{034F}\u{0308}", "\u{000A}"],
-		"  ÷ [0.2] COMBINING GRAPHEME JOINER (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	grapheme_test("\u{200C}\u{0308}\u{000A}",
+		&["\u{200C}\u{0308}", "\u{000A}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
 	);
-	grapheme_test("\u{034F}\u{0001}",
-		&["\u{034F}", "\u{0001}"],
-		"  ÷ [0.2] COMBINING GRAPHEME JOINER (Extend) ÷ [5.0] <START OF HEADING> (Control) ÷ [0.3]"
+	grapheme_test("\u{200C}\u{0001}",
+		&["\u{200C}", "\u{0001}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) ÷ [5.0] <START OF HEADING> (Control) ÷ [0.3]"
 	);
-	grapheme_test("\u{034F}\u{0308}\u{0001}",
-		&["\u{034F}\u{0308}", "\u{0001}"],
-		"  ÷ [0.2] COMBINING GRAPHEME JOINER (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <START OF HEADING> (Control) ÷ [0.3]"
+	grapheme_test("\u{200C}\u{0308}\u{0001}",
+		&["\u{200C}\u{0308}", "\u{0001}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <START OF HEADING> (Control) ÷ [0.3]"
 	);
-	grapheme_test("\u{034F}\u{034F}",
-		&["\u{034F}\u{034F}"],
-		"  ÷ [0.2] COMBINING GRAPHEME JOINER (Extend) × [9.0] COMBINING GRAPHEME JOINER (Extend) ÷ [0.3]"
+	grapheme_test("\u{200C}\u{200C}",
+		&["\u{200C}\u{200C}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
 	);
-	grapheme_test("\u{034F}\u{0308}\u{034F}",
-		&["\u{034F}\u{0308}\u{034F}"],
-		"  ÷ [0.2] COMBINING GRAPHEME JOINER (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAPHEME JOINER (Extend) ÷ [0.3]"
+	grapheme_test("\u{200C}\u{0308}\u{200C}",
+		&["\u{200C}\u{0308}\u{200C}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
 	);
-	grapheme_test("\u{034F}\u{1F1E6}",
-		&["\u{034F}", "\u{1F1E6}"],
-		"  ÷ [0.2] COMBINING GRAPHEME JOINER (Extend) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	grapheme_test("\u{200C}\u{1F1E6}",
+		&["\u{200C}", "\u{1F1E6}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
 	);
-	grapheme_test("\u{034F}\u{0308}\u{1F1E6}",
-		&["\u{034F}\u{0308}", "\u{1F1E6}"],
-		"  ÷ [0.2] COMBINING GRAPHEME JOINER (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	grapheme_test("\u{200C}\u{0308}\u{1F1E6}",
+		&["\u{200C}\u{0308}", "\u{1F1E6}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
 	);
-	grapheme_test("\u{034F}\u{0600}",
-		&["\u{034F}", "\u{0600}"],
-		"  ÷ [0.2] COMBINING GRAPHEME JOINER (Extend) ÷ [999.0] ARABIC NUMBER SIGN (Prepend) ÷ [0.3]"
+	grapheme_test("\u{200C}\u{0600}",
+		&["\u{200C}", "\u{0600}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) ÷ [999.0] ARABIC NUMBER SIGN (Prepend) ÷ [0.3]"
 	);
-	grapheme_test("\u{034F}\u{0308}\u{0600}",
-		&["\u{034F}\u{0308}", "\u{0600}"],
-		"  ÷ [0.2] COMBINING GRAPHEME JOINER (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] ARABIC NUMBER SIGN (Prepend) ÷ [0.3]"
+	grapheme_test("\u{200C}\u{0308}\u{0600}",
+		&["\u{200C}\u{0308}", "\u{0600}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] ARABIC NUMBER SIGN (Prepend) ÷ [0.3]"
 	);
-	grapheme_test("\u{034F}\u{0903}",
-		&["\u{034F}\u{0903}"],
-		"  ÷ [0.2] COMBINING GRAPHEME JOINER (Extend) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	grapheme_test("\u{200C}\u{0A03}",
+		&["\u{200C}\u{0A03}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
 	);
-	grapheme_test("\u{034F}\u{0308}\u{0903}",
-		&["\u{034F}\u{0308}\u{0903}"],
-		"  ÷ [0.2] COMBINING GRAPHEME JOINER (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	grapheme_test("\u{200C}\u{0308}\u{0A03}",
+		&["\u{200C}\u{0308}\u{0A03}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
 	);
-	grapheme_test("\u{034F}\u{1100}",
-		&["\u{034F}", "\u{1100}"],
-		"  ÷ [0.2] COMBINING GRAPHEME JOINER (Extend) ÷ [999.0] HANGUL CHOSEONG KIYEOK (L) ÷ [0.3]"
+	grapheme_test("\u{200C}\u{1100}",
+		&["\u{200C}", "\u{1100}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) ÷ [999.0] HANGUL CHOSEONG KIYEOK (L) ÷ [0.3]"
 	);
-	grapheme_test("\u{034F}\u{0308}\u{1100}",
-		&["\u{034F}\u{0308}", "\u{1100}"],
-		"  ÷ [0.2] COMBINING GRAPHEME JOINER (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL CHOSEONG KIYEOK (L) ÷ [0.3]"
+	grapheme_test("\u{200C}\u{0308}\u{1100}",
+		&["\u{200C}\u{0308}", "\u{1100}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL CHOSEONG KIYEOK (L) ÷ [0.3]"
 	);
-	grapheme_test("\u{034F}\u{1160}",
-		&["\u{034F}", "\u{1160}"],
-		"  ÷ [0.2] COMBINING GRAPHEME JOINER (Extend) ÷ [999.0] HANGUL JUNGSEONG FILLER (V) ÷ [0.3]"
+	grapheme_test("\u{200C}\u{1160}",
+		&["\u{200C}", "\u{1160}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) ÷ [999.0] HANGUL JUNGSEONG FILLER (V) ÷ [0.3]"
 	);
-	grapheme_test("\u{034F}\u{0308}\u{1160}",
-		&["\u{034F}\u{0308}", "\u{1160}"],
-		"  ÷ [0.2] COMBINING GRAPHEME JOINER (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL JUNGSEONG FILLER (V) ÷ [0.3]"
+	grapheme_test("\u{200C}\u{0308}\u{1160}",
+		&["\u{200C}\u{0308}", "\u{1160}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL JUNGSEONG FILLER (V) ÷ [0.3]"
 	);
-	grapheme_test("\u{034F}\u{11A8}",
-		&["\u{034F}", "\u{11A8}"],
-		"  ÷ [0.2] COMBINING GRAPHEME JOINER (Extend) ÷ [999.0] HANGUL JONGSEONG KIYEOK (T) ÷ [0.3]"
+	grapheme_test("\u{200C}\u{11A8}",
+		&["\u{200C}", "\u{11A8}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) ÷ [999.0] HANGUL JONGSEONG KIYEOK (T) ÷ [0.3]"
 	);
-	grapheme_test("\u{034F}\u{0308}\u{11A8}",
-		&["\u{034F}\u{0308}", "\u{11A8}"],
-		"  ÷ [0.2] COMBINING GRAPHEME JOINER (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL JONGSEONG KIYEOK (T) ÷ [0.3]"
+	grapheme_test("\u{200C}\u{0308}\u{11A8}",
+		&["\u{200C}\u{0308}", "\u{11A8}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL JONGSEONG KIYEOK (T) ÷ [0.3]"
 	);
-	grapheme_test("\u{034F}\u{AC00}",
-		&["\u{034F}", "\u{AC00}"],
-		"  ÷ [0.2] COMBINING GRAPHEME JOINER (Extend) ÷ [999.0] HANGUL SYLLABLE GA (LV) ÷ [0.3]"
+	grapheme_test("\u{200C}\u{AC00}",
+		&["\u{200C}", "\u{AC00}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) ÷ [999.0] HANGUL SYLLABLE GA (LV) ÷ [0.3]"
 	);
-	grapheme_test("\u{034F}\u{0308}\u{AC00}",
-		&["\u{034F}\u{0308}", "\u{AC00}"],
-		"  ÷ [0.2] COMBINING GRAPHEME JOINER (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL SYLLABLE GA (LV) ÷ [0.3]"
+	grapheme_test("\u{200C}\u{0308}\u{AC00}",
+		&["\u{200C}\u{0308}", "\u{AC00}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL SYLLABLE GA (LV) ÷ [0.3]"
 	);
-	grapheme_test("\u{034F}\u{AC01}",
-		&["\u{034F}", "\u{AC01}"],
-		"  ÷ [0.2] COMBINING GRAPHEME JOINER (Extend) ÷ [999.0] HANGUL SYLLABLE GAG (LVT) ÷ [0.3]"
+	grapheme_test("\u{200C}\u{AC01}",
+		&["\u{200C}", "\u{AC01}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) ÷ [999.0] HANGUL SYLLABLE GAG (LVT) ÷ [0.3]"
 	);
-	grapheme_test("\u{034F}\u{0308}\u{AC01}",
-		&["\u{034F}\u{0308}", "\u{AC01}"],
-		"  ÷ [0.2] COMBINING GRAPHEME JOINER (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL SYLLABLE GAG (LVT) ÷ [0.3]"
+	grapheme_test("\u{200C}\u{0308}\u{AC01}",
+		&["\u{200C}\u{0308}", "\u{AC01}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL SYLLABLE GAG (LVT) ÷ [0.3]"
 	);
-	grapheme_test("\u{034F}\u{231A}",
-		&["\u{034F}", "\u{231A}"],
-		"  ÷ [0.2] COMBINING GRAPHEME JOINER (Extend) ÷ [999.0] WATCH (ExtPict) ÷ [0.3]"
+	grapheme_test("\u{200C}\u{0903}",
+		&["\u{200C}\u{0903}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
 	);
-	grapheme_test("\u{034F}\u{0308}\u{231A}",
-		&["\u{034F}\u{0308}", "\u{231A}"],
-		"  ÷ [0.2] COMBINING GRAPHEME JOINER (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] WATCH (ExtPict) ÷ [0.3]"
+	grapheme_test("\u{200C}\u{0308}\u{0903}",
+		&["\u{200C}\u{0308}\u{0903}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
 	);
-	grapheme_test("\u{034F}\u{0300}",
-		&["\u{034F}\u{0300}"],
-		"  ÷ [0.2] COMBINING GRAPHEME JOINER (Extend) × [9.0] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) ÷ [0.3]"
+	grapheme_test("\u{200C}\u{0904}",
+		&["\u{200C}", "\u{0904}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
 	);
-	grapheme_test("\u{034F}\u{0308}\u{0300}",
-		&["\u{034F}\u{0308}\u{0300}"],
-		"  ÷ [0.2] COMBINING GRAPHEME JOINER (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) ÷ [0.3]"
+	grapheme_test("\u{200C}\u{0308}\u{0904}",
+		&["\u{200C}\u{0308}", "\u{0904}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
 	);
-	grapheme_test("\u{034F}\u{200D}",
-		&["\u{034F}\u{200D}"],
-		"  ÷ [0.2] COMBINING GRAPHEME JOINER (Extend) × [9.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [0.3]"
+	grapheme_test("\u{200C}\u{0D4E}",
+		&["\u{200C}", "\u{0D4E}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
 	);
-	grapheme_test("\u{034F}\u{0308}\u{200D}",
-		&["\u{034F}\u{0308}\u{200D}"],
-		"  ÷ [0.2] COMBINING GRAPHEME JOINER (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [0.3]"
+	grapheme_test("\u{200C}\u{0308}\u{0D4E}",
+		&["\u{200C}\u{0308}", "\u{0D4E}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
 	);
-	grapheme_test("\u{034F}\u{0378}",
-		&["\u{034F}", "\u{0378}"],
-		"  ÷ [0.2] COMBINING GRAPHEME JOINER (Extend) ÷ [999.0] <reserved-0378> (Other) ÷ [0.3]"
+	grapheme_test("\u{200C}\u{0915}",
+		&["\u{200C}", "\u{0915}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
 	);
-	grapheme_test("\u{034F}\u{0308}\u{0378}",
-		&["\u{034F}\u{0308}", "\u{0378}"],
-		"  ÷ [0.2] COMBINING GRAPHEME JOINER (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] <reserved-0378> (Other) ÷ [0.3]"
+	grapheme_test("\u{200C}\u{0308}\u{0915}",
+		&["\u{200C}\u{0308}", "\u{0915}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
+	grapheme_test("\u{200C}\u{231A}",
+		&["\u{200C}", "\u{231A}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) ÷ [999.0] WATCH (ExtPict) ÷ [0.3]"
+	);
+	grapheme_test("\u{200C}\u{0308}\u{231A}",
+		&["\u{200C}\u{0308}", "\u{231A}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] WATCH (ExtPict) ÷ [0.3]"
+	);
+	grapheme_test("\u{200C}\u{0300}",
+		&["\u{200C}\u{0300}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) × [9.0] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{200C}\u{0308}\u{0300}",
+		&["\u{200C}\u{0308}\u{0300}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{200C}\u{0900}",
+		&["\u{200C}\u{0900}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{200C}\u{0308}\u{0900}",
+		&["\u{200C}\u{0308}\u{0900}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{200C}\u{094D}",
+		&["\u{200C}\u{094D}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{200C}\u{0308}\u{094D}",
+		&["\u{200C}\u{0308}\u{094D}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{200C}\u{200D}",
+		&["\u{200C}\u{200D}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) × [9.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{200C}\u{0308}\u{200D}",
+		&["\u{200C}\u{0308}\u{200D}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{200C}\u{0378}",
+		&["\u{200C}", "\u{0378}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) ÷ [999.0] <reserved-0378> (Other) ÷ [0.3]"
+	);
+	grapheme_test("\u{200C}\u{0308}\u{0378}",
+		&["\u{200C}\u{0308}", "\u{0378}"],
+		"  ÷ [0.2] ZERO WIDTH NON-JOINER (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] <reserved-0378> (Other) ÷ [0.3]"
 	);
 	grapheme_test("\u{1F1E6}\u{0020}",
 		&["\u{1F1E6}", "\u{0020}"],
@@ -716,13 +956,13 @@ fn standard_grapheme_test() {
 		&["\u{1F1E6}\u{0308}", "\u{0001}"],
 		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <START OF HEADING> (Control) ÷ [0.3]"
 	);
-	grapheme_test("\u{1F1E6}\u{034F}",
-		&["\u{1F1E6}\u{034F}"],
-		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [9.0] COMBINING GRAPHEME JOINER (Extend) ÷ [0.3]"
+	grapheme_test("\u{1F1E6}\u{200C}",
+		&["\u{1F1E6}\u{200C}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
 	);
-	grapheme_test("\u{1F1E6}\u{0308}\u{034F}",
-		&["\u{1F1E6}\u{0308}\u{034F}"],
-		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAPHEME JOINER (Extend) ÷ [0.3]"
+	grapheme_test("\u{1F1E6}\u{0308}\u{200C}",
+		&["\u{1F1E6}\u{0308}\u{200C}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
 	);
 	grapheme_test("\u{1F1E6}\u{1F1E6}",
 		&["\u{1F1E6}\u{1F1E6}"],
@@ -740,13 +980,13 @@ fn standard_grapheme_test() {
 		&["\u{1F1E6}\u{0308}", "\u{0600}"],
 		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] ARABIC NUMBER SIGN (Prepend) ÷ [0.3]"
 	);
-	grapheme_test("\u{1F1E6}\u{0903}",
-		&["\u{1F1E6}\u{0903}"],
-		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	grapheme_test("\u{1F1E6}\u{0A03}",
+		&["\u{1F1E6}\u{0A03}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
 	);
-	grapheme_test("\u{1F1E6}\u{0308}\u{0903}",
-		&["\u{1F1E6}\u{0308}\u{0903}"],
-		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	grapheme_test("\u{1F1E6}\u{0308}\u{0A03}",
+		&["\u{1F1E6}\u{0308}\u{0A03}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
 	);
 	grapheme_test("\u{1F1E6}\u{1100}",
 		&["\u{1F1E6}", "\u{1100}"],
@@ -788,6 +1028,38 @@ fn standard_grapheme_test() {
 		&["\u{1F1E6}\u{0308}", "\u{AC01}"],
 		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL SYLLABLE GAG (LVT) ÷ [0.3]"
 	);
+	grapheme_test("\u{1F1E6}\u{0903}",
+		&["\u{1F1E6}\u{0903}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{1F1E6}\u{0308}\u{0903}",
+		&["\u{1F1E6}\u{0308}\u{0903}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{1F1E6}\u{0904}",
+		&["\u{1F1E6}", "\u{0904}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{1F1E6}\u{0308}\u{0904}",
+		&["\u{1F1E6}\u{0308}", "\u{0904}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{1F1E6}\u{0D4E}",
+		&["\u{1F1E6}", "\u{0D4E}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{1F1E6}\u{0308}\u{0D4E}",
+		&["\u{1F1E6}\u{0308}", "\u{0D4E}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{1F1E6}\u{0915}",
+		&["\u{1F1E6}", "\u{0915}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
+	grapheme_test("\u{1F1E6}\u{0308}\u{0915}",
+		&["\u{1F1E6}\u{0308}", "\u{0915}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
 	grapheme_test("\u{1F1E6}\u{231A}",
 		&["\u{1F1E6}", "\u{231A}"],
 		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [999.0] WATCH (ExtPict) ÷ [0.3]"
@@ -804,6 +1076,22 @@ fn standard_grapheme_test() {
 		&["\u{1F1E6}\u{0308}\u{0300}"],
 		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) ÷ [0.3]"
 	);
+	grapheme_test("\u{1F1E6}\u{0900}",
+		&["\u{1F1E6}\u{0900}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{1F1E6}\u{0308}\u{0900}",
+		&["\u{1F1E6}\u{0308}\u{0900}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{1F1E6}\u{094D}",
+		&["\u{1F1E6}\u{094D}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{1F1E6}\u{0308}\u{094D}",
+		&["\u{1F1E6}\u{0308}\u{094D}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
 	grapheme_test("\u{1F1E6}\u{200D}",
 		&["\u{1F1E6}\u{200D}"],
 		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [9.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [0.3]"
@@ -852,13 +1140,13 @@ fn standard_grapheme_test() {
 		&["\u{0600}\u{0308}", "\u{0001}"],
 		"  ÷ [0.2] ARABIC NUMBER SIGN (Prepend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <START OF HEADING> (Control) ÷ [0.3]"
 	);
-	grapheme_test("\u{0600}\u{034F}",
-		&["\u{0600}\u{034F}"],
-		"  ÷ [0.2] ARABIC NUMBER SIGN (Prepend) × [9.0] COMBINING GRAPHEME JOINER (Extend) ÷ [0.3]"
+	grapheme_test("\u{0600}\u{200C}",
+		&["\u{0600}\u{200C}"],
+		"  ÷ [0.2] ARABIC NUMBER SIGN (Prepend) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
 	);
-	grapheme_test("\u{0600}\u{0308}\u{034F}",
-		&["\u{0600}\u{0308}\u{034F}"],
-		"  ÷ [0.2] ARABIC NUMBER SIGN (Prepend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAPHEME JOINER (Extend) ÷ [0.3]"
+	grapheme_test("\u{0600}\u{0308}\u{200C}",
+		&["\u{0600}\u{0308}\u{200C}"],
+		"  ÷ [0.2] ARABIC NUMBER SIGN (Prepend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
 	);
 	grapheme_test("\u{0600}\u{1F1E6}",
 		&["\u{0600}\u{1F1E6}"],
@@ -876,13 +1164,13 @@ fn standard_grapheme_test() {
 		&["\u{0600}\u{0308}", "\u{0600}"],
 		"  ÷ [0.2] ARABIC NUMBER SIGN (Prepend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] ARABIC NUMBER SIGN (Prepend) ÷ [0.3]"
 	);
-	grapheme_test("\u{0600}\u{0903}",
-		&["\u{0600}\u{0903}"],
-		"  ÷ [0.2] ARABIC NUMBER SIGN (Prepend) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	grapheme_test("\u{0600}\u{0A03}",
+		&["\u{0600}\u{0A03}"],
+		"  ÷ [0.2] ARABIC NUMBER SIGN (Prepend) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
 	);
-	grapheme_test("\u{0600}\u{0308}\u{0903}",
-		&["\u{0600}\u{0308}\u{0903}"],
-		"  ÷ [0.2] ARABIC NUMBER SIGN (Prepend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	grapheme_test("\u{0600}\u{0308}\u{0A03}",
+		&["\u{0600}\u{0308}\u{0A03}"],
+		"  ÷ [0.2] ARABIC NUMBER SIGN (Prepend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
 	);
 	grapheme_test("\u{0600}\u{1100}",
 		&["\u{0600}\u{1100}"],
@@ -924,6 +1212,38 @@ fn standard_grapheme_test() {
 		&["\u{0600}\u{0308}", "\u{AC01}"],
 		"  ÷ [0.2] ARABIC NUMBER SIGN (Prepend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL SYLLABLE GAG (LVT) ÷ [0.3]"
 	);
+	grapheme_test("\u{0600}\u{0903}",
+		&["\u{0600}\u{0903}"],
+		"  ÷ [0.2] ARABIC NUMBER SIGN (Prepend) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0600}\u{0308}\u{0903}",
+		&["\u{0600}\u{0308}\u{0903}"],
+		"  ÷ [0.2] ARABIC NUMBER SIGN (Prepend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0600}\u{0904}",
+		&["\u{0600}\u{0904}"],
+		"  ÷ [0.2] ARABIC NUMBER SIGN (Prepend) × [9.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0600}\u{0308}\u{0904}",
+		&["\u{0600}\u{0308}", "\u{0904}"],
+		"  ÷ [0.2] ARABIC NUMBER SIGN (Prepend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0600}\u{0D4E}",
+		&["\u{0600}\u{0D4E}"],
+		"  ÷ [0.2] ARABIC NUMBER SIGN (Prepend) × [9.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0600}\u{0308}\u{0D4E}",
+		&["\u{0600}\u{0308}", "\u{0D4E}"],
+		"  ÷ [0.2] ARABIC NUMBER SIGN (Prepend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0600}\u{0915}",
+		&["\u{0600}\u{0915}"],
+		"  ÷ [0.2] ARABIC NUMBER SIGN (Prepend) × [9.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
+	grapheme_test("\u{0600}\u{0308}\u{0915}",
+		&["\u{0600}\u{0308}", "\u{0915}"],
+		"  ÷ [0.2] ARABIC NUMBER SIGN (Prepend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
 	grapheme_test("\u{0600}\u{231A}",
 		&["\u{0600}\u{231A}"],
 		"  ÷ [0.2] ARABIC NUMBER SIGN (Prepend) × [9.2] WATCH (ExtPict) ÷ [0.3]"
@@ -940,6 +1260,22 @@ fn standard_grapheme_test() {
 		&["\u{0600}\u{0308}\u{0300}"],
 		"  ÷ [0.2] ARABIC NUMBER SIGN (Prepend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) ÷ [0.3]"
 	);
+	grapheme_test("\u{0600}\u{0900}",
+		&["\u{0600}\u{0900}"],
+		"  ÷ [0.2] ARABIC NUMBER SIGN (Prepend) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0600}\u{0308}\u{0900}",
+		&["\u{0600}\u{0308}\u{0900}"],
+		"  ÷ [0.2] ARABIC NUMBER SIGN (Prepend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0600}\u{094D}",
+		&["\u{0600}\u{094D}"],
+		"  ÷ [0.2] ARABIC NUMBER SIGN (Prepend) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0600}\u{0308}\u{094D}",
+		&["\u{0600}\u{0308}\u{094D}"],
+		"  ÷ [0.2] ARABIC NUMBER SIGN (Prepend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
 	grapheme_test("\u{0600}\u{200D}",
 		&["\u{0600}\u{200D}"],
 		"  ÷ [0.2] ARABIC NUMBER SIGN (Prepend) × [9.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [0.3]"
@@ -956,141 +1292,189 @@ fn standard_grapheme_test() {
 		&["\u{0600}\u{0308}", "\u{0378}"],
 		"  ÷ [0.2] ARABIC NUMBER SIGN (Prepend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] <reserved-0378> (Other) ÷ [0.3]"
 	);
-	grapheme_test("\u{0903}\u{0020}",
-		&["\u{0903}", "\u{0020}"],
-		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [999.0] SPACE (Other) ÷ [0.3]"
+	grapheme_test("\u{0A03}\u{0020}",
+		&["\u{0A03}", "\u{0020}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [999.0] SPACE (Other) ÷ [0.3]"
 	);
-	grapheme_test("\u{0903}\u{0308}\u{0020}",
-		&["\u{0903}\u{0308}", "\u{0020}"],
-		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] SPACE (Other) ÷ [0.3]"
+	grapheme_test("\u{0A03}\u{0308}\u{0020}",
+		&["\u{0A03}\u{0308}", "\u{0020}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] SPACE (Other) ÷ [0.3]"
 	);
-	grapheme_test("\u{0903}\u{000D}",
-		&["\u{0903}", "\u{000D}"],
-		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [5.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	grapheme_test("\u{0A03}\u{000D}",
+		&["\u{0A03}", "\u{000D}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [5.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
 	);
-	grapheme_test("\u{0903}\u{0308}\u{000D}",
-		&["\u{0903}\u{0308}", "\u{000D}"],
-		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	grapheme_test("\u{0A03}\u{0308}\u{000D}",
+		&["\u{0A03}\u{0308}", "\u{000D}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
 	);
-	grapheme_test("\u{0903}\u{000A}",
-		&["\u{0903}", "\u{000A}"],
-		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [5.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	grapheme_test("\u{0A03}\u{000A}",
+		&["\u{0A03}", "\u{000A}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [5.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
 	);
-	grapheme_test("\u{0903}\u{0308}\u{000A}",
-		&["\u{0903}\u{0308}", "\u{000A}"],
-		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	grapheme_test("\u{0A03}\u{0308}\u{000A}",
+		&["\u{0A03}\u{0308}", "\u{000A}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
 	);
-	grapheme_test("\u{0903}\u{0001}",
-		&["\u{0903}", "\u{0001}"],
-		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [5.0] <START OF HEADING> (Control) ÷ [0.3]"
+	grapheme_test("\u{0A03}\u{0001}",
+		&["\u{0A03}", "\u{0001}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [5.0] <START OF HEADING> (Control) ÷ [0.3]"
 	);
-	grapheme_test("\u{0903}\u{0308}\u{0001}",
-		&["\u{0903}\u{0308}", "\u{0001}"],
-		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <START OF HEADING> (Control) ÷ [0.3]"
+	grapheme_test("\u{0A03}\u{0308}\u{0001}",
+		&["\u{0A03}\u{0308}", "\u{0001}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <START OF HEADING> (Control) ÷ [0.3]"
 	);
-	grapheme_test("\u{0903}\u{034F}",
-		&["\u{0903}\u{034F}"],
-		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark) × [9.0] COMBINING GRAPHEME JOINER (Extend) ÷ [0.3]"
+	grapheme_test("\u{0A03}\u{200C}",
+		&["\u{0A03}\u{200C}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
 	);
-	grapheme_test("\u{0903}\u{0308}\u{034F}",
-		&["\u{0903}\u{0308}\u{034F}"],
-		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAPHEME JOINER (Extend) ÷ [0.3]"
+	grapheme_test("\u{0A03}\u{0308}\u{200C}",
+		&["\u{0A03}\u{0308}\u{200C}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
 	);
-	grapheme_test("\u{0903}\u{1F1E6}",
-		&["\u{0903}", "\u{1F1E6}"],
-		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	grapheme_test("\u{0A03}\u{1F1E6}",
+		&["\u{0A03}", "\u{1F1E6}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
 	);
-	grapheme_test("\u{0903}\u{0308}\u{1F1E6}",
-		&["\u{0903}\u{0308}", "\u{1F1E6}"],
-		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	grapheme_test("\u{0A03}\u{0308}\u{1F1E6}",
+		&["\u{0A03}\u{0308}", "\u{1F1E6}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
 	);
-	grapheme_test("\u{0903}\u{0600}",
-		&["\u{0903}", "\u{0600}"],
-		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [999.0] ARABIC NUMBER SIGN (Prepend) ÷ [0.3]"
+	grapheme_test("\u{0A03}\u{0600}",
+		&["\u{0A03}", "\u{0600}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [999.0] ARABIC NUMBER SIGN (Prepend) ÷ [0.3]"
 	);
-	grapheme_test("\u{0903}\u{0308}\u{0600}",
-		&["\u{0903}\u{0308}", "\u{0600}"],
-		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] ARABIC NUMBER SIGN (Prepend) ÷ [0.3]"
+	grapheme_test("\u{0A03}\u{0308}\u{0600}",
+		&["\u{0A03}\u{0308}", "\u{0600}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] ARABIC NUMBER SIGN (Prepend) ÷ [0.3]"
 	);
-	grapheme_test("\u{0903}\u{0903}",
-		&["\u{0903}\u{0903}"],
-		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	grapheme_test("\u{0A03}\u{0A03}",
+		&["\u{0A03}\u{0A03}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
 	);
-	grapheme_test("\u{0903}\u{0308}\u{0903}",
-		&["\u{0903}\u{0308}\u{0903}"],
-		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	grapheme_test("\u{0A03}\u{0308}\u{0A03}",
+		&["\u{0A03}\u{0308}\u{0A03}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
 	);
-	grapheme_test("\u{0903}\u{1100}",
-		&["\u{0903}", "\u{1100}"],
-		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [999.0] HANGUL CHOSEONG KIYEOK (L) ÷ [0.3]"
+	grapheme_test("\u{0A03}\u{1100}",
+		&["\u{0A03}", "\u{1100}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [999.0] HANGUL CHOSEONG KIYEOK (L) ÷ [0.3]"
 	);
-	grapheme_test("\u{0903}\u{0308}\u{1100}",
-		&["\u{0903}\u{0308}", "\u{1100}"],
-		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL CHOSEONG KIYEOK (L) ÷ [0.3]"
+	grapheme_test("\u{0A03}\u{0308}\u{1100}",
+		&["\u{0A03}\u{0308}", "\u{1100}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL CHOSEONG KIYEOK (L) ÷ [0.3]"
 	);
-	grapheme_test("\u{0903}\u{1160}",
-		&["\u{0903}", "\u{1160}"],
-		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [999.0] HANGUL JUNGSEONG FILLER (V) ÷ [0.3]"
+	grapheme_test("\u{0A03}\u{1160}",
+		&["\u{0A03}", "\u{1160}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [999.0] HANGUL JUNGSEONG FILLER (V) ÷ [0.3]"
 	);
-	grapheme_test("\u{0903}\u{0308}\u{1160}",
-		&["\u{0903}\u{0308}", "\u{1160}"],
-		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL JUNGSEONG FILLER (V) ÷ [0.3]"
+	grapheme_test("\u{0A03}\u{0308}\u{1160}",
+		&["\u{0A03}\u{0308}", "\u{1160}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL JUNGSEONG FILLER (V) ÷ [0.3]"
 	);
-	grapheme_test("\u{0903}\u{11A8}",
-		&["\u{0903}", "\u{11A8}"],
-		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [999.0] HANGUL JONGSEONG KIYEOK (T) ÷ [0.3]"
+	grapheme_test("\u{0A03}\u{11A8}",
+		&["\u{0A03}", "\u{11A8}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [999.0] HANGUL JONGSEONG KIYEOK (T) ÷ [0.3]"
 	);
-	grapheme_test("\u{0903}\u{0308}\u{11A8}",
-		&["\u{0903}\u{0308}", "\u{11A8}"],
-		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL JONGSEONG KIYEOK (T) ÷ [0.3]"
+	grapheme_test("\u{0A03}\u{0308}\u{11A8}",
+		&["\u{0A03}\u{0308}", "\u{11A8}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL JONGSEONG KIYEOK (T) ÷ [0.3]"
 	);
-	grapheme_test("\u{0903}\u{AC00}",
-		&["\u{0903}", "\u{AC00}"],
-		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [999.0] HANGUL SYLLABLE GA (LV) ÷ [0.3]"
+	grapheme_test("\u{0A03}\u{AC00}",
+		&["\u{0A03}", "\u{AC00}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [999.0] HANGUL SYLLABLE GA (LV) ÷ [0.3]"
 	);
-	grapheme_test("\u{0903}\u{0308}\u{AC00}",
-		&["\u{0903}\u{0308}", "\u{AC00}"],
-		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL SYLLABLE GA (LV) ÷ [0.3]"
+	grapheme_test("\u{0A03}\u{0308}\u{AC00}",
+		&["\u{0A03}\u{0308}", "\u{AC00}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL SYLLABLE GA (LV) ÷ [0.3]"
 	);
-	grapheme_test("\u{0903}\u{AC01}",
-		&["\u{0903}", "\u{AC01}"],
-		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [999.0] HANGUL SYLLABLE GAG (LVT) ÷ [0.3]"
+	grapheme_test("\u{0A03}\u{AC01}",
+		&["\u{0A03}", "\u{AC01}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [999.0] HANGUL SYLLABLE GAG (LVT) ÷ [0.3]"
 	);
-	grapheme_test("\u{0903}\u{0308}\u{AC01}",
-		&["\u{0903}\u{0308}", "\u{AC01}"],
-		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL SYLLABLE GAG (LVT) ÷ [0.3]"
+	grapheme_test("\u{0A03}\u{0308}\u{AC01}",
+		&["\u{0A03}\u{0308}", "\u{AC01}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL SYLLABLE GAG (LVT) ÷ [0.3]"
 	);
-	grapheme_test("\u{0903}\u{231A}",
-		&["\u{0903}", "\u{231A}"],
-		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [999.0] WATCH (ExtPict) ÷ [0.3]"
+	grapheme_test("\u{0A03}\u{0903}",
+		&["\u{0A03}\u{0903}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
 	);
-	grapheme_test("\u{0903}\u{0308}\u{231A}",
-		&["\u{0903}\u{0308}", "\u{231A}"],
-		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] WATCH (ExtPict) ÷ [0.3]"
+	grapheme_test("\u{0A03}\u{0308}\u{0903}",
+		&["\u{0A03}\u{0308}\u{0903}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
 	);
-	grapheme_test("\u{0903}\u{0300}",
-		&["\u{0903}\u{0300}"],
-		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark) × [9.0] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) ÷ [0.3]"
+	grapheme_test("\u{0A03}\u{0904}",
+		&["\u{0A03}", "\u{0904}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
 	);
-	grapheme_test("\u{0903}\u{0308}\u{0300}",
-		&["\u{0903}\u{0308}\u{0300}"],
-		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) ÷ [0.3]"
+	grapheme_test("\u{0A03}\u{0308}\u{0904}",
+		&["\u{0A03}\u{0308}", "\u{0904}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
 	);
-	grapheme_test("\u{0903}\u{200D}",
-		&["\u{0903}\u{200D}"],
-		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark) × [9.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [0.3]"
+	grapheme_test("\u{0A03}\u{0D4E}",
+		&["\u{0A03}", "\u{0D4E}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
 	);
-	grapheme_test("\u{0903}\u{0308}\u{200D}",
-		&["\u{0903}\u{0308}\u{200D}"],
-		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [0.3]"
+	grapheme_test("\u{0A03}\u{0308}\u{0D4E}",
+		&["\u{0A03}\u{0308}", "\u{0D4E}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
 	);
-	grapheme_test("\u{0903}\u{0378}",
-		&["\u{0903}", "\u{0378}"],
-		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [999.0] <reserved-0378> (Other) ÷ [0.3]"
+	grapheme_test("\u{0A03}\u{0915}",
+		&["\u{0A03}", "\u{0915}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
 	);
-	grapheme_test("\u{0903}\u{0308}\u{0378}",
-		&["\u{0903}\u{0308}", "\u{0378}"],
-		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] <reserved-0378> (Other) ÷ [0.3]"
+	grapheme_test("\u{0A03}\u{0308}\u{0915}",
+		&["\u{0A03}\u{0308}", "\u{0915}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
+	grapheme_test("\u{0A03}\u{231A}",
+		&["\u{0A03}", "\u{231A}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [999.0] WATCH (ExtPict) ÷ [0.3]"
+	);
+	grapheme_test("\u{0A03}\u{0308}\u{231A}",
+		&["\u{0A03}\u{0308}", "\u{231A}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] WATCH (ExtPict) ÷ [0.3]"
+	);
+	grapheme_test("\u{0A03}\u{0300}",
+		&["\u{0A03}\u{0300}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) × [9.0] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0A03}\u{0308}\u{0300}",
+		&["\u{0A03}\u{0308}\u{0300}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0A03}\u{0900}",
+		&["\u{0A03}\u{0900}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0A03}\u{0308}\u{0900}",
+		&["\u{0A03}\u{0308}\u{0900}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0A03}\u{094D}",
+		&["\u{0A03}\u{094D}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0A03}\u{0308}\u{094D}",
+		&["\u{0A03}\u{0308}\u{094D}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0A03}\u{200D}",
+		&["\u{0A03}\u{200D}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) × [9.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0A03}\u{0308}\u{200D}",
+		&["\u{0A03}\u{0308}\u{200D}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0A03}\u{0378}",
+		&["\u{0A03}", "\u{0378}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [999.0] <reserved-0378> (Other) ÷ [0.3]"
+	);
+	grapheme_test("\u{0A03}\u{0308}\u{0378}",
+		&["\u{0A03}\u{0308}", "\u{0378}"],
+		"  ÷ [0.2] GURMUKHI SIGN VISARGA (SpacingMark) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] <reserved-0378> (Other) ÷ [0.3]"
 	);
 	grapheme_test("\u{1100}\u{0020}",
 		&["\u{1100}", "\u{0020}"],
@@ -1124,13 +1508,13 @@ fn standard_grapheme_test() {
 		&["\u{1100}\u{0308}", "\u{0001}"],
 		"  ÷ [0.2] HANGUL CHOSEONG KIYEOK (L) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <START OF HEADING> (Control) ÷ [0.3]"
 	);
-	grapheme_test("\u{1100}\u{034F}",
-		&["\u{1100}\u{034F}"],
-		"  ÷ [0.2] HANGUL CHOSEONG KIYEOK (L) × [9.0] COMBINING GRAPHEME JOINER (Extend) ÷ [0.3]"
+	grapheme_test("\u{1100}\u{200C}",
+		&["\u{1100}\u{200C}"],
+		"  ÷ [0.2] HANGUL CHOSEONG KIYEOK (L) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
 	);
-	grapheme_test("\u{1100}\u{0308}\u{034F}",
-		&["\u{1100}\u{0308}\u{034F}"],
-		"  ÷ [0.2] HANGUL CHOSEONG KIYEOK (L) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAPHEME JOINER (Extend) ÷ [0.3]"
+	grapheme_test("\u{1100}\u{0308}\u{200C}",
+		&["\u{1100}\u{0308}\u{200C}"],
+		"  ÷ [0.2] HANGUL CHOSEONG KIYEOK (L) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
 	);
 	grapheme_test("\u{1100}\u{1F1E6}",
 		&["\u{1100}", "\u{1F1E6}"],
@@ -1148,13 +1532,13 @@ fn standard_grapheme_test() {
 		&["\u{1100}\u{0308}", "\u{0600}"],
 		"  ÷ [0.2] HANGUL CHOSEONG KIYEOK (L) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] ARABIC NUMBER SIGN (Prepend) ÷ [0.3]"
 	);
-	grapheme_test("\u{1100}\u{0903}",
-		&["\u{1100}\u{0903}"],
-		"  ÷ [0.2] HANGUL CHOSEONG KIYEOK (L) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	grapheme_test("\u{1100}\u{0A03}",
+		&["\u{1100}\u{0A03}"],
+		"  ÷ [0.2] HANGUL CHOSEONG KIYEOK (L) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
 	);
-	grapheme_test("\u{1100}\u{0308}\u{0903}",
-		&["\u{1100}\u{0308}\u{0903}"],
-		"  ÷ [0.2] HANGUL CHOSEONG KIYEOK (L) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	grapheme_test("\u{1100}\u{0308}\u{0A03}",
+		&["\u{1100}\u{0308}\u{0A03}"],
+		"  ÷ [0.2] HANGUL CHOSEONG KIYEOK (L) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
 	);
 	grapheme_test("\u{1100}\u{1100}",
 		&["\u{1100}\u{1100}"],
@@ -1196,6 +1580,38 @@ fn standard_grapheme_test() {
 		&["\u{1100}\u{0308}", "\u{AC01}"],
 		"  ÷ [0.2] HANGUL CHOSEONG KIYEOK (L) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL SYLLABLE GAG (LVT) ÷ [0.3]"
 	);
+	grapheme_test("\u{1100}\u{0903}",
+		&["\u{1100}\u{0903}"],
+		"  ÷ [0.2] HANGUL CHOSEONG KIYEOK (L) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{1100}\u{0308}\u{0903}",
+		&["\u{1100}\u{0308}\u{0903}"],
+		"  ÷ [0.2] HANGUL CHOSEONG KIYEOK (L) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{1100}\u{0904}",
+		&["\u{1100}", "\u{0904}"],
+		"  ÷ [0.2] HANGUL CHOSEONG KIYEOK (L) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{1100}\u{0308}\u{0904}",
+		&["\u{1100}\u{0308}", "\u{0904}"],
+		"  ÷ [0.2] HANGUL CHOSEONG KIYEOK (L) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{1100}\u{0D4E}",
+		&["\u{1100}", "\u{0D4E}"],
+		"  ÷ [0.2] HANGUL CHOSEONG KIYEOK (L) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{1100}\u{0308}\u{0D4E}",
+		&["\u{1100}\u{0308}", "\u{0D4E}"],
+		"  ÷ [0.2] HANGUL CHOSEONG KIYEOK (L) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{1100}\u{0915}",
+		&["\u{1100}", "\u{0915}"],
+		"  ÷ [0.2] HANGUL CHOSEONG KIYEOK (L) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
+	grapheme_test("\u{1100}\u{0308}\u{0915}",
+		&["\u{1100}\u{0308}", "\u{0915}"],
+		"  ÷ [0.2] HANGUL CHOSEONG KIYEOK (L) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
 	grapheme_test("\u{1100}\u{231A}",
 		&["\u{1100}", "\u{231A}"],
 		"  ÷ [0.2] HANGUL CHOSEONG KIYEOK (L) ÷ [999.0] WATCH (ExtPict) ÷ [0.3]"
@@ -1212,6 +1628,22 @@ fn standard_grapheme_test() {
 		&["\u{1100}\u{0308}\u{0300}"],
 		"  ÷ [0.2] HANGUL CHOSEONG KIYEOK (L) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) ÷ [0.3]"
 	);
+	grapheme_test("\u{1100}\u{0900}",
+		&["\u{1100}\u{0900}"],
+		"  ÷ [0.2] HANGUL CHOSEONG KIYEOK (L) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{1100}\u{0308}\u{0900}",
+		&["\u{1100}\u{0308}\u{0900}"],
+		"  ÷ [0.2] HANGUL CHOSEONG KIYEOK (L) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{1100}\u{094D}",
+		&["\u{1100}\u{094D}"],
+		"  ÷ [0.2] HANGUL CHOSEONG KIYEOK (L) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{1100}\u{0308}\u{094D}",
+		&["\u{1100}\u{0308}\u{094D}"],
+		"  ÷ [0.2] HANGUL CHOSEONG KIYEOK (L) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
 	grapheme_test("\u{1100}\u{200D}",
 		&["\u{1100}\u{200D}"],
 		"  ÷ [0.2] HANGUL CHOSEONG KIYEOK (L) × [9.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [0.3]"
@@ -1260,13 +1692,13 @@ fn standard_grapheme_test() {
 		&["\u{1160}\u{0308}", "\u{0001}"],
 		"  ÷ [0.2] HANGUL JUNGSEONG FILLER (V) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <START OF HEADING> (Control) ÷ [0.3]"
 	);
-	grapheme_test("\u{1160}\u{034F}",
-		&["\u{1160}\u{034F}"],
-		"  ÷ [0.2] HANGUL JUNGSEONG FILLER (V) × [9.0] COMBINING GRAPHEME JOINER (Extend) ÷ [0.3]"
+	grapheme_test("\u{1160}\u{200C}",
+		&["\u{1160}\u{200C}"],
+		"  ÷ [0.2] HANGUL JUNGSEONG FILLER (V) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
 	);
-	grapheme_test("\u{1160}\u{0308}\u{034F}",
-		&["\u{1160}\u{0308}\u{034F}"],
-		"  ÷ [0.2] HANGUL JUNGSEONG FILLER (V) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAPHEME JOINER (Extend) ÷ [0.3]"
+	grapheme_test("\u{1160}\u{0308}\u{200C}",
+		&["\u{1160}\u{0308}\u{200C}"],
+		"  ÷ [0.2] HANGUL JUNGSEONG FILLER (V) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
 	);
 	grapheme_test("\u{1160}\u{1F1E6}",
 		&["\u{1160}", "\u{1F1E6}"],
@@ -1284,13 +1716,13 @@ fn standard_grapheme_test() {
 		&["\u{1160}\u{0308}", "\u{0600}"],
 		"  ÷ [0.2] HANGUL JUNGSEONG FILLER (V) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] ARABIC NUMBER SIGN (Prepend) ÷ [0.3]"
 	);
-	grapheme_test("\u{1160}\u{0903}",
-		&["\u{1160}\u{0903}"],
-		"  ÷ [0.2] HANGUL JUNGSEONG FILLER (V) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	grapheme_test("\u{1160}\u{0A03}",
+		&["\u{1160}\u{0A03}"],
+		"  ÷ [0.2] HANGUL JUNGSEONG FILLER (V) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
 	);
-	grapheme_test("\u{1160}\u{0308}\u{0903}",
-		&["\u{1160}\u{0308}\u{0903}"],
-		"  ÷ [0.2] HANGUL JUNGSEONG FILLER (V) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	grapheme_test("\u{1160}\u{0308}\u{0A03}",
+		&["\u{1160}\u{0308}\u{0A03}"],
+		"  ÷ [0.2] HANGUL JUNGSEONG FILLER (V) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
 	);
 	grapheme_test("\u{1160}\u{1100}",
 		&["\u{1160}", "\u{1100}"],
@@ -1332,6 +1764,38 @@ fn standard_grapheme_test() {
 		&["\u{1160}\u{0308}", "\u{AC01}"],
 		"  ÷ [0.2] HANGUL JUNGSEONG FILLER (V) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL SYLLABLE GAG (LVT) ÷ [0.3]"
 	);
+	grapheme_test("\u{1160}\u{0903}",
+		&["\u{1160}\u{0903}"],
+		"  ÷ [0.2] HANGUL JUNGSEONG FILLER (V) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{1160}\u{0308}\u{0903}",
+		&["\u{1160}\u{0308}\u{0903}"],
+		"  ÷ [0.2] HANGUL JUNGSEONG FILLER (V) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{1160}\u{0904}",
+		&["\u{1160}", "\u{0904}"],
+		"  ÷ [0.2] HANGUL JUNGSEONG FILLER (V) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{1160}\u{0308}\u{0904}",
+		&["\u{1160}\u{0308}", "\u{0904}"],
+		"  ÷ [0.2] HANGUL JUNGSEONG FILLER (V) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{1160}\u{0D4E}",
+		&["\u{1160}", "\u{0D4E}"],
+		"  ÷ [0.2] HANGUL JUNGSEONG FILLER (V) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{1160}\u{0308}\u{0D4E}",
+		&["\u{1160}\u{0308}", "\u{0D4E}"],
+		"  ÷ [0.2] HANGUL JUNGSEONG FILLER (V) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{1160}\u{0915}",
+		&["\u{1160}", "\u{0915}"],
+		"  ÷ [0.2] HANGUL JUNGSEONG FILLER (V) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
+	grapheme_test("\u{1160}\u{0308}\u{0915}",
+		&["\u{1160}\u{0308}", "\u{0915}"],
+		"  ÷ [0.2] HANGUL JUNGSEONG FILLER (V) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
 	grapheme_test("\u{1160}\u{231A}",
 		&["\u{1160}", "\u{231A}"],
 		"  ÷ [0.2] HANGUL JUNGSEONG FILLER (V) ÷ [999.0] WATCH (ExtPict) ÷ [0.3]"
@@ -1348,6 +1812,22 @@ fn standard_grapheme_test() {
 		&["\u{1160}\u{0308}\u{0300}"],
 		"  ÷ [0.2] HANGUL JUNGSEONG FILLER (V) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) ÷ [0.3]"
 	);
+	grapheme_test("\u{1160}\u{0900}",
+		&["\u{1160}\u{0900}"],
+		"  ÷ [0.2] HANGUL JUNGSEONG FILLER (V) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{1160}\u{0308}\u{0900}",
+		&["\u{1160}\u{0308}\u{0900}"],
+		"  ÷ [0.2] HANGUL JUNGSEONG FILLER (V) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{1160}\u{094D}",
+		&["\u{1160}\u{094D}"],
+		"  ÷ [0.2] HANGUL JUNGSEONG FILLER (V) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{1160}\u{0308}\u{094D}",
+		&["\u{1160}\u{0308}\u{094D}"],
+		"  ÷ [0.2] HANGUL JUNGSEONG FILLER (V) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
 	grapheme_test("\u{1160}\u{200D}",
 		&["\u{1160}\u{200D}"],
 		"  ÷ [0.2] HANGUL JUNGSEONG FILLER (V) × [9.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [0.3]"
@@ -1396,13 +1876,13 @@ fn standard_grapheme_test() {
 		&["\u{11A8}\u{0308}", "\u{0001}"],
 		"  ÷ [0.2] HANGUL JONGSEONG KIYEOK (T) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <START OF HEADING> (Control) ÷ [0.3]"
 	);
-	grapheme_test("\u{11A8}\u{034F}",
-		&["\u{11A8}\u{034F}"],
-		"  ÷ [0.2] HANGUL JONGSEONG KIYEOK (T) × [9.0] COMBINING GRAPHEME JOINER (Extend) ÷ [0.3]"
+	grapheme_test("\u{11A8}\u{200C}",
+		&["\u{11A8}\u{200C}"],
+		"  ÷ [0.2] HANGUL JONGSEONG KIYEOK (T) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
 	);
-	grapheme_test("\u{11A8}\u{0308}\u{034F}",
-		&["\u{11A8}\u{0308}\u{034F}"],
-		"  ÷ [0.2] HANGUL JONGSEONG KIYEOK (T) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAPHEME JOINER (Extend) ÷ [0.3]"
+	grapheme_test("\u{11A8}\u{0308}\u{200C}",
+		&["\u{11A8}\u{0308}\u{200C}"],
+		"  ÷ [0.2] HANGUL JONGSEONG KIYEOK (T) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
 	);
 	grapheme_test("\u{11A8}\u{1F1E6}",
 		&["\u{11A8}", "\u{1F1E6}"],
@@ -1420,13 +1900,13 @@ fn standard_grapheme_test() {
 		&["\u{11A8}\u{0308}", "\u{0600}"],
 		"  ÷ [0.2] HANGUL JONGSEONG KIYEOK (T) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] ARABIC NUMBER SIGN (Prepend) ÷ [0.3]"
 	);
-	grapheme_test("\u{11A8}\u{0903}",
-		&["\u{11A8}\u{0903}"],
-		"  ÷ [0.2] HANGUL JONGSEONG KIYEOK (T) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	grapheme_test("\u{11A8}\u{0A03}",
+		&["\u{11A8}\u{0A03}"],
+		"  ÷ [0.2] HANGUL JONGSEONG KIYEOK (T) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
 	);
-	grapheme_test("\u{11A8}\u{0308}\u{0903}",
-		&["\u{11A8}\u{0308}\u{0903}"],
-		"  ÷ [0.2] HANGUL JONGSEONG KIYEOK (T) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	grapheme_test("\u{11A8}\u{0308}\u{0A03}",
+		&["\u{11A8}\u{0308}\u{0A03}"],
+		"  ÷ [0.2] HANGUL JONGSEONG KIYEOK (T) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
 	);
 	grapheme_test("\u{11A8}\u{1100}",
 		&["\u{11A8}", "\u{1100}"],
@@ -1468,10 +1948,42 @@ fn standard_grapheme_test() {
 		&["\u{11A8}\u{0308}", "\u{AC01}"],
 		"  ÷ [0.2] HANGUL JONGSEONG KIYEOK (T) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL SYLLABLE GAG (LVT) ÷ [0.3]"
 	);
-	grapheme_test("\u{11A8}\u{231A}",
-		&["\u{11A8}", "\u{231A}"],
-		"  ÷ [0.2] HANGUL JONGSEONG KIYEOK (T) ÷ [999.0] WATCH (ExtPict) ÷ [0.3]"
-	);
+	grapheme_test("\u{11A8}\u{0903}",
+		&["\u{11A8}\u{0903}"],
+		"  ÷ [0.2] HANGUL JONGSEONG KIYEOK (T) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{11A8}\u{0308}\u{0903}",
+		&["\u{11A8}\u{0308}\u{0903}"],
+		"  ÷ [0.2] HANGUL JONGSEONG KIYEOK (T) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{11A8}\u{0904}",
+		&["\u{11A8}", "\u{0904}"],
+		"  ÷ [0.2] HANGUL JONGSEONG KIYEOK (T) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{11A8}\u{0308}\u{0904}",
+		&["\u{11A8}\u{0308}", "\u{0904}"],
+		"  ÷ [0.2] HANGUL JONGSEONG KIYEOK (T) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{11A8}\u{0D4E}",
+		&["\u{11A8}", "\u{0D4E}"],
+		"  ÷ [0.2] HANGUL JONGSEONG KIYEOK (T) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{11A8}\u{0308}\u{0D4E}",
+		&["\u{11A8}\u{0308}", "\u{0D4E}"],
+		"  ÷ [0.2] HANGUL JONGSEONG KIYEOK (T) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{11A8}\u{0915}",
+		&["\u{11A8}", "\u{0915}"],
+		"  ÷ [0.2] HANGUL JONGSEONG KIYEOK (T) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
+	grapheme_test("\u{11A8}\u{0308}\u{0915}",
+		&["\u{11A8}\u{0308}", "\u{0915}"],
+		"  ÷ [0.2] HANGUL JONGSEONG KIYEOK (T) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
+	grapheme_test("\u{11A8}\u{231A}",
+		&["\u{11A8}", "\u{231A}"],
+		"  ÷ [0.2] HANGUL JONGSEONG KIYEOK (T) ÷ [999.0] WATCH (ExtPict) ÷ [0.3]"
+	);
 	grapheme_test("\u{11A8}\u{0308}\u{231A}",
 		&["\u{11A8}\u{0308}", "\u{231A}"],
 		"  ÷ [0.2] HANGUL JONGSEONG KIYEOK (T) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] WATCH (ExtPict) ÷ [0.3]"
@@ -1484,6 +1996,22 @@ fn standard_grapheme_test() {
 		&["\u{11A8}\u{0308}\u{0300}"],
 		"  ÷ [0.2] HANGUL JONGSEONG KIYEOK (T) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) ÷ [0.3]"
 	);
+	grapheme_test("\u{11A8}\u{0900}",
+		&["\u{11A8}\u{0900}"],
+		"  ÷ [0.2] HANGUL JONGSEONG KIYEOK (T) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{11A8}\u{0308}\u{0900}",
+		&["\u{11A8}\u{0308}\u{0900}"],
+		"  ÷ [0.2] HANGUL JONGSEONG KIYEOK (T) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{11A8}\u{094D}",
+		&["\u{11A8}\u{094D}"],
+		"  ÷ [0.2] HANGUL JONGSEONG KIYEOK (T) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{11A8}\u{0308}\u{094D}",
+		&["\u{11A8}\u{0308}\u{094D}"],
+		"  ÷ [0.2] HANGUL JONGSEONG KIYEOK (T) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
 	grapheme_test("\u{11A8}\u{200D}",
 		&["\u{11A8}\u{200D}"],
 		"  ÷ [0.2] HANGUL JONGSEONG KIYEOK (T) × [9.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [0.3]"
@@ -1532,13 +2060,13 @@ fn standard_grapheme_test() {
 		&["\u{AC00}\u{0308}", "\u{0001}"],
 		"  ÷ [0.2] HANGUL SYLLABLE GA (LV) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <START OF HEADING> (Control) ÷ [0.3]"
 	);
-	grapheme_test("\u{AC00}\u{034F}",
-		&["\u{AC00}\u{034F}"],
-		"  ÷ [0.2] HANGUL SYLLABLE GA (LV) × [9.0] COMBINING GRAPHEME JOINER (Extend) ÷ [0.3]"
+	grapheme_test("\u{AC00}\u{200C}",
+		&["\u{AC00}\u{200C}"],
+		"  ÷ [0.2] HANGUL SYLLABLE GA (LV) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
 	);
-	grapheme_test("\u{AC00}\u{0308}\u{034F}",
-		&["\u{AC00}\u{0308}\u{034F}"],
-		"  ÷ [0.2] HANGUL SYLLABLE GA (LV) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAPHEME JOINER (Extend) ÷ [0.3]"
+	grapheme_test("\u{AC00}\u{0308}\u{200C}",
+		&["\u{AC00}\u{0308}\u{200C}"],
+		"  ÷ [0.2] HANGUL SYLLABLE GA (LV) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
 	);
 	grapheme_test("\u{AC00}\u{1F1E6}",
 		&["\u{AC00}", "\u{1F1E6}"],
@@ -1556,13 +2084,13 @@ fn standard_grapheme_test() {
 		&["\u{AC00}\u{0308}", "\u{0600}"],
 		"  ÷ [0.2] HANGUL SYLLABLE GA (LV) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] ARABIC NUMBER SIGN (Prepend) ÷ [0.3]"
 	);
-	grapheme_test("\u{AC00}\u{0903}",
-		&["\u{AC00}\u{0903}"],
-		"  ÷ [0.2] HANGUL SYLLABLE GA (LV) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	grapheme_test("\u{AC00}\u{0A03}",
+		&["\u{AC00}\u{0A03}"],
+		"  ÷ [0.2] HANGUL SYLLABLE GA (LV) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
 	);
-	grapheme_test("\u{AC00}\u{0308}\u{0903}",
-		&["\u{AC00}\u{0308}\u{0903}"],
-		"  ÷ [0.2] HANGUL SYLLABLE GA (LV) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	grapheme_test("\u{AC00}\u{0308}\u{0A03}",
+		&["\u{AC00}\u{0308}\u{0A03}"],
+		"  ÷ [0.2] HANGUL SYLLABLE GA (LV) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
 	);
 	grapheme_test("\u{AC00}\u{1100}",
 		&["\u{AC00}", "\u{1100}"],
@@ -1604,6 +2132,38 @@ fn standard_grapheme_test() {
 		&["\u{AC00}\u{0308}", "\u{AC01}"],
 		"  ÷ [0.2] HANGUL SYLLABLE GA (LV) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL SYLLABLE GAG (LVT) ÷ [0.3]"
 	);
+	grapheme_test("\u{AC00}\u{0903}",
+		&["\u{AC00}\u{0903}"],
+		"  ÷ [0.2] HANGUL SYLLABLE GA (LV) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{AC00}\u{0308}\u{0903}",
+		&["\u{AC00}\u{0308}\u{0903}"],
+		"  ÷ [0.2] HANGUL SYLLABLE GA (LV) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{AC00}\u{0904}",
+		&["\u{AC00}", "\u{0904}"],
+		"  ÷ [0.2] HANGUL SYLLABLE GA (LV) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{AC00}\u{0308}\u{0904}",
+		&["\u{AC00}\u{0308}", "\u{0904}"],
+		"  ÷ [0.2] HANGUL SYLLABLE GA (LV) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{AC00}\u{0D4E}",
+		&["\u{AC00}", "\u{0D4E}"],
+		"  ÷ [0.2] HANGUL SYLLABLE GA (LV) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{AC00}\u{0308}\u{0D4E}",
+		&["\u{AC00}\u{0308}", "\u{0D4E}"],
+		"  ÷ [0.2] HANGUL SYLLABLE GA (LV) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{AC00}\u{0915}",
+		&["\u{AC00}", "\u{0915}"],
+		"  ÷ [0.2] HANGUL SYLLABLE GA (LV) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
+	grapheme_test("\u{AC00}\u{0308}\u{0915}",
+		&["\u{AC00}\u{0308}", "\u{0915}"],
+		"  ÷ [0.2] HANGUL SYLLABLE GA (LV) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
 	grapheme_test("\u{AC00}\u{231A}",
 		&["\u{AC00}", "\u{231A}"],
 		"  ÷ [0.2] HANGUL SYLLABLE GA (LV) ÷ [999.0] WATCH (ExtPict) ÷ [0.3]"
@@ -1620,6 +2180,22 @@ fn standard_grapheme_test() {
 		&["\u{AC00}\u{0308}\u{0300}"],
 		"  ÷ [0.2] HANGUL SYLLABLE GA (LV) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) ÷ [0.3]"
 	);
+	grapheme_test("\u{AC00}\u{0900}",
+		&["\u{AC00}\u{0900}"],
+		"  ÷ [0.2] HANGUL SYLLABLE GA (LV) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{AC00}\u{0308}\u{0900}",
+		&["\u{AC00}\u{0308}\u{0900}"],
+		"  ÷ [0.2] HANGUL SYLLABLE GA (LV) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{AC00}\u{094D}",
+		&["\u{AC00}\u{094D}"],
+		"  ÷ [0.2] HANGUL SYLLABLE GA (LV) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{AC00}\u{0308}\u{094D}",
+		&["\u{AC00}\u{0308}\u{094D}"],
+		"  ÷ [0.2] HANGUL SYLLABLE GA (LV) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
 	grapheme_test("\u{AC00}\u{200D}",
 		&["\u{AC00}\u{200D}"],
 		"  ÷ [0.2] HANGUL SYLLABLE GA (LV) × [9.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [0.3]"
@@ -1668,13 +2244,13 @@ fn standard_grapheme_test() {
 		&["\u{AC01}\u{0308}", "\u{0001}"],
 		"  ÷ [0.2] HANGUL SYLLABLE GAG (LVT) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <START OF HEADING> (Control) ÷ [0.3]"
 	);
-	grapheme_test("\u{AC01}\u{034F}",
-		&["\u{AC01}\u{034F}"],
-		"  ÷ [0.2] HANGUL SYLLABLE GAG (LVT) × [9.0] COMBINING GRAPHEME JOINER (Extend) ÷ [0.3]"
+	grapheme_test("\u{AC01}\u{200C}",
+		&["\u{AC01}\u{200C}"],
+		"  ÷ [0.2] HANGUL SYLLABLE GAG (LVT) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
 	);
-	grapheme_test("\u{AC01}\u{0308}\u{034F}",
-		&["\u{AC01}\u{0308}\u{034F}"],
-		"  ÷ [0.2] HANGUL SYLLABLE GAG (LVT) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAPHEME JOINER (Extend) ÷ [0.3]"
+	grapheme_test("\u{AC01}\u{0308}\u{200C}",
+		&["\u{AC01}\u{0308}\u{200C}"],
+		"  ÷ [0.2] HANGUL SYLLABLE GAG (LVT) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
 	);
 	grapheme_test("\u{AC01}\u{1F1E6}",
 		&["\u{AC01}", "\u{1F1E6}"],
@@ -1692,13 +2268,13 @@ fn standard_grapheme_test() {
 		&["\u{AC01}\u{0308}", "\u{0600}"],
 		"  ÷ [0.2] HANGUL SYLLABLE GAG (LVT) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] ARABIC NUMBER SIGN (Prepend) ÷ [0.3]"
 	);
-	grapheme_test("\u{AC01}\u{0903}",
-		&["\u{AC01}\u{0903}"],
-		"  ÷ [0.2] HANGUL SYLLABLE GAG (LVT) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	grapheme_test("\u{AC01}\u{0A03}",
+		&["\u{AC01}\u{0A03}"],
+		"  ÷ [0.2] HANGUL SYLLABLE GAG (LVT) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
 	);
-	grapheme_test("\u{AC01}\u{0308}\u{0903}",
-		&["\u{AC01}\u{0308}\u{0903}"],
-		"  ÷ [0.2] HANGUL SYLLABLE GAG (LVT) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	grapheme_test("\u{AC01}\u{0308}\u{0A03}",
+		&["\u{AC01}\u{0308}\u{0A03}"],
+		"  ÷ [0.2] HANGUL SYLLABLE GAG (LVT) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
 	);
 	grapheme_test("\u{AC01}\u{1100}",
 		&["\u{AC01}", "\u{1100}"],
@@ -1740,6 +2316,38 @@ fn standard_grapheme_test() {
 		&["\u{AC01}\u{0308}", "\u{AC01}"],
 		"  ÷ [0.2] HANGUL SYLLABLE GAG (LVT) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL SYLLABLE GAG (LVT) ÷ [0.3]"
 	);
+	grapheme_test("\u{AC01}\u{0903}",
+		&["\u{AC01}\u{0903}"],
+		"  ÷ [0.2] HANGUL SYLLABLE GAG (LVT) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{AC01}\u{0308}\u{0903}",
+		&["\u{AC01}\u{0308}\u{0903}"],
+		"  ÷ [0.2] HANGUL SYLLABLE GAG (LVT) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{AC01}\u{0904}",
+		&["\u{AC01}", "\u{0904}"],
+		"  ÷ [0.2] HANGUL SYLLABLE GAG (LVT) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{AC01}\u{0308}\u{0904}",
+		&["\u{AC01}\u{0308}", "\u{0904}"],
+		"  ÷ [0.2] HANGUL SYLLABLE GAG (LVT) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{AC01}\u{0D4E}",
+		&["\u{AC01}", "\u{0D4E}"],
+		"  ÷ [0.2] HANGUL SYLLABLE GAG (LVT) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{AC01}\u{0308}\u{0D4E}",
+		&["\u{AC01}\u{0308}", "\u{0D4E}"],
+		"  ÷ [0.2] HANGUL SYLLABLE GAG (LVT) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{AC01}\u{0915}",
+		&["\u{AC01}", "\u{0915}"],
+		"  ÷ [0.2] HANGUL SYLLABLE GAG (LVT) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
+	grapheme_test("\u{AC01}\u{0308}\u{0915}",
+		&["\u{AC01}\u{0308}", "\u{0915}"],
+		"  ÷ [0.2] HANGUL SYLLABLE GAG (LVT) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
 	grapheme_test("\u{AC01}\u{231A}",
 		&["\u{AC01}", "\u{231A}"],
 		"  ÷ [0.2] HANGUL SYLLABLE GAG (LVT) ÷ [999.0] WATCH (ExtPict) ÷ [0.3]"
@@ -1756,6 +2364,22 @@ fn standard_grapheme_test() {
 		&["\u{AC01}\u{0308}\u{0300}"],
 		"  ÷ [0.2] HANGUL SYLLABLE GAG (LVT) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) ÷ [0.3]"
 	);
+	grapheme_test("\u{AC01}\u{0900}",
+		&["\u{AC01}\u{0900}"],
+		"  ÷ [0.2] HANGUL SYLLABLE GAG (LVT) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{AC01}\u{0308}\u{0900}",
+		&["\u{AC01}\u{0308}\u{0900}"],
+		"  ÷ [0.2] HANGUL SYLLABLE GAG (LVT) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{AC01}\u{094D}",
+		&["\u{AC01}\u{094D}"],
+		"  ÷ [0.2] HANGUL SYLLABLE GAG (LVT) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{AC01}\u{0308}\u{094D}",
+		&["\u{AC01}\u{0308}\u{094D}"],
+		"  ÷ [0.2] HANGUL SYLLABLE GAG (LVT) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
 	grapheme_test("\u{AC01}\u{200D}",
 		&["\u{AC01}\u{200D}"],
 		"  ÷ [0.2] HANGUL SYLLABLE GAG (LVT) × [9.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [0.3]"
@@ -1772,6 +2396,742 @@ fn standard_grapheme_test() {
 		&["\u{AC01}\u{0308}", "\u{0378}"],
 		"  ÷ [0.2] HANGUL SYLLABLE GAG (LVT) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] <reserved-0378> (Other) ÷ [0.3]"
 	);
+	grapheme_test("\u{0903}\u{0020}",
+		&["\u{0903}", "\u{0020}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [999.0] SPACE (Other) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{0308}\u{0020}",
+		&["\u{0903}\u{0308}", "\u{0020}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] SPACE (Other) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{000D}",
+		&["\u{0903}", "\u{000D}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [5.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{0308}\u{000D}",
+		&["\u{0903}\u{0308}", "\u{000D}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{000A}",
+		&["\u{0903}", "\u{000A}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [5.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{0308}\u{000A}",
+		&["\u{0903}\u{0308}", "\u{000A}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{0001}",
+		&["\u{0903}", "\u{0001}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [5.0] <START OF HEADING> (Control) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{0308}\u{0001}",
+		&["\u{0903}\u{0308}", "\u{0001}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <START OF HEADING> (Control) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{200C}",
+		&["\u{0903}\u{200C}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{0308}\u{200C}",
+		&["\u{0903}\u{0308}\u{200C}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{1F1E6}",
+		&["\u{0903}", "\u{1F1E6}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{0308}\u{1F1E6}",
+		&["\u{0903}\u{0308}", "\u{1F1E6}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{0600}",
+		&["\u{0903}", "\u{0600}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [999.0] ARABIC NUMBER SIGN (Prepend) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{0308}\u{0600}",
+		&["\u{0903}\u{0308}", "\u{0600}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] ARABIC NUMBER SIGN (Prepend) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{0A03}",
+		&["\u{0903}\u{0A03}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{0308}\u{0A03}",
+		&["\u{0903}\u{0308}\u{0A03}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{1100}",
+		&["\u{0903}", "\u{1100}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [999.0] HANGUL CHOSEONG KIYEOK (L) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{0308}\u{1100}",
+		&["\u{0903}\u{0308}", "\u{1100}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL CHOSEONG KIYEOK (L) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{1160}",
+		&["\u{0903}", "\u{1160}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [999.0] HANGUL JUNGSEONG FILLER (V) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{0308}\u{1160}",
+		&["\u{0903}\u{0308}", "\u{1160}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL JUNGSEONG FILLER (V) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{11A8}",
+		&["\u{0903}", "\u{11A8}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [999.0] HANGUL JONGSEONG KIYEOK (T) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{0308}\u{11A8}",
+		&["\u{0903}\u{0308}", "\u{11A8}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL JONGSEONG KIYEOK (T) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{AC00}",
+		&["\u{0903}", "\u{AC00}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [999.0] HANGUL SYLLABLE GA (LV) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{0308}\u{AC00}",
+		&["\u{0903}\u{0308}", "\u{AC00}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL SYLLABLE GA (LV) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{AC01}",
+		&["\u{0903}", "\u{AC01}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [999.0] HANGUL SYLLABLE GAG (LVT) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{0308}\u{AC01}",
+		&["\u{0903}\u{0308}", "\u{AC01}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL SYLLABLE GAG (LVT) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{0903}",
+		&["\u{0903}\u{0903}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{0308}\u{0903}",
+		&["\u{0903}\u{0308}\u{0903}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{0904}",
+		&["\u{0903}", "\u{0904}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{0308}\u{0904}",
+		&["\u{0903}\u{0308}", "\u{0904}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{0D4E}",
+		&["\u{0903}", "\u{0D4E}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{0308}\u{0D4E}",
+		&["\u{0903}\u{0308}", "\u{0D4E}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{0915}",
+		&["\u{0903}", "\u{0915}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{0308}\u{0915}",
+		&["\u{0903}\u{0308}", "\u{0915}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{231A}",
+		&["\u{0903}", "\u{231A}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [999.0] WATCH (ExtPict) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{0308}\u{231A}",
+		&["\u{0903}\u{0308}", "\u{231A}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] WATCH (ExtPict) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{0300}",
+		&["\u{0903}\u{0300}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) × [9.0] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{0308}\u{0300}",
+		&["\u{0903}\u{0308}\u{0300}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{0900}",
+		&["\u{0903}\u{0900}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{0308}\u{0900}",
+		&["\u{0903}\u{0308}\u{0900}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{094D}",
+		&["\u{0903}\u{094D}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{0308}\u{094D}",
+		&["\u{0903}\u{0308}\u{094D}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{200D}",
+		&["\u{0903}\u{200D}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) × [9.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{0308}\u{200D}",
+		&["\u{0903}\u{0308}\u{200D}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{0378}",
+		&["\u{0903}", "\u{0378}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [999.0] <reserved-0378> (Other) ÷ [0.3]"
+	);
+	grapheme_test("\u{0903}\u{0308}\u{0378}",
+		&["\u{0903}\u{0308}", "\u{0378}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] <reserved-0378> (Other) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{0020}",
+		&["\u{0904}", "\u{0020}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [999.0] SPACE (Other) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{0308}\u{0020}",
+		&["\u{0904}\u{0308}", "\u{0020}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] SPACE (Other) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{000D}",
+		&["\u{0904}", "\u{000D}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [5.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{0308}\u{000D}",
+		&["\u{0904}\u{0308}", "\u{000D}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{000A}",
+		&["\u{0904}", "\u{000A}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [5.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{0308}\u{000A}",
+		&["\u{0904}\u{0308}", "\u{000A}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{0001}",
+		&["\u{0904}", "\u{0001}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [5.0] <START OF HEADING> (Control) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{0308}\u{0001}",
+		&["\u{0904}\u{0308}", "\u{0001}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <START OF HEADING> (Control) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{200C}",
+		&["\u{0904}\u{200C}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{0308}\u{200C}",
+		&["\u{0904}\u{0308}\u{200C}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{1F1E6}",
+		&["\u{0904}", "\u{1F1E6}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{0308}\u{1F1E6}",
+		&["\u{0904}\u{0308}", "\u{1F1E6}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{0600}",
+		&["\u{0904}", "\u{0600}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [999.0] ARABIC NUMBER SIGN (Prepend) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{0308}\u{0600}",
+		&["\u{0904}\u{0308}", "\u{0600}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] ARABIC NUMBER SIGN (Prepend) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{0A03}",
+		&["\u{0904}\u{0A03}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{0308}\u{0A03}",
+		&["\u{0904}\u{0308}\u{0A03}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{1100}",
+		&["\u{0904}", "\u{1100}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [999.0] HANGUL CHOSEONG KIYEOK (L) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{0308}\u{1100}",
+		&["\u{0904}\u{0308}", "\u{1100}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL CHOSEONG KIYEOK (L) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{1160}",
+		&["\u{0904}", "\u{1160}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [999.0] HANGUL JUNGSEONG FILLER (V) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{0308}\u{1160}",
+		&["\u{0904}\u{0308}", "\u{1160}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL JUNGSEONG FILLER (V) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{11A8}",
+		&["\u{0904}", "\u{11A8}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [999.0] HANGUL JONGSEONG KIYEOK (T) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{0308}\u{11A8}",
+		&["\u{0904}\u{0308}", "\u{11A8}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL JONGSEONG KIYEOK (T) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{AC00}",
+		&["\u{0904}", "\u{AC00}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [999.0] HANGUL SYLLABLE GA (LV) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{0308}\u{AC00}",
+		&["\u{0904}\u{0308}", "\u{AC00}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL SYLLABLE GA (LV) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{AC01}",
+		&["\u{0904}", "\u{AC01}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [999.0] HANGUL SYLLABLE GAG (LVT) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{0308}\u{AC01}",
+		&["\u{0904}\u{0308}", "\u{AC01}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL SYLLABLE GAG (LVT) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{0903}",
+		&["\u{0904}\u{0903}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{0308}\u{0903}",
+		&["\u{0904}\u{0308}\u{0903}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{0904}",
+		&["\u{0904}", "\u{0904}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{0308}\u{0904}",
+		&["\u{0904}\u{0308}", "\u{0904}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{0D4E}",
+		&["\u{0904}", "\u{0D4E}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{0308}\u{0D4E}",
+		&["\u{0904}\u{0308}", "\u{0D4E}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{0915}",
+		&["\u{0904}", "\u{0915}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{0308}\u{0915}",
+		&["\u{0904}\u{0308}", "\u{0915}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{231A}",
+		&["\u{0904}", "\u{231A}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [999.0] WATCH (ExtPict) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{0308}\u{231A}",
+		&["\u{0904}\u{0308}", "\u{231A}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] WATCH (ExtPict) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{0300}",
+		&["\u{0904}\u{0300}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) × [9.0] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{0308}\u{0300}",
+		&["\u{0904}\u{0308}\u{0300}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{0900}",
+		&["\u{0904}\u{0900}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{0308}\u{0900}",
+		&["\u{0904}\u{0308}\u{0900}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{094D}",
+		&["\u{0904}\u{094D}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{0308}\u{094D}",
+		&["\u{0904}\u{0308}\u{094D}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{200D}",
+		&["\u{0904}\u{200D}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) × [9.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{0308}\u{200D}",
+		&["\u{0904}\u{0308}\u{200D}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{0378}",
+		&["\u{0904}", "\u{0378}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [999.0] <reserved-0378> (Other) ÷ [0.3]"
+	);
+	grapheme_test("\u{0904}\u{0308}\u{0378}",
+		&["\u{0904}\u{0308}", "\u{0378}"],
+		"  ÷ [0.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] <reserved-0378> (Other) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{0020}",
+		&["\u{0D4E}\u{0020}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.2] SPACE (Other) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{0308}\u{0020}",
+		&["\u{0D4E}\u{0308}", "\u{0020}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] SPACE (Other) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{000D}",
+		&["\u{0D4E}", "\u{000D}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [5.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{0308}\u{000D}",
+		&["\u{0D4E}\u{0308}", "\u{000D}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{000A}",
+		&["\u{0D4E}", "\u{000A}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [5.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{0308}\u{000A}",
+		&["\u{0D4E}\u{0308}", "\u{000A}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{0001}",
+		&["\u{0D4E}", "\u{0001}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [5.0] <START OF HEADING> (Control) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{0308}\u{0001}",
+		&["\u{0D4E}\u{0308}", "\u{0001}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <START OF HEADING> (Control) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{200C}",
+		&["\u{0D4E}\u{200C}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{0308}\u{200C}",
+		&["\u{0D4E}\u{0308}\u{200C}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{1F1E6}",
+		&["\u{0D4E}\u{1F1E6}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{0308}\u{1F1E6}",
+		&["\u{0D4E}\u{0308}", "\u{1F1E6}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{0600}",
+		&["\u{0D4E}\u{0600}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.2] ARABIC NUMBER SIGN (Prepend) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{0308}\u{0600}",
+		&["\u{0D4E}\u{0308}", "\u{0600}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] ARABIC NUMBER SIGN (Prepend) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{0A03}",
+		&["\u{0D4E}\u{0A03}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{0308}\u{0A03}",
+		&["\u{0D4E}\u{0308}\u{0A03}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{1100}",
+		&["\u{0D4E}\u{1100}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.2] HANGUL CHOSEONG KIYEOK (L) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{0308}\u{1100}",
+		&["\u{0D4E}\u{0308}", "\u{1100}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL CHOSEONG KIYEOK (L) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{1160}",
+		&["\u{0D4E}\u{1160}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.2] HANGUL JUNGSEONG FILLER (V) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{0308}\u{1160}",
+		&["\u{0D4E}\u{0308}", "\u{1160}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL JUNGSEONG FILLER (V) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{11A8}",
+		&["\u{0D4E}\u{11A8}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.2] HANGUL JONGSEONG KIYEOK (T) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{0308}\u{11A8}",
+		&["\u{0D4E}\u{0308}", "\u{11A8}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL JONGSEONG KIYEOK (T) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{AC00}",
+		&["\u{0D4E}\u{AC00}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.2] HANGUL SYLLABLE GA (LV) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{0308}\u{AC00}",
+		&["\u{0D4E}\u{0308}", "\u{AC00}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL SYLLABLE GA (LV) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{AC01}",
+		&["\u{0D4E}\u{AC01}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.2] HANGUL SYLLABLE GAG (LVT) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{0308}\u{AC01}",
+		&["\u{0D4E}\u{0308}", "\u{AC01}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL SYLLABLE GAG (LVT) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{0903}",
+		&["\u{0D4E}\u{0903}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{0308}\u{0903}",
+		&["\u{0D4E}\u{0308}\u{0903}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{0904}",
+		&["\u{0D4E}\u{0904}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.2] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{0308}\u{0904}",
+		&["\u{0D4E}\u{0308}", "\u{0904}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{0D4E}",
+		&["\u{0D4E}\u{0D4E}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{0308}\u{0D4E}",
+		&["\u{0D4E}\u{0308}", "\u{0D4E}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{0915}",
+		&["\u{0D4E}\u{0915}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{0308}\u{0915}",
+		&["\u{0D4E}\u{0308}", "\u{0915}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{231A}",
+		&["\u{0D4E}\u{231A}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.2] WATCH (ExtPict) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{0308}\u{231A}",
+		&["\u{0D4E}\u{0308}", "\u{231A}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] WATCH (ExtPict) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{0300}",
+		&["\u{0D4E}\u{0300}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.0] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{0308}\u{0300}",
+		&["\u{0D4E}\u{0308}\u{0300}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{0900}",
+		&["\u{0D4E}\u{0900}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{0308}\u{0900}",
+		&["\u{0D4E}\u{0308}\u{0900}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{094D}",
+		&["\u{0D4E}\u{094D}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{0308}\u{094D}",
+		&["\u{0D4E}\u{0308}\u{094D}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{200D}",
+		&["\u{0D4E}\u{200D}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{0308}\u{200D}",
+		&["\u{0D4E}\u{0308}\u{200D}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{0378}",
+		&["\u{0D4E}\u{0378}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.2] <reserved-0378> (Other) ÷ [0.3]"
+	);
+	grapheme_test("\u{0D4E}\u{0308}\u{0378}",
+		&["\u{0D4E}\u{0308}", "\u{0378}"],
+		"  ÷ [0.2] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] <reserved-0378> (Other) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{0020}",
+		&["\u{0915}", "\u{0020}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [999.0] SPACE (Other) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{0308}\u{0020}",
+		&["\u{0915}\u{0308}", "\u{0020}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] SPACE (Other) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{000D}",
+		&["\u{0915}", "\u{000D}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [5.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{0308}\u{000D}",
+		&["\u{0915}\u{0308}", "\u{000D}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{000A}",
+		&["\u{0915}", "\u{000A}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [5.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{0308}\u{000A}",
+		&["\u{0915}\u{0308}", "\u{000A}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{0001}",
+		&["\u{0915}", "\u{0001}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [5.0] <START OF HEADING> (Control) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{0308}\u{0001}",
+		&["\u{0915}\u{0308}", "\u{0001}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <START OF HEADING> (Control) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{200C}",
+		&["\u{0915}\u{200C}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{0308}\u{200C}",
+		&["\u{0915}\u{0308}\u{200C}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{1F1E6}",
+		&["\u{0915}", "\u{1F1E6}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{0308}\u{1F1E6}",
+		&["\u{0915}\u{0308}", "\u{1F1E6}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{0600}",
+		&["\u{0915}", "\u{0600}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [999.0] ARABIC NUMBER SIGN (Prepend) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{0308}\u{0600}",
+		&["\u{0915}\u{0308}", "\u{0600}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] ARABIC NUMBER SIGN (Prepend) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{0A03}",
+		&["\u{0915}\u{0A03}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{0308}\u{0A03}",
+		&["\u{0915}\u{0308}\u{0A03}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{1100}",
+		&["\u{0915}", "\u{1100}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [999.0] HANGUL CHOSEONG KIYEOK (L) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{0308}\u{1100}",
+		&["\u{0915}\u{0308}", "\u{1100}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL CHOSEONG KIYEOK (L) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{1160}",
+		&["\u{0915}", "\u{1160}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [999.0] HANGUL JUNGSEONG FILLER (V) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{0308}\u{1160}",
+		&["\u{0915}\u{0308}", "\u{1160}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL JUNGSEONG FILLER (V) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{11A8}",
+		&["\u{0915}", "\u{11A8}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [999.0] HANGUL JONGSEONG KIYEOK (T) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{0308}\u{11A8}",
+		&["\u{0915}\u{0308}", "\u{11A8}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL JONGSEONG KIYEOK (T) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{AC00}",
+		&["\u{0915}", "\u{AC00}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [999.0] HANGUL SYLLABLE GA (LV) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{0308}\u{AC00}",
+		&["\u{0915}\u{0308}", "\u{AC00}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL SYLLABLE GA (LV) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{AC01}",
+		&["\u{0915}", "\u{AC01}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [999.0] HANGUL SYLLABLE GAG (LVT) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{0308}\u{AC01}",
+		&["\u{0915}\u{0308}", "\u{AC01}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL SYLLABLE GAG (LVT) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{0903}",
+		&["\u{0915}\u{0903}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{0308}\u{0903}",
+		&["\u{0915}\u{0308}\u{0903}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{0904}",
+		&["\u{0915}", "\u{0904}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{0308}\u{0904}",
+		&["\u{0915}\u{0308}", "\u{0904}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{0D4E}",
+		&["\u{0915}", "\u{0D4E}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{0308}\u{0D4E}",
+		&["\u{0915}\u{0308}", "\u{0D4E}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{0915}",
+		&["\u{0915}", "\u{0915}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{0308}\u{0915}",
+		&["\u{0915}\u{0308}", "\u{0915}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{231A}",
+		&["\u{0915}", "\u{231A}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [999.0] WATCH (ExtPict) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{0308}\u{231A}",
+		&["\u{0915}\u{0308}", "\u{231A}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] WATCH (ExtPict) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{0300}",
+		&["\u{0915}\u{0300}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) × [9.0] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{0308}\u{0300}",
+		&["\u{0915}\u{0308}\u{0300}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{0900}",
+		&["\u{0915}\u{0900}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{0308}\u{0900}",
+		&["\u{0915}\u{0308}\u{0900}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{094D}",
+		&["\u{0915}\u{094D}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{0308}\u{094D}",
+		&["\u{0915}\u{0308}\u{094D}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{200D}",
+		&["\u{0915}\u{200D}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) × [9.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{0308}\u{200D}",
+		&["\u{0915}\u{0308}\u{200D}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{0378}",
+		&["\u{0915}", "\u{0378}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [999.0] <reserved-0378> (Other) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{0308}\u{0378}",
+		&["\u{0915}\u{0308}", "\u{0378}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] <reserved-0378> (Other) ÷ [0.3]"
+	);
 	grapheme_test("\u{231A}\u{0020}",
 		&["\u{231A}", "\u{0020}"],
 		"  ÷ [0.2] WATCH (ExtPict) ÷ [999.0] SPACE (Other) ÷ [0.3]"
@@ -1804,13 +3164,13 @@ fn standard_grapheme_test() {
 		&["\u{231A}\u{0308}", "\u{0001}"],
 		"  ÷ [0.2] WATCH (ExtPict) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <START OF HEADING> (Control) ÷ [0.3]"
 	);
-	grapheme_test("\u{231A}\u{034F}",
-		&["\u{231A}\u{034F}"],
-		"  ÷ [0.2] WATCH (ExtPict) × [9.0] COMBINING GRAPHEME JOINER (Extend) ÷ [0.3]"
+	grapheme_test("\u{231A}\u{200C}",
+		&["\u{231A}\u{200C}"],
+		"  ÷ [0.2] WATCH (ExtPict) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
 	);
-	grapheme_test("\u{231A}\u{0308}\u{034F}",
-		&["\u{231A}\u{0308}\u{034F}"],
-		"  ÷ [0.2] WATCH (ExtPict) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAPHEME JOINER (Extend) ÷ [0.3]"
+	grapheme_test("\u{231A}\u{0308}\u{200C}",
+		&["\u{231A}\u{0308}\u{200C}"],
+		"  ÷ [0.2] WATCH (ExtPict) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
 	);
 	grapheme_test("\u{231A}\u{1F1E6}",
 		&["\u{231A}", "\u{1F1E6}"],
@@ -1828,13 +3188,13 @@ fn standard_grapheme_test() {
 		&["\u{231A}\u{0308}", "\u{0600}"],
 		"  ÷ [0.2] WATCH (ExtPict) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] ARABIC NUMBER SIGN (Prepend) ÷ [0.3]"
 	);
-	grapheme_test("\u{231A}\u{0903}",
-		&["\u{231A}\u{0903}"],
-		"  ÷ [0.2] WATCH (ExtPict) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	grapheme_test("\u{231A}\u{0A03}",
+		&["\u{231A}\u{0A03}"],
+		"  ÷ [0.2] WATCH (ExtPict) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
 	);
-	grapheme_test("\u{231A}\u{0308}\u{0903}",
-		&["\u{231A}\u{0308}\u{0903}"],
-		"  ÷ [0.2] WATCH (ExtPict) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	grapheme_test("\u{231A}\u{0308}\u{0A03}",
+		&["\u{231A}\u{0308}\u{0A03}"],
+		"  ÷ [0.2] WATCH (ExtPict) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
 	);
 	grapheme_test("\u{231A}\u{1100}",
 		&["\u{231A}", "\u{1100}"],
@@ -1876,6 +3236,38 @@ fn standard_grapheme_test() {
 		&["\u{231A}\u{0308}", "\u{AC01}"],
 		"  ÷ [0.2] WATCH (ExtPict) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL SYLLABLE GAG (LVT) ÷ [0.3]"
 	);
+	grapheme_test("\u{231A}\u{0903}",
+		&["\u{231A}\u{0903}"],
+		"  ÷ [0.2] WATCH (ExtPict) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{231A}\u{0308}\u{0903}",
+		&["\u{231A}\u{0308}\u{0903}"],
+		"  ÷ [0.2] WATCH (ExtPict) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{231A}\u{0904}",
+		&["\u{231A}", "\u{0904}"],
+		"  ÷ [0.2] WATCH (ExtPict) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{231A}\u{0308}\u{0904}",
+		&["\u{231A}\u{0308}", "\u{0904}"],
+		"  ÷ [0.2] WATCH (ExtPict) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{231A}\u{0D4E}",
+		&["\u{231A}", "\u{0D4E}"],
+		"  ÷ [0.2] WATCH (ExtPict) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{231A}\u{0308}\u{0D4E}",
+		&["\u{231A}\u{0308}", "\u{0D4E}"],
+		"  ÷ [0.2] WATCH (ExtPict) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{231A}\u{0915}",
+		&["\u{231A}", "\u{0915}"],
+		"  ÷ [0.2] WATCH (ExtPict) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
+	grapheme_test("\u{231A}\u{0308}\u{0915}",
+		&["\u{231A}\u{0308}", "\u{0915}"],
+		"  ÷ [0.2] WATCH (ExtPict) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
 	grapheme_test("\u{231A}\u{231A}",
 		&["\u{231A}", "\u{231A}"],
 		"  ÷ [0.2] WATCH (ExtPict) ÷ [999.0] WATCH (ExtPict) ÷ [0.3]"
@@ -1892,6 +3284,22 @@ fn standard_grapheme_test() {
 		&["\u{231A}\u{0308}\u{0300}"],
 		"  ÷ [0.2] WATCH (ExtPict) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) ÷ [0.3]"
 	);
+	grapheme_test("\u{231A}\u{0900}",
+		&["\u{231A}\u{0900}"],
+		"  ÷ [0.2] WATCH (ExtPict) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{231A}\u{0308}\u{0900}",
+		&["\u{231A}\u{0308}\u{0900}"],
+		"  ÷ [0.2] WATCH (ExtPict) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{231A}\u{094D}",
+		&["\u{231A}\u{094D}"],
+		"  ÷ [0.2] WATCH (ExtPict) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{231A}\u{0308}\u{094D}",
+		&["\u{231A}\u{0308}\u{094D}"],
+		"  ÷ [0.2] WATCH (ExtPict) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
 	grapheme_test("\u{231A}\u{200D}",
 		&["\u{231A}\u{200D}"],
 		"  ÷ [0.2] WATCH (ExtPict) × [9.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [0.3]"
@@ -1940,13 +3348,13 @@ fn standard_grapheme_test() {
 		&["\u{0300}\u{0308}", "\u{0001}"],
 		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <START OF HEADING> (Control) ÷ [0.3]"
 	);
-	grapheme_test("\u{0300}\u{034F}",
-		&["\u{0300}\u{034F}"],
-		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) × [9.0] COMBINING GRAPHEME JOINER (Extend) ÷ [0.3]"
+	grapheme_test("\u{0300}\u{200C}",
+		&["\u{0300}\u{200C}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
 	);
-	grapheme_test("\u{0300}\u{0308}\u{034F}",
-		&["\u{0300}\u{0308}\u{034F}"],
-		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAPHEME JOINER (Extend) ÷ [0.3]"
+	grapheme_test("\u{0300}\u{0308}\u{200C}",
+		&["\u{0300}\u{0308}\u{200C}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
 	);
 	grapheme_test("\u{0300}\u{1F1E6}",
 		&["\u{0300}", "\u{1F1E6}"],
@@ -1964,13 +3372,13 @@ fn standard_grapheme_test() {
 		&["\u{0300}\u{0308}", "\u{0600}"],
 		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] ARABIC NUMBER SIGN (Prepend) ÷ [0.3]"
 	);
-	grapheme_test("\u{0300}\u{0903}",
-		&["\u{0300}\u{0903}"],
-		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	grapheme_test("\u{0300}\u{0A03}",
+		&["\u{0300}\u{0A03}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
 	);
-	grapheme_test("\u{0300}\u{0308}\u{0903}",
-		&["\u{0300}\u{0308}\u{0903}"],
-		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	grapheme_test("\u{0300}\u{0308}\u{0A03}",
+		&["\u{0300}\u{0308}\u{0A03}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
 	);
 	grapheme_test("\u{0300}\u{1100}",
 		&["\u{0300}", "\u{1100}"],
@@ -2012,6 +3420,38 @@ fn standard_grapheme_test() {
 		&["\u{0300}\u{0308}", "\u{AC01}"],
 		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL SYLLABLE GAG (LVT) ÷ [0.3]"
 	);
+	grapheme_test("\u{0300}\u{0903}",
+		&["\u{0300}\u{0903}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0300}\u{0308}\u{0903}",
+		&["\u{0300}\u{0308}\u{0903}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0300}\u{0904}",
+		&["\u{0300}", "\u{0904}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0300}\u{0308}\u{0904}",
+		&["\u{0300}\u{0308}", "\u{0904}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0300}\u{0D4E}",
+		&["\u{0300}", "\u{0D4E}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0300}\u{0308}\u{0D4E}",
+		&["\u{0300}\u{0308}", "\u{0D4E}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0300}\u{0915}",
+		&["\u{0300}", "\u{0915}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
+	grapheme_test("\u{0300}\u{0308}\u{0915}",
+		&["\u{0300}\u{0308}", "\u{0915}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
 	grapheme_test("\u{0300}\u{231A}",
 		&["\u{0300}", "\u{231A}"],
 		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) ÷ [999.0] WATCH (ExtPict) ÷ [0.3]"
@@ -2028,6 +3468,22 @@ fn standard_grapheme_test() {
 		&["\u{0300}\u{0308}\u{0300}"],
 		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) ÷ [0.3]"
 	);
+	grapheme_test("\u{0300}\u{0900}",
+		&["\u{0300}\u{0900}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0300}\u{0308}\u{0900}",
+		&["\u{0300}\u{0308}\u{0900}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0300}\u{094D}",
+		&["\u{0300}\u{094D}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0300}\u{0308}\u{094D}",
+		&["\u{0300}\u{0308}\u{094D}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
 	grapheme_test("\u{0300}\u{200D}",
 		&["\u{0300}\u{200D}"],
 		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) × [9.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [0.3]"
@@ -2044,6 +3500,374 @@ fn standard_grapheme_test() {
 		&["\u{0300}\u{0308}", "\u{0378}"],
 		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] <reserved-0378> (Other) ÷ [0.3]"
 	);
+	grapheme_test("\u{0900}\u{0020}",
+		&["\u{0900}", "\u{0020}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [999.0] SPACE (Other) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{0308}\u{0020}",
+		&["\u{0900}\u{0308}", "\u{0020}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] SPACE (Other) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{000D}",
+		&["\u{0900}", "\u{000D}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [5.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{0308}\u{000D}",
+		&["\u{0900}\u{0308}", "\u{000D}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{000A}",
+		&["\u{0900}", "\u{000A}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [5.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{0308}\u{000A}",
+		&["\u{0900}\u{0308}", "\u{000A}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{0001}",
+		&["\u{0900}", "\u{0001}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [5.0] <START OF HEADING> (Control) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{0308}\u{0001}",
+		&["\u{0900}\u{0308}", "\u{0001}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <START OF HEADING> (Control) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{200C}",
+		&["\u{0900}\u{200C}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{0308}\u{200C}",
+		&["\u{0900}\u{0308}\u{200C}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{1F1E6}",
+		&["\u{0900}", "\u{1F1E6}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{0308}\u{1F1E6}",
+		&["\u{0900}\u{0308}", "\u{1F1E6}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{0600}",
+		&["\u{0900}", "\u{0600}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [999.0] ARABIC NUMBER SIGN (Prepend) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{0308}\u{0600}",
+		&["\u{0900}\u{0308}", "\u{0600}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] ARABIC NUMBER SIGN (Prepend) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{0A03}",
+		&["\u{0900}\u{0A03}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{0308}\u{0A03}",
+		&["\u{0900}\u{0308}\u{0A03}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{1100}",
+		&["\u{0900}", "\u{1100}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [999.0] HANGUL CHOSEONG KIYEOK (L) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{0308}\u{1100}",
+		&["\u{0900}\u{0308}", "\u{1100}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL CHOSEONG KIYEOK (L) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{1160}",
+		&["\u{0900}", "\u{1160}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [999.0] HANGUL JUNGSEONG FILLER (V) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{0308}\u{1160}",
+		&["\u{0900}\u{0308}", "\u{1160}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL JUNGSEONG FILLER (V) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{11A8}",
+		&["\u{0900}", "\u{11A8}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [999.0] HANGUL JONGSEONG KIYEOK (T) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{0308}\u{11A8}",
+		&["\u{0900}\u{0308}", "\u{11A8}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL JONGSEONG KIYEOK (T) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{AC00}",
+		&["\u{0900}", "\u{AC00}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [999.0] HANGUL SYLLABLE GA (LV) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{0308}\u{AC00}",
+		&["\u{0900}\u{0308}", "\u{AC00}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL SYLLABLE GA (LV) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{AC01}",
+		&["\u{0900}", "\u{AC01}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [999.0] HANGUL SYLLABLE GAG (LVT) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{0308}\u{AC01}",
+		&["\u{0900}\u{0308}", "\u{AC01}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL SYLLABLE GAG (LVT) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{0903}",
+		&["\u{0900}\u{0903}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{0308}\u{0903}",
+		&["\u{0900}\u{0308}\u{0903}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{0904}",
+		&["\u{0900}", "\u{0904}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{0308}\u{0904}",
+		&["\u{0900}\u{0308}", "\u{0904}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{0D4E}",
+		&["\u{0900}", "\u{0D4E}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{0308}\u{0D4E}",
+		&["\u{0900}\u{0308}", "\u{0D4E}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{0915}",
+		&["\u{0900}", "\u{0915}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{0308}\u{0915}",
+		&["\u{0900}\u{0308}", "\u{0915}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{231A}",
+		&["\u{0900}", "\u{231A}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [999.0] WATCH (ExtPict) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{0308}\u{231A}",
+		&["\u{0900}\u{0308}", "\u{231A}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] WATCH (ExtPict) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{0300}",
+		&["\u{0900}\u{0300}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) × [9.0] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{0308}\u{0300}",
+		&["\u{0900}\u{0308}\u{0300}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{0900}",
+		&["\u{0900}\u{0900}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{0308}\u{0900}",
+		&["\u{0900}\u{0308}\u{0900}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{094D}",
+		&["\u{0900}\u{094D}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{0308}\u{094D}",
+		&["\u{0900}\u{0308}\u{094D}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{200D}",
+		&["\u{0900}\u{200D}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) × [9.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{0308}\u{200D}",
+		&["\u{0900}\u{0308}\u{200D}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{0378}",
+		&["\u{0900}", "\u{0378}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [999.0] <reserved-0378> (Other) ÷ [0.3]"
+	);
+	grapheme_test("\u{0900}\u{0308}\u{0378}",
+		&["\u{0900}\u{0308}", "\u{0378}"],
+		"  ÷ [0.2] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] <reserved-0378> (Other) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{0020}",
+		&["\u{094D}", "\u{0020}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [999.0] SPACE (Other) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{0308}\u{0020}",
+		&["\u{094D}\u{0308}", "\u{0020}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] SPACE (Other) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{000D}",
+		&["\u{094D}", "\u{000D}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [5.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{0308}\u{000D}",
+		&["\u{094D}\u{0308}", "\u{000D}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{000A}",
+		&["\u{094D}", "\u{000A}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [5.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{0308}\u{000A}",
+		&["\u{094D}\u{0308}", "\u{000A}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{0001}",
+		&["\u{094D}", "\u{0001}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [5.0] <START OF HEADING> (Control) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{0308}\u{0001}",
+		&["\u{094D}\u{0308}", "\u{0001}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <START OF HEADING> (Control) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{200C}",
+		&["\u{094D}\u{200C}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{0308}\u{200C}",
+		&["\u{094D}\u{0308}\u{200C}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{1F1E6}",
+		&["\u{094D}", "\u{1F1E6}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{0308}\u{1F1E6}",
+		&["\u{094D}\u{0308}", "\u{1F1E6}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{0600}",
+		&["\u{094D}", "\u{0600}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [999.0] ARABIC NUMBER SIGN (Prepend) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{0308}\u{0600}",
+		&["\u{094D}\u{0308}", "\u{0600}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] ARABIC NUMBER SIGN (Prepend) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{0A03}",
+		&["\u{094D}\u{0A03}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{0308}\u{0A03}",
+		&["\u{094D}\u{0308}\u{0A03}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{1100}",
+		&["\u{094D}", "\u{1100}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [999.0] HANGUL CHOSEONG KIYEOK (L) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{0308}\u{1100}",
+		&["\u{094D}\u{0308}", "\u{1100}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL CHOSEONG KIYEOK (L) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{1160}",
+		&["\u{094D}", "\u{1160}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [999.0] HANGUL JUNGSEONG FILLER (V) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{0308}\u{1160}",
+		&["\u{094D}\u{0308}", "\u{1160}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL JUNGSEONG FILLER (V) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{11A8}",
+		&["\u{094D}", "\u{11A8}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [999.0] HANGUL JONGSEONG KIYEOK (T) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{0308}\u{11A8}",
+		&["\u{094D}\u{0308}", "\u{11A8}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL JONGSEONG KIYEOK (T) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{AC00}",
+		&["\u{094D}", "\u{AC00}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [999.0] HANGUL SYLLABLE GA (LV) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{0308}\u{AC00}",
+		&["\u{094D}\u{0308}", "\u{AC00}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL SYLLABLE GA (LV) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{AC01}",
+		&["\u{094D}", "\u{AC01}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [999.0] HANGUL SYLLABLE GAG (LVT) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{0308}\u{AC01}",
+		&["\u{094D}\u{0308}", "\u{AC01}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL SYLLABLE GAG (LVT) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{0903}",
+		&["\u{094D}\u{0903}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{0308}\u{0903}",
+		&["\u{094D}\u{0308}\u{0903}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{0904}",
+		&["\u{094D}", "\u{0904}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{0308}\u{0904}",
+		&["\u{094D}\u{0308}", "\u{0904}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{0D4E}",
+		&["\u{094D}", "\u{0D4E}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{0308}\u{0D4E}",
+		&["\u{094D}\u{0308}", "\u{0D4E}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{0915}",
+		&["\u{094D}", "\u{0915}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{0308}\u{0915}",
+		&["\u{094D}\u{0308}", "\u{0915}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{231A}",
+		&["\u{094D}", "\u{231A}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [999.0] WATCH (ExtPict) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{0308}\u{231A}",
+		&["\u{094D}\u{0308}", "\u{231A}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] WATCH (ExtPict) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{0300}",
+		&["\u{094D}\u{0300}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) × [9.0] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{0308}\u{0300}",
+		&["\u{094D}\u{0308}\u{0300}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{0900}",
+		&["\u{094D}\u{0900}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{0308}\u{0900}",
+		&["\u{094D}\u{0308}\u{0900}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{094D}",
+		&["\u{094D}\u{094D}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{0308}\u{094D}",
+		&["\u{094D}\u{0308}\u{094D}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{200D}",
+		&["\u{094D}\u{200D}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) × [9.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{0308}\u{200D}",
+		&["\u{094D}\u{0308}\u{200D}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{0378}",
+		&["\u{094D}", "\u{0378}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [999.0] <reserved-0378> (Other) ÷ [0.3]"
+	);
+	grapheme_test("\u{094D}\u{0308}\u{0378}",
+		&["\u{094D}\u{0308}", "\u{0378}"],
+		"  ÷ [0.2] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] <reserved-0378> (Other) ÷ [0.3]"
+	);
 	grapheme_test("\u{200D}\u{0020}",
 		&["\u{200D}", "\u{0020}"],
 		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [999.0] SPACE (Other) ÷ [0.3]"
@@ -2076,13 +3900,13 @@ fn standard_grapheme_test() {
 		&["\u{200D}\u{0308}", "\u{0001}"],
 		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <START OF HEADING> (Control) ÷ [0.3]"
 	);
-	grapheme_test("\u{200D}\u{034F}",
-		&["\u{200D}\u{034F}"],
-		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) × [9.0] COMBINING GRAPHEME JOINER (Extend) ÷ [0.3]"
+	grapheme_test("\u{200D}\u{200C}",
+		&["\u{200D}\u{200C}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
 	);
-	grapheme_test("\u{200D}\u{0308}\u{034F}",
-		&["\u{200D}\u{0308}\u{034F}"],
-		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAPHEME JOINER (Extend) ÷ [0.3]"
+	grapheme_test("\u{200D}\u{0308}\u{200C}",
+		&["\u{200D}\u{0308}\u{200C}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
 	);
 	grapheme_test("\u{200D}\u{1F1E6}",
 		&["\u{200D}", "\u{1F1E6}"],
@@ -2100,13 +3924,13 @@ fn standard_grapheme_test() {
 		&["\u{200D}\u{0308}", "\u{0600}"],
 		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] ARABIC NUMBER SIGN (Prepend) ÷ [0.3]"
 	);
-	grapheme_test("\u{200D}\u{0903}",
-		&["\u{200D}\u{0903}"],
-		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	grapheme_test("\u{200D}\u{0A03}",
+		&["\u{200D}\u{0A03}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
 	);
-	grapheme_test("\u{200D}\u{0308}\u{0903}",
-		&["\u{200D}\u{0308}\u{0903}"],
-		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	grapheme_test("\u{200D}\u{0308}\u{0A03}",
+		&["\u{200D}\u{0308}\u{0A03}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
 	);
 	grapheme_test("\u{200D}\u{1100}",
 		&["\u{200D}", "\u{1100}"],
@@ -2148,6 +3972,38 @@ fn standard_grapheme_test() {
 		&["\u{200D}\u{0308}", "\u{AC01}"],
 		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL SYLLABLE GAG (LVT) ÷ [0.3]"
 	);
+	grapheme_test("\u{200D}\u{0903}",
+		&["\u{200D}\u{0903}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{200D}\u{0308}\u{0903}",
+		&["\u{200D}\u{0308}\u{0903}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{200D}\u{0904}",
+		&["\u{200D}", "\u{0904}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{200D}\u{0308}\u{0904}",
+		&["\u{200D}\u{0308}", "\u{0904}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{200D}\u{0D4E}",
+		&["\u{200D}", "\u{0D4E}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{200D}\u{0308}\u{0D4E}",
+		&["\u{200D}\u{0308}", "\u{0D4E}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{200D}\u{0915}",
+		&["\u{200D}", "\u{0915}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
+	grapheme_test("\u{200D}\u{0308}\u{0915}",
+		&["\u{200D}\u{0308}", "\u{0915}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
 	grapheme_test("\u{200D}\u{231A}",
 		&["\u{200D}", "\u{231A}"],
 		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [999.0] WATCH (ExtPict) ÷ [0.3]"
@@ -2164,6 +4020,22 @@ fn standard_grapheme_test() {
 		&["\u{200D}\u{0308}\u{0300}"],
 		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) ÷ [0.3]"
 	);
+	grapheme_test("\u{200D}\u{0900}",
+		&["\u{200D}\u{0900}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{200D}\u{0308}\u{0900}",
+		&["\u{200D}\u{0308}\u{0900}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{200D}\u{094D}",
+		&["\u{200D}\u{094D}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{200D}\u{0308}\u{094D}",
+		&["\u{200D}\u{0308}\u{094D}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
 	grapheme_test("\u{200D}\u{200D}",
 		&["\u{200D}\u{200D}"],
 		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) × [9.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [0.3]"
@@ -2212,13 +4084,13 @@ fn standard_grapheme_test() {
 		&["\u{0378}\u{0308}", "\u{0001}"],
 		"  ÷ [0.2] <reserved-0378> (Other) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [5.0] <START OF HEADING> (Control) ÷ [0.3]"
 	);
-	grapheme_test("\u{0378}\u{034F}",
-		&["\u{0378}\u{034F}"],
-		"  ÷ [0.2] <reserved-0378> (Other) × [9.0] COMBINING GRAPHEME JOINER (Extend) ÷ [0.3]"
+	grapheme_test("\u{0378}\u{200C}",
+		&["\u{0378}\u{200C}"],
+		"  ÷ [0.2] <reserved-0378> (Other) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
 	);
-	grapheme_test("\u{0378}\u{0308}\u{034F}",
-		&["\u{0378}\u{0308}\u{034F}"],
-		"  ÷ [0.2] <reserved-0378> (Other) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAPHEME JOINER (Extend) ÷ [0.3]"
+	grapheme_test("\u{0378}\u{0308}\u{200C}",
+		&["\u{0378}\u{0308}\u{200C}"],
+		"  ÷ [0.2] <reserved-0378> (Other) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] ZERO WIDTH NON-JOINER (Extend) ÷ [0.3]"
 	);
 	grapheme_test("\u{0378}\u{1F1E6}",
 		&["\u{0378}", "\u{1F1E6}"],
@@ -2236,13 +4108,13 @@ fn standard_grapheme_test() {
 		&["\u{0378}\u{0308}", "\u{0600}"],
 		"  ÷ [0.2] <reserved-0378> (Other) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] ARABIC NUMBER SIGN (Prepend) ÷ [0.3]"
 	);
-	grapheme_test("\u{0378}\u{0903}",
-		&["\u{0378}\u{0903}"],
-		"  ÷ [0.2] <reserved-0378> (Other) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	grapheme_test("\u{0378}\u{0A03}",
+		&["\u{0378}\u{0A03}"],
+		"  ÷ [0.2] <reserved-0378> (Other) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
 	);
-	grapheme_test("\u{0378}\u{0308}\u{0903}",
-		&["\u{0378}\u{0308}\u{0903}"],
-		"  ÷ [0.2] <reserved-0378> (Other) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [0.3]"
+	grapheme_test("\u{0378}\u{0308}\u{0A03}",
+		&["\u{0378}\u{0308}\u{0A03}"],
+		"  ÷ [0.2] <reserved-0378> (Other) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] GURMUKHI SIGN VISARGA (SpacingMark) ÷ [0.3]"
 	);
 	grapheme_test("\u{0378}\u{1100}",
 		&["\u{0378}", "\u{1100}"],
@@ -2284,6 +4156,38 @@ fn standard_grapheme_test() {
 		&["\u{0378}\u{0308}", "\u{AC01}"],
 		"  ÷ [0.2] <reserved-0378> (Other) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] HANGUL SYLLABLE GAG (LVT) ÷ [0.3]"
 	);
+	grapheme_test("\u{0378}\u{0903}",
+		&["\u{0378}\u{0903}"],
+		"  ÷ [0.2] <reserved-0378> (Other) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0378}\u{0308}\u{0903}",
+		&["\u{0378}\u{0308}\u{0903}"],
+		"  ÷ [0.2] <reserved-0378> (Other) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0378}\u{0904}",
+		&["\u{0378}", "\u{0904}"],
+		"  ÷ [0.2] <reserved-0378> (Other) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0378}\u{0308}\u{0904}",
+		&["\u{0378}\u{0308}", "\u{0904}"],
+		"  ÷ [0.2] <reserved-0378> (Other) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER SHORT A (ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0378}\u{0D4E}",
+		&["\u{0378}", "\u{0D4E}"],
+		"  ÷ [0.2] <reserved-0378> (Other) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0378}\u{0308}\u{0D4E}",
+		&["\u{0378}\u{0308}", "\u{0D4E}"],
+		"  ÷ [0.2] <reserved-0378> (Other) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] MALAYALAM LETTER DOT REPH (Prepend_ConjunctLinkingScripts) ÷ [0.3]"
+	);
+	grapheme_test("\u{0378}\u{0915}",
+		&["\u{0378}", "\u{0915}"],
+		"  ÷ [0.2] <reserved-0378> (Other) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
+	grapheme_test("\u{0378}\u{0308}\u{0915}",
+		&["\u{0378}\u{0308}", "\u{0915}"],
+		"  ÷ [0.2] <reserved-0378> (Other) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
 	grapheme_test("\u{0378}\u{231A}",
 		&["\u{0378}", "\u{231A}"],
 		"  ÷ [0.2] <reserved-0378> (Other) ÷ [999.0] WATCH (ExtPict) ÷ [0.3]"
@@ -2300,6 +4204,22 @@ fn standard_grapheme_test() {
 		&["\u{0378}\u{0308}\u{0300}"],
 		"  ÷ [0.2] <reserved-0378> (Other) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] COMBINING GRAVE ACCENT (Extend_ExtCccZwj) ÷ [0.3]"
 	);
+	grapheme_test("\u{0378}\u{0900}",
+		&["\u{0378}\u{0900}"],
+		"  ÷ [0.2] <reserved-0378> (Other) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0378}\u{0308}\u{0900}",
+		&["\u{0378}\u{0308}\u{0900}"],
+		"  ÷ [0.2] <reserved-0378> (Other) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN INVERTED CANDRABINDU (Extend_ConjunctLinkingScripts_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0378}\u{094D}",
+		&["\u{0378}\u{094D}"],
+		"  ÷ [0.2] <reserved-0378> (Other) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
+	grapheme_test("\u{0378}\u{0308}\u{094D}",
+		&["\u{0378}\u{0308}\u{094D}"],
+		"  ÷ [0.2] <reserved-0378> (Other) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [0.3]"
+	);
 	grapheme_test("\u{0378}\u{200D}",
 		&["\u{0378}\u{200D}"],
 		"  ÷ [0.2] <reserved-0378> (Other) × [9.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [0.3]"
@@ -2374,7 +4294,7 @@ fn standard_grapheme_test() {
 	);
 	grapheme_test("\u{0061}\u{0903}\u{0062}",
 		&["\u{0061}\u{0903}", "\u{0062}"],
-		"  ÷ [0.2] LATIN SMALL LETTER A (Other) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark) ÷ [999.0] LATIN SMALL LETTER B (Other) ÷ [0.3]"
+		"  ÷ [0.2] LATIN SMALL LETTER A (Other) × [9.1] DEVANAGARI SIGN VISARGA (SpacingMark_ConjunctLinkingScripts) ÷ [999.0] LATIN SMALL LETTER B (Other) ÷ [0.3]"
 	);
 	grapheme_test("\u{0061}\u{0600}\u{0062}",
 		&["\u{0061}", "\u{0600}\u{0062}"],
@@ -2382,19 +4302,19 @@ fn standard_grapheme_test() {
 	);
 	grapheme_test("\u{1F476}\u{1F3FF}\u{1F476}",
 		&["\u{1F476}\u{1F3FF}", "\u{1F476}"],
-		"  ÷ [0.2] BABY (ExtPict) × [9.0] EMOJI MODIFIER FITZPATRICK TYPE-6 (Extend) ÷ [999.0] BABY (ExtPict) ÷ [0.3]"
+		"  ÷ [0.2] BABY (ExtPict) × [9.0] EMOJI MODIFIER FITZPATRICK TYPE-6 (Extend_ExtCccZwj) ÷ [999.0] BABY (ExtPict) ÷ [0.3]"
 	);
 	grapheme_test("\u{0061}\u{1F3FF}\u{1F476}",
 		&["\u{0061}\u{1F3FF}", "\u{1F476}"],
-		"  ÷ [0.2] LATIN SMALL LETTER A (Other) × [9.0] EMOJI MODIFIER FITZPATRICK TYPE-6 (Extend) ÷ [999.0] BABY (ExtPict) ÷ [0.3]"
+		"  ÷ [0.2] LATIN SMALL LETTER A (Other) × [9.0] EMOJI MODIFIER FITZPATRICK TYPE-6 (Extend_ExtCccZwj) ÷ [999.0] BABY (ExtPict) ÷ [0.3]"
 	);
 	grapheme_test("\u{0061}\u{1F3FF}\u{1F476}\u{200D}\u{1F6D1}",
 		&["\u{0061}\u{1F3FF}", "\u{1F476}\u{200D}\u{1F6D1}"],
-		"  ÷ [0.2] LATIN SMALL LETTER A (Other) × [9.0] EMOJI MODIFIER FITZPATRICK TYPE-6 (Extend) ÷ [999.0] BABY (ExtPict) × [9.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) × [11.0] OCTAGONAL SIGN (ExtPict) ÷ [0.3]"
+		"  ÷ [0.2] LATIN SMALL LETTER A (Other) × [9.0] EMOJI MODIFIER FITZPATRICK TYPE-6 (Extend_ExtCccZwj) ÷ [999.0] BABY (ExtPict) × [9.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) × [11.0] OCTAGONAL SIGN (ExtPict) ÷ [0.3]"
 	);
 	grapheme_test("\u{1F476}\u{1F3FF}\u{0308}\u{200D}\u{1F476}\u{1F3FF}",
 		&["\u{1F476}\u{1F3FF}\u{0308}\u{200D}\u{1F476}\u{1F3FF}"],
-		"  ÷ [0.2] BABY (ExtPict) × [9.0] EMOJI MODIFIER FITZPATRICK TYPE-6 (Extend) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) × [11.0] BABY (ExtPict) × [9.0] EMOJI MODIFIER FITZPATRICK TYPE-6 (Extend) ÷ [0.3]"
+		"  ÷ [0.2] BABY (ExtPict) × [9.0] EMOJI MODIFIER FITZPATRICK TYPE-6 (Extend_ExtCccZwj) × [9.0] COMBINING DIAERESIS (Extend_ExtCccZwj) × [9.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) × [11.0] BABY (ExtPict) × [9.0] EMOJI MODIFIER FITZPATRICK TYPE-6 (Extend_ExtCccZwj) ÷ [0.3]"
 	);
 	grapheme_test("\u{1F6D1}\u{200D}\u{1F6D1}",
 		&["\u{1F6D1}\u{200D}\u{1F6D1}"],
@@ -2412,4 +4332,48 @@ fn standard_grapheme_test() {
 		&["\u{0061}\u{200D}", "\u{2701}"],
 		"  ÷ [0.2] LATIN SMALL LETTER A (Other) × [9.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) ÷ [999.0] UPPER BLADE SCISSORS (Other) ÷ [0.3]"
 	);
+	grapheme_test("\u{0915}\u{0924}",
+		&["\u{0915}", "\u{0924}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) ÷ [999.0] DEVANAGARI LETTER TA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{094D}\u{0924}",
+		&["\u{0915}\u{094D}\u{0924}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) × [9.3] DEVANAGARI LETTER TA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{094D}\u{094D}\u{0924}",
+		&["\u{0915}\u{094D}\u{094D}\u{0924}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) × [9.3] DEVANAGARI LETTER TA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{094D}\u{200D}\u{0924}",
+		&["\u{0915}\u{094D}\u{200D}\u{0924}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) × [9.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) × [9.3] DEVANAGARI LETTER TA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{093C}\u{200D}\u{094D}\u{0924}",
+		&["\u{0915}\u{093C}\u{200D}\u{094D}\u{0924}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) × [9.0] DEVANAGARI SIGN NUKTA (Extend_ConjunctLinkingScripts_ExtCccZwj) × [9.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) × [9.3] DEVANAGARI LETTER TA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{093C}\u{094D}\u{200D}\u{0924}",
+		&["\u{0915}\u{093C}\u{094D}\u{200D}\u{0924}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) × [9.0] DEVANAGARI SIGN NUKTA (Extend_ConjunctLinkingScripts_ExtCccZwj) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) × [9.0] ZERO WIDTH JOINER (ZWJ_ExtCccZwj) × [9.3] DEVANAGARI LETTER TA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{094D}\u{0924}\u{094D}\u{092F}",
+		&["\u{0915}\u{094D}\u{0924}\u{094D}\u{092F}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) × [9.3] DEVANAGARI LETTER TA (ConjunctLinkingScripts_LinkingConsonant) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) × [9.3] DEVANAGARI LETTER YA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{094D}\u{0061}",
+		&["\u{0915}\u{094D}", "\u{0061}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [999.0] LATIN SMALL LETTER A (Other) ÷ [0.3]"
+	);
+	grapheme_test("\u{0061}\u{094D}\u{0924}",
+		&["\u{0061}\u{094D}", "\u{0924}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (Other) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER TA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
+	grapheme_test("\u{003F}\u{094D}\u{0924}",
+		&["\u{003F}\u{094D}", "\u{0924}"],
+		"  ÷ [0.2] QUESTION MARK (Other) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) ÷ [999.0] DEVANAGARI LETTER TA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
+	grapheme_test("\u{0915}\u{094D}\u{094D}\u{0924}",
+		&["\u{0915}\u{094D}\u{094D}\u{0924}"],
+		"  ÷ [0.2] DEVANAGARI LETTER KA (ConjunctLinkingScripts_LinkingConsonant) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) × [9.0] DEVANAGARI SIGN VIRAMA (Extend_ConjunctLinkingScripts_ConjunctLinker_ExtCccZwj) × [9.3] DEVANAGARI LETTER TA (ConjunctLinkingScripts_LinkingConsonant) ÷ [0.3]"
+	);
 }
@@ -0,0 +1,39445 @@
+// GENERATED CODE DO NOT MANUALLY EDIT
+pub const ID_TABLE: [u8;0x1100] = [
+	 0, // 0x0
+	 1, // 0x1
+	 2, // 0x2
+	 3, // 0x3
+	 4, // 0x4
+	 5, // 0x5
+	 6, // 0x6
+	 7, // 0x7
+	 8, // 0x8
+	 9, // 0x9
+	 10, // 0xa
+	 11, // 0xb
+	 12, // 0xc
+	 13, // 0xd
+	 14, // 0xe
+	 15, // 0xf
+	 16, // 0x10
+	 1, // 0x11
+	 17, // 0x12
+	 18, // 0x13
+	 19, // 0x14
+	 1, // 0x15
+	 20, // 0x16
+	 21, // 0x17
+	 22, // 0x18
+	 23, // 0x19
+	 24, // 0x1a
+	 25, // 0x1b
+	 26, // 0x1c
+	 27, // 0x1d
+	 1, // 0x1e
+	 28, // 0x1f
+	 29, // 0x20
+	 30, // 0x21
+	 31, // 0x22
+	 31, // 0x23
+	 32, // 0x24
+	 31, // 0x25
+	 31, // 0x26
+	 33, // 0x27
+	 31, // 0x28
+	 31, // 0x29
+	 31, // 0x2a
+	 34, // 0x2b
+	 35, // 0x2c
+	 36, // 0x2d
+	 37, // 0x2e
+	 38, // 0x2f
+	 39, // 0x30
+	 40, // 0x31
+	 38, // 0x32
+	 38, // 0x33
+	 1, // 0x34
+	 1, // 0x35
+	 1, // 0x36
+	 1, // 0x37
+	 1, // 0x38
+	 1, // 0x39
+	 1, // 0x3a
+	 1, // 0x3b
+	 1, // 0x3c
+	 1, // 0x3d
+	 1, // 0x3e
+	 1, // 0x3f
+	 1, // 0x40
+	 1, // 0x41
+	 1, // 0x42
+	 1, // 0x43
+	 1, // 0x44
+	 1, // 0x45
+	 1, // 0x46
+	 1, // 0x47
+	 1, // 0x48
+	 1, // 0x49
+	 1, // 0x4a
+	 1, // 0x4b
+	 1, // 0x4c
+	 41, // 0x4d
+	 1, // 0x4e
+	 1, // 0x4f
+	 1, // 0x50
+	 1, // 0x51
+	 1, // 0x52
+	 1, // 0x53
+	 1, // 0x54
+	 1, // 0x55
+	 1, // 0x56
+	 1, // 0x57
+	 1, // 0x58
+	 1, // 0x59
+	 1, // 0x5a
+	 1, // 0x5b
+	 1, // 0x5c
+	 1, // 0x5d
+	 1, // 0x5e
+	 1, // 0x5f
+	 1, // 0x60
+	 1, // 0x61
+	 1, // 0x62
+	 1, // 0x63
+	 1, // 0x64
+	 1, // 0x65
+	 1, // 0x66
+	 1, // 0x67
+	 1, // 0x68
+	 1, // 0x69
+	 1, // 0x6a
+	 1, // 0x6b
+	 1, // 0x6c
+	 1, // 0x6d
+	 1, // 0x6e
+	 1, // 0x6f
+	 1, // 0x70
+	 1, // 0x71
+	 1, // 0x72
+	 1, // 0x73
+	 1, // 0x74
+	 1, // 0x75
+	 1, // 0x76
+	 1, // 0x77
+	 1, // 0x78
+	 1, // 0x79
+	 1, // 0x7a
+	 1, // 0x7b
+	 1, // 0x7c
+	 1, // 0x7d
+	 1, // 0x7e
+	 1, // 0x7f
+	 1, // 0x80
+	 1, // 0x81
+	 1, // 0x82
+	 1, // 0x83
+	 1, // 0x84
+	 1, // 0x85
+	 1, // 0x86
+	 1, // 0x87
+	 1, // 0x88
+	 1, // 0x89
+	 1, // 0x8a
+	 1, // 0x8b
+	 1, // 0x8c
+	 1, // 0x8d
+	 1, // 0x8e
+	 1, // 0x8f
+	 1, // 0x90
+	 1, // 0x91
+	 1, // 0x92
+	 1, // 0x93
+	 1, // 0x94
+	 1, // 0x95
+	 1, // 0x96
+	 1, // 0x97
+	 1, // 0x98
+	 1, // 0x99
+	 1, // 0x9a
+	 1, // 0x9b
+	 1, // 0x9c
+	 1, // 0x9d
+	 1, // 0x9e
+	 1, // 0x9f
+	 1, // 0xa0
+	 1, // 0xa1
+	 1, // 0xa2
+	 1, // 0xa3
+	 42, // 0xa4
+	 1, // 0xa5
+	 43, // 0xa6
+	 44, // 0xa7
+	 45, // 0xa8
+	 46, // 0xa9
+	 47, // 0xaa
+	 48, // 0xab
+	 1, // 0xac
+	 1, // 0xad
+	 1, // 0xae
+	 1, // 0xaf
+	 1, // 0xb0
+	 1, // 0xb1
+	 1, // 0xb2
+	 1, // 0xb3
+	 1, // 0xb4
+	 1, // 0xb5
+	 1, // 0xb6
+	 1, // 0xb7
+	 1, // 0xb8
+	 1, // 0xb9
+	 1, // 0xba
+	 1, // 0xbb
+	 1, // 0xbc
+	 1, // 0xbd
+	 1, // 0xbe
+	 1, // 0xbf
+	 1, // 0xc0
+	 1, // 0xc1
+	 1, // 0xc2
+	 1, // 0xc3
+	 1, // 0xc4
+	 1, // 0xc5
+	 1, // 0xc6
+	 1, // 0xc7
+	 1, // 0xc8
+	 1, // 0xc9
+	 1, // 0xca
+	 1, // 0xcb
+	 1, // 0xcc
+	 1, // 0xcd
+	 1, // 0xce
+	 1, // 0xcf
+	 1, // 0xd0
+	 1, // 0xd1
+	 1, // 0xd2
+	 1, // 0xd3
+	 1, // 0xd4
+	 1, // 0xd5
+	 1, // 0xd6
+	 49, // 0xd7
+	 38, // 0xd8
+	 38, // 0xd9
+	 38, // 0xda
+	 38, // 0xdb
+	 38, // 0xdc
+	 38, // 0xdd
+	 38, // 0xde
+	 38, // 0xdf
+	 38, // 0xe0
+	 38, // 0xe1
+	 38, // 0xe2
+	 38, // 0xe3
+	 38, // 0xe4
+	 38, // 0xe5
+	 38, // 0xe6
+	 38, // 0xe7
+	 38, // 0xe8
+	 38, // 0xe9
+	 38, // 0xea
+	 38, // 0xeb
+	 38, // 0xec
+	 38, // 0xed
+	 38, // 0xee
+	 38, // 0xef
+	 38, // 0xf0
+	 38, // 0xf1
+	 38, // 0xf2
+	 38, // 0xf3
+	 38, // 0xf4
+	 38, // 0xf5
+	 38, // 0xf6
+	 38, // 0xf7
+	 38, // 0xf8
+	 1, // 0xf9
+	 50, // 0xfa
+	 51, // 0xfb
+	 52, // 0xfc
+	 53, // 0xfd
+	 54, // 0xfe
+	 55, // 0xff
+	 56, // 0x100
+	 57, // 0x101
+	 58, // 0x102
+	 59, // 0x103
+	 60, // 0x104
+	 61, // 0x105
+	 1, // 0x106
+	 62, // 0x107
+	 63, // 0x108
+	 64, // 0x109
+	 65, // 0x10a
+	 66, // 0x10b
+	 67, // 0x10c
+	 68, // 0x10d
+	 69, // 0x10e
+	 70, // 0x10f
+	 71, // 0x110
+	 72, // 0x111
+	 73, // 0x112
+	 74, // 0x113
+	 75, // 0x114
+	 76, // 0x115
+	 77, // 0x116
+	 78, // 0x117
+	 79, // 0x118
+	 80, // 0x119
+	 81, // 0x11a
+	 82, // 0x11b
+	 83, // 0x11c
+	 84, // 0x11d
+	 85, // 0x11e
+	 86, // 0x11f
+	 1, // 0x120
+	 1, // 0x121
+	 1, // 0x122
+	 87, // 0x123
+	 88, // 0x124
+	 89, // 0x125
+	 38, // 0x126
+	 38, // 0x127
+	 38, // 0x128
+	 38, // 0x129
+	 38, // 0x12a
+	 38, // 0x12b
+	 38, // 0x12c
+	 38, // 0x12d
+	 38, // 0x12e
+	 90, // 0x12f
+	 1, // 0x130
+	 1, // 0x131
+	 1, // 0x132
+	 1, // 0x133
+	 91, // 0x134
+	 1, // 0x135
+	 1, // 0x136
+	 1, // 0x137
+	 1, // 0x138
+	 1, // 0x139
+	 1, // 0x13a
+	 1, // 0x13b
+	 1, // 0x13c
+	 1, // 0x13d
+	 1, // 0x13e
+	 1, // 0x13f
+	 1, // 0x140
+	 1, // 0x141
+	 1, // 0x142
+	 92, // 0x143
+	 1, // 0x144
+	 1, // 0x145
+	 93, // 0x146
+	 38, // 0x147
+	 38, // 0x148
+	 38, // 0x149
+	 38, // 0x14a
+	 38, // 0x14b
+	 38, // 0x14c
+	 38, // 0x14d
+	 38, // 0x14e
+	 38, // 0x14f
+	 38, // 0x150
+	 38, // 0x151
+	 38, // 0x152
+	 38, // 0x153
+	 38, // 0x154
+	 38, // 0x155
+	 38, // 0x156
+	 38, // 0x157
+	 38, // 0x158
+	 38, // 0x159
+	 38, // 0x15a
+	 38, // 0x15b
+	 38, // 0x15c
+	 38, // 0x15d
+	 38, // 0x15e
+	 38, // 0x15f
+	 38, // 0x160
+	 94, // 0x161
+	 38, // 0x162
+	 38, // 0x163
+	 38, // 0x164
+	 38, // 0x165
+	 38, // 0x166
+	 38, // 0x167
+	 1, // 0x168
+	 1, // 0x169
+	 95, // 0x16a
+	 96, // 0x16b
+	 38, // 0x16c
+	 97, // 0x16d
+	 98, // 0x16e
+	 99, // 0x16f
+	 1, // 0x170
+	 1, // 0x171
+	 1, // 0x172
+	 1, // 0x173
+	 1, // 0x174
+	 1, // 0x175
+	 1, // 0x176
+	 1, // 0x177
+	 1, // 0x178
+	 1, // 0x179
+	 1, // 0x17a
+	 1, // 0x17b
+	 1, // 0x17c
+	 1, // 0x17d
+	 1, // 0x17e
+	 1, // 0x17f
+	 1, // 0x180
+	 1, // 0x181
+	 1, // 0x182
+	 1, // 0x183
+	 1, // 0x184
+	 1, // 0x185
+	 1, // 0x186
+	 100, // 0x187
+	 1, // 0x188
+	 1, // 0x189
+	 1, // 0x18a
+	 1, // 0x18b
+	 101, // 0x18c
+	 102, // 0x18d
+	 38, // 0x18e
+	 38, // 0x18f
+	 38, // 0x190
+	 38, // 0x191
+	 38, // 0x192
+	 38, // 0x193
+	 38, // 0x194
+	 38, // 0x195
+	 38, // 0x196
+	 38, // 0x197
+	 38, // 0x198
+	 38, // 0x199
+	 38, // 0x19a
+	 38, // 0x19b
+	 38, // 0x19c
+	 38, // 0x19d
+	 38, // 0x19e
+	 38, // 0x19f
+	 38, // 0x1a0
+	 38, // 0x1a1
+	 38, // 0x1a2
+	 38, // 0x1a3
+	 38, // 0x1a4
+	 38, // 0x1a5
+	 38, // 0x1a6
+	 38, // 0x1a7
+	 38, // 0x1a8
+	 38, // 0x1a9
+	 38, // 0x1aa
+	 38, // 0x1ab
+	 38, // 0x1ac
+	 38, // 0x1ad
+	 38, // 0x1ae
+	 103, // 0x1af
+	 1, // 0x1b0
+	 104, // 0x1b1
+	 105, // 0x1b2
+	 38, // 0x1b3
+	 38, // 0x1b4
+	 38, // 0x1b5
+	 38, // 0x1b6
+	 38, // 0x1b7
+	 38, // 0x1b8
+	 38, // 0x1b9
+	 38, // 0x1ba
+	 38, // 0x1bb
+	 106, // 0x1bc
+	 38, // 0x1bd
+	 38, // 0x1be
+	 38, // 0x1bf
+	 38, // 0x1c0
+	 38, // 0x1c1
+	 38, // 0x1c2
+	 38, // 0x1c3
+	 38, // 0x1c4
+	 38, // 0x1c5
+	 38, // 0x1c6
+	 38, // 0x1c7
+	 38, // 0x1c8
+	 38, // 0x1c9
+	 38, // 0x1ca
+	 38, // 0x1cb
+	 107, // 0x1cc
+	 38, // 0x1cd
+	 38, // 0x1ce
+	 108, // 0x1cf
+	 38, // 0x1d0
+	 109, // 0x1d1
+	 110, // 0x1d2
+	 38, // 0x1d3
+	 111, // 0x1d4
+	 112, // 0x1d5
+	 113, // 0x1d6
+	 114, // 0x1d7
+	 38, // 0x1d8
+	 38, // 0x1d9
+	 115, // 0x1da
+	 38, // 0x1db
+	 38, // 0x1dc
+	 38, // 0x1dd
+	 38, // 0x1de
+	 116, // 0x1df
+	 117, // 0x1e0
+	 118, // 0x1e1
+	 119, // 0x1e2
+	 38, // 0x1e3
+	 120, // 0x1e4
+	 121, // 0x1e5
+	 38, // 0x1e6
+	 122, // 0x1e7
+	 123, // 0x1e8
+	 124, // 0x1e9
+	 38, // 0x1ea
+	 38, // 0x1eb
+	 38, // 0x1ec
+	 38, // 0x1ed
+	 125, // 0x1ee
+	 38, // 0x1ef
+	 38, // 0x1f0
+	 38, // 0x1f1
+	 38, // 0x1f2
+	 38, // 0x1f3
+	 38, // 0x1f4
+	 38, // 0x1f5
+	 38, // 0x1f6
+	 38, // 0x1f7
+	 38, // 0x1f8
+	 38, // 0x1f9
+	 38, // 0x1fa
+	 107, // 0x1fb
+	 38, // 0x1fc
+	 38, // 0x1fd
+	 38, // 0x1fe
+	 38, // 0x1ff
+	 1, // 0x200
+	 1, // 0x201
+	 1, // 0x202
+	 1, // 0x203
+	 1, // 0x204
+	 1, // 0x205
+	 1, // 0x206
+	 1, // 0x207
+	 1, // 0x208
+	 1, // 0x209
+	 1, // 0x20a
+	 1, // 0x20b
+	 1, // 0x20c
+	 1, // 0x20d
+	 1, // 0x20e
+	 1, // 0x20f
+	 1, // 0x210
+	 1, // 0x211
+	 1, // 0x212
+	 1, // 0x213
+	 1, // 0x214
+	 1, // 0x215
+	 1, // 0x216
+	 1, // 0x217
+	 1, // 0x218
+	 1, // 0x219
+	 1, // 0x21a
+	 1, // 0x21b
+	 1, // 0x21c
+	 1, // 0x21d
+	 1, // 0x21e
+	 1, // 0x21f
+	 1, // 0x220
+	 1, // 0x221
+	 1, // 0x222
+	 1, // 0x223
+	 1, // 0x224
+	 1, // 0x225
+	 1, // 0x226
+	 1, // 0x227
+	 1, // 0x228
+	 1, // 0x229
+	 1, // 0x22a
+	 1, // 0x22b
+	 1, // 0x22c
+	 1, // 0x22d
+	 1, // 0x22e
+	 1, // 0x22f
+	 1, // 0x230
+	 1, // 0x231
+	 1, // 0x232
+	 1, // 0x233
+	 1, // 0x234
+	 1, // 0x235
+	 1, // 0x236
+	 1, // 0x237
+	 1, // 0x238
+	 1, // 0x239
+	 1, // 0x23a
+	 1, // 0x23b
+	 1, // 0x23c
+	 1, // 0x23d
+	 1, // 0x23e
+	 1, // 0x23f
+	 1, // 0x240
+	 1, // 0x241
+	 1, // 0x242
+	 1, // 0x243
+	 1, // 0x244
+	 1, // 0x245
+	 1, // 0x246
+	 1, // 0x247
+	 1, // 0x248
+	 1, // 0x249
+	 1, // 0x24a
+	 1, // 0x24b
+	 1, // 0x24c
+	 1, // 0x24d
+	 1, // 0x24e
+	 1, // 0x24f
+	 1, // 0x250
+	 1, // 0x251
+	 1, // 0x252
+	 1, // 0x253
+	 1, // 0x254
+	 1, // 0x255
+	 1, // 0x256
+	 1, // 0x257
+	 1, // 0x258
+	 1, // 0x259
+	 1, // 0x25a
+	 1, // 0x25b
+	 1, // 0x25c
+	 1, // 0x25d
+	 1, // 0x25e
+	 1, // 0x25f
+	 1, // 0x260
+	 1, // 0x261
+	 1, // 0x262
+	 1, // 0x263
+	 1, // 0x264
+	 1, // 0x265
+	 1, // 0x266
+	 1, // 0x267
+	 1, // 0x268
+	 1, // 0x269
+	 1, // 0x26a
+	 1, // 0x26b
+	 1, // 0x26c
+	 1, // 0x26d
+	 1, // 0x26e
+	 1, // 0x26f
+	 1, // 0x270
+	 1, // 0x271
+	 1, // 0x272
+	 1, // 0x273
+	 1, // 0x274
+	 1, // 0x275
+	 1, // 0x276
+	 1, // 0x277
+	 1, // 0x278
+	 1, // 0x279
+	 1, // 0x27a
+	 1, // 0x27b
+	 1, // 0x27c
+	 1, // 0x27d
+	 1, // 0x27e
+	 1, // 0x27f
+	 1, // 0x280
+	 1, // 0x281
+	 1, // 0x282
+	 1, // 0x283
+	 1, // 0x284
+	 1, // 0x285
+	 1, // 0x286
+	 1, // 0x287
+	 1, // 0x288
+	 1, // 0x289
+	 1, // 0x28a
+	 1, // 0x28b
+	 1, // 0x28c
+	 1, // 0x28d
+	 1, // 0x28e
+	 1, // 0x28f
+	 1, // 0x290
+	 1, // 0x291
+	 1, // 0x292
+	 1, // 0x293
+	 1, // 0x294
+	 1, // 0x295
+	 1, // 0x296
+	 1, // 0x297
+	 1, // 0x298
+	 1, // 0x299
+	 1, // 0x29a
+	 1, // 0x29b
+	 1, // 0x29c
+	 1, // 0x29d
+	 1, // 0x29e
+	 1, // 0x29f
+	 1, // 0x2a0
+	 1, // 0x2a1
+	 1, // 0x2a2
+	 1, // 0x2a3
+	 1, // 0x2a4
+	 1, // 0x2a5
+	 126, // 0x2a6
+	 1, // 0x2a7
+	 1, // 0x2a8
+	 1, // 0x2a9
+	 1, // 0x2aa
+	 1, // 0x2ab
+	 1, // 0x2ac
+	 1, // 0x2ad
+	 1, // 0x2ae
+	 1, // 0x2af
+	 1, // 0x2b0
+	 1, // 0x2b1
+	 1, // 0x2b2
+	 1, // 0x2b3
+	 1, // 0x2b4
+	 1, // 0x2b5
+	 1, // 0x2b6
+	 127, // 0x2b7
+	 128, // 0x2b8
+	 1, // 0x2b9
+	 1, // 0x2ba
+	 1, // 0x2bb
+	 1, // 0x2bc
+	 1, // 0x2bd
+	 1, // 0x2be
+	 1, // 0x2bf
+	 1, // 0x2c0
+	 1, // 0x2c1
+	 1, // 0x2c2
+	 1, // 0x2c3
+	 1, // 0x2c4
+	 1, // 0x2c5
+	 1, // 0x2c6
+	 1, // 0x2c7
+	 1, // 0x2c8
+	 1, // 0x2c9
+	 1, // 0x2ca
+	 1, // 0x2cb
+	 1, // 0x2cc
+	 1, // 0x2cd
+	 129, // 0x2ce
+	 1, // 0x2cf
+	 1, // 0x2d0
+	 1, // 0x2d1
+	 1, // 0x2d2
+	 1, // 0x2d3
+	 1, // 0x2d4
+	 1, // 0x2d5
+	 1, // 0x2d6
+	 1, // 0x2d7
+	 1, // 0x2d8
+	 1, // 0x2d9
+	 1, // 0x2da
+	 1, // 0x2db
+	 1, // 0x2dc
+	 1, // 0x2dd
+	 1, // 0x2de
+	 1, // 0x2df
+	 1, // 0x2e0
+	 1, // 0x2e1
+	 1, // 0x2e2
+	 1, // 0x2e3
+	 1, // 0x2e4
+	 1, // 0x2e5
+	 1, // 0x2e6
+	 1, // 0x2e7
+	 1, // 0x2e8
+	 1, // 0x2e9
+	 1, // 0x2ea
+	 130, // 0x2eb
+	 1, // 0x2ec
+	 1, // 0x2ed
+	 131, // 0x2ee
+	 38, // 0x2ef
+	 38, // 0x2f0
+	 38, // 0x2f1
+	 38, // 0x2f2
+	 38, // 0x2f3
+	 38, // 0x2f4
+	 38, // 0x2f5
+	 38, // 0x2f6
+	 38, // 0x2f7
+	 1, // 0x2f8
+	 1, // 0x2f9
+	 132, // 0x2fa
+	 38, // 0x2fb
+	 38, // 0x2fc
+	 38, // 0x2fd
+	 38, // 0x2fe
+	 38, // 0x2ff
+	 1, // 0x300
+	 1, // 0x301
+	 1, // 0x302
+	 1, // 0x303
+	 1, // 0x304
+	 1, // 0x305
+	 1, // 0x306
+	 1, // 0x307
+	 1, // 0x308
+	 1, // 0x309
+	 1, // 0x30a
+	 1, // 0x30b
+	 1, // 0x30c
+	 1, // 0x30d
+	 1, // 0x30e
+	 1, // 0x30f
+	 1, // 0x310
+	 1, // 0x311
+	 1, // 0x312
+	 133, // 0x313
+	 1, // 0x314
+	 1, // 0x315
+	 1, // 0x316
+	 1, // 0x317
+	 1, // 0x318
+	 1, // 0x319
+	 1, // 0x31a
+	 1, // 0x31b
+	 1, // 0x31c
+	 1, // 0x31d
+	 1, // 0x31e
+	 1, // 0x31f
+	 1, // 0x320
+	 1, // 0x321
+	 1, // 0x322
+	 134, // 0x323
+	 38, // 0x324
+	 38, // 0x325
+	 38, // 0x326
+	 38, // 0x327
+	 38, // 0x328
+	 38, // 0x329
+	 38, // 0x32a
+	 38, // 0x32b
+	 38, // 0x32c
+	 38, // 0x32d
+	 38, // 0x32e
+	 38, // 0x32f
+	 38, // 0x330
+	 38, // 0x331
+	 38, // 0x332
+	 38, // 0x333
+	 38, // 0x334
+	 38, // 0x335
+	 38, // 0x336
+	 38, // 0x337
+	 38, // 0x338
+	 38, // 0x339
+	 38, // 0x33a
+	 38, // 0x33b
+	 38, // 0x33c
+	 38, // 0x33d
+	 38, // 0x33e
+	 38, // 0x33f
+	 38, // 0x340
+	 38, // 0x341
+	 38, // 0x342
+	 38, // 0x343
+	 38, // 0x344
+	 38, // 0x345
+	 38, // 0x346
+	 38, // 0x347
+	 38, // 0x348
+	 38, // 0x349
+	 38, // 0x34a
+	 38, // 0x34b
+	 38, // 0x34c
+	 38, // 0x34d
+	 38, // 0x34e
+	 38, // 0x34f
+	 38, // 0x350
+	 38, // 0x351
+	 38, // 0x352
+	 38, // 0x353
+	 38, // 0x354
+	 38, // 0x355
+	 38, // 0x356
+	 38, // 0x357
+	 38, // 0x358
+	 38, // 0x359
+	 38, // 0x35a
+	 38, // 0x35b
+	 38, // 0x35c
+	 38, // 0x35d
+	 38, // 0x35e
+	 38, // 0x35f
+	 38, // 0x360
+	 38, // 0x361
+	 38, // 0x362
+	 38, // 0x363
+	 38, // 0x364
+	 38, // 0x365
+	 38, // 0x366
+	 38, // 0x367
+	 38, // 0x368
+	 38, // 0x369
+	 38, // 0x36a
+	 38, // 0x36b
+	 38, // 0x36c
+	 38, // 0x36d
+	 38, // 0x36e
+	 38, // 0x36f
+	 38, // 0x370
+	 38, // 0x371
+	 38, // 0x372
+	 38, // 0x373
+	 38, // 0x374
+	 38, // 0x375
+	 38, // 0x376
+	 38, // 0x377
+	 38, // 0x378
+	 38, // 0x379
+	 38, // 0x37a
+	 38, // 0x37b
+	 38, // 0x37c
+	 38, // 0x37d
+	 38, // 0x37e
+	 38, // 0x37f
+	 38, // 0x380
+	 38, // 0x381
+	 38, // 0x382
+	 38, // 0x383
+	 38, // 0x384
+	 38, // 0x385
+	 38, // 0x386
+	 38, // 0x387
+	 38, // 0x388
+	 38, // 0x389
+	 38, // 0x38a
+	 38, // 0x38b
+	 38, // 0x38c
+	 38, // 0x38d
+	 38, // 0x38e
+	 38, // 0x38f
+	 38, // 0x390
+	 38, // 0x391
+	 38, // 0x392
+	 38, // 0x393
+	 38, // 0x394
+	 38, // 0x395
+	 38, // 0x396
+	 38, // 0x397
+	 38, // 0x398
+	 38, // 0x399
+	 38, // 0x39a
+	 38, // 0x39b
+	 38, // 0x39c
+	 38, // 0x39d
+	 38, // 0x39e
+	 38, // 0x39f
+	 38, // 0x3a0
+	 38, // 0x3a1
+	 38, // 0x3a2
+	 38, // 0x3a3
+	 38, // 0x3a4
+	 38, // 0x3a5
+	 38, // 0x3a6
+	 38, // 0x3a7
+	 38, // 0x3a8
+	 38, // 0x3a9
+	 38, // 0x3aa
+	 38, // 0x3ab
+	 38, // 0x3ac
+	 38, // 0x3ad
+	 38, // 0x3ae
+	 38, // 0x3af
+	 38, // 0x3b0
+	 38, // 0x3b1
+	 38, // 0x3b2
+	 38, // 0x3b3
+	 38, // 0x3b4
+	 38, // 0x3b5
+	 38, // 0x3b6
+	 38, // 0x3b7
+	 38, // 0x3b8
+	 38, // 0x3b9
+	 38, // 0x3ba
+	 38, // 0x3bb
+	 38, // 0x3bc
+	 38, // 0x3bd
+	 38, // 0x3be
+	 38, // 0x3bf
+	 38, // 0x3c0
+	 38, // 0x3c1
+	 38, // 0x3c2
+	 38, // 0x3c3
+	 38, // 0x3c4
+	 38, // 0x3c5
+	 38, // 0x3c6
+	 38, // 0x3c7
+	 38, // 0x3c8
+	 38, // 0x3c9
+	 38, // 0x3ca
+	 38, // 0x3cb
+	 38, // 0x3cc
+	 38, // 0x3cd
+	 38, // 0x3ce
+	 38, // 0x3cf
+	 38, // 0x3d0
+	 38, // 0x3d1
+	 38, // 0x3d2
+	 38, // 0x3d3
+	 38, // 0x3d4
+	 38, // 0x3d5
+	 38, // 0x3d6
+	 38, // 0x3d7
+	 38, // 0x3d8
+	 38, // 0x3d9
+	 38, // 0x3da
+	 38, // 0x3db
+	 38, // 0x3dc
+	 38, // 0x3dd
+	 38, // 0x3de
+	 38, // 0x3df
+	 38, // 0x3e0
+	 38, // 0x3e1
+	 38, // 0x3e2
+	 38, // 0x3e3
+	 38, // 0x3e4
+	 38, // 0x3e5
+	 38, // 0x3e6
+	 38, // 0x3e7
+	 38, // 0x3e8
+	 38, // 0x3e9
+	 38, // 0x3ea
+	 38, // 0x3eb
+	 38, // 0x3ec
+	 38, // 0x3ed
+	 38, // 0x3ee
+	 38, // 0x3ef
+	 38, // 0x3f0
+	 38, // 0x3f1
+	 38, // 0x3f2
+	 38, // 0x3f3
+	 38, // 0x3f4
+	 38, // 0x3f5
+	 38, // 0x3f6
+	 38, // 0x3f7
+	 38, // 0x3f8
+	 38, // 0x3f9
+	 38, // 0x3fa
+	 38, // 0x3fb
+	 38, // 0x3fc
+	 38, // 0x3fd
+	 38, // 0x3fe
+	 38, // 0x3ff
+	 38, // 0x400
+	 38, // 0x401
+	 38, // 0x402
+	 38, // 0x403
+	 38, // 0x404
+	 38, // 0x405
+	 38, // 0x406
+	 38, // 0x407
+	 38, // 0x408
+	 38, // 0x409
+	 38, // 0x40a
+	 38, // 0x40b
+	 38, // 0x40c
+	 38, // 0x40d
+	 38, // 0x40e
+	 38, // 0x40f
+	 38, // 0x410
+	 38, // 0x411
+	 38, // 0x412
+	 38, // 0x413
+	 38, // 0x414
+	 38, // 0x415
+	 38, // 0x416
+	 38, // 0x417
+	 38, // 0x418
+	 38, // 0x419
+	 38, // 0x41a
+	 38, // 0x41b
+	 38, // 0x41c
+	 38, // 0x41d
+	 38, // 0x41e
+	 38, // 0x41f
+	 38, // 0x420
+	 38, // 0x421
+	 38, // 0x422
+	 38, // 0x423
+	 38, // 0x424
+	 38, // 0x425
+	 38, // 0x426
+	 38, // 0x427
+	 38, // 0x428
+	 38, // 0x429
+	 38, // 0x42a
+	 38, // 0x42b
+	 38, // 0x42c
+	 38, // 0x42d
+	 38, // 0x42e
+	 38, // 0x42f
+	 38, // 0x430
+	 38, // 0x431
+	 38, // 0x432
+	 38, // 0x433
+	 38, // 0x434
+	 38, // 0x435
+	 38, // 0x436
+	 38, // 0x437
+	 38, // 0x438
+	 38, // 0x439
+	 38, // 0x43a
+	 38, // 0x43b
+	 38, // 0x43c
+	 38, // 0x43d
+	 38, // 0x43e
+	 38, // 0x43f
+	 38, // 0x440
+	 38, // 0x441
+	 38, // 0x442
+	 38, // 0x443
+	 38, // 0x444
+	 38, // 0x445
+	 38, // 0x446
+	 38, // 0x447
+	 38, // 0x448
+	 38, // 0x449
+	 38, // 0x44a
+	 38, // 0x44b
+	 38, // 0x44c
+	 38, // 0x44d
+	 38, // 0x44e
+	 38, // 0x44f
+	 38, // 0x450
+	 38, // 0x451
+	 38, // 0x452
+	 38, // 0x453
+	 38, // 0x454
+	 38, // 0x455
+	 38, // 0x456
+	 38, // 0x457
+	 38, // 0x458
+	 38, // 0x459
+	 38, // 0x45a
+	 38, // 0x45b
+	 38, // 0x45c
+	 38, // 0x45d
+	 38, // 0x45e
+	 38, // 0x45f
+	 38, // 0x460
+	 38, // 0x461
+	 38, // 0x462
+	 38, // 0x463
+	 38, // 0x464
+	 38, // 0x465
+	 38, // 0x466
+	 38, // 0x467
+	 38, // 0x468
+	 38, // 0x469
+	 38, // 0x46a
+	 38, // 0x46b
+	 38, // 0x46c
+	 38, // 0x46d
+	 38, // 0x46e
+	 38, // 0x46f
+	 38, // 0x470
+	 38, // 0x471
+	 38, // 0x472
+	 38, // 0x473
+	 38, // 0x474
+	 38, // 0x475
+	 38, // 0x476
+	 38, // 0x477
+	 38, // 0x478
+	 38, // 0x479
+	 38, // 0x47a
+	 38, // 0x47b
+	 38, // 0x47c
+	 38, // 0x47d
+	 38, // 0x47e
+	 38, // 0x47f
+	 38, // 0x480
+	 38, // 0x481
+	 38, // 0x482
+	 38, // 0x483
+	 38, // 0x484
+	 38, // 0x485
+	 38, // 0x486
+	 38, // 0x487
+	 38, // 0x488
+	 38, // 0x489
+	 38, // 0x48a
+	 38, // 0x48b
+	 38, // 0x48c
+	 38, // 0x48d
+	 38, // 0x48e
+	 38, // 0x48f
+	 38, // 0x490
+	 38, // 0x491
+	 38, // 0x492
+	 38, // 0x493
+	 38, // 0x494
+	 38, // 0x495
+	 38, // 0x496
+	 38, // 0x497
+	 38, // 0x498
+	 38, // 0x499
+	 38, // 0x49a
+	 38, // 0x49b
+	 38, // 0x49c
+	 38, // 0x49d
+	 38, // 0x49e
+	 38, // 0x49f
+	 38, // 0x4a0
+	 38, // 0x4a1
+	 38, // 0x4a2
+	 38, // 0x4a3
+	 38, // 0x4a4
+	 38, // 0x4a5
+	 38, // 0x4a6
+	 38, // 0x4a7
+	 38, // 0x4a8
+	 38, // 0x4a9
+	 38, // 0x4aa
+	 38, // 0x4ab
+	 38, // 0x4ac
+	 38, // 0x4ad
+	 38, // 0x4ae
+	 38, // 0x4af
+	 38, // 0x4b0
+	 38, // 0x4b1
+	 38, // 0x4b2
+	 38, // 0x4b3
+	 38, // 0x4b4
+	 38, // 0x4b5
+	 38, // 0x4b6
+	 38, // 0x4b7
+	 38, // 0x4b8
+	 38, // 0x4b9
+	 38, // 0x4ba
+	 38, // 0x4bb
+	 38, // 0x4bc
+	 38, // 0x4bd
+	 38, // 0x4be
+	 38, // 0x4bf
+	 38, // 0x4c0
+	 38, // 0x4c1
+	 38, // 0x4c2
+	 38, // 0x4c3
+	 38, // 0x4c4
+	 38, // 0x4c5
+	 38, // 0x4c6
+	 38, // 0x4c7
+	 38, // 0x4c8
+	 38, // 0x4c9
+	 38, // 0x4ca
+	 38, // 0x4cb
+	 38, // 0x4cc
+	 38, // 0x4cd
+	 38, // 0x4ce
+	 38, // 0x4cf
+	 38, // 0x4d0
+	 38, // 0x4d1
+	 38, // 0x4d2
+	 38, // 0x4d3
+	 38, // 0x4d4
+	 38, // 0x4d5
+	 38, // 0x4d6
+	 38, // 0x4d7
+	 38, // 0x4d8
+	 38, // 0x4d9
+	 38, // 0x4da
+	 38, // 0x4db
+	 38, // 0x4dc
+	 38, // 0x4dd
+	 38, // 0x4de
+	 38, // 0x4df
+	 38, // 0x4e0
+	 38, // 0x4e1
+	 38, // 0x4e2
+	 38, // 0x4e3
+	 38, // 0x4e4
+	 38, // 0x4e5
+	 38, // 0x4e6
+	 38, // 0x4e7
+	 38, // 0x4e8
+	 38, // 0x4e9
+	 38, // 0x4ea
+	 38, // 0x4eb
+	 38, // 0x4ec
+	 38, // 0x4ed
+	 38, // 0x4ee
+	 38, // 0x4ef
+	 38, // 0x4f0
+	 38, // 0x4f1
+	 38, // 0x4f2
+	 38, // 0x4f3
+	 38, // 0x4f4
+	 38, // 0x4f5
+	 38, // 0x4f6
+	 38, // 0x4f7
+	 38, // 0x4f8
+	 38, // 0x4f9
+	 38, // 0x4fa
+	 38, // 0x4fb
+	 38, // 0x4fc
+	 38, // 0x4fd
+	 38, // 0x4fe
+	 38, // 0x4ff
+	 38, // 0x500
+	 38, // 0x501
+	 38, // 0x502
+	 38, // 0x503
+	 38, // 0x504
+	 38, // 0x505
+	 38, // 0x506
+	 38, // 0x507
+	 38, // 0x508
+	 38, // 0x509
+	 38, // 0x50a
+	 38, // 0x50b
+	 38, // 0x50c
+	 38, // 0x50d
+	 38, // 0x50e
+	 38, // 0x50f
+	 38, // 0x510
+	 38, // 0x511
+	 38, // 0x512
+	 38, // 0x513
+	 38, // 0x514
+	 38, // 0x515
+	 38, // 0x516
+	 38, // 0x517
+	 38, // 0x518
+	 38, // 0x519
+	 38, // 0x51a
+	 38, // 0x51b
+	 38, // 0x51c
+	 38, // 0x51d
+	 38, // 0x51e
+	 38, // 0x51f
+	 38, // 0x520
+	 38, // 0x521
+	 38, // 0x522
+	 38, // 0x523
+	 38, // 0x524
+	 38, // 0x525
+	 38, // 0x526
+	 38, // 0x527
+	 38, // 0x528
+	 38, // 0x529
+	 38, // 0x52a
+	 38, // 0x52b
+	 38, // 0x52c
+	 38, // 0x52d
+	 38, // 0x52e
+	 38, // 0x52f
+	 38, // 0x530
+	 38, // 0x531
+	 38, // 0x532
+	 38, // 0x533
+	 38, // 0x534
+	 38, // 0x535
+	 38, // 0x536
+	 38, // 0x537
+	 38, // 0x538
+	 38, // 0x539
+	 38, // 0x53a
+	 38, // 0x53b
+	 38, // 0x53c
+	 38, // 0x53d
+	 38, // 0x53e
+	 38, // 0x53f
+	 38, // 0x540
+	 38, // 0x541
+	 38, // 0x542
+	 38, // 0x543
+	 38, // 0x544
+	 38, // 0x545
+	 38, // 0x546
+	 38, // 0x547
+	 38, // 0x548
+	 38, // 0x549
+	 38, // 0x54a
+	 38, // 0x54b
+	 38, // 0x54c
+	 38, // 0x54d
+	 38, // 0x54e
+	 38, // 0x54f
+	 38, // 0x550
+	 38, // 0x551
+	 38, // 0x552
+	 38, // 0x553
+	 38, // 0x554
+	 38, // 0x555
+	 38, // 0x556
+	 38, // 0x557
+	 38, // 0x558
+	 38, // 0x559
+	 38, // 0x55a
+	 38, // 0x55b
+	 38, // 0x55c
+	 38, // 0x55d
+	 38, // 0x55e
+	 38, // 0x55f
+	 38, // 0x560
+	 38, // 0x561
+	 38, // 0x562
+	 38, // 0x563
+	 38, // 0x564
+	 38, // 0x565
+	 38, // 0x566
+	 38, // 0x567
+	 38, // 0x568
+	 38, // 0x569
+	 38, // 0x56a
+	 38, // 0x56b
+	 38, // 0x56c
+	 38, // 0x56d
+	 38, // 0x56e
+	 38, // 0x56f
+	 38, // 0x570
+	 38, // 0x571
+	 38, // 0x572
+	 38, // 0x573
+	 38, // 0x574
+	 38, // 0x575
+	 38, // 0x576
+	 38, // 0x577
+	 38, // 0x578
+	 38, // 0x579
+	 38, // 0x57a
+	 38, // 0x57b
+	 38, // 0x57c
+	 38, // 0x57d
+	 38, // 0x57e
+	 38, // 0x57f
+	 38, // 0x580
+	 38, // 0x581
+	 38, // 0x582
+	 38, // 0x583
+	 38, // 0x584
+	 38, // 0x585
+	 38, // 0x586
+	 38, // 0x587
+	 38, // 0x588
+	 38, // 0x589
+	 38, // 0x58a
+	 38, // 0x58b
+	 38, // 0x58c
+	 38, // 0x58d
+	 38, // 0x58e
+	 38, // 0x58f
+	 38, // 0x590
+	 38, // 0x591
+	 38, // 0x592
+	 38, // 0x593
+	 38, // 0x594
+	 38, // 0x595
+	 38, // 0x596
+	 38, // 0x597
+	 38, // 0x598
+	 38, // 0x599
+	 38, // 0x59a
+	 38, // 0x59b
+	 38, // 0x59c
+	 38, // 0x59d
+	 38, // 0x59e
+	 38, // 0x59f
+	 38, // 0x5a0
+	 38, // 0x5a1
+	 38, // 0x5a2
+	 38, // 0x5a3
+	 38, // 0x5a4
+	 38, // 0x5a5
+	 38, // 0x5a6
+	 38, // 0x5a7
+	 38, // 0x5a8
+	 38, // 0x5a9
+	 38, // 0x5aa
+	 38, // 0x5ab
+	 38, // 0x5ac
+	 38, // 0x5ad
+	 38, // 0x5ae
+	 38, // 0x5af
+	 38, // 0x5b0
+	 38, // 0x5b1
+	 38, // 0x5b2
+	 38, // 0x5b3
+	 38, // 0x5b4
+	 38, // 0x5b5
+	 38, // 0x5b6
+	 38, // 0x5b7
+	 38, // 0x5b8
+	 38, // 0x5b9
+	 38, // 0x5ba
+	 38, // 0x5bb
+	 38, // 0x5bc
+	 38, // 0x5bd
+	 38, // 0x5be
+	 38, // 0x5bf
+	 38, // 0x5c0
+	 38, // 0x5c1
+	 38, // 0x5c2
+	 38, // 0x5c3
+	 38, // 0x5c4
+	 38, // 0x5c5
+	 38, // 0x5c6
+	 38, // 0x5c7
+	 38, // 0x5c8
+	 38, // 0x5c9
+	 38, // 0x5ca
+	 38, // 0x5cb
+	 38, // 0x5cc
+	 38, // 0x5cd
+	 38, // 0x5ce
+	 38, // 0x5cf
+	 38, // 0x5d0
+	 38, // 0x5d1
+	 38, // 0x5d2
+	 38, // 0x5d3
+	 38, // 0x5d4
+	 38, // 0x5d5
+	 38, // 0x5d6
+	 38, // 0x5d7
+	 38, // 0x5d8
+	 38, // 0x5d9
+	 38, // 0x5da
+	 38, // 0x5db
+	 38, // 0x5dc
+	 38, // 0x5dd
+	 38, // 0x5de
+	 38, // 0x5df
+	 38, // 0x5e0
+	 38, // 0x5e1
+	 38, // 0x5e2
+	 38, // 0x5e3
+	 38, // 0x5e4
+	 38, // 0x5e5
+	 38, // 0x5e6
+	 38, // 0x5e7
+	 38, // 0x5e8
+	 38, // 0x5e9
+	 38, // 0x5ea
+	 38, // 0x5eb
+	 38, // 0x5ec
+	 38, // 0x5ed
+	 38, // 0x5ee
+	 38, // 0x5ef
+	 38, // 0x5f0
+	 38, // 0x5f1
+	 38, // 0x5f2
+	 38, // 0x5f3
+	 38, // 0x5f4
+	 38, // 0x5f5
+	 38, // 0x5f6
+	 38, // 0x5f7
+	 38, // 0x5f8
+	 38, // 0x5f9
+	 38, // 0x5fa
+	 38, // 0x5fb
+	 38, // 0x5fc
+	 38, // 0x5fd
+	 38, // 0x5fe
+	 38, // 0x5ff
+	 38, // 0x600
+	 38, // 0x601
+	 38, // 0x602
+	 38, // 0x603
+	 38, // 0x604
+	 38, // 0x605
+	 38, // 0x606
+	 38, // 0x607
+	 38, // 0x608
+	 38, // 0x609
+	 38, // 0x60a
+	 38, // 0x60b
+	 38, // 0x60c
+	 38, // 0x60d
+	 38, // 0x60e
+	 38, // 0x60f
+	 38, // 0x610
+	 38, // 0x611
+	 38, // 0x612
+	 38, // 0x613
+	 38, // 0x614
+	 38, // 0x615
+	 38, // 0x616
+	 38, // 0x617
+	 38, // 0x618
+	 38, // 0x619
+	 38, // 0x61a
+	 38, // 0x61b
+	 38, // 0x61c
+	 38, // 0x61d
+	 38, // 0x61e
+	 38, // 0x61f
+	 38, // 0x620
+	 38, // 0x621
+	 38, // 0x622
+	 38, // 0x623
+	 38, // 0x624
+	 38, // 0x625
+	 38, // 0x626
+	 38, // 0x627
+	 38, // 0x628
+	 38, // 0x629
+	 38, // 0x62a
+	 38, // 0x62b
+	 38, // 0x62c
+	 38, // 0x62d
+	 38, // 0x62e
+	 38, // 0x62f
+	 38, // 0x630
+	 38, // 0x631
+	 38, // 0x632
+	 38, // 0x633
+	 38, // 0x634
+	 38, // 0x635
+	 38, // 0x636
+	 38, // 0x637
+	 38, // 0x638
+	 38, // 0x639
+	 38, // 0x63a
+	 38, // 0x63b
+	 38, // 0x63c
+	 38, // 0x63d
+	 38, // 0x63e
+	 38, // 0x63f
+	 38, // 0x640
+	 38, // 0x641
+	 38, // 0x642
+	 38, // 0x643
+	 38, // 0x644
+	 38, // 0x645
+	 38, // 0x646
+	 38, // 0x647
+	 38, // 0x648
+	 38, // 0x649
+	 38, // 0x64a
+	 38, // 0x64b
+	 38, // 0x64c
+	 38, // 0x64d
+	 38, // 0x64e
+	 38, // 0x64f
+	 38, // 0x650
+	 38, // 0x651
+	 38, // 0x652
+	 38, // 0x653
+	 38, // 0x654
+	 38, // 0x655
+	 38, // 0x656
+	 38, // 0x657
+	 38, // 0x658
+	 38, // 0x659
+	 38, // 0x65a
+	 38, // 0x65b
+	 38, // 0x65c
+	 38, // 0x65d
+	 38, // 0x65e
+	 38, // 0x65f
+	 38, // 0x660
+	 38, // 0x661
+	 38, // 0x662
+	 38, // 0x663
+	 38, // 0x664
+	 38, // 0x665
+	 38, // 0x666
+	 38, // 0x667
+	 38, // 0x668
+	 38, // 0x669
+	 38, // 0x66a
+	 38, // 0x66b
+	 38, // 0x66c
+	 38, // 0x66d
+	 38, // 0x66e
+	 38, // 0x66f
+	 38, // 0x670
+	 38, // 0x671
+	 38, // 0x672
+	 38, // 0x673
+	 38, // 0x674
+	 38, // 0x675
+	 38, // 0x676
+	 38, // 0x677
+	 38, // 0x678
+	 38, // 0x679
+	 38, // 0x67a
+	 38, // 0x67b
+	 38, // 0x67c
+	 38, // 0x67d
+	 38, // 0x67e
+	 38, // 0x67f
+	 38, // 0x680
+	 38, // 0x681
+	 38, // 0x682
+	 38, // 0x683
+	 38, // 0x684
+	 38, // 0x685
+	 38, // 0x686
+	 38, // 0x687
+	 38, // 0x688
+	 38, // 0x689
+	 38, // 0x68a
+	 38, // 0x68b
+	 38, // 0x68c
+	 38, // 0x68d
+	 38, // 0x68e
+	 38, // 0x68f
+	 38, // 0x690
+	 38, // 0x691
+	 38, // 0x692
+	 38, // 0x693
+	 38, // 0x694
+	 38, // 0x695
+	 38, // 0x696
+	 38, // 0x697
+	 38, // 0x698
+	 38, // 0x699
+	 38, // 0x69a
+	 38, // 0x69b
+	 38, // 0x69c
+	 38, // 0x69d
+	 38, // 0x69e
+	 38, // 0x69f
+	 38, // 0x6a0
+	 38, // 0x6a1
+	 38, // 0x6a2
+	 38, // 0x6a3
+	 38, // 0x6a4
+	 38, // 0x6a5
+	 38, // 0x6a6
+	 38, // 0x6a7
+	 38, // 0x6a8
+	 38, // 0x6a9
+	 38, // 0x6aa
+	 38, // 0x6ab
+	 38, // 0x6ac
+	 38, // 0x6ad
+	 38, // 0x6ae
+	 38, // 0x6af
+	 38, // 0x6b0
+	 38, // 0x6b1
+	 38, // 0x6b2
+	 38, // 0x6b3
+	 38, // 0x6b4
+	 38, // 0x6b5
+	 38, // 0x6b6
+	 38, // 0x6b7
+	 38, // 0x6b8
+	 38, // 0x6b9
+	 38, // 0x6ba
+	 38, // 0x6bb
+	 38, // 0x6bc
+	 38, // 0x6bd
+	 38, // 0x6be
+	 38, // 0x6bf
+	 38, // 0x6c0
+	 38, // 0x6c1
+	 38, // 0x6c2
+	 38, // 0x6c3
+	 38, // 0x6c4
+	 38, // 0x6c5
+	 38, // 0x6c6
+	 38, // 0x6c7
+	 38, // 0x6c8
+	 38, // 0x6c9
+	 38, // 0x6ca
+	 38, // 0x6cb
+	 38, // 0x6cc
+	 38, // 0x6cd
+	 38, // 0x6ce
+	 38, // 0x6cf
+	 38, // 0x6d0
+	 38, // 0x6d1
+	 38, // 0x6d2
+	 38, // 0x6d3
+	 38, // 0x6d4
+	 38, // 0x6d5
+	 38, // 0x6d6
+	 38, // 0x6d7
+	 38, // 0x6d8
+	 38, // 0x6d9
+	 38, // 0x6da
+	 38, // 0x6db
+	 38, // 0x6dc
+	 38, // 0x6dd
+	 38, // 0x6de
+	 38, // 0x6df
+	 38, // 0x6e0
+	 38, // 0x6e1
+	 38, // 0x6e2
+	 38, // 0x6e3
+	 38, // 0x6e4
+	 38, // 0x6e5
+	 38, // 0x6e6
+	 38, // 0x6e7
+	 38, // 0x6e8
+	 38, // 0x6e9
+	 38, // 0x6ea
+	 38, // 0x6eb
+	 38, // 0x6ec
+	 38, // 0x6ed
+	 38, // 0x6ee
+	 38, // 0x6ef
+	 38, // 0x6f0
+	 38, // 0x6f1
+	 38, // 0x6f2
+	 38, // 0x6f3
+	 38, // 0x6f4
+	 38, // 0x6f5
+	 38, // 0x6f6
+	 38, // 0x6f7
+	 38, // 0x6f8
+	 38, // 0x6f9
+	 38, // 0x6fa
+	 38, // 0x6fb
+	 38, // 0x6fc
+	 38, // 0x6fd
+	 38, // 0x6fe
+	 38, // 0x6ff
+	 38, // 0x700
+	 38, // 0x701
+	 38, // 0x702
+	 38, // 0x703
+	 38, // 0x704
+	 38, // 0x705
+	 38, // 0x706
+	 38, // 0x707
+	 38, // 0x708
+	 38, // 0x709
+	 38, // 0x70a
+	 38, // 0x70b
+	 38, // 0x70c
+	 38, // 0x70d
+	 38, // 0x70e
+	 38, // 0x70f
+	 38, // 0x710
+	 38, // 0x711
+	 38, // 0x712
+	 38, // 0x713
+	 38, // 0x714
+	 38, // 0x715
+	 38, // 0x716
+	 38, // 0x717
+	 38, // 0x718
+	 38, // 0x719
+	 38, // 0x71a
+	 38, // 0x71b
+	 38, // 0x71c
+	 38, // 0x71d
+	 38, // 0x71e
+	 38, // 0x71f
+	 38, // 0x720
+	 38, // 0x721
+	 38, // 0x722
+	 38, // 0x723
+	 38, // 0x724
+	 38, // 0x725
+	 38, // 0x726
+	 38, // 0x727
+	 38, // 0x728
+	 38, // 0x729
+	 38, // 0x72a
+	 38, // 0x72b
+	 38, // 0x72c
+	 38, // 0x72d
+	 38, // 0x72e
+	 38, // 0x72f
+	 38, // 0x730
+	 38, // 0x731
+	 38, // 0x732
+	 38, // 0x733
+	 38, // 0x734
+	 38, // 0x735
+	 38, // 0x736
+	 38, // 0x737
+	 38, // 0x738
+	 38, // 0x739
+	 38, // 0x73a
+	 38, // 0x73b
+	 38, // 0x73c
+	 38, // 0x73d
+	 38, // 0x73e
+	 38, // 0x73f
+	 38, // 0x740
+	 38, // 0x741
+	 38, // 0x742
+	 38, // 0x743
+	 38, // 0x744
+	 38, // 0x745
+	 38, // 0x746
+	 38, // 0x747
+	 38, // 0x748
+	 38, // 0x749
+	 38, // 0x74a
+	 38, // 0x74b
+	 38, // 0x74c
+	 38, // 0x74d
+	 38, // 0x74e
+	 38, // 0x74f
+	 38, // 0x750
+	 38, // 0x751
+	 38, // 0x752
+	 38, // 0x753
+	 38, // 0x754
+	 38, // 0x755
+	 38, // 0x756
+	 38, // 0x757
+	 38, // 0x758
+	 38, // 0x759
+	 38, // 0x75a
+	 38, // 0x75b
+	 38, // 0x75c
+	 38, // 0x75d
+	 38, // 0x75e
+	 38, // 0x75f
+	 38, // 0x760
+	 38, // 0x761
+	 38, // 0x762
+	 38, // 0x763
+	 38, // 0x764
+	 38, // 0x765
+	 38, // 0x766
+	 38, // 0x767
+	 38, // 0x768
+	 38, // 0x769
+	 38, // 0x76a
+	 38, // 0x76b
+	 38, // 0x76c
+	 38, // 0x76d
+	 38, // 0x76e
+	 38, // 0x76f
+	 38, // 0x770
+	 38, // 0x771
+	 38, // 0x772
+	 38, // 0x773
+	 38, // 0x774
+	 38, // 0x775
+	 38, // 0x776
+	 38, // 0x777
+	 38, // 0x778
+	 38, // 0x779
+	 38, // 0x77a
+	 38, // 0x77b
+	 38, // 0x77c
+	 38, // 0x77d
+	 38, // 0x77e
+	 38, // 0x77f
+	 38, // 0x780
+	 38, // 0x781
+	 38, // 0x782
+	 38, // 0x783
+	 38, // 0x784
+	 38, // 0x785
+	 38, // 0x786
+	 38, // 0x787
+	 38, // 0x788
+	 38, // 0x789
+	 38, // 0x78a
+	 38, // 0x78b
+	 38, // 0x78c
+	 38, // 0x78d
+	 38, // 0x78e
+	 38, // 0x78f
+	 38, // 0x790
+	 38, // 0x791
+	 38, // 0x792
+	 38, // 0x793
+	 38, // 0x794
+	 38, // 0x795
+	 38, // 0x796
+	 38, // 0x797
+	 38, // 0x798
+	 38, // 0x799
+	 38, // 0x79a
+	 38, // 0x79b
+	 38, // 0x79c
+	 38, // 0x79d
+	 38, // 0x79e
+	 38, // 0x79f
+	 38, // 0x7a0
+	 38, // 0x7a1
+	 38, // 0x7a2
+	 38, // 0x7a3
+	 38, // 0x7a4
+	 38, // 0x7a5
+	 38, // 0x7a6
+	 38, // 0x7a7
+	 38, // 0x7a8
+	 38, // 0x7a9
+	 38, // 0x7aa
+	 38, // 0x7ab
+	 38, // 0x7ac
+	 38, // 0x7ad
+	 38, // 0x7ae
+	 38, // 0x7af
+	 38, // 0x7b0
+	 38, // 0x7b1
+	 38, // 0x7b2
+	 38, // 0x7b3
+	 38, // 0x7b4
+	 38, // 0x7b5
+	 38, // 0x7b6
+	 38, // 0x7b7
+	 38, // 0x7b8
+	 38, // 0x7b9
+	 38, // 0x7ba
+	 38, // 0x7bb
+	 38, // 0x7bc
+	 38, // 0x7bd
+	 38, // 0x7be
+	 38, // 0x7bf
+	 38, // 0x7c0
+	 38, // 0x7c1
+	 38, // 0x7c2
+	 38, // 0x7c3
+	 38, // 0x7c4
+	 38, // 0x7c5
+	 38, // 0x7c6
+	 38, // 0x7c7
+	 38, // 0x7c8
+	 38, // 0x7c9
+	 38, // 0x7ca
+	 38, // 0x7cb
+	 38, // 0x7cc
+	 38, // 0x7cd
+	 38, // 0x7ce
+	 38, // 0x7cf
+	 38, // 0x7d0
+	 38, // 0x7d1
+	 38, // 0x7d2
+	 38, // 0x7d3
+	 38, // 0x7d4
+	 38, // 0x7d5
+	 38, // 0x7d6
+	 38, // 0x7d7
+	 38, // 0x7d8
+	 38, // 0x7d9
+	 38, // 0x7da
+	 38, // 0x7db
+	 38, // 0x7dc
+	 38, // 0x7dd
+	 38, // 0x7de
+	 38, // 0x7df
+	 38, // 0x7e0
+	 38, // 0x7e1
+	 38, // 0x7e2
+	 38, // 0x7e3
+	 38, // 0x7e4
+	 38, // 0x7e5
+	 38, // 0x7e6
+	 38, // 0x7e7
+	 38, // 0x7e8
+	 38, // 0x7e9
+	 38, // 0x7ea
+	 38, // 0x7eb
+	 38, // 0x7ec
+	 38, // 0x7ed
+	 38, // 0x7ee
+	 38, // 0x7ef
+	 38, // 0x7f0
+	 38, // 0x7f1
+	 38, // 0x7f2
+	 38, // 0x7f3
+	 38, // 0x7f4
+	 38, // 0x7f5
+	 38, // 0x7f6
+	 38, // 0x7f7
+	 38, // 0x7f8
+	 38, // 0x7f9
+	 38, // 0x7fa
+	 38, // 0x7fb
+	 38, // 0x7fc
+	 38, // 0x7fd
+	 38, // 0x7fe
+	 38, // 0x7ff
+	 38, // 0x800
+	 38, // 0x801
+	 38, // 0x802
+	 38, // 0x803
+	 38, // 0x804
+	 38, // 0x805
+	 38, // 0x806
+	 38, // 0x807
+	 38, // 0x808
+	 38, // 0x809
+	 38, // 0x80a
+	 38, // 0x80b
+	 38, // 0x80c
+	 38, // 0x80d
+	 38, // 0x80e
+	 38, // 0x80f
+	 38, // 0x810
+	 38, // 0x811
+	 38, // 0x812
+	 38, // 0x813
+	 38, // 0x814
+	 38, // 0x815
+	 38, // 0x816
+	 38, // 0x817
+	 38, // 0x818
+	 38, // 0x819
+	 38, // 0x81a
+	 38, // 0x81b
+	 38, // 0x81c
+	 38, // 0x81d
+	 38, // 0x81e
+	 38, // 0x81f
+	 38, // 0x820
+	 38, // 0x821
+	 38, // 0x822
+	 38, // 0x823
+	 38, // 0x824
+	 38, // 0x825
+	 38, // 0x826
+	 38, // 0x827
+	 38, // 0x828
+	 38, // 0x829
+	 38, // 0x82a
+	 38, // 0x82b
+	 38, // 0x82c
+	 38, // 0x82d
+	 38, // 0x82e
+	 38, // 0x82f
+	 38, // 0x830
+	 38, // 0x831
+	 38, // 0x832
+	 38, // 0x833
+	 38, // 0x834
+	 38, // 0x835
+	 38, // 0x836
+	 38, // 0x837
+	 38, // 0x838
+	 38, // 0x839
+	 38, // 0x83a
+	 38, // 0x83b
+	 38, // 0x83c
+	 38, // 0x83d
+	 38, // 0x83e
+	 38, // 0x83f
+	 38, // 0x840
+	 38, // 0x841
+	 38, // 0x842
+	 38, // 0x843
+	 38, // 0x844
+	 38, // 0x845
+	 38, // 0x846
+	 38, // 0x847
+	 38, // 0x848
+	 38, // 0x849
+	 38, // 0x84a
+	 38, // 0x84b
+	 38, // 0x84c
+	 38, // 0x84d
+	 38, // 0x84e
+	 38, // 0x84f
+	 38, // 0x850
+	 38, // 0x851
+	 38, // 0x852
+	 38, // 0x853
+	 38, // 0x854
+	 38, // 0x855
+	 38, // 0x856
+	 38, // 0x857
+	 38, // 0x858
+	 38, // 0x859
+	 38, // 0x85a
+	 38, // 0x85b
+	 38, // 0x85c
+	 38, // 0x85d
+	 38, // 0x85e
+	 38, // 0x85f
+	 38, // 0x860
+	 38, // 0x861
+	 38, // 0x862
+	 38, // 0x863
+	 38, // 0x864
+	 38, // 0x865
+	 38, // 0x866
+	 38, // 0x867
+	 38, // 0x868
+	 38, // 0x869
+	 38, // 0x86a
+	 38, // 0x86b
+	 38, // 0x86c
+	 38, // 0x86d
+	 38, // 0x86e
+	 38, // 0x86f
+	 38, // 0x870
+	 38, // 0x871
+	 38, // 0x872
+	 38, // 0x873
+	 38, // 0x874
+	 38, // 0x875
+	 38, // 0x876
+	 38, // 0x877
+	 38, // 0x878
+	 38, // 0x879
+	 38, // 0x87a
+	 38, // 0x87b
+	 38, // 0x87c
+	 38, // 0x87d
+	 38, // 0x87e
+	 38, // 0x87f
+	 38, // 0x880
+	 38, // 0x881
+	 38, // 0x882
+	 38, // 0x883
+	 38, // 0x884
+	 38, // 0x885
+	 38, // 0x886
+	 38, // 0x887
+	 38, // 0x888
+	 38, // 0x889
+	 38, // 0x88a
+	 38, // 0x88b
+	 38, // 0x88c
+	 38, // 0x88d
+	 38, // 0x88e
+	 38, // 0x88f
+	 38, // 0x890
+	 38, // 0x891
+	 38, // 0x892
+	 38, // 0x893
+	 38, // 0x894
+	 38, // 0x895
+	 38, // 0x896
+	 38, // 0x897
+	 38, // 0x898
+	 38, // 0x899
+	 38, // 0x89a
+	 38, // 0x89b
+	 38, // 0x89c
+	 38, // 0x89d
+	 38, // 0x89e
+	 38, // 0x89f
+	 38, // 0x8a0
+	 38, // 0x8a1
+	 38, // 0x8a2
+	 38, // 0x8a3
+	 38, // 0x8a4
+	 38, // 0x8a5
+	 38, // 0x8a6
+	 38, // 0x8a7
+	 38, // 0x8a8
+	 38, // 0x8a9
+	 38, // 0x8aa
+	 38, // 0x8ab
+	 38, // 0x8ac
+	 38, // 0x8ad
+	 38, // 0x8ae
+	 38, // 0x8af
+	 38, // 0x8b0
+	 38, // 0x8b1
+	 38, // 0x8b2
+	 38, // 0x8b3
+	 38, // 0x8b4
+	 38, // 0x8b5
+	 38, // 0x8b6
+	 38, // 0x8b7
+	 38, // 0x8b8
+	 38, // 0x8b9
+	 38, // 0x8ba
+	 38, // 0x8bb
+	 38, // 0x8bc
+	 38, // 0x8bd
+	 38, // 0x8be
+	 38, // 0x8bf
+	 38, // 0x8c0
+	 38, // 0x8c1
+	 38, // 0x8c2
+	 38, // 0x8c3
+	 38, // 0x8c4
+	 38, // 0x8c5
+	 38, // 0x8c6
+	 38, // 0x8c7
+	 38, // 0x8c8
+	 38, // 0x8c9
+	 38, // 0x8ca
+	 38, // 0x8cb
+	 38, // 0x8cc
+	 38, // 0x8cd
+	 38, // 0x8ce
+	 38, // 0x8cf
+	 38, // 0x8d0
+	 38, // 0x8d1
+	 38, // 0x8d2
+	 38, // 0x8d3
+	 38, // 0x8d4
+	 38, // 0x8d5
+	 38, // 0x8d6
+	 38, // 0x8d7
+	 38, // 0x8d8
+	 38, // 0x8d9
+	 38, // 0x8da
+	 38, // 0x8db
+	 38, // 0x8dc
+	 38, // 0x8dd
+	 38, // 0x8de
+	 38, // 0x8df
+	 38, // 0x8e0
+	 38, // 0x8e1
+	 38, // 0x8e2
+	 38, // 0x8e3
+	 38, // 0x8e4
+	 38, // 0x8e5
+	 38, // 0x8e6
+	 38, // 0x8e7
+	 38, // 0x8e8
+	 38, // 0x8e9
+	 38, // 0x8ea
+	 38, // 0x8eb
+	 38, // 0x8ec
+	 38, // 0x8ed
+	 38, // 0x8ee
+	 38, // 0x8ef
+	 38, // 0x8f0
+	 38, // 0x8f1
+	 38, // 0x8f2
+	 38, // 0x8f3
+	 38, // 0x8f4
+	 38, // 0x8f5
+	 38, // 0x8f6
+	 38, // 0x8f7
+	 38, // 0x8f8
+	 38, // 0x8f9
+	 38, // 0x8fa
+	 38, // 0x8fb
+	 38, // 0x8fc
+	 38, // 0x8fd
+	 38, // 0x8fe
+	 38, // 0x8ff
+	 38, // 0x900
+	 38, // 0x901
+	 38, // 0x902
+	 38, // 0x903
+	 38, // 0x904
+	 38, // 0x905
+	 38, // 0x906
+	 38, // 0x907
+	 38, // 0x908
+	 38, // 0x909
+	 38, // 0x90a
+	 38, // 0x90b
+	 38, // 0x90c
+	 38, // 0x90d
+	 38, // 0x90e
+	 38, // 0x90f
+	 38, // 0x910
+	 38, // 0x911
+	 38, // 0x912
+	 38, // 0x913
+	 38, // 0x914
+	 38, // 0x915
+	 38, // 0x916
+	 38, // 0x917
+	 38, // 0x918
+	 38, // 0x919
+	 38, // 0x91a
+	 38, // 0x91b
+	 38, // 0x91c
+	 38, // 0x91d
+	 38, // 0x91e
+	 38, // 0x91f
+	 38, // 0x920
+	 38, // 0x921
+	 38, // 0x922
+	 38, // 0x923
+	 38, // 0x924
+	 38, // 0x925
+	 38, // 0x926
+	 38, // 0x927
+	 38, // 0x928
+	 38, // 0x929
+	 38, // 0x92a
+	 38, // 0x92b
+	 38, // 0x92c
+	 38, // 0x92d
+	 38, // 0x92e
+	 38, // 0x92f
+	 38, // 0x930
+	 38, // 0x931
+	 38, // 0x932
+	 38, // 0x933
+	 38, // 0x934
+	 38, // 0x935
+	 38, // 0x936
+	 38, // 0x937
+	 38, // 0x938
+	 38, // 0x939
+	 38, // 0x93a
+	 38, // 0x93b
+	 38, // 0x93c
+	 38, // 0x93d
+	 38, // 0x93e
+	 38, // 0x93f
+	 38, // 0x940
+	 38, // 0x941
+	 38, // 0x942
+	 38, // 0x943
+	 38, // 0x944
+	 38, // 0x945
+	 38, // 0x946
+	 38, // 0x947
+	 38, // 0x948
+	 38, // 0x949
+	 38, // 0x94a
+	 38, // 0x94b
+	 38, // 0x94c
+	 38, // 0x94d
+	 38, // 0x94e
+	 38, // 0x94f
+	 38, // 0x950
+	 38, // 0x951
+	 38, // 0x952
+	 38, // 0x953
+	 38, // 0x954
+	 38, // 0x955
+	 38, // 0x956
+	 38, // 0x957
+	 38, // 0x958
+	 38, // 0x959
+	 38, // 0x95a
+	 38, // 0x95b
+	 38, // 0x95c
+	 38, // 0x95d
+	 38, // 0x95e
+	 38, // 0x95f
+	 38, // 0x960
+	 38, // 0x961
+	 38, // 0x962
+	 38, // 0x963
+	 38, // 0x964
+	 38, // 0x965
+	 38, // 0x966
+	 38, // 0x967
+	 38, // 0x968
+	 38, // 0x969
+	 38, // 0x96a
+	 38, // 0x96b
+	 38, // 0x96c
+	 38, // 0x96d
+	 38, // 0x96e
+	 38, // 0x96f
+	 38, // 0x970
+	 38, // 0x971
+	 38, // 0x972
+	 38, // 0x973
+	 38, // 0x974
+	 38, // 0x975
+	 38, // 0x976
+	 38, // 0x977
+	 38, // 0x978
+	 38, // 0x979
+	 38, // 0x97a
+	 38, // 0x97b
+	 38, // 0x97c
+	 38, // 0x97d
+	 38, // 0x97e
+	 38, // 0x97f
+	 38, // 0x980
+	 38, // 0x981
+	 38, // 0x982
+	 38, // 0x983
+	 38, // 0x984
+	 38, // 0x985
+	 38, // 0x986
+	 38, // 0x987
+	 38, // 0x988
+	 38, // 0x989
+	 38, // 0x98a
+	 38, // 0x98b
+	 38, // 0x98c
+	 38, // 0x98d
+	 38, // 0x98e
+	 38, // 0x98f
+	 38, // 0x990
+	 38, // 0x991
+	 38, // 0x992
+	 38, // 0x993
+	 38, // 0x994
+	 38, // 0x995
+	 38, // 0x996
+	 38, // 0x997
+	 38, // 0x998
+	 38, // 0x999
+	 38, // 0x99a
+	 38, // 0x99b
+	 38, // 0x99c
+	 38, // 0x99d
+	 38, // 0x99e
+	 38, // 0x99f
+	 38, // 0x9a0
+	 38, // 0x9a1
+	 38, // 0x9a2
+	 38, // 0x9a3
+	 38, // 0x9a4
+	 38, // 0x9a5
+	 38, // 0x9a6
+	 38, // 0x9a7
+	 38, // 0x9a8
+	 38, // 0x9a9
+	 38, // 0x9aa
+	 38, // 0x9ab
+	 38, // 0x9ac
+	 38, // 0x9ad
+	 38, // 0x9ae
+	 38, // 0x9af
+	 38, // 0x9b0
+	 38, // 0x9b1
+	 38, // 0x9b2
+	 38, // 0x9b3
+	 38, // 0x9b4
+	 38, // 0x9b5
+	 38, // 0x9b6
+	 38, // 0x9b7
+	 38, // 0x9b8
+	 38, // 0x9b9
+	 38, // 0x9ba
+	 38, // 0x9bb
+	 38, // 0x9bc
+	 38, // 0x9bd
+	 38, // 0x9be
+	 38, // 0x9bf
+	 38, // 0x9c0
+	 38, // 0x9c1
+	 38, // 0x9c2
+	 38, // 0x9c3
+	 38, // 0x9c4
+	 38, // 0x9c5
+	 38, // 0x9c6
+	 38, // 0x9c7
+	 38, // 0x9c8
+	 38, // 0x9c9
+	 38, // 0x9ca
+	 38, // 0x9cb
+	 38, // 0x9cc
+	 38, // 0x9cd
+	 38, // 0x9ce
+	 38, // 0x9cf
+	 38, // 0x9d0
+	 38, // 0x9d1
+	 38, // 0x9d2
+	 38, // 0x9d3
+	 38, // 0x9d4
+	 38, // 0x9d5
+	 38, // 0x9d6
+	 38, // 0x9d7
+	 38, // 0x9d8
+	 38, // 0x9d9
+	 38, // 0x9da
+	 38, // 0x9db
+	 38, // 0x9dc
+	 38, // 0x9dd
+	 38, // 0x9de
+	 38, // 0x9df
+	 38, // 0x9e0
+	 38, // 0x9e1
+	 38, // 0x9e2
+	 38, // 0x9e3
+	 38, // 0x9e4
+	 38, // 0x9e5
+	 38, // 0x9e6
+	 38, // 0x9e7
+	 38, // 0x9e8
+	 38, // 0x9e9
+	 38, // 0x9ea
+	 38, // 0x9eb
+	 38, // 0x9ec
+	 38, // 0x9ed
+	 38, // 0x9ee
+	 38, // 0x9ef
+	 38, // 0x9f0
+	 38, // 0x9f1
+	 38, // 0x9f2
+	 38, // 0x9f3
+	 38, // 0x9f4
+	 38, // 0x9f5
+	 38, // 0x9f6
+	 38, // 0x9f7
+	 38, // 0x9f8
+	 38, // 0x9f9
+	 38, // 0x9fa
+	 38, // 0x9fb
+	 38, // 0x9fc
+	 38, // 0x9fd
+	 38, // 0x9fe
+	 38, // 0x9ff
+	 38, // 0xa00
+	 38, // 0xa01
+	 38, // 0xa02
+	 38, // 0xa03
+	 38, // 0xa04
+	 38, // 0xa05
+	 38, // 0xa06
+	 38, // 0xa07
+	 38, // 0xa08
+	 38, // 0xa09
+	 38, // 0xa0a
+	 38, // 0xa0b
+	 38, // 0xa0c
+	 38, // 0xa0d
+	 38, // 0xa0e
+	 38, // 0xa0f
+	 38, // 0xa10
+	 38, // 0xa11
+	 38, // 0xa12
+	 38, // 0xa13
+	 38, // 0xa14
+	 38, // 0xa15
+	 38, // 0xa16
+	 38, // 0xa17
+	 38, // 0xa18
+	 38, // 0xa19
+	 38, // 0xa1a
+	 38, // 0xa1b
+	 38, // 0xa1c
+	 38, // 0xa1d
+	 38, // 0xa1e
+	 38, // 0xa1f
+	 38, // 0xa20
+	 38, // 0xa21
+	 38, // 0xa22
+	 38, // 0xa23
+	 38, // 0xa24
+	 38, // 0xa25
+	 38, // 0xa26
+	 38, // 0xa27
+	 38, // 0xa28
+	 38, // 0xa29
+	 38, // 0xa2a
+	 38, // 0xa2b
+	 38, // 0xa2c
+	 38, // 0xa2d
+	 38, // 0xa2e
+	 38, // 0xa2f
+	 38, // 0xa30
+	 38, // 0xa31
+	 38, // 0xa32
+	 38, // 0xa33
+	 38, // 0xa34
+	 38, // 0xa35
+	 38, // 0xa36
+	 38, // 0xa37
+	 38, // 0xa38
+	 38, // 0xa39
+	 38, // 0xa3a
+	 38, // 0xa3b
+	 38, // 0xa3c
+	 38, // 0xa3d
+	 38, // 0xa3e
+	 38, // 0xa3f
+	 38, // 0xa40
+	 38, // 0xa41
+	 38, // 0xa42
+	 38, // 0xa43
+	 38, // 0xa44
+	 38, // 0xa45
+	 38, // 0xa46
+	 38, // 0xa47
+	 38, // 0xa48
+	 38, // 0xa49
+	 38, // 0xa4a
+	 38, // 0xa4b
+	 38, // 0xa4c
+	 38, // 0xa4d
+	 38, // 0xa4e
+	 38, // 0xa4f
+	 38, // 0xa50
+	 38, // 0xa51
+	 38, // 0xa52
+	 38, // 0xa53
+	 38, // 0xa54
+	 38, // 0xa55
+	 38, // 0xa56
+	 38, // 0xa57
+	 38, // 0xa58
+	 38, // 0xa59
+	 38, // 0xa5a
+	 38, // 0xa5b
+	 38, // 0xa5c
+	 38, // 0xa5d
+	 38, // 0xa5e
+	 38, // 0xa5f
+	 38, // 0xa60
+	 38, // 0xa61
+	 38, // 0xa62
+	 38, // 0xa63
+	 38, // 0xa64
+	 38, // 0xa65
+	 38, // 0xa66
+	 38, // 0xa67
+	 38, // 0xa68
+	 38, // 0xa69
+	 38, // 0xa6a
+	 38, // 0xa6b
+	 38, // 0xa6c
+	 38, // 0xa6d
+	 38, // 0xa6e
+	 38, // 0xa6f
+	 38, // 0xa70
+	 38, // 0xa71
+	 38, // 0xa72
+	 38, // 0xa73
+	 38, // 0xa74
+	 38, // 0xa75
+	 38, // 0xa76
+	 38, // 0xa77
+	 38, // 0xa78
+	 38, // 0xa79
+	 38, // 0xa7a
+	 38, // 0xa7b
+	 38, // 0xa7c
+	 38, // 0xa7d
+	 38, // 0xa7e
+	 38, // 0xa7f
+	 38, // 0xa80
+	 38, // 0xa81
+	 38, // 0xa82
+	 38, // 0xa83
+	 38, // 0xa84
+	 38, // 0xa85
+	 38, // 0xa86
+	 38, // 0xa87
+	 38, // 0xa88
+	 38, // 0xa89
+	 38, // 0xa8a
+	 38, // 0xa8b
+	 38, // 0xa8c
+	 38, // 0xa8d
+	 38, // 0xa8e
+	 38, // 0xa8f
+	 38, // 0xa90
+	 38, // 0xa91
+	 38, // 0xa92
+	 38, // 0xa93
+	 38, // 0xa94
+	 38, // 0xa95
+	 38, // 0xa96
+	 38, // 0xa97
+	 38, // 0xa98
+	 38, // 0xa99
+	 38, // 0xa9a
+	 38, // 0xa9b
+	 38, // 0xa9c
+	 38, // 0xa9d
+	 38, // 0xa9e
+	 38, // 0xa9f
+	 38, // 0xaa0
+	 38, // 0xaa1
+	 38, // 0xaa2
+	 38, // 0xaa3
+	 38, // 0xaa4
+	 38, // 0xaa5
+	 38, // 0xaa6
+	 38, // 0xaa7
+	 38, // 0xaa8
+	 38, // 0xaa9
+	 38, // 0xaaa
+	 38, // 0xaab
+	 38, // 0xaac
+	 38, // 0xaad
+	 38, // 0xaae
+	 38, // 0xaaf
+	 38, // 0xab0
+	 38, // 0xab1
+	 38, // 0xab2
+	 38, // 0xab3
+	 38, // 0xab4
+	 38, // 0xab5
+	 38, // 0xab6
+	 38, // 0xab7
+	 38, // 0xab8
+	 38, // 0xab9
+	 38, // 0xaba
+	 38, // 0xabb
+	 38, // 0xabc
+	 38, // 0xabd
+	 38, // 0xabe
+	 38, // 0xabf
+	 38, // 0xac0
+	 38, // 0xac1
+	 38, // 0xac2
+	 38, // 0xac3
+	 38, // 0xac4
+	 38, // 0xac5
+	 38, // 0xac6
+	 38, // 0xac7
+	 38, // 0xac8
+	 38, // 0xac9
+	 38, // 0xaca
+	 38, // 0xacb
+	 38, // 0xacc
+	 38, // 0xacd
+	 38, // 0xace
+	 38, // 0xacf
+	 38, // 0xad0
+	 38, // 0xad1
+	 38, // 0xad2
+	 38, // 0xad3
+	 38, // 0xad4
+	 38, // 0xad5
+	 38, // 0xad6
+	 38, // 0xad7
+	 38, // 0xad8
+	 38, // 0xad9
+	 38, // 0xada
+	 38, // 0xadb
+	 38, // 0xadc
+	 38, // 0xadd
+	 38, // 0xade
+	 38, // 0xadf
+	 38, // 0xae0
+	 38, // 0xae1
+	 38, // 0xae2
+	 38, // 0xae3
+	 38, // 0xae4
+	 38, // 0xae5
+	 38, // 0xae6
+	 38, // 0xae7
+	 38, // 0xae8
+	 38, // 0xae9
+	 38, // 0xaea
+	 38, // 0xaeb
+	 38, // 0xaec
+	 38, // 0xaed
+	 38, // 0xaee
+	 38, // 0xaef
+	 38, // 0xaf0
+	 38, // 0xaf1
+	 38, // 0xaf2
+	 38, // 0xaf3
+	 38, // 0xaf4
+	 38, // 0xaf5
+	 38, // 0xaf6
+	 38, // 0xaf7
+	 38, // 0xaf8
+	 38, // 0xaf9
+	 38, // 0xafa
+	 38, // 0xafb
+	 38, // 0xafc
+	 38, // 0xafd
+	 38, // 0xafe
+	 38, // 0xaff
+	 38, // 0xb00
+	 38, // 0xb01
+	 38, // 0xb02
+	 38, // 0xb03
+	 38, // 0xb04
+	 38, // 0xb05
+	 38, // 0xb06
+	 38, // 0xb07
+	 38, // 0xb08
+	 38, // 0xb09
+	 38, // 0xb0a
+	 38, // 0xb0b
+	 38, // 0xb0c
+	 38, // 0xb0d
+	 38, // 0xb0e
+	 38, // 0xb0f
+	 38, // 0xb10
+	 38, // 0xb11
+	 38, // 0xb12
+	 38, // 0xb13
+	 38, // 0xb14
+	 38, // 0xb15
+	 38, // 0xb16
+	 38, // 0xb17
+	 38, // 0xb18
+	 38, // 0xb19
+	 38, // 0xb1a
+	 38, // 0xb1b
+	 38, // 0xb1c
+	 38, // 0xb1d
+	 38, // 0xb1e
+	 38, // 0xb1f
+	 38, // 0xb20
+	 38, // 0xb21
+	 38, // 0xb22
+	 38, // 0xb23
+	 38, // 0xb24
+	 38, // 0xb25
+	 38, // 0xb26
+	 38, // 0xb27
+	 38, // 0xb28
+	 38, // 0xb29
+	 38, // 0xb2a
+	 38, // 0xb2b
+	 38, // 0xb2c
+	 38, // 0xb2d
+	 38, // 0xb2e
+	 38, // 0xb2f
+	 38, // 0xb30
+	 38, // 0xb31
+	 38, // 0xb32
+	 38, // 0xb33
+	 38, // 0xb34
+	 38, // 0xb35
+	 38, // 0xb36
+	 38, // 0xb37
+	 38, // 0xb38
+	 38, // 0xb39
+	 38, // 0xb3a
+	 38, // 0xb3b
+	 38, // 0xb3c
+	 38, // 0xb3d
+	 38, // 0xb3e
+	 38, // 0xb3f
+	 38, // 0xb40
+	 38, // 0xb41
+	 38, // 0xb42
+	 38, // 0xb43
+	 38, // 0xb44
+	 38, // 0xb45
+	 38, // 0xb46
+	 38, // 0xb47
+	 38, // 0xb48
+	 38, // 0xb49
+	 38, // 0xb4a
+	 38, // 0xb4b
+	 38, // 0xb4c
+	 38, // 0xb4d
+	 38, // 0xb4e
+	 38, // 0xb4f
+	 38, // 0xb50
+	 38, // 0xb51
+	 38, // 0xb52
+	 38, // 0xb53
+	 38, // 0xb54
+	 38, // 0xb55
+	 38, // 0xb56
+	 38, // 0xb57
+	 38, // 0xb58
+	 38, // 0xb59
+	 38, // 0xb5a
+	 38, // 0xb5b
+	 38, // 0xb5c
+	 38, // 0xb5d
+	 38, // 0xb5e
+	 38, // 0xb5f
+	 38, // 0xb60
+	 38, // 0xb61
+	 38, // 0xb62
+	 38, // 0xb63
+	 38, // 0xb64
+	 38, // 0xb65
+	 38, // 0xb66
+	 38, // 0xb67
+	 38, // 0xb68
+	 38, // 0xb69
+	 38, // 0xb6a
+	 38, // 0xb6b
+	 38, // 0xb6c
+	 38, // 0xb6d
+	 38, // 0xb6e
+	 38, // 0xb6f
+	 38, // 0xb70
+	 38, // 0xb71
+	 38, // 0xb72
+	 38, // 0xb73
+	 38, // 0xb74
+	 38, // 0xb75
+	 38, // 0xb76
+	 38, // 0xb77
+	 38, // 0xb78
+	 38, // 0xb79
+	 38, // 0xb7a
+	 38, // 0xb7b
+	 38, // 0xb7c
+	 38, // 0xb7d
+	 38, // 0xb7e
+	 38, // 0xb7f
+	 38, // 0xb80
+	 38, // 0xb81
+	 38, // 0xb82
+	 38, // 0xb83
+	 38, // 0xb84
+	 38, // 0xb85
+	 38, // 0xb86
+	 38, // 0xb87
+	 38, // 0xb88
+	 38, // 0xb89
+	 38, // 0xb8a
+	 38, // 0xb8b
+	 38, // 0xb8c
+	 38, // 0xb8d
+	 38, // 0xb8e
+	 38, // 0xb8f
+	 38, // 0xb90
+	 38, // 0xb91
+	 38, // 0xb92
+	 38, // 0xb93
+	 38, // 0xb94
+	 38, // 0xb95
+	 38, // 0xb96
+	 38, // 0xb97
+	 38, // 0xb98
+	 38, // 0xb99
+	 38, // 0xb9a
+	 38, // 0xb9b
+	 38, // 0xb9c
+	 38, // 0xb9d
+	 38, // 0xb9e
+	 38, // 0xb9f
+	 38, // 0xba0
+	 38, // 0xba1
+	 38, // 0xba2
+	 38, // 0xba3
+	 38, // 0xba4
+	 38, // 0xba5
+	 38, // 0xba6
+	 38, // 0xba7
+	 38, // 0xba8
+	 38, // 0xba9
+	 38, // 0xbaa
+	 38, // 0xbab
+	 38, // 0xbac
+	 38, // 0xbad
+	 38, // 0xbae
+	 38, // 0xbaf
+	 38, // 0xbb0
+	 38, // 0xbb1
+	 38, // 0xbb2
+	 38, // 0xbb3
+	 38, // 0xbb4
+	 38, // 0xbb5
+	 38, // 0xbb6
+	 38, // 0xbb7
+	 38, // 0xbb8
+	 38, // 0xbb9
+	 38, // 0xbba
+	 38, // 0xbbb
+	 38, // 0xbbc
+	 38, // 0xbbd
+	 38, // 0xbbe
+	 38, // 0xbbf
+	 38, // 0xbc0
+	 38, // 0xbc1
+	 38, // 0xbc2
+	 38, // 0xbc3
+	 38, // 0xbc4
+	 38, // 0xbc5
+	 38, // 0xbc6
+	 38, // 0xbc7
+	 38, // 0xbc8
+	 38, // 0xbc9
+	 38, // 0xbca
+	 38, // 0xbcb
+	 38, // 0xbcc
+	 38, // 0xbcd
+	 38, // 0xbce
+	 38, // 0xbcf
+	 38, // 0xbd0
+	 38, // 0xbd1
+	 38, // 0xbd2
+	 38, // 0xbd3
+	 38, // 0xbd4
+	 38, // 0xbd5
+	 38, // 0xbd6
+	 38, // 0xbd7
+	 38, // 0xbd8
+	 38, // 0xbd9
+	 38, // 0xbda
+	 38, // 0xbdb
+	 38, // 0xbdc
+	 38, // 0xbdd
+	 38, // 0xbde
+	 38, // 0xbdf
+	 38, // 0xbe0
+	 38, // 0xbe1
+	 38, // 0xbe2
+	 38, // 0xbe3
+	 38, // 0xbe4
+	 38, // 0xbe5
+	 38, // 0xbe6
+	 38, // 0xbe7
+	 38, // 0xbe8
+	 38, // 0xbe9
+	 38, // 0xbea
+	 38, // 0xbeb
+	 38, // 0xbec
+	 38, // 0xbed
+	 38, // 0xbee
+	 38, // 0xbef
+	 38, // 0xbf0
+	 38, // 0xbf1
+	 38, // 0xbf2
+	 38, // 0xbf3
+	 38, // 0xbf4
+	 38, // 0xbf5
+	 38, // 0xbf6
+	 38, // 0xbf7
+	 38, // 0xbf8
+	 38, // 0xbf9
+	 38, // 0xbfa
+	 38, // 0xbfb
+	 38, // 0xbfc
+	 38, // 0xbfd
+	 38, // 0xbfe
+	 38, // 0xbff
+	 38, // 0xc00
+	 38, // 0xc01
+	 38, // 0xc02
+	 38, // 0xc03
+	 38, // 0xc04
+	 38, // 0xc05
+	 38, // 0xc06
+	 38, // 0xc07
+	 38, // 0xc08
+	 38, // 0xc09
+	 38, // 0xc0a
+	 38, // 0xc0b
+	 38, // 0xc0c
+	 38, // 0xc0d
+	 38, // 0xc0e
+	 38, // 0xc0f
+	 38, // 0xc10
+	 38, // 0xc11
+	 38, // 0xc12
+	 38, // 0xc13
+	 38, // 0xc14
+	 38, // 0xc15
+	 38, // 0xc16
+	 38, // 0xc17
+	 38, // 0xc18
+	 38, // 0xc19
+	 38, // 0xc1a
+	 38, // 0xc1b
+	 38, // 0xc1c
+	 38, // 0xc1d
+	 38, // 0xc1e
+	 38, // 0xc1f
+	 38, // 0xc20
+	 38, // 0xc21
+	 38, // 0xc22
+	 38, // 0xc23
+	 38, // 0xc24
+	 38, // 0xc25
+	 38, // 0xc26
+	 38, // 0xc27
+	 38, // 0xc28
+	 38, // 0xc29
+	 38, // 0xc2a
+	 38, // 0xc2b
+	 38, // 0xc2c
+	 38, // 0xc2d
+	 38, // 0xc2e
+	 38, // 0xc2f
+	 38, // 0xc30
+	 38, // 0xc31
+	 38, // 0xc32
+	 38, // 0xc33
+	 38, // 0xc34
+	 38, // 0xc35
+	 38, // 0xc36
+	 38, // 0xc37
+	 38, // 0xc38
+	 38, // 0xc39
+	 38, // 0xc3a
+	 38, // 0xc3b
+	 38, // 0xc3c
+	 38, // 0xc3d
+	 38, // 0xc3e
+	 38, // 0xc3f
+	 38, // 0xc40
+	 38, // 0xc41
+	 38, // 0xc42
+	 38, // 0xc43
+	 38, // 0xc44
+	 38, // 0xc45
+	 38, // 0xc46
+	 38, // 0xc47
+	 38, // 0xc48
+	 38, // 0xc49
+	 38, // 0xc4a
+	 38, // 0xc4b
+	 38, // 0xc4c
+	 38, // 0xc4d
+	 38, // 0xc4e
+	 38, // 0xc4f
+	 38, // 0xc50
+	 38, // 0xc51
+	 38, // 0xc52
+	 38, // 0xc53
+	 38, // 0xc54
+	 38, // 0xc55
+	 38, // 0xc56
+	 38, // 0xc57
+	 38, // 0xc58
+	 38, // 0xc59
+	 38, // 0xc5a
+	 38, // 0xc5b
+	 38, // 0xc5c
+	 38, // 0xc5d
+	 38, // 0xc5e
+	 38, // 0xc5f
+	 38, // 0xc60
+	 38, // 0xc61
+	 38, // 0xc62
+	 38, // 0xc63
+	 38, // 0xc64
+	 38, // 0xc65
+	 38, // 0xc66
+	 38, // 0xc67
+	 38, // 0xc68
+	 38, // 0xc69
+	 38, // 0xc6a
+	 38, // 0xc6b
+	 38, // 0xc6c
+	 38, // 0xc6d
+	 38, // 0xc6e
+	 38, // 0xc6f
+	 38, // 0xc70
+	 38, // 0xc71
+	 38, // 0xc72
+	 38, // 0xc73
+	 38, // 0xc74
+	 38, // 0xc75
+	 38, // 0xc76
+	 38, // 0xc77
+	 38, // 0xc78
+	 38, // 0xc79
+	 38, // 0xc7a
+	 38, // 0xc7b
+	 38, // 0xc7c
+	 38, // 0xc7d
+	 38, // 0xc7e
+	 38, // 0xc7f
+	 38, // 0xc80
+	 38, // 0xc81
+	 38, // 0xc82
+	 38, // 0xc83
+	 38, // 0xc84
+	 38, // 0xc85
+	 38, // 0xc86
+	 38, // 0xc87
+	 38, // 0xc88
+	 38, // 0xc89
+	 38, // 0xc8a
+	 38, // 0xc8b
+	 38, // 0xc8c
+	 38, // 0xc8d
+	 38, // 0xc8e
+	 38, // 0xc8f
+	 38, // 0xc90
+	 38, // 0xc91
+	 38, // 0xc92
+	 38, // 0xc93
+	 38, // 0xc94
+	 38, // 0xc95
+	 38, // 0xc96
+	 38, // 0xc97
+	 38, // 0xc98
+	 38, // 0xc99
+	 38, // 0xc9a
+	 38, // 0xc9b
+	 38, // 0xc9c
+	 38, // 0xc9d
+	 38, // 0xc9e
+	 38, // 0xc9f
+	 38, // 0xca0
+	 38, // 0xca1
+	 38, // 0xca2
+	 38, // 0xca3
+	 38, // 0xca4
+	 38, // 0xca5
+	 38, // 0xca6
+	 38, // 0xca7
+	 38, // 0xca8
+	 38, // 0xca9
+	 38, // 0xcaa
+	 38, // 0xcab
+	 38, // 0xcac
+	 38, // 0xcad
+	 38, // 0xcae
+	 38, // 0xcaf
+	 38, // 0xcb0
+	 38, // 0xcb1
+	 38, // 0xcb2
+	 38, // 0xcb3
+	 38, // 0xcb4
+	 38, // 0xcb5
+	 38, // 0xcb6
+	 38, // 0xcb7
+	 38, // 0xcb8
+	 38, // 0xcb9
+	 38, // 0xcba
+	 38, // 0xcbb
+	 38, // 0xcbc
+	 38, // 0xcbd
+	 38, // 0xcbe
+	 38, // 0xcbf
+	 38, // 0xcc0
+	 38, // 0xcc1
+	 38, // 0xcc2
+	 38, // 0xcc3
+	 38, // 0xcc4
+	 38, // 0xcc5
+	 38, // 0xcc6
+	 38, // 0xcc7
+	 38, // 0xcc8
+	 38, // 0xcc9
+	 38, // 0xcca
+	 38, // 0xccb
+	 38, // 0xccc
+	 38, // 0xccd
+	 38, // 0xcce
+	 38, // 0xccf
+	 38, // 0xcd0
+	 38, // 0xcd1
+	 38, // 0xcd2
+	 38, // 0xcd3
+	 38, // 0xcd4
+	 38, // 0xcd5
+	 38, // 0xcd6
+	 38, // 0xcd7
+	 38, // 0xcd8
+	 38, // 0xcd9
+	 38, // 0xcda
+	 38, // 0xcdb
+	 38, // 0xcdc
+	 38, // 0xcdd
+	 38, // 0xcde
+	 38, // 0xcdf
+	 38, // 0xce0
+	 38, // 0xce1
+	 38, // 0xce2
+	 38, // 0xce3
+	 38, // 0xce4
+	 38, // 0xce5
+	 38, // 0xce6
+	 38, // 0xce7
+	 38, // 0xce8
+	 38, // 0xce9
+	 38, // 0xcea
+	 38, // 0xceb
+	 38, // 0xcec
+	 38, // 0xced
+	 38, // 0xcee
+	 38, // 0xcef
+	 38, // 0xcf0
+	 38, // 0xcf1
+	 38, // 0xcf2
+	 38, // 0xcf3
+	 38, // 0xcf4
+	 38, // 0xcf5
+	 38, // 0xcf6
+	 38, // 0xcf7
+	 38, // 0xcf8
+	 38, // 0xcf9
+	 38, // 0xcfa
+	 38, // 0xcfb
+	 38, // 0xcfc
+	 38, // 0xcfd
+	 38, // 0xcfe
+	 38, // 0xcff
+	 38, // 0xd00
+	 38, // 0xd01
+	 38, // 0xd02
+	 38, // 0xd03
+	 38, // 0xd04
+	 38, // 0xd05
+	 38, // 0xd06
+	 38, // 0xd07
+	 38, // 0xd08
+	 38, // 0xd09
+	 38, // 0xd0a
+	 38, // 0xd0b
+	 38, // 0xd0c
+	 38, // 0xd0d
+	 38, // 0xd0e
+	 38, // 0xd0f
+	 38, // 0xd10
+	 38, // 0xd11
+	 38, // 0xd12
+	 38, // 0xd13
+	 38, // 0xd14
+	 38, // 0xd15
+	 38, // 0xd16
+	 38, // 0xd17
+	 38, // 0xd18
+	 38, // 0xd19
+	 38, // 0xd1a
+	 38, // 0xd1b
+	 38, // 0xd1c
+	 38, // 0xd1d
+	 38, // 0xd1e
+	 38, // 0xd1f
+	 38, // 0xd20
+	 38, // 0xd21
+	 38, // 0xd22
+	 38, // 0xd23
+	 38, // 0xd24
+	 38, // 0xd25
+	 38, // 0xd26
+	 38, // 0xd27
+	 38, // 0xd28
+	 38, // 0xd29
+	 38, // 0xd2a
+	 38, // 0xd2b
+	 38, // 0xd2c
+	 38, // 0xd2d
+	 38, // 0xd2e
+	 38, // 0xd2f
+	 38, // 0xd30
+	 38, // 0xd31
+	 38, // 0xd32
+	 38, // 0xd33
+	 38, // 0xd34
+	 38, // 0xd35
+	 38, // 0xd36
+	 38, // 0xd37
+	 38, // 0xd38
+	 38, // 0xd39
+	 38, // 0xd3a
+	 38, // 0xd3b
+	 38, // 0xd3c
+	 38, // 0xd3d
+	 38, // 0xd3e
+	 38, // 0xd3f
+	 38, // 0xd40
+	 38, // 0xd41
+	 38, // 0xd42
+	 38, // 0xd43
+	 38, // 0xd44
+	 38, // 0xd45
+	 38, // 0xd46
+	 38, // 0xd47
+	 38, // 0xd48
+	 38, // 0xd49
+	 38, // 0xd4a
+	 38, // 0xd4b
+	 38, // 0xd4c
+	 38, // 0xd4d
+	 38, // 0xd4e
+	 38, // 0xd4f
+	 38, // 0xd50
+	 38, // 0xd51
+	 38, // 0xd52
+	 38, // 0xd53
+	 38, // 0xd54
+	 38, // 0xd55
+	 38, // 0xd56
+	 38, // 0xd57
+	 38, // 0xd58
+	 38, // 0xd59
+	 38, // 0xd5a
+	 38, // 0xd5b
+	 38, // 0xd5c
+	 38, // 0xd5d
+	 38, // 0xd5e
+	 38, // 0xd5f
+	 38, // 0xd60
+	 38, // 0xd61
+	 38, // 0xd62
+	 38, // 0xd63
+	 38, // 0xd64
+	 38, // 0xd65
+	 38, // 0xd66
+	 38, // 0xd67
+	 38, // 0xd68
+	 38, // 0xd69
+	 38, // 0xd6a
+	 38, // 0xd6b
+	 38, // 0xd6c
+	 38, // 0xd6d
+	 38, // 0xd6e
+	 38, // 0xd6f
+	 38, // 0xd70
+	 38, // 0xd71
+	 38, // 0xd72
+	 38, // 0xd73
+	 38, // 0xd74
+	 38, // 0xd75
+	 38, // 0xd76
+	 38, // 0xd77
+	 38, // 0xd78
+	 38, // 0xd79
+	 38, // 0xd7a
+	 38, // 0xd7b
+	 38, // 0xd7c
+	 38, // 0xd7d
+	 38, // 0xd7e
+	 38, // 0xd7f
+	 38, // 0xd80
+	 38, // 0xd81
+	 38, // 0xd82
+	 38, // 0xd83
+	 38, // 0xd84
+	 38, // 0xd85
+	 38, // 0xd86
+	 38, // 0xd87
+	 38, // 0xd88
+	 38, // 0xd89
+	 38, // 0xd8a
+	 38, // 0xd8b
+	 38, // 0xd8c
+	 38, // 0xd8d
+	 38, // 0xd8e
+	 38, // 0xd8f
+	 38, // 0xd90
+	 38, // 0xd91
+	 38, // 0xd92
+	 38, // 0xd93
+	 38, // 0xd94
+	 38, // 0xd95
+	 38, // 0xd96
+	 38, // 0xd97
+	 38, // 0xd98
+	 38, // 0xd99
+	 38, // 0xd9a
+	 38, // 0xd9b
+	 38, // 0xd9c
+	 38, // 0xd9d
+	 38, // 0xd9e
+	 38, // 0xd9f
+	 38, // 0xda0
+	 38, // 0xda1
+	 38, // 0xda2
+	 38, // 0xda3
+	 38, // 0xda4
+	 38, // 0xda5
+	 38, // 0xda6
+	 38, // 0xda7
+	 38, // 0xda8
+	 38, // 0xda9
+	 38, // 0xdaa
+	 38, // 0xdab
+	 38, // 0xdac
+	 38, // 0xdad
+	 38, // 0xdae
+	 38, // 0xdaf
+	 38, // 0xdb0
+	 38, // 0xdb1
+	 38, // 0xdb2
+	 38, // 0xdb3
+	 38, // 0xdb4
+	 38, // 0xdb5
+	 38, // 0xdb6
+	 38, // 0xdb7
+	 38, // 0xdb8
+	 38, // 0xdb9
+	 38, // 0xdba
+	 38, // 0xdbb
+	 38, // 0xdbc
+	 38, // 0xdbd
+	 38, // 0xdbe
+	 38, // 0xdbf
+	 38, // 0xdc0
+	 38, // 0xdc1
+	 38, // 0xdc2
+	 38, // 0xdc3
+	 38, // 0xdc4
+	 38, // 0xdc5
+	 38, // 0xdc6
+	 38, // 0xdc7
+	 38, // 0xdc8
+	 38, // 0xdc9
+	 38, // 0xdca
+	 38, // 0xdcb
+	 38, // 0xdcc
+	 38, // 0xdcd
+	 38, // 0xdce
+	 38, // 0xdcf
+	 38, // 0xdd0
+	 38, // 0xdd1
+	 38, // 0xdd2
+	 38, // 0xdd3
+	 38, // 0xdd4
+	 38, // 0xdd5
+	 38, // 0xdd6
+	 38, // 0xdd7
+	 38, // 0xdd8
+	 38, // 0xdd9
+	 38, // 0xdda
+	 38, // 0xddb
+	 38, // 0xddc
+	 38, // 0xddd
+	 38, // 0xdde
+	 38, // 0xddf
+	 38, // 0xde0
+	 38, // 0xde1
+	 38, // 0xde2
+	 38, // 0xde3
+	 38, // 0xde4
+	 38, // 0xde5
+	 38, // 0xde6
+	 38, // 0xde7
+	 38, // 0xde8
+	 38, // 0xde9
+	 38, // 0xdea
+	 38, // 0xdeb
+	 38, // 0xdec
+	 38, // 0xded
+	 38, // 0xdee
+	 38, // 0xdef
+	 38, // 0xdf0
+	 38, // 0xdf1
+	 38, // 0xdf2
+	 38, // 0xdf3
+	 38, // 0xdf4
+	 38, // 0xdf5
+	 38, // 0xdf6
+	 38, // 0xdf7
+	 38, // 0xdf8
+	 38, // 0xdf9
+	 38, // 0xdfa
+	 38, // 0xdfb
+	 38, // 0xdfc
+	 38, // 0xdfd
+	 38, // 0xdfe
+	 38, // 0xdff
+	 38, // 0xe00
+	 135, // 0xe01
+	 38, // 0xe02
+	 38, // 0xe03
+	 38, // 0xe04
+	 38, // 0xe05
+	 38, // 0xe06
+	 38, // 0xe07
+	 38, // 0xe08
+	 38, // 0xe09
+	 38, // 0xe0a
+	 38, // 0xe0b
+	 38, // 0xe0c
+	 38, // 0xe0d
+	 38, // 0xe0e
+	 38, // 0xe0f
+	 38, // 0xe10
+	 38, // 0xe11
+	 38, // 0xe12
+	 38, // 0xe13
+	 38, // 0xe14
+	 38, // 0xe15
+	 38, // 0xe16
+	 38, // 0xe17
+	 38, // 0xe18
+	 38, // 0xe19
+	 38, // 0xe1a
+	 38, // 0xe1b
+	 38, // 0xe1c
+	 38, // 0xe1d
+	 38, // 0xe1e
+	 38, // 0xe1f
+	 38, // 0xe20
+	 38, // 0xe21
+	 38, // 0xe22
+	 38, // 0xe23
+	 38, // 0xe24
+	 38, // 0xe25
+	 38, // 0xe26
+	 38, // 0xe27
+	 38, // 0xe28
+	 38, // 0xe29
+	 38, // 0xe2a
+	 38, // 0xe2b
+	 38, // 0xe2c
+	 38, // 0xe2d
+	 38, // 0xe2e
+	 38, // 0xe2f
+	 38, // 0xe30
+	 38, // 0xe31
+	 38, // 0xe32
+	 38, // 0xe33
+	 38, // 0xe34
+	 38, // 0xe35
+	 38, // 0xe36
+	 38, // 0xe37
+	 38, // 0xe38
+	 38, // 0xe39
+	 38, // 0xe3a
+	 38, // 0xe3b
+	 38, // 0xe3c
+	 38, // 0xe3d
+	 38, // 0xe3e
+	 38, // 0xe3f
+	 38, // 0xe40
+	 38, // 0xe41
+	 38, // 0xe42
+	 38, // 0xe43
+	 38, // 0xe44
+	 38, // 0xe45
+	 38, // 0xe46
+	 38, // 0xe47
+	 38, // 0xe48
+	 38, // 0xe49
+	 38, // 0xe4a
+	 38, // 0xe4b
+	 38, // 0xe4c
+	 38, // 0xe4d
+	 38, // 0xe4e
+	 38, // 0xe4f
+	 38, // 0xe50
+	 38, // 0xe51
+	 38, // 0xe52
+	 38, // 0xe53
+	 38, // 0xe54
+	 38, // 0xe55
+	 38, // 0xe56
+	 38, // 0xe57
+	 38, // 0xe58
+	 38, // 0xe59
+	 38, // 0xe5a
+	 38, // 0xe5b
+	 38, // 0xe5c
+	 38, // 0xe5d
+	 38, // 0xe5e
+	 38, // 0xe5f
+	 38, // 0xe60
+	 38, // 0xe61
+	 38, // 0xe62
+	 38, // 0xe63
+	 38, // 0xe64
+	 38, // 0xe65
+	 38, // 0xe66
+	 38, // 0xe67
+	 38, // 0xe68
+	 38, // 0xe69
+	 38, // 0xe6a
+	 38, // 0xe6b
+	 38, // 0xe6c
+	 38, // 0xe6d
+	 38, // 0xe6e
+	 38, // 0xe6f
+	 38, // 0xe70
+	 38, // 0xe71
+	 38, // 0xe72
+	 38, // 0xe73
+	 38, // 0xe74
+	 38, // 0xe75
+	 38, // 0xe76
+	 38, // 0xe77
+	 38, // 0xe78
+	 38, // 0xe79
+	 38, // 0xe7a
+	 38, // 0xe7b
+	 38, // 0xe7c
+	 38, // 0xe7d
+	 38, // 0xe7e
+	 38, // 0xe7f
+	 38, // 0xe80
+	 38, // 0xe81
+	 38, // 0xe82
+	 38, // 0xe83
+	 38, // 0xe84
+	 38, // 0xe85
+	 38, // 0xe86
+	 38, // 0xe87
+	 38, // 0xe88
+	 38, // 0xe89
+	 38, // 0xe8a
+	 38, // 0xe8b
+	 38, // 0xe8c
+	 38, // 0xe8d
+	 38, // 0xe8e
+	 38, // 0xe8f
+	 38, // 0xe90
+	 38, // 0xe91
+	 38, // 0xe92
+	 38, // 0xe93
+	 38, // 0xe94
+	 38, // 0xe95
+	 38, // 0xe96
+	 38, // 0xe97
+	 38, // 0xe98
+	 38, // 0xe99
+	 38, // 0xe9a
+	 38, // 0xe9b
+	 38, // 0xe9c
+	 38, // 0xe9d
+	 38, // 0xe9e
+	 38, // 0xe9f
+	 38, // 0xea0
+	 38, // 0xea1
+	 38, // 0xea2
+	 38, // 0xea3
+	 38, // 0xea4
+	 38, // 0xea5
+	 38, // 0xea6
+	 38, // 0xea7
+	 38, // 0xea8
+	 38, // 0xea9
+	 38, // 0xeaa
+	 38, // 0xeab
+	 38, // 0xeac
+	 38, // 0xead
+	 38, // 0xeae
+	 38, // 0xeaf
+	 38, // 0xeb0
+	 38, // 0xeb1
+	 38, // 0xeb2
+	 38, // 0xeb3
+	 38, // 0xeb4
+	 38, // 0xeb5
+	 38, // 0xeb6
+	 38, // 0xeb7
+	 38, // 0xeb8
+	 38, // 0xeb9
+	 38, // 0xeba
+	 38, // 0xebb
+	 38, // 0xebc
+	 38, // 0xebd
+	 38, // 0xebe
+	 38, // 0xebf
+	 38, // 0xec0
+	 38, // 0xec1
+	 38, // 0xec2
+	 38, // 0xec3
+	 38, // 0xec4
+	 38, // 0xec5
+	 38, // 0xec6
+	 38, // 0xec7
+	 38, // 0xec8
+	 38, // 0xec9
+	 38, // 0xeca
+	 38, // 0xecb
+	 38, // 0xecc
+	 38, // 0xecd
+	 38, // 0xece
+	 38, // 0xecf
+	 38, // 0xed0
+	 38, // 0xed1
+	 38, // 0xed2
+	 38, // 0xed3
+	 38, // 0xed4
+	 38, // 0xed5
+	 38, // 0xed6
+	 38, // 0xed7
+	 38, // 0xed8
+	 38, // 0xed9
+	 38, // 0xeda
+	 38, // 0xedb
+	 38, // 0xedc
+	 38, // 0xedd
+	 38, // 0xede
+	 38, // 0xedf
+	 38, // 0xee0
+	 38, // 0xee1
+	 38, // 0xee2
+	 38, // 0xee3
+	 38, // 0xee4
+	 38, // 0xee5
+	 38, // 0xee6
+	 38, // 0xee7
+	 38, // 0xee8
+	 38, // 0xee9
+	 38, // 0xeea
+	 38, // 0xeeb
+	 38, // 0xeec
+	 38, // 0xeed
+	 38, // 0xeee
+	 38, // 0xeef
+	 38, // 0xef0
+	 38, // 0xef1
+	 38, // 0xef2
+	 38, // 0xef3
+	 38, // 0xef4
+	 38, // 0xef5
+	 38, // 0xef6
+	 38, // 0xef7
+	 38, // 0xef8
+	 38, // 0xef9
+	 38, // 0xefa
+	 38, // 0xefb
+	 38, // 0xefc
+	 38, // 0xefd
+	 38, // 0xefe
+	 38, // 0xeff
+	 38, // 0xf00
+	 38, // 0xf01
+	 38, // 0xf02
+	 38, // 0xf03
+	 38, // 0xf04
+	 38, // 0xf05
+	 38, // 0xf06
+	 38, // 0xf07
+	 38, // 0xf08
+	 38, // 0xf09
+	 38, // 0xf0a
+	 38, // 0xf0b
+	 38, // 0xf0c
+	 38, // 0xf0d
+	 38, // 0xf0e
+	 38, // 0xf0f
+	 38, // 0xf10
+	 38, // 0xf11
+	 38, // 0xf12
+	 38, // 0xf13
+	 38, // 0xf14
+	 38, // 0xf15
+	 38, // 0xf16
+	 38, // 0xf17
+	 38, // 0xf18
+	 38, // 0xf19
+	 38, // 0xf1a
+	 38, // 0xf1b
+	 38, // 0xf1c
+	 38, // 0xf1d
+	 38, // 0xf1e
+	 38, // 0xf1f
+	 38, // 0xf20
+	 38, // 0xf21
+	 38, // 0xf22
+	 38, // 0xf23
+	 38, // 0xf24
+	 38, // 0xf25
+	 38, // 0xf26
+	 38, // 0xf27
+	 38, // 0xf28
+	 38, // 0xf29
+	 38, // 0xf2a
+	 38, // 0xf2b
+	 38, // 0xf2c
+	 38, // 0xf2d
+	 38, // 0xf2e
+	 38, // 0xf2f
+	 38, // 0xf30
+	 38, // 0xf31
+	 38, // 0xf32
+	 38, // 0xf33
+	 38, // 0xf34
+	 38, // 0xf35
+	 38, // 0xf36
+	 38, // 0xf37
+	 38, // 0xf38
+	 38, // 0xf39
+	 38, // 0xf3a
+	 38, // 0xf3b
+	 38, // 0xf3c
+	 38, // 0xf3d
+	 38, // 0xf3e
+	 38, // 0xf3f
+	 38, // 0xf40
+	 38, // 0xf41
+	 38, // 0xf42
+	 38, // 0xf43
+	 38, // 0xf44
+	 38, // 0xf45
+	 38, // 0xf46
+	 38, // 0xf47
+	 38, // 0xf48
+	 38, // 0xf49
+	 38, // 0xf4a
+	 38, // 0xf4b
+	 38, // 0xf4c
+	 38, // 0xf4d
+	 38, // 0xf4e
+	 38, // 0xf4f
+	 38, // 0xf50
+	 38, // 0xf51
+	 38, // 0xf52
+	 38, // 0xf53
+	 38, // 0xf54
+	 38, // 0xf55
+	 38, // 0xf56
+	 38, // 0xf57
+	 38, // 0xf58
+	 38, // 0xf59
+	 38, // 0xf5a
+	 38, // 0xf5b
+	 38, // 0xf5c
+	 38, // 0xf5d
+	 38, // 0xf5e
+	 38, // 0xf5f
+	 38, // 0xf60
+	 38, // 0xf61
+	 38, // 0xf62
+	 38, // 0xf63
+	 38, // 0xf64
+	 38, // 0xf65
+	 38, // 0xf66
+	 38, // 0xf67
+	 38, // 0xf68
+	 38, // 0xf69
+	 38, // 0xf6a
+	 38, // 0xf6b
+	 38, // 0xf6c
+	 38, // 0xf6d
+	 38, // 0xf6e
+	 38, // 0xf6f
+	 38, // 0xf70
+	 38, // 0xf71
+	 38, // 0xf72
+	 38, // 0xf73
+	 38, // 0xf74
+	 38, // 0xf75
+	 38, // 0xf76
+	 38, // 0xf77
+	 38, // 0xf78
+	 38, // 0xf79
+	 38, // 0xf7a
+	 38, // 0xf7b
+	 38, // 0xf7c
+	 38, // 0xf7d
+	 38, // 0xf7e
+	 38, // 0xf7f
+	 38, // 0xf80
+	 38, // 0xf81
+	 38, // 0xf82
+	 38, // 0xf83
+	 38, // 0xf84
+	 38, // 0xf85
+	 38, // 0xf86
+	 38, // 0xf87
+	 38, // 0xf88
+	 38, // 0xf89
+	 38, // 0xf8a
+	 38, // 0xf8b
+	 38, // 0xf8c
+	 38, // 0xf8d
+	 38, // 0xf8e
+	 38, // 0xf8f
+	 38, // 0xf90
+	 38, // 0xf91
+	 38, // 0xf92
+	 38, // 0xf93
+	 38, // 0xf94
+	 38, // 0xf95
+	 38, // 0xf96
+	 38, // 0xf97
+	 38, // 0xf98
+	 38, // 0xf99
+	 38, // 0xf9a
+	 38, // 0xf9b
+	 38, // 0xf9c
+	 38, // 0xf9d
+	 38, // 0xf9e
+	 38, // 0xf9f
+	 38, // 0xfa0
+	 38, // 0xfa1
+	 38, // 0xfa2
+	 38, // 0xfa3
+	 38, // 0xfa4
+	 38, // 0xfa5
+	 38, // 0xfa6
+	 38, // 0xfa7
+	 38, // 0xfa8
+	 38, // 0xfa9
+	 38, // 0xfaa
+	 38, // 0xfab
+	 38, // 0xfac
+	 38, // 0xfad
+	 38, // 0xfae
+	 38, // 0xfaf
+	 38, // 0xfb0
+	 38, // 0xfb1
+	 38, // 0xfb2
+	 38, // 0xfb3
+	 38, // 0xfb4
+	 38, // 0xfb5
+	 38, // 0xfb6
+	 38, // 0xfb7
+	 38, // 0xfb8
+	 38, // 0xfb9
+	 38, // 0xfba
+	 38, // 0xfbb
+	 38, // 0xfbc
+	 38, // 0xfbd
+	 38, // 0xfbe
+	 38, // 0xfbf
+	 38, // 0xfc0
+	 38, // 0xfc1
+	 38, // 0xfc2
+	 38, // 0xfc3
+	 38, // 0xfc4
+	 38, // 0xfc5
+	 38, // 0xfc6
+	 38, // 0xfc7
+	 38, // 0xfc8
+	 38, // 0xfc9
+	 38, // 0xfca
+	 38, // 0xfcb
+	 38, // 0xfcc
+	 38, // 0xfcd
+	 38, // 0xfce
+	 38, // 0xfcf
+	 38, // 0xfd0
+	 38, // 0xfd1
+	 38, // 0xfd2
+	 38, // 0xfd3
+	 38, // 0xfd4
+	 38, // 0xfd5
+	 38, // 0xfd6
+	 38, // 0xfd7
+	 38, // 0xfd8
+	 38, // 0xfd9
+	 38, // 0xfda
+	 38, // 0xfdb
+	 38, // 0xfdc
+	 38, // 0xfdd
+	 38, // 0xfde
+	 38, // 0xfdf
+	 38, // 0xfe0
+	 38, // 0xfe1
+	 38, // 0xfe2
+	 38, // 0xfe3
+	 38, // 0xfe4
+	 38, // 0xfe5
+	 38, // 0xfe6
+	 38, // 0xfe7
+	 38, // 0xfe8
+	 38, // 0xfe9
+	 38, // 0xfea
+	 38, // 0xfeb
+	 38, // 0xfec
+	 38, // 0xfed
+	 38, // 0xfee
+	 38, // 0xfef
+	 38, // 0xff0
+	 38, // 0xff1
+	 38, // 0xff2
+	 38, // 0xff3
+	 38, // 0xff4
+	 38, // 0xff5
+	 38, // 0xff6
+	 38, // 0xff7
+	 38, // 0xff8
+	 38, // 0xff9
+	 38, // 0xffa
+	 38, // 0xffb
+	 38, // 0xffc
+	 38, // 0xffd
+	 38, // 0xffe
+	 38, // 0xfff
+	 38, // 0x1000
+	 38, // 0x1001
+	 38, // 0x1002
+	 38, // 0x1003
+	 38, // 0x1004
+	 38, // 0x1005
+	 38, // 0x1006
+	 38, // 0x1007
+	 38, // 0x1008
+	 38, // 0x1009
+	 38, // 0x100a
+	 38, // 0x100b
+	 38, // 0x100c
+	 38, // 0x100d
+	 38, // 0x100e
+	 38, // 0x100f
+	 38, // 0x1010
+	 38, // 0x1011
+	 38, // 0x1012
+	 38, // 0x1013
+	 38, // 0x1014
+	 38, // 0x1015
+	 38, // 0x1016
+	 38, // 0x1017
+	 38, // 0x1018
+	 38, // 0x1019
+	 38, // 0x101a
+	 38, // 0x101b
+	 38, // 0x101c
+	 38, // 0x101d
+	 38, // 0x101e
+	 38, // 0x101f
+	 38, // 0x1020
+	 38, // 0x1021
+	 38, // 0x1022
+	 38, // 0x1023
+	 38, // 0x1024
+	 38, // 0x1025
+	 38, // 0x1026
+	 38, // 0x1027
+	 38, // 0x1028
+	 38, // 0x1029
+	 38, // 0x102a
+	 38, // 0x102b
+	 38, // 0x102c
+	 38, // 0x102d
+	 38, // 0x102e
+	 38, // 0x102f
+	 38, // 0x1030
+	 38, // 0x1031
+	 38, // 0x1032
+	 38, // 0x1033
+	 38, // 0x1034
+	 38, // 0x1035
+	 38, // 0x1036
+	 38, // 0x1037
+	 38, // 0x1038
+	 38, // 0x1039
+	 38, // 0x103a
+	 38, // 0x103b
+	 38, // 0x103c
+	 38, // 0x103d
+	 38, // 0x103e
+	 38, // 0x103f
+	 38, // 0x1040
+	 38, // 0x1041
+	 38, // 0x1042
+	 38, // 0x1043
+	 38, // 0x1044
+	 38, // 0x1045
+	 38, // 0x1046
+	 38, // 0x1047
+	 38, // 0x1048
+	 38, // 0x1049
+	 38, // 0x104a
+	 38, // 0x104b
+	 38, // 0x104c
+	 38, // 0x104d
+	 38, // 0x104e
+	 38, // 0x104f
+	 38, // 0x1050
+	 38, // 0x1051
+	 38, // 0x1052
+	 38, // 0x1053
+	 38, // 0x1054
+	 38, // 0x1055
+	 38, // 0x1056
+	 38, // 0x1057
+	 38, // 0x1058
+	 38, // 0x1059
+	 38, // 0x105a
+	 38, // 0x105b
+	 38, // 0x105c
+	 38, // 0x105d
+	 38, // 0x105e
+	 38, // 0x105f
+	 38, // 0x1060
+	 38, // 0x1061
+	 38, // 0x1062
+	 38, // 0x1063
+	 38, // 0x1064
+	 38, // 0x1065
+	 38, // 0x1066
+	 38, // 0x1067
+	 38, // 0x1068
+	 38, // 0x1069
+	 38, // 0x106a
+	 38, // 0x106b
+	 38, // 0x106c
+	 38, // 0x106d
+	 38, // 0x106e
+	 38, // 0x106f
+	 38, // 0x1070
+	 38, // 0x1071
+	 38, // 0x1072
+	 38, // 0x1073
+	 38, // 0x1074
+	 38, // 0x1075
+	 38, // 0x1076
+	 38, // 0x1077
+	 38, // 0x1078
+	 38, // 0x1079
+	 38, // 0x107a
+	 38, // 0x107b
+	 38, // 0x107c
+	 38, // 0x107d
+	 38, // 0x107e
+	 38, // 0x107f
+	 38, // 0x1080
+	 38, // 0x1081
+	 38, // 0x1082
+	 38, // 0x1083
+	 38, // 0x1084
+	 38, // 0x1085
+	 38, // 0x1086
+	 38, // 0x1087
+	 38, // 0x1088
+	 38, // 0x1089
+	 38, // 0x108a
+	 38, // 0x108b
+	 38, // 0x108c
+	 38, // 0x108d
+	 38, // 0x108e
+	 38, // 0x108f
+	 38, // 0x1090
+	 38, // 0x1091
+	 38, // 0x1092
+	 38, // 0x1093
+	 38, // 0x1094
+	 38, // 0x1095
+	 38, // 0x1096
+	 38, // 0x1097
+	 38, // 0x1098
+	 38, // 0x1099
+	 38, // 0x109a
+	 38, // 0x109b
+	 38, // 0x109c
+	 38, // 0x109d
+	 38, // 0x109e
+	 38, // 0x109f
+	 38, // 0x10a0
+	 38, // 0x10a1
+	 38, // 0x10a2
+	 38, // 0x10a3
+	 38, // 0x10a4
+	 38, // 0x10a5
+	 38, // 0x10a6
+	 38, // 0x10a7
+	 38, // 0x10a8
+	 38, // 0x10a9
+	 38, // 0x10aa
+	 38, // 0x10ab
+	 38, // 0x10ac
+	 38, // 0x10ad
+	 38, // 0x10ae
+	 38, // 0x10af
+	 38, // 0x10b0
+	 38, // 0x10b1
+	 38, // 0x10b2
+	 38, // 0x10b3
+	 38, // 0x10b4
+	 38, // 0x10b5
+	 38, // 0x10b6
+	 38, // 0x10b7
+	 38, // 0x10b8
+	 38, // 0x10b9
+	 38, // 0x10ba
+	 38, // 0x10bb
+	 38, // 0x10bc
+	 38, // 0x10bd
+	 38, // 0x10be
+	 38, // 0x10bf
+	 38, // 0x10c0
+	 38, // 0x10c1
+	 38, // 0x10c2
+	 38, // 0x10c3
+	 38, // 0x10c4
+	 38, // 0x10c5
+	 38, // 0x10c6
+	 38, // 0x10c7
+	 38, // 0x10c8
+	 38, // 0x10c9
+	 38, // 0x10ca
+	 38, // 0x10cb
+	 38, // 0x10cc
+	 38, // 0x10cd
+	 38, // 0x10ce
+	 38, // 0x10cf
+	 38, // 0x10d0
+	 38, // 0x10d1
+	 38, // 0x10d2
+	 38, // 0x10d3
+	 38, // 0x10d4
+	 38, // 0x10d5
+	 38, // 0x10d6
+	 38, // 0x10d7
+	 38, // 0x10d8
+	 38, // 0x10d9
+	 38, // 0x10da
+	 38, // 0x10db
+	 38, // 0x10dc
+	 38, // 0x10dd
+	 38, // 0x10de
+	 38, // 0x10df
+	 38, // 0x10e0
+	 38, // 0x10e1
+	 38, // 0x10e2
+	 38, // 0x10e3
+	 38, // 0x10e4
+	 38, // 0x10e5
+	 38, // 0x10e6
+	 38, // 0x10e7
+	 38, // 0x10e8
+	 38, // 0x10e9
+	 38, // 0x10ea
+	 38, // 0x10eb
+	 38, // 0x10ec
+	 38, // 0x10ed
+	 38, // 0x10ee
+	 38, // 0x10ef
+	 38, // 0x10f0
+	 38, // 0x10f1
+	 38, // 0x10f2
+	 38, // 0x10f3
+	 38, // 0x10f4
+	 38, // 0x10f5
+	 38, // 0x10f6
+	 38, // 0x10f7
+	 38, // 0x10f8
+	 38, // 0x10f9
+	 38, // 0x10fa
+	 38, // 0x10fb
+	 38, // 0x10fc
+	 38, // 0x10fd
+	 38, // 0x10fe
+	 38, // 0x10ff
+];
+pub const ID_PAGES: [[u8;256];136] = [
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x8,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x2,
+        0x4,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x8,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x0,
+        0x4,
+        0x3,
+        0x4,
+        0x4,
+        0x0,
+        0x4,
+        0x0,
+        0x4,
+        0x4,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x4,
+        0x2,
+        0x0,
+        0x0,
+        0x3,
+        0x4,
+        0x0,
+        0x0,
+        0x0,
+        0x4,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x4,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x4,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x0,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x2,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+    ],
+    [
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x2,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x2,
+        0x0,
+    ],
+    [
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x2,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+    ],
+    [
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x2,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x2,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x2,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x3,
+        0x3,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x0,
+        0x2,
+        0x0,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x2,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x8,
+        0x8,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x8,
+        0x8,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x2,
+        0x2,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x2,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+    ],
+    [
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+    ],
+    [
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+    ],
+    [
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x0,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+    ],
+    [
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x4,
+        0x4,
+        0x4,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x4,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+    ],
+    [
+        0x3,
+        0x3,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x2,
+    ],
+    [
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x3,
+        0x2,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x4,
+        0x4,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x4,
+        0x4,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x2,
+        0x2,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x3,
+        0x3,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x2,
+        0x2,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x2,
+        0x0,
+        0x0,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x2,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x2,
+        0x3,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x0,
+        0x3,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x2,
+        0x2,
+        0x3,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+    ],
+    [
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+];
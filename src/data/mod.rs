@@ -1,4 +1,140 @@
+// GENERATED CODE DO NOT MANUALLY EDIT
+// Pinned tables: unicode_14, unicode_15
+#[cfg(all(feature = "categories", not(any(feature = "unicode_14", feature = "unicode_15"))))]
+#[allow(clippy::large_const_arrays)]
 pub mod characters;
+#[cfg(all(feature = "grapheme_clusters", not(any(feature = "unicode_14", feature = "unicode_15"))))]
+#[allow(clippy::large_const_arrays)]
 pub mod grapheme_property;
-#[cfg(test)]
-pub mod grapheme_test;
\ No newline at end of file
+#[cfg(all(test, feature = "grapheme_clusters", not(any(feature = "unicode_14", feature = "unicode_15"))))]
+pub mod grapheme_test;
+#[cfg(all(any(feature = "categories", feature = "grapheme_clusters"), not(any(feature = "unicode_14", feature = "unicode_15"))))]
+pub use unicode_version::UNICODE_VERSION as PINNED_UNICODE_VERSION;
+#[cfg(feature = "unicode_14")]
+mod unicode_14;
+#[cfg(all(feature = "categories", feature = "unicode_14"))]
+pub use unicode_14::characters;
+#[cfg(all(feature = "grapheme_clusters", feature = "unicode_14"))]
+pub use unicode_14::grapheme_property;
+#[cfg(all(any(feature = "categories", feature = "grapheme_clusters"), feature = "unicode_14"))]
+pub use unicode_14::UNICODE_VERSION as PINNED_UNICODE_VERSION;
+#[cfg(all(feature = "unicode_15", not(feature = "unicode_14")))]
+mod unicode_15;
+#[cfg(all(feature = "categories", feature = "unicode_15", not(feature = "unicode_14")))]
+pub use unicode_15::characters;
+#[cfg(all(feature = "grapheme_clusters", feature = "unicode_15", not(feature = "unicode_14")))]
+pub use unicode_15::grapheme_property;
+#[cfg(all(any(feature = "categories", feature = "grapheme_clusters"), feature = "unicode_15", not(feature = "unicode_14")))]
+pub use unicode_15::UNICODE_VERSION as PINNED_UNICODE_VERSION;
+pub mod unicode_version;
+#[cfg(feature = "word_boundaries")]
+#[allow(clippy::large_const_arrays)]
+pub mod word_property;
+#[cfg(all(test, feature = "word_boundaries"))]
+pub mod word_test;
+#[cfg(feature = "sentences")]
+#[allow(clippy::large_const_arrays)]
+pub mod sentence_property;
+#[cfg(all(test, feature = "sentences"))]
+pub mod sentence_test;
+#[cfg(feature = "line_break")]
+#[allow(clippy::large_const_arrays)]
+pub mod line_property;
+#[cfg(all(test, feature = "line_break"))]
+pub mod line_test;
+#[cfg(any(feature = "normalization", feature = "properties"))]
+#[allow(clippy::large_const_arrays)]
+pub mod combining_class;
+#[cfg(feature = "normalization")]
+#[allow(clippy::large_const_arrays)]
+pub mod normalization_property;
+#[cfg(feature = "normalization")]
+#[allow(clippy::large_const_arrays)]
+pub mod decompositions;
+#[cfg(all(test, feature = "normalization"))]
+pub mod normalization_test;
+#[cfg(feature = "properties")]
+#[allow(clippy::large_const_arrays)]
+pub mod bidi_class;
+#[cfg(feature = "properties")]
+#[allow(clippy::large_const_arrays)]
+pub mod decomposition_type;
+#[cfg(feature = "properties")]
+#[allow(clippy::large_const_arrays)]
+pub mod numeric_value;
+#[cfg(feature = "scripts")]
+pub mod scripts;
+#[cfg(feature = "scripts")]
+#[allow(clippy::large_const_arrays)]
+pub mod script_property;
+#[cfg(feature = "scripts")]
+#[allow(clippy::large_const_arrays)]
+pub mod script_extensions;
+#[cfg(feature = "blocks")]
+pub mod blocks;
+#[cfg(feature = "east_asian_width")]
+#[allow(clippy::large_const_arrays)]
+pub mod east_asian_width;
+#[cfg(feature = "case")]
+#[allow(clippy::type_complexity)]
+pub mod case_mapping;
+#[cfg(feature = "case")]
+#[allow(clippy::large_const_arrays)]
+pub mod case_property;
+#[cfg(feature = "bidi")]
+pub mod bidi_brackets;
+#[cfg(all(test, feature = "bidi"))]
+pub mod bidi_test;
+#[cfg(all(test, feature = "bidi"))]
+pub mod bidi_character_test;
+#[cfg(feature = "identifiers")]
+#[allow(clippy::large_const_arrays)]
+pub mod identifier_property;
+#[cfg(feature = "binary_properties")]
+pub mod binary_properties;
+#[cfg(feature = "binary_properties")]
+#[allow(clippy::large_const_arrays)]
+pub mod binary_property;
+#[cfg(feature = "emoji")]
+#[allow(clippy::large_const_arrays)]
+pub mod emoji_property;
+#[cfg(feature = "emoji")]
+#[allow(clippy::large_const_arrays)]
+pub mod emoji_sequences;
+#[cfg(feature = "names")]
+pub mod names;
+
+pub const COMPILED_TABLES: &[crate::DataTable] = &[
+	#[cfg(feature = "categories")]
+	crate::DataTable { feature: "categories", unicode_version: PINNED_UNICODE_VERSION },
+	#[cfg(feature = "grapheme_clusters")]
+	crate::DataTable { feature: "grapheme_clusters", unicode_version: PINNED_UNICODE_VERSION },
+	#[cfg(feature = "word_boundaries")]
+	crate::DataTable { feature: "word_boundaries", unicode_version: unicode_version::UNICODE_VERSION },
+	#[cfg(feature = "sentences")]
+	crate::DataTable { feature: "sentences", unicode_version: unicode_version::UNICODE_VERSION },
+	#[cfg(feature = "line_break")]
+	crate::DataTable { feature: "line_break", unicode_version: unicode_version::UNICODE_VERSION },
+	#[cfg(feature = "normalization")]
+	crate::DataTable { feature: "normalization", unicode_version: unicode_version::UNICODE_VERSION },
+	#[cfg(feature = "properties")]
+	crate::DataTable { feature: "properties", unicode_version: unicode_version::UNICODE_VERSION },
+	#[cfg(feature = "scripts")]
+	crate::DataTable { feature: "scripts", unicode_version: unicode_version::UNICODE_VERSION },
+	#[cfg(feature = "blocks")]
+	crate::DataTable { feature: "blocks", unicode_version: unicode_version::UNICODE_VERSION },
+	#[cfg(feature = "east_asian_width")]
+	crate::DataTable { feature: "east_asian_width", unicode_version: unicode_version::UNICODE_VERSION },
+	#[cfg(feature = "case")]
+	crate::DataTable { feature: "case", unicode_version: unicode_version::UNICODE_VERSION },
+	#[cfg(feature = "bidi")]
+	crate::DataTable { feature: "bidi", unicode_version: unicode_version::UNICODE_VERSION },
+	#[cfg(feature = "identifiers")]
+	crate::DataTable { feature: "identifiers", unicode_version: unicode_version::UNICODE_VERSION },
+	#[cfg(feature = "binary_properties")]
+	crate::DataTable { feature: "binary_properties", unicode_version: unicode_version::UNICODE_VERSION },
+	#[cfg(feature = "emoji")]
+	crate::DataTable { feature: "emoji", unicode_version: unicode_version::UNICODE_VERSION },
+	#[cfg(feature = "names")]
+	crate::DataTable { feature: "names", unicode_version: unicode_version::UNICODE_VERSION },
+];
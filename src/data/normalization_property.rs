@@ -0,0 +1,21901 @@
+// GENERATED CODE DO NOT MANUALLY EDIT
+pub const NORM_TABLE: [u8;0x1100] = [
+	 0, // 0x0
+	 1, // 0x1
+	 2, // 0x2
+	 3, // 0x3
+	 4, // 0x4
+	 5, // 0x5
+	 6, // 0x6
+	 7, // 0x7
+	 7, // 0x8
+	 8, // 0x9
+	 9, // 0xa
+	 10, // 0xb
+	 11, // 0xc
+	 12, // 0xd
+	 13, // 0xe
+	 14, // 0xf
+	 15, // 0x10
+	 16, // 0x11
+	 7, // 0x12
+	 7, // 0x13
+	 7, // 0x14
+	 7, // 0x15
+	 7, // 0x16
+	 7, // 0x17
+	 7, // 0x18
+	 7, // 0x19
+	 7, // 0x1a
+	 17, // 0x1b
+	 7, // 0x1c
+	 18, // 0x1d
+	 19, // 0x1e
+	 20, // 0x1f
+	 21, // 0x20
+	 22, // 0x21
+	 23, // 0x22
+	 24, // 0x23
+	 25, // 0x24
+	 7, // 0x25
+	 7, // 0x26
+	 7, // 0x27
+	 7, // 0x28
+	 7, // 0x29
+	 26, // 0x2a
+	 7, // 0x2b
+	 27, // 0x2c
+	 28, // 0x2d
+	 29, // 0x2e
+	 30, // 0x2f
+	 31, // 0x30
+	 32, // 0x31
+	 33, // 0x32
+	 34, // 0x33
+	 7, // 0x34
+	 7, // 0x35
+	 7, // 0x36
+	 7, // 0x37
+	 7, // 0x38
+	 7, // 0x39
+	 7, // 0x3a
+	 7, // 0x3b
+	 7, // 0x3c
+	 7, // 0x3d
+	 7, // 0x3e
+	 7, // 0x3f
+	 7, // 0x40
+	 7, // 0x41
+	 7, // 0x42
+	 7, // 0x43
+	 7, // 0x44
+	 7, // 0x45
+	 7, // 0x46
+	 7, // 0x47
+	 7, // 0x48
+	 7, // 0x49
+	 7, // 0x4a
+	 7, // 0x4b
+	 7, // 0x4c
+	 7, // 0x4d
+	 7, // 0x4e
+	 7, // 0x4f
+	 7, // 0x50
+	 7, // 0x51
+	 7, // 0x52
+	 7, // 0x53
+	 7, // 0x54
+	 7, // 0x55
+	 7, // 0x56
+	 7, // 0x57
+	 7, // 0x58
+	 7, // 0x59
+	 7, // 0x5a
+	 7, // 0x5b
+	 7, // 0x5c
+	 7, // 0x5d
+	 7, // 0x5e
+	 7, // 0x5f
+	 7, // 0x60
+	 7, // 0x61
+	 7, // 0x62
+	 7, // 0x63
+	 7, // 0x64
+	 7, // 0x65
+	 7, // 0x66
+	 7, // 0x67
+	 7, // 0x68
+	 7, // 0x69
+	 7, // 0x6a
+	 7, // 0x6b
+	 7, // 0x6c
+	 7, // 0x6d
+	 7, // 0x6e
+	 7, // 0x6f
+	 7, // 0x70
+	 7, // 0x71
+	 7, // 0x72
+	 7, // 0x73
+	 7, // 0x74
+	 7, // 0x75
+	 7, // 0x76
+	 7, // 0x77
+	 7, // 0x78
+	 7, // 0x79
+	 7, // 0x7a
+	 7, // 0x7b
+	 7, // 0x7c
+	 7, // 0x7d
+	 7, // 0x7e
+	 7, // 0x7f
+	 7, // 0x80
+	 7, // 0x81
+	 7, // 0x82
+	 7, // 0x83
+	 7, // 0x84
+	 7, // 0x85
+	 7, // 0x86
+	 7, // 0x87
+	 7, // 0x88
+	 7, // 0x89
+	 7, // 0x8a
+	 7, // 0x8b
+	 7, // 0x8c
+	 7, // 0x8d
+	 7, // 0x8e
+	 7, // 0x8f
+	 7, // 0x90
+	 7, // 0x91
+	 7, // 0x92
+	 7, // 0x93
+	 7, // 0x94
+	 7, // 0x95
+	 7, // 0x96
+	 7, // 0x97
+	 7, // 0x98
+	 7, // 0x99
+	 7, // 0x9a
+	 7, // 0x9b
+	 7, // 0x9c
+	 7, // 0x9d
+	 7, // 0x9e
+	 7, // 0x9f
+	 7, // 0xa0
+	 7, // 0xa1
+	 7, // 0xa2
+	 7, // 0xa3
+	 7, // 0xa4
+	 7, // 0xa5
+	 35, // 0xa6
+	 36, // 0xa7
+	 7, // 0xa8
+	 7, // 0xa9
+	 7, // 0xaa
+	 37, // 0xab
+	 38, // 0xac
+	 38, // 0xad
+	 38, // 0xae
+	 38, // 0xaf
+	 38, // 0xb0
+	 38, // 0xb1
+	 38, // 0xb2
+	 38, // 0xb3
+	 38, // 0xb4
+	 38, // 0xb5
+	 38, // 0xb6
+	 38, // 0xb7
+	 38, // 0xb8
+	 38, // 0xb9
+	 38, // 0xba
+	 38, // 0xbb
+	 38, // 0xbc
+	 38, // 0xbd
+	 38, // 0xbe
+	 38, // 0xbf
+	 38, // 0xc0
+	 38, // 0xc1
+	 38, // 0xc2
+	 38, // 0xc3
+	 38, // 0xc4
+	 38, // 0xc5
+	 38, // 0xc6
+	 38, // 0xc7
+	 38, // 0xc8
+	 38, // 0xc9
+	 38, // 0xca
+	 38, // 0xcb
+	 38, // 0xcc
+	 38, // 0xcd
+	 38, // 0xce
+	 38, // 0xcf
+	 38, // 0xd0
+	 38, // 0xd1
+	 38, // 0xd2
+	 38, // 0xd3
+	 38, // 0xd4
+	 38, // 0xd5
+	 38, // 0xd6
+	 39, // 0xd7
+	 7, // 0xd8
+	 7, // 0xd9
+	 7, // 0xda
+	 7, // 0xdb
+	 7, // 0xdc
+	 7, // 0xdd
+	 7, // 0xde
+	 7, // 0xdf
+	 7, // 0xe0
+	 7, // 0xe1
+	 7, // 0xe2
+	 7, // 0xe3
+	 7, // 0xe4
+	 7, // 0xe5
+	 7, // 0xe6
+	 7, // 0xe7
+	 7, // 0xe8
+	 7, // 0xe9
+	 7, // 0xea
+	 7, // 0xeb
+	 7, // 0xec
+	 7, // 0xed
+	 7, // 0xee
+	 7, // 0xef
+	 7, // 0xf0
+	 7, // 0xf1
+	 7, // 0xf2
+	 7, // 0xf3
+	 7, // 0xf4
+	 7, // 0xf5
+	 7, // 0xf6
+	 7, // 0xf7
+	 7, // 0xf8
+	 40, // 0xf9
+	 41, // 0xfa
+	 42, // 0xfb
+	 34, // 0xfc
+	 43, // 0xfd
+	 44, // 0xfe
+	 45, // 0xff
+	 7, // 0x100
+	 7, // 0x101
+	 7, // 0x102
+	 7, // 0x103
+	 7, // 0x104
+	 46, // 0x105
+	 7, // 0x106
+	 47, // 0x107
+	 7, // 0x108
+	 7, // 0x109
+	 7, // 0x10a
+	 7, // 0x10b
+	 7, // 0x10c
+	 7, // 0x10d
+	 7, // 0x10e
+	 7, // 0x10f
+	 48, // 0x110
+	 49, // 0x111
+	 7, // 0x112
+	 50, // 0x113
+	 51, // 0x114
+	 52, // 0x115
+	 7, // 0x116
+	 7, // 0x117
+	 7, // 0x118
+	 53, // 0x119
+	 7, // 0x11a
+	 7, // 0x11b
+	 7, // 0x11c
+	 7, // 0x11d
+	 7, // 0x11e
+	 7, // 0x11f
+	 7, // 0x120
+	 7, // 0x121
+	 7, // 0x122
+	 7, // 0x123
+	 7, // 0x124
+	 7, // 0x125
+	 7, // 0x126
+	 7, // 0x127
+	 7, // 0x128
+	 7, // 0x129
+	 7, // 0x12a
+	 7, // 0x12b
+	 7, // 0x12c
+	 7, // 0x12d
+	 7, // 0x12e
+	 7, // 0x12f
+	 7, // 0x130
+	 7, // 0x131
+	 7, // 0x132
+	 7, // 0x133
+	 7, // 0x134
+	 7, // 0x135
+	 7, // 0x136
+	 7, // 0x137
+	 7, // 0x138
+	 7, // 0x139
+	 7, // 0x13a
+	 7, // 0x13b
+	 7, // 0x13c
+	 7, // 0x13d
+	 7, // 0x13e
+	 7, // 0x13f
+	 7, // 0x140
+	 7, // 0x141
+	 7, // 0x142
+	 7, // 0x143
+	 7, // 0x144
+	 7, // 0x145
+	 7, // 0x146
+	 7, // 0x147
+	 7, // 0x148
+	 7, // 0x149
+	 7, // 0x14a
+	 7, // 0x14b
+	 7, // 0x14c
+	 7, // 0x14d
+	 7, // 0x14e
+	 7, // 0x14f
+	 7, // 0x150
+	 7, // 0x151
+	 7, // 0x152
+	 7, // 0x153
+	 7, // 0x154
+	 7, // 0x155
+	 7, // 0x156
+	 7, // 0x157
+	 7, // 0x158
+	 7, // 0x159
+	 7, // 0x15a
+	 7, // 0x15b
+	 7, // 0x15c
+	 7, // 0x15d
+	 7, // 0x15e
+	 7, // 0x15f
+	 7, // 0x160
+	 54, // 0x161
+	 7, // 0x162
+	 7, // 0x163
+	 7, // 0x164
+	 7, // 0x165
+	 7, // 0x166
+	 7, // 0x167
+	 7, // 0x168
+	 7, // 0x169
+	 7, // 0x16a
+	 7, // 0x16b
+	 7, // 0x16c
+	 55, // 0x16d
+	 7, // 0x16e
+	 7, // 0x16f
+	 7, // 0x170
+	 7, // 0x171
+	 7, // 0x172
+	 7, // 0x173
+	 7, // 0x174
+	 7, // 0x175
+	 7, // 0x176
+	 7, // 0x177
+	 7, // 0x178
+	 7, // 0x179
+	 7, // 0x17a
+	 7, // 0x17b
+	 7, // 0x17c
+	 7, // 0x17d
+	 7, // 0x17e
+	 7, // 0x17f
+	 7, // 0x180
+	 7, // 0x181
+	 7, // 0x182
+	 7, // 0x183
+	 7, // 0x184
+	 7, // 0x185
+	 7, // 0x186
+	 7, // 0x187
+	 7, // 0x188
+	 7, // 0x189
+	 7, // 0x18a
+	 7, // 0x18b
+	 7, // 0x18c
+	 7, // 0x18d
+	 7, // 0x18e
+	 7, // 0x18f
+	 7, // 0x190
+	 7, // 0x191
+	 7, // 0x192
+	 7, // 0x193
+	 7, // 0x194
+	 7, // 0x195
+	 7, // 0x196
+	 7, // 0x197
+	 7, // 0x198
+	 7, // 0x199
+	 7, // 0x19a
+	 7, // 0x19b
+	 7, // 0x19c
+	 7, // 0x19d
+	 7, // 0x19e
+	 7, // 0x19f
+	 7, // 0x1a0
+	 7, // 0x1a1
+	 7, // 0x1a2
+	 7, // 0x1a3
+	 7, // 0x1a4
+	 7, // 0x1a5
+	 7, // 0x1a6
+	 7, // 0x1a7
+	 7, // 0x1a8
+	 7, // 0x1a9
+	 7, // 0x1aa
+	 7, // 0x1ab
+	 7, // 0x1ac
+	 7, // 0x1ad
+	 7, // 0x1ae
+	 7, // 0x1af
+	 7, // 0x1b0
+	 7, // 0x1b1
+	 7, // 0x1b2
+	 7, // 0x1b3
+	 7, // 0x1b4
+	 7, // 0x1b5
+	 7, // 0x1b6
+	 7, // 0x1b7
+	 7, // 0x1b8
+	 7, // 0x1b9
+	 7, // 0x1ba
+	 7, // 0x1bb
+	 7, // 0x1bc
+	 7, // 0x1bd
+	 7, // 0x1be
+	 7, // 0x1bf
+	 7, // 0x1c0
+	 7, // 0x1c1
+	 7, // 0x1c2
+	 7, // 0x1c3
+	 7, // 0x1c4
+	 7, // 0x1c5
+	 7, // 0x1c6
+	 7, // 0x1c7
+	 7, // 0x1c8
+	 7, // 0x1c9
+	 7, // 0x1ca
+	 7, // 0x1cb
+	 56, // 0x1cc
+	 7, // 0x1cd
+	 7, // 0x1ce
+	 7, // 0x1cf
+	 7, // 0x1d0
+	 57, // 0x1d1
+	 7, // 0x1d2
+	 7, // 0x1d3
+	 58, // 0x1d4
+	 59, // 0x1d5
+	 60, // 0x1d6
+	 61, // 0x1d7
+	 7, // 0x1d8
+	 7, // 0x1d9
+	 7, // 0x1da
+	 7, // 0x1db
+	 7, // 0x1dc
+	 7, // 0x1dd
+	 7, // 0x1de
+	 7, // 0x1df
+	 62, // 0x1e0
+	 7, // 0x1e1
+	 7, // 0x1e2
+	 7, // 0x1e3
+	 7, // 0x1e4
+	 7, // 0x1e5
+	 7, // 0x1e6
+	 7, // 0x1e7
+	 7, // 0x1e8
+	 7, // 0x1e9
+	 7, // 0x1ea
+	 7, // 0x1eb
+	 7, // 0x1ec
+	 7, // 0x1ed
+	 63, // 0x1ee
+	 7, // 0x1ef
+	 7, // 0x1f0
+	 64, // 0x1f1
+	 65, // 0x1f2
+	 7, // 0x1f3
+	 7, // 0x1f4
+	 7, // 0x1f5
+	 7, // 0x1f6
+	 7, // 0x1f7
+	 7, // 0x1f8
+	 7, // 0x1f9
+	 7, // 0x1fa
+	 66, // 0x1fb
+	 7, // 0x1fc
+	 7, // 0x1fd
+	 7, // 0x1fe
+	 7, // 0x1ff
+	 7, // 0x200
+	 7, // 0x201
+	 7, // 0x202
+	 7, // 0x203
+	 7, // 0x204
+	 7, // 0x205
+	 7, // 0x206
+	 7, // 0x207
+	 7, // 0x208
+	 7, // 0x209
+	 7, // 0x20a
+	 7, // 0x20b
+	 7, // 0x20c
+	 7, // 0x20d
+	 7, // 0x20e
+	 7, // 0x20f
+	 7, // 0x210
+	 7, // 0x211
+	 7, // 0x212
+	 7, // 0x213
+	 7, // 0x214
+	 7, // 0x215
+	 7, // 0x216
+	 7, // 0x217
+	 7, // 0x218
+	 7, // 0x219
+	 7, // 0x21a
+	 7, // 0x21b
+	 7, // 0x21c
+	 7, // 0x21d
+	 7, // 0x21e
+	 7, // 0x21f
+	 7, // 0x220
+	 7, // 0x221
+	 7, // 0x222
+	 7, // 0x223
+	 7, // 0x224
+	 7, // 0x225
+	 7, // 0x226
+	 7, // 0x227
+	 7, // 0x228
+	 7, // 0x229
+	 7, // 0x22a
+	 7, // 0x22b
+	 7, // 0x22c
+	 7, // 0x22d
+	 7, // 0x22e
+	 7, // 0x22f
+	 7, // 0x230
+	 7, // 0x231
+	 7, // 0x232
+	 7, // 0x233
+	 7, // 0x234
+	 7, // 0x235
+	 7, // 0x236
+	 7, // 0x237
+	 7, // 0x238
+	 7, // 0x239
+	 7, // 0x23a
+	 7, // 0x23b
+	 7, // 0x23c
+	 7, // 0x23d
+	 7, // 0x23e
+	 7, // 0x23f
+	 7, // 0x240
+	 7, // 0x241
+	 7, // 0x242
+	 7, // 0x243
+	 7, // 0x244
+	 7, // 0x245
+	 7, // 0x246
+	 7, // 0x247
+	 7, // 0x248
+	 7, // 0x249
+	 7, // 0x24a
+	 7, // 0x24b
+	 7, // 0x24c
+	 7, // 0x24d
+	 7, // 0x24e
+	 7, // 0x24f
+	 7, // 0x250
+	 7, // 0x251
+	 7, // 0x252
+	 7, // 0x253
+	 7, // 0x254
+	 7, // 0x255
+	 7, // 0x256
+	 7, // 0x257
+	 7, // 0x258
+	 7, // 0x259
+	 7, // 0x25a
+	 7, // 0x25b
+	 7, // 0x25c
+	 7, // 0x25d
+	 7, // 0x25e
+	 7, // 0x25f
+	 7, // 0x260
+	 7, // 0x261
+	 7, // 0x262
+	 7, // 0x263
+	 7, // 0x264
+	 7, // 0x265
+	 7, // 0x266
+	 7, // 0x267
+	 7, // 0x268
+	 7, // 0x269
+	 7, // 0x26a
+	 7, // 0x26b
+	 7, // 0x26c
+	 7, // 0x26d
+	 7, // 0x26e
+	 7, // 0x26f
+	 7, // 0x270
+	 7, // 0x271
+	 7, // 0x272
+	 7, // 0x273
+	 7, // 0x274
+	 7, // 0x275
+	 7, // 0x276
+	 7, // 0x277
+	 7, // 0x278
+	 7, // 0x279
+	 7, // 0x27a
+	 7, // 0x27b
+	 7, // 0x27c
+	 7, // 0x27d
+	 7, // 0x27e
+	 7, // 0x27f
+	 7, // 0x280
+	 7, // 0x281
+	 7, // 0x282
+	 7, // 0x283
+	 7, // 0x284
+	 7, // 0x285
+	 7, // 0x286
+	 7, // 0x287
+	 7, // 0x288
+	 7, // 0x289
+	 7, // 0x28a
+	 7, // 0x28b
+	 7, // 0x28c
+	 7, // 0x28d
+	 7, // 0x28e
+	 7, // 0x28f
+	 7, // 0x290
+	 7, // 0x291
+	 7, // 0x292
+	 7, // 0x293
+	 7, // 0x294
+	 7, // 0x295
+	 7, // 0x296
+	 7, // 0x297
+	 7, // 0x298
+	 7, // 0x299
+	 7, // 0x29a
+	 7, // 0x29b
+	 7, // 0x29c
+	 7, // 0x29d
+	 7, // 0x29e
+	 7, // 0x29f
+	 7, // 0x2a0
+	 7, // 0x2a1
+	 7, // 0x2a2
+	 7, // 0x2a3
+	 7, // 0x2a4
+	 7, // 0x2a5
+	 7, // 0x2a6
+	 7, // 0x2a7
+	 7, // 0x2a8
+	 7, // 0x2a9
+	 7, // 0x2aa
+	 7, // 0x2ab
+	 7, // 0x2ac
+	 7, // 0x2ad
+	 7, // 0x2ae
+	 7, // 0x2af
+	 7, // 0x2b0
+	 7, // 0x2b1
+	 7, // 0x2b2
+	 7, // 0x2b3
+	 7, // 0x2b4
+	 7, // 0x2b5
+	 7, // 0x2b6
+	 7, // 0x2b7
+	 7, // 0x2b8
+	 7, // 0x2b9
+	 7, // 0x2ba
+	 7, // 0x2bb
+	 7, // 0x2bc
+	 7, // 0x2bd
+	 7, // 0x2be
+	 7, // 0x2bf
+	 7, // 0x2c0
+	 7, // 0x2c1
+	 7, // 0x2c2
+	 7, // 0x2c3
+	 7, // 0x2c4
+	 7, // 0x2c5
+	 7, // 0x2c6
+	 7, // 0x2c7
+	 7, // 0x2c8
+	 7, // 0x2c9
+	 7, // 0x2ca
+	 7, // 0x2cb
+	 7, // 0x2cc
+	 7, // 0x2cd
+	 7, // 0x2ce
+	 7, // 0x2cf
+	 7, // 0x2d0
+	 7, // 0x2d1
+	 7, // 0x2d2
+	 7, // 0x2d3
+	 7, // 0x2d4
+	 7, // 0x2d5
+	 7, // 0x2d6
+	 7, // 0x2d7
+	 7, // 0x2d8
+	 7, // 0x2d9
+	 7, // 0x2da
+	 7, // 0x2db
+	 7, // 0x2dc
+	 7, // 0x2dd
+	 7, // 0x2de
+	 7, // 0x2df
+	 7, // 0x2e0
+	 7, // 0x2e1
+	 7, // 0x2e2
+	 7, // 0x2e3
+	 7, // 0x2e4
+	 7, // 0x2e5
+	 7, // 0x2e6
+	 7, // 0x2e7
+	 7, // 0x2e8
+	 7, // 0x2e9
+	 7, // 0x2ea
+	 7, // 0x2eb
+	 7, // 0x2ec
+	 7, // 0x2ed
+	 7, // 0x2ee
+	 7, // 0x2ef
+	 7, // 0x2f0
+	 7, // 0x2f1
+	 7, // 0x2f2
+	 7, // 0x2f3
+	 7, // 0x2f4
+	 7, // 0x2f5
+	 7, // 0x2f6
+	 7, // 0x2f7
+	 40, // 0x2f8
+	 40, // 0x2f9
+	 67, // 0x2fa
+	 7, // 0x2fb
+	 7, // 0x2fc
+	 7, // 0x2fd
+	 7, // 0x2fe
+	 7, // 0x2ff
+	 7, // 0x300
+	 7, // 0x301
+	 7, // 0x302
+	 7, // 0x303
+	 7, // 0x304
+	 7, // 0x305
+	 7, // 0x306
+	 7, // 0x307
+	 7, // 0x308
+	 7, // 0x309
+	 7, // 0x30a
+	 7, // 0x30b
+	 7, // 0x30c
+	 7, // 0x30d
+	 7, // 0x30e
+	 7, // 0x30f
+	 7, // 0x310
+	 7, // 0x311
+	 7, // 0x312
+	 7, // 0x313
+	 7, // 0x314
+	 7, // 0x315
+	 7, // 0x316
+	 7, // 0x317
+	 7, // 0x318
+	 7, // 0x319
+	 7, // 0x31a
+	 7, // 0x31b
+	 7, // 0x31c
+	 7, // 0x31d
+	 7, // 0x31e
+	 7, // 0x31f
+	 7, // 0x320
+	 7, // 0x321
+	 7, // 0x322
+	 7, // 0x323
+	 7, // 0x324
+	 7, // 0x325
+	 7, // 0x326
+	 7, // 0x327
+	 7, // 0x328
+	 7, // 0x329
+	 7, // 0x32a
+	 7, // 0x32b
+	 7, // 0x32c
+	 7, // 0x32d
+	 7, // 0x32e
+	 7, // 0x32f
+	 7, // 0x330
+	 7, // 0x331
+	 7, // 0x332
+	 7, // 0x333
+	 7, // 0x334
+	 7, // 0x335
+	 7, // 0x336
+	 7, // 0x337
+	 7, // 0x338
+	 7, // 0x339
+	 7, // 0x33a
+	 7, // 0x33b
+	 7, // 0x33c
+	 7, // 0x33d
+	 7, // 0x33e
+	 7, // 0x33f
+	 7, // 0x340
+	 7, // 0x341
+	 7, // 0x342
+	 7, // 0x343
+	 7, // 0x344
+	 7, // 0x345
+	 7, // 0x346
+	 7, // 0x347
+	 7, // 0x348
+	 7, // 0x349
+	 7, // 0x34a
+	 7, // 0x34b
+	 7, // 0x34c
+	 7, // 0x34d
+	 7, // 0x34e
+	 7, // 0x34f
+	 7, // 0x350
+	 7, // 0x351
+	 7, // 0x352
+	 7, // 0x353
+	 7, // 0x354
+	 7, // 0x355
+	 7, // 0x356
+	 7, // 0x357
+	 7, // 0x358
+	 7, // 0x359
+	 7, // 0x35a
+	 7, // 0x35b
+	 7, // 0x35c
+	 7, // 0x35d
+	 7, // 0x35e
+	 7, // 0x35f
+	 7, // 0x360
+	 7, // 0x361
+	 7, // 0x362
+	 7, // 0x363
+	 7, // 0x364
+	 7, // 0x365
+	 7, // 0x366
+	 7, // 0x367
+	 7, // 0x368
+	 7, // 0x369
+	 7, // 0x36a
+	 7, // 0x36b
+	 7, // 0x36c
+	 7, // 0x36d
+	 7, // 0x36e
+	 7, // 0x36f
+	 7, // 0x370
+	 7, // 0x371
+	 7, // 0x372
+	 7, // 0x373
+	 7, // 0x374
+	 7, // 0x375
+	 7, // 0x376
+	 7, // 0x377
+	 7, // 0x378
+	 7, // 0x379
+	 7, // 0x37a
+	 7, // 0x37b
+	 7, // 0x37c
+	 7, // 0x37d
+	 7, // 0x37e
+	 7, // 0x37f
+	 7, // 0x380
+	 7, // 0x381
+	 7, // 0x382
+	 7, // 0x383
+	 7, // 0x384
+	 7, // 0x385
+	 7, // 0x386
+	 7, // 0x387
+	 7, // 0x388
+	 7, // 0x389
+	 7, // 0x38a
+	 7, // 0x38b
+	 7, // 0x38c
+	 7, // 0x38d
+	 7, // 0x38e
+	 7, // 0x38f
+	 7, // 0x390
+	 7, // 0x391
+	 7, // 0x392
+	 7, // 0x393
+	 7, // 0x394
+	 7, // 0x395
+	 7, // 0x396
+	 7, // 0x397
+	 7, // 0x398
+	 7, // 0x399
+	 7, // 0x39a
+	 7, // 0x39b
+	 7, // 0x39c
+	 7, // 0x39d
+	 7, // 0x39e
+	 7, // 0x39f
+	 7, // 0x3a0
+	 7, // 0x3a1
+	 7, // 0x3a2
+	 7, // 0x3a3
+	 7, // 0x3a4
+	 7, // 0x3a5
+	 7, // 0x3a6
+	 7, // 0x3a7
+	 7, // 0x3a8
+	 7, // 0x3a9
+	 7, // 0x3aa
+	 7, // 0x3ab
+	 7, // 0x3ac
+	 7, // 0x3ad
+	 7, // 0x3ae
+	 7, // 0x3af
+	 7, // 0x3b0
+	 7, // 0x3b1
+	 7, // 0x3b2
+	 7, // 0x3b3
+	 7, // 0x3b4
+	 7, // 0x3b5
+	 7, // 0x3b6
+	 7, // 0x3b7
+	 7, // 0x3b8
+	 7, // 0x3b9
+	 7, // 0x3ba
+	 7, // 0x3bb
+	 7, // 0x3bc
+	 7, // 0x3bd
+	 7, // 0x3be
+	 7, // 0x3bf
+	 7, // 0x3c0
+	 7, // 0x3c1
+	 7, // 0x3c2
+	 7, // 0x3c3
+	 7, // 0x3c4
+	 7, // 0x3c5
+	 7, // 0x3c6
+	 7, // 0x3c7
+	 7, // 0x3c8
+	 7, // 0x3c9
+	 7, // 0x3ca
+	 7, // 0x3cb
+	 7, // 0x3cc
+	 7, // 0x3cd
+	 7, // 0x3ce
+	 7, // 0x3cf
+	 7, // 0x3d0
+	 7, // 0x3d1
+	 7, // 0x3d2
+	 7, // 0x3d3
+	 7, // 0x3d4
+	 7, // 0x3d5
+	 7, // 0x3d6
+	 7, // 0x3d7
+	 7, // 0x3d8
+	 7, // 0x3d9
+	 7, // 0x3da
+	 7, // 0x3db
+	 7, // 0x3dc
+	 7, // 0x3dd
+	 7, // 0x3de
+	 7, // 0x3df
+	 7, // 0x3e0
+	 7, // 0x3e1
+	 7, // 0x3e2
+	 7, // 0x3e3
+	 7, // 0x3e4
+	 7, // 0x3e5
+	 7, // 0x3e6
+	 7, // 0x3e7
+	 7, // 0x3e8
+	 7, // 0x3e9
+	 7, // 0x3ea
+	 7, // 0x3eb
+	 7, // 0x3ec
+	 7, // 0x3ed
+	 7, // 0x3ee
+	 7, // 0x3ef
+	 7, // 0x3f0
+	 7, // 0x3f1
+	 7, // 0x3f2
+	 7, // 0x3f3
+	 7, // 0x3f4
+	 7, // 0x3f5
+	 7, // 0x3f6
+	 7, // 0x3f7
+	 7, // 0x3f8
+	 7, // 0x3f9
+	 7, // 0x3fa
+	 7, // 0x3fb
+	 7, // 0x3fc
+	 7, // 0x3fd
+	 7, // 0x3fe
+	 7, // 0x3ff
+	 7, // 0x400
+	 7, // 0x401
+	 7, // 0x402
+	 7, // 0x403
+	 7, // 0x404
+	 7, // 0x405
+	 7, // 0x406
+	 7, // 0x407
+	 7, // 0x408
+	 7, // 0x409
+	 7, // 0x40a
+	 7, // 0x40b
+	 7, // 0x40c
+	 7, // 0x40d
+	 7, // 0x40e
+	 7, // 0x40f
+	 7, // 0x410
+	 7, // 0x411
+	 7, // 0x412
+	 7, // 0x413
+	 7, // 0x414
+	 7, // 0x415
+	 7, // 0x416
+	 7, // 0x417
+	 7, // 0x418
+	 7, // 0x419
+	 7, // 0x41a
+	 7, // 0x41b
+	 7, // 0x41c
+	 7, // 0x41d
+	 7, // 0x41e
+	 7, // 0x41f
+	 7, // 0x420
+	 7, // 0x421
+	 7, // 0x422
+	 7, // 0x423
+	 7, // 0x424
+	 7, // 0x425
+	 7, // 0x426
+	 7, // 0x427
+	 7, // 0x428
+	 7, // 0x429
+	 7, // 0x42a
+	 7, // 0x42b
+	 7, // 0x42c
+	 7, // 0x42d
+	 7, // 0x42e
+	 7, // 0x42f
+	 7, // 0x430
+	 7, // 0x431
+	 7, // 0x432
+	 7, // 0x433
+	 7, // 0x434
+	 7, // 0x435
+	 7, // 0x436
+	 7, // 0x437
+	 7, // 0x438
+	 7, // 0x439
+	 7, // 0x43a
+	 7, // 0x43b
+	 7, // 0x43c
+	 7, // 0x43d
+	 7, // 0x43e
+	 7, // 0x43f
+	 7, // 0x440
+	 7, // 0x441
+	 7, // 0x442
+	 7, // 0x443
+	 7, // 0x444
+	 7, // 0x445
+	 7, // 0x446
+	 7, // 0x447
+	 7, // 0x448
+	 7, // 0x449
+	 7, // 0x44a
+	 7, // 0x44b
+	 7, // 0x44c
+	 7, // 0x44d
+	 7, // 0x44e
+	 7, // 0x44f
+	 7, // 0x450
+	 7, // 0x451
+	 7, // 0x452
+	 7, // 0x453
+	 7, // 0x454
+	 7, // 0x455
+	 7, // 0x456
+	 7, // 0x457
+	 7, // 0x458
+	 7, // 0x459
+	 7, // 0x45a
+	 7, // 0x45b
+	 7, // 0x45c
+	 7, // 0x45d
+	 7, // 0x45e
+	 7, // 0x45f
+	 7, // 0x460
+	 7, // 0x461
+	 7, // 0x462
+	 7, // 0x463
+	 7, // 0x464
+	 7, // 0x465
+	 7, // 0x466
+	 7, // 0x467
+	 7, // 0x468
+	 7, // 0x469
+	 7, // 0x46a
+	 7, // 0x46b
+	 7, // 0x46c
+	 7, // 0x46d
+	 7, // 0x46e
+	 7, // 0x46f
+	 7, // 0x470
+	 7, // 0x471
+	 7, // 0x472
+	 7, // 0x473
+	 7, // 0x474
+	 7, // 0x475
+	 7, // 0x476
+	 7, // 0x477
+	 7, // 0x478
+	 7, // 0x479
+	 7, // 0x47a
+	 7, // 0x47b
+	 7, // 0x47c
+	 7, // 0x47d
+	 7, // 0x47e
+	 7, // 0x47f
+	 7, // 0x480
+	 7, // 0x481
+	 7, // 0x482
+	 7, // 0x483
+	 7, // 0x484
+	 7, // 0x485
+	 7, // 0x486
+	 7, // 0x487
+	 7, // 0x488
+	 7, // 0x489
+	 7, // 0x48a
+	 7, // 0x48b
+	 7, // 0x48c
+	 7, // 0x48d
+	 7, // 0x48e
+	 7, // 0x48f
+	 7, // 0x490
+	 7, // 0x491
+	 7, // 0x492
+	 7, // 0x493
+	 7, // 0x494
+	 7, // 0x495
+	 7, // 0x496
+	 7, // 0x497
+	 7, // 0x498
+	 7, // 0x499
+	 7, // 0x49a
+	 7, // 0x49b
+	 7, // 0x49c
+	 7, // 0x49d
+	 7, // 0x49e
+	 7, // 0x49f
+	 7, // 0x4a0
+	 7, // 0x4a1
+	 7, // 0x4a2
+	 7, // 0x4a3
+	 7, // 0x4a4
+	 7, // 0x4a5
+	 7, // 0x4a6
+	 7, // 0x4a7
+	 7, // 0x4a8
+	 7, // 0x4a9
+	 7, // 0x4aa
+	 7, // 0x4ab
+	 7, // 0x4ac
+	 7, // 0x4ad
+	 7, // 0x4ae
+	 7, // 0x4af
+	 7, // 0x4b0
+	 7, // 0x4b1
+	 7, // 0x4b2
+	 7, // 0x4b3
+	 7, // 0x4b4
+	 7, // 0x4b5
+	 7, // 0x4b6
+	 7, // 0x4b7
+	 7, // 0x4b8
+	 7, // 0x4b9
+	 7, // 0x4ba
+	 7, // 0x4bb
+	 7, // 0x4bc
+	 7, // 0x4bd
+	 7, // 0x4be
+	 7, // 0x4bf
+	 7, // 0x4c0
+	 7, // 0x4c1
+	 7, // 0x4c2
+	 7, // 0x4c3
+	 7, // 0x4c4
+	 7, // 0x4c5
+	 7, // 0x4c6
+	 7, // 0x4c7
+	 7, // 0x4c8
+	 7, // 0x4c9
+	 7, // 0x4ca
+	 7, // 0x4cb
+	 7, // 0x4cc
+	 7, // 0x4cd
+	 7, // 0x4ce
+	 7, // 0x4cf
+	 7, // 0x4d0
+	 7, // 0x4d1
+	 7, // 0x4d2
+	 7, // 0x4d3
+	 7, // 0x4d4
+	 7, // 0x4d5
+	 7, // 0x4d6
+	 7, // 0x4d7
+	 7, // 0x4d8
+	 7, // 0x4d9
+	 7, // 0x4da
+	 7, // 0x4db
+	 7, // 0x4dc
+	 7, // 0x4dd
+	 7, // 0x4de
+	 7, // 0x4df
+	 7, // 0x4e0
+	 7, // 0x4e1
+	 7, // 0x4e2
+	 7, // 0x4e3
+	 7, // 0x4e4
+	 7, // 0x4e5
+	 7, // 0x4e6
+	 7, // 0x4e7
+	 7, // 0x4e8
+	 7, // 0x4e9
+	 7, // 0x4ea
+	 7, // 0x4eb
+	 7, // 0x4ec
+	 7, // 0x4ed
+	 7, // 0x4ee
+	 7, // 0x4ef
+	 7, // 0x4f0
+	 7, // 0x4f1
+	 7, // 0x4f2
+	 7, // 0x4f3
+	 7, // 0x4f4
+	 7, // 0x4f5
+	 7, // 0x4f6
+	 7, // 0x4f7
+	 7, // 0x4f8
+	 7, // 0x4f9
+	 7, // 0x4fa
+	 7, // 0x4fb
+	 7, // 0x4fc
+	 7, // 0x4fd
+	 7, // 0x4fe
+	 7, // 0x4ff
+	 7, // 0x500
+	 7, // 0x501
+	 7, // 0x502
+	 7, // 0x503
+	 7, // 0x504
+	 7, // 0x505
+	 7, // 0x506
+	 7, // 0x507
+	 7, // 0x508
+	 7, // 0x509
+	 7, // 0x50a
+	 7, // 0x50b
+	 7, // 0x50c
+	 7, // 0x50d
+	 7, // 0x50e
+	 7, // 0x50f
+	 7, // 0x510
+	 7, // 0x511
+	 7, // 0x512
+	 7, // 0x513
+	 7, // 0x514
+	 7, // 0x515
+	 7, // 0x516
+	 7, // 0x517
+	 7, // 0x518
+	 7, // 0x519
+	 7, // 0x51a
+	 7, // 0x51b
+	 7, // 0x51c
+	 7, // 0x51d
+	 7, // 0x51e
+	 7, // 0x51f
+	 7, // 0x520
+	 7, // 0x521
+	 7, // 0x522
+	 7, // 0x523
+	 7, // 0x524
+	 7, // 0x525
+	 7, // 0x526
+	 7, // 0x527
+	 7, // 0x528
+	 7, // 0x529
+	 7, // 0x52a
+	 7, // 0x52b
+	 7, // 0x52c
+	 7, // 0x52d
+	 7, // 0x52e
+	 7, // 0x52f
+	 7, // 0x530
+	 7, // 0x531
+	 7, // 0x532
+	 7, // 0x533
+	 7, // 0x534
+	 7, // 0x535
+	 7, // 0x536
+	 7, // 0x537
+	 7, // 0x538
+	 7, // 0x539
+	 7, // 0x53a
+	 7, // 0x53b
+	 7, // 0x53c
+	 7, // 0x53d
+	 7, // 0x53e
+	 7, // 0x53f
+	 7, // 0x540
+	 7, // 0x541
+	 7, // 0x542
+	 7, // 0x543
+	 7, // 0x544
+	 7, // 0x545
+	 7, // 0x546
+	 7, // 0x547
+	 7, // 0x548
+	 7, // 0x549
+	 7, // 0x54a
+	 7, // 0x54b
+	 7, // 0x54c
+	 7, // 0x54d
+	 7, // 0x54e
+	 7, // 0x54f
+	 7, // 0x550
+	 7, // 0x551
+	 7, // 0x552
+	 7, // 0x553
+	 7, // 0x554
+	 7, // 0x555
+	 7, // 0x556
+	 7, // 0x557
+	 7, // 0x558
+	 7, // 0x559
+	 7, // 0x55a
+	 7, // 0x55b
+	 7, // 0x55c
+	 7, // 0x55d
+	 7, // 0x55e
+	 7, // 0x55f
+	 7, // 0x560
+	 7, // 0x561
+	 7, // 0x562
+	 7, // 0x563
+	 7, // 0x564
+	 7, // 0x565
+	 7, // 0x566
+	 7, // 0x567
+	 7, // 0x568
+	 7, // 0x569
+	 7, // 0x56a
+	 7, // 0x56b
+	 7, // 0x56c
+	 7, // 0x56d
+	 7, // 0x56e
+	 7, // 0x56f
+	 7, // 0x570
+	 7, // 0x571
+	 7, // 0x572
+	 7, // 0x573
+	 7, // 0x574
+	 7, // 0x575
+	 7, // 0x576
+	 7, // 0x577
+	 7, // 0x578
+	 7, // 0x579
+	 7, // 0x57a
+	 7, // 0x57b
+	 7, // 0x57c
+	 7, // 0x57d
+	 7, // 0x57e
+	 7, // 0x57f
+	 7, // 0x580
+	 7, // 0x581
+	 7, // 0x582
+	 7, // 0x583
+	 7, // 0x584
+	 7, // 0x585
+	 7, // 0x586
+	 7, // 0x587
+	 7, // 0x588
+	 7, // 0x589
+	 7, // 0x58a
+	 7, // 0x58b
+	 7, // 0x58c
+	 7, // 0x58d
+	 7, // 0x58e
+	 7, // 0x58f
+	 7, // 0x590
+	 7, // 0x591
+	 7, // 0x592
+	 7, // 0x593
+	 7, // 0x594
+	 7, // 0x595
+	 7, // 0x596
+	 7, // 0x597
+	 7, // 0x598
+	 7, // 0x599
+	 7, // 0x59a
+	 7, // 0x59b
+	 7, // 0x59c
+	 7, // 0x59d
+	 7, // 0x59e
+	 7, // 0x59f
+	 7, // 0x5a0
+	 7, // 0x5a1
+	 7, // 0x5a2
+	 7, // 0x5a3
+	 7, // 0x5a4
+	 7, // 0x5a5
+	 7, // 0x5a6
+	 7, // 0x5a7
+	 7, // 0x5a8
+	 7, // 0x5a9
+	 7, // 0x5aa
+	 7, // 0x5ab
+	 7, // 0x5ac
+	 7, // 0x5ad
+	 7, // 0x5ae
+	 7, // 0x5af
+	 7, // 0x5b0
+	 7, // 0x5b1
+	 7, // 0x5b2
+	 7, // 0x5b3
+	 7, // 0x5b4
+	 7, // 0x5b5
+	 7, // 0x5b6
+	 7, // 0x5b7
+	 7, // 0x5b8
+	 7, // 0x5b9
+	 7, // 0x5ba
+	 7, // 0x5bb
+	 7, // 0x5bc
+	 7, // 0x5bd
+	 7, // 0x5be
+	 7, // 0x5bf
+	 7, // 0x5c0
+	 7, // 0x5c1
+	 7, // 0x5c2
+	 7, // 0x5c3
+	 7, // 0x5c4
+	 7, // 0x5c5
+	 7, // 0x5c6
+	 7, // 0x5c7
+	 7, // 0x5c8
+	 7, // 0x5c9
+	 7, // 0x5ca
+	 7, // 0x5cb
+	 7, // 0x5cc
+	 7, // 0x5cd
+	 7, // 0x5ce
+	 7, // 0x5cf
+	 7, // 0x5d0
+	 7, // 0x5d1
+	 7, // 0x5d2
+	 7, // 0x5d3
+	 7, // 0x5d4
+	 7, // 0x5d5
+	 7, // 0x5d6
+	 7, // 0x5d7
+	 7, // 0x5d8
+	 7, // 0x5d9
+	 7, // 0x5da
+	 7, // 0x5db
+	 7, // 0x5dc
+	 7, // 0x5dd
+	 7, // 0x5de
+	 7, // 0x5df
+	 7, // 0x5e0
+	 7, // 0x5e1
+	 7, // 0x5e2
+	 7, // 0x5e3
+	 7, // 0x5e4
+	 7, // 0x5e5
+	 7, // 0x5e6
+	 7, // 0x5e7
+	 7, // 0x5e8
+	 7, // 0x5e9
+	 7, // 0x5ea
+	 7, // 0x5eb
+	 7, // 0x5ec
+	 7, // 0x5ed
+	 7, // 0x5ee
+	 7, // 0x5ef
+	 7, // 0x5f0
+	 7, // 0x5f1
+	 7, // 0x5f2
+	 7, // 0x5f3
+	 7, // 0x5f4
+	 7, // 0x5f5
+	 7, // 0x5f6
+	 7, // 0x5f7
+	 7, // 0x5f8
+	 7, // 0x5f9
+	 7, // 0x5fa
+	 7, // 0x5fb
+	 7, // 0x5fc
+	 7, // 0x5fd
+	 7, // 0x5fe
+	 7, // 0x5ff
+	 7, // 0x600
+	 7, // 0x601
+	 7, // 0x602
+	 7, // 0x603
+	 7, // 0x604
+	 7, // 0x605
+	 7, // 0x606
+	 7, // 0x607
+	 7, // 0x608
+	 7, // 0x609
+	 7, // 0x60a
+	 7, // 0x60b
+	 7, // 0x60c
+	 7, // 0x60d
+	 7, // 0x60e
+	 7, // 0x60f
+	 7, // 0x610
+	 7, // 0x611
+	 7, // 0x612
+	 7, // 0x613
+	 7, // 0x614
+	 7, // 0x615
+	 7, // 0x616
+	 7, // 0x617
+	 7, // 0x618
+	 7, // 0x619
+	 7, // 0x61a
+	 7, // 0x61b
+	 7, // 0x61c
+	 7, // 0x61d
+	 7, // 0x61e
+	 7, // 0x61f
+	 7, // 0x620
+	 7, // 0x621
+	 7, // 0x622
+	 7, // 0x623
+	 7, // 0x624
+	 7, // 0x625
+	 7, // 0x626
+	 7, // 0x627
+	 7, // 0x628
+	 7, // 0x629
+	 7, // 0x62a
+	 7, // 0x62b
+	 7, // 0x62c
+	 7, // 0x62d
+	 7, // 0x62e
+	 7, // 0x62f
+	 7, // 0x630
+	 7, // 0x631
+	 7, // 0x632
+	 7, // 0x633
+	 7, // 0x634
+	 7, // 0x635
+	 7, // 0x636
+	 7, // 0x637
+	 7, // 0x638
+	 7, // 0x639
+	 7, // 0x63a
+	 7, // 0x63b
+	 7, // 0x63c
+	 7, // 0x63d
+	 7, // 0x63e
+	 7, // 0x63f
+	 7, // 0x640
+	 7, // 0x641
+	 7, // 0x642
+	 7, // 0x643
+	 7, // 0x644
+	 7, // 0x645
+	 7, // 0x646
+	 7, // 0x647
+	 7, // 0x648
+	 7, // 0x649
+	 7, // 0x64a
+	 7, // 0x64b
+	 7, // 0x64c
+	 7, // 0x64d
+	 7, // 0x64e
+	 7, // 0x64f
+	 7, // 0x650
+	 7, // 0x651
+	 7, // 0x652
+	 7, // 0x653
+	 7, // 0x654
+	 7, // 0x655
+	 7, // 0x656
+	 7, // 0x657
+	 7, // 0x658
+	 7, // 0x659
+	 7, // 0x65a
+	 7, // 0x65b
+	 7, // 0x65c
+	 7, // 0x65d
+	 7, // 0x65e
+	 7, // 0x65f
+	 7, // 0x660
+	 7, // 0x661
+	 7, // 0x662
+	 7, // 0x663
+	 7, // 0x664
+	 7, // 0x665
+	 7, // 0x666
+	 7, // 0x667
+	 7, // 0x668
+	 7, // 0x669
+	 7, // 0x66a
+	 7, // 0x66b
+	 7, // 0x66c
+	 7, // 0x66d
+	 7, // 0x66e
+	 7, // 0x66f
+	 7, // 0x670
+	 7, // 0x671
+	 7, // 0x672
+	 7, // 0x673
+	 7, // 0x674
+	 7, // 0x675
+	 7, // 0x676
+	 7, // 0x677
+	 7, // 0x678
+	 7, // 0x679
+	 7, // 0x67a
+	 7, // 0x67b
+	 7, // 0x67c
+	 7, // 0x67d
+	 7, // 0x67e
+	 7, // 0x67f
+	 7, // 0x680
+	 7, // 0x681
+	 7, // 0x682
+	 7, // 0x683
+	 7, // 0x684
+	 7, // 0x685
+	 7, // 0x686
+	 7, // 0x687
+	 7, // 0x688
+	 7, // 0x689
+	 7, // 0x68a
+	 7, // 0x68b
+	 7, // 0x68c
+	 7, // 0x68d
+	 7, // 0x68e
+	 7, // 0x68f
+	 7, // 0x690
+	 7, // 0x691
+	 7, // 0x692
+	 7, // 0x693
+	 7, // 0x694
+	 7, // 0x695
+	 7, // 0x696
+	 7, // 0x697
+	 7, // 0x698
+	 7, // 0x699
+	 7, // 0x69a
+	 7, // 0x69b
+	 7, // 0x69c
+	 7, // 0x69d
+	 7, // 0x69e
+	 7, // 0x69f
+	 7, // 0x6a0
+	 7, // 0x6a1
+	 7, // 0x6a2
+	 7, // 0x6a3
+	 7, // 0x6a4
+	 7, // 0x6a5
+	 7, // 0x6a6
+	 7, // 0x6a7
+	 7, // 0x6a8
+	 7, // 0x6a9
+	 7, // 0x6aa
+	 7, // 0x6ab
+	 7, // 0x6ac
+	 7, // 0x6ad
+	 7, // 0x6ae
+	 7, // 0x6af
+	 7, // 0x6b0
+	 7, // 0x6b1
+	 7, // 0x6b2
+	 7, // 0x6b3
+	 7, // 0x6b4
+	 7, // 0x6b5
+	 7, // 0x6b6
+	 7, // 0x6b7
+	 7, // 0x6b8
+	 7, // 0x6b9
+	 7, // 0x6ba
+	 7, // 0x6bb
+	 7, // 0x6bc
+	 7, // 0x6bd
+	 7, // 0x6be
+	 7, // 0x6bf
+	 7, // 0x6c0
+	 7, // 0x6c1
+	 7, // 0x6c2
+	 7, // 0x6c3
+	 7, // 0x6c4
+	 7, // 0x6c5
+	 7, // 0x6c6
+	 7, // 0x6c7
+	 7, // 0x6c8
+	 7, // 0x6c9
+	 7, // 0x6ca
+	 7, // 0x6cb
+	 7, // 0x6cc
+	 7, // 0x6cd
+	 7, // 0x6ce
+	 7, // 0x6cf
+	 7, // 0x6d0
+	 7, // 0x6d1
+	 7, // 0x6d2
+	 7, // 0x6d3
+	 7, // 0x6d4
+	 7, // 0x6d5
+	 7, // 0x6d6
+	 7, // 0x6d7
+	 7, // 0x6d8
+	 7, // 0x6d9
+	 7, // 0x6da
+	 7, // 0x6db
+	 7, // 0x6dc
+	 7, // 0x6dd
+	 7, // 0x6de
+	 7, // 0x6df
+	 7, // 0x6e0
+	 7, // 0x6e1
+	 7, // 0x6e2
+	 7, // 0x6e3
+	 7, // 0x6e4
+	 7, // 0x6e5
+	 7, // 0x6e6
+	 7, // 0x6e7
+	 7, // 0x6e8
+	 7, // 0x6e9
+	 7, // 0x6ea
+	 7, // 0x6eb
+	 7, // 0x6ec
+	 7, // 0x6ed
+	 7, // 0x6ee
+	 7, // 0x6ef
+	 7, // 0x6f0
+	 7, // 0x6f1
+	 7, // 0x6f2
+	 7, // 0x6f3
+	 7, // 0x6f4
+	 7, // 0x6f5
+	 7, // 0x6f6
+	 7, // 0x6f7
+	 7, // 0x6f8
+	 7, // 0x6f9
+	 7, // 0x6fa
+	 7, // 0x6fb
+	 7, // 0x6fc
+	 7, // 0x6fd
+	 7, // 0x6fe
+	 7, // 0x6ff
+	 7, // 0x700
+	 7, // 0x701
+	 7, // 0x702
+	 7, // 0x703
+	 7, // 0x704
+	 7, // 0x705
+	 7, // 0x706
+	 7, // 0x707
+	 7, // 0x708
+	 7, // 0x709
+	 7, // 0x70a
+	 7, // 0x70b
+	 7, // 0x70c
+	 7, // 0x70d
+	 7, // 0x70e
+	 7, // 0x70f
+	 7, // 0x710
+	 7, // 0x711
+	 7, // 0x712
+	 7, // 0x713
+	 7, // 0x714
+	 7, // 0x715
+	 7, // 0x716
+	 7, // 0x717
+	 7, // 0x718
+	 7, // 0x719
+	 7, // 0x71a
+	 7, // 0x71b
+	 7, // 0x71c
+	 7, // 0x71d
+	 7, // 0x71e
+	 7, // 0x71f
+	 7, // 0x720
+	 7, // 0x721
+	 7, // 0x722
+	 7, // 0x723
+	 7, // 0x724
+	 7, // 0x725
+	 7, // 0x726
+	 7, // 0x727
+	 7, // 0x728
+	 7, // 0x729
+	 7, // 0x72a
+	 7, // 0x72b
+	 7, // 0x72c
+	 7, // 0x72d
+	 7, // 0x72e
+	 7, // 0x72f
+	 7, // 0x730
+	 7, // 0x731
+	 7, // 0x732
+	 7, // 0x733
+	 7, // 0x734
+	 7, // 0x735
+	 7, // 0x736
+	 7, // 0x737
+	 7, // 0x738
+	 7, // 0x739
+	 7, // 0x73a
+	 7, // 0x73b
+	 7, // 0x73c
+	 7, // 0x73d
+	 7, // 0x73e
+	 7, // 0x73f
+	 7, // 0x740
+	 7, // 0x741
+	 7, // 0x742
+	 7, // 0x743
+	 7, // 0x744
+	 7, // 0x745
+	 7, // 0x746
+	 7, // 0x747
+	 7, // 0x748
+	 7, // 0x749
+	 7, // 0x74a
+	 7, // 0x74b
+	 7, // 0x74c
+	 7, // 0x74d
+	 7, // 0x74e
+	 7, // 0x74f
+	 7, // 0x750
+	 7, // 0x751
+	 7, // 0x752
+	 7, // 0x753
+	 7, // 0x754
+	 7, // 0x755
+	 7, // 0x756
+	 7, // 0x757
+	 7, // 0x758
+	 7, // 0x759
+	 7, // 0x75a
+	 7, // 0x75b
+	 7, // 0x75c
+	 7, // 0x75d
+	 7, // 0x75e
+	 7, // 0x75f
+	 7, // 0x760
+	 7, // 0x761
+	 7, // 0x762
+	 7, // 0x763
+	 7, // 0x764
+	 7, // 0x765
+	 7, // 0x766
+	 7, // 0x767
+	 7, // 0x768
+	 7, // 0x769
+	 7, // 0x76a
+	 7, // 0x76b
+	 7, // 0x76c
+	 7, // 0x76d
+	 7, // 0x76e
+	 7, // 0x76f
+	 7, // 0x770
+	 7, // 0x771
+	 7, // 0x772
+	 7, // 0x773
+	 7, // 0x774
+	 7, // 0x775
+	 7, // 0x776
+	 7, // 0x777
+	 7, // 0x778
+	 7, // 0x779
+	 7, // 0x77a
+	 7, // 0x77b
+	 7, // 0x77c
+	 7, // 0x77d
+	 7, // 0x77e
+	 7, // 0x77f
+	 7, // 0x780
+	 7, // 0x781
+	 7, // 0x782
+	 7, // 0x783
+	 7, // 0x784
+	 7, // 0x785
+	 7, // 0x786
+	 7, // 0x787
+	 7, // 0x788
+	 7, // 0x789
+	 7, // 0x78a
+	 7, // 0x78b
+	 7, // 0x78c
+	 7, // 0x78d
+	 7, // 0x78e
+	 7, // 0x78f
+	 7, // 0x790
+	 7, // 0x791
+	 7, // 0x792
+	 7, // 0x793
+	 7, // 0x794
+	 7, // 0x795
+	 7, // 0x796
+	 7, // 0x797
+	 7, // 0x798
+	 7, // 0x799
+	 7, // 0x79a
+	 7, // 0x79b
+	 7, // 0x79c
+	 7, // 0x79d
+	 7, // 0x79e
+	 7, // 0x79f
+	 7, // 0x7a0
+	 7, // 0x7a1
+	 7, // 0x7a2
+	 7, // 0x7a3
+	 7, // 0x7a4
+	 7, // 0x7a5
+	 7, // 0x7a6
+	 7, // 0x7a7
+	 7, // 0x7a8
+	 7, // 0x7a9
+	 7, // 0x7aa
+	 7, // 0x7ab
+	 7, // 0x7ac
+	 7, // 0x7ad
+	 7, // 0x7ae
+	 7, // 0x7af
+	 7, // 0x7b0
+	 7, // 0x7b1
+	 7, // 0x7b2
+	 7, // 0x7b3
+	 7, // 0x7b4
+	 7, // 0x7b5
+	 7, // 0x7b6
+	 7, // 0x7b7
+	 7, // 0x7b8
+	 7, // 0x7b9
+	 7, // 0x7ba
+	 7, // 0x7bb
+	 7, // 0x7bc
+	 7, // 0x7bd
+	 7, // 0x7be
+	 7, // 0x7bf
+	 7, // 0x7c0
+	 7, // 0x7c1
+	 7, // 0x7c2
+	 7, // 0x7c3
+	 7, // 0x7c4
+	 7, // 0x7c5
+	 7, // 0x7c6
+	 7, // 0x7c7
+	 7, // 0x7c8
+	 7, // 0x7c9
+	 7, // 0x7ca
+	 7, // 0x7cb
+	 7, // 0x7cc
+	 7, // 0x7cd
+	 7, // 0x7ce
+	 7, // 0x7cf
+	 7, // 0x7d0
+	 7, // 0x7d1
+	 7, // 0x7d2
+	 7, // 0x7d3
+	 7, // 0x7d4
+	 7, // 0x7d5
+	 7, // 0x7d6
+	 7, // 0x7d7
+	 7, // 0x7d8
+	 7, // 0x7d9
+	 7, // 0x7da
+	 7, // 0x7db
+	 7, // 0x7dc
+	 7, // 0x7dd
+	 7, // 0x7de
+	 7, // 0x7df
+	 7, // 0x7e0
+	 7, // 0x7e1
+	 7, // 0x7e2
+	 7, // 0x7e3
+	 7, // 0x7e4
+	 7, // 0x7e5
+	 7, // 0x7e6
+	 7, // 0x7e7
+	 7, // 0x7e8
+	 7, // 0x7e9
+	 7, // 0x7ea
+	 7, // 0x7eb
+	 7, // 0x7ec
+	 7, // 0x7ed
+	 7, // 0x7ee
+	 7, // 0x7ef
+	 7, // 0x7f0
+	 7, // 0x7f1
+	 7, // 0x7f2
+	 7, // 0x7f3
+	 7, // 0x7f4
+	 7, // 0x7f5
+	 7, // 0x7f6
+	 7, // 0x7f7
+	 7, // 0x7f8
+	 7, // 0x7f9
+	 7, // 0x7fa
+	 7, // 0x7fb
+	 7, // 0x7fc
+	 7, // 0x7fd
+	 7, // 0x7fe
+	 7, // 0x7ff
+	 7, // 0x800
+	 7, // 0x801
+	 7, // 0x802
+	 7, // 0x803
+	 7, // 0x804
+	 7, // 0x805
+	 7, // 0x806
+	 7, // 0x807
+	 7, // 0x808
+	 7, // 0x809
+	 7, // 0x80a
+	 7, // 0x80b
+	 7, // 0x80c
+	 7, // 0x80d
+	 7, // 0x80e
+	 7, // 0x80f
+	 7, // 0x810
+	 7, // 0x811
+	 7, // 0x812
+	 7, // 0x813
+	 7, // 0x814
+	 7, // 0x815
+	 7, // 0x816
+	 7, // 0x817
+	 7, // 0x818
+	 7, // 0x819
+	 7, // 0x81a
+	 7, // 0x81b
+	 7, // 0x81c
+	 7, // 0x81d
+	 7, // 0x81e
+	 7, // 0x81f
+	 7, // 0x820
+	 7, // 0x821
+	 7, // 0x822
+	 7, // 0x823
+	 7, // 0x824
+	 7, // 0x825
+	 7, // 0x826
+	 7, // 0x827
+	 7, // 0x828
+	 7, // 0x829
+	 7, // 0x82a
+	 7, // 0x82b
+	 7, // 0x82c
+	 7, // 0x82d
+	 7, // 0x82e
+	 7, // 0x82f
+	 7, // 0x830
+	 7, // 0x831
+	 7, // 0x832
+	 7, // 0x833
+	 7, // 0x834
+	 7, // 0x835
+	 7, // 0x836
+	 7, // 0x837
+	 7, // 0x838
+	 7, // 0x839
+	 7, // 0x83a
+	 7, // 0x83b
+	 7, // 0x83c
+	 7, // 0x83d
+	 7, // 0x83e
+	 7, // 0x83f
+	 7, // 0x840
+	 7, // 0x841
+	 7, // 0x842
+	 7, // 0x843
+	 7, // 0x844
+	 7, // 0x845
+	 7, // 0x846
+	 7, // 0x847
+	 7, // 0x848
+	 7, // 0x849
+	 7, // 0x84a
+	 7, // 0x84b
+	 7, // 0x84c
+	 7, // 0x84d
+	 7, // 0x84e
+	 7, // 0x84f
+	 7, // 0x850
+	 7, // 0x851
+	 7, // 0x852
+	 7, // 0x853
+	 7, // 0x854
+	 7, // 0x855
+	 7, // 0x856
+	 7, // 0x857
+	 7, // 0x858
+	 7, // 0x859
+	 7, // 0x85a
+	 7, // 0x85b
+	 7, // 0x85c
+	 7, // 0x85d
+	 7, // 0x85e
+	 7, // 0x85f
+	 7, // 0x860
+	 7, // 0x861
+	 7, // 0x862
+	 7, // 0x863
+	 7, // 0x864
+	 7, // 0x865
+	 7, // 0x866
+	 7, // 0x867
+	 7, // 0x868
+	 7, // 0x869
+	 7, // 0x86a
+	 7, // 0x86b
+	 7, // 0x86c
+	 7, // 0x86d
+	 7, // 0x86e
+	 7, // 0x86f
+	 7, // 0x870
+	 7, // 0x871
+	 7, // 0x872
+	 7, // 0x873
+	 7, // 0x874
+	 7, // 0x875
+	 7, // 0x876
+	 7, // 0x877
+	 7, // 0x878
+	 7, // 0x879
+	 7, // 0x87a
+	 7, // 0x87b
+	 7, // 0x87c
+	 7, // 0x87d
+	 7, // 0x87e
+	 7, // 0x87f
+	 7, // 0x880
+	 7, // 0x881
+	 7, // 0x882
+	 7, // 0x883
+	 7, // 0x884
+	 7, // 0x885
+	 7, // 0x886
+	 7, // 0x887
+	 7, // 0x888
+	 7, // 0x889
+	 7, // 0x88a
+	 7, // 0x88b
+	 7, // 0x88c
+	 7, // 0x88d
+	 7, // 0x88e
+	 7, // 0x88f
+	 7, // 0x890
+	 7, // 0x891
+	 7, // 0x892
+	 7, // 0x893
+	 7, // 0x894
+	 7, // 0x895
+	 7, // 0x896
+	 7, // 0x897
+	 7, // 0x898
+	 7, // 0x899
+	 7, // 0x89a
+	 7, // 0x89b
+	 7, // 0x89c
+	 7, // 0x89d
+	 7, // 0x89e
+	 7, // 0x89f
+	 7, // 0x8a0
+	 7, // 0x8a1
+	 7, // 0x8a2
+	 7, // 0x8a3
+	 7, // 0x8a4
+	 7, // 0x8a5
+	 7, // 0x8a6
+	 7, // 0x8a7
+	 7, // 0x8a8
+	 7, // 0x8a9
+	 7, // 0x8aa
+	 7, // 0x8ab
+	 7, // 0x8ac
+	 7, // 0x8ad
+	 7, // 0x8ae
+	 7, // 0x8af
+	 7, // 0x8b0
+	 7, // 0x8b1
+	 7, // 0x8b2
+	 7, // 0x8b3
+	 7, // 0x8b4
+	 7, // 0x8b5
+	 7, // 0x8b6
+	 7, // 0x8b7
+	 7, // 0x8b8
+	 7, // 0x8b9
+	 7, // 0x8ba
+	 7, // 0x8bb
+	 7, // 0x8bc
+	 7, // 0x8bd
+	 7, // 0x8be
+	 7, // 0x8bf
+	 7, // 0x8c0
+	 7, // 0x8c1
+	 7, // 0x8c2
+	 7, // 0x8c3
+	 7, // 0x8c4
+	 7, // 0x8c5
+	 7, // 0x8c6
+	 7, // 0x8c7
+	 7, // 0x8c8
+	 7, // 0x8c9
+	 7, // 0x8ca
+	 7, // 0x8cb
+	 7, // 0x8cc
+	 7, // 0x8cd
+	 7, // 0x8ce
+	 7, // 0x8cf
+	 7, // 0x8d0
+	 7, // 0x8d1
+	 7, // 0x8d2
+	 7, // 0x8d3
+	 7, // 0x8d4
+	 7, // 0x8d5
+	 7, // 0x8d6
+	 7, // 0x8d7
+	 7, // 0x8d8
+	 7, // 0x8d9
+	 7, // 0x8da
+	 7, // 0x8db
+	 7, // 0x8dc
+	 7, // 0x8dd
+	 7, // 0x8de
+	 7, // 0x8df
+	 7, // 0x8e0
+	 7, // 0x8e1
+	 7, // 0x8e2
+	 7, // 0x8e3
+	 7, // 0x8e4
+	 7, // 0x8e5
+	 7, // 0x8e6
+	 7, // 0x8e7
+	 7, // 0x8e8
+	 7, // 0x8e9
+	 7, // 0x8ea
+	 7, // 0x8eb
+	 7, // 0x8ec
+	 7, // 0x8ed
+	 7, // 0x8ee
+	 7, // 0x8ef
+	 7, // 0x8f0
+	 7, // 0x8f1
+	 7, // 0x8f2
+	 7, // 0x8f3
+	 7, // 0x8f4
+	 7, // 0x8f5
+	 7, // 0x8f6
+	 7, // 0x8f7
+	 7, // 0x8f8
+	 7, // 0x8f9
+	 7, // 0x8fa
+	 7, // 0x8fb
+	 7, // 0x8fc
+	 7, // 0x8fd
+	 7, // 0x8fe
+	 7, // 0x8ff
+	 7, // 0x900
+	 7, // 0x901
+	 7, // 0x902
+	 7, // 0x903
+	 7, // 0x904
+	 7, // 0x905
+	 7, // 0x906
+	 7, // 0x907
+	 7, // 0x908
+	 7, // 0x909
+	 7, // 0x90a
+	 7, // 0x90b
+	 7, // 0x90c
+	 7, // 0x90d
+	 7, // 0x90e
+	 7, // 0x90f
+	 7, // 0x910
+	 7, // 0x911
+	 7, // 0x912
+	 7, // 0x913
+	 7, // 0x914
+	 7, // 0x915
+	 7, // 0x916
+	 7, // 0x917
+	 7, // 0x918
+	 7, // 0x919
+	 7, // 0x91a
+	 7, // 0x91b
+	 7, // 0x91c
+	 7, // 0x91d
+	 7, // 0x91e
+	 7, // 0x91f
+	 7, // 0x920
+	 7, // 0x921
+	 7, // 0x922
+	 7, // 0x923
+	 7, // 0x924
+	 7, // 0x925
+	 7, // 0x926
+	 7, // 0x927
+	 7, // 0x928
+	 7, // 0x929
+	 7, // 0x92a
+	 7, // 0x92b
+	 7, // 0x92c
+	 7, // 0x92d
+	 7, // 0x92e
+	 7, // 0x92f
+	 7, // 0x930
+	 7, // 0x931
+	 7, // 0x932
+	 7, // 0x933
+	 7, // 0x934
+	 7, // 0x935
+	 7, // 0x936
+	 7, // 0x937
+	 7, // 0x938
+	 7, // 0x939
+	 7, // 0x93a
+	 7, // 0x93b
+	 7, // 0x93c
+	 7, // 0x93d
+	 7, // 0x93e
+	 7, // 0x93f
+	 7, // 0x940
+	 7, // 0x941
+	 7, // 0x942
+	 7, // 0x943
+	 7, // 0x944
+	 7, // 0x945
+	 7, // 0x946
+	 7, // 0x947
+	 7, // 0x948
+	 7, // 0x949
+	 7, // 0x94a
+	 7, // 0x94b
+	 7, // 0x94c
+	 7, // 0x94d
+	 7, // 0x94e
+	 7, // 0x94f
+	 7, // 0x950
+	 7, // 0x951
+	 7, // 0x952
+	 7, // 0x953
+	 7, // 0x954
+	 7, // 0x955
+	 7, // 0x956
+	 7, // 0x957
+	 7, // 0x958
+	 7, // 0x959
+	 7, // 0x95a
+	 7, // 0x95b
+	 7, // 0x95c
+	 7, // 0x95d
+	 7, // 0x95e
+	 7, // 0x95f
+	 7, // 0x960
+	 7, // 0x961
+	 7, // 0x962
+	 7, // 0x963
+	 7, // 0x964
+	 7, // 0x965
+	 7, // 0x966
+	 7, // 0x967
+	 7, // 0x968
+	 7, // 0x969
+	 7, // 0x96a
+	 7, // 0x96b
+	 7, // 0x96c
+	 7, // 0x96d
+	 7, // 0x96e
+	 7, // 0x96f
+	 7, // 0x970
+	 7, // 0x971
+	 7, // 0x972
+	 7, // 0x973
+	 7, // 0x974
+	 7, // 0x975
+	 7, // 0x976
+	 7, // 0x977
+	 7, // 0x978
+	 7, // 0x979
+	 7, // 0x97a
+	 7, // 0x97b
+	 7, // 0x97c
+	 7, // 0x97d
+	 7, // 0x97e
+	 7, // 0x97f
+	 7, // 0x980
+	 7, // 0x981
+	 7, // 0x982
+	 7, // 0x983
+	 7, // 0x984
+	 7, // 0x985
+	 7, // 0x986
+	 7, // 0x987
+	 7, // 0x988
+	 7, // 0x989
+	 7, // 0x98a
+	 7, // 0x98b
+	 7, // 0x98c
+	 7, // 0x98d
+	 7, // 0x98e
+	 7, // 0x98f
+	 7, // 0x990
+	 7, // 0x991
+	 7, // 0x992
+	 7, // 0x993
+	 7, // 0x994
+	 7, // 0x995
+	 7, // 0x996
+	 7, // 0x997
+	 7, // 0x998
+	 7, // 0x999
+	 7, // 0x99a
+	 7, // 0x99b
+	 7, // 0x99c
+	 7, // 0x99d
+	 7, // 0x99e
+	 7, // 0x99f
+	 7, // 0x9a0
+	 7, // 0x9a1
+	 7, // 0x9a2
+	 7, // 0x9a3
+	 7, // 0x9a4
+	 7, // 0x9a5
+	 7, // 0x9a6
+	 7, // 0x9a7
+	 7, // 0x9a8
+	 7, // 0x9a9
+	 7, // 0x9aa
+	 7, // 0x9ab
+	 7, // 0x9ac
+	 7, // 0x9ad
+	 7, // 0x9ae
+	 7, // 0x9af
+	 7, // 0x9b0
+	 7, // 0x9b1
+	 7, // 0x9b2
+	 7, // 0x9b3
+	 7, // 0x9b4
+	 7, // 0x9b5
+	 7, // 0x9b6
+	 7, // 0x9b7
+	 7, // 0x9b8
+	 7, // 0x9b9
+	 7, // 0x9ba
+	 7, // 0x9bb
+	 7, // 0x9bc
+	 7, // 0x9bd
+	 7, // 0x9be
+	 7, // 0x9bf
+	 7, // 0x9c0
+	 7, // 0x9c1
+	 7, // 0x9c2
+	 7, // 0x9c3
+	 7, // 0x9c4
+	 7, // 0x9c5
+	 7, // 0x9c6
+	 7, // 0x9c7
+	 7, // 0x9c8
+	 7, // 0x9c9
+	 7, // 0x9ca
+	 7, // 0x9cb
+	 7, // 0x9cc
+	 7, // 0x9cd
+	 7, // 0x9ce
+	 7, // 0x9cf
+	 7, // 0x9d0
+	 7, // 0x9d1
+	 7, // 0x9d2
+	 7, // 0x9d3
+	 7, // 0x9d4
+	 7, // 0x9d5
+	 7, // 0x9d6
+	 7, // 0x9d7
+	 7, // 0x9d8
+	 7, // 0x9d9
+	 7, // 0x9da
+	 7, // 0x9db
+	 7, // 0x9dc
+	 7, // 0x9dd
+	 7, // 0x9de
+	 7, // 0x9df
+	 7, // 0x9e0
+	 7, // 0x9e1
+	 7, // 0x9e2
+	 7, // 0x9e3
+	 7, // 0x9e4
+	 7, // 0x9e5
+	 7, // 0x9e6
+	 7, // 0x9e7
+	 7, // 0x9e8
+	 7, // 0x9e9
+	 7, // 0x9ea
+	 7, // 0x9eb
+	 7, // 0x9ec
+	 7, // 0x9ed
+	 7, // 0x9ee
+	 7, // 0x9ef
+	 7, // 0x9f0
+	 7, // 0x9f1
+	 7, // 0x9f2
+	 7, // 0x9f3
+	 7, // 0x9f4
+	 7, // 0x9f5
+	 7, // 0x9f6
+	 7, // 0x9f7
+	 7, // 0x9f8
+	 7, // 0x9f9
+	 7, // 0x9fa
+	 7, // 0x9fb
+	 7, // 0x9fc
+	 7, // 0x9fd
+	 7, // 0x9fe
+	 7, // 0x9ff
+	 7, // 0xa00
+	 7, // 0xa01
+	 7, // 0xa02
+	 7, // 0xa03
+	 7, // 0xa04
+	 7, // 0xa05
+	 7, // 0xa06
+	 7, // 0xa07
+	 7, // 0xa08
+	 7, // 0xa09
+	 7, // 0xa0a
+	 7, // 0xa0b
+	 7, // 0xa0c
+	 7, // 0xa0d
+	 7, // 0xa0e
+	 7, // 0xa0f
+	 7, // 0xa10
+	 7, // 0xa11
+	 7, // 0xa12
+	 7, // 0xa13
+	 7, // 0xa14
+	 7, // 0xa15
+	 7, // 0xa16
+	 7, // 0xa17
+	 7, // 0xa18
+	 7, // 0xa19
+	 7, // 0xa1a
+	 7, // 0xa1b
+	 7, // 0xa1c
+	 7, // 0xa1d
+	 7, // 0xa1e
+	 7, // 0xa1f
+	 7, // 0xa20
+	 7, // 0xa21
+	 7, // 0xa22
+	 7, // 0xa23
+	 7, // 0xa24
+	 7, // 0xa25
+	 7, // 0xa26
+	 7, // 0xa27
+	 7, // 0xa28
+	 7, // 0xa29
+	 7, // 0xa2a
+	 7, // 0xa2b
+	 7, // 0xa2c
+	 7, // 0xa2d
+	 7, // 0xa2e
+	 7, // 0xa2f
+	 7, // 0xa30
+	 7, // 0xa31
+	 7, // 0xa32
+	 7, // 0xa33
+	 7, // 0xa34
+	 7, // 0xa35
+	 7, // 0xa36
+	 7, // 0xa37
+	 7, // 0xa38
+	 7, // 0xa39
+	 7, // 0xa3a
+	 7, // 0xa3b
+	 7, // 0xa3c
+	 7, // 0xa3d
+	 7, // 0xa3e
+	 7, // 0xa3f
+	 7, // 0xa40
+	 7, // 0xa41
+	 7, // 0xa42
+	 7, // 0xa43
+	 7, // 0xa44
+	 7, // 0xa45
+	 7, // 0xa46
+	 7, // 0xa47
+	 7, // 0xa48
+	 7, // 0xa49
+	 7, // 0xa4a
+	 7, // 0xa4b
+	 7, // 0xa4c
+	 7, // 0xa4d
+	 7, // 0xa4e
+	 7, // 0xa4f
+	 7, // 0xa50
+	 7, // 0xa51
+	 7, // 0xa52
+	 7, // 0xa53
+	 7, // 0xa54
+	 7, // 0xa55
+	 7, // 0xa56
+	 7, // 0xa57
+	 7, // 0xa58
+	 7, // 0xa59
+	 7, // 0xa5a
+	 7, // 0xa5b
+	 7, // 0xa5c
+	 7, // 0xa5d
+	 7, // 0xa5e
+	 7, // 0xa5f
+	 7, // 0xa60
+	 7, // 0xa61
+	 7, // 0xa62
+	 7, // 0xa63
+	 7, // 0xa64
+	 7, // 0xa65
+	 7, // 0xa66
+	 7, // 0xa67
+	 7, // 0xa68
+	 7, // 0xa69
+	 7, // 0xa6a
+	 7, // 0xa6b
+	 7, // 0xa6c
+	 7, // 0xa6d
+	 7, // 0xa6e
+	 7, // 0xa6f
+	 7, // 0xa70
+	 7, // 0xa71
+	 7, // 0xa72
+	 7, // 0xa73
+	 7, // 0xa74
+	 7, // 0xa75
+	 7, // 0xa76
+	 7, // 0xa77
+	 7, // 0xa78
+	 7, // 0xa79
+	 7, // 0xa7a
+	 7, // 0xa7b
+	 7, // 0xa7c
+	 7, // 0xa7d
+	 7, // 0xa7e
+	 7, // 0xa7f
+	 7, // 0xa80
+	 7, // 0xa81
+	 7, // 0xa82
+	 7, // 0xa83
+	 7, // 0xa84
+	 7, // 0xa85
+	 7, // 0xa86
+	 7, // 0xa87
+	 7, // 0xa88
+	 7, // 0xa89
+	 7, // 0xa8a
+	 7, // 0xa8b
+	 7, // 0xa8c
+	 7, // 0xa8d
+	 7, // 0xa8e
+	 7, // 0xa8f
+	 7, // 0xa90
+	 7, // 0xa91
+	 7, // 0xa92
+	 7, // 0xa93
+	 7, // 0xa94
+	 7, // 0xa95
+	 7, // 0xa96
+	 7, // 0xa97
+	 7, // 0xa98
+	 7, // 0xa99
+	 7, // 0xa9a
+	 7, // 0xa9b
+	 7, // 0xa9c
+	 7, // 0xa9d
+	 7, // 0xa9e
+	 7, // 0xa9f
+	 7, // 0xaa0
+	 7, // 0xaa1
+	 7, // 0xaa2
+	 7, // 0xaa3
+	 7, // 0xaa4
+	 7, // 0xaa5
+	 7, // 0xaa6
+	 7, // 0xaa7
+	 7, // 0xaa8
+	 7, // 0xaa9
+	 7, // 0xaaa
+	 7, // 0xaab
+	 7, // 0xaac
+	 7, // 0xaad
+	 7, // 0xaae
+	 7, // 0xaaf
+	 7, // 0xab0
+	 7, // 0xab1
+	 7, // 0xab2
+	 7, // 0xab3
+	 7, // 0xab4
+	 7, // 0xab5
+	 7, // 0xab6
+	 7, // 0xab7
+	 7, // 0xab8
+	 7, // 0xab9
+	 7, // 0xaba
+	 7, // 0xabb
+	 7, // 0xabc
+	 7, // 0xabd
+	 7, // 0xabe
+	 7, // 0xabf
+	 7, // 0xac0
+	 7, // 0xac1
+	 7, // 0xac2
+	 7, // 0xac3
+	 7, // 0xac4
+	 7, // 0xac5
+	 7, // 0xac6
+	 7, // 0xac7
+	 7, // 0xac8
+	 7, // 0xac9
+	 7, // 0xaca
+	 7, // 0xacb
+	 7, // 0xacc
+	 7, // 0xacd
+	 7, // 0xace
+	 7, // 0xacf
+	 7, // 0xad0
+	 7, // 0xad1
+	 7, // 0xad2
+	 7, // 0xad3
+	 7, // 0xad4
+	 7, // 0xad5
+	 7, // 0xad6
+	 7, // 0xad7
+	 7, // 0xad8
+	 7, // 0xad9
+	 7, // 0xada
+	 7, // 0xadb
+	 7, // 0xadc
+	 7, // 0xadd
+	 7, // 0xade
+	 7, // 0xadf
+	 7, // 0xae0
+	 7, // 0xae1
+	 7, // 0xae2
+	 7, // 0xae3
+	 7, // 0xae4
+	 7, // 0xae5
+	 7, // 0xae6
+	 7, // 0xae7
+	 7, // 0xae8
+	 7, // 0xae9
+	 7, // 0xaea
+	 7, // 0xaeb
+	 7, // 0xaec
+	 7, // 0xaed
+	 7, // 0xaee
+	 7, // 0xaef
+	 7, // 0xaf0
+	 7, // 0xaf1
+	 7, // 0xaf2
+	 7, // 0xaf3
+	 7, // 0xaf4
+	 7, // 0xaf5
+	 7, // 0xaf6
+	 7, // 0xaf7
+	 7, // 0xaf8
+	 7, // 0xaf9
+	 7, // 0xafa
+	 7, // 0xafb
+	 7, // 0xafc
+	 7, // 0xafd
+	 7, // 0xafe
+	 7, // 0xaff
+	 7, // 0xb00
+	 7, // 0xb01
+	 7, // 0xb02
+	 7, // 0xb03
+	 7, // 0xb04
+	 7, // 0xb05
+	 7, // 0xb06
+	 7, // 0xb07
+	 7, // 0xb08
+	 7, // 0xb09
+	 7, // 0xb0a
+	 7, // 0xb0b
+	 7, // 0xb0c
+	 7, // 0xb0d
+	 7, // 0xb0e
+	 7, // 0xb0f
+	 7, // 0xb10
+	 7, // 0xb11
+	 7, // 0xb12
+	 7, // 0xb13
+	 7, // 0xb14
+	 7, // 0xb15
+	 7, // 0xb16
+	 7, // 0xb17
+	 7, // 0xb18
+	 7, // 0xb19
+	 7, // 0xb1a
+	 7, // 0xb1b
+	 7, // 0xb1c
+	 7, // 0xb1d
+	 7, // 0xb1e
+	 7, // 0xb1f
+	 7, // 0xb20
+	 7, // 0xb21
+	 7, // 0xb22
+	 7, // 0xb23
+	 7, // 0xb24
+	 7, // 0xb25
+	 7, // 0xb26
+	 7, // 0xb27
+	 7, // 0xb28
+	 7, // 0xb29
+	 7, // 0xb2a
+	 7, // 0xb2b
+	 7, // 0xb2c
+	 7, // 0xb2d
+	 7, // 0xb2e
+	 7, // 0xb2f
+	 7, // 0xb30
+	 7, // 0xb31
+	 7, // 0xb32
+	 7, // 0xb33
+	 7, // 0xb34
+	 7, // 0xb35
+	 7, // 0xb36
+	 7, // 0xb37
+	 7, // 0xb38
+	 7, // 0xb39
+	 7, // 0xb3a
+	 7, // 0xb3b
+	 7, // 0xb3c
+	 7, // 0xb3d
+	 7, // 0xb3e
+	 7, // 0xb3f
+	 7, // 0xb40
+	 7, // 0xb41
+	 7, // 0xb42
+	 7, // 0xb43
+	 7, // 0xb44
+	 7, // 0xb45
+	 7, // 0xb46
+	 7, // 0xb47
+	 7, // 0xb48
+	 7, // 0xb49
+	 7, // 0xb4a
+	 7, // 0xb4b
+	 7, // 0xb4c
+	 7, // 0xb4d
+	 7, // 0xb4e
+	 7, // 0xb4f
+	 7, // 0xb50
+	 7, // 0xb51
+	 7, // 0xb52
+	 7, // 0xb53
+	 7, // 0xb54
+	 7, // 0xb55
+	 7, // 0xb56
+	 7, // 0xb57
+	 7, // 0xb58
+	 7, // 0xb59
+	 7, // 0xb5a
+	 7, // 0xb5b
+	 7, // 0xb5c
+	 7, // 0xb5d
+	 7, // 0xb5e
+	 7, // 0xb5f
+	 7, // 0xb60
+	 7, // 0xb61
+	 7, // 0xb62
+	 7, // 0xb63
+	 7, // 0xb64
+	 7, // 0xb65
+	 7, // 0xb66
+	 7, // 0xb67
+	 7, // 0xb68
+	 7, // 0xb69
+	 7, // 0xb6a
+	 7, // 0xb6b
+	 7, // 0xb6c
+	 7, // 0xb6d
+	 7, // 0xb6e
+	 7, // 0xb6f
+	 7, // 0xb70
+	 7, // 0xb71
+	 7, // 0xb72
+	 7, // 0xb73
+	 7, // 0xb74
+	 7, // 0xb75
+	 7, // 0xb76
+	 7, // 0xb77
+	 7, // 0xb78
+	 7, // 0xb79
+	 7, // 0xb7a
+	 7, // 0xb7b
+	 7, // 0xb7c
+	 7, // 0xb7d
+	 7, // 0xb7e
+	 7, // 0xb7f
+	 7, // 0xb80
+	 7, // 0xb81
+	 7, // 0xb82
+	 7, // 0xb83
+	 7, // 0xb84
+	 7, // 0xb85
+	 7, // 0xb86
+	 7, // 0xb87
+	 7, // 0xb88
+	 7, // 0xb89
+	 7, // 0xb8a
+	 7, // 0xb8b
+	 7, // 0xb8c
+	 7, // 0xb8d
+	 7, // 0xb8e
+	 7, // 0xb8f
+	 7, // 0xb90
+	 7, // 0xb91
+	 7, // 0xb92
+	 7, // 0xb93
+	 7, // 0xb94
+	 7, // 0xb95
+	 7, // 0xb96
+	 7, // 0xb97
+	 7, // 0xb98
+	 7, // 0xb99
+	 7, // 0xb9a
+	 7, // 0xb9b
+	 7, // 0xb9c
+	 7, // 0xb9d
+	 7, // 0xb9e
+	 7, // 0xb9f
+	 7, // 0xba0
+	 7, // 0xba1
+	 7, // 0xba2
+	 7, // 0xba3
+	 7, // 0xba4
+	 7, // 0xba5
+	 7, // 0xba6
+	 7, // 0xba7
+	 7, // 0xba8
+	 7, // 0xba9
+	 7, // 0xbaa
+	 7, // 0xbab
+	 7, // 0xbac
+	 7, // 0xbad
+	 7, // 0xbae
+	 7, // 0xbaf
+	 7, // 0xbb0
+	 7, // 0xbb1
+	 7, // 0xbb2
+	 7, // 0xbb3
+	 7, // 0xbb4
+	 7, // 0xbb5
+	 7, // 0xbb6
+	 7, // 0xbb7
+	 7, // 0xbb8
+	 7, // 0xbb9
+	 7, // 0xbba
+	 7, // 0xbbb
+	 7, // 0xbbc
+	 7, // 0xbbd
+	 7, // 0xbbe
+	 7, // 0xbbf
+	 7, // 0xbc0
+	 7, // 0xbc1
+	 7, // 0xbc2
+	 7, // 0xbc3
+	 7, // 0xbc4
+	 7, // 0xbc5
+	 7, // 0xbc6
+	 7, // 0xbc7
+	 7, // 0xbc8
+	 7, // 0xbc9
+	 7, // 0xbca
+	 7, // 0xbcb
+	 7, // 0xbcc
+	 7, // 0xbcd
+	 7, // 0xbce
+	 7, // 0xbcf
+	 7, // 0xbd0
+	 7, // 0xbd1
+	 7, // 0xbd2
+	 7, // 0xbd3
+	 7, // 0xbd4
+	 7, // 0xbd5
+	 7, // 0xbd6
+	 7, // 0xbd7
+	 7, // 0xbd8
+	 7, // 0xbd9
+	 7, // 0xbda
+	 7, // 0xbdb
+	 7, // 0xbdc
+	 7, // 0xbdd
+	 7, // 0xbde
+	 7, // 0xbdf
+	 7, // 0xbe0
+	 7, // 0xbe1
+	 7, // 0xbe2
+	 7, // 0xbe3
+	 7, // 0xbe4
+	 7, // 0xbe5
+	 7, // 0xbe6
+	 7, // 0xbe7
+	 7, // 0xbe8
+	 7, // 0xbe9
+	 7, // 0xbea
+	 7, // 0xbeb
+	 7, // 0xbec
+	 7, // 0xbed
+	 7, // 0xbee
+	 7, // 0xbef
+	 7, // 0xbf0
+	 7, // 0xbf1
+	 7, // 0xbf2
+	 7, // 0xbf3
+	 7, // 0xbf4
+	 7, // 0xbf5
+	 7, // 0xbf6
+	 7, // 0xbf7
+	 7, // 0xbf8
+	 7, // 0xbf9
+	 7, // 0xbfa
+	 7, // 0xbfb
+	 7, // 0xbfc
+	 7, // 0xbfd
+	 7, // 0xbfe
+	 7, // 0xbff
+	 7, // 0xc00
+	 7, // 0xc01
+	 7, // 0xc02
+	 7, // 0xc03
+	 7, // 0xc04
+	 7, // 0xc05
+	 7, // 0xc06
+	 7, // 0xc07
+	 7, // 0xc08
+	 7, // 0xc09
+	 7, // 0xc0a
+	 7, // 0xc0b
+	 7, // 0xc0c
+	 7, // 0xc0d
+	 7, // 0xc0e
+	 7, // 0xc0f
+	 7, // 0xc10
+	 7, // 0xc11
+	 7, // 0xc12
+	 7, // 0xc13
+	 7, // 0xc14
+	 7, // 0xc15
+	 7, // 0xc16
+	 7, // 0xc17
+	 7, // 0xc18
+	 7, // 0xc19
+	 7, // 0xc1a
+	 7, // 0xc1b
+	 7, // 0xc1c
+	 7, // 0xc1d
+	 7, // 0xc1e
+	 7, // 0xc1f
+	 7, // 0xc20
+	 7, // 0xc21
+	 7, // 0xc22
+	 7, // 0xc23
+	 7, // 0xc24
+	 7, // 0xc25
+	 7, // 0xc26
+	 7, // 0xc27
+	 7, // 0xc28
+	 7, // 0xc29
+	 7, // 0xc2a
+	 7, // 0xc2b
+	 7, // 0xc2c
+	 7, // 0xc2d
+	 7, // 0xc2e
+	 7, // 0xc2f
+	 7, // 0xc30
+	 7, // 0xc31
+	 7, // 0xc32
+	 7, // 0xc33
+	 7, // 0xc34
+	 7, // 0xc35
+	 7, // 0xc36
+	 7, // 0xc37
+	 7, // 0xc38
+	 7, // 0xc39
+	 7, // 0xc3a
+	 7, // 0xc3b
+	 7, // 0xc3c
+	 7, // 0xc3d
+	 7, // 0xc3e
+	 7, // 0xc3f
+	 7, // 0xc40
+	 7, // 0xc41
+	 7, // 0xc42
+	 7, // 0xc43
+	 7, // 0xc44
+	 7, // 0xc45
+	 7, // 0xc46
+	 7, // 0xc47
+	 7, // 0xc48
+	 7, // 0xc49
+	 7, // 0xc4a
+	 7, // 0xc4b
+	 7, // 0xc4c
+	 7, // 0xc4d
+	 7, // 0xc4e
+	 7, // 0xc4f
+	 7, // 0xc50
+	 7, // 0xc51
+	 7, // 0xc52
+	 7, // 0xc53
+	 7, // 0xc54
+	 7, // 0xc55
+	 7, // 0xc56
+	 7, // 0xc57
+	 7, // 0xc58
+	 7, // 0xc59
+	 7, // 0xc5a
+	 7, // 0xc5b
+	 7, // 0xc5c
+	 7, // 0xc5d
+	 7, // 0xc5e
+	 7, // 0xc5f
+	 7, // 0xc60
+	 7, // 0xc61
+	 7, // 0xc62
+	 7, // 0xc63
+	 7, // 0xc64
+	 7, // 0xc65
+	 7, // 0xc66
+	 7, // 0xc67
+	 7, // 0xc68
+	 7, // 0xc69
+	 7, // 0xc6a
+	 7, // 0xc6b
+	 7, // 0xc6c
+	 7, // 0xc6d
+	 7, // 0xc6e
+	 7, // 0xc6f
+	 7, // 0xc70
+	 7, // 0xc71
+	 7, // 0xc72
+	 7, // 0xc73
+	 7, // 0xc74
+	 7, // 0xc75
+	 7, // 0xc76
+	 7, // 0xc77
+	 7, // 0xc78
+	 7, // 0xc79
+	 7, // 0xc7a
+	 7, // 0xc7b
+	 7, // 0xc7c
+	 7, // 0xc7d
+	 7, // 0xc7e
+	 7, // 0xc7f
+	 7, // 0xc80
+	 7, // 0xc81
+	 7, // 0xc82
+	 7, // 0xc83
+	 7, // 0xc84
+	 7, // 0xc85
+	 7, // 0xc86
+	 7, // 0xc87
+	 7, // 0xc88
+	 7, // 0xc89
+	 7, // 0xc8a
+	 7, // 0xc8b
+	 7, // 0xc8c
+	 7, // 0xc8d
+	 7, // 0xc8e
+	 7, // 0xc8f
+	 7, // 0xc90
+	 7, // 0xc91
+	 7, // 0xc92
+	 7, // 0xc93
+	 7, // 0xc94
+	 7, // 0xc95
+	 7, // 0xc96
+	 7, // 0xc97
+	 7, // 0xc98
+	 7, // 0xc99
+	 7, // 0xc9a
+	 7, // 0xc9b
+	 7, // 0xc9c
+	 7, // 0xc9d
+	 7, // 0xc9e
+	 7, // 0xc9f
+	 7, // 0xca0
+	 7, // 0xca1
+	 7, // 0xca2
+	 7, // 0xca3
+	 7, // 0xca4
+	 7, // 0xca5
+	 7, // 0xca6
+	 7, // 0xca7
+	 7, // 0xca8
+	 7, // 0xca9
+	 7, // 0xcaa
+	 7, // 0xcab
+	 7, // 0xcac
+	 7, // 0xcad
+	 7, // 0xcae
+	 7, // 0xcaf
+	 7, // 0xcb0
+	 7, // 0xcb1
+	 7, // 0xcb2
+	 7, // 0xcb3
+	 7, // 0xcb4
+	 7, // 0xcb5
+	 7, // 0xcb6
+	 7, // 0xcb7
+	 7, // 0xcb8
+	 7, // 0xcb9
+	 7, // 0xcba
+	 7, // 0xcbb
+	 7, // 0xcbc
+	 7, // 0xcbd
+	 7, // 0xcbe
+	 7, // 0xcbf
+	 7, // 0xcc0
+	 7, // 0xcc1
+	 7, // 0xcc2
+	 7, // 0xcc3
+	 7, // 0xcc4
+	 7, // 0xcc5
+	 7, // 0xcc6
+	 7, // 0xcc7
+	 7, // 0xcc8
+	 7, // 0xcc9
+	 7, // 0xcca
+	 7, // 0xccb
+	 7, // 0xccc
+	 7, // 0xccd
+	 7, // 0xcce
+	 7, // 0xccf
+	 7, // 0xcd0
+	 7, // 0xcd1
+	 7, // 0xcd2
+	 7, // 0xcd3
+	 7, // 0xcd4
+	 7, // 0xcd5
+	 7, // 0xcd6
+	 7, // 0xcd7
+	 7, // 0xcd8
+	 7, // 0xcd9
+	 7, // 0xcda
+	 7, // 0xcdb
+	 7, // 0xcdc
+	 7, // 0xcdd
+	 7, // 0xcde
+	 7, // 0xcdf
+	 7, // 0xce0
+	 7, // 0xce1
+	 7, // 0xce2
+	 7, // 0xce3
+	 7, // 0xce4
+	 7, // 0xce5
+	 7, // 0xce6
+	 7, // 0xce7
+	 7, // 0xce8
+	 7, // 0xce9
+	 7, // 0xcea
+	 7, // 0xceb
+	 7, // 0xcec
+	 7, // 0xced
+	 7, // 0xcee
+	 7, // 0xcef
+	 7, // 0xcf0
+	 7, // 0xcf1
+	 7, // 0xcf2
+	 7, // 0xcf3
+	 7, // 0xcf4
+	 7, // 0xcf5
+	 7, // 0xcf6
+	 7, // 0xcf7
+	 7, // 0xcf8
+	 7, // 0xcf9
+	 7, // 0xcfa
+	 7, // 0xcfb
+	 7, // 0xcfc
+	 7, // 0xcfd
+	 7, // 0xcfe
+	 7, // 0xcff
+	 7, // 0xd00
+	 7, // 0xd01
+	 7, // 0xd02
+	 7, // 0xd03
+	 7, // 0xd04
+	 7, // 0xd05
+	 7, // 0xd06
+	 7, // 0xd07
+	 7, // 0xd08
+	 7, // 0xd09
+	 7, // 0xd0a
+	 7, // 0xd0b
+	 7, // 0xd0c
+	 7, // 0xd0d
+	 7, // 0xd0e
+	 7, // 0xd0f
+	 7, // 0xd10
+	 7, // 0xd11
+	 7, // 0xd12
+	 7, // 0xd13
+	 7, // 0xd14
+	 7, // 0xd15
+	 7, // 0xd16
+	 7, // 0xd17
+	 7, // 0xd18
+	 7, // 0xd19
+	 7, // 0xd1a
+	 7, // 0xd1b
+	 7, // 0xd1c
+	 7, // 0xd1d
+	 7, // 0xd1e
+	 7, // 0xd1f
+	 7, // 0xd20
+	 7, // 0xd21
+	 7, // 0xd22
+	 7, // 0xd23
+	 7, // 0xd24
+	 7, // 0xd25
+	 7, // 0xd26
+	 7, // 0xd27
+	 7, // 0xd28
+	 7, // 0xd29
+	 7, // 0xd2a
+	 7, // 0xd2b
+	 7, // 0xd2c
+	 7, // 0xd2d
+	 7, // 0xd2e
+	 7, // 0xd2f
+	 7, // 0xd30
+	 7, // 0xd31
+	 7, // 0xd32
+	 7, // 0xd33
+	 7, // 0xd34
+	 7, // 0xd35
+	 7, // 0xd36
+	 7, // 0xd37
+	 7, // 0xd38
+	 7, // 0xd39
+	 7, // 0xd3a
+	 7, // 0xd3b
+	 7, // 0xd3c
+	 7, // 0xd3d
+	 7, // 0xd3e
+	 7, // 0xd3f
+	 7, // 0xd40
+	 7, // 0xd41
+	 7, // 0xd42
+	 7, // 0xd43
+	 7, // 0xd44
+	 7, // 0xd45
+	 7, // 0xd46
+	 7, // 0xd47
+	 7, // 0xd48
+	 7, // 0xd49
+	 7, // 0xd4a
+	 7, // 0xd4b
+	 7, // 0xd4c
+	 7, // 0xd4d
+	 7, // 0xd4e
+	 7, // 0xd4f
+	 7, // 0xd50
+	 7, // 0xd51
+	 7, // 0xd52
+	 7, // 0xd53
+	 7, // 0xd54
+	 7, // 0xd55
+	 7, // 0xd56
+	 7, // 0xd57
+	 7, // 0xd58
+	 7, // 0xd59
+	 7, // 0xd5a
+	 7, // 0xd5b
+	 7, // 0xd5c
+	 7, // 0xd5d
+	 7, // 0xd5e
+	 7, // 0xd5f
+	 7, // 0xd60
+	 7, // 0xd61
+	 7, // 0xd62
+	 7, // 0xd63
+	 7, // 0xd64
+	 7, // 0xd65
+	 7, // 0xd66
+	 7, // 0xd67
+	 7, // 0xd68
+	 7, // 0xd69
+	 7, // 0xd6a
+	 7, // 0xd6b
+	 7, // 0xd6c
+	 7, // 0xd6d
+	 7, // 0xd6e
+	 7, // 0xd6f
+	 7, // 0xd70
+	 7, // 0xd71
+	 7, // 0xd72
+	 7, // 0xd73
+	 7, // 0xd74
+	 7, // 0xd75
+	 7, // 0xd76
+	 7, // 0xd77
+	 7, // 0xd78
+	 7, // 0xd79
+	 7, // 0xd7a
+	 7, // 0xd7b
+	 7, // 0xd7c
+	 7, // 0xd7d
+	 7, // 0xd7e
+	 7, // 0xd7f
+	 7, // 0xd80
+	 7, // 0xd81
+	 7, // 0xd82
+	 7, // 0xd83
+	 7, // 0xd84
+	 7, // 0xd85
+	 7, // 0xd86
+	 7, // 0xd87
+	 7, // 0xd88
+	 7, // 0xd89
+	 7, // 0xd8a
+	 7, // 0xd8b
+	 7, // 0xd8c
+	 7, // 0xd8d
+	 7, // 0xd8e
+	 7, // 0xd8f
+	 7, // 0xd90
+	 7, // 0xd91
+	 7, // 0xd92
+	 7, // 0xd93
+	 7, // 0xd94
+	 7, // 0xd95
+	 7, // 0xd96
+	 7, // 0xd97
+	 7, // 0xd98
+	 7, // 0xd99
+	 7, // 0xd9a
+	 7, // 0xd9b
+	 7, // 0xd9c
+	 7, // 0xd9d
+	 7, // 0xd9e
+	 7, // 0xd9f
+	 7, // 0xda0
+	 7, // 0xda1
+	 7, // 0xda2
+	 7, // 0xda3
+	 7, // 0xda4
+	 7, // 0xda5
+	 7, // 0xda6
+	 7, // 0xda7
+	 7, // 0xda8
+	 7, // 0xda9
+	 7, // 0xdaa
+	 7, // 0xdab
+	 7, // 0xdac
+	 7, // 0xdad
+	 7, // 0xdae
+	 7, // 0xdaf
+	 7, // 0xdb0
+	 7, // 0xdb1
+	 7, // 0xdb2
+	 7, // 0xdb3
+	 7, // 0xdb4
+	 7, // 0xdb5
+	 7, // 0xdb6
+	 7, // 0xdb7
+	 7, // 0xdb8
+	 7, // 0xdb9
+	 7, // 0xdba
+	 7, // 0xdbb
+	 7, // 0xdbc
+	 7, // 0xdbd
+	 7, // 0xdbe
+	 7, // 0xdbf
+	 7, // 0xdc0
+	 7, // 0xdc1
+	 7, // 0xdc2
+	 7, // 0xdc3
+	 7, // 0xdc4
+	 7, // 0xdc5
+	 7, // 0xdc6
+	 7, // 0xdc7
+	 7, // 0xdc8
+	 7, // 0xdc9
+	 7, // 0xdca
+	 7, // 0xdcb
+	 7, // 0xdcc
+	 7, // 0xdcd
+	 7, // 0xdce
+	 7, // 0xdcf
+	 7, // 0xdd0
+	 7, // 0xdd1
+	 7, // 0xdd2
+	 7, // 0xdd3
+	 7, // 0xdd4
+	 7, // 0xdd5
+	 7, // 0xdd6
+	 7, // 0xdd7
+	 7, // 0xdd8
+	 7, // 0xdd9
+	 7, // 0xdda
+	 7, // 0xddb
+	 7, // 0xddc
+	 7, // 0xddd
+	 7, // 0xdde
+	 7, // 0xddf
+	 7, // 0xde0
+	 7, // 0xde1
+	 7, // 0xde2
+	 7, // 0xde3
+	 7, // 0xde4
+	 7, // 0xde5
+	 7, // 0xde6
+	 7, // 0xde7
+	 7, // 0xde8
+	 7, // 0xde9
+	 7, // 0xdea
+	 7, // 0xdeb
+	 7, // 0xdec
+	 7, // 0xded
+	 7, // 0xdee
+	 7, // 0xdef
+	 7, // 0xdf0
+	 7, // 0xdf1
+	 7, // 0xdf2
+	 7, // 0xdf3
+	 7, // 0xdf4
+	 7, // 0xdf5
+	 7, // 0xdf6
+	 7, // 0xdf7
+	 7, // 0xdf8
+	 7, // 0xdf9
+	 7, // 0xdfa
+	 7, // 0xdfb
+	 7, // 0xdfc
+	 7, // 0xdfd
+	 7, // 0xdfe
+	 7, // 0xdff
+	 7, // 0xe00
+	 7, // 0xe01
+	 7, // 0xe02
+	 7, // 0xe03
+	 7, // 0xe04
+	 7, // 0xe05
+	 7, // 0xe06
+	 7, // 0xe07
+	 7, // 0xe08
+	 7, // 0xe09
+	 7, // 0xe0a
+	 7, // 0xe0b
+	 7, // 0xe0c
+	 7, // 0xe0d
+	 7, // 0xe0e
+	 7, // 0xe0f
+	 7, // 0xe10
+	 7, // 0xe11
+	 7, // 0xe12
+	 7, // 0xe13
+	 7, // 0xe14
+	 7, // 0xe15
+	 7, // 0xe16
+	 7, // 0xe17
+	 7, // 0xe18
+	 7, // 0xe19
+	 7, // 0xe1a
+	 7, // 0xe1b
+	 7, // 0xe1c
+	 7, // 0xe1d
+	 7, // 0xe1e
+	 7, // 0xe1f
+	 7, // 0xe20
+	 7, // 0xe21
+	 7, // 0xe22
+	 7, // 0xe23
+	 7, // 0xe24
+	 7, // 0xe25
+	 7, // 0xe26
+	 7, // 0xe27
+	 7, // 0xe28
+	 7, // 0xe29
+	 7, // 0xe2a
+	 7, // 0xe2b
+	 7, // 0xe2c
+	 7, // 0xe2d
+	 7, // 0xe2e
+	 7, // 0xe2f
+	 7, // 0xe30
+	 7, // 0xe31
+	 7, // 0xe32
+	 7, // 0xe33
+	 7, // 0xe34
+	 7, // 0xe35
+	 7, // 0xe36
+	 7, // 0xe37
+	 7, // 0xe38
+	 7, // 0xe39
+	 7, // 0xe3a
+	 7, // 0xe3b
+	 7, // 0xe3c
+	 7, // 0xe3d
+	 7, // 0xe3e
+	 7, // 0xe3f
+	 7, // 0xe40
+	 7, // 0xe41
+	 7, // 0xe42
+	 7, // 0xe43
+	 7, // 0xe44
+	 7, // 0xe45
+	 7, // 0xe46
+	 7, // 0xe47
+	 7, // 0xe48
+	 7, // 0xe49
+	 7, // 0xe4a
+	 7, // 0xe4b
+	 7, // 0xe4c
+	 7, // 0xe4d
+	 7, // 0xe4e
+	 7, // 0xe4f
+	 7, // 0xe50
+	 7, // 0xe51
+	 7, // 0xe52
+	 7, // 0xe53
+	 7, // 0xe54
+	 7, // 0xe55
+	 7, // 0xe56
+	 7, // 0xe57
+	 7, // 0xe58
+	 7, // 0xe59
+	 7, // 0xe5a
+	 7, // 0xe5b
+	 7, // 0xe5c
+	 7, // 0xe5d
+	 7, // 0xe5e
+	 7, // 0xe5f
+	 7, // 0xe60
+	 7, // 0xe61
+	 7, // 0xe62
+	 7, // 0xe63
+	 7, // 0xe64
+	 7, // 0xe65
+	 7, // 0xe66
+	 7, // 0xe67
+	 7, // 0xe68
+	 7, // 0xe69
+	 7, // 0xe6a
+	 7, // 0xe6b
+	 7, // 0xe6c
+	 7, // 0xe6d
+	 7, // 0xe6e
+	 7, // 0xe6f
+	 7, // 0xe70
+	 7, // 0xe71
+	 7, // 0xe72
+	 7, // 0xe73
+	 7, // 0xe74
+	 7, // 0xe75
+	 7, // 0xe76
+	 7, // 0xe77
+	 7, // 0xe78
+	 7, // 0xe79
+	 7, // 0xe7a
+	 7, // 0xe7b
+	 7, // 0xe7c
+	 7, // 0xe7d
+	 7, // 0xe7e
+	 7, // 0xe7f
+	 7, // 0xe80
+	 7, // 0xe81
+	 7, // 0xe82
+	 7, // 0xe83
+	 7, // 0xe84
+	 7, // 0xe85
+	 7, // 0xe86
+	 7, // 0xe87
+	 7, // 0xe88
+	 7, // 0xe89
+	 7, // 0xe8a
+	 7, // 0xe8b
+	 7, // 0xe8c
+	 7, // 0xe8d
+	 7, // 0xe8e
+	 7, // 0xe8f
+	 7, // 0xe90
+	 7, // 0xe91
+	 7, // 0xe92
+	 7, // 0xe93
+	 7, // 0xe94
+	 7, // 0xe95
+	 7, // 0xe96
+	 7, // 0xe97
+	 7, // 0xe98
+	 7, // 0xe99
+	 7, // 0xe9a
+	 7, // 0xe9b
+	 7, // 0xe9c
+	 7, // 0xe9d
+	 7, // 0xe9e
+	 7, // 0xe9f
+	 7, // 0xea0
+	 7, // 0xea1
+	 7, // 0xea2
+	 7, // 0xea3
+	 7, // 0xea4
+	 7, // 0xea5
+	 7, // 0xea6
+	 7, // 0xea7
+	 7, // 0xea8
+	 7, // 0xea9
+	 7, // 0xeaa
+	 7, // 0xeab
+	 7, // 0xeac
+	 7, // 0xead
+	 7, // 0xeae
+	 7, // 0xeaf
+	 7, // 0xeb0
+	 7, // 0xeb1
+	 7, // 0xeb2
+	 7, // 0xeb3
+	 7, // 0xeb4
+	 7, // 0xeb5
+	 7, // 0xeb6
+	 7, // 0xeb7
+	 7, // 0xeb8
+	 7, // 0xeb9
+	 7, // 0xeba
+	 7, // 0xebb
+	 7, // 0xebc
+	 7, // 0xebd
+	 7, // 0xebe
+	 7, // 0xebf
+	 7, // 0xec0
+	 7, // 0xec1
+	 7, // 0xec2
+	 7, // 0xec3
+	 7, // 0xec4
+	 7, // 0xec5
+	 7, // 0xec6
+	 7, // 0xec7
+	 7, // 0xec8
+	 7, // 0xec9
+	 7, // 0xeca
+	 7, // 0xecb
+	 7, // 0xecc
+	 7, // 0xecd
+	 7, // 0xece
+	 7, // 0xecf
+	 7, // 0xed0
+	 7, // 0xed1
+	 7, // 0xed2
+	 7, // 0xed3
+	 7, // 0xed4
+	 7, // 0xed5
+	 7, // 0xed6
+	 7, // 0xed7
+	 7, // 0xed8
+	 7, // 0xed9
+	 7, // 0xeda
+	 7, // 0xedb
+	 7, // 0xedc
+	 7, // 0xedd
+	 7, // 0xede
+	 7, // 0xedf
+	 7, // 0xee0
+	 7, // 0xee1
+	 7, // 0xee2
+	 7, // 0xee3
+	 7, // 0xee4
+	 7, // 0xee5
+	 7, // 0xee6
+	 7, // 0xee7
+	 7, // 0xee8
+	 7, // 0xee9
+	 7, // 0xeea
+	 7, // 0xeeb
+	 7, // 0xeec
+	 7, // 0xeed
+	 7, // 0xeee
+	 7, // 0xeef
+	 7, // 0xef0
+	 7, // 0xef1
+	 7, // 0xef2
+	 7, // 0xef3
+	 7, // 0xef4
+	 7, // 0xef5
+	 7, // 0xef6
+	 7, // 0xef7
+	 7, // 0xef8
+	 7, // 0xef9
+	 7, // 0xefa
+	 7, // 0xefb
+	 7, // 0xefc
+	 7, // 0xefd
+	 7, // 0xefe
+	 7, // 0xeff
+	 7, // 0xf00
+	 7, // 0xf01
+	 7, // 0xf02
+	 7, // 0xf03
+	 7, // 0xf04
+	 7, // 0xf05
+	 7, // 0xf06
+	 7, // 0xf07
+	 7, // 0xf08
+	 7, // 0xf09
+	 7, // 0xf0a
+	 7, // 0xf0b
+	 7, // 0xf0c
+	 7, // 0xf0d
+	 7, // 0xf0e
+	 7, // 0xf0f
+	 7, // 0xf10
+	 7, // 0xf11
+	 7, // 0xf12
+	 7, // 0xf13
+	 7, // 0xf14
+	 7, // 0xf15
+	 7, // 0xf16
+	 7, // 0xf17
+	 7, // 0xf18
+	 7, // 0xf19
+	 7, // 0xf1a
+	 7, // 0xf1b
+	 7, // 0xf1c
+	 7, // 0xf1d
+	 7, // 0xf1e
+	 7, // 0xf1f
+	 7, // 0xf20
+	 7, // 0xf21
+	 7, // 0xf22
+	 7, // 0xf23
+	 7, // 0xf24
+	 7, // 0xf25
+	 7, // 0xf26
+	 7, // 0xf27
+	 7, // 0xf28
+	 7, // 0xf29
+	 7, // 0xf2a
+	 7, // 0xf2b
+	 7, // 0xf2c
+	 7, // 0xf2d
+	 7, // 0xf2e
+	 7, // 0xf2f
+	 7, // 0xf30
+	 7, // 0xf31
+	 7, // 0xf32
+	 7, // 0xf33
+	 7, // 0xf34
+	 7, // 0xf35
+	 7, // 0xf36
+	 7, // 0xf37
+	 7, // 0xf38
+	 7, // 0xf39
+	 7, // 0xf3a
+	 7, // 0xf3b
+	 7, // 0xf3c
+	 7, // 0xf3d
+	 7, // 0xf3e
+	 7, // 0xf3f
+	 7, // 0xf40
+	 7, // 0xf41
+	 7, // 0xf42
+	 7, // 0xf43
+	 7, // 0xf44
+	 7, // 0xf45
+	 7, // 0xf46
+	 7, // 0xf47
+	 7, // 0xf48
+	 7, // 0xf49
+	 7, // 0xf4a
+	 7, // 0xf4b
+	 7, // 0xf4c
+	 7, // 0xf4d
+	 7, // 0xf4e
+	 7, // 0xf4f
+	 7, // 0xf50
+	 7, // 0xf51
+	 7, // 0xf52
+	 7, // 0xf53
+	 7, // 0xf54
+	 7, // 0xf55
+	 7, // 0xf56
+	 7, // 0xf57
+	 7, // 0xf58
+	 7, // 0xf59
+	 7, // 0xf5a
+	 7, // 0xf5b
+	 7, // 0xf5c
+	 7, // 0xf5d
+	 7, // 0xf5e
+	 7, // 0xf5f
+	 7, // 0xf60
+	 7, // 0xf61
+	 7, // 0xf62
+	 7, // 0xf63
+	 7, // 0xf64
+	 7, // 0xf65
+	 7, // 0xf66
+	 7, // 0xf67
+	 7, // 0xf68
+	 7, // 0xf69
+	 7, // 0xf6a
+	 7, // 0xf6b
+	 7, // 0xf6c
+	 7, // 0xf6d
+	 7, // 0xf6e
+	 7, // 0xf6f
+	 7, // 0xf70
+	 7, // 0xf71
+	 7, // 0xf72
+	 7, // 0xf73
+	 7, // 0xf74
+	 7, // 0xf75
+	 7, // 0xf76
+	 7, // 0xf77
+	 7, // 0xf78
+	 7, // 0xf79
+	 7, // 0xf7a
+	 7, // 0xf7b
+	 7, // 0xf7c
+	 7, // 0xf7d
+	 7, // 0xf7e
+	 7, // 0xf7f
+	 7, // 0xf80
+	 7, // 0xf81
+	 7, // 0xf82
+	 7, // 0xf83
+	 7, // 0xf84
+	 7, // 0xf85
+	 7, // 0xf86
+	 7, // 0xf87
+	 7, // 0xf88
+	 7, // 0xf89
+	 7, // 0xf8a
+	 7, // 0xf8b
+	 7, // 0xf8c
+	 7, // 0xf8d
+	 7, // 0xf8e
+	 7, // 0xf8f
+	 7, // 0xf90
+	 7, // 0xf91
+	 7, // 0xf92
+	 7, // 0xf93
+	 7, // 0xf94
+	 7, // 0xf95
+	 7, // 0xf96
+	 7, // 0xf97
+	 7, // 0xf98
+	 7, // 0xf99
+	 7, // 0xf9a
+	 7, // 0xf9b
+	 7, // 0xf9c
+	 7, // 0xf9d
+	 7, // 0xf9e
+	 7, // 0xf9f
+	 7, // 0xfa0
+	 7, // 0xfa1
+	 7, // 0xfa2
+	 7, // 0xfa3
+	 7, // 0xfa4
+	 7, // 0xfa5
+	 7, // 0xfa6
+	 7, // 0xfa7
+	 7, // 0xfa8
+	 7, // 0xfa9
+	 7, // 0xfaa
+	 7, // 0xfab
+	 7, // 0xfac
+	 7, // 0xfad
+	 7, // 0xfae
+	 7, // 0xfaf
+	 7, // 0xfb0
+	 7, // 0xfb1
+	 7, // 0xfb2
+	 7, // 0xfb3
+	 7, // 0xfb4
+	 7, // 0xfb5
+	 7, // 0xfb6
+	 7, // 0xfb7
+	 7, // 0xfb8
+	 7, // 0xfb9
+	 7, // 0xfba
+	 7, // 0xfbb
+	 7, // 0xfbc
+	 7, // 0xfbd
+	 7, // 0xfbe
+	 7, // 0xfbf
+	 7, // 0xfc0
+	 7, // 0xfc1
+	 7, // 0xfc2
+	 7, // 0xfc3
+	 7, // 0xfc4
+	 7, // 0xfc5
+	 7, // 0xfc6
+	 7, // 0xfc7
+	 7, // 0xfc8
+	 7, // 0xfc9
+	 7, // 0xfca
+	 7, // 0xfcb
+	 7, // 0xfcc
+	 7, // 0xfcd
+	 7, // 0xfce
+	 7, // 0xfcf
+	 7, // 0xfd0
+	 7, // 0xfd1
+	 7, // 0xfd2
+	 7, // 0xfd3
+	 7, // 0xfd4
+	 7, // 0xfd5
+	 7, // 0xfd6
+	 7, // 0xfd7
+	 7, // 0xfd8
+	 7, // 0xfd9
+	 7, // 0xfda
+	 7, // 0xfdb
+	 7, // 0xfdc
+	 7, // 0xfdd
+	 7, // 0xfde
+	 7, // 0xfdf
+	 7, // 0xfe0
+	 7, // 0xfe1
+	 7, // 0xfe2
+	 7, // 0xfe3
+	 7, // 0xfe4
+	 7, // 0xfe5
+	 7, // 0xfe6
+	 7, // 0xfe7
+	 7, // 0xfe8
+	 7, // 0xfe9
+	 7, // 0xfea
+	 7, // 0xfeb
+	 7, // 0xfec
+	 7, // 0xfed
+	 7, // 0xfee
+	 7, // 0xfef
+	 7, // 0xff0
+	 7, // 0xff1
+	 7, // 0xff2
+	 7, // 0xff3
+	 7, // 0xff4
+	 7, // 0xff5
+	 7, // 0xff6
+	 7, // 0xff7
+	 7, // 0xff8
+	 7, // 0xff9
+	 7, // 0xffa
+	 7, // 0xffb
+	 7, // 0xffc
+	 7, // 0xffd
+	 7, // 0xffe
+	 7, // 0xfff
+	 7, // 0x1000
+	 7, // 0x1001
+	 7, // 0x1002
+	 7, // 0x1003
+	 7, // 0x1004
+	 7, // 0x1005
+	 7, // 0x1006
+	 7, // 0x1007
+	 7, // 0x1008
+	 7, // 0x1009
+	 7, // 0x100a
+	 7, // 0x100b
+	 7, // 0x100c
+	 7, // 0x100d
+	 7, // 0x100e
+	 7, // 0x100f
+	 7, // 0x1010
+	 7, // 0x1011
+	 7, // 0x1012
+	 7, // 0x1013
+	 7, // 0x1014
+	 7, // 0x1015
+	 7, // 0x1016
+	 7, // 0x1017
+	 7, // 0x1018
+	 7, // 0x1019
+	 7, // 0x101a
+	 7, // 0x101b
+	 7, // 0x101c
+	 7, // 0x101d
+	 7, // 0x101e
+	 7, // 0x101f
+	 7, // 0x1020
+	 7, // 0x1021
+	 7, // 0x1022
+	 7, // 0x1023
+	 7, // 0x1024
+	 7, // 0x1025
+	 7, // 0x1026
+	 7, // 0x1027
+	 7, // 0x1028
+	 7, // 0x1029
+	 7, // 0x102a
+	 7, // 0x102b
+	 7, // 0x102c
+	 7, // 0x102d
+	 7, // 0x102e
+	 7, // 0x102f
+	 7, // 0x1030
+	 7, // 0x1031
+	 7, // 0x1032
+	 7, // 0x1033
+	 7, // 0x1034
+	 7, // 0x1035
+	 7, // 0x1036
+	 7, // 0x1037
+	 7, // 0x1038
+	 7, // 0x1039
+	 7, // 0x103a
+	 7, // 0x103b
+	 7, // 0x103c
+	 7, // 0x103d
+	 7, // 0x103e
+	 7, // 0x103f
+	 7, // 0x1040
+	 7, // 0x1041
+	 7, // 0x1042
+	 7, // 0x1043
+	 7, // 0x1044
+	 7, // 0x1045
+	 7, // 0x1046
+	 7, // 0x1047
+	 7, // 0x1048
+	 7, // 0x1049
+	 7, // 0x104a
+	 7, // 0x104b
+	 7, // 0x104c
+	 7, // 0x104d
+	 7, // 0x104e
+	 7, // 0x104f
+	 7, // 0x1050
+	 7, // 0x1051
+	 7, // 0x1052
+	 7, // 0x1053
+	 7, // 0x1054
+	 7, // 0x1055
+	 7, // 0x1056
+	 7, // 0x1057
+	 7, // 0x1058
+	 7, // 0x1059
+	 7, // 0x105a
+	 7, // 0x105b
+	 7, // 0x105c
+	 7, // 0x105d
+	 7, // 0x105e
+	 7, // 0x105f
+	 7, // 0x1060
+	 7, // 0x1061
+	 7, // 0x1062
+	 7, // 0x1063
+	 7, // 0x1064
+	 7, // 0x1065
+	 7, // 0x1066
+	 7, // 0x1067
+	 7, // 0x1068
+	 7, // 0x1069
+	 7, // 0x106a
+	 7, // 0x106b
+	 7, // 0x106c
+	 7, // 0x106d
+	 7, // 0x106e
+	 7, // 0x106f
+	 7, // 0x1070
+	 7, // 0x1071
+	 7, // 0x1072
+	 7, // 0x1073
+	 7, // 0x1074
+	 7, // 0x1075
+	 7, // 0x1076
+	 7, // 0x1077
+	 7, // 0x1078
+	 7, // 0x1079
+	 7, // 0x107a
+	 7, // 0x107b
+	 7, // 0x107c
+	 7, // 0x107d
+	 7, // 0x107e
+	 7, // 0x107f
+	 7, // 0x1080
+	 7, // 0x1081
+	 7, // 0x1082
+	 7, // 0x1083
+	 7, // 0x1084
+	 7, // 0x1085
+	 7, // 0x1086
+	 7, // 0x1087
+	 7, // 0x1088
+	 7, // 0x1089
+	 7, // 0x108a
+	 7, // 0x108b
+	 7, // 0x108c
+	 7, // 0x108d
+	 7, // 0x108e
+	 7, // 0x108f
+	 7, // 0x1090
+	 7, // 0x1091
+	 7, // 0x1092
+	 7, // 0x1093
+	 7, // 0x1094
+	 7, // 0x1095
+	 7, // 0x1096
+	 7, // 0x1097
+	 7, // 0x1098
+	 7, // 0x1099
+	 7, // 0x109a
+	 7, // 0x109b
+	 7, // 0x109c
+	 7, // 0x109d
+	 7, // 0x109e
+	 7, // 0x109f
+	 7, // 0x10a0
+	 7, // 0x10a1
+	 7, // 0x10a2
+	 7, // 0x10a3
+	 7, // 0x10a4
+	 7, // 0x10a5
+	 7, // 0x10a6
+	 7, // 0x10a7
+	 7, // 0x10a8
+	 7, // 0x10a9
+	 7, // 0x10aa
+	 7, // 0x10ab
+	 7, // 0x10ac
+	 7, // 0x10ad
+	 7, // 0x10ae
+	 7, // 0x10af
+	 7, // 0x10b0
+	 7, // 0x10b1
+	 7, // 0x10b2
+	 7, // 0x10b3
+	 7, // 0x10b4
+	 7, // 0x10b5
+	 7, // 0x10b6
+	 7, // 0x10b7
+	 7, // 0x10b8
+	 7, // 0x10b9
+	 7, // 0x10ba
+	 7, // 0x10bb
+	 7, // 0x10bc
+	 7, // 0x10bd
+	 7, // 0x10be
+	 7, // 0x10bf
+	 7, // 0x10c0
+	 7, // 0x10c1
+	 7, // 0x10c2
+	 7, // 0x10c3
+	 7, // 0x10c4
+	 7, // 0x10c5
+	 7, // 0x10c6
+	 7, // 0x10c7
+	 7, // 0x10c8
+	 7, // 0x10c9
+	 7, // 0x10ca
+	 7, // 0x10cb
+	 7, // 0x10cc
+	 7, // 0x10cd
+	 7, // 0x10ce
+	 7, // 0x10cf
+	 7, // 0x10d0
+	 7, // 0x10d1
+	 7, // 0x10d2
+	 7, // 0x10d3
+	 7, // 0x10d4
+	 7, // 0x10d5
+	 7, // 0x10d6
+	 7, // 0x10d7
+	 7, // 0x10d8
+	 7, // 0x10d9
+	 7, // 0x10da
+	 7, // 0x10db
+	 7, // 0x10dc
+	 7, // 0x10dd
+	 7, // 0x10de
+	 7, // 0x10df
+	 7, // 0x10e0
+	 7, // 0x10e1
+	 7, // 0x10e2
+	 7, // 0x10e3
+	 7, // 0x10e4
+	 7, // 0x10e5
+	 7, // 0x10e6
+	 7, // 0x10e7
+	 7, // 0x10e8
+	 7, // 0x10e9
+	 7, // 0x10ea
+	 7, // 0x10eb
+	 7, // 0x10ec
+	 7, // 0x10ed
+	 7, // 0x10ee
+	 7, // 0x10ef
+	 7, // 0x10f0
+	 7, // 0x10f1
+	 7, // 0x10f2
+	 7, // 0x10f3
+	 7, // 0x10f4
+	 7, // 0x10f5
+	 7, // 0x10f6
+	 7, // 0x10f7
+	 7, // 0x10f8
+	 7, // 0x10f9
+	 7, // 0x10fa
+	 7, // 0x10fb
+	 7, // 0x10fc
+	 7, // 0x10fd
+	 7, // 0x10fe
+	 7, // 0x10ff
+];
+pub const NORM_PAGES: [[u8;256];68] = [
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x0,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x12,
+        0x12,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x12,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x12,
+        0x12,
+        0x12,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x0,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x0,
+        0x0,
+        0x28,
+        0x0,
+        0x28,
+        0x0,
+        0x28,
+        0x28,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x28,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x28,
+        0x28,
+        0x0,
+        0x28,
+        0x28,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x28,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x17,
+        0x17,
+        0x28,
+        0x17,
+        0x17,
+        0x28,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x17,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x17,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x13,
+        0x3,
+        0x17,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x13,
+        0x13,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x28,
+        0x28,
+        0x28,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x28,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x28,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x28,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x17,
+        0x17,
+        0x0,
+        0x17,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x17,
+        0x0,
+        0x0,
+        0x17,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x17,
+        0x17,
+        0x17,
+        0x0,
+        0x0,
+        0x17,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x28,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x28,
+        0x28,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x17,
+        0x17,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x28,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x28,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x28,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x28,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x28,
+        0x28,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x28,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x28,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x28,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x28,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x28,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x17,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x17,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x17,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x17,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x17,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x17,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x17,
+        0x0,
+        0x17,
+        0x17,
+        0x12,
+        0x17,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x17,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x17,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x17,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x17,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x17,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x17,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x17,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x28,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x28,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x12,
+        0x13,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x17,
+        0x3,
+        0x17,
+        0x3,
+        0x17,
+        0x3,
+        0x17,
+        0x3,
+        0x17,
+        0x3,
+        0x17,
+        0x3,
+        0x17,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x17,
+        0x3,
+        0x12,
+        0x17,
+        0x12,
+        0x12,
+        0x13,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x17,
+        0x3,
+        0x17,
+        0x3,
+        0x13,
+        0x13,
+        0x13,
+        0x3,
+        0x3,
+        0x3,
+        0x17,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x17,
+        0x0,
+        0x13,
+        0x13,
+        0x13,
+        0x3,
+        0x3,
+        0x3,
+        0x17,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x17,
+        0x3,
+        0x13,
+        0x17,
+        0x17,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x17,
+        0x3,
+        0x17,
+        0x3,
+        0x17,
+        0x12,
+        0x0,
+    ],
+    [
+        0x17,
+        0x17,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x0,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x0,
+        0x17,
+        0x0,
+        0x12,
+        0x0,
+        0x17,
+        0x17,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x17,
+        0x17,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x17,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x28,
+        0x28,
+        0x12,
+        0x12,
+        0x0,
+        0x3,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x12,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+    ],
+    [
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+    ],
+    [
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x0,
+        0x0,
+        0x17,
+        0x0,
+        0x17,
+        0x0,
+        0x0,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x0,
+        0x17,
+        0x0,
+        0x17,
+        0x0,
+        0x0,
+        0x17,
+        0x17,
+        0x0,
+        0x0,
+        0x0,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x0,
+        0x0,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x17,
+        0x0,
+        0x17,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x0,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x0,
+        0x17,
+        0x0,
+        0x17,
+        0x17,
+        0x0,
+        0x17,
+        0x17,
+        0x0,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+    ],
+    [
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x28,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x28,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x28,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x28,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x28,
+        0x0,
+        0x0,
+        0x28,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x28,
+        0x0,
+        0x0,
+        0x2b,
+        0x0,
+        0x2b,
+        0x2b,
+        0x28,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x28,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x28,
+        0x3,
+        0x3,
+        0x28,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x28,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x28,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x28,
+        0x28,
+        0x28,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x28,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x28,
+        0x2b,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x12,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+    ],
+    [
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+    ],
+    [
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+    ],
+    [
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x0,
+        0x0,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x0,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x0,
+        0x12,
+        0x0,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x0,
+        0x0,
+        0x12,
+        0x0,
+        0x12,
+        0x0,
+        0x12,
+        0x0,
+        0x12,
+        0x0,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+];
@@ -0,0 +1,14419 @@
+// GENERATED CODE DO NOT MANUALLY EDIT
+pub const SCX_TABLE: [u8;0x1100] = [
+	 0, // 0x0
+	 1, // 0x1
+	 2, // 0x2
+	 3, // 0x3
+	 4, // 0x4
+	 5, // 0x5
+	 6, // 0x6
+	 1, // 0x7
+	 1, // 0x8
+	 7, // 0x9
+	 8, // 0xa
+	 9, // 0xb
+	 10, // 0xc
+	 1, // 0xd
+	 1, // 0xe
+	 1, // 0xf
+	 11, // 0x10
+	 1, // 0x11
+	 1, // 0x12
+	 1, // 0x13
+	 1, // 0x14
+	 1, // 0x15
+	 12, // 0x16
+	 13, // 0x17
+	 14, // 0x18
+	 1, // 0x19
+	 1, // 0x1a
+	 1, // 0x1b
+	 15, // 0x1c
+	 16, // 0x1d
+	 1, // 0x1e
+	 1, // 0x1f
+	 17, // 0x20
+	 1, // 0x21
+	 1, // 0x22
+	 1, // 0x23
+	 1, // 0x24
+	 1, // 0x25
+	 1, // 0x26
+	 1, // 0x27
+	 1, // 0x28
+	 1, // 0x29
+	 1, // 0x2a
+	 1, // 0x2b
+	 1, // 0x2c
+	 1, // 0x2d
+	 18, // 0x2e
+	 19, // 0x2f
+	 20, // 0x30
+	 21, // 0x31
+	 22, // 0x32
+	 23, // 0x33
+	 1, // 0x34
+	 1, // 0x35
+	 1, // 0x36
+	 1, // 0x37
+	 1, // 0x38
+	 1, // 0x39
+	 1, // 0x3a
+	 1, // 0x3b
+	 1, // 0x3c
+	 1, // 0x3d
+	 1, // 0x3e
+	 1, // 0x3f
+	 1, // 0x40
+	 1, // 0x41
+	 1, // 0x42
+	 1, // 0x43
+	 1, // 0x44
+	 1, // 0x45
+	 1, // 0x46
+	 1, // 0x47
+	 1, // 0x48
+	 1, // 0x49
+	 1, // 0x4a
+	 1, // 0x4b
+	 1, // 0x4c
+	 1, // 0x4d
+	 1, // 0x4e
+	 1, // 0x4f
+	 1, // 0x50
+	 1, // 0x51
+	 1, // 0x52
+	 1, // 0x53
+	 1, // 0x54
+	 1, // 0x55
+	 1, // 0x56
+	 1, // 0x57
+	 1, // 0x58
+	 1, // 0x59
+	 1, // 0x5a
+	 1, // 0x5b
+	 1, // 0x5c
+	 1, // 0x5d
+	 1, // 0x5e
+	 1, // 0x5f
+	 1, // 0x60
+	 1, // 0x61
+	 1, // 0x62
+	 1, // 0x63
+	 1, // 0x64
+	 1, // 0x65
+	 1, // 0x66
+	 1, // 0x67
+	 1, // 0x68
+	 1, // 0x69
+	 1, // 0x6a
+	 1, // 0x6b
+	 1, // 0x6c
+	 1, // 0x6d
+	 1, // 0x6e
+	 1, // 0x6f
+	 1, // 0x70
+	 1, // 0x71
+	 1, // 0x72
+	 1, // 0x73
+	 1, // 0x74
+	 1, // 0x75
+	 1, // 0x76
+	 1, // 0x77
+	 1, // 0x78
+	 1, // 0x79
+	 1, // 0x7a
+	 1, // 0x7b
+	 1, // 0x7c
+	 1, // 0x7d
+	 1, // 0x7e
+	 1, // 0x7f
+	 1, // 0x80
+	 1, // 0x81
+	 1, // 0x82
+	 1, // 0x83
+	 1, // 0x84
+	 1, // 0x85
+	 1, // 0x86
+	 1, // 0x87
+	 1, // 0x88
+	 1, // 0x89
+	 1, // 0x8a
+	 1, // 0x8b
+	 1, // 0x8c
+	 1, // 0x8d
+	 1, // 0x8e
+	 1, // 0x8f
+	 1, // 0x90
+	 1, // 0x91
+	 1, // 0x92
+	 1, // 0x93
+	 1, // 0x94
+	 1, // 0x95
+	 1, // 0x96
+	 1, // 0x97
+	 1, // 0x98
+	 1, // 0x99
+	 1, // 0x9a
+	 1, // 0x9b
+	 1, // 0x9c
+	 1, // 0x9d
+	 1, // 0x9e
+	 1, // 0x9f
+	 1, // 0xa0
+	 1, // 0xa1
+	 1, // 0xa2
+	 1, // 0xa3
+	 1, // 0xa4
+	 1, // 0xa5
+	 24, // 0xa6
+	 25, // 0xa7
+	 26, // 0xa8
+	 27, // 0xa9
+	 1, // 0xaa
+	 1, // 0xab
+	 1, // 0xac
+	 1, // 0xad
+	 1, // 0xae
+	 1, // 0xaf
+	 1, // 0xb0
+	 1, // 0xb1
+	 1, // 0xb2
+	 1, // 0xb3
+	 1, // 0xb4
+	 1, // 0xb5
+	 1, // 0xb6
+	 1, // 0xb7
+	 1, // 0xb8
+	 1, // 0xb9
+	 1, // 0xba
+	 1, // 0xbb
+	 1, // 0xbc
+	 1, // 0xbd
+	 1, // 0xbe
+	 1, // 0xbf
+	 1, // 0xc0
+	 1, // 0xc1
+	 1, // 0xc2
+	 1, // 0xc3
+	 1, // 0xc4
+	 1, // 0xc5
+	 1, // 0xc6
+	 1, // 0xc7
+	 1, // 0xc8
+	 1, // 0xc9
+	 1, // 0xca
+	 1, // 0xcb
+	 1, // 0xcc
+	 1, // 0xcd
+	 1, // 0xce
+	 1, // 0xcf
+	 1, // 0xd0
+	 1, // 0xd1
+	 1, // 0xd2
+	 1, // 0xd3
+	 1, // 0xd4
+	 1, // 0xd5
+	 1, // 0xd6
+	 1, // 0xd7
+	 1, // 0xd8
+	 1, // 0xd9
+	 1, // 0xda
+	 1, // 0xdb
+	 1, // 0xdc
+	 1, // 0xdd
+	 1, // 0xde
+	 1, // 0xdf
+	 1, // 0xe0
+	 1, // 0xe1
+	 1, // 0xe2
+	 1, // 0xe3
+	 1, // 0xe4
+	 1, // 0xe5
+	 1, // 0xe6
+	 1, // 0xe7
+	 1, // 0xe8
+	 1, // 0xe9
+	 1, // 0xea
+	 1, // 0xeb
+	 1, // 0xec
+	 1, // 0xed
+	 1, // 0xee
+	 1, // 0xef
+	 1, // 0xf0
+	 1, // 0xf1
+	 1, // 0xf2
+	 1, // 0xf3
+	 1, // 0xf4
+	 1, // 0xf5
+	 1, // 0xf6
+	 1, // 0xf7
+	 1, // 0xf8
+	 1, // 0xf9
+	 1, // 0xfa
+	 1, // 0xfb
+	 1, // 0xfc
+	 28, // 0xfd
+	 29, // 0xfe
+	 30, // 0xff
+	 1, // 0x100
+	 31, // 0x101
+	 32, // 0x102
+	 1, // 0x103
+	 1, // 0x104
+	 1, // 0x105
+	 1, // 0x106
+	 1, // 0x107
+	 1, // 0x108
+	 1, // 0x109
+	 33, // 0x10a
+	 1, // 0x10b
+	 1, // 0x10c
+	 1, // 0x10d
+	 1, // 0x10e
+	 1, // 0x10f
+	 1, // 0x110
+	 1, // 0x111
+	 1, // 0x112
+	 34, // 0x113
+	 1, // 0x114
+	 1, // 0x115
+	 1, // 0x116
+	 1, // 0x117
+	 1, // 0x118
+	 1, // 0x119
+	 1, // 0x11a
+	 1, // 0x11b
+	 1, // 0x11c
+	 1, // 0x11d
+	 1, // 0x11e
+	 35, // 0x11f
+	 1, // 0x120
+	 1, // 0x121
+	 1, // 0x122
+	 1, // 0x123
+	 1, // 0x124
+	 1, // 0x125
+	 1, // 0x126
+	 1, // 0x127
+	 1, // 0x128
+	 1, // 0x129
+	 1, // 0x12a
+	 1, // 0x12b
+	 1, // 0x12c
+	 1, // 0x12d
+	 1, // 0x12e
+	 1, // 0x12f
+	 1, // 0x130
+	 1, // 0x131
+	 1, // 0x132
+	 1, // 0x133
+	 1, // 0x134
+	 1, // 0x135
+	 1, // 0x136
+	 1, // 0x137
+	 1, // 0x138
+	 1, // 0x139
+	 1, // 0x13a
+	 1, // 0x13b
+	 1, // 0x13c
+	 1, // 0x13d
+	 1, // 0x13e
+	 1, // 0x13f
+	 1, // 0x140
+	 1, // 0x141
+	 1, // 0x142
+	 1, // 0x143
+	 1, // 0x144
+	 1, // 0x145
+	 1, // 0x146
+	 1, // 0x147
+	 1, // 0x148
+	 1, // 0x149
+	 1, // 0x14a
+	 1, // 0x14b
+	 1, // 0x14c
+	 1, // 0x14d
+	 1, // 0x14e
+	 1, // 0x14f
+	 1, // 0x150
+	 1, // 0x151
+	 1, // 0x152
+	 1, // 0x153
+	 1, // 0x154
+	 1, // 0x155
+	 1, // 0x156
+	 1, // 0x157
+	 1, // 0x158
+	 1, // 0x159
+	 1, // 0x15a
+	 1, // 0x15b
+	 1, // 0x15c
+	 1, // 0x15d
+	 1, // 0x15e
+	 1, // 0x15f
+	 1, // 0x160
+	 1, // 0x161
+	 1, // 0x162
+	 1, // 0x163
+	 1, // 0x164
+	 1, // 0x165
+	 1, // 0x166
+	 1, // 0x167
+	 1, // 0x168
+	 1, // 0x169
+	 1, // 0x16a
+	 1, // 0x16b
+	 1, // 0x16c
+	 1, // 0x16d
+	 1, // 0x16e
+	 1, // 0x16f
+	 1, // 0x170
+	 1, // 0x171
+	 1, // 0x172
+	 1, // 0x173
+	 1, // 0x174
+	 1, // 0x175
+	 1, // 0x176
+	 1, // 0x177
+	 1, // 0x178
+	 1, // 0x179
+	 1, // 0x17a
+	 1, // 0x17b
+	 1, // 0x17c
+	 1, // 0x17d
+	 1, // 0x17e
+	 1, // 0x17f
+	 1, // 0x180
+	 1, // 0x181
+	 1, // 0x182
+	 1, // 0x183
+	 1, // 0x184
+	 1, // 0x185
+	 1, // 0x186
+	 1, // 0x187
+	 1, // 0x188
+	 1, // 0x189
+	 1, // 0x18a
+	 1, // 0x18b
+	 1, // 0x18c
+	 1, // 0x18d
+	 1, // 0x18e
+	 1, // 0x18f
+	 1, // 0x190
+	 1, // 0x191
+	 1, // 0x192
+	 1, // 0x193
+	 1, // 0x194
+	 1, // 0x195
+	 1, // 0x196
+	 1, // 0x197
+	 1, // 0x198
+	 1, // 0x199
+	 1, // 0x19a
+	 1, // 0x19b
+	 1, // 0x19c
+	 1, // 0x19d
+	 1, // 0x19e
+	 1, // 0x19f
+	 1, // 0x1a0
+	 1, // 0x1a1
+	 1, // 0x1a2
+	 1, // 0x1a3
+	 1, // 0x1a4
+	 1, // 0x1a5
+	 1, // 0x1a6
+	 1, // 0x1a7
+	 1, // 0x1a8
+	 1, // 0x1a9
+	 1, // 0x1aa
+	 1, // 0x1ab
+	 1, // 0x1ac
+	 1, // 0x1ad
+	 1, // 0x1ae
+	 1, // 0x1af
+	 1, // 0x1b0
+	 1, // 0x1b1
+	 1, // 0x1b2
+	 1, // 0x1b3
+	 1, // 0x1b4
+	 1, // 0x1b5
+	 1, // 0x1b6
+	 1, // 0x1b7
+	 1, // 0x1b8
+	 1, // 0x1b9
+	 1, // 0x1ba
+	 1, // 0x1bb
+	 36, // 0x1bc
+	 1, // 0x1bd
+	 1, // 0x1be
+	 1, // 0x1bf
+	 1, // 0x1c0
+	 1, // 0x1c1
+	 1, // 0x1c2
+	 1, // 0x1c3
+	 1, // 0x1c4
+	 1, // 0x1c5
+	 1, // 0x1c6
+	 1, // 0x1c7
+	 1, // 0x1c8
+	 1, // 0x1c9
+	 1, // 0x1ca
+	 1, // 0x1cb
+	 1, // 0x1cc
+	 1, // 0x1cd
+	 1, // 0x1ce
+	 1, // 0x1cf
+	 1, // 0x1d0
+	 1, // 0x1d1
+	 1, // 0x1d2
+	 37, // 0x1d3
+	 1, // 0x1d4
+	 1, // 0x1d5
+	 1, // 0x1d6
+	 1, // 0x1d7
+	 1, // 0x1d8
+	 1, // 0x1d9
+	 1, // 0x1da
+	 1, // 0x1db
+	 1, // 0x1dc
+	 1, // 0x1dd
+	 1, // 0x1de
+	 1, // 0x1df
+	 1, // 0x1e0
+	 1, // 0x1e1
+	 1, // 0x1e2
+	 1, // 0x1e3
+	 1, // 0x1e4
+	 1, // 0x1e5
+	 1, // 0x1e6
+	 1, // 0x1e7
+	 1, // 0x1e8
+	 1, // 0x1e9
+	 1, // 0x1ea
+	 1, // 0x1eb
+	 1, // 0x1ec
+	 1, // 0x1ed
+	 1, // 0x1ee
+	 1, // 0x1ef
+	 1, // 0x1f0
+	 1, // 0x1f1
+	 38, // 0x1f2
+	 1, // 0x1f3
+	 1, // 0x1f4
+	 1, // 0x1f5
+	 1, // 0x1f6
+	 1, // 0x1f7
+	 1, // 0x1f8
+	 1, // 0x1f9
+	 1, // 0x1fa
+	 1, // 0x1fb
+	 1, // 0x1fc
+	 1, // 0x1fd
+	 1, // 0x1fe
+	 1, // 0x1ff
+	 1, // 0x200
+	 1, // 0x201
+	 1, // 0x202
+	 1, // 0x203
+	 1, // 0x204
+	 1, // 0x205
+	 1, // 0x206
+	 1, // 0x207
+	 1, // 0x208
+	 1, // 0x209
+	 1, // 0x20a
+	 1, // 0x20b
+	 1, // 0x20c
+	 1, // 0x20d
+	 1, // 0x20e
+	 1, // 0x20f
+	 1, // 0x210
+	 1, // 0x211
+	 1, // 0x212
+	 1, // 0x213
+	 1, // 0x214
+	 1, // 0x215
+	 1, // 0x216
+	 1, // 0x217
+	 1, // 0x218
+	 1, // 0x219
+	 1, // 0x21a
+	 1, // 0x21b
+	 1, // 0x21c
+	 1, // 0x21d
+	 1, // 0x21e
+	 1, // 0x21f
+	 1, // 0x220
+	 1, // 0x221
+	 1, // 0x222
+	 1, // 0x223
+	 1, // 0x224
+	 1, // 0x225
+	 1, // 0x226
+	 1, // 0x227
+	 1, // 0x228
+	 1, // 0x229
+	 1, // 0x22a
+	 1, // 0x22b
+	 1, // 0x22c
+	 1, // 0x22d
+	 1, // 0x22e
+	 1, // 0x22f
+	 1, // 0x230
+	 1, // 0x231
+	 1, // 0x232
+	 1, // 0x233
+	 1, // 0x234
+	 1, // 0x235
+	 1, // 0x236
+	 1, // 0x237
+	 1, // 0x238
+	 1, // 0x239
+	 1, // 0x23a
+	 1, // 0x23b
+	 1, // 0x23c
+	 1, // 0x23d
+	 1, // 0x23e
+	 1, // 0x23f
+	 1, // 0x240
+	 1, // 0x241
+	 1, // 0x242
+	 1, // 0x243
+	 1, // 0x244
+	 1, // 0x245
+	 1, // 0x246
+	 1, // 0x247
+	 1, // 0x248
+	 1, // 0x249
+	 1, // 0x24a
+	 1, // 0x24b
+	 1, // 0x24c
+	 1, // 0x24d
+	 1, // 0x24e
+	 1, // 0x24f
+	 1, // 0x250
+	 1, // 0x251
+	 1, // 0x252
+	 1, // 0x253
+	 1, // 0x254
+	 1, // 0x255
+	 1, // 0x256
+	 1, // 0x257
+	 1, // 0x258
+	 1, // 0x259
+	 1, // 0x25a
+	 1, // 0x25b
+	 1, // 0x25c
+	 1, // 0x25d
+	 1, // 0x25e
+	 1, // 0x25f
+	 1, // 0x260
+	 1, // 0x261
+	 1, // 0x262
+	 1, // 0x263
+	 1, // 0x264
+	 1, // 0x265
+	 1, // 0x266
+	 1, // 0x267
+	 1, // 0x268
+	 1, // 0x269
+	 1, // 0x26a
+	 1, // 0x26b
+	 1, // 0x26c
+	 1, // 0x26d
+	 1, // 0x26e
+	 1, // 0x26f
+	 1, // 0x270
+	 1, // 0x271
+	 1, // 0x272
+	 1, // 0x273
+	 1, // 0x274
+	 1, // 0x275
+	 1, // 0x276
+	 1, // 0x277
+	 1, // 0x278
+	 1, // 0x279
+	 1, // 0x27a
+	 1, // 0x27b
+	 1, // 0x27c
+	 1, // 0x27d
+	 1, // 0x27e
+	 1, // 0x27f
+	 1, // 0x280
+	 1, // 0x281
+	 1, // 0x282
+	 1, // 0x283
+	 1, // 0x284
+	 1, // 0x285
+	 1, // 0x286
+	 1, // 0x287
+	 1, // 0x288
+	 1, // 0x289
+	 1, // 0x28a
+	 1, // 0x28b
+	 1, // 0x28c
+	 1, // 0x28d
+	 1, // 0x28e
+	 1, // 0x28f
+	 1, // 0x290
+	 1, // 0x291
+	 1, // 0x292
+	 1, // 0x293
+	 1, // 0x294
+	 1, // 0x295
+	 1, // 0x296
+	 1, // 0x297
+	 1, // 0x298
+	 1, // 0x299
+	 1, // 0x29a
+	 1, // 0x29b
+	 1, // 0x29c
+	 1, // 0x29d
+	 1, // 0x29e
+	 1, // 0x29f
+	 1, // 0x2a0
+	 1, // 0x2a1
+	 1, // 0x2a2
+	 1, // 0x2a3
+	 1, // 0x2a4
+	 1, // 0x2a5
+	 1, // 0x2a6
+	 1, // 0x2a7
+	 1, // 0x2a8
+	 1, // 0x2a9
+	 1, // 0x2aa
+	 1, // 0x2ab
+	 1, // 0x2ac
+	 1, // 0x2ad
+	 1, // 0x2ae
+	 1, // 0x2af
+	 1, // 0x2b0
+	 1, // 0x2b1
+	 1, // 0x2b2
+	 1, // 0x2b3
+	 1, // 0x2b4
+	 1, // 0x2b5
+	 1, // 0x2b6
+	 1, // 0x2b7
+	 1, // 0x2b8
+	 1, // 0x2b9
+	 1, // 0x2ba
+	 1, // 0x2bb
+	 1, // 0x2bc
+	 1, // 0x2bd
+	 1, // 0x2be
+	 1, // 0x2bf
+	 1, // 0x2c0
+	 1, // 0x2c1
+	 1, // 0x2c2
+	 1, // 0x2c3
+	 1, // 0x2c4
+	 1, // 0x2c5
+	 1, // 0x2c6
+	 1, // 0x2c7
+	 1, // 0x2c8
+	 1, // 0x2c9
+	 1, // 0x2ca
+	 1, // 0x2cb
+	 1, // 0x2cc
+	 1, // 0x2cd
+	 1, // 0x2ce
+	 1, // 0x2cf
+	 1, // 0x2d0
+	 1, // 0x2d1
+	 1, // 0x2d2
+	 1, // 0x2d3
+	 1, // 0x2d4
+	 1, // 0x2d5
+	 1, // 0x2d6
+	 1, // 0x2d7
+	 1, // 0x2d8
+	 1, // 0x2d9
+	 1, // 0x2da
+	 1, // 0x2db
+	 1, // 0x2dc
+	 1, // 0x2dd
+	 1, // 0x2de
+	 1, // 0x2df
+	 1, // 0x2e0
+	 1, // 0x2e1
+	 1, // 0x2e2
+	 1, // 0x2e3
+	 1, // 0x2e4
+	 1, // 0x2e5
+	 1, // 0x2e6
+	 1, // 0x2e7
+	 1, // 0x2e8
+	 1, // 0x2e9
+	 1, // 0x2ea
+	 1, // 0x2eb
+	 1, // 0x2ec
+	 1, // 0x2ed
+	 1, // 0x2ee
+	 1, // 0x2ef
+	 1, // 0x2f0
+	 1, // 0x2f1
+	 1, // 0x2f2
+	 1, // 0x2f3
+	 1, // 0x2f4
+	 1, // 0x2f5
+	 1, // 0x2f6
+	 1, // 0x2f7
+	 1, // 0x2f8
+	 1, // 0x2f9
+	 1, // 0x2fa
+	 1, // 0x2fb
+	 1, // 0x2fc
+	 1, // 0x2fd
+	 1, // 0x2fe
+	 1, // 0x2ff
+	 1, // 0x300
+	 1, // 0x301
+	 1, // 0x302
+	 1, // 0x303
+	 1, // 0x304
+	 1, // 0x305
+	 1, // 0x306
+	 1, // 0x307
+	 1, // 0x308
+	 1, // 0x309
+	 1, // 0x30a
+	 1, // 0x30b
+	 1, // 0x30c
+	 1, // 0x30d
+	 1, // 0x30e
+	 1, // 0x30f
+	 1, // 0x310
+	 1, // 0x311
+	 1, // 0x312
+	 1, // 0x313
+	 1, // 0x314
+	 1, // 0x315
+	 1, // 0x316
+	 1, // 0x317
+	 1, // 0x318
+	 1, // 0x319
+	 1, // 0x31a
+	 1, // 0x31b
+	 1, // 0x31c
+	 1, // 0x31d
+	 1, // 0x31e
+	 1, // 0x31f
+	 1, // 0x320
+	 1, // 0x321
+	 1, // 0x322
+	 1, // 0x323
+	 1, // 0x324
+	 1, // 0x325
+	 1, // 0x326
+	 1, // 0x327
+	 1, // 0x328
+	 1, // 0x329
+	 1, // 0x32a
+	 1, // 0x32b
+	 1, // 0x32c
+	 1, // 0x32d
+	 1, // 0x32e
+	 1, // 0x32f
+	 1, // 0x330
+	 1, // 0x331
+	 1, // 0x332
+	 1, // 0x333
+	 1, // 0x334
+	 1, // 0x335
+	 1, // 0x336
+	 1, // 0x337
+	 1, // 0x338
+	 1, // 0x339
+	 1, // 0x33a
+	 1, // 0x33b
+	 1, // 0x33c
+	 1, // 0x33d
+	 1, // 0x33e
+	 1, // 0x33f
+	 1, // 0x340
+	 1, // 0x341
+	 1, // 0x342
+	 1, // 0x343
+	 1, // 0x344
+	 1, // 0x345
+	 1, // 0x346
+	 1, // 0x347
+	 1, // 0x348
+	 1, // 0x349
+	 1, // 0x34a
+	 1, // 0x34b
+	 1, // 0x34c
+	 1, // 0x34d
+	 1, // 0x34e
+	 1, // 0x34f
+	 1, // 0x350
+	 1, // 0x351
+	 1, // 0x352
+	 1, // 0x353
+	 1, // 0x354
+	 1, // 0x355
+	 1, // 0x356
+	 1, // 0x357
+	 1, // 0x358
+	 1, // 0x359
+	 1, // 0x35a
+	 1, // 0x35b
+	 1, // 0x35c
+	 1, // 0x35d
+	 1, // 0x35e
+	 1, // 0x35f
+	 1, // 0x360
+	 1, // 0x361
+	 1, // 0x362
+	 1, // 0x363
+	 1, // 0x364
+	 1, // 0x365
+	 1, // 0x366
+	 1, // 0x367
+	 1, // 0x368
+	 1, // 0x369
+	 1, // 0x36a
+	 1, // 0x36b
+	 1, // 0x36c
+	 1, // 0x36d
+	 1, // 0x36e
+	 1, // 0x36f
+	 1, // 0x370
+	 1, // 0x371
+	 1, // 0x372
+	 1, // 0x373
+	 1, // 0x374
+	 1, // 0x375
+	 1, // 0x376
+	 1, // 0x377
+	 1, // 0x378
+	 1, // 0x379
+	 1, // 0x37a
+	 1, // 0x37b
+	 1, // 0x37c
+	 1, // 0x37d
+	 1, // 0x37e
+	 1, // 0x37f
+	 1, // 0x380
+	 1, // 0x381
+	 1, // 0x382
+	 1, // 0x383
+	 1, // 0x384
+	 1, // 0x385
+	 1, // 0x386
+	 1, // 0x387
+	 1, // 0x388
+	 1, // 0x389
+	 1, // 0x38a
+	 1, // 0x38b
+	 1, // 0x38c
+	 1, // 0x38d
+	 1, // 0x38e
+	 1, // 0x38f
+	 1, // 0x390
+	 1, // 0x391
+	 1, // 0x392
+	 1, // 0x393
+	 1, // 0x394
+	 1, // 0x395
+	 1, // 0x396
+	 1, // 0x397
+	 1, // 0x398
+	 1, // 0x399
+	 1, // 0x39a
+	 1, // 0x39b
+	 1, // 0x39c
+	 1, // 0x39d
+	 1, // 0x39e
+	 1, // 0x39f
+	 1, // 0x3a0
+	 1, // 0x3a1
+	 1, // 0x3a2
+	 1, // 0x3a3
+	 1, // 0x3a4
+	 1, // 0x3a5
+	 1, // 0x3a6
+	 1, // 0x3a7
+	 1, // 0x3a8
+	 1, // 0x3a9
+	 1, // 0x3aa
+	 1, // 0x3ab
+	 1, // 0x3ac
+	 1, // 0x3ad
+	 1, // 0x3ae
+	 1, // 0x3af
+	 1, // 0x3b0
+	 1, // 0x3b1
+	 1, // 0x3b2
+	 1, // 0x3b3
+	 1, // 0x3b4
+	 1, // 0x3b5
+	 1, // 0x3b6
+	 1, // 0x3b7
+	 1, // 0x3b8
+	 1, // 0x3b9
+	 1, // 0x3ba
+	 1, // 0x3bb
+	 1, // 0x3bc
+	 1, // 0x3bd
+	 1, // 0x3be
+	 1, // 0x3bf
+	 1, // 0x3c0
+	 1, // 0x3c1
+	 1, // 0x3c2
+	 1, // 0x3c3
+	 1, // 0x3c4
+	 1, // 0x3c5
+	 1, // 0x3c6
+	 1, // 0x3c7
+	 1, // 0x3c8
+	 1, // 0x3c9
+	 1, // 0x3ca
+	 1, // 0x3cb
+	 1, // 0x3cc
+	 1, // 0x3cd
+	 1, // 0x3ce
+	 1, // 0x3cf
+	 1, // 0x3d0
+	 1, // 0x3d1
+	 1, // 0x3d2
+	 1, // 0x3d3
+	 1, // 0x3d4
+	 1, // 0x3d5
+	 1, // 0x3d6
+	 1, // 0x3d7
+	 1, // 0x3d8
+	 1, // 0x3d9
+	 1, // 0x3da
+	 1, // 0x3db
+	 1, // 0x3dc
+	 1, // 0x3dd
+	 1, // 0x3de
+	 1, // 0x3df
+	 1, // 0x3e0
+	 1, // 0x3e1
+	 1, // 0x3e2
+	 1, // 0x3e3
+	 1, // 0x3e4
+	 1, // 0x3e5
+	 1, // 0x3e6
+	 1, // 0x3e7
+	 1, // 0x3e8
+	 1, // 0x3e9
+	 1, // 0x3ea
+	 1, // 0x3eb
+	 1, // 0x3ec
+	 1, // 0x3ed
+	 1, // 0x3ee
+	 1, // 0x3ef
+	 1, // 0x3f0
+	 1, // 0x3f1
+	 1, // 0x3f2
+	 1, // 0x3f3
+	 1, // 0x3f4
+	 1, // 0x3f5
+	 1, // 0x3f6
+	 1, // 0x3f7
+	 1, // 0x3f8
+	 1, // 0x3f9
+	 1, // 0x3fa
+	 1, // 0x3fb
+	 1, // 0x3fc
+	 1, // 0x3fd
+	 1, // 0x3fe
+	 1, // 0x3ff
+	 1, // 0x400
+	 1, // 0x401
+	 1, // 0x402
+	 1, // 0x403
+	 1, // 0x404
+	 1, // 0x405
+	 1, // 0x406
+	 1, // 0x407
+	 1, // 0x408
+	 1, // 0x409
+	 1, // 0x40a
+	 1, // 0x40b
+	 1, // 0x40c
+	 1, // 0x40d
+	 1, // 0x40e
+	 1, // 0x40f
+	 1, // 0x410
+	 1, // 0x411
+	 1, // 0x412
+	 1, // 0x413
+	 1, // 0x414
+	 1, // 0x415
+	 1, // 0x416
+	 1, // 0x417
+	 1, // 0x418
+	 1, // 0x419
+	 1, // 0x41a
+	 1, // 0x41b
+	 1, // 0x41c
+	 1, // 0x41d
+	 1, // 0x41e
+	 1, // 0x41f
+	 1, // 0x420
+	 1, // 0x421
+	 1, // 0x422
+	 1, // 0x423
+	 1, // 0x424
+	 1, // 0x425
+	 1, // 0x426
+	 1, // 0x427
+	 1, // 0x428
+	 1, // 0x429
+	 1, // 0x42a
+	 1, // 0x42b
+	 1, // 0x42c
+	 1, // 0x42d
+	 1, // 0x42e
+	 1, // 0x42f
+	 1, // 0x430
+	 1, // 0x431
+	 1, // 0x432
+	 1, // 0x433
+	 1, // 0x434
+	 1, // 0x435
+	 1, // 0x436
+	 1, // 0x437
+	 1, // 0x438
+	 1, // 0x439
+	 1, // 0x43a
+	 1, // 0x43b
+	 1, // 0x43c
+	 1, // 0x43d
+	 1, // 0x43e
+	 1, // 0x43f
+	 1, // 0x440
+	 1, // 0x441
+	 1, // 0x442
+	 1, // 0x443
+	 1, // 0x444
+	 1, // 0x445
+	 1, // 0x446
+	 1, // 0x447
+	 1, // 0x448
+	 1, // 0x449
+	 1, // 0x44a
+	 1, // 0x44b
+	 1, // 0x44c
+	 1, // 0x44d
+	 1, // 0x44e
+	 1, // 0x44f
+	 1, // 0x450
+	 1, // 0x451
+	 1, // 0x452
+	 1, // 0x453
+	 1, // 0x454
+	 1, // 0x455
+	 1, // 0x456
+	 1, // 0x457
+	 1, // 0x458
+	 1, // 0x459
+	 1, // 0x45a
+	 1, // 0x45b
+	 1, // 0x45c
+	 1, // 0x45d
+	 1, // 0x45e
+	 1, // 0x45f
+	 1, // 0x460
+	 1, // 0x461
+	 1, // 0x462
+	 1, // 0x463
+	 1, // 0x464
+	 1, // 0x465
+	 1, // 0x466
+	 1, // 0x467
+	 1, // 0x468
+	 1, // 0x469
+	 1, // 0x46a
+	 1, // 0x46b
+	 1, // 0x46c
+	 1, // 0x46d
+	 1, // 0x46e
+	 1, // 0x46f
+	 1, // 0x470
+	 1, // 0x471
+	 1, // 0x472
+	 1, // 0x473
+	 1, // 0x474
+	 1, // 0x475
+	 1, // 0x476
+	 1, // 0x477
+	 1, // 0x478
+	 1, // 0x479
+	 1, // 0x47a
+	 1, // 0x47b
+	 1, // 0x47c
+	 1, // 0x47d
+	 1, // 0x47e
+	 1, // 0x47f
+	 1, // 0x480
+	 1, // 0x481
+	 1, // 0x482
+	 1, // 0x483
+	 1, // 0x484
+	 1, // 0x485
+	 1, // 0x486
+	 1, // 0x487
+	 1, // 0x488
+	 1, // 0x489
+	 1, // 0x48a
+	 1, // 0x48b
+	 1, // 0x48c
+	 1, // 0x48d
+	 1, // 0x48e
+	 1, // 0x48f
+	 1, // 0x490
+	 1, // 0x491
+	 1, // 0x492
+	 1, // 0x493
+	 1, // 0x494
+	 1, // 0x495
+	 1, // 0x496
+	 1, // 0x497
+	 1, // 0x498
+	 1, // 0x499
+	 1, // 0x49a
+	 1, // 0x49b
+	 1, // 0x49c
+	 1, // 0x49d
+	 1, // 0x49e
+	 1, // 0x49f
+	 1, // 0x4a0
+	 1, // 0x4a1
+	 1, // 0x4a2
+	 1, // 0x4a3
+	 1, // 0x4a4
+	 1, // 0x4a5
+	 1, // 0x4a6
+	 1, // 0x4a7
+	 1, // 0x4a8
+	 1, // 0x4a9
+	 1, // 0x4aa
+	 1, // 0x4ab
+	 1, // 0x4ac
+	 1, // 0x4ad
+	 1, // 0x4ae
+	 1, // 0x4af
+	 1, // 0x4b0
+	 1, // 0x4b1
+	 1, // 0x4b2
+	 1, // 0x4b3
+	 1, // 0x4b4
+	 1, // 0x4b5
+	 1, // 0x4b6
+	 1, // 0x4b7
+	 1, // 0x4b8
+	 1, // 0x4b9
+	 1, // 0x4ba
+	 1, // 0x4bb
+	 1, // 0x4bc
+	 1, // 0x4bd
+	 1, // 0x4be
+	 1, // 0x4bf
+	 1, // 0x4c0
+	 1, // 0x4c1
+	 1, // 0x4c2
+	 1, // 0x4c3
+	 1, // 0x4c4
+	 1, // 0x4c5
+	 1, // 0x4c6
+	 1, // 0x4c7
+	 1, // 0x4c8
+	 1, // 0x4c9
+	 1, // 0x4ca
+	 1, // 0x4cb
+	 1, // 0x4cc
+	 1, // 0x4cd
+	 1, // 0x4ce
+	 1, // 0x4cf
+	 1, // 0x4d0
+	 1, // 0x4d1
+	 1, // 0x4d2
+	 1, // 0x4d3
+	 1, // 0x4d4
+	 1, // 0x4d5
+	 1, // 0x4d6
+	 1, // 0x4d7
+	 1, // 0x4d8
+	 1, // 0x4d9
+	 1, // 0x4da
+	 1, // 0x4db
+	 1, // 0x4dc
+	 1, // 0x4dd
+	 1, // 0x4de
+	 1, // 0x4df
+	 1, // 0x4e0
+	 1, // 0x4e1
+	 1, // 0x4e2
+	 1, // 0x4e3
+	 1, // 0x4e4
+	 1, // 0x4e5
+	 1, // 0x4e6
+	 1, // 0x4e7
+	 1, // 0x4e8
+	 1, // 0x4e9
+	 1, // 0x4ea
+	 1, // 0x4eb
+	 1, // 0x4ec
+	 1, // 0x4ed
+	 1, // 0x4ee
+	 1, // 0x4ef
+	 1, // 0x4f0
+	 1, // 0x4f1
+	 1, // 0x4f2
+	 1, // 0x4f3
+	 1, // 0x4f4
+	 1, // 0x4f5
+	 1, // 0x4f6
+	 1, // 0x4f7
+	 1, // 0x4f8
+	 1, // 0x4f9
+	 1, // 0x4fa
+	 1, // 0x4fb
+	 1, // 0x4fc
+	 1, // 0x4fd
+	 1, // 0x4fe
+	 1, // 0x4ff
+	 1, // 0x500
+	 1, // 0x501
+	 1, // 0x502
+	 1, // 0x503
+	 1, // 0x504
+	 1, // 0x505
+	 1, // 0x506
+	 1, // 0x507
+	 1, // 0x508
+	 1, // 0x509
+	 1, // 0x50a
+	 1, // 0x50b
+	 1, // 0x50c
+	 1, // 0x50d
+	 1, // 0x50e
+	 1, // 0x50f
+	 1, // 0x510
+	 1, // 0x511
+	 1, // 0x512
+	 1, // 0x513
+	 1, // 0x514
+	 1, // 0x515
+	 1, // 0x516
+	 1, // 0x517
+	 1, // 0x518
+	 1, // 0x519
+	 1, // 0x51a
+	 1, // 0x51b
+	 1, // 0x51c
+	 1, // 0x51d
+	 1, // 0x51e
+	 1, // 0x51f
+	 1, // 0x520
+	 1, // 0x521
+	 1, // 0x522
+	 1, // 0x523
+	 1, // 0x524
+	 1, // 0x525
+	 1, // 0x526
+	 1, // 0x527
+	 1, // 0x528
+	 1, // 0x529
+	 1, // 0x52a
+	 1, // 0x52b
+	 1, // 0x52c
+	 1, // 0x52d
+	 1, // 0x52e
+	 1, // 0x52f
+	 1, // 0x530
+	 1, // 0x531
+	 1, // 0x532
+	 1, // 0x533
+	 1, // 0x534
+	 1, // 0x535
+	 1, // 0x536
+	 1, // 0x537
+	 1, // 0x538
+	 1, // 0x539
+	 1, // 0x53a
+	 1, // 0x53b
+	 1, // 0x53c
+	 1, // 0x53d
+	 1, // 0x53e
+	 1, // 0x53f
+	 1, // 0x540
+	 1, // 0x541
+	 1, // 0x542
+	 1, // 0x543
+	 1, // 0x544
+	 1, // 0x545
+	 1, // 0x546
+	 1, // 0x547
+	 1, // 0x548
+	 1, // 0x549
+	 1, // 0x54a
+	 1, // 0x54b
+	 1, // 0x54c
+	 1, // 0x54d
+	 1, // 0x54e
+	 1, // 0x54f
+	 1, // 0x550
+	 1, // 0x551
+	 1, // 0x552
+	 1, // 0x553
+	 1, // 0x554
+	 1, // 0x555
+	 1, // 0x556
+	 1, // 0x557
+	 1, // 0x558
+	 1, // 0x559
+	 1, // 0x55a
+	 1, // 0x55b
+	 1, // 0x55c
+	 1, // 0x55d
+	 1, // 0x55e
+	 1, // 0x55f
+	 1, // 0x560
+	 1, // 0x561
+	 1, // 0x562
+	 1, // 0x563
+	 1, // 0x564
+	 1, // 0x565
+	 1, // 0x566
+	 1, // 0x567
+	 1, // 0x568
+	 1, // 0x569
+	 1, // 0x56a
+	 1, // 0x56b
+	 1, // 0x56c
+	 1, // 0x56d
+	 1, // 0x56e
+	 1, // 0x56f
+	 1, // 0x570
+	 1, // 0x571
+	 1, // 0x572
+	 1, // 0x573
+	 1, // 0x574
+	 1, // 0x575
+	 1, // 0x576
+	 1, // 0x577
+	 1, // 0x578
+	 1, // 0x579
+	 1, // 0x57a
+	 1, // 0x57b
+	 1, // 0x57c
+	 1, // 0x57d
+	 1, // 0x57e
+	 1, // 0x57f
+	 1, // 0x580
+	 1, // 0x581
+	 1, // 0x582
+	 1, // 0x583
+	 1, // 0x584
+	 1, // 0x585
+	 1, // 0x586
+	 1, // 0x587
+	 1, // 0x588
+	 1, // 0x589
+	 1, // 0x58a
+	 1, // 0x58b
+	 1, // 0x58c
+	 1, // 0x58d
+	 1, // 0x58e
+	 1, // 0x58f
+	 1, // 0x590
+	 1, // 0x591
+	 1, // 0x592
+	 1, // 0x593
+	 1, // 0x594
+	 1, // 0x595
+	 1, // 0x596
+	 1, // 0x597
+	 1, // 0x598
+	 1, // 0x599
+	 1, // 0x59a
+	 1, // 0x59b
+	 1, // 0x59c
+	 1, // 0x59d
+	 1, // 0x59e
+	 1, // 0x59f
+	 1, // 0x5a0
+	 1, // 0x5a1
+	 1, // 0x5a2
+	 1, // 0x5a3
+	 1, // 0x5a4
+	 1, // 0x5a5
+	 1, // 0x5a6
+	 1, // 0x5a7
+	 1, // 0x5a8
+	 1, // 0x5a9
+	 1, // 0x5aa
+	 1, // 0x5ab
+	 1, // 0x5ac
+	 1, // 0x5ad
+	 1, // 0x5ae
+	 1, // 0x5af
+	 1, // 0x5b0
+	 1, // 0x5b1
+	 1, // 0x5b2
+	 1, // 0x5b3
+	 1, // 0x5b4
+	 1, // 0x5b5
+	 1, // 0x5b6
+	 1, // 0x5b7
+	 1, // 0x5b8
+	 1, // 0x5b9
+	 1, // 0x5ba
+	 1, // 0x5bb
+	 1, // 0x5bc
+	 1, // 0x5bd
+	 1, // 0x5be
+	 1, // 0x5bf
+	 1, // 0x5c0
+	 1, // 0x5c1
+	 1, // 0x5c2
+	 1, // 0x5c3
+	 1, // 0x5c4
+	 1, // 0x5c5
+	 1, // 0x5c6
+	 1, // 0x5c7
+	 1, // 0x5c8
+	 1, // 0x5c9
+	 1, // 0x5ca
+	 1, // 0x5cb
+	 1, // 0x5cc
+	 1, // 0x5cd
+	 1, // 0x5ce
+	 1, // 0x5cf
+	 1, // 0x5d0
+	 1, // 0x5d1
+	 1, // 0x5d2
+	 1, // 0x5d3
+	 1, // 0x5d4
+	 1, // 0x5d5
+	 1, // 0x5d6
+	 1, // 0x5d7
+	 1, // 0x5d8
+	 1, // 0x5d9
+	 1, // 0x5da
+	 1, // 0x5db
+	 1, // 0x5dc
+	 1, // 0x5dd
+	 1, // 0x5de
+	 1, // 0x5df
+	 1, // 0x5e0
+	 1, // 0x5e1
+	 1, // 0x5e2
+	 1, // 0x5e3
+	 1, // 0x5e4
+	 1, // 0x5e5
+	 1, // 0x5e6
+	 1, // 0x5e7
+	 1, // 0x5e8
+	 1, // 0x5e9
+	 1, // 0x5ea
+	 1, // 0x5eb
+	 1, // 0x5ec
+	 1, // 0x5ed
+	 1, // 0x5ee
+	 1, // 0x5ef
+	 1, // 0x5f0
+	 1, // 0x5f1
+	 1, // 0x5f2
+	 1, // 0x5f3
+	 1, // 0x5f4
+	 1, // 0x5f5
+	 1, // 0x5f6
+	 1, // 0x5f7
+	 1, // 0x5f8
+	 1, // 0x5f9
+	 1, // 0x5fa
+	 1, // 0x5fb
+	 1, // 0x5fc
+	 1, // 0x5fd
+	 1, // 0x5fe
+	 1, // 0x5ff
+	 1, // 0x600
+	 1, // 0x601
+	 1, // 0x602
+	 1, // 0x603
+	 1, // 0x604
+	 1, // 0x605
+	 1, // 0x606
+	 1, // 0x607
+	 1, // 0x608
+	 1, // 0x609
+	 1, // 0x60a
+	 1, // 0x60b
+	 1, // 0x60c
+	 1, // 0x60d
+	 1, // 0x60e
+	 1, // 0x60f
+	 1, // 0x610
+	 1, // 0x611
+	 1, // 0x612
+	 1, // 0x613
+	 1, // 0x614
+	 1, // 0x615
+	 1, // 0x616
+	 1, // 0x617
+	 1, // 0x618
+	 1, // 0x619
+	 1, // 0x61a
+	 1, // 0x61b
+	 1, // 0x61c
+	 1, // 0x61d
+	 1, // 0x61e
+	 1, // 0x61f
+	 1, // 0x620
+	 1, // 0x621
+	 1, // 0x622
+	 1, // 0x623
+	 1, // 0x624
+	 1, // 0x625
+	 1, // 0x626
+	 1, // 0x627
+	 1, // 0x628
+	 1, // 0x629
+	 1, // 0x62a
+	 1, // 0x62b
+	 1, // 0x62c
+	 1, // 0x62d
+	 1, // 0x62e
+	 1, // 0x62f
+	 1, // 0x630
+	 1, // 0x631
+	 1, // 0x632
+	 1, // 0x633
+	 1, // 0x634
+	 1, // 0x635
+	 1, // 0x636
+	 1, // 0x637
+	 1, // 0x638
+	 1, // 0x639
+	 1, // 0x63a
+	 1, // 0x63b
+	 1, // 0x63c
+	 1, // 0x63d
+	 1, // 0x63e
+	 1, // 0x63f
+	 1, // 0x640
+	 1, // 0x641
+	 1, // 0x642
+	 1, // 0x643
+	 1, // 0x644
+	 1, // 0x645
+	 1, // 0x646
+	 1, // 0x647
+	 1, // 0x648
+	 1, // 0x649
+	 1, // 0x64a
+	 1, // 0x64b
+	 1, // 0x64c
+	 1, // 0x64d
+	 1, // 0x64e
+	 1, // 0x64f
+	 1, // 0x650
+	 1, // 0x651
+	 1, // 0x652
+	 1, // 0x653
+	 1, // 0x654
+	 1, // 0x655
+	 1, // 0x656
+	 1, // 0x657
+	 1, // 0x658
+	 1, // 0x659
+	 1, // 0x65a
+	 1, // 0x65b
+	 1, // 0x65c
+	 1, // 0x65d
+	 1, // 0x65e
+	 1, // 0x65f
+	 1, // 0x660
+	 1, // 0x661
+	 1, // 0x662
+	 1, // 0x663
+	 1, // 0x664
+	 1, // 0x665
+	 1, // 0x666
+	 1, // 0x667
+	 1, // 0x668
+	 1, // 0x669
+	 1, // 0x66a
+	 1, // 0x66b
+	 1, // 0x66c
+	 1, // 0x66d
+	 1, // 0x66e
+	 1, // 0x66f
+	 1, // 0x670
+	 1, // 0x671
+	 1, // 0x672
+	 1, // 0x673
+	 1, // 0x674
+	 1, // 0x675
+	 1, // 0x676
+	 1, // 0x677
+	 1, // 0x678
+	 1, // 0x679
+	 1, // 0x67a
+	 1, // 0x67b
+	 1, // 0x67c
+	 1, // 0x67d
+	 1, // 0x67e
+	 1, // 0x67f
+	 1, // 0x680
+	 1, // 0x681
+	 1, // 0x682
+	 1, // 0x683
+	 1, // 0x684
+	 1, // 0x685
+	 1, // 0x686
+	 1, // 0x687
+	 1, // 0x688
+	 1, // 0x689
+	 1, // 0x68a
+	 1, // 0x68b
+	 1, // 0x68c
+	 1, // 0x68d
+	 1, // 0x68e
+	 1, // 0x68f
+	 1, // 0x690
+	 1, // 0x691
+	 1, // 0x692
+	 1, // 0x693
+	 1, // 0x694
+	 1, // 0x695
+	 1, // 0x696
+	 1, // 0x697
+	 1, // 0x698
+	 1, // 0x699
+	 1, // 0x69a
+	 1, // 0x69b
+	 1, // 0x69c
+	 1, // 0x69d
+	 1, // 0x69e
+	 1, // 0x69f
+	 1, // 0x6a0
+	 1, // 0x6a1
+	 1, // 0x6a2
+	 1, // 0x6a3
+	 1, // 0x6a4
+	 1, // 0x6a5
+	 1, // 0x6a6
+	 1, // 0x6a7
+	 1, // 0x6a8
+	 1, // 0x6a9
+	 1, // 0x6aa
+	 1, // 0x6ab
+	 1, // 0x6ac
+	 1, // 0x6ad
+	 1, // 0x6ae
+	 1, // 0x6af
+	 1, // 0x6b0
+	 1, // 0x6b1
+	 1, // 0x6b2
+	 1, // 0x6b3
+	 1, // 0x6b4
+	 1, // 0x6b5
+	 1, // 0x6b6
+	 1, // 0x6b7
+	 1, // 0x6b8
+	 1, // 0x6b9
+	 1, // 0x6ba
+	 1, // 0x6bb
+	 1, // 0x6bc
+	 1, // 0x6bd
+	 1, // 0x6be
+	 1, // 0x6bf
+	 1, // 0x6c0
+	 1, // 0x6c1
+	 1, // 0x6c2
+	 1, // 0x6c3
+	 1, // 0x6c4
+	 1, // 0x6c5
+	 1, // 0x6c6
+	 1, // 0x6c7
+	 1, // 0x6c8
+	 1, // 0x6c9
+	 1, // 0x6ca
+	 1, // 0x6cb
+	 1, // 0x6cc
+	 1, // 0x6cd
+	 1, // 0x6ce
+	 1, // 0x6cf
+	 1, // 0x6d0
+	 1, // 0x6d1
+	 1, // 0x6d2
+	 1, // 0x6d3
+	 1, // 0x6d4
+	 1, // 0x6d5
+	 1, // 0x6d6
+	 1, // 0x6d7
+	 1, // 0x6d8
+	 1, // 0x6d9
+	 1, // 0x6da
+	 1, // 0x6db
+	 1, // 0x6dc
+	 1, // 0x6dd
+	 1, // 0x6de
+	 1, // 0x6df
+	 1, // 0x6e0
+	 1, // 0x6e1
+	 1, // 0x6e2
+	 1, // 0x6e3
+	 1, // 0x6e4
+	 1, // 0x6e5
+	 1, // 0x6e6
+	 1, // 0x6e7
+	 1, // 0x6e8
+	 1, // 0x6e9
+	 1, // 0x6ea
+	 1, // 0x6eb
+	 1, // 0x6ec
+	 1, // 0x6ed
+	 1, // 0x6ee
+	 1, // 0x6ef
+	 1, // 0x6f0
+	 1, // 0x6f1
+	 1, // 0x6f2
+	 1, // 0x6f3
+	 1, // 0x6f4
+	 1, // 0x6f5
+	 1, // 0x6f6
+	 1, // 0x6f7
+	 1, // 0x6f8
+	 1, // 0x6f9
+	 1, // 0x6fa
+	 1, // 0x6fb
+	 1, // 0x6fc
+	 1, // 0x6fd
+	 1, // 0x6fe
+	 1, // 0x6ff
+	 1, // 0x700
+	 1, // 0x701
+	 1, // 0x702
+	 1, // 0x703
+	 1, // 0x704
+	 1, // 0x705
+	 1, // 0x706
+	 1, // 0x707
+	 1, // 0x708
+	 1, // 0x709
+	 1, // 0x70a
+	 1, // 0x70b
+	 1, // 0x70c
+	 1, // 0x70d
+	 1, // 0x70e
+	 1, // 0x70f
+	 1, // 0x710
+	 1, // 0x711
+	 1, // 0x712
+	 1, // 0x713
+	 1, // 0x714
+	 1, // 0x715
+	 1, // 0x716
+	 1, // 0x717
+	 1, // 0x718
+	 1, // 0x719
+	 1, // 0x71a
+	 1, // 0x71b
+	 1, // 0x71c
+	 1, // 0x71d
+	 1, // 0x71e
+	 1, // 0x71f
+	 1, // 0x720
+	 1, // 0x721
+	 1, // 0x722
+	 1, // 0x723
+	 1, // 0x724
+	 1, // 0x725
+	 1, // 0x726
+	 1, // 0x727
+	 1, // 0x728
+	 1, // 0x729
+	 1, // 0x72a
+	 1, // 0x72b
+	 1, // 0x72c
+	 1, // 0x72d
+	 1, // 0x72e
+	 1, // 0x72f
+	 1, // 0x730
+	 1, // 0x731
+	 1, // 0x732
+	 1, // 0x733
+	 1, // 0x734
+	 1, // 0x735
+	 1, // 0x736
+	 1, // 0x737
+	 1, // 0x738
+	 1, // 0x739
+	 1, // 0x73a
+	 1, // 0x73b
+	 1, // 0x73c
+	 1, // 0x73d
+	 1, // 0x73e
+	 1, // 0x73f
+	 1, // 0x740
+	 1, // 0x741
+	 1, // 0x742
+	 1, // 0x743
+	 1, // 0x744
+	 1, // 0x745
+	 1, // 0x746
+	 1, // 0x747
+	 1, // 0x748
+	 1, // 0x749
+	 1, // 0x74a
+	 1, // 0x74b
+	 1, // 0x74c
+	 1, // 0x74d
+	 1, // 0x74e
+	 1, // 0x74f
+	 1, // 0x750
+	 1, // 0x751
+	 1, // 0x752
+	 1, // 0x753
+	 1, // 0x754
+	 1, // 0x755
+	 1, // 0x756
+	 1, // 0x757
+	 1, // 0x758
+	 1, // 0x759
+	 1, // 0x75a
+	 1, // 0x75b
+	 1, // 0x75c
+	 1, // 0x75d
+	 1, // 0x75e
+	 1, // 0x75f
+	 1, // 0x760
+	 1, // 0x761
+	 1, // 0x762
+	 1, // 0x763
+	 1, // 0x764
+	 1, // 0x765
+	 1, // 0x766
+	 1, // 0x767
+	 1, // 0x768
+	 1, // 0x769
+	 1, // 0x76a
+	 1, // 0x76b
+	 1, // 0x76c
+	 1, // 0x76d
+	 1, // 0x76e
+	 1, // 0x76f
+	 1, // 0x770
+	 1, // 0x771
+	 1, // 0x772
+	 1, // 0x773
+	 1, // 0x774
+	 1, // 0x775
+	 1, // 0x776
+	 1, // 0x777
+	 1, // 0x778
+	 1, // 0x779
+	 1, // 0x77a
+	 1, // 0x77b
+	 1, // 0x77c
+	 1, // 0x77d
+	 1, // 0x77e
+	 1, // 0x77f
+	 1, // 0x780
+	 1, // 0x781
+	 1, // 0x782
+	 1, // 0x783
+	 1, // 0x784
+	 1, // 0x785
+	 1, // 0x786
+	 1, // 0x787
+	 1, // 0x788
+	 1, // 0x789
+	 1, // 0x78a
+	 1, // 0x78b
+	 1, // 0x78c
+	 1, // 0x78d
+	 1, // 0x78e
+	 1, // 0x78f
+	 1, // 0x790
+	 1, // 0x791
+	 1, // 0x792
+	 1, // 0x793
+	 1, // 0x794
+	 1, // 0x795
+	 1, // 0x796
+	 1, // 0x797
+	 1, // 0x798
+	 1, // 0x799
+	 1, // 0x79a
+	 1, // 0x79b
+	 1, // 0x79c
+	 1, // 0x79d
+	 1, // 0x79e
+	 1, // 0x79f
+	 1, // 0x7a0
+	 1, // 0x7a1
+	 1, // 0x7a2
+	 1, // 0x7a3
+	 1, // 0x7a4
+	 1, // 0x7a5
+	 1, // 0x7a6
+	 1, // 0x7a7
+	 1, // 0x7a8
+	 1, // 0x7a9
+	 1, // 0x7aa
+	 1, // 0x7ab
+	 1, // 0x7ac
+	 1, // 0x7ad
+	 1, // 0x7ae
+	 1, // 0x7af
+	 1, // 0x7b0
+	 1, // 0x7b1
+	 1, // 0x7b2
+	 1, // 0x7b3
+	 1, // 0x7b4
+	 1, // 0x7b5
+	 1, // 0x7b6
+	 1, // 0x7b7
+	 1, // 0x7b8
+	 1, // 0x7b9
+	 1, // 0x7ba
+	 1, // 0x7bb
+	 1, // 0x7bc
+	 1, // 0x7bd
+	 1, // 0x7be
+	 1, // 0x7bf
+	 1, // 0x7c0
+	 1, // 0x7c1
+	 1, // 0x7c2
+	 1, // 0x7c3
+	 1, // 0x7c4
+	 1, // 0x7c5
+	 1, // 0x7c6
+	 1, // 0x7c7
+	 1, // 0x7c8
+	 1, // 0x7c9
+	 1, // 0x7ca
+	 1, // 0x7cb
+	 1, // 0x7cc
+	 1, // 0x7cd
+	 1, // 0x7ce
+	 1, // 0x7cf
+	 1, // 0x7d0
+	 1, // 0x7d1
+	 1, // 0x7d2
+	 1, // 0x7d3
+	 1, // 0x7d4
+	 1, // 0x7d5
+	 1, // 0x7d6
+	 1, // 0x7d7
+	 1, // 0x7d8
+	 1, // 0x7d9
+	 1, // 0x7da
+	 1, // 0x7db
+	 1, // 0x7dc
+	 1, // 0x7dd
+	 1, // 0x7de
+	 1, // 0x7df
+	 1, // 0x7e0
+	 1, // 0x7e1
+	 1, // 0x7e2
+	 1, // 0x7e3
+	 1, // 0x7e4
+	 1, // 0x7e5
+	 1, // 0x7e6
+	 1, // 0x7e7
+	 1, // 0x7e8
+	 1, // 0x7e9
+	 1, // 0x7ea
+	 1, // 0x7eb
+	 1, // 0x7ec
+	 1, // 0x7ed
+	 1, // 0x7ee
+	 1, // 0x7ef
+	 1, // 0x7f0
+	 1, // 0x7f1
+	 1, // 0x7f2
+	 1, // 0x7f3
+	 1, // 0x7f4
+	 1, // 0x7f5
+	 1, // 0x7f6
+	 1, // 0x7f7
+	 1, // 0x7f8
+	 1, // 0x7f9
+	 1, // 0x7fa
+	 1, // 0x7fb
+	 1, // 0x7fc
+	 1, // 0x7fd
+	 1, // 0x7fe
+	 1, // 0x7ff
+	 1, // 0x800
+	 1, // 0x801
+	 1, // 0x802
+	 1, // 0x803
+	 1, // 0x804
+	 1, // 0x805
+	 1, // 0x806
+	 1, // 0x807
+	 1, // 0x808
+	 1, // 0x809
+	 1, // 0x80a
+	 1, // 0x80b
+	 1, // 0x80c
+	 1, // 0x80d
+	 1, // 0x80e
+	 1, // 0x80f
+	 1, // 0x810
+	 1, // 0x811
+	 1, // 0x812
+	 1, // 0x813
+	 1, // 0x814
+	 1, // 0x815
+	 1, // 0x816
+	 1, // 0x817
+	 1, // 0x818
+	 1, // 0x819
+	 1, // 0x81a
+	 1, // 0x81b
+	 1, // 0x81c
+	 1, // 0x81d
+	 1, // 0x81e
+	 1, // 0x81f
+	 1, // 0x820
+	 1, // 0x821
+	 1, // 0x822
+	 1, // 0x823
+	 1, // 0x824
+	 1, // 0x825
+	 1, // 0x826
+	 1, // 0x827
+	 1, // 0x828
+	 1, // 0x829
+	 1, // 0x82a
+	 1, // 0x82b
+	 1, // 0x82c
+	 1, // 0x82d
+	 1, // 0x82e
+	 1, // 0x82f
+	 1, // 0x830
+	 1, // 0x831
+	 1, // 0x832
+	 1, // 0x833
+	 1, // 0x834
+	 1, // 0x835
+	 1, // 0x836
+	 1, // 0x837
+	 1, // 0x838
+	 1, // 0x839
+	 1, // 0x83a
+	 1, // 0x83b
+	 1, // 0x83c
+	 1, // 0x83d
+	 1, // 0x83e
+	 1, // 0x83f
+	 1, // 0x840
+	 1, // 0x841
+	 1, // 0x842
+	 1, // 0x843
+	 1, // 0x844
+	 1, // 0x845
+	 1, // 0x846
+	 1, // 0x847
+	 1, // 0x848
+	 1, // 0x849
+	 1, // 0x84a
+	 1, // 0x84b
+	 1, // 0x84c
+	 1, // 0x84d
+	 1, // 0x84e
+	 1, // 0x84f
+	 1, // 0x850
+	 1, // 0x851
+	 1, // 0x852
+	 1, // 0x853
+	 1, // 0x854
+	 1, // 0x855
+	 1, // 0x856
+	 1, // 0x857
+	 1, // 0x858
+	 1, // 0x859
+	 1, // 0x85a
+	 1, // 0x85b
+	 1, // 0x85c
+	 1, // 0x85d
+	 1, // 0x85e
+	 1, // 0x85f
+	 1, // 0x860
+	 1, // 0x861
+	 1, // 0x862
+	 1, // 0x863
+	 1, // 0x864
+	 1, // 0x865
+	 1, // 0x866
+	 1, // 0x867
+	 1, // 0x868
+	 1, // 0x869
+	 1, // 0x86a
+	 1, // 0x86b
+	 1, // 0x86c
+	 1, // 0x86d
+	 1, // 0x86e
+	 1, // 0x86f
+	 1, // 0x870
+	 1, // 0x871
+	 1, // 0x872
+	 1, // 0x873
+	 1, // 0x874
+	 1, // 0x875
+	 1, // 0x876
+	 1, // 0x877
+	 1, // 0x878
+	 1, // 0x879
+	 1, // 0x87a
+	 1, // 0x87b
+	 1, // 0x87c
+	 1, // 0x87d
+	 1, // 0x87e
+	 1, // 0x87f
+	 1, // 0x880
+	 1, // 0x881
+	 1, // 0x882
+	 1, // 0x883
+	 1, // 0x884
+	 1, // 0x885
+	 1, // 0x886
+	 1, // 0x887
+	 1, // 0x888
+	 1, // 0x889
+	 1, // 0x88a
+	 1, // 0x88b
+	 1, // 0x88c
+	 1, // 0x88d
+	 1, // 0x88e
+	 1, // 0x88f
+	 1, // 0x890
+	 1, // 0x891
+	 1, // 0x892
+	 1, // 0x893
+	 1, // 0x894
+	 1, // 0x895
+	 1, // 0x896
+	 1, // 0x897
+	 1, // 0x898
+	 1, // 0x899
+	 1, // 0x89a
+	 1, // 0x89b
+	 1, // 0x89c
+	 1, // 0x89d
+	 1, // 0x89e
+	 1, // 0x89f
+	 1, // 0x8a0
+	 1, // 0x8a1
+	 1, // 0x8a2
+	 1, // 0x8a3
+	 1, // 0x8a4
+	 1, // 0x8a5
+	 1, // 0x8a6
+	 1, // 0x8a7
+	 1, // 0x8a8
+	 1, // 0x8a9
+	 1, // 0x8aa
+	 1, // 0x8ab
+	 1, // 0x8ac
+	 1, // 0x8ad
+	 1, // 0x8ae
+	 1, // 0x8af
+	 1, // 0x8b0
+	 1, // 0x8b1
+	 1, // 0x8b2
+	 1, // 0x8b3
+	 1, // 0x8b4
+	 1, // 0x8b5
+	 1, // 0x8b6
+	 1, // 0x8b7
+	 1, // 0x8b8
+	 1, // 0x8b9
+	 1, // 0x8ba
+	 1, // 0x8bb
+	 1, // 0x8bc
+	 1, // 0x8bd
+	 1, // 0x8be
+	 1, // 0x8bf
+	 1, // 0x8c0
+	 1, // 0x8c1
+	 1, // 0x8c2
+	 1, // 0x8c3
+	 1, // 0x8c4
+	 1, // 0x8c5
+	 1, // 0x8c6
+	 1, // 0x8c7
+	 1, // 0x8c8
+	 1, // 0x8c9
+	 1, // 0x8ca
+	 1, // 0x8cb
+	 1, // 0x8cc
+	 1, // 0x8cd
+	 1, // 0x8ce
+	 1, // 0x8cf
+	 1, // 0x8d0
+	 1, // 0x8d1
+	 1, // 0x8d2
+	 1, // 0x8d3
+	 1, // 0x8d4
+	 1, // 0x8d5
+	 1, // 0x8d6
+	 1, // 0x8d7
+	 1, // 0x8d8
+	 1, // 0x8d9
+	 1, // 0x8da
+	 1, // 0x8db
+	 1, // 0x8dc
+	 1, // 0x8dd
+	 1, // 0x8de
+	 1, // 0x8df
+	 1, // 0x8e0
+	 1, // 0x8e1
+	 1, // 0x8e2
+	 1, // 0x8e3
+	 1, // 0x8e4
+	 1, // 0x8e5
+	 1, // 0x8e6
+	 1, // 0x8e7
+	 1, // 0x8e8
+	 1, // 0x8e9
+	 1, // 0x8ea
+	 1, // 0x8eb
+	 1, // 0x8ec
+	 1, // 0x8ed
+	 1, // 0x8ee
+	 1, // 0x8ef
+	 1, // 0x8f0
+	 1, // 0x8f1
+	 1, // 0x8f2
+	 1, // 0x8f3
+	 1, // 0x8f4
+	 1, // 0x8f5
+	 1, // 0x8f6
+	 1, // 0x8f7
+	 1, // 0x8f8
+	 1, // 0x8f9
+	 1, // 0x8fa
+	 1, // 0x8fb
+	 1, // 0x8fc
+	 1, // 0x8fd
+	 1, // 0x8fe
+	 1, // 0x8ff
+	 1, // 0x900
+	 1, // 0x901
+	 1, // 0x902
+	 1, // 0x903
+	 1, // 0x904
+	 1, // 0x905
+	 1, // 0x906
+	 1, // 0x907
+	 1, // 0x908
+	 1, // 0x909
+	 1, // 0x90a
+	 1, // 0x90b
+	 1, // 0x90c
+	 1, // 0x90d
+	 1, // 0x90e
+	 1, // 0x90f
+	 1, // 0x910
+	 1, // 0x911
+	 1, // 0x912
+	 1, // 0x913
+	 1, // 0x914
+	 1, // 0x915
+	 1, // 0x916
+	 1, // 0x917
+	 1, // 0x918
+	 1, // 0x919
+	 1, // 0x91a
+	 1, // 0x91b
+	 1, // 0x91c
+	 1, // 0x91d
+	 1, // 0x91e
+	 1, // 0x91f
+	 1, // 0x920
+	 1, // 0x921
+	 1, // 0x922
+	 1, // 0x923
+	 1, // 0x924
+	 1, // 0x925
+	 1, // 0x926
+	 1, // 0x927
+	 1, // 0x928
+	 1, // 0x929
+	 1, // 0x92a
+	 1, // 0x92b
+	 1, // 0x92c
+	 1, // 0x92d
+	 1, // 0x92e
+	 1, // 0x92f
+	 1, // 0x930
+	 1, // 0x931
+	 1, // 0x932
+	 1, // 0x933
+	 1, // 0x934
+	 1, // 0x935
+	 1, // 0x936
+	 1, // 0x937
+	 1, // 0x938
+	 1, // 0x939
+	 1, // 0x93a
+	 1, // 0x93b
+	 1, // 0x93c
+	 1, // 0x93d
+	 1, // 0x93e
+	 1, // 0x93f
+	 1, // 0x940
+	 1, // 0x941
+	 1, // 0x942
+	 1, // 0x943
+	 1, // 0x944
+	 1, // 0x945
+	 1, // 0x946
+	 1, // 0x947
+	 1, // 0x948
+	 1, // 0x949
+	 1, // 0x94a
+	 1, // 0x94b
+	 1, // 0x94c
+	 1, // 0x94d
+	 1, // 0x94e
+	 1, // 0x94f
+	 1, // 0x950
+	 1, // 0x951
+	 1, // 0x952
+	 1, // 0x953
+	 1, // 0x954
+	 1, // 0x955
+	 1, // 0x956
+	 1, // 0x957
+	 1, // 0x958
+	 1, // 0x959
+	 1, // 0x95a
+	 1, // 0x95b
+	 1, // 0x95c
+	 1, // 0x95d
+	 1, // 0x95e
+	 1, // 0x95f
+	 1, // 0x960
+	 1, // 0x961
+	 1, // 0x962
+	 1, // 0x963
+	 1, // 0x964
+	 1, // 0x965
+	 1, // 0x966
+	 1, // 0x967
+	 1, // 0x968
+	 1, // 0x969
+	 1, // 0x96a
+	 1, // 0x96b
+	 1, // 0x96c
+	 1, // 0x96d
+	 1, // 0x96e
+	 1, // 0x96f
+	 1, // 0x970
+	 1, // 0x971
+	 1, // 0x972
+	 1, // 0x973
+	 1, // 0x974
+	 1, // 0x975
+	 1, // 0x976
+	 1, // 0x977
+	 1, // 0x978
+	 1, // 0x979
+	 1, // 0x97a
+	 1, // 0x97b
+	 1, // 0x97c
+	 1, // 0x97d
+	 1, // 0x97e
+	 1, // 0x97f
+	 1, // 0x980
+	 1, // 0x981
+	 1, // 0x982
+	 1, // 0x983
+	 1, // 0x984
+	 1, // 0x985
+	 1, // 0x986
+	 1, // 0x987
+	 1, // 0x988
+	 1, // 0x989
+	 1, // 0x98a
+	 1, // 0x98b
+	 1, // 0x98c
+	 1, // 0x98d
+	 1, // 0x98e
+	 1, // 0x98f
+	 1, // 0x990
+	 1, // 0x991
+	 1, // 0x992
+	 1, // 0x993
+	 1, // 0x994
+	 1, // 0x995
+	 1, // 0x996
+	 1, // 0x997
+	 1, // 0x998
+	 1, // 0x999
+	 1, // 0x99a
+	 1, // 0x99b
+	 1, // 0x99c
+	 1, // 0x99d
+	 1, // 0x99e
+	 1, // 0x99f
+	 1, // 0x9a0
+	 1, // 0x9a1
+	 1, // 0x9a2
+	 1, // 0x9a3
+	 1, // 0x9a4
+	 1, // 0x9a5
+	 1, // 0x9a6
+	 1, // 0x9a7
+	 1, // 0x9a8
+	 1, // 0x9a9
+	 1, // 0x9aa
+	 1, // 0x9ab
+	 1, // 0x9ac
+	 1, // 0x9ad
+	 1, // 0x9ae
+	 1, // 0x9af
+	 1, // 0x9b0
+	 1, // 0x9b1
+	 1, // 0x9b2
+	 1, // 0x9b3
+	 1, // 0x9b4
+	 1, // 0x9b5
+	 1, // 0x9b6
+	 1, // 0x9b7
+	 1, // 0x9b8
+	 1, // 0x9b9
+	 1, // 0x9ba
+	 1, // 0x9bb
+	 1, // 0x9bc
+	 1, // 0x9bd
+	 1, // 0x9be
+	 1, // 0x9bf
+	 1, // 0x9c0
+	 1, // 0x9c1
+	 1, // 0x9c2
+	 1, // 0x9c3
+	 1, // 0x9c4
+	 1, // 0x9c5
+	 1, // 0x9c6
+	 1, // 0x9c7
+	 1, // 0x9c8
+	 1, // 0x9c9
+	 1, // 0x9ca
+	 1, // 0x9cb
+	 1, // 0x9cc
+	 1, // 0x9cd
+	 1, // 0x9ce
+	 1, // 0x9cf
+	 1, // 0x9d0
+	 1, // 0x9d1
+	 1, // 0x9d2
+	 1, // 0x9d3
+	 1, // 0x9d4
+	 1, // 0x9d5
+	 1, // 0x9d6
+	 1, // 0x9d7
+	 1, // 0x9d8
+	 1, // 0x9d9
+	 1, // 0x9da
+	 1, // 0x9db
+	 1, // 0x9dc
+	 1, // 0x9dd
+	 1, // 0x9de
+	 1, // 0x9df
+	 1, // 0x9e0
+	 1, // 0x9e1
+	 1, // 0x9e2
+	 1, // 0x9e3
+	 1, // 0x9e4
+	 1, // 0x9e5
+	 1, // 0x9e6
+	 1, // 0x9e7
+	 1, // 0x9e8
+	 1, // 0x9e9
+	 1, // 0x9ea
+	 1, // 0x9eb
+	 1, // 0x9ec
+	 1, // 0x9ed
+	 1, // 0x9ee
+	 1, // 0x9ef
+	 1, // 0x9f0
+	 1, // 0x9f1
+	 1, // 0x9f2
+	 1, // 0x9f3
+	 1, // 0x9f4
+	 1, // 0x9f5
+	 1, // 0x9f6
+	 1, // 0x9f7
+	 1, // 0x9f8
+	 1, // 0x9f9
+	 1, // 0x9fa
+	 1, // 0x9fb
+	 1, // 0x9fc
+	 1, // 0x9fd
+	 1, // 0x9fe
+	 1, // 0x9ff
+	 1, // 0xa00
+	 1, // 0xa01
+	 1, // 0xa02
+	 1, // 0xa03
+	 1, // 0xa04
+	 1, // 0xa05
+	 1, // 0xa06
+	 1, // 0xa07
+	 1, // 0xa08
+	 1, // 0xa09
+	 1, // 0xa0a
+	 1, // 0xa0b
+	 1, // 0xa0c
+	 1, // 0xa0d
+	 1, // 0xa0e
+	 1, // 0xa0f
+	 1, // 0xa10
+	 1, // 0xa11
+	 1, // 0xa12
+	 1, // 0xa13
+	 1, // 0xa14
+	 1, // 0xa15
+	 1, // 0xa16
+	 1, // 0xa17
+	 1, // 0xa18
+	 1, // 0xa19
+	 1, // 0xa1a
+	 1, // 0xa1b
+	 1, // 0xa1c
+	 1, // 0xa1d
+	 1, // 0xa1e
+	 1, // 0xa1f
+	 1, // 0xa20
+	 1, // 0xa21
+	 1, // 0xa22
+	 1, // 0xa23
+	 1, // 0xa24
+	 1, // 0xa25
+	 1, // 0xa26
+	 1, // 0xa27
+	 1, // 0xa28
+	 1, // 0xa29
+	 1, // 0xa2a
+	 1, // 0xa2b
+	 1, // 0xa2c
+	 1, // 0xa2d
+	 1, // 0xa2e
+	 1, // 0xa2f
+	 1, // 0xa30
+	 1, // 0xa31
+	 1, // 0xa32
+	 1, // 0xa33
+	 1, // 0xa34
+	 1, // 0xa35
+	 1, // 0xa36
+	 1, // 0xa37
+	 1, // 0xa38
+	 1, // 0xa39
+	 1, // 0xa3a
+	 1, // 0xa3b
+	 1, // 0xa3c
+	 1, // 0xa3d
+	 1, // 0xa3e
+	 1, // 0xa3f
+	 1, // 0xa40
+	 1, // 0xa41
+	 1, // 0xa42
+	 1, // 0xa43
+	 1, // 0xa44
+	 1, // 0xa45
+	 1, // 0xa46
+	 1, // 0xa47
+	 1, // 0xa48
+	 1, // 0xa49
+	 1, // 0xa4a
+	 1, // 0xa4b
+	 1, // 0xa4c
+	 1, // 0xa4d
+	 1, // 0xa4e
+	 1, // 0xa4f
+	 1, // 0xa50
+	 1, // 0xa51
+	 1, // 0xa52
+	 1, // 0xa53
+	 1, // 0xa54
+	 1, // 0xa55
+	 1, // 0xa56
+	 1, // 0xa57
+	 1, // 0xa58
+	 1, // 0xa59
+	 1, // 0xa5a
+	 1, // 0xa5b
+	 1, // 0xa5c
+	 1, // 0xa5d
+	 1, // 0xa5e
+	 1, // 0xa5f
+	 1, // 0xa60
+	 1, // 0xa61
+	 1, // 0xa62
+	 1, // 0xa63
+	 1, // 0xa64
+	 1, // 0xa65
+	 1, // 0xa66
+	 1, // 0xa67
+	 1, // 0xa68
+	 1, // 0xa69
+	 1, // 0xa6a
+	 1, // 0xa6b
+	 1, // 0xa6c
+	 1, // 0xa6d
+	 1, // 0xa6e
+	 1, // 0xa6f
+	 1, // 0xa70
+	 1, // 0xa71
+	 1, // 0xa72
+	 1, // 0xa73
+	 1, // 0xa74
+	 1, // 0xa75
+	 1, // 0xa76
+	 1, // 0xa77
+	 1, // 0xa78
+	 1, // 0xa79
+	 1, // 0xa7a
+	 1, // 0xa7b
+	 1, // 0xa7c
+	 1, // 0xa7d
+	 1, // 0xa7e
+	 1, // 0xa7f
+	 1, // 0xa80
+	 1, // 0xa81
+	 1, // 0xa82
+	 1, // 0xa83
+	 1, // 0xa84
+	 1, // 0xa85
+	 1, // 0xa86
+	 1, // 0xa87
+	 1, // 0xa88
+	 1, // 0xa89
+	 1, // 0xa8a
+	 1, // 0xa8b
+	 1, // 0xa8c
+	 1, // 0xa8d
+	 1, // 0xa8e
+	 1, // 0xa8f
+	 1, // 0xa90
+	 1, // 0xa91
+	 1, // 0xa92
+	 1, // 0xa93
+	 1, // 0xa94
+	 1, // 0xa95
+	 1, // 0xa96
+	 1, // 0xa97
+	 1, // 0xa98
+	 1, // 0xa99
+	 1, // 0xa9a
+	 1, // 0xa9b
+	 1, // 0xa9c
+	 1, // 0xa9d
+	 1, // 0xa9e
+	 1, // 0xa9f
+	 1, // 0xaa0
+	 1, // 0xaa1
+	 1, // 0xaa2
+	 1, // 0xaa3
+	 1, // 0xaa4
+	 1, // 0xaa5
+	 1, // 0xaa6
+	 1, // 0xaa7
+	 1, // 0xaa8
+	 1, // 0xaa9
+	 1, // 0xaaa
+	 1, // 0xaab
+	 1, // 0xaac
+	 1, // 0xaad
+	 1, // 0xaae
+	 1, // 0xaaf
+	 1, // 0xab0
+	 1, // 0xab1
+	 1, // 0xab2
+	 1, // 0xab3
+	 1, // 0xab4
+	 1, // 0xab5
+	 1, // 0xab6
+	 1, // 0xab7
+	 1, // 0xab8
+	 1, // 0xab9
+	 1, // 0xaba
+	 1, // 0xabb
+	 1, // 0xabc
+	 1, // 0xabd
+	 1, // 0xabe
+	 1, // 0xabf
+	 1, // 0xac0
+	 1, // 0xac1
+	 1, // 0xac2
+	 1, // 0xac3
+	 1, // 0xac4
+	 1, // 0xac5
+	 1, // 0xac6
+	 1, // 0xac7
+	 1, // 0xac8
+	 1, // 0xac9
+	 1, // 0xaca
+	 1, // 0xacb
+	 1, // 0xacc
+	 1, // 0xacd
+	 1, // 0xace
+	 1, // 0xacf
+	 1, // 0xad0
+	 1, // 0xad1
+	 1, // 0xad2
+	 1, // 0xad3
+	 1, // 0xad4
+	 1, // 0xad5
+	 1, // 0xad6
+	 1, // 0xad7
+	 1, // 0xad8
+	 1, // 0xad9
+	 1, // 0xada
+	 1, // 0xadb
+	 1, // 0xadc
+	 1, // 0xadd
+	 1, // 0xade
+	 1, // 0xadf
+	 1, // 0xae0
+	 1, // 0xae1
+	 1, // 0xae2
+	 1, // 0xae3
+	 1, // 0xae4
+	 1, // 0xae5
+	 1, // 0xae6
+	 1, // 0xae7
+	 1, // 0xae8
+	 1, // 0xae9
+	 1, // 0xaea
+	 1, // 0xaeb
+	 1, // 0xaec
+	 1, // 0xaed
+	 1, // 0xaee
+	 1, // 0xaef
+	 1, // 0xaf0
+	 1, // 0xaf1
+	 1, // 0xaf2
+	 1, // 0xaf3
+	 1, // 0xaf4
+	 1, // 0xaf5
+	 1, // 0xaf6
+	 1, // 0xaf7
+	 1, // 0xaf8
+	 1, // 0xaf9
+	 1, // 0xafa
+	 1, // 0xafb
+	 1, // 0xafc
+	 1, // 0xafd
+	 1, // 0xafe
+	 1, // 0xaff
+	 1, // 0xb00
+	 1, // 0xb01
+	 1, // 0xb02
+	 1, // 0xb03
+	 1, // 0xb04
+	 1, // 0xb05
+	 1, // 0xb06
+	 1, // 0xb07
+	 1, // 0xb08
+	 1, // 0xb09
+	 1, // 0xb0a
+	 1, // 0xb0b
+	 1, // 0xb0c
+	 1, // 0xb0d
+	 1, // 0xb0e
+	 1, // 0xb0f
+	 1, // 0xb10
+	 1, // 0xb11
+	 1, // 0xb12
+	 1, // 0xb13
+	 1, // 0xb14
+	 1, // 0xb15
+	 1, // 0xb16
+	 1, // 0xb17
+	 1, // 0xb18
+	 1, // 0xb19
+	 1, // 0xb1a
+	 1, // 0xb1b
+	 1, // 0xb1c
+	 1, // 0xb1d
+	 1, // 0xb1e
+	 1, // 0xb1f
+	 1, // 0xb20
+	 1, // 0xb21
+	 1, // 0xb22
+	 1, // 0xb23
+	 1, // 0xb24
+	 1, // 0xb25
+	 1, // 0xb26
+	 1, // 0xb27
+	 1, // 0xb28
+	 1, // 0xb29
+	 1, // 0xb2a
+	 1, // 0xb2b
+	 1, // 0xb2c
+	 1, // 0xb2d
+	 1, // 0xb2e
+	 1, // 0xb2f
+	 1, // 0xb30
+	 1, // 0xb31
+	 1, // 0xb32
+	 1, // 0xb33
+	 1, // 0xb34
+	 1, // 0xb35
+	 1, // 0xb36
+	 1, // 0xb37
+	 1, // 0xb38
+	 1, // 0xb39
+	 1, // 0xb3a
+	 1, // 0xb3b
+	 1, // 0xb3c
+	 1, // 0xb3d
+	 1, // 0xb3e
+	 1, // 0xb3f
+	 1, // 0xb40
+	 1, // 0xb41
+	 1, // 0xb42
+	 1, // 0xb43
+	 1, // 0xb44
+	 1, // 0xb45
+	 1, // 0xb46
+	 1, // 0xb47
+	 1, // 0xb48
+	 1, // 0xb49
+	 1, // 0xb4a
+	 1, // 0xb4b
+	 1, // 0xb4c
+	 1, // 0xb4d
+	 1, // 0xb4e
+	 1, // 0xb4f
+	 1, // 0xb50
+	 1, // 0xb51
+	 1, // 0xb52
+	 1, // 0xb53
+	 1, // 0xb54
+	 1, // 0xb55
+	 1, // 0xb56
+	 1, // 0xb57
+	 1, // 0xb58
+	 1, // 0xb59
+	 1, // 0xb5a
+	 1, // 0xb5b
+	 1, // 0xb5c
+	 1, // 0xb5d
+	 1, // 0xb5e
+	 1, // 0xb5f
+	 1, // 0xb60
+	 1, // 0xb61
+	 1, // 0xb62
+	 1, // 0xb63
+	 1, // 0xb64
+	 1, // 0xb65
+	 1, // 0xb66
+	 1, // 0xb67
+	 1, // 0xb68
+	 1, // 0xb69
+	 1, // 0xb6a
+	 1, // 0xb6b
+	 1, // 0xb6c
+	 1, // 0xb6d
+	 1, // 0xb6e
+	 1, // 0xb6f
+	 1, // 0xb70
+	 1, // 0xb71
+	 1, // 0xb72
+	 1, // 0xb73
+	 1, // 0xb74
+	 1, // 0xb75
+	 1, // 0xb76
+	 1, // 0xb77
+	 1, // 0xb78
+	 1, // 0xb79
+	 1, // 0xb7a
+	 1, // 0xb7b
+	 1, // 0xb7c
+	 1, // 0xb7d
+	 1, // 0xb7e
+	 1, // 0xb7f
+	 1, // 0xb80
+	 1, // 0xb81
+	 1, // 0xb82
+	 1, // 0xb83
+	 1, // 0xb84
+	 1, // 0xb85
+	 1, // 0xb86
+	 1, // 0xb87
+	 1, // 0xb88
+	 1, // 0xb89
+	 1, // 0xb8a
+	 1, // 0xb8b
+	 1, // 0xb8c
+	 1, // 0xb8d
+	 1, // 0xb8e
+	 1, // 0xb8f
+	 1, // 0xb90
+	 1, // 0xb91
+	 1, // 0xb92
+	 1, // 0xb93
+	 1, // 0xb94
+	 1, // 0xb95
+	 1, // 0xb96
+	 1, // 0xb97
+	 1, // 0xb98
+	 1, // 0xb99
+	 1, // 0xb9a
+	 1, // 0xb9b
+	 1, // 0xb9c
+	 1, // 0xb9d
+	 1, // 0xb9e
+	 1, // 0xb9f
+	 1, // 0xba0
+	 1, // 0xba1
+	 1, // 0xba2
+	 1, // 0xba3
+	 1, // 0xba4
+	 1, // 0xba5
+	 1, // 0xba6
+	 1, // 0xba7
+	 1, // 0xba8
+	 1, // 0xba9
+	 1, // 0xbaa
+	 1, // 0xbab
+	 1, // 0xbac
+	 1, // 0xbad
+	 1, // 0xbae
+	 1, // 0xbaf
+	 1, // 0xbb0
+	 1, // 0xbb1
+	 1, // 0xbb2
+	 1, // 0xbb3
+	 1, // 0xbb4
+	 1, // 0xbb5
+	 1, // 0xbb6
+	 1, // 0xbb7
+	 1, // 0xbb8
+	 1, // 0xbb9
+	 1, // 0xbba
+	 1, // 0xbbb
+	 1, // 0xbbc
+	 1, // 0xbbd
+	 1, // 0xbbe
+	 1, // 0xbbf
+	 1, // 0xbc0
+	 1, // 0xbc1
+	 1, // 0xbc2
+	 1, // 0xbc3
+	 1, // 0xbc4
+	 1, // 0xbc5
+	 1, // 0xbc6
+	 1, // 0xbc7
+	 1, // 0xbc8
+	 1, // 0xbc9
+	 1, // 0xbca
+	 1, // 0xbcb
+	 1, // 0xbcc
+	 1, // 0xbcd
+	 1, // 0xbce
+	 1, // 0xbcf
+	 1, // 0xbd0
+	 1, // 0xbd1
+	 1, // 0xbd2
+	 1, // 0xbd3
+	 1, // 0xbd4
+	 1, // 0xbd5
+	 1, // 0xbd6
+	 1, // 0xbd7
+	 1, // 0xbd8
+	 1, // 0xbd9
+	 1, // 0xbda
+	 1, // 0xbdb
+	 1, // 0xbdc
+	 1, // 0xbdd
+	 1, // 0xbde
+	 1, // 0xbdf
+	 1, // 0xbe0
+	 1, // 0xbe1
+	 1, // 0xbe2
+	 1, // 0xbe3
+	 1, // 0xbe4
+	 1, // 0xbe5
+	 1, // 0xbe6
+	 1, // 0xbe7
+	 1, // 0xbe8
+	 1, // 0xbe9
+	 1, // 0xbea
+	 1, // 0xbeb
+	 1, // 0xbec
+	 1, // 0xbed
+	 1, // 0xbee
+	 1, // 0xbef
+	 1, // 0xbf0
+	 1, // 0xbf1
+	 1, // 0xbf2
+	 1, // 0xbf3
+	 1, // 0xbf4
+	 1, // 0xbf5
+	 1, // 0xbf6
+	 1, // 0xbf7
+	 1, // 0xbf8
+	 1, // 0xbf9
+	 1, // 0xbfa
+	 1, // 0xbfb
+	 1, // 0xbfc
+	 1, // 0xbfd
+	 1, // 0xbfe
+	 1, // 0xbff
+	 1, // 0xc00
+	 1, // 0xc01
+	 1, // 0xc02
+	 1, // 0xc03
+	 1, // 0xc04
+	 1, // 0xc05
+	 1, // 0xc06
+	 1, // 0xc07
+	 1, // 0xc08
+	 1, // 0xc09
+	 1, // 0xc0a
+	 1, // 0xc0b
+	 1, // 0xc0c
+	 1, // 0xc0d
+	 1, // 0xc0e
+	 1, // 0xc0f
+	 1, // 0xc10
+	 1, // 0xc11
+	 1, // 0xc12
+	 1, // 0xc13
+	 1, // 0xc14
+	 1, // 0xc15
+	 1, // 0xc16
+	 1, // 0xc17
+	 1, // 0xc18
+	 1, // 0xc19
+	 1, // 0xc1a
+	 1, // 0xc1b
+	 1, // 0xc1c
+	 1, // 0xc1d
+	 1, // 0xc1e
+	 1, // 0xc1f
+	 1, // 0xc20
+	 1, // 0xc21
+	 1, // 0xc22
+	 1, // 0xc23
+	 1, // 0xc24
+	 1, // 0xc25
+	 1, // 0xc26
+	 1, // 0xc27
+	 1, // 0xc28
+	 1, // 0xc29
+	 1, // 0xc2a
+	 1, // 0xc2b
+	 1, // 0xc2c
+	 1, // 0xc2d
+	 1, // 0xc2e
+	 1, // 0xc2f
+	 1, // 0xc30
+	 1, // 0xc31
+	 1, // 0xc32
+	 1, // 0xc33
+	 1, // 0xc34
+	 1, // 0xc35
+	 1, // 0xc36
+	 1, // 0xc37
+	 1, // 0xc38
+	 1, // 0xc39
+	 1, // 0xc3a
+	 1, // 0xc3b
+	 1, // 0xc3c
+	 1, // 0xc3d
+	 1, // 0xc3e
+	 1, // 0xc3f
+	 1, // 0xc40
+	 1, // 0xc41
+	 1, // 0xc42
+	 1, // 0xc43
+	 1, // 0xc44
+	 1, // 0xc45
+	 1, // 0xc46
+	 1, // 0xc47
+	 1, // 0xc48
+	 1, // 0xc49
+	 1, // 0xc4a
+	 1, // 0xc4b
+	 1, // 0xc4c
+	 1, // 0xc4d
+	 1, // 0xc4e
+	 1, // 0xc4f
+	 1, // 0xc50
+	 1, // 0xc51
+	 1, // 0xc52
+	 1, // 0xc53
+	 1, // 0xc54
+	 1, // 0xc55
+	 1, // 0xc56
+	 1, // 0xc57
+	 1, // 0xc58
+	 1, // 0xc59
+	 1, // 0xc5a
+	 1, // 0xc5b
+	 1, // 0xc5c
+	 1, // 0xc5d
+	 1, // 0xc5e
+	 1, // 0xc5f
+	 1, // 0xc60
+	 1, // 0xc61
+	 1, // 0xc62
+	 1, // 0xc63
+	 1, // 0xc64
+	 1, // 0xc65
+	 1, // 0xc66
+	 1, // 0xc67
+	 1, // 0xc68
+	 1, // 0xc69
+	 1, // 0xc6a
+	 1, // 0xc6b
+	 1, // 0xc6c
+	 1, // 0xc6d
+	 1, // 0xc6e
+	 1, // 0xc6f
+	 1, // 0xc70
+	 1, // 0xc71
+	 1, // 0xc72
+	 1, // 0xc73
+	 1, // 0xc74
+	 1, // 0xc75
+	 1, // 0xc76
+	 1, // 0xc77
+	 1, // 0xc78
+	 1, // 0xc79
+	 1, // 0xc7a
+	 1, // 0xc7b
+	 1, // 0xc7c
+	 1, // 0xc7d
+	 1, // 0xc7e
+	 1, // 0xc7f
+	 1, // 0xc80
+	 1, // 0xc81
+	 1, // 0xc82
+	 1, // 0xc83
+	 1, // 0xc84
+	 1, // 0xc85
+	 1, // 0xc86
+	 1, // 0xc87
+	 1, // 0xc88
+	 1, // 0xc89
+	 1, // 0xc8a
+	 1, // 0xc8b
+	 1, // 0xc8c
+	 1, // 0xc8d
+	 1, // 0xc8e
+	 1, // 0xc8f
+	 1, // 0xc90
+	 1, // 0xc91
+	 1, // 0xc92
+	 1, // 0xc93
+	 1, // 0xc94
+	 1, // 0xc95
+	 1, // 0xc96
+	 1, // 0xc97
+	 1, // 0xc98
+	 1, // 0xc99
+	 1, // 0xc9a
+	 1, // 0xc9b
+	 1, // 0xc9c
+	 1, // 0xc9d
+	 1, // 0xc9e
+	 1, // 0xc9f
+	 1, // 0xca0
+	 1, // 0xca1
+	 1, // 0xca2
+	 1, // 0xca3
+	 1, // 0xca4
+	 1, // 0xca5
+	 1, // 0xca6
+	 1, // 0xca7
+	 1, // 0xca8
+	 1, // 0xca9
+	 1, // 0xcaa
+	 1, // 0xcab
+	 1, // 0xcac
+	 1, // 0xcad
+	 1, // 0xcae
+	 1, // 0xcaf
+	 1, // 0xcb0
+	 1, // 0xcb1
+	 1, // 0xcb2
+	 1, // 0xcb3
+	 1, // 0xcb4
+	 1, // 0xcb5
+	 1, // 0xcb6
+	 1, // 0xcb7
+	 1, // 0xcb8
+	 1, // 0xcb9
+	 1, // 0xcba
+	 1, // 0xcbb
+	 1, // 0xcbc
+	 1, // 0xcbd
+	 1, // 0xcbe
+	 1, // 0xcbf
+	 1, // 0xcc0
+	 1, // 0xcc1
+	 1, // 0xcc2
+	 1, // 0xcc3
+	 1, // 0xcc4
+	 1, // 0xcc5
+	 1, // 0xcc6
+	 1, // 0xcc7
+	 1, // 0xcc8
+	 1, // 0xcc9
+	 1, // 0xcca
+	 1, // 0xccb
+	 1, // 0xccc
+	 1, // 0xccd
+	 1, // 0xcce
+	 1, // 0xccf
+	 1, // 0xcd0
+	 1, // 0xcd1
+	 1, // 0xcd2
+	 1, // 0xcd3
+	 1, // 0xcd4
+	 1, // 0xcd5
+	 1, // 0xcd6
+	 1, // 0xcd7
+	 1, // 0xcd8
+	 1, // 0xcd9
+	 1, // 0xcda
+	 1, // 0xcdb
+	 1, // 0xcdc
+	 1, // 0xcdd
+	 1, // 0xcde
+	 1, // 0xcdf
+	 1, // 0xce0
+	 1, // 0xce1
+	 1, // 0xce2
+	 1, // 0xce3
+	 1, // 0xce4
+	 1, // 0xce5
+	 1, // 0xce6
+	 1, // 0xce7
+	 1, // 0xce8
+	 1, // 0xce9
+	 1, // 0xcea
+	 1, // 0xceb
+	 1, // 0xcec
+	 1, // 0xced
+	 1, // 0xcee
+	 1, // 0xcef
+	 1, // 0xcf0
+	 1, // 0xcf1
+	 1, // 0xcf2
+	 1, // 0xcf3
+	 1, // 0xcf4
+	 1, // 0xcf5
+	 1, // 0xcf6
+	 1, // 0xcf7
+	 1, // 0xcf8
+	 1, // 0xcf9
+	 1, // 0xcfa
+	 1, // 0xcfb
+	 1, // 0xcfc
+	 1, // 0xcfd
+	 1, // 0xcfe
+	 1, // 0xcff
+	 1, // 0xd00
+	 1, // 0xd01
+	 1, // 0xd02
+	 1, // 0xd03
+	 1, // 0xd04
+	 1, // 0xd05
+	 1, // 0xd06
+	 1, // 0xd07
+	 1, // 0xd08
+	 1, // 0xd09
+	 1, // 0xd0a
+	 1, // 0xd0b
+	 1, // 0xd0c
+	 1, // 0xd0d
+	 1, // 0xd0e
+	 1, // 0xd0f
+	 1, // 0xd10
+	 1, // 0xd11
+	 1, // 0xd12
+	 1, // 0xd13
+	 1, // 0xd14
+	 1, // 0xd15
+	 1, // 0xd16
+	 1, // 0xd17
+	 1, // 0xd18
+	 1, // 0xd19
+	 1, // 0xd1a
+	 1, // 0xd1b
+	 1, // 0xd1c
+	 1, // 0xd1d
+	 1, // 0xd1e
+	 1, // 0xd1f
+	 1, // 0xd20
+	 1, // 0xd21
+	 1, // 0xd22
+	 1, // 0xd23
+	 1, // 0xd24
+	 1, // 0xd25
+	 1, // 0xd26
+	 1, // 0xd27
+	 1, // 0xd28
+	 1, // 0xd29
+	 1, // 0xd2a
+	 1, // 0xd2b
+	 1, // 0xd2c
+	 1, // 0xd2d
+	 1, // 0xd2e
+	 1, // 0xd2f
+	 1, // 0xd30
+	 1, // 0xd31
+	 1, // 0xd32
+	 1, // 0xd33
+	 1, // 0xd34
+	 1, // 0xd35
+	 1, // 0xd36
+	 1, // 0xd37
+	 1, // 0xd38
+	 1, // 0xd39
+	 1, // 0xd3a
+	 1, // 0xd3b
+	 1, // 0xd3c
+	 1, // 0xd3d
+	 1, // 0xd3e
+	 1, // 0xd3f
+	 1, // 0xd40
+	 1, // 0xd41
+	 1, // 0xd42
+	 1, // 0xd43
+	 1, // 0xd44
+	 1, // 0xd45
+	 1, // 0xd46
+	 1, // 0xd47
+	 1, // 0xd48
+	 1, // 0xd49
+	 1, // 0xd4a
+	 1, // 0xd4b
+	 1, // 0xd4c
+	 1, // 0xd4d
+	 1, // 0xd4e
+	 1, // 0xd4f
+	 1, // 0xd50
+	 1, // 0xd51
+	 1, // 0xd52
+	 1, // 0xd53
+	 1, // 0xd54
+	 1, // 0xd55
+	 1, // 0xd56
+	 1, // 0xd57
+	 1, // 0xd58
+	 1, // 0xd59
+	 1, // 0xd5a
+	 1, // 0xd5b
+	 1, // 0xd5c
+	 1, // 0xd5d
+	 1, // 0xd5e
+	 1, // 0xd5f
+	 1, // 0xd60
+	 1, // 0xd61
+	 1, // 0xd62
+	 1, // 0xd63
+	 1, // 0xd64
+	 1, // 0xd65
+	 1, // 0xd66
+	 1, // 0xd67
+	 1, // 0xd68
+	 1, // 0xd69
+	 1, // 0xd6a
+	 1, // 0xd6b
+	 1, // 0xd6c
+	 1, // 0xd6d
+	 1, // 0xd6e
+	 1, // 0xd6f
+	 1, // 0xd70
+	 1, // 0xd71
+	 1, // 0xd72
+	 1, // 0xd73
+	 1, // 0xd74
+	 1, // 0xd75
+	 1, // 0xd76
+	 1, // 0xd77
+	 1, // 0xd78
+	 1, // 0xd79
+	 1, // 0xd7a
+	 1, // 0xd7b
+	 1, // 0xd7c
+	 1, // 0xd7d
+	 1, // 0xd7e
+	 1, // 0xd7f
+	 1, // 0xd80
+	 1, // 0xd81
+	 1, // 0xd82
+	 1, // 0xd83
+	 1, // 0xd84
+	 1, // 0xd85
+	 1, // 0xd86
+	 1, // 0xd87
+	 1, // 0xd88
+	 1, // 0xd89
+	 1, // 0xd8a
+	 1, // 0xd8b
+	 1, // 0xd8c
+	 1, // 0xd8d
+	 1, // 0xd8e
+	 1, // 0xd8f
+	 1, // 0xd90
+	 1, // 0xd91
+	 1, // 0xd92
+	 1, // 0xd93
+	 1, // 0xd94
+	 1, // 0xd95
+	 1, // 0xd96
+	 1, // 0xd97
+	 1, // 0xd98
+	 1, // 0xd99
+	 1, // 0xd9a
+	 1, // 0xd9b
+	 1, // 0xd9c
+	 1, // 0xd9d
+	 1, // 0xd9e
+	 1, // 0xd9f
+	 1, // 0xda0
+	 1, // 0xda1
+	 1, // 0xda2
+	 1, // 0xda3
+	 1, // 0xda4
+	 1, // 0xda5
+	 1, // 0xda6
+	 1, // 0xda7
+	 1, // 0xda8
+	 1, // 0xda9
+	 1, // 0xdaa
+	 1, // 0xdab
+	 1, // 0xdac
+	 1, // 0xdad
+	 1, // 0xdae
+	 1, // 0xdaf
+	 1, // 0xdb0
+	 1, // 0xdb1
+	 1, // 0xdb2
+	 1, // 0xdb3
+	 1, // 0xdb4
+	 1, // 0xdb5
+	 1, // 0xdb6
+	 1, // 0xdb7
+	 1, // 0xdb8
+	 1, // 0xdb9
+	 1, // 0xdba
+	 1, // 0xdbb
+	 1, // 0xdbc
+	 1, // 0xdbd
+	 1, // 0xdbe
+	 1, // 0xdbf
+	 1, // 0xdc0
+	 1, // 0xdc1
+	 1, // 0xdc2
+	 1, // 0xdc3
+	 1, // 0xdc4
+	 1, // 0xdc5
+	 1, // 0xdc6
+	 1, // 0xdc7
+	 1, // 0xdc8
+	 1, // 0xdc9
+	 1, // 0xdca
+	 1, // 0xdcb
+	 1, // 0xdcc
+	 1, // 0xdcd
+	 1, // 0xdce
+	 1, // 0xdcf
+	 1, // 0xdd0
+	 1, // 0xdd1
+	 1, // 0xdd2
+	 1, // 0xdd3
+	 1, // 0xdd4
+	 1, // 0xdd5
+	 1, // 0xdd6
+	 1, // 0xdd7
+	 1, // 0xdd8
+	 1, // 0xdd9
+	 1, // 0xdda
+	 1, // 0xddb
+	 1, // 0xddc
+	 1, // 0xddd
+	 1, // 0xdde
+	 1, // 0xddf
+	 1, // 0xde0
+	 1, // 0xde1
+	 1, // 0xde2
+	 1, // 0xde3
+	 1, // 0xde4
+	 1, // 0xde5
+	 1, // 0xde6
+	 1, // 0xde7
+	 1, // 0xde8
+	 1, // 0xde9
+	 1, // 0xdea
+	 1, // 0xdeb
+	 1, // 0xdec
+	 1, // 0xded
+	 1, // 0xdee
+	 1, // 0xdef
+	 1, // 0xdf0
+	 1, // 0xdf1
+	 1, // 0xdf2
+	 1, // 0xdf3
+	 1, // 0xdf4
+	 1, // 0xdf5
+	 1, // 0xdf6
+	 1, // 0xdf7
+	 1, // 0xdf8
+	 1, // 0xdf9
+	 1, // 0xdfa
+	 1, // 0xdfb
+	 1, // 0xdfc
+	 1, // 0xdfd
+	 1, // 0xdfe
+	 1, // 0xdff
+	 1, // 0xe00
+	 1, // 0xe01
+	 1, // 0xe02
+	 1, // 0xe03
+	 1, // 0xe04
+	 1, // 0xe05
+	 1, // 0xe06
+	 1, // 0xe07
+	 1, // 0xe08
+	 1, // 0xe09
+	 1, // 0xe0a
+	 1, // 0xe0b
+	 1, // 0xe0c
+	 1, // 0xe0d
+	 1, // 0xe0e
+	 1, // 0xe0f
+	 1, // 0xe10
+	 1, // 0xe11
+	 1, // 0xe12
+	 1, // 0xe13
+	 1, // 0xe14
+	 1, // 0xe15
+	 1, // 0xe16
+	 1, // 0xe17
+	 1, // 0xe18
+	 1, // 0xe19
+	 1, // 0xe1a
+	 1, // 0xe1b
+	 1, // 0xe1c
+	 1, // 0xe1d
+	 1, // 0xe1e
+	 1, // 0xe1f
+	 1, // 0xe20
+	 1, // 0xe21
+	 1, // 0xe22
+	 1, // 0xe23
+	 1, // 0xe24
+	 1, // 0xe25
+	 1, // 0xe26
+	 1, // 0xe27
+	 1, // 0xe28
+	 1, // 0xe29
+	 1, // 0xe2a
+	 1, // 0xe2b
+	 1, // 0xe2c
+	 1, // 0xe2d
+	 1, // 0xe2e
+	 1, // 0xe2f
+	 1, // 0xe30
+	 1, // 0xe31
+	 1, // 0xe32
+	 1, // 0xe33
+	 1, // 0xe34
+	 1, // 0xe35
+	 1, // 0xe36
+	 1, // 0xe37
+	 1, // 0xe38
+	 1, // 0xe39
+	 1, // 0xe3a
+	 1, // 0xe3b
+	 1, // 0xe3c
+	 1, // 0xe3d
+	 1, // 0xe3e
+	 1, // 0xe3f
+	 1, // 0xe40
+	 1, // 0xe41
+	 1, // 0xe42
+	 1, // 0xe43
+	 1, // 0xe44
+	 1, // 0xe45
+	 1, // 0xe46
+	 1, // 0xe47
+	 1, // 0xe48
+	 1, // 0xe49
+	 1, // 0xe4a
+	 1, // 0xe4b
+	 1, // 0xe4c
+	 1, // 0xe4d
+	 1, // 0xe4e
+	 1, // 0xe4f
+	 1, // 0xe50
+	 1, // 0xe51
+	 1, // 0xe52
+	 1, // 0xe53
+	 1, // 0xe54
+	 1, // 0xe55
+	 1, // 0xe56
+	 1, // 0xe57
+	 1, // 0xe58
+	 1, // 0xe59
+	 1, // 0xe5a
+	 1, // 0xe5b
+	 1, // 0xe5c
+	 1, // 0xe5d
+	 1, // 0xe5e
+	 1, // 0xe5f
+	 1, // 0xe60
+	 1, // 0xe61
+	 1, // 0xe62
+	 1, // 0xe63
+	 1, // 0xe64
+	 1, // 0xe65
+	 1, // 0xe66
+	 1, // 0xe67
+	 1, // 0xe68
+	 1, // 0xe69
+	 1, // 0xe6a
+	 1, // 0xe6b
+	 1, // 0xe6c
+	 1, // 0xe6d
+	 1, // 0xe6e
+	 1, // 0xe6f
+	 1, // 0xe70
+	 1, // 0xe71
+	 1, // 0xe72
+	 1, // 0xe73
+	 1, // 0xe74
+	 1, // 0xe75
+	 1, // 0xe76
+	 1, // 0xe77
+	 1, // 0xe78
+	 1, // 0xe79
+	 1, // 0xe7a
+	 1, // 0xe7b
+	 1, // 0xe7c
+	 1, // 0xe7d
+	 1, // 0xe7e
+	 1, // 0xe7f
+	 1, // 0xe80
+	 1, // 0xe81
+	 1, // 0xe82
+	 1, // 0xe83
+	 1, // 0xe84
+	 1, // 0xe85
+	 1, // 0xe86
+	 1, // 0xe87
+	 1, // 0xe88
+	 1, // 0xe89
+	 1, // 0xe8a
+	 1, // 0xe8b
+	 1, // 0xe8c
+	 1, // 0xe8d
+	 1, // 0xe8e
+	 1, // 0xe8f
+	 1, // 0xe90
+	 1, // 0xe91
+	 1, // 0xe92
+	 1, // 0xe93
+	 1, // 0xe94
+	 1, // 0xe95
+	 1, // 0xe96
+	 1, // 0xe97
+	 1, // 0xe98
+	 1, // 0xe99
+	 1, // 0xe9a
+	 1, // 0xe9b
+	 1, // 0xe9c
+	 1, // 0xe9d
+	 1, // 0xe9e
+	 1, // 0xe9f
+	 1, // 0xea0
+	 1, // 0xea1
+	 1, // 0xea2
+	 1, // 0xea3
+	 1, // 0xea4
+	 1, // 0xea5
+	 1, // 0xea6
+	 1, // 0xea7
+	 1, // 0xea8
+	 1, // 0xea9
+	 1, // 0xeaa
+	 1, // 0xeab
+	 1, // 0xeac
+	 1, // 0xead
+	 1, // 0xeae
+	 1, // 0xeaf
+	 1, // 0xeb0
+	 1, // 0xeb1
+	 1, // 0xeb2
+	 1, // 0xeb3
+	 1, // 0xeb4
+	 1, // 0xeb5
+	 1, // 0xeb6
+	 1, // 0xeb7
+	 1, // 0xeb8
+	 1, // 0xeb9
+	 1, // 0xeba
+	 1, // 0xebb
+	 1, // 0xebc
+	 1, // 0xebd
+	 1, // 0xebe
+	 1, // 0xebf
+	 1, // 0xec0
+	 1, // 0xec1
+	 1, // 0xec2
+	 1, // 0xec3
+	 1, // 0xec4
+	 1, // 0xec5
+	 1, // 0xec6
+	 1, // 0xec7
+	 1, // 0xec8
+	 1, // 0xec9
+	 1, // 0xeca
+	 1, // 0xecb
+	 1, // 0xecc
+	 1, // 0xecd
+	 1, // 0xece
+	 1, // 0xecf
+	 1, // 0xed0
+	 1, // 0xed1
+	 1, // 0xed2
+	 1, // 0xed3
+	 1, // 0xed4
+	 1, // 0xed5
+	 1, // 0xed6
+	 1, // 0xed7
+	 1, // 0xed8
+	 1, // 0xed9
+	 1, // 0xeda
+	 1, // 0xedb
+	 1, // 0xedc
+	 1, // 0xedd
+	 1, // 0xede
+	 1, // 0xedf
+	 1, // 0xee0
+	 1, // 0xee1
+	 1, // 0xee2
+	 1, // 0xee3
+	 1, // 0xee4
+	 1, // 0xee5
+	 1, // 0xee6
+	 1, // 0xee7
+	 1, // 0xee8
+	 1, // 0xee9
+	 1, // 0xeea
+	 1, // 0xeeb
+	 1, // 0xeec
+	 1, // 0xeed
+	 1, // 0xeee
+	 1, // 0xeef
+	 1, // 0xef0
+	 1, // 0xef1
+	 1, // 0xef2
+	 1, // 0xef3
+	 1, // 0xef4
+	 1, // 0xef5
+	 1, // 0xef6
+	 1, // 0xef7
+	 1, // 0xef8
+	 1, // 0xef9
+	 1, // 0xefa
+	 1, // 0xefb
+	 1, // 0xefc
+	 1, // 0xefd
+	 1, // 0xefe
+	 1, // 0xeff
+	 1, // 0xf00
+	 1, // 0xf01
+	 1, // 0xf02
+	 1, // 0xf03
+	 1, // 0xf04
+	 1, // 0xf05
+	 1, // 0xf06
+	 1, // 0xf07
+	 1, // 0xf08
+	 1, // 0xf09
+	 1, // 0xf0a
+	 1, // 0xf0b
+	 1, // 0xf0c
+	 1, // 0xf0d
+	 1, // 0xf0e
+	 1, // 0xf0f
+	 1, // 0xf10
+	 1, // 0xf11
+	 1, // 0xf12
+	 1, // 0xf13
+	 1, // 0xf14
+	 1, // 0xf15
+	 1, // 0xf16
+	 1, // 0xf17
+	 1, // 0xf18
+	 1, // 0xf19
+	 1, // 0xf1a
+	 1, // 0xf1b
+	 1, // 0xf1c
+	 1, // 0xf1d
+	 1, // 0xf1e
+	 1, // 0xf1f
+	 1, // 0xf20
+	 1, // 0xf21
+	 1, // 0xf22
+	 1, // 0xf23
+	 1, // 0xf24
+	 1, // 0xf25
+	 1, // 0xf26
+	 1, // 0xf27
+	 1, // 0xf28
+	 1, // 0xf29
+	 1, // 0xf2a
+	 1, // 0xf2b
+	 1, // 0xf2c
+	 1, // 0xf2d
+	 1, // 0xf2e
+	 1, // 0xf2f
+	 1, // 0xf30
+	 1, // 0xf31
+	 1, // 0xf32
+	 1, // 0xf33
+	 1, // 0xf34
+	 1, // 0xf35
+	 1, // 0xf36
+	 1, // 0xf37
+	 1, // 0xf38
+	 1, // 0xf39
+	 1, // 0xf3a
+	 1, // 0xf3b
+	 1, // 0xf3c
+	 1, // 0xf3d
+	 1, // 0xf3e
+	 1, // 0xf3f
+	 1, // 0xf40
+	 1, // 0xf41
+	 1, // 0xf42
+	 1, // 0xf43
+	 1, // 0xf44
+	 1, // 0xf45
+	 1, // 0xf46
+	 1, // 0xf47
+	 1, // 0xf48
+	 1, // 0xf49
+	 1, // 0xf4a
+	 1, // 0xf4b
+	 1, // 0xf4c
+	 1, // 0xf4d
+	 1, // 0xf4e
+	 1, // 0xf4f
+	 1, // 0xf50
+	 1, // 0xf51
+	 1, // 0xf52
+	 1, // 0xf53
+	 1, // 0xf54
+	 1, // 0xf55
+	 1, // 0xf56
+	 1, // 0xf57
+	 1, // 0xf58
+	 1, // 0xf59
+	 1, // 0xf5a
+	 1, // 0xf5b
+	 1, // 0xf5c
+	 1, // 0xf5d
+	 1, // 0xf5e
+	 1, // 0xf5f
+	 1, // 0xf60
+	 1, // 0xf61
+	 1, // 0xf62
+	 1, // 0xf63
+	 1, // 0xf64
+	 1, // 0xf65
+	 1, // 0xf66
+	 1, // 0xf67
+	 1, // 0xf68
+	 1, // 0xf69
+	 1, // 0xf6a
+	 1, // 0xf6b
+	 1, // 0xf6c
+	 1, // 0xf6d
+	 1, // 0xf6e
+	 1, // 0xf6f
+	 1, // 0xf70
+	 1, // 0xf71
+	 1, // 0xf72
+	 1, // 0xf73
+	 1, // 0xf74
+	 1, // 0xf75
+	 1, // 0xf76
+	 1, // 0xf77
+	 1, // 0xf78
+	 1, // 0xf79
+	 1, // 0xf7a
+	 1, // 0xf7b
+	 1, // 0xf7c
+	 1, // 0xf7d
+	 1, // 0xf7e
+	 1, // 0xf7f
+	 1, // 0xf80
+	 1, // 0xf81
+	 1, // 0xf82
+	 1, // 0xf83
+	 1, // 0xf84
+	 1, // 0xf85
+	 1, // 0xf86
+	 1, // 0xf87
+	 1, // 0xf88
+	 1, // 0xf89
+	 1, // 0xf8a
+	 1, // 0xf8b
+	 1, // 0xf8c
+	 1, // 0xf8d
+	 1, // 0xf8e
+	 1, // 0xf8f
+	 1, // 0xf90
+	 1, // 0xf91
+	 1, // 0xf92
+	 1, // 0xf93
+	 1, // 0xf94
+	 1, // 0xf95
+	 1, // 0xf96
+	 1, // 0xf97
+	 1, // 0xf98
+	 1, // 0xf99
+	 1, // 0xf9a
+	 1, // 0xf9b
+	 1, // 0xf9c
+	 1, // 0xf9d
+	 1, // 0xf9e
+	 1, // 0xf9f
+	 1, // 0xfa0
+	 1, // 0xfa1
+	 1, // 0xfa2
+	 1, // 0xfa3
+	 1, // 0xfa4
+	 1, // 0xfa5
+	 1, // 0xfa6
+	 1, // 0xfa7
+	 1, // 0xfa8
+	 1, // 0xfa9
+	 1, // 0xfaa
+	 1, // 0xfab
+	 1, // 0xfac
+	 1, // 0xfad
+	 1, // 0xfae
+	 1, // 0xfaf
+	 1, // 0xfb0
+	 1, // 0xfb1
+	 1, // 0xfb2
+	 1, // 0xfb3
+	 1, // 0xfb4
+	 1, // 0xfb5
+	 1, // 0xfb6
+	 1, // 0xfb7
+	 1, // 0xfb8
+	 1, // 0xfb9
+	 1, // 0xfba
+	 1, // 0xfbb
+	 1, // 0xfbc
+	 1, // 0xfbd
+	 1, // 0xfbe
+	 1, // 0xfbf
+	 1, // 0xfc0
+	 1, // 0xfc1
+	 1, // 0xfc2
+	 1, // 0xfc3
+	 1, // 0xfc4
+	 1, // 0xfc5
+	 1, // 0xfc6
+	 1, // 0xfc7
+	 1, // 0xfc8
+	 1, // 0xfc9
+	 1, // 0xfca
+	 1, // 0xfcb
+	 1, // 0xfcc
+	 1, // 0xfcd
+	 1, // 0xfce
+	 1, // 0xfcf
+	 1, // 0xfd0
+	 1, // 0xfd1
+	 1, // 0xfd2
+	 1, // 0xfd3
+	 1, // 0xfd4
+	 1, // 0xfd5
+	 1, // 0xfd6
+	 1, // 0xfd7
+	 1, // 0xfd8
+	 1, // 0xfd9
+	 1, // 0xfda
+	 1, // 0xfdb
+	 1, // 0xfdc
+	 1, // 0xfdd
+	 1, // 0xfde
+	 1, // 0xfdf
+	 1, // 0xfe0
+	 1, // 0xfe1
+	 1, // 0xfe2
+	 1, // 0xfe3
+	 1, // 0xfe4
+	 1, // 0xfe5
+	 1, // 0xfe6
+	 1, // 0xfe7
+	 1, // 0xfe8
+	 1, // 0xfe9
+	 1, // 0xfea
+	 1, // 0xfeb
+	 1, // 0xfec
+	 1, // 0xfed
+	 1, // 0xfee
+	 1, // 0xfef
+	 1, // 0xff0
+	 1, // 0xff1
+	 1, // 0xff2
+	 1, // 0xff3
+	 1, // 0xff4
+	 1, // 0xff5
+	 1, // 0xff6
+	 1, // 0xff7
+	 1, // 0xff8
+	 1, // 0xff9
+	 1, // 0xffa
+	 1, // 0xffb
+	 1, // 0xffc
+	 1, // 0xffd
+	 1, // 0xffe
+	 1, // 0xfff
+	 1, // 0x1000
+	 1, // 0x1001
+	 1, // 0x1002
+	 1, // 0x1003
+	 1, // 0x1004
+	 1, // 0x1005
+	 1, // 0x1006
+	 1, // 0x1007
+	 1, // 0x1008
+	 1, // 0x1009
+	 1, // 0x100a
+	 1, // 0x100b
+	 1, // 0x100c
+	 1, // 0x100d
+	 1, // 0x100e
+	 1, // 0x100f
+	 1, // 0x1010
+	 1, // 0x1011
+	 1, // 0x1012
+	 1, // 0x1013
+	 1, // 0x1014
+	 1, // 0x1015
+	 1, // 0x1016
+	 1, // 0x1017
+	 1, // 0x1018
+	 1, // 0x1019
+	 1, // 0x101a
+	 1, // 0x101b
+	 1, // 0x101c
+	 1, // 0x101d
+	 1, // 0x101e
+	 1, // 0x101f
+	 1, // 0x1020
+	 1, // 0x1021
+	 1, // 0x1022
+	 1, // 0x1023
+	 1, // 0x1024
+	 1, // 0x1025
+	 1, // 0x1026
+	 1, // 0x1027
+	 1, // 0x1028
+	 1, // 0x1029
+	 1, // 0x102a
+	 1, // 0x102b
+	 1, // 0x102c
+	 1, // 0x102d
+	 1, // 0x102e
+	 1, // 0x102f
+	 1, // 0x1030
+	 1, // 0x1031
+	 1, // 0x1032
+	 1, // 0x1033
+	 1, // 0x1034
+	 1, // 0x1035
+	 1, // 0x1036
+	 1, // 0x1037
+	 1, // 0x1038
+	 1, // 0x1039
+	 1, // 0x103a
+	 1, // 0x103b
+	 1, // 0x103c
+	 1, // 0x103d
+	 1, // 0x103e
+	 1, // 0x103f
+	 1, // 0x1040
+	 1, // 0x1041
+	 1, // 0x1042
+	 1, // 0x1043
+	 1, // 0x1044
+	 1, // 0x1045
+	 1, // 0x1046
+	 1, // 0x1047
+	 1, // 0x1048
+	 1, // 0x1049
+	 1, // 0x104a
+	 1, // 0x104b
+	 1, // 0x104c
+	 1, // 0x104d
+	 1, // 0x104e
+	 1, // 0x104f
+	 1, // 0x1050
+	 1, // 0x1051
+	 1, // 0x1052
+	 1, // 0x1053
+	 1, // 0x1054
+	 1, // 0x1055
+	 1, // 0x1056
+	 1, // 0x1057
+	 1, // 0x1058
+	 1, // 0x1059
+	 1, // 0x105a
+	 1, // 0x105b
+	 1, // 0x105c
+	 1, // 0x105d
+	 1, // 0x105e
+	 1, // 0x105f
+	 1, // 0x1060
+	 1, // 0x1061
+	 1, // 0x1062
+	 1, // 0x1063
+	 1, // 0x1064
+	 1, // 0x1065
+	 1, // 0x1066
+	 1, // 0x1067
+	 1, // 0x1068
+	 1, // 0x1069
+	 1, // 0x106a
+	 1, // 0x106b
+	 1, // 0x106c
+	 1, // 0x106d
+	 1, // 0x106e
+	 1, // 0x106f
+	 1, // 0x1070
+	 1, // 0x1071
+	 1, // 0x1072
+	 1, // 0x1073
+	 1, // 0x1074
+	 1, // 0x1075
+	 1, // 0x1076
+	 1, // 0x1077
+	 1, // 0x1078
+	 1, // 0x1079
+	 1, // 0x107a
+	 1, // 0x107b
+	 1, // 0x107c
+	 1, // 0x107d
+	 1, // 0x107e
+	 1, // 0x107f
+	 1, // 0x1080
+	 1, // 0x1081
+	 1, // 0x1082
+	 1, // 0x1083
+	 1, // 0x1084
+	 1, // 0x1085
+	 1, // 0x1086
+	 1, // 0x1087
+	 1, // 0x1088
+	 1, // 0x1089
+	 1, // 0x108a
+	 1, // 0x108b
+	 1, // 0x108c
+	 1, // 0x108d
+	 1, // 0x108e
+	 1, // 0x108f
+	 1, // 0x1090
+	 1, // 0x1091
+	 1, // 0x1092
+	 1, // 0x1093
+	 1, // 0x1094
+	 1, // 0x1095
+	 1, // 0x1096
+	 1, // 0x1097
+	 1, // 0x1098
+	 1, // 0x1099
+	 1, // 0x109a
+	 1, // 0x109b
+	 1, // 0x109c
+	 1, // 0x109d
+	 1, // 0x109e
+	 1, // 0x109f
+	 1, // 0x10a0
+	 1, // 0x10a1
+	 1, // 0x10a2
+	 1, // 0x10a3
+	 1, // 0x10a4
+	 1, // 0x10a5
+	 1, // 0x10a6
+	 1, // 0x10a7
+	 1, // 0x10a8
+	 1, // 0x10a9
+	 1, // 0x10aa
+	 1, // 0x10ab
+	 1, // 0x10ac
+	 1, // 0x10ad
+	 1, // 0x10ae
+	 1, // 0x10af
+	 1, // 0x10b0
+	 1, // 0x10b1
+	 1, // 0x10b2
+	 1, // 0x10b3
+	 1, // 0x10b4
+	 1, // 0x10b5
+	 1, // 0x10b6
+	 1, // 0x10b7
+	 1, // 0x10b8
+	 1, // 0x10b9
+	 1, // 0x10ba
+	 1, // 0x10bb
+	 1, // 0x10bc
+	 1, // 0x10bd
+	 1, // 0x10be
+	 1, // 0x10bf
+	 1, // 0x10c0
+	 1, // 0x10c1
+	 1, // 0x10c2
+	 1, // 0x10c3
+	 1, // 0x10c4
+	 1, // 0x10c5
+	 1, // 0x10c6
+	 1, // 0x10c7
+	 1, // 0x10c8
+	 1, // 0x10c9
+	 1, // 0x10ca
+	 1, // 0x10cb
+	 1, // 0x10cc
+	 1, // 0x10cd
+	 1, // 0x10ce
+	 1, // 0x10cf
+	 1, // 0x10d0
+	 1, // 0x10d1
+	 1, // 0x10d2
+	 1, // 0x10d3
+	 1, // 0x10d4
+	 1, // 0x10d5
+	 1, // 0x10d6
+	 1, // 0x10d7
+	 1, // 0x10d8
+	 1, // 0x10d9
+	 1, // 0x10da
+	 1, // 0x10db
+	 1, // 0x10dc
+	 1, // 0x10dd
+	 1, // 0x10de
+	 1, // 0x10df
+	 1, // 0x10e0
+	 1, // 0x10e1
+	 1, // 0x10e2
+	 1, // 0x10e3
+	 1, // 0x10e4
+	 1, // 0x10e5
+	 1, // 0x10e6
+	 1, // 0x10e7
+	 1, // 0x10e8
+	 1, // 0x10e9
+	 1, // 0x10ea
+	 1, // 0x10eb
+	 1, // 0x10ec
+	 1, // 0x10ed
+	 1, // 0x10ee
+	 1, // 0x10ef
+	 1, // 0x10f0
+	 1, // 0x10f1
+	 1, // 0x10f2
+	 1, // 0x10f3
+	 1, // 0x10f4
+	 1, // 0x10f5
+	 1, // 0x10f6
+	 1, // 0x10f7
+	 1, // 0x10f8
+	 1, // 0x10f9
+	 1, // 0x10fa
+	 1, // 0x10fb
+	 1, // 0x10fc
+	 1, // 0x10fd
+	 1, // 0x10fe
+	 1, // 0x10ff
+];
+pub const SCX_PAGES: [[u8;256];39] = [
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x4,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x5,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x6,
+        0x7,
+        0x8,
+        0x9,
+        0xa,
+        0xb,
+        0xc,
+        0xd,
+        0xe,
+        0xf,
+        0x10,
+        0x11,
+        0x12,
+        0x13,
+        0x14,
+        0x0,
+        0x13,
+        0x15,
+        0x0,
+        0x16,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x17,
+        0x18,
+        0x19,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1a,
+        0x19,
+        0x0,
+        0x1b,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1d,
+        0x0,
+        0x0,
+        0x1d,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1f,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x21,
+        0x21,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x22,
+        0x23,
+        0x24,
+        0x24,
+        0x23,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x25,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x26,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x26,
+        0x27,
+        0x0,
+        0x0,
+        0x28,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x29,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2a,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2d,
+        0x2e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2f,
+        0x30,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x38,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x39,
+        0x39,
+        0x39,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3a,
+        0x3a,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x3b,
+        0x3b,
+        0x0,
+        0x3b,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3c,
+        0x3d,
+        0x3c,
+        0x3e,
+        0x3d,
+        0x3f,
+        0x40,
+        0x41,
+        0x42,
+        0x43,
+        0x44,
+        0x3d,
+        0x43,
+        0x43,
+        0x3d,
+        0x3d,
+        0x43,
+        0x45,
+        0x46,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x47,
+        0x48,
+        0x49,
+        0x3d,
+        0x4a,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x4b,
+        0x4c,
+        0x4d,
+        0x45,
+        0x45,
+        0x4e,
+        0x4c,
+        0x4c,
+        0x4f,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1d,
+        0x1d,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x50,
+        0x0,
+        0x51,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x52,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x53,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x54,
+        0x0,
+        0x0,
+        0x55,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x56,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x57,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x58,
+        0x59,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x5a,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x5b,
+        0x0,
+        0x23,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+    ],
+    [
+        0x0,
+        0x5d,
+        0x5e,
+        0x5f,
+        0x0,
+        0x0,
+        0x60,
+        0x0,
+        0x61,
+        0x61,
+        0x62,
+        0x62,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x0,
+        0x5f,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x0,
+        0x0,
+        0x5f,
+        0x65,
+        0x65,
+        0x65,
+        0x65,
+        0x65,
+        0x0,
+        0x5f,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x66,
+        0x66,
+        0x60,
+        0x60,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x65,
+        0x65,
+        0x65,
+        0x65,
+        0x0,
+        0x0,
+        0x0,
+        0x65,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x63,
+        0x65,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x5c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x60,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x23,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x68,
+        0x68,
+        0x68,
+        0x69,
+        0x69,
+        0x69,
+        0x6a,
+        0x6a,
+        0x6b,
+        0x6a,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x6c,
+        0x0,
+        0x6d,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x6e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x6f,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x70,
+        0x70,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x71,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x71,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x5f,
+        0x5f,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x65,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x65,
+        0x65,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x72,
+        0x72,
+        0x73,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x0,
+        0x0,
+        0x0,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x76,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x35,
+        0x0,
+        0x35,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x35,
+        0x35,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x35,
+        0x35,
+        0x0,
+        0x35,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x5a,
+        0x5a,
+        0x5a,
+        0x5a,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x60,
+        0x60,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+];
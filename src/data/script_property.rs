@@ -0,0 +1,47185 @@
+// GENERATED CODE DO NOT MANUALLY EDIT
+pub const SC_TABLE: [u8;0x1100] = [
+	 0, // 0x0
+	 1, // 0x1
+	 2, // 0x2
+	 3, // 0x3
+	 4, // 0x4
+	 5, // 0x5
+	 6, // 0x6
+	 7, // 0x7
+	 8, // 0x8
+	 9, // 0x9
+	 10, // 0xa
+	 11, // 0xb
+	 12, // 0xc
+	 13, // 0xd
+	 14, // 0xe
+	 15, // 0xf
+	 16, // 0x10
+	 17, // 0x11
+	 18, // 0x12
+	 19, // 0x13
+	 20, // 0x14
+	 20, // 0x15
+	 21, // 0x16
+	 22, // 0x17
+	 23, // 0x18
+	 24, // 0x19
+	 25, // 0x1a
+	 26, // 0x1b
+	 27, // 0x1c
+	 28, // 0x1d
+	 1, // 0x1e
+	 29, // 0x1f
+	 30, // 0x20
+	 31, // 0x21
+	 32, // 0x22
+	 32, // 0x23
+	 33, // 0x24
+	 32, // 0x25
+	 32, // 0x26
+	 32, // 0x27
+	 34, // 0x28
+	 32, // 0x29
+	 32, // 0x2a
+	 35, // 0x2b
+	 36, // 0x2c
+	 37, // 0x2d
+	 38, // 0x2e
+	 39, // 0x2f
+	 40, // 0x30
+	 41, // 0x31
+	 42, // 0x32
+	 43, // 0x33
+	 44, // 0x34
+	 44, // 0x35
+	 44, // 0x36
+	 44, // 0x37
+	 44, // 0x38
+	 44, // 0x39
+	 44, // 0x3a
+	 44, // 0x3b
+	 44, // 0x3c
+	 44, // 0x3d
+	 44, // 0x3e
+	 44, // 0x3f
+	 44, // 0x40
+	 44, // 0x41
+	 44, // 0x42
+	 44, // 0x43
+	 44, // 0x44
+	 44, // 0x45
+	 44, // 0x46
+	 44, // 0x47
+	 44, // 0x48
+	 44, // 0x49
+	 44, // 0x4a
+	 44, // 0x4b
+	 44, // 0x4c
+	 45, // 0x4d
+	 44, // 0x4e
+	 44, // 0x4f
+	 44, // 0x50
+	 44, // 0x51
+	 44, // 0x52
+	 44, // 0x53
+	 44, // 0x54
+	 44, // 0x55
+	 44, // 0x56
+	 44, // 0x57
+	 44, // 0x58
+	 44, // 0x59
+	 44, // 0x5a
+	 44, // 0x5b
+	 44, // 0x5c
+	 44, // 0x5d
+	 44, // 0x5e
+	 44, // 0x5f
+	 44, // 0x60
+	 44, // 0x61
+	 44, // 0x62
+	 44, // 0x63
+	 44, // 0x64
+	 44, // 0x65
+	 44, // 0x66
+	 44, // 0x67
+	 44, // 0x68
+	 44, // 0x69
+	 44, // 0x6a
+	 44, // 0x6b
+	 44, // 0x6c
+	 44, // 0x6d
+	 44, // 0x6e
+	 44, // 0x6f
+	 44, // 0x70
+	 44, // 0x71
+	 44, // 0x72
+	 44, // 0x73
+	 44, // 0x74
+	 44, // 0x75
+	 44, // 0x76
+	 44, // 0x77
+	 44, // 0x78
+	 44, // 0x79
+	 44, // 0x7a
+	 44, // 0x7b
+	 44, // 0x7c
+	 44, // 0x7d
+	 44, // 0x7e
+	 44, // 0x7f
+	 44, // 0x80
+	 44, // 0x81
+	 44, // 0x82
+	 44, // 0x83
+	 44, // 0x84
+	 44, // 0x85
+	 44, // 0x86
+	 44, // 0x87
+	 44, // 0x88
+	 44, // 0x89
+	 44, // 0x8a
+	 44, // 0x8b
+	 44, // 0x8c
+	 44, // 0x8d
+	 44, // 0x8e
+	 44, // 0x8f
+	 44, // 0x90
+	 44, // 0x91
+	 44, // 0x92
+	 44, // 0x93
+	 44, // 0x94
+	 44, // 0x95
+	 44, // 0x96
+	 44, // 0x97
+	 44, // 0x98
+	 44, // 0x99
+	 44, // 0x9a
+	 44, // 0x9b
+	 44, // 0x9c
+	 44, // 0x9d
+	 44, // 0x9e
+	 44, // 0x9f
+	 46, // 0xa0
+	 46, // 0xa1
+	 46, // 0xa2
+	 46, // 0xa3
+	 47, // 0xa4
+	 48, // 0xa5
+	 49, // 0xa6
+	 50, // 0xa7
+	 51, // 0xa8
+	 52, // 0xa9
+	 53, // 0xaa
+	 54, // 0xab
+	 17, // 0xac
+	 17, // 0xad
+	 17, // 0xae
+	 17, // 0xaf
+	 17, // 0xb0
+	 17, // 0xb1
+	 17, // 0xb2
+	 17, // 0xb3
+	 17, // 0xb4
+	 17, // 0xb5
+	 17, // 0xb6
+	 17, // 0xb7
+	 17, // 0xb8
+	 17, // 0xb9
+	 17, // 0xba
+	 17, // 0xbb
+	 17, // 0xbc
+	 17, // 0xbd
+	 17, // 0xbe
+	 17, // 0xbf
+	 17, // 0xc0
+	 17, // 0xc1
+	 17, // 0xc2
+	 17, // 0xc3
+	 17, // 0xc4
+	 17, // 0xc5
+	 17, // 0xc6
+	 17, // 0xc7
+	 17, // 0xc8
+	 17, // 0xc9
+	 17, // 0xca
+	 17, // 0xcb
+	 17, // 0xcc
+	 17, // 0xcd
+	 17, // 0xce
+	 17, // 0xcf
+	 17, // 0xd0
+	 17, // 0xd1
+	 17, // 0xd2
+	 17, // 0xd3
+	 17, // 0xd4
+	 17, // 0xd5
+	 17, // 0xd6
+	 55, // 0xd7
+	 56, // 0xd8
+	 56, // 0xd9
+	 56, // 0xda
+	 56, // 0xdb
+	 56, // 0xdc
+	 56, // 0xdd
+	 56, // 0xde
+	 56, // 0xdf
+	 56, // 0xe0
+	 56, // 0xe1
+	 56, // 0xe2
+	 56, // 0xe3
+	 56, // 0xe4
+	 56, // 0xe5
+	 56, // 0xe6
+	 56, // 0xe7
+	 56, // 0xe8
+	 56, // 0xe9
+	 56, // 0xea
+	 56, // 0xeb
+	 56, // 0xec
+	 56, // 0xed
+	 56, // 0xee
+	 56, // 0xef
+	 56, // 0xf0
+	 56, // 0xf1
+	 56, // 0xf2
+	 56, // 0xf3
+	 56, // 0xf4
+	 56, // 0xf5
+	 56, // 0xf6
+	 56, // 0xf7
+	 56, // 0xf8
+	 44, // 0xf9
+	 57, // 0xfa
+	 58, // 0xfb
+	 59, // 0xfc
+	 60, // 0xfd
+	 61, // 0xfe
+	 62, // 0xff
+	 63, // 0x100
+	 64, // 0x101
+	 65, // 0x102
+	 66, // 0x103
+	 67, // 0x104
+	 68, // 0x105
+	 69, // 0x106
+	 70, // 0x107
+	 71, // 0x108
+	 72, // 0x109
+	 73, // 0x10a
+	 74, // 0x10b
+	 75, // 0x10c
+	 76, // 0x10d
+	 77, // 0x10e
+	 78, // 0x10f
+	 79, // 0x110
+	 80, // 0x111
+	 81, // 0x112
+	 82, // 0x113
+	 83, // 0x114
+	 84, // 0x115
+	 85, // 0x116
+	 86, // 0x117
+	 87, // 0x118
+	 88, // 0x119
+	 89, // 0x11a
+	 90, // 0x11b
+	 91, // 0x11c
+	 92, // 0x11d
+	 93, // 0x11e
+	 94, // 0x11f
+	 95, // 0x120
+	 95, // 0x121
+	 95, // 0x122
+	 96, // 0x123
+	 97, // 0x124
+	 98, // 0x125
+	 56, // 0x126
+	 56, // 0x127
+	 56, // 0x128
+	 56, // 0x129
+	 56, // 0x12a
+	 56, // 0x12b
+	 56, // 0x12c
+	 56, // 0x12d
+	 56, // 0x12e
+	 99, // 0x12f
+	 100, // 0x130
+	 100, // 0x131
+	 100, // 0x132
+	 100, // 0x133
+	 101, // 0x134
+	 100, // 0x135
+	 100, // 0x136
+	 100, // 0x137
+	 100, // 0x138
+	 100, // 0x139
+	 100, // 0x13a
+	 100, // 0x13b
+	 100, // 0x13c
+	 100, // 0x13d
+	 100, // 0x13e
+	 100, // 0x13f
+	 100, // 0x140
+	 100, // 0x141
+	 100, // 0x142
+	 102, // 0x143
+	 103, // 0x144
+	 103, // 0x145
+	 104, // 0x146
+	 56, // 0x147
+	 56, // 0x148
+	 56, // 0x149
+	 56, // 0x14a
+	 56, // 0x14b
+	 56, // 0x14c
+	 56, // 0x14d
+	 56, // 0x14e
+	 56, // 0x14f
+	 56, // 0x150
+	 56, // 0x151
+	 56, // 0x152
+	 56, // 0x153
+	 56, // 0x154
+	 56, // 0x155
+	 56, // 0x156
+	 56, // 0x157
+	 56, // 0x158
+	 56, // 0x159
+	 56, // 0x15a
+	 56, // 0x15b
+	 56, // 0x15c
+	 56, // 0x15d
+	 56, // 0x15e
+	 56, // 0x15f
+	 56, // 0x160
+	 105, // 0x161
+	 56, // 0x162
+	 56, // 0x163
+	 56, // 0x164
+	 56, // 0x165
+	 56, // 0x166
+	 56, // 0x167
+	 106, // 0x168
+	 106, // 0x169
+	 107, // 0x16a
+	 108, // 0x16b
+	 56, // 0x16c
+	 109, // 0x16d
+	 110, // 0x16e
+	 111, // 0x16f
+	 112, // 0x170
+	 112, // 0x171
+	 112, // 0x172
+	 112, // 0x173
+	 112, // 0x174
+	 112, // 0x175
+	 112, // 0x176
+	 112, // 0x177
+	 112, // 0x178
+	 112, // 0x179
+	 112, // 0x17a
+	 112, // 0x17b
+	 112, // 0x17c
+	 112, // 0x17d
+	 112, // 0x17e
+	 112, // 0x17f
+	 112, // 0x180
+	 112, // 0x181
+	 112, // 0x182
+	 112, // 0x183
+	 112, // 0x184
+	 112, // 0x185
+	 112, // 0x186
+	 113, // 0x187
+	 112, // 0x188
+	 112, // 0x189
+	 112, // 0x18a
+	 114, // 0x18b
+	 115, // 0x18c
+	 116, // 0x18d
+	 56, // 0x18e
+	 56, // 0x18f
+	 56, // 0x190
+	 56, // 0x191
+	 56, // 0x192
+	 56, // 0x193
+	 56, // 0x194
+	 56, // 0x195
+	 56, // 0x196
+	 56, // 0x197
+	 56, // 0x198
+	 56, // 0x199
+	 56, // 0x19a
+	 56, // 0x19b
+	 56, // 0x19c
+	 56, // 0x19d
+	 56, // 0x19e
+	 56, // 0x19f
+	 56, // 0x1a0
+	 56, // 0x1a1
+	 56, // 0x1a2
+	 56, // 0x1a3
+	 56, // 0x1a4
+	 56, // 0x1a5
+	 56, // 0x1a6
+	 56, // 0x1a7
+	 56, // 0x1a8
+	 56, // 0x1a9
+	 56, // 0x1aa
+	 56, // 0x1ab
+	 56, // 0x1ac
+	 56, // 0x1ad
+	 56, // 0x1ae
+	 117, // 0x1af
+	 118, // 0x1b0
+	 119, // 0x1b1
+	 120, // 0x1b2
+	 56, // 0x1b3
+	 56, // 0x1b4
+	 56, // 0x1b5
+	 56, // 0x1b6
+	 56, // 0x1b7
+	 56, // 0x1b8
+	 56, // 0x1b9
+	 56, // 0x1ba
+	 56, // 0x1bb
+	 121, // 0x1bc
+	 56, // 0x1bd
+	 56, // 0x1be
+	 56, // 0x1bf
+	 56, // 0x1c0
+	 56, // 0x1c1
+	 56, // 0x1c2
+	 56, // 0x1c3
+	 56, // 0x1c4
+	 56, // 0x1c5
+	 56, // 0x1c6
+	 56, // 0x1c7
+	 56, // 0x1c8
+	 56, // 0x1c9
+	 56, // 0x1ca
+	 56, // 0x1cb
+	 122, // 0x1cc
+	 32, // 0x1cd
+	 123, // 0x1ce
+	 124, // 0x1cf
+	 125, // 0x1d0
+	 126, // 0x1d1
+	 127, // 0x1d2
+	 128, // 0x1d3
+	 129, // 0x1d4
+	 130, // 0x1d5
+	 131, // 0x1d6
+	 132, // 0x1d7
+	 133, // 0x1d8
+	 133, // 0x1d9
+	 134, // 0x1da
+	 56, // 0x1db
+	 56, // 0x1dc
+	 56, // 0x1dd
+	 56, // 0x1de
+	 135, // 0x1df
+	 136, // 0x1e0
+	 137, // 0x1e1
+	 138, // 0x1e2
+	 56, // 0x1e3
+	 139, // 0x1e4
+	 140, // 0x1e5
+	 56, // 0x1e6
+	 141, // 0x1e7
+	 142, // 0x1e8
+	 143, // 0x1e9
+	 56, // 0x1ea
+	 56, // 0x1eb
+	 144, // 0x1ec
+	 145, // 0x1ed
+	 146, // 0x1ee
+	 56, // 0x1ef
+	 147, // 0x1f0
+	 148, // 0x1f1
+	 149, // 0x1f2
+	 32, // 0x1f3
+	 32, // 0x1f4
+	 32, // 0x1f5
+	 150, // 0x1f6
+	 151, // 0x1f7
+	 152, // 0x1f8
+	 32, // 0x1f9
+	 153, // 0x1fa
+	 154, // 0x1fb
+	 56, // 0x1fc
+	 56, // 0x1fd
+	 56, // 0x1fe
+	 56, // 0x1ff
+	 44, // 0x200
+	 44, // 0x201
+	 44, // 0x202
+	 44, // 0x203
+	 44, // 0x204
+	 44, // 0x205
+	 44, // 0x206
+	 44, // 0x207
+	 44, // 0x208
+	 44, // 0x209
+	 44, // 0x20a
+	 44, // 0x20b
+	 44, // 0x20c
+	 44, // 0x20d
+	 44, // 0x20e
+	 44, // 0x20f
+	 44, // 0x210
+	 44, // 0x211
+	 44, // 0x212
+	 44, // 0x213
+	 44, // 0x214
+	 44, // 0x215
+	 44, // 0x216
+	 44, // 0x217
+	 44, // 0x218
+	 44, // 0x219
+	 44, // 0x21a
+	 44, // 0x21b
+	 44, // 0x21c
+	 44, // 0x21d
+	 44, // 0x21e
+	 44, // 0x21f
+	 44, // 0x220
+	 44, // 0x221
+	 44, // 0x222
+	 44, // 0x223
+	 44, // 0x224
+	 44, // 0x225
+	 44, // 0x226
+	 44, // 0x227
+	 44, // 0x228
+	 44, // 0x229
+	 44, // 0x22a
+	 44, // 0x22b
+	 44, // 0x22c
+	 44, // 0x22d
+	 44, // 0x22e
+	 44, // 0x22f
+	 44, // 0x230
+	 44, // 0x231
+	 44, // 0x232
+	 44, // 0x233
+	 44, // 0x234
+	 44, // 0x235
+	 44, // 0x236
+	 44, // 0x237
+	 44, // 0x238
+	 44, // 0x239
+	 44, // 0x23a
+	 44, // 0x23b
+	 44, // 0x23c
+	 44, // 0x23d
+	 44, // 0x23e
+	 44, // 0x23f
+	 44, // 0x240
+	 44, // 0x241
+	 44, // 0x242
+	 44, // 0x243
+	 44, // 0x244
+	 44, // 0x245
+	 44, // 0x246
+	 44, // 0x247
+	 44, // 0x248
+	 44, // 0x249
+	 44, // 0x24a
+	 44, // 0x24b
+	 44, // 0x24c
+	 44, // 0x24d
+	 44, // 0x24e
+	 44, // 0x24f
+	 44, // 0x250
+	 44, // 0x251
+	 44, // 0x252
+	 44, // 0x253
+	 44, // 0x254
+	 44, // 0x255
+	 44, // 0x256
+	 44, // 0x257
+	 44, // 0x258
+	 44, // 0x259
+	 44, // 0x25a
+	 44, // 0x25b
+	 44, // 0x25c
+	 44, // 0x25d
+	 44, // 0x25e
+	 44, // 0x25f
+	 44, // 0x260
+	 44, // 0x261
+	 44, // 0x262
+	 44, // 0x263
+	 44, // 0x264
+	 44, // 0x265
+	 44, // 0x266
+	 44, // 0x267
+	 44, // 0x268
+	 44, // 0x269
+	 44, // 0x26a
+	 44, // 0x26b
+	 44, // 0x26c
+	 44, // 0x26d
+	 44, // 0x26e
+	 44, // 0x26f
+	 44, // 0x270
+	 44, // 0x271
+	 44, // 0x272
+	 44, // 0x273
+	 44, // 0x274
+	 44, // 0x275
+	 44, // 0x276
+	 44, // 0x277
+	 44, // 0x278
+	 44, // 0x279
+	 44, // 0x27a
+	 44, // 0x27b
+	 44, // 0x27c
+	 44, // 0x27d
+	 44, // 0x27e
+	 44, // 0x27f
+	 44, // 0x280
+	 44, // 0x281
+	 44, // 0x282
+	 44, // 0x283
+	 44, // 0x284
+	 44, // 0x285
+	 44, // 0x286
+	 44, // 0x287
+	 44, // 0x288
+	 44, // 0x289
+	 44, // 0x28a
+	 44, // 0x28b
+	 44, // 0x28c
+	 44, // 0x28d
+	 44, // 0x28e
+	 44, // 0x28f
+	 44, // 0x290
+	 44, // 0x291
+	 44, // 0x292
+	 44, // 0x293
+	 44, // 0x294
+	 44, // 0x295
+	 44, // 0x296
+	 44, // 0x297
+	 44, // 0x298
+	 44, // 0x299
+	 44, // 0x29a
+	 44, // 0x29b
+	 44, // 0x29c
+	 44, // 0x29d
+	 44, // 0x29e
+	 44, // 0x29f
+	 44, // 0x2a0
+	 44, // 0x2a1
+	 44, // 0x2a2
+	 44, // 0x2a3
+	 44, // 0x2a4
+	 44, // 0x2a5
+	 155, // 0x2a6
+	 44, // 0x2a7
+	 44, // 0x2a8
+	 44, // 0x2a9
+	 44, // 0x2aa
+	 44, // 0x2ab
+	 44, // 0x2ac
+	 44, // 0x2ad
+	 44, // 0x2ae
+	 44, // 0x2af
+	 44, // 0x2b0
+	 44, // 0x2b1
+	 44, // 0x2b2
+	 44, // 0x2b3
+	 44, // 0x2b4
+	 44, // 0x2b5
+	 44, // 0x2b6
+	 156, // 0x2b7
+	 157, // 0x2b8
+	 44, // 0x2b9
+	 44, // 0x2ba
+	 44, // 0x2bb
+	 44, // 0x2bc
+	 44, // 0x2bd
+	 44, // 0x2be
+	 44, // 0x2bf
+	 44, // 0x2c0
+	 44, // 0x2c1
+	 44, // 0x2c2
+	 44, // 0x2c3
+	 44, // 0x2c4
+	 44, // 0x2c5
+	 44, // 0x2c6
+	 44, // 0x2c7
+	 44, // 0x2c8
+	 44, // 0x2c9
+	 44, // 0x2ca
+	 44, // 0x2cb
+	 44, // 0x2cc
+	 44, // 0x2cd
+	 158, // 0x2ce
+	 44, // 0x2cf
+	 44, // 0x2d0
+	 44, // 0x2d1
+	 44, // 0x2d2
+	 44, // 0x2d3
+	 44, // 0x2d4
+	 44, // 0x2d5
+	 44, // 0x2d6
+	 44, // 0x2d7
+	 44, // 0x2d8
+	 44, // 0x2d9
+	 44, // 0x2da
+	 44, // 0x2db
+	 44, // 0x2dc
+	 44, // 0x2dd
+	 44, // 0x2de
+	 44, // 0x2df
+	 44, // 0x2e0
+	 44, // 0x2e1
+	 44, // 0x2e2
+	 44, // 0x2e3
+	 44, // 0x2e4
+	 44, // 0x2e5
+	 44, // 0x2e6
+	 44, // 0x2e7
+	 44, // 0x2e8
+	 44, // 0x2e9
+	 44, // 0x2ea
+	 159, // 0x2eb
+	 44, // 0x2ec
+	 44, // 0x2ed
+	 160, // 0x2ee
+	 56, // 0x2ef
+	 56, // 0x2f0
+	 56, // 0x2f1
+	 56, // 0x2f2
+	 56, // 0x2f3
+	 56, // 0x2f4
+	 56, // 0x2f5
+	 56, // 0x2f6
+	 56, // 0x2f7
+	 44, // 0x2f8
+	 44, // 0x2f9
+	 161, // 0x2fa
+	 56, // 0x2fb
+	 56, // 0x2fc
+	 56, // 0x2fd
+	 56, // 0x2fe
+	 56, // 0x2ff
+	 44, // 0x300
+	 44, // 0x301
+	 44, // 0x302
+	 44, // 0x303
+	 44, // 0x304
+	 44, // 0x305
+	 44, // 0x306
+	 44, // 0x307
+	 44, // 0x308
+	 44, // 0x309
+	 44, // 0x30a
+	 44, // 0x30b
+	 44, // 0x30c
+	 44, // 0x30d
+	 44, // 0x30e
+	 44, // 0x30f
+	 44, // 0x310
+	 44, // 0x311
+	 44, // 0x312
+	 162, // 0x313
+	 44, // 0x314
+	 44, // 0x315
+	 44, // 0x316
+	 44, // 0x317
+	 44, // 0x318
+	 44, // 0x319
+	 44, // 0x31a
+	 44, // 0x31b
+	 44, // 0x31c
+	 44, // 0x31d
+	 44, // 0x31e
+	 44, // 0x31f
+	 44, // 0x320
+	 44, // 0x321
+	 44, // 0x322
+	 163, // 0x323
+	 56, // 0x324
+	 56, // 0x325
+	 56, // 0x326
+	 56, // 0x327
+	 56, // 0x328
+	 56, // 0x329
+	 56, // 0x32a
+	 56, // 0x32b
+	 56, // 0x32c
+	 56, // 0x32d
+	 56, // 0x32e
+	 56, // 0x32f
+	 56, // 0x330
+	 56, // 0x331
+	 56, // 0x332
+	 56, // 0x333
+	 56, // 0x334
+	 56, // 0x335
+	 56, // 0x336
+	 56, // 0x337
+	 56, // 0x338
+	 56, // 0x339
+	 56, // 0x33a
+	 56, // 0x33b
+	 56, // 0x33c
+	 56, // 0x33d
+	 56, // 0x33e
+	 56, // 0x33f
+	 56, // 0x340
+	 56, // 0x341
+	 56, // 0x342
+	 56, // 0x343
+	 56, // 0x344
+	 56, // 0x345
+	 56, // 0x346
+	 56, // 0x347
+	 56, // 0x348
+	 56, // 0x349
+	 56, // 0x34a
+	 56, // 0x34b
+	 56, // 0x34c
+	 56, // 0x34d
+	 56, // 0x34e
+	 56, // 0x34f
+	 56, // 0x350
+	 56, // 0x351
+	 56, // 0x352
+	 56, // 0x353
+	 56, // 0x354
+	 56, // 0x355
+	 56, // 0x356
+	 56, // 0x357
+	 56, // 0x358
+	 56, // 0x359
+	 56, // 0x35a
+	 56, // 0x35b
+	 56, // 0x35c
+	 56, // 0x35d
+	 56, // 0x35e
+	 56, // 0x35f
+	 56, // 0x360
+	 56, // 0x361
+	 56, // 0x362
+	 56, // 0x363
+	 56, // 0x364
+	 56, // 0x365
+	 56, // 0x366
+	 56, // 0x367
+	 56, // 0x368
+	 56, // 0x369
+	 56, // 0x36a
+	 56, // 0x36b
+	 56, // 0x36c
+	 56, // 0x36d
+	 56, // 0x36e
+	 56, // 0x36f
+	 56, // 0x370
+	 56, // 0x371
+	 56, // 0x372
+	 56, // 0x373
+	 56, // 0x374
+	 56, // 0x375
+	 56, // 0x376
+	 56, // 0x377
+	 56, // 0x378
+	 56, // 0x379
+	 56, // 0x37a
+	 56, // 0x37b
+	 56, // 0x37c
+	 56, // 0x37d
+	 56, // 0x37e
+	 56, // 0x37f
+	 56, // 0x380
+	 56, // 0x381
+	 56, // 0x382
+	 56, // 0x383
+	 56, // 0x384
+	 56, // 0x385
+	 56, // 0x386
+	 56, // 0x387
+	 56, // 0x388
+	 56, // 0x389
+	 56, // 0x38a
+	 56, // 0x38b
+	 56, // 0x38c
+	 56, // 0x38d
+	 56, // 0x38e
+	 56, // 0x38f
+	 56, // 0x390
+	 56, // 0x391
+	 56, // 0x392
+	 56, // 0x393
+	 56, // 0x394
+	 56, // 0x395
+	 56, // 0x396
+	 56, // 0x397
+	 56, // 0x398
+	 56, // 0x399
+	 56, // 0x39a
+	 56, // 0x39b
+	 56, // 0x39c
+	 56, // 0x39d
+	 56, // 0x39e
+	 56, // 0x39f
+	 56, // 0x3a0
+	 56, // 0x3a1
+	 56, // 0x3a2
+	 56, // 0x3a3
+	 56, // 0x3a4
+	 56, // 0x3a5
+	 56, // 0x3a6
+	 56, // 0x3a7
+	 56, // 0x3a8
+	 56, // 0x3a9
+	 56, // 0x3aa
+	 56, // 0x3ab
+	 56, // 0x3ac
+	 56, // 0x3ad
+	 56, // 0x3ae
+	 56, // 0x3af
+	 56, // 0x3b0
+	 56, // 0x3b1
+	 56, // 0x3b2
+	 56, // 0x3b3
+	 56, // 0x3b4
+	 56, // 0x3b5
+	 56, // 0x3b6
+	 56, // 0x3b7
+	 56, // 0x3b8
+	 56, // 0x3b9
+	 56, // 0x3ba
+	 56, // 0x3bb
+	 56, // 0x3bc
+	 56, // 0x3bd
+	 56, // 0x3be
+	 56, // 0x3bf
+	 56, // 0x3c0
+	 56, // 0x3c1
+	 56, // 0x3c2
+	 56, // 0x3c3
+	 56, // 0x3c4
+	 56, // 0x3c5
+	 56, // 0x3c6
+	 56, // 0x3c7
+	 56, // 0x3c8
+	 56, // 0x3c9
+	 56, // 0x3ca
+	 56, // 0x3cb
+	 56, // 0x3cc
+	 56, // 0x3cd
+	 56, // 0x3ce
+	 56, // 0x3cf
+	 56, // 0x3d0
+	 56, // 0x3d1
+	 56, // 0x3d2
+	 56, // 0x3d3
+	 56, // 0x3d4
+	 56, // 0x3d5
+	 56, // 0x3d6
+	 56, // 0x3d7
+	 56, // 0x3d8
+	 56, // 0x3d9
+	 56, // 0x3da
+	 56, // 0x3db
+	 56, // 0x3dc
+	 56, // 0x3dd
+	 56, // 0x3de
+	 56, // 0x3df
+	 56, // 0x3e0
+	 56, // 0x3e1
+	 56, // 0x3e2
+	 56, // 0x3e3
+	 56, // 0x3e4
+	 56, // 0x3e5
+	 56, // 0x3e6
+	 56, // 0x3e7
+	 56, // 0x3e8
+	 56, // 0x3e9
+	 56, // 0x3ea
+	 56, // 0x3eb
+	 56, // 0x3ec
+	 56, // 0x3ed
+	 56, // 0x3ee
+	 56, // 0x3ef
+	 56, // 0x3f0
+	 56, // 0x3f1
+	 56, // 0x3f2
+	 56, // 0x3f3
+	 56, // 0x3f4
+	 56, // 0x3f5
+	 56, // 0x3f6
+	 56, // 0x3f7
+	 56, // 0x3f8
+	 56, // 0x3f9
+	 56, // 0x3fa
+	 56, // 0x3fb
+	 56, // 0x3fc
+	 56, // 0x3fd
+	 56, // 0x3fe
+	 56, // 0x3ff
+	 56, // 0x400
+	 56, // 0x401
+	 56, // 0x402
+	 56, // 0x403
+	 56, // 0x404
+	 56, // 0x405
+	 56, // 0x406
+	 56, // 0x407
+	 56, // 0x408
+	 56, // 0x409
+	 56, // 0x40a
+	 56, // 0x40b
+	 56, // 0x40c
+	 56, // 0x40d
+	 56, // 0x40e
+	 56, // 0x40f
+	 56, // 0x410
+	 56, // 0x411
+	 56, // 0x412
+	 56, // 0x413
+	 56, // 0x414
+	 56, // 0x415
+	 56, // 0x416
+	 56, // 0x417
+	 56, // 0x418
+	 56, // 0x419
+	 56, // 0x41a
+	 56, // 0x41b
+	 56, // 0x41c
+	 56, // 0x41d
+	 56, // 0x41e
+	 56, // 0x41f
+	 56, // 0x420
+	 56, // 0x421
+	 56, // 0x422
+	 56, // 0x423
+	 56, // 0x424
+	 56, // 0x425
+	 56, // 0x426
+	 56, // 0x427
+	 56, // 0x428
+	 56, // 0x429
+	 56, // 0x42a
+	 56, // 0x42b
+	 56, // 0x42c
+	 56, // 0x42d
+	 56, // 0x42e
+	 56, // 0x42f
+	 56, // 0x430
+	 56, // 0x431
+	 56, // 0x432
+	 56, // 0x433
+	 56, // 0x434
+	 56, // 0x435
+	 56, // 0x436
+	 56, // 0x437
+	 56, // 0x438
+	 56, // 0x439
+	 56, // 0x43a
+	 56, // 0x43b
+	 56, // 0x43c
+	 56, // 0x43d
+	 56, // 0x43e
+	 56, // 0x43f
+	 56, // 0x440
+	 56, // 0x441
+	 56, // 0x442
+	 56, // 0x443
+	 56, // 0x444
+	 56, // 0x445
+	 56, // 0x446
+	 56, // 0x447
+	 56, // 0x448
+	 56, // 0x449
+	 56, // 0x44a
+	 56, // 0x44b
+	 56, // 0x44c
+	 56, // 0x44d
+	 56, // 0x44e
+	 56, // 0x44f
+	 56, // 0x450
+	 56, // 0x451
+	 56, // 0x452
+	 56, // 0x453
+	 56, // 0x454
+	 56, // 0x455
+	 56, // 0x456
+	 56, // 0x457
+	 56, // 0x458
+	 56, // 0x459
+	 56, // 0x45a
+	 56, // 0x45b
+	 56, // 0x45c
+	 56, // 0x45d
+	 56, // 0x45e
+	 56, // 0x45f
+	 56, // 0x460
+	 56, // 0x461
+	 56, // 0x462
+	 56, // 0x463
+	 56, // 0x464
+	 56, // 0x465
+	 56, // 0x466
+	 56, // 0x467
+	 56, // 0x468
+	 56, // 0x469
+	 56, // 0x46a
+	 56, // 0x46b
+	 56, // 0x46c
+	 56, // 0x46d
+	 56, // 0x46e
+	 56, // 0x46f
+	 56, // 0x470
+	 56, // 0x471
+	 56, // 0x472
+	 56, // 0x473
+	 56, // 0x474
+	 56, // 0x475
+	 56, // 0x476
+	 56, // 0x477
+	 56, // 0x478
+	 56, // 0x479
+	 56, // 0x47a
+	 56, // 0x47b
+	 56, // 0x47c
+	 56, // 0x47d
+	 56, // 0x47e
+	 56, // 0x47f
+	 56, // 0x480
+	 56, // 0x481
+	 56, // 0x482
+	 56, // 0x483
+	 56, // 0x484
+	 56, // 0x485
+	 56, // 0x486
+	 56, // 0x487
+	 56, // 0x488
+	 56, // 0x489
+	 56, // 0x48a
+	 56, // 0x48b
+	 56, // 0x48c
+	 56, // 0x48d
+	 56, // 0x48e
+	 56, // 0x48f
+	 56, // 0x490
+	 56, // 0x491
+	 56, // 0x492
+	 56, // 0x493
+	 56, // 0x494
+	 56, // 0x495
+	 56, // 0x496
+	 56, // 0x497
+	 56, // 0x498
+	 56, // 0x499
+	 56, // 0x49a
+	 56, // 0x49b
+	 56, // 0x49c
+	 56, // 0x49d
+	 56, // 0x49e
+	 56, // 0x49f
+	 56, // 0x4a0
+	 56, // 0x4a1
+	 56, // 0x4a2
+	 56, // 0x4a3
+	 56, // 0x4a4
+	 56, // 0x4a5
+	 56, // 0x4a6
+	 56, // 0x4a7
+	 56, // 0x4a8
+	 56, // 0x4a9
+	 56, // 0x4aa
+	 56, // 0x4ab
+	 56, // 0x4ac
+	 56, // 0x4ad
+	 56, // 0x4ae
+	 56, // 0x4af
+	 56, // 0x4b0
+	 56, // 0x4b1
+	 56, // 0x4b2
+	 56, // 0x4b3
+	 56, // 0x4b4
+	 56, // 0x4b5
+	 56, // 0x4b6
+	 56, // 0x4b7
+	 56, // 0x4b8
+	 56, // 0x4b9
+	 56, // 0x4ba
+	 56, // 0x4bb
+	 56, // 0x4bc
+	 56, // 0x4bd
+	 56, // 0x4be
+	 56, // 0x4bf
+	 56, // 0x4c0
+	 56, // 0x4c1
+	 56, // 0x4c2
+	 56, // 0x4c3
+	 56, // 0x4c4
+	 56, // 0x4c5
+	 56, // 0x4c6
+	 56, // 0x4c7
+	 56, // 0x4c8
+	 56, // 0x4c9
+	 56, // 0x4ca
+	 56, // 0x4cb
+	 56, // 0x4cc
+	 56, // 0x4cd
+	 56, // 0x4ce
+	 56, // 0x4cf
+	 56, // 0x4d0
+	 56, // 0x4d1
+	 56, // 0x4d2
+	 56, // 0x4d3
+	 56, // 0x4d4
+	 56, // 0x4d5
+	 56, // 0x4d6
+	 56, // 0x4d7
+	 56, // 0x4d8
+	 56, // 0x4d9
+	 56, // 0x4da
+	 56, // 0x4db
+	 56, // 0x4dc
+	 56, // 0x4dd
+	 56, // 0x4de
+	 56, // 0x4df
+	 56, // 0x4e0
+	 56, // 0x4e1
+	 56, // 0x4e2
+	 56, // 0x4e3
+	 56, // 0x4e4
+	 56, // 0x4e5
+	 56, // 0x4e6
+	 56, // 0x4e7
+	 56, // 0x4e8
+	 56, // 0x4e9
+	 56, // 0x4ea
+	 56, // 0x4eb
+	 56, // 0x4ec
+	 56, // 0x4ed
+	 56, // 0x4ee
+	 56, // 0x4ef
+	 56, // 0x4f0
+	 56, // 0x4f1
+	 56, // 0x4f2
+	 56, // 0x4f3
+	 56, // 0x4f4
+	 56, // 0x4f5
+	 56, // 0x4f6
+	 56, // 0x4f7
+	 56, // 0x4f8
+	 56, // 0x4f9
+	 56, // 0x4fa
+	 56, // 0x4fb
+	 56, // 0x4fc
+	 56, // 0x4fd
+	 56, // 0x4fe
+	 56, // 0x4ff
+	 56, // 0x500
+	 56, // 0x501
+	 56, // 0x502
+	 56, // 0x503
+	 56, // 0x504
+	 56, // 0x505
+	 56, // 0x506
+	 56, // 0x507
+	 56, // 0x508
+	 56, // 0x509
+	 56, // 0x50a
+	 56, // 0x50b
+	 56, // 0x50c
+	 56, // 0x50d
+	 56, // 0x50e
+	 56, // 0x50f
+	 56, // 0x510
+	 56, // 0x511
+	 56, // 0x512
+	 56, // 0x513
+	 56, // 0x514
+	 56, // 0x515
+	 56, // 0x516
+	 56, // 0x517
+	 56, // 0x518
+	 56, // 0x519
+	 56, // 0x51a
+	 56, // 0x51b
+	 56, // 0x51c
+	 56, // 0x51d
+	 56, // 0x51e
+	 56, // 0x51f
+	 56, // 0x520
+	 56, // 0x521
+	 56, // 0x522
+	 56, // 0x523
+	 56, // 0x524
+	 56, // 0x525
+	 56, // 0x526
+	 56, // 0x527
+	 56, // 0x528
+	 56, // 0x529
+	 56, // 0x52a
+	 56, // 0x52b
+	 56, // 0x52c
+	 56, // 0x52d
+	 56, // 0x52e
+	 56, // 0x52f
+	 56, // 0x530
+	 56, // 0x531
+	 56, // 0x532
+	 56, // 0x533
+	 56, // 0x534
+	 56, // 0x535
+	 56, // 0x536
+	 56, // 0x537
+	 56, // 0x538
+	 56, // 0x539
+	 56, // 0x53a
+	 56, // 0x53b
+	 56, // 0x53c
+	 56, // 0x53d
+	 56, // 0x53e
+	 56, // 0x53f
+	 56, // 0x540
+	 56, // 0x541
+	 56, // 0x542
+	 56, // 0x543
+	 56, // 0x544
+	 56, // 0x545
+	 56, // 0x546
+	 56, // 0x547
+	 56, // 0x548
+	 56, // 0x549
+	 56, // 0x54a
+	 56, // 0x54b
+	 56, // 0x54c
+	 56, // 0x54d
+	 56, // 0x54e
+	 56, // 0x54f
+	 56, // 0x550
+	 56, // 0x551
+	 56, // 0x552
+	 56, // 0x553
+	 56, // 0x554
+	 56, // 0x555
+	 56, // 0x556
+	 56, // 0x557
+	 56, // 0x558
+	 56, // 0x559
+	 56, // 0x55a
+	 56, // 0x55b
+	 56, // 0x55c
+	 56, // 0x55d
+	 56, // 0x55e
+	 56, // 0x55f
+	 56, // 0x560
+	 56, // 0x561
+	 56, // 0x562
+	 56, // 0x563
+	 56, // 0x564
+	 56, // 0x565
+	 56, // 0x566
+	 56, // 0x567
+	 56, // 0x568
+	 56, // 0x569
+	 56, // 0x56a
+	 56, // 0x56b
+	 56, // 0x56c
+	 56, // 0x56d
+	 56, // 0x56e
+	 56, // 0x56f
+	 56, // 0x570
+	 56, // 0x571
+	 56, // 0x572
+	 56, // 0x573
+	 56, // 0x574
+	 56, // 0x575
+	 56, // 0x576
+	 56, // 0x577
+	 56, // 0x578
+	 56, // 0x579
+	 56, // 0x57a
+	 56, // 0x57b
+	 56, // 0x57c
+	 56, // 0x57d
+	 56, // 0x57e
+	 56, // 0x57f
+	 56, // 0x580
+	 56, // 0x581
+	 56, // 0x582
+	 56, // 0x583
+	 56, // 0x584
+	 56, // 0x585
+	 56, // 0x586
+	 56, // 0x587
+	 56, // 0x588
+	 56, // 0x589
+	 56, // 0x58a
+	 56, // 0x58b
+	 56, // 0x58c
+	 56, // 0x58d
+	 56, // 0x58e
+	 56, // 0x58f
+	 56, // 0x590
+	 56, // 0x591
+	 56, // 0x592
+	 56, // 0x593
+	 56, // 0x594
+	 56, // 0x595
+	 56, // 0x596
+	 56, // 0x597
+	 56, // 0x598
+	 56, // 0x599
+	 56, // 0x59a
+	 56, // 0x59b
+	 56, // 0x59c
+	 56, // 0x59d
+	 56, // 0x59e
+	 56, // 0x59f
+	 56, // 0x5a0
+	 56, // 0x5a1
+	 56, // 0x5a2
+	 56, // 0x5a3
+	 56, // 0x5a4
+	 56, // 0x5a5
+	 56, // 0x5a6
+	 56, // 0x5a7
+	 56, // 0x5a8
+	 56, // 0x5a9
+	 56, // 0x5aa
+	 56, // 0x5ab
+	 56, // 0x5ac
+	 56, // 0x5ad
+	 56, // 0x5ae
+	 56, // 0x5af
+	 56, // 0x5b0
+	 56, // 0x5b1
+	 56, // 0x5b2
+	 56, // 0x5b3
+	 56, // 0x5b4
+	 56, // 0x5b5
+	 56, // 0x5b6
+	 56, // 0x5b7
+	 56, // 0x5b8
+	 56, // 0x5b9
+	 56, // 0x5ba
+	 56, // 0x5bb
+	 56, // 0x5bc
+	 56, // 0x5bd
+	 56, // 0x5be
+	 56, // 0x5bf
+	 56, // 0x5c0
+	 56, // 0x5c1
+	 56, // 0x5c2
+	 56, // 0x5c3
+	 56, // 0x5c4
+	 56, // 0x5c5
+	 56, // 0x5c6
+	 56, // 0x5c7
+	 56, // 0x5c8
+	 56, // 0x5c9
+	 56, // 0x5ca
+	 56, // 0x5cb
+	 56, // 0x5cc
+	 56, // 0x5cd
+	 56, // 0x5ce
+	 56, // 0x5cf
+	 56, // 0x5d0
+	 56, // 0x5d1
+	 56, // 0x5d2
+	 56, // 0x5d3
+	 56, // 0x5d4
+	 56, // 0x5d5
+	 56, // 0x5d6
+	 56, // 0x5d7
+	 56, // 0x5d8
+	 56, // 0x5d9
+	 56, // 0x5da
+	 56, // 0x5db
+	 56, // 0x5dc
+	 56, // 0x5dd
+	 56, // 0x5de
+	 56, // 0x5df
+	 56, // 0x5e0
+	 56, // 0x5e1
+	 56, // 0x5e2
+	 56, // 0x5e3
+	 56, // 0x5e4
+	 56, // 0x5e5
+	 56, // 0x5e6
+	 56, // 0x5e7
+	 56, // 0x5e8
+	 56, // 0x5e9
+	 56, // 0x5ea
+	 56, // 0x5eb
+	 56, // 0x5ec
+	 56, // 0x5ed
+	 56, // 0x5ee
+	 56, // 0x5ef
+	 56, // 0x5f0
+	 56, // 0x5f1
+	 56, // 0x5f2
+	 56, // 0x5f3
+	 56, // 0x5f4
+	 56, // 0x5f5
+	 56, // 0x5f6
+	 56, // 0x5f7
+	 56, // 0x5f8
+	 56, // 0x5f9
+	 56, // 0x5fa
+	 56, // 0x5fb
+	 56, // 0x5fc
+	 56, // 0x5fd
+	 56, // 0x5fe
+	 56, // 0x5ff
+	 56, // 0x600
+	 56, // 0x601
+	 56, // 0x602
+	 56, // 0x603
+	 56, // 0x604
+	 56, // 0x605
+	 56, // 0x606
+	 56, // 0x607
+	 56, // 0x608
+	 56, // 0x609
+	 56, // 0x60a
+	 56, // 0x60b
+	 56, // 0x60c
+	 56, // 0x60d
+	 56, // 0x60e
+	 56, // 0x60f
+	 56, // 0x610
+	 56, // 0x611
+	 56, // 0x612
+	 56, // 0x613
+	 56, // 0x614
+	 56, // 0x615
+	 56, // 0x616
+	 56, // 0x617
+	 56, // 0x618
+	 56, // 0x619
+	 56, // 0x61a
+	 56, // 0x61b
+	 56, // 0x61c
+	 56, // 0x61d
+	 56, // 0x61e
+	 56, // 0x61f
+	 56, // 0x620
+	 56, // 0x621
+	 56, // 0x622
+	 56, // 0x623
+	 56, // 0x624
+	 56, // 0x625
+	 56, // 0x626
+	 56, // 0x627
+	 56, // 0x628
+	 56, // 0x629
+	 56, // 0x62a
+	 56, // 0x62b
+	 56, // 0x62c
+	 56, // 0x62d
+	 56, // 0x62e
+	 56, // 0x62f
+	 56, // 0x630
+	 56, // 0x631
+	 56, // 0x632
+	 56, // 0x633
+	 56, // 0x634
+	 56, // 0x635
+	 56, // 0x636
+	 56, // 0x637
+	 56, // 0x638
+	 56, // 0x639
+	 56, // 0x63a
+	 56, // 0x63b
+	 56, // 0x63c
+	 56, // 0x63d
+	 56, // 0x63e
+	 56, // 0x63f
+	 56, // 0x640
+	 56, // 0x641
+	 56, // 0x642
+	 56, // 0x643
+	 56, // 0x644
+	 56, // 0x645
+	 56, // 0x646
+	 56, // 0x647
+	 56, // 0x648
+	 56, // 0x649
+	 56, // 0x64a
+	 56, // 0x64b
+	 56, // 0x64c
+	 56, // 0x64d
+	 56, // 0x64e
+	 56, // 0x64f
+	 56, // 0x650
+	 56, // 0x651
+	 56, // 0x652
+	 56, // 0x653
+	 56, // 0x654
+	 56, // 0x655
+	 56, // 0x656
+	 56, // 0x657
+	 56, // 0x658
+	 56, // 0x659
+	 56, // 0x65a
+	 56, // 0x65b
+	 56, // 0x65c
+	 56, // 0x65d
+	 56, // 0x65e
+	 56, // 0x65f
+	 56, // 0x660
+	 56, // 0x661
+	 56, // 0x662
+	 56, // 0x663
+	 56, // 0x664
+	 56, // 0x665
+	 56, // 0x666
+	 56, // 0x667
+	 56, // 0x668
+	 56, // 0x669
+	 56, // 0x66a
+	 56, // 0x66b
+	 56, // 0x66c
+	 56, // 0x66d
+	 56, // 0x66e
+	 56, // 0x66f
+	 56, // 0x670
+	 56, // 0x671
+	 56, // 0x672
+	 56, // 0x673
+	 56, // 0x674
+	 56, // 0x675
+	 56, // 0x676
+	 56, // 0x677
+	 56, // 0x678
+	 56, // 0x679
+	 56, // 0x67a
+	 56, // 0x67b
+	 56, // 0x67c
+	 56, // 0x67d
+	 56, // 0x67e
+	 56, // 0x67f
+	 56, // 0x680
+	 56, // 0x681
+	 56, // 0x682
+	 56, // 0x683
+	 56, // 0x684
+	 56, // 0x685
+	 56, // 0x686
+	 56, // 0x687
+	 56, // 0x688
+	 56, // 0x689
+	 56, // 0x68a
+	 56, // 0x68b
+	 56, // 0x68c
+	 56, // 0x68d
+	 56, // 0x68e
+	 56, // 0x68f
+	 56, // 0x690
+	 56, // 0x691
+	 56, // 0x692
+	 56, // 0x693
+	 56, // 0x694
+	 56, // 0x695
+	 56, // 0x696
+	 56, // 0x697
+	 56, // 0x698
+	 56, // 0x699
+	 56, // 0x69a
+	 56, // 0x69b
+	 56, // 0x69c
+	 56, // 0x69d
+	 56, // 0x69e
+	 56, // 0x69f
+	 56, // 0x6a0
+	 56, // 0x6a1
+	 56, // 0x6a2
+	 56, // 0x6a3
+	 56, // 0x6a4
+	 56, // 0x6a5
+	 56, // 0x6a6
+	 56, // 0x6a7
+	 56, // 0x6a8
+	 56, // 0x6a9
+	 56, // 0x6aa
+	 56, // 0x6ab
+	 56, // 0x6ac
+	 56, // 0x6ad
+	 56, // 0x6ae
+	 56, // 0x6af
+	 56, // 0x6b0
+	 56, // 0x6b1
+	 56, // 0x6b2
+	 56, // 0x6b3
+	 56, // 0x6b4
+	 56, // 0x6b5
+	 56, // 0x6b6
+	 56, // 0x6b7
+	 56, // 0x6b8
+	 56, // 0x6b9
+	 56, // 0x6ba
+	 56, // 0x6bb
+	 56, // 0x6bc
+	 56, // 0x6bd
+	 56, // 0x6be
+	 56, // 0x6bf
+	 56, // 0x6c0
+	 56, // 0x6c1
+	 56, // 0x6c2
+	 56, // 0x6c3
+	 56, // 0x6c4
+	 56, // 0x6c5
+	 56, // 0x6c6
+	 56, // 0x6c7
+	 56, // 0x6c8
+	 56, // 0x6c9
+	 56, // 0x6ca
+	 56, // 0x6cb
+	 56, // 0x6cc
+	 56, // 0x6cd
+	 56, // 0x6ce
+	 56, // 0x6cf
+	 56, // 0x6d0
+	 56, // 0x6d1
+	 56, // 0x6d2
+	 56, // 0x6d3
+	 56, // 0x6d4
+	 56, // 0x6d5
+	 56, // 0x6d6
+	 56, // 0x6d7
+	 56, // 0x6d8
+	 56, // 0x6d9
+	 56, // 0x6da
+	 56, // 0x6db
+	 56, // 0x6dc
+	 56, // 0x6dd
+	 56, // 0x6de
+	 56, // 0x6df
+	 56, // 0x6e0
+	 56, // 0x6e1
+	 56, // 0x6e2
+	 56, // 0x6e3
+	 56, // 0x6e4
+	 56, // 0x6e5
+	 56, // 0x6e6
+	 56, // 0x6e7
+	 56, // 0x6e8
+	 56, // 0x6e9
+	 56, // 0x6ea
+	 56, // 0x6eb
+	 56, // 0x6ec
+	 56, // 0x6ed
+	 56, // 0x6ee
+	 56, // 0x6ef
+	 56, // 0x6f0
+	 56, // 0x6f1
+	 56, // 0x6f2
+	 56, // 0x6f3
+	 56, // 0x6f4
+	 56, // 0x6f5
+	 56, // 0x6f6
+	 56, // 0x6f7
+	 56, // 0x6f8
+	 56, // 0x6f9
+	 56, // 0x6fa
+	 56, // 0x6fb
+	 56, // 0x6fc
+	 56, // 0x6fd
+	 56, // 0x6fe
+	 56, // 0x6ff
+	 56, // 0x700
+	 56, // 0x701
+	 56, // 0x702
+	 56, // 0x703
+	 56, // 0x704
+	 56, // 0x705
+	 56, // 0x706
+	 56, // 0x707
+	 56, // 0x708
+	 56, // 0x709
+	 56, // 0x70a
+	 56, // 0x70b
+	 56, // 0x70c
+	 56, // 0x70d
+	 56, // 0x70e
+	 56, // 0x70f
+	 56, // 0x710
+	 56, // 0x711
+	 56, // 0x712
+	 56, // 0x713
+	 56, // 0x714
+	 56, // 0x715
+	 56, // 0x716
+	 56, // 0x717
+	 56, // 0x718
+	 56, // 0x719
+	 56, // 0x71a
+	 56, // 0x71b
+	 56, // 0x71c
+	 56, // 0x71d
+	 56, // 0x71e
+	 56, // 0x71f
+	 56, // 0x720
+	 56, // 0x721
+	 56, // 0x722
+	 56, // 0x723
+	 56, // 0x724
+	 56, // 0x725
+	 56, // 0x726
+	 56, // 0x727
+	 56, // 0x728
+	 56, // 0x729
+	 56, // 0x72a
+	 56, // 0x72b
+	 56, // 0x72c
+	 56, // 0x72d
+	 56, // 0x72e
+	 56, // 0x72f
+	 56, // 0x730
+	 56, // 0x731
+	 56, // 0x732
+	 56, // 0x733
+	 56, // 0x734
+	 56, // 0x735
+	 56, // 0x736
+	 56, // 0x737
+	 56, // 0x738
+	 56, // 0x739
+	 56, // 0x73a
+	 56, // 0x73b
+	 56, // 0x73c
+	 56, // 0x73d
+	 56, // 0x73e
+	 56, // 0x73f
+	 56, // 0x740
+	 56, // 0x741
+	 56, // 0x742
+	 56, // 0x743
+	 56, // 0x744
+	 56, // 0x745
+	 56, // 0x746
+	 56, // 0x747
+	 56, // 0x748
+	 56, // 0x749
+	 56, // 0x74a
+	 56, // 0x74b
+	 56, // 0x74c
+	 56, // 0x74d
+	 56, // 0x74e
+	 56, // 0x74f
+	 56, // 0x750
+	 56, // 0x751
+	 56, // 0x752
+	 56, // 0x753
+	 56, // 0x754
+	 56, // 0x755
+	 56, // 0x756
+	 56, // 0x757
+	 56, // 0x758
+	 56, // 0x759
+	 56, // 0x75a
+	 56, // 0x75b
+	 56, // 0x75c
+	 56, // 0x75d
+	 56, // 0x75e
+	 56, // 0x75f
+	 56, // 0x760
+	 56, // 0x761
+	 56, // 0x762
+	 56, // 0x763
+	 56, // 0x764
+	 56, // 0x765
+	 56, // 0x766
+	 56, // 0x767
+	 56, // 0x768
+	 56, // 0x769
+	 56, // 0x76a
+	 56, // 0x76b
+	 56, // 0x76c
+	 56, // 0x76d
+	 56, // 0x76e
+	 56, // 0x76f
+	 56, // 0x770
+	 56, // 0x771
+	 56, // 0x772
+	 56, // 0x773
+	 56, // 0x774
+	 56, // 0x775
+	 56, // 0x776
+	 56, // 0x777
+	 56, // 0x778
+	 56, // 0x779
+	 56, // 0x77a
+	 56, // 0x77b
+	 56, // 0x77c
+	 56, // 0x77d
+	 56, // 0x77e
+	 56, // 0x77f
+	 56, // 0x780
+	 56, // 0x781
+	 56, // 0x782
+	 56, // 0x783
+	 56, // 0x784
+	 56, // 0x785
+	 56, // 0x786
+	 56, // 0x787
+	 56, // 0x788
+	 56, // 0x789
+	 56, // 0x78a
+	 56, // 0x78b
+	 56, // 0x78c
+	 56, // 0x78d
+	 56, // 0x78e
+	 56, // 0x78f
+	 56, // 0x790
+	 56, // 0x791
+	 56, // 0x792
+	 56, // 0x793
+	 56, // 0x794
+	 56, // 0x795
+	 56, // 0x796
+	 56, // 0x797
+	 56, // 0x798
+	 56, // 0x799
+	 56, // 0x79a
+	 56, // 0x79b
+	 56, // 0x79c
+	 56, // 0x79d
+	 56, // 0x79e
+	 56, // 0x79f
+	 56, // 0x7a0
+	 56, // 0x7a1
+	 56, // 0x7a2
+	 56, // 0x7a3
+	 56, // 0x7a4
+	 56, // 0x7a5
+	 56, // 0x7a6
+	 56, // 0x7a7
+	 56, // 0x7a8
+	 56, // 0x7a9
+	 56, // 0x7aa
+	 56, // 0x7ab
+	 56, // 0x7ac
+	 56, // 0x7ad
+	 56, // 0x7ae
+	 56, // 0x7af
+	 56, // 0x7b0
+	 56, // 0x7b1
+	 56, // 0x7b2
+	 56, // 0x7b3
+	 56, // 0x7b4
+	 56, // 0x7b5
+	 56, // 0x7b6
+	 56, // 0x7b7
+	 56, // 0x7b8
+	 56, // 0x7b9
+	 56, // 0x7ba
+	 56, // 0x7bb
+	 56, // 0x7bc
+	 56, // 0x7bd
+	 56, // 0x7be
+	 56, // 0x7bf
+	 56, // 0x7c0
+	 56, // 0x7c1
+	 56, // 0x7c2
+	 56, // 0x7c3
+	 56, // 0x7c4
+	 56, // 0x7c5
+	 56, // 0x7c6
+	 56, // 0x7c7
+	 56, // 0x7c8
+	 56, // 0x7c9
+	 56, // 0x7ca
+	 56, // 0x7cb
+	 56, // 0x7cc
+	 56, // 0x7cd
+	 56, // 0x7ce
+	 56, // 0x7cf
+	 56, // 0x7d0
+	 56, // 0x7d1
+	 56, // 0x7d2
+	 56, // 0x7d3
+	 56, // 0x7d4
+	 56, // 0x7d5
+	 56, // 0x7d6
+	 56, // 0x7d7
+	 56, // 0x7d8
+	 56, // 0x7d9
+	 56, // 0x7da
+	 56, // 0x7db
+	 56, // 0x7dc
+	 56, // 0x7dd
+	 56, // 0x7de
+	 56, // 0x7df
+	 56, // 0x7e0
+	 56, // 0x7e1
+	 56, // 0x7e2
+	 56, // 0x7e3
+	 56, // 0x7e4
+	 56, // 0x7e5
+	 56, // 0x7e6
+	 56, // 0x7e7
+	 56, // 0x7e8
+	 56, // 0x7e9
+	 56, // 0x7ea
+	 56, // 0x7eb
+	 56, // 0x7ec
+	 56, // 0x7ed
+	 56, // 0x7ee
+	 56, // 0x7ef
+	 56, // 0x7f0
+	 56, // 0x7f1
+	 56, // 0x7f2
+	 56, // 0x7f3
+	 56, // 0x7f4
+	 56, // 0x7f5
+	 56, // 0x7f6
+	 56, // 0x7f7
+	 56, // 0x7f8
+	 56, // 0x7f9
+	 56, // 0x7fa
+	 56, // 0x7fb
+	 56, // 0x7fc
+	 56, // 0x7fd
+	 56, // 0x7fe
+	 56, // 0x7ff
+	 56, // 0x800
+	 56, // 0x801
+	 56, // 0x802
+	 56, // 0x803
+	 56, // 0x804
+	 56, // 0x805
+	 56, // 0x806
+	 56, // 0x807
+	 56, // 0x808
+	 56, // 0x809
+	 56, // 0x80a
+	 56, // 0x80b
+	 56, // 0x80c
+	 56, // 0x80d
+	 56, // 0x80e
+	 56, // 0x80f
+	 56, // 0x810
+	 56, // 0x811
+	 56, // 0x812
+	 56, // 0x813
+	 56, // 0x814
+	 56, // 0x815
+	 56, // 0x816
+	 56, // 0x817
+	 56, // 0x818
+	 56, // 0x819
+	 56, // 0x81a
+	 56, // 0x81b
+	 56, // 0x81c
+	 56, // 0x81d
+	 56, // 0x81e
+	 56, // 0x81f
+	 56, // 0x820
+	 56, // 0x821
+	 56, // 0x822
+	 56, // 0x823
+	 56, // 0x824
+	 56, // 0x825
+	 56, // 0x826
+	 56, // 0x827
+	 56, // 0x828
+	 56, // 0x829
+	 56, // 0x82a
+	 56, // 0x82b
+	 56, // 0x82c
+	 56, // 0x82d
+	 56, // 0x82e
+	 56, // 0x82f
+	 56, // 0x830
+	 56, // 0x831
+	 56, // 0x832
+	 56, // 0x833
+	 56, // 0x834
+	 56, // 0x835
+	 56, // 0x836
+	 56, // 0x837
+	 56, // 0x838
+	 56, // 0x839
+	 56, // 0x83a
+	 56, // 0x83b
+	 56, // 0x83c
+	 56, // 0x83d
+	 56, // 0x83e
+	 56, // 0x83f
+	 56, // 0x840
+	 56, // 0x841
+	 56, // 0x842
+	 56, // 0x843
+	 56, // 0x844
+	 56, // 0x845
+	 56, // 0x846
+	 56, // 0x847
+	 56, // 0x848
+	 56, // 0x849
+	 56, // 0x84a
+	 56, // 0x84b
+	 56, // 0x84c
+	 56, // 0x84d
+	 56, // 0x84e
+	 56, // 0x84f
+	 56, // 0x850
+	 56, // 0x851
+	 56, // 0x852
+	 56, // 0x853
+	 56, // 0x854
+	 56, // 0x855
+	 56, // 0x856
+	 56, // 0x857
+	 56, // 0x858
+	 56, // 0x859
+	 56, // 0x85a
+	 56, // 0x85b
+	 56, // 0x85c
+	 56, // 0x85d
+	 56, // 0x85e
+	 56, // 0x85f
+	 56, // 0x860
+	 56, // 0x861
+	 56, // 0x862
+	 56, // 0x863
+	 56, // 0x864
+	 56, // 0x865
+	 56, // 0x866
+	 56, // 0x867
+	 56, // 0x868
+	 56, // 0x869
+	 56, // 0x86a
+	 56, // 0x86b
+	 56, // 0x86c
+	 56, // 0x86d
+	 56, // 0x86e
+	 56, // 0x86f
+	 56, // 0x870
+	 56, // 0x871
+	 56, // 0x872
+	 56, // 0x873
+	 56, // 0x874
+	 56, // 0x875
+	 56, // 0x876
+	 56, // 0x877
+	 56, // 0x878
+	 56, // 0x879
+	 56, // 0x87a
+	 56, // 0x87b
+	 56, // 0x87c
+	 56, // 0x87d
+	 56, // 0x87e
+	 56, // 0x87f
+	 56, // 0x880
+	 56, // 0x881
+	 56, // 0x882
+	 56, // 0x883
+	 56, // 0x884
+	 56, // 0x885
+	 56, // 0x886
+	 56, // 0x887
+	 56, // 0x888
+	 56, // 0x889
+	 56, // 0x88a
+	 56, // 0x88b
+	 56, // 0x88c
+	 56, // 0x88d
+	 56, // 0x88e
+	 56, // 0x88f
+	 56, // 0x890
+	 56, // 0x891
+	 56, // 0x892
+	 56, // 0x893
+	 56, // 0x894
+	 56, // 0x895
+	 56, // 0x896
+	 56, // 0x897
+	 56, // 0x898
+	 56, // 0x899
+	 56, // 0x89a
+	 56, // 0x89b
+	 56, // 0x89c
+	 56, // 0x89d
+	 56, // 0x89e
+	 56, // 0x89f
+	 56, // 0x8a0
+	 56, // 0x8a1
+	 56, // 0x8a2
+	 56, // 0x8a3
+	 56, // 0x8a4
+	 56, // 0x8a5
+	 56, // 0x8a6
+	 56, // 0x8a7
+	 56, // 0x8a8
+	 56, // 0x8a9
+	 56, // 0x8aa
+	 56, // 0x8ab
+	 56, // 0x8ac
+	 56, // 0x8ad
+	 56, // 0x8ae
+	 56, // 0x8af
+	 56, // 0x8b0
+	 56, // 0x8b1
+	 56, // 0x8b2
+	 56, // 0x8b3
+	 56, // 0x8b4
+	 56, // 0x8b5
+	 56, // 0x8b6
+	 56, // 0x8b7
+	 56, // 0x8b8
+	 56, // 0x8b9
+	 56, // 0x8ba
+	 56, // 0x8bb
+	 56, // 0x8bc
+	 56, // 0x8bd
+	 56, // 0x8be
+	 56, // 0x8bf
+	 56, // 0x8c0
+	 56, // 0x8c1
+	 56, // 0x8c2
+	 56, // 0x8c3
+	 56, // 0x8c4
+	 56, // 0x8c5
+	 56, // 0x8c6
+	 56, // 0x8c7
+	 56, // 0x8c8
+	 56, // 0x8c9
+	 56, // 0x8ca
+	 56, // 0x8cb
+	 56, // 0x8cc
+	 56, // 0x8cd
+	 56, // 0x8ce
+	 56, // 0x8cf
+	 56, // 0x8d0
+	 56, // 0x8d1
+	 56, // 0x8d2
+	 56, // 0x8d3
+	 56, // 0x8d4
+	 56, // 0x8d5
+	 56, // 0x8d6
+	 56, // 0x8d7
+	 56, // 0x8d8
+	 56, // 0x8d9
+	 56, // 0x8da
+	 56, // 0x8db
+	 56, // 0x8dc
+	 56, // 0x8dd
+	 56, // 0x8de
+	 56, // 0x8df
+	 56, // 0x8e0
+	 56, // 0x8e1
+	 56, // 0x8e2
+	 56, // 0x8e3
+	 56, // 0x8e4
+	 56, // 0x8e5
+	 56, // 0x8e6
+	 56, // 0x8e7
+	 56, // 0x8e8
+	 56, // 0x8e9
+	 56, // 0x8ea
+	 56, // 0x8eb
+	 56, // 0x8ec
+	 56, // 0x8ed
+	 56, // 0x8ee
+	 56, // 0x8ef
+	 56, // 0x8f0
+	 56, // 0x8f1
+	 56, // 0x8f2
+	 56, // 0x8f3
+	 56, // 0x8f4
+	 56, // 0x8f5
+	 56, // 0x8f6
+	 56, // 0x8f7
+	 56, // 0x8f8
+	 56, // 0x8f9
+	 56, // 0x8fa
+	 56, // 0x8fb
+	 56, // 0x8fc
+	 56, // 0x8fd
+	 56, // 0x8fe
+	 56, // 0x8ff
+	 56, // 0x900
+	 56, // 0x901
+	 56, // 0x902
+	 56, // 0x903
+	 56, // 0x904
+	 56, // 0x905
+	 56, // 0x906
+	 56, // 0x907
+	 56, // 0x908
+	 56, // 0x909
+	 56, // 0x90a
+	 56, // 0x90b
+	 56, // 0x90c
+	 56, // 0x90d
+	 56, // 0x90e
+	 56, // 0x90f
+	 56, // 0x910
+	 56, // 0x911
+	 56, // 0x912
+	 56, // 0x913
+	 56, // 0x914
+	 56, // 0x915
+	 56, // 0x916
+	 56, // 0x917
+	 56, // 0x918
+	 56, // 0x919
+	 56, // 0x91a
+	 56, // 0x91b
+	 56, // 0x91c
+	 56, // 0x91d
+	 56, // 0x91e
+	 56, // 0x91f
+	 56, // 0x920
+	 56, // 0x921
+	 56, // 0x922
+	 56, // 0x923
+	 56, // 0x924
+	 56, // 0x925
+	 56, // 0x926
+	 56, // 0x927
+	 56, // 0x928
+	 56, // 0x929
+	 56, // 0x92a
+	 56, // 0x92b
+	 56, // 0x92c
+	 56, // 0x92d
+	 56, // 0x92e
+	 56, // 0x92f
+	 56, // 0x930
+	 56, // 0x931
+	 56, // 0x932
+	 56, // 0x933
+	 56, // 0x934
+	 56, // 0x935
+	 56, // 0x936
+	 56, // 0x937
+	 56, // 0x938
+	 56, // 0x939
+	 56, // 0x93a
+	 56, // 0x93b
+	 56, // 0x93c
+	 56, // 0x93d
+	 56, // 0x93e
+	 56, // 0x93f
+	 56, // 0x940
+	 56, // 0x941
+	 56, // 0x942
+	 56, // 0x943
+	 56, // 0x944
+	 56, // 0x945
+	 56, // 0x946
+	 56, // 0x947
+	 56, // 0x948
+	 56, // 0x949
+	 56, // 0x94a
+	 56, // 0x94b
+	 56, // 0x94c
+	 56, // 0x94d
+	 56, // 0x94e
+	 56, // 0x94f
+	 56, // 0x950
+	 56, // 0x951
+	 56, // 0x952
+	 56, // 0x953
+	 56, // 0x954
+	 56, // 0x955
+	 56, // 0x956
+	 56, // 0x957
+	 56, // 0x958
+	 56, // 0x959
+	 56, // 0x95a
+	 56, // 0x95b
+	 56, // 0x95c
+	 56, // 0x95d
+	 56, // 0x95e
+	 56, // 0x95f
+	 56, // 0x960
+	 56, // 0x961
+	 56, // 0x962
+	 56, // 0x963
+	 56, // 0x964
+	 56, // 0x965
+	 56, // 0x966
+	 56, // 0x967
+	 56, // 0x968
+	 56, // 0x969
+	 56, // 0x96a
+	 56, // 0x96b
+	 56, // 0x96c
+	 56, // 0x96d
+	 56, // 0x96e
+	 56, // 0x96f
+	 56, // 0x970
+	 56, // 0x971
+	 56, // 0x972
+	 56, // 0x973
+	 56, // 0x974
+	 56, // 0x975
+	 56, // 0x976
+	 56, // 0x977
+	 56, // 0x978
+	 56, // 0x979
+	 56, // 0x97a
+	 56, // 0x97b
+	 56, // 0x97c
+	 56, // 0x97d
+	 56, // 0x97e
+	 56, // 0x97f
+	 56, // 0x980
+	 56, // 0x981
+	 56, // 0x982
+	 56, // 0x983
+	 56, // 0x984
+	 56, // 0x985
+	 56, // 0x986
+	 56, // 0x987
+	 56, // 0x988
+	 56, // 0x989
+	 56, // 0x98a
+	 56, // 0x98b
+	 56, // 0x98c
+	 56, // 0x98d
+	 56, // 0x98e
+	 56, // 0x98f
+	 56, // 0x990
+	 56, // 0x991
+	 56, // 0x992
+	 56, // 0x993
+	 56, // 0x994
+	 56, // 0x995
+	 56, // 0x996
+	 56, // 0x997
+	 56, // 0x998
+	 56, // 0x999
+	 56, // 0x99a
+	 56, // 0x99b
+	 56, // 0x99c
+	 56, // 0x99d
+	 56, // 0x99e
+	 56, // 0x99f
+	 56, // 0x9a0
+	 56, // 0x9a1
+	 56, // 0x9a2
+	 56, // 0x9a3
+	 56, // 0x9a4
+	 56, // 0x9a5
+	 56, // 0x9a6
+	 56, // 0x9a7
+	 56, // 0x9a8
+	 56, // 0x9a9
+	 56, // 0x9aa
+	 56, // 0x9ab
+	 56, // 0x9ac
+	 56, // 0x9ad
+	 56, // 0x9ae
+	 56, // 0x9af
+	 56, // 0x9b0
+	 56, // 0x9b1
+	 56, // 0x9b2
+	 56, // 0x9b3
+	 56, // 0x9b4
+	 56, // 0x9b5
+	 56, // 0x9b6
+	 56, // 0x9b7
+	 56, // 0x9b8
+	 56, // 0x9b9
+	 56, // 0x9ba
+	 56, // 0x9bb
+	 56, // 0x9bc
+	 56, // 0x9bd
+	 56, // 0x9be
+	 56, // 0x9bf
+	 56, // 0x9c0
+	 56, // 0x9c1
+	 56, // 0x9c2
+	 56, // 0x9c3
+	 56, // 0x9c4
+	 56, // 0x9c5
+	 56, // 0x9c6
+	 56, // 0x9c7
+	 56, // 0x9c8
+	 56, // 0x9c9
+	 56, // 0x9ca
+	 56, // 0x9cb
+	 56, // 0x9cc
+	 56, // 0x9cd
+	 56, // 0x9ce
+	 56, // 0x9cf
+	 56, // 0x9d0
+	 56, // 0x9d1
+	 56, // 0x9d2
+	 56, // 0x9d3
+	 56, // 0x9d4
+	 56, // 0x9d5
+	 56, // 0x9d6
+	 56, // 0x9d7
+	 56, // 0x9d8
+	 56, // 0x9d9
+	 56, // 0x9da
+	 56, // 0x9db
+	 56, // 0x9dc
+	 56, // 0x9dd
+	 56, // 0x9de
+	 56, // 0x9df
+	 56, // 0x9e0
+	 56, // 0x9e1
+	 56, // 0x9e2
+	 56, // 0x9e3
+	 56, // 0x9e4
+	 56, // 0x9e5
+	 56, // 0x9e6
+	 56, // 0x9e7
+	 56, // 0x9e8
+	 56, // 0x9e9
+	 56, // 0x9ea
+	 56, // 0x9eb
+	 56, // 0x9ec
+	 56, // 0x9ed
+	 56, // 0x9ee
+	 56, // 0x9ef
+	 56, // 0x9f0
+	 56, // 0x9f1
+	 56, // 0x9f2
+	 56, // 0x9f3
+	 56, // 0x9f4
+	 56, // 0x9f5
+	 56, // 0x9f6
+	 56, // 0x9f7
+	 56, // 0x9f8
+	 56, // 0x9f9
+	 56, // 0x9fa
+	 56, // 0x9fb
+	 56, // 0x9fc
+	 56, // 0x9fd
+	 56, // 0x9fe
+	 56, // 0x9ff
+	 56, // 0xa00
+	 56, // 0xa01
+	 56, // 0xa02
+	 56, // 0xa03
+	 56, // 0xa04
+	 56, // 0xa05
+	 56, // 0xa06
+	 56, // 0xa07
+	 56, // 0xa08
+	 56, // 0xa09
+	 56, // 0xa0a
+	 56, // 0xa0b
+	 56, // 0xa0c
+	 56, // 0xa0d
+	 56, // 0xa0e
+	 56, // 0xa0f
+	 56, // 0xa10
+	 56, // 0xa11
+	 56, // 0xa12
+	 56, // 0xa13
+	 56, // 0xa14
+	 56, // 0xa15
+	 56, // 0xa16
+	 56, // 0xa17
+	 56, // 0xa18
+	 56, // 0xa19
+	 56, // 0xa1a
+	 56, // 0xa1b
+	 56, // 0xa1c
+	 56, // 0xa1d
+	 56, // 0xa1e
+	 56, // 0xa1f
+	 56, // 0xa20
+	 56, // 0xa21
+	 56, // 0xa22
+	 56, // 0xa23
+	 56, // 0xa24
+	 56, // 0xa25
+	 56, // 0xa26
+	 56, // 0xa27
+	 56, // 0xa28
+	 56, // 0xa29
+	 56, // 0xa2a
+	 56, // 0xa2b
+	 56, // 0xa2c
+	 56, // 0xa2d
+	 56, // 0xa2e
+	 56, // 0xa2f
+	 56, // 0xa30
+	 56, // 0xa31
+	 56, // 0xa32
+	 56, // 0xa33
+	 56, // 0xa34
+	 56, // 0xa35
+	 56, // 0xa36
+	 56, // 0xa37
+	 56, // 0xa38
+	 56, // 0xa39
+	 56, // 0xa3a
+	 56, // 0xa3b
+	 56, // 0xa3c
+	 56, // 0xa3d
+	 56, // 0xa3e
+	 56, // 0xa3f
+	 56, // 0xa40
+	 56, // 0xa41
+	 56, // 0xa42
+	 56, // 0xa43
+	 56, // 0xa44
+	 56, // 0xa45
+	 56, // 0xa46
+	 56, // 0xa47
+	 56, // 0xa48
+	 56, // 0xa49
+	 56, // 0xa4a
+	 56, // 0xa4b
+	 56, // 0xa4c
+	 56, // 0xa4d
+	 56, // 0xa4e
+	 56, // 0xa4f
+	 56, // 0xa50
+	 56, // 0xa51
+	 56, // 0xa52
+	 56, // 0xa53
+	 56, // 0xa54
+	 56, // 0xa55
+	 56, // 0xa56
+	 56, // 0xa57
+	 56, // 0xa58
+	 56, // 0xa59
+	 56, // 0xa5a
+	 56, // 0xa5b
+	 56, // 0xa5c
+	 56, // 0xa5d
+	 56, // 0xa5e
+	 56, // 0xa5f
+	 56, // 0xa60
+	 56, // 0xa61
+	 56, // 0xa62
+	 56, // 0xa63
+	 56, // 0xa64
+	 56, // 0xa65
+	 56, // 0xa66
+	 56, // 0xa67
+	 56, // 0xa68
+	 56, // 0xa69
+	 56, // 0xa6a
+	 56, // 0xa6b
+	 56, // 0xa6c
+	 56, // 0xa6d
+	 56, // 0xa6e
+	 56, // 0xa6f
+	 56, // 0xa70
+	 56, // 0xa71
+	 56, // 0xa72
+	 56, // 0xa73
+	 56, // 0xa74
+	 56, // 0xa75
+	 56, // 0xa76
+	 56, // 0xa77
+	 56, // 0xa78
+	 56, // 0xa79
+	 56, // 0xa7a
+	 56, // 0xa7b
+	 56, // 0xa7c
+	 56, // 0xa7d
+	 56, // 0xa7e
+	 56, // 0xa7f
+	 56, // 0xa80
+	 56, // 0xa81
+	 56, // 0xa82
+	 56, // 0xa83
+	 56, // 0xa84
+	 56, // 0xa85
+	 56, // 0xa86
+	 56, // 0xa87
+	 56, // 0xa88
+	 56, // 0xa89
+	 56, // 0xa8a
+	 56, // 0xa8b
+	 56, // 0xa8c
+	 56, // 0xa8d
+	 56, // 0xa8e
+	 56, // 0xa8f
+	 56, // 0xa90
+	 56, // 0xa91
+	 56, // 0xa92
+	 56, // 0xa93
+	 56, // 0xa94
+	 56, // 0xa95
+	 56, // 0xa96
+	 56, // 0xa97
+	 56, // 0xa98
+	 56, // 0xa99
+	 56, // 0xa9a
+	 56, // 0xa9b
+	 56, // 0xa9c
+	 56, // 0xa9d
+	 56, // 0xa9e
+	 56, // 0xa9f
+	 56, // 0xaa0
+	 56, // 0xaa1
+	 56, // 0xaa2
+	 56, // 0xaa3
+	 56, // 0xaa4
+	 56, // 0xaa5
+	 56, // 0xaa6
+	 56, // 0xaa7
+	 56, // 0xaa8
+	 56, // 0xaa9
+	 56, // 0xaaa
+	 56, // 0xaab
+	 56, // 0xaac
+	 56, // 0xaad
+	 56, // 0xaae
+	 56, // 0xaaf
+	 56, // 0xab0
+	 56, // 0xab1
+	 56, // 0xab2
+	 56, // 0xab3
+	 56, // 0xab4
+	 56, // 0xab5
+	 56, // 0xab6
+	 56, // 0xab7
+	 56, // 0xab8
+	 56, // 0xab9
+	 56, // 0xaba
+	 56, // 0xabb
+	 56, // 0xabc
+	 56, // 0xabd
+	 56, // 0xabe
+	 56, // 0xabf
+	 56, // 0xac0
+	 56, // 0xac1
+	 56, // 0xac2
+	 56, // 0xac3
+	 56, // 0xac4
+	 56, // 0xac5
+	 56, // 0xac6
+	 56, // 0xac7
+	 56, // 0xac8
+	 56, // 0xac9
+	 56, // 0xaca
+	 56, // 0xacb
+	 56, // 0xacc
+	 56, // 0xacd
+	 56, // 0xace
+	 56, // 0xacf
+	 56, // 0xad0
+	 56, // 0xad1
+	 56, // 0xad2
+	 56, // 0xad3
+	 56, // 0xad4
+	 56, // 0xad5
+	 56, // 0xad6
+	 56, // 0xad7
+	 56, // 0xad8
+	 56, // 0xad9
+	 56, // 0xada
+	 56, // 0xadb
+	 56, // 0xadc
+	 56, // 0xadd
+	 56, // 0xade
+	 56, // 0xadf
+	 56, // 0xae0
+	 56, // 0xae1
+	 56, // 0xae2
+	 56, // 0xae3
+	 56, // 0xae4
+	 56, // 0xae5
+	 56, // 0xae6
+	 56, // 0xae7
+	 56, // 0xae8
+	 56, // 0xae9
+	 56, // 0xaea
+	 56, // 0xaeb
+	 56, // 0xaec
+	 56, // 0xaed
+	 56, // 0xaee
+	 56, // 0xaef
+	 56, // 0xaf0
+	 56, // 0xaf1
+	 56, // 0xaf2
+	 56, // 0xaf3
+	 56, // 0xaf4
+	 56, // 0xaf5
+	 56, // 0xaf6
+	 56, // 0xaf7
+	 56, // 0xaf8
+	 56, // 0xaf9
+	 56, // 0xafa
+	 56, // 0xafb
+	 56, // 0xafc
+	 56, // 0xafd
+	 56, // 0xafe
+	 56, // 0xaff
+	 56, // 0xb00
+	 56, // 0xb01
+	 56, // 0xb02
+	 56, // 0xb03
+	 56, // 0xb04
+	 56, // 0xb05
+	 56, // 0xb06
+	 56, // 0xb07
+	 56, // 0xb08
+	 56, // 0xb09
+	 56, // 0xb0a
+	 56, // 0xb0b
+	 56, // 0xb0c
+	 56, // 0xb0d
+	 56, // 0xb0e
+	 56, // 0xb0f
+	 56, // 0xb10
+	 56, // 0xb11
+	 56, // 0xb12
+	 56, // 0xb13
+	 56, // 0xb14
+	 56, // 0xb15
+	 56, // 0xb16
+	 56, // 0xb17
+	 56, // 0xb18
+	 56, // 0xb19
+	 56, // 0xb1a
+	 56, // 0xb1b
+	 56, // 0xb1c
+	 56, // 0xb1d
+	 56, // 0xb1e
+	 56, // 0xb1f
+	 56, // 0xb20
+	 56, // 0xb21
+	 56, // 0xb22
+	 56, // 0xb23
+	 56, // 0xb24
+	 56, // 0xb25
+	 56, // 0xb26
+	 56, // 0xb27
+	 56, // 0xb28
+	 56, // 0xb29
+	 56, // 0xb2a
+	 56, // 0xb2b
+	 56, // 0xb2c
+	 56, // 0xb2d
+	 56, // 0xb2e
+	 56, // 0xb2f
+	 56, // 0xb30
+	 56, // 0xb31
+	 56, // 0xb32
+	 56, // 0xb33
+	 56, // 0xb34
+	 56, // 0xb35
+	 56, // 0xb36
+	 56, // 0xb37
+	 56, // 0xb38
+	 56, // 0xb39
+	 56, // 0xb3a
+	 56, // 0xb3b
+	 56, // 0xb3c
+	 56, // 0xb3d
+	 56, // 0xb3e
+	 56, // 0xb3f
+	 56, // 0xb40
+	 56, // 0xb41
+	 56, // 0xb42
+	 56, // 0xb43
+	 56, // 0xb44
+	 56, // 0xb45
+	 56, // 0xb46
+	 56, // 0xb47
+	 56, // 0xb48
+	 56, // 0xb49
+	 56, // 0xb4a
+	 56, // 0xb4b
+	 56, // 0xb4c
+	 56, // 0xb4d
+	 56, // 0xb4e
+	 56, // 0xb4f
+	 56, // 0xb50
+	 56, // 0xb51
+	 56, // 0xb52
+	 56, // 0xb53
+	 56, // 0xb54
+	 56, // 0xb55
+	 56, // 0xb56
+	 56, // 0xb57
+	 56, // 0xb58
+	 56, // 0xb59
+	 56, // 0xb5a
+	 56, // 0xb5b
+	 56, // 0xb5c
+	 56, // 0xb5d
+	 56, // 0xb5e
+	 56, // 0xb5f
+	 56, // 0xb60
+	 56, // 0xb61
+	 56, // 0xb62
+	 56, // 0xb63
+	 56, // 0xb64
+	 56, // 0xb65
+	 56, // 0xb66
+	 56, // 0xb67
+	 56, // 0xb68
+	 56, // 0xb69
+	 56, // 0xb6a
+	 56, // 0xb6b
+	 56, // 0xb6c
+	 56, // 0xb6d
+	 56, // 0xb6e
+	 56, // 0xb6f
+	 56, // 0xb70
+	 56, // 0xb71
+	 56, // 0xb72
+	 56, // 0xb73
+	 56, // 0xb74
+	 56, // 0xb75
+	 56, // 0xb76
+	 56, // 0xb77
+	 56, // 0xb78
+	 56, // 0xb79
+	 56, // 0xb7a
+	 56, // 0xb7b
+	 56, // 0xb7c
+	 56, // 0xb7d
+	 56, // 0xb7e
+	 56, // 0xb7f
+	 56, // 0xb80
+	 56, // 0xb81
+	 56, // 0xb82
+	 56, // 0xb83
+	 56, // 0xb84
+	 56, // 0xb85
+	 56, // 0xb86
+	 56, // 0xb87
+	 56, // 0xb88
+	 56, // 0xb89
+	 56, // 0xb8a
+	 56, // 0xb8b
+	 56, // 0xb8c
+	 56, // 0xb8d
+	 56, // 0xb8e
+	 56, // 0xb8f
+	 56, // 0xb90
+	 56, // 0xb91
+	 56, // 0xb92
+	 56, // 0xb93
+	 56, // 0xb94
+	 56, // 0xb95
+	 56, // 0xb96
+	 56, // 0xb97
+	 56, // 0xb98
+	 56, // 0xb99
+	 56, // 0xb9a
+	 56, // 0xb9b
+	 56, // 0xb9c
+	 56, // 0xb9d
+	 56, // 0xb9e
+	 56, // 0xb9f
+	 56, // 0xba0
+	 56, // 0xba1
+	 56, // 0xba2
+	 56, // 0xba3
+	 56, // 0xba4
+	 56, // 0xba5
+	 56, // 0xba6
+	 56, // 0xba7
+	 56, // 0xba8
+	 56, // 0xba9
+	 56, // 0xbaa
+	 56, // 0xbab
+	 56, // 0xbac
+	 56, // 0xbad
+	 56, // 0xbae
+	 56, // 0xbaf
+	 56, // 0xbb0
+	 56, // 0xbb1
+	 56, // 0xbb2
+	 56, // 0xbb3
+	 56, // 0xbb4
+	 56, // 0xbb5
+	 56, // 0xbb6
+	 56, // 0xbb7
+	 56, // 0xbb8
+	 56, // 0xbb9
+	 56, // 0xbba
+	 56, // 0xbbb
+	 56, // 0xbbc
+	 56, // 0xbbd
+	 56, // 0xbbe
+	 56, // 0xbbf
+	 56, // 0xbc0
+	 56, // 0xbc1
+	 56, // 0xbc2
+	 56, // 0xbc3
+	 56, // 0xbc4
+	 56, // 0xbc5
+	 56, // 0xbc6
+	 56, // 0xbc7
+	 56, // 0xbc8
+	 56, // 0xbc9
+	 56, // 0xbca
+	 56, // 0xbcb
+	 56, // 0xbcc
+	 56, // 0xbcd
+	 56, // 0xbce
+	 56, // 0xbcf
+	 56, // 0xbd0
+	 56, // 0xbd1
+	 56, // 0xbd2
+	 56, // 0xbd3
+	 56, // 0xbd4
+	 56, // 0xbd5
+	 56, // 0xbd6
+	 56, // 0xbd7
+	 56, // 0xbd8
+	 56, // 0xbd9
+	 56, // 0xbda
+	 56, // 0xbdb
+	 56, // 0xbdc
+	 56, // 0xbdd
+	 56, // 0xbde
+	 56, // 0xbdf
+	 56, // 0xbe0
+	 56, // 0xbe1
+	 56, // 0xbe2
+	 56, // 0xbe3
+	 56, // 0xbe4
+	 56, // 0xbe5
+	 56, // 0xbe6
+	 56, // 0xbe7
+	 56, // 0xbe8
+	 56, // 0xbe9
+	 56, // 0xbea
+	 56, // 0xbeb
+	 56, // 0xbec
+	 56, // 0xbed
+	 56, // 0xbee
+	 56, // 0xbef
+	 56, // 0xbf0
+	 56, // 0xbf1
+	 56, // 0xbf2
+	 56, // 0xbf3
+	 56, // 0xbf4
+	 56, // 0xbf5
+	 56, // 0xbf6
+	 56, // 0xbf7
+	 56, // 0xbf8
+	 56, // 0xbf9
+	 56, // 0xbfa
+	 56, // 0xbfb
+	 56, // 0xbfc
+	 56, // 0xbfd
+	 56, // 0xbfe
+	 56, // 0xbff
+	 56, // 0xc00
+	 56, // 0xc01
+	 56, // 0xc02
+	 56, // 0xc03
+	 56, // 0xc04
+	 56, // 0xc05
+	 56, // 0xc06
+	 56, // 0xc07
+	 56, // 0xc08
+	 56, // 0xc09
+	 56, // 0xc0a
+	 56, // 0xc0b
+	 56, // 0xc0c
+	 56, // 0xc0d
+	 56, // 0xc0e
+	 56, // 0xc0f
+	 56, // 0xc10
+	 56, // 0xc11
+	 56, // 0xc12
+	 56, // 0xc13
+	 56, // 0xc14
+	 56, // 0xc15
+	 56, // 0xc16
+	 56, // 0xc17
+	 56, // 0xc18
+	 56, // 0xc19
+	 56, // 0xc1a
+	 56, // 0xc1b
+	 56, // 0xc1c
+	 56, // 0xc1d
+	 56, // 0xc1e
+	 56, // 0xc1f
+	 56, // 0xc20
+	 56, // 0xc21
+	 56, // 0xc22
+	 56, // 0xc23
+	 56, // 0xc24
+	 56, // 0xc25
+	 56, // 0xc26
+	 56, // 0xc27
+	 56, // 0xc28
+	 56, // 0xc29
+	 56, // 0xc2a
+	 56, // 0xc2b
+	 56, // 0xc2c
+	 56, // 0xc2d
+	 56, // 0xc2e
+	 56, // 0xc2f
+	 56, // 0xc30
+	 56, // 0xc31
+	 56, // 0xc32
+	 56, // 0xc33
+	 56, // 0xc34
+	 56, // 0xc35
+	 56, // 0xc36
+	 56, // 0xc37
+	 56, // 0xc38
+	 56, // 0xc39
+	 56, // 0xc3a
+	 56, // 0xc3b
+	 56, // 0xc3c
+	 56, // 0xc3d
+	 56, // 0xc3e
+	 56, // 0xc3f
+	 56, // 0xc40
+	 56, // 0xc41
+	 56, // 0xc42
+	 56, // 0xc43
+	 56, // 0xc44
+	 56, // 0xc45
+	 56, // 0xc46
+	 56, // 0xc47
+	 56, // 0xc48
+	 56, // 0xc49
+	 56, // 0xc4a
+	 56, // 0xc4b
+	 56, // 0xc4c
+	 56, // 0xc4d
+	 56, // 0xc4e
+	 56, // 0xc4f
+	 56, // 0xc50
+	 56, // 0xc51
+	 56, // 0xc52
+	 56, // 0xc53
+	 56, // 0xc54
+	 56, // 0xc55
+	 56, // 0xc56
+	 56, // 0xc57
+	 56, // 0xc58
+	 56, // 0xc59
+	 56, // 0xc5a
+	 56, // 0xc5b
+	 56, // 0xc5c
+	 56, // 0xc5d
+	 56, // 0xc5e
+	 56, // 0xc5f
+	 56, // 0xc60
+	 56, // 0xc61
+	 56, // 0xc62
+	 56, // 0xc63
+	 56, // 0xc64
+	 56, // 0xc65
+	 56, // 0xc66
+	 56, // 0xc67
+	 56, // 0xc68
+	 56, // 0xc69
+	 56, // 0xc6a
+	 56, // 0xc6b
+	 56, // 0xc6c
+	 56, // 0xc6d
+	 56, // 0xc6e
+	 56, // 0xc6f
+	 56, // 0xc70
+	 56, // 0xc71
+	 56, // 0xc72
+	 56, // 0xc73
+	 56, // 0xc74
+	 56, // 0xc75
+	 56, // 0xc76
+	 56, // 0xc77
+	 56, // 0xc78
+	 56, // 0xc79
+	 56, // 0xc7a
+	 56, // 0xc7b
+	 56, // 0xc7c
+	 56, // 0xc7d
+	 56, // 0xc7e
+	 56, // 0xc7f
+	 56, // 0xc80
+	 56, // 0xc81
+	 56, // 0xc82
+	 56, // 0xc83
+	 56, // 0xc84
+	 56, // 0xc85
+	 56, // 0xc86
+	 56, // 0xc87
+	 56, // 0xc88
+	 56, // 0xc89
+	 56, // 0xc8a
+	 56, // 0xc8b
+	 56, // 0xc8c
+	 56, // 0xc8d
+	 56, // 0xc8e
+	 56, // 0xc8f
+	 56, // 0xc90
+	 56, // 0xc91
+	 56, // 0xc92
+	 56, // 0xc93
+	 56, // 0xc94
+	 56, // 0xc95
+	 56, // 0xc96
+	 56, // 0xc97
+	 56, // 0xc98
+	 56, // 0xc99
+	 56, // 0xc9a
+	 56, // 0xc9b
+	 56, // 0xc9c
+	 56, // 0xc9d
+	 56, // 0xc9e
+	 56, // 0xc9f
+	 56, // 0xca0
+	 56, // 0xca1
+	 56, // 0xca2
+	 56, // 0xca3
+	 56, // 0xca4
+	 56, // 0xca5
+	 56, // 0xca6
+	 56, // 0xca7
+	 56, // 0xca8
+	 56, // 0xca9
+	 56, // 0xcaa
+	 56, // 0xcab
+	 56, // 0xcac
+	 56, // 0xcad
+	 56, // 0xcae
+	 56, // 0xcaf
+	 56, // 0xcb0
+	 56, // 0xcb1
+	 56, // 0xcb2
+	 56, // 0xcb3
+	 56, // 0xcb4
+	 56, // 0xcb5
+	 56, // 0xcb6
+	 56, // 0xcb7
+	 56, // 0xcb8
+	 56, // 0xcb9
+	 56, // 0xcba
+	 56, // 0xcbb
+	 56, // 0xcbc
+	 56, // 0xcbd
+	 56, // 0xcbe
+	 56, // 0xcbf
+	 56, // 0xcc0
+	 56, // 0xcc1
+	 56, // 0xcc2
+	 56, // 0xcc3
+	 56, // 0xcc4
+	 56, // 0xcc5
+	 56, // 0xcc6
+	 56, // 0xcc7
+	 56, // 0xcc8
+	 56, // 0xcc9
+	 56, // 0xcca
+	 56, // 0xccb
+	 56, // 0xccc
+	 56, // 0xccd
+	 56, // 0xcce
+	 56, // 0xccf
+	 56, // 0xcd0
+	 56, // 0xcd1
+	 56, // 0xcd2
+	 56, // 0xcd3
+	 56, // 0xcd4
+	 56, // 0xcd5
+	 56, // 0xcd6
+	 56, // 0xcd7
+	 56, // 0xcd8
+	 56, // 0xcd9
+	 56, // 0xcda
+	 56, // 0xcdb
+	 56, // 0xcdc
+	 56, // 0xcdd
+	 56, // 0xcde
+	 56, // 0xcdf
+	 56, // 0xce0
+	 56, // 0xce1
+	 56, // 0xce2
+	 56, // 0xce3
+	 56, // 0xce4
+	 56, // 0xce5
+	 56, // 0xce6
+	 56, // 0xce7
+	 56, // 0xce8
+	 56, // 0xce9
+	 56, // 0xcea
+	 56, // 0xceb
+	 56, // 0xcec
+	 56, // 0xced
+	 56, // 0xcee
+	 56, // 0xcef
+	 56, // 0xcf0
+	 56, // 0xcf1
+	 56, // 0xcf2
+	 56, // 0xcf3
+	 56, // 0xcf4
+	 56, // 0xcf5
+	 56, // 0xcf6
+	 56, // 0xcf7
+	 56, // 0xcf8
+	 56, // 0xcf9
+	 56, // 0xcfa
+	 56, // 0xcfb
+	 56, // 0xcfc
+	 56, // 0xcfd
+	 56, // 0xcfe
+	 56, // 0xcff
+	 56, // 0xd00
+	 56, // 0xd01
+	 56, // 0xd02
+	 56, // 0xd03
+	 56, // 0xd04
+	 56, // 0xd05
+	 56, // 0xd06
+	 56, // 0xd07
+	 56, // 0xd08
+	 56, // 0xd09
+	 56, // 0xd0a
+	 56, // 0xd0b
+	 56, // 0xd0c
+	 56, // 0xd0d
+	 56, // 0xd0e
+	 56, // 0xd0f
+	 56, // 0xd10
+	 56, // 0xd11
+	 56, // 0xd12
+	 56, // 0xd13
+	 56, // 0xd14
+	 56, // 0xd15
+	 56, // 0xd16
+	 56, // 0xd17
+	 56, // 0xd18
+	 56, // 0xd19
+	 56, // 0xd1a
+	 56, // 0xd1b
+	 56, // 0xd1c
+	 56, // 0xd1d
+	 56, // 0xd1e
+	 56, // 0xd1f
+	 56, // 0xd20
+	 56, // 0xd21
+	 56, // 0xd22
+	 56, // 0xd23
+	 56, // 0xd24
+	 56, // 0xd25
+	 56, // 0xd26
+	 56, // 0xd27
+	 56, // 0xd28
+	 56, // 0xd29
+	 56, // 0xd2a
+	 56, // 0xd2b
+	 56, // 0xd2c
+	 56, // 0xd2d
+	 56, // 0xd2e
+	 56, // 0xd2f
+	 56, // 0xd30
+	 56, // 0xd31
+	 56, // 0xd32
+	 56, // 0xd33
+	 56, // 0xd34
+	 56, // 0xd35
+	 56, // 0xd36
+	 56, // 0xd37
+	 56, // 0xd38
+	 56, // 0xd39
+	 56, // 0xd3a
+	 56, // 0xd3b
+	 56, // 0xd3c
+	 56, // 0xd3d
+	 56, // 0xd3e
+	 56, // 0xd3f
+	 56, // 0xd40
+	 56, // 0xd41
+	 56, // 0xd42
+	 56, // 0xd43
+	 56, // 0xd44
+	 56, // 0xd45
+	 56, // 0xd46
+	 56, // 0xd47
+	 56, // 0xd48
+	 56, // 0xd49
+	 56, // 0xd4a
+	 56, // 0xd4b
+	 56, // 0xd4c
+	 56, // 0xd4d
+	 56, // 0xd4e
+	 56, // 0xd4f
+	 56, // 0xd50
+	 56, // 0xd51
+	 56, // 0xd52
+	 56, // 0xd53
+	 56, // 0xd54
+	 56, // 0xd55
+	 56, // 0xd56
+	 56, // 0xd57
+	 56, // 0xd58
+	 56, // 0xd59
+	 56, // 0xd5a
+	 56, // 0xd5b
+	 56, // 0xd5c
+	 56, // 0xd5d
+	 56, // 0xd5e
+	 56, // 0xd5f
+	 56, // 0xd60
+	 56, // 0xd61
+	 56, // 0xd62
+	 56, // 0xd63
+	 56, // 0xd64
+	 56, // 0xd65
+	 56, // 0xd66
+	 56, // 0xd67
+	 56, // 0xd68
+	 56, // 0xd69
+	 56, // 0xd6a
+	 56, // 0xd6b
+	 56, // 0xd6c
+	 56, // 0xd6d
+	 56, // 0xd6e
+	 56, // 0xd6f
+	 56, // 0xd70
+	 56, // 0xd71
+	 56, // 0xd72
+	 56, // 0xd73
+	 56, // 0xd74
+	 56, // 0xd75
+	 56, // 0xd76
+	 56, // 0xd77
+	 56, // 0xd78
+	 56, // 0xd79
+	 56, // 0xd7a
+	 56, // 0xd7b
+	 56, // 0xd7c
+	 56, // 0xd7d
+	 56, // 0xd7e
+	 56, // 0xd7f
+	 56, // 0xd80
+	 56, // 0xd81
+	 56, // 0xd82
+	 56, // 0xd83
+	 56, // 0xd84
+	 56, // 0xd85
+	 56, // 0xd86
+	 56, // 0xd87
+	 56, // 0xd88
+	 56, // 0xd89
+	 56, // 0xd8a
+	 56, // 0xd8b
+	 56, // 0xd8c
+	 56, // 0xd8d
+	 56, // 0xd8e
+	 56, // 0xd8f
+	 56, // 0xd90
+	 56, // 0xd91
+	 56, // 0xd92
+	 56, // 0xd93
+	 56, // 0xd94
+	 56, // 0xd95
+	 56, // 0xd96
+	 56, // 0xd97
+	 56, // 0xd98
+	 56, // 0xd99
+	 56, // 0xd9a
+	 56, // 0xd9b
+	 56, // 0xd9c
+	 56, // 0xd9d
+	 56, // 0xd9e
+	 56, // 0xd9f
+	 56, // 0xda0
+	 56, // 0xda1
+	 56, // 0xda2
+	 56, // 0xda3
+	 56, // 0xda4
+	 56, // 0xda5
+	 56, // 0xda6
+	 56, // 0xda7
+	 56, // 0xda8
+	 56, // 0xda9
+	 56, // 0xdaa
+	 56, // 0xdab
+	 56, // 0xdac
+	 56, // 0xdad
+	 56, // 0xdae
+	 56, // 0xdaf
+	 56, // 0xdb0
+	 56, // 0xdb1
+	 56, // 0xdb2
+	 56, // 0xdb3
+	 56, // 0xdb4
+	 56, // 0xdb5
+	 56, // 0xdb6
+	 56, // 0xdb7
+	 56, // 0xdb8
+	 56, // 0xdb9
+	 56, // 0xdba
+	 56, // 0xdbb
+	 56, // 0xdbc
+	 56, // 0xdbd
+	 56, // 0xdbe
+	 56, // 0xdbf
+	 56, // 0xdc0
+	 56, // 0xdc1
+	 56, // 0xdc2
+	 56, // 0xdc3
+	 56, // 0xdc4
+	 56, // 0xdc5
+	 56, // 0xdc6
+	 56, // 0xdc7
+	 56, // 0xdc8
+	 56, // 0xdc9
+	 56, // 0xdca
+	 56, // 0xdcb
+	 56, // 0xdcc
+	 56, // 0xdcd
+	 56, // 0xdce
+	 56, // 0xdcf
+	 56, // 0xdd0
+	 56, // 0xdd1
+	 56, // 0xdd2
+	 56, // 0xdd3
+	 56, // 0xdd4
+	 56, // 0xdd5
+	 56, // 0xdd6
+	 56, // 0xdd7
+	 56, // 0xdd8
+	 56, // 0xdd9
+	 56, // 0xdda
+	 56, // 0xddb
+	 56, // 0xddc
+	 56, // 0xddd
+	 56, // 0xdde
+	 56, // 0xddf
+	 56, // 0xde0
+	 56, // 0xde1
+	 56, // 0xde2
+	 56, // 0xde3
+	 56, // 0xde4
+	 56, // 0xde5
+	 56, // 0xde6
+	 56, // 0xde7
+	 56, // 0xde8
+	 56, // 0xde9
+	 56, // 0xdea
+	 56, // 0xdeb
+	 56, // 0xdec
+	 56, // 0xded
+	 56, // 0xdee
+	 56, // 0xdef
+	 56, // 0xdf0
+	 56, // 0xdf1
+	 56, // 0xdf2
+	 56, // 0xdf3
+	 56, // 0xdf4
+	 56, // 0xdf5
+	 56, // 0xdf6
+	 56, // 0xdf7
+	 56, // 0xdf8
+	 56, // 0xdf9
+	 56, // 0xdfa
+	 56, // 0xdfb
+	 56, // 0xdfc
+	 56, // 0xdfd
+	 56, // 0xdfe
+	 56, // 0xdff
+	 164, // 0xe00
+	 165, // 0xe01
+	 56, // 0xe02
+	 56, // 0xe03
+	 56, // 0xe04
+	 56, // 0xe05
+	 56, // 0xe06
+	 56, // 0xe07
+	 56, // 0xe08
+	 56, // 0xe09
+	 56, // 0xe0a
+	 56, // 0xe0b
+	 56, // 0xe0c
+	 56, // 0xe0d
+	 56, // 0xe0e
+	 56, // 0xe0f
+	 56, // 0xe10
+	 56, // 0xe11
+	 56, // 0xe12
+	 56, // 0xe13
+	 56, // 0xe14
+	 56, // 0xe15
+	 56, // 0xe16
+	 56, // 0xe17
+	 56, // 0xe18
+	 56, // 0xe19
+	 56, // 0xe1a
+	 56, // 0xe1b
+	 56, // 0xe1c
+	 56, // 0xe1d
+	 56, // 0xe1e
+	 56, // 0xe1f
+	 56, // 0xe20
+	 56, // 0xe21
+	 56, // 0xe22
+	 56, // 0xe23
+	 56, // 0xe24
+	 56, // 0xe25
+	 56, // 0xe26
+	 56, // 0xe27
+	 56, // 0xe28
+	 56, // 0xe29
+	 56, // 0xe2a
+	 56, // 0xe2b
+	 56, // 0xe2c
+	 56, // 0xe2d
+	 56, // 0xe2e
+	 56, // 0xe2f
+	 56, // 0xe30
+	 56, // 0xe31
+	 56, // 0xe32
+	 56, // 0xe33
+	 56, // 0xe34
+	 56, // 0xe35
+	 56, // 0xe36
+	 56, // 0xe37
+	 56, // 0xe38
+	 56, // 0xe39
+	 56, // 0xe3a
+	 56, // 0xe3b
+	 56, // 0xe3c
+	 56, // 0xe3d
+	 56, // 0xe3e
+	 56, // 0xe3f
+	 56, // 0xe40
+	 56, // 0xe41
+	 56, // 0xe42
+	 56, // 0xe43
+	 56, // 0xe44
+	 56, // 0xe45
+	 56, // 0xe46
+	 56, // 0xe47
+	 56, // 0xe48
+	 56, // 0xe49
+	 56, // 0xe4a
+	 56, // 0xe4b
+	 56, // 0xe4c
+	 56, // 0xe4d
+	 56, // 0xe4e
+	 56, // 0xe4f
+	 56, // 0xe50
+	 56, // 0xe51
+	 56, // 0xe52
+	 56, // 0xe53
+	 56, // 0xe54
+	 56, // 0xe55
+	 56, // 0xe56
+	 56, // 0xe57
+	 56, // 0xe58
+	 56, // 0xe59
+	 56, // 0xe5a
+	 56, // 0xe5b
+	 56, // 0xe5c
+	 56, // 0xe5d
+	 56, // 0xe5e
+	 56, // 0xe5f
+	 56, // 0xe60
+	 56, // 0xe61
+	 56, // 0xe62
+	 56, // 0xe63
+	 56, // 0xe64
+	 56, // 0xe65
+	 56, // 0xe66
+	 56, // 0xe67
+	 56, // 0xe68
+	 56, // 0xe69
+	 56, // 0xe6a
+	 56, // 0xe6b
+	 56, // 0xe6c
+	 56, // 0xe6d
+	 56, // 0xe6e
+	 56, // 0xe6f
+	 56, // 0xe70
+	 56, // 0xe71
+	 56, // 0xe72
+	 56, // 0xe73
+	 56, // 0xe74
+	 56, // 0xe75
+	 56, // 0xe76
+	 56, // 0xe77
+	 56, // 0xe78
+	 56, // 0xe79
+	 56, // 0xe7a
+	 56, // 0xe7b
+	 56, // 0xe7c
+	 56, // 0xe7d
+	 56, // 0xe7e
+	 56, // 0xe7f
+	 56, // 0xe80
+	 56, // 0xe81
+	 56, // 0xe82
+	 56, // 0xe83
+	 56, // 0xe84
+	 56, // 0xe85
+	 56, // 0xe86
+	 56, // 0xe87
+	 56, // 0xe88
+	 56, // 0xe89
+	 56, // 0xe8a
+	 56, // 0xe8b
+	 56, // 0xe8c
+	 56, // 0xe8d
+	 56, // 0xe8e
+	 56, // 0xe8f
+	 56, // 0xe90
+	 56, // 0xe91
+	 56, // 0xe92
+	 56, // 0xe93
+	 56, // 0xe94
+	 56, // 0xe95
+	 56, // 0xe96
+	 56, // 0xe97
+	 56, // 0xe98
+	 56, // 0xe99
+	 56, // 0xe9a
+	 56, // 0xe9b
+	 56, // 0xe9c
+	 56, // 0xe9d
+	 56, // 0xe9e
+	 56, // 0xe9f
+	 56, // 0xea0
+	 56, // 0xea1
+	 56, // 0xea2
+	 56, // 0xea3
+	 56, // 0xea4
+	 56, // 0xea5
+	 56, // 0xea6
+	 56, // 0xea7
+	 56, // 0xea8
+	 56, // 0xea9
+	 56, // 0xeaa
+	 56, // 0xeab
+	 56, // 0xeac
+	 56, // 0xead
+	 56, // 0xeae
+	 56, // 0xeaf
+	 56, // 0xeb0
+	 56, // 0xeb1
+	 56, // 0xeb2
+	 56, // 0xeb3
+	 56, // 0xeb4
+	 56, // 0xeb5
+	 56, // 0xeb6
+	 56, // 0xeb7
+	 56, // 0xeb8
+	 56, // 0xeb9
+	 56, // 0xeba
+	 56, // 0xebb
+	 56, // 0xebc
+	 56, // 0xebd
+	 56, // 0xebe
+	 56, // 0xebf
+	 56, // 0xec0
+	 56, // 0xec1
+	 56, // 0xec2
+	 56, // 0xec3
+	 56, // 0xec4
+	 56, // 0xec5
+	 56, // 0xec6
+	 56, // 0xec7
+	 56, // 0xec8
+	 56, // 0xec9
+	 56, // 0xeca
+	 56, // 0xecb
+	 56, // 0xecc
+	 56, // 0xecd
+	 56, // 0xece
+	 56, // 0xecf
+	 56, // 0xed0
+	 56, // 0xed1
+	 56, // 0xed2
+	 56, // 0xed3
+	 56, // 0xed4
+	 56, // 0xed5
+	 56, // 0xed6
+	 56, // 0xed7
+	 56, // 0xed8
+	 56, // 0xed9
+	 56, // 0xeda
+	 56, // 0xedb
+	 56, // 0xedc
+	 56, // 0xedd
+	 56, // 0xede
+	 56, // 0xedf
+	 56, // 0xee0
+	 56, // 0xee1
+	 56, // 0xee2
+	 56, // 0xee3
+	 56, // 0xee4
+	 56, // 0xee5
+	 56, // 0xee6
+	 56, // 0xee7
+	 56, // 0xee8
+	 56, // 0xee9
+	 56, // 0xeea
+	 56, // 0xeeb
+	 56, // 0xeec
+	 56, // 0xeed
+	 56, // 0xeee
+	 56, // 0xeef
+	 56, // 0xef0
+	 56, // 0xef1
+	 56, // 0xef2
+	 56, // 0xef3
+	 56, // 0xef4
+	 56, // 0xef5
+	 56, // 0xef6
+	 56, // 0xef7
+	 56, // 0xef8
+	 56, // 0xef9
+	 56, // 0xefa
+	 56, // 0xefb
+	 56, // 0xefc
+	 56, // 0xefd
+	 56, // 0xefe
+	 56, // 0xeff
+	 56, // 0xf00
+	 56, // 0xf01
+	 56, // 0xf02
+	 56, // 0xf03
+	 56, // 0xf04
+	 56, // 0xf05
+	 56, // 0xf06
+	 56, // 0xf07
+	 56, // 0xf08
+	 56, // 0xf09
+	 56, // 0xf0a
+	 56, // 0xf0b
+	 56, // 0xf0c
+	 56, // 0xf0d
+	 56, // 0xf0e
+	 56, // 0xf0f
+	 56, // 0xf10
+	 56, // 0xf11
+	 56, // 0xf12
+	 56, // 0xf13
+	 56, // 0xf14
+	 56, // 0xf15
+	 56, // 0xf16
+	 56, // 0xf17
+	 56, // 0xf18
+	 56, // 0xf19
+	 56, // 0xf1a
+	 56, // 0xf1b
+	 56, // 0xf1c
+	 56, // 0xf1d
+	 56, // 0xf1e
+	 56, // 0xf1f
+	 56, // 0xf20
+	 56, // 0xf21
+	 56, // 0xf22
+	 56, // 0xf23
+	 56, // 0xf24
+	 56, // 0xf25
+	 56, // 0xf26
+	 56, // 0xf27
+	 56, // 0xf28
+	 56, // 0xf29
+	 56, // 0xf2a
+	 56, // 0xf2b
+	 56, // 0xf2c
+	 56, // 0xf2d
+	 56, // 0xf2e
+	 56, // 0xf2f
+	 56, // 0xf30
+	 56, // 0xf31
+	 56, // 0xf32
+	 56, // 0xf33
+	 56, // 0xf34
+	 56, // 0xf35
+	 56, // 0xf36
+	 56, // 0xf37
+	 56, // 0xf38
+	 56, // 0xf39
+	 56, // 0xf3a
+	 56, // 0xf3b
+	 56, // 0xf3c
+	 56, // 0xf3d
+	 56, // 0xf3e
+	 56, // 0xf3f
+	 56, // 0xf40
+	 56, // 0xf41
+	 56, // 0xf42
+	 56, // 0xf43
+	 56, // 0xf44
+	 56, // 0xf45
+	 56, // 0xf46
+	 56, // 0xf47
+	 56, // 0xf48
+	 56, // 0xf49
+	 56, // 0xf4a
+	 56, // 0xf4b
+	 56, // 0xf4c
+	 56, // 0xf4d
+	 56, // 0xf4e
+	 56, // 0xf4f
+	 56, // 0xf50
+	 56, // 0xf51
+	 56, // 0xf52
+	 56, // 0xf53
+	 56, // 0xf54
+	 56, // 0xf55
+	 56, // 0xf56
+	 56, // 0xf57
+	 56, // 0xf58
+	 56, // 0xf59
+	 56, // 0xf5a
+	 56, // 0xf5b
+	 56, // 0xf5c
+	 56, // 0xf5d
+	 56, // 0xf5e
+	 56, // 0xf5f
+	 56, // 0xf60
+	 56, // 0xf61
+	 56, // 0xf62
+	 56, // 0xf63
+	 56, // 0xf64
+	 56, // 0xf65
+	 56, // 0xf66
+	 56, // 0xf67
+	 56, // 0xf68
+	 56, // 0xf69
+	 56, // 0xf6a
+	 56, // 0xf6b
+	 56, // 0xf6c
+	 56, // 0xf6d
+	 56, // 0xf6e
+	 56, // 0xf6f
+	 56, // 0xf70
+	 56, // 0xf71
+	 56, // 0xf72
+	 56, // 0xf73
+	 56, // 0xf74
+	 56, // 0xf75
+	 56, // 0xf76
+	 56, // 0xf77
+	 56, // 0xf78
+	 56, // 0xf79
+	 56, // 0xf7a
+	 56, // 0xf7b
+	 56, // 0xf7c
+	 56, // 0xf7d
+	 56, // 0xf7e
+	 56, // 0xf7f
+	 56, // 0xf80
+	 56, // 0xf81
+	 56, // 0xf82
+	 56, // 0xf83
+	 56, // 0xf84
+	 56, // 0xf85
+	 56, // 0xf86
+	 56, // 0xf87
+	 56, // 0xf88
+	 56, // 0xf89
+	 56, // 0xf8a
+	 56, // 0xf8b
+	 56, // 0xf8c
+	 56, // 0xf8d
+	 56, // 0xf8e
+	 56, // 0xf8f
+	 56, // 0xf90
+	 56, // 0xf91
+	 56, // 0xf92
+	 56, // 0xf93
+	 56, // 0xf94
+	 56, // 0xf95
+	 56, // 0xf96
+	 56, // 0xf97
+	 56, // 0xf98
+	 56, // 0xf99
+	 56, // 0xf9a
+	 56, // 0xf9b
+	 56, // 0xf9c
+	 56, // 0xf9d
+	 56, // 0xf9e
+	 56, // 0xf9f
+	 56, // 0xfa0
+	 56, // 0xfa1
+	 56, // 0xfa2
+	 56, // 0xfa3
+	 56, // 0xfa4
+	 56, // 0xfa5
+	 56, // 0xfa6
+	 56, // 0xfa7
+	 56, // 0xfa8
+	 56, // 0xfa9
+	 56, // 0xfaa
+	 56, // 0xfab
+	 56, // 0xfac
+	 56, // 0xfad
+	 56, // 0xfae
+	 56, // 0xfaf
+	 56, // 0xfb0
+	 56, // 0xfb1
+	 56, // 0xfb2
+	 56, // 0xfb3
+	 56, // 0xfb4
+	 56, // 0xfb5
+	 56, // 0xfb6
+	 56, // 0xfb7
+	 56, // 0xfb8
+	 56, // 0xfb9
+	 56, // 0xfba
+	 56, // 0xfbb
+	 56, // 0xfbc
+	 56, // 0xfbd
+	 56, // 0xfbe
+	 56, // 0xfbf
+	 56, // 0xfc0
+	 56, // 0xfc1
+	 56, // 0xfc2
+	 56, // 0xfc3
+	 56, // 0xfc4
+	 56, // 0xfc5
+	 56, // 0xfc6
+	 56, // 0xfc7
+	 56, // 0xfc8
+	 56, // 0xfc9
+	 56, // 0xfca
+	 56, // 0xfcb
+	 56, // 0xfcc
+	 56, // 0xfcd
+	 56, // 0xfce
+	 56, // 0xfcf
+	 56, // 0xfd0
+	 56, // 0xfd1
+	 56, // 0xfd2
+	 56, // 0xfd3
+	 56, // 0xfd4
+	 56, // 0xfd5
+	 56, // 0xfd6
+	 56, // 0xfd7
+	 56, // 0xfd8
+	 56, // 0xfd9
+	 56, // 0xfda
+	 56, // 0xfdb
+	 56, // 0xfdc
+	 56, // 0xfdd
+	 56, // 0xfde
+	 56, // 0xfdf
+	 56, // 0xfe0
+	 56, // 0xfe1
+	 56, // 0xfe2
+	 56, // 0xfe3
+	 56, // 0xfe4
+	 56, // 0xfe5
+	 56, // 0xfe6
+	 56, // 0xfe7
+	 56, // 0xfe8
+	 56, // 0xfe9
+	 56, // 0xfea
+	 56, // 0xfeb
+	 56, // 0xfec
+	 56, // 0xfed
+	 56, // 0xfee
+	 56, // 0xfef
+	 56, // 0xff0
+	 56, // 0xff1
+	 56, // 0xff2
+	 56, // 0xff3
+	 56, // 0xff4
+	 56, // 0xff5
+	 56, // 0xff6
+	 56, // 0xff7
+	 56, // 0xff8
+	 56, // 0xff9
+	 56, // 0xffa
+	 56, // 0xffb
+	 56, // 0xffc
+	 56, // 0xffd
+	 56, // 0xffe
+	 56, // 0xfff
+	 56, // 0x1000
+	 56, // 0x1001
+	 56, // 0x1002
+	 56, // 0x1003
+	 56, // 0x1004
+	 56, // 0x1005
+	 56, // 0x1006
+	 56, // 0x1007
+	 56, // 0x1008
+	 56, // 0x1009
+	 56, // 0x100a
+	 56, // 0x100b
+	 56, // 0x100c
+	 56, // 0x100d
+	 56, // 0x100e
+	 56, // 0x100f
+	 56, // 0x1010
+	 56, // 0x1011
+	 56, // 0x1012
+	 56, // 0x1013
+	 56, // 0x1014
+	 56, // 0x1015
+	 56, // 0x1016
+	 56, // 0x1017
+	 56, // 0x1018
+	 56, // 0x1019
+	 56, // 0x101a
+	 56, // 0x101b
+	 56, // 0x101c
+	 56, // 0x101d
+	 56, // 0x101e
+	 56, // 0x101f
+	 56, // 0x1020
+	 56, // 0x1021
+	 56, // 0x1022
+	 56, // 0x1023
+	 56, // 0x1024
+	 56, // 0x1025
+	 56, // 0x1026
+	 56, // 0x1027
+	 56, // 0x1028
+	 56, // 0x1029
+	 56, // 0x102a
+	 56, // 0x102b
+	 56, // 0x102c
+	 56, // 0x102d
+	 56, // 0x102e
+	 56, // 0x102f
+	 56, // 0x1030
+	 56, // 0x1031
+	 56, // 0x1032
+	 56, // 0x1033
+	 56, // 0x1034
+	 56, // 0x1035
+	 56, // 0x1036
+	 56, // 0x1037
+	 56, // 0x1038
+	 56, // 0x1039
+	 56, // 0x103a
+	 56, // 0x103b
+	 56, // 0x103c
+	 56, // 0x103d
+	 56, // 0x103e
+	 56, // 0x103f
+	 56, // 0x1040
+	 56, // 0x1041
+	 56, // 0x1042
+	 56, // 0x1043
+	 56, // 0x1044
+	 56, // 0x1045
+	 56, // 0x1046
+	 56, // 0x1047
+	 56, // 0x1048
+	 56, // 0x1049
+	 56, // 0x104a
+	 56, // 0x104b
+	 56, // 0x104c
+	 56, // 0x104d
+	 56, // 0x104e
+	 56, // 0x104f
+	 56, // 0x1050
+	 56, // 0x1051
+	 56, // 0x1052
+	 56, // 0x1053
+	 56, // 0x1054
+	 56, // 0x1055
+	 56, // 0x1056
+	 56, // 0x1057
+	 56, // 0x1058
+	 56, // 0x1059
+	 56, // 0x105a
+	 56, // 0x105b
+	 56, // 0x105c
+	 56, // 0x105d
+	 56, // 0x105e
+	 56, // 0x105f
+	 56, // 0x1060
+	 56, // 0x1061
+	 56, // 0x1062
+	 56, // 0x1063
+	 56, // 0x1064
+	 56, // 0x1065
+	 56, // 0x1066
+	 56, // 0x1067
+	 56, // 0x1068
+	 56, // 0x1069
+	 56, // 0x106a
+	 56, // 0x106b
+	 56, // 0x106c
+	 56, // 0x106d
+	 56, // 0x106e
+	 56, // 0x106f
+	 56, // 0x1070
+	 56, // 0x1071
+	 56, // 0x1072
+	 56, // 0x1073
+	 56, // 0x1074
+	 56, // 0x1075
+	 56, // 0x1076
+	 56, // 0x1077
+	 56, // 0x1078
+	 56, // 0x1079
+	 56, // 0x107a
+	 56, // 0x107b
+	 56, // 0x107c
+	 56, // 0x107d
+	 56, // 0x107e
+	 56, // 0x107f
+	 56, // 0x1080
+	 56, // 0x1081
+	 56, // 0x1082
+	 56, // 0x1083
+	 56, // 0x1084
+	 56, // 0x1085
+	 56, // 0x1086
+	 56, // 0x1087
+	 56, // 0x1088
+	 56, // 0x1089
+	 56, // 0x108a
+	 56, // 0x108b
+	 56, // 0x108c
+	 56, // 0x108d
+	 56, // 0x108e
+	 56, // 0x108f
+	 56, // 0x1090
+	 56, // 0x1091
+	 56, // 0x1092
+	 56, // 0x1093
+	 56, // 0x1094
+	 56, // 0x1095
+	 56, // 0x1096
+	 56, // 0x1097
+	 56, // 0x1098
+	 56, // 0x1099
+	 56, // 0x109a
+	 56, // 0x109b
+	 56, // 0x109c
+	 56, // 0x109d
+	 56, // 0x109e
+	 56, // 0x109f
+	 56, // 0x10a0
+	 56, // 0x10a1
+	 56, // 0x10a2
+	 56, // 0x10a3
+	 56, // 0x10a4
+	 56, // 0x10a5
+	 56, // 0x10a6
+	 56, // 0x10a7
+	 56, // 0x10a8
+	 56, // 0x10a9
+	 56, // 0x10aa
+	 56, // 0x10ab
+	 56, // 0x10ac
+	 56, // 0x10ad
+	 56, // 0x10ae
+	 56, // 0x10af
+	 56, // 0x10b0
+	 56, // 0x10b1
+	 56, // 0x10b2
+	 56, // 0x10b3
+	 56, // 0x10b4
+	 56, // 0x10b5
+	 56, // 0x10b6
+	 56, // 0x10b7
+	 56, // 0x10b8
+	 56, // 0x10b9
+	 56, // 0x10ba
+	 56, // 0x10bb
+	 56, // 0x10bc
+	 56, // 0x10bd
+	 56, // 0x10be
+	 56, // 0x10bf
+	 56, // 0x10c0
+	 56, // 0x10c1
+	 56, // 0x10c2
+	 56, // 0x10c3
+	 56, // 0x10c4
+	 56, // 0x10c5
+	 56, // 0x10c6
+	 56, // 0x10c7
+	 56, // 0x10c8
+	 56, // 0x10c9
+	 56, // 0x10ca
+	 56, // 0x10cb
+	 56, // 0x10cc
+	 56, // 0x10cd
+	 56, // 0x10ce
+	 56, // 0x10cf
+	 56, // 0x10d0
+	 56, // 0x10d1
+	 56, // 0x10d2
+	 56, // 0x10d3
+	 56, // 0x10d4
+	 56, // 0x10d5
+	 56, // 0x10d6
+	 56, // 0x10d7
+	 56, // 0x10d8
+	 56, // 0x10d9
+	 56, // 0x10da
+	 56, // 0x10db
+	 56, // 0x10dc
+	 56, // 0x10dd
+	 56, // 0x10de
+	 56, // 0x10df
+	 56, // 0x10e0
+	 56, // 0x10e1
+	 56, // 0x10e2
+	 56, // 0x10e3
+	 56, // 0x10e4
+	 56, // 0x10e5
+	 56, // 0x10e6
+	 56, // 0x10e7
+	 56, // 0x10e8
+	 56, // 0x10e9
+	 56, // 0x10ea
+	 56, // 0x10eb
+	 56, // 0x10ec
+	 56, // 0x10ed
+	 56, // 0x10ee
+	 56, // 0x10ef
+	 56, // 0x10f0
+	 56, // 0x10f1
+	 56, // 0x10f2
+	 56, // 0x10f3
+	 56, // 0x10f4
+	 56, // 0x10f5
+	 56, // 0x10f6
+	 56, // 0x10f7
+	 56, // 0x10f8
+	 56, // 0x10f9
+	 56, // 0x10fa
+	 56, // 0x10fb
+	 56, // 0x10fc
+	 56, // 0x10fd
+	 56, // 0x10fe
+	 56, // 0x10ff
+];
+pub const SC_PAGES: [[u8;256];166] = [
+    [
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x4a,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x4a,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0xab,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0xab,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+    ],
+    [
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+    ],
+    [
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xe,
+        0xe,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+    ],
+    [
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0xab,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x0,
+        0x0,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0xab,
+        0x2d,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2d,
+        0xab,
+        0x2d,
+        0xab,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x0,
+        0x2d,
+        0x0,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x0,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+    ],
+    [
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0xaa,
+        0xaa,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+    ],
+    [
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x0,
+        0x0,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x0,
+        0x0,
+        0x6,
+        0x6,
+        0x6,
+        0x0,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0xab,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0xab,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0xab,
+        0x4,
+        0x4,
+        0x4,
+        0xab,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0xab,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0xaa,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0xab,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+    ],
+    [
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x0,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x0,
+        0x0,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x98,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x67,
+        0x0,
+        0x0,
+        0x67,
+        0x67,
+        0x67,
+    ],
+    [
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x0,
+        0x0,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x7d,
+        0x0,
+        0x54,
+        0x54,
+        0x54,
+        0x54,
+        0x54,
+        0x54,
+        0x54,
+        0x54,
+        0x54,
+        0x54,
+        0x54,
+        0x54,
+        0x54,
+        0x54,
+        0x54,
+        0x54,
+        0x54,
+        0x54,
+        0x54,
+        0x54,
+        0x54,
+        0x54,
+        0x54,
+        0x54,
+        0x54,
+        0x54,
+        0x54,
+        0x54,
+        0x0,
+        0x0,
+        0x54,
+        0x0,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x8d,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x0,
+        0x4,
+        0x4,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0xab,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+    ],
+    [
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0xab,
+        0xab,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0x0,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0x0,
+        0x0,
+        0xc,
+        0xc,
+        0x0,
+        0x0,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0x0,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0x0,
+        0xc,
+        0x0,
+        0x0,
+        0x0,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0x0,
+        0x0,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0x0,
+        0x0,
+        0xc,
+        0xc,
+        0x0,
+        0x0,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xc,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xc,
+        0xc,
+        0x0,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0x0,
+        0x0,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0xc,
+        0x0,
+    ],
+    [
+        0x0,
+        0x30,
+        0x30,
+        0x30,
+        0x0,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x30,
+        0x30,
+        0x0,
+        0x0,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x0,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x0,
+        0x30,
+        0x30,
+        0x0,
+        0x30,
+        0x30,
+        0x0,
+        0x30,
+        0x30,
+        0x0,
+        0x0,
+        0x30,
+        0x0,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x30,
+        0x30,
+        0x0,
+        0x0,
+        0x30,
+        0x30,
+        0x30,
+        0x0,
+        0x0,
+        0x0,
+        0x30,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x0,
+        0x30,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x30,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x0,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x0,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x0,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x0,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x0,
+        0x2e,
+        0x2e,
+        0x0,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x0,
+        0x0,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x0,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x0,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x0,
+        0x0,
+        0x2e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x0,
+        0x0,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+        0x2e,
+    ],
+    [
+        0x0,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x0,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x0,
+        0x0,
+        0x6d,
+        0x6d,
+        0x0,
+        0x0,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x0,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x0,
+        0x6d,
+        0x6d,
+        0x0,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x0,
+        0x0,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x0,
+        0x0,
+        0x6d,
+        0x6d,
+        0x0,
+        0x0,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x6d,
+        0x6d,
+        0x0,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x0,
+        0x0,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x6d,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x92,
+        0x92,
+        0x0,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x0,
+        0x0,
+        0x0,
+        0x92,
+        0x92,
+        0x92,
+        0x0,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x0,
+        0x0,
+        0x0,
+        0x92,
+        0x92,
+        0x0,
+        0x92,
+        0x0,
+        0x92,
+        0x92,
+        0x0,
+        0x0,
+        0x0,
+        0x92,
+        0x92,
+        0x0,
+        0x0,
+        0x0,
+        0x92,
+        0x92,
+        0x92,
+        0x0,
+        0x0,
+        0x0,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x0,
+        0x0,
+        0x0,
+        0x92,
+        0x92,
+        0x92,
+        0x0,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x0,
+        0x0,
+        0x92,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x92,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x0,
+        0x95,
+        0x95,
+        0x95,
+        0x0,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x0,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x0,
+        0x0,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x0,
+        0x95,
+        0x95,
+        0x95,
+        0x0,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x95,
+        0x95,
+        0x0,
+        0x95,
+        0x95,
+        0x95,
+        0x0,
+        0x0,
+        0x95,
+        0x0,
+        0x0,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x0,
+        0x0,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x95,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x0,
+        0x45,
+        0x45,
+        0x45,
+        0x0,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x0,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x0,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x0,
+        0x0,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x0,
+        0x45,
+        0x45,
+        0x45,
+        0x0,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x45,
+        0x45,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x45,
+        0x45,
+        0x0,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x0,
+        0x0,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x45,
+        0x0,
+        0x45,
+        0x45,
+        0x45,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x0,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x0,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x0,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x0,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x0,
+        0x0,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x5b,
+        0x0,
+        0x85,
+        0x85,
+        0x85,
+        0x0,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x0,
+        0x0,
+        0x0,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x0,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x0,
+        0x85,
+        0x0,
+        0x0,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x0,
+        0x0,
+        0x0,
+        0x85,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x0,
+        0x85,
+        0x0,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x0,
+        0x0,
+        0x85,
+        0x85,
+        0x85,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x99,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x49,
+        0x49,
+        0x0,
+        0x49,
+        0x0,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x0,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x0,
+        0x49,
+        0x0,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x0,
+        0x0,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x0,
+        0x49,
+        0x0,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x0,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x0,
+        0x0,
+        0x49,
+        0x49,
+        0x49,
+        0x49,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x0,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x0,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x0,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x0,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0x9a,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x9a,
+        0x9a,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x0,
+        0x27,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x27,
+        0x0,
+        0x0,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0xab,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+    ],
+    [
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+    ],
+    [
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x25,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x25,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+    ],
+    [
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x0,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x0,
+        0x0,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x0,
+        0x0,
+    ],
+    [
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+    ],
+    [
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x69,
+        0x69,
+        0x69,
+        0x69,
+        0x69,
+        0x69,
+        0x69,
+        0x69,
+        0x69,
+        0x69,
+        0x69,
+        0x69,
+        0x69,
+        0x69,
+        0x69,
+        0x69,
+        0x69,
+        0x69,
+        0x69,
+        0x69,
+        0x69,
+        0x69,
+        0x69,
+        0x69,
+        0x69,
+        0x69,
+        0x69,
+        0x69,
+        0x69,
+        0x0,
+        0x0,
+        0x0,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0xab,
+        0xab,
+        0xab,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x7c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x97,
+        0x97,
+        0x97,
+        0x97,
+        0x97,
+        0x97,
+        0x97,
+        0x97,
+        0x97,
+        0x97,
+        0x97,
+        0x97,
+        0x97,
+        0x97,
+        0x97,
+        0x97,
+        0x97,
+        0x97,
+        0x97,
+        0x97,
+        0x97,
+        0x97,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x97,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+        0x33,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x8e,
+        0x8e,
+        0x8e,
+        0x8e,
+        0x8e,
+        0x8e,
+        0x8e,
+        0x8e,
+        0x8e,
+        0x8e,
+        0x8e,
+        0x8e,
+        0x8e,
+        0x0,
+        0x8e,
+        0x8e,
+        0x8e,
+        0x0,
+        0x8e,
+        0x8e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x0,
+        0x0,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x5d,
+        0x5d,
+        0xab,
+        0xab,
+        0x5d,
+        0xab,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x0,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x4c,
+        0x0,
+        0x0,
+        0x0,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x4c,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x0,
+        0x0,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x0,
+        0x0,
+        0x0,
+        0x91,
+        0x91,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+    ],
+    [
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x0,
+        0x0,
+        0x11,
+        0x11,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x0,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x0,
+        0x0,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x48,
+        0x0,
+        0x0,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x0,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xb,
+        0xb,
+        0xb,
+        0xb,
+    ],
+    [
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x0,
+        0x0,
+        0x0,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x0,
+        0x0,
+        0x0,
+        0x4b,
+        0x4b,
+        0x4b,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x6a,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x0,
+        0x0,
+        0x27,
+        0x27,
+        0x27,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x8a,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xab,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xab,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xaa,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xaa,
+        0xab,
+        0xab,
+        0xab,
+        0xaa,
+        0xaa,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x1c,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x1c,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x2d,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+    ],
+    [
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x0,
+        0x0,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x0,
+        0x0,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x0,
+        0x0,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x0,
+        0x0,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x0,
+        0x2d,
+        0x0,
+        0x2d,
+        0x0,
+        0x2d,
+        0x0,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x0,
+        0x0,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x0,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x0,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x0,
+        0x0,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x0,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x0,
+        0x0,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x0,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x0,
+    ],
+    [
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xaa,
+        0xaa,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x4a,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x4a,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x2d,
+        0xab,
+        0xab,
+        0xab,
+        0x4a,
+        0x4a,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x4a,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x4a,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+    ],
+    [
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+    ],
+    [
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+    ],
+    [
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+        0x19,
+    ],
+    [
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x27,
+        0x0,
+        0x27,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x27,
+        0x0,
+        0x0,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x96,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x96,
+        0x96,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x96,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+    ],
+    [
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x0,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+    ],
+    [
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x32,
+        0xab,
+        0x32,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0x31,
+        0x31,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x0,
+        0x0,
+        0xaa,
+        0xaa,
+        0xab,
+        0xab,
+        0x36,
+        0x36,
+        0x36,
+        0xab,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0xab,
+        0xab,
+        0x3f,
+        0x3f,
+        0x3f,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x0,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+    ],
+    [
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0xab,
+    ],
+    [
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+    ],
+    [
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+    ],
+    [
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+    ],
+    [
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+    ],
+    [
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0x0,
+        0x0,
+        0x0,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0xa8,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+        0x4f,
+    ],
+    [
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+    ],
+    [
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0xa1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0xab,
+        0xab,
+        0xab,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x0,
+        0x0,
+        0x4a,
+        0x4a,
+        0x0,
+        0x4a,
+        0x0,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+    ],
+    [
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x8c,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x74,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x7f,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+    ],
+    [
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0x3e,
+        0xab,
+        0x3e,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x7a,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7a,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x0,
+        0x0,
+        0x0,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x0,
+        0xab,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x3d,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3d,
+        0x3d,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x0,
+    ],
+    [
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x0,
+        0x0,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x0,
+        0x0,
+        0x16,
+        0x16,
+        0x16,
+        0x16,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x94,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0xab,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x2d,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x17,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x0,
+        0x0,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x5f,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x0,
+        0x0,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x0,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x0,
+        0x35,
+        0x0,
+        0x35,
+        0x35,
+        0x0,
+        0x35,
+        0x35,
+        0x0,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x35,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+    ],
+    [
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+    ],
+    [
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0xab,
+        0xab,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x0,
+        0x0,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x4,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+    ],
+    [
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0x1c,
+        0x1c,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x0,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x0,
+        0x0,
+        0xab,
+    ],
+    [
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0xab,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0xab,
+        0xab,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x0,
+        0x0,
+        0x0,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x0,
+        0x0,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x0,
+        0x0,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x0,
+        0x0,
+        0x31,
+        0x31,
+        0x31,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+    ],
+    [
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x0,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x0,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x0,
+        0x4e,
+        0x4e,
+        0x0,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x0,
+        0x0,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x4e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x2d,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xaa,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x0,
+        0x0,
+        0x0,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x15,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xaa,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3c,
+        0x3c,
+        0x3c,
+        0x3c,
+        0x3c,
+        0x3c,
+        0x3c,
+        0x3c,
+        0x3c,
+        0x3c,
+        0x3c,
+        0x3c,
+        0x3c,
+        0x3c,
+        0x3c,
+        0x3c,
+        0x3c,
+        0x3c,
+        0x3c,
+        0x3c,
+        0x3c,
+        0x3c,
+        0x3c,
+        0x3c,
+        0x3c,
+        0x3c,
+        0x3c,
+        0x3c,
+        0x3c,
+        0x3c,
+        0x3c,
+        0x3c,
+        0x3c,
+        0x3c,
+        0x3c,
+        0x3c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3c,
+        0x3c,
+        0x3c,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x2b,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x73,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xa0,
+        0xa0,
+        0xa0,
+        0xa0,
+        0xa0,
+        0xa0,
+        0xa0,
+        0xa0,
+        0xa0,
+        0xa0,
+        0xa0,
+        0xa0,
+        0xa0,
+        0xa0,
+        0xa0,
+        0xa0,
+        0xa0,
+        0xa0,
+        0xa0,
+        0xa0,
+        0xa0,
+        0xa0,
+        0xa0,
+        0xa0,
+        0xa0,
+        0xa0,
+        0xa0,
+        0xa0,
+        0xa0,
+        0xa0,
+        0x0,
+        0xa0,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0xa5,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x81,
+        0x6f,
+        0x6f,
+        0x6f,
+        0x6f,
+        0x6f,
+        0x6f,
+        0x6f,
+        0x6f,
+        0x6f,
+        0x6f,
+        0x6f,
+        0x6f,
+        0x6f,
+        0x6f,
+        0x6f,
+        0x6f,
+        0x6f,
+        0x6f,
+        0x6f,
+        0x6f,
+        0x6f,
+        0x6f,
+        0x6f,
+        0x6f,
+        0x6f,
+        0x6f,
+        0x6f,
+        0x6f,
+        0x6f,
+        0x6f,
+        0x0,
+        0x0,
+        0x6f,
+        0x6f,
+        0x6f,
+        0x6f,
+        0x6f,
+        0x6f,
+        0x6f,
+        0x6f,
+        0x6f,
+        0x6f,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x6e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x23,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0x0,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0x0,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0x0,
+        0xa2,
+        0xa2,
+        0x0,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0x0,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0x0,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0xa2,
+        0x0,
+        0xa2,
+        0xa2,
+        0x0,
+        0x0,
+        0x0,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x9d,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+    ],
+    [
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x4d,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x0,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x0,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x0,
+        0x0,
+        0x1b,
+        0x0,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x1b,
+        0x0,
+        0x1b,
+        0x1b,
+        0x0,
+        0x0,
+        0x0,
+        0x1b,
+        0x0,
+        0x0,
+        0x1b,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x0,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x71,
+        0x71,
+        0x71,
+        0x71,
+        0x71,
+        0x71,
+        0x71,
+        0x71,
+        0x71,
+        0x71,
+        0x71,
+        0x71,
+        0x71,
+        0x71,
+        0x71,
+        0x71,
+        0x71,
+        0x71,
+        0x71,
+        0x71,
+        0x71,
+        0x71,
+        0x71,
+        0x71,
+        0x71,
+        0x71,
+        0x71,
+        0x71,
+        0x71,
+        0x71,
+        0x71,
+        0x71,
+        0x65,
+        0x65,
+        0x65,
+        0x65,
+        0x65,
+        0x65,
+        0x65,
+        0x65,
+        0x65,
+        0x65,
+        0x65,
+        0x65,
+        0x65,
+        0x65,
+        0x65,
+        0x65,
+        0x65,
+        0x65,
+        0x65,
+        0x65,
+        0x65,
+        0x65,
+        0x65,
+        0x65,
+        0x65,
+        0x65,
+        0x65,
+        0x65,
+        0x65,
+        0x65,
+        0x65,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x65,
+        0x65,
+        0x65,
+        0x65,
+        0x65,
+        0x65,
+        0x65,
+        0x65,
+        0x65,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x0,
+        0x34,
+        0x34,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+        0x34,
+    ],
+    [
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x77,
+        0x0,
+        0x0,
+        0x0,
+        0x77,
+        0x51,
+        0x51,
+        0x51,
+        0x51,
+        0x51,
+        0x51,
+        0x51,
+        0x51,
+        0x51,
+        0x51,
+        0x51,
+        0x51,
+        0x51,
+        0x51,
+        0x51,
+        0x51,
+        0x51,
+        0x51,
+        0x51,
+        0x51,
+        0x51,
+        0x51,
+        0x51,
+        0x51,
+        0x51,
+        0x51,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x51,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x5a,
+        0x5a,
+        0x5a,
+        0x5a,
+        0x5a,
+        0x5a,
+        0x5a,
+        0x5a,
+        0x5a,
+        0x5a,
+        0x5a,
+        0x5a,
+        0x5a,
+        0x5a,
+        0x5a,
+        0x5a,
+        0x5a,
+        0x5a,
+        0x5a,
+        0x5a,
+        0x5a,
+        0x5a,
+        0x5a,
+        0x5a,
+        0x5a,
+        0x5a,
+        0x5a,
+        0x5a,
+        0x5a,
+        0x5a,
+        0x5a,
+        0x5a,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x0,
+        0x0,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+        0x59,
+    ],
+    [
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x0,
+        0x41,
+        0x41,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x0,
+        0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7e,
+        0x7e,
+        0x7e,
+        0x7e,
+        0x7e,
+        0x7e,
+        0x7e,
+        0x7e,
+        0x7e,
+        0x7e,
+        0x7e,
+        0x7e,
+        0x7e,
+        0x7e,
+        0x7e,
+        0x7e,
+        0x7e,
+        0x7e,
+        0x7e,
+        0x7e,
+        0x7e,
+        0x7e,
+        0x7e,
+        0x7e,
+        0x7e,
+        0x7e,
+        0x7e,
+        0x7e,
+        0x7e,
+        0x7e,
+        0x7e,
+        0x7e,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x55,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x79,
+        0x79,
+        0x79,
+        0x79,
+        0x79,
+        0x79,
+        0x79,
+        0x79,
+        0x79,
+        0x79,
+        0x79,
+        0x79,
+        0x79,
+        0x79,
+        0x79,
+        0x79,
+        0x79,
+        0x79,
+        0x79,
+        0x79,
+        0x79,
+        0x79,
+        0x0,
+        0x0,
+        0x79,
+        0x79,
+        0x79,
+        0x79,
+        0x79,
+        0x79,
+        0x79,
+        0x79,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x75,
+        0x76,
+        0x76,
+        0x76,
+        0x76,
+        0x76,
+        0x76,
+        0x76,
+        0x76,
+        0x76,
+        0x76,
+        0x76,
+        0x76,
+        0x76,
+        0x76,
+        0x76,
+        0x76,
+        0x76,
+        0x76,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x76,
+        0x76,
+        0x76,
+        0x76,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x76,
+        0x76,
+        0x76,
+        0x76,
+        0x76,
+        0x76,
+        0x76,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x6c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+        0x3b,
+    ],
+    [
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x7b,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x0,
+        0x0,
+        0x0,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x26,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x26,
+        0x26,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x0,
+        0xa7,
+        0xa7,
+        0xa7,
+        0xa7,
+        0xa7,
+        0xa7,
+        0xa7,
+        0xa7,
+        0xa7,
+        0xa7,
+        0xa7,
+        0xa7,
+        0xa7,
+        0xa7,
+        0xa7,
+        0xa7,
+        0xa7,
+        0xa7,
+        0xa7,
+        0xa7,
+        0xa7,
+        0xa7,
+        0xa7,
+        0xa7,
+        0xa7,
+        0xa7,
+        0xa7,
+        0xa7,
+        0xa7,
+        0xa7,
+        0xa7,
+        0xa7,
+        0xa7,
+        0xa7,
+        0xa7,
+        0xa7,
+        0xa7,
+        0xa7,
+        0xa7,
+        0xa7,
+        0xa7,
+        0xa7,
+        0x0,
+        0xa7,
+        0xa7,
+        0xa7,
+        0x0,
+        0x0,
+        0xa7,
+        0xa7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x4,
+        0x4,
+        0x4,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+    ],
+    [
+        0x87,
+        0x87,
+        0x87,
+        0x87,
+        0x87,
+        0x87,
+        0x87,
+        0x87,
+        0x87,
+        0x87,
+        0x87,
+        0x87,
+        0x87,
+        0x87,
+        0x87,
+        0x87,
+        0x87,
+        0x87,
+        0x87,
+        0x87,
+        0x87,
+        0x87,
+        0x87,
+        0x87,
+        0x87,
+        0x87,
+        0x87,
+        0x87,
+        0x87,
+        0x87,
+        0x87,
+        0x87,
+        0x87,
+        0x87,
+        0x87,
+        0x87,
+        0x87,
+        0x87,
+        0x87,
+        0x87,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x86,
+        0x86,
+        0x86,
+        0x86,
+        0x86,
+        0x86,
+        0x86,
+        0x86,
+        0x86,
+        0x86,
+        0x86,
+        0x86,
+        0x86,
+        0x86,
+        0x86,
+        0x86,
+        0x86,
+        0x86,
+        0x86,
+        0x86,
+        0x86,
+        0x86,
+        0x86,
+        0x86,
+        0x86,
+        0x86,
+        0x86,
+        0x86,
+        0x86,
+        0x86,
+        0x86,
+        0x86,
+        0x86,
+        0x86,
+        0x86,
+        0x86,
+        0x86,
+        0x86,
+        0x86,
+        0x86,
+        0x86,
+        0x86,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x70,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x18,
+        0x18,
+        0x18,
+        0x18,
+        0x18,
+        0x18,
+        0x18,
+        0x18,
+        0x18,
+        0x18,
+        0x18,
+        0x18,
+        0x18,
+        0x18,
+        0x18,
+        0x18,
+        0x18,
+        0x18,
+        0x18,
+        0x18,
+        0x18,
+        0x18,
+        0x18,
+        0x18,
+        0x18,
+        0x18,
+        0x18,
+        0x18,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x24,
+        0x24,
+        0x24,
+        0x24,
+        0x24,
+        0x24,
+        0x24,
+        0x24,
+        0x24,
+        0x24,
+        0x24,
+        0x24,
+        0x24,
+        0x24,
+        0x24,
+        0x24,
+        0x24,
+        0x24,
+        0x24,
+        0x24,
+        0x24,
+        0x24,
+        0x24,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0xf,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xf,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x47,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x47,
+        0x0,
+        0x0,
+        0x88,
+        0x88,
+        0x88,
+        0x88,
+        0x88,
+        0x88,
+        0x88,
+        0x88,
+        0x88,
+        0x88,
+        0x88,
+        0x88,
+        0x88,
+        0x88,
+        0x88,
+        0x88,
+        0x88,
+        0x88,
+        0x88,
+        0x88,
+        0x88,
+        0x88,
+        0x88,
+        0x88,
+        0x88,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x88,
+        0x88,
+        0x88,
+        0x88,
+        0x88,
+        0x88,
+        0x88,
+        0x88,
+        0x88,
+        0x88,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x0,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x13,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x52,
+        0x52,
+        0x52,
+        0x52,
+        0x52,
+        0x52,
+        0x52,
+        0x52,
+        0x52,
+        0x52,
+        0x52,
+        0x52,
+        0x52,
+        0x52,
+        0x52,
+        0x52,
+        0x52,
+        0x52,
+        0x52,
+        0x52,
+        0x52,
+        0x52,
+        0x52,
+        0x52,
+        0x52,
+        0x52,
+        0x52,
+        0x52,
+        0x52,
+        0x52,
+        0x52,
+        0x52,
+        0x52,
+        0x52,
+        0x52,
+        0x52,
+        0x52,
+        0x52,
+        0x52,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x82,
+        0x0,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x85,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x0,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x0,
+        0x60,
+        0x0,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x0,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x0,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x0,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x0,
+        0x0,
+        0x2c,
+        0x2c,
+        0x0,
+        0x0,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x0,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x0,
+        0x2c,
+        0x2c,
+        0x0,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x0,
+        0xaa,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x0,
+        0x0,
+        0x2c,
+        0x2c,
+        0x0,
+        0x0,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x0,
+        0x0,
+        0x2c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x0,
+        0x0,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x0,
+        0x0,
+        0x0,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x2c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x0,
+        0x9f,
+        0x0,
+        0x0,
+        0x9f,
+        0x0,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x0,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x0,
+        0x9f,
+        0x0,
+        0x0,
+        0x9f,
+        0x0,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x0,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x9f,
+        0x0,
+        0x9f,
+        0x9f,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9f,
+        0x9f,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x0,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x66,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x9b,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x0,
+        0x0,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x5c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x5d,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x8f,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x1f,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0xa3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xa3,
+    ],
+    [
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x1e,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x1e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x0,
+        0x0,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x0,
+        0x0,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0xa9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x89,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x14,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x72,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x1d,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x8b,
+        0x8b,
+        0x8b,
+        0x8b,
+        0x8b,
+        0x8b,
+        0x8b,
+        0x8b,
+        0x8b,
+        0x8b,
+        0x8b,
+        0x8b,
+        0x8b,
+        0x8b,
+        0x8b,
+        0x8b,
+        0x8b,
+        0x8b,
+        0x8b,
+        0x8b,
+        0x8b,
+        0x8b,
+        0x8b,
+        0x8b,
+        0x8b,
+        0x8b,
+        0x8b,
+        0x8b,
+        0x8b,
+        0x8b,
+        0x8b,
+        0x8b,
+        0x8b,
+        0x8b,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x8b,
+        0x8b,
+        0x8b,
+        0x8b,
+        0x8b,
+        0x8b,
+        0x8b,
+        0x8b,
+        0x8b,
+        0x8b,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0x0,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0x0,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x0,
+        0x0,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x0,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x56,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x0,
+        0x2a,
+        0x2a,
+        0x0,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x0,
+        0x0,
+        0x0,
+        0x2a,
+        0x0,
+        0x2a,
+        0x2a,
+        0x0,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x2a,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x0,
+        0x29,
+        0x29,
+        0x0,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x0,
+        0x29,
+        0x29,
+        0x0,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x29,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x53,
+        0x53,
+        0x53,
+        0x53,
+        0x53,
+        0x53,
+        0x53,
+        0x53,
+        0x53,
+        0x53,
+        0x53,
+        0x53,
+        0x53,
+        0x53,
+        0x53,
+        0x53,
+        0x53,
+        0x53,
+        0x53,
+        0x53,
+        0x53,
+        0x53,
+        0x53,
+        0x53,
+        0x53,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x0,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x0,
+        0x0,
+        0x0,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x4f,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x92,
+    ],
+    [
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+    ],
+    [
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0x0,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+    ],
+    [
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0xa6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x1a,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+    ],
+    [
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+    ],
+    [
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+    ],
+    [
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x37,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x2f,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x0,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x5e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x5e,
+        0x5e,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x0,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x9c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x0,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x0,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x38,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x46,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x57,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x78,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x93,
+        0x68,
+        0x32,
+        0x32,
+        0x44,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x32,
+        0x32,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+    ],
+    [
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+    ],
+    [
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x44,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x44,
+    ],
+    [
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x93,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x0,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x0,
+        0x3f,
+        0x3f,
+        0x0,
+    ],
+    [
+        0x3f,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+    ],
+    [
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x36,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x36,
+        0x36,
+        0x36,
+        0x0,
+        0x0,
+        0x3f,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x3f,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+    ],
+    [
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x68,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x0,
+        0x0,
+        0x0,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x0,
+        0x0,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0x0,
+        0x0,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xab,
+        0xab,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x2d,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0xab,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0xab,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+    ],
+    [
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+    ],
+    [
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+    ],
+    [
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+    ],
+    [
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+    ],
+    [
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x0,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x80,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x4a,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x0,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x0,
+        0x0,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x0,
+        0x28,
+        0x28,
+        0x0,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x28,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1c,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x0,
+        0x0,
+        0x0,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x0,
+        0x0,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x39,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x39,
+        0x39,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9e,
+        0x9e,
+        0x9e,
+        0x9e,
+        0x9e,
+        0x9e,
+        0x9e,
+        0x9e,
+        0x9e,
+        0x9e,
+        0x9e,
+        0x9e,
+        0x9e,
+        0x9e,
+        0x9e,
+        0x9e,
+        0x9e,
+        0x9e,
+        0x9e,
+        0x9e,
+        0x9e,
+        0x9e,
+        0x9e,
+        0x9e,
+        0x9e,
+        0x9e,
+        0x9e,
+        0x9e,
+        0x9e,
+        0x9e,
+        0x9e,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0xa4,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xa4,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x6b,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x6b,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+        0x25,
+        0x25,
+        0x0,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x25,
+        0x0,
+    ],
+    [
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x0,
+        0x0,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x58,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x1,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x0,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x0,
+        0x4,
+        0x4,
+        0x0,
+        0x4,
+        0x0,
+        0x0,
+        0x4,
+        0x0,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x0,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x0,
+        0x4,
+        0x0,
+        0x4,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x4,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x4,
+        0x0,
+        0x4,
+        0x0,
+        0x4,
+        0x0,
+        0x4,
+        0x4,
+        0x4,
+        0x0,
+        0x4,
+        0x4,
+        0x0,
+        0x4,
+        0x0,
+        0x0,
+        0x4,
+        0x0,
+        0x4,
+        0x0,
+        0x4,
+        0x0,
+        0x4,
+        0x0,
+        0x4,
+        0x0,
+        0x4,
+        0x4,
+        0x0,
+        0x4,
+        0x0,
+        0x0,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x0,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x0,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x0,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x0,
+        0x4,
+        0x0,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x0,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x4,
+        0x4,
+        0x4,
+        0x0,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x0,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x4,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x4,
+        0x4,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+    ],
+    [
+        0x36,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+    ],
+    [
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x0,
+        0x0,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+    ],
+    [
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+    ],
+    [
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+    ],
+    [
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+    ],
+    [
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x32,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0xab,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0xaa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+];
@@ -0,0 +1,994 @@
+// GENERATED CODE DO NOT MANUALLY EDIT
+/// enum for the Unicode scripts. The variants are the long names of the scripts with the
+/// underscores removed and are documented with their ISO 15924 codes.
+/// Each version of Unicode adds scripts, so a `match` on a `Script` needs a wildcard arm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Script {
+	/// Zzzz
+	Unknown,
+	/// Adlm
+	Adlam,
+	/// Aghb
+	CaucasianAlbanian,
+	/// Ahom
+	Ahom,
+	/// Arab
+	Arabic,
+	/// Armi
+	ImperialAramaic,
+	/// Armn
+	Armenian,
+	/// Avst
+	Avestan,
+	/// Bali
+	Balinese,
+	/// Bamu
+	Bamum,
+	/// Bass
+	BassaVah,
+	/// Batk
+	Batak,
+	/// Beng
+	Bengali,
+	/// Bhks
+	Bhaiksuki,
+	/// Bopo
+	Bopomofo,
+	/// Brah
+	Brahmi,
+	/// Brai
+	Braille,
+	/// Bugi
+	Buginese,
+	/// Buhd
+	Buhid,
+	/// Cakm
+	Chakma,
+	/// Cans
+	CanadianAboriginal,
+	/// Cari
+	Carian,
+	/// Cham
+	Cham,
+	/// Cher
+	Cherokee,
+	/// Chrs
+	Chorasmian,
+	/// Copt
+	Coptic,
+	/// Cpmn
+	CyproMinoan,
+	/// Cprt
+	Cypriot,
+	/// Cyrl
+	Cyrillic,
+	/// Deva
+	Devanagari,
+	/// Diak
+	DivesAkuru,
+	/// Dogr
+	Dogra,
+	/// Dsrt
+	Deseret,
+	/// Dupl
+	Duployan,
+	/// Egyp
+	EgyptianHieroglyphs,
+	/// Elba
+	Elbasan,
+	/// Elym
+	Elymaic,
+	/// Ethi
+	Ethiopic,
+	/// Gara
+	Garay,
+	/// Geor
+	Georgian,
+	/// Glag
+	Glagolitic,
+	/// Gong
+	GunjalaGondi,
+	/// Gonm
+	MasaramGondi,
+	/// Goth
+	Gothic,
+	/// Gran
+	Grantha,
+	/// Grek
+	Greek,
+	/// Gujr
+	Gujarati,
+	/// Gukh
+	GurungKhema,
+	/// Guru
+	Gurmukhi,
+	/// Hang
+	Hangul,
+	/// Hani
+	Han,
+	/// Hano
+	Hanunoo,
+	/// Hatr
+	Hatran,
+	/// Hebr
+	Hebrew,
+	/// Hira
+	Hiragana,
+	/// Hluw
+	AnatolianHieroglyphs,
+	/// Hmng
+	PahawhHmong,
+	/// Hmnp
+	NyiakengPuachueHmong,
+	/// Hrkt
+	KatakanaOrHiragana,
+	/// Hung
+	OldHungarian,
+	/// Ital
+	OldItalic,
+	/// Java
+	Javanese,
+	/// Kali
+	KayahLi,
+	/// Kana
+	Katakana,
+	/// Kawi
+	Kawi,
+	/// Khar
+	Kharoshthi,
+	/// Khmr
+	Khmer,
+	/// Khoj
+	Khojki,
+	/// Kits
+	KhitanSmallScript,
+	/// Knda
+	Kannada,
+	/// Krai
+	KiratRai,
+	/// Kthi
+	Kaithi,
+	/// Lana
+	TaiTham,
+	/// Laoo
+	Lao,
+	/// Latn
+	Latin,
+	/// Lepc
+	Lepcha,
+	/// Limb
+	Limbu,
+	/// Lina
+	LinearA,
+	/// Linb
+	LinearB,
+	/// Lisu
+	Lisu,
+	/// Lyci
+	Lycian,
+	/// Lydi
+	Lydian,
+	/// Mahj
+	Mahajani,
+	/// Maka
+	Makasar,
+	/// Mand
+	Mandaic,
+	/// Mani
+	Manichaean,
+	/// Marc
+	Marchen,
+	/// Medf
+	Medefaidrin,
+	/// Mend
+	MendeKikakui,
+	/// Merc
+	MeroiticCursive,
+	/// Mero
+	MeroiticHieroglyphs,
+	/// Mlym
+	Malayalam,
+	/// Modi
+	Modi,
+	/// Mong
+	Mongolian,
+	/// Mroo
+	Mro,
+	/// Mtei
+	MeeteiMayek,
+	/// Mult
+	Multani,
+	/// Mymr
+	Myanmar,
+	/// Nagm
+	NagMundari,
+	/// Nand
+	Nandinagari,
+	/// Narb
+	OldNorthArabian,
+	/// Nbat
+	Nabataean,
+	/// Newa
+	Newa,
+	/// Nkoo
+	Nko,
+	/// Nshu
+	Nushu,
+	/// Ogam
+	Ogham,
+	/// Olck
+	OlChiki,
+	/// Onao
+	OlOnal,
+	/// Orkh
+	OldTurkic,
+	/// Orya
+	Oriya,
+	/// Osge
+	Osage,
+	/// Osma
+	Osmanya,
+	/// Ougr
+	OldUyghur,
+	/// Palm
+	Palmyrene,
+	/// Pauc
+	PauCinHau,
+	/// Perm
+	OldPermic,
+	/// Phag
+	PhagsPa,
+	/// Phli
+	InscriptionalPahlavi,
+	/// Phlp
+	PsalterPahlavi,
+	/// Phnx
+	Phoenician,
+	/// Plrd
+	Miao,
+	/// Prti
+	InscriptionalParthian,
+	/// Rjng
+	Rejang,
+	/// Rohg
+	HanifiRohingya,
+	/// Runr
+	Runic,
+	/// Samr
+	Samaritan,
+	/// Sarb
+	OldSouthArabian,
+	/// Saur
+	Saurashtra,
+	/// Sgnw
+	SignWriting,
+	/// Shaw
+	Shavian,
+	/// Shrd
+	Sharada,
+	/// Sidd
+	Siddham,
+	/// Sind
+	Khudawadi,
+	/// Sinh
+	Sinhala,
+	/// Sogd
+	Sogdian,
+	/// Sogo
+	OldSogdian,
+	/// Sora
+	SoraSompeng,
+	/// Soyo
+	Soyombo,
+	/// Sund
+	Sundanese,
+	/// Sunu
+	Sunuwar,
+	/// Sylo
+	SylotiNagri,
+	/// Syrc
+	Syriac,
+	/// Tagb
+	Tagbanwa,
+	/// Takr
+	Takri,
+	/// Tale
+	TaiLe,
+	/// Talu
+	NewTaiLue,
+	/// Taml
+	Tamil,
+	/// Tang
+	Tangut,
+	/// Tavt
+	TaiViet,
+	/// Telu
+	Telugu,
+	/// Tfng
+	Tifinagh,
+	/// Tglg
+	Tagalog,
+	/// Thaa
+	Thaana,
+	/// Thai
+	Thai,
+	/// Tibt
+	Tibetan,
+	/// Tirh
+	Tirhuta,
+	/// Tnsa
+	Tangsa,
+	/// Todr
+	Todhri,
+	/// Toto
+	Toto,
+	/// Tutg
+	TuluTigalari,
+	/// Ugar
+	Ugaritic,
+	/// Vaii
+	Vai,
+	/// Vith
+	Vithkuqi,
+	/// Wara
+	WarangCiti,
+	/// Wcho
+	Wancho,
+	/// Xpeo
+	OldPersian,
+	/// Xsux
+	Cuneiform,
+	/// Yezi
+	Yezidi,
+	/// Yiii
+	Yi,
+	/// Zanb
+	ZanabazarSquare,
+	/// Zinh
+	Inherited,
+	/// Zyyy
+	Common,
+}
+pub static SCRIPTS: [Script;172] = [
+	Script::Unknown,
+	Script::Adlam,
+	Script::CaucasianAlbanian,
+	Script::Ahom,
+	Script::Arabic,
+	Script::ImperialAramaic,
+	Script::Armenian,
+	Script::Avestan,
+	Script::Balinese,
+	Script::Bamum,
+	Script::BassaVah,
+	Script::Batak,
+	Script::Bengali,
+	Script::Bhaiksuki,
+	Script::Bopomofo,
+	Script::Brahmi,
+	Script::Braille,
+	Script::Buginese,
+	Script::Buhid,
+	Script::Chakma,
+	Script::CanadianAboriginal,
+	Script::Carian,
+	Script::Cham,
+	Script::Cherokee,
+	Script::Chorasmian,
+	Script::Coptic,
+	Script::CyproMinoan,
+	Script::Cypriot,
+	Script::Cyrillic,
+	Script::Devanagari,
+	Script::DivesAkuru,
+	Script::Dogra,
+	Script::Deseret,
+	Script::Duployan,
+	Script::EgyptianHieroglyphs,
+	Script::Elbasan,
+	Script::Elymaic,
+	Script::Ethiopic,
+	Script::Garay,
+	Script::Georgian,
+	Script::Glagolitic,
+	Script::GunjalaGondi,
+	Script::MasaramGondi,
+	Script::Gothic,
+	Script::Grantha,
+	Script::Greek,
+	Script::Gujarati,
+	Script::GurungKhema,
+	Script::Gurmukhi,
+	Script::Hangul,
+	Script::Han,
+	Script::Hanunoo,
+	Script::Hatran,
+	Script::Hebrew,
+	Script::Hiragana,
+	Script::AnatolianHieroglyphs,
+	Script::PahawhHmong,
+	Script::NyiakengPuachueHmong,
+	Script::KatakanaOrHiragana,
+	Script::OldHungarian,
+	Script::OldItalic,
+	Script::Javanese,
+	Script::KayahLi,
+	Script::Katakana,
+	Script::Kawi,
+	Script::Kharoshthi,
+	Script::Khmer,
+	Script::Khojki,
+	Script::KhitanSmallScript,
+	Script::Kannada,
+	Script::KiratRai,
+	Script::Kaithi,
+	Script::TaiTham,
+	Script::Lao,
+	Script::Latin,
+	Script::Lepcha,
+	Script::Limbu,
+	Script::LinearA,
+	Script::LinearB,
+	Script::Lisu,
+	Script::Lycian,
+	Script::Lydian,
+	Script::Mahajani,
+	Script::Makasar,
+	Script::Mandaic,
+	Script::Manichaean,
+	Script::Marchen,
+	Script::Medefaidrin,
+	Script::MendeKikakui,
+	Script::MeroiticCursive,
+	Script::MeroiticHieroglyphs,
+	Script::Malayalam,
+	Script::Modi,
+	Script::Mongolian,
+	Script::Mro,
+	Script::MeeteiMayek,
+	Script::Multani,
+	Script::Myanmar,
+	Script::NagMundari,
+	Script::Nandinagari,
+	Script::OldNorthArabian,
+	Script::Nabataean,
+	Script::Newa,
+	Script::Nko,
+	Script::Nushu,
+	Script::Ogham,
+	Script::OlChiki,
+	Script::OlOnal,
+	Script::OldTurkic,
+	Script::Oriya,
+	Script::Osage,
+	Script::Osmanya,
+	Script::OldUyghur,
+	Script::Palmyrene,
+	Script::PauCinHau,
+	Script::OldPermic,
+	Script::PhagsPa,
+	Script::InscriptionalPahlavi,
+	Script::PsalterPahlavi,
+	Script::Phoenician,
+	Script::Miao,
+	Script::InscriptionalParthian,
+	Script::Rejang,
+	Script::HanifiRohingya,
+	Script::Runic,
+	Script::Samaritan,
+	Script::OldSouthArabian,
+	Script::Saurashtra,
+	Script::SignWriting,
+	Script::Shavian,
+	Script::Sharada,
+	Script::Siddham,
+	Script::Khudawadi,
+	Script::Sinhala,
+	Script::Sogdian,
+	Script::OldSogdian,
+	Script::SoraSompeng,
+	Script::Soyombo,
+	Script::Sundanese,
+	Script::Sunuwar,
+	Script::SylotiNagri,
+	Script::Syriac,
+	Script::Tagbanwa,
+	Script::Takri,
+	Script::TaiLe,
+	Script::NewTaiLue,
+	Script::Tamil,
+	Script::Tangut,
+	Script::TaiViet,
+	Script::Telugu,
+	Script::Tifinagh,
+	Script::Tagalog,
+	Script::Thaana,
+	Script::Thai,
+	Script::Tibetan,
+	Script::Tirhuta,
+	Script::Tangsa,
+	Script::Todhri,
+	Script::Toto,
+	Script::TuluTigalari,
+	Script::Ugaritic,
+	Script::Vai,
+	Script::Vithkuqi,
+	Script::WarangCiti,
+	Script::Wancho,
+	Script::OldPersian,
+	Script::Cuneiform,
+	Script::Yezidi,
+	Script::Yi,
+	Script::ZanabazarSquare,
+	Script::Inherited,
+	Script::Common,
+];
+pub const SCRIPT_CODES: [&str;172] = [
+	"Zzzz",
+	"Adlm",
+	"Aghb",
+	"Ahom",
+	"Arab",
+	"Armi",
+	"Armn",
+	"Avst",
+	"Bali",
+	"Bamu",
+	"Bass",
+	"Batk",
+	"Beng",
+	"Bhks",
+	"Bopo",
+	"Brah",
+	"Brai",
+	"Bugi",
+	"Buhd",
+	"Cakm",
+	"Cans",
+	"Cari",
+	"Cham",
+	"Cher",
+	"Chrs",
+	"Copt",
+	"Cpmn",
+	"Cprt",
+	"Cyrl",
+	"Deva",
+	"Diak",
+	"Dogr",
+	"Dsrt",
+	"Dupl",
+	"Egyp",
+	"Elba",
+	"Elym",
+	"Ethi",
+	"Gara",
+	"Geor",
+	"Glag",
+	"Gong",
+	"Gonm",
+	"Goth",
+	"Gran",
+	"Grek",
+	"Gujr",
+	"Gukh",
+	"Guru",
+	"Hang",
+	"Hani",
+	"Hano",
+	"Hatr",
+	"Hebr",
+	"Hira",
+	"Hluw",
+	"Hmng",
+	"Hmnp",
+	"Hrkt",
+	"Hung",
+	"Ital",
+	"Java",
+	"Kali",
+	"Kana",
+	"Kawi",
+	"Khar",
+	"Khmr",
+	"Khoj",
+	"Kits",
+	"Knda",
+	"Krai",
+	"Kthi",
+	"Lana",
+	"Laoo",
+	"Latn",
+	"Lepc",
+	"Limb",
+	"Lina",
+	"Linb",
+	"Lisu",
+	"Lyci",
+	"Lydi",
+	"Mahj",
+	"Maka",
+	"Mand",
+	"Mani",
+	"Marc",
+	"Medf",
+	"Mend",
+	"Merc",
+	"Mero",
+	"Mlym",
+	"Modi",
+	"Mong",
+	"Mroo",
+	"Mtei",
+	"Mult",
+	"Mymr",
+	"Nagm",
+	"Nand",
+	"Narb",
+	"Nbat",
+	"Newa",
+	"Nkoo",
+	"Nshu",
+	"Ogam",
+	"Olck",
+	"Onao",
+	"Orkh",
+	"Orya",
+	"Osge",
+	"Osma",
+	"Ougr",
+	"Palm",
+	"Pauc",
+	"Perm",
+	"Phag",
+	"Phli",
+	"Phlp",
+	"Phnx",
+	"Plrd",
+	"Prti",
+	"Rjng",
+	"Rohg",
+	"Runr",
+	"Samr",
+	"Sarb",
+	"Saur",
+	"Sgnw",
+	"Shaw",
+	"Shrd",
+	"Sidd",
+	"Sind",
+	"Sinh",
+	"Sogd",
+	"Sogo",
+	"Sora",
+	"Soyo",
+	"Sund",
+	"Sunu",
+	"Sylo",
+	"Syrc",
+	"Tagb",
+	"Takr",
+	"Tale",
+	"Talu",
+	"Taml",
+	"Tang",
+	"Tavt",
+	"Telu",
+	"Tfng",
+	"Tglg",
+	"Thaa",
+	"Thai",
+	"Tibt",
+	"Tirh",
+	"Tnsa",
+	"Todr",
+	"Toto",
+	"Tutg",
+	"Ugar",
+	"Vaii",
+	"Vith",
+	"Wara",
+	"Wcho",
+	"Xpeo",
+	"Xsux",
+	"Yezi",
+	"Yiii",
+	"Zanb",
+	"Zinh",
+	"Zyyy",
+];
+pub const SCRIPT_NAMES: [&str;172] = [
+	"Unknown",
+	"Adlam",
+	"Caucasian_Albanian",
+	"Ahom",
+	"Arabic",
+	"Imperial_Aramaic",
+	"Armenian",
+	"Avestan",
+	"Balinese",
+	"Bamum",
+	"Bassa_Vah",
+	"Batak",
+	"Bengali",
+	"Bhaiksuki",
+	"Bopomofo",
+	"Brahmi",
+	"Braille",
+	"Buginese",
+	"Buhid",
+	"Chakma",
+	"Canadian_Aboriginal",
+	"Carian",
+	"Cham",
+	"Cherokee",
+	"Chorasmian",
+	"Coptic",
+	"Cypro_Minoan",
+	"Cypriot",
+	"Cyrillic",
+	"Devanagari",
+	"Dives_Akuru",
+	"Dogra",
+	"Deseret",
+	"Duployan",
+	"Egyptian_Hieroglyphs",
+	"Elbasan",
+	"Elymaic",
+	"Ethiopic",
+	"Garay",
+	"Georgian",
+	"Glagolitic",
+	"Gunjala_Gondi",
+	"Masaram_Gondi",
+	"Gothic",
+	"Grantha",
+	"Greek",
+	"Gujarati",
+	"Gurung_Khema",
+	"Gurmukhi",
+	"Hangul",
+	"Han",
+	"Hanunoo",
+	"Hatran",
+	"Hebrew",
+	"Hiragana",
+	"Anatolian_Hieroglyphs",
+	"Pahawh_Hmong",
+	"Nyiakeng_Puachue_Hmong",
+	"Katakana_Or_Hiragana",
+	"Old_Hungarian",
+	"Old_Italic",
+	"Javanese",
+	"Kayah_Li",
+	"Katakana",
+	"Kawi",
+	"Kharoshthi",
+	"Khmer",
+	"Khojki",
+	"Khitan_Small_Script",
+	"Kannada",
+	"Kirat_Rai",
+	"Kaithi",
+	"Tai_Tham",
+	"Lao",
+	"Latin",
+	"Lepcha",
+	"Limbu",
+	"Linear_A",
+	"Linear_B",
+	"Lisu",
+	"Lycian",
+	"Lydian",
+	"Mahajani",
+	"Makasar",
+	"Mandaic",
+	"Manichaean",
+	"Marchen",
+	"Medefaidrin",
+	"Mende_Kikakui",
+	"Meroitic_Cursive",
+	"Meroitic_Hieroglyphs",
+	"Malayalam",
+	"Modi",
+	"Mongolian",
+	"Mro",
+	"Meetei_Mayek",
+	"Multani",
+	"Myanmar",
+	"Nag_Mundari",
+	"Nandinagari",
+	"Old_North_Arabian",
+	"Nabataean",
+	"Newa",
+	"Nko",
+	"Nushu",
+	"Ogham",
+	"Ol_Chiki",
+	"Ol_Onal",
+	"Old_Turkic",
+	"Oriya",
+	"Osage",
+	"Osmanya",
+	"Old_Uyghur",
+	"Palmyrene",
+	"Pau_Cin_Hau",
+	"Old_Permic",
+	"Phags_Pa",
+	"Inscriptional_Pahlavi",
+	"Psalter_Pahlavi",
+	"Phoenician",
+	"Miao",
+	"Inscriptional_Parthian",
+	"Rejang",
+	"Hanifi_Rohingya",
+	"Runic",
+	"Samaritan",
+	"Old_South_Arabian",
+	"Saurashtra",
+	"SignWriting",
+	"Shavian",
+	"Sharada",
+	"Siddham",
+	"Khudawadi",
+	"Sinhala",
+	"Sogdian",
+	"Old_Sogdian",
+	"Sora_Sompeng",
+	"Soyombo",
+	"Sundanese",
+	"Sunuwar",
+	"Syloti_Nagri",
+	"Syriac",
+	"Tagbanwa",
+	"Takri",
+	"Tai_Le",
+	"New_Tai_Lue",
+	"Tamil",
+	"Tangut",
+	"Tai_Viet",
+	"Telugu",
+	"Tifinagh",
+	"Tagalog",
+	"Thaana",
+	"Thai",
+	"Tibetan",
+	"Tirhuta",
+	"Tangsa",
+	"Todhri",
+	"Toto",
+	"Tulu_Tigalari",
+	"Ugaritic",
+	"Vai",
+	"Vithkuqi",
+	"Warang_Citi",
+	"Wancho",
+	"Old_Persian",
+	"Cuneiform",
+	"Yezidi",
+	"Yi",
+	"Zanabazar_Square",
+	"Inherited",
+	"Common",
+];
+pub const SCRIPT_EXTENSIONS: [&[Script];118] = [
+	&[Script::Avestan, Script::Carian, Script::Coptic, Script::Duployan, Script::Elbasan, Script::Georgian, Script::Glagolitic, Script::GunjalaGondi, Script::Gothic, Script::Greek, Script::Han, Script::Latin, Script::Lydian, Script::Mahajani, Script::OldPermic, Script::Shavian],
+	&[Script::Bengali, Script::Cyrillic, Script::Devanagari, Script::Latin, Script::Lisu, Script::Thai, Script::Toto],
+	&[Script::Bopomofo, Script::Latin],
+	&[Script::Latin, Script::Lisu],
+	&[Script::Latin, Script::Thai],
+	&[Script::Cherokee, Script::Coptic, Script::Cyrillic, Script::Greek, Script::Latin, Script::OldPermic, Script::Sunuwar, Script::TaiLe],
+	&[Script::Cherokee, Script::Cyrillic, Script::Greek, Script::Latin, Script::Osage, Script::Sunuwar, Script::TaiLe, Script::Todhri],
+	&[Script::Cherokee, Script::Cyrillic, Script::Latin, Script::Tifinagh],
+	&[Script::Glagolitic, Script::Latin, Script::Sunuwar, Script::Syriac, Script::Thai],
+	&[Script::CaucasianAlbanian, Script::Cherokee, Script::Coptic, Script::Cyrillic, Script::Gothic, Script::Greek, Script::Latin, Script::Osage, Script::Syriac, Script::Tifinagh, Script::Todhri],
+	&[Script::Coptic, Script::Elbasan, Script::Glagolitic, Script::Gothic, Script::Katakana, Script::Latin],
+	&[Script::Cyrillic, Script::Greek, Script::Latin, Script::OldPermic, Script::Tifinagh],
+	&[Script::Coptic, Script::Duployan, Script::Hebrew, Script::Latin, Script::OldPermic, Script::Syriac, Script::TaiLe, Script::Tifinagh, Script::Todhri],
+	&[Script::Armenian, Script::Cyrillic, Script::Duployan, Script::Gothic, Script::Greek, Script::Hebrew, Script::Latin, Script::OldPermic, Script::Syriac, Script::TaiLe, Script::Tifinagh],
+	&[Script::Latin, Script::Tifinagh],
+	&[Script::Duployan, Script::Latin, Script::Syriac],
+	&[Script::Cherokee, Script::Cyrillic, Script::Latin, Script::Osage],
+	&[Script::Cherokee, Script::Latin, Script::TaiLe],
+	&[Script::Latin, Script::Sunuwar],
+	&[Script::Ethiopic, Script::Latin],
+	&[Script::Cyrillic, Script::Latin, Script::Todhri],
+	&[Script::Greek, Script::Latin, Script::OldPermic, Script::Todhri],
+	&[Script::Cherokee, Script::Duployan, Script::Katakana, Script::Latin, Script::Syriac, Script::Tifinagh],
+	&[Script::Cherokee, Script::Duployan, Script::Latin, Script::Syriac],
+	&[Script::Latin, Script::Syriac],
+	&[Script::Latin, Script::Sunuwar, Script::Syriac],
+	&[Script::Cherokee, Script::Latin, Script::Syriac],
+	&[Script::CaucasianAlbanian, Script::Cherokee, Script::Gothic, Script::Latin, Script::Sunuwar, Script::Syriac, Script::Thai],
+	&[Script::Greek],
+	&[Script::Latin, Script::Osage],
+	&[Script::CaucasianAlbanian, Script::Latin, Script::Todhri],
+	&[Script::Latin],
+	&[Script::Coptic, Script::Greek],
+	&[Script::Cyrillic, Script::OldPermic],
+	&[Script::Cyrillic, Script::Glagolitic],
+	&[Script::Cyrillic, Script::Latin],
+	&[Script::Armenian, Script::Georgian, Script::Glagolitic],
+	&[Script::Arabic, Script::Garay, Script::Nko, Script::HanifiRohingya, Script::Syriac, Script::Thaana, Script::Yezidi],
+	&[Script::Arabic, Script::Syriac, Script::Thaana],
+	&[Script::Adlam, Script::Arabic, Script::Garay, Script::Nko, Script::HanifiRohingya, Script::Syriac, Script::Thaana, Script::Yezidi],
+	&[Script::Adlam, Script::Arabic, Script::Mandaic, Script::Manichaean, Script::OldUyghur, Script::PsalterPahlavi, Script::HanifiRohingya, Script::Sogdian, Script::Syriac],
+	&[Script::Arabic, Script::Syriac],
+	&[Script::Arabic, Script::Thaana, Script::Yezidi],
+	&[Script::Arabic, Script::HanifiRohingya],
+	&[Script::Bengali, Script::Devanagari, Script::Grantha, Script::Gujarati, Script::Gurmukhi, Script::Kannada, Script::Latin, Script::Malayalam, Script::Nandinagari, Script::Newa, Script::Oriya, Script::Sharada, Script::Tamil, Script::Telugu, Script::Tirhuta],
+	&[Script::Bengali, Script::Devanagari, Script::Grantha, Script::Gujarati, Script::Gurmukhi, Script::Kannada, Script::Latin, Script::Malayalam, Script::Newa, Script::Oriya, Script::Tamil, Script::Telugu, Script::Tirhuta],
+	&[Script::Bengali, Script::Devanagari, Script::Dogra, Script::GunjalaGondi, Script::MasaramGondi, Script::Grantha, Script::Gujarati, Script::Gurmukhi, Script::Kannada, Script::Mahajani, Script::Malayalam, Script::Nandinagari, Script::OlOnal, Script::Oriya, Script::Khudawadi, Script::Sinhala, Script::SylotiNagri, Script::Takri, Script::Tamil, Script::Telugu, Script::Tirhuta],
+	&[Script::Bengali, Script::Devanagari, Script::Dogra, Script::GunjalaGondi, Script::MasaramGondi, Script::Grantha, Script::Gujarati, Script::GurungKhema, Script::Gurmukhi, Script::Kannada, Script::Limbu, Script::Mahajani, Script::Malayalam, Script::Nandinagari, Script::OlOnal, Script::Oriya, Script::Khudawadi, Script::Sinhala, Script::SylotiNagri, Script::Takri, Script::Tamil, Script::Telugu, Script::Tirhuta],
+	&[Script::Devanagari, Script::Dogra, Script::Kaithi, Script::Mahajani],
+	&[Script::Bengali, Script::Chakma, Script::SylotiNagri],
+	&[Script::Gurmukhi, Script::Multani],
+	&[Script::Gujarati, Script::Khojki],
+	&[Script::Grantha, Script::Tamil],
+	&[Script::Kannada, Script::Nandinagari, Script::TuluTigalari],
+	&[Script::Chakma, Script::Myanmar, Script::TaiLe],
+	&[Script::Georgian, Script::Glagolitic, Script::Latin],
+	&[Script::Runic],
+	&[Script::Buhid, Script::Hanunoo, Script::Tagbanwa, Script::Tagalog],
+	&[Script::Mongolian, Script::PhagsPa],
+	&[Script::Bengali, Script::Devanagari, Script::Grantha, Script::Kannada],
+	&[Script::Devanagari],
+	&[Script::Devanagari, Script::Grantha, Script::Kannada],
+	&[Script::Bengali, Script::Devanagari, Script::Newa, Script::Telugu, Script::Tirhuta],
+	&[Script::Bengali, Script::Devanagari, Script::Telugu],
+	&[Script::Devanagari, Script::Newa, Script::Sharada],
+	&[Script::Bengali, Script::Devanagari, Script::Newa, Script::Telugu],
+	&[Script::Devanagari, Script::Sharada],
+	&[Script::Devanagari, Script::Kannada, Script::Malayalam, Script::Oriya, Script::Tamil, Script::Telugu],
+	&[Script::Bengali, Script::Devanagari],
+	&[Script::Devanagari, Script::Newa, Script::Tirhuta],
+	&[Script::Devanagari, Script::Nandinagari, Script::Newa],
+	&[Script::Bengali, Script::Devanagari, Script::Sharada],
+	&[Script::Devanagari, Script::Newa],
+	&[Script::Bengali, Script::Devanagari, Script::Newa, Script::Sharada],
+	&[Script::Bengali, Script::Devanagari, Script::Grantha, Script::Kannada, Script::Malayalam, Script::Nandinagari, Script::Oriya, Script::Sinhala, Script::Telugu, Script::Tirhuta, Script::TuluTigalari],
+	&[Script::Devanagari, Script::Grantha],
+	&[Script::Devanagari, Script::Grantha, Script::Kannada, Script::TuluTigalari],
+	&[Script::Bengali],
+	&[Script::Nandinagari],
+	&[Script::Cyrillic, Script::Latin, Script::Syriac],
+	&[Script::Syriac],
+	&[Script::Latin, Script::Mongolian, Script::PhagsPa],
+	&[Script::Adlam, Script::Arabic],
+	&[Script::Carian, Script::Georgian, Script::Glagolitic, Script::OldHungarian, Script::Lycian, Script::OldTurkic],
+	&[Script::Carian, Script::Greek, Script::OldHungarian, Script::MeroiticHieroglyphs],
+	&[Script::Devanagari, Script::Grantha, Script::Latin],
+	&[Script::Coptic, Script::Latin],
+	&[Script::Avestan, Script::OldTurkic],
+	&[Script::Avestan, Script::Carian, Script::Georgian, Script::OldHungarian, Script::Kaithi, Script::Lydian, Script::Samaritan],
+	&[Script::Duployan],
+	&[Script::Adlam, Script::Arabic, Script::OldHungarian],
+	&[Script::Han, Script::Tangut],
+	&[Script::Bopomofo, Script::Hangul, Script::Han, Script::Hiragana, Script::Katakana, Script::Mongolian, Script::Yi],
+	&[Script::Bopomofo, Script::Hangul, Script::Han, Script::Hiragana, Script::Katakana, Script::Mongolian, Script::PhagsPa, Script::Yi],
+	&[Script::Bopomofo, Script::Hangul, Script::Han, Script::Hiragana, Script::Katakana],
+	&[Script::Han],
+	&[Script::Bopomofo, Script::Hangul, Script::Han, Script::Hiragana, Script::Katakana, Script::Mongolian, Script::Tibetan, Script::Yi],
+	&[Script::Bopomofo, Script::Hangul, Script::Han, Script::Hiragana, Script::Katakana, Script::Lisu, Script::Mongolian, Script::Tibetan, Script::Yi],
+	&[Script::Bopomofo, Script::Hangul, Script::Han, Script::Hiragana, Script::Katakana, Script::Yi],
+	&[Script::Bopomofo, Script::Han],
+	&[Script::Hiragana, Script::Katakana],
+	&[Script::Han, Script::Hiragana, Script::Katakana],
+	&[Script::Han, Script::Latin],
+	&[Script::Devanagari, Script::Dogra, Script::Gujarati, Script::Gurmukhi, Script::Khojki, Script::Kannada, Script::Kaithi, Script::Mahajani, Script::Malayalam, Script::Modi, Script::Nandinagari, Script::Sharada, Script::Khudawadi, Script::Takri, Script::Tirhuta, Script::TuluTigalari],
+	&[Script::Devanagari, Script::Dogra, Script::Gujarati, Script::Gurmukhi, Script::Khojki, Script::Kannada, Script::Kaithi, Script::Mahajani, Script::Modi, Script::Nandinagari, Script::Sharada, Script::Khudawadi, Script::Takri, Script::Tirhuta, Script::TuluTigalari],
+	&[Script::Devanagari, Script::Dogra, Script::Gujarati, Script::Gurmukhi, Script::Khojki, Script::Kaithi, Script::Mahajani, Script::Modi, Script::Khudawadi, Script::Takri, Script::Tirhuta],
+	&[Script::Devanagari, Script::Dogra, Script::Gujarati, Script::Gurmukhi, Script::Khojki, Script::Kaithi, Script::Mahajani, Script::Modi, Script::Sharada, Script::Khudawadi, Script::Takri, Script::Tirhuta],
+	&[Script::Bengali, Script::Devanagari, Script::TuluTigalari],
+	&[Script::Devanagari, Script::Tamil],
+	&[Script::KayahLi, Script::Latin, Script::Myanmar],
+	&[Script::Buginese, Script::Javanese],
+	&[Script::Arabic, Script::Nko],
+	&[Script::Arabic, Script::Thaana],
+	&[Script::CyproMinoan, Script::Cypriot, Script::LinearB],
+	&[Script::Cypriot, Script::LinearB],
+	&[Script::Cypriot, Script::LinearA, Script::LinearB],
+	&[Script::Arabic, Script::Coptic],
+	&[Script::Manichaean, Script::OldUyghur],
+];
@@ -0,0 +1,40477 @@
+// GENERATED CODE DO NOT MANUALLY EDIT
+pub const SB_TABLE: [u8;0x1100] = [
+	 0, // 0x0
+	 1, // 0x1
+	 2, // 0x2
+	 3, // 0x3
+	 4, // 0x4
+	 5, // 0x5
+	 6, // 0x6
+	 7, // 0x7
+	 8, // 0x8
+	 9, // 0x9
+	 10, // 0xa
+	 11, // 0xb
+	 12, // 0xc
+	 13, // 0xd
+	 14, // 0xe
+	 15, // 0xf
+	 16, // 0x10
+	 17, // 0x11
+	 18, // 0x12
+	 19, // 0x13
+	 20, // 0x14
+	 17, // 0x15
+	 21, // 0x16
+	 22, // 0x17
+	 23, // 0x18
+	 24, // 0x19
+	 25, // 0x1a
+	 26, // 0x1b
+	 27, // 0x1c
+	 28, // 0x1d
+	 29, // 0x1e
+	 30, // 0x1f
+	 31, // 0x20
+	 32, // 0x21
+	 33, // 0x22
+	 34, // 0x23
+	 35, // 0x24
+	 33, // 0x25
+	 33, // 0x26
+	 36, // 0x27
+	 33, // 0x28
+	 37, // 0x29
+	 33, // 0x2a
+	 33, // 0x2b
+	 38, // 0x2c
+	 39, // 0x2d
+	 40, // 0x2e
+	 33, // 0x2f
+	 41, // 0x30
+	 42, // 0x31
+	 33, // 0x32
+	 33, // 0x33
+	 17, // 0x34
+	 17, // 0x35
+	 17, // 0x36
+	 17, // 0x37
+	 17, // 0x38
+	 17, // 0x39
+	 17, // 0x3a
+	 17, // 0x3b
+	 17, // 0x3c
+	 17, // 0x3d
+	 17, // 0x3e
+	 17, // 0x3f
+	 17, // 0x40
+	 17, // 0x41
+	 17, // 0x42
+	 17, // 0x43
+	 17, // 0x44
+	 17, // 0x45
+	 17, // 0x46
+	 17, // 0x47
+	 17, // 0x48
+	 17, // 0x49
+	 17, // 0x4a
+	 17, // 0x4b
+	 17, // 0x4c
+	 43, // 0x4d
+	 17, // 0x4e
+	 17, // 0x4f
+	 17, // 0x50
+	 17, // 0x51
+	 17, // 0x52
+	 17, // 0x53
+	 17, // 0x54
+	 17, // 0x55
+	 17, // 0x56
+	 17, // 0x57
+	 17, // 0x58
+	 17, // 0x59
+	 17, // 0x5a
+	 17, // 0x5b
+	 17, // 0x5c
+	 17, // 0x5d
+	 17, // 0x5e
+	 17, // 0x5f
+	 17, // 0x60
+	 17, // 0x61
+	 17, // 0x62
+	 17, // 0x63
+	 17, // 0x64
+	 17, // 0x65
+	 17, // 0x66
+	 17, // 0x67
+	 17, // 0x68
+	 17, // 0x69
+	 17, // 0x6a
+	 17, // 0x6b
+	 17, // 0x6c
+	 17, // 0x6d
+	 17, // 0x6e
+	 17, // 0x6f
+	 17, // 0x70
+	 17, // 0x71
+	 17, // 0x72
+	 17, // 0x73
+	 17, // 0x74
+	 17, // 0x75
+	 17, // 0x76
+	 17, // 0x77
+	 17, // 0x78
+	 17, // 0x79
+	 17, // 0x7a
+	 17, // 0x7b
+	 17, // 0x7c
+	 17, // 0x7d
+	 17, // 0x7e
+	 17, // 0x7f
+	 17, // 0x80
+	 17, // 0x81
+	 17, // 0x82
+	 17, // 0x83
+	 17, // 0x84
+	 17, // 0x85
+	 17, // 0x86
+	 17, // 0x87
+	 17, // 0x88
+	 17, // 0x89
+	 17, // 0x8a
+	 17, // 0x8b
+	 17, // 0x8c
+	 17, // 0x8d
+	 17, // 0x8e
+	 17, // 0x8f
+	 17, // 0x90
+	 17, // 0x91
+	 17, // 0x92
+	 17, // 0x93
+	 17, // 0x94
+	 17, // 0x95
+	 17, // 0x96
+	 17, // 0x97
+	 17, // 0x98
+	 17, // 0x99
+	 17, // 0x9a
+	 17, // 0x9b
+	 17, // 0x9c
+	 17, // 0x9d
+	 17, // 0x9e
+	 17, // 0x9f
+	 17, // 0xa0
+	 17, // 0xa1
+	 17, // 0xa2
+	 17, // 0xa3
+	 44, // 0xa4
+	 17, // 0xa5
+	 45, // 0xa6
+	 46, // 0xa7
+	 47, // 0xa8
+	 48, // 0xa9
+	 49, // 0xaa
+	 50, // 0xab
+	 17, // 0xac
+	 17, // 0xad
+	 17, // 0xae
+	 17, // 0xaf
+	 17, // 0xb0
+	 17, // 0xb1
+	 17, // 0xb2
+	 17, // 0xb3
+	 17, // 0xb4
+	 17, // 0xb5
+	 17, // 0xb6
+	 17, // 0xb7
+	 17, // 0xb8
+	 17, // 0xb9
+	 17, // 0xba
+	 17, // 0xbb
+	 17, // 0xbc
+	 17, // 0xbd
+	 17, // 0xbe
+	 17, // 0xbf
+	 17, // 0xc0
+	 17, // 0xc1
+	 17, // 0xc2
+	 17, // 0xc3
+	 17, // 0xc4
+	 17, // 0xc5
+	 17, // 0xc6
+	 17, // 0xc7
+	 17, // 0xc8
+	 17, // 0xc9
+	 17, // 0xca
+	 17, // 0xcb
+	 17, // 0xcc
+	 17, // 0xcd
+	 17, // 0xce
+	 17, // 0xcf
+	 17, // 0xd0
+	 17, // 0xd1
+	 17, // 0xd2
+	 17, // 0xd3
+	 17, // 0xd4
+	 17, // 0xd5
+	 17, // 0xd6
+	 51, // 0xd7
+	 33, // 0xd8
+	 33, // 0xd9
+	 33, // 0xda
+	 33, // 0xdb
+	 33, // 0xdc
+	 33, // 0xdd
+	 33, // 0xde
+	 33, // 0xdf
+	 33, // 0xe0
+	 33, // 0xe1
+	 33, // 0xe2
+	 33, // 0xe3
+	 33, // 0xe4
+	 33, // 0xe5
+	 33, // 0xe6
+	 33, // 0xe7
+	 33, // 0xe8
+	 33, // 0xe9
+	 33, // 0xea
+	 33, // 0xeb
+	 33, // 0xec
+	 33, // 0xed
+	 33, // 0xee
+	 33, // 0xef
+	 33, // 0xf0
+	 33, // 0xf1
+	 33, // 0xf2
+	 33, // 0xf3
+	 33, // 0xf4
+	 33, // 0xf5
+	 33, // 0xf6
+	 33, // 0xf7
+	 33, // 0xf8
+	 17, // 0xf9
+	 52, // 0xfa
+	 53, // 0xfb
+	 17, // 0xfc
+	 54, // 0xfd
+	 55, // 0xfe
+	 56, // 0xff
+	 57, // 0x100
+	 58, // 0x101
+	 59, // 0x102
+	 60, // 0x103
+	 61, // 0x104
+	 62, // 0x105
+	 17, // 0x106
+	 63, // 0x107
+	 64, // 0x108
+	 65, // 0x109
+	 66, // 0x10a
+	 67, // 0x10b
+	 68, // 0x10c
+	 69, // 0x10d
+	 70, // 0x10e
+	 71, // 0x10f
+	 72, // 0x110
+	 73, // 0x111
+	 74, // 0x112
+	 75, // 0x113
+	 76, // 0x114
+	 77, // 0x115
+	 78, // 0x116
+	 79, // 0x117
+	 80, // 0x118
+	 81, // 0x119
+	 82, // 0x11a
+	 83, // 0x11b
+	 84, // 0x11c
+	 85, // 0x11d
+	 86, // 0x11e
+	 87, // 0x11f
+	 17, // 0x120
+	 17, // 0x121
+	 17, // 0x122
+	 88, // 0x123
+	 89, // 0x124
+	 90, // 0x125
+	 33, // 0x126
+	 33, // 0x127
+	 33, // 0x128
+	 33, // 0x129
+	 33, // 0x12a
+	 33, // 0x12b
+	 33, // 0x12c
+	 33, // 0x12d
+	 33, // 0x12e
+	 91, // 0x12f
+	 17, // 0x130
+	 17, // 0x131
+	 17, // 0x132
+	 17, // 0x133
+	 92, // 0x134
+	 17, // 0x135
+	 17, // 0x136
+	 17, // 0x137
+	 17, // 0x138
+	 17, // 0x139
+	 17, // 0x13a
+	 17, // 0x13b
+	 17, // 0x13c
+	 17, // 0x13d
+	 17, // 0x13e
+	 17, // 0x13f
+	 17, // 0x140
+	 17, // 0x141
+	 17, // 0x142
+	 93, // 0x143
+	 17, // 0x144
+	 17, // 0x145
+	 94, // 0x146
+	 33, // 0x147
+	 33, // 0x148
+	 33, // 0x149
+	 33, // 0x14a
+	 33, // 0x14b
+	 33, // 0x14c
+	 33, // 0x14d
+	 33, // 0x14e
+	 33, // 0x14f
+	 33, // 0x150
+	 33, // 0x151
+	 33, // 0x152
+	 33, // 0x153
+	 33, // 0x154
+	 33, // 0x155
+	 33, // 0x156
+	 33, // 0x157
+	 33, // 0x158
+	 33, // 0x159
+	 33, // 0x15a
+	 33, // 0x15b
+	 33, // 0x15c
+	 33, // 0x15d
+	 33, // 0x15e
+	 33, // 0x15f
+	 33, // 0x160
+	 95, // 0x161
+	 33, // 0x162
+	 33, // 0x163
+	 33, // 0x164
+	 33, // 0x165
+	 33, // 0x166
+	 33, // 0x167
+	 17, // 0x168
+	 17, // 0x169
+	 96, // 0x16a
+	 97, // 0x16b
+	 33, // 0x16c
+	 98, // 0x16d
+	 99, // 0x16e
+	 100, // 0x16f
+	 17, // 0x170
+	 17, // 0x171
+	 17, // 0x172
+	 17, // 0x173
+	 17, // 0x174
+	 17, // 0x175
+	 17, // 0x176
+	 17, // 0x177
+	 17, // 0x178
+	 17, // 0x179
+	 17, // 0x17a
+	 17, // 0x17b
+	 17, // 0x17c
+	 17, // 0x17d
+	 17, // 0x17e
+	 17, // 0x17f
+	 17, // 0x180
+	 17, // 0x181
+	 17, // 0x182
+	 17, // 0x183
+	 17, // 0x184
+	 17, // 0x185
+	 17, // 0x186
+	 101, // 0x187
+	 17, // 0x188
+	 17, // 0x189
+	 17, // 0x18a
+	 17, // 0x18b
+	 102, // 0x18c
+	 103, // 0x18d
+	 33, // 0x18e
+	 33, // 0x18f
+	 33, // 0x190
+	 33, // 0x191
+	 33, // 0x192
+	 33, // 0x193
+	 33, // 0x194
+	 33, // 0x195
+	 33, // 0x196
+	 33, // 0x197
+	 33, // 0x198
+	 33, // 0x199
+	 33, // 0x19a
+	 33, // 0x19b
+	 33, // 0x19c
+	 33, // 0x19d
+	 33, // 0x19e
+	 33, // 0x19f
+	 33, // 0x1a0
+	 33, // 0x1a1
+	 33, // 0x1a2
+	 33, // 0x1a3
+	 33, // 0x1a4
+	 33, // 0x1a5
+	 33, // 0x1a6
+	 33, // 0x1a7
+	 33, // 0x1a8
+	 33, // 0x1a9
+	 33, // 0x1aa
+	 33, // 0x1ab
+	 33, // 0x1ac
+	 33, // 0x1ad
+	 33, // 0x1ae
+	 104, // 0x1af
+	 17, // 0x1b0
+	 105, // 0x1b1
+	 106, // 0x1b2
+	 33, // 0x1b3
+	 33, // 0x1b4
+	 33, // 0x1b5
+	 33, // 0x1b6
+	 33, // 0x1b7
+	 33, // 0x1b8
+	 33, // 0x1b9
+	 33, // 0x1ba
+	 33, // 0x1bb
+	 107, // 0x1bc
+	 33, // 0x1bd
+	 33, // 0x1be
+	 33, // 0x1bf
+	 33, // 0x1c0
+	 33, // 0x1c1
+	 33, // 0x1c2
+	 33, // 0x1c3
+	 33, // 0x1c4
+	 33, // 0x1c5
+	 33, // 0x1c6
+	 33, // 0x1c7
+	 33, // 0x1c8
+	 33, // 0x1c9
+	 33, // 0x1ca
+	 33, // 0x1cb
+	 108, // 0x1cc
+	 33, // 0x1cd
+	 33, // 0x1ce
+	 109, // 0x1cf
+	 33, // 0x1d0
+	 110, // 0x1d1
+	 111, // 0x1d2
+	 33, // 0x1d3
+	 112, // 0x1d4
+	 113, // 0x1d5
+	 114, // 0x1d6
+	 115, // 0x1d7
+	 33, // 0x1d8
+	 33, // 0x1d9
+	 116, // 0x1da
+	 33, // 0x1db
+	 33, // 0x1dc
+	 33, // 0x1dd
+	 33, // 0x1de
+	 117, // 0x1df
+	 118, // 0x1e0
+	 119, // 0x1e1
+	 120, // 0x1e2
+	 33, // 0x1e3
+	 121, // 0x1e4
+	 122, // 0x1e5
+	 33, // 0x1e6
+	 123, // 0x1e7
+	 124, // 0x1e8
+	 125, // 0x1e9
+	 33, // 0x1ea
+	 33, // 0x1eb
+	 33, // 0x1ec
+	 33, // 0x1ed
+	 126, // 0x1ee
+	 33, // 0x1ef
+	 33, // 0x1f0
+	 127, // 0x1f1
+	 33, // 0x1f2
+	 33, // 0x1f3
+	 33, // 0x1f4
+	 33, // 0x1f5
+	 128, // 0x1f6
+	 33, // 0x1f7
+	 33, // 0x1f8
+	 33, // 0x1f9
+	 33, // 0x1fa
+	 108, // 0x1fb
+	 33, // 0x1fc
+	 33, // 0x1fd
+	 33, // 0x1fe
+	 33, // 0x1ff
+	 17, // 0x200
+	 17, // 0x201
+	 17, // 0x202
+	 17, // 0x203
+	 17, // 0x204
+	 17, // 0x205
+	 17, // 0x206
+	 17, // 0x207
+	 17, // 0x208
+	 17, // 0x209
+	 17, // 0x20a
+	 17, // 0x20b
+	 17, // 0x20c
+	 17, // 0x20d
+	 17, // 0x20e
+	 17, // 0x20f
+	 17, // 0x210
+	 17, // 0x211
+	 17, // 0x212
+	 17, // 0x213
+	 17, // 0x214
+	 17, // 0x215
+	 17, // 0x216
+	 17, // 0x217
+	 17, // 0x218
+	 17, // 0x219
+	 17, // 0x21a
+	 17, // 0x21b
+	 17, // 0x21c
+	 17, // 0x21d
+	 17, // 0x21e
+	 17, // 0x21f
+	 17, // 0x220
+	 17, // 0x221
+	 17, // 0x222
+	 17, // 0x223
+	 17, // 0x224
+	 17, // 0x225
+	 17, // 0x226
+	 17, // 0x227
+	 17, // 0x228
+	 17, // 0x229
+	 17, // 0x22a
+	 17, // 0x22b
+	 17, // 0x22c
+	 17, // 0x22d
+	 17, // 0x22e
+	 17, // 0x22f
+	 17, // 0x230
+	 17, // 0x231
+	 17, // 0x232
+	 17, // 0x233
+	 17, // 0x234
+	 17, // 0x235
+	 17, // 0x236
+	 17, // 0x237
+	 17, // 0x238
+	 17, // 0x239
+	 17, // 0x23a
+	 17, // 0x23b
+	 17, // 0x23c
+	 17, // 0x23d
+	 17, // 0x23e
+	 17, // 0x23f
+	 17, // 0x240
+	 17, // 0x241
+	 17, // 0x242
+	 17, // 0x243
+	 17, // 0x244
+	 17, // 0x245
+	 17, // 0x246
+	 17, // 0x247
+	 17, // 0x248
+	 17, // 0x249
+	 17, // 0x24a
+	 17, // 0x24b
+	 17, // 0x24c
+	 17, // 0x24d
+	 17, // 0x24e
+	 17, // 0x24f
+	 17, // 0x250
+	 17, // 0x251
+	 17, // 0x252
+	 17, // 0x253
+	 17, // 0x254
+	 17, // 0x255
+	 17, // 0x256
+	 17, // 0x257
+	 17, // 0x258
+	 17, // 0x259
+	 17, // 0x25a
+	 17, // 0x25b
+	 17, // 0x25c
+	 17, // 0x25d
+	 17, // 0x25e
+	 17, // 0x25f
+	 17, // 0x260
+	 17, // 0x261
+	 17, // 0x262
+	 17, // 0x263
+	 17, // 0x264
+	 17, // 0x265
+	 17, // 0x266
+	 17, // 0x267
+	 17, // 0x268
+	 17, // 0x269
+	 17, // 0x26a
+	 17, // 0x26b
+	 17, // 0x26c
+	 17, // 0x26d
+	 17, // 0x26e
+	 17, // 0x26f
+	 17, // 0x270
+	 17, // 0x271
+	 17, // 0x272
+	 17, // 0x273
+	 17, // 0x274
+	 17, // 0x275
+	 17, // 0x276
+	 17, // 0x277
+	 17, // 0x278
+	 17, // 0x279
+	 17, // 0x27a
+	 17, // 0x27b
+	 17, // 0x27c
+	 17, // 0x27d
+	 17, // 0x27e
+	 17, // 0x27f
+	 17, // 0x280
+	 17, // 0x281
+	 17, // 0x282
+	 17, // 0x283
+	 17, // 0x284
+	 17, // 0x285
+	 17, // 0x286
+	 17, // 0x287
+	 17, // 0x288
+	 17, // 0x289
+	 17, // 0x28a
+	 17, // 0x28b
+	 17, // 0x28c
+	 17, // 0x28d
+	 17, // 0x28e
+	 17, // 0x28f
+	 17, // 0x290
+	 17, // 0x291
+	 17, // 0x292
+	 17, // 0x293
+	 17, // 0x294
+	 17, // 0x295
+	 17, // 0x296
+	 17, // 0x297
+	 17, // 0x298
+	 17, // 0x299
+	 17, // 0x29a
+	 17, // 0x29b
+	 17, // 0x29c
+	 17, // 0x29d
+	 17, // 0x29e
+	 17, // 0x29f
+	 17, // 0x2a0
+	 17, // 0x2a1
+	 17, // 0x2a2
+	 17, // 0x2a3
+	 17, // 0x2a4
+	 17, // 0x2a5
+	 129, // 0x2a6
+	 17, // 0x2a7
+	 17, // 0x2a8
+	 17, // 0x2a9
+	 17, // 0x2aa
+	 17, // 0x2ab
+	 17, // 0x2ac
+	 17, // 0x2ad
+	 17, // 0x2ae
+	 17, // 0x2af
+	 17, // 0x2b0
+	 17, // 0x2b1
+	 17, // 0x2b2
+	 17, // 0x2b3
+	 17, // 0x2b4
+	 17, // 0x2b5
+	 17, // 0x2b6
+	 130, // 0x2b7
+	 131, // 0x2b8
+	 17, // 0x2b9
+	 17, // 0x2ba
+	 17, // 0x2bb
+	 17, // 0x2bc
+	 17, // 0x2bd
+	 17, // 0x2be
+	 17, // 0x2bf
+	 17, // 0x2c0
+	 17, // 0x2c1
+	 17, // 0x2c2
+	 17, // 0x2c3
+	 17, // 0x2c4
+	 17, // 0x2c5
+	 17, // 0x2c6
+	 17, // 0x2c7
+	 17, // 0x2c8
+	 17, // 0x2c9
+	 17, // 0x2ca
+	 17, // 0x2cb
+	 17, // 0x2cc
+	 17, // 0x2cd
+	 132, // 0x2ce
+	 17, // 0x2cf
+	 17, // 0x2d0
+	 17, // 0x2d1
+	 17, // 0x2d2
+	 17, // 0x2d3
+	 17, // 0x2d4
+	 17, // 0x2d5
+	 17, // 0x2d6
+	 17, // 0x2d7
+	 17, // 0x2d8
+	 17, // 0x2d9
+	 17, // 0x2da
+	 17, // 0x2db
+	 17, // 0x2dc
+	 17, // 0x2dd
+	 17, // 0x2de
+	 17, // 0x2df
+	 17, // 0x2e0
+	 17, // 0x2e1
+	 17, // 0x2e2
+	 17, // 0x2e3
+	 17, // 0x2e4
+	 17, // 0x2e5
+	 17, // 0x2e6
+	 17, // 0x2e7
+	 17, // 0x2e8
+	 17, // 0x2e9
+	 17, // 0x2ea
+	 133, // 0x2eb
+	 17, // 0x2ec
+	 17, // 0x2ed
+	 134, // 0x2ee
+	 33, // 0x2ef
+	 33, // 0x2f0
+	 33, // 0x2f1
+	 33, // 0x2f2
+	 33, // 0x2f3
+	 33, // 0x2f4
+	 33, // 0x2f5
+	 33, // 0x2f6
+	 33, // 0x2f7
+	 17, // 0x2f8
+	 17, // 0x2f9
+	 135, // 0x2fa
+	 33, // 0x2fb
+	 33, // 0x2fc
+	 33, // 0x2fd
+	 33, // 0x2fe
+	 33, // 0x2ff
+	 17, // 0x300
+	 17, // 0x301
+	 17, // 0x302
+	 17, // 0x303
+	 17, // 0x304
+	 17, // 0x305
+	 17, // 0x306
+	 17, // 0x307
+	 17, // 0x308
+	 17, // 0x309
+	 17, // 0x30a
+	 17, // 0x30b
+	 17, // 0x30c
+	 17, // 0x30d
+	 17, // 0x30e
+	 17, // 0x30f
+	 17, // 0x310
+	 17, // 0x311
+	 17, // 0x312
+	 136, // 0x313
+	 17, // 0x314
+	 17, // 0x315
+	 17, // 0x316
+	 17, // 0x317
+	 17, // 0x318
+	 17, // 0x319
+	 17, // 0x31a
+	 17, // 0x31b
+	 17, // 0x31c
+	 17, // 0x31d
+	 17, // 0x31e
+	 17, // 0x31f
+	 17, // 0x320
+	 17, // 0x321
+	 17, // 0x322
+	 137, // 0x323
+	 33, // 0x324
+	 33, // 0x325
+	 33, // 0x326
+	 33, // 0x327
+	 33, // 0x328
+	 33, // 0x329
+	 33, // 0x32a
+	 33, // 0x32b
+	 33, // 0x32c
+	 33, // 0x32d
+	 33, // 0x32e
+	 33, // 0x32f
+	 33, // 0x330
+	 33, // 0x331
+	 33, // 0x332
+	 33, // 0x333
+	 33, // 0x334
+	 33, // 0x335
+	 33, // 0x336
+	 33, // 0x337
+	 33, // 0x338
+	 33, // 0x339
+	 33, // 0x33a
+	 33, // 0x33b
+	 33, // 0x33c
+	 33, // 0x33d
+	 33, // 0x33e
+	 33, // 0x33f
+	 33, // 0x340
+	 33, // 0x341
+	 33, // 0x342
+	 33, // 0x343
+	 33, // 0x344
+	 33, // 0x345
+	 33, // 0x346
+	 33, // 0x347
+	 33, // 0x348
+	 33, // 0x349
+	 33, // 0x34a
+	 33, // 0x34b
+	 33, // 0x34c
+	 33, // 0x34d
+	 33, // 0x34e
+	 33, // 0x34f
+	 33, // 0x350
+	 33, // 0x351
+	 33, // 0x352
+	 33, // 0x353
+	 33, // 0x354
+	 33, // 0x355
+	 33, // 0x356
+	 33, // 0x357
+	 33, // 0x358
+	 33, // 0x359
+	 33, // 0x35a
+	 33, // 0x35b
+	 33, // 0x35c
+	 33, // 0x35d
+	 33, // 0x35e
+	 33, // 0x35f
+	 33, // 0x360
+	 33, // 0x361
+	 33, // 0x362
+	 33, // 0x363
+	 33, // 0x364
+	 33, // 0x365
+	 33, // 0x366
+	 33, // 0x367
+	 33, // 0x368
+	 33, // 0x369
+	 33, // 0x36a
+	 33, // 0x36b
+	 33, // 0x36c
+	 33, // 0x36d
+	 33, // 0x36e
+	 33, // 0x36f
+	 33, // 0x370
+	 33, // 0x371
+	 33, // 0x372
+	 33, // 0x373
+	 33, // 0x374
+	 33, // 0x375
+	 33, // 0x376
+	 33, // 0x377
+	 33, // 0x378
+	 33, // 0x379
+	 33, // 0x37a
+	 33, // 0x37b
+	 33, // 0x37c
+	 33, // 0x37d
+	 33, // 0x37e
+	 33, // 0x37f
+	 33, // 0x380
+	 33, // 0x381
+	 33, // 0x382
+	 33, // 0x383
+	 33, // 0x384
+	 33, // 0x385
+	 33, // 0x386
+	 33, // 0x387
+	 33, // 0x388
+	 33, // 0x389
+	 33, // 0x38a
+	 33, // 0x38b
+	 33, // 0x38c
+	 33, // 0x38d
+	 33, // 0x38e
+	 33, // 0x38f
+	 33, // 0x390
+	 33, // 0x391
+	 33, // 0x392
+	 33, // 0x393
+	 33, // 0x394
+	 33, // 0x395
+	 33, // 0x396
+	 33, // 0x397
+	 33, // 0x398
+	 33, // 0x399
+	 33, // 0x39a
+	 33, // 0x39b
+	 33, // 0x39c
+	 33, // 0x39d
+	 33, // 0x39e
+	 33, // 0x39f
+	 33, // 0x3a0
+	 33, // 0x3a1
+	 33, // 0x3a2
+	 33, // 0x3a3
+	 33, // 0x3a4
+	 33, // 0x3a5
+	 33, // 0x3a6
+	 33, // 0x3a7
+	 33, // 0x3a8
+	 33, // 0x3a9
+	 33, // 0x3aa
+	 33, // 0x3ab
+	 33, // 0x3ac
+	 33, // 0x3ad
+	 33, // 0x3ae
+	 33, // 0x3af
+	 33, // 0x3b0
+	 33, // 0x3b1
+	 33, // 0x3b2
+	 33, // 0x3b3
+	 33, // 0x3b4
+	 33, // 0x3b5
+	 33, // 0x3b6
+	 33, // 0x3b7
+	 33, // 0x3b8
+	 33, // 0x3b9
+	 33, // 0x3ba
+	 33, // 0x3bb
+	 33, // 0x3bc
+	 33, // 0x3bd
+	 33, // 0x3be
+	 33, // 0x3bf
+	 33, // 0x3c0
+	 33, // 0x3c1
+	 33, // 0x3c2
+	 33, // 0x3c3
+	 33, // 0x3c4
+	 33, // 0x3c5
+	 33, // 0x3c6
+	 33, // 0x3c7
+	 33, // 0x3c8
+	 33, // 0x3c9
+	 33, // 0x3ca
+	 33, // 0x3cb
+	 33, // 0x3cc
+	 33, // 0x3cd
+	 33, // 0x3ce
+	 33, // 0x3cf
+	 33, // 0x3d0
+	 33, // 0x3d1
+	 33, // 0x3d2
+	 33, // 0x3d3
+	 33, // 0x3d4
+	 33, // 0x3d5
+	 33, // 0x3d6
+	 33, // 0x3d7
+	 33, // 0x3d8
+	 33, // 0x3d9
+	 33, // 0x3da
+	 33, // 0x3db
+	 33, // 0x3dc
+	 33, // 0x3dd
+	 33, // 0x3de
+	 33, // 0x3df
+	 33, // 0x3e0
+	 33, // 0x3e1
+	 33, // 0x3e2
+	 33, // 0x3e3
+	 33, // 0x3e4
+	 33, // 0x3e5
+	 33, // 0x3e6
+	 33, // 0x3e7
+	 33, // 0x3e8
+	 33, // 0x3e9
+	 33, // 0x3ea
+	 33, // 0x3eb
+	 33, // 0x3ec
+	 33, // 0x3ed
+	 33, // 0x3ee
+	 33, // 0x3ef
+	 33, // 0x3f0
+	 33, // 0x3f1
+	 33, // 0x3f2
+	 33, // 0x3f3
+	 33, // 0x3f4
+	 33, // 0x3f5
+	 33, // 0x3f6
+	 33, // 0x3f7
+	 33, // 0x3f8
+	 33, // 0x3f9
+	 33, // 0x3fa
+	 33, // 0x3fb
+	 33, // 0x3fc
+	 33, // 0x3fd
+	 33, // 0x3fe
+	 33, // 0x3ff
+	 33, // 0x400
+	 33, // 0x401
+	 33, // 0x402
+	 33, // 0x403
+	 33, // 0x404
+	 33, // 0x405
+	 33, // 0x406
+	 33, // 0x407
+	 33, // 0x408
+	 33, // 0x409
+	 33, // 0x40a
+	 33, // 0x40b
+	 33, // 0x40c
+	 33, // 0x40d
+	 33, // 0x40e
+	 33, // 0x40f
+	 33, // 0x410
+	 33, // 0x411
+	 33, // 0x412
+	 33, // 0x413
+	 33, // 0x414
+	 33, // 0x415
+	 33, // 0x416
+	 33, // 0x417
+	 33, // 0x418
+	 33, // 0x419
+	 33, // 0x41a
+	 33, // 0x41b
+	 33, // 0x41c
+	 33, // 0x41d
+	 33, // 0x41e
+	 33, // 0x41f
+	 33, // 0x420
+	 33, // 0x421
+	 33, // 0x422
+	 33, // 0x423
+	 33, // 0x424
+	 33, // 0x425
+	 33, // 0x426
+	 33, // 0x427
+	 33, // 0x428
+	 33, // 0x429
+	 33, // 0x42a
+	 33, // 0x42b
+	 33, // 0x42c
+	 33, // 0x42d
+	 33, // 0x42e
+	 33, // 0x42f
+	 33, // 0x430
+	 33, // 0x431
+	 33, // 0x432
+	 33, // 0x433
+	 33, // 0x434
+	 33, // 0x435
+	 33, // 0x436
+	 33, // 0x437
+	 33, // 0x438
+	 33, // 0x439
+	 33, // 0x43a
+	 33, // 0x43b
+	 33, // 0x43c
+	 33, // 0x43d
+	 33, // 0x43e
+	 33, // 0x43f
+	 33, // 0x440
+	 33, // 0x441
+	 33, // 0x442
+	 33, // 0x443
+	 33, // 0x444
+	 33, // 0x445
+	 33, // 0x446
+	 33, // 0x447
+	 33, // 0x448
+	 33, // 0x449
+	 33, // 0x44a
+	 33, // 0x44b
+	 33, // 0x44c
+	 33, // 0x44d
+	 33, // 0x44e
+	 33, // 0x44f
+	 33, // 0x450
+	 33, // 0x451
+	 33, // 0x452
+	 33, // 0x453
+	 33, // 0x454
+	 33, // 0x455
+	 33, // 0x456
+	 33, // 0x457
+	 33, // 0x458
+	 33, // 0x459
+	 33, // 0x45a
+	 33, // 0x45b
+	 33, // 0x45c
+	 33, // 0x45d
+	 33, // 0x45e
+	 33, // 0x45f
+	 33, // 0x460
+	 33, // 0x461
+	 33, // 0x462
+	 33, // 0x463
+	 33, // 0x464
+	 33, // 0x465
+	 33, // 0x466
+	 33, // 0x467
+	 33, // 0x468
+	 33, // 0x469
+	 33, // 0x46a
+	 33, // 0x46b
+	 33, // 0x46c
+	 33, // 0x46d
+	 33, // 0x46e
+	 33, // 0x46f
+	 33, // 0x470
+	 33, // 0x471
+	 33, // 0x472
+	 33, // 0x473
+	 33, // 0x474
+	 33, // 0x475
+	 33, // 0x476
+	 33, // 0x477
+	 33, // 0x478
+	 33, // 0x479
+	 33, // 0x47a
+	 33, // 0x47b
+	 33, // 0x47c
+	 33, // 0x47d
+	 33, // 0x47e
+	 33, // 0x47f
+	 33, // 0x480
+	 33, // 0x481
+	 33, // 0x482
+	 33, // 0x483
+	 33, // 0x484
+	 33, // 0x485
+	 33, // 0x486
+	 33, // 0x487
+	 33, // 0x488
+	 33, // 0x489
+	 33, // 0x48a
+	 33, // 0x48b
+	 33, // 0x48c
+	 33, // 0x48d
+	 33, // 0x48e
+	 33, // 0x48f
+	 33, // 0x490
+	 33, // 0x491
+	 33, // 0x492
+	 33, // 0x493
+	 33, // 0x494
+	 33, // 0x495
+	 33, // 0x496
+	 33, // 0x497
+	 33, // 0x498
+	 33, // 0x499
+	 33, // 0x49a
+	 33, // 0x49b
+	 33, // 0x49c
+	 33, // 0x49d
+	 33, // 0x49e
+	 33, // 0x49f
+	 33, // 0x4a0
+	 33, // 0x4a1
+	 33, // 0x4a2
+	 33, // 0x4a3
+	 33, // 0x4a4
+	 33, // 0x4a5
+	 33, // 0x4a6
+	 33, // 0x4a7
+	 33, // 0x4a8
+	 33, // 0x4a9
+	 33, // 0x4aa
+	 33, // 0x4ab
+	 33, // 0x4ac
+	 33, // 0x4ad
+	 33, // 0x4ae
+	 33, // 0x4af
+	 33, // 0x4b0
+	 33, // 0x4b1
+	 33, // 0x4b2
+	 33, // 0x4b3
+	 33, // 0x4b4
+	 33, // 0x4b5
+	 33, // 0x4b6
+	 33, // 0x4b7
+	 33, // 0x4b8
+	 33, // 0x4b9
+	 33, // 0x4ba
+	 33, // 0x4bb
+	 33, // 0x4bc
+	 33, // 0x4bd
+	 33, // 0x4be
+	 33, // 0x4bf
+	 33, // 0x4c0
+	 33, // 0x4c1
+	 33, // 0x4c2
+	 33, // 0x4c3
+	 33, // 0x4c4
+	 33, // 0x4c5
+	 33, // 0x4c6
+	 33, // 0x4c7
+	 33, // 0x4c8
+	 33, // 0x4c9
+	 33, // 0x4ca
+	 33, // 0x4cb
+	 33, // 0x4cc
+	 33, // 0x4cd
+	 33, // 0x4ce
+	 33, // 0x4cf
+	 33, // 0x4d0
+	 33, // 0x4d1
+	 33, // 0x4d2
+	 33, // 0x4d3
+	 33, // 0x4d4
+	 33, // 0x4d5
+	 33, // 0x4d6
+	 33, // 0x4d7
+	 33, // 0x4d8
+	 33, // 0x4d9
+	 33, // 0x4da
+	 33, // 0x4db
+	 33, // 0x4dc
+	 33, // 0x4dd
+	 33, // 0x4de
+	 33, // 0x4df
+	 33, // 0x4e0
+	 33, // 0x4e1
+	 33, // 0x4e2
+	 33, // 0x4e3
+	 33, // 0x4e4
+	 33, // 0x4e5
+	 33, // 0x4e6
+	 33, // 0x4e7
+	 33, // 0x4e8
+	 33, // 0x4e9
+	 33, // 0x4ea
+	 33, // 0x4eb
+	 33, // 0x4ec
+	 33, // 0x4ed
+	 33, // 0x4ee
+	 33, // 0x4ef
+	 33, // 0x4f0
+	 33, // 0x4f1
+	 33, // 0x4f2
+	 33, // 0x4f3
+	 33, // 0x4f4
+	 33, // 0x4f5
+	 33, // 0x4f6
+	 33, // 0x4f7
+	 33, // 0x4f8
+	 33, // 0x4f9
+	 33, // 0x4fa
+	 33, // 0x4fb
+	 33, // 0x4fc
+	 33, // 0x4fd
+	 33, // 0x4fe
+	 33, // 0x4ff
+	 33, // 0x500
+	 33, // 0x501
+	 33, // 0x502
+	 33, // 0x503
+	 33, // 0x504
+	 33, // 0x505
+	 33, // 0x506
+	 33, // 0x507
+	 33, // 0x508
+	 33, // 0x509
+	 33, // 0x50a
+	 33, // 0x50b
+	 33, // 0x50c
+	 33, // 0x50d
+	 33, // 0x50e
+	 33, // 0x50f
+	 33, // 0x510
+	 33, // 0x511
+	 33, // 0x512
+	 33, // 0x513
+	 33, // 0x514
+	 33, // 0x515
+	 33, // 0x516
+	 33, // 0x517
+	 33, // 0x518
+	 33, // 0x519
+	 33, // 0x51a
+	 33, // 0x51b
+	 33, // 0x51c
+	 33, // 0x51d
+	 33, // 0x51e
+	 33, // 0x51f
+	 33, // 0x520
+	 33, // 0x521
+	 33, // 0x522
+	 33, // 0x523
+	 33, // 0x524
+	 33, // 0x525
+	 33, // 0x526
+	 33, // 0x527
+	 33, // 0x528
+	 33, // 0x529
+	 33, // 0x52a
+	 33, // 0x52b
+	 33, // 0x52c
+	 33, // 0x52d
+	 33, // 0x52e
+	 33, // 0x52f
+	 33, // 0x530
+	 33, // 0x531
+	 33, // 0x532
+	 33, // 0x533
+	 33, // 0x534
+	 33, // 0x535
+	 33, // 0x536
+	 33, // 0x537
+	 33, // 0x538
+	 33, // 0x539
+	 33, // 0x53a
+	 33, // 0x53b
+	 33, // 0x53c
+	 33, // 0x53d
+	 33, // 0x53e
+	 33, // 0x53f
+	 33, // 0x540
+	 33, // 0x541
+	 33, // 0x542
+	 33, // 0x543
+	 33, // 0x544
+	 33, // 0x545
+	 33, // 0x546
+	 33, // 0x547
+	 33, // 0x548
+	 33, // 0x549
+	 33, // 0x54a
+	 33, // 0x54b
+	 33, // 0x54c
+	 33, // 0x54d
+	 33, // 0x54e
+	 33, // 0x54f
+	 33, // 0x550
+	 33, // 0x551
+	 33, // 0x552
+	 33, // 0x553
+	 33, // 0x554
+	 33, // 0x555
+	 33, // 0x556
+	 33, // 0x557
+	 33, // 0x558
+	 33, // 0x559
+	 33, // 0x55a
+	 33, // 0x55b
+	 33, // 0x55c
+	 33, // 0x55d
+	 33, // 0x55e
+	 33, // 0x55f
+	 33, // 0x560
+	 33, // 0x561
+	 33, // 0x562
+	 33, // 0x563
+	 33, // 0x564
+	 33, // 0x565
+	 33, // 0x566
+	 33, // 0x567
+	 33, // 0x568
+	 33, // 0x569
+	 33, // 0x56a
+	 33, // 0x56b
+	 33, // 0x56c
+	 33, // 0x56d
+	 33, // 0x56e
+	 33, // 0x56f
+	 33, // 0x570
+	 33, // 0x571
+	 33, // 0x572
+	 33, // 0x573
+	 33, // 0x574
+	 33, // 0x575
+	 33, // 0x576
+	 33, // 0x577
+	 33, // 0x578
+	 33, // 0x579
+	 33, // 0x57a
+	 33, // 0x57b
+	 33, // 0x57c
+	 33, // 0x57d
+	 33, // 0x57e
+	 33, // 0x57f
+	 33, // 0x580
+	 33, // 0x581
+	 33, // 0x582
+	 33, // 0x583
+	 33, // 0x584
+	 33, // 0x585
+	 33, // 0x586
+	 33, // 0x587
+	 33, // 0x588
+	 33, // 0x589
+	 33, // 0x58a
+	 33, // 0x58b
+	 33, // 0x58c
+	 33, // 0x58d
+	 33, // 0x58e
+	 33, // 0x58f
+	 33, // 0x590
+	 33, // 0x591
+	 33, // 0x592
+	 33, // 0x593
+	 33, // 0x594
+	 33, // 0x595
+	 33, // 0x596
+	 33, // 0x597
+	 33, // 0x598
+	 33, // 0x599
+	 33, // 0x59a
+	 33, // 0x59b
+	 33, // 0x59c
+	 33, // 0x59d
+	 33, // 0x59e
+	 33, // 0x59f
+	 33, // 0x5a0
+	 33, // 0x5a1
+	 33, // 0x5a2
+	 33, // 0x5a3
+	 33, // 0x5a4
+	 33, // 0x5a5
+	 33, // 0x5a6
+	 33, // 0x5a7
+	 33, // 0x5a8
+	 33, // 0x5a9
+	 33, // 0x5aa
+	 33, // 0x5ab
+	 33, // 0x5ac
+	 33, // 0x5ad
+	 33, // 0x5ae
+	 33, // 0x5af
+	 33, // 0x5b0
+	 33, // 0x5b1
+	 33, // 0x5b2
+	 33, // 0x5b3
+	 33, // 0x5b4
+	 33, // 0x5b5
+	 33, // 0x5b6
+	 33, // 0x5b7
+	 33, // 0x5b8
+	 33, // 0x5b9
+	 33, // 0x5ba
+	 33, // 0x5bb
+	 33, // 0x5bc
+	 33, // 0x5bd
+	 33, // 0x5be
+	 33, // 0x5bf
+	 33, // 0x5c0
+	 33, // 0x5c1
+	 33, // 0x5c2
+	 33, // 0x5c3
+	 33, // 0x5c4
+	 33, // 0x5c5
+	 33, // 0x5c6
+	 33, // 0x5c7
+	 33, // 0x5c8
+	 33, // 0x5c9
+	 33, // 0x5ca
+	 33, // 0x5cb
+	 33, // 0x5cc
+	 33, // 0x5cd
+	 33, // 0x5ce
+	 33, // 0x5cf
+	 33, // 0x5d0
+	 33, // 0x5d1
+	 33, // 0x5d2
+	 33, // 0x5d3
+	 33, // 0x5d4
+	 33, // 0x5d5
+	 33, // 0x5d6
+	 33, // 0x5d7
+	 33, // 0x5d8
+	 33, // 0x5d9
+	 33, // 0x5da
+	 33, // 0x5db
+	 33, // 0x5dc
+	 33, // 0x5dd
+	 33, // 0x5de
+	 33, // 0x5df
+	 33, // 0x5e0
+	 33, // 0x5e1
+	 33, // 0x5e2
+	 33, // 0x5e3
+	 33, // 0x5e4
+	 33, // 0x5e5
+	 33, // 0x5e6
+	 33, // 0x5e7
+	 33, // 0x5e8
+	 33, // 0x5e9
+	 33, // 0x5ea
+	 33, // 0x5eb
+	 33, // 0x5ec
+	 33, // 0x5ed
+	 33, // 0x5ee
+	 33, // 0x5ef
+	 33, // 0x5f0
+	 33, // 0x5f1
+	 33, // 0x5f2
+	 33, // 0x5f3
+	 33, // 0x5f4
+	 33, // 0x5f5
+	 33, // 0x5f6
+	 33, // 0x5f7
+	 33, // 0x5f8
+	 33, // 0x5f9
+	 33, // 0x5fa
+	 33, // 0x5fb
+	 33, // 0x5fc
+	 33, // 0x5fd
+	 33, // 0x5fe
+	 33, // 0x5ff
+	 33, // 0x600
+	 33, // 0x601
+	 33, // 0x602
+	 33, // 0x603
+	 33, // 0x604
+	 33, // 0x605
+	 33, // 0x606
+	 33, // 0x607
+	 33, // 0x608
+	 33, // 0x609
+	 33, // 0x60a
+	 33, // 0x60b
+	 33, // 0x60c
+	 33, // 0x60d
+	 33, // 0x60e
+	 33, // 0x60f
+	 33, // 0x610
+	 33, // 0x611
+	 33, // 0x612
+	 33, // 0x613
+	 33, // 0x614
+	 33, // 0x615
+	 33, // 0x616
+	 33, // 0x617
+	 33, // 0x618
+	 33, // 0x619
+	 33, // 0x61a
+	 33, // 0x61b
+	 33, // 0x61c
+	 33, // 0x61d
+	 33, // 0x61e
+	 33, // 0x61f
+	 33, // 0x620
+	 33, // 0x621
+	 33, // 0x622
+	 33, // 0x623
+	 33, // 0x624
+	 33, // 0x625
+	 33, // 0x626
+	 33, // 0x627
+	 33, // 0x628
+	 33, // 0x629
+	 33, // 0x62a
+	 33, // 0x62b
+	 33, // 0x62c
+	 33, // 0x62d
+	 33, // 0x62e
+	 33, // 0x62f
+	 33, // 0x630
+	 33, // 0x631
+	 33, // 0x632
+	 33, // 0x633
+	 33, // 0x634
+	 33, // 0x635
+	 33, // 0x636
+	 33, // 0x637
+	 33, // 0x638
+	 33, // 0x639
+	 33, // 0x63a
+	 33, // 0x63b
+	 33, // 0x63c
+	 33, // 0x63d
+	 33, // 0x63e
+	 33, // 0x63f
+	 33, // 0x640
+	 33, // 0x641
+	 33, // 0x642
+	 33, // 0x643
+	 33, // 0x644
+	 33, // 0x645
+	 33, // 0x646
+	 33, // 0x647
+	 33, // 0x648
+	 33, // 0x649
+	 33, // 0x64a
+	 33, // 0x64b
+	 33, // 0x64c
+	 33, // 0x64d
+	 33, // 0x64e
+	 33, // 0x64f
+	 33, // 0x650
+	 33, // 0x651
+	 33, // 0x652
+	 33, // 0x653
+	 33, // 0x654
+	 33, // 0x655
+	 33, // 0x656
+	 33, // 0x657
+	 33, // 0x658
+	 33, // 0x659
+	 33, // 0x65a
+	 33, // 0x65b
+	 33, // 0x65c
+	 33, // 0x65d
+	 33, // 0x65e
+	 33, // 0x65f
+	 33, // 0x660
+	 33, // 0x661
+	 33, // 0x662
+	 33, // 0x663
+	 33, // 0x664
+	 33, // 0x665
+	 33, // 0x666
+	 33, // 0x667
+	 33, // 0x668
+	 33, // 0x669
+	 33, // 0x66a
+	 33, // 0x66b
+	 33, // 0x66c
+	 33, // 0x66d
+	 33, // 0x66e
+	 33, // 0x66f
+	 33, // 0x670
+	 33, // 0x671
+	 33, // 0x672
+	 33, // 0x673
+	 33, // 0x674
+	 33, // 0x675
+	 33, // 0x676
+	 33, // 0x677
+	 33, // 0x678
+	 33, // 0x679
+	 33, // 0x67a
+	 33, // 0x67b
+	 33, // 0x67c
+	 33, // 0x67d
+	 33, // 0x67e
+	 33, // 0x67f
+	 33, // 0x680
+	 33, // 0x681
+	 33, // 0x682
+	 33, // 0x683
+	 33, // 0x684
+	 33, // 0x685
+	 33, // 0x686
+	 33, // 0x687
+	 33, // 0x688
+	 33, // 0x689
+	 33, // 0x68a
+	 33, // 0x68b
+	 33, // 0x68c
+	 33, // 0x68d
+	 33, // 0x68e
+	 33, // 0x68f
+	 33, // 0x690
+	 33, // 0x691
+	 33, // 0x692
+	 33, // 0x693
+	 33, // 0x694
+	 33, // 0x695
+	 33, // 0x696
+	 33, // 0x697
+	 33, // 0x698
+	 33, // 0x699
+	 33, // 0x69a
+	 33, // 0x69b
+	 33, // 0x69c
+	 33, // 0x69d
+	 33, // 0x69e
+	 33, // 0x69f
+	 33, // 0x6a0
+	 33, // 0x6a1
+	 33, // 0x6a2
+	 33, // 0x6a3
+	 33, // 0x6a4
+	 33, // 0x6a5
+	 33, // 0x6a6
+	 33, // 0x6a7
+	 33, // 0x6a8
+	 33, // 0x6a9
+	 33, // 0x6aa
+	 33, // 0x6ab
+	 33, // 0x6ac
+	 33, // 0x6ad
+	 33, // 0x6ae
+	 33, // 0x6af
+	 33, // 0x6b0
+	 33, // 0x6b1
+	 33, // 0x6b2
+	 33, // 0x6b3
+	 33, // 0x6b4
+	 33, // 0x6b5
+	 33, // 0x6b6
+	 33, // 0x6b7
+	 33, // 0x6b8
+	 33, // 0x6b9
+	 33, // 0x6ba
+	 33, // 0x6bb
+	 33, // 0x6bc
+	 33, // 0x6bd
+	 33, // 0x6be
+	 33, // 0x6bf
+	 33, // 0x6c0
+	 33, // 0x6c1
+	 33, // 0x6c2
+	 33, // 0x6c3
+	 33, // 0x6c4
+	 33, // 0x6c5
+	 33, // 0x6c6
+	 33, // 0x6c7
+	 33, // 0x6c8
+	 33, // 0x6c9
+	 33, // 0x6ca
+	 33, // 0x6cb
+	 33, // 0x6cc
+	 33, // 0x6cd
+	 33, // 0x6ce
+	 33, // 0x6cf
+	 33, // 0x6d0
+	 33, // 0x6d1
+	 33, // 0x6d2
+	 33, // 0x6d3
+	 33, // 0x6d4
+	 33, // 0x6d5
+	 33, // 0x6d6
+	 33, // 0x6d7
+	 33, // 0x6d8
+	 33, // 0x6d9
+	 33, // 0x6da
+	 33, // 0x6db
+	 33, // 0x6dc
+	 33, // 0x6dd
+	 33, // 0x6de
+	 33, // 0x6df
+	 33, // 0x6e0
+	 33, // 0x6e1
+	 33, // 0x6e2
+	 33, // 0x6e3
+	 33, // 0x6e4
+	 33, // 0x6e5
+	 33, // 0x6e6
+	 33, // 0x6e7
+	 33, // 0x6e8
+	 33, // 0x6e9
+	 33, // 0x6ea
+	 33, // 0x6eb
+	 33, // 0x6ec
+	 33, // 0x6ed
+	 33, // 0x6ee
+	 33, // 0x6ef
+	 33, // 0x6f0
+	 33, // 0x6f1
+	 33, // 0x6f2
+	 33, // 0x6f3
+	 33, // 0x6f4
+	 33, // 0x6f5
+	 33, // 0x6f6
+	 33, // 0x6f7
+	 33, // 0x6f8
+	 33, // 0x6f9
+	 33, // 0x6fa
+	 33, // 0x6fb
+	 33, // 0x6fc
+	 33, // 0x6fd
+	 33, // 0x6fe
+	 33, // 0x6ff
+	 33, // 0x700
+	 33, // 0x701
+	 33, // 0x702
+	 33, // 0x703
+	 33, // 0x704
+	 33, // 0x705
+	 33, // 0x706
+	 33, // 0x707
+	 33, // 0x708
+	 33, // 0x709
+	 33, // 0x70a
+	 33, // 0x70b
+	 33, // 0x70c
+	 33, // 0x70d
+	 33, // 0x70e
+	 33, // 0x70f
+	 33, // 0x710
+	 33, // 0x711
+	 33, // 0x712
+	 33, // 0x713
+	 33, // 0x714
+	 33, // 0x715
+	 33, // 0x716
+	 33, // 0x717
+	 33, // 0x718
+	 33, // 0x719
+	 33, // 0x71a
+	 33, // 0x71b
+	 33, // 0x71c
+	 33, // 0x71d
+	 33, // 0x71e
+	 33, // 0x71f
+	 33, // 0x720
+	 33, // 0x721
+	 33, // 0x722
+	 33, // 0x723
+	 33, // 0x724
+	 33, // 0x725
+	 33, // 0x726
+	 33, // 0x727
+	 33, // 0x728
+	 33, // 0x729
+	 33, // 0x72a
+	 33, // 0x72b
+	 33, // 0x72c
+	 33, // 0x72d
+	 33, // 0x72e
+	 33, // 0x72f
+	 33, // 0x730
+	 33, // 0x731
+	 33, // 0x732
+	 33, // 0x733
+	 33, // 0x734
+	 33, // 0x735
+	 33, // 0x736
+	 33, // 0x737
+	 33, // 0x738
+	 33, // 0x739
+	 33, // 0x73a
+	 33, // 0x73b
+	 33, // 0x73c
+	 33, // 0x73d
+	 33, // 0x73e
+	 33, // 0x73f
+	 33, // 0x740
+	 33, // 0x741
+	 33, // 0x742
+	 33, // 0x743
+	 33, // 0x744
+	 33, // 0x745
+	 33, // 0x746
+	 33, // 0x747
+	 33, // 0x748
+	 33, // 0x749
+	 33, // 0x74a
+	 33, // 0x74b
+	 33, // 0x74c
+	 33, // 0x74d
+	 33, // 0x74e
+	 33, // 0x74f
+	 33, // 0x750
+	 33, // 0x751
+	 33, // 0x752
+	 33, // 0x753
+	 33, // 0x754
+	 33, // 0x755
+	 33, // 0x756
+	 33, // 0x757
+	 33, // 0x758
+	 33, // 0x759
+	 33, // 0x75a
+	 33, // 0x75b
+	 33, // 0x75c
+	 33, // 0x75d
+	 33, // 0x75e
+	 33, // 0x75f
+	 33, // 0x760
+	 33, // 0x761
+	 33, // 0x762
+	 33, // 0x763
+	 33, // 0x764
+	 33, // 0x765
+	 33, // 0x766
+	 33, // 0x767
+	 33, // 0x768
+	 33, // 0x769
+	 33, // 0x76a
+	 33, // 0x76b
+	 33, // 0x76c
+	 33, // 0x76d
+	 33, // 0x76e
+	 33, // 0x76f
+	 33, // 0x770
+	 33, // 0x771
+	 33, // 0x772
+	 33, // 0x773
+	 33, // 0x774
+	 33, // 0x775
+	 33, // 0x776
+	 33, // 0x777
+	 33, // 0x778
+	 33, // 0x779
+	 33, // 0x77a
+	 33, // 0x77b
+	 33, // 0x77c
+	 33, // 0x77d
+	 33, // 0x77e
+	 33, // 0x77f
+	 33, // 0x780
+	 33, // 0x781
+	 33, // 0x782
+	 33, // 0x783
+	 33, // 0x784
+	 33, // 0x785
+	 33, // 0x786
+	 33, // 0x787
+	 33, // 0x788
+	 33, // 0x789
+	 33, // 0x78a
+	 33, // 0x78b
+	 33, // 0x78c
+	 33, // 0x78d
+	 33, // 0x78e
+	 33, // 0x78f
+	 33, // 0x790
+	 33, // 0x791
+	 33, // 0x792
+	 33, // 0x793
+	 33, // 0x794
+	 33, // 0x795
+	 33, // 0x796
+	 33, // 0x797
+	 33, // 0x798
+	 33, // 0x799
+	 33, // 0x79a
+	 33, // 0x79b
+	 33, // 0x79c
+	 33, // 0x79d
+	 33, // 0x79e
+	 33, // 0x79f
+	 33, // 0x7a0
+	 33, // 0x7a1
+	 33, // 0x7a2
+	 33, // 0x7a3
+	 33, // 0x7a4
+	 33, // 0x7a5
+	 33, // 0x7a6
+	 33, // 0x7a7
+	 33, // 0x7a8
+	 33, // 0x7a9
+	 33, // 0x7aa
+	 33, // 0x7ab
+	 33, // 0x7ac
+	 33, // 0x7ad
+	 33, // 0x7ae
+	 33, // 0x7af
+	 33, // 0x7b0
+	 33, // 0x7b1
+	 33, // 0x7b2
+	 33, // 0x7b3
+	 33, // 0x7b4
+	 33, // 0x7b5
+	 33, // 0x7b6
+	 33, // 0x7b7
+	 33, // 0x7b8
+	 33, // 0x7b9
+	 33, // 0x7ba
+	 33, // 0x7bb
+	 33, // 0x7bc
+	 33, // 0x7bd
+	 33, // 0x7be
+	 33, // 0x7bf
+	 33, // 0x7c0
+	 33, // 0x7c1
+	 33, // 0x7c2
+	 33, // 0x7c3
+	 33, // 0x7c4
+	 33, // 0x7c5
+	 33, // 0x7c6
+	 33, // 0x7c7
+	 33, // 0x7c8
+	 33, // 0x7c9
+	 33, // 0x7ca
+	 33, // 0x7cb
+	 33, // 0x7cc
+	 33, // 0x7cd
+	 33, // 0x7ce
+	 33, // 0x7cf
+	 33, // 0x7d0
+	 33, // 0x7d1
+	 33, // 0x7d2
+	 33, // 0x7d3
+	 33, // 0x7d4
+	 33, // 0x7d5
+	 33, // 0x7d6
+	 33, // 0x7d7
+	 33, // 0x7d8
+	 33, // 0x7d9
+	 33, // 0x7da
+	 33, // 0x7db
+	 33, // 0x7dc
+	 33, // 0x7dd
+	 33, // 0x7de
+	 33, // 0x7df
+	 33, // 0x7e0
+	 33, // 0x7e1
+	 33, // 0x7e2
+	 33, // 0x7e3
+	 33, // 0x7e4
+	 33, // 0x7e5
+	 33, // 0x7e6
+	 33, // 0x7e7
+	 33, // 0x7e8
+	 33, // 0x7e9
+	 33, // 0x7ea
+	 33, // 0x7eb
+	 33, // 0x7ec
+	 33, // 0x7ed
+	 33, // 0x7ee
+	 33, // 0x7ef
+	 33, // 0x7f0
+	 33, // 0x7f1
+	 33, // 0x7f2
+	 33, // 0x7f3
+	 33, // 0x7f4
+	 33, // 0x7f5
+	 33, // 0x7f6
+	 33, // 0x7f7
+	 33, // 0x7f8
+	 33, // 0x7f9
+	 33, // 0x7fa
+	 33, // 0x7fb
+	 33, // 0x7fc
+	 33, // 0x7fd
+	 33, // 0x7fe
+	 33, // 0x7ff
+	 33, // 0x800
+	 33, // 0x801
+	 33, // 0x802
+	 33, // 0x803
+	 33, // 0x804
+	 33, // 0x805
+	 33, // 0x806
+	 33, // 0x807
+	 33, // 0x808
+	 33, // 0x809
+	 33, // 0x80a
+	 33, // 0x80b
+	 33, // 0x80c
+	 33, // 0x80d
+	 33, // 0x80e
+	 33, // 0x80f
+	 33, // 0x810
+	 33, // 0x811
+	 33, // 0x812
+	 33, // 0x813
+	 33, // 0x814
+	 33, // 0x815
+	 33, // 0x816
+	 33, // 0x817
+	 33, // 0x818
+	 33, // 0x819
+	 33, // 0x81a
+	 33, // 0x81b
+	 33, // 0x81c
+	 33, // 0x81d
+	 33, // 0x81e
+	 33, // 0x81f
+	 33, // 0x820
+	 33, // 0x821
+	 33, // 0x822
+	 33, // 0x823
+	 33, // 0x824
+	 33, // 0x825
+	 33, // 0x826
+	 33, // 0x827
+	 33, // 0x828
+	 33, // 0x829
+	 33, // 0x82a
+	 33, // 0x82b
+	 33, // 0x82c
+	 33, // 0x82d
+	 33, // 0x82e
+	 33, // 0x82f
+	 33, // 0x830
+	 33, // 0x831
+	 33, // 0x832
+	 33, // 0x833
+	 33, // 0x834
+	 33, // 0x835
+	 33, // 0x836
+	 33, // 0x837
+	 33, // 0x838
+	 33, // 0x839
+	 33, // 0x83a
+	 33, // 0x83b
+	 33, // 0x83c
+	 33, // 0x83d
+	 33, // 0x83e
+	 33, // 0x83f
+	 33, // 0x840
+	 33, // 0x841
+	 33, // 0x842
+	 33, // 0x843
+	 33, // 0x844
+	 33, // 0x845
+	 33, // 0x846
+	 33, // 0x847
+	 33, // 0x848
+	 33, // 0x849
+	 33, // 0x84a
+	 33, // 0x84b
+	 33, // 0x84c
+	 33, // 0x84d
+	 33, // 0x84e
+	 33, // 0x84f
+	 33, // 0x850
+	 33, // 0x851
+	 33, // 0x852
+	 33, // 0x853
+	 33, // 0x854
+	 33, // 0x855
+	 33, // 0x856
+	 33, // 0x857
+	 33, // 0x858
+	 33, // 0x859
+	 33, // 0x85a
+	 33, // 0x85b
+	 33, // 0x85c
+	 33, // 0x85d
+	 33, // 0x85e
+	 33, // 0x85f
+	 33, // 0x860
+	 33, // 0x861
+	 33, // 0x862
+	 33, // 0x863
+	 33, // 0x864
+	 33, // 0x865
+	 33, // 0x866
+	 33, // 0x867
+	 33, // 0x868
+	 33, // 0x869
+	 33, // 0x86a
+	 33, // 0x86b
+	 33, // 0x86c
+	 33, // 0x86d
+	 33, // 0x86e
+	 33, // 0x86f
+	 33, // 0x870
+	 33, // 0x871
+	 33, // 0x872
+	 33, // 0x873
+	 33, // 0x874
+	 33, // 0x875
+	 33, // 0x876
+	 33, // 0x877
+	 33, // 0x878
+	 33, // 0x879
+	 33, // 0x87a
+	 33, // 0x87b
+	 33, // 0x87c
+	 33, // 0x87d
+	 33, // 0x87e
+	 33, // 0x87f
+	 33, // 0x880
+	 33, // 0x881
+	 33, // 0x882
+	 33, // 0x883
+	 33, // 0x884
+	 33, // 0x885
+	 33, // 0x886
+	 33, // 0x887
+	 33, // 0x888
+	 33, // 0x889
+	 33, // 0x88a
+	 33, // 0x88b
+	 33, // 0x88c
+	 33, // 0x88d
+	 33, // 0x88e
+	 33, // 0x88f
+	 33, // 0x890
+	 33, // 0x891
+	 33, // 0x892
+	 33, // 0x893
+	 33, // 0x894
+	 33, // 0x895
+	 33, // 0x896
+	 33, // 0x897
+	 33, // 0x898
+	 33, // 0x899
+	 33, // 0x89a
+	 33, // 0x89b
+	 33, // 0x89c
+	 33, // 0x89d
+	 33, // 0x89e
+	 33, // 0x89f
+	 33, // 0x8a0
+	 33, // 0x8a1
+	 33, // 0x8a2
+	 33, // 0x8a3
+	 33, // 0x8a4
+	 33, // 0x8a5
+	 33, // 0x8a6
+	 33, // 0x8a7
+	 33, // 0x8a8
+	 33, // 0x8a9
+	 33, // 0x8aa
+	 33, // 0x8ab
+	 33, // 0x8ac
+	 33, // 0x8ad
+	 33, // 0x8ae
+	 33, // 0x8af
+	 33, // 0x8b0
+	 33, // 0x8b1
+	 33, // 0x8b2
+	 33, // 0x8b3
+	 33, // 0x8b4
+	 33, // 0x8b5
+	 33, // 0x8b6
+	 33, // 0x8b7
+	 33, // 0x8b8
+	 33, // 0x8b9
+	 33, // 0x8ba
+	 33, // 0x8bb
+	 33, // 0x8bc
+	 33, // 0x8bd
+	 33, // 0x8be
+	 33, // 0x8bf
+	 33, // 0x8c0
+	 33, // 0x8c1
+	 33, // 0x8c2
+	 33, // 0x8c3
+	 33, // 0x8c4
+	 33, // 0x8c5
+	 33, // 0x8c6
+	 33, // 0x8c7
+	 33, // 0x8c8
+	 33, // 0x8c9
+	 33, // 0x8ca
+	 33, // 0x8cb
+	 33, // 0x8cc
+	 33, // 0x8cd
+	 33, // 0x8ce
+	 33, // 0x8cf
+	 33, // 0x8d0
+	 33, // 0x8d1
+	 33, // 0x8d2
+	 33, // 0x8d3
+	 33, // 0x8d4
+	 33, // 0x8d5
+	 33, // 0x8d6
+	 33, // 0x8d7
+	 33, // 0x8d8
+	 33, // 0x8d9
+	 33, // 0x8da
+	 33, // 0x8db
+	 33, // 0x8dc
+	 33, // 0x8dd
+	 33, // 0x8de
+	 33, // 0x8df
+	 33, // 0x8e0
+	 33, // 0x8e1
+	 33, // 0x8e2
+	 33, // 0x8e3
+	 33, // 0x8e4
+	 33, // 0x8e5
+	 33, // 0x8e6
+	 33, // 0x8e7
+	 33, // 0x8e8
+	 33, // 0x8e9
+	 33, // 0x8ea
+	 33, // 0x8eb
+	 33, // 0x8ec
+	 33, // 0x8ed
+	 33, // 0x8ee
+	 33, // 0x8ef
+	 33, // 0x8f0
+	 33, // 0x8f1
+	 33, // 0x8f2
+	 33, // 0x8f3
+	 33, // 0x8f4
+	 33, // 0x8f5
+	 33, // 0x8f6
+	 33, // 0x8f7
+	 33, // 0x8f8
+	 33, // 0x8f9
+	 33, // 0x8fa
+	 33, // 0x8fb
+	 33, // 0x8fc
+	 33, // 0x8fd
+	 33, // 0x8fe
+	 33, // 0x8ff
+	 33, // 0x900
+	 33, // 0x901
+	 33, // 0x902
+	 33, // 0x903
+	 33, // 0x904
+	 33, // 0x905
+	 33, // 0x906
+	 33, // 0x907
+	 33, // 0x908
+	 33, // 0x909
+	 33, // 0x90a
+	 33, // 0x90b
+	 33, // 0x90c
+	 33, // 0x90d
+	 33, // 0x90e
+	 33, // 0x90f
+	 33, // 0x910
+	 33, // 0x911
+	 33, // 0x912
+	 33, // 0x913
+	 33, // 0x914
+	 33, // 0x915
+	 33, // 0x916
+	 33, // 0x917
+	 33, // 0x918
+	 33, // 0x919
+	 33, // 0x91a
+	 33, // 0x91b
+	 33, // 0x91c
+	 33, // 0x91d
+	 33, // 0x91e
+	 33, // 0x91f
+	 33, // 0x920
+	 33, // 0x921
+	 33, // 0x922
+	 33, // 0x923
+	 33, // 0x924
+	 33, // 0x925
+	 33, // 0x926
+	 33, // 0x927
+	 33, // 0x928
+	 33, // 0x929
+	 33, // 0x92a
+	 33, // 0x92b
+	 33, // 0x92c
+	 33, // 0x92d
+	 33, // 0x92e
+	 33, // 0x92f
+	 33, // 0x930
+	 33, // 0x931
+	 33, // 0x932
+	 33, // 0x933
+	 33, // 0x934
+	 33, // 0x935
+	 33, // 0x936
+	 33, // 0x937
+	 33, // 0x938
+	 33, // 0x939
+	 33, // 0x93a
+	 33, // 0x93b
+	 33, // 0x93c
+	 33, // 0x93d
+	 33, // 0x93e
+	 33, // 0x93f
+	 33, // 0x940
+	 33, // 0x941
+	 33, // 0x942
+	 33, // 0x943
+	 33, // 0x944
+	 33, // 0x945
+	 33, // 0x946
+	 33, // 0x947
+	 33, // 0x948
+	 33, // 0x949
+	 33, // 0x94a
+	 33, // 0x94b
+	 33, // 0x94c
+	 33, // 0x94d
+	 33, // 0x94e
+	 33, // 0x94f
+	 33, // 0x950
+	 33, // 0x951
+	 33, // 0x952
+	 33, // 0x953
+	 33, // 0x954
+	 33, // 0x955
+	 33, // 0x956
+	 33, // 0x957
+	 33, // 0x958
+	 33, // 0x959
+	 33, // 0x95a
+	 33, // 0x95b
+	 33, // 0x95c
+	 33, // 0x95d
+	 33, // 0x95e
+	 33, // 0x95f
+	 33, // 0x960
+	 33, // 0x961
+	 33, // 0x962
+	 33, // 0x963
+	 33, // 0x964
+	 33, // 0x965
+	 33, // 0x966
+	 33, // 0x967
+	 33, // 0x968
+	 33, // 0x969
+	 33, // 0x96a
+	 33, // 0x96b
+	 33, // 0x96c
+	 33, // 0x96d
+	 33, // 0x96e
+	 33, // 0x96f
+	 33, // 0x970
+	 33, // 0x971
+	 33, // 0x972
+	 33, // 0x973
+	 33, // 0x974
+	 33, // 0x975
+	 33, // 0x976
+	 33, // 0x977
+	 33, // 0x978
+	 33, // 0x979
+	 33, // 0x97a
+	 33, // 0x97b
+	 33, // 0x97c
+	 33, // 0x97d
+	 33, // 0x97e
+	 33, // 0x97f
+	 33, // 0x980
+	 33, // 0x981
+	 33, // 0x982
+	 33, // 0x983
+	 33, // 0x984
+	 33, // 0x985
+	 33, // 0x986
+	 33, // 0x987
+	 33, // 0x988
+	 33, // 0x989
+	 33, // 0x98a
+	 33, // 0x98b
+	 33, // 0x98c
+	 33, // 0x98d
+	 33, // 0x98e
+	 33, // 0x98f
+	 33, // 0x990
+	 33, // 0x991
+	 33, // 0x992
+	 33, // 0x993
+	 33, // 0x994
+	 33, // 0x995
+	 33, // 0x996
+	 33, // 0x997
+	 33, // 0x998
+	 33, // 0x999
+	 33, // 0x99a
+	 33, // 0x99b
+	 33, // 0x99c
+	 33, // 0x99d
+	 33, // 0x99e
+	 33, // 0x99f
+	 33, // 0x9a0
+	 33, // 0x9a1
+	 33, // 0x9a2
+	 33, // 0x9a3
+	 33, // 0x9a4
+	 33, // 0x9a5
+	 33, // 0x9a6
+	 33, // 0x9a7
+	 33, // 0x9a8
+	 33, // 0x9a9
+	 33, // 0x9aa
+	 33, // 0x9ab
+	 33, // 0x9ac
+	 33, // 0x9ad
+	 33, // 0x9ae
+	 33, // 0x9af
+	 33, // 0x9b0
+	 33, // 0x9b1
+	 33, // 0x9b2
+	 33, // 0x9b3
+	 33, // 0x9b4
+	 33, // 0x9b5
+	 33, // 0x9b6
+	 33, // 0x9b7
+	 33, // 0x9b8
+	 33, // 0x9b9
+	 33, // 0x9ba
+	 33, // 0x9bb
+	 33, // 0x9bc
+	 33, // 0x9bd
+	 33, // 0x9be
+	 33, // 0x9bf
+	 33, // 0x9c0
+	 33, // 0x9c1
+	 33, // 0x9c2
+	 33, // 0x9c3
+	 33, // 0x9c4
+	 33, // 0x9c5
+	 33, // 0x9c6
+	 33, // 0x9c7
+	 33, // 0x9c8
+	 33, // 0x9c9
+	 33, // 0x9ca
+	 33, // 0x9cb
+	 33, // 0x9cc
+	 33, // 0x9cd
+	 33, // 0x9ce
+	 33, // 0x9cf
+	 33, // 0x9d0
+	 33, // 0x9d1
+	 33, // 0x9d2
+	 33, // 0x9d3
+	 33, // 0x9d4
+	 33, // 0x9d5
+	 33, // 0x9d6
+	 33, // 0x9d7
+	 33, // 0x9d8
+	 33, // 0x9d9
+	 33, // 0x9da
+	 33, // 0x9db
+	 33, // 0x9dc
+	 33, // 0x9dd
+	 33, // 0x9de
+	 33, // 0x9df
+	 33, // 0x9e0
+	 33, // 0x9e1
+	 33, // 0x9e2
+	 33, // 0x9e3
+	 33, // 0x9e4
+	 33, // 0x9e5
+	 33, // 0x9e6
+	 33, // 0x9e7
+	 33, // 0x9e8
+	 33, // 0x9e9
+	 33, // 0x9ea
+	 33, // 0x9eb
+	 33, // 0x9ec
+	 33, // 0x9ed
+	 33, // 0x9ee
+	 33, // 0x9ef
+	 33, // 0x9f0
+	 33, // 0x9f1
+	 33, // 0x9f2
+	 33, // 0x9f3
+	 33, // 0x9f4
+	 33, // 0x9f5
+	 33, // 0x9f6
+	 33, // 0x9f7
+	 33, // 0x9f8
+	 33, // 0x9f9
+	 33, // 0x9fa
+	 33, // 0x9fb
+	 33, // 0x9fc
+	 33, // 0x9fd
+	 33, // 0x9fe
+	 33, // 0x9ff
+	 33, // 0xa00
+	 33, // 0xa01
+	 33, // 0xa02
+	 33, // 0xa03
+	 33, // 0xa04
+	 33, // 0xa05
+	 33, // 0xa06
+	 33, // 0xa07
+	 33, // 0xa08
+	 33, // 0xa09
+	 33, // 0xa0a
+	 33, // 0xa0b
+	 33, // 0xa0c
+	 33, // 0xa0d
+	 33, // 0xa0e
+	 33, // 0xa0f
+	 33, // 0xa10
+	 33, // 0xa11
+	 33, // 0xa12
+	 33, // 0xa13
+	 33, // 0xa14
+	 33, // 0xa15
+	 33, // 0xa16
+	 33, // 0xa17
+	 33, // 0xa18
+	 33, // 0xa19
+	 33, // 0xa1a
+	 33, // 0xa1b
+	 33, // 0xa1c
+	 33, // 0xa1d
+	 33, // 0xa1e
+	 33, // 0xa1f
+	 33, // 0xa20
+	 33, // 0xa21
+	 33, // 0xa22
+	 33, // 0xa23
+	 33, // 0xa24
+	 33, // 0xa25
+	 33, // 0xa26
+	 33, // 0xa27
+	 33, // 0xa28
+	 33, // 0xa29
+	 33, // 0xa2a
+	 33, // 0xa2b
+	 33, // 0xa2c
+	 33, // 0xa2d
+	 33, // 0xa2e
+	 33, // 0xa2f
+	 33, // 0xa30
+	 33, // 0xa31
+	 33, // 0xa32
+	 33, // 0xa33
+	 33, // 0xa34
+	 33, // 0xa35
+	 33, // 0xa36
+	 33, // 0xa37
+	 33, // 0xa38
+	 33, // 0xa39
+	 33, // 0xa3a
+	 33, // 0xa3b
+	 33, // 0xa3c
+	 33, // 0xa3d
+	 33, // 0xa3e
+	 33, // 0xa3f
+	 33, // 0xa40
+	 33, // 0xa41
+	 33, // 0xa42
+	 33, // 0xa43
+	 33, // 0xa44
+	 33, // 0xa45
+	 33, // 0xa46
+	 33, // 0xa47
+	 33, // 0xa48
+	 33, // 0xa49
+	 33, // 0xa4a
+	 33, // 0xa4b
+	 33, // 0xa4c
+	 33, // 0xa4d
+	 33, // 0xa4e
+	 33, // 0xa4f
+	 33, // 0xa50
+	 33, // 0xa51
+	 33, // 0xa52
+	 33, // 0xa53
+	 33, // 0xa54
+	 33, // 0xa55
+	 33, // 0xa56
+	 33, // 0xa57
+	 33, // 0xa58
+	 33, // 0xa59
+	 33, // 0xa5a
+	 33, // 0xa5b
+	 33, // 0xa5c
+	 33, // 0xa5d
+	 33, // 0xa5e
+	 33, // 0xa5f
+	 33, // 0xa60
+	 33, // 0xa61
+	 33, // 0xa62
+	 33, // 0xa63
+	 33, // 0xa64
+	 33, // 0xa65
+	 33, // 0xa66
+	 33, // 0xa67
+	 33, // 0xa68
+	 33, // 0xa69
+	 33, // 0xa6a
+	 33, // 0xa6b
+	 33, // 0xa6c
+	 33, // 0xa6d
+	 33, // 0xa6e
+	 33, // 0xa6f
+	 33, // 0xa70
+	 33, // 0xa71
+	 33, // 0xa72
+	 33, // 0xa73
+	 33, // 0xa74
+	 33, // 0xa75
+	 33, // 0xa76
+	 33, // 0xa77
+	 33, // 0xa78
+	 33, // 0xa79
+	 33, // 0xa7a
+	 33, // 0xa7b
+	 33, // 0xa7c
+	 33, // 0xa7d
+	 33, // 0xa7e
+	 33, // 0xa7f
+	 33, // 0xa80
+	 33, // 0xa81
+	 33, // 0xa82
+	 33, // 0xa83
+	 33, // 0xa84
+	 33, // 0xa85
+	 33, // 0xa86
+	 33, // 0xa87
+	 33, // 0xa88
+	 33, // 0xa89
+	 33, // 0xa8a
+	 33, // 0xa8b
+	 33, // 0xa8c
+	 33, // 0xa8d
+	 33, // 0xa8e
+	 33, // 0xa8f
+	 33, // 0xa90
+	 33, // 0xa91
+	 33, // 0xa92
+	 33, // 0xa93
+	 33, // 0xa94
+	 33, // 0xa95
+	 33, // 0xa96
+	 33, // 0xa97
+	 33, // 0xa98
+	 33, // 0xa99
+	 33, // 0xa9a
+	 33, // 0xa9b
+	 33, // 0xa9c
+	 33, // 0xa9d
+	 33, // 0xa9e
+	 33, // 0xa9f
+	 33, // 0xaa0
+	 33, // 0xaa1
+	 33, // 0xaa2
+	 33, // 0xaa3
+	 33, // 0xaa4
+	 33, // 0xaa5
+	 33, // 0xaa6
+	 33, // 0xaa7
+	 33, // 0xaa8
+	 33, // 0xaa9
+	 33, // 0xaaa
+	 33, // 0xaab
+	 33, // 0xaac
+	 33, // 0xaad
+	 33, // 0xaae
+	 33, // 0xaaf
+	 33, // 0xab0
+	 33, // 0xab1
+	 33, // 0xab2
+	 33, // 0xab3
+	 33, // 0xab4
+	 33, // 0xab5
+	 33, // 0xab6
+	 33, // 0xab7
+	 33, // 0xab8
+	 33, // 0xab9
+	 33, // 0xaba
+	 33, // 0xabb
+	 33, // 0xabc
+	 33, // 0xabd
+	 33, // 0xabe
+	 33, // 0xabf
+	 33, // 0xac0
+	 33, // 0xac1
+	 33, // 0xac2
+	 33, // 0xac3
+	 33, // 0xac4
+	 33, // 0xac5
+	 33, // 0xac6
+	 33, // 0xac7
+	 33, // 0xac8
+	 33, // 0xac9
+	 33, // 0xaca
+	 33, // 0xacb
+	 33, // 0xacc
+	 33, // 0xacd
+	 33, // 0xace
+	 33, // 0xacf
+	 33, // 0xad0
+	 33, // 0xad1
+	 33, // 0xad2
+	 33, // 0xad3
+	 33, // 0xad4
+	 33, // 0xad5
+	 33, // 0xad6
+	 33, // 0xad7
+	 33, // 0xad8
+	 33, // 0xad9
+	 33, // 0xada
+	 33, // 0xadb
+	 33, // 0xadc
+	 33, // 0xadd
+	 33, // 0xade
+	 33, // 0xadf
+	 33, // 0xae0
+	 33, // 0xae1
+	 33, // 0xae2
+	 33, // 0xae3
+	 33, // 0xae4
+	 33, // 0xae5
+	 33, // 0xae6
+	 33, // 0xae7
+	 33, // 0xae8
+	 33, // 0xae9
+	 33, // 0xaea
+	 33, // 0xaeb
+	 33, // 0xaec
+	 33, // 0xaed
+	 33, // 0xaee
+	 33, // 0xaef
+	 33, // 0xaf0
+	 33, // 0xaf1
+	 33, // 0xaf2
+	 33, // 0xaf3
+	 33, // 0xaf4
+	 33, // 0xaf5
+	 33, // 0xaf6
+	 33, // 0xaf7
+	 33, // 0xaf8
+	 33, // 0xaf9
+	 33, // 0xafa
+	 33, // 0xafb
+	 33, // 0xafc
+	 33, // 0xafd
+	 33, // 0xafe
+	 33, // 0xaff
+	 33, // 0xb00
+	 33, // 0xb01
+	 33, // 0xb02
+	 33, // 0xb03
+	 33, // 0xb04
+	 33, // 0xb05
+	 33, // 0xb06
+	 33, // 0xb07
+	 33, // 0xb08
+	 33, // 0xb09
+	 33, // 0xb0a
+	 33, // 0xb0b
+	 33, // 0xb0c
+	 33, // 0xb0d
+	 33, // 0xb0e
+	 33, // 0xb0f
+	 33, // 0xb10
+	 33, // 0xb11
+	 33, // 0xb12
+	 33, // 0xb13
+	 33, // 0xb14
+	 33, // 0xb15
+	 33, // 0xb16
+	 33, // 0xb17
+	 33, // 0xb18
+	 33, // 0xb19
+	 33, // 0xb1a
+	 33, // 0xb1b
+	 33, // 0xb1c
+	 33, // 0xb1d
+	 33, // 0xb1e
+	 33, // 0xb1f
+	 33, // 0xb20
+	 33, // 0xb21
+	 33, // 0xb22
+	 33, // 0xb23
+	 33, // 0xb24
+	 33, // 0xb25
+	 33, // 0xb26
+	 33, // 0xb27
+	 33, // 0xb28
+	 33, // 0xb29
+	 33, // 0xb2a
+	 33, // 0xb2b
+	 33, // 0xb2c
+	 33, // 0xb2d
+	 33, // 0xb2e
+	 33, // 0xb2f
+	 33, // 0xb30
+	 33, // 0xb31
+	 33, // 0xb32
+	 33, // 0xb33
+	 33, // 0xb34
+	 33, // 0xb35
+	 33, // 0xb36
+	 33, // 0xb37
+	 33, // 0xb38
+	 33, // 0xb39
+	 33, // 0xb3a
+	 33, // 0xb3b
+	 33, // 0xb3c
+	 33, // 0xb3d
+	 33, // 0xb3e
+	 33, // 0xb3f
+	 33, // 0xb40
+	 33, // 0xb41
+	 33, // 0xb42
+	 33, // 0xb43
+	 33, // 0xb44
+	 33, // 0xb45
+	 33, // 0xb46
+	 33, // 0xb47
+	 33, // 0xb48
+	 33, // 0xb49
+	 33, // 0xb4a
+	 33, // 0xb4b
+	 33, // 0xb4c
+	 33, // 0xb4d
+	 33, // 0xb4e
+	 33, // 0xb4f
+	 33, // 0xb50
+	 33, // 0xb51
+	 33, // 0xb52
+	 33, // 0xb53
+	 33, // 0xb54
+	 33, // 0xb55
+	 33, // 0xb56
+	 33, // 0xb57
+	 33, // 0xb58
+	 33, // 0xb59
+	 33, // 0xb5a
+	 33, // 0xb5b
+	 33, // 0xb5c
+	 33, // 0xb5d
+	 33, // 0xb5e
+	 33, // 0xb5f
+	 33, // 0xb60
+	 33, // 0xb61
+	 33, // 0xb62
+	 33, // 0xb63
+	 33, // 0xb64
+	 33, // 0xb65
+	 33, // 0xb66
+	 33, // 0xb67
+	 33, // 0xb68
+	 33, // 0xb69
+	 33, // 0xb6a
+	 33, // 0xb6b
+	 33, // 0xb6c
+	 33, // 0xb6d
+	 33, // 0xb6e
+	 33, // 0xb6f
+	 33, // 0xb70
+	 33, // 0xb71
+	 33, // 0xb72
+	 33, // 0xb73
+	 33, // 0xb74
+	 33, // 0xb75
+	 33, // 0xb76
+	 33, // 0xb77
+	 33, // 0xb78
+	 33, // 0xb79
+	 33, // 0xb7a
+	 33, // 0xb7b
+	 33, // 0xb7c
+	 33, // 0xb7d
+	 33, // 0xb7e
+	 33, // 0xb7f
+	 33, // 0xb80
+	 33, // 0xb81
+	 33, // 0xb82
+	 33, // 0xb83
+	 33, // 0xb84
+	 33, // 0xb85
+	 33, // 0xb86
+	 33, // 0xb87
+	 33, // 0xb88
+	 33, // 0xb89
+	 33, // 0xb8a
+	 33, // 0xb8b
+	 33, // 0xb8c
+	 33, // 0xb8d
+	 33, // 0xb8e
+	 33, // 0xb8f
+	 33, // 0xb90
+	 33, // 0xb91
+	 33, // 0xb92
+	 33, // 0xb93
+	 33, // 0xb94
+	 33, // 0xb95
+	 33, // 0xb96
+	 33, // 0xb97
+	 33, // 0xb98
+	 33, // 0xb99
+	 33, // 0xb9a
+	 33, // 0xb9b
+	 33, // 0xb9c
+	 33, // 0xb9d
+	 33, // 0xb9e
+	 33, // 0xb9f
+	 33, // 0xba0
+	 33, // 0xba1
+	 33, // 0xba2
+	 33, // 0xba3
+	 33, // 0xba4
+	 33, // 0xba5
+	 33, // 0xba6
+	 33, // 0xba7
+	 33, // 0xba8
+	 33, // 0xba9
+	 33, // 0xbaa
+	 33, // 0xbab
+	 33, // 0xbac
+	 33, // 0xbad
+	 33, // 0xbae
+	 33, // 0xbaf
+	 33, // 0xbb0
+	 33, // 0xbb1
+	 33, // 0xbb2
+	 33, // 0xbb3
+	 33, // 0xbb4
+	 33, // 0xbb5
+	 33, // 0xbb6
+	 33, // 0xbb7
+	 33, // 0xbb8
+	 33, // 0xbb9
+	 33, // 0xbba
+	 33, // 0xbbb
+	 33, // 0xbbc
+	 33, // 0xbbd
+	 33, // 0xbbe
+	 33, // 0xbbf
+	 33, // 0xbc0
+	 33, // 0xbc1
+	 33, // 0xbc2
+	 33, // 0xbc3
+	 33, // 0xbc4
+	 33, // 0xbc5
+	 33, // 0xbc6
+	 33, // 0xbc7
+	 33, // 0xbc8
+	 33, // 0xbc9
+	 33, // 0xbca
+	 33, // 0xbcb
+	 33, // 0xbcc
+	 33, // 0xbcd
+	 33, // 0xbce
+	 33, // 0xbcf
+	 33, // 0xbd0
+	 33, // 0xbd1
+	 33, // 0xbd2
+	 33, // 0xbd3
+	 33, // 0xbd4
+	 33, // 0xbd5
+	 33, // 0xbd6
+	 33, // 0xbd7
+	 33, // 0xbd8
+	 33, // 0xbd9
+	 33, // 0xbda
+	 33, // 0xbdb
+	 33, // 0xbdc
+	 33, // 0xbdd
+	 33, // 0xbde
+	 33, // 0xbdf
+	 33, // 0xbe0
+	 33, // 0xbe1
+	 33, // 0xbe2
+	 33, // 0xbe3
+	 33, // 0xbe4
+	 33, // 0xbe5
+	 33, // 0xbe6
+	 33, // 0xbe7
+	 33, // 0xbe8
+	 33, // 0xbe9
+	 33, // 0xbea
+	 33, // 0xbeb
+	 33, // 0xbec
+	 33, // 0xbed
+	 33, // 0xbee
+	 33, // 0xbef
+	 33, // 0xbf0
+	 33, // 0xbf1
+	 33, // 0xbf2
+	 33, // 0xbf3
+	 33, // 0xbf4
+	 33, // 0xbf5
+	 33, // 0xbf6
+	 33, // 0xbf7
+	 33, // 0xbf8
+	 33, // 0xbf9
+	 33, // 0xbfa
+	 33, // 0xbfb
+	 33, // 0xbfc
+	 33, // 0xbfd
+	 33, // 0xbfe
+	 33, // 0xbff
+	 33, // 0xc00
+	 33, // 0xc01
+	 33, // 0xc02
+	 33, // 0xc03
+	 33, // 0xc04
+	 33, // 0xc05
+	 33, // 0xc06
+	 33, // 0xc07
+	 33, // 0xc08
+	 33, // 0xc09
+	 33, // 0xc0a
+	 33, // 0xc0b
+	 33, // 0xc0c
+	 33, // 0xc0d
+	 33, // 0xc0e
+	 33, // 0xc0f
+	 33, // 0xc10
+	 33, // 0xc11
+	 33, // 0xc12
+	 33, // 0xc13
+	 33, // 0xc14
+	 33, // 0xc15
+	 33, // 0xc16
+	 33, // 0xc17
+	 33, // 0xc18
+	 33, // 0xc19
+	 33, // 0xc1a
+	 33, // 0xc1b
+	 33, // 0xc1c
+	 33, // 0xc1d
+	 33, // 0xc1e
+	 33, // 0xc1f
+	 33, // 0xc20
+	 33, // 0xc21
+	 33, // 0xc22
+	 33, // 0xc23
+	 33, // 0xc24
+	 33, // 0xc25
+	 33, // 0xc26
+	 33, // 0xc27
+	 33, // 0xc28
+	 33, // 0xc29
+	 33, // 0xc2a
+	 33, // 0xc2b
+	 33, // 0xc2c
+	 33, // 0xc2d
+	 33, // 0xc2e
+	 33, // 0xc2f
+	 33, // 0xc30
+	 33, // 0xc31
+	 33, // 0xc32
+	 33, // 0xc33
+	 33, // 0xc34
+	 33, // 0xc35
+	 33, // 0xc36
+	 33, // 0xc37
+	 33, // 0xc38
+	 33, // 0xc39
+	 33, // 0xc3a
+	 33, // 0xc3b
+	 33, // 0xc3c
+	 33, // 0xc3d
+	 33, // 0xc3e
+	 33, // 0xc3f
+	 33, // 0xc40
+	 33, // 0xc41
+	 33, // 0xc42
+	 33, // 0xc43
+	 33, // 0xc44
+	 33, // 0xc45
+	 33, // 0xc46
+	 33, // 0xc47
+	 33, // 0xc48
+	 33, // 0xc49
+	 33, // 0xc4a
+	 33, // 0xc4b
+	 33, // 0xc4c
+	 33, // 0xc4d
+	 33, // 0xc4e
+	 33, // 0xc4f
+	 33, // 0xc50
+	 33, // 0xc51
+	 33, // 0xc52
+	 33, // 0xc53
+	 33, // 0xc54
+	 33, // 0xc55
+	 33, // 0xc56
+	 33, // 0xc57
+	 33, // 0xc58
+	 33, // 0xc59
+	 33, // 0xc5a
+	 33, // 0xc5b
+	 33, // 0xc5c
+	 33, // 0xc5d
+	 33, // 0xc5e
+	 33, // 0xc5f
+	 33, // 0xc60
+	 33, // 0xc61
+	 33, // 0xc62
+	 33, // 0xc63
+	 33, // 0xc64
+	 33, // 0xc65
+	 33, // 0xc66
+	 33, // 0xc67
+	 33, // 0xc68
+	 33, // 0xc69
+	 33, // 0xc6a
+	 33, // 0xc6b
+	 33, // 0xc6c
+	 33, // 0xc6d
+	 33, // 0xc6e
+	 33, // 0xc6f
+	 33, // 0xc70
+	 33, // 0xc71
+	 33, // 0xc72
+	 33, // 0xc73
+	 33, // 0xc74
+	 33, // 0xc75
+	 33, // 0xc76
+	 33, // 0xc77
+	 33, // 0xc78
+	 33, // 0xc79
+	 33, // 0xc7a
+	 33, // 0xc7b
+	 33, // 0xc7c
+	 33, // 0xc7d
+	 33, // 0xc7e
+	 33, // 0xc7f
+	 33, // 0xc80
+	 33, // 0xc81
+	 33, // 0xc82
+	 33, // 0xc83
+	 33, // 0xc84
+	 33, // 0xc85
+	 33, // 0xc86
+	 33, // 0xc87
+	 33, // 0xc88
+	 33, // 0xc89
+	 33, // 0xc8a
+	 33, // 0xc8b
+	 33, // 0xc8c
+	 33, // 0xc8d
+	 33, // 0xc8e
+	 33, // 0xc8f
+	 33, // 0xc90
+	 33, // 0xc91
+	 33, // 0xc92
+	 33, // 0xc93
+	 33, // 0xc94
+	 33, // 0xc95
+	 33, // 0xc96
+	 33, // 0xc97
+	 33, // 0xc98
+	 33, // 0xc99
+	 33, // 0xc9a
+	 33, // 0xc9b
+	 33, // 0xc9c
+	 33, // 0xc9d
+	 33, // 0xc9e
+	 33, // 0xc9f
+	 33, // 0xca0
+	 33, // 0xca1
+	 33, // 0xca2
+	 33, // 0xca3
+	 33, // 0xca4
+	 33, // 0xca5
+	 33, // 0xca6
+	 33, // 0xca7
+	 33, // 0xca8
+	 33, // 0xca9
+	 33, // 0xcaa
+	 33, // 0xcab
+	 33, // 0xcac
+	 33, // 0xcad
+	 33, // 0xcae
+	 33, // 0xcaf
+	 33, // 0xcb0
+	 33, // 0xcb1
+	 33, // 0xcb2
+	 33, // 0xcb3
+	 33, // 0xcb4
+	 33, // 0xcb5
+	 33, // 0xcb6
+	 33, // 0xcb7
+	 33, // 0xcb8
+	 33, // 0xcb9
+	 33, // 0xcba
+	 33, // 0xcbb
+	 33, // 0xcbc
+	 33, // 0xcbd
+	 33, // 0xcbe
+	 33, // 0xcbf
+	 33, // 0xcc0
+	 33, // 0xcc1
+	 33, // 0xcc2
+	 33, // 0xcc3
+	 33, // 0xcc4
+	 33, // 0xcc5
+	 33, // 0xcc6
+	 33, // 0xcc7
+	 33, // 0xcc8
+	 33, // 0xcc9
+	 33, // 0xcca
+	 33, // 0xccb
+	 33, // 0xccc
+	 33, // 0xccd
+	 33, // 0xcce
+	 33, // 0xccf
+	 33, // 0xcd0
+	 33, // 0xcd1
+	 33, // 0xcd2
+	 33, // 0xcd3
+	 33, // 0xcd4
+	 33, // 0xcd5
+	 33, // 0xcd6
+	 33, // 0xcd7
+	 33, // 0xcd8
+	 33, // 0xcd9
+	 33, // 0xcda
+	 33, // 0xcdb
+	 33, // 0xcdc
+	 33, // 0xcdd
+	 33, // 0xcde
+	 33, // 0xcdf
+	 33, // 0xce0
+	 33, // 0xce1
+	 33, // 0xce2
+	 33, // 0xce3
+	 33, // 0xce4
+	 33, // 0xce5
+	 33, // 0xce6
+	 33, // 0xce7
+	 33, // 0xce8
+	 33, // 0xce9
+	 33, // 0xcea
+	 33, // 0xceb
+	 33, // 0xcec
+	 33, // 0xced
+	 33, // 0xcee
+	 33, // 0xcef
+	 33, // 0xcf0
+	 33, // 0xcf1
+	 33, // 0xcf2
+	 33, // 0xcf3
+	 33, // 0xcf4
+	 33, // 0xcf5
+	 33, // 0xcf6
+	 33, // 0xcf7
+	 33, // 0xcf8
+	 33, // 0xcf9
+	 33, // 0xcfa
+	 33, // 0xcfb
+	 33, // 0xcfc
+	 33, // 0xcfd
+	 33, // 0xcfe
+	 33, // 0xcff
+	 33, // 0xd00
+	 33, // 0xd01
+	 33, // 0xd02
+	 33, // 0xd03
+	 33, // 0xd04
+	 33, // 0xd05
+	 33, // 0xd06
+	 33, // 0xd07
+	 33, // 0xd08
+	 33, // 0xd09
+	 33, // 0xd0a
+	 33, // 0xd0b
+	 33, // 0xd0c
+	 33, // 0xd0d
+	 33, // 0xd0e
+	 33, // 0xd0f
+	 33, // 0xd10
+	 33, // 0xd11
+	 33, // 0xd12
+	 33, // 0xd13
+	 33, // 0xd14
+	 33, // 0xd15
+	 33, // 0xd16
+	 33, // 0xd17
+	 33, // 0xd18
+	 33, // 0xd19
+	 33, // 0xd1a
+	 33, // 0xd1b
+	 33, // 0xd1c
+	 33, // 0xd1d
+	 33, // 0xd1e
+	 33, // 0xd1f
+	 33, // 0xd20
+	 33, // 0xd21
+	 33, // 0xd22
+	 33, // 0xd23
+	 33, // 0xd24
+	 33, // 0xd25
+	 33, // 0xd26
+	 33, // 0xd27
+	 33, // 0xd28
+	 33, // 0xd29
+	 33, // 0xd2a
+	 33, // 0xd2b
+	 33, // 0xd2c
+	 33, // 0xd2d
+	 33, // 0xd2e
+	 33, // 0xd2f
+	 33, // 0xd30
+	 33, // 0xd31
+	 33, // 0xd32
+	 33, // 0xd33
+	 33, // 0xd34
+	 33, // 0xd35
+	 33, // 0xd36
+	 33, // 0xd37
+	 33, // 0xd38
+	 33, // 0xd39
+	 33, // 0xd3a
+	 33, // 0xd3b
+	 33, // 0xd3c
+	 33, // 0xd3d
+	 33, // 0xd3e
+	 33, // 0xd3f
+	 33, // 0xd40
+	 33, // 0xd41
+	 33, // 0xd42
+	 33, // 0xd43
+	 33, // 0xd44
+	 33, // 0xd45
+	 33, // 0xd46
+	 33, // 0xd47
+	 33, // 0xd48
+	 33, // 0xd49
+	 33, // 0xd4a
+	 33, // 0xd4b
+	 33, // 0xd4c
+	 33, // 0xd4d
+	 33, // 0xd4e
+	 33, // 0xd4f
+	 33, // 0xd50
+	 33, // 0xd51
+	 33, // 0xd52
+	 33, // 0xd53
+	 33, // 0xd54
+	 33, // 0xd55
+	 33, // 0xd56
+	 33, // 0xd57
+	 33, // 0xd58
+	 33, // 0xd59
+	 33, // 0xd5a
+	 33, // 0xd5b
+	 33, // 0xd5c
+	 33, // 0xd5d
+	 33, // 0xd5e
+	 33, // 0xd5f
+	 33, // 0xd60
+	 33, // 0xd61
+	 33, // 0xd62
+	 33, // 0xd63
+	 33, // 0xd64
+	 33, // 0xd65
+	 33, // 0xd66
+	 33, // 0xd67
+	 33, // 0xd68
+	 33, // 0xd69
+	 33, // 0xd6a
+	 33, // 0xd6b
+	 33, // 0xd6c
+	 33, // 0xd6d
+	 33, // 0xd6e
+	 33, // 0xd6f
+	 33, // 0xd70
+	 33, // 0xd71
+	 33, // 0xd72
+	 33, // 0xd73
+	 33, // 0xd74
+	 33, // 0xd75
+	 33, // 0xd76
+	 33, // 0xd77
+	 33, // 0xd78
+	 33, // 0xd79
+	 33, // 0xd7a
+	 33, // 0xd7b
+	 33, // 0xd7c
+	 33, // 0xd7d
+	 33, // 0xd7e
+	 33, // 0xd7f
+	 33, // 0xd80
+	 33, // 0xd81
+	 33, // 0xd82
+	 33, // 0xd83
+	 33, // 0xd84
+	 33, // 0xd85
+	 33, // 0xd86
+	 33, // 0xd87
+	 33, // 0xd88
+	 33, // 0xd89
+	 33, // 0xd8a
+	 33, // 0xd8b
+	 33, // 0xd8c
+	 33, // 0xd8d
+	 33, // 0xd8e
+	 33, // 0xd8f
+	 33, // 0xd90
+	 33, // 0xd91
+	 33, // 0xd92
+	 33, // 0xd93
+	 33, // 0xd94
+	 33, // 0xd95
+	 33, // 0xd96
+	 33, // 0xd97
+	 33, // 0xd98
+	 33, // 0xd99
+	 33, // 0xd9a
+	 33, // 0xd9b
+	 33, // 0xd9c
+	 33, // 0xd9d
+	 33, // 0xd9e
+	 33, // 0xd9f
+	 33, // 0xda0
+	 33, // 0xda1
+	 33, // 0xda2
+	 33, // 0xda3
+	 33, // 0xda4
+	 33, // 0xda5
+	 33, // 0xda6
+	 33, // 0xda7
+	 33, // 0xda8
+	 33, // 0xda9
+	 33, // 0xdaa
+	 33, // 0xdab
+	 33, // 0xdac
+	 33, // 0xdad
+	 33, // 0xdae
+	 33, // 0xdaf
+	 33, // 0xdb0
+	 33, // 0xdb1
+	 33, // 0xdb2
+	 33, // 0xdb3
+	 33, // 0xdb4
+	 33, // 0xdb5
+	 33, // 0xdb6
+	 33, // 0xdb7
+	 33, // 0xdb8
+	 33, // 0xdb9
+	 33, // 0xdba
+	 33, // 0xdbb
+	 33, // 0xdbc
+	 33, // 0xdbd
+	 33, // 0xdbe
+	 33, // 0xdbf
+	 33, // 0xdc0
+	 33, // 0xdc1
+	 33, // 0xdc2
+	 33, // 0xdc3
+	 33, // 0xdc4
+	 33, // 0xdc5
+	 33, // 0xdc6
+	 33, // 0xdc7
+	 33, // 0xdc8
+	 33, // 0xdc9
+	 33, // 0xdca
+	 33, // 0xdcb
+	 33, // 0xdcc
+	 33, // 0xdcd
+	 33, // 0xdce
+	 33, // 0xdcf
+	 33, // 0xdd0
+	 33, // 0xdd1
+	 33, // 0xdd2
+	 33, // 0xdd3
+	 33, // 0xdd4
+	 33, // 0xdd5
+	 33, // 0xdd6
+	 33, // 0xdd7
+	 33, // 0xdd8
+	 33, // 0xdd9
+	 33, // 0xdda
+	 33, // 0xddb
+	 33, // 0xddc
+	 33, // 0xddd
+	 33, // 0xdde
+	 33, // 0xddf
+	 33, // 0xde0
+	 33, // 0xde1
+	 33, // 0xde2
+	 33, // 0xde3
+	 33, // 0xde4
+	 33, // 0xde5
+	 33, // 0xde6
+	 33, // 0xde7
+	 33, // 0xde8
+	 33, // 0xde9
+	 33, // 0xdea
+	 33, // 0xdeb
+	 33, // 0xdec
+	 33, // 0xded
+	 33, // 0xdee
+	 33, // 0xdef
+	 33, // 0xdf0
+	 33, // 0xdf1
+	 33, // 0xdf2
+	 33, // 0xdf3
+	 33, // 0xdf4
+	 33, // 0xdf5
+	 33, // 0xdf6
+	 33, // 0xdf7
+	 33, // 0xdf8
+	 33, // 0xdf9
+	 33, // 0xdfa
+	 33, // 0xdfb
+	 33, // 0xdfc
+	 33, // 0xdfd
+	 33, // 0xdfe
+	 33, // 0xdff
+	 138, // 0xe00
+	 139, // 0xe01
+	 33, // 0xe02
+	 33, // 0xe03
+	 33, // 0xe04
+	 33, // 0xe05
+	 33, // 0xe06
+	 33, // 0xe07
+	 33, // 0xe08
+	 33, // 0xe09
+	 33, // 0xe0a
+	 33, // 0xe0b
+	 33, // 0xe0c
+	 33, // 0xe0d
+	 33, // 0xe0e
+	 33, // 0xe0f
+	 33, // 0xe10
+	 33, // 0xe11
+	 33, // 0xe12
+	 33, // 0xe13
+	 33, // 0xe14
+	 33, // 0xe15
+	 33, // 0xe16
+	 33, // 0xe17
+	 33, // 0xe18
+	 33, // 0xe19
+	 33, // 0xe1a
+	 33, // 0xe1b
+	 33, // 0xe1c
+	 33, // 0xe1d
+	 33, // 0xe1e
+	 33, // 0xe1f
+	 33, // 0xe20
+	 33, // 0xe21
+	 33, // 0xe22
+	 33, // 0xe23
+	 33, // 0xe24
+	 33, // 0xe25
+	 33, // 0xe26
+	 33, // 0xe27
+	 33, // 0xe28
+	 33, // 0xe29
+	 33, // 0xe2a
+	 33, // 0xe2b
+	 33, // 0xe2c
+	 33, // 0xe2d
+	 33, // 0xe2e
+	 33, // 0xe2f
+	 33, // 0xe30
+	 33, // 0xe31
+	 33, // 0xe32
+	 33, // 0xe33
+	 33, // 0xe34
+	 33, // 0xe35
+	 33, // 0xe36
+	 33, // 0xe37
+	 33, // 0xe38
+	 33, // 0xe39
+	 33, // 0xe3a
+	 33, // 0xe3b
+	 33, // 0xe3c
+	 33, // 0xe3d
+	 33, // 0xe3e
+	 33, // 0xe3f
+	 33, // 0xe40
+	 33, // 0xe41
+	 33, // 0xe42
+	 33, // 0xe43
+	 33, // 0xe44
+	 33, // 0xe45
+	 33, // 0xe46
+	 33, // 0xe47
+	 33, // 0xe48
+	 33, // 0xe49
+	 33, // 0xe4a
+	 33, // 0xe4b
+	 33, // 0xe4c
+	 33, // 0xe4d
+	 33, // 0xe4e
+	 33, // 0xe4f
+	 33, // 0xe50
+	 33, // 0xe51
+	 33, // 0xe52
+	 33, // 0xe53
+	 33, // 0xe54
+	 33, // 0xe55
+	 33, // 0xe56
+	 33, // 0xe57
+	 33, // 0xe58
+	 33, // 0xe59
+	 33, // 0xe5a
+	 33, // 0xe5b
+	 33, // 0xe5c
+	 33, // 0xe5d
+	 33, // 0xe5e
+	 33, // 0xe5f
+	 33, // 0xe60
+	 33, // 0xe61
+	 33, // 0xe62
+	 33, // 0xe63
+	 33, // 0xe64
+	 33, // 0xe65
+	 33, // 0xe66
+	 33, // 0xe67
+	 33, // 0xe68
+	 33, // 0xe69
+	 33, // 0xe6a
+	 33, // 0xe6b
+	 33, // 0xe6c
+	 33, // 0xe6d
+	 33, // 0xe6e
+	 33, // 0xe6f
+	 33, // 0xe70
+	 33, // 0xe71
+	 33, // 0xe72
+	 33, // 0xe73
+	 33, // 0xe74
+	 33, // 0xe75
+	 33, // 0xe76
+	 33, // 0xe77
+	 33, // 0xe78
+	 33, // 0xe79
+	 33, // 0xe7a
+	 33, // 0xe7b
+	 33, // 0xe7c
+	 33, // 0xe7d
+	 33, // 0xe7e
+	 33, // 0xe7f
+	 33, // 0xe80
+	 33, // 0xe81
+	 33, // 0xe82
+	 33, // 0xe83
+	 33, // 0xe84
+	 33, // 0xe85
+	 33, // 0xe86
+	 33, // 0xe87
+	 33, // 0xe88
+	 33, // 0xe89
+	 33, // 0xe8a
+	 33, // 0xe8b
+	 33, // 0xe8c
+	 33, // 0xe8d
+	 33, // 0xe8e
+	 33, // 0xe8f
+	 33, // 0xe90
+	 33, // 0xe91
+	 33, // 0xe92
+	 33, // 0xe93
+	 33, // 0xe94
+	 33, // 0xe95
+	 33, // 0xe96
+	 33, // 0xe97
+	 33, // 0xe98
+	 33, // 0xe99
+	 33, // 0xe9a
+	 33, // 0xe9b
+	 33, // 0xe9c
+	 33, // 0xe9d
+	 33, // 0xe9e
+	 33, // 0xe9f
+	 33, // 0xea0
+	 33, // 0xea1
+	 33, // 0xea2
+	 33, // 0xea3
+	 33, // 0xea4
+	 33, // 0xea5
+	 33, // 0xea6
+	 33, // 0xea7
+	 33, // 0xea8
+	 33, // 0xea9
+	 33, // 0xeaa
+	 33, // 0xeab
+	 33, // 0xeac
+	 33, // 0xead
+	 33, // 0xeae
+	 33, // 0xeaf
+	 33, // 0xeb0
+	 33, // 0xeb1
+	 33, // 0xeb2
+	 33, // 0xeb3
+	 33, // 0xeb4
+	 33, // 0xeb5
+	 33, // 0xeb6
+	 33, // 0xeb7
+	 33, // 0xeb8
+	 33, // 0xeb9
+	 33, // 0xeba
+	 33, // 0xebb
+	 33, // 0xebc
+	 33, // 0xebd
+	 33, // 0xebe
+	 33, // 0xebf
+	 33, // 0xec0
+	 33, // 0xec1
+	 33, // 0xec2
+	 33, // 0xec3
+	 33, // 0xec4
+	 33, // 0xec5
+	 33, // 0xec6
+	 33, // 0xec7
+	 33, // 0xec8
+	 33, // 0xec9
+	 33, // 0xeca
+	 33, // 0xecb
+	 33, // 0xecc
+	 33, // 0xecd
+	 33, // 0xece
+	 33, // 0xecf
+	 33, // 0xed0
+	 33, // 0xed1
+	 33, // 0xed2
+	 33, // 0xed3
+	 33, // 0xed4
+	 33, // 0xed5
+	 33, // 0xed6
+	 33, // 0xed7
+	 33, // 0xed8
+	 33, // 0xed9
+	 33, // 0xeda
+	 33, // 0xedb
+	 33, // 0xedc
+	 33, // 0xedd
+	 33, // 0xede
+	 33, // 0xedf
+	 33, // 0xee0
+	 33, // 0xee1
+	 33, // 0xee2
+	 33, // 0xee3
+	 33, // 0xee4
+	 33, // 0xee5
+	 33, // 0xee6
+	 33, // 0xee7
+	 33, // 0xee8
+	 33, // 0xee9
+	 33, // 0xeea
+	 33, // 0xeeb
+	 33, // 0xeec
+	 33, // 0xeed
+	 33, // 0xeee
+	 33, // 0xeef
+	 33, // 0xef0
+	 33, // 0xef1
+	 33, // 0xef2
+	 33, // 0xef3
+	 33, // 0xef4
+	 33, // 0xef5
+	 33, // 0xef6
+	 33, // 0xef7
+	 33, // 0xef8
+	 33, // 0xef9
+	 33, // 0xefa
+	 33, // 0xefb
+	 33, // 0xefc
+	 33, // 0xefd
+	 33, // 0xefe
+	 33, // 0xeff
+	 33, // 0xf00
+	 33, // 0xf01
+	 33, // 0xf02
+	 33, // 0xf03
+	 33, // 0xf04
+	 33, // 0xf05
+	 33, // 0xf06
+	 33, // 0xf07
+	 33, // 0xf08
+	 33, // 0xf09
+	 33, // 0xf0a
+	 33, // 0xf0b
+	 33, // 0xf0c
+	 33, // 0xf0d
+	 33, // 0xf0e
+	 33, // 0xf0f
+	 33, // 0xf10
+	 33, // 0xf11
+	 33, // 0xf12
+	 33, // 0xf13
+	 33, // 0xf14
+	 33, // 0xf15
+	 33, // 0xf16
+	 33, // 0xf17
+	 33, // 0xf18
+	 33, // 0xf19
+	 33, // 0xf1a
+	 33, // 0xf1b
+	 33, // 0xf1c
+	 33, // 0xf1d
+	 33, // 0xf1e
+	 33, // 0xf1f
+	 33, // 0xf20
+	 33, // 0xf21
+	 33, // 0xf22
+	 33, // 0xf23
+	 33, // 0xf24
+	 33, // 0xf25
+	 33, // 0xf26
+	 33, // 0xf27
+	 33, // 0xf28
+	 33, // 0xf29
+	 33, // 0xf2a
+	 33, // 0xf2b
+	 33, // 0xf2c
+	 33, // 0xf2d
+	 33, // 0xf2e
+	 33, // 0xf2f
+	 33, // 0xf30
+	 33, // 0xf31
+	 33, // 0xf32
+	 33, // 0xf33
+	 33, // 0xf34
+	 33, // 0xf35
+	 33, // 0xf36
+	 33, // 0xf37
+	 33, // 0xf38
+	 33, // 0xf39
+	 33, // 0xf3a
+	 33, // 0xf3b
+	 33, // 0xf3c
+	 33, // 0xf3d
+	 33, // 0xf3e
+	 33, // 0xf3f
+	 33, // 0xf40
+	 33, // 0xf41
+	 33, // 0xf42
+	 33, // 0xf43
+	 33, // 0xf44
+	 33, // 0xf45
+	 33, // 0xf46
+	 33, // 0xf47
+	 33, // 0xf48
+	 33, // 0xf49
+	 33, // 0xf4a
+	 33, // 0xf4b
+	 33, // 0xf4c
+	 33, // 0xf4d
+	 33, // 0xf4e
+	 33, // 0xf4f
+	 33, // 0xf50
+	 33, // 0xf51
+	 33, // 0xf52
+	 33, // 0xf53
+	 33, // 0xf54
+	 33, // 0xf55
+	 33, // 0xf56
+	 33, // 0xf57
+	 33, // 0xf58
+	 33, // 0xf59
+	 33, // 0xf5a
+	 33, // 0xf5b
+	 33, // 0xf5c
+	 33, // 0xf5d
+	 33, // 0xf5e
+	 33, // 0xf5f
+	 33, // 0xf60
+	 33, // 0xf61
+	 33, // 0xf62
+	 33, // 0xf63
+	 33, // 0xf64
+	 33, // 0xf65
+	 33, // 0xf66
+	 33, // 0xf67
+	 33, // 0xf68
+	 33, // 0xf69
+	 33, // 0xf6a
+	 33, // 0xf6b
+	 33, // 0xf6c
+	 33, // 0xf6d
+	 33, // 0xf6e
+	 33, // 0xf6f
+	 33, // 0xf70
+	 33, // 0xf71
+	 33, // 0xf72
+	 33, // 0xf73
+	 33, // 0xf74
+	 33, // 0xf75
+	 33, // 0xf76
+	 33, // 0xf77
+	 33, // 0xf78
+	 33, // 0xf79
+	 33, // 0xf7a
+	 33, // 0xf7b
+	 33, // 0xf7c
+	 33, // 0xf7d
+	 33, // 0xf7e
+	 33, // 0xf7f
+	 33, // 0xf80
+	 33, // 0xf81
+	 33, // 0xf82
+	 33, // 0xf83
+	 33, // 0xf84
+	 33, // 0xf85
+	 33, // 0xf86
+	 33, // 0xf87
+	 33, // 0xf88
+	 33, // 0xf89
+	 33, // 0xf8a
+	 33, // 0xf8b
+	 33, // 0xf8c
+	 33, // 0xf8d
+	 33, // 0xf8e
+	 33, // 0xf8f
+	 33, // 0xf90
+	 33, // 0xf91
+	 33, // 0xf92
+	 33, // 0xf93
+	 33, // 0xf94
+	 33, // 0xf95
+	 33, // 0xf96
+	 33, // 0xf97
+	 33, // 0xf98
+	 33, // 0xf99
+	 33, // 0xf9a
+	 33, // 0xf9b
+	 33, // 0xf9c
+	 33, // 0xf9d
+	 33, // 0xf9e
+	 33, // 0xf9f
+	 33, // 0xfa0
+	 33, // 0xfa1
+	 33, // 0xfa2
+	 33, // 0xfa3
+	 33, // 0xfa4
+	 33, // 0xfa5
+	 33, // 0xfa6
+	 33, // 0xfa7
+	 33, // 0xfa8
+	 33, // 0xfa9
+	 33, // 0xfaa
+	 33, // 0xfab
+	 33, // 0xfac
+	 33, // 0xfad
+	 33, // 0xfae
+	 33, // 0xfaf
+	 33, // 0xfb0
+	 33, // 0xfb1
+	 33, // 0xfb2
+	 33, // 0xfb3
+	 33, // 0xfb4
+	 33, // 0xfb5
+	 33, // 0xfb6
+	 33, // 0xfb7
+	 33, // 0xfb8
+	 33, // 0xfb9
+	 33, // 0xfba
+	 33, // 0xfbb
+	 33, // 0xfbc
+	 33, // 0xfbd
+	 33, // 0xfbe
+	 33, // 0xfbf
+	 33, // 0xfc0
+	 33, // 0xfc1
+	 33, // 0xfc2
+	 33, // 0xfc3
+	 33, // 0xfc4
+	 33, // 0xfc5
+	 33, // 0xfc6
+	 33, // 0xfc7
+	 33, // 0xfc8
+	 33, // 0xfc9
+	 33, // 0xfca
+	 33, // 0xfcb
+	 33, // 0xfcc
+	 33, // 0xfcd
+	 33, // 0xfce
+	 33, // 0xfcf
+	 33, // 0xfd0
+	 33, // 0xfd1
+	 33, // 0xfd2
+	 33, // 0xfd3
+	 33, // 0xfd4
+	 33, // 0xfd5
+	 33, // 0xfd6
+	 33, // 0xfd7
+	 33, // 0xfd8
+	 33, // 0xfd9
+	 33, // 0xfda
+	 33, // 0xfdb
+	 33, // 0xfdc
+	 33, // 0xfdd
+	 33, // 0xfde
+	 33, // 0xfdf
+	 33, // 0xfe0
+	 33, // 0xfe1
+	 33, // 0xfe2
+	 33, // 0xfe3
+	 33, // 0xfe4
+	 33, // 0xfe5
+	 33, // 0xfe6
+	 33, // 0xfe7
+	 33, // 0xfe8
+	 33, // 0xfe9
+	 33, // 0xfea
+	 33, // 0xfeb
+	 33, // 0xfec
+	 33, // 0xfed
+	 33, // 0xfee
+	 33, // 0xfef
+	 33, // 0xff0
+	 33, // 0xff1
+	 33, // 0xff2
+	 33, // 0xff3
+	 33, // 0xff4
+	 33, // 0xff5
+	 33, // 0xff6
+	 33, // 0xff7
+	 33, // 0xff8
+	 33, // 0xff9
+	 33, // 0xffa
+	 33, // 0xffb
+	 33, // 0xffc
+	 33, // 0xffd
+	 33, // 0xffe
+	 33, // 0xfff
+	 33, // 0x1000
+	 33, // 0x1001
+	 33, // 0x1002
+	 33, // 0x1003
+	 33, // 0x1004
+	 33, // 0x1005
+	 33, // 0x1006
+	 33, // 0x1007
+	 33, // 0x1008
+	 33, // 0x1009
+	 33, // 0x100a
+	 33, // 0x100b
+	 33, // 0x100c
+	 33, // 0x100d
+	 33, // 0x100e
+	 33, // 0x100f
+	 33, // 0x1010
+	 33, // 0x1011
+	 33, // 0x1012
+	 33, // 0x1013
+	 33, // 0x1014
+	 33, // 0x1015
+	 33, // 0x1016
+	 33, // 0x1017
+	 33, // 0x1018
+	 33, // 0x1019
+	 33, // 0x101a
+	 33, // 0x101b
+	 33, // 0x101c
+	 33, // 0x101d
+	 33, // 0x101e
+	 33, // 0x101f
+	 33, // 0x1020
+	 33, // 0x1021
+	 33, // 0x1022
+	 33, // 0x1023
+	 33, // 0x1024
+	 33, // 0x1025
+	 33, // 0x1026
+	 33, // 0x1027
+	 33, // 0x1028
+	 33, // 0x1029
+	 33, // 0x102a
+	 33, // 0x102b
+	 33, // 0x102c
+	 33, // 0x102d
+	 33, // 0x102e
+	 33, // 0x102f
+	 33, // 0x1030
+	 33, // 0x1031
+	 33, // 0x1032
+	 33, // 0x1033
+	 33, // 0x1034
+	 33, // 0x1035
+	 33, // 0x1036
+	 33, // 0x1037
+	 33, // 0x1038
+	 33, // 0x1039
+	 33, // 0x103a
+	 33, // 0x103b
+	 33, // 0x103c
+	 33, // 0x103d
+	 33, // 0x103e
+	 33, // 0x103f
+	 33, // 0x1040
+	 33, // 0x1041
+	 33, // 0x1042
+	 33, // 0x1043
+	 33, // 0x1044
+	 33, // 0x1045
+	 33, // 0x1046
+	 33, // 0x1047
+	 33, // 0x1048
+	 33, // 0x1049
+	 33, // 0x104a
+	 33, // 0x104b
+	 33, // 0x104c
+	 33, // 0x104d
+	 33, // 0x104e
+	 33, // 0x104f
+	 33, // 0x1050
+	 33, // 0x1051
+	 33, // 0x1052
+	 33, // 0x1053
+	 33, // 0x1054
+	 33, // 0x1055
+	 33, // 0x1056
+	 33, // 0x1057
+	 33, // 0x1058
+	 33, // 0x1059
+	 33, // 0x105a
+	 33, // 0x105b
+	 33, // 0x105c
+	 33, // 0x105d
+	 33, // 0x105e
+	 33, // 0x105f
+	 33, // 0x1060
+	 33, // 0x1061
+	 33, // 0x1062
+	 33, // 0x1063
+	 33, // 0x1064
+	 33, // 0x1065
+	 33, // 0x1066
+	 33, // 0x1067
+	 33, // 0x1068
+	 33, // 0x1069
+	 33, // 0x106a
+	 33, // 0x106b
+	 33, // 0x106c
+	 33, // 0x106d
+	 33, // 0x106e
+	 33, // 0x106f
+	 33, // 0x1070
+	 33, // 0x1071
+	 33, // 0x1072
+	 33, // 0x1073
+	 33, // 0x1074
+	 33, // 0x1075
+	 33, // 0x1076
+	 33, // 0x1077
+	 33, // 0x1078
+	 33, // 0x1079
+	 33, // 0x107a
+	 33, // 0x107b
+	 33, // 0x107c
+	 33, // 0x107d
+	 33, // 0x107e
+	 33, // 0x107f
+	 33, // 0x1080
+	 33, // 0x1081
+	 33, // 0x1082
+	 33, // 0x1083
+	 33, // 0x1084
+	 33, // 0x1085
+	 33, // 0x1086
+	 33, // 0x1087
+	 33, // 0x1088
+	 33, // 0x1089
+	 33, // 0x108a
+	 33, // 0x108b
+	 33, // 0x108c
+	 33, // 0x108d
+	 33, // 0x108e
+	 33, // 0x108f
+	 33, // 0x1090
+	 33, // 0x1091
+	 33, // 0x1092
+	 33, // 0x1093
+	 33, // 0x1094
+	 33, // 0x1095
+	 33, // 0x1096
+	 33, // 0x1097
+	 33, // 0x1098
+	 33, // 0x1099
+	 33, // 0x109a
+	 33, // 0x109b
+	 33, // 0x109c
+	 33, // 0x109d
+	 33, // 0x109e
+	 33, // 0x109f
+	 33, // 0x10a0
+	 33, // 0x10a1
+	 33, // 0x10a2
+	 33, // 0x10a3
+	 33, // 0x10a4
+	 33, // 0x10a5
+	 33, // 0x10a6
+	 33, // 0x10a7
+	 33, // 0x10a8
+	 33, // 0x10a9
+	 33, // 0x10aa
+	 33, // 0x10ab
+	 33, // 0x10ac
+	 33, // 0x10ad
+	 33, // 0x10ae
+	 33, // 0x10af
+	 33, // 0x10b0
+	 33, // 0x10b1
+	 33, // 0x10b2
+	 33, // 0x10b3
+	 33, // 0x10b4
+	 33, // 0x10b5
+	 33, // 0x10b6
+	 33, // 0x10b7
+	 33, // 0x10b8
+	 33, // 0x10b9
+	 33, // 0x10ba
+	 33, // 0x10bb
+	 33, // 0x10bc
+	 33, // 0x10bd
+	 33, // 0x10be
+	 33, // 0x10bf
+	 33, // 0x10c0
+	 33, // 0x10c1
+	 33, // 0x10c2
+	 33, // 0x10c3
+	 33, // 0x10c4
+	 33, // 0x10c5
+	 33, // 0x10c6
+	 33, // 0x10c7
+	 33, // 0x10c8
+	 33, // 0x10c9
+	 33, // 0x10ca
+	 33, // 0x10cb
+	 33, // 0x10cc
+	 33, // 0x10cd
+	 33, // 0x10ce
+	 33, // 0x10cf
+	 33, // 0x10d0
+	 33, // 0x10d1
+	 33, // 0x10d2
+	 33, // 0x10d3
+	 33, // 0x10d4
+	 33, // 0x10d5
+	 33, // 0x10d6
+	 33, // 0x10d7
+	 33, // 0x10d8
+	 33, // 0x10d9
+	 33, // 0x10da
+	 33, // 0x10db
+	 33, // 0x10dc
+	 33, // 0x10dd
+	 33, // 0x10de
+	 33, // 0x10df
+	 33, // 0x10e0
+	 33, // 0x10e1
+	 33, // 0x10e2
+	 33, // 0x10e3
+	 33, // 0x10e4
+	 33, // 0x10e5
+	 33, // 0x10e6
+	 33, // 0x10e7
+	 33, // 0x10e8
+	 33, // 0x10e9
+	 33, // 0x10ea
+	 33, // 0x10eb
+	 33, // 0x10ec
+	 33, // 0x10ed
+	 33, // 0x10ee
+	 33, // 0x10ef
+	 33, // 0x10f0
+	 33, // 0x10f1
+	 33, // 0x10f2
+	 33, // 0x10f3
+	 33, // 0x10f4
+	 33, // 0x10f5
+	 33, // 0x10f6
+	 33, // 0x10f7
+	 33, // 0x10f8
+	 33, // 0x10f9
+	 33, // 0x10fa
+	 33, // 0x10fb
+	 33, // 0x10fc
+	 33, // 0x10fd
+	 33, // 0x10fe
+	 33, // 0x10ff
+];
+pub const SB_PAGES: [[u8;256];140] = [
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x6,
+        0x2,
+        0x6,
+        0x6,
+        0x1,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x6,
+        0xd,
+        0xe,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe,
+        0xe,
+        0xe,
+        0x0,
+        0x0,
+        0xc,
+        0xc,
+        0xb,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xc,
+        0xc,
+        0x0,
+        0x0,
+        0x0,
+        0xd,
+        0x0,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0xe,
+        0x0,
+        0xe,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0xe,
+        0x0,
+        0xe,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x4,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0xe,
+        0x0,
+        0x5,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0xe,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x0,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+    ],
+    [
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x8,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x8,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x8,
+        0x8,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x8,
+        0x8,
+        0x7,
+        0x8,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x8,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x8,
+        0x7,
+        0x7,
+        0x9,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x8,
+        0x8,
+        0x7,
+        0x8,
+        0x8,
+        0x7,
+        0x8,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x7,
+        0x8,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+    ],
+    [
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x8,
+        0x8,
+        0x7,
+        0x8,
+        0x8,
+        0x7,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x9,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x7,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x9,
+        0x0,
+        0x8,
+        0x7,
+        0x0,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0xc,
+        0x8,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x8,
+        0x0,
+        0x8,
+        0x8,
+        0x8,
+        0x0,
+        0x8,
+        0x0,
+        0x8,
+        0x8,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x0,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x8,
+        0x7,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x8,
+        0x7,
+        0x0,
+        0x8,
+        0x7,
+        0x8,
+        0x8,
+        0x7,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+    ],
+    [
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+    ],
+    [
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x0,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0xc,
+        0x0,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xc,
+        0xc,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x5,
+        0xd,
+        0xd,
+        0xd,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0xa,
+        0xa,
+        0x0,
+        0x9,
+        0x9,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0xd,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0xa,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+    ],
+    [
+        0xd,
+        0xd,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x5,
+        0x9,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0xc,
+        0xd,
+        0x9,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xd,
+        0x0,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0xd,
+        0xd,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0xa,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0xd,
+        0xd,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x3,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x3,
+        0x0,
+    ],
+    [
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x3,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+    ],
+    [
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x3,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x3,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x3,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x9,
+        0x9,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xd,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x3,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x0,
+        0x8,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x8,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x7,
+        0x9,
+        0x9,
+        0x9,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xd,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x0,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0xd,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x6,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0xe,
+        0xe,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0xd,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0xd,
+        0xd,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x3,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0xc,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xc,
+        0xd,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x5,
+        0x3,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xd,
+        0xd,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0xd,
+        0xd,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xd,
+        0xd,
+        0x0,
+        0x0,
+        0xd,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xd,
+        0xd,
+        0xd,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0xd,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0xd,
+        0xd,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x8,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+    ],
+    [
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+    ],
+    [
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x0,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x0,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x0,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x0,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x8,
+        0x0,
+        0x8,
+        0x0,
+        0x8,
+        0x0,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x7,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x0,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x7,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x0,
+        0x7,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x7,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x6,
+        0x5,
+        0x3,
+        0x3,
+        0x5,
+        0x5,
+        0x0,
+        0x0,
+        0x0,
+        0xc,
+        0xc,
+        0x0,
+        0x0,
+        0x0,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xb,
+        0x0,
+        0x0,
+        0x0,
+        0x4,
+        0x4,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x6,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe,
+        0xe,
+        0x0,
+        0xd,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe,
+        0xe,
+        0xd,
+        0xd,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x6,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x0,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x0,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe,
+        0xe,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe,
+        0xe,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x8,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x8,
+        0x0,
+        0x0,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x0,
+        0x8,
+        0x0,
+        0x0,
+        0x0,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x8,
+        0x0,
+        0x8,
+        0x0,
+        0x8,
+        0x0,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x0,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x7,
+        0x0,
+        0x0,
+        0x7,
+        0x7,
+        0x8,
+        0x8,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x9,
+        0x9,
+        0x9,
+        0x8,
+        0x7,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe,
+        0xe,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe,
+        0xe,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe,
+        0xe,
+        0x0,
+        0x0,
+    ],
+    [
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x7,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x3,
+        0x3,
+        0x3,
+        0x8,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xd,
+        0xd,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+    ],
+    [
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe,
+        0xe,
+        0x0,
+        0x0,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xd,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xd,
+        0xd,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x6,
+        0xc,
+        0xd,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x0,
+        0x0,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x0,
+        0xe,
+        0xe,
+        0xe,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0xd,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0xd,
+        0xd,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x9,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x0,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x9,
+        0x0,
+        0x0,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x9,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x8,
+        0x7,
+        0x0,
+        0x0,
+        0x8,
+        0x7,
+        0x0,
+        0x7,
+        0x0,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x7,
+        0x8,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x8,
+        0x7,
+        0x9,
+        0x7,
+        0x7,
+        0x7,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+    ],
+    [
+        0x9,
+        0x9,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0xd,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xd,
+        0xd,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x3,
+    ],
+    [
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0xd,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xd,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0xd,
+        0xd,
+        0xd,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x9,
+        0x3,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0xd,
+        0xd,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0xd,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0xe,
+        0xe,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0xc,
+        0xc,
+        0xd,
+        0xc,
+        0xc,
+        0xd,
+        0xd,
+        0xe,
+        0xe,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0xc,
+        0xc,
+        0x0,
+        0x0,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x0,
+        0x0,
+        0xe,
+        0xe,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xc,
+        0xc,
+        0xb,
+        0x0,
+        0xc,
+        0xc,
+        0xd,
+        0xd,
+        0xc,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0xe,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xc,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x5,
+    ],
+    [
+        0x0,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe,
+        0xe,
+        0x0,
+        0x0,
+        0xc,
+        0xc,
+        0xb,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xc,
+        0xc,
+        0x0,
+        0x0,
+        0x0,
+        0xd,
+        0x0,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0xe,
+        0x0,
+        0xe,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0xe,
+        0x0,
+        0xe,
+        0x0,
+        0xe,
+        0xe,
+        0xd,
+        0xe,
+        0xe,
+        0xc,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x5,
+        0x5,
+        0x5,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x0,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x0,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x0,
+        0x8,
+        0x8,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x7,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0x9,
+        0x9,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xd,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x9,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0xd,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x3,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0xa,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xa,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0xd,
+        0xd,
+        0xd,
+        0x9,
+        0x3,
+        0x3,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0xd,
+        0xd,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0xd,
+        0x3,
+        0x3,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x9,
+        0x0,
+        0x9,
+        0x0,
+        0xd,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0xd,
+        0xd,
+        0x0,
+        0xd,
+        0xd,
+        0x0,
+        0x3,
+        0x9,
+        0x9,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x3,
+        0x3,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x3,
+        0x9,
+        0xd,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0xd,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0xd,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0xd,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0xd,
+        0xd,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0xd,
+        0xd,
+        0xd,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x3,
+        0x9,
+        0x3,
+        0x3,
+        0xd,
+        0x0,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x0,
+        0x9,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0xd,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0xd,
+        0xd,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0xd,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0xd,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x9,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0xd,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xd,
+        0xd,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0xd,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0xd,
+        0xd,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0xd,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x5,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x8,
+        0x0,
+        0x8,
+        0x8,
+        0x0,
+        0x0,
+        0x8,
+        0x0,
+        0x0,
+        0x8,
+        0x8,
+        0x0,
+        0x0,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x0,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x7,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+    ],
+    [
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x8,
+        0x8,
+        0x0,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x0,
+        0x0,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x0,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x8,
+        0x8,
+        0x0,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x0,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x0,
+        0x8,
+        0x0,
+        0x0,
+        0x0,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+    ],
+    [
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x0,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+    ],
+    [
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x8,
+        0x7,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0xd,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x9,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x3,
+        0x3,
+        0x9,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x7,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0xa,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x9,
+        0x0,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x0,
+        0x9,
+        0x0,
+        0x9,
+        0x0,
+        0x9,
+        0x0,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x8,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0xe,
+        0xe,
+        0xe,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+    ],
+    [
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x9,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x0,
+        0x5,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+    [
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x3,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ],
+];
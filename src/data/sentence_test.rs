@@ -0,0 +1,2055 @@
+// GENERATED CODE DO NOT MANUALLY EDIT
+
+use crate::sentences::tests::sentence_test;
+
+#[test]
+fn standard_sentence_test() {
+	sentence_test("\u{0001}\u{0001}",
+		&["\u{0001}\u{0001}"],
+		"  ÷ [0.2] <START OF HEADING> (XX) × [998.0] <START OF HEADING> (XX) ÷ [0.3]"
+	);
+	sentence_test("\u{0001}\u{0308}\u{0001}",
+		&["\u{0001}\u{0308}\u{0001}"],
+		"  ÷ [0.2] <START OF HEADING> (XX) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <START OF HEADING> (XX) ÷ [0.3]"
+	);
+	sentence_test("\u{0001}\u{000D}",
+		&["\u{0001}\u{000D}"],
+		"  ÷ [0.2] <START OF HEADING> (XX) × [998.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	sentence_test("\u{0001}\u{0308}\u{000D}",
+		&["\u{0001}\u{0308}\u{000D}"],
+		"  ÷ [0.2] <START OF HEADING> (XX) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	sentence_test("\u{0001}\u{000A}",
+		&["\u{0001}\u{000A}"],
+		"  ÷ [0.2] <START OF HEADING> (XX) × [998.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	sentence_test("\u{0001}\u{0308}\u{000A}",
+		&["\u{0001}\u{0308}\u{000A}"],
+		"  ÷ [0.2] <START OF HEADING> (XX) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	sentence_test("\u{0001}\u{0085}",
+		&["\u{0001}\u{0085}"],
+		"  ÷ [0.2] <START OF HEADING> (XX) × [998.0] <NEXT LINE (NEL)> (Sep) ÷ [0.3]"
+	);
+	sentence_test("\u{0001}\u{0308}\u{0085}",
+		&["\u{0001}\u{0308}\u{0085}"],
+		"  ÷ [0.2] <START OF HEADING> (XX) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <NEXT LINE (NEL)> (Sep) ÷ [0.3]"
+	);
+	sentence_test("\u{0001}\u{0009}",
+		&["\u{0001}\u{0009}"],
+		"  ÷ [0.2] <START OF HEADING> (XX) × [998.0] <CHARACTER TABULATION> (Sp) ÷ [0.3]"
+	);
+	sentence_test("\u{0001}\u{0308}\u{0009}",
+		&["\u{0001}\u{0308}\u{0009}"],
+		"  ÷ [0.2] <START OF HEADING> (XX) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <CHARACTER TABULATION> (Sp) ÷ [0.3]"
+	);
+	sentence_test("\u{0001}\u{0061}",
+		&["\u{0001}\u{0061}"],
+		"  ÷ [0.2] <START OF HEADING> (XX) × [998.0] LATIN SMALL LETTER A (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{0001}\u{0308}\u{0061}",
+		&["\u{0001}\u{0308}\u{0061}"],
+		"  ÷ [0.2] <START OF HEADING> (XX) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] LATIN SMALL LETTER A (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{0001}\u{0041}",
+		&["\u{0001}\u{0041}"],
+		"  ÷ [0.2] <START OF HEADING> (XX) × [998.0] LATIN CAPITAL LETTER A (Upper) ÷ [0.3]"
+	);
+	sentence_test("\u{0001}\u{0308}\u{0041}",
+		&["\u{0001}\u{0308}\u{0041}"],
+		"  ÷ [0.2] <START OF HEADING> (XX) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] LATIN CAPITAL LETTER A (Upper) ÷ [0.3]"
+	);
+	sentence_test("\u{0001}\u{01BB}",
+		&["\u{0001}\u{01BB}"],
+		"  ÷ [0.2] <START OF HEADING> (XX) × [998.0] LATIN LETTER TWO WITH STROKE (OLetter) ÷ [0.3]"
+	);
+	sentence_test("\u{0001}\u{0308}\u{01BB}",
+		&["\u{0001}\u{0308}\u{01BB}"],
+		"  ÷ [0.2] <START OF HEADING> (XX) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] LATIN LETTER TWO WITH STROKE (OLetter) ÷ [0.3]"
+	);
+	sentence_test("\u{0001}\u{0030}",
+		&["\u{0001}\u{0030}"],
+		"  ÷ [0.2] <START OF HEADING> (XX) × [998.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	sentence_test("\u{0001}\u{0308}\u{0030}",
+		&["\u{0001}\u{0308}\u{0030}"],
+		"  ÷ [0.2] <START OF HEADING> (XX) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	sentence_test("\u{0001}\u{002E}",
+		&["\u{0001}\u{002E}"],
+		"  ÷ [0.2] <START OF HEADING> (XX) × [998.0] FULL STOP (ATerm) ÷ [0.3]"
+	);
+	sentence_test("\u{0001}\u{0308}\u{002E}",
+		&["\u{0001}\u{0308}\u{002E}"],
+		"  ÷ [0.2] <START OF HEADING> (XX) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] FULL STOP (ATerm) ÷ [0.3]"
+	);
+	sentence_test("\u{0001}\u{0021}",
+		&["\u{0001}\u{0021}"],
+		"  ÷ [0.2] <START OF HEADING> (XX) × [998.0] EXCLAMATION MARK (STerm) ÷ [0.3]"
+	);
+	sentence_test("\u{0001}\u{0308}\u{0021}",
+		&["\u{0001}\u{0308}\u{0021}"],
+		"  ÷ [0.2] <START OF HEADING> (XX) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] EXCLAMATION MARK (STerm) ÷ [0.3]"
+	);
+	sentence_test("\u{0001}\u{0022}",
+		&["\u{0001}\u{0022}"],
+		"  ÷ [0.2] <START OF HEADING> (XX) × [998.0] QUOTATION MARK (Close) ÷ [0.3]"
+	);
+	sentence_test("\u{0001}\u{0308}\u{0022}",
+		&["\u{0001}\u{0308}\u{0022}"],
+		"  ÷ [0.2] <START OF HEADING> (XX) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] QUOTATION MARK (Close) ÷ [0.3]"
+	);
+	sentence_test("\u{0001}\u{002C}",
+		&["\u{0001}\u{002C}"],
+		"  ÷ [0.2] <START OF HEADING> (XX) × [998.0] COMMA (SContinue) ÷ [0.3]"
+	);
+	sentence_test("\u{0001}\u{0308}\u{002C}",
+		&["\u{0001}\u{0308}\u{002C}"],
+		"  ÷ [0.2] <START OF HEADING> (XX) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] COMMA (SContinue) ÷ [0.3]"
+	);
+	sentence_test("\u{0001}\u{00AD}",
+		&["\u{0001}\u{00AD}"],
+		"  ÷ [0.2] <START OF HEADING> (XX) × [5.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{0001}\u{0308}\u{00AD}",
+		&["\u{0001}\u{0308}\u{00AD}"],
+		"  ÷ [0.2] <START OF HEADING> (XX) × [5.0] COMBINING DIAERESIS (Extend) × [5.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{0001}\u{0300}",
+		&["\u{0001}\u{0300}"],
+		"  ÷ [0.2] <START OF HEADING> (XX) × [5.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	sentence_test("\u{0001}\u{0308}\u{0300}",
+		&["\u{0001}\u{0308}\u{0300}"],
+		"  ÷ [0.2] <START OF HEADING> (XX) × [5.0] COMBINING DIAERESIS (Extend) × [5.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	sentence_test("\u{000D}\u{0001}",
+		&["\u{000D}", "\u{0001}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] <START OF HEADING> (XX) ÷ [0.3]"
+	);
+	sentence_test("\u{000D}\u{0308}\u{0001}",
+		&["\u{000D}", "\u{0308}\u{0001}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] COMBINING DIAERESIS (Extend) × [998.0] <START OF HEADING> (XX) ÷ [0.3]"
+	);
+	sentence_test("\u{000D}\u{000D}",
+		&["\u{000D}", "\u{000D}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	sentence_test("\u{000D}\u{0308}\u{000D}",
+		&["\u{000D}", "\u{0308}\u{000D}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] COMBINING DIAERESIS (Extend) × [998.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	sentence_test("\u{000D}\u{000A}",
+		&["\u{000D}\u{000A}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) × [3.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	sentence_test("\u{000D}\u{0308}\u{000A}",
+		&["\u{000D}", "\u{0308}\u{000A}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] COMBINING DIAERESIS (Extend) × [998.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	sentence_test("\u{000D}\u{0085}",
+		&["\u{000D}", "\u{0085}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] <NEXT LINE (NEL)> (Sep) ÷ [0.3]"
+	);
+	sentence_test("\u{000D}\u{0308}\u{0085}",
+		&["\u{000D}", "\u{0308}\u{0085}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] COMBINING DIAERESIS (Extend) × [998.0] <NEXT LINE (NEL)> (Sep) ÷ [0.3]"
+	);
+	sentence_test("\u{000D}\u{0009}",
+		&["\u{000D}", "\u{0009}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] <CHARACTER TABULATION> (Sp) ÷ [0.3]"
+	);
+	sentence_test("\u{000D}\u{0308}\u{0009}",
+		&["\u{000D}", "\u{0308}\u{0009}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] COMBINING DIAERESIS (Extend) × [998.0] <CHARACTER TABULATION> (Sp) ÷ [0.3]"
+	);
+	sentence_test("\u{000D}\u{0061}",
+		&["\u{000D}", "\u{0061}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] LATIN SMALL LETTER A (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{000D}\u{0308}\u{0061}",
+		&["\u{000D}", "\u{0308}\u{0061}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] COMBINING DIAERESIS (Extend) × [998.0] LATIN SMALL LETTER A (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{000D}\u{0041}",
+		&["\u{000D}", "\u{0041}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] LATIN CAPITAL LETTER A (Upper) ÷ [0.3]"
+	);
+	sentence_test("\u{000D}\u{0308}\u{0041}",
+		&["\u{000D}", "\u{0308}\u{0041}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] COMBINING DIAERESIS (Extend) × [998.0] LATIN CAPITAL LETTER A (Upper) ÷ [0.3]"
+	);
+	sentence_test("\u{000D}\u{01BB}",
+		&["\u{000D}", "\u{01BB}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] LATIN LETTER TWO WITH STROKE (OLetter) ÷ [0.3]"
+	);
+	sentence_test("\u{000D}\u{0308}\u{01BB}",
+		&["\u{000D}", "\u{0308}\u{01BB}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] COMBINING DIAERESIS (Extend) × [998.0] LATIN LETTER TWO WITH STROKE (OLetter) ÷ [0.3]"
+	);
+	sentence_test("\u{000D}\u{0030}",
+		&["\u{000D}", "\u{0030}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	sentence_test("\u{000D}\u{0308}\u{0030}",
+		&["\u{000D}", "\u{0308}\u{0030}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] COMBINING DIAERESIS (Extend) × [998.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	sentence_test("\u{000D}\u{002E}",
+		&["\u{000D}", "\u{002E}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] FULL STOP (ATerm) ÷ [0.3]"
+	);
+	sentence_test("\u{000D}\u{0308}\u{002E}",
+		&["\u{000D}", "\u{0308}\u{002E}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] COMBINING DIAERESIS (Extend) × [998.0] FULL STOP (ATerm) ÷ [0.3]"
+	);
+	sentence_test("\u{000D}\u{0021}",
+		&["\u{000D}", "\u{0021}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] EXCLAMATION MARK (STerm) ÷ [0.3]"
+	);
+	sentence_test("\u{000D}\u{0308}\u{0021}",
+		&["\u{000D}", "\u{0308}\u{0021}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] COMBINING DIAERESIS (Extend) × [998.0] EXCLAMATION MARK (STerm) ÷ [0.3]"
+	);
+	sentence_test("\u{000D}\u{0022}",
+		&["\u{000D}", "\u{0022}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] QUOTATION MARK (Close) ÷ [0.3]"
+	);
+	sentence_test("\u{000D}\u{0308}\u{0022}",
+		&["\u{000D}", "\u{0308}\u{0022}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] COMBINING DIAERESIS (Extend) × [998.0] QUOTATION MARK (Close) ÷ [0.3]"
+	);
+	sentence_test("\u{000D}\u{002C}",
+		&["\u{000D}", "\u{002C}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] COMMA (SContinue) ÷ [0.3]"
+	);
+	sentence_test("\u{000D}\u{0308}\u{002C}",
+		&["\u{000D}", "\u{0308}\u{002C}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] COMBINING DIAERESIS (Extend) × [998.0] COMMA (SContinue) ÷ [0.3]"
+	);
+	sentence_test("\u{000D}\u{00AD}",
+		&["\u{000D}", "\u{00AD}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{000D}\u{0308}\u{00AD}",
+		&["\u{000D}", "\u{0308}\u{00AD}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] COMBINING DIAERESIS (Extend) × [5.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{000D}\u{0300}",
+		&["\u{000D}", "\u{0300}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	sentence_test("\u{000D}\u{0308}\u{0300}",
+		&["\u{000D}", "\u{0308}\u{0300}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] COMBINING DIAERESIS (Extend) × [5.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	sentence_test("\u{000A}\u{0001}",
+		&["\u{000A}", "\u{0001}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] <START OF HEADING> (XX) ÷ [0.3]"
+	);
+	sentence_test("\u{000A}\u{0308}\u{0001}",
+		&["\u{000A}", "\u{0308}\u{0001}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] COMBINING DIAERESIS (Extend) × [998.0] <START OF HEADING> (XX) ÷ [0.3]"
+	);
+	sentence_test("\u{000A}\u{000D}",
+		&["\u{000A}", "\u{000D}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	sentence_test("\u{000A}\u{0308}\u{000D}",
+		&["\u{000A}", "\u{0308}\u{000D}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] COMBINING DIAERESIS (Extend) × [998.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	sentence_test("\u{000A}\u{000A}",
+		&["\u{000A}", "\u{000A}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	sentence_test("\u{000A}\u{0308}\u{000A}",
+		&["\u{000A}", "\u{0308}\u{000A}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] COMBINING DIAERESIS (Extend) × [998.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	sentence_test("\u{000A}\u{0085}",
+		&["\u{000A}", "\u{0085}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] <NEXT LINE (NEL)> (Sep) ÷ [0.3]"
+	);
+	sentence_test("\u{000A}\u{0308}\u{0085}",
+		&["\u{000A}", "\u{0308}\u{0085}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] COMBINING DIAERESIS (Extend) × [998.0] <NEXT LINE (NEL)> (Sep) ÷ [0.3]"
+	);
+	sentence_test("\u{000A}\u{0009}",
+		&["\u{000A}", "\u{0009}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] <CHARACTER TABULATION> (Sp) ÷ [0.3]"
+	);
+	sentence_test("\u{000A}\u{0308}\u{0009}",
+		&["\u{000A}", "\u{0308}\u{0009}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] COMBINING DIAERESIS (Extend) × [998.0] <CHARACTER TABULATION> (Sp) ÷ [0.3]"
+	);
+	sentence_test("\u{000A}\u{0061}",
+		&["\u{000A}", "\u{0061}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] LATIN SMALL LETTER A (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{000A}\u{0308}\u{0061}",
+		&["\u{000A}", "\u{0308}\u{0061}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] COMBINING DIAERESIS (Extend) × [998.0] LATIN SMALL LETTER A (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{000A}\u{0041}",
+		&["\u{000A}", "\u{0041}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] LATIN CAPITAL LETTER A (Upper) ÷ [0.3]"
+	);
+	sentence_test("\u{000A}\u{0308}\u{0041}",
+		&["\u{000A}", "\u{0308}\u{0041}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] COMBINING DIAERESIS (Extend) × [998.0] LATIN CAPITAL LETTER A (Upper) ÷ [0.3]"
+	);
+	sentence_test("\u{000A}\u{01BB}",
+		&["\u{000A}", "\u{01BB}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] LATIN LETTER TWO WITH STROKE (OLetter) ÷ [0.3]"
+	);
+	sentence_test("\u{000A}\u{0308}\u{01BB}",
+		&["\u{000A}", "\u{0308}\u{01BB}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] COMBINING DIAERESIS (Extend) × [998.0] LATIN LETTER TWO WITH STROKE (OLetter) ÷ [0.3]"
+	);
+	sentence_test("\u{000A}\u{0030}",
+		&["\u{000A}", "\u{0030}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	sentence_test("\u{000A}\u{0308}\u{0030}",
+		&["\u{000A}", "\u{0308}\u{0030}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] COMBINING DIAERESIS (Extend) × [998.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	sentence_test("\u{000A}\u{002E}",
+		&["\u{000A}", "\u{002E}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] FULL STOP (ATerm) ÷ [0.3]"
+	);
+	sentence_test("\u{000A}\u{0308}\u{002E}",
+		&["\u{000A}", "\u{0308}\u{002E}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] COMBINING DIAERESIS (Extend) × [998.0] FULL STOP (ATerm) ÷ [0.3]"
+	);
+	sentence_test("\u{000A}\u{0021}",
+		&["\u{000A}", "\u{0021}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] EXCLAMATION MARK (STerm) ÷ [0.3]"
+	);
+	sentence_test("\u{000A}\u{0308}\u{0021}",
+		&["\u{000A}", "\u{0308}\u{0021}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] COMBINING DIAERESIS (Extend) × [998.0] EXCLAMATION MARK (STerm) ÷ [0.3]"
+	);
+	sentence_test("\u{000A}\u{0022}",
+		&["\u{000A}", "\u{0022}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] QUOTATION MARK (Close) ÷ [0.3]"
+	);
+	sentence_test("\u{000A}\u{0308}\u{0022}",
+		&["\u{000A}", "\u{0308}\u{0022}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] COMBINING DIAERESIS (Extend) × [998.0] QUOTATION MARK (Close) ÷ [0.3]"
+	);
+	sentence_test("\u{000A}\u{002C}",
+		&["\u{000A}", "\u{002C}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] COMMA (SContinue) ÷ [0.3]"
+	);
+	sentence_test("\u{000A}\u{0308}\u{002C}",
+		&["\u{000A}", "\u{0308}\u{002C}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] COMBINING DIAERESIS (Extend) × [998.0] COMMA (SContinue) ÷ [0.3]"
+	);
+	sentence_test("\u{000A}\u{00AD}",
+		&["\u{000A}", "\u{00AD}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{000A}\u{0308}\u{00AD}",
+		&["\u{000A}", "\u{0308}\u{00AD}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] COMBINING DIAERESIS (Extend) × [5.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{000A}\u{0300}",
+		&["\u{000A}", "\u{0300}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	sentence_test("\u{000A}\u{0308}\u{0300}",
+		&["\u{000A}", "\u{0308}\u{0300}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [4.0] COMBINING DIAERESIS (Extend) × [5.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	sentence_test("\u{0085}\u{0001}",
+		&["\u{0085}", "\u{0001}"],
+		"  ÷ [0.2] <NEXT LINE (NEL)> (Sep) ÷ [4.0] <START OF HEADING> (XX) ÷ [0.3]"
+	);
+	sentence_test("\u{0085}\u{0308}\u{0001}",
+		&["\u{0085}", "\u{0308}\u{0001}"],
+		"  ÷ [0.2] <NEXT LINE (NEL)> (Sep) ÷ [4.0] COMBINING DIAERESIS (Extend) × [998.0] <START OF HEADING> (XX) ÷ [0.3]"
+	);
+	sentence_test("\u{0085}\u{000D}",
+		&["\u{0085}", "\u{000D}"],
+		"  ÷ [0.2] <NEXT LINE (NEL)> (Sep) ÷ [4.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	sentence_test("\u{0085}\u{0308}\u{000D}",
+		&["\u{0085}", "\u{0308}\u{000D}"],
+		"  ÷ [0.2] <NEXT LINE (NEL)> (Sep) ÷ [4.0] COMBINING DIAERESIS (Extend) × [998.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	sentence_test("\u{0085}\u{000A}",
+		&["\u{0085}", "\u{000A}"],
+		"  ÷ [0.2] <NEXT LINE (NEL)> (Sep) ÷ [4.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	sentence_test("\u{0085}\u{0308}\u{000A}",
+		&["\u{0085}", "\u{0308}\u{000A}"],
+		"  ÷ [0.2] <NEXT LINE (NEL)> (Sep) ÷ [4.0] COMBINING DIAERESIS (Extend) × [998.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	sentence_test("\u{0085}\u{0085}",
+		&["\u{0085}", "\u{0085}"],
+		"  ÷ [0.2] <NEXT LINE (NEL)> (Sep) ÷ [4.0] <NEXT LINE (NEL)> (Sep) ÷ [0.3]"
+	);
+	sentence_test("\u{0085}\u{0308}\u{0085}",
+		&["\u{0085}", "\u{0308}\u{0085}"],
+		"  ÷ [0.2] <NEXT LINE (NEL)> (Sep) ÷ [4.0] COMBINING DIAERESIS (Extend) × [998.0] <NEXT LINE (NEL)> (Sep) ÷ [0.3]"
+	);
+	sentence_test("\u{0085}\u{0009}",
+		&["\u{0085}", "\u{0009}"],
+		"  ÷ [0.2] <NEXT LINE (NEL)> (Sep) ÷ [4.0] <CHARACTER TABULATION> (Sp) ÷ [0.3]"
+	);
+	sentence_test("\u{0085}\u{0308}\u{0009}",
+		&["\u{0085}", "\u{0308}\u{0009}"],
+		"  ÷ [0.2] <NEXT LINE (NEL)> (Sep) ÷ [4.0] COMBINING DIAERESIS (Extend) × [998.0] <CHARACTER TABULATION> (Sp) ÷ [0.3]"
+	);
+	sentence_test("\u{0085}\u{0061}",
+		&["\u{0085}", "\u{0061}"],
+		"  ÷ [0.2] <NEXT LINE (NEL)> (Sep) ÷ [4.0] LATIN SMALL LETTER A (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{0085}\u{0308}\u{0061}",
+		&["\u{0085}", "\u{0308}\u{0061}"],
+		"  ÷ [0.2] <NEXT LINE (NEL)> (Sep) ÷ [4.0] COMBINING DIAERESIS (Extend) × [998.0] LATIN SMALL LETTER A (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{0085}\u{0041}",
+		&["\u{0085}", "\u{0041}"],
+		"  ÷ [0.2] <NEXT LINE (NEL)> (Sep) ÷ [4.0] LATIN CAPITAL LETTER A (Upper) ÷ [0.3]"
+	);
+	sentence_test("\u{0085}\u{0308}\u{0041}",
+		&["\u{0085}", "\u{0308}\u{0041}"],
+		"  ÷ [0.2] <NEXT LINE (NEL)> (Sep) ÷ [4.0] COMBINING DIAERESIS (Extend) × [998.0] LATIN CAPITAL LETTER A (Upper) ÷ [0.3]"
+	);
+	sentence_test("\u{0085}\u{01BB}",
+		&["\u{0085}", "\u{01BB}"],
+		"  ÷ [0.2] <NEXT LINE (NEL)> (Sep) ÷ [4.0] LATIN LETTER TWO WITH STROKE (OLetter) ÷ [0.3]"
+	);
+	sentence_test("\u{0085}\u{0308}\u{01BB}",
+		&["\u{0085}", "\u{0308}\u{01BB}"],
+		"  ÷ [0.2] <NEXT LINE (NEL)> (Sep) ÷ [4.0] COMBINING DIAERESIS (Extend) × [998.0] LATIN LETTER TWO WITH STROKE (OLetter) ÷ [0.3]"
+	);
+	sentence_test("\u{0085}\u{0030}",
+		&["\u{0085}", "\u{0030}"],
+		"  ÷ [0.2] <NEXT LINE (NEL)> (Sep) ÷ [4.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	sentence_test("\u{0085}\u{0308}\u{0030}",
+		&["\u{0085}", "\u{0308}\u{0030}"],
+		"  ÷ [0.2] <NEXT LINE (NEL)> (Sep) ÷ [4.0] COMBINING DIAERESIS (Extend) × [998.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	sentence_test("\u{0085}\u{002E}",
+		&["\u{0085}", "\u{002E}"],
+		"  ÷ [0.2] <NEXT LINE (NEL)> (Sep) ÷ [4.0] FULL STOP (ATerm) ÷ [0.3]"
+	);
+	sentence_test("\u{0085}\u{0308}\u{002E}",
+		&["\u{0085}", "\u{0308}\u{002E}"],
+		"  ÷ [0.2] <NEXT LINE (NEL)> (Sep) ÷ [4.0] COMBINING DIAERESIS (Extend) × [998.0] FULL STOP (ATerm) ÷ [0.3]"
+	);
+	sentence_test("\u{0085}\u{0021}",
+		&["\u{0085}", "\u{0021}"],
+		"  ÷ [0.2] <NEXT LINE (NEL)> (Sep) ÷ [4.0] EXCLAMATION MARK (STerm) ÷ [0.3]"
+	);
+	sentence_test("\u{0085}\u{0308}\u{0021}",
+		&["\u{0085}", "\u{0308}\u{0021}"],
+		"  ÷ [0.2] <NEXT LINE (NEL)> (Sep) ÷ [4.0] COMBINING DIAERESIS (Extend) × [998.0] EXCLAMATION MARK (STerm) ÷ [0.3]"
+	);
+	sentence_test("\u{0085}\u{0022}",
+		&["\u{0085}", "\u{0022}"],
+		"  ÷ [0.2] <NEXT LINE (NEL)> (Sep) ÷ [4.0] QUOTATION MARK (Close) ÷ [0.3]"
+	);
+	sentence_test("\u{0085}\u{0308}\u{0022}",
+		&["\u{0085}", "\u{0308}\u{0022}"],
+		"  ÷ [0.2] <NEXT LINE (NEL)> (Sep) ÷ [4.0] COMBINING DIAERESIS (Extend) × [998.0] QUOTATION MARK (Close) ÷ [0.3]"
+	);
+	sentence_test("\u{0085}\u{002C}",
+		&["\u{0085}", "\u{002C}"],
+		"  ÷ [0.2] <NEXT LINE (NEL)> (Sep) ÷ [4.0] COMMA (SContinue) ÷ [0.3]"
+	);
+	sentence_test("\u{0085}\u{0308}\u{002C}",
+		&["\u{0085}", "\u{0308}\u{002C}"],
+		"  ÷ [0.2] <NEXT LINE (NEL)> (Sep) ÷ [4.0] COMBINING DIAERESIS (Extend) × [998.0] COMMA (SContinue) ÷ [0.3]"
+	);
+	sentence_test("\u{0085}\u{00AD}",
+		&["\u{0085}", "\u{00AD}"],
+		"  ÷ [0.2] <NEXT LINE (NEL)> (Sep) ÷ [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{0085}\u{0308}\u{00AD}",
+		&["\u{0085}", "\u{0308}\u{00AD}"],
+		"  ÷ [0.2] <NEXT LINE (NEL)> (Sep) ÷ [4.0] COMBINING DIAERESIS (Extend) × [5.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{0085}\u{0300}",
+		&["\u{0085}", "\u{0300}"],
+		"  ÷ [0.2] <NEXT LINE (NEL)> (Sep) ÷ [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	sentence_test("\u{0085}\u{0308}\u{0300}",
+		&["\u{0085}", "\u{0308}\u{0300}"],
+		"  ÷ [0.2] <NEXT LINE (NEL)> (Sep) ÷ [4.0] COMBINING DIAERESIS (Extend) × [5.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	sentence_test("\u{0009}\u{0001}",
+		&["\u{0009}\u{0001}"],
+		"  ÷ [0.2] <CHARACTER TABULATION> (Sp) × [998.0] <START OF HEADING> (XX) ÷ [0.3]"
+	);
+	sentence_test("\u{0009}\u{0308}\u{0001}",
+		&["\u{0009}\u{0308}\u{0001}"],
+		"  ÷ [0.2] <CHARACTER TABULATION> (Sp) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <START OF HEADING> (XX) ÷ [0.3]"
+	);
+	sentence_test("\u{0009}\u{000D}",
+		&["\u{0009}\u{000D}"],
+		"  ÷ [0.2] <CHARACTER TABULATION> (Sp) × [998.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	sentence_test("\u{0009}\u{0308}\u{000D}",
+		&["\u{0009}\u{0308}\u{000D}"],
+		"  ÷ [0.2] <CHARACTER TABULATION> (Sp) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	sentence_test("\u{0009}\u{000A}",
+		&["\u{0009}\u{000A}"],
+		"  ÷ [0.2] <CHARACTER TABULATION> (Sp) × [998.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	sentence_test("\u{0009}\u{0308}\u{000A}",
+		&["\u{0009}\u{0308}\u{000A}"],
+		"  ÷ [0.2] <CHARACTER TABULATION> (Sp) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	sentence_test("\u{0009}\u{0085}",
+		&["\u{0009}\u{0085}"],
+		"  ÷ [0.2] <CHARACTER TABULATION> (Sp) × [998.0] <NEXT LINE (NEL)> (Sep) ÷ [0.3]"
+	);
+	sentence_test("\u{0009}\u{0308}\u{0085}",
+		&["\u{0009}\u{0308}\u{0085}"],
+		"  ÷ [0.2] <CHARACTER TABULATION> (Sp) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <NEXT LINE (NEL)> (Sep) ÷ [0.3]"
+	);
+	sentence_test("\u{0009}\u{0009}",
+		&["\u{0009}\u{0009}"],
+		"  ÷ [0.2] <CHARACTER TABULATION> (Sp) × [998.0] <CHARACTER TABULATION> (Sp) ÷ [0.3]"
+	);
+	sentence_test("\u{0009}\u{0308}\u{0009}",
+		&["\u{0009}\u{0308}\u{0009}"],
+		"  ÷ [0.2] <CHARACTER TABULATION> (Sp) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <CHARACTER TABULATION> (Sp) ÷ [0.3]"
+	);
+	sentence_test("\u{0009}\u{0061}",
+		&["\u{0009}\u{0061}"],
+		"  ÷ [0.2] <CHARACTER TABULATION> (Sp) × [998.0] LATIN SMALL LETTER A (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{0009}\u{0308}\u{0061}",
+		&["\u{0009}\u{0308}\u{0061}"],
+		"  ÷ [0.2] <CHARACTER TABULATION> (Sp) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] LATIN SMALL LETTER A (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{0009}\u{0041}",
+		&["\u{0009}\u{0041}"],
+		"  ÷ [0.2] <CHARACTER TABULATION> (Sp) × [998.0] LATIN CAPITAL LETTER A (Upper) ÷ [0.3]"
+	);
+	sentence_test("\u{0009}\u{0308}\u{0041}",
+		&["\u{0009}\u{0308}\u{0041}"],
+		"  ÷ [0.2] <CHARACTER TABULATION> (Sp) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] LATIN CAPITAL LETTER A (Upper) ÷ [0.3]"
+	);
+	sentence_test("\u{0009}\u{01BB}",
+		&["\u{0009}\u{01BB}"],
+		"  ÷ [0.2] <CHARACTER TABULATION> (Sp) × [998.0] LATIN LETTER TWO WITH STROKE (OLetter) ÷ [0.3]"
+	);
+	sentence_test("\u{0009}\u{0308}\u{01BB}",
+		&["\u{0009}\u{0308}\u{01BB}"],
+		"  ÷ [0.2] <CHARACTER TABULATION> (Sp) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] LATIN LETTER TWO WITH STROKE (OLetter) ÷ [0.3]"
+	);
+	sentence_test("\u{0009}\u{0030}",
+		&["\u{0009}\u{0030}"],
+		"  ÷ [0.2] <CHARACTER TABULATION> (Sp) × [998.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	sentence_test("\u{0009}\u{0308}\u{0030}",
+		&["\u{0009}\u{0308}\u{0030}"],
+		"  ÷ [0.2] <CHARACTER TABULATION> (Sp) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	sentence_test("\u{0009}\u{002E}",
+		&["\u{0009}\u{002E}"],
+		"  ÷ [0.2] <CHARACTER TABULATION> (Sp) × [998.0] FULL STOP (ATerm) ÷ [0.3]"
+	);
+	sentence_test("\u{0009}\u{0308}\u{002E}",
+		&["\u{0009}\u{0308}\u{002E}"],
+		"  ÷ [0.2] <CHARACTER TABULATION> (Sp) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] FULL STOP (ATerm) ÷ [0.3]"
+	);
+	sentence_test("\u{0009}\u{0021}",
+		&["\u{0009}\u{0021}"],
+		"  ÷ [0.2] <CHARACTER TABULATION> (Sp) × [998.0] EXCLAMATION MARK (STerm) ÷ [0.3]"
+	);
+	sentence_test("\u{0009}\u{0308}\u{0021}",
+		&["\u{0009}\u{0308}\u{0021}"],
+		"  ÷ [0.2] <CHARACTER TABULATION> (Sp) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] EXCLAMATION MARK (STerm) ÷ [0.3]"
+	);
+	sentence_test("\u{0009}\u{0022}",
+		&["\u{0009}\u{0022}"],
+		"  ÷ [0.2] <CHARACTER TABULATION> (Sp) × [998.0] QUOTATION MARK (Close) ÷ [0.3]"
+	);
+	sentence_test("\u{0009}\u{0308}\u{0022}",
+		&["\u{0009}\u{0308}\u{0022}"],
+		"  ÷ [0.2] <CHARACTER TABULATION> (Sp) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] QUOTATION MARK (Close) ÷ [0.3]"
+	);
+	sentence_test("\u{0009}\u{002C}",
+		&["\u{0009}\u{002C}"],
+		"  ÷ [0.2] <CHARACTER TABULATION> (Sp) × [998.0] COMMA (SContinue) ÷ [0.3]"
+	);
+	sentence_test("\u{0009}\u{0308}\u{002C}",
+		&["\u{0009}\u{0308}\u{002C}"],
+		"  ÷ [0.2] <CHARACTER TABULATION> (Sp) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] COMMA (SContinue) ÷ [0.3]"
+	);
+	sentence_test("\u{0009}\u{00AD}",
+		&["\u{0009}\u{00AD}"],
+		"  ÷ [0.2] <CHARACTER TABULATION> (Sp) × [5.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{0009}\u{0308}\u{00AD}",
+		&["\u{0009}\u{0308}\u{00AD}"],
+		"  ÷ [0.2] <CHARACTER TABULATION> (Sp) × [5.0] COMBINING DIAERESIS (Extend) × [5.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{0009}\u{0300}",
+		&["\u{0009}\u{0300}"],
+		"  ÷ [0.2] <CHARACTER TABULATION> (Sp) × [5.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	sentence_test("\u{0009}\u{0308}\u{0300}",
+		&["\u{0009}\u{0308}\u{0300}"],
+		"  ÷ [0.2] <CHARACTER TABULATION> (Sp) × [5.0] COMBINING DIAERESIS (Extend) × [5.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	sentence_test("\u{0061}\u{0001}",
+		&["\u{0061}\u{0001}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (Lower) × [998.0] <START OF HEADING> (XX) ÷ [0.3]"
+	);
+	sentence_test("\u{0061}\u{0308}\u{0001}",
+		&["\u{0061}\u{0308}\u{0001}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (Lower) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <START OF HEADING> (XX) ÷ [0.3]"
+	);
+	sentence_test("\u{0061}\u{000D}",
+		&["\u{0061}\u{000D}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (Lower) × [998.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	sentence_test("\u{0061}\u{0308}\u{000D}",
+		&["\u{0061}\u{0308}\u{000D}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (Lower) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	sentence_test("\u{0061}\u{000A}",
+		&["\u{0061}\u{000A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (Lower) × [998.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	sentence_test("\u{0061}\u{0308}\u{000A}",
+		&["\u{0061}\u{0308}\u{000A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (Lower) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	sentence_test("\u{0061}\u{0085}",
+		&["\u{0061}\u{0085}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (Lower) × [998.0] <NEXT LINE (NEL)> (Sep) ÷ [0.3]"
+	);
+	sentence_test("\u{0061}\u{0308}\u{0085}",
+		&["\u{0061}\u{0308}\u{0085}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (Lower) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <NEXT LINE (NEL)> (Sep) ÷ [0.3]"
+	);
+	sentence_test("\u{0061}\u{0009}",
+		&["\u{0061}\u{0009}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (Lower) × [998.0] <CHARACTER TABULATION> (Sp) ÷ [0.3]"
+	);
+	sentence_test("\u{0061}\u{0308}\u{0009}",
+		&["\u{0061}\u{0308}\u{0009}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (Lower) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <CHARACTER TABULATION> (Sp) ÷ [0.3]"
+	);
+	sentence_test("\u{0061}\u{0061}",
+		&["\u{0061}\u{0061}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (Lower) × [998.0] LATIN SMALL LETTER A (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{0061}\u{0308}\u{0061}",
+		&["\u{0061}\u{0308}\u{0061}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (Lower) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] LATIN SMALL LETTER A (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{0061}\u{0041}",
+		&["\u{0061}\u{0041}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (Lower) × [998.0] LATIN CAPITAL LETTER A (Upper) ÷ [0.3]"
+	);
+	sentence_test("\u{0061}\u{0308}\u{0041}",
+		&["\u{0061}\u{0308}\u{0041}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (Lower) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] LATIN CAPITAL LETTER A (Upper) ÷ [0.3]"
+	);
+	sentence_test("\u{0061}\u{01BB}",
+		&["\u{0061}\u{01BB}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (Lower) × [998.0] LATIN LETTER TWO WITH STROKE (OLetter) ÷ [0.3]"
+	);
+	sentence_test("\u{0061}\u{0308}\u{01BB}",
+		&["\u{0061}\u{0308}\u{01BB}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (Lower) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] LATIN LETTER TWO WITH STROKE (OLetter) ÷ [0.3]"
+	);
+	sentence_test("\u{0061}\u{0030}",
+		&["\u{0061}\u{0030}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (Lower) × [998.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	sentence_test("\u{0061}\u{0308}\u{0030}",
+		&["\u{0061}\u{0308}\u{0030}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (Lower) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	sentence_test("\u{0061}\u{002E}",
+		&["\u{0061}\u{002E}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (Lower) × [998.0] FULL STOP (ATerm) ÷ [0.3]"
+	);
+	sentence_test("\u{0061}\u{0308}\u{002E}",
+		&["\u{0061}\u{0308}\u{002E}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (Lower) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] FULL STOP (ATerm) ÷ [0.3]"
+	);
+	sentence_test("\u{0061}\u{0021}",
+		&["\u{0061}\u{0021}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (Lower) × [998.0] EXCLAMATION MARK (STerm) ÷ [0.3]"
+	);
+	sentence_test("\u{0061}\u{0308}\u{0021}",
+		&["\u{0061}\u{0308}\u{0021}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (Lower) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] EXCLAMATION MARK (STerm) ÷ [0.3]"
+	);
+	sentence_test("\u{0061}\u{0022}",
+		&["\u{0061}\u{0022}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (Lower) × [998.0] QUOTATION MARK (Close) ÷ [0.3]"
+	);
+	sentence_test("\u{0061}\u{0308}\u{0022}",
+		&["\u{0061}\u{0308}\u{0022}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (Lower) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] QUOTATION MARK (Close) ÷ [0.3]"
+	);
+	sentence_test("\u{0061}\u{002C}",
+		&["\u{0061}\u{002C}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (Lower) × [998.0] COMMA (SContinue) ÷ [0.3]"
+	);
+	sentence_test("\u{0061}\u{0308}\u{002C}",
+		&["\u{0061}\u{0308}\u{002C}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (Lower) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] COMMA (SContinue) ÷ [0.3]"
+	);
+	sentence_test("\u{0061}\u{00AD}",
+		&["\u{0061}\u{00AD}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (Lower) × [5.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{0061}\u{0308}\u{00AD}",
+		&["\u{0061}\u{0308}\u{00AD}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (Lower) × [5.0] COMBINING DIAERESIS (Extend) × [5.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{0061}\u{0300}",
+		&["\u{0061}\u{0300}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (Lower) × [5.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	sentence_test("\u{0061}\u{0308}\u{0300}",
+		&["\u{0061}\u{0308}\u{0300}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (Lower) × [5.0] COMBINING DIAERESIS (Extend) × [5.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	sentence_test("\u{0041}\u{0001}",
+		&["\u{0041}\u{0001}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (Upper) × [998.0] <START OF HEADING> (XX) ÷ [0.3]"
+	);
+	sentence_test("\u{0041}\u{0308}\u{0001}",
+		&["\u{0041}\u{0308}\u{0001}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (Upper) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <START OF HEADING> (XX) ÷ [0.3]"
+	);
+	sentence_test("\u{0041}\u{000D}",
+		&["\u{0041}\u{000D}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (Upper) × [998.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	sentence_test("\u{0041}\u{0308}\u{000D}",
+		&["\u{0041}\u{0308}\u{000D}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (Upper) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	sentence_test("\u{0041}\u{000A}",
+		&["\u{0041}\u{000A}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (Upper) × [998.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	sentence_test("\u{0041}\u{0308}\u{000A}",
+		&["\u{0041}\u{0308}\u{000A}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (Upper) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	sentence_test("\u{0041}\u{0085}",
+		&["\u{0041}\u{0085}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (Upper) × [998.0] <NEXT LINE (NEL)> (Sep) ÷ [0.3]"
+	);
+	sentence_test("\u{0041}\u{0308}\u{0085}",
+		&["\u{0041}\u{0308}\u{0085}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (Upper) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <NEXT LINE (NEL)> (Sep) ÷ [0.3]"
+	);
+	sentence_test("\u{0041}\u{0009}",
+		&["\u{0041}\u{0009}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (Upper) × [998.0] <CHARACTER TABULATION> (Sp) ÷ [0.3]"
+	);
+	sentence_test("\u{0041}\u{0308}\u{0009}",
+		&["\u{0041}\u{0308}\u{0009}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (Upper) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <CHARACTER TABULATION> (Sp) ÷ [0.3]"
+	);
+	sentence_test("\u{0041}\u{0061}",
+		&["\u{0041}\u{0061}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (Upper) × [998.0] LATIN SMALL LETTER A (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{0041}\u{0308}\u{0061}",
+		&["\u{0041}\u{0308}\u{0061}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (Upper) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] LATIN SMALL LETTER A (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{0041}\u{0041}",
+		&["\u{0041}\u{0041}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (Upper) × [998.0] LATIN CAPITAL LETTER A (Upper) ÷ [0.3]"
+	);
+	sentence_test("\u{0041}\u{0308}\u{0041}",
+		&["\u{0041}\u{0308}\u{0041}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (Upper) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] LATIN CAPITAL LETTER A (Upper) ÷ [0.3]"
+	);
+	sentence_test("\u{0041}\u{01BB}",
+		&["\u{0041}\u{01BB}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (Upper) × [998.0] LATIN LETTER TWO WITH STROKE (OLetter) ÷ [0.3]"
+	);
+	sentence_test("\u{0041}\u{0308}\u{01BB}",
+		&["\u{0041}\u{0308}\u{01BB}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (Upper) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] LATIN LETTER TWO WITH STROKE (OLetter) ÷ [0.3]"
+	);
+	sentence_test("\u{0041}\u{0030}",
+		&["\u{0041}\u{0030}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (Upper) × [998.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	sentence_test("\u{0041}\u{0308}\u{0030}",
+		&["\u{0041}\u{0308}\u{0030}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (Upper) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	sentence_test("\u{0041}\u{002E}",
+		&["\u{0041}\u{002E}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (Upper) × [998.0] FULL STOP (ATerm) ÷ [0.3]"
+	);
+	sentence_test("\u{0041}\u{0308}\u{002E}",
+		&["\u{0041}\u{0308}\u{002E}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (Upper) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] FULL STOP (ATerm) ÷ [0.3]"
+	);
+	sentence_test("\u{0041}\u{0021}",
+		&["\u{0041}\u{0021}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (Upper) × [998.0] EXCLAMATION MARK (STerm) ÷ [0.3]"
+	);
+	sentence_test("\u{0041}\u{0308}\u{0021}",
+		&["\u{0041}\u{0308}\u{0021}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (Upper) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] EXCLAMATION MARK (STerm) ÷ [0.3]"
+	);
+	sentence_test("\u{0041}\u{0022}",
+		&["\u{0041}\u{0022}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (Upper) × [998.0] QUOTATION MARK (Close) ÷ [0.3]"
+	);
+	sentence_test("\u{0041}\u{0308}\u{0022}",
+		&["\u{0041}\u{0308}\u{0022}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (Upper) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] QUOTATION MARK (Close) ÷ [0.3]"
+	);
+	sentence_test("\u{0041}\u{002C}",
+		&["\u{0041}\u{002C}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (Upper) × [998.0] COMMA (SContinue) ÷ [0.3]"
+	);
+	sentence_test("\u{0041}\u{0308}\u{002C}",
+		&["\u{0041}\u{0308}\u{002C}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (Upper) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] COMMA (SContinue) ÷ [0.3]"
+	);
+	sentence_test("\u{0041}\u{00AD}",
+		&["\u{0041}\u{00AD}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (Upper) × [5.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{0041}\u{0308}\u{00AD}",
+		&["\u{0041}\u{0308}\u{00AD}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (Upper) × [5.0] COMBINING DIAERESIS (Extend) × [5.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{0041}\u{0300}",
+		&["\u{0041}\u{0300}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (Upper) × [5.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	sentence_test("\u{0041}\u{0308}\u{0300}",
+		&["\u{0041}\u{0308}\u{0300}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (Upper) × [5.0] COMBINING DIAERESIS (Extend) × [5.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	sentence_test("\u{01BB}\u{0001}",
+		&["\u{01BB}\u{0001}"],
+		"  ÷ [0.2] LATIN LETTER TWO WITH STROKE (OLetter) × [998.0] <START OF HEADING> (XX) ÷ [0.3]"
+	);
+	sentence_test("\u{01BB}\u{0308}\u{0001}",
+		&["\u{01BB}\u{0308}\u{0001}"],
+		"  ÷ [0.2] LATIN LETTER TWO WITH STROKE (OLetter) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <START OF HEADING> (XX) ÷ [0.3]"
+	);
+	sentence_test("\u{01BB}\u{000D}",
+		&["\u{01BB}\u{000D}"],
+		"  ÷ [0.2] LATIN LETTER TWO WITH STROKE (OLetter) × [998.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	sentence_test("\u{01BB}\u{0308}\u{000D}",
+		&["\u{01BB}\u{0308}\u{000D}"],
+		"  ÷ [0.2] LATIN LETTER TWO WITH STROKE (OLetter) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	sentence_test("\u{01BB}\u{000A}",
+		&["\u{01BB}\u{000A}"],
+		"  ÷ [0.2] LATIN LETTER TWO WITH STROKE (OLetter) × [998.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	sentence_test("\u{01BB}\u{0308}\u{000A}",
+		&["\u{01BB}\u{0308}\u{000A}"],
+		"  ÷ [0.2] LATIN LETTER TWO WITH STROKE (OLetter) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	sentence_test("\u{01BB}\u{0085}",
+		&["\u{01BB}\u{0085}"],
+		"  ÷ [0.2] LATIN LETTER TWO WITH STROKE (OLetter) × [998.0] <NEXT LINE (NEL)> (Sep) ÷ [0.3]"
+	);
+	sentence_test("\u{01BB}\u{0308}\u{0085}",
+		&["\u{01BB}\u{0308}\u{0085}"],
+		"  ÷ [0.2] LATIN LETTER TWO WITH STROKE (OLetter) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <NEXT LINE (NEL)> (Sep) ÷ [0.3]"
+	);
+	sentence_test("\u{01BB}\u{0009}",
+		&["\u{01BB}\u{0009}"],
+		"  ÷ [0.2] LATIN LETTER TWO WITH STROKE (OLetter) × [998.0] <CHARACTER TABULATION> (Sp) ÷ [0.3]"
+	);
+	sentence_test("\u{01BB}\u{0308}\u{0009}",
+		&["\u{01BB}\u{0308}\u{0009}"],
+		"  ÷ [0.2] LATIN LETTER TWO WITH STROKE (OLetter) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <CHARACTER TABULATION> (Sp) ÷ [0.3]"
+	);
+	sentence_test("\u{01BB}\u{0061}",
+		&["\u{01BB}\u{0061}"],
+		"  ÷ [0.2] LATIN LETTER TWO WITH STROKE (OLetter) × [998.0] LATIN SMALL LETTER A (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{01BB}\u{0308}\u{0061}",
+		&["\u{01BB}\u{0308}\u{0061}"],
+		"  ÷ [0.2] LATIN LETTER TWO WITH STROKE (OLetter) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] LATIN SMALL LETTER A (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{01BB}\u{0041}",
+		&["\u{01BB}\u{0041}"],
+		"  ÷ [0.2] LATIN LETTER TWO WITH STROKE (OLetter) × [998.0] LATIN CAPITAL LETTER A (Upper) ÷ [0.3]"
+	);
+	sentence_test("\u{01BB}\u{0308}\u{0041}",
+		&["\u{01BB}\u{0308}\u{0041}"],
+		"  ÷ [0.2] LATIN LETTER TWO WITH STROKE (OLetter) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] LATIN CAPITAL LETTER A (Upper) ÷ [0.3]"
+	);
+	sentence_test("\u{01BB}\u{01BB}",
+		&["\u{01BB}\u{01BB}"],
+		"  ÷ [0.2] LATIN LETTER TWO WITH STROKE (OLetter) × [998.0] LATIN LETTER TWO WITH STROKE (OLetter) ÷ [0.3]"
+	);
+	sentence_test("\u{01BB}\u{0308}\u{01BB}",
+		&["\u{01BB}\u{0308}\u{01BB}"],
+		"  ÷ [0.2] LATIN LETTER TWO WITH STROKE (OLetter) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] LATIN LETTER TWO WITH STROKE (OLetter) ÷ [0.3]"
+	);
+	sentence_test("\u{01BB}\u{0030}",
+		&["\u{01BB}\u{0030}"],
+		"  ÷ [0.2] LATIN LETTER TWO WITH STROKE (OLetter) × [998.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	sentence_test("\u{01BB}\u{0308}\u{0030}",
+		&["\u{01BB}\u{0308}\u{0030}"],
+		"  ÷ [0.2] LATIN LETTER TWO WITH STROKE (OLetter) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	sentence_test("\u{01BB}\u{002E}",
+		&["\u{01BB}\u{002E}"],
+		"  ÷ [0.2] LATIN LETTER TWO WITH STROKE (OLetter) × [998.0] FULL STOP (ATerm) ÷ [0.3]"
+	);
+	sentence_test("\u{01BB}\u{0308}\u{002E}",
+		&["\u{01BB}\u{0308}\u{002E}"],
+		"  ÷ [0.2] LATIN LETTER TWO WITH STROKE (OLetter) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] FULL STOP (ATerm) ÷ [0.3]"
+	);
+	sentence_test("\u{01BB}\u{0021}",
+		&["\u{01BB}\u{0021}"],
+		"  ÷ [0.2] LATIN LETTER TWO WITH STROKE (OLetter) × [998.0] EXCLAMATION MARK (STerm) ÷ [0.3]"
+	);
+	sentence_test("\u{01BB}\u{0308}\u{0021}",
+		&["\u{01BB}\u{0308}\u{0021}"],
+		"  ÷ [0.2] LATIN LETTER TWO WITH STROKE (OLetter) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] EXCLAMATION MARK (STerm) ÷ [0.3]"
+	);
+	sentence_test("\u{01BB}\u{0022}",
+		&["\u{01BB}\u{0022}"],
+		"  ÷ [0.2] LATIN LETTER TWO WITH STROKE (OLetter) × [998.0] QUOTATION MARK (Close) ÷ [0.3]"
+	);
+	sentence_test("\u{01BB}\u{0308}\u{0022}",
+		&["\u{01BB}\u{0308}\u{0022}"],
+		"  ÷ [0.2] LATIN LETTER TWO WITH STROKE (OLetter) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] QUOTATION MARK (Close) ÷ [0.3]"
+	);
+	sentence_test("\u{01BB}\u{002C}",
+		&["\u{01BB}\u{002C}"],
+		"  ÷ [0.2] LATIN LETTER TWO WITH STROKE (OLetter) × [998.0] COMMA (SContinue) ÷ [0.3]"
+	);
+	sentence_test("\u{01BB}\u{0308}\u{002C}",
+		&["\u{01BB}\u{0308}\u{002C}"],
+		"  ÷ [0.2] LATIN LETTER TWO WITH STROKE (OLetter) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] COMMA (SContinue) ÷ [0.3]"
+	);
+	sentence_test("\u{01BB}\u{00AD}",
+		&["\u{01BB}\u{00AD}"],
+		"  ÷ [0.2] LATIN LETTER TWO WITH STROKE (OLetter) × [5.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{01BB}\u{0308}\u{00AD}",
+		&["\u{01BB}\u{0308}\u{00AD}"],
+		"  ÷ [0.2] LATIN LETTER TWO WITH STROKE (OLetter) × [5.0] COMBINING DIAERESIS (Extend) × [5.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{01BB}\u{0300}",
+		&["\u{01BB}\u{0300}"],
+		"  ÷ [0.2] LATIN LETTER TWO WITH STROKE (OLetter) × [5.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	sentence_test("\u{01BB}\u{0308}\u{0300}",
+		&["\u{01BB}\u{0308}\u{0300}"],
+		"  ÷ [0.2] LATIN LETTER TWO WITH STROKE (OLetter) × [5.0] COMBINING DIAERESIS (Extend) × [5.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	sentence_test("\u{0030}\u{0001}",
+		&["\u{0030}\u{0001}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [998.0] <START OF HEADING> (XX) ÷ [0.3]"
+	);
+	sentence_test("\u{0030}\u{0308}\u{0001}",
+		&["\u{0030}\u{0308}\u{0001}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <START OF HEADING> (XX) ÷ [0.3]"
+	);
+	sentence_test("\u{0030}\u{000D}",
+		&["\u{0030}\u{000D}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [998.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	sentence_test("\u{0030}\u{0308}\u{000D}",
+		&["\u{0030}\u{0308}\u{000D}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	sentence_test("\u{0030}\u{000A}",
+		&["\u{0030}\u{000A}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [998.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	sentence_test("\u{0030}\u{0308}\u{000A}",
+		&["\u{0030}\u{0308}\u{000A}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	sentence_test("\u{0030}\u{0085}",
+		&["\u{0030}\u{0085}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [998.0] <NEXT LINE (NEL)> (Sep) ÷ [0.3]"
+	);
+	sentence_test("\u{0030}\u{0308}\u{0085}",
+		&["\u{0030}\u{0308}\u{0085}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <NEXT LINE (NEL)> (Sep) ÷ [0.3]"
+	);
+	sentence_test("\u{0030}\u{0009}",
+		&["\u{0030}\u{0009}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [998.0] <CHARACTER TABULATION> (Sp) ÷ [0.3]"
+	);
+	sentence_test("\u{0030}\u{0308}\u{0009}",
+		&["\u{0030}\u{0308}\u{0009}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <CHARACTER TABULATION> (Sp) ÷ [0.3]"
+	);
+	sentence_test("\u{0030}\u{0061}",
+		&["\u{0030}\u{0061}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [998.0] LATIN SMALL LETTER A (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{0030}\u{0308}\u{0061}",
+		&["\u{0030}\u{0308}\u{0061}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] LATIN SMALL LETTER A (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{0030}\u{0041}",
+		&["\u{0030}\u{0041}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [998.0] LATIN CAPITAL LETTER A (Upper) ÷ [0.3]"
+	);
+	sentence_test("\u{0030}\u{0308}\u{0041}",
+		&["\u{0030}\u{0308}\u{0041}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] LATIN CAPITAL LETTER A (Upper) ÷ [0.3]"
+	);
+	sentence_test("\u{0030}\u{01BB}",
+		&["\u{0030}\u{01BB}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [998.0] LATIN LETTER TWO WITH STROKE (OLetter) ÷ [0.3]"
+	);
+	sentence_test("\u{0030}\u{0308}\u{01BB}",
+		&["\u{0030}\u{0308}\u{01BB}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] LATIN LETTER TWO WITH STROKE (OLetter) ÷ [0.3]"
+	);
+	sentence_test("\u{0030}\u{0030}",
+		&["\u{0030}\u{0030}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [998.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	sentence_test("\u{0030}\u{0308}\u{0030}",
+		&["\u{0030}\u{0308}\u{0030}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	sentence_test("\u{0030}\u{002E}",
+		&["\u{0030}\u{002E}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [998.0] FULL STOP (ATerm) ÷ [0.3]"
+	);
+	sentence_test("\u{0030}\u{0308}\u{002E}",
+		&["\u{0030}\u{0308}\u{002E}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] FULL STOP (ATerm) ÷ [0.3]"
+	);
+	sentence_test("\u{0030}\u{0021}",
+		&["\u{0030}\u{0021}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [998.0] EXCLAMATION MARK (STerm) ÷ [0.3]"
+	);
+	sentence_test("\u{0030}\u{0308}\u{0021}",
+		&["\u{0030}\u{0308}\u{0021}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] EXCLAMATION MARK (STerm) ÷ [0.3]"
+	);
+	sentence_test("\u{0030}\u{0022}",
+		&["\u{0030}\u{0022}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [998.0] QUOTATION MARK (Close) ÷ [0.3]"
+	);
+	sentence_test("\u{0030}\u{0308}\u{0022}",
+		&["\u{0030}\u{0308}\u{0022}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] QUOTATION MARK (Close) ÷ [0.3]"
+	);
+	sentence_test("\u{0030}\u{002C}",
+		&["\u{0030}\u{002C}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [998.0] COMMA (SContinue) ÷ [0.3]"
+	);
+	sentence_test("\u{0030}\u{0308}\u{002C}",
+		&["\u{0030}\u{0308}\u{002C}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] COMMA (SContinue) ÷ [0.3]"
+	);
+	sentence_test("\u{0030}\u{00AD}",
+		&["\u{0030}\u{00AD}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [5.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{0030}\u{0308}\u{00AD}",
+		&["\u{0030}\u{0308}\u{00AD}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [5.0] COMBINING DIAERESIS (Extend) × [5.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{0030}\u{0300}",
+		&["\u{0030}\u{0300}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [5.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	sentence_test("\u{0030}\u{0308}\u{0300}",
+		&["\u{0030}\u{0308}\u{0300}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [5.0] COMBINING DIAERESIS (Extend) × [5.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	sentence_test("\u{002E}\u{0001}",
+		&["\u{002E}", "\u{0001}"],
+		"  ÷ [0.2] FULL STOP (ATerm) ÷ [11.0] <START OF HEADING> (XX) ÷ [0.3]"
+	);
+	sentence_test("\u{002E}\u{0308}\u{0001}",
+		&["\u{002E}\u{0308}", "\u{0001}"],
+		"  ÷ [0.2] FULL STOP (ATerm) × [5.0] COMBINING DIAERESIS (Extend) ÷ [11.0] <START OF HEADING> (XX) ÷ [0.3]"
+	);
+	sentence_test("\u{002E}\u{000D}",
+		&["\u{002E}\u{000D}"],
+		"  ÷ [0.2] FULL STOP (ATerm) × [9.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	sentence_test("\u{002E}\u{0308}\u{000D}",
+		&["\u{002E}\u{0308}\u{000D}"],
+		"  ÷ [0.2] FULL STOP (ATerm) × [5.0] COMBINING DIAERESIS (Extend) × [9.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	sentence_test("\u{002E}\u{000A}",
+		&["\u{002E}\u{000A}"],
+		"  ÷ [0.2] FULL STOP (ATerm) × [9.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	sentence_test("\u{002E}\u{0308}\u{000A}",
+		&["\u{002E}\u{0308}\u{000A}"],
+		"  ÷ [0.2] FULL STOP (ATerm) × [5.0] COMBINING DIAERESIS (Extend) × [9.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	sentence_test("\u{002E}\u{0085}",
+		&["\u{002E}\u{0085}"],
+		"  ÷ [0.2] FULL STOP (ATerm) × [9.0] <NEXT LINE (NEL)> (Sep) ÷ [0.3]"
+	);
+	sentence_test("\u{002E}\u{0308}\u{0085}",
+		&["\u{002E}\u{0308}\u{0085}"],
+		"  ÷ [0.2] FULL STOP (ATerm) × [5.0] COMBINING DIAERESIS (Extend) × [9.0] <NEXT LINE (NEL)> (Sep) ÷ [0.3]"
+	);
+	sentence_test("\u{002E}\u{0009}",
+		&["\u{002E}\u{0009}"],
+		"  ÷ [0.2] FULL STOP (ATerm) × [9.0] <CHARACTER TABULATION> (Sp) ÷ [0.3]"
+	);
+	sentence_test("\u{002E}\u{0308}\u{0009}",
+		&["\u{002E}\u{0308}\u{0009}"],
+		"  ÷ [0.2] FULL STOP (ATerm) × [5.0] COMBINING DIAERESIS (Extend) × [9.0] <CHARACTER TABULATION> (Sp) ÷ [0.3]"
+	);
+	sentence_test("\u{002E}\u{0061}",
+		&["\u{002E}\u{0061}"],
+		"  ÷ [0.2] FULL STOP (ATerm) × [8.0] LATIN SMALL LETTER A (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{002E}\u{0308}\u{0061}",
+		&["\u{002E}\u{0308}\u{0061}"],
+		"  ÷ [0.2] FULL STOP (ATerm) × [5.0] COMBINING DIAERESIS (Extend) × [8.0] LATIN SMALL LETTER A (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{002E}\u{0041}",
+		&["\u{002E}", "\u{0041}"],
+		"  ÷ [0.2] FULL STOP (ATerm) ÷ [11.0] LATIN CAPITAL LETTER A (Upper) ÷ [0.3]"
+	);
+	sentence_test("\u{002E}\u{0308}\u{0041}",
+		&["\u{002E}\u{0308}", "\u{0041}"],
+		"  ÷ [0.2] FULL STOP (ATerm) × [5.0] COMBINING DIAERESIS (Extend) ÷ [11.0] LATIN CAPITAL LETTER A (Upper) ÷ [0.3]"
+	);
+	sentence_test("\u{002E}\u{01BB}",
+		&["\u{002E}", "\u{01BB}"],
+		"  ÷ [0.2] FULL STOP (ATerm) ÷ [11.0] LATIN LETTER TWO WITH STROKE (OLetter) ÷ [0.3]"
+	);
+	sentence_test("\u{002E}\u{0308}\u{01BB}",
+		&["\u{002E}\u{0308}", "\u{01BB}"],
+		"  ÷ [0.2] FULL STOP (ATerm) × [5.0] COMBINING DIAERESIS (Extend) ÷ [11.0] LATIN LETTER TWO WITH STROKE (OLetter) ÷ [0.3]"
+	);
+	sentence_test("\u{002E}\u{0030}",
+		&["\u{002E}\u{0030}"],
+		"  ÷ [0.2] FULL STOP (ATerm) × [6.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	sentence_test("\u{002E}\u{0308}\u{0030}",
+		&["\u{002E}\u{0308}\u{0030}"],
+		"  ÷ [0.2] FULL STOP (ATerm) × [5.0] COMBINING DIAERESIS (Extend) × [6.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	sentence_test("\u{002E}\u{002E}",
+		&["\u{002E}\u{002E}"],
+		"  ÷ [0.2] FULL STOP (ATerm) × [8.1] FULL STOP (ATerm) ÷ [0.3]"
+	);
+	sentence_test("\u{002E}\u{0308}\u{002E}",
+		&["\u{002E}\u{0308}\u{002E}"],
+		"  ÷ [0.2] FULL STOP (ATerm) × [5.0] COMBINING DIAERESIS (Extend) × [8.1] FULL STOP (ATerm) ÷ [0.3]"
+	);
+	sentence_test("\u{002E}\u{0021}",
+		&["\u{002E}\u{0021}"],
+		"  ÷ [0.2] FULL STOP (ATerm) × [8.1] EXCLAMATION MARK (STerm) ÷ [0.3]"
+	);
+	sentence_test("\u{002E}\u{0308}\u{0021}",
+		&["\u{002E}\u{0308}\u{0021}"],
+		"  ÷ [0.2] FULL STOP (ATerm) × [5.0] COMBINING DIAERESIS (Extend) × [8.1] EXCLAMATION MARK (STerm) ÷ [0.3]"
+	);
+	sentence_test("\u{002E}\u{0022}",
+		&["\u{002E}\u{0022}"],
+		"  ÷ [0.2] FULL STOP (ATerm) × [9.0] QUOTATION MARK (Close) ÷ [0.3]"
+	);
+	sentence_test("\u{002E}\u{0308}\u{0022}",
+		&["\u{002E}\u{0308}\u{0022}"],
+		"  ÷ [0.2] FULL STOP (ATerm) × [5.0] COMBINING DIAERESIS (Extend) × [9.0] QUOTATION MARK (Close) ÷ [0.3]"
+	);
+	sentence_test("\u{002E}\u{002C}",
+		&["\u{002E}\u{002C}"],
+		"  ÷ [0.2] FULL STOP (ATerm) × [8.1] COMMA (SContinue) ÷ [0.3]"
+	);
+	sentence_test("\u{002E}\u{0308}\u{002C}",
+		&["\u{002E}\u{0308}\u{002C}"],
+		"  ÷ [0.2] FULL STOP (ATerm) × [5.0] COMBINING DIAERESIS (Extend) × [8.1] COMMA (SContinue) ÷ [0.3]"
+	);
+	sentence_test("\u{002E}\u{00AD}",
+		&["\u{002E}\u{00AD}"],
+		"  ÷ [0.2] FULL STOP (ATerm) × [5.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{002E}\u{0308}\u{00AD}",
+		&["\u{002E}\u{0308}\u{00AD}"],
+		"  ÷ [0.2] FULL STOP (ATerm) × [5.0] COMBINING DIAERESIS (Extend) × [5.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{002E}\u{0300}",
+		&["\u{002E}\u{0300}"],
+		"  ÷ [0.2] FULL STOP (ATerm) × [5.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	sentence_test("\u{002E}\u{0308}\u{0300}",
+		&["\u{002E}\u{0308}\u{0300}"],
+		"  ÷ [0.2] FULL STOP (ATerm) × [5.0] COMBINING DIAERESIS (Extend) × [5.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	sentence_test("\u{0021}\u{0001}",
+		&["\u{0021}", "\u{0001}"],
+		"  ÷ [0.2] EXCLAMATION MARK (STerm) ÷ [11.0] <START OF HEADING> (XX) ÷ [0.3]"
+	);
+	sentence_test("\u{0021}\u{0308}\u{0001}",
+		&["\u{0021}\u{0308}", "\u{0001}"],
+		"  ÷ [0.2] EXCLAMATION MARK (STerm) × [5.0] COMBINING DIAERESIS (Extend) ÷ [11.0] <START OF HEADING> (XX) ÷ [0.3]"
+	);
+	sentence_test("\u{0021}\u{000D}",
+		&["\u{0021}\u{000D}"],
+		"  ÷ [0.2] EXCLAMATION MARK (STerm) × [9.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	sentence_test("\u{0021}\u{0308}\u{000D}",
+		&["\u{0021}\u{0308}\u{000D}"],
+		"  ÷ [0.2] EXCLAMATION MARK (STerm) × [5.0] COMBINING DIAERESIS (Extend) × [9.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	sentence_test("\u{0021}\u{000A}",
+		&["\u{0021}\u{000A}"],
+		"  ÷ [0.2] EXCLAMATION MARK (STerm) × [9.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	sentence_test("\u{0021}\u{0308}\u{000A}",
+		&["\u{0021}\u{0308}\u{000A}"],
+		"  ÷ [0.2] EXCLAMATION MARK (STerm) × [5.0] COMBINING DIAERESIS (Extend) × [9.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	sentence_test("\u{0021}\u{0085}",
+		&["\u{0021}\u{0085}"],
+		"  ÷ [0.2] EXCLAMATION MARK (STerm) × [9.0] <NEXT LINE (NEL)> (Sep) ÷ [0.3]"
+	);
+	sentence_test("\u{0021}\u{0308}\u{0085}",
+		&["\u{0021}\u{0308}\u{0085}"],
+		"  ÷ [0.2] EXCLAMATION MARK (STerm) × [5.0] COMBINING DIAERESIS (Extend) × [9.0] <NEXT LINE (NEL)> (Sep) ÷ [0.3]"
+	);
+	sentence_test("\u{0021}\u{0009}",
+		&["\u{0021}\u{0009}"],
+		"  ÷ [0.2] EXCLAMATION MARK (STerm) × [9.0] <CHARACTER TABULATION> (Sp) ÷ [0.3]"
+	);
+	sentence_test("\u{0021}\u{0308}\u{0009}",
+		&["\u{0021}\u{0308}\u{0009}"],
+		"  ÷ [0.2] EXCLAMATION MARK (STerm) × [5.0] COMBINING DIAERESIS (Extend) × [9.0] <CHARACTER TABULATION> (Sp) ÷ [0.3]"
+	);
+	sentence_test("\u{0021}\u{0061}",
+		&["\u{0021}", "\u{0061}"],
+		"  ÷ [0.2] EXCLAMATION MARK (STerm) ÷ [11.0] LATIN SMALL LETTER A (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{0021}\u{0308}\u{0061}",
+		&["\u{0021}\u{0308}", "\u{0061}"],
+		"  ÷ [0.2] EXCLAMATION MARK (STerm) × [5.0] COMBINING DIAERESIS (Extend) ÷ [11.0] LATIN SMALL LETTER A (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{0021}\u{0041}",
+		&["\u{0021}", "\u{0041}"],
+		"  ÷ [0.2] EXCLAMATION MARK (STerm) ÷ [11.0] LATIN CAPITAL LETTER A (Upper) ÷ [0.3]"
+	);
+	sentence_test("\u{0021}\u{0308}\u{0041}",
+		&["\u{0021}\u{0308}", "\u{0041}"],
+		"  ÷ [0.2] EXCLAMATION MARK (STerm) × [5.0] COMBINING DIAERESIS (Extend) ÷ [11.0] LATIN CAPITAL LETTER A (Upper) ÷ [0.3]"
+	);
+	sentence_test("\u{0021}\u{01BB}",
+		&["\u{0021}", "\u{01BB}"],
+		"  ÷ [0.2] EXCLAMATION MARK (STerm) ÷ [11.0] LATIN LETTER TWO WITH STROKE (OLetter) ÷ [0.3]"
+	);
+	sentence_test("\u{0021}\u{0308}\u{01BB}",
+		&["\u{0021}\u{0308}", "\u{01BB}"],
+		"  ÷ [0.2] EXCLAMATION MARK (STerm) × [5.0] COMBINING DIAERESIS (Extend) ÷ [11.0] LATIN LETTER TWO WITH STROKE (OLetter) ÷ [0.3]"
+	);
+	sentence_test("\u{0021}\u{0030}",
+		&["\u{0021}", "\u{0030}"],
+		"  ÷ [0.2] EXCLAMATION MARK (STerm) ÷ [11.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	sentence_test("\u{0021}\u{0308}\u{0030}",
+		&["\u{0021}\u{0308}", "\u{0030}"],
+		"  ÷ [0.2] EXCLAMATION MARK (STerm) × [5.0] COMBINING DIAERESIS (Extend) ÷ [11.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	sentence_test("\u{0021}\u{002E}",
+		&["\u{0021}\u{002E}"],
+		"  ÷ [0.2] EXCLAMATION MARK (STerm) × [8.1] FULL STOP (ATerm) ÷ [0.3]"
+	);
+	sentence_test("\u{0021}\u{0308}\u{002E}",
+		&["\u{0021}\u{0308}\u{002E}"],
+		"  ÷ [0.2] EXCLAMATION MARK (STerm) × [5.0] COMBINING DIAERESIS (Extend) × [8.1] FULL STOP (ATerm) ÷ [0.3]"
+	);
+	sentence_test("\u{0021}\u{0021}",
+		&["\u{0021}\u{0021}"],
+		"  ÷ [0.2] EXCLAMATION MARK (STerm) × [8.1] EXCLAMATION MARK (STerm) ÷ [0.3]"
+	);
+	sentence_test("\u{0021}\u{0308}\u{0021}",
+		&["\u{0021}\u{0308}\u{0021}"],
+		"  ÷ [0.2] EXCLAMATION MARK (STerm) × [5.0] COMBINING DIAERESIS (Extend) × [8.1] EXCLAMATION MARK (STerm) ÷ [0.3]"
+	);
+	sentence_test("\u{0021}\u{0022}",
+		&["\u{0021}\u{0022}"],
+		"  ÷ [0.2] EXCLAMATION MARK (STerm) × [9.0] QUOTATION MARK (Close) ÷ [0.3]"
+	);
+	sentence_test("\u{0021}\u{0308}\u{0022}",
+		&["\u{0021}\u{0308}\u{0022}"],
+		"  ÷ [0.2] EXCLAMATION MARK (STerm) × [5.0] COMBINING DIAERESIS (Extend) × [9.0] QUOTATION MARK (Close) ÷ [0.3]"
+	);
+	sentence_test("\u{0021}\u{002C}",
+		&["\u{0021}\u{002C}"],
+		"  ÷ [0.2] EXCLAMATION MARK (STerm) × [8.1] COMMA (SContinue) ÷ [0.3]"
+	);
+	sentence_test("\u{0021}\u{0308}\u{002C}",
+		&["\u{0021}\u{0308}\u{002C}"],
+		"  ÷ [0.2] EXCLAMATION MARK (STerm) × [5.0] COMBINING DIAERESIS (Extend) × [8.1] COMMA (SContinue) ÷ [0.3]"
+	);
+	sentence_test("\u{0021}\u{00AD}",
+		&["\u{0021}\u{00AD}"],
+		"  ÷ [0.2] EXCLAMATION MARK (STerm) × [5.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{0021}\u{0308}\u{00AD}",
+		&["\u{0021}\u{0308}\u{00AD}"],
+		"  ÷ [0.2] EXCLAMATION MARK (STerm) × [5.0] COMBINING DIAERESIS (Extend) × [5.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{0021}\u{0300}",
+		&["\u{0021}\u{0300}"],
+		"  ÷ [0.2] EXCLAMATION MARK (STerm) × [5.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	sentence_test("\u{0021}\u{0308}\u{0300}",
+		&["\u{0021}\u{0308}\u{0300}"],
+		"  ÷ [0.2] EXCLAMATION MARK (STerm) × [5.0] COMBINING DIAERESIS (Extend) × [5.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	sentence_test("\u{0022}\u{0001}",
+		&["\u{0022}\u{0001}"],
+		"  ÷ [0.2] QUOTATION MARK (Close) × [998.0] <START OF HEADING> (XX) ÷ [0.3]"
+	);
+	sentence_test("\u{0022}\u{0308}\u{0001}",
+		&["\u{0022}\u{0308}\u{0001}"],
+		"  ÷ [0.2] QUOTATION MARK (Close) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <START OF HEADING> (XX) ÷ [0.3]"
+	);
+	sentence_test("\u{0022}\u{000D}",
+		&["\u{0022}\u{000D}"],
+		"  ÷ [0.2] QUOTATION MARK (Close) × [998.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	sentence_test("\u{0022}\u{0308}\u{000D}",
+		&["\u{0022}\u{0308}\u{000D}"],
+		"  ÷ [0.2] QUOTATION MARK (Close) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	sentence_test("\u{0022}\u{000A}",
+		&["\u{0022}\u{000A}"],
+		"  ÷ [0.2] QUOTATION MARK (Close) × [998.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	sentence_test("\u{0022}\u{0308}\u{000A}",
+		&["\u{0022}\u{0308}\u{000A}"],
+		"  ÷ [0.2] QUOTATION MARK (Close) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	sentence_test("\u{0022}\u{0085}",
+		&["\u{0022}\u{0085}"],
+		"  ÷ [0.2] QUOTATION MARK (Close) × [998.0] <NEXT LINE (NEL)> (Sep) ÷ [0.3]"
+	);
+	sentence_test("\u{0022}\u{0308}\u{0085}",
+		&["\u{0022}\u{0308}\u{0085}"],
+		"  ÷ [0.2] QUOTATION MARK (Close) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <NEXT LINE (NEL)> (Sep) ÷ [0.3]"
+	);
+	sentence_test("\u{0022}\u{0009}",
+		&["\u{0022}\u{0009}"],
+		"  ÷ [0.2] QUOTATION MARK (Close) × [998.0] <CHARACTER TABULATION> (Sp) ÷ [0.3]"
+	);
+	sentence_test("\u{0022}\u{0308}\u{0009}",
+		&["\u{0022}\u{0308}\u{0009}"],
+		"  ÷ [0.2] QUOTATION MARK (Close) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <CHARACTER TABULATION> (Sp) ÷ [0.3]"
+	);
+	sentence_test("\u{0022}\u{0061}",
+		&["\u{0022}\u{0061}"],
+		"  ÷ [0.2] QUOTATION MARK (Close) × [998.0] LATIN SMALL LETTER A (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{0022}\u{0308}\u{0061}",
+		&["\u{0022}\u{0308}\u{0061}"],
+		"  ÷ [0.2] QUOTATION MARK (Close) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] LATIN SMALL LETTER A (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{0022}\u{0041}",
+		&["\u{0022}\u{0041}"],
+		"  ÷ [0.2] QUOTATION MARK (Close) × [998.0] LATIN CAPITAL LETTER A (Upper) ÷ [0.3]"
+	);
+	sentence_test("\u{0022}\u{0308}\u{0041}",
+		&["\u{0022}\u{0308}\u{0041}"],
+		"  ÷ [0.2] QUOTATION MARK (Close) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] LATIN CAPITAL LETTER A (Upper) ÷ [0.3]"
+	);
+	sentence_test("\u{0022}\u{01BB}",
+		&["\u{0022}\u{01BB}"],
+		"  ÷ [0.2] QUOTATION MARK (Close) × [998.0] LATIN LETTER TWO WITH STROKE (OLetter) ÷ [0.3]"
+	);
+	sentence_test("\u{0022}\u{0308}\u{01BB}",
+		&["\u{0022}\u{0308}\u{01BB}"],
+		"  ÷ [0.2] QUOTATION MARK (Close) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] LATIN LETTER TWO WITH STROKE (OLetter) ÷ [0.3]"
+	);
+	sentence_test("\u{0022}\u{0030}",
+		&["\u{0022}\u{0030}"],
+		"  ÷ [0.2] QUOTATION MARK (Close) × [998.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	sentence_test("\u{0022}\u{0308}\u{0030}",
+		&["\u{0022}\u{0308}\u{0030}"],
+		"  ÷ [0.2] QUOTATION MARK (Close) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	sentence_test("\u{0022}\u{002E}",
+		&["\u{0022}\u{002E}"],
+		"  ÷ [0.2] QUOTATION MARK (Close) × [998.0] FULL STOP (ATerm) ÷ [0.3]"
+	);
+	sentence_test("\u{0022}\u{0308}\u{002E}",
+		&["\u{0022}\u{0308}\u{002E}"],
+		"  ÷ [0.2] QUOTATION MARK (Close) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] FULL STOP (ATerm) ÷ [0.3]"
+	);
+	sentence_test("\u{0022}\u{0021}",
+		&["\u{0022}\u{0021}"],
+		"  ÷ [0.2] QUOTATION MARK (Close) × [998.0] EXCLAMATION MARK (STerm) ÷ [0.3]"
+	);
+	sentence_test("\u{0022}\u{0308}\u{0021}",
+		&["\u{0022}\u{0308}\u{0021}"],
+		"  ÷ [0.2] QUOTATION MARK (Close) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] EXCLAMATION MARK (STerm) ÷ [0.3]"
+	);
+	sentence_test("\u{0022}\u{0022}",
+		&["\u{0022}\u{0022}"],
+		"  ÷ [0.2] QUOTATION MARK (Close) × [998.0] QUOTATION MARK (Close) ÷ [0.3]"
+	);
+	sentence_test("\u{0022}\u{0308}\u{0022}",
+		&["\u{0022}\u{0308}\u{0022}"],
+		"  ÷ [0.2] QUOTATION MARK (Close) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] QUOTATION MARK (Close) ÷ [0.3]"
+	);
+	sentence_test("\u{0022}\u{002C}",
+		&["\u{0022}\u{002C}"],
+		"  ÷ [0.2] QUOTATION MARK (Close) × [998.0] COMMA (SContinue) ÷ [0.3]"
+	);
+	sentence_test("\u{0022}\u{0308}\u{002C}",
+		&["\u{0022}\u{0308}\u{002C}"],
+		"  ÷ [0.2] QUOTATION MARK (Close) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] COMMA (SContinue) ÷ [0.3]"
+	);
+	sentence_test("\u{0022}\u{00AD}",
+		&["\u{0022}\u{00AD}"],
+		"  ÷ [0.2] QUOTATION MARK (Close) × [5.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{0022}\u{0308}\u{00AD}",
+		&["\u{0022}\u{0308}\u{00AD}"],
+		"  ÷ [0.2] QUOTATION MARK (Close) × [5.0] COMBINING DIAERESIS (Extend) × [5.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{0022}\u{0300}",
+		&["\u{0022}\u{0300}"],
+		"  ÷ [0.2] QUOTATION MARK (Close) × [5.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	sentence_test("\u{0022}\u{0308}\u{0300}",
+		&["\u{0022}\u{0308}\u{0300}"],
+		"  ÷ [0.2] QUOTATION MARK (Close) × [5.0] COMBINING DIAERESIS (Extend) × [5.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	sentence_test("\u{002C}\u{0001}",
+		&["\u{002C}\u{0001}"],
+		"  ÷ [0.2] COMMA (SContinue) × [998.0] <START OF HEADING> (XX) ÷ [0.3]"
+	);
+	sentence_test("\u{002C}\u{0308}\u{0001}",
+		&["\u{002C}\u{0308}\u{0001}"],
+		"  ÷ [0.2] COMMA (SContinue) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <START OF HEADING> (XX) ÷ [0.3]"
+	);
+	sentence_test("\u{002C}\u{000D}",
+		&["\u{002C}\u{000D}"],
+		"  ÷ [0.2] COMMA (SContinue) × [998.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	sentence_test("\u{002C}\u{0308}\u{000D}",
+		&["\u{002C}\u{0308}\u{000D}"],
+		"  ÷ [0.2] COMMA (SContinue) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	sentence_test("\u{002C}\u{000A}",
+		&["\u{002C}\u{000A}"],
+		"  ÷ [0.2] COMMA (SContinue) × [998.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	sentence_test("\u{002C}\u{0308}\u{000A}",
+		&["\u{002C}\u{0308}\u{000A}"],
+		"  ÷ [0.2] COMMA (SContinue) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	sentence_test("\u{002C}\u{0085}",
+		&["\u{002C}\u{0085}"],
+		"  ÷ [0.2] COMMA (SContinue) × [998.0] <NEXT LINE (NEL)> (Sep) ÷ [0.3]"
+	);
+	sentence_test("\u{002C}\u{0308}\u{0085}",
+		&["\u{002C}\u{0308}\u{0085}"],
+		"  ÷ [0.2] COMMA (SContinue) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <NEXT LINE (NEL)> (Sep) ÷ [0.3]"
+	);
+	sentence_test("\u{002C}\u{0009}",
+		&["\u{002C}\u{0009}"],
+		"  ÷ [0.2] COMMA (SContinue) × [998.0] <CHARACTER TABULATION> (Sp) ÷ [0.3]"
+	);
+	sentence_test("\u{002C}\u{0308}\u{0009}",
+		&["\u{002C}\u{0308}\u{0009}"],
+		"  ÷ [0.2] COMMA (SContinue) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <CHARACTER TABULATION> (Sp) ÷ [0.3]"
+	);
+	sentence_test("\u{002C}\u{0061}",
+		&["\u{002C}\u{0061}"],
+		"  ÷ [0.2] COMMA (SContinue) × [998.0] LATIN SMALL LETTER A (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{002C}\u{0308}\u{0061}",
+		&["\u{002C}\u{0308}\u{0061}"],
+		"  ÷ [0.2] COMMA (SContinue) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] LATIN SMALL LETTER A (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{002C}\u{0041}",
+		&["\u{002C}\u{0041}"],
+		"  ÷ [0.2] COMMA (SContinue) × [998.0] LATIN CAPITAL LETTER A (Upper) ÷ [0.3]"
+	);
+	sentence_test("\u{002C}\u{0308}\u{0041}",
+		&["\u{002C}\u{0308}\u{0041}"],
+		"  ÷ [0.2] COMMA (SContinue) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] LATIN CAPITAL LETTER A (Upper) ÷ [0.3]"
+	);
+	sentence_test("\u{002C}\u{01BB}",
+		&["\u{002C}\u{01BB}"],
+		"  ÷ [0.2] COMMA (SContinue) × [998.0] LATIN LETTER TWO WITH STROKE (OLetter) ÷ [0.3]"
+	);
+	sentence_test("\u{002C}\u{0308}\u{01BB}",
+		&["\u{002C}\u{0308}\u{01BB}"],
+		"  ÷ [0.2] COMMA (SContinue) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] LATIN LETTER TWO WITH STROKE (OLetter) ÷ [0.3]"
+	);
+	sentence_test("\u{002C}\u{0030}",
+		&["\u{002C}\u{0030}"],
+		"  ÷ [0.2] COMMA (SContinue) × [998.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	sentence_test("\u{002C}\u{0308}\u{0030}",
+		&["\u{002C}\u{0308}\u{0030}"],
+		"  ÷ [0.2] COMMA (SContinue) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	sentence_test("\u{002C}\u{002E}",
+		&["\u{002C}\u{002E}"],
+		"  ÷ [0.2] COMMA (SContinue) × [998.0] FULL STOP (ATerm) ÷ [0.3]"
+	);
+	sentence_test("\u{002C}\u{0308}\u{002E}",
+		&["\u{002C}\u{0308}\u{002E}"],
+		"  ÷ [0.2] COMMA (SContinue) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] FULL STOP (ATerm) ÷ [0.3]"
+	);
+	sentence_test("\u{002C}\u{0021}",
+		&["\u{002C}\u{0021}"],
+		"  ÷ [0.2] COMMA (SContinue) × [998.0] EXCLAMATION MARK (STerm) ÷ [0.3]"
+	);
+	sentence_test("\u{002C}\u{0308}\u{0021}",
+		&["\u{002C}\u{0308}\u{0021}"],
+		"  ÷ [0.2] COMMA (SContinue) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] EXCLAMATION MARK (STerm) ÷ [0.3]"
+	);
+	sentence_test("\u{002C}\u{0022}",
+		&["\u{002C}\u{0022}"],
+		"  ÷ [0.2] COMMA (SContinue) × [998.0] QUOTATION MARK (Close) ÷ [0.3]"
+	);
+	sentence_test("\u{002C}\u{0308}\u{0022}",
+		&["\u{002C}\u{0308}\u{0022}"],
+		"  ÷ [0.2] COMMA (SContinue) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] QUOTATION MARK (Close) ÷ [0.3]"
+	);
+	sentence_test("\u{002C}\u{002C}",
+		&["\u{002C}\u{002C}"],
+		"  ÷ [0.2] COMMA (SContinue) × [998.0] COMMA (SContinue) ÷ [0.3]"
+	);
+	sentence_test("\u{002C}\u{0308}\u{002C}",
+		&["\u{002C}\u{0308}\u{002C}"],
+		"  ÷ [0.2] COMMA (SContinue) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] COMMA (SContinue) ÷ [0.3]"
+	);
+	sentence_test("\u{002C}\u{00AD}",
+		&["\u{002C}\u{00AD}"],
+		"  ÷ [0.2] COMMA (SContinue) × [5.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{002C}\u{0308}\u{00AD}",
+		&["\u{002C}\u{0308}\u{00AD}"],
+		"  ÷ [0.2] COMMA (SContinue) × [5.0] COMBINING DIAERESIS (Extend) × [5.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{002C}\u{0300}",
+		&["\u{002C}\u{0300}"],
+		"  ÷ [0.2] COMMA (SContinue) × [5.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	sentence_test("\u{002C}\u{0308}\u{0300}",
+		&["\u{002C}\u{0308}\u{0300}"],
+		"  ÷ [0.2] COMMA (SContinue) × [5.0] COMBINING DIAERESIS (Extend) × [5.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	sentence_test("\u{00AD}\u{0001}",
+		&["\u{00AD}\u{0001}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [998.0] <START OF HEADING> (XX) ÷ [0.3]"
+	);
+	sentence_test("\u{00AD}\u{0308}\u{0001}",
+		&["\u{00AD}\u{0308}\u{0001}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <START OF HEADING> (XX) ÷ [0.3]"
+	);
+	sentence_test("\u{00AD}\u{000D}",
+		&["\u{00AD}\u{000D}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [998.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	sentence_test("\u{00AD}\u{0308}\u{000D}",
+		&["\u{00AD}\u{0308}\u{000D}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	sentence_test("\u{00AD}\u{000A}",
+		&["\u{00AD}\u{000A}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [998.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	sentence_test("\u{00AD}\u{0308}\u{000A}",
+		&["\u{00AD}\u{0308}\u{000A}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	sentence_test("\u{00AD}\u{0085}",
+		&["\u{00AD}\u{0085}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [998.0] <NEXT LINE (NEL)> (Sep) ÷ [0.3]"
+	);
+	sentence_test("\u{00AD}\u{0308}\u{0085}",
+		&["\u{00AD}\u{0308}\u{0085}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <NEXT LINE (NEL)> (Sep) ÷ [0.3]"
+	);
+	sentence_test("\u{00AD}\u{0009}",
+		&["\u{00AD}\u{0009}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [998.0] <CHARACTER TABULATION> (Sp) ÷ [0.3]"
+	);
+	sentence_test("\u{00AD}\u{0308}\u{0009}",
+		&["\u{00AD}\u{0308}\u{0009}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <CHARACTER TABULATION> (Sp) ÷ [0.3]"
+	);
+	sentence_test("\u{00AD}\u{0061}",
+		&["\u{00AD}\u{0061}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [998.0] LATIN SMALL LETTER A (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{00AD}\u{0308}\u{0061}",
+		&["\u{00AD}\u{0308}\u{0061}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] LATIN SMALL LETTER A (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{00AD}\u{0041}",
+		&["\u{00AD}\u{0041}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [998.0] LATIN CAPITAL LETTER A (Upper) ÷ [0.3]"
+	);
+	sentence_test("\u{00AD}\u{0308}\u{0041}",
+		&["\u{00AD}\u{0308}\u{0041}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] LATIN CAPITAL LETTER A (Upper) ÷ [0.3]"
+	);
+	sentence_test("\u{00AD}\u{01BB}",
+		&["\u{00AD}\u{01BB}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [998.0] LATIN LETTER TWO WITH STROKE (OLetter) ÷ [0.3]"
+	);
+	sentence_test("\u{00AD}\u{0308}\u{01BB}",
+		&["\u{00AD}\u{0308}\u{01BB}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] LATIN LETTER TWO WITH STROKE (OLetter) ÷ [0.3]"
+	);
+	sentence_test("\u{00AD}\u{0030}",
+		&["\u{00AD}\u{0030}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [998.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	sentence_test("\u{00AD}\u{0308}\u{0030}",
+		&["\u{00AD}\u{0308}\u{0030}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	sentence_test("\u{00AD}\u{002E}",
+		&["\u{00AD}\u{002E}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [998.0] FULL STOP (ATerm) ÷ [0.3]"
+	);
+	sentence_test("\u{00AD}\u{0308}\u{002E}",
+		&["\u{00AD}\u{0308}\u{002E}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] FULL STOP (ATerm) ÷ [0.3]"
+	);
+	sentence_test("\u{00AD}\u{0021}",
+		&["\u{00AD}\u{0021}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [998.0] EXCLAMATION MARK (STerm) ÷ [0.3]"
+	);
+	sentence_test("\u{00AD}\u{0308}\u{0021}",
+		&["\u{00AD}\u{0308}\u{0021}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] EXCLAMATION MARK (STerm) ÷ [0.3]"
+	);
+	sentence_test("\u{00AD}\u{0022}",
+		&["\u{00AD}\u{0022}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [998.0] QUOTATION MARK (Close) ÷ [0.3]"
+	);
+	sentence_test("\u{00AD}\u{0308}\u{0022}",
+		&["\u{00AD}\u{0308}\u{0022}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] QUOTATION MARK (Close) ÷ [0.3]"
+	);
+	sentence_test("\u{00AD}\u{002C}",
+		&["\u{00AD}\u{002C}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [998.0] COMMA (SContinue) ÷ [0.3]"
+	);
+	sentence_test("\u{00AD}\u{0308}\u{002C}",
+		&["\u{00AD}\u{0308}\u{002C}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] COMMA (SContinue) ÷ [0.3]"
+	);
+	sentence_test("\u{00AD}\u{00AD}",
+		&["\u{00AD}\u{00AD}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [5.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{00AD}\u{0308}\u{00AD}",
+		&["\u{00AD}\u{0308}\u{00AD}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [5.0] COMBINING DIAERESIS (Extend) × [5.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{00AD}\u{0300}",
+		&["\u{00AD}\u{0300}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [5.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	sentence_test("\u{00AD}\u{0308}\u{0300}",
+		&["\u{00AD}\u{0308}\u{0300}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [5.0] COMBINING DIAERESIS (Extend) × [5.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	sentence_test("\u{0300}\u{0001}",
+		&["\u{0300}\u{0001}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [998.0] <START OF HEADING> (XX) ÷ [0.3]"
+	);
+	sentence_test("\u{0300}\u{0308}\u{0001}",
+		&["\u{0300}\u{0308}\u{0001}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <START OF HEADING> (XX) ÷ [0.3]"
+	);
+	sentence_test("\u{0300}\u{000D}",
+		&["\u{0300}\u{000D}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [998.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	sentence_test("\u{0300}\u{0308}\u{000D}",
+		&["\u{0300}\u{0308}\u{000D}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	sentence_test("\u{0300}\u{000A}",
+		&["\u{0300}\u{000A}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [998.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	sentence_test("\u{0300}\u{0308}\u{000A}",
+		&["\u{0300}\u{0308}\u{000A}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	sentence_test("\u{0300}\u{0085}",
+		&["\u{0300}\u{0085}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [998.0] <NEXT LINE (NEL)> (Sep) ÷ [0.3]"
+	);
+	sentence_test("\u{0300}\u{0308}\u{0085}",
+		&["\u{0300}\u{0308}\u{0085}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <NEXT LINE (NEL)> (Sep) ÷ [0.3]"
+	);
+	sentence_test("\u{0300}\u{0009}",
+		&["\u{0300}\u{0009}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [998.0] <CHARACTER TABULATION> (Sp) ÷ [0.3]"
+	);
+	sentence_test("\u{0300}\u{0308}\u{0009}",
+		&["\u{0300}\u{0308}\u{0009}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] <CHARACTER TABULATION> (Sp) ÷ [0.3]"
+	);
+	sentence_test("\u{0300}\u{0061}",
+		&["\u{0300}\u{0061}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [998.0] LATIN SMALL LETTER A (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{0300}\u{0308}\u{0061}",
+		&["\u{0300}\u{0308}\u{0061}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] LATIN SMALL LETTER A (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{0300}\u{0041}",
+		&["\u{0300}\u{0041}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [998.0] LATIN CAPITAL LETTER A (Upper) ÷ [0.3]"
+	);
+	sentence_test("\u{0300}\u{0308}\u{0041}",
+		&["\u{0300}\u{0308}\u{0041}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] LATIN CAPITAL LETTER A (Upper) ÷ [0.3]"
+	);
+	sentence_test("\u{0300}\u{01BB}",
+		&["\u{0300}\u{01BB}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [998.0] LATIN LETTER TWO WITH STROKE (OLetter) ÷ [0.3]"
+	);
+	sentence_test("\u{0300}\u{0308}\u{01BB}",
+		&["\u{0300}\u{0308}\u{01BB}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] LATIN LETTER TWO WITH STROKE (OLetter) ÷ [0.3]"
+	);
+	sentence_test("\u{0300}\u{0030}",
+		&["\u{0300}\u{0030}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [998.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	sentence_test("\u{0300}\u{0308}\u{0030}",
+		&["\u{0300}\u{0308}\u{0030}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	sentence_test("\u{0300}\u{002E}",
+		&["\u{0300}\u{002E}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [998.0] FULL STOP (ATerm) ÷ [0.3]"
+	);
+	sentence_test("\u{0300}\u{0308}\u{002E}",
+		&["\u{0300}\u{0308}\u{002E}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] FULL STOP (ATerm) ÷ [0.3]"
+	);
+	sentence_test("\u{0300}\u{0021}",
+		&["\u{0300}\u{0021}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [998.0] EXCLAMATION MARK (STerm) ÷ [0.3]"
+	);
+	sentence_test("\u{0300}\u{0308}\u{0021}",
+		&["\u{0300}\u{0308}\u{0021}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] EXCLAMATION MARK (STerm) ÷ [0.3]"
+	);
+	sentence_test("\u{0300}\u{0022}",
+		&["\u{0300}\u{0022}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [998.0] QUOTATION MARK (Close) ÷ [0.3]"
+	);
+	sentence_test("\u{0300}\u{0308}\u{0022}",
+		&["\u{0300}\u{0308}\u{0022}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] QUOTATION MARK (Close) ÷ [0.3]"
+	);
+	sentence_test("\u{0300}\u{002C}",
+		&["\u{0300}\u{002C}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [998.0] COMMA (SContinue) ÷ [0.3]"
+	);
+	sentence_test("\u{0300}\u{0308}\u{002C}",
+		&["\u{0300}\u{0308}\u{002C}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [5.0] COMBINING DIAERESIS (Extend) × [998.0] COMMA (SContinue) ÷ [0.3]"
+	);
+	sentence_test("\u{0300}\u{00AD}",
+		&["\u{0300}\u{00AD}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [5.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{0300}\u{0308}\u{00AD}",
+		&["\u{0300}\u{0308}\u{00AD}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [5.0] COMBINING DIAERESIS (Extend) × [5.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{0300}\u{0300}",
+		&["\u{0300}\u{0300}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [5.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	sentence_test("\u{0300}\u{0308}\u{0300}",
+		&["\u{0300}\u{0308}\u{0300}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [5.0] COMBINING DIAERESIS (Extend) × [5.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	sentence_test("\u{000D}\u{000A}\u{0061}\u{000A}\u{0308}",
+		&["\u{000D}\u{000A}", "\u{0061}\u{000A}", "\u{0308}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) × [3.0] <LINE FEED (LF)> (LF) ÷ [4.0] LATIN SMALL LETTER A (Lower) × [998.0] <LINE FEED (LF)> (LF) ÷ [4.0] COMBINING DIAERESIS (Extend) ÷ [0.3]"
+	);
+	sentence_test("\u{0061}\u{0308}",
+		&["\u{0061}\u{0308}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (Lower) × [5.0] COMBINING DIAERESIS (Extend) ÷ [0.3]"
+	);
+	sentence_test("\u{0020}\u{200D}\u{0646}",
+		&["\u{0020}\u{200D}\u{0646}"],
+		"  ÷ [0.2] SPACE (Sp) × [5.0] ZERO WIDTH JOINER (Extend) × [998.0] ARABIC LETTER NOON (OLetter) ÷ [0.3]"
+	);
+	sentence_test("\u{0646}\u{200D}\u{0020}",
+		&["\u{0646}\u{200D}\u{0020}"],
+		"  ÷ [0.2] ARABIC LETTER NOON (OLetter) × [5.0] ZERO WIDTH JOINER (Extend) × [998.0] SPACE (Sp) ÷ [0.3]"
+	);
+	sentence_test("\u{0028}\u{0022}\u{0047}\u{006F}\u{002E}\u{0022}\u{0029}\u{0020}\u{0028}\u{0048}\u{0065}\u{0020}\u{0064}\u{0069}\u{0064}\u{002E}\u{0029}",
+		&["\u{0028}\u{0022}\u{0047}\u{006F}\u{002E}\u{0022}\u{0029}\u{0020}", "\u{0028}\u{0048}\u{0065}\u{0020}\u{0064}\u{0069}\u{0064}\u{002E}\u{0029}"],
+		"  ÷ [0.2] LEFT PARENTHESIS (Close) × [998.0] QUOTATION MARK (Close) × [998.0] LATIN CAPITAL LETTER G (Upper) × [998.0] LATIN SMALL LETTER O (Lower) × [998.0] FULL STOP (ATerm) × [9.0] QUOTATION MARK (Close) × [9.0] RIGHT PARENTHESIS (Close) × [9.0] SPACE (Sp) ÷ [11.0] LEFT PARENTHESIS (Close) × [998.0] LATIN CAPITAL LETTER H (Upper) × [998.0] LATIN SMALL LETTER E (Lower) × [998.0] SPACE (Sp) × [998.0] LATIN SMALL LETTER D (Lower) × [998.0] LATIN SMALL LETTER I (Lower) × [998.0] LATIN SMALL LETTER D (Lower) × [998.0] FULL STOP (ATerm) × [9.0] RIGHT PARENTHESIS (Close) ÷ [0.3]"
+	);
+	sentence_test("\u{0028}\u{201C}\u{0047}\u{006F}\u{003F}\u{201D}\u{0029}\u{0020}\u{0028}\u{0048}\u{0065}\u{0020}\u{0064}\u{0069}\u{0064}\u{002E}\u{0029}",
+		&["\u{0028}\u{201C}\u{0047}\u{006F}\u{003F}\u{201D}\u{0029}\u{0020}", "\u{0028}\u{0048}\u{0065}\u{0020}\u{0064}\u{0069}\u{0064}\u{002E}\u{0029}"],
+		"  ÷ [0.2] LEFT PARENTHESIS (Close) × [998.0] LEFT DOUBLE QUOTATION MARK (Close) × [998.0] LATIN CAPITAL LETTER G (Upper) × [998.0] LATIN SMALL LETTER O (Lower) × [998.0] QUESTION MARK (STerm) × [9.0] RIGHT DOUBLE QUOTATION MARK (Close) × [9.0] RIGHT PARENTHESIS (Close) × [9.0] SPACE (Sp) ÷ [11.0] LEFT PARENTHESIS (Close) × [998.0] LATIN CAPITAL LETTER H (Upper) × [998.0] LATIN SMALL LETTER E (Lower) × [998.0] SPACE (Sp) × [998.0] LATIN SMALL LETTER D (Lower) × [998.0] LATIN SMALL LETTER I (Lower) × [998.0] LATIN SMALL LETTER D (Lower) × [998.0] FULL STOP (ATerm) × [9.0] RIGHT PARENTHESIS (Close) ÷ [0.3]"
+	);
+	sentence_test("\u{0055}\u{002E}\u{0053}\u{002E}\u{0041}\u{0300}\u{002E}\u{0020}\u{0069}\u{0073}",
+		&["\u{0055}\u{002E}\u{0053}\u{002E}\u{0041}\u{0300}\u{002E}\u{0020}\u{0069}\u{0073}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER U (Upper) × [998.0] FULL STOP (ATerm) × [7.0] LATIN CAPITAL LETTER S (Upper) × [998.0] FULL STOP (ATerm) × [7.0] LATIN CAPITAL LETTER A (Upper) × [5.0] COMBINING GRAVE ACCENT (Extend) × [998.0] FULL STOP (ATerm) × [8.0] SPACE (Sp) × [8.0] LATIN SMALL LETTER I (Lower) × [998.0] LATIN SMALL LETTER S (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{0055}\u{002E}\u{0053}\u{002E}\u{0041}\u{0300}\u{003F}\u{0020}\u{0048}\u{0065}",
+		&["\u{0055}\u{002E}\u{0053}\u{002E}\u{0041}\u{0300}\u{003F}\u{0020}", "\u{0048}\u{0065}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER U (Upper) × [998.0] FULL STOP (ATerm) × [7.0] LATIN CAPITAL LETTER S (Upper) × [998.0] FULL STOP (ATerm) × [7.0] LATIN CAPITAL LETTER A (Upper) × [5.0] COMBINING GRAVE ACCENT (Extend) × [998.0] QUESTION MARK (STerm) × [9.0] SPACE (Sp) ÷ [11.0] LATIN CAPITAL LETTER H (Upper) × [998.0] LATIN SMALL LETTER E (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{0055}\u{002E}\u{0053}\u{002E}\u{0041}\u{0300}\u{002E}",
+		&["\u{0055}\u{002E}\u{0053}\u{002E}\u{0041}\u{0300}\u{002E}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER U (Upper) × [998.0] FULL STOP (ATerm) × [7.0] LATIN CAPITAL LETTER S (Upper) × [998.0] FULL STOP (ATerm) × [7.0] LATIN CAPITAL LETTER A (Upper) × [5.0] COMBINING GRAVE ACCENT (Extend) × [998.0] FULL STOP (ATerm) ÷ [0.3]"
+	);
+	sentence_test("\u{0033}\u{002E}\u{0034}",
+		&["\u{0033}\u{002E}\u{0034}"],
+		"  ÷ [0.2] DIGIT THREE (Numeric) × [998.0] FULL STOP (ATerm) × [6.0] DIGIT FOUR (Numeric) ÷ [0.3]"
+	);
+	sentence_test("\u{0063}\u{002E}\u{0064}",
+		&["\u{0063}\u{002E}\u{0064}"],
+		"  ÷ [0.2] LATIN SMALL LETTER C (Lower) × [998.0] FULL STOP (ATerm) × [8.0] LATIN SMALL LETTER D (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{0043}\u{002E}\u{0064}",
+		&["\u{0043}\u{002E}\u{0064}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER C (Upper) × [998.0] FULL STOP (ATerm) × [8.0] LATIN SMALL LETTER D (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{0063}\u{002E}\u{0044}",
+		&["\u{0063}\u{002E}\u{0044}"],
+		"  ÷ [0.2] LATIN SMALL LETTER C (Lower) × [998.0] FULL STOP (ATerm) × [7.0] LATIN CAPITAL LETTER D (Upper) ÷ [0.3]"
+	);
+	sentence_test("\u{0043}\u{002E}\u{0044}",
+		&["\u{0043}\u{002E}\u{0044}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER C (Upper) × [998.0] FULL STOP (ATerm) × [7.0] LATIN CAPITAL LETTER D (Upper) ÷ [0.3]"
+	);
+	sentence_test("\u{0065}\u{0074}\u{0063}\u{002E}\u{0029}\u{2019}\u{00A0}\u{0074}\u{0068}\u{0065}",
+		&["\u{0065}\u{0074}\u{0063}\u{002E}\u{0029}\u{2019}\u{00A0}\u{0074}\u{0068}\u{0065}"],
+		"  ÷ [0.2] LATIN SMALL LETTER E (Lower) × [998.0] LATIN SMALL LETTER T (Lower) × [998.0] LATIN SMALL LETTER C (Lower) × [998.0] FULL STOP (ATerm) × [8.0] RIGHT PARENTHESIS (Close) × [8.0] RIGHT SINGLE QUOTATION MARK (Close) × [8.0] NO-BREAK SPACE (Sp) × [8.0] LATIN SMALL LETTER T (Lower) × [998.0] LATIN SMALL LETTER H (Lower) × [998.0] LATIN SMALL LETTER E (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{0065}\u{0074}\u{0063}\u{002E}\u{0029}\u{2019}\u{00A0}\u{0054}\u{0068}\u{0065}",
+		&["\u{0065}\u{0074}\u{0063}\u{002E}\u{0029}\u{2019}\u{00A0}", "\u{0054}\u{0068}\u{0065}"],
+		"  ÷ [0.2] LATIN SMALL LETTER E (Lower) × [998.0] LATIN SMALL LETTER T (Lower) × [998.0] LATIN SMALL LETTER C (Lower) × [998.0] FULL STOP (ATerm) × [9.0] RIGHT PARENTHESIS (Close) × [9.0] RIGHT SINGLE QUOTATION MARK (Close) × [9.0] NO-BREAK SPACE (Sp) ÷ [11.0] LATIN CAPITAL LETTER T (Upper) × [998.0] LATIN SMALL LETTER H (Lower) × [998.0] LATIN SMALL LETTER E (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{0065}\u{0074}\u{0063}\u{002E}\u{0029}\u{2019}\u{00A0}\u{2018}\u{0028}\u{0074}\u{0068}\u{0065}",
+		&["\u{0065}\u{0074}\u{0063}\u{002E}\u{0029}\u{2019}\u{00A0}\u{2018}\u{0028}\u{0074}\u{0068}\u{0065}"],
+		"  ÷ [0.2] LATIN SMALL LETTER E (Lower) × [998.0] LATIN SMALL LETTER T (Lower) × [998.0] LATIN SMALL LETTER C (Lower) × [998.0] FULL STOP (ATerm) × [8.0] RIGHT PARENTHESIS (Close) × [8.0] RIGHT SINGLE QUOTATION MARK (Close) × [8.0] NO-BREAK SPACE (Sp) × [8.0] LEFT SINGLE QUOTATION MARK (Close) × [998.0] LEFT PARENTHESIS (Close) × [998.0] LATIN SMALL LETTER T (Lower) × [998.0] LATIN SMALL LETTER H (Lower) × [998.0] LATIN SMALL LETTER E (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{0065}\u{0074}\u{0063}\u{002E}\u{0029}\u{2019}\u{00A0}\u{2018}\u{0028}\u{0054}\u{0068}\u{0065}",
+		&["\u{0065}\u{0074}\u{0063}\u{002E}\u{0029}\u{2019}\u{00A0}", "\u{2018}\u{0028}\u{0054}\u{0068}\u{0065}"],
+		"  ÷ [0.2] LATIN SMALL LETTER E (Lower) × [998.0] LATIN SMALL LETTER T (Lower) × [998.0] LATIN SMALL LETTER C (Lower) × [998.0] FULL STOP (ATerm) × [9.0] RIGHT PARENTHESIS (Close) × [9.0] RIGHT SINGLE QUOTATION MARK (Close) × [9.0] NO-BREAK SPACE (Sp) ÷ [11.0] LEFT SINGLE QUOTATION MARK (Close) × [998.0] LEFT PARENTHESIS (Close) × [998.0] LATIN CAPITAL LETTER T (Upper) × [998.0] LATIN SMALL LETTER H (Lower) × [998.0] LATIN SMALL LETTER E (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{0065}\u{0074}\u{0063}\u{002E}\u{0029}\u{2019}\u{00A0}\u{0308}\u{0074}\u{0068}\u{0065}",
+		&["\u{0065}\u{0074}\u{0063}\u{002E}\u{0029}\u{2019}\u{00A0}\u{0308}\u{0074}\u{0068}\u{0065}"],
+		"  ÷ [0.2] LATIN SMALL LETTER E (Lower) × [998.0] LATIN SMALL LETTER T (Lower) × [998.0] LATIN SMALL LETTER C (Lower) × [998.0] FULL STOP (ATerm) × [8.0] RIGHT PARENTHESIS (Close) × [8.0] RIGHT SINGLE QUOTATION MARK (Close) × [8.0] NO-BREAK SPACE (Sp) × [5.0] COMBINING DIAERESIS (Extend) × [8.0] LATIN SMALL LETTER T (Lower) × [998.0] LATIN SMALL LETTER H (Lower) × [998.0] LATIN SMALL LETTER E (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{0065}\u{0074}\u{0063}\u{002E}\u{0029}\u{2019}\u{00A0}\u{0308}\u{0054}\u{0068}\u{0065}",
+		&["\u{0065}\u{0074}\u{0063}\u{002E}\u{0029}\u{2019}\u{00A0}\u{0308}", "\u{0054}\u{0068}\u{0065}"],
+		"  ÷ [0.2] LATIN SMALL LETTER E (Lower) × [998.0] LATIN SMALL LETTER T (Lower) × [998.0] LATIN SMALL LETTER C (Lower) × [998.0] FULL STOP (ATerm) × [9.0] RIGHT PARENTHESIS (Close) × [9.0] RIGHT SINGLE QUOTATION MARK (Close) × [9.0] NO-BREAK SPACE (Sp) × [5.0] COMBINING DIAERESIS (Extend) ÷ [11.0] LATIN CAPITAL LETTER T (Upper) × [998.0] LATIN SMALL LETTER H (Lower) × [998.0] LATIN SMALL LETTER E (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{0065}\u{0074}\u{0063}\u{002E}\u{0029}\u{2019}\u{0308}\u{0054}\u{0068}\u{0065}",
+		&["\u{0065}\u{0074}\u{0063}\u{002E}\u{0029}\u{2019}\u{0308}", "\u{0054}\u{0068}\u{0065}"],
+		"  ÷ [0.2] LATIN SMALL LETTER E (Lower) × [998.0] LATIN SMALL LETTER T (Lower) × [998.0] LATIN SMALL LETTER C (Lower) × [998.0] FULL STOP (ATerm) × [9.0] RIGHT PARENTHESIS (Close) × [9.0] RIGHT SINGLE QUOTATION MARK (Close) × [5.0] COMBINING DIAERESIS (Extend) ÷ [11.0] LATIN CAPITAL LETTER T (Upper) × [998.0] LATIN SMALL LETTER H (Lower) × [998.0] LATIN SMALL LETTER E (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{0065}\u{0074}\u{0063}\u{002E}\u{0029}\u{000A}\u{0308}\u{0054}\u{0068}\u{0065}",
+		&["\u{0065}\u{0074}\u{0063}\u{002E}\u{0029}\u{000A}", "\u{0308}\u{0054}\u{0068}\u{0065}"],
+		"  ÷ [0.2] LATIN SMALL LETTER E (Lower) × [998.0] LATIN SMALL LETTER T (Lower) × [998.0] LATIN SMALL LETTER C (Lower) × [998.0] FULL STOP (ATerm) × [9.0] RIGHT PARENTHESIS (Close) × [9.0] <LINE FEED (LF)> (LF) ÷ [4.0] COMBINING DIAERESIS (Extend) × [998.0] LATIN CAPITAL LETTER T (Upper) × [998.0] LATIN SMALL LETTER H (Lower) × [998.0] LATIN SMALL LETTER E (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{0074}\u{0068}\u{0065}\u{0020}\u{0072}\u{0065}\u{0073}\u{0070}\u{002E}\u{0020}\u{006C}\u{0065}\u{0061}\u{0064}\u{0065}\u{0072}\u{0073}\u{0020}\u{0061}\u{0072}\u{0065}",
+		&["\u{0074}\u{0068}\u{0065}\u{0020}\u{0072}\u{0065}\u{0073}\u{0070}\u{002E}\u{0020}\u{006C}\u{0065}\u{0061}\u{0064}\u{0065}\u{0072}\u{0073}\u{0020}\u{0061}\u{0072}\u{0065}"],
+		"  ÷ [0.2] LATIN SMALL LETTER T (Lower) × [998.0] LATIN SMALL LETTER H (Lower) × [998.0] LATIN SMALL LETTER E (Lower) × [998.0] SPACE (Sp) × [998.0] LATIN SMALL LETTER R (Lower) × [998.0] LATIN SMALL LETTER E (Lower) × [998.0] LATIN SMALL LETTER S (Lower) × [998.0] LATIN SMALL LETTER P (Lower) × [998.0] FULL STOP (ATerm) × [8.0] SPACE (Sp) × [8.0] LATIN SMALL LETTER L (Lower) × [998.0] LATIN SMALL LETTER E (Lower) × [998.0] LATIN SMALL LETTER A (Lower) × [998.0] LATIN SMALL LETTER D (Lower) × [998.0] LATIN SMALL LETTER E (Lower) × [998.0] LATIN SMALL LETTER R (Lower) × [998.0] LATIN SMALL LETTER S (Lower) × [998.0] SPACE (Sp) × [998.0] LATIN SMALL LETTER A (Lower) × [998.0] LATIN SMALL LETTER R (Lower) × [998.0] LATIN SMALL LETTER E (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{5B57}\u{002E}\u{5B57}",
+		&["\u{5B57}\u{002E}", "\u{5B57}"],
+		"  ÷ [0.2] CJK UNIFIED IDEOGRAPH-5B57 (OLetter) × [998.0] FULL STOP (ATerm) ÷ [11.0] CJK UNIFIED IDEOGRAPH-5B57 (OLetter) ÷ [0.3]"
+	);
+	sentence_test("\u{0065}\u{0074}\u{0063}\u{002E}\u{5B83}",
+		&["\u{0065}\u{0074}\u{0063}\u{002E}", "\u{5B83}"],
+		"  ÷ [0.2] LATIN SMALL LETTER E (Lower) × [998.0] LATIN SMALL LETTER T (Lower) × [998.0] LATIN SMALL LETTER C (Lower) × [998.0] FULL STOP (ATerm) ÷ [11.0] CJK UNIFIED IDEOGRAPH-5B83 (OLetter) ÷ [0.3]"
+	);
+	sentence_test("\u{0065}\u{0074}\u{0063}\u{002E}\u{3002}",
+		&["\u{0065}\u{0074}\u{0063}\u{002E}\u{3002}"],
+		"  ÷ [0.2] LATIN SMALL LETTER E (Lower) × [998.0] LATIN SMALL LETTER T (Lower) × [998.0] LATIN SMALL LETTER C (Lower) × [998.0] FULL STOP (ATerm) × [8.1] IDEOGRAPHIC FULL STOP (STerm) ÷ [0.3]"
+	);
+	sentence_test("\u{5B57}\u{3002}\u{5B83}",
+		&["\u{5B57}\u{3002}", "\u{5B83}"],
+		"  ÷ [0.2] CJK UNIFIED IDEOGRAPH-5B57 (OLetter) × [998.0] IDEOGRAPHIC FULL STOP (STerm) ÷ [11.0] CJK UNIFIED IDEOGRAPH-5B83 (OLetter) ÷ [0.3]"
+	);
+	sentence_test("\u{0021}\u{0020}\u{0020}",
+		&["\u{0021}\u{0020}\u{0020}"],
+		"  ÷ [0.2] EXCLAMATION MARK (STerm) × [9.0] SPACE (Sp) × [10.0] SPACE (Sp) ÷ [0.3]"
+	);
+	sentence_test("\u{0061}\u{002E}",
+		&["\u{0061}\u{002E}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (Lower) × [998.0] FULL STOP (ATerm) ÷ [0.3]"
+	);
+	sentence_test("\u{0061}\u{002E}\u{000D}\u{000A}",
+		&["\u{0061}\u{002E}\u{000D}\u{000A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (Lower) × [998.0] FULL STOP (ATerm) × [9.0] <CARRIAGE RETURN (CR)> (CR) × [3.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	sentence_test("\u{0061}\u{002E}\u{000D}\u{000A}\u{0020}",
+		&["\u{0061}\u{002E}\u{000D}\u{000A}", "\u{0020}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (Lower) × [998.0] FULL STOP (ATerm) × [9.0] <CARRIAGE RETURN (CR)> (CR) × [3.0] <LINE FEED (LF)> (LF) ÷ [4.0] SPACE (Sp) ÷ [0.3]"
+	);
+	sentence_test("\u{0061}\u{002E}\u{000D}\u{000A}\u{0061}",
+		&["\u{0061}\u{002E}\u{000D}\u{000A}", "\u{0061}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (Lower) × [998.0] FULL STOP (ATerm) × [9.0] <CARRIAGE RETURN (CR)> (CR) × [3.0] <LINE FEED (LF)> (LF) ÷ [4.0] LATIN SMALL LETTER A (Lower) ÷ [0.3]"
+	);
+	sentence_test("\u{0041}\u{002E}\u{000D}\u{000A}\u{0041}",
+		&["\u{0041}\u{002E}\u{000D}\u{000A}", "\u{0041}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (Upper) × [998.0] FULL STOP (ATerm) × [9.0] <CARRIAGE RETURN (CR)> (CR) × [3.0] <LINE FEED (LF)> (LF) ÷ [4.0] LATIN CAPITAL LETTER A (Upper) ÷ [0.3]"
+	);
+	sentence_test("\u{2060}\u{0028}\u{2060}\u{0022}\u{2060}\u{0047}\u{2060}\u{006F}\u{2060}\u{002E}\u{2060}\u{0022}\u{2060}\u{0029}\u{2060}\u{0020}\u{2060}\u{0028}\u{2060}\u{0048}\u{2060}\u{0065}\u{2060}\u{0020}\u{2060}\u{0064}\u{2060}\u{0069}\u{2060}\u{0064}\u{2060}\u{002E}\u{2060}\u{0029}\u{2060}\u{2060}",
+		&["\u{2060}\u{0028}\u{2060}\u{0022}\u{2060}\u{0047}\u{2060}\u{006F}\u{2060}\u{002E}\u{2060}\u{0022}\u{2060}\u{0029}\u{2060}\u{0020}\u{2060}", "\u{0028}\u{2060}\u{0048}\u{2060}\u{0065}\u{2060}\u{0020}\u{2060}\u{0064}\u{2060}\u{0069}\u{2060}\u{0064}\u{2060}\u{002E}\u{2060}\u{0029}\u{2060}\u{2060}"],
+		"  ÷ [0.2] WORD JOINER (Format) × [998.0] LEFT PARENTHESIS (Close) × [5.0] WORD JOINER (Format) × [998.0] QUOTATION MARK (Close) × [5.0] WORD JOINER (Format) × [998.0] LATIN CAPITAL LETTER G (Upper) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER O (Lower) × [5.0] WORD JOINER (Format) × [998.0] FULL STOP (ATerm) × [5.0] WORD JOINER (Format) × [9.0] QUOTATION MARK (Close) × [5.0] WORD JOINER (Format) × [9.0] RIGHT PARENTHESIS (Close) × [5.0] WORD JOINER (Format) × [9.0] SPACE (Sp) × [5.0] WORD JOINER (Format) ÷ [11.0] LEFT PARENTHESIS (Close) × [5.0] WORD JOINER (Format) × [998.0] LATIN CAPITAL LETTER H (Upper) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER E (Lower) × [5.0] WORD JOINER (Format) × [998.0] SPACE (Sp) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER D (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER I (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER D (Lower) × [5.0] WORD JOINER (Format) × [998.0] FULL STOP (ATerm) × [5.0] WORD JOINER (Format) × [9.0] RIGHT PARENTHESIS (Close) × [5.0] WORD JOINER (Format) × [5.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{2060}\u{0028}\u{2060}\u{201C}\u{2060}\u{0047}\u{2060}\u{006F}\u{2060}\u{003F}\u{2060}\u{201D}\u{2060}\u{0029}\u{2060}\u{0020}\u{2060}\u{0028}\u{2060}\u{0048}\u{2060}\u{0065}\u{2060}\u{0020}\u{2060}\u{0064}\u{2060}\u{0069}\u{2060}\u{0064}\u{2060}\u{002E}\u{2060}\u{0029}\u{2060}\u{2060}",
+		&["\u{2060}\u{0028}\u{2060}\u{201C}\u{2060}\u{0047}\u{2060}\u{006F}\u{2060}\u{003F}\u{2060}\u{201D}\u{2060}\u{0029}\u{2060}\u{0020}\u{2060}", "\u{0028}\u{2060}\u{0048}\u{2060}\u{0065}\u{2060}\u{0020}\u{2060}\u{0064}\u{2060}\u{0069}\u{2060}\u{0064}\u{2060}\u{002E}\u{2060}\u{0029}\u{2060}\u{2060}"],
+		"  ÷ [0.2] WORD JOINER (Format) × [998.0] LEFT PARENTHESIS (Close) × [5.0] WORD JOINER (Format) × [998.0] LEFT DOUBLE QUOTATION MARK (Close) × [5.0] WORD JOINER (Format) × [998.0] LATIN CAPITAL LETTER G (Upper) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER O (Lower) × [5.0] WORD JOINER (Format) × [998.0] QUESTION MARK (STerm) × [5.0] WORD JOINER (Format) × [9.0] RIGHT DOUBLE QUOTATION MARK (Close) × [5.0] WORD JOINER (Format) × [9.0] RIGHT PARENTHESIS (Close) × [5.0] WORD JOINER (Format) × [9.0] SPACE (Sp) × [5.0] WORD JOINER (Format) ÷ [11.0] LEFT PARENTHESIS (Close) × [5.0] WORD JOINER (Format) × [998.0] LATIN CAPITAL LETTER H (Upper) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER E (Lower) × [5.0] WORD JOINER (Format) × [998.0] SPACE (Sp) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER D (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER I (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER D (Lower) × [5.0] WORD JOINER (Format) × [998.0] FULL STOP (ATerm) × [5.0] WORD JOINER (Format) × [9.0] RIGHT PARENTHESIS (Close) × [5.0] WORD JOINER (Format) × [5.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{2060}\u{0055}\u{2060}\u{002E}\u{2060}\u{0053}\u{2060}\u{002E}\u{2060}\u{0041}\u{2060}\u{0300}\u{002E}\u{2060}\u{0020}\u{2060}\u{0069}\u{2060}\u{0073}\u{2060}\u{2060}",
+		&["\u{2060}\u{0055}\u{2060}\u{002E}\u{2060}\u{0053}\u{2060}\u{002E}\u{2060}\u{0041}\u{2060}\u{0300}\u{002E}\u{2060}\u{0020}\u{2060}\u{0069}\u{2060}\u{0073}\u{2060}\u{2060}"],
+		"  ÷ [0.2] WORD JOINER (Format) × [998.0] LATIN CAPITAL LETTER U (Upper) × [5.0] WORD JOINER (Format) × [998.0] FULL STOP (ATerm) × [5.0] WORD JOINER (Format) × [7.0] LATIN CAPITAL LETTER S (Upper) × [5.0] WORD JOINER (Format) × [998.0] FULL STOP (ATerm) × [5.0] WORD JOINER (Format) × [7.0] LATIN CAPITAL LETTER A (Upper) × [5.0] WORD JOINER (Format) × [5.0] COMBINING GRAVE ACCENT (Extend) × [998.0] FULL STOP (ATerm) × [5.0] WORD JOINER (Format) × [8.0] SPACE (Sp) × [5.0] WORD JOINER (Format) × [8.0] LATIN SMALL LETTER I (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER S (Lower) × [5.0] WORD JOINER (Format) × [5.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{2060}\u{0055}\u{2060}\u{002E}\u{2060}\u{0053}\u{2060}\u{002E}\u{2060}\u{0041}\u{2060}\u{0300}\u{003F}\u{2060}\u{0020}\u{2060}\u{0048}\u{2060}\u{0065}\u{2060}\u{2060}",
+		&["\u{2060}\u{0055}\u{2060}\u{002E}\u{2060}\u{0053}\u{2060}\u{002E}\u{2060}\u{0041}\u{2060}\u{0300}\u{003F}\u{2060}\u{0020}\u{2060}", "\u{0048}\u{2060}\u{0065}\u{2060}\u{2060}"],
+		"  ÷ [0.2] WORD JOINER (Format) × [998.0] LATIN CAPITAL LETTER U (Upper) × [5.0] WORD JOINER (Format) × [998.0] FULL STOP (ATerm) × [5.0] WORD JOINER (Format) × [7.0] LATIN CAPITAL LETTER S (Upper) × [5.0] WORD JOINER (Format) × [998.0] FULL STOP (ATerm) × [5.0] WORD JOINER (Format) × [7.0] LATIN CAPITAL LETTER A (Upper) × [5.0] WORD JOINER (Format) × [5.0] COMBINING GRAVE ACCENT (Extend) × [998.0] QUESTION MARK (STerm) × [5.0] WORD JOINER (Format) × [9.0] SPACE (Sp) × [5.0] WORD JOINER (Format) ÷ [11.0] LATIN CAPITAL LETTER H (Upper) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER E (Lower) × [5.0] WORD JOINER (Format) × [5.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{2060}\u{0055}\u{2060}\u{002E}\u{2060}\u{0053}\u{2060}\u{002E}\u{2060}\u{0041}\u{2060}\u{0300}\u{002E}\u{2060}\u{2060}",
+		&["\u{2060}\u{0055}\u{2060}\u{002E}\u{2060}\u{0053}\u{2060}\u{002E}\u{2060}\u{0041}\u{2060}\u{0300}\u{002E}\u{2060}\u{2060}"],
+		"  ÷ [0.2] WORD JOINER (Format) × [998.0] LATIN CAPITAL LETTER U (Upper) × [5.0] WORD JOINER (Format) × [998.0] FULL STOP (ATerm) × [5.0] WORD JOINER (Format) × [7.0] LATIN CAPITAL LETTER S (Upper) × [5.0] WORD JOINER (Format) × [998.0] FULL STOP (ATerm) × [5.0] WORD JOINER (Format) × [7.0] LATIN CAPITAL LETTER A (Upper) × [5.0] WORD JOINER (Format) × [5.0] COMBINING GRAVE ACCENT (Extend) × [998.0] FULL STOP (ATerm) × [5.0] WORD JOINER (Format) × [5.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{2060}\u{0033}\u{2060}\u{002E}\u{2060}\u{0034}\u{2060}\u{2060}",
+		&["\u{2060}\u{0033}\u{2060}\u{002E}\u{2060}\u{0034}\u{2060}\u{2060}"],
+		"  ÷ [0.2] WORD JOINER (Format) × [998.0] DIGIT THREE (Numeric) × [5.0] WORD JOINER (Format) × [998.0] FULL STOP (ATerm) × [5.0] WORD JOINER (Format) × [6.0] DIGIT FOUR (Numeric) × [5.0] WORD JOINER (Format) × [5.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{2060}\u{0063}\u{2060}\u{002E}\u{2060}\u{0064}\u{2060}\u{2060}",
+		&["\u{2060}\u{0063}\u{2060}\u{002E}\u{2060}\u{0064}\u{2060}\u{2060}"],
+		"  ÷ [0.2] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER C (Lower) × [5.0] WORD JOINER (Format) × [998.0] FULL STOP (ATerm) × [5.0] WORD JOINER (Format) × [8.0] LATIN SMALL LETTER D (Lower) × [5.0] WORD JOINER (Format) × [5.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{2060}\u{0043}\u{2060}\u{002E}\u{2060}\u{0064}\u{2060}\u{2060}",
+		&["\u{2060}\u{0043}\u{2060}\u{002E}\u{2060}\u{0064}\u{2060}\u{2060}"],
+		"  ÷ [0.2] WORD JOINER (Format) × [998.0] LATIN CAPITAL LETTER C (Upper) × [5.0] WORD JOINER (Format) × [998.0] FULL STOP (ATerm) × [5.0] WORD JOINER (Format) × [8.0] LATIN SMALL LETTER D (Lower) × [5.0] WORD JOINER (Format) × [5.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{2060}\u{0063}\u{2060}\u{002E}\u{2060}\u{0044}\u{2060}\u{2060}",
+		&["\u{2060}\u{0063}\u{2060}\u{002E}\u{2060}\u{0044}\u{2060}\u{2060}"],
+		"  ÷ [0.2] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER C (Lower) × [5.0] WORD JOINER (Format) × [998.0] FULL STOP (ATerm) × [5.0] WORD JOINER (Format) × [7.0] LATIN CAPITAL LETTER D (Upper) × [5.0] WORD JOINER (Format) × [5.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{2060}\u{0043}\u{2060}\u{002E}\u{2060}\u{0044}\u{2060}\u{2060}",
+		&["\u{2060}\u{0043}\u{2060}\u{002E}\u{2060}\u{0044}\u{2060}\u{2060}"],
+		"  ÷ [0.2] WORD JOINER (Format) × [998.0] LATIN CAPITAL LETTER C (Upper) × [5.0] WORD JOINER (Format) × [998.0] FULL STOP (ATerm) × [5.0] WORD JOINER (Format) × [7.0] LATIN CAPITAL LETTER D (Upper) × [5.0] WORD JOINER (Format) × [5.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{2060}\u{0065}\u{2060}\u{0074}\u{2060}\u{0063}\u{2060}\u{002E}\u{2060}\u{0029}\u{2060}\u{2019}\u{2060}\u{00A0}\u{2060}\u{0074}\u{2060}\u{0068}\u{2060}\u{0065}\u{2060}\u{2060}",
+		&["\u{2060}\u{0065}\u{2060}\u{0074}\u{2060}\u{0063}\u{2060}\u{002E}\u{2060}\u{0029}\u{2060}\u{2019}\u{2060}\u{00A0}\u{2060}\u{0074}\u{2060}\u{0068}\u{2060}\u{0065}\u{2060}\u{2060}"],
+		"  ÷ [0.2] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER E (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER T (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER C (Lower) × [5.0] WORD JOINER (Format) × [998.0] FULL STOP (ATerm) × [5.0] WORD JOINER (Format) × [8.0] RIGHT PARENTHESIS (Close) × [5.0] WORD JOINER (Format) × [8.0] RIGHT SINGLE QUOTATION MARK (Close) × [5.0] WORD JOINER (Format) × [8.0] NO-BREAK SPACE (Sp) × [5.0] WORD JOINER (Format) × [8.0] LATIN SMALL LETTER T (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER H (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER E (Lower) × [5.0] WORD JOINER (Format) × [5.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{2060}\u{0065}\u{2060}\u{0074}\u{2060}\u{0063}\u{2060}\u{002E}\u{2060}\u{0029}\u{2060}\u{2019}\u{2060}\u{00A0}\u{2060}\u{0054}\u{2060}\u{0068}\u{2060}\u{0065}\u{2060}\u{2060}",
+		&["\u{2060}\u{0065}\u{2060}\u{0074}\u{2060}\u{0063}\u{2060}\u{002E}\u{2060}\u{0029}\u{2060}\u{2019}\u{2060}\u{00A0}\u{2060}", "\u{0054}\u{2060}\u{0068}\u{2060}\u{0065}\u{2060}\u{2060}"],
+		"  ÷ [0.2] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER E (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER T (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER C (Lower) × [5.0] WORD JOINER (Format) × [998.0] FULL STOP (ATerm) × [5.0] WORD JOINER (Format) × [9.0] RIGHT PARENTHESIS (Close) × [5.0] WORD JOINER (Format) × [9.0] RIGHT SINGLE QUOTATION MARK (Close) × [5.0] WORD JOINER (Format) × [9.0] NO-BREAK SPACE (Sp) × [5.0] WORD JOINER (Format) ÷ [11.0] LATIN CAPITAL LETTER T (Upper) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER H (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER E (Lower) × [5.0] WORD JOINER (Format) × [5.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{2060}\u{0065}\u{2060}\u{0074}\u{2060}\u{0063}\u{2060}\u{002E}\u{2060}\u{0029}\u{2060}\u{2019}\u{2060}\u{00A0}\u{2060}\u{2018}\u{2060}\u{0028}\u{2060}\u{0074}\u{2060}\u{0068}\u{2060}\u{0065}\u{2060}\u{2060}",
+		&["\u{2060}\u{0065}\u{2060}\u{0074}\u{2060}\u{0063}\u{2060}\u{002E}\u{2060}\u{0029}\u{2060}\u{2019}\u{2060}\u{00A0}\u{2060}\u{2018}\u{2060}\u{0028}\u{2060}\u{0074}\u{2060}\u{0068}\u{2060}\u{0065}\u{2060}\u{2060}"],
+		"  ÷ [0.2] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER E (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER T (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER C (Lower) × [5.0] WORD JOINER (Format) × [998.0] FULL STOP (ATerm) × [5.0] WORD JOINER (Format) × [8.0] RIGHT PARENTHESIS (Close) × [5.0] WORD JOINER (Format) × [8.0] RIGHT SINGLE QUOTATION MARK (Close) × [5.0] WORD JOINER (Format) × [8.0] NO-BREAK SPACE (Sp) × [5.0] WORD JOINER (Format) × [8.0] LEFT SINGLE QUOTATION MARK (Close) × [5.0] WORD JOINER (Format) × [998.0] LEFT PARENTHESIS (Close) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER T (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER H (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER E (Lower) × [5.0] WORD JOINER (Format) × [5.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{2060}\u{0065}\u{2060}\u{0074}\u{2060}\u{0063}\u{2060}\u{002E}\u{2060}\u{0029}\u{2060}\u{2019}\u{2060}\u{00A0}\u{2060}\u{2018}\u{2060}\u{0028}\u{2060}\u{0054}\u{2060}\u{0068}\u{2060}\u{0065}\u{2060}\u{2060}",
+		&["\u{2060}\u{0065}\u{2060}\u{0074}\u{2060}\u{0063}\u{2060}\u{002E}\u{2060}\u{0029}\u{2060}\u{2019}\u{2060}\u{00A0}\u{2060}", "\u{2018}\u{2060}\u{0028}\u{2060}\u{0054}\u{2060}\u{0068}\u{2060}\u{0065}\u{2060}\u{2060}"],
+		"  ÷ [0.2] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER E (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER T (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER C (Lower) × [5.0] WORD JOINER (Format) × [998.0] FULL STOP (ATerm) × [5.0] WORD JOINER (Format) × [9.0] RIGHT PARENTHESIS (Close) × [5.0] WORD JOINER (Format) × [9.0] RIGHT SINGLE QUOTATION MARK (Close) × [5.0] WORD JOINER (Format) × [9.0] NO-BREAK SPACE (Sp) × [5.0] WORD JOINER (Format) ÷ [11.0] LEFT SINGLE QUOTATION MARK (Close) × [5.0] WORD JOINER (Format) × [998.0] LEFT PARENTHESIS (Close) × [5.0] WORD JOINER (Format) × [998.0] LATIN CAPITAL LETTER T (Upper) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER H (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER E (Lower) × [5.0] WORD JOINER (Format) × [5.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{2060}\u{0065}\u{2060}\u{0074}\u{2060}\u{0063}\u{2060}\u{002E}\u{2060}\u{0029}\u{2060}\u{2019}\u{2060}\u{00A0}\u{2060}\u{0308}\u{0074}\u{2060}\u{0068}\u{2060}\u{0065}\u{2060}\u{2060}",
+		&["\u{2060}\u{0065}\u{2060}\u{0074}\u{2060}\u{0063}\u{2060}\u{002E}\u{2060}\u{0029}\u{2060}\u{2019}\u{2060}\u{00A0}\u{2060}\u{0308}\u{0074}\u{2060}\u{0068}\u{2060}\u{0065}\u{2060}\u{2060}"],
+		"  ÷ [0.2] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER E (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER T (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER C (Lower) × [5.0] WORD JOINER (Format) × [998.0] FULL STOP (ATerm) × [5.0] WORD JOINER (Format) × [8.0] RIGHT PARENTHESIS (Close) × [5.0] WORD JOINER (Format) × [8.0] RIGHT SINGLE QUOTATION MARK (Close) × [5.0] WORD JOINER (Format) × [8.0] NO-BREAK SPACE (Sp) × [5.0] WORD JOINER (Format) × [5.0] COMBINING DIAERESIS (Extend) × [8.0] LATIN SMALL LETTER T (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER H (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER E (Lower) × [5.0] WORD JOINER (Format) × [5.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{2060}\u{0065}\u{2060}\u{0074}\u{2060}\u{0063}\u{2060}\u{002E}\u{2060}\u{0029}\u{2060}\u{2019}\u{2060}\u{00A0}\u{2060}\u{0308}\u{0054}\u{2060}\u{0068}\u{2060}\u{0065}\u{2060}\u{2060}",
+		&["\u{2060}\u{0065}\u{2060}\u{0074}\u{2060}\u{0063}\u{2060}\u{002E}\u{2060}\u{0029}\u{2060}\u{2019}\u{2060}\u{00A0}\u{2060}\u{0308}", "\u{0054}\u{2060}\u{0068}\u{2060}\u{0065}\u{2060}\u{2060}"],
+		"  ÷ [0.2] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER E (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER T (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER C (Lower) × [5.0] WORD JOINER (Format) × [998.0] FULL STOP (ATerm) × [5.0] WORD JOINER (Format) × [9.0] RIGHT PARENTHESIS (Close) × [5.0] WORD JOINER (Format) × [9.0] RIGHT SINGLE QUOTATION MARK (Close) × [5.0] WORD JOINER (Format) × [9.0] NO-BREAK SPACE (Sp) × [5.0] WORD JOINER (Format) × [5.0] COMBINING DIAERESIS (Extend) ÷ [11.0] LATIN CAPITAL LETTER T (Upper) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER H (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER E (Lower) × [5.0] WORD JOINER (Format) × [5.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{2060}\u{0065}\u{2060}\u{0074}\u{2060}\u{0063}\u{2060}\u{002E}\u{2060}\u{0029}\u{2060}\u{2019}\u{2060}\u{0308}\u{0054}\u{2060}\u{0068}\u{2060}\u{0065}\u{2060}\u{2060}",
+		&["\u{2060}\u{0065}\u{2060}\u{0074}\u{2060}\u{0063}\u{2060}\u{002E}\u{2060}\u{0029}\u{2060}\u{2019}\u{2060}\u{0308}", "\u{0054}\u{2060}\u{0068}\u{2060}\u{0065}\u{2060}\u{2060}"],
+		"  ÷ [0.2] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER E (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER T (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER C (Lower) × [5.0] WORD JOINER (Format) × [998.0] FULL STOP (ATerm) × [5.0] WORD JOINER (Format) × [9.0] RIGHT PARENTHESIS (Close) × [5.0] WORD JOINER (Format) × [9.0] RIGHT SINGLE QUOTATION MARK (Close) × [5.0] WORD JOINER (Format) × [5.0] COMBINING DIAERESIS (Extend) ÷ [11.0] LATIN CAPITAL LETTER T (Upper) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER H (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER E (Lower) × [5.0] WORD JOINER (Format) × [5.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{2060}\u{0065}\u{2060}\u{0074}\u{2060}\u{0063}\u{2060}\u{002E}\u{2060}\u{0029}\u{2060}\u{000A}\u{2060}\u{0308}\u{2060}\u{0054}\u{2060}\u{0068}\u{2060}\u{0065}\u{2060}\u{2060}",
+		&["\u{2060}\u{0065}\u{2060}\u{0074}\u{2060}\u{0063}\u{2060}\u{002E}\u{2060}\u{0029}\u{2060}\u{000A}", "\u{2060}\u{0308}\u{2060}\u{0054}\u{2060}\u{0068}\u{2060}\u{0065}\u{2060}\u{2060}"],
+		"  ÷ [0.2] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER E (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER T (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER C (Lower) × [5.0] WORD JOINER (Format) × [998.0] FULL STOP (ATerm) × [5.0] WORD JOINER (Format) × [9.0] RIGHT PARENTHESIS (Close) × [5.0] WORD JOINER (Format) × [9.0] <LINE FEED (LF)> (LF) ÷ [4.0] WORD JOINER (Format) × [5.0] COMBINING DIAERESIS (Extend) × [5.0] WORD JOINER (Format) × [998.0] LATIN CAPITAL LETTER T (Upper) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER H (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER E (Lower) × [5.0] WORD JOINER (Format) × [5.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{2060}\u{0074}\u{2060}\u{0068}\u{2060}\u{0065}\u{2060}\u{0020}\u{2060}\u{0072}\u{2060}\u{0065}\u{2060}\u{0073}\u{2060}\u{0070}\u{2060}\u{002E}\u{2060}\u{0020}\u{2060}\u{006C}\u{2060}\u{0065}\u{2060}\u{0061}\u{2060}\u{0064}\u{2060}\u{0065}\u{2060}\u{0072}\u{2060}\u{0073}\u{2060}\u{0020}\u{2060}\u{0061}\u{2060}\u{0072}\u{2060}\u{0065}\u{2060}\u{2060}",
+		&["\u{2060}\u{0074}\u{2060}\u{0068}\u{2060}\u{0065}\u{2060}\u{0020}\u{2060}\u{0072}\u{2060}\u{0065}\u{2060}\u{0073}\u{2060}\u{0070}\u{2060}\u{002E}\u{2060}\u{0020}\u{2060}\u{006C}\u{2060}\u{0065}\u{2060}\u{0061}\u{2060}\u{0064}\u{2060}\u{0065}\u{2060}\u{0072}\u{2060}\u{0073}\u{2060}\u{0020}\u{2060}\u{0061}\u{2060}\u{0072}\u{2060}\u{0065}\u{2060}\u{2060}"],
+		"  ÷ [0.2] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER T (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER H (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER E (Lower) × [5.0] WORD JOINER (Format) × [998.0] SPACE (Sp) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER R (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER E (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER S (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER P (Lower) × [5.0] WORD JOINER (Format) × [998.0] FULL STOP (ATerm) × [5.0] WORD JOINER (Format) × [8.0] SPACE (Sp) × [5.0] WORD JOINER (Format) × [8.0] LATIN SMALL LETTER L (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER E (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER A (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER D (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER E (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER R (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER S (Lower) × [5.0] WORD JOINER (Format) × [998.0] SPACE (Sp) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER A (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER R (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER E (Lower) × [5.0] WORD JOINER (Format) × [5.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{2060}\u{5B57}\u{2060}\u{002E}\u{2060}\u{5B57}\u{2060}\u{2060}",
+		&["\u{2060}\u{5B57}\u{2060}\u{002E}\u{2060}", "\u{5B57}\u{2060}\u{2060}"],
+		"  ÷ [0.2] WORD JOINER (Format) × [998.0] CJK UNIFIED IDEOGRAPH-5B57 (OLetter) × [5.0] WORD JOINER (Format) × [998.0] FULL STOP (ATerm) × [5.0] WORD JOINER (Format) ÷ [11.0] CJK UNIFIED IDEOGRAPH-5B57 (OLetter) × [5.0] WORD JOINER (Format) × [5.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{2060}\u{0065}\u{2060}\u{0074}\u{2060}\u{0063}\u{2060}\u{002E}\u{2060}\u{5B83}\u{2060}\u{2060}",
+		&["\u{2060}\u{0065}\u{2060}\u{0074}\u{2060}\u{0063}\u{2060}\u{002E}\u{2060}", "\u{5B83}\u{2060}\u{2060}"],
+		"  ÷ [0.2] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER E (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER T (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER C (Lower) × [5.0] WORD JOINER (Format) × [998.0] FULL STOP (ATerm) × [5.0] WORD JOINER (Format) ÷ [11.0] CJK UNIFIED IDEOGRAPH-5B83 (OLetter) × [5.0] WORD JOINER (Format) × [5.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{2060}\u{0065}\u{2060}\u{0074}\u{2060}\u{0063}\u{2060}\u{002E}\u{2060}\u{3002}\u{2060}\u{2060}",
+		&["\u{2060}\u{0065}\u{2060}\u{0074}\u{2060}\u{0063}\u{2060}\u{002E}\u{2060}\u{3002}\u{2060}\u{2060}"],
+		"  ÷ [0.2] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER E (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER T (Lower) × [5.0] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER C (Lower) × [5.0] WORD JOINER (Format) × [998.0] FULL STOP (ATerm) × [5.0] WORD JOINER (Format) × [8.1] IDEOGRAPHIC FULL STOP (STerm) × [5.0] WORD JOINER (Format) × [5.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{2060}\u{5B57}\u{2060}\u{3002}\u{2060}\u{5B83}\u{2060}\u{2060}",
+		&["\u{2060}\u{5B57}\u{2060}\u{3002}\u{2060}", "\u{5B83}\u{2060}\u{2060}"],
+		"  ÷ [0.2] WORD JOINER (Format) × [998.0] CJK UNIFIED IDEOGRAPH-5B57 (OLetter) × [5.0] WORD JOINER (Format) × [998.0] IDEOGRAPHIC FULL STOP (STerm) × [5.0] WORD JOINER (Format) ÷ [11.0] CJK UNIFIED IDEOGRAPH-5B83 (OLetter) × [5.0] WORD JOINER (Format) × [5.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{2060}\u{0021}\u{2060}\u{0020}\u{2060}\u{0020}\u{2060}\u{2060}",
+		&["\u{2060}\u{0021}\u{2060}\u{0020}\u{2060}\u{0020}\u{2060}\u{2060}"],
+		"  ÷ [0.2] WORD JOINER (Format) × [998.0] EXCLAMATION MARK (STerm) × [5.0] WORD JOINER (Format) × [9.0] SPACE (Sp) × [5.0] WORD JOINER (Format) × [10.0] SPACE (Sp) × [5.0] WORD JOINER (Format) × [5.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{2060}\u{0061}\u{2060}\u{002E}\u{2060}\u{2060}",
+		&["\u{2060}\u{0061}\u{2060}\u{002E}\u{2060}\u{2060}"],
+		"  ÷ [0.2] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER A (Lower) × [5.0] WORD JOINER (Format) × [998.0] FULL STOP (ATerm) × [5.0] WORD JOINER (Format) × [5.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{2060}\u{0061}\u{2060}\u{002E}\u{2060}\u{000D}\u{2060}\u{000A}\u{2060}",
+		&["\u{2060}\u{0061}\u{2060}\u{002E}\u{2060}\u{000D}", "\u{2060}\u{000A}", "\u{2060}"],
+		"  ÷ [0.2] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER A (Lower) × [5.0] WORD JOINER (Format) × [998.0] FULL STOP (ATerm) × [5.0] WORD JOINER (Format) × [9.0] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] WORD JOINER (Format) × [998.0] <LINE FEED (LF)> (LF) ÷ [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{2060}\u{0061}\u{2060}\u{002E}\u{2060}\u{000D}\u{2060}\u{000A}\u{0020}\u{2060}\u{2060}",
+		&["\u{2060}\u{0061}\u{2060}\u{002E}\u{2060}\u{000D}", "\u{2060}\u{000A}", "\u{0020}\u{2060}\u{2060}"],
+		"  ÷ [0.2] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER A (Lower) × [5.0] WORD JOINER (Format) × [998.0] FULL STOP (ATerm) × [5.0] WORD JOINER (Format) × [9.0] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] WORD JOINER (Format) × [998.0] <LINE FEED (LF)> (LF) ÷ [4.0] SPACE (Sp) × [5.0] WORD JOINER (Format) × [5.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{2060}\u{0061}\u{2060}\u{002E}\u{2060}\u{000D}\u{2060}\u{000A}\u{0061}\u{2060}\u{2060}",
+		&["\u{2060}\u{0061}\u{2060}\u{002E}\u{2060}\u{000D}", "\u{2060}\u{000A}", "\u{0061}\u{2060}\u{2060}"],
+		"  ÷ [0.2] WORD JOINER (Format) × [998.0] LATIN SMALL LETTER A (Lower) × [5.0] WORD JOINER (Format) × [998.0] FULL STOP (ATerm) × [5.0] WORD JOINER (Format) × [9.0] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] WORD JOINER (Format) × [998.0] <LINE FEED (LF)> (LF) ÷ [4.0] LATIN SMALL LETTER A (Lower) × [5.0] WORD JOINER (Format) × [5.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	sentence_test("\u{2060}\u{0041}\u{2060}\u{002E}\u{2060}\u{000D}\u{2060}\u{000A}\u{0041}\u{2060}\u{2060}",
+		&["\u{2060}\u{0041}\u{2060}\u{002E}\u{2060}\u{000D}", "\u{2060}\u{000A}", "\u{0041}\u{2060}\u{2060}"],
+		"  ÷ [0.2] WORD JOINER (Format) × [998.0] LATIN CAPITAL LETTER A (Upper) × [5.0] WORD JOINER (Format) × [998.0] FULL STOP (ATerm) × [5.0] WORD JOINER (Format) × [9.0] <CARRIAGE RETURN (CR)> (CR) ÷ [4.0] WORD JOINER (Format) × [998.0] <LINE FEED (LF)> (LF) ÷ [4.0] LATIN CAPITAL LETTER A (Upper) × [5.0] WORD JOINER (Format) × [5.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+}
@@ -0,0 +1,43833 @@
+// GENERATED CODE DO NOT MANUALLY EDIT
+// Generated from Unicode 14.0.0 data files:
+//   24c958328f6eab35845c6fb3d475b3a8a424ad42dee3082222318bc43fccfd40  ucd/UnicodeData.txt
+pub const CAT_TABLE: [u8;0x1100] = [
+	 0, // 0x0
+	 1, // 0x1
+	 2, // 0x2
+	 3, // 0x3
+	 4, // 0x4
+	 5, // 0x5
+	 6, // 0x6
+	 7, // 0x7
+	 8, // 0x8
+	 9, // 0x9
+	 10, // 0xa
+	 11, // 0xb
+	 12, // 0xc
+	 13, // 0xd
+	 14, // 0xe
+	 15, // 0xf
+	 16, // 0x10
+	 17, // 0x11
+	 18, // 0x12
+	 19, // 0x13
+	 20, // 0x14
+	 17, // 0x15
+	 21, // 0x16
+	 22, // 0x17
+	 23, // 0x18
+	 24, // 0x19
+	 25, // 0x1a
+	 26, // 0x1b
+	 27, // 0x1c
+	 28, // 0x1d
+	 29, // 0x1e
+	 30, // 0x1f
+	 31, // 0x20
+	 32, // 0x21
+	 33, // 0x22
+	 34, // 0x23
+	 35, // 0x24
+	 36, // 0x25
+	 37, // 0x26
+	 38, // 0x27
+	 39, // 0x28
+	 40, // 0x29
+	 33, // 0x2a
+	 41, // 0x2b
+	 42, // 0x2c
+	 43, // 0x2d
+	 44, // 0x2e
+	 45, // 0x2f
+	 46, // 0x30
+	 47, // 0x31
+	 48, // 0x32
+	 39, // 0x33
+	 17, // 0x34
+	 17, // 0x35
+	 17, // 0x36
+	 17, // 0x37
+	 17, // 0x38
+	 17, // 0x39
+	 17, // 0x3a
+	 17, // 0x3b
+	 17, // 0x3c
+	 17, // 0x3d
+	 17, // 0x3e
+	 17, // 0x3f
+	 17, // 0x40
+	 17, // 0x41
+	 17, // 0x42
+	 17, // 0x43
+	 17, // 0x44
+	 17, // 0x45
+	 17, // 0x46
+	 17, // 0x47
+	 17, // 0x48
+	 17, // 0x49
+	 17, // 0x4a
+	 17, // 0x4b
+	 17, // 0x4c
+	 49, // 0x4d
+	 17, // 0x4e
+	 17, // 0x4f
+	 17, // 0x50
+	 17, // 0x51
+	 17, // 0x52
+	 17, // 0x53
+	 17, // 0x54
+	 17, // 0x55
+	 17, // 0x56
+	 17, // 0x57
+	 17, // 0x58
+	 17, // 0x59
+	 17, // 0x5a
+	 17, // 0x5b
+	 17, // 0x5c
+	 17, // 0x5d
+	 17, // 0x5e
+	 17, // 0x5f
+	 17, // 0x60
+	 17, // 0x61
+	 17, // 0x62
+	 17, // 0x63
+	 17, // 0x64
+	 17, // 0x65
+	 17, // 0x66
+	 17, // 0x67
+	 17, // 0x68
+	 17, // 0x69
+	 17, // 0x6a
+	 17, // 0x6b
+	 17, // 0x6c
+	 17, // 0x6d
+	 17, // 0x6e
+	 17, // 0x6f
+	 17, // 0x70
+	 17, // 0x71
+	 17, // 0x72
+	 17, // 0x73
+	 17, // 0x74
+	 17, // 0x75
+	 17, // 0x76
+	 17, // 0x77
+	 17, // 0x78
+	 17, // 0x79
+	 17, // 0x7a
+	 17, // 0x7b
+	 17, // 0x7c
+	 17, // 0x7d
+	 17, // 0x7e
+	 17, // 0x7f
+	 17, // 0x80
+	 17, // 0x81
+	 17, // 0x82
+	 17, // 0x83
+	 17, // 0x84
+	 17, // 0x85
+	 17, // 0x86
+	 17, // 0x87
+	 17, // 0x88
+	 17, // 0x89
+	 17, // 0x8a
+	 17, // 0x8b
+	 17, // 0x8c
+	 17, // 0x8d
+	 17, // 0x8e
+	 17, // 0x8f
+	 17, // 0x90
+	 17, // 0x91
+	 17, // 0x92
+	 17, // 0x93
+	 17, // 0x94
+	 17, // 0x95
+	 17, // 0x96
+	 17, // 0x97
+	 17, // 0x98
+	 17, // 0x99
+	 17, // 0x9a
+	 17, // 0x9b
+	 17, // 0x9c
+	 17, // 0x9d
+	 17, // 0x9e
+	 17, // 0x9f
+	 50, // 0xa0
+	 17, // 0xa1
+	 17, // 0xa2
+	 17, // 0xa3
+	 51, // 0xa4
+	 17, // 0xa5
+	 52, // 0xa6
+	 53, // 0xa7
+	 54, // 0xa8
+	 55, // 0xa9
+	 56, // 0xaa
+	 57, // 0xab
+	 17, // 0xac
+	 17, // 0xad
+	 17, // 0xae
+	 17, // 0xaf
+	 17, // 0xb0
+	 17, // 0xb1
+	 17, // 0xb2
+	 17, // 0xb3
+	 17, // 0xb4
+	 17, // 0xb5
+	 17, // 0xb6
+	 17, // 0xb7
+	 17, // 0xb8
+	 17, // 0xb9
+	 17, // 0xba
+	 17, // 0xbb
+	 17, // 0xbc
+	 17, // 0xbd
+	 17, // 0xbe
+	 17, // 0xbf
+	 17, // 0xc0
+	 17, // 0xc1
+	 17, // 0xc2
+	 17, // 0xc3
+	 17, // 0xc4
+	 17, // 0xc5
+	 17, // 0xc6
+	 17, // 0xc7
+	 17, // 0xc8
+	 17, // 0xc9
+	 17, // 0xca
+	 17, // 0xcb
+	 17, // 0xcc
+	 17, // 0xcd
+	 17, // 0xce
+	 17, // 0xcf
+	 17, // 0xd0
+	 17, // 0xd1
+	 17, // 0xd2
+	 17, // 0xd3
+	 17, // 0xd4
+	 17, // 0xd5
+	 17, // 0xd6
+	 58, // 0xd7
+	 59, // 0xd8
+	 59, // 0xd9
+	 59, // 0xda
+	 59, // 0xdb
+	 59, // 0xdc
+	 59, // 0xdd
+	 59, // 0xde
+	 59, // 0xdf
+	 60, // 0xe0
+	 60, // 0xe1
+	 60, // 0xe2
+	 60, // 0xe3
+	 60, // 0xe4
+	 60, // 0xe5
+	 60, // 0xe6
+	 60, // 0xe7
+	 60, // 0xe8
+	 60, // 0xe9
+	 60, // 0xea
+	 60, // 0xeb
+	 60, // 0xec
+	 60, // 0xed
+	 60, // 0xee
+	 60, // 0xef
+	 60, // 0xf0
+	 60, // 0xf1
+	 60, // 0xf2
+	 60, // 0xf3
+	 60, // 0xf4
+	 60, // 0xf5
+	 60, // 0xf6
+	 60, // 0xf7
+	 60, // 0xf8
+	 17, // 0xf9
+	 61, // 0xfa
+	 62, // 0xfb
+	 17, // 0xfc
+	 63, // 0xfd
+	 64, // 0xfe
+	 65, // 0xff
+	 66, // 0x100
+	 67, // 0x101
+	 68, // 0x102
+	 69, // 0x103
+	 70, // 0x104
+	 71, // 0x105
+	 17, // 0x106
+	 72, // 0x107
+	 73, // 0x108
+	 74, // 0x109
+	 75, // 0x10a
+	 76, // 0x10b
+	 77, // 0x10c
+	 78, // 0x10d
+	 79, // 0x10e
+	 80, // 0x10f
+	 81, // 0x110
+	 82, // 0x111
+	 83, // 0x112
+	 84, // 0x113
+	 85, // 0x114
+	 86, // 0x115
+	 87, // 0x116
+	 88, // 0x117
+	 89, // 0x118
+	 90, // 0x119
+	 91, // 0x11a
+	 92, // 0x11b
+	 93, // 0x11c
+	 94, // 0x11d
+	 95, // 0x11e
+	 96, // 0x11f
+	 17, // 0x120
+	 17, // 0x121
+	 17, // 0x122
+	 97, // 0x123
+	 98, // 0x124
+	 99, // 0x125
+	 92, // 0x126
+	 92, // 0x127
+	 92, // 0x128
+	 92, // 0x129
+	 92, // 0x12a
+	 92, // 0x12b
+	 92, // 0x12c
+	 92, // 0x12d
+	 92, // 0x12e
+	 100, // 0x12f
+	 17, // 0x130
+	 17, // 0x131
+	 17, // 0x132
+	 17, // 0x133
+	 101, // 0x134
+	 92, // 0x135
+	 92, // 0x136
+	 92, // 0x137
+	 92, // 0x138
+	 92, // 0x139
+	 92, // 0x13a
+	 92, // 0x13b
+	 92, // 0x13c
+	 92, // 0x13d
+	 92, // 0x13e
+	 92, // 0x13f
+	 92, // 0x140
+	 92, // 0x141
+	 92, // 0x142
+	 92, // 0x143
+	 17, // 0x144
+	 17, // 0x145
+	 102, // 0x146
+	 92, // 0x147
+	 92, // 0x148
+	 92, // 0x149
+	 92, // 0x14a
+	 92, // 0x14b
+	 92, // 0x14c
+	 92, // 0x14d
+	 92, // 0x14e
+	 92, // 0x14f
+	 92, // 0x150
+	 92, // 0x151
+	 92, // 0x152
+	 92, // 0x153
+	 92, // 0x154
+	 92, // 0x155
+	 92, // 0x156
+	 92, // 0x157
+	 92, // 0x158
+	 92, // 0x159
+	 92, // 0x15a
+	 92, // 0x15b
+	 92, // 0x15c
+	 92, // 0x15d
+	 92, // 0x15e
+	 92, // 0x15f
+	 92, // 0x160
+	 92, // 0x161
+	 92, // 0x162
+	 92, // 0x163
+	 92, // 0x164
+	 92, // 0x165
+	 92, // 0x166
+	 92, // 0x167
+	 17, // 0x168
+	 17, // 0x169
+	 103, // 0x16a
+	 104, // 0x16b
+	 92, // 0x16c
+	 92, // 0x16d
+	 105, // 0x16e
+	 106, // 0x16f
+	 17, // 0x170
+	 17, // 0x171
+	 17, // 0x172
+	 17, // 0x173
+	 17, // 0x174
+	 17, // 0x175
+	 17, // 0x176
+	 17, // 0x177
+	 17, // 0x178
+	 17, // 0x179
+	 17, // 0x17a
+	 17, // 0x17b
+	 17, // 0x17c
+	 17, // 0x17d
+	 17, // 0x17e
+	 17, // 0x17f
+	 17, // 0x180
+	 17, // 0x181
+	 17, // 0x182
+	 17, // 0x183
+	 17, // 0x184
+	 17, // 0x185
+	 17, // 0x186
+	 107, // 0x187
+	 17, // 0x188
+	 17, // 0x189
+	 17, // 0x18a
+	 17, // 0x18b
+	 108, // 0x18c
+	 109, // 0x18d
+	 92, // 0x18e
+	 92, // 0x18f
+	 92, // 0x190
+	 92, // 0x191
+	 92, // 0x192
+	 92, // 0x193
+	 92, // 0x194
+	 92, // 0x195
+	 92, // 0x196
+	 92, // 0x197
+	 92, // 0x198
+	 92, // 0x199
+	 92, // 0x19a
+	 92, // 0x19b
+	 92, // 0x19c
+	 92, // 0x19d
+	 92, // 0x19e
+	 92, // 0x19f
+	 92, // 0x1a0
+	 92, // 0x1a1
+	 92, // 0x1a2
+	 92, // 0x1a3
+	 92, // 0x1a4
+	 92, // 0x1a5
+	 92, // 0x1a6
+	 92, // 0x1a7
+	 92, // 0x1a8
+	 92, // 0x1a9
+	 92, // 0x1aa
+	 92, // 0x1ab
+	 92, // 0x1ac
+	 92, // 0x1ad
+	 92, // 0x1ae
+	 110, // 0x1af
+	 17, // 0x1b0
+	 111, // 0x1b1
+	 112, // 0x1b2
+	 92, // 0x1b3
+	 92, // 0x1b4
+	 92, // 0x1b5
+	 92, // 0x1b6
+	 92, // 0x1b7
+	 92, // 0x1b8
+	 92, // 0x1b9
+	 92, // 0x1ba
+	 92, // 0x1bb
+	 113, // 0x1bc
+	 92, // 0x1bd
+	 92, // 0x1be
+	 92, // 0x1bf
+	 92, // 0x1c0
+	 92, // 0x1c1
+	 92, // 0x1c2
+	 92, // 0x1c3
+	 92, // 0x1c4
+	 92, // 0x1c5
+	 92, // 0x1c6
+	 92, // 0x1c7
+	 92, // 0x1c8
+	 92, // 0x1c9
+	 92, // 0x1ca
+	 92, // 0x1cb
+	 92, // 0x1cc
+	 92, // 0x1cd
+	 92, // 0x1ce
+	 114, // 0x1cf
+	 115, // 0x1d0
+	 116, // 0x1d1
+	 117, // 0x1d2
+	 118, // 0x1d3
+	 119, // 0x1d4
+	 120, // 0x1d5
+	 121, // 0x1d6
+	 122, // 0x1d7
+	 39, // 0x1d8
+	 39, // 0x1d9
+	 123, // 0x1da
+	 92, // 0x1db
+	 92, // 0x1dc
+	 92, // 0x1dd
+	 92, // 0x1de
+	 124, // 0x1df
+	 125, // 0x1e0
+	 126, // 0x1e1
+	 127, // 0x1e2
+	 92, // 0x1e3
+	 92, // 0x1e4
+	 92, // 0x1e5
+	 92, // 0x1e6
+	 128, // 0x1e7
+	 129, // 0x1e8
+	 130, // 0x1e9
+	 92, // 0x1ea
+	 92, // 0x1eb
+	 131, // 0x1ec
+	 132, // 0x1ed
+	 133, // 0x1ee
+	 92, // 0x1ef
+	 134, // 0x1f0
+	 135, // 0x1f1
+	 136, // 0x1f2
+	 137, // 0x1f3
+	 39, // 0x1f4
+	 39, // 0x1f5
+	 138, // 0x1f6
+	 139, // 0x1f7
+	 140, // 0x1f8
+	 39, // 0x1f9
+	 141, // 0x1fa
+	 142, // 0x1fb
+	 92, // 0x1fc
+	 92, // 0x1fd
+	 92, // 0x1fe
+	 92, // 0x1ff
+	 17, // 0x200
+	 17, // 0x201
+	 17, // 0x202
+	 17, // 0x203
+	 17, // 0x204
+	 17, // 0x205
+	 17, // 0x206
+	 17, // 0x207
+	 17, // 0x208
+	 17, // 0x209
+	 17, // 0x20a
+	 17, // 0x20b
+	 17, // 0x20c
+	 17, // 0x20d
+	 17, // 0x20e
+	 17, // 0x20f
+	 17, // 0x210
+	 17, // 0x211
+	 17, // 0x212
+	 17, // 0x213
+	 17, // 0x214
+	 17, // 0x215
+	 17, // 0x216
+	 17, // 0x217
+	 17, // 0x218
+	 17, // 0x219
+	 17, // 0x21a
+	 17, // 0x21b
+	 17, // 0x21c
+	 17, // 0x21d
+	 17, // 0x21e
+	 17, // 0x21f
+	 17, // 0x220
+	 17, // 0x221
+	 17, // 0x222
+	 17, // 0x223
+	 17, // 0x224
+	 17, // 0x225
+	 17, // 0x226
+	 17, // 0x227
+	 17, // 0x228
+	 17, // 0x229
+	 17, // 0x22a
+	 17, // 0x22b
+	 17, // 0x22c
+	 17, // 0x22d
+	 17, // 0x22e
+	 17, // 0x22f
+	 17, // 0x230
+	 17, // 0x231
+	 17, // 0x232
+	 17, // 0x233
+	 17, // 0x234
+	 17, // 0x235
+	 17, // 0x236
+	 17, // 0x237
+	 17, // 0x238
+	 17, // 0x239
+	 17, // 0x23a
+	 17, // 0x23b
+	 17, // 0x23c
+	 17, // 0x23d
+	 17, // 0x23e
+	 17, // 0x23f
+	 17, // 0x240
+	 17, // 0x241
+	 17, // 0x242
+	 17, // 0x243
+	 17, // 0x244
+	 17, // 0x245
+	 17, // 0x246
+	 17, // 0x247
+	 17, // 0x248
+	 17, // 0x249
+	 17, // 0x24a
+	 17, // 0x24b
+	 17, // 0x24c
+	 17, // 0x24d
+	 17, // 0x24e
+	 17, // 0x24f
+	 17, // 0x250
+	 17, // 0x251
+	 17, // 0x252
+	 17, // 0x253
+	 17, // 0x254
+	 17, // 0x255
+	 17, // 0x256
+	 17, // 0x257
+	 17, // 0x258
+	 17, // 0x259
+	 17, // 0x25a
+	 17, // 0x25b
+	 17, // 0x25c
+	 17, // 0x25d
+	 17, // 0x25e
+	 17, // 0x25f
+	 17, // 0x260
+	 17, // 0x261
+	 17, // 0x262
+	 17, // 0x263
+	 17, // 0x264
+	 17, // 0x265
+	 17, // 0x266
+	 17, // 0x267
+	 17, // 0x268
+	 17, // 0x269
+	 17, // 0x26a
+	 17, // 0x26b
+	 17, // 0x26c
+	 17, // 0x26d
+	 17, // 0x26e
+	 17, // 0x26f
+	 17, // 0x270
+	 17, // 0x271
+	 17, // 0x272
+	 17, // 0x273
+	 17, // 0x274
+	 17, // 0x275
+	 17, // 0x276
+	 17, // 0x277
+	 17, // 0x278
+	 17, // 0x279
+	 17, // 0x27a
+	 17, // 0x27b
+	 17, // 0x27c
+	 17, // 0x27d
+	 17, // 0x27e
+	 17, // 0x27f
+	 17, // 0x280
+	 17, // 0x281
+	 17, // 0x282
+	 17, // 0x283
+	 17, // 0x284
+	 17, // 0x285
+	 17, // 0x286
+	 17, // 0x287
+	 17, // 0x288
+	 17, // 0x289
+	 17, // 0x28a
+	 17, // 0x28b
+	 17, // 0x28c
+	 17, // 0x28d
+	 17, // 0x28e
+	 17, // 0x28f
+	 17, // 0x290
+	 17, // 0x291
+	 17, // 0x292
+	 17, // 0x293
+	 17, // 0x294
+	 17, // 0x295
+	 17, // 0x296
+	 17, // 0x297
+	 17, // 0x298
+	 17, // 0x299
+	 17, // 0x29a
+	 17, // 0x29b
+	 17, // 0x29c
+	 17, // 0x29d
+	 17, // 0x29e
+	 17, // 0x29f
+	 17, // 0x2a0
+	 17, // 0x2a1
+	 17, // 0x2a2
+	 17, // 0x2a3
+	 17, // 0x2a4
+	 17, // 0x2a5
+	 143, // 0x2a6
+	 17, // 0x2a7
+	 17, // 0x2a8
+	 17, // 0x2a9
+	 17, // 0x2aa
+	 17, // 0x2ab
+	 17, // 0x2ac
+	 17, // 0x2ad
+	 17, // 0x2ae
+	 17, // 0x2af
+	 17, // 0x2b0
+	 17, // 0x2b1
+	 17, // 0x2b2
+	 17, // 0x2b3
+	 17, // 0x2b4
+	 17, // 0x2b5
+	 17, // 0x2b6
+	 144, // 0x2b7
+	 145, // 0x2b8
+	 17, // 0x2b9
+	 17, // 0x2ba
+	 17, // 0x2bb
+	 17, // 0x2bc
+	 17, // 0x2bd
+	 17, // 0x2be
+	 17, // 0x2bf
+	 17, // 0x2c0
+	 17, // 0x2c1
+	 17, // 0x2c2
+	 17, // 0x2c3
+	 17, // 0x2c4
+	 17, // 0x2c5
+	 17, // 0x2c6
+	 17, // 0x2c7
+	 17, // 0x2c8
+	 17, // 0x2c9
+	 17, // 0x2ca
+	 17, // 0x2cb
+	 17, // 0x2cc
+	 17, // 0x2cd
+	 146, // 0x2ce
+	 17, // 0x2cf
+	 17, // 0x2d0
+	 17, // 0x2d1
+	 17, // 0x2d2
+	 17, // 0x2d3
+	 17, // 0x2d4
+	 17, // 0x2d5
+	 17, // 0x2d6
+	 17, // 0x2d7
+	 17, // 0x2d8
+	 17, // 0x2d9
+	 17, // 0x2da
+	 17, // 0x2db
+	 17, // 0x2dc
+	 17, // 0x2dd
+	 17, // 0x2de
+	 17, // 0x2df
+	 17, // 0x2e0
+	 17, // 0x2e1
+	 17, // 0x2e2
+	 17, // 0x2e3
+	 17, // 0x2e4
+	 17, // 0x2e5
+	 17, // 0x2e6
+	 17, // 0x2e7
+	 17, // 0x2e8
+	 17, // 0x2e9
+	 17, // 0x2ea
+	 147, // 0x2eb
+	 92, // 0x2ec
+	 92, // 0x2ed
+	 92, // 0x2ee
+	 92, // 0x2ef
+	 92, // 0x2f0
+	 92, // 0x2f1
+	 92, // 0x2f2
+	 92, // 0x2f3
+	 92, // 0x2f4
+	 92, // 0x2f5
+	 92, // 0x2f6
+	 92, // 0x2f7
+	 17, // 0x2f8
+	 17, // 0x2f9
+	 148, // 0x2fa
+	 92, // 0x2fb
+	 92, // 0x2fc
+	 92, // 0x2fd
+	 92, // 0x2fe
+	 92, // 0x2ff
+	 17, // 0x300
+	 17, // 0x301
+	 17, // 0x302
+	 17, // 0x303
+	 17, // 0x304
+	 17, // 0x305
+	 17, // 0x306
+	 17, // 0x307
+	 17, // 0x308
+	 17, // 0x309
+	 17, // 0x30a
+	 17, // 0x30b
+	 17, // 0x30c
+	 17, // 0x30d
+	 17, // 0x30e
+	 17, // 0x30f
+	 17, // 0x310
+	 17, // 0x311
+	 17, // 0x312
+	 149, // 0x313
+	 92, // 0x314
+	 92, // 0x315
+	 92, // 0x316
+	 92, // 0x317
+	 92, // 0x318
+	 92, // 0x319
+	 92, // 0x31a
+	 92, // 0x31b
+	 92, // 0x31c
+	 92, // 0x31d
+	 92, // 0x31e
+	 92, // 0x31f
+	 92, // 0x320
+	 92, // 0x321
+	 92, // 0x322
+	 92, // 0x323
+	 92, // 0x324
+	 92, // 0x325
+	 92, // 0x326
+	 92, // 0x327
+	 92, // 0x328
+	 92, // 0x329
+	 92, // 0x32a
+	 92, // 0x32b
+	 92, // 0x32c
+	 92, // 0x32d
+	 92, // 0x32e
+	 92, // 0x32f
+	 92, // 0x330
+	 92, // 0x331
+	 92, // 0x332
+	 92, // 0x333
+	 92, // 0x334
+	 92, // 0x335
+	 92, // 0x336
+	 92, // 0x337
+	 92, // 0x338
+	 92, // 0x339
+	 92, // 0x33a
+	 92, // 0x33b
+	 92, // 0x33c
+	 92, // 0x33d
+	 92, // 0x33e
+	 92, // 0x33f
+	 92, // 0x340
+	 92, // 0x341
+	 92, // 0x342
+	 92, // 0x343
+	 92, // 0x344
+	 92, // 0x345
+	 92, // 0x346
+	 92, // 0x347
+	 92, // 0x348
+	 92, // 0x349
+	 92, // 0x34a
+	 92, // 0x34b
+	 92, // 0x34c
+	 92, // 0x34d
+	 92, // 0x34e
+	 92, // 0x34f
+	 92, // 0x350
+	 92, // 0x351
+	 92, // 0x352
+	 92, // 0x353
+	 92, // 0x354
+	 92, // 0x355
+	 92, // 0x356
+	 92, // 0x357
+	 92, // 0x358
+	 92, // 0x359
+	 92, // 0x35a
+	 92, // 0x35b
+	 92, // 0x35c
+	 92, // 0x35d
+	 92, // 0x35e
+	 92, // 0x35f
+	 92, // 0x360
+	 92, // 0x361
+	 92, // 0x362
+	 92, // 0x363
+	 92, // 0x364
+	 92, // 0x365
+	 92, // 0x366
+	 92, // 0x367
+	 92, // 0x368
+	 92, // 0x369
+	 92, // 0x36a
+	 92, // 0x36b
+	 92, // 0x36c
+	 92, // 0x36d
+	 92, // 0x36e
+	 92, // 0x36f
+	 92, // 0x370
+	 92, // 0x371
+	 92, // 0x372
+	 92, // 0x373
+	 92, // 0x374
+	 92, // 0x375
+	 92, // 0x376
+	 92, // 0x377
+	 92, // 0x378
+	 92, // 0x379
+	 92, // 0x37a
+	 92, // 0x37b
+	 92, // 0x37c
+	 92, // 0x37d
+	 92, // 0x37e
+	 92, // 0x37f
+	 92, // 0x380
+	 92, // 0x381
+	 92, // 0x382
+	 92, // 0x383
+	 92, // 0x384
+	 92, // 0x385
+	 92, // 0x386
+	 92, // 0x387
+	 92, // 0x388
+	 92, // 0x389
+	 92, // 0x38a
+	 92, // 0x38b
+	 92, // 0x38c
+	 92, // 0x38d
+	 92, // 0x38e
+	 92, // 0x38f
+	 92, // 0x390
+	 92, // 0x391
+	 92, // 0x392
+	 92, // 0x393
+	 92, // 0x394
+	 92, // 0x395
+	 92, // 0x396
+	 92, // 0x397
+	 92, // 0x398
+	 92, // 0x399
+	 92, // 0x39a
+	 92, // 0x39b
+	 92, // 0x39c
+	 92, // 0x39d
+	 92, // 0x39e
+	 92, // 0x39f
+	 92, // 0x3a0
+	 92, // 0x3a1
+	 92, // 0x3a2
+	 92, // 0x3a3
+	 92, // 0x3a4
+	 92, // 0x3a5
+	 92, // 0x3a6
+	 92, // 0x3a7
+	 92, // 0x3a8
+	 92, // 0x3a9
+	 92, // 0x3aa
+	 92, // 0x3ab
+	 92, // 0x3ac
+	 92, // 0x3ad
+	 92, // 0x3ae
+	 92, // 0x3af
+	 92, // 0x3b0
+	 92, // 0x3b1
+	 92, // 0x3b2
+	 92, // 0x3b3
+	 92, // 0x3b4
+	 92, // 0x3b5
+	 92, // 0x3b6
+	 92, // 0x3b7
+	 92, // 0x3b8
+	 92, // 0x3b9
+	 92, // 0x3ba
+	 92, // 0x3bb
+	 92, // 0x3bc
+	 92, // 0x3bd
+	 92, // 0x3be
+	 92, // 0x3bf
+	 92, // 0x3c0
+	 92, // 0x3c1
+	 92, // 0x3c2
+	 92, // 0x3c3
+	 92, // 0x3c4
+	 92, // 0x3c5
+	 92, // 0x3c6
+	 92, // 0x3c7
+	 92, // 0x3c8
+	 92, // 0x3c9
+	 92, // 0x3ca
+	 92, // 0x3cb
+	 92, // 0x3cc
+	 92, // 0x3cd
+	 92, // 0x3ce
+	 92, // 0x3cf
+	 92, // 0x3d0
+	 92, // 0x3d1
+	 92, // 0x3d2
+	 92, // 0x3d3
+	 92, // 0x3d4
+	 92, // 0x3d5
+	 92, // 0x3d6
+	 92, // 0x3d7
+	 92, // 0x3d8
+	 92, // 0x3d9
+	 92, // 0x3da
+	 92, // 0x3db
+	 92, // 0x3dc
+	 92, // 0x3dd
+	 92, // 0x3de
+	 92, // 0x3df
+	 92, // 0x3e0
+	 92, // 0x3e1
+	 92, // 0x3e2
+	 92, // 0x3e3
+	 92, // 0x3e4
+	 92, // 0x3e5
+	 92, // 0x3e6
+	 92, // 0x3e7
+	 92, // 0x3e8
+	 92, // 0x3e9
+	 92, // 0x3ea
+	 92, // 0x3eb
+	 92, // 0x3ec
+	 92, // 0x3ed
+	 92, // 0x3ee
+	 92, // 0x3ef
+	 92, // 0x3f0
+	 92, // 0x3f1
+	 92, // 0x3f2
+	 92, // 0x3f3
+	 92, // 0x3f4
+	 92, // 0x3f5
+	 92, // 0x3f6
+	 92, // 0x3f7
+	 92, // 0x3f8
+	 92, // 0x3f9
+	 92, // 0x3fa
+	 92, // 0x3fb
+	 92, // 0x3fc
+	 92, // 0x3fd
+	 92, // 0x3fe
+	 92, // 0x3ff
+	 92, // 0x400
+	 92, // 0x401
+	 92, // 0x402
+	 92, // 0x403
+	 92, // 0x404
+	 92, // 0x405
+	 92, // 0x406
+	 92, // 0x407
+	 92, // 0x408
+	 92, // 0x409
+	 92, // 0x40a
+	 92, // 0x40b
+	 92, // 0x40c
+	 92, // 0x40d
+	 92, // 0x40e
+	 92, // 0x40f
+	 92, // 0x410
+	 92, // 0x411
+	 92, // 0x412
+	 92, // 0x413
+	 92, // 0x414
+	 92, // 0x415
+	 92, // 0x416
+	 92, // 0x417
+	 92, // 0x418
+	 92, // 0x419
+	 92, // 0x41a
+	 92, // 0x41b
+	 92, // 0x41c
+	 92, // 0x41d
+	 92, // 0x41e
+	 92, // 0x41f
+	 92, // 0x420
+	 92, // 0x421
+	 92, // 0x422
+	 92, // 0x423
+	 92, // 0x424
+	 92, // 0x425
+	 92, // 0x426
+	 92, // 0x427
+	 92, // 0x428
+	 92, // 0x429
+	 92, // 0x42a
+	 92, // 0x42b
+	 92, // 0x42c
+	 92, // 0x42d
+	 92, // 0x42e
+	 92, // 0x42f
+	 92, // 0x430
+	 92, // 0x431
+	 92, // 0x432
+	 92, // 0x433
+	 92, // 0x434
+	 92, // 0x435
+	 92, // 0x436
+	 92, // 0x437
+	 92, // 0x438
+	 92, // 0x439
+	 92, // 0x43a
+	 92, // 0x43b
+	 92, // 0x43c
+	 92, // 0x43d
+	 92, // 0x43e
+	 92, // 0x43f
+	 92, // 0x440
+	 92, // 0x441
+	 92, // 0x442
+	 92, // 0x443
+	 92, // 0x444
+	 92, // 0x445
+	 92, // 0x446
+	 92, // 0x447
+	 92, // 0x448
+	 92, // 0x449
+	 92, // 0x44a
+	 92, // 0x44b
+	 92, // 0x44c
+	 92, // 0x44d
+	 92, // 0x44e
+	 92, // 0x44f
+	 92, // 0x450
+	 92, // 0x451
+	 92, // 0x452
+	 92, // 0x453
+	 92, // 0x454
+	 92, // 0x455
+	 92, // 0x456
+	 92, // 0x457
+	 92, // 0x458
+	 92, // 0x459
+	 92, // 0x45a
+	 92, // 0x45b
+	 92, // 0x45c
+	 92, // 0x45d
+	 92, // 0x45e
+	 92, // 0x45f
+	 92, // 0x460
+	 92, // 0x461
+	 92, // 0x462
+	 92, // 0x463
+	 92, // 0x464
+	 92, // 0x465
+	 92, // 0x466
+	 92, // 0x467
+	 92, // 0x468
+	 92, // 0x469
+	 92, // 0x46a
+	 92, // 0x46b
+	 92, // 0x46c
+	 92, // 0x46d
+	 92, // 0x46e
+	 92, // 0x46f
+	 92, // 0x470
+	 92, // 0x471
+	 92, // 0x472
+	 92, // 0x473
+	 92, // 0x474
+	 92, // 0x475
+	 92, // 0x476
+	 92, // 0x477
+	 92, // 0x478
+	 92, // 0x479
+	 92, // 0x47a
+	 92, // 0x47b
+	 92, // 0x47c
+	 92, // 0x47d
+	 92, // 0x47e
+	 92, // 0x47f
+	 92, // 0x480
+	 92, // 0x481
+	 92, // 0x482
+	 92, // 0x483
+	 92, // 0x484
+	 92, // 0x485
+	 92, // 0x486
+	 92, // 0x487
+	 92, // 0x488
+	 92, // 0x489
+	 92, // 0x48a
+	 92, // 0x48b
+	 92, // 0x48c
+	 92, // 0x48d
+	 92, // 0x48e
+	 92, // 0x48f
+	 92, // 0x490
+	 92, // 0x491
+	 92, // 0x492
+	 92, // 0x493
+	 92, // 0x494
+	 92, // 0x495
+	 92, // 0x496
+	 92, // 0x497
+	 92, // 0x498
+	 92, // 0x499
+	 92, // 0x49a
+	 92, // 0x49b
+	 92, // 0x49c
+	 92, // 0x49d
+	 92, // 0x49e
+	 92, // 0x49f
+	 92, // 0x4a0
+	 92, // 0x4a1
+	 92, // 0x4a2
+	 92, // 0x4a3
+	 92, // 0x4a4
+	 92, // 0x4a5
+	 92, // 0x4a6
+	 92, // 0x4a7
+	 92, // 0x4a8
+	 92, // 0x4a9
+	 92, // 0x4aa
+	 92, // 0x4ab
+	 92, // 0x4ac
+	 92, // 0x4ad
+	 92, // 0x4ae
+	 92, // 0x4af
+	 92, // 0x4b0
+	 92, // 0x4b1
+	 92, // 0x4b2
+	 92, // 0x4b3
+	 92, // 0x4b4
+	 92, // 0x4b5
+	 92, // 0x4b6
+	 92, // 0x4b7
+	 92, // 0x4b8
+	 92, // 0x4b9
+	 92, // 0x4ba
+	 92, // 0x4bb
+	 92, // 0x4bc
+	 92, // 0x4bd
+	 92, // 0x4be
+	 92, // 0x4bf
+	 92, // 0x4c0
+	 92, // 0x4c1
+	 92, // 0x4c2
+	 92, // 0x4c3
+	 92, // 0x4c4
+	 92, // 0x4c5
+	 92, // 0x4c6
+	 92, // 0x4c7
+	 92, // 0x4c8
+	 92, // 0x4c9
+	 92, // 0x4ca
+	 92, // 0x4cb
+	 92, // 0x4cc
+	 92, // 0x4cd
+	 92, // 0x4ce
+	 92, // 0x4cf
+	 92, // 0x4d0
+	 92, // 0x4d1
+	 92, // 0x4d2
+	 92, // 0x4d3
+	 92, // 0x4d4
+	 92, // 0x4d5
+	 92, // 0x4d6
+	 92, // 0x4d7
+	 92, // 0x4d8
+	 92, // 0x4d9
+	 92, // 0x4da
+	 92, // 0x4db
+	 92, // 0x4dc
+	 92, // 0x4dd
+	 92, // 0x4de
+	 92, // 0x4df
+	 92, // 0x4e0
+	 92, // 0x4e1
+	 92, // 0x4e2
+	 92, // 0x4e3
+	 92, // 0x4e4
+	 92, // 0x4e5
+	 92, // 0x4e6
+	 92, // 0x4e7
+	 92, // 0x4e8
+	 92, // 0x4e9
+	 92, // 0x4ea
+	 92, // 0x4eb
+	 92, // 0x4ec
+	 92, // 0x4ed
+	 92, // 0x4ee
+	 92, // 0x4ef
+	 92, // 0x4f0
+	 92, // 0x4f1
+	 92, // 0x4f2
+	 92, // 0x4f3
+	 92, // 0x4f4
+	 92, // 0x4f5
+	 92, // 0x4f6
+	 92, // 0x4f7
+	 92, // 0x4f8
+	 92, // 0x4f9
+	 92, // 0x4fa
+	 92, // 0x4fb
+	 92, // 0x4fc
+	 92, // 0x4fd
+	 92, // 0x4fe
+	 92, // 0x4ff
+	 92, // 0x500
+	 92, // 0x501
+	 92, // 0x502
+	 92, // 0x503
+	 92, // 0x504
+	 92, // 0x505
+	 92, // 0x506
+	 92, // 0x507
+	 92, // 0x508
+	 92, // 0x509
+	 92, // 0x50a
+	 92, // 0x50b
+	 92, // 0x50c
+	 92, // 0x50d
+	 92, // 0x50e
+	 92, // 0x50f
+	 92, // 0x510
+	 92, // 0x511
+	 92, // 0x512
+	 92, // 0x513
+	 92, // 0x514
+	 92, // 0x515
+	 92, // 0x516
+	 92, // 0x517
+	 92, // 0x518
+	 92, // 0x519
+	 92, // 0x51a
+	 92, // 0x51b
+	 92, // 0x51c
+	 92, // 0x51d
+	 92, // 0x51e
+	 92, // 0x51f
+	 92, // 0x520
+	 92, // 0x521
+	 92, // 0x522
+	 92, // 0x523
+	 92, // 0x524
+	 92, // 0x525
+	 92, // 0x526
+	 92, // 0x527
+	 92, // 0x528
+	 92, // 0x529
+	 92, // 0x52a
+	 92, // 0x52b
+	 92, // 0x52c
+	 92, // 0x52d
+	 92, // 0x52e
+	 92, // 0x52f
+	 92, // 0x530
+	 92, // 0x531
+	 92, // 0x532
+	 92, // 0x533
+	 92, // 0x534
+	 92, // 0x535
+	 92, // 0x536
+	 92, // 0x537
+	 92, // 0x538
+	 92, // 0x539
+	 92, // 0x53a
+	 92, // 0x53b
+	 92, // 0x53c
+	 92, // 0x53d
+	 92, // 0x53e
+	 92, // 0x53f
+	 92, // 0x540
+	 92, // 0x541
+	 92, // 0x542
+	 92, // 0x543
+	 92, // 0x544
+	 92, // 0x545
+	 92, // 0x546
+	 92, // 0x547
+	 92, // 0x548
+	 92, // 0x549
+	 92, // 0x54a
+	 92, // 0x54b
+	 92, // 0x54c
+	 92, // 0x54d
+	 92, // 0x54e
+	 92, // 0x54f
+	 92, // 0x550
+	 92, // 0x551
+	 92, // 0x552
+	 92, // 0x553
+	 92, // 0x554
+	 92, // 0x555
+	 92, // 0x556
+	 92, // 0x557
+	 92, // 0x558
+	 92, // 0x559
+	 92, // 0x55a
+	 92, // 0x55b
+	 92, // 0x55c
+	 92, // 0x55d
+	 92, // 0x55e
+	 92, // 0x55f
+	 92, // 0x560
+	 92, // 0x561
+	 92, // 0x562
+	 92, // 0x563
+	 92, // 0x564
+	 92, // 0x565
+	 92, // 0x566
+	 92, // 0x567
+	 92, // 0x568
+	 92, // 0x569
+	 92, // 0x56a
+	 92, // 0x56b
+	 92, // 0x56c
+	 92, // 0x56d
+	 92, // 0x56e
+	 92, // 0x56f
+	 92, // 0x570
+	 92, // 0x571
+	 92, // 0x572
+	 92, // 0x573
+	 92, // 0x574
+	 92, // 0x575
+	 92, // 0x576
+	 92, // 0x577
+	 92, // 0x578
+	 92, // 0x579
+	 92, // 0x57a
+	 92, // 0x57b
+	 92, // 0x57c
+	 92, // 0x57d
+	 92, // 0x57e
+	 92, // 0x57f
+	 92, // 0x580
+	 92, // 0x581
+	 92, // 0x582
+	 92, // 0x583
+	 92, // 0x584
+	 92, // 0x585
+	 92, // 0x586
+	 92, // 0x587
+	 92, // 0x588
+	 92, // 0x589
+	 92, // 0x58a
+	 92, // 0x58b
+	 92, // 0x58c
+	 92, // 0x58d
+	 92, // 0x58e
+	 92, // 0x58f
+	 92, // 0x590
+	 92, // 0x591
+	 92, // 0x592
+	 92, // 0x593
+	 92, // 0x594
+	 92, // 0x595
+	 92, // 0x596
+	 92, // 0x597
+	 92, // 0x598
+	 92, // 0x599
+	 92, // 0x59a
+	 92, // 0x59b
+	 92, // 0x59c
+	 92, // 0x59d
+	 92, // 0x59e
+	 92, // 0x59f
+	 92, // 0x5a0
+	 92, // 0x5a1
+	 92, // 0x5a2
+	 92, // 0x5a3
+	 92, // 0x5a4
+	 92, // 0x5a5
+	 92, // 0x5a6
+	 92, // 0x5a7
+	 92, // 0x5a8
+	 92, // 0x5a9
+	 92, // 0x5aa
+	 92, // 0x5ab
+	 92, // 0x5ac
+	 92, // 0x5ad
+	 92, // 0x5ae
+	 92, // 0x5af
+	 92, // 0x5b0
+	 92, // 0x5b1
+	 92, // 0x5b2
+	 92, // 0x5b3
+	 92, // 0x5b4
+	 92, // 0x5b5
+	 92, // 0x5b6
+	 92, // 0x5b7
+	 92, // 0x5b8
+	 92, // 0x5b9
+	 92, // 0x5ba
+	 92, // 0x5bb
+	 92, // 0x5bc
+	 92, // 0x5bd
+	 92, // 0x5be
+	 92, // 0x5bf
+	 92, // 0x5c0
+	 92, // 0x5c1
+	 92, // 0x5c2
+	 92, // 0x5c3
+	 92, // 0x5c4
+	 92, // 0x5c5
+	 92, // 0x5c6
+	 92, // 0x5c7
+	 92, // 0x5c8
+	 92, // 0x5c9
+	 92, // 0x5ca
+	 92, // 0x5cb
+	 92, // 0x5cc
+	 92, // 0x5cd
+	 92, // 0x5ce
+	 92, // 0x5cf
+	 92, // 0x5d0
+	 92, // 0x5d1
+	 92, // 0x5d2
+	 92, // 0x5d3
+	 92, // 0x5d4
+	 92, // 0x5d5
+	 92, // 0x5d6
+	 92, // 0x5d7
+	 92, // 0x5d8
+	 92, // 0x5d9
+	 92, // 0x5da
+	 92, // 0x5db
+	 92, // 0x5dc
+	 92, // 0x5dd
+	 92, // 0x5de
+	 92, // 0x5df
+	 92, // 0x5e0
+	 92, // 0x5e1
+	 92, // 0x5e2
+	 92, // 0x5e3
+	 92, // 0x5e4
+	 92, // 0x5e5
+	 92, // 0x5e6
+	 92, // 0x5e7
+	 92, // 0x5e8
+	 92, // 0x5e9
+	 92, // 0x5ea
+	 92, // 0x5eb
+	 92, // 0x5ec
+	 92, // 0x5ed
+	 92, // 0x5ee
+	 92, // 0x5ef
+	 92, // 0x5f0
+	 92, // 0x5f1
+	 92, // 0x5f2
+	 92, // 0x5f3
+	 92, // 0x5f4
+	 92, // 0x5f5
+	 92, // 0x5f6
+	 92, // 0x5f7
+	 92, // 0x5f8
+	 92, // 0x5f9
+	 92, // 0x5fa
+	 92, // 0x5fb
+	 92, // 0x5fc
+	 92, // 0x5fd
+	 92, // 0x5fe
+	 92, // 0x5ff
+	 92, // 0x600
+	 92, // 0x601
+	 92, // 0x602
+	 92, // 0x603
+	 92, // 0x604
+	 92, // 0x605
+	 92, // 0x606
+	 92, // 0x607
+	 92, // 0x608
+	 92, // 0x609
+	 92, // 0x60a
+	 92, // 0x60b
+	 92, // 0x60c
+	 92, // 0x60d
+	 92, // 0x60e
+	 92, // 0x60f
+	 92, // 0x610
+	 92, // 0x611
+	 92, // 0x612
+	 92, // 0x613
+	 92, // 0x614
+	 92, // 0x615
+	 92, // 0x616
+	 92, // 0x617
+	 92, // 0x618
+	 92, // 0x619
+	 92, // 0x61a
+	 92, // 0x61b
+	 92, // 0x61c
+	 92, // 0x61d
+	 92, // 0x61e
+	 92, // 0x61f
+	 92, // 0x620
+	 92, // 0x621
+	 92, // 0x622
+	 92, // 0x623
+	 92, // 0x624
+	 92, // 0x625
+	 92, // 0x626
+	 92, // 0x627
+	 92, // 0x628
+	 92, // 0x629
+	 92, // 0x62a
+	 92, // 0x62b
+	 92, // 0x62c
+	 92, // 0x62d
+	 92, // 0x62e
+	 92, // 0x62f
+	 92, // 0x630
+	 92, // 0x631
+	 92, // 0x632
+	 92, // 0x633
+	 92, // 0x634
+	 92, // 0x635
+	 92, // 0x636
+	 92, // 0x637
+	 92, // 0x638
+	 92, // 0x639
+	 92, // 0x63a
+	 92, // 0x63b
+	 92, // 0x63c
+	 92, // 0x63d
+	 92, // 0x63e
+	 92, // 0x63f
+	 92, // 0x640
+	 92, // 0x641
+	 92, // 0x642
+	 92, // 0x643
+	 92, // 0x644
+	 92, // 0x645
+	 92, // 0x646
+	 92, // 0x647
+	 92, // 0x648
+	 92, // 0x649
+	 92, // 0x64a
+	 92, // 0x64b
+	 92, // 0x64c
+	 92, // 0x64d
+	 92, // 0x64e
+	 92, // 0x64f
+	 92, // 0x650
+	 92, // 0x651
+	 92, // 0x652
+	 92, // 0x653
+	 92, // 0x654
+	 92, // 0x655
+	 92, // 0x656
+	 92, // 0x657
+	 92, // 0x658
+	 92, // 0x659
+	 92, // 0x65a
+	 92, // 0x65b
+	 92, // 0x65c
+	 92, // 0x65d
+	 92, // 0x65e
+	 92, // 0x65f
+	 92, // 0x660
+	 92, // 0x661
+	 92, // 0x662
+	 92, // 0x663
+	 92, // 0x664
+	 92, // 0x665
+	 92, // 0x666
+	 92, // 0x667
+	 92, // 0x668
+	 92, // 0x669
+	 92, // 0x66a
+	 92, // 0x66b
+	 92, // 0x66c
+	 92, // 0x66d
+	 92, // 0x66e
+	 92, // 0x66f
+	 92, // 0x670
+	 92, // 0x671
+	 92, // 0x672
+	 92, // 0x673
+	 92, // 0x674
+	 92, // 0x675
+	 92, // 0x676
+	 92, // 0x677
+	 92, // 0x678
+	 92, // 0x679
+	 92, // 0x67a
+	 92, // 0x67b
+	 92, // 0x67c
+	 92, // 0x67d
+	 92, // 0x67e
+	 92, // 0x67f
+	 92, // 0x680
+	 92, // 0x681
+	 92, // 0x682
+	 92, // 0x683
+	 92, // 0x684
+	 92, // 0x685
+	 92, // 0x686
+	 92, // 0x687
+	 92, // 0x688
+	 92, // 0x689
+	 92, // 0x68a
+	 92, // 0x68b
+	 92, // 0x68c
+	 92, // 0x68d
+	 92, // 0x68e
+	 92, // 0x68f
+	 92, // 0x690
+	 92, // 0x691
+	 92, // 0x692
+	 92, // 0x693
+	 92, // 0x694
+	 92, // 0x695
+	 92, // 0x696
+	 92, // 0x697
+	 92, // 0x698
+	 92, // 0x699
+	 92, // 0x69a
+	 92, // 0x69b
+	 92, // 0x69c
+	 92, // 0x69d
+	 92, // 0x69e
+	 92, // 0x69f
+	 92, // 0x6a0
+	 92, // 0x6a1
+	 92, // 0x6a2
+	 92, // 0x6a3
+	 92, // 0x6a4
+	 92, // 0x6a5
+	 92, // 0x6a6
+	 92, // 0x6a7
+	 92, // 0x6a8
+	 92, // 0x6a9
+	 92, // 0x6aa
+	 92, // 0x6ab
+	 92, // 0x6ac
+	 92, // 0x6ad
+	 92, // 0x6ae
+	 92, // 0x6af
+	 92, // 0x6b0
+	 92, // 0x6b1
+	 92, // 0x6b2
+	 92, // 0x6b3
+	 92, // 0x6b4
+	 92, // 0x6b5
+	 92, // 0x6b6
+	 92, // 0x6b7
+	 92, // 0x6b8
+	 92, // 0x6b9
+	 92, // 0x6ba
+	 92, // 0x6bb
+	 92, // 0x6bc
+	 92, // 0x6bd
+	 92, // 0x6be
+	 92, // 0x6bf
+	 92, // 0x6c0
+	 92, // 0x6c1
+	 92, // 0x6c2
+	 92, // 0x6c3
+	 92, // 0x6c4
+	 92, // 0x6c5
+	 92, // 0x6c6
+	 92, // 0x6c7
+	 92, // 0x6c8
+	 92, // 0x6c9
+	 92, // 0x6ca
+	 92, // 0x6cb
+	 92, // 0x6cc
+	 92, // 0x6cd
+	 92, // 0x6ce
+	 92, // 0x6cf
+	 92, // 0x6d0
+	 92, // 0x6d1
+	 92, // 0x6d2
+	 92, // 0x6d3
+	 92, // 0x6d4
+	 92, // 0x6d5
+	 92, // 0x6d6
+	 92, // 0x6d7
+	 92, // 0x6d8
+	 92, // 0x6d9
+	 92, // 0x6da
+	 92, // 0x6db
+	 92, // 0x6dc
+	 92, // 0x6dd
+	 92, // 0x6de
+	 92, // 0x6df
+	 92, // 0x6e0
+	 92, // 0x6e1
+	 92, // 0x6e2
+	 92, // 0x6e3
+	 92, // 0x6e4
+	 92, // 0x6e5
+	 92, // 0x6e6
+	 92, // 0x6e7
+	 92, // 0x6e8
+	 92, // 0x6e9
+	 92, // 0x6ea
+	 92, // 0x6eb
+	 92, // 0x6ec
+	 92, // 0x6ed
+	 92, // 0x6ee
+	 92, // 0x6ef
+	 92, // 0x6f0
+	 92, // 0x6f1
+	 92, // 0x6f2
+	 92, // 0x6f3
+	 92, // 0x6f4
+	 92, // 0x6f5
+	 92, // 0x6f6
+	 92, // 0x6f7
+	 92, // 0x6f8
+	 92, // 0x6f9
+	 92, // 0x6fa
+	 92, // 0x6fb
+	 92, // 0x6fc
+	 92, // 0x6fd
+	 92, // 0x6fe
+	 92, // 0x6ff
+	 92, // 0x700
+	 92, // 0x701
+	 92, // 0x702
+	 92, // 0x703
+	 92, // 0x704
+	 92, // 0x705
+	 92, // 0x706
+	 92, // 0x707
+	 92, // 0x708
+	 92, // 0x709
+	 92, // 0x70a
+	 92, // 0x70b
+	 92, // 0x70c
+	 92, // 0x70d
+	 92, // 0x70e
+	 92, // 0x70f
+	 92, // 0x710
+	 92, // 0x711
+	 92, // 0x712
+	 92, // 0x713
+	 92, // 0x714
+	 92, // 0x715
+	 92, // 0x716
+	 92, // 0x717
+	 92, // 0x718
+	 92, // 0x719
+	 92, // 0x71a
+	 92, // 0x71b
+	 92, // 0x71c
+	 92, // 0x71d
+	 92, // 0x71e
+	 92, // 0x71f
+	 92, // 0x720
+	 92, // 0x721
+	 92, // 0x722
+	 92, // 0x723
+	 92, // 0x724
+	 92, // 0x725
+	 92, // 0x726
+	 92, // 0x727
+	 92, // 0x728
+	 92, // 0x729
+	 92, // 0x72a
+	 92, // 0x72b
+	 92, // 0x72c
+	 92, // 0x72d
+	 92, // 0x72e
+	 92, // 0x72f
+	 92, // 0x730
+	 92, // 0x731
+	 92, // 0x732
+	 92, // 0x733
+	 92, // 0x734
+	 92, // 0x735
+	 92, // 0x736
+	 92, // 0x737
+	 92, // 0x738
+	 92, // 0x739
+	 92, // 0x73a
+	 92, // 0x73b
+	 92, // 0x73c
+	 92, // 0x73d
+	 92, // 0x73e
+	 92, // 0x73f
+	 92, // 0x740
+	 92, // 0x741
+	 92, // 0x742
+	 92, // 0x743
+	 92, // 0x744
+	 92, // 0x745
+	 92, // 0x746
+	 92, // 0x747
+	 92, // 0x748
+	 92, // 0x749
+	 92, // 0x74a
+	 92, // 0x74b
+	 92, // 0x74c
+	 92, // 0x74d
+	 92, // 0x74e
+	 92, // 0x74f
+	 92, // 0x750
+	 92, // 0x751
+	 92, // 0x752
+	 92, // 0x753
+	 92, // 0x754
+	 92, // 0x755
+	 92, // 0x756
+	 92, // 0x757
+	 92, // 0x758
+	 92, // 0x759
+	 92, // 0x75a
+	 92, // 0x75b
+	 92, // 0x75c
+	 92, // 0x75d
+	 92, // 0x75e
+	 92, // 0x75f
+	 92, // 0x760
+	 92, // 0x761
+	 92, // 0x762
+	 92, // 0x763
+	 92, // 0x764
+	 92, // 0x765
+	 92, // 0x766
+	 92, // 0x767
+	 92, // 0x768
+	 92, // 0x769
+	 92, // 0x76a
+	 92, // 0x76b
+	 92, // 0x76c
+	 92, // 0x76d
+	 92, // 0x76e
+	 92, // 0x76f
+	 92, // 0x770
+	 92, // 0x771
+	 92, // 0x772
+	 92, // 0x773
+	 92, // 0x774
+	 92, // 0x775
+	 92, // 0x776
+	 92, // 0x777
+	 92, // 0x778
+	 92, // 0x779
+	 92, // 0x77a
+	 92, // 0x77b
+	 92, // 0x77c
+	 92, // 0x77d
+	 92, // 0x77e
+	 92, // 0x77f
+	 92, // 0x780
+	 92, // 0x781
+	 92, // 0x782
+	 92, // 0x783
+	 92, // 0x784
+	 92, // 0x785
+	 92, // 0x786
+	 92, // 0x787
+	 92, // 0x788
+	 92, // 0x789
+	 92, // 0x78a
+	 92, // 0x78b
+	 92, // 0x78c
+	 92, // 0x78d
+	 92, // 0x78e
+	 92, // 0x78f
+	 92, // 0x790
+	 92, // 0x791
+	 92, // 0x792
+	 92, // 0x793
+	 92, // 0x794
+	 92, // 0x795
+	 92, // 0x796
+	 92, // 0x797
+	 92, // 0x798
+	 92, // 0x799
+	 92, // 0x79a
+	 92, // 0x79b
+	 92, // 0x79c
+	 92, // 0x79d
+	 92, // 0x79e
+	 92, // 0x79f
+	 92, // 0x7a0
+	 92, // 0x7a1
+	 92, // 0x7a2
+	 92, // 0x7a3
+	 92, // 0x7a4
+	 92, // 0x7a5
+	 92, // 0x7a6
+	 92, // 0x7a7
+	 92, // 0x7a8
+	 92, // 0x7a9
+	 92, // 0x7aa
+	 92, // 0x7ab
+	 92, // 0x7ac
+	 92, // 0x7ad
+	 92, // 0x7ae
+	 92, // 0x7af
+	 92, // 0x7b0
+	 92, // 0x7b1
+	 92, // 0x7b2
+	 92, // 0x7b3
+	 92, // 0x7b4
+	 92, // 0x7b5
+	 92, // 0x7b6
+	 92, // 0x7b7
+	 92, // 0x7b8
+	 92, // 0x7b9
+	 92, // 0x7ba
+	 92, // 0x7bb
+	 92, // 0x7bc
+	 92, // 0x7bd
+	 92, // 0x7be
+	 92, // 0x7bf
+	 92, // 0x7c0
+	 92, // 0x7c1
+	 92, // 0x7c2
+	 92, // 0x7c3
+	 92, // 0x7c4
+	 92, // 0x7c5
+	 92, // 0x7c6
+	 92, // 0x7c7
+	 92, // 0x7c8
+	 92, // 0x7c9
+	 92, // 0x7ca
+	 92, // 0x7cb
+	 92, // 0x7cc
+	 92, // 0x7cd
+	 92, // 0x7ce
+	 92, // 0x7cf
+	 92, // 0x7d0
+	 92, // 0x7d1
+	 92, // 0x7d2
+	 92, // 0x7d3
+	 92, // 0x7d4
+	 92, // 0x7d5
+	 92, // 0x7d6
+	 92, // 0x7d7
+	 92, // 0x7d8
+	 92, // 0x7d9
+	 92, // 0x7da
+	 92, // 0x7db
+	 92, // 0x7dc
+	 92, // 0x7dd
+	 92, // 0x7de
+	 92, // 0x7df
+	 92, // 0x7e0
+	 92, // 0x7e1
+	 92, // 0x7e2
+	 92, // 0x7e3
+	 92, // 0x7e4
+	 92, // 0x7e5
+	 92, // 0x7e6
+	 92, // 0x7e7
+	 92, // 0x7e8
+	 92, // 0x7e9
+	 92, // 0x7ea
+	 92, // 0x7eb
+	 92, // 0x7ec
+	 92, // 0x7ed
+	 92, // 0x7ee
+	 92, // 0x7ef
+	 92, // 0x7f0
+	 92, // 0x7f1
+	 92, // 0x7f2
+	 92, // 0x7f3
+	 92, // 0x7f4
+	 92, // 0x7f5
+	 92, // 0x7f6
+	 92, // 0x7f7
+	 92, // 0x7f8
+	 92, // 0x7f9
+	 92, // 0x7fa
+	 92, // 0x7fb
+	 92, // 0x7fc
+	 92, // 0x7fd
+	 92, // 0x7fe
+	 92, // 0x7ff
+	 92, // 0x800
+	 92, // 0x801
+	 92, // 0x802
+	 92, // 0x803
+	 92, // 0x804
+	 92, // 0x805
+	 92, // 0x806
+	 92, // 0x807
+	 92, // 0x808
+	 92, // 0x809
+	 92, // 0x80a
+	 92, // 0x80b
+	 92, // 0x80c
+	 92, // 0x80d
+	 92, // 0x80e
+	 92, // 0x80f
+	 92, // 0x810
+	 92, // 0x811
+	 92, // 0x812
+	 92, // 0x813
+	 92, // 0x814
+	 92, // 0x815
+	 92, // 0x816
+	 92, // 0x817
+	 92, // 0x818
+	 92, // 0x819
+	 92, // 0x81a
+	 92, // 0x81b
+	 92, // 0x81c
+	 92, // 0x81d
+	 92, // 0x81e
+	 92, // 0x81f
+	 92, // 0x820
+	 92, // 0x821
+	 92, // 0x822
+	 92, // 0x823
+	 92, // 0x824
+	 92, // 0x825
+	 92, // 0x826
+	 92, // 0x827
+	 92, // 0x828
+	 92, // 0x829
+	 92, // 0x82a
+	 92, // 0x82b
+	 92, // 0x82c
+	 92, // 0x82d
+	 92, // 0x82e
+	 92, // 0x82f
+	 92, // 0x830
+	 92, // 0x831
+	 92, // 0x832
+	 92, // 0x833
+	 92, // 0x834
+	 92, // 0x835
+	 92, // 0x836
+	 92, // 0x837
+	 92, // 0x838
+	 92, // 0x839
+	 92, // 0x83a
+	 92, // 0x83b
+	 92, // 0x83c
+	 92, // 0x83d
+	 92, // 0x83e
+	 92, // 0x83f
+	 92, // 0x840
+	 92, // 0x841
+	 92, // 0x842
+	 92, // 0x843
+	 92, // 0x844
+	 92, // 0x845
+	 92, // 0x846
+	 92, // 0x847
+	 92, // 0x848
+	 92, // 0x849
+	 92, // 0x84a
+	 92, // 0x84b
+	 92, // 0x84c
+	 92, // 0x84d
+	 92, // 0x84e
+	 92, // 0x84f
+	 92, // 0x850
+	 92, // 0x851
+	 92, // 0x852
+	 92, // 0x853
+	 92, // 0x854
+	 92, // 0x855
+	 92, // 0x856
+	 92, // 0x857
+	 92, // 0x858
+	 92, // 0x859
+	 92, // 0x85a
+	 92, // 0x85b
+	 92, // 0x85c
+	 92, // 0x85d
+	 92, // 0x85e
+	 92, // 0x85f
+	 92, // 0x860
+	 92, // 0x861
+	 92, // 0x862
+	 92, // 0x863
+	 92, // 0x864
+	 92, // 0x865
+	 92, // 0x866
+	 92, // 0x867
+	 92, // 0x868
+	 92, // 0x869
+	 92, // 0x86a
+	 92, // 0x86b
+	 92, // 0x86c
+	 92, // 0x86d
+	 92, // 0x86e
+	 92, // 0x86f
+	 92, // 0x870
+	 92, // 0x871
+	 92, // 0x872
+	 92, // 0x873
+	 92, // 0x874
+	 92, // 0x875
+	 92, // 0x876
+	 92, // 0x877
+	 92, // 0x878
+	 92, // 0x879
+	 92, // 0x87a
+	 92, // 0x87b
+	 92, // 0x87c
+	 92, // 0x87d
+	 92, // 0x87e
+	 92, // 0x87f
+	 92, // 0x880
+	 92, // 0x881
+	 92, // 0x882
+	 92, // 0x883
+	 92, // 0x884
+	 92, // 0x885
+	 92, // 0x886
+	 92, // 0x887
+	 92, // 0x888
+	 92, // 0x889
+	 92, // 0x88a
+	 92, // 0x88b
+	 92, // 0x88c
+	 92, // 0x88d
+	 92, // 0x88e
+	 92, // 0x88f
+	 92, // 0x890
+	 92, // 0x891
+	 92, // 0x892
+	 92, // 0x893
+	 92, // 0x894
+	 92, // 0x895
+	 92, // 0x896
+	 92, // 0x897
+	 92, // 0x898
+	 92, // 0x899
+	 92, // 0x89a
+	 92, // 0x89b
+	 92, // 0x89c
+	 92, // 0x89d
+	 92, // 0x89e
+	 92, // 0x89f
+	 92, // 0x8a0
+	 92, // 0x8a1
+	 92, // 0x8a2
+	 92, // 0x8a3
+	 92, // 0x8a4
+	 92, // 0x8a5
+	 92, // 0x8a6
+	 92, // 0x8a7
+	 92, // 0x8a8
+	 92, // 0x8a9
+	 92, // 0x8aa
+	 92, // 0x8ab
+	 92, // 0x8ac
+	 92, // 0x8ad
+	 92, // 0x8ae
+	 92, // 0x8af
+	 92, // 0x8b0
+	 92, // 0x8b1
+	 92, // 0x8b2
+	 92, // 0x8b3
+	 92, // 0x8b4
+	 92, // 0x8b5
+	 92, // 0x8b6
+	 92, // 0x8b7
+	 92, // 0x8b8
+	 92, // 0x8b9
+	 92, // 0x8ba
+	 92, // 0x8bb
+	 92, // 0x8bc
+	 92, // 0x8bd
+	 92, // 0x8be
+	 92, // 0x8bf
+	 92, // 0x8c0
+	 92, // 0x8c1
+	 92, // 0x8c2
+	 92, // 0x8c3
+	 92, // 0x8c4
+	 92, // 0x8c5
+	 92, // 0x8c6
+	 92, // 0x8c7
+	 92, // 0x8c8
+	 92, // 0x8c9
+	 92, // 0x8ca
+	 92, // 0x8cb
+	 92, // 0x8cc
+	 92, // 0x8cd
+	 92, // 0x8ce
+	 92, // 0x8cf
+	 92, // 0x8d0
+	 92, // 0x8d1
+	 92, // 0x8d2
+	 92, // 0x8d3
+	 92, // 0x8d4
+	 92, // 0x8d5
+	 92, // 0x8d6
+	 92, // 0x8d7
+	 92, // 0x8d8
+	 92, // 0x8d9
+	 92, // 0x8da
+	 92, // 0x8db
+	 92, // 0x8dc
+	 92, // 0x8dd
+	 92, // 0x8de
+	 92, // 0x8df
+	 92, // 0x8e0
+	 92, // 0x8e1
+	 92, // 0x8e2
+	 92, // 0x8e3
+	 92, // 0x8e4
+	 92, // 0x8e5
+	 92, // 0x8e6
+	 92, // 0x8e7
+	 92, // 0x8e8
+	 92, // 0x8e9
+	 92, // 0x8ea
+	 92, // 0x8eb
+	 92, // 0x8ec
+	 92, // 0x8ed
+	 92, // 0x8ee
+	 92, // 0x8ef
+	 92, // 0x8f0
+	 92, // 0x8f1
+	 92, // 0x8f2
+	 92, // 0x8f3
+	 92, // 0x8f4
+	 92, // 0x8f5
+	 92, // 0x8f6
+	 92, // 0x8f7
+	 92, // 0x8f8
+	 92, // 0x8f9
+	 92, // 0x8fa
+	 92, // 0x8fb
+	 92, // 0x8fc
+	 92, // 0x8fd
+	 92, // 0x8fe
+	 92, // 0x8ff
+	 92, // 0x900
+	 92, // 0x901
+	 92, // 0x902
+	 92, // 0x903
+	 92, // 0x904
+	 92, // 0x905
+	 92, // 0x906
+	 92, // 0x907
+	 92, // 0x908
+	 92, // 0x909
+	 92, // 0x90a
+	 92, // 0x90b
+	 92, // 0x90c
+	 92, // 0x90d
+	 92, // 0x90e
+	 92, // 0x90f
+	 92, // 0x910
+	 92, // 0x911
+	 92, // 0x912
+	 92, // 0x913
+	 92, // 0x914
+	 92, // 0x915
+	 92, // 0x916
+	 92, // 0x917
+	 92, // 0x918
+	 92, // 0x919
+	 92, // 0x91a
+	 92, // 0x91b
+	 92, // 0x91c
+	 92, // 0x91d
+	 92, // 0x91e
+	 92, // 0x91f
+	 92, // 0x920
+	 92, // 0x921
+	 92, // 0x922
+	 92, // 0x923
+	 92, // 0x924
+	 92, // 0x925
+	 92, // 0x926
+	 92, // 0x927
+	 92, // 0x928
+	 92, // 0x929
+	 92, // 0x92a
+	 92, // 0x92b
+	 92, // 0x92c
+	 92, // 0x92d
+	 92, // 0x92e
+	 92, // 0x92f
+	 92, // 0x930
+	 92, // 0x931
+	 92, // 0x932
+	 92, // 0x933
+	 92, // 0x934
+	 92, // 0x935
+	 92, // 0x936
+	 92, // 0x937
+	 92, // 0x938
+	 92, // 0x939
+	 92, // 0x93a
+	 92, // 0x93b
+	 92, // 0x93c
+	 92, // 0x93d
+	 92, // 0x93e
+	 92, // 0x93f
+	 92, // 0x940
+	 92, // 0x941
+	 92, // 0x942
+	 92, // 0x943
+	 92, // 0x944
+	 92, // 0x945
+	 92, // 0x946
+	 92, // 0x947
+	 92, // 0x948
+	 92, // 0x949
+	 92, // 0x94a
+	 92, // 0x94b
+	 92, // 0x94c
+	 92, // 0x94d
+	 92, // 0x94e
+	 92, // 0x94f
+	 92, // 0x950
+	 92, // 0x951
+	 92, // 0x952
+	 92, // 0x953
+	 92, // 0x954
+	 92, // 0x955
+	 92, // 0x956
+	 92, // 0x957
+	 92, // 0x958
+	 92, // 0x959
+	 92, // 0x95a
+	 92, // 0x95b
+	 92, // 0x95c
+	 92, // 0x95d
+	 92, // 0x95e
+	 92, // 0x95f
+	 92, // 0x960
+	 92, // 0x961
+	 92, // 0x962
+	 92, // 0x963
+	 92, // 0x964
+	 92, // 0x965
+	 92, // 0x966
+	 92, // 0x967
+	 92, // 0x968
+	 92, // 0x969
+	 92, // 0x96a
+	 92, // 0x96b
+	 92, // 0x96c
+	 92, // 0x96d
+	 92, // 0x96e
+	 92, // 0x96f
+	 92, // 0x970
+	 92, // 0x971
+	 92, // 0x972
+	 92, // 0x973
+	 92, // 0x974
+	 92, // 0x975
+	 92, // 0x976
+	 92, // 0x977
+	 92, // 0x978
+	 92, // 0x979
+	 92, // 0x97a
+	 92, // 0x97b
+	 92, // 0x97c
+	 92, // 0x97d
+	 92, // 0x97e
+	 92, // 0x97f
+	 92, // 0x980
+	 92, // 0x981
+	 92, // 0x982
+	 92, // 0x983
+	 92, // 0x984
+	 92, // 0x985
+	 92, // 0x986
+	 92, // 0x987
+	 92, // 0x988
+	 92, // 0x989
+	 92, // 0x98a
+	 92, // 0x98b
+	 92, // 0x98c
+	 92, // 0x98d
+	 92, // 0x98e
+	 92, // 0x98f
+	 92, // 0x990
+	 92, // 0x991
+	 92, // 0x992
+	 92, // 0x993
+	 92, // 0x994
+	 92, // 0x995
+	 92, // 0x996
+	 92, // 0x997
+	 92, // 0x998
+	 92, // 0x999
+	 92, // 0x99a
+	 92, // 0x99b
+	 92, // 0x99c
+	 92, // 0x99d
+	 92, // 0x99e
+	 92, // 0x99f
+	 92, // 0x9a0
+	 92, // 0x9a1
+	 92, // 0x9a2
+	 92, // 0x9a3
+	 92, // 0x9a4
+	 92, // 0x9a5
+	 92, // 0x9a6
+	 92, // 0x9a7
+	 92, // 0x9a8
+	 92, // 0x9a9
+	 92, // 0x9aa
+	 92, // 0x9ab
+	 92, // 0x9ac
+	 92, // 0x9ad
+	 92, // 0x9ae
+	 92, // 0x9af
+	 92, // 0x9b0
+	 92, // 0x9b1
+	 92, // 0x9b2
+	 92, // 0x9b3
+	 92, // 0x9b4
+	 92, // 0x9b5
+	 92, // 0x9b6
+	 92, // 0x9b7
+	 92, // 0x9b8
+	 92, // 0x9b9
+	 92, // 0x9ba
+	 92, // 0x9bb
+	 92, // 0x9bc
+	 92, // 0x9bd
+	 92, // 0x9be
+	 92, // 0x9bf
+	 92, // 0x9c0
+	 92, // 0x9c1
+	 92, // 0x9c2
+	 92, // 0x9c3
+	 92, // 0x9c4
+	 92, // 0x9c5
+	 92, // 0x9c6
+	 92, // 0x9c7
+	 92, // 0x9c8
+	 92, // 0x9c9
+	 92, // 0x9ca
+	 92, // 0x9cb
+	 92, // 0x9cc
+	 92, // 0x9cd
+	 92, // 0x9ce
+	 92, // 0x9cf
+	 92, // 0x9d0
+	 92, // 0x9d1
+	 92, // 0x9d2
+	 92, // 0x9d3
+	 92, // 0x9d4
+	 92, // 0x9d5
+	 92, // 0x9d6
+	 92, // 0x9d7
+	 92, // 0x9d8
+	 92, // 0x9d9
+	 92, // 0x9da
+	 92, // 0x9db
+	 92, // 0x9dc
+	 92, // 0x9dd
+	 92, // 0x9de
+	 92, // 0x9df
+	 92, // 0x9e0
+	 92, // 0x9e1
+	 92, // 0x9e2
+	 92, // 0x9e3
+	 92, // 0x9e4
+	 92, // 0x9e5
+	 92, // 0x9e6
+	 92, // 0x9e7
+	 92, // 0x9e8
+	 92, // 0x9e9
+	 92, // 0x9ea
+	 92, // 0x9eb
+	 92, // 0x9ec
+	 92, // 0x9ed
+	 92, // 0x9ee
+	 92, // 0x9ef
+	 92, // 0x9f0
+	 92, // 0x9f1
+	 92, // 0x9f2
+	 92, // 0x9f3
+	 92, // 0x9f4
+	 92, // 0x9f5
+	 92, // 0x9f6
+	 92, // 0x9f7
+	 92, // 0x9f8
+	 92, // 0x9f9
+	 92, // 0x9fa
+	 92, // 0x9fb
+	 92, // 0x9fc
+	 92, // 0x9fd
+	 92, // 0x9fe
+	 92, // 0x9ff
+	 92, // 0xa00
+	 92, // 0xa01
+	 92, // 0xa02
+	 92, // 0xa03
+	 92, // 0xa04
+	 92, // 0xa05
+	 92, // 0xa06
+	 92, // 0xa07
+	 92, // 0xa08
+	 92, // 0xa09
+	 92, // 0xa0a
+	 92, // 0xa0b
+	 92, // 0xa0c
+	 92, // 0xa0d
+	 92, // 0xa0e
+	 92, // 0xa0f
+	 92, // 0xa10
+	 92, // 0xa11
+	 92, // 0xa12
+	 92, // 0xa13
+	 92, // 0xa14
+	 92, // 0xa15
+	 92, // 0xa16
+	 92, // 0xa17
+	 92, // 0xa18
+	 92, // 0xa19
+	 92, // 0xa1a
+	 92, // 0xa1b
+	 92, // 0xa1c
+	 92, // 0xa1d
+	 92, // 0xa1e
+	 92, // 0xa1f
+	 92, // 0xa20
+	 92, // 0xa21
+	 92, // 0xa22
+	 92, // 0xa23
+	 92, // 0xa24
+	 92, // 0xa25
+	 92, // 0xa26
+	 92, // 0xa27
+	 92, // 0xa28
+	 92, // 0xa29
+	 92, // 0xa2a
+	 92, // 0xa2b
+	 92, // 0xa2c
+	 92, // 0xa2d
+	 92, // 0xa2e
+	 92, // 0xa2f
+	 92, // 0xa30
+	 92, // 0xa31
+	 92, // 0xa32
+	 92, // 0xa33
+	 92, // 0xa34
+	 92, // 0xa35
+	 92, // 0xa36
+	 92, // 0xa37
+	 92, // 0xa38
+	 92, // 0xa39
+	 92, // 0xa3a
+	 92, // 0xa3b
+	 92, // 0xa3c
+	 92, // 0xa3d
+	 92, // 0xa3e
+	 92, // 0xa3f
+	 92, // 0xa40
+	 92, // 0xa41
+	 92, // 0xa42
+	 92, // 0xa43
+	 92, // 0xa44
+	 92, // 0xa45
+	 92, // 0xa46
+	 92, // 0xa47
+	 92, // 0xa48
+	 92, // 0xa49
+	 92, // 0xa4a
+	 92, // 0xa4b
+	 92, // 0xa4c
+	 92, // 0xa4d
+	 92, // 0xa4e
+	 92, // 0xa4f
+	 92, // 0xa50
+	 92, // 0xa51
+	 92, // 0xa52
+	 92, // 0xa53
+	 92, // 0xa54
+	 92, // 0xa55
+	 92, // 0xa56
+	 92, // 0xa57
+	 92, // 0xa58
+	 92, // 0xa59
+	 92, // 0xa5a
+	 92, // 0xa5b
+	 92, // 0xa5c
+	 92, // 0xa5d
+	 92, // 0xa5e
+	 92, // 0xa5f
+	 92, // 0xa60
+	 92, // 0xa61
+	 92, // 0xa62
+	 92, // 0xa63
+	 92, // 0xa64
+	 92, // 0xa65
+	 92, // 0xa66
+	 92, // 0xa67
+	 92, // 0xa68
+	 92, // 0xa69
+	 92, // 0xa6a
+	 92, // 0xa6b
+	 92, // 0xa6c
+	 92, // 0xa6d
+	 92, // 0xa6e
+	 92, // 0xa6f
+	 92, // 0xa70
+	 92, // 0xa71
+	 92, // 0xa72
+	 92, // 0xa73
+	 92, // 0xa74
+	 92, // 0xa75
+	 92, // 0xa76
+	 92, // 0xa77
+	 92, // 0xa78
+	 92, // 0xa79
+	 92, // 0xa7a
+	 92, // 0xa7b
+	 92, // 0xa7c
+	 92, // 0xa7d
+	 92, // 0xa7e
+	 92, // 0xa7f
+	 92, // 0xa80
+	 92, // 0xa81
+	 92, // 0xa82
+	 92, // 0xa83
+	 92, // 0xa84
+	 92, // 0xa85
+	 92, // 0xa86
+	 92, // 0xa87
+	 92, // 0xa88
+	 92, // 0xa89
+	 92, // 0xa8a
+	 92, // 0xa8b
+	 92, // 0xa8c
+	 92, // 0xa8d
+	 92, // 0xa8e
+	 92, // 0xa8f
+	 92, // 0xa90
+	 92, // 0xa91
+	 92, // 0xa92
+	 92, // 0xa93
+	 92, // 0xa94
+	 92, // 0xa95
+	 92, // 0xa96
+	 92, // 0xa97
+	 92, // 0xa98
+	 92, // 0xa99
+	 92, // 0xa9a
+	 92, // 0xa9b
+	 92, // 0xa9c
+	 92, // 0xa9d
+	 92, // 0xa9e
+	 92, // 0xa9f
+	 92, // 0xaa0
+	 92, // 0xaa1
+	 92, // 0xaa2
+	 92, // 0xaa3
+	 92, // 0xaa4
+	 92, // 0xaa5
+	 92, // 0xaa6
+	 92, // 0xaa7
+	 92, // 0xaa8
+	 92, // 0xaa9
+	 92, // 0xaaa
+	 92, // 0xaab
+	 92, // 0xaac
+	 92, // 0xaad
+	 92, // 0xaae
+	 92, // 0xaaf
+	 92, // 0xab0
+	 92, // 0xab1
+	 92, // 0xab2
+	 92, // 0xab3
+	 92, // 0xab4
+	 92, // 0xab5
+	 92, // 0xab6
+	 92, // 0xab7
+	 92, // 0xab8
+	 92, // 0xab9
+	 92, // 0xaba
+	 92, // 0xabb
+	 92, // 0xabc
+	 92, // 0xabd
+	 92, // 0xabe
+	 92, // 0xabf
+	 92, // 0xac0
+	 92, // 0xac1
+	 92, // 0xac2
+	 92, // 0xac3
+	 92, // 0xac4
+	 92, // 0xac5
+	 92, // 0xac6
+	 92, // 0xac7
+	 92, // 0xac8
+	 92, // 0xac9
+	 92, // 0xaca
+	 92, // 0xacb
+	 92, // 0xacc
+	 92, // 0xacd
+	 92, // 0xace
+	 92, // 0xacf
+	 92, // 0xad0
+	 92, // 0xad1
+	 92, // 0xad2
+	 92, // 0xad3
+	 92, // 0xad4
+	 92, // 0xad5
+	 92, // 0xad6
+	 92, // 0xad7
+	 92, // 0xad8
+	 92, // 0xad9
+	 92, // 0xada
+	 92, // 0xadb
+	 92, // 0xadc
+	 92, // 0xadd
+	 92, // 0xade
+	 92, // 0xadf
+	 92, // 0xae0
+	 92, // 0xae1
+	 92, // 0xae2
+	 92, // 0xae3
+	 92, // 0xae4
+	 92, // 0xae5
+	 92, // 0xae6
+	 92, // 0xae7
+	 92, // 0xae8
+	 92, // 0xae9
+	 92, // 0xaea
+	 92, // 0xaeb
+	 92, // 0xaec
+	 92, // 0xaed
+	 92, // 0xaee
+	 92, // 0xaef
+	 92, // 0xaf0
+	 92, // 0xaf1
+	 92, // 0xaf2
+	 92, // 0xaf3
+	 92, // 0xaf4
+	 92, // 0xaf5
+	 92, // 0xaf6
+	 92, // 0xaf7
+	 92, // 0xaf8
+	 92, // 0xaf9
+	 92, // 0xafa
+	 92, // 0xafb
+	 92, // 0xafc
+	 92, // 0xafd
+	 92, // 0xafe
+	 92, // 0xaff
+	 92, // 0xb00
+	 92, // 0xb01
+	 92, // 0xb02
+	 92, // 0xb03
+	 92, // 0xb04
+	 92, // 0xb05
+	 92, // 0xb06
+	 92, // 0xb07
+	 92, // 0xb08
+	 92, // 0xb09
+	 92, // 0xb0a
+	 92, // 0xb0b
+	 92, // 0xb0c
+	 92, // 0xb0d
+	 92, // 0xb0e
+	 92, // 0xb0f
+	 92, // 0xb10
+	 92, // 0xb11
+	 92, // 0xb12
+	 92, // 0xb13
+	 92, // 0xb14
+	 92, // 0xb15
+	 92, // 0xb16
+	 92, // 0xb17
+	 92, // 0xb18
+	 92, // 0xb19
+	 92, // 0xb1a
+	 92, // 0xb1b
+	 92, // 0xb1c
+	 92, // 0xb1d
+	 92, // 0xb1e
+	 92, // 0xb1f
+	 92, // 0xb20
+	 92, // 0xb21
+	 92, // 0xb22
+	 92, // 0xb23
+	 92, // 0xb24
+	 92, // 0xb25
+	 92, // 0xb26
+	 92, // 0xb27
+	 92, // 0xb28
+	 92, // 0xb29
+	 92, // 0xb2a
+	 92, // 0xb2b
+	 92, // 0xb2c
+	 92, // 0xb2d
+	 92, // 0xb2e
+	 92, // 0xb2f
+	 92, // 0xb30
+	 92, // 0xb31
+	 92, // 0xb32
+	 92, // 0xb33
+	 92, // 0xb34
+	 92, // 0xb35
+	 92, // 0xb36
+	 92, // 0xb37
+	 92, // 0xb38
+	 92, // 0xb39
+	 92, // 0xb3a
+	 92, // 0xb3b
+	 92, // 0xb3c
+	 92, // 0xb3d
+	 92, // 0xb3e
+	 92, // 0xb3f
+	 92, // 0xb40
+	 92, // 0xb41
+	 92, // 0xb42
+	 92, // 0xb43
+	 92, // 0xb44
+	 92, // 0xb45
+	 92, // 0xb46
+	 92, // 0xb47
+	 92, // 0xb48
+	 92, // 0xb49
+	 92, // 0xb4a
+	 92, // 0xb4b
+	 92, // 0xb4c
+	 92, // 0xb4d
+	 92, // 0xb4e
+	 92, // 0xb4f
+	 92, // 0xb50
+	 92, // 0xb51
+	 92, // 0xb52
+	 92, // 0xb53
+	 92, // 0xb54
+	 92, // 0xb55
+	 92, // 0xb56
+	 92, // 0xb57
+	 92, // 0xb58
+	 92, // 0xb59
+	 92, // 0xb5a
+	 92, // 0xb5b
+	 92, // 0xb5c
+	 92, // 0xb5d
+	 92, // 0xb5e
+	 92, // 0xb5f
+	 92, // 0xb60
+	 92, // 0xb61
+	 92, // 0xb62
+	 92, // 0xb63
+	 92, // 0xb64
+	 92, // 0xb65
+	 92, // 0xb66
+	 92, // 0xb67
+	 92, // 0xb68
+	 92, // 0xb69
+	 92, // 0xb6a
+	 92, // 0xb6b
+	 92, // 0xb6c
+	 92, // 0xb6d
+	 92, // 0xb6e
+	 92, // 0xb6f
+	 92, // 0xb70
+	 92, // 0xb71
+	 92, // 0xb72
+	 92, // 0xb73
+	 92, // 0xb74
+	 92, // 0xb75
+	 92, // 0xb76
+	 92, // 0xb77
+	 92, // 0xb78
+	 92, // 0xb79
+	 92, // 0xb7a
+	 92, // 0xb7b
+	 92, // 0xb7c
+	 92, // 0xb7d
+	 92, // 0xb7e
+	 92, // 0xb7f
+	 92, // 0xb80
+	 92, // 0xb81
+	 92, // 0xb82
+	 92, // 0xb83
+	 92, // 0xb84
+	 92, // 0xb85
+	 92, // 0xb86
+	 92, // 0xb87
+	 92, // 0xb88
+	 92, // 0xb89
+	 92, // 0xb8a
+	 92, // 0xb8b
+	 92, // 0xb8c
+	 92, // 0xb8d
+	 92, // 0xb8e
+	 92, // 0xb8f
+	 92, // 0xb90
+	 92, // 0xb91
+	 92, // 0xb92
+	 92, // 0xb93
+	 92, // 0xb94
+	 92, // 0xb95
+	 92, // 0xb96
+	 92, // 0xb97
+	 92, // 0xb98
+	 92, // 0xb99
+	 92, // 0xb9a
+	 92, // 0xb9b
+	 92, // 0xb9c
+	 92, // 0xb9d
+	 92, // 0xb9e
+	 92, // 0xb9f
+	 92, // 0xba0
+	 92, // 0xba1
+	 92, // 0xba2
+	 92, // 0xba3
+	 92, // 0xba4
+	 92, // 0xba5
+	 92, // 0xba6
+	 92, // 0xba7
+	 92, // 0xba8
+	 92, // 0xba9
+	 92, // 0xbaa
+	 92, // 0xbab
+	 92, // 0xbac
+	 92, // 0xbad
+	 92, // 0xbae
+	 92, // 0xbaf
+	 92, // 0xbb0
+	 92, // 0xbb1
+	 92, // 0xbb2
+	 92, // 0xbb3
+	 92, // 0xbb4
+	 92, // 0xbb5
+	 92, // 0xbb6
+	 92, // 0xbb7
+	 92, // 0xbb8
+	 92, // 0xbb9
+	 92, // 0xbba
+	 92, // 0xbbb
+	 92, // 0xbbc
+	 92, // 0xbbd
+	 92, // 0xbbe
+	 92, // 0xbbf
+	 92, // 0xbc0
+	 92, // 0xbc1
+	 92, // 0xbc2
+	 92, // 0xbc3
+	 92, // 0xbc4
+	 92, // 0xbc5
+	 92, // 0xbc6
+	 92, // 0xbc7
+	 92, // 0xbc8
+	 92, // 0xbc9
+	 92, // 0xbca
+	 92, // 0xbcb
+	 92, // 0xbcc
+	 92, // 0xbcd
+	 92, // 0xbce
+	 92, // 0xbcf
+	 92, // 0xbd0
+	 92, // 0xbd1
+	 92, // 0xbd2
+	 92, // 0xbd3
+	 92, // 0xbd4
+	 92, // 0xbd5
+	 92, // 0xbd6
+	 92, // 0xbd7
+	 92, // 0xbd8
+	 92, // 0xbd9
+	 92, // 0xbda
+	 92, // 0xbdb
+	 92, // 0xbdc
+	 92, // 0xbdd
+	 92, // 0xbde
+	 92, // 0xbdf
+	 92, // 0xbe0
+	 92, // 0xbe1
+	 92, // 0xbe2
+	 92, // 0xbe3
+	 92, // 0xbe4
+	 92, // 0xbe5
+	 92, // 0xbe6
+	 92, // 0xbe7
+	 92, // 0xbe8
+	 92, // 0xbe9
+	 92, // 0xbea
+	 92, // 0xbeb
+	 92, // 0xbec
+	 92, // 0xbed
+	 92, // 0xbee
+	 92, // 0xbef
+	 92, // 0xbf0
+	 92, // 0xbf1
+	 92, // 0xbf2
+	 92, // 0xbf3
+	 92, // 0xbf4
+	 92, // 0xbf5
+	 92, // 0xbf6
+	 92, // 0xbf7
+	 92, // 0xbf8
+	 92, // 0xbf9
+	 92, // 0xbfa
+	 92, // 0xbfb
+	 92, // 0xbfc
+	 92, // 0xbfd
+	 92, // 0xbfe
+	 92, // 0xbff
+	 92, // 0xc00
+	 92, // 0xc01
+	 92, // 0xc02
+	 92, // 0xc03
+	 92, // 0xc04
+	 92, // 0xc05
+	 92, // 0xc06
+	 92, // 0xc07
+	 92, // 0xc08
+	 92, // 0xc09
+	 92, // 0xc0a
+	 92, // 0xc0b
+	 92, // 0xc0c
+	 92, // 0xc0d
+	 92, // 0xc0e
+	 92, // 0xc0f
+	 92, // 0xc10
+	 92, // 0xc11
+	 92, // 0xc12
+	 92, // 0xc13
+	 92, // 0xc14
+	 92, // 0xc15
+	 92, // 0xc16
+	 92, // 0xc17
+	 92, // 0xc18
+	 92, // 0xc19
+	 92, // 0xc1a
+	 92, // 0xc1b
+	 92, // 0xc1c
+	 92, // 0xc1d
+	 92, // 0xc1e
+	 92, // 0xc1f
+	 92, // 0xc20
+	 92, // 0xc21
+	 92, // 0xc22
+	 92, // 0xc23
+	 92, // 0xc24
+	 92, // 0xc25
+	 92, // 0xc26
+	 92, // 0xc27
+	 92, // 0xc28
+	 92, // 0xc29
+	 92, // 0xc2a
+	 92, // 0xc2b
+	 92, // 0xc2c
+	 92, // 0xc2d
+	 92, // 0xc2e
+	 92, // 0xc2f
+	 92, // 0xc30
+	 92, // 0xc31
+	 92, // 0xc32
+	 92, // 0xc33
+	 92, // 0xc34
+	 92, // 0xc35
+	 92, // 0xc36
+	 92, // 0xc37
+	 92, // 0xc38
+	 92, // 0xc39
+	 92, // 0xc3a
+	 92, // 0xc3b
+	 92, // 0xc3c
+	 92, // 0xc3d
+	 92, // 0xc3e
+	 92, // 0xc3f
+	 92, // 0xc40
+	 92, // 0xc41
+	 92, // 0xc42
+	 92, // 0xc43
+	 92, // 0xc44
+	 92, // 0xc45
+	 92, // 0xc46
+	 92, // 0xc47
+	 92, // 0xc48
+	 92, // 0xc49
+	 92, // 0xc4a
+	 92, // 0xc4b
+	 92, // 0xc4c
+	 92, // 0xc4d
+	 92, // 0xc4e
+	 92, // 0xc4f
+	 92, // 0xc50
+	 92, // 0xc51
+	 92, // 0xc52
+	 92, // 0xc53
+	 92, // 0xc54
+	 92, // 0xc55
+	 92, // 0xc56
+	 92, // 0xc57
+	 92, // 0xc58
+	 92, // 0xc59
+	 92, // 0xc5a
+	 92, // 0xc5b
+	 92, // 0xc5c
+	 92, // 0xc5d
+	 92, // 0xc5e
+	 92, // 0xc5f
+	 92, // 0xc60
+	 92, // 0xc61
+	 92, // 0xc62
+	 92, // 0xc63
+	 92, // 0xc64
+	 92, // 0xc65
+	 92, // 0xc66
+	 92, // 0xc67
+	 92, // 0xc68
+	 92, // 0xc69
+	 92, // 0xc6a
+	 92, // 0xc6b
+	 92, // 0xc6c
+	 92, // 0xc6d
+	 92, // 0xc6e
+	 92, // 0xc6f
+	 92, // 0xc70
+	 92, // 0xc71
+	 92, // 0xc72
+	 92, // 0xc73
+	 92, // 0xc74
+	 92, // 0xc75
+	 92, // 0xc76
+	 92, // 0xc77
+	 92, // 0xc78
+	 92, // 0xc79
+	 92, // 0xc7a
+	 92, // 0xc7b
+	 92, // 0xc7c
+	 92, // 0xc7d
+	 92, // 0xc7e
+	 92, // 0xc7f
+	 92, // 0xc80
+	 92, // 0xc81
+	 92, // 0xc82
+	 92, // 0xc83
+	 92, // 0xc84
+	 92, // 0xc85
+	 92, // 0xc86
+	 92, // 0xc87
+	 92, // 0xc88
+	 92, // 0xc89
+	 92, // 0xc8a
+	 92, // 0xc8b
+	 92, // 0xc8c
+	 92, // 0xc8d
+	 92, // 0xc8e
+	 92, // 0xc8f
+	 92, // 0xc90
+	 92, // 0xc91
+	 92, // 0xc92
+	 92, // 0xc93
+	 92, // 0xc94
+	 92, // 0xc95
+	 92, // 0xc96
+	 92, // 0xc97
+	 92, // 0xc98
+	 92, // 0xc99
+	 92, // 0xc9a
+	 92, // 0xc9b
+	 92, // 0xc9c
+	 92, // 0xc9d
+	 92, // 0xc9e
+	 92, // 0xc9f
+	 92, // 0xca0
+	 92, // 0xca1
+	 92, // 0xca2
+	 92, // 0xca3
+	 92, // 0xca4
+	 92, // 0xca5
+	 92, // 0xca6
+	 92, // 0xca7
+	 92, // 0xca8
+	 92, // 0xca9
+	 92, // 0xcaa
+	 92, // 0xcab
+	 92, // 0xcac
+	 92, // 0xcad
+	 92, // 0xcae
+	 92, // 0xcaf
+	 92, // 0xcb0
+	 92, // 0xcb1
+	 92, // 0xcb2
+	 92, // 0xcb3
+	 92, // 0xcb4
+	 92, // 0xcb5
+	 92, // 0xcb6
+	 92, // 0xcb7
+	 92, // 0xcb8
+	 92, // 0xcb9
+	 92, // 0xcba
+	 92, // 0xcbb
+	 92, // 0xcbc
+	 92, // 0xcbd
+	 92, // 0xcbe
+	 92, // 0xcbf
+	 92, // 0xcc0
+	 92, // 0xcc1
+	 92, // 0xcc2
+	 92, // 0xcc3
+	 92, // 0xcc4
+	 92, // 0xcc5
+	 92, // 0xcc6
+	 92, // 0xcc7
+	 92, // 0xcc8
+	 92, // 0xcc9
+	 92, // 0xcca
+	 92, // 0xccb
+	 92, // 0xccc
+	 92, // 0xccd
+	 92, // 0xcce
+	 92, // 0xccf
+	 92, // 0xcd0
+	 92, // 0xcd1
+	 92, // 0xcd2
+	 92, // 0xcd3
+	 92, // 0xcd4
+	 92, // 0xcd5
+	 92, // 0xcd6
+	 92, // 0xcd7
+	 92, // 0xcd8
+	 92, // 0xcd9
+	 92, // 0xcda
+	 92, // 0xcdb
+	 92, // 0xcdc
+	 92, // 0xcdd
+	 92, // 0xcde
+	 92, // 0xcdf
+	 92, // 0xce0
+	 92, // 0xce1
+	 92, // 0xce2
+	 92, // 0xce3
+	 92, // 0xce4
+	 92, // 0xce5
+	 92, // 0xce6
+	 92, // 0xce7
+	 92, // 0xce8
+	 92, // 0xce9
+	 92, // 0xcea
+	 92, // 0xceb
+	 92, // 0xcec
+	 92, // 0xced
+	 92, // 0xcee
+	 92, // 0xcef
+	 92, // 0xcf0
+	 92, // 0xcf1
+	 92, // 0xcf2
+	 92, // 0xcf3
+	 92, // 0xcf4
+	 92, // 0xcf5
+	 92, // 0xcf6
+	 92, // 0xcf7
+	 92, // 0xcf8
+	 92, // 0xcf9
+	 92, // 0xcfa
+	 92, // 0xcfb
+	 92, // 0xcfc
+	 92, // 0xcfd
+	 92, // 0xcfe
+	 92, // 0xcff
+	 92, // 0xd00
+	 92, // 0xd01
+	 92, // 0xd02
+	 92, // 0xd03
+	 92, // 0xd04
+	 92, // 0xd05
+	 92, // 0xd06
+	 92, // 0xd07
+	 92, // 0xd08
+	 92, // 0xd09
+	 92, // 0xd0a
+	 92, // 0xd0b
+	 92, // 0xd0c
+	 92, // 0xd0d
+	 92, // 0xd0e
+	 92, // 0xd0f
+	 92, // 0xd10
+	 92, // 0xd11
+	 92, // 0xd12
+	 92, // 0xd13
+	 92, // 0xd14
+	 92, // 0xd15
+	 92, // 0xd16
+	 92, // 0xd17
+	 92, // 0xd18
+	 92, // 0xd19
+	 92, // 0xd1a
+	 92, // 0xd1b
+	 92, // 0xd1c
+	 92, // 0xd1d
+	 92, // 0xd1e
+	 92, // 0xd1f
+	 92, // 0xd20
+	 92, // 0xd21
+	 92, // 0xd22
+	 92, // 0xd23
+	 92, // 0xd24
+	 92, // 0xd25
+	 92, // 0xd26
+	 92, // 0xd27
+	 92, // 0xd28
+	 92, // 0xd29
+	 92, // 0xd2a
+	 92, // 0xd2b
+	 92, // 0xd2c
+	 92, // 0xd2d
+	 92, // 0xd2e
+	 92, // 0xd2f
+	 92, // 0xd30
+	 92, // 0xd31
+	 92, // 0xd32
+	 92, // 0xd33
+	 92, // 0xd34
+	 92, // 0xd35
+	 92, // 0xd36
+	 92, // 0xd37
+	 92, // 0xd38
+	 92, // 0xd39
+	 92, // 0xd3a
+	 92, // 0xd3b
+	 92, // 0xd3c
+	 92, // 0xd3d
+	 92, // 0xd3e
+	 92, // 0xd3f
+	 92, // 0xd40
+	 92, // 0xd41
+	 92, // 0xd42
+	 92, // 0xd43
+	 92, // 0xd44
+	 92, // 0xd45
+	 92, // 0xd46
+	 92, // 0xd47
+	 92, // 0xd48
+	 92, // 0xd49
+	 92, // 0xd4a
+	 92, // 0xd4b
+	 92, // 0xd4c
+	 92, // 0xd4d
+	 92, // 0xd4e
+	 92, // 0xd4f
+	 92, // 0xd50
+	 92, // 0xd51
+	 92, // 0xd52
+	 92, // 0xd53
+	 92, // 0xd54
+	 92, // 0xd55
+	 92, // 0xd56
+	 92, // 0xd57
+	 92, // 0xd58
+	 92, // 0xd59
+	 92, // 0xd5a
+	 92, // 0xd5b
+	 92, // 0xd5c
+	 92, // 0xd5d
+	 92, // 0xd5e
+	 92, // 0xd5f
+	 92, // 0xd60
+	 92, // 0xd61
+	 92, // 0xd62
+	 92, // 0xd63
+	 92, // 0xd64
+	 92, // 0xd65
+	 92, // 0xd66
+	 92, // 0xd67
+	 92, // 0xd68
+	 92, // 0xd69
+	 92, // 0xd6a
+	 92, // 0xd6b
+	 92, // 0xd6c
+	 92, // 0xd6d
+	 92, // 0xd6e
+	 92, // 0xd6f
+	 92, // 0xd70
+	 92, // 0xd71
+	 92, // 0xd72
+	 92, // 0xd73
+	 92, // 0xd74
+	 92, // 0xd75
+	 92, // 0xd76
+	 92, // 0xd77
+	 92, // 0xd78
+	 92, // 0xd79
+	 92, // 0xd7a
+	 92, // 0xd7b
+	 92, // 0xd7c
+	 92, // 0xd7d
+	 92, // 0xd7e
+	 92, // 0xd7f
+	 92, // 0xd80
+	 92, // 0xd81
+	 92, // 0xd82
+	 92, // 0xd83
+	 92, // 0xd84
+	 92, // 0xd85
+	 92, // 0xd86
+	 92, // 0xd87
+	 92, // 0xd88
+	 92, // 0xd89
+	 92, // 0xd8a
+	 92, // 0xd8b
+	 92, // 0xd8c
+	 92, // 0xd8d
+	 92, // 0xd8e
+	 92, // 0xd8f
+	 92, // 0xd90
+	 92, // 0xd91
+	 92, // 0xd92
+	 92, // 0xd93
+	 92, // 0xd94
+	 92, // 0xd95
+	 92, // 0xd96
+	 92, // 0xd97
+	 92, // 0xd98
+	 92, // 0xd99
+	 92, // 0xd9a
+	 92, // 0xd9b
+	 92, // 0xd9c
+	 92, // 0xd9d
+	 92, // 0xd9e
+	 92, // 0xd9f
+	 92, // 0xda0
+	 92, // 0xda1
+	 92, // 0xda2
+	 92, // 0xda3
+	 92, // 0xda4
+	 92, // 0xda5
+	 92, // 0xda6
+	 92, // 0xda7
+	 92, // 0xda8
+	 92, // 0xda9
+	 92, // 0xdaa
+	 92, // 0xdab
+	 92, // 0xdac
+	 92, // 0xdad
+	 92, // 0xdae
+	 92, // 0xdaf
+	 92, // 0xdb0
+	 92, // 0xdb1
+	 92, // 0xdb2
+	 92, // 0xdb3
+	 92, // 0xdb4
+	 92, // 0xdb5
+	 92, // 0xdb6
+	 92, // 0xdb7
+	 92, // 0xdb8
+	 92, // 0xdb9
+	 92, // 0xdba
+	 92, // 0xdbb
+	 92, // 0xdbc
+	 92, // 0xdbd
+	 92, // 0xdbe
+	 92, // 0xdbf
+	 92, // 0xdc0
+	 92, // 0xdc1
+	 92, // 0xdc2
+	 92, // 0xdc3
+	 92, // 0xdc4
+	 92, // 0xdc5
+	 92, // 0xdc6
+	 92, // 0xdc7
+	 92, // 0xdc8
+	 92, // 0xdc9
+	 92, // 0xdca
+	 92, // 0xdcb
+	 92, // 0xdcc
+	 92, // 0xdcd
+	 92, // 0xdce
+	 92, // 0xdcf
+	 92, // 0xdd0
+	 92, // 0xdd1
+	 92, // 0xdd2
+	 92, // 0xdd3
+	 92, // 0xdd4
+	 92, // 0xdd5
+	 92, // 0xdd6
+	 92, // 0xdd7
+	 92, // 0xdd8
+	 92, // 0xdd9
+	 92, // 0xdda
+	 92, // 0xddb
+	 92, // 0xddc
+	 92, // 0xddd
+	 92, // 0xdde
+	 92, // 0xddf
+	 92, // 0xde0
+	 92, // 0xde1
+	 92, // 0xde2
+	 92, // 0xde3
+	 92, // 0xde4
+	 92, // 0xde5
+	 92, // 0xde6
+	 92, // 0xde7
+	 92, // 0xde8
+	 92, // 0xde9
+	 92, // 0xdea
+	 92, // 0xdeb
+	 92, // 0xdec
+	 92, // 0xded
+	 92, // 0xdee
+	 92, // 0xdef
+	 92, // 0xdf0
+	 92, // 0xdf1
+	 92, // 0xdf2
+	 92, // 0xdf3
+	 92, // 0xdf4
+	 92, // 0xdf5
+	 92, // 0xdf6
+	 92, // 0xdf7
+	 92, // 0xdf8
+	 92, // 0xdf9
+	 92, // 0xdfa
+	 92, // 0xdfb
+	 92, // 0xdfc
+	 92, // 0xdfd
+	 92, // 0xdfe
+	 92, // 0xdff
+	 150, // 0xe00
+	 151, // 0xe01
+	 92, // 0xe02
+	 92, // 0xe03
+	 92, // 0xe04
+	 92, // 0xe05
+	 92, // 0xe06
+	 92, // 0xe07
+	 92, // 0xe08
+	 92, // 0xe09
+	 92, // 0xe0a
+	 92, // 0xe0b
+	 92, // 0xe0c
+	 92, // 0xe0d
+	 92, // 0xe0e
+	 92, // 0xe0f
+	 92, // 0xe10
+	 92, // 0xe11
+	 92, // 0xe12
+	 92, // 0xe13
+	 92, // 0xe14
+	 92, // 0xe15
+	 92, // 0xe16
+	 92, // 0xe17
+	 92, // 0xe18
+	 92, // 0xe19
+	 92, // 0xe1a
+	 92, // 0xe1b
+	 92, // 0xe1c
+	 92, // 0xe1d
+	 92, // 0xe1e
+	 92, // 0xe1f
+	 92, // 0xe20
+	 92, // 0xe21
+	 92, // 0xe22
+	 92, // 0xe23
+	 92, // 0xe24
+	 92, // 0xe25
+	 92, // 0xe26
+	 92, // 0xe27
+	 92, // 0xe28
+	 92, // 0xe29
+	 92, // 0xe2a
+	 92, // 0xe2b
+	 92, // 0xe2c
+	 92, // 0xe2d
+	 92, // 0xe2e
+	 92, // 0xe2f
+	 92, // 0xe30
+	 92, // 0xe31
+	 92, // 0xe32
+	 92, // 0xe33
+	 92, // 0xe34
+	 92, // 0xe35
+	 92, // 0xe36
+	 92, // 0xe37
+	 92, // 0xe38
+	 92, // 0xe39
+	 92, // 0xe3a
+	 92, // 0xe3b
+	 92, // 0xe3c
+	 92, // 0xe3d
+	 92, // 0xe3e
+	 92, // 0xe3f
+	 92, // 0xe40
+	 92, // 0xe41
+	 92, // 0xe42
+	 92, // 0xe43
+	 92, // 0xe44
+	 92, // 0xe45
+	 92, // 0xe46
+	 92, // 0xe47
+	 92, // 0xe48
+	 92, // 0xe49
+	 92, // 0xe4a
+	 92, // 0xe4b
+	 92, // 0xe4c
+	 92, // 0xe4d
+	 92, // 0xe4e
+	 92, // 0xe4f
+	 92, // 0xe50
+	 92, // 0xe51
+	 92, // 0xe52
+	 92, // 0xe53
+	 92, // 0xe54
+	 92, // 0xe55
+	 92, // 0xe56
+	 92, // 0xe57
+	 92, // 0xe58
+	 92, // 0xe59
+	 92, // 0xe5a
+	 92, // 0xe5b
+	 92, // 0xe5c
+	 92, // 0xe5d
+	 92, // 0xe5e
+	 92, // 0xe5f
+	 92, // 0xe60
+	 92, // 0xe61
+	 92, // 0xe62
+	 92, // 0xe63
+	 92, // 0xe64
+	 92, // 0xe65
+	 92, // 0xe66
+	 92, // 0xe67
+	 92, // 0xe68
+	 92, // 0xe69
+	 92, // 0xe6a
+	 92, // 0xe6b
+	 92, // 0xe6c
+	 92, // 0xe6d
+	 92, // 0xe6e
+	 92, // 0xe6f
+	 92, // 0xe70
+	 92, // 0xe71
+	 92, // 0xe72
+	 92, // 0xe73
+	 92, // 0xe74
+	 92, // 0xe75
+	 92, // 0xe76
+	 92, // 0xe77
+	 92, // 0xe78
+	 92, // 0xe79
+	 92, // 0xe7a
+	 92, // 0xe7b
+	 92, // 0xe7c
+	 92, // 0xe7d
+	 92, // 0xe7e
+	 92, // 0xe7f
+	 92, // 0xe80
+	 92, // 0xe81
+	 92, // 0xe82
+	 92, // 0xe83
+	 92, // 0xe84
+	 92, // 0xe85
+	 92, // 0xe86
+	 92, // 0xe87
+	 92, // 0xe88
+	 92, // 0xe89
+	 92, // 0xe8a
+	 92, // 0xe8b
+	 92, // 0xe8c
+	 92, // 0xe8d
+	 92, // 0xe8e
+	 92, // 0xe8f
+	 92, // 0xe90
+	 92, // 0xe91
+	 92, // 0xe92
+	 92, // 0xe93
+	 92, // 0xe94
+	 92, // 0xe95
+	 92, // 0xe96
+	 92, // 0xe97
+	 92, // 0xe98
+	 92, // 0xe99
+	 92, // 0xe9a
+	 92, // 0xe9b
+	 92, // 0xe9c
+	 92, // 0xe9d
+	 92, // 0xe9e
+	 92, // 0xe9f
+	 92, // 0xea0
+	 92, // 0xea1
+	 92, // 0xea2
+	 92, // 0xea3
+	 92, // 0xea4
+	 92, // 0xea5
+	 92, // 0xea6
+	 92, // 0xea7
+	 92, // 0xea8
+	 92, // 0xea9
+	 92, // 0xeaa
+	 92, // 0xeab
+	 92, // 0xeac
+	 92, // 0xead
+	 92, // 0xeae
+	 92, // 0xeaf
+	 92, // 0xeb0
+	 92, // 0xeb1
+	 92, // 0xeb2
+	 92, // 0xeb3
+	 92, // 0xeb4
+	 92, // 0xeb5
+	 92, // 0xeb6
+	 92, // 0xeb7
+	 92, // 0xeb8
+	 92, // 0xeb9
+	 92, // 0xeba
+	 92, // 0xebb
+	 92, // 0xebc
+	 92, // 0xebd
+	 92, // 0xebe
+	 92, // 0xebf
+	 92, // 0xec0
+	 92, // 0xec1
+	 92, // 0xec2
+	 92, // 0xec3
+	 92, // 0xec4
+	 92, // 0xec5
+	 92, // 0xec6
+	 92, // 0xec7
+	 92, // 0xec8
+	 92, // 0xec9
+	 92, // 0xeca
+	 92, // 0xecb
+	 92, // 0xecc
+	 92, // 0xecd
+	 92, // 0xece
+	 92, // 0xecf
+	 92, // 0xed0
+	 92, // 0xed1
+	 92, // 0xed2
+	 92, // 0xed3
+	 92, // 0xed4
+	 92, // 0xed5
+	 92, // 0xed6
+	 92, // 0xed7
+	 92, // 0xed8
+	 92, // 0xed9
+	 92, // 0xeda
+	 92, // 0xedb
+	 92, // 0xedc
+	 92, // 0xedd
+	 92, // 0xede
+	 92, // 0xedf
+	 92, // 0xee0
+	 92, // 0xee1
+	 92, // 0xee2
+	 92, // 0xee3
+	 92, // 0xee4
+	 92, // 0xee5
+	 92, // 0xee6
+	 92, // 0xee7
+	 92, // 0xee8
+	 92, // 0xee9
+	 92, // 0xeea
+	 92, // 0xeeb
+	 92, // 0xeec
+	 92, // 0xeed
+	 92, // 0xeee
+	 92, // 0xeef
+	 92, // 0xef0
+	 92, // 0xef1
+	 92, // 0xef2
+	 92, // 0xef3
+	 92, // 0xef4
+	 92, // 0xef5
+	 92, // 0xef6
+	 92, // 0xef7
+	 92, // 0xef8
+	 92, // 0xef9
+	 92, // 0xefa
+	 92, // 0xefb
+	 92, // 0xefc
+	 92, // 0xefd
+	 92, // 0xefe
+	 92, // 0xeff
+	 60, // 0xf00
+	 60, // 0xf01
+	 60, // 0xf02
+	 60, // 0xf03
+	 60, // 0xf04
+	 60, // 0xf05
+	 60, // 0xf06
+	 60, // 0xf07
+	 60, // 0xf08
+	 60, // 0xf09
+	 60, // 0xf0a
+	 60, // 0xf0b
+	 60, // 0xf0c
+	 60, // 0xf0d
+	 60, // 0xf0e
+	 60, // 0xf0f
+	 60, // 0xf10
+	 60, // 0xf11
+	 60, // 0xf12
+	 60, // 0xf13
+	 60, // 0xf14
+	 60, // 0xf15
+	 60, // 0xf16
+	 60, // 0xf17
+	 60, // 0xf18
+	 60, // 0xf19
+	 60, // 0xf1a
+	 60, // 0xf1b
+	 60, // 0xf1c
+	 60, // 0xf1d
+	 60, // 0xf1e
+	 60, // 0xf1f
+	 60, // 0xf20
+	 60, // 0xf21
+	 60, // 0xf22
+	 60, // 0xf23
+	 60, // 0xf24
+	 60, // 0xf25
+	 60, // 0xf26
+	 60, // 0xf27
+	 60, // 0xf28
+	 60, // 0xf29
+	 60, // 0xf2a
+	 60, // 0xf2b
+	 60, // 0xf2c
+	 60, // 0xf2d
+	 60, // 0xf2e
+	 60, // 0xf2f
+	 60, // 0xf30
+	 60, // 0xf31
+	 60, // 0xf32
+	 60, // 0xf33
+	 60, // 0xf34
+	 60, // 0xf35
+	 60, // 0xf36
+	 60, // 0xf37
+	 60, // 0xf38
+	 60, // 0xf39
+	 60, // 0xf3a
+	 60, // 0xf3b
+	 60, // 0xf3c
+	 60, // 0xf3d
+	 60, // 0xf3e
+	 60, // 0xf3f
+	 60, // 0xf40
+	 60, // 0xf41
+	 60, // 0xf42
+	 60, // 0xf43
+	 60, // 0xf44
+	 60, // 0xf45
+	 60, // 0xf46
+	 60, // 0xf47
+	 60, // 0xf48
+	 60, // 0xf49
+	 60, // 0xf4a
+	 60, // 0xf4b
+	 60, // 0xf4c
+	 60, // 0xf4d
+	 60, // 0xf4e
+	 60, // 0xf4f
+	 60, // 0xf50
+	 60, // 0xf51
+	 60, // 0xf52
+	 60, // 0xf53
+	 60, // 0xf54
+	 60, // 0xf55
+	 60, // 0xf56
+	 60, // 0xf57
+	 60, // 0xf58
+	 60, // 0xf59
+	 60, // 0xf5a
+	 60, // 0xf5b
+	 60, // 0xf5c
+	 60, // 0xf5d
+	 60, // 0xf5e
+	 60, // 0xf5f
+	 60, // 0xf60
+	 60, // 0xf61
+	 60, // 0xf62
+	 60, // 0xf63
+	 60, // 0xf64
+	 60, // 0xf65
+	 60, // 0xf66
+	 60, // 0xf67
+	 60, // 0xf68
+	 60, // 0xf69
+	 60, // 0xf6a
+	 60, // 0xf6b
+	 60, // 0xf6c
+	 60, // 0xf6d
+	 60, // 0xf6e
+	 60, // 0xf6f
+	 60, // 0xf70
+	 60, // 0xf71
+	 60, // 0xf72
+	 60, // 0xf73
+	 60, // 0xf74
+	 60, // 0xf75
+	 60, // 0xf76
+	 60, // 0xf77
+	 60, // 0xf78
+	 60, // 0xf79
+	 60, // 0xf7a
+	 60, // 0xf7b
+	 60, // 0xf7c
+	 60, // 0xf7d
+	 60, // 0xf7e
+	 60, // 0xf7f
+	 60, // 0xf80
+	 60, // 0xf81
+	 60, // 0xf82
+	 60, // 0xf83
+	 60, // 0xf84
+	 60, // 0xf85
+	 60, // 0xf86
+	 60, // 0xf87
+	 60, // 0xf88
+	 60, // 0xf89
+	 60, // 0xf8a
+	 60, // 0xf8b
+	 60, // 0xf8c
+	 60, // 0xf8d
+	 60, // 0xf8e
+	 60, // 0xf8f
+	 60, // 0xf90
+	 60, // 0xf91
+	 60, // 0xf92
+	 60, // 0xf93
+	 60, // 0xf94
+	 60, // 0xf95
+	 60, // 0xf96
+	 60, // 0xf97
+	 60, // 0xf98
+	 60, // 0xf99
+	 60, // 0xf9a
+	 60, // 0xf9b
+	 60, // 0xf9c
+	 60, // 0xf9d
+	 60, // 0xf9e
+	 60, // 0xf9f
+	 60, // 0xfa0
+	 60, // 0xfa1
+	 60, // 0xfa2
+	 60, // 0xfa3
+	 60, // 0xfa4
+	 60, // 0xfa5
+	 60, // 0xfa6
+	 60, // 0xfa7
+	 60, // 0xfa8
+	 60, // 0xfa9
+	 60, // 0xfaa
+	 60, // 0xfab
+	 60, // 0xfac
+	 60, // 0xfad
+	 60, // 0xfae
+	 60, // 0xfaf
+	 60, // 0xfb0
+	 60, // 0xfb1
+	 60, // 0xfb2
+	 60, // 0xfb3
+	 60, // 0xfb4
+	 60, // 0xfb5
+	 60, // 0xfb6
+	 60, // 0xfb7
+	 60, // 0xfb8
+	 60, // 0xfb9
+	 60, // 0xfba
+	 60, // 0xfbb
+	 60, // 0xfbc
+	 60, // 0xfbd
+	 60, // 0xfbe
+	 60, // 0xfbf
+	 60, // 0xfc0
+	 60, // 0xfc1
+	 60, // 0xfc2
+	 60, // 0xfc3
+	 60, // 0xfc4
+	 60, // 0xfc5
+	 60, // 0xfc6
+	 60, // 0xfc7
+	 60, // 0xfc8
+	 60, // 0xfc9
+	 60, // 0xfca
+	 60, // 0xfcb
+	 60, // 0xfcc
+	 60, // 0xfcd
+	 60, // 0xfce
+	 60, // 0xfcf
+	 60, // 0xfd0
+	 60, // 0xfd1
+	 60, // 0xfd2
+	 60, // 0xfd3
+	 60, // 0xfd4
+	 60, // 0xfd5
+	 60, // 0xfd6
+	 60, // 0xfd7
+	 60, // 0xfd8
+	 60, // 0xfd9
+	 60, // 0xfda
+	 60, // 0xfdb
+	 60, // 0xfdc
+	 60, // 0xfdd
+	 60, // 0xfde
+	 60, // 0xfdf
+	 60, // 0xfe0
+	 60, // 0xfe1
+	 60, // 0xfe2
+	 60, // 0xfe3
+	 60, // 0xfe4
+	 60, // 0xfe5
+	 60, // 0xfe6
+	 60, // 0xfe7
+	 60, // 0xfe8
+	 60, // 0xfe9
+	 60, // 0xfea
+	 60, // 0xfeb
+	 60, // 0xfec
+	 60, // 0xfed
+	 60, // 0xfee
+	 60, // 0xfef
+	 60, // 0xff0
+	 60, // 0xff1
+	 60, // 0xff2
+	 60, // 0xff3
+	 60, // 0xff4
+	 60, // 0xff5
+	 60, // 0xff6
+	 60, // 0xff7
+	 60, // 0xff8
+	 60, // 0xff9
+	 60, // 0xffa
+	 60, // 0xffb
+	 60, // 0xffc
+	 60, // 0xffd
+	 60, // 0xffe
+	 152, // 0xfff
+	 60, // 0x1000
+	 60, // 0x1001
+	 60, // 0x1002
+	 60, // 0x1003
+	 60, // 0x1004
+	 60, // 0x1005
+	 60, // 0x1006
+	 60, // 0x1007
+	 60, // 0x1008
+	 60, // 0x1009
+	 60, // 0x100a
+	 60, // 0x100b
+	 60, // 0x100c
+	 60, // 0x100d
+	 60, // 0x100e
+	 60, // 0x100f
+	 60, // 0x1010
+	 60, // 0x1011
+	 60, // 0x1012
+	 60, // 0x1013
+	 60, // 0x1014
+	 60, // 0x1015
+	 60, // 0x1016
+	 60, // 0x1017
+	 60, // 0x1018
+	 60, // 0x1019
+	 60, // 0x101a
+	 60, // 0x101b
+	 60, // 0x101c
+	 60, // 0x101d
+	 60, // 0x101e
+	 60, // 0x101f
+	 60, // 0x1020
+	 60, // 0x1021
+	 60, // 0x1022
+	 60, // 0x1023
+	 60, // 0x1024
+	 60, // 0x1025
+	 60, // 0x1026
+	 60, // 0x1027
+	 60, // 0x1028
+	 60, // 0x1029
+	 60, // 0x102a
+	 60, // 0x102b
+	 60, // 0x102c
+	 60, // 0x102d
+	 60, // 0x102e
+	 60, // 0x102f
+	 60, // 0x1030
+	 60, // 0x1031
+	 60, // 0x1032
+	 60, // 0x1033
+	 60, // 0x1034
+	 60, // 0x1035
+	 60, // 0x1036
+	 60, // 0x1037
+	 60, // 0x1038
+	 60, // 0x1039
+	 60, // 0x103a
+	 60, // 0x103b
+	 60, // 0x103c
+	 60, // 0x103d
+	 60, // 0x103e
+	 60, // 0x103f
+	 60, // 0x1040
+	 60, // 0x1041
+	 60, // 0x1042
+	 60, // 0x1043
+	 60, // 0x1044
+	 60, // 0x1045
+	 60, // 0x1046
+	 60, // 0x1047
+	 60, // 0x1048
+	 60, // 0x1049
+	 60, // 0x104a
+	 60, // 0x104b
+	 60, // 0x104c
+	 60, // 0x104d
+	 60, // 0x104e
+	 60, // 0x104f
+	 60, // 0x1050
+	 60, // 0x1051
+	 60, // 0x1052
+	 60, // 0x1053
+	 60, // 0x1054
+	 60, // 0x1055
+	 60, // 0x1056
+	 60, // 0x1057
+	 60, // 0x1058
+	 60, // 0x1059
+	 60, // 0x105a
+	 60, // 0x105b
+	 60, // 0x105c
+	 60, // 0x105d
+	 60, // 0x105e
+	 60, // 0x105f
+	 60, // 0x1060
+	 60, // 0x1061
+	 60, // 0x1062
+	 60, // 0x1063
+	 60, // 0x1064
+	 60, // 0x1065
+	 60, // 0x1066
+	 60, // 0x1067
+	 60, // 0x1068
+	 60, // 0x1069
+	 60, // 0x106a
+	 60, // 0x106b
+	 60, // 0x106c
+	 60, // 0x106d
+	 60, // 0x106e
+	 60, // 0x106f
+	 60, // 0x1070
+	 60, // 0x1071
+	 60, // 0x1072
+	 60, // 0x1073
+	 60, // 0x1074
+	 60, // 0x1075
+	 60, // 0x1076
+	 60, // 0x1077
+	 60, // 0x1078
+	 60, // 0x1079
+	 60, // 0x107a
+	 60, // 0x107b
+	 60, // 0x107c
+	 60, // 0x107d
+	 60, // 0x107e
+	 60, // 0x107f
+	 60, // 0x1080
+	 60, // 0x1081
+	 60, // 0x1082
+	 60, // 0x1083
+	 60, // 0x1084
+	 60, // 0x1085
+	 60, // 0x1086
+	 60, // 0x1087
+	 60, // 0x1088
+	 60, // 0x1089
+	 60, // 0x108a
+	 60, // 0x108b
+	 60, // 0x108c
+	 60, // 0x108d
+	 60, // 0x108e
+	 60, // 0x108f
+	 60, // 0x1090
+	 60, // 0x1091
+	 60, // 0x1092
+	 60, // 0x1093
+	 60, // 0x1094
+	 60, // 0x1095
+	 60, // 0x1096
+	 60, // 0x1097
+	 60, // 0x1098
+	 60, // 0x1099
+	 60, // 0x109a
+	 60, // 0x109b
+	 60, // 0x109c
+	 60, // 0x109d
+	 60, // 0x109e
+	 60, // 0x109f
+	 60, // 0x10a0
+	 60, // 0x10a1
+	 60, // 0x10a2
+	 60, // 0x10a3
+	 60, // 0x10a4
+	 60, // 0x10a5
+	 60, // 0x10a6
+	 60, // 0x10a7
+	 60, // 0x10a8
+	 60, // 0x10a9
+	 60, // 0x10aa
+	 60, // 0x10ab
+	 60, // 0x10ac
+	 60, // 0x10ad
+	 60, // 0x10ae
+	 60, // 0x10af
+	 60, // 0x10b0
+	 60, // 0x10b1
+	 60, // 0x10b2
+	 60, // 0x10b3
+	 60, // 0x10b4
+	 60, // 0x10b5
+	 60, // 0x10b6
+	 60, // 0x10b7
+	 60, // 0x10b8
+	 60, // 0x10b9
+	 60, // 0x10ba
+	 60, // 0x10bb
+	 60, // 0x10bc
+	 60, // 0x10bd
+	 60, // 0x10be
+	 60, // 0x10bf
+	 60, // 0x10c0
+	 60, // 0x10c1
+	 60, // 0x10c2
+	 60, // 0x10c3
+	 60, // 0x10c4
+	 60, // 0x10c5
+	 60, // 0x10c6
+	 60, // 0x10c7
+	 60, // 0x10c8
+	 60, // 0x10c9
+	 60, // 0x10ca
+	 60, // 0x10cb
+	 60, // 0x10cc
+	 60, // 0x10cd
+	 60, // 0x10ce
+	 60, // 0x10cf
+	 60, // 0x10d0
+	 60, // 0x10d1
+	 60, // 0x10d2
+	 60, // 0x10d3
+	 60, // 0x10d4
+	 60, // 0x10d5
+	 60, // 0x10d6
+	 60, // 0x10d7
+	 60, // 0x10d8
+	 60, // 0x10d9
+	 60, // 0x10da
+	 60, // 0x10db
+	 60, // 0x10dc
+	 60, // 0x10dd
+	 60, // 0x10de
+	 60, // 0x10df
+	 60, // 0x10e0
+	 60, // 0x10e1
+	 60, // 0x10e2
+	 60, // 0x10e3
+	 60, // 0x10e4
+	 60, // 0x10e5
+	 60, // 0x10e6
+	 60, // 0x10e7
+	 60, // 0x10e8
+	 60, // 0x10e9
+	 60, // 0x10ea
+	 60, // 0x10eb
+	 60, // 0x10ec
+	 60, // 0x10ed
+	 60, // 0x10ee
+	 60, // 0x10ef
+	 60, // 0x10f0
+	 60, // 0x10f1
+	 60, // 0x10f2
+	 60, // 0x10f3
+	 60, // 0x10f4
+	 60, // 0x10f5
+	 60, // 0x10f6
+	 60, // 0x10f7
+	 60, // 0x10f8
+	 60, // 0x10f9
+	 60, // 0x10fa
+	 60, // 0x10fb
+	 60, // 0x10fc
+	 60, // 0x10fd
+	 60, // 0x10fe
+	 152, // 0x10ff
+];
+pub const CAT_PAGES: [[u8;256];153] = [
+    [
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x50,
+        0x36,
+        0x36,
+        0x36,
+        0x41,
+        0x36,
+        0x36,
+        0x36,
+        0x32,
+        0x33,
+        0x36,
+        0x40,
+        0x36,
+        0x31,
+        0x36,
+        0x36,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x36,
+        0x36,
+        0x40,
+        0x40,
+        0x40,
+        0x36,
+        0x36,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x32,
+        0x36,
+        0x33,
+        0x42,
+        0x30,
+        0x42,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x32,
+        0x40,
+        0x33,
+        0x40,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x50,
+        0x36,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x43,
+        0x36,
+        0x42,
+        0x43,
+        0x84,
+        0x34,
+        0x40,
+        0x62,
+        0x43,
+        0x42,
+        0x43,
+        0x40,
+        0x22,
+        0x22,
+        0x42,
+        0x91,
+        0x36,
+        0x36,
+        0x42,
+        0x22,
+        0x84,
+        0x35,
+        0x22,
+        0x22,
+        0x22,
+        0x36,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x40,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x40,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+    ],
+    [
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x84,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x90,
+        0x92,
+        0x91,
+        0x90,
+        0x92,
+        0x91,
+        0x90,
+        0x92,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x91,
+        0x90,
+        0x92,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+    ],
+    [
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x84,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x83,
+        0x42,
+        0x83,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x83,
+        0x42,
+        0x90,
+        0x91,
+        0x60,
+        0x60,
+        0x83,
+        0x91,
+        0x91,
+        0x91,
+        0x36,
+        0x90,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x42,
+        0x42,
+        0x90,
+        0x36,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x90,
+        0x60,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x91,
+        0x40,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+    ],
+    [
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x43,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+    ],
+    [
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x60,
+        0x83,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x36,
+        0x31,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x41,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x31,
+        0x10,
+        0x36,
+        0x10,
+        0x10,
+        0x36,
+        0x10,
+        0x10,
+        0x36,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x40,
+        0x40,
+        0x40,
+        0x36,
+        0x36,
+        0x41,
+        0x36,
+        0x36,
+        0x43,
+        0x43,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x36,
+        0x62,
+        0x36,
+        0x36,
+        0x36,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x83,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x84,
+        0x84,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x36,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x62,
+        0x43,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x83,
+        0x83,
+        0x10,
+        0x10,
+        0x43,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x84,
+        0x84,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x84,
+        0x84,
+        0x84,
+        0x43,
+        0x43,
+        0x84,
+    ],
+    [
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x62,
+        0x84,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x83,
+        0x83,
+        0x43,
+        0x36,
+        0x36,
+        0x36,
+        0x83,
+        0x60,
+        0x60,
+        0x10,
+        0x41,
+        0x41,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x83,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x83,
+        0x10,
+        0x10,
+        0x10,
+        0x83,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x36,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x42,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x62,
+        0x62,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x83,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x62,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x11,
+        0x10,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x36,
+        0x36,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x36,
+        0x83,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x11,
+        0x11,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x10,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x11,
+        0x11,
+        0x60,
+        0x60,
+        0x11,
+        0x11,
+        0x10,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x11,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x84,
+        0x84,
+        0x41,
+        0x41,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x43,
+        0x41,
+        0x84,
+        0x36,
+        0x10,
+        0x60,
+    ],
+    [
+        0x60,
+        0x10,
+        0x10,
+        0x11,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x10,
+        0x60,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x10,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x11,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x10,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x10,
+        0x10,
+        0x11,
+        0x60,
+        0x11,
+        0x11,
+        0x10,
+        0x60,
+        0x60,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x36,
+        0x41,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x60,
+        0x10,
+        0x11,
+        0x11,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x10,
+        0x84,
+        0x11,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x11,
+        0x11,
+        0x60,
+        0x60,
+        0x11,
+        0x11,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x11,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x43,
+        0x84,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x60,
+        0x60,
+        0x60,
+        0x11,
+        0x11,
+        0x11,
+        0x60,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x60,
+        0x60,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x11,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x22,
+        0x22,
+        0x22,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x41,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x10,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x43,
+        0x84,
+        0x10,
+        0x11,
+        0x11,
+        0x36,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x10,
+        0x84,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x60,
+        0x10,
+        0x11,
+        0x11,
+        0x60,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x11,
+        0x11,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x11,
+        0x11,
+        0x11,
+        0x60,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x84,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x43,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x10,
+        0x11,
+        0x11,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x10,
+        0x60,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x11,
+        0x11,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x41,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x83,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x36,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x83,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x43,
+        0x43,
+        0x43,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x43,
+        0x36,
+        0x43,
+        0x43,
+        0x43,
+        0x10,
+        0x10,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x43,
+        0x10,
+        0x43,
+        0x10,
+        0x43,
+        0x10,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x11,
+        0x11,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x36,
+        0x10,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x10,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x43,
+        0x43,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x84,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x84,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x84,
+        0x11,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x43,
+        0x43,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x90,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x90,
+        0x60,
+        0x60,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x36,
+        0x83,
+        0x91,
+        0x91,
+        0x91,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x60,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x60,
+    ],
+    [
+        0x31,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x43,
+        0x36,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x50,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x32,
+        0x33,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x36,
+        0x36,
+        0x36,
+        0x21,
+        0x21,
+        0x21,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x11,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x36,
+        0x36,
+        0x36,
+        0x83,
+        0x36,
+        0x36,
+        0x36,
+        0x41,
+        0x84,
+        0x10,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x31,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x10,
+        0x10,
+        0x10,
+        0x62,
+        0x10,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x83,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+        0x36,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x60,
+        0x60,
+        0x36,
+        0x36,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x10,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x10,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x83,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x36,
+        0x36,
+        0x60,
+        0x10,
+        0x10,
+        0x11,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x84,
+        0x84,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x36,
+        0x36,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x36,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x84,
+        0x84,
+        0x11,
+        0x10,
+        0x10,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x83,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+    ],
+    [
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x60,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x60,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x90,
+        0x60,
+        0x90,
+        0x60,
+        0x90,
+        0x60,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x60,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x92,
+        0x42,
+        0x91,
+        0x42,
+        0x42,
+        0x42,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x92,
+        0x42,
+        0x42,
+        0x42,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x60,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x42,
+        0x42,
+        0x42,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x42,
+        0x42,
+        0x42,
+        0x60,
+        0x60,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x92,
+        0x42,
+        0x42,
+        0x60,
+    ],
+    [
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x36,
+        0x36,
+        0x34,
+        0x35,
+        0x32,
+        0x34,
+        0x34,
+        0x35,
+        0x32,
+        0x34,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x51,
+        0x52,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x50,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x34,
+        0x35,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x30,
+        0x30,
+        0x36,
+        0x36,
+        0x36,
+        0x40,
+        0x32,
+        0x33,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x40,
+        0x36,
+        0x30,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x50,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x60,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x22,
+        0x83,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x40,
+        0x40,
+        0x40,
+        0x32,
+        0x33,
+        0x83,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x40,
+        0x40,
+        0x40,
+        0x32,
+        0x33,
+        0x60,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x60,
+        0x60,
+        0x60,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x43,
+        0x43,
+        0x90,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x90,
+        0x43,
+        0x43,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x43,
+        0x90,
+        0x43,
+        0x43,
+        0x40,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x90,
+        0x43,
+        0x90,
+        0x43,
+        0x90,
+        0x43,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x43,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x91,
+        0x43,
+        0x43,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x43,
+        0x40,
+        0x43,
+        0x43,
+        0x91,
+        0x43,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x90,
+        0x91,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x22,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x40,
+        0x40,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x40,
+        0x43,
+        0x43,
+        0x40,
+        0x43,
+        0x43,
+        0x40,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x40,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x40,
+        0x40,
+        0x43,
+        0x43,
+        0x40,
+        0x43,
+        0x40,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+    ],
+    [
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x40,
+        0x40,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x32,
+        0x33,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x40,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x40,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x40,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x40,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x32,
+        0x33,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+    ],
+    [
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x32,
+        0x33,
+        0x40,
+        0x40,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x43,
+        0x43,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+    ],
+    [
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x91,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x83,
+        0x83,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x91,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x10,
+        0x10,
+        0x10,
+        0x90,
+        0x91,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x22,
+        0x36,
+        0x36,
+    ],
+    [
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x91,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x91,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x83,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x36,
+        0x36,
+        0x34,
+        0x35,
+        0x34,
+        0x35,
+        0x36,
+        0x36,
+        0x36,
+        0x34,
+        0x35,
+        0x36,
+        0x34,
+        0x35,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x31,
+        0x36,
+        0x36,
+        0x31,
+        0x36,
+        0x34,
+        0x35,
+        0x36,
+        0x36,
+        0x34,
+        0x35,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x83,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x31,
+        0x31,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x31,
+        0x36,
+        0x32,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x43,
+        0x43,
+        0x36,
+        0x36,
+        0x36,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x31,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x50,
+        0x36,
+        0x36,
+        0x36,
+        0x43,
+        0x83,
+        0x84,
+        0x21,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x43,
+        0x43,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x31,
+        0x32,
+        0x33,
+        0x33,
+        0x43,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x31,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x43,
+        0x43,
+        0x21,
+        0x21,
+        0x21,
+        0x83,
+        0x84,
+        0x36,
+        0x43,
+        0x43,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x42,
+        0x42,
+        0x83,
+        0x83,
+        0x84,
+        0x31,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x36,
+        0x83,
+        0x83,
+        0x83,
+        0x84,
+    ],
+    [
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x43,
+        0x43,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x43,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x83,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x36,
+        0x36,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x83,
+        0x36,
+        0x36,
+        0x36,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x84,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x36,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x36,
+        0x83,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x83,
+        0x83,
+        0x10,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x10,
+        0x10,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x42,
+        0x42,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x83,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x83,
+        0x42,
+        0x42,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x84,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x90,
+        0x91,
+        0x60,
+        0x91,
+        0x60,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x83,
+        0x83,
+        0x83,
+        0x90,
+        0x91,
+        0x84,
+        0x83,
+        0x83,
+        0x91,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+    ],
+    [
+        0x84,
+        0x84,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x43,
+        0x43,
+        0x41,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x11,
+        0x11,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+        0x36,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x36,
+        0x36,
+        0x36,
+        0x84,
+        0x36,
+        0x84,
+        0x84,
+        0x10,
+    ],
+    [
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x36,
+        0x36,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x83,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+        0x36,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x83,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x11,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x83,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x43,
+        0x43,
+        0x43,
+        0x84,
+        0x11,
+        0x10,
+        0x11,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x84,
+        0x10,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x83,
+        0x36,
+        0x36,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x36,
+        0x36,
+        0x84,
+        0x83,
+        0x83,
+        0x11,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x42,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x83,
+        0x42,
+        0x42,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x36,
+        0x11,
+        0x10,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+    ],
+    [
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x40,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x33,
+        0x32,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x41,
+        0x43,
+        0x43,
+        0x43,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x32,
+        0x33,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x36,
+        0x31,
+        0x31,
+        0x30,
+        0x30,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x36,
+        0x36,
+        0x32,
+        0x33,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x30,
+        0x30,
+        0x30,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x31,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x36,
+        0x36,
+        0x36,
+        0x40,
+        0x31,
+        0x40,
+        0x40,
+        0x40,
+        0x60,
+        0x36,
+        0x41,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x62,
+    ],
+    [
+        0x60,
+        0x36,
+        0x36,
+        0x36,
+        0x41,
+        0x36,
+        0x36,
+        0x36,
+        0x32,
+        0x33,
+        0x36,
+        0x40,
+        0x36,
+        0x31,
+        0x36,
+        0x36,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x36,
+        0x36,
+        0x40,
+        0x40,
+        0x40,
+        0x36,
+        0x36,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x32,
+        0x36,
+        0x33,
+        0x42,
+        0x30,
+        0x42,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x32,
+        0x40,
+        0x33,
+        0x40,
+        0x32,
+        0x33,
+        0x36,
+        0x32,
+        0x33,
+        0x36,
+        0x36,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x83,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x83,
+        0x83,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x41,
+        0x41,
+        0x40,
+        0x42,
+        0x43,
+        0x41,
+        0x41,
+        0x60,
+        0x43,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x62,
+        0x62,
+        0x62,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x22,
+        0x22,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x10,
+        0x60,
+        0x60,
+    ],
+    [
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x21,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x21,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x36,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x36,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x90,
+        0x90,
+        0x60,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x91,
+        0x91,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x60,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x60,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x36,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x43,
+        0x43,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x84,
+        0x84,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+    ],
+    [
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x22,
+        0x22,
+        0x36,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x43,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x10,
+        0x10,
+        0x31,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x11,
+        0x10,
+        0x11,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x10,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x36,
+        0x36,
+        0x62,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x62,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x84,
+        0x11,
+        0x11,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x36,
+        0x36,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x11,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x36,
+        0x11,
+        0x10,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x84,
+        0x36,
+        0x84,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x10,
+        0x10,
+        0x84,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x60,
+        0x60,
+        0x11,
+        0x11,
+        0x60,
+        0x60,
+        0x11,
+        0x11,
+        0x11,
+        0x60,
+        0x60,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x11,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x11,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x36,
+        0x36,
+        0x60,
+        0x36,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x84,
+        0x84,
+        0x36,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x36,
+        0x36,
+        0x36,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x84,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x22,
+        0x22,
+        0x36,
+        0x36,
+        0x36,
+        0x43,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x60,
+        0x11,
+        0x11,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x84,
+        0x11,
+        0x84,
+        0x11,
+        0x10,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x84,
+        0x36,
+        0x84,
+        0x11,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x36,
+        0x36,
+        0x36,
+        0x84,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x84,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+        0x36,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x60,
+        0x10,
+        0x10,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x84,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x60,
+        0x10,
+        0x10,
+        0x60,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x10,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x60,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+        0x36,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x36,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x83,
+        0x83,
+        0x36,
+        0x83,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x11,
+        0x11,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x60,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x60,
+        0x83,
+        0x83,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x43,
+        0x10,
+        0x10,
+        0x36,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x43,
+        0x43,
+        0x43,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x43,
+        0x43,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x10,
+        0x10,
+        0x10,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x60,
+        0x90,
+        0x90,
+        0x60,
+        0x60,
+        0x90,
+        0x60,
+        0x60,
+        0x90,
+        0x90,
+        0x60,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x91,
+        0x60,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+    ],
+    [
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x90,
+        0x60,
+        0x60,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+    ],
+    [
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x40,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x40,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x40,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+    ],
+    [
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x40,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x40,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x40,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x40,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x40,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x40,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x40,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x91,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x10,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x10,
+        0x43,
+        0x43,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x84,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x10,
+        0x10,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x41,
+    ],
+    [
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x83,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x43,
+        0x22,
+        0x22,
+        0x22,
+        0x41,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x43,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x40,
+        0x40,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x60,
+        0x62,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x60,
+        0x60,
+    ],
+];
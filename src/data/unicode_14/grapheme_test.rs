@@ -0,0 +1,2417 @@
+// GENERATED CODE DO NOT MANUALLY EDIT
+// Generated from Unicode 14.0.0 data files:
+//   23dc23374dbb42630336b79e6e497002aaf9cd8f78c9548ec2434a7855e78b8c  ucd/auxiliary/GraphemeBreakTest.txt
+
+use crate::grapheme_clusters::tests::grapheme_test;
+
+#[test]
+fn standard_grapheme_test() {
+	grapheme_test("\u{0020}\u{0020}",
+		&["\u{0020}", "\u{0020}"],
+		""
+	);
+	grapheme_test("\u{0020}\u{0308}\u{0020}",
+		&["\u{0020}\u{0308}", "\u{0020}"],
+		""
+	);
+	grapheme_test("\u{0020}\u{000D}",
+		&["\u{0020}", "\u{000D}"],
+		""
+	);
+	grapheme_test("\u{0020}\u{0308}\u{000D}",
+		&["\u{0020}\u{0308}", "\u{000D}"],
+		""
+	);
+	grapheme_test("\u{0020}\u{000A}",
+		&["\u{0020}", "\u{000A}"],
+		""
+	);
+	grapheme_test("\u{0020}\u{0308}\u{000A}",
+		&["\u{0020}\u{0308}", "\u{000A}"],
+		""
+	);
+	grapheme_test("\u{0020}\u{0001}",
+		&["\u{0020}", "\u{0001}"],
+		""
+	);
+	grapheme_test("\u{0020}\u{0308}\u{0001}",
+		&["\u{0020}\u{0308}", "\u{0001}"],
+		""
+	);
+	grapheme_test("\u{0020}\u{034F}",
+		&["\u{0020}\u{034F}"],
+		""
+	);
+	grapheme_test("\u{0020}\u{0308}\u{034F}",
+		&["\u{0020}\u{0308}\u{034F}"],
+		""
+	);
+	grapheme_test("\u{0020}\u{1F1E6}",
+		&["\u{0020}", "\u{1F1E6}"],
+		""
+	);
+	grapheme_test("\u{0020}\u{0308}\u{1F1E6}",
+		&["\u{0020}\u{0308}", "\u{1F1E6}"],
+		""
+	);
+	grapheme_test("\u{0020}\u{0600}",
+		&["\u{0020}", "\u{0600}"],
+		""
+	);
+	grapheme_test("\u{0020}\u{0308}\u{0600}",
+		&["\u{0020}\u{0308}", "\u{0600}"],
+		""
+	);
+	grapheme_test("\u{0020}\u{1100}",
+		&["\u{0020}", "\u{1100}"],
+		""
+	);
+	grapheme_test("\u{0020}\u{0308}\u{1100}",
+		&["\u{0020}\u{0308}", "\u{1100}"],
+		""
+	);
+	grapheme_test("\u{0020}\u{1160}",
+		&["\u{0020}", "\u{1160}"],
+		""
+	);
+	grapheme_test("\u{0020}\u{0308}\u{1160}",
+		&["\u{0020}\u{0308}", "\u{1160}"],
+		""
+	);
+	grapheme_test("\u{0020}\u{11A8}",
+		&["\u{0020}", "\u{11A8}"],
+		""
+	);
+	grapheme_test("\u{0020}\u{0308}\u{11A8}",
+		&["\u{0020}\u{0308}", "\u{11A8}"],
+		""
+	);
+	grapheme_test("\u{0020}\u{AC00}",
+		&["\u{0020}", "\u{AC00}"],
+		""
+	);
+	grapheme_test("\u{0020}\u{0308}\u{AC00}",
+		&["\u{0020}\u{0308}", "\u{AC00}"],
+		""
+	);
+	grapheme_test("\u{0020}\u{AC01}",
+		&["\u{0020}", "\u{AC01}"],
+		""
+	);
+	grapheme_test("\u{0020}\u{0308}\u{AC01}",
+		&["\u{0020}\u{0308}", "\u{AC01}"],
+		""
+	);
+	grapheme_test("\u{0020}\u{231A}",
+		&["\u{0020}", "\u{231A}"],
+		""
+	);
+	grapheme_test("\u{0020}\u{0308}\u{231A}",
+		&["\u{0020}\u{0308}", "\u{231A}"],
+		""
+	);
+	grapheme_test("\u{0020}\u{0300}",
+		&["\u{0020}\u{0300}"],
+		""
+	);
+	grapheme_test("\u{0020}\u{0308}\u{0300}",
+		&["\u{0020}\u{0308}\u{0300}"],
+		""
+	);
+	grapheme_test("\u{0020}\u{200D}",
+		&["\u{0020}\u{200D}"],
+		""
+	);
+	grapheme_test("\u{0020}\u{0308}\u{200D}",
+		&["\u{0020}\u{0308}\u{200D}"],
+		""
+	);
+	grapheme_test("\u{0020}\u{0378}",
+		&["\u{0020}", "\u{0378}"],
+		""
+	);
+	grapheme_test("\u{0020}\u{0308}\u{0378}",
+		&["\u{0020}\u{0308}", "\u{0378}"],
+		""
+	);
+	grapheme_test("\u{000D}\u{0020}",
+		&["\u{000D}", "\u{0020}"],
+		""
+	);
+	grapheme_test("\u{000D}\u{0308}\u{0020}",
+		&["\u{000D}", "\u{0308}", "\u{0020}"],
+		""
+	);
+	grapheme_test("\u{000D}\u{000D}",
+		&["\u{000D}", "\u{000D}"],
+		""
+	);
+	grapheme_test("\u{000D}\u{0308}\u{000D}",
+		&["\u{000D}", "\u{0308}", "\u{000D}"],
+		""
+	);
+	grapheme_test("\u{000D}\u{000A}",
+		&["\u{000D}\u{000A}"],
+		""
+	);
+	grapheme_test("\u{000D}\u{0308}\u{000A}",
+		&["\u{000D}", "\u{0308}", "\u{000A}"],
+		""
+	);
+	grapheme_test("\u{000D}\u{0001}",
+		&["\u{000D}", "\u{0001}"],
+		""
+	);
+	grapheme_test("\u{000D}\u{0308}\u{0001}",
+		&["\u{000D}", "\u{0308}", "\u{0001}"],
+		""
+	);
+	grapheme_test("\u{000D}\u{034F}",
+		&["\u{000D}", "\u{034F}"],
+		""
+	);
+	grapheme_test("\u{000D}\u{0308}\u{034F}",
+		&["\u{000D}", "\u{0308}\u{034F}"],
+		""
+	);
+	grapheme_test("\u{000D}\u{1F1E6}",
+		&["\u{000D}", "\u{1F1E6}"],
+		""
+	);
+	grapheme_test("\u{000D}\u{0308}\u{1F1E6}",
+		&["\u{000D}", "\u{0308}", "\u{1F1E6}"],
+		""
+	);
+	grapheme_test("\u{000D}\u{0600}",
+		&["\u{000D}", "\u{0600}"],
+		""
+	);
+	grapheme_test("\u{000D}\u{0308}\u{0600}",
+		&["\u{000D}", "\u{0308}", "\u{0600}"],
+		""
+	);
+	grapheme_test("\u{000D}\u{0903}",
+		&["\u{000D}", "\u{0903}"],
+		""
+	);
+	grapheme_test("\u{000D}\u{1100}",
+		&["\u{000D}", "\u{1100}"],
+		""
+	);
+	grapheme_test("\u{000D}\u{0308}\u{1100}",
+		&["\u{000D}", "\u{0308}", "\u{1100}"],
+		""
+	);
+	grapheme_test("\u{000D}\u{1160}",
+		&["\u{000D}", "\u{1160}"],
+		""
+	);
+	grapheme_test("\u{000D}\u{0308}\u{1160}",
+		&["\u{000D}", "\u{0308}", "\u{1160}"],
+		""
+	);
+	grapheme_test("\u{000D}\u{11A8}",
+		&["\u{000D}", "\u{11A8}"],
+		""
+	);
+	grapheme_test("\u{000D}\u{0308}\u{11A8}",
+		&["\u{000D}", "\u{0308}", "\u{11A8}"],
+		""
+	);
+	grapheme_test("\u{000D}\u{AC00}",
+		&["\u{000D}", "\u{AC00}"],
+		""
+	);
+	grapheme_test("\u{000D}\u{0308}\u{AC00}",
+		&["\u{000D}", "\u{0308}", "\u{AC00}"],
+		""
+	);
+	grapheme_test("\u{000D}\u{AC01}",
+		&["\u{000D}", "\u{AC01}"],
+		""
+	);
+	grapheme_test("\u{000D}\u{0308}\u{AC01}",
+		&["\u{000D}", "\u{0308}", "\u{AC01}"],
+		""
+	);
+	grapheme_test("\u{000D}\u{231A}",
+		&["\u{000D}", "\u{231A}"],
+		""
+	);
+	grapheme_test("\u{000D}\u{0308}\u{231A}",
+		&["\u{000D}", "\u{0308}", "\u{231A}"],
+		""
+	);
+	grapheme_test("\u{000D}\u{0300}",
+		&["\u{000D}", "\u{0300}"],
+		""
+	);
+	grapheme_test("\u{000D}\u{0308}\u{0300}",
+		&["\u{000D}", "\u{0308}\u{0300}"],
+		""
+	);
+	grapheme_test("\u{000D}\u{200D}",
+		&["\u{000D}", "\u{200D}"],
+		""
+	);
+	grapheme_test("\u{000D}\u{0308}\u{200D}",
+		&["\u{000D}", "\u{0308}\u{200D}"],
+		""
+	);
+	grapheme_test("\u{000D}\u{0378}",
+		&["\u{000D}", "\u{0378}"],
+		""
+	);
+	grapheme_test("\u{000D}\u{0308}\u{0378}",
+		&["\u{000D}", "\u{0308}", "\u{0378}"],
+		""
+	);
+	grapheme_test("\u{000A}\u{0020}",
+		&["\u{000A}", "\u{0020}"],
+		""
+	);
+	grapheme_test("\u{000A}\u{0308}\u{0020}",
+		&["\u{000A}", "\u{0308}", "\u{0020}"],
+		""
+	);
+	grapheme_test("\u{000A}\u{000D}",
+		&["\u{000A}", "\u{000D}"],
+		""
+	);
+	grapheme_test("\u{000A}\u{0308}\u{000D}",
+		&["\u{000A}", "\u{0308}", "\u{000D}"],
+		""
+	);
+	grapheme_test("\u{000A}\u{000A}",
+		&["\u{000A}", "\u{000A}"],
+		""
+	);
+	grapheme_test("\u{000A}\u{0308}\u{000A}",
+		&["\u{000A}", "\u{0308}", "\u{000A}"],
+		""
+	);
+	grapheme_test("\u{000A}\u{0001}",
+		&["\u{000A}", "\u{0001}"],
+		""
+	);
+	grapheme_test("\u{000A}\u{0308}\u{0001}",
+		&["\u{000A}", "\u{0308}", "\u{0001}"],
+		""
+	);
+	grapheme_test("\u{000A}\u{034F}",
+		&["\u{000A}", "\u{034F}"],
+		""
+	);
+	grapheme_test("\u{000A}\u{0308}\u{034F}",
+		&["\u{000A}", "\u{0308}\u{034F}"],
+		""
+	);
+	grapheme_test("\u{000A}\u{1F1E6}",
+		&["\u{000A}", "\u{1F1E6}"],
+		""
+	);
+	grapheme_test("\u{000A}\u{0308}\u{1F1E6}",
+		&["\u{000A}", "\u{0308}", "\u{1F1E6}"],
+		""
+	);
+	grapheme_test("\u{000A}\u{0600}",
+		&["\u{000A}", "\u{0600}"],
+		""
+	);
+	grapheme_test("\u{000A}\u{0308}\u{0600}",
+		&["\u{000A}", "\u{0308}", "\u{0600}"],
+		""
+	);
+	grapheme_test("\u{000A}\u{0903}",
+		&["\u{000A}", "\u{0903}"],
+		""
+	);
+	grapheme_test("\u{000A}\u{1100}",
+		&["\u{000A}", "\u{1100}"],
+		""
+	);
+	grapheme_test("\u{000A}\u{0308}\u{1100}",
+		&["\u{000A}", "\u{0308}", "\u{1100}"],
+		""
+	);
+	grapheme_test("\u{000A}\u{1160}",
+		&["\u{000A}", "\u{1160}"],
+		""
+	);
+	grapheme_test("\u{000A}\u{0308}\u{1160}",
+		&["\u{000A}", "\u{0308}", "\u{1160}"],
+		""
+	);
+	grapheme_test("\u{000A}\u{11A8}",
+		&["\u{000A}", "\u{11A8}"],
+		""
+	);
+	grapheme_test("\u{000A}\u{0308}\u{11A8}",
+		&["\u{000A}", "\u{0308}", "\u{11A8}"],
+		""
+	);
+	grapheme_test("\u{000A}\u{AC00}",
+		&["\u{000A}", "\u{AC00}"],
+		""
+	);
+	grapheme_test("\u{000A}\u{0308}\u{AC00}",
+		&["\u{000A}", "\u{0308}", "\u{AC00}"],
+		""
+	);
+	grapheme_test("\u{000A}\u{AC01}",
+		&["\u{000A}", "\u{AC01}"],
+		""
+	);
+	grapheme_test("\u{000A}\u{0308}\u{AC01}",
+		&["\u{000A}", "\u{0308}", "\u{AC01}"],
+		""
+	);
+	grapheme_test("\u{000A}\u{231A}",
+		&["\u{000A}", "\u{231A}"],
+		""
+	);
+	grapheme_test("\u{000A}\u{0308}\u{231A}",
+		&["\u{000A}", "\u{0308}", "\u{231A}"],
+		""
+	);
+	grapheme_test("\u{000A}\u{0300}",
+		&["\u{000A}", "\u{0300}"],
+		""
+	);
+	grapheme_test("\u{000A}\u{0308}\u{0300}",
+		&["\u{000A}", "\u{0308}\u{0300}"],
+		""
+	);
+	grapheme_test("\u{000A}\u{200D}",
+		&["\u{000A}", "\u{200D}"],
+		""
+	);
+	grapheme_test("\u{000A}\u{0308}\u{200D}",
+		&["\u{000A}", "\u{0308}\u{200D}"],
+		""
+	);
+	grapheme_test("\u{000A}\u{0378}",
+		&["\u{000A}", "\u{0378}"],
+		""
+	);
+	grapheme_test("\u{000A}\u{0308}\u{0378}",
+		&["\u{000A}", "\u{0308}", "\u{0378}"],
+		""
+	);
+	grapheme_test("\u{0001}\u{0020}",
+		&["\u{0001}", "\u{0020}"],
+		""
+	);
+	grapheme_test("\u{0001}\u{0308}\u{0020}",
+		&["\u{0001}", "\u{0308}", "\u{0020}"],
+		""
+	);
+	grapheme_test("\u{0001}\u{000D}",
+		&["\u{0001}", "\u{000D}"],
+		""
+	);
+	grapheme_test("\u{0001}\u{0308}\u{000D}",
+		&["\u{0001}", "\u{0308}", "\u{000D}"],
+		""
+	);
+	grapheme_test("\u{0001}\u{000A}",
+		&["\u{0001}", "\u{000A}"],
+		""
+	);
+	grapheme_test("\u{0001}\u{0308}\u{000A}",
+		&["\u{0001}", "\u{0308}", "\u{000A}"],
+		""
+	);
+	grapheme_test("\u{0001}\u{0001}",
+		&["\u{0001}", "\u{0001}"],
+		""
+	);
+	grapheme_test("\u{0001}\u{0308}\u{0001}",
+		&["\u{0001}", "\u{0308}", "\u{0001}"],
+		""
+	);
+	grapheme_test("\u{0001}\u{034F}",
+		&["\u{0001}", "\u{034F}"],
+		""
+	);
+	grapheme_test("\u{0001}\u{0308}\u{034F}",
+		&["\u{0001}", "\u{0308}\u{034F}"],
+		""
+	);
+	grapheme_test("\u{0001}\u{1F1E6}",
+		&["\u{0001}", "\u{1F1E6}"],
+		""
+	);
+	grapheme_test("\u{0001}\u{0308}\u{1F1E6}",
+		&["\u{0001}", "\u{0308}", "\u{1F1E6}"],
+		""
+	);
+	grapheme_test("\u{0001}\u{0600}",
+		&["\u{0001}", "\u{0600}"],
+		""
+	);
+	grapheme_test("\u{0001}\u{0308}\u{0600}",
+		&["\u{0001}", "\u{0308}", "\u{0600}"],
+		""
+	);
+	grapheme_test("\u{0001}\u{0903}",
+		&["\u{0001}", "\u{0903}"],
+		""
+	);
+	grapheme_test("\u{0001}\u{1100}",
+		&["\u{0001}", "\u{1100}"],
+		""
+	);
+	grapheme_test("\u{0001}\u{0308}\u{1100}",
+		&["\u{0001}", "\u{0308}", "\u{1100}"],
+		""
+	);
+	grapheme_test("\u{0001}\u{1160}",
+		&["\u{0001}", "\u{1160}"],
+		""
+	);
+	grapheme_test("\u{0001}\u{0308}\u{1160}",
+		&["\u{0001}", "\u{0308}", "\u{1160}"],
+		""
+	);
+	grapheme_test("\u{0001}\u{11A8}",
+		&["\u{0001}", "\u{11A8}"],
+		""
+	);
+	grapheme_test("\u{0001}\u{0308}\u{11A8}",
+		&["\u{0001}", "\u{0308}", "\u{11A8}"],
+		""
+	);
+	grapheme_test("\u{0001}\u{AC00}",
+		&["\u{0001}", "\u{AC00}"],
+		""
+	);
+	grapheme_test("\u{0001}\u{0308}\u{AC00}",
+		&["\u{0001}", "\u{0308}", "\u{AC00}"],
+		""
+	);
+	grapheme_test("\u{0001}\u{AC01}",
+		&["\u{0001}", "\u{AC01}"],
+		""
+	);
+	grapheme_test("\u{0001}\u{0308}\u{AC01}",
+		&["\u{0001}", "\u{0308}", "\u{AC01}"],
+		""
+	);
+	grapheme_test("\u{0001}\u{231A}",
+		&["\u{0001}", "\u{231A}"],
+		""
+	);
+	grapheme_test("\u{0001}\u{0308}\u{231A}",
+		&["\u{0001}", "\u{0308}", "\u{231A}"],
+		""
+	);
+	grapheme_test("\u{0001}\u{0300}",
+		&["\u{0001}", "\u{0300}"],
+		""
+	);
+	grapheme_test("\u{0001}\u{0308}\u{0300}",
+		&["\u{0001}", "\u{0308}\u{0300}"],
+		""
+	);
+	grapheme_test("\u{0001}\u{200D}",
+		&["\u{0001}", "\u{200D}"],
+		""
+	);
+	grapheme_test("\u{0001}\u{0308}\u{200D}",
+		&["\u{0001}", "\u{0308}\u{200D}"],
+		""
+	);
+	grapheme_test("\u{0001}\u{0378}",
+		&["\u{0001}", "\u{0378}"],
+		""
+	);
+	grapheme_test("\u{0001}\u{0308}\u{0378}",
+		&["\u{0001}", "\u{0308}", "\u{0378}"],
+		""
+	);
+	grapheme_test("\u{034F}\u{0020}",
+		&["\u{034F}", "\u{0020}"],
+		""
+	);
+	grapheme_test("\u{034F}\u{0308}\u{0020}",
+		&["\u{034F}\u{0308}", "\u{0020}"],
+		""
+	);
+	grapheme_test("\u{034F}\u{000D}",
+		&["\u{034F}", "\u{000D}"],
+		""
+	);
+	grapheme_test("\u{034F}\u{0308}\u{000D}",
+		&["\u{034F}\u{0308}", "\u{000D}"],
+		""
+	);
+	grapheme_test("\u{034F}\u{000A}",
+		&["\u{034F}", "\u{000A}"],
+		""
+	);
+	grapheme_test("\u{034F}\u{0308}\u{000A}",
+		&["\u{034F}\u{0308}", "\u{000A}"],
+		""
+	);
+	grapheme_test("\u{034F}\u{0001}",
+		&["\u{034F}", "\u{0001}"],
+		""
+	);
+	grapheme_test("\u{034F}\u{0308}\u{0001}",
+		&["\u{034F}\u{0308}", "\u{0001}"],
+		""
+	);
+	grapheme_test("\u{034F}\u{034F}",
+		&["\u{034F}\u{034F}"],
+		""
+	);
+	grapheme_test("\u{034F}\u{0308}\u{034F}",
+		&["\u{034F}\u{0308}\u{034F}"],
+		""
+	);
+	grapheme_test("\u{034F}\u{1F1E6}",
+		&["\u{034F}", "\u{1F1E6}"],
+		""
+	);
+	grapheme_test("\u{034F}\u{0308}\u{1F1E6}",
+		&["\u{034F}\u{0308}", "\u{1F1E6}"],
+		""
+	);
+	grapheme_test("\u{034F}\u{0600}",
+		&["\u{034F}", "\u{0600}"],
+		""
+	);
+	grapheme_test("\u{034F}\u{0308}\u{0600}",
+		&["\u{034F}\u{0308}", "\u{0600}"],
+		""
+	);
+	grapheme_test("\u{034F}\u{1100}",
+		&["\u{034F}", "\u{1100}"],
+		""
+	);
+	grapheme_test("\u{034F}\u{0308}\u{1100}",
+		&["\u{034F}\u{0308}", "\u{1100}"],
+		""
+	);
+	grapheme_test("\u{034F}\u{1160}",
+		&["\u{034F}", "\u{1160}"],
+		""
+	);
+	grapheme_test("\u{034F}\u{0308}\u{1160}",
+		&["\u{034F}\u{0308}", "\u{1160}"],
+		""
+	);
+	grapheme_test("\u{034F}\u{11A8}",
+		&["\u{034F}", "\u{11A8}"],
+		""
+	);
+	grapheme_test("\u{034F}\u{0308}\u{11A8}",
+		&["\u{034F}\u{0308}", "\u{11A8}"],
+		""
+	);
+	grapheme_test("\u{034F}\u{AC00}",
+		&["\u{034F}", "\u{AC00}"],
+		""
+	);
+	grapheme_test("\u{034F}\u{0308}\u{AC00}",
+		&["\u{034F}\u{0308}", "\u{AC00}"],
+		""
+	);
+	grapheme_test("\u{034F}\u{AC01}",
+		&["\u{034F}", "\u{AC01}"],
+		""
+	);
+	grapheme_test("\u{034F}\u{0308}\u{AC01}",
+		&["\u{034F}\u{0308}", "\u{AC01}"],
+		""
+	);
+	grapheme_test("\u{034F}\u{231A}",
+		&["\u{034F}", "\u{231A}"],
+		""
+	);
+	grapheme_test("\u{034F}\u{0308}\u{231A}",
+		&["\u{034F}\u{0308}", "\u{231A}"],
+		""
+	);
+	grapheme_test("\u{034F}\u{0300}",
+		&["\u{034F}\u{0300}"],
+		""
+	);
+	grapheme_test("\u{034F}\u{0308}\u{0300}",
+		&["\u{034F}\u{0308}\u{0300}"],
+		""
+	);
+	grapheme_test("\u{034F}\u{200D}",
+		&["\u{034F}\u{200D}"],
+		""
+	);
+	grapheme_test("\u{034F}\u{0308}\u{200D}",
+		&["\u{034F}\u{0308}\u{200D}"],
+		""
+	);
+	grapheme_test("\u{034F}\u{0378}",
+		&["\u{034F}", "\u{0378}"],
+		""
+	);
+	grapheme_test("\u{034F}\u{0308}\u{0378}",
+		&["\u{034F}\u{0308}", "\u{0378}"],
+		""
+	);
+	grapheme_test("\u{1F1E6}\u{0020}",
+		&["\u{1F1E6}", "\u{0020}"],
+		""
+	);
+	grapheme_test("\u{1F1E6}\u{0308}\u{0020}",
+		&["\u{1F1E6}\u{0308}", "\u{0020}"],
+		""
+	);
+	grapheme_test("\u{1F1E6}\u{000D}",
+		&["\u{1F1E6}", "\u{000D}"],
+		""
+	);
+	grapheme_test("\u{1F1E6}\u{0308}\u{000D}",
+		&["\u{1F1E6}\u{0308}", "\u{000D}"],
+		""
+	);
+	grapheme_test("\u{1F1E6}\u{000A}",
+		&["\u{1F1E6}", "\u{000A}"],
+		""
+	);
+	grapheme_test("\u{1F1E6}\u{0308}\u{000A}",
+		&["\u{1F1E6}\u{0308}", "\u{000A}"],
+		""
+	);
+	grapheme_test("\u{1F1E6}\u{0001}",
+		&["\u{1F1E6}", "\u{0001}"],
+		""
+	);
+	grapheme_test("\u{1F1E6}\u{0308}\u{0001}",
+		&["\u{1F1E6}\u{0308}", "\u{0001}"],
+		""
+	);
+	grapheme_test("\u{1F1E6}\u{034F}",
+		&["\u{1F1E6}\u{034F}"],
+		""
+	);
+	grapheme_test("\u{1F1E6}\u{0308}\u{034F}",
+		&["\u{1F1E6}\u{0308}\u{034F}"],
+		""
+	);
+	grapheme_test("\u{1F1E6}\u{1F1E6}",
+		&["\u{1F1E6}\u{1F1E6}"],
+		""
+	);
+	grapheme_test("\u{1F1E6}\u{0308}\u{1F1E6}",
+		&["\u{1F1E6}\u{0308}", "\u{1F1E6}"],
+		""
+	);
+	grapheme_test("\u{1F1E6}\u{0600}",
+		&["\u{1F1E6}", "\u{0600}"],
+		""
+	);
+	grapheme_test("\u{1F1E6}\u{0308}\u{0600}",
+		&["\u{1F1E6}\u{0308}", "\u{0600}"],
+		""
+	);
+	grapheme_test("\u{1F1E6}\u{1100}",
+		&["\u{1F1E6}", "\u{1100}"],
+		""
+	);
+	grapheme_test("\u{1F1E6}\u{0308}\u{1100}",
+		&["\u{1F1E6}\u{0308}", "\u{1100}"],
+		""
+	);
+	grapheme_test("\u{1F1E6}\u{1160}",
+		&["\u{1F1E6}", "\u{1160}"],
+		""
+	);
+	grapheme_test("\u{1F1E6}\u{0308}\u{1160}",
+		&["\u{1F1E6}\u{0308}", "\u{1160}"],
+		""
+	);
+	grapheme_test("\u{1F1E6}\u{11A8}",
+		&["\u{1F1E6}", "\u{11A8}"],
+		""
+	);
+	grapheme_test("\u{1F1E6}\u{0308}\u{11A8}",
+		&["\u{1F1E6}\u{0308}", "\u{11A8}"],
+		""
+	);
+	grapheme_test("\u{1F1E6}\u{AC00}",
+		&["\u{1F1E6}", "\u{AC00}"],
+		""
+	);
+	grapheme_test("\u{1F1E6}\u{0308}\u{AC00}",
+		&["\u{1F1E6}\u{0308}", "\u{AC00}"],
+		""
+	);
+	grapheme_test("\u{1F1E6}\u{AC01}",
+		&["\u{1F1E6}", "\u{AC01}"],
+		""
+	);
+	grapheme_test("\u{1F1E6}\u{0308}\u{AC01}",
+		&["\u{1F1E6}\u{0308}", "\u{AC01}"],
+		""
+	);
+	grapheme_test("\u{1F1E6}\u{231A}",
+		&["\u{1F1E6}", "\u{231A}"],
+		""
+	);
+	grapheme_test("\u{1F1E6}\u{0308}\u{231A}",
+		&["\u{1F1E6}\u{0308}", "\u{231A}"],
+		""
+	);
+	grapheme_test("\u{1F1E6}\u{0300}",
+		&["\u{1F1E6}\u{0300}"],
+		""
+	);
+	grapheme_test("\u{1F1E6}\u{0308}\u{0300}",
+		&["\u{1F1E6}\u{0308}\u{0300}"],
+		""
+	);
+	grapheme_test("\u{1F1E6}\u{200D}",
+		&["\u{1F1E6}\u{200D}"],
+		""
+	);
+	grapheme_test("\u{1F1E6}\u{0308}\u{200D}",
+		&["\u{1F1E6}\u{0308}\u{200D}"],
+		""
+	);
+	grapheme_test("\u{1F1E6}\u{0378}",
+		&["\u{1F1E6}", "\u{0378}"],
+		""
+	);
+	grapheme_test("\u{1F1E6}\u{0308}\u{0378}",
+		&["\u{1F1E6}\u{0308}", "\u{0378}"],
+		""
+	);
+	grapheme_test("\u{0600}\u{0308}\u{0020}",
+		&["\u{0600}\u{0308}", "\u{0020}"],
+		""
+	);
+	grapheme_test("\u{0600}\u{000D}",
+		&["\u{0600}", "\u{000D}"],
+		""
+	);
+	grapheme_test("\u{0600}\u{0308}\u{000D}",
+		&["\u{0600}\u{0308}", "\u{000D}"],
+		""
+	);
+	grapheme_test("\u{0600}\u{000A}",
+		&["\u{0600}", "\u{000A}"],
+		""
+	);
+	grapheme_test("\u{0600}\u{0308}\u{000A}",
+		&["\u{0600}\u{0308}", "\u{000A}"],
+		""
+	);
+	grapheme_test("\u{0600}\u{0001}",
+		&["\u{0600}", "\u{0001}"],
+		""
+	);
+	grapheme_test("\u{0600}\u{0308}\u{0001}",
+		&["\u{0600}\u{0308}", "\u{0001}"],
+		""
+	);
+	grapheme_test("\u{0600}\u{034F}",
+		&["\u{0600}\u{034F}"],
+		""
+	);
+	grapheme_test("\u{0600}\u{0308}\u{034F}",
+		&["\u{0600}\u{0308}\u{034F}"],
+		""
+	);
+	grapheme_test("\u{0600}\u{0308}\u{1F1E6}",
+		&["\u{0600}\u{0308}", "\u{1F1E6}"],
+		""
+	);
+	grapheme_test("\u{0600}\u{0308}\u{0600}",
+		&["\u{0600}\u{0308}", "\u{0600}"],
+		""
+	);
+	grapheme_test("\u{0600}\u{0308}\u{1100}",
+		&["\u{0600}\u{0308}", "\u{1100}"],
+		""
+	);
+	grapheme_test("\u{0600}\u{0308}\u{1160}",
+		&["\u{0600}\u{0308}", "\u{1160}"],
+		""
+	);
+	grapheme_test("\u{0600}\u{0308}\u{11A8}",
+		&["\u{0600}\u{0308}", "\u{11A8}"],
+		""
+	);
+	grapheme_test("\u{0600}\u{0308}\u{AC00}",
+		&["\u{0600}\u{0308}", "\u{AC00}"],
+		""
+	);
+	grapheme_test("\u{0600}\u{0308}\u{AC01}",
+		&["\u{0600}\u{0308}", "\u{AC01}"],
+		""
+	);
+	grapheme_test("\u{0600}\u{0308}\u{231A}",
+		&["\u{0600}\u{0308}", "\u{231A}"],
+		""
+	);
+	grapheme_test("\u{0600}\u{0300}",
+		&["\u{0600}\u{0300}"],
+		""
+	);
+	grapheme_test("\u{0600}\u{0308}\u{0300}",
+		&["\u{0600}\u{0308}\u{0300}"],
+		""
+	);
+	grapheme_test("\u{0600}\u{200D}",
+		&["\u{0600}\u{200D}"],
+		""
+	);
+	grapheme_test("\u{0600}\u{0308}\u{200D}",
+		&["\u{0600}\u{0308}\u{200D}"],
+		""
+	);
+	grapheme_test("\u{0600}\u{0308}\u{0378}",
+		&["\u{0600}\u{0308}", "\u{0378}"],
+		""
+	);
+	grapheme_test("\u{0903}\u{0020}",
+		&["\u{0903}", "\u{0020}"],
+		""
+	);
+	grapheme_test("\u{0903}\u{0308}\u{0020}",
+		&["\u{0903}\u{0308}", "\u{0020}"],
+		""
+	);
+	grapheme_test("\u{0903}\u{000D}",
+		&["\u{0903}", "\u{000D}"],
+		""
+	);
+	grapheme_test("\u{0903}\u{0308}\u{000D}",
+		&["\u{0903}\u{0308}", "\u{000D}"],
+		""
+	);
+	grapheme_test("\u{0903}\u{000A}",
+		&["\u{0903}", "\u{000A}"],
+		""
+	);
+	grapheme_test("\u{0903}\u{0308}\u{000A}",
+		&["\u{0903}\u{0308}", "\u{000A}"],
+		""
+	);
+	grapheme_test("\u{0903}\u{0001}",
+		&["\u{0903}", "\u{0001}"],
+		""
+	);
+	grapheme_test("\u{0903}\u{0308}\u{0001}",
+		&["\u{0903}\u{0308}", "\u{0001}"],
+		""
+	);
+	grapheme_test("\u{0903}\u{034F}",
+		&["\u{0903}\u{034F}"],
+		""
+	);
+	grapheme_test("\u{0903}\u{0308}\u{034F}",
+		&["\u{0903}\u{0308}\u{034F}"],
+		""
+	);
+	grapheme_test("\u{0903}\u{1F1E6}",
+		&["\u{0903}", "\u{1F1E6}"],
+		""
+	);
+	grapheme_test("\u{0903}\u{0308}\u{1F1E6}",
+		&["\u{0903}\u{0308}", "\u{1F1E6}"],
+		""
+	);
+	grapheme_test("\u{0903}\u{0600}",
+		&["\u{0903}", "\u{0600}"],
+		""
+	);
+	grapheme_test("\u{0903}\u{0308}\u{0600}",
+		&["\u{0903}\u{0308}", "\u{0600}"],
+		""
+	);
+	grapheme_test("\u{0903}\u{1100}",
+		&["\u{0903}", "\u{1100}"],
+		""
+	);
+	grapheme_test("\u{0903}\u{0308}\u{1100}",
+		&["\u{0903}\u{0308}", "\u{1100}"],
+		""
+	);
+	grapheme_test("\u{0903}\u{1160}",
+		&["\u{0903}", "\u{1160}"],
+		""
+	);
+	grapheme_test("\u{0903}\u{0308}\u{1160}",
+		&["\u{0903}\u{0308}", "\u{1160}"],
+		""
+	);
+	grapheme_test("\u{0903}\u{11A8}",
+		&["\u{0903}", "\u{11A8}"],
+		""
+	);
+	grapheme_test("\u{0903}\u{0308}\u{11A8}",
+		&["\u{0903}\u{0308}", "\u{11A8}"],
+		""
+	);
+	grapheme_test("\u{0903}\u{AC00}",
+		&["\u{0903}", "\u{AC00}"],
+		""
+	);
+	grapheme_test("\u{0903}\u{0308}\u{AC00}",
+		&["\u{0903}\u{0308}", "\u{AC00}"],
+		""
+	);
+	grapheme_test("\u{0903}\u{AC01}",
+		&["\u{0903}", "\u{AC01}"],
+		""
+	);
+	grapheme_test("\u{0903}\u{0308}\u{AC01}",
+		&["\u{0903}\u{0308}", "\u{AC01}"],
+		""
+	);
+	grapheme_test("\u{0903}\u{231A}",
+		&["\u{0903}", "\u{231A}"],
+		""
+	);
+	grapheme_test("\u{0903}\u{0308}\u{231A}",
+		&["\u{0903}\u{0308}", "\u{231A}"],
+		""
+	);
+	grapheme_test("\u{0903}\u{0300}",
+		&["\u{0903}\u{0300}"],
+		""
+	);
+	grapheme_test("\u{0903}\u{0308}\u{0300}",
+		&["\u{0903}\u{0308}\u{0300}"],
+		""
+	);
+	grapheme_test("\u{0903}\u{200D}",
+		&["\u{0903}\u{200D}"],
+		""
+	);
+	grapheme_test("\u{0903}\u{0308}\u{200D}",
+		&["\u{0903}\u{0308}\u{200D}"],
+		""
+	);
+	grapheme_test("\u{0903}\u{0378}",
+		&["\u{0903}", "\u{0378}"],
+		""
+	);
+	grapheme_test("\u{0903}\u{0308}\u{0378}",
+		&["\u{0903}\u{0308}", "\u{0378}"],
+		""
+	);
+	grapheme_test("\u{1100}\u{0020}",
+		&["\u{1100}", "\u{0020}"],
+		""
+	);
+	grapheme_test("\u{1100}\u{0308}\u{0020}",
+		&["\u{1100}\u{0308}", "\u{0020}"],
+		""
+	);
+	grapheme_test("\u{1100}\u{000D}",
+		&["\u{1100}", "\u{000D}"],
+		""
+	);
+	grapheme_test("\u{1100}\u{0308}\u{000D}",
+		&["\u{1100}\u{0308}", "\u{000D}"],
+		""
+	);
+	grapheme_test("\u{1100}\u{000A}",
+		&["\u{1100}", "\u{000A}"],
+		""
+	);
+	grapheme_test("\u{1100}\u{0308}\u{000A}",
+		&["\u{1100}\u{0308}", "\u{000A}"],
+		""
+	);
+	grapheme_test("\u{1100}\u{0001}",
+		&["\u{1100}", "\u{0001}"],
+		""
+	);
+	grapheme_test("\u{1100}\u{0308}\u{0001}",
+		&["\u{1100}\u{0308}", "\u{0001}"],
+		""
+	);
+	grapheme_test("\u{1100}\u{034F}",
+		&["\u{1100}\u{034F}"],
+		""
+	);
+	grapheme_test("\u{1100}\u{0308}\u{034F}",
+		&["\u{1100}\u{0308}\u{034F}"],
+		""
+	);
+	grapheme_test("\u{1100}\u{1F1E6}",
+		&["\u{1100}", "\u{1F1E6}"],
+		""
+	);
+	grapheme_test("\u{1100}\u{0308}\u{1F1E6}",
+		&["\u{1100}\u{0308}", "\u{1F1E6}"],
+		""
+	);
+	grapheme_test("\u{1100}\u{0600}",
+		&["\u{1100}", "\u{0600}"],
+		""
+	);
+	grapheme_test("\u{1100}\u{0308}\u{0600}",
+		&["\u{1100}\u{0308}", "\u{0600}"],
+		""
+	);
+	grapheme_test("\u{1100}\u{1100}",
+		&["\u{1100}\u{1100}"],
+		""
+	);
+	grapheme_test("\u{1100}\u{0308}\u{1100}",
+		&["\u{1100}\u{0308}", "\u{1100}"],
+		""
+	);
+	grapheme_test("\u{1100}\u{1160}",
+		&["\u{1100}\u{1160}"],
+		""
+	);
+	grapheme_test("\u{1100}\u{0308}\u{1160}",
+		&["\u{1100}\u{0308}", "\u{1160}"],
+		""
+	);
+	grapheme_test("\u{1100}\u{11A8}",
+		&["\u{1100}", "\u{11A8}"],
+		""
+	);
+	grapheme_test("\u{1100}\u{0308}\u{11A8}",
+		&["\u{1100}\u{0308}", "\u{11A8}"],
+		""
+	);
+	grapheme_test("\u{1100}\u{AC00}",
+		&["\u{1100}\u{AC00}"],
+		""
+	);
+	grapheme_test("\u{1100}\u{0308}\u{AC00}",
+		&["\u{1100}\u{0308}", "\u{AC00}"],
+		""
+	);
+	grapheme_test("\u{1100}\u{AC01}",
+		&["\u{1100}\u{AC01}"],
+		""
+	);
+	grapheme_test("\u{1100}\u{0308}\u{AC01}",
+		&["\u{1100}\u{0308}", "\u{AC01}"],
+		""
+	);
+	grapheme_test("\u{1100}\u{231A}",
+		&["\u{1100}", "\u{231A}"],
+		""
+	);
+	grapheme_test("\u{1100}\u{0308}\u{231A}",
+		&["\u{1100}\u{0308}", "\u{231A}"],
+		""
+	);
+	grapheme_test("\u{1100}\u{0300}",
+		&["\u{1100}\u{0300}"],
+		""
+	);
+	grapheme_test("\u{1100}\u{0308}\u{0300}",
+		&["\u{1100}\u{0308}\u{0300}"],
+		""
+	);
+	grapheme_test("\u{1100}\u{200D}",
+		&["\u{1100}\u{200D}"],
+		""
+	);
+	grapheme_test("\u{1100}\u{0308}\u{200D}",
+		&["\u{1100}\u{0308}\u{200D}"],
+		""
+	);
+	grapheme_test("\u{1100}\u{0378}",
+		&["\u{1100}", "\u{0378}"],
+		""
+	);
+	grapheme_test("\u{1100}\u{0308}\u{0378}",
+		&["\u{1100}\u{0308}", "\u{0378}"],
+		""
+	);
+	grapheme_test("\u{1160}\u{0020}",
+		&["\u{1160}", "\u{0020}"],
+		""
+	);
+	grapheme_test("\u{1160}\u{0308}\u{0020}",
+		&["\u{1160}\u{0308}", "\u{0020}"],
+		""
+	);
+	grapheme_test("\u{1160}\u{000D}",
+		&["\u{1160}", "\u{000D}"],
+		""
+	);
+	grapheme_test("\u{1160}\u{0308}\u{000D}",
+		&["\u{1160}\u{0308}", "\u{000D}"],
+		""
+	);
+	grapheme_test("\u{1160}\u{000A}",
+		&["\u{1160}", "\u{000A}"],
+		""
+	);
+	grapheme_test("\u{1160}\u{0308}\u{000A}",
+		&["\u{1160}\u{0308}", "\u{000A}"],
+		""
+	);
+	grapheme_test("\u{1160}\u{0001}",
+		&["\u{1160}", "\u{0001}"],
+		""
+	);
+	grapheme_test("\u{1160}\u{0308}\u{0001}",
+		&["\u{1160}\u{0308}", "\u{0001}"],
+		""
+	);
+	grapheme_test("\u{1160}\u{034F}",
+		&["\u{1160}\u{034F}"],
+		""
+	);
+	grapheme_test("\u{1160}\u{0308}\u{034F}",
+		&["\u{1160}\u{0308}\u{034F}"],
+		""
+	);
+	grapheme_test("\u{1160}\u{1F1E6}",
+		&["\u{1160}", "\u{1F1E6}"],
+		""
+	);
+	grapheme_test("\u{1160}\u{0308}\u{1F1E6}",
+		&["\u{1160}\u{0308}", "\u{1F1E6}"],
+		""
+	);
+	grapheme_test("\u{1160}\u{0600}",
+		&["\u{1160}", "\u{0600}"],
+		""
+	);
+	grapheme_test("\u{1160}\u{0308}\u{0600}",
+		&["\u{1160}\u{0308}", "\u{0600}"],
+		""
+	);
+	grapheme_test("\u{1160}\u{1100}",
+		&["\u{1160}", "\u{1100}"],
+		""
+	);
+	grapheme_test("\u{1160}\u{0308}\u{1100}",
+		&["\u{1160}\u{0308}", "\u{1100}"],
+		""
+	);
+	grapheme_test("\u{1160}\u{1160}",
+		&["\u{1160}\u{1160}"],
+		""
+	);
+	grapheme_test("\u{1160}\u{0308}\u{1160}",
+		&["\u{1160}\u{0308}", "\u{1160}"],
+		""
+	);
+	grapheme_test("\u{1160}\u{11A8}",
+		&["\u{1160}\u{11A8}"],
+		""
+	);
+	grapheme_test("\u{1160}\u{0308}\u{11A8}",
+		&["\u{1160}\u{0308}", "\u{11A8}"],
+		""
+	);
+	grapheme_test("\u{1160}\u{AC00}",
+		&["\u{1160}", "\u{AC00}"],
+		""
+	);
+	grapheme_test("\u{1160}\u{0308}\u{AC00}",
+		&["\u{1160}\u{0308}", "\u{AC00}"],
+		""
+	);
+	grapheme_test("\u{1160}\u{AC01}",
+		&["\u{1160}", "\u{AC01}"],
+		""
+	);
+	grapheme_test("\u{1160}\u{0308}\u{AC01}",
+		&["\u{1160}\u{0308}", "\u{AC01}"],
+		""
+	);
+	grapheme_test("\u{1160}\u{231A}",
+		&["\u{1160}", "\u{231A}"],
+		""
+	);
+	grapheme_test("\u{1160}\u{0308}\u{231A}",
+		&["\u{1160}\u{0308}", "\u{231A}"],
+		""
+	);
+	grapheme_test("\u{1160}\u{0300}",
+		&["\u{1160}\u{0300}"],
+		""
+	);
+	grapheme_test("\u{1160}\u{0308}\u{0300}",
+		&["\u{1160}\u{0308}\u{0300}"],
+		""
+	);
+	grapheme_test("\u{1160}\u{200D}",
+		&["\u{1160}\u{200D}"],
+		""
+	);
+	grapheme_test("\u{1160}\u{0308}\u{200D}",
+		&["\u{1160}\u{0308}\u{200D}"],
+		""
+	);
+	grapheme_test("\u{1160}\u{0378}",
+		&["\u{1160}", "\u{0378}"],
+		""
+	);
+	grapheme_test("\u{1160}\u{0308}\u{0378}",
+		&["\u{1160}\u{0308}", "\u{0378}"],
+		""
+	);
+	grapheme_test("\u{11A8}\u{0020}",
+		&["\u{11A8}", "\u{0020}"],
+		""
+	);
+	grapheme_test("\u{11A8}\u{0308}\u{0020}",
+		&["\u{11A8}\u{0308}", "\u{0020}"],
+		""
+	);
+	grapheme_test("\u{11A8}\u{000D}",
+		&["\u{11A8}", "\u{000D}"],
+		""
+	);
+	grapheme_test("\u{11A8}\u{0308}\u{000D}",
+		&["\u{11A8}\u{0308}", "\u{000D}"],
+		""
+	);
+	grapheme_test("\u{11A8}\u{000A}",
+		&["\u{11A8}", "\u{000A}"],
+		""
+	);
+	grapheme_test("\u{11A8}\u{0308}\u{000A}",
+		&["\u{11A8}\u{0308}", "\u{000A}"],
+		""
+	);
+	grapheme_test("\u{11A8}\u{0001}",
+		&["\u{11A8}", "\u{0001}"],
+		""
+	);
+	grapheme_test("\u{11A8}\u{0308}\u{0001}",
+		&["\u{11A8}\u{0308}", "\u{0001}"],
+		""
+	);
+	grapheme_test("\u{11A8}\u{034F}",
+		&["\u{11A8}\u{034F}"],
+		""
+	);
+	grapheme_test("\u{11A8}\u{0308}\u{034F}",
+		&["\u{11A8}\u{0308}\u{034F}"],
+		""
+	);
+	grapheme_test("\u{11A8}\u{1F1E6}",
+		&["\u{11A8}", "\u{1F1E6}"],
+		""
+	);
+	grapheme_test("\u{11A8}\u{0308}\u{1F1E6}",
+		&["\u{11A8}\u{0308}", "\u{1F1E6}"],
+		""
+	);
+	grapheme_test("\u{11A8}\u{0600}",
+		&["\u{11A8}", "\u{0600}"],
+		""
+	);
+	grapheme_test("\u{11A8}\u{0308}\u{0600}",
+		&["\u{11A8}\u{0308}", "\u{0600}"],
+		""
+	);
+	grapheme_test("\u{11A8}\u{1100}",
+		&["\u{11A8}", "\u{1100}"],
+		""
+	);
+	grapheme_test("\u{11A8}\u{0308}\u{1100}",
+		&["\u{11A8}\u{0308}", "\u{1100}"],
+		""
+	);
+	grapheme_test("\u{11A8}\u{1160}",
+		&["\u{11A8}", "\u{1160}"],
+		""
+	);
+	grapheme_test("\u{11A8}\u{0308}\u{1160}",
+		&["\u{11A8}\u{0308}", "\u{1160}"],
+		""
+	);
+	grapheme_test("\u{11A8}\u{11A8}",
+		&["\u{11A8}\u{11A8}"],
+		""
+	);
+	grapheme_test("\u{11A8}\u{0308}\u{11A8}",
+		&["\u{11A8}\u{0308}", "\u{11A8}"],
+		""
+	);
+	grapheme_test("\u{11A8}\u{AC00}",
+		&["\u{11A8}", "\u{AC00}"],
+		""
+	);
+	grapheme_test("\u{11A8}\u{0308}\u{AC00}",
+		&["\u{11A8}\u{0308}", "\u{AC00}"],
+		""
+	);
+	grapheme_test("\u{11A8}\u{AC01}",
+		&["\u{11A8}", "\u{AC01}"],
+		""
+	);
+	grapheme_test("\u{11A8}\u{0308}\u{AC01}",
+		&["\u{11A8}\u{0308}", "\u{AC01}"],
+		""
+	);
+	grapheme_test("\u{11A8}\u{231A}",
+		&["\u{11A8}", "\u{231A}"],
+		""
+	);
+	grapheme_test("\u{11A8}\u{0308}\u{231A}",
+		&["\u{11A8}\u{0308}", "\u{231A}"],
+		""
+	);
+	grapheme_test("\u{11A8}\u{0300}",
+		&["\u{11A8}\u{0300}"],
+		""
+	);
+	grapheme_test("\u{11A8}\u{0308}\u{0300}",
+		&["\u{11A8}\u{0308}\u{0300}"],
+		""
+	);
+	grapheme_test("\u{11A8}\u{200D}",
+		&["\u{11A8}\u{200D}"],
+		""
+	);
+	grapheme_test("\u{11A8}\u{0308}\u{200D}",
+		&["\u{11A8}\u{0308}\u{200D}"],
+		""
+	);
+	grapheme_test("\u{11A8}\u{0378}",
+		&["\u{11A8}", "\u{0378}"],
+		""
+	);
+	grapheme_test("\u{11A8}\u{0308}\u{0378}",
+		&["\u{11A8}\u{0308}", "\u{0378}"],
+		""
+	);
+	grapheme_test("\u{AC00}\u{0020}",
+		&["\u{AC00}", "\u{0020}"],
+		""
+	);
+	grapheme_test("\u{AC00}\u{0308}\u{0020}",
+		&["\u{AC00}\u{0308}", "\u{0020}"],
+		""
+	);
+	grapheme_test("\u{AC00}\u{000D}",
+		&["\u{AC00}", "\u{000D}"],
+		""
+	);
+	grapheme_test("\u{AC00}\u{0308}\u{000D}",
+		&["\u{AC00}\u{0308}", "\u{000D}"],
+		""
+	);
+	grapheme_test("\u{AC00}\u{000A}",
+		&["\u{AC00}", "\u{000A}"],
+		""
+	);
+	grapheme_test("\u{AC00}\u{0308}\u{000A}",
+		&["\u{AC00}\u{0308}", "\u{000A}"],
+		""
+	);
+	grapheme_test("\u{AC00}\u{0001}",
+		&["\u{AC00}", "\u{0001}"],
+		""
+	);
+	grapheme_test("\u{AC00}\u{0308}\u{0001}",
+		&["\u{AC00}\u{0308}", "\u{0001}"],
+		""
+	);
+	grapheme_test("\u{AC00}\u{034F}",
+		&["\u{AC00}\u{034F}"],
+		""
+	);
+	grapheme_test("\u{AC00}\u{0308}\u{034F}",
+		&["\u{AC00}\u{0308}\u{034F}"],
+		""
+	);
+	grapheme_test("\u{AC00}\u{1F1E6}",
+		&["\u{AC00}", "\u{1F1E6}"],
+		""
+	);
+	grapheme_test("\u{AC00}\u{0308}\u{1F1E6}",
+		&["\u{AC00}\u{0308}", "\u{1F1E6}"],
+		""
+	);
+	grapheme_test("\u{AC00}\u{0600}",
+		&["\u{AC00}", "\u{0600}"],
+		""
+	);
+	grapheme_test("\u{AC00}\u{0308}\u{0600}",
+		&["\u{AC00}\u{0308}", "\u{0600}"],
+		""
+	);
+	grapheme_test("\u{AC00}\u{1100}",
+		&["\u{AC00}", "\u{1100}"],
+		""
+	);
+	grapheme_test("\u{AC00}\u{0308}\u{1100}",
+		&["\u{AC00}\u{0308}", "\u{1100}"],
+		""
+	);
+	grapheme_test("\u{AC00}\u{1160}",
+		&["\u{AC00}\u{1160}"],
+		""
+	);
+	grapheme_test("\u{AC00}\u{0308}\u{1160}",
+		&["\u{AC00}\u{0308}", "\u{1160}"],
+		""
+	);
+	grapheme_test("\u{AC00}\u{11A8}",
+		&["\u{AC00}\u{11A8}"],
+		""
+	);
+	grapheme_test("\u{AC00}\u{0308}\u{11A8}",
+		&["\u{AC00}\u{0308}", "\u{11A8}"],
+		""
+	);
+	grapheme_test("\u{AC00}\u{AC00}",
+		&["\u{AC00}", "\u{AC00}"],
+		""
+	);
+	grapheme_test("\u{AC00}\u{0308}\u{AC00}",
+		&["\u{AC00}\u{0308}", "\u{AC00}"],
+		""
+	);
+	grapheme_test("\u{AC00}\u{AC01}",
+		&["\u{AC00}", "\u{AC01}"],
+		""
+	);
+	grapheme_test("\u{AC00}\u{0308}\u{AC01}",
+		&["\u{AC00}\u{0308}", "\u{AC01}"],
+		""
+	);
+	grapheme_test("\u{AC00}\u{231A}",
+		&["\u{AC00}", "\u{231A}"],
+		""
+	);
+	grapheme_test("\u{AC00}\u{0308}\u{231A}",
+		&["\u{AC00}\u{0308}", "\u{231A}"],
+		""
+	);
+	grapheme_test("\u{AC00}\u{0300}",
+		&["\u{AC00}\u{0300}"],
+		""
+	);
+	grapheme_test("\u{AC00}\u{0308}\u{0300}",
+		&["\u{AC00}\u{0308}\u{0300}"],
+		""
+	);
+	grapheme_test("\u{AC00}\u{200D}",
+		&["\u{AC00}\u{200D}"],
+		""
+	);
+	grapheme_test("\u{AC00}\u{0308}\u{200D}",
+		&["\u{AC00}\u{0308}\u{200D}"],
+		""
+	);
+	grapheme_test("\u{AC00}\u{0378}",
+		&["\u{AC00}", "\u{0378}"],
+		""
+	);
+	grapheme_test("\u{AC00}\u{0308}\u{0378}",
+		&["\u{AC00}\u{0308}", "\u{0378}"],
+		""
+	);
+	grapheme_test("\u{AC01}\u{0020}",
+		&["\u{AC01}", "\u{0020}"],
+		""
+	);
+	grapheme_test("\u{AC01}\u{0308}\u{0020}",
+		&["\u{AC01}\u{0308}", "\u{0020}"],
+		""
+	);
+	grapheme_test("\u{AC01}\u{000D}",
+		&["\u{AC01}", "\u{000D}"],
+		""
+	);
+	grapheme_test("\u{AC01}\u{0308}\u{000D}",
+		&["\u{AC01}\u{0308}", "\u{000D}"],
+		""
+	);
+	grapheme_test("\u{AC01}\u{000A}",
+		&["\u{AC01}", "\u{000A}"],
+		""
+	);
+	grapheme_test("\u{AC01}\u{0308}\u{000A}",
+		&["\u{AC01}\u{0308}", "\u{000A}"],
+		""
+	);
+	grapheme_test("\u{AC01}\u{0001}",
+		&["\u{AC01}", "\u{0001}"],
+		""
+	);
+	grapheme_test("\u{AC01}\u{0308}\u{0001}",
+		&["\u{AC01}\u{0308}", "\u{0001}"],
+		""
+	);
+	grapheme_test("\u{AC01}\u{034F}",
+		&["\u{AC01}\u{034F}"],
+		""
+	);
+	grapheme_test("\u{AC01}\u{0308}\u{034F}",
+		&["\u{AC01}\u{0308}\u{034F}"],
+		""
+	);
+	grapheme_test("\u{AC01}\u{1F1E6}",
+		&["\u{AC01}", "\u{1F1E6}"],
+		""
+	);
+	grapheme_test("\u{AC01}\u{0308}\u{1F1E6}",
+		&["\u{AC01}\u{0308}", "\u{1F1E6}"],
+		""
+	);
+	grapheme_test("\u{AC01}\u{0600}",
+		&["\u{AC01}", "\u{0600}"],
+		""
+	);
+	grapheme_test("\u{AC01}\u{0308}\u{0600}",
+		&["\u{AC01}\u{0308}", "\u{0600}"],
+		""
+	);
+	grapheme_test("\u{AC01}\u{1100}",
+		&["\u{AC01}", "\u{1100}"],
+		""
+	);
+	grapheme_test("\u{AC01}\u{0308}\u{1100}",
+		&["\u{AC01}\u{0308}", "\u{1100}"],
+		""
+	);
+	grapheme_test("\u{AC01}\u{1160}",
+		&["\u{AC01}", "\u{1160}"],
+		""
+	);
+	grapheme_test("\u{AC01}\u{0308}\u{1160}",
+		&["\u{AC01}\u{0308}", "\u{1160}"],
+		""
+	);
+	grapheme_test("\u{AC01}\u{11A8}",
+		&["\u{AC01}\u{11A8}"],
+		""
+	);
+	grapheme_test("\u{AC01}\u{0308}\u{11A8}",
+		&["\u{AC01}\u{0308}", "\u{11A8}"],
+		""
+	);
+	grapheme_test("\u{AC01}\u{AC00}",
+		&["\u{AC01}", "\u{AC00}"],
+		""
+	);
+	grapheme_test("\u{AC01}\u{0308}\u{AC00}",
+		&["\u{AC01}\u{0308}", "\u{AC00}"],
+		""
+	);
+	grapheme_test("\u{AC01}\u{AC01}",
+		&["\u{AC01}", "\u{AC01}"],
+		""
+	);
+	grapheme_test("\u{AC01}\u{0308}\u{AC01}",
+		&["\u{AC01}\u{0308}", "\u{AC01}"],
+		""
+	);
+	grapheme_test("\u{AC01}\u{231A}",
+		&["\u{AC01}", "\u{231A}"],
+		""
+	);
+	grapheme_test("\u{AC01}\u{0308}\u{231A}",
+		&["\u{AC01}\u{0308}", "\u{231A}"],
+		""
+	);
+	grapheme_test("\u{AC01}\u{0300}",
+		&["\u{AC01}\u{0300}"],
+		""
+	);
+	grapheme_test("\u{AC01}\u{0308}\u{0300}",
+		&["\u{AC01}\u{0308}\u{0300}"],
+		""
+	);
+	grapheme_test("\u{AC01}\u{200D}",
+		&["\u{AC01}\u{200D}"],
+		""
+	);
+	grapheme_test("\u{AC01}\u{0308}\u{200D}",
+		&["\u{AC01}\u{0308}\u{200D}"],
+		""
+	);
+	grapheme_test("\u{AC01}\u{0378}",
+		&["\u{AC01}", "\u{0378}"],
+		""
+	);
+	grapheme_test("\u{AC01}\u{0308}\u{0378}",
+		&["\u{AC01}\u{0308}", "\u{0378}"],
+		""
+	);
+	grapheme_test("\u{231A}\u{0020}",
+		&["\u{231A}", "\u{0020}"],
+		""
+	);
+	grapheme_test("\u{231A}\u{0308}\u{0020}",
+		&["\u{231A}\u{0308}", "\u{0020}"],
+		""
+	);
+	grapheme_test("\u{231A}\u{000D}",
+		&["\u{231A}", "\u{000D}"],
+		""
+	);
+	grapheme_test("\u{231A}\u{0308}\u{000D}",
+		&["\u{231A}\u{0308}", "\u{000D}"],
+		""
+	);
+	grapheme_test("\u{231A}\u{000A}",
+		&["\u{231A}", "\u{000A}"],
+		""
+	);
+	grapheme_test("\u{231A}\u{0308}\u{000A}",
+		&["\u{231A}\u{0308}", "\u{000A}"],
+		""
+	);
+	grapheme_test("\u{231A}\u{0001}",
+		&["\u{231A}", "\u{0001}"],
+		""
+	);
+	grapheme_test("\u{231A}\u{0308}\u{0001}",
+		&["\u{231A}\u{0308}", "\u{0001}"],
+		""
+	);
+	grapheme_test("\u{231A}\u{034F}",
+		&["\u{231A}\u{034F}"],
+		""
+	);
+	grapheme_test("\u{231A}\u{0308}\u{034F}",
+		&["\u{231A}\u{0308}\u{034F}"],
+		""
+	);
+	grapheme_test("\u{231A}\u{1F1E6}",
+		&["\u{231A}", "\u{1F1E6}"],
+		""
+	);
+	grapheme_test("\u{231A}\u{0308}\u{1F1E6}",
+		&["\u{231A}\u{0308}", "\u{1F1E6}"],
+		""
+	);
+	grapheme_test("\u{231A}\u{0600}",
+		&["\u{231A}", "\u{0600}"],
+		""
+	);
+	grapheme_test("\u{231A}\u{0308}\u{0600}",
+		&["\u{231A}\u{0308}", "\u{0600}"],
+		""
+	);
+	grapheme_test("\u{231A}\u{1100}",
+		&["\u{231A}", "\u{1100}"],
+		""
+	);
+	grapheme_test("\u{231A}\u{0308}\u{1100}",
+		&["\u{231A}\u{0308}", "\u{1100}"],
+		""
+	);
+	grapheme_test("\u{231A}\u{1160}",
+		&["\u{231A}", "\u{1160}"],
+		""
+	);
+	grapheme_test("\u{231A}\u{0308}\u{1160}",
+		&["\u{231A}\u{0308}", "\u{1160}"],
+		""
+	);
+	grapheme_test("\u{231A}\u{11A8}",
+		&["\u{231A}", "\u{11A8}"],
+		""
+	);
+	grapheme_test("\u{231A}\u{0308}\u{11A8}",
+		&["\u{231A}\u{0308}", "\u{11A8}"],
+		""
+	);
+	grapheme_test("\u{231A}\u{AC00}",
+		&["\u{231A}", "\u{AC00}"],
+		""
+	);
+	grapheme_test("\u{231A}\u{0308}\u{AC00}",
+		&["\u{231A}\u{0308}", "\u{AC00}"],
+		""
+	);
+	grapheme_test("\u{231A}\u{AC01}",
+		&["\u{231A}", "\u{AC01}"],
+		""
+	);
+	grapheme_test("\u{231A}\u{0308}\u{AC01}",
+		&["\u{231A}\u{0308}", "\u{AC01}"],
+		""
+	);
+	grapheme_test("\u{231A}\u{231A}",
+		&["\u{231A}", "\u{231A}"],
+		""
+	);
+	grapheme_test("\u{231A}\u{0308}\u{231A}",
+		&["\u{231A}\u{0308}", "\u{231A}"],
+		""
+	);
+	grapheme_test("\u{231A}\u{0300}",
+		&["\u{231A}\u{0300}"],
+		""
+	);
+	grapheme_test("\u{231A}\u{0308}\u{0300}",
+		&["\u{231A}\u{0308}\u{0300}"],
+		""
+	);
+	grapheme_test("\u{231A}\u{200D}",
+		&["\u{231A}\u{200D}"],
+		""
+	);
+	grapheme_test("\u{231A}\u{0308}\u{200D}",
+		&["\u{231A}\u{0308}\u{200D}"],
+		""
+	);
+	grapheme_test("\u{231A}\u{0378}",
+		&["\u{231A}", "\u{0378}"],
+		""
+	);
+	grapheme_test("\u{231A}\u{0308}\u{0378}",
+		&["\u{231A}\u{0308}", "\u{0378}"],
+		""
+	);
+	grapheme_test("\u{0300}\u{0020}",
+		&["\u{0300}", "\u{0020}"],
+		""
+	);
+	grapheme_test("\u{0300}\u{0308}\u{0020}",
+		&["\u{0300}\u{0308}", "\u{0020}"],
+		""
+	);
+	grapheme_test("\u{0300}\u{000D}",
+		&["\u{0300}", "\u{000D}"],
+		""
+	);
+	grapheme_test("\u{0300}\u{0308}\u{000D}",
+		&["\u{0300}\u{0308}", "\u{000D}"],
+		""
+	);
+	grapheme_test("\u{0300}\u{000A}",
+		&["\u{0300}", "\u{000A}"],
+		""
+	);
+	grapheme_test("\u{0300}\u{0308}\u{000A}",
+		&["\u{0300}\u{0308}", "\u{000A}"],
+		""
+	);
+	grapheme_test("\u{0300}\u{0001}",
+		&["\u{0300}", "\u{0001}"],
+		""
+	);
+	grapheme_test("\u{0300}\u{0308}\u{0001}",
+		&["\u{0300}\u{0308}", "\u{0001}"],
+		""
+	);
+	grapheme_test("\u{0300}\u{034F}",
+		&["\u{0300}\u{034F}"],
+		""
+	);
+	grapheme_test("\u{0300}\u{0308}\u{034F}",
+		&["\u{0300}\u{0308}\u{034F}"],
+		""
+	);
+	grapheme_test("\u{0300}\u{1F1E6}",
+		&["\u{0300}", "\u{1F1E6}"],
+		""
+	);
+	grapheme_test("\u{0300}\u{0308}\u{1F1E6}",
+		&["\u{0300}\u{0308}", "\u{1F1E6}"],
+		""
+	);
+	grapheme_test("\u{0300}\u{0600}",
+		&["\u{0300}", "\u{0600}"],
+		""
+	);
+	grapheme_test("\u{0300}\u{0308}\u{0600}",
+		&["\u{0300}\u{0308}", "\u{0600}"],
+		""
+	);
+	grapheme_test("\u{0300}\u{1100}",
+		&["\u{0300}", "\u{1100}"],
+		""
+	);
+	grapheme_test("\u{0300}\u{0308}\u{1100}",
+		&["\u{0300}\u{0308}", "\u{1100}"],
+		""
+	);
+	grapheme_test("\u{0300}\u{1160}",
+		&["\u{0300}", "\u{1160}"],
+		""
+	);
+	grapheme_test("\u{0300}\u{0308}\u{1160}",
+		&["\u{0300}\u{0308}", "\u{1160}"],
+		""
+	);
+	grapheme_test("\u{0300}\u{11A8}",
+		&["\u{0300}", "\u{11A8}"],
+		""
+	);
+	grapheme_test("\u{0300}\u{0308}\u{11A8}",
+		&["\u{0300}\u{0308}", "\u{11A8}"],
+		""
+	);
+	grapheme_test("\u{0300}\u{AC00}",
+		&["\u{0300}", "\u{AC00}"],
+		""
+	);
+	grapheme_test("\u{0300}\u{0308}\u{AC00}",
+		&["\u{0300}\u{0308}", "\u{AC00}"],
+		""
+	);
+	grapheme_test("\u{0300}\u{AC01}",
+		&["\u{0300}", "\u{AC01}"],
+		""
+	);
+	grapheme_test("\u{0300}\u{0308}\u{AC01}",
+		&["\u{0300}\u{0308}", "\u{AC01}"],
+		""
+	);
+	grapheme_test("\u{0300}\u{231A}",
+		&["\u{0300}", "\u{231A}"],
+		""
+	);
+	grapheme_test("\u{0300}\u{0308}\u{231A}",
+		&["\u{0300}\u{0308}", "\u{231A}"],
+		""
+	);
+	grapheme_test("\u{0300}\u{0300}",
+		&["\u{0300}\u{0300}"],
+		""
+	);
+	grapheme_test("\u{0300}\u{0308}\u{0300}",
+		&["\u{0300}\u{0308}\u{0300}"],
+		""
+	);
+	grapheme_test("\u{0300}\u{200D}",
+		&["\u{0300}\u{200D}"],
+		""
+	);
+	grapheme_test("\u{0300}\u{0308}\u{200D}",
+		&["\u{0300}\u{0308}\u{200D}"],
+		""
+	);
+	grapheme_test("\u{0300}\u{0378}",
+		&["\u{0300}", "\u{0378}"],
+		""
+	);
+	grapheme_test("\u{0300}\u{0308}\u{0378}",
+		&["\u{0300}\u{0308}", "\u{0378}"],
+		""
+	);
+	grapheme_test("\u{200D}\u{0020}",
+		&["\u{200D}", "\u{0020}"],
+		""
+	);
+	grapheme_test("\u{200D}\u{0308}\u{0020}",
+		&["\u{200D}\u{0308}", "\u{0020}"],
+		""
+	);
+	grapheme_test("\u{200D}\u{000D}",
+		&["\u{200D}", "\u{000D}"],
+		""
+	);
+	grapheme_test("\u{200D}\u{0308}\u{000D}",
+		&["\u{200D}\u{0308}", "\u{000D}"],
+		""
+	);
+	grapheme_test("\u{200D}\u{000A}",
+		&["\u{200D}", "\u{000A}"],
+		""
+	);
+	grapheme_test("\u{200D}\u{0308}\u{000A}",
+		&["\u{200D}\u{0308}", "\u{000A}"],
+		""
+	);
+	grapheme_test("\u{200D}\u{0001}",
+		&["\u{200D}", "\u{0001}"],
+		""
+	);
+	grapheme_test("\u{200D}\u{0308}\u{0001}",
+		&["\u{200D}\u{0308}", "\u{0001}"],
+		""
+	);
+	grapheme_test("\u{200D}\u{034F}",
+		&["\u{200D}\u{034F}"],
+		""
+	);
+	grapheme_test("\u{200D}\u{0308}\u{034F}",
+		&["\u{200D}\u{0308}\u{034F}"],
+		""
+	);
+	grapheme_test("\u{200D}\u{1F1E6}",
+		&["\u{200D}", "\u{1F1E6}"],
+		""
+	);
+	grapheme_test("\u{200D}\u{0308}\u{1F1E6}",
+		&["\u{200D}\u{0308}", "\u{1F1E6}"],
+		""
+	);
+	grapheme_test("\u{200D}\u{0600}",
+		&["\u{200D}", "\u{0600}"],
+		""
+	);
+	grapheme_test("\u{200D}\u{0308}\u{0600}",
+		&["\u{200D}\u{0308}", "\u{0600}"],
+		""
+	);
+	grapheme_test("\u{200D}\u{1100}",
+		&["\u{200D}", "\u{1100}"],
+		""
+	);
+	grapheme_test("\u{200D}\u{0308}\u{1100}",
+		&["\u{200D}\u{0308}", "\u{1100}"],
+		""
+	);
+	grapheme_test("\u{200D}\u{1160}",
+		&["\u{200D}", "\u{1160}"],
+		""
+	);
+	grapheme_test("\u{200D}\u{0308}\u{1160}",
+		&["\u{200D}\u{0308}", "\u{1160}"],
+		""
+	);
+	grapheme_test("\u{200D}\u{11A8}",
+		&["\u{200D}", "\u{11A8}"],
+		""
+	);
+	grapheme_test("\u{200D}\u{0308}\u{11A8}",
+		&["\u{200D}\u{0308}", "\u{11A8}"],
+		""
+	);
+	grapheme_test("\u{200D}\u{AC00}",
+		&["\u{200D}", "\u{AC00}"],
+		""
+	);
+	grapheme_test("\u{200D}\u{0308}\u{AC00}",
+		&["\u{200D}\u{0308}", "\u{AC00}"],
+		""
+	);
+	grapheme_test("\u{200D}\u{AC01}",
+		&["\u{200D}", "\u{AC01}"],
+		""
+	);
+	grapheme_test("\u{200D}\u{0308}\u{AC01}",
+		&["\u{200D}\u{0308}", "\u{AC01}"],
+		""
+	);
+	grapheme_test("\u{200D}\u{231A}",
+		&["\u{200D}", "\u{231A}"],
+		""
+	);
+	grapheme_test("\u{200D}\u{0308}\u{231A}",
+		&["\u{200D}\u{0308}", "\u{231A}"],
+		""
+	);
+	grapheme_test("\u{200D}\u{0300}",
+		&["\u{200D}\u{0300}"],
+		""
+	);
+	grapheme_test("\u{200D}\u{0308}\u{0300}",
+		&["\u{200D}\u{0308}\u{0300}"],
+		""
+	);
+	grapheme_test("\u{200D}\u{200D}",
+		&["\u{200D}\u{200D}"],
+		""
+	);
+	grapheme_test("\u{200D}\u{0308}\u{200D}",
+		&["\u{200D}\u{0308}\u{200D}"],
+		""
+	);
+	grapheme_test("\u{200D}\u{0378}",
+		&["\u{200D}", "\u{0378}"],
+		""
+	);
+	grapheme_test("\u{200D}\u{0308}\u{0378}",
+		&["\u{200D}\u{0308}", "\u{0378}"],
+		""
+	);
+	grapheme_test("\u{0378}\u{0020}",
+		&["\u{0378}", "\u{0020}"],
+		""
+	);
+	grapheme_test("\u{0378}\u{0308}\u{0020}",
+		&["\u{0378}\u{0308}", "\u{0020}"],
+		""
+	);
+	grapheme_test("\u{0378}\u{000D}",
+		&["\u{0378}", "\u{000D}"],
+		""
+	);
+	grapheme_test("\u{0378}\u{0308}\u{000D}",
+		&["\u{0378}\u{0308}", "\u{000D}"],
+		""
+	);
+	grapheme_test("\u{0378}\u{000A}",
+		&["\u{0378}", "\u{000A}"],
+		""
+	);
+	grapheme_test("\u{0378}\u{0308}\u{000A}",
+		&["\u{0378}\u{0308}", "\u{000A}"],
+		""
+	);
+	grapheme_test("\u{0378}\u{0001}",
+		&["\u{0378}", "\u{0001}"],
+		""
+	);
+	grapheme_test("\u{0378}\u{0308}\u{0001}",
+		&["\u{0378}\u{0308}", "\u{0001}"],
+		""
+	);
+	grapheme_test("\u{0378}\u{034F}",
+		&["\u{0378}\u{034F}"],
+		""
+	);
+	grapheme_test("\u{0378}\u{0308}\u{034F}",
+		&["\u{0378}\u{0308}\u{034F}"],
+		""
+	);
+	grapheme_test("\u{0378}\u{1F1E6}",
+		&["\u{0378}", "\u{1F1E6}"],
+		""
+	);
+	grapheme_test("\u{0378}\u{0308}\u{1F1E6}",
+		&["\u{0378}\u{0308}", "\u{1F1E6}"],
+		""
+	);
+	grapheme_test("\u{0378}\u{0600}",
+		&["\u{0378}", "\u{0600}"],
+		""
+	);
+	grapheme_test("\u{0378}\u{0308}\u{0600}",
+		&["\u{0378}\u{0308}", "\u{0600}"],
+		""
+	);
+	grapheme_test("\u{0378}\u{1100}",
+		&["\u{0378}", "\u{1100}"],
+		""
+	);
+	grapheme_test("\u{0378}\u{0308}\u{1100}",
+		&["\u{0378}\u{0308}", "\u{1100}"],
+		""
+	);
+	grapheme_test("\u{0378}\u{1160}",
+		&["\u{0378}", "\u{1160}"],
+		""
+	);
+	grapheme_test("\u{0378}\u{0308}\u{1160}",
+		&["\u{0378}\u{0308}", "\u{1160}"],
+		""
+	);
+	grapheme_test("\u{0378}\u{11A8}",
+		&["\u{0378}", "\u{11A8}"],
+		""
+	);
+	grapheme_test("\u{0378}\u{0308}\u{11A8}",
+		&["\u{0378}\u{0308}", "\u{11A8}"],
+		""
+	);
+	grapheme_test("\u{0378}\u{AC00}",
+		&["\u{0378}", "\u{AC00}"],
+		""
+	);
+	grapheme_test("\u{0378}\u{0308}\u{AC00}",
+		&["\u{0378}\u{0308}", "\u{AC00}"],
+		""
+	);
+	grapheme_test("\u{0378}\u{AC01}",
+		&["\u{0378}", "\u{AC01}"],
+		""
+	);
+	grapheme_test("\u{0378}\u{0308}\u{AC01}",
+		&["\u{0378}\u{0308}", "\u{AC01}"],
+		""
+	);
+	grapheme_test("\u{0378}\u{231A}",
+		&["\u{0378}", "\u{231A}"],
+		""
+	);
+	grapheme_test("\u{0378}\u{0308}\u{231A}",
+		&["\u{0378}\u{0308}", "\u{231A}"],
+		""
+	);
+	grapheme_test("\u{0378}\u{0300}",
+		&["\u{0378}\u{0300}"],
+		""
+	);
+	grapheme_test("\u{0378}\u{0308}\u{0300}",
+		&["\u{0378}\u{0308}\u{0300}"],
+		""
+	);
+	grapheme_test("\u{0378}\u{200D}",
+		&["\u{0378}\u{200D}"],
+		""
+	);
+	grapheme_test("\u{0378}\u{0308}\u{200D}",
+		&["\u{0378}\u{0308}\u{200D}"],
+		""
+	);
+	grapheme_test("\u{0378}\u{0378}",
+		&["\u{0378}", "\u{0378}"],
+		""
+	);
+	grapheme_test("\u{0378}\u{0308}\u{0378}",
+		&["\u{0378}\u{0308}", "\u{0378}"],
+		""
+	);
+	grapheme_test("\u{000D}\u{000A}\u{0061}\u{000A}\u{0308}",
+		&["\u{000D}\u{000A}", "\u{0061}", "\u{000A}", "\u{0308}"],
+		""
+	);
+	grapheme_test("\u{0061}\u{0308}",
+		&["\u{0061}\u{0308}"],
+		""
+	);
+	grapheme_test("\u{0020}\u{200D}\u{0646}",
+		&["\u{0020}\u{200D}", "\u{0646}"],
+		""
+	);
+	grapheme_test("\u{0646}\u{200D}\u{0020}",
+		&["\u{0646}\u{200D}", "\u{0020}"],
+		""
+	);
+	grapheme_test("\u{1100}\u{1100}",
+		&["\u{1100}\u{1100}"],
+		""
+	);
+	grapheme_test("\u{AC00}\u{11A8}\u{1100}",
+		&["\u{AC00}\u{11A8}", "\u{1100}"],
+		""
+	);
+	grapheme_test("\u{AC01}\u{11A8}\u{1100}",
+		&["\u{AC01}\u{11A8}", "\u{1100}"],
+		""
+	);
+	grapheme_test("\u{1F1E6}\u{1F1E7}\u{1F1E8}\u{0062}",
+		&["\u{1F1E6}\u{1F1E7}", "\u{1F1E8}", "\u{0062}"],
+		""
+	);
+	grapheme_test("\u{0061}\u{1F1E6}\u{1F1E7}\u{1F1E8}\u{0062}",
+		&["\u{0061}", "\u{1F1E6}\u{1F1E7}", "\u{1F1E8}", "\u{0062}"],
+		""
+	);
+	grapheme_test("\u{0061}\u{1F1E6}\u{1F1E7}\u{200D}\u{1F1E8}\u{0062}",
+		&["\u{0061}", "\u{1F1E6}\u{1F1E7}\u{200D}", "\u{1F1E8}", "\u{0062}"],
+		""
+	);
+	grapheme_test("\u{0061}\u{1F1E6}\u{200D}\u{1F1E7}\u{1F1E8}\u{0062}",
+		&["\u{0061}", "\u{1F1E6}\u{200D}", "\u{1F1E7}\u{1F1E8}", "\u{0062}"],
+		""
+	);
+	grapheme_test("\u{0061}\u{1F1E6}\u{1F1E7}\u{1F1E8}\u{1F1E9}\u{0062}",
+		&["\u{0061}", "\u{1F1E6}\u{1F1E7}", "\u{1F1E8}\u{1F1E9}", "\u{0062}"],
+		""
+	);
+	grapheme_test("\u{0061}\u{200D}",
+		&["\u{0061}\u{200D}"],
+		""
+	);
+	grapheme_test("\u{0061}\u{0308}\u{0062}",
+		&["\u{0061}\u{0308}", "\u{0062}"],
+		""
+	);
+	grapheme_test("\u{1F476}\u{1F3FF}\u{1F476}",
+		&["\u{1F476}\u{1F3FF}", "\u{1F476}"],
+		""
+	);
+	grapheme_test("\u{0061}\u{1F3FF}\u{1F476}",
+		&["\u{0061}\u{1F3FF}", "\u{1F476}"],
+		""
+	);
+	grapheme_test("\u{0061}\u{1F3FF}\u{1F476}\u{200D}\u{1F6D1}",
+		&["\u{0061}\u{1F3FF}", "\u{1F476}\u{200D}\u{1F6D1}"],
+		""
+	);
+	grapheme_test("\u{1F476}\u{1F3FF}\u{0308}\u{200D}\u{1F476}\u{1F3FF}",
+		&["\u{1F476}\u{1F3FF}\u{0308}\u{200D}\u{1F476}\u{1F3FF}"],
+		""
+	);
+	grapheme_test("\u{1F6D1}\u{200D}\u{1F6D1}",
+		&["\u{1F6D1}\u{200D}\u{1F6D1}"],
+		""
+	);
+	grapheme_test("\u{0061}\u{200D}\u{1F6D1}",
+		&["\u{0061}\u{200D}", "\u{1F6D1}"],
+		""
+	);
+	grapheme_test("\u{2701}\u{200D}\u{2701}",
+		&["\u{2701}\u{200D}\u{2701}"],
+		""
+	);
+	grapheme_test("\u{0061}\u{200D}\u{2701}",
+		&["\u{0061}\u{200D}", "\u{2701}"],
+		""
+	);
+	grapheme_test("\u{0020}\u{0903}",
+		&["\u{0020}\u{0903}"],
+		""
+	);
+	grapheme_test("\u{0020}\u{0308}\u{0903}",
+		&["\u{0020}\u{0308}\u{0903}"],
+		""
+	);
+	grapheme_test("\u{000D}\u{0308}\u{0903}",
+		&["\u{000D}", "\u{0308}\u{0903}"],
+		""
+	);
+	grapheme_test("\u{000A}\u{0308}\u{0903}",
+		&["\u{000A}", "\u{0308}\u{0903}"],
+		""
+	);
+	grapheme_test("\u{0001}\u{0308}\u{0903}",
+		&["\u{0001}", "\u{0308}\u{0903}"],
+		""
+	);
+	grapheme_test("\u{034F}\u{0903}",
+		&["\u{034F}\u{0903}"],
+		""
+	);
+	grapheme_test("\u{034F}\u{0308}\u{0903}",
+		&["\u{034F}\u{0308}\u{0903}"],
+		""
+	);
+	grapheme_test("\u{1F1E6}\u{0903}",
+		&["\u{1F1E6}\u{0903}"],
+		""
+	);
+	grapheme_test("\u{1F1E6}\u{0308}\u{0903}",
+		&["\u{1F1E6}\u{0308}\u{0903}"],
+		""
+	);
+	grapheme_test("\u{0600}\u{0020}",
+		&["\u{0600}\u{0020}"],
+		""
+	);
+	grapheme_test("\u{0600}\u{1F1E6}",
+		&["\u{0600}\u{1F1E6}"],
+		""
+	);
+	grapheme_test("\u{0600}\u{0600}",
+		&["\u{0600}\u{0600}"],
+		""
+	);
+	grapheme_test("\u{0600}\u{0903}",
+		&["\u{0600}\u{0903}"],
+		""
+	);
+	grapheme_test("\u{0600}\u{0308}\u{0903}",
+		&["\u{0600}\u{0308}\u{0903}"],
+		""
+	);
+	grapheme_test("\u{0600}\u{1100}",
+		&["\u{0600}\u{1100}"],
+		""
+	);
+	grapheme_test("\u{0600}\u{1160}",
+		&["\u{0600}\u{1160}"],
+		""
+	);
+	grapheme_test("\u{0600}\u{11A8}",
+		&["\u{0600}\u{11A8}"],
+		""
+	);
+	grapheme_test("\u{0600}\u{AC00}",
+		&["\u{0600}\u{AC00}"],
+		""
+	);
+	grapheme_test("\u{0600}\u{AC01}",
+		&["\u{0600}\u{AC01}"],
+		""
+	);
+	grapheme_test("\u{0600}\u{231A}",
+		&["\u{0600}\u{231A}"],
+		""
+	);
+	grapheme_test("\u{0600}\u{0378}",
+		&["\u{0600}\u{0378}"],
+		""
+	);
+	grapheme_test("\u{0903}\u{0903}",
+		&["\u{0903}\u{0903}"],
+		""
+	);
+	grapheme_test("\u{0903}\u{0308}\u{0903}",
+		&["\u{0903}\u{0308}\u{0903}"],
+		""
+	);
+	grapheme_test("\u{1100}\u{0903}",
+		&["\u{1100}\u{0903}"],
+		""
+	);
+	grapheme_test("\u{1100}\u{0308}\u{0903}",
+		&["\u{1100}\u{0308}\u{0903}"],
+		""
+	);
+	grapheme_test("\u{1160}\u{0903}",
+		&["\u{1160}\u{0903}"],
+		""
+	);
+	grapheme_test("\u{1160}\u{0308}\u{0903}",
+		&["\u{1160}\u{0308}\u{0903}"],
+		""
+	);
+	grapheme_test("\u{11A8}\u{0903}",
+		&["\u{11A8}\u{0903}"],
+		""
+	);
+	grapheme_test("\u{11A8}\u{0308}\u{0903}",
+		&["\u{11A8}\u{0308}\u{0903}"],
+		""
+	);
+	grapheme_test("\u{AC00}\u{0903}",
+		&["\u{AC00}\u{0903}"],
+		""
+	);
+	grapheme_test("\u{AC00}\u{0308}\u{0903}",
+		&["\u{AC00}\u{0308}\u{0903}"],
+		""
+	);
+	grapheme_test("\u{AC01}\u{0903}",
+		&["\u{AC01}\u{0903}"],
+		""
+	);
+	grapheme_test("\u{AC01}\u{0308}\u{0903}",
+		&["\u{AC01}\u{0308}\u{0903}"],
+		""
+	);
+	grapheme_test("\u{231A}\u{0903}",
+		&["\u{231A}\u{0903}"],
+		""
+	);
+	grapheme_test("\u{231A}\u{0308}\u{0903}",
+		&["\u{231A}\u{0308}\u{0903}"],
+		""
+	);
+	grapheme_test("\u{0300}\u{0903}",
+		&["\u{0300}\u{0903}"],
+		""
+	);
+	grapheme_test("\u{0300}\u{0308}\u{0903}",
+		&["\u{0300}\u{0308}\u{0903}"],
+		""
+	);
+	grapheme_test("\u{200D}\u{0903}",
+		&["\u{200D}\u{0903}"],
+		""
+	);
+	grapheme_test("\u{200D}\u{0308}\u{0903}",
+		&["\u{200D}\u{0308}\u{0903}"],
+		""
+	);
+	grapheme_test("\u{0378}\u{0903}",
+		&["\u{0378}\u{0903}"],
+		""
+	);
+	grapheme_test("\u{0378}\u{0308}\u{0903}",
+		&["\u{0378}\u{0308}\u{0903}"],
+		""
+	);
+	grapheme_test("\u{0061}\u{0903}\u{0062}",
+		&["\u{0061}\u{0903}", "\u{0062}"],
+		""
+	);
+	grapheme_test("\u{0061}\u{0600}\u{0062}",
+		&["\u{0061}", "\u{0600}\u{0062}"],
+		""
+	);
+}
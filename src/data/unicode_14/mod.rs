@@ -0,0 +1,12 @@
+// GENERATED CODE DO NOT MANUALLY EDIT
+// Category and grapheme cluster tables for Unicode 14.0.0
+#[cfg(any(feature = "categories", feature = "grapheme_clusters"))]
+pub const UNICODE_VERSION: (u8, u8, u8) = (14, 0, 0);
+#[cfg(feature = "categories")]
+#[allow(clippy::large_const_arrays)]
+pub mod characters;
+#[cfg(feature = "grapheme_clusters")]
+#[allow(clippy::large_const_arrays)]
+pub mod grapheme_property;
+#[cfg(all(test, feature = "grapheme_clusters"))]
+pub mod grapheme_test;
@@ -0,0 +1,44607 @@
+// GENERATED CODE DO NOT MANUALLY EDIT
+// Generated from Unicode 15.0.0 data files:
+//   d7b51628ab4dc5853b881aaad23b3cc252792a272c7beb36c39cc1891cdaa575  ucd/UnicodeData.txt
+pub const CAT_TABLE: [u8;0x1100] = [
+	 0, // 0x0
+	 1, // 0x1
+	 2, // 0x2
+	 3, // 0x3
+	 4, // 0x4
+	 5, // 0x5
+	 6, // 0x6
+	 7, // 0x7
+	 8, // 0x8
+	 9, // 0x9
+	 10, // 0xa
+	 11, // 0xb
+	 12, // 0xc
+	 13, // 0xd
+	 14, // 0xe
+	 15, // 0xf
+	 16, // 0x10
+	 17, // 0x11
+	 18, // 0x12
+	 19, // 0x13
+	 20, // 0x14
+	 17, // 0x15
+	 21, // 0x16
+	 22, // 0x17
+	 23, // 0x18
+	 24, // 0x19
+	 25, // 0x1a
+	 26, // 0x1b
+	 27, // 0x1c
+	 28, // 0x1d
+	 29, // 0x1e
+	 30, // 0x1f
+	 31, // 0x20
+	 32, // 0x21
+	 33, // 0x22
+	 34, // 0x23
+	 35, // 0x24
+	 36, // 0x25
+	 37, // 0x26
+	 38, // 0x27
+	 39, // 0x28
+	 40, // 0x29
+	 33, // 0x2a
+	 41, // 0x2b
+	 42, // 0x2c
+	 43, // 0x2d
+	 44, // 0x2e
+	 45, // 0x2f
+	 46, // 0x30
+	 47, // 0x31
+	 48, // 0x32
+	 39, // 0x33
+	 17, // 0x34
+	 17, // 0x35
+	 17, // 0x36
+	 17, // 0x37
+	 17, // 0x38
+	 17, // 0x39
+	 17, // 0x3a
+	 17, // 0x3b
+	 17, // 0x3c
+	 17, // 0x3d
+	 17, // 0x3e
+	 17, // 0x3f
+	 17, // 0x40
+	 17, // 0x41
+	 17, // 0x42
+	 17, // 0x43
+	 17, // 0x44
+	 17, // 0x45
+	 17, // 0x46
+	 17, // 0x47
+	 17, // 0x48
+	 17, // 0x49
+	 17, // 0x4a
+	 17, // 0x4b
+	 17, // 0x4c
+	 49, // 0x4d
+	 17, // 0x4e
+	 17, // 0x4f
+	 17, // 0x50
+	 17, // 0x51
+	 17, // 0x52
+	 17, // 0x53
+	 17, // 0x54
+	 17, // 0x55
+	 17, // 0x56
+	 17, // 0x57
+	 17, // 0x58
+	 17, // 0x59
+	 17, // 0x5a
+	 17, // 0x5b
+	 17, // 0x5c
+	 17, // 0x5d
+	 17, // 0x5e
+	 17, // 0x5f
+	 17, // 0x60
+	 17, // 0x61
+	 17, // 0x62
+	 17, // 0x63
+	 17, // 0x64
+	 17, // 0x65
+	 17, // 0x66
+	 17, // 0x67
+	 17, // 0x68
+	 17, // 0x69
+	 17, // 0x6a
+	 17, // 0x6b
+	 17, // 0x6c
+	 17, // 0x6d
+	 17, // 0x6e
+	 17, // 0x6f
+	 17, // 0x70
+	 17, // 0x71
+	 17, // 0x72
+	 17, // 0x73
+	 17, // 0x74
+	 17, // 0x75
+	 17, // 0x76
+	 17, // 0x77
+	 17, // 0x78
+	 17, // 0x79
+	 17, // 0x7a
+	 17, // 0x7b
+	 17, // 0x7c
+	 17, // 0x7d
+	 17, // 0x7e
+	 17, // 0x7f
+	 17, // 0x80
+	 17, // 0x81
+	 17, // 0x82
+	 17, // 0x83
+	 17, // 0x84
+	 17, // 0x85
+	 17, // 0x86
+	 17, // 0x87
+	 17, // 0x88
+	 17, // 0x89
+	 17, // 0x8a
+	 17, // 0x8b
+	 17, // 0x8c
+	 17, // 0x8d
+	 17, // 0x8e
+	 17, // 0x8f
+	 17, // 0x90
+	 17, // 0x91
+	 17, // 0x92
+	 17, // 0x93
+	 17, // 0x94
+	 17, // 0x95
+	 17, // 0x96
+	 17, // 0x97
+	 17, // 0x98
+	 17, // 0x99
+	 17, // 0x9a
+	 17, // 0x9b
+	 17, // 0x9c
+	 17, // 0x9d
+	 17, // 0x9e
+	 17, // 0x9f
+	 50, // 0xa0
+	 17, // 0xa1
+	 17, // 0xa2
+	 17, // 0xa3
+	 51, // 0xa4
+	 17, // 0xa5
+	 52, // 0xa6
+	 53, // 0xa7
+	 54, // 0xa8
+	 55, // 0xa9
+	 56, // 0xaa
+	 57, // 0xab
+	 17, // 0xac
+	 17, // 0xad
+	 17, // 0xae
+	 17, // 0xaf
+	 17, // 0xb0
+	 17, // 0xb1
+	 17, // 0xb2
+	 17, // 0xb3
+	 17, // 0xb4
+	 17, // 0xb5
+	 17, // 0xb6
+	 17, // 0xb7
+	 17, // 0xb8
+	 17, // 0xb9
+	 17, // 0xba
+	 17, // 0xbb
+	 17, // 0xbc
+	 17, // 0xbd
+	 17, // 0xbe
+	 17, // 0xbf
+	 17, // 0xc0
+	 17, // 0xc1
+	 17, // 0xc2
+	 17, // 0xc3
+	 17, // 0xc4
+	 17, // 0xc5
+	 17, // 0xc6
+	 17, // 0xc7
+	 17, // 0xc8
+	 17, // 0xc9
+	 17, // 0xca
+	 17, // 0xcb
+	 17, // 0xcc
+	 17, // 0xcd
+	 17, // 0xce
+	 17, // 0xcf
+	 17, // 0xd0
+	 17, // 0xd1
+	 17, // 0xd2
+	 17, // 0xd3
+	 17, // 0xd4
+	 17, // 0xd5
+	 17, // 0xd6
+	 58, // 0xd7
+	 59, // 0xd8
+	 59, // 0xd9
+	 59, // 0xda
+	 59, // 0xdb
+	 59, // 0xdc
+	 59, // 0xdd
+	 59, // 0xde
+	 59, // 0xdf
+	 60, // 0xe0
+	 60, // 0xe1
+	 60, // 0xe2
+	 60, // 0xe3
+	 60, // 0xe4
+	 60, // 0xe5
+	 60, // 0xe6
+	 60, // 0xe7
+	 60, // 0xe8
+	 60, // 0xe9
+	 60, // 0xea
+	 60, // 0xeb
+	 60, // 0xec
+	 60, // 0xed
+	 60, // 0xee
+	 60, // 0xef
+	 60, // 0xf0
+	 60, // 0xf1
+	 60, // 0xf2
+	 60, // 0xf3
+	 60, // 0xf4
+	 60, // 0xf5
+	 60, // 0xf6
+	 60, // 0xf7
+	 60, // 0xf8
+	 17, // 0xf9
+	 61, // 0xfa
+	 62, // 0xfb
+	 17, // 0xfc
+	 63, // 0xfd
+	 64, // 0xfe
+	 65, // 0xff
+	 66, // 0x100
+	 67, // 0x101
+	 68, // 0x102
+	 69, // 0x103
+	 70, // 0x104
+	 71, // 0x105
+	 17, // 0x106
+	 72, // 0x107
+	 73, // 0x108
+	 74, // 0x109
+	 75, // 0x10a
+	 76, // 0x10b
+	 77, // 0x10c
+	 78, // 0x10d
+	 79, // 0x10e
+	 80, // 0x10f
+	 81, // 0x110
+	 82, // 0x111
+	 83, // 0x112
+	 84, // 0x113
+	 85, // 0x114
+	 86, // 0x115
+	 87, // 0x116
+	 88, // 0x117
+	 89, // 0x118
+	 90, // 0x119
+	 91, // 0x11a
+	 92, // 0x11b
+	 93, // 0x11c
+	 94, // 0x11d
+	 95, // 0x11e
+	 96, // 0x11f
+	 17, // 0x120
+	 17, // 0x121
+	 17, // 0x122
+	 97, // 0x123
+	 98, // 0x124
+	 99, // 0x125
+	 100, // 0x126
+	 100, // 0x127
+	 100, // 0x128
+	 100, // 0x129
+	 100, // 0x12a
+	 100, // 0x12b
+	 100, // 0x12c
+	 100, // 0x12d
+	 100, // 0x12e
+	 101, // 0x12f
+	 17, // 0x130
+	 17, // 0x131
+	 17, // 0x132
+	 17, // 0x133
+	 102, // 0x134
+	 100, // 0x135
+	 100, // 0x136
+	 100, // 0x137
+	 100, // 0x138
+	 100, // 0x139
+	 100, // 0x13a
+	 100, // 0x13b
+	 100, // 0x13c
+	 100, // 0x13d
+	 100, // 0x13e
+	 100, // 0x13f
+	 100, // 0x140
+	 100, // 0x141
+	 100, // 0x142
+	 100, // 0x143
+	 17, // 0x144
+	 17, // 0x145
+	 103, // 0x146
+	 100, // 0x147
+	 100, // 0x148
+	 100, // 0x149
+	 100, // 0x14a
+	 100, // 0x14b
+	 100, // 0x14c
+	 100, // 0x14d
+	 100, // 0x14e
+	 100, // 0x14f
+	 100, // 0x150
+	 100, // 0x151
+	 100, // 0x152
+	 100, // 0x153
+	 100, // 0x154
+	 100, // 0x155
+	 100, // 0x156
+	 100, // 0x157
+	 100, // 0x158
+	 100, // 0x159
+	 100, // 0x15a
+	 100, // 0x15b
+	 100, // 0x15c
+	 100, // 0x15d
+	 100, // 0x15e
+	 100, // 0x15f
+	 100, // 0x160
+	 100, // 0x161
+	 100, // 0x162
+	 100, // 0x163
+	 100, // 0x164
+	 100, // 0x165
+	 100, // 0x166
+	 100, // 0x167
+	 17, // 0x168
+	 17, // 0x169
+	 104, // 0x16a
+	 105, // 0x16b
+	 100, // 0x16c
+	 100, // 0x16d
+	 106, // 0x16e
+	 107, // 0x16f
+	 17, // 0x170
+	 17, // 0x171
+	 17, // 0x172
+	 17, // 0x173
+	 17, // 0x174
+	 17, // 0x175
+	 17, // 0x176
+	 17, // 0x177
+	 17, // 0x178
+	 17, // 0x179
+	 17, // 0x17a
+	 17, // 0x17b
+	 17, // 0x17c
+	 17, // 0x17d
+	 17, // 0x17e
+	 17, // 0x17f
+	 17, // 0x180
+	 17, // 0x181
+	 17, // 0x182
+	 17, // 0x183
+	 17, // 0x184
+	 17, // 0x185
+	 17, // 0x186
+	 108, // 0x187
+	 17, // 0x188
+	 17, // 0x189
+	 17, // 0x18a
+	 17, // 0x18b
+	 109, // 0x18c
+	 110, // 0x18d
+	 100, // 0x18e
+	 100, // 0x18f
+	 100, // 0x190
+	 100, // 0x191
+	 100, // 0x192
+	 100, // 0x193
+	 100, // 0x194
+	 100, // 0x195
+	 100, // 0x196
+	 100, // 0x197
+	 100, // 0x198
+	 100, // 0x199
+	 100, // 0x19a
+	 100, // 0x19b
+	 100, // 0x19c
+	 100, // 0x19d
+	 100, // 0x19e
+	 100, // 0x19f
+	 100, // 0x1a0
+	 100, // 0x1a1
+	 100, // 0x1a2
+	 100, // 0x1a3
+	 100, // 0x1a4
+	 100, // 0x1a5
+	 100, // 0x1a6
+	 100, // 0x1a7
+	 100, // 0x1a8
+	 100, // 0x1a9
+	 100, // 0x1aa
+	 100, // 0x1ab
+	 100, // 0x1ac
+	 100, // 0x1ad
+	 100, // 0x1ae
+	 111, // 0x1af
+	 17, // 0x1b0
+	 112, // 0x1b1
+	 113, // 0x1b2
+	 100, // 0x1b3
+	 100, // 0x1b4
+	 100, // 0x1b5
+	 100, // 0x1b6
+	 100, // 0x1b7
+	 100, // 0x1b8
+	 100, // 0x1b9
+	 100, // 0x1ba
+	 100, // 0x1bb
+	 114, // 0x1bc
+	 100, // 0x1bd
+	 100, // 0x1be
+	 100, // 0x1bf
+	 100, // 0x1c0
+	 100, // 0x1c1
+	 100, // 0x1c2
+	 100, // 0x1c3
+	 100, // 0x1c4
+	 100, // 0x1c5
+	 100, // 0x1c6
+	 100, // 0x1c7
+	 100, // 0x1c8
+	 100, // 0x1c9
+	 100, // 0x1ca
+	 100, // 0x1cb
+	 100, // 0x1cc
+	 100, // 0x1cd
+	 100, // 0x1ce
+	 115, // 0x1cf
+	 116, // 0x1d0
+	 117, // 0x1d1
+	 118, // 0x1d2
+	 119, // 0x1d3
+	 120, // 0x1d4
+	 121, // 0x1d5
+	 122, // 0x1d6
+	 123, // 0x1d7
+	 39, // 0x1d8
+	 39, // 0x1d9
+	 124, // 0x1da
+	 100, // 0x1db
+	 100, // 0x1dc
+	 100, // 0x1dd
+	 100, // 0x1de
+	 125, // 0x1df
+	 126, // 0x1e0
+	 127, // 0x1e1
+	 128, // 0x1e2
+	 100, // 0x1e3
+	 129, // 0x1e4
+	 100, // 0x1e5
+	 100, // 0x1e6
+	 130, // 0x1e7
+	 131, // 0x1e8
+	 132, // 0x1e9
+	 100, // 0x1ea
+	 100, // 0x1eb
+	 133, // 0x1ec
+	 134, // 0x1ed
+	 135, // 0x1ee
+	 100, // 0x1ef
+	 136, // 0x1f0
+	 137, // 0x1f1
+	 138, // 0x1f2
+	 139, // 0x1f3
+	 39, // 0x1f4
+	 39, // 0x1f5
+	 140, // 0x1f6
+	 141, // 0x1f7
+	 142, // 0x1f8
+	 39, // 0x1f9
+	 143, // 0x1fa
+	 144, // 0x1fb
+	 100, // 0x1fc
+	 100, // 0x1fd
+	 100, // 0x1fe
+	 100, // 0x1ff
+	 17, // 0x200
+	 17, // 0x201
+	 17, // 0x202
+	 17, // 0x203
+	 17, // 0x204
+	 17, // 0x205
+	 17, // 0x206
+	 17, // 0x207
+	 17, // 0x208
+	 17, // 0x209
+	 17, // 0x20a
+	 17, // 0x20b
+	 17, // 0x20c
+	 17, // 0x20d
+	 17, // 0x20e
+	 17, // 0x20f
+	 17, // 0x210
+	 17, // 0x211
+	 17, // 0x212
+	 17, // 0x213
+	 17, // 0x214
+	 17, // 0x215
+	 17, // 0x216
+	 17, // 0x217
+	 17, // 0x218
+	 17, // 0x219
+	 17, // 0x21a
+	 17, // 0x21b
+	 17, // 0x21c
+	 17, // 0x21d
+	 17, // 0x21e
+	 17, // 0x21f
+	 17, // 0x220
+	 17, // 0x221
+	 17, // 0x222
+	 17, // 0x223
+	 17, // 0x224
+	 17, // 0x225
+	 17, // 0x226
+	 17, // 0x227
+	 17, // 0x228
+	 17, // 0x229
+	 17, // 0x22a
+	 17, // 0x22b
+	 17, // 0x22c
+	 17, // 0x22d
+	 17, // 0x22e
+	 17, // 0x22f
+	 17, // 0x230
+	 17, // 0x231
+	 17, // 0x232
+	 17, // 0x233
+	 17, // 0x234
+	 17, // 0x235
+	 17, // 0x236
+	 17, // 0x237
+	 17, // 0x238
+	 17, // 0x239
+	 17, // 0x23a
+	 17, // 0x23b
+	 17, // 0x23c
+	 17, // 0x23d
+	 17, // 0x23e
+	 17, // 0x23f
+	 17, // 0x240
+	 17, // 0x241
+	 17, // 0x242
+	 17, // 0x243
+	 17, // 0x244
+	 17, // 0x245
+	 17, // 0x246
+	 17, // 0x247
+	 17, // 0x248
+	 17, // 0x249
+	 17, // 0x24a
+	 17, // 0x24b
+	 17, // 0x24c
+	 17, // 0x24d
+	 17, // 0x24e
+	 17, // 0x24f
+	 17, // 0x250
+	 17, // 0x251
+	 17, // 0x252
+	 17, // 0x253
+	 17, // 0x254
+	 17, // 0x255
+	 17, // 0x256
+	 17, // 0x257
+	 17, // 0x258
+	 17, // 0x259
+	 17, // 0x25a
+	 17, // 0x25b
+	 17, // 0x25c
+	 17, // 0x25d
+	 17, // 0x25e
+	 17, // 0x25f
+	 17, // 0x260
+	 17, // 0x261
+	 17, // 0x262
+	 17, // 0x263
+	 17, // 0x264
+	 17, // 0x265
+	 17, // 0x266
+	 17, // 0x267
+	 17, // 0x268
+	 17, // 0x269
+	 17, // 0x26a
+	 17, // 0x26b
+	 17, // 0x26c
+	 17, // 0x26d
+	 17, // 0x26e
+	 17, // 0x26f
+	 17, // 0x270
+	 17, // 0x271
+	 17, // 0x272
+	 17, // 0x273
+	 17, // 0x274
+	 17, // 0x275
+	 17, // 0x276
+	 17, // 0x277
+	 17, // 0x278
+	 17, // 0x279
+	 17, // 0x27a
+	 17, // 0x27b
+	 17, // 0x27c
+	 17, // 0x27d
+	 17, // 0x27e
+	 17, // 0x27f
+	 17, // 0x280
+	 17, // 0x281
+	 17, // 0x282
+	 17, // 0x283
+	 17, // 0x284
+	 17, // 0x285
+	 17, // 0x286
+	 17, // 0x287
+	 17, // 0x288
+	 17, // 0x289
+	 17, // 0x28a
+	 17, // 0x28b
+	 17, // 0x28c
+	 17, // 0x28d
+	 17, // 0x28e
+	 17, // 0x28f
+	 17, // 0x290
+	 17, // 0x291
+	 17, // 0x292
+	 17, // 0x293
+	 17, // 0x294
+	 17, // 0x295
+	 17, // 0x296
+	 17, // 0x297
+	 17, // 0x298
+	 17, // 0x299
+	 17, // 0x29a
+	 17, // 0x29b
+	 17, // 0x29c
+	 17, // 0x29d
+	 17, // 0x29e
+	 17, // 0x29f
+	 17, // 0x2a0
+	 17, // 0x2a1
+	 17, // 0x2a2
+	 17, // 0x2a3
+	 17, // 0x2a4
+	 17, // 0x2a5
+	 145, // 0x2a6
+	 17, // 0x2a7
+	 17, // 0x2a8
+	 17, // 0x2a9
+	 17, // 0x2aa
+	 17, // 0x2ab
+	 17, // 0x2ac
+	 17, // 0x2ad
+	 17, // 0x2ae
+	 17, // 0x2af
+	 17, // 0x2b0
+	 17, // 0x2b1
+	 17, // 0x2b2
+	 17, // 0x2b3
+	 17, // 0x2b4
+	 17, // 0x2b5
+	 17, // 0x2b6
+	 146, // 0x2b7
+	 147, // 0x2b8
+	 17, // 0x2b9
+	 17, // 0x2ba
+	 17, // 0x2bb
+	 17, // 0x2bc
+	 17, // 0x2bd
+	 17, // 0x2be
+	 17, // 0x2bf
+	 17, // 0x2c0
+	 17, // 0x2c1
+	 17, // 0x2c2
+	 17, // 0x2c3
+	 17, // 0x2c4
+	 17, // 0x2c5
+	 17, // 0x2c6
+	 17, // 0x2c7
+	 17, // 0x2c8
+	 17, // 0x2c9
+	 17, // 0x2ca
+	 17, // 0x2cb
+	 17, // 0x2cc
+	 17, // 0x2cd
+	 148, // 0x2ce
+	 17, // 0x2cf
+	 17, // 0x2d0
+	 17, // 0x2d1
+	 17, // 0x2d2
+	 17, // 0x2d3
+	 17, // 0x2d4
+	 17, // 0x2d5
+	 17, // 0x2d6
+	 17, // 0x2d7
+	 17, // 0x2d8
+	 17, // 0x2d9
+	 17, // 0x2da
+	 17, // 0x2db
+	 17, // 0x2dc
+	 17, // 0x2dd
+	 17, // 0x2de
+	 17, // 0x2df
+	 17, // 0x2e0
+	 17, // 0x2e1
+	 17, // 0x2e2
+	 17, // 0x2e3
+	 17, // 0x2e4
+	 17, // 0x2e5
+	 17, // 0x2e6
+	 17, // 0x2e7
+	 17, // 0x2e8
+	 17, // 0x2e9
+	 17, // 0x2ea
+	 149, // 0x2eb
+	 100, // 0x2ec
+	 100, // 0x2ed
+	 100, // 0x2ee
+	 100, // 0x2ef
+	 100, // 0x2f0
+	 100, // 0x2f1
+	 100, // 0x2f2
+	 100, // 0x2f3
+	 100, // 0x2f4
+	 100, // 0x2f5
+	 100, // 0x2f6
+	 100, // 0x2f7
+	 17, // 0x2f8
+	 17, // 0x2f9
+	 150, // 0x2fa
+	 100, // 0x2fb
+	 100, // 0x2fc
+	 100, // 0x2fd
+	 100, // 0x2fe
+	 100, // 0x2ff
+	 17, // 0x300
+	 17, // 0x301
+	 17, // 0x302
+	 17, // 0x303
+	 17, // 0x304
+	 17, // 0x305
+	 17, // 0x306
+	 17, // 0x307
+	 17, // 0x308
+	 17, // 0x309
+	 17, // 0x30a
+	 17, // 0x30b
+	 17, // 0x30c
+	 17, // 0x30d
+	 17, // 0x30e
+	 17, // 0x30f
+	 17, // 0x310
+	 17, // 0x311
+	 17, // 0x312
+	 151, // 0x313
+	 17, // 0x314
+	 17, // 0x315
+	 17, // 0x316
+	 17, // 0x317
+	 17, // 0x318
+	 17, // 0x319
+	 17, // 0x31a
+	 17, // 0x31b
+	 17, // 0x31c
+	 17, // 0x31d
+	 17, // 0x31e
+	 17, // 0x31f
+	 17, // 0x320
+	 17, // 0x321
+	 17, // 0x322
+	 152, // 0x323
+	 100, // 0x324
+	 100, // 0x325
+	 100, // 0x326
+	 100, // 0x327
+	 100, // 0x328
+	 100, // 0x329
+	 100, // 0x32a
+	 100, // 0x32b
+	 100, // 0x32c
+	 100, // 0x32d
+	 100, // 0x32e
+	 100, // 0x32f
+	 100, // 0x330
+	 100, // 0x331
+	 100, // 0x332
+	 100, // 0x333
+	 100, // 0x334
+	 100, // 0x335
+	 100, // 0x336
+	 100, // 0x337
+	 100, // 0x338
+	 100, // 0x339
+	 100, // 0x33a
+	 100, // 0x33b
+	 100, // 0x33c
+	 100, // 0x33d
+	 100, // 0x33e
+	 100, // 0x33f
+	 100, // 0x340
+	 100, // 0x341
+	 100, // 0x342
+	 100, // 0x343
+	 100, // 0x344
+	 100, // 0x345
+	 100, // 0x346
+	 100, // 0x347
+	 100, // 0x348
+	 100, // 0x349
+	 100, // 0x34a
+	 100, // 0x34b
+	 100, // 0x34c
+	 100, // 0x34d
+	 100, // 0x34e
+	 100, // 0x34f
+	 100, // 0x350
+	 100, // 0x351
+	 100, // 0x352
+	 100, // 0x353
+	 100, // 0x354
+	 100, // 0x355
+	 100, // 0x356
+	 100, // 0x357
+	 100, // 0x358
+	 100, // 0x359
+	 100, // 0x35a
+	 100, // 0x35b
+	 100, // 0x35c
+	 100, // 0x35d
+	 100, // 0x35e
+	 100, // 0x35f
+	 100, // 0x360
+	 100, // 0x361
+	 100, // 0x362
+	 100, // 0x363
+	 100, // 0x364
+	 100, // 0x365
+	 100, // 0x366
+	 100, // 0x367
+	 100, // 0x368
+	 100, // 0x369
+	 100, // 0x36a
+	 100, // 0x36b
+	 100, // 0x36c
+	 100, // 0x36d
+	 100, // 0x36e
+	 100, // 0x36f
+	 100, // 0x370
+	 100, // 0x371
+	 100, // 0x372
+	 100, // 0x373
+	 100, // 0x374
+	 100, // 0x375
+	 100, // 0x376
+	 100, // 0x377
+	 100, // 0x378
+	 100, // 0x379
+	 100, // 0x37a
+	 100, // 0x37b
+	 100, // 0x37c
+	 100, // 0x37d
+	 100, // 0x37e
+	 100, // 0x37f
+	 100, // 0x380
+	 100, // 0x381
+	 100, // 0x382
+	 100, // 0x383
+	 100, // 0x384
+	 100, // 0x385
+	 100, // 0x386
+	 100, // 0x387
+	 100, // 0x388
+	 100, // 0x389
+	 100, // 0x38a
+	 100, // 0x38b
+	 100, // 0x38c
+	 100, // 0x38d
+	 100, // 0x38e
+	 100, // 0x38f
+	 100, // 0x390
+	 100, // 0x391
+	 100, // 0x392
+	 100, // 0x393
+	 100, // 0x394
+	 100, // 0x395
+	 100, // 0x396
+	 100, // 0x397
+	 100, // 0x398
+	 100, // 0x399
+	 100, // 0x39a
+	 100, // 0x39b
+	 100, // 0x39c
+	 100, // 0x39d
+	 100, // 0x39e
+	 100, // 0x39f
+	 100, // 0x3a0
+	 100, // 0x3a1
+	 100, // 0x3a2
+	 100, // 0x3a3
+	 100, // 0x3a4
+	 100, // 0x3a5
+	 100, // 0x3a6
+	 100, // 0x3a7
+	 100, // 0x3a8
+	 100, // 0x3a9
+	 100, // 0x3aa
+	 100, // 0x3ab
+	 100, // 0x3ac
+	 100, // 0x3ad
+	 100, // 0x3ae
+	 100, // 0x3af
+	 100, // 0x3b0
+	 100, // 0x3b1
+	 100, // 0x3b2
+	 100, // 0x3b3
+	 100, // 0x3b4
+	 100, // 0x3b5
+	 100, // 0x3b6
+	 100, // 0x3b7
+	 100, // 0x3b8
+	 100, // 0x3b9
+	 100, // 0x3ba
+	 100, // 0x3bb
+	 100, // 0x3bc
+	 100, // 0x3bd
+	 100, // 0x3be
+	 100, // 0x3bf
+	 100, // 0x3c0
+	 100, // 0x3c1
+	 100, // 0x3c2
+	 100, // 0x3c3
+	 100, // 0x3c4
+	 100, // 0x3c5
+	 100, // 0x3c6
+	 100, // 0x3c7
+	 100, // 0x3c8
+	 100, // 0x3c9
+	 100, // 0x3ca
+	 100, // 0x3cb
+	 100, // 0x3cc
+	 100, // 0x3cd
+	 100, // 0x3ce
+	 100, // 0x3cf
+	 100, // 0x3d0
+	 100, // 0x3d1
+	 100, // 0x3d2
+	 100, // 0x3d3
+	 100, // 0x3d4
+	 100, // 0x3d5
+	 100, // 0x3d6
+	 100, // 0x3d7
+	 100, // 0x3d8
+	 100, // 0x3d9
+	 100, // 0x3da
+	 100, // 0x3db
+	 100, // 0x3dc
+	 100, // 0x3dd
+	 100, // 0x3de
+	 100, // 0x3df
+	 100, // 0x3e0
+	 100, // 0x3e1
+	 100, // 0x3e2
+	 100, // 0x3e3
+	 100, // 0x3e4
+	 100, // 0x3e5
+	 100, // 0x3e6
+	 100, // 0x3e7
+	 100, // 0x3e8
+	 100, // 0x3e9
+	 100, // 0x3ea
+	 100, // 0x3eb
+	 100, // 0x3ec
+	 100, // 0x3ed
+	 100, // 0x3ee
+	 100, // 0x3ef
+	 100, // 0x3f0
+	 100, // 0x3f1
+	 100, // 0x3f2
+	 100, // 0x3f3
+	 100, // 0x3f4
+	 100, // 0x3f5
+	 100, // 0x3f6
+	 100, // 0x3f7
+	 100, // 0x3f8
+	 100, // 0x3f9
+	 100, // 0x3fa
+	 100, // 0x3fb
+	 100, // 0x3fc
+	 100, // 0x3fd
+	 100, // 0x3fe
+	 100, // 0x3ff
+	 100, // 0x400
+	 100, // 0x401
+	 100, // 0x402
+	 100, // 0x403
+	 100, // 0x404
+	 100, // 0x405
+	 100, // 0x406
+	 100, // 0x407
+	 100, // 0x408
+	 100, // 0x409
+	 100, // 0x40a
+	 100, // 0x40b
+	 100, // 0x40c
+	 100, // 0x40d
+	 100, // 0x40e
+	 100, // 0x40f
+	 100, // 0x410
+	 100, // 0x411
+	 100, // 0x412
+	 100, // 0x413
+	 100, // 0x414
+	 100, // 0x415
+	 100, // 0x416
+	 100, // 0x417
+	 100, // 0x418
+	 100, // 0x419
+	 100, // 0x41a
+	 100, // 0x41b
+	 100, // 0x41c
+	 100, // 0x41d
+	 100, // 0x41e
+	 100, // 0x41f
+	 100, // 0x420
+	 100, // 0x421
+	 100, // 0x422
+	 100, // 0x423
+	 100, // 0x424
+	 100, // 0x425
+	 100, // 0x426
+	 100, // 0x427
+	 100, // 0x428
+	 100, // 0x429
+	 100, // 0x42a
+	 100, // 0x42b
+	 100, // 0x42c
+	 100, // 0x42d
+	 100, // 0x42e
+	 100, // 0x42f
+	 100, // 0x430
+	 100, // 0x431
+	 100, // 0x432
+	 100, // 0x433
+	 100, // 0x434
+	 100, // 0x435
+	 100, // 0x436
+	 100, // 0x437
+	 100, // 0x438
+	 100, // 0x439
+	 100, // 0x43a
+	 100, // 0x43b
+	 100, // 0x43c
+	 100, // 0x43d
+	 100, // 0x43e
+	 100, // 0x43f
+	 100, // 0x440
+	 100, // 0x441
+	 100, // 0x442
+	 100, // 0x443
+	 100, // 0x444
+	 100, // 0x445
+	 100, // 0x446
+	 100, // 0x447
+	 100, // 0x448
+	 100, // 0x449
+	 100, // 0x44a
+	 100, // 0x44b
+	 100, // 0x44c
+	 100, // 0x44d
+	 100, // 0x44e
+	 100, // 0x44f
+	 100, // 0x450
+	 100, // 0x451
+	 100, // 0x452
+	 100, // 0x453
+	 100, // 0x454
+	 100, // 0x455
+	 100, // 0x456
+	 100, // 0x457
+	 100, // 0x458
+	 100, // 0x459
+	 100, // 0x45a
+	 100, // 0x45b
+	 100, // 0x45c
+	 100, // 0x45d
+	 100, // 0x45e
+	 100, // 0x45f
+	 100, // 0x460
+	 100, // 0x461
+	 100, // 0x462
+	 100, // 0x463
+	 100, // 0x464
+	 100, // 0x465
+	 100, // 0x466
+	 100, // 0x467
+	 100, // 0x468
+	 100, // 0x469
+	 100, // 0x46a
+	 100, // 0x46b
+	 100, // 0x46c
+	 100, // 0x46d
+	 100, // 0x46e
+	 100, // 0x46f
+	 100, // 0x470
+	 100, // 0x471
+	 100, // 0x472
+	 100, // 0x473
+	 100, // 0x474
+	 100, // 0x475
+	 100, // 0x476
+	 100, // 0x477
+	 100, // 0x478
+	 100, // 0x479
+	 100, // 0x47a
+	 100, // 0x47b
+	 100, // 0x47c
+	 100, // 0x47d
+	 100, // 0x47e
+	 100, // 0x47f
+	 100, // 0x480
+	 100, // 0x481
+	 100, // 0x482
+	 100, // 0x483
+	 100, // 0x484
+	 100, // 0x485
+	 100, // 0x486
+	 100, // 0x487
+	 100, // 0x488
+	 100, // 0x489
+	 100, // 0x48a
+	 100, // 0x48b
+	 100, // 0x48c
+	 100, // 0x48d
+	 100, // 0x48e
+	 100, // 0x48f
+	 100, // 0x490
+	 100, // 0x491
+	 100, // 0x492
+	 100, // 0x493
+	 100, // 0x494
+	 100, // 0x495
+	 100, // 0x496
+	 100, // 0x497
+	 100, // 0x498
+	 100, // 0x499
+	 100, // 0x49a
+	 100, // 0x49b
+	 100, // 0x49c
+	 100, // 0x49d
+	 100, // 0x49e
+	 100, // 0x49f
+	 100, // 0x4a0
+	 100, // 0x4a1
+	 100, // 0x4a2
+	 100, // 0x4a3
+	 100, // 0x4a4
+	 100, // 0x4a5
+	 100, // 0x4a6
+	 100, // 0x4a7
+	 100, // 0x4a8
+	 100, // 0x4a9
+	 100, // 0x4aa
+	 100, // 0x4ab
+	 100, // 0x4ac
+	 100, // 0x4ad
+	 100, // 0x4ae
+	 100, // 0x4af
+	 100, // 0x4b0
+	 100, // 0x4b1
+	 100, // 0x4b2
+	 100, // 0x4b3
+	 100, // 0x4b4
+	 100, // 0x4b5
+	 100, // 0x4b6
+	 100, // 0x4b7
+	 100, // 0x4b8
+	 100, // 0x4b9
+	 100, // 0x4ba
+	 100, // 0x4bb
+	 100, // 0x4bc
+	 100, // 0x4bd
+	 100, // 0x4be
+	 100, // 0x4bf
+	 100, // 0x4c0
+	 100, // 0x4c1
+	 100, // 0x4c2
+	 100, // 0x4c3
+	 100, // 0x4c4
+	 100, // 0x4c5
+	 100, // 0x4c6
+	 100, // 0x4c7
+	 100, // 0x4c8
+	 100, // 0x4c9
+	 100, // 0x4ca
+	 100, // 0x4cb
+	 100, // 0x4cc
+	 100, // 0x4cd
+	 100, // 0x4ce
+	 100, // 0x4cf
+	 100, // 0x4d0
+	 100, // 0x4d1
+	 100, // 0x4d2
+	 100, // 0x4d3
+	 100, // 0x4d4
+	 100, // 0x4d5
+	 100, // 0x4d6
+	 100, // 0x4d7
+	 100, // 0x4d8
+	 100, // 0x4d9
+	 100, // 0x4da
+	 100, // 0x4db
+	 100, // 0x4dc
+	 100, // 0x4dd
+	 100, // 0x4de
+	 100, // 0x4df
+	 100, // 0x4e0
+	 100, // 0x4e1
+	 100, // 0x4e2
+	 100, // 0x4e3
+	 100, // 0x4e4
+	 100, // 0x4e5
+	 100, // 0x4e6
+	 100, // 0x4e7
+	 100, // 0x4e8
+	 100, // 0x4e9
+	 100, // 0x4ea
+	 100, // 0x4eb
+	 100, // 0x4ec
+	 100, // 0x4ed
+	 100, // 0x4ee
+	 100, // 0x4ef
+	 100, // 0x4f0
+	 100, // 0x4f1
+	 100, // 0x4f2
+	 100, // 0x4f3
+	 100, // 0x4f4
+	 100, // 0x4f5
+	 100, // 0x4f6
+	 100, // 0x4f7
+	 100, // 0x4f8
+	 100, // 0x4f9
+	 100, // 0x4fa
+	 100, // 0x4fb
+	 100, // 0x4fc
+	 100, // 0x4fd
+	 100, // 0x4fe
+	 100, // 0x4ff
+	 100, // 0x500
+	 100, // 0x501
+	 100, // 0x502
+	 100, // 0x503
+	 100, // 0x504
+	 100, // 0x505
+	 100, // 0x506
+	 100, // 0x507
+	 100, // 0x508
+	 100, // 0x509
+	 100, // 0x50a
+	 100, // 0x50b
+	 100, // 0x50c
+	 100, // 0x50d
+	 100, // 0x50e
+	 100, // 0x50f
+	 100, // 0x510
+	 100, // 0x511
+	 100, // 0x512
+	 100, // 0x513
+	 100, // 0x514
+	 100, // 0x515
+	 100, // 0x516
+	 100, // 0x517
+	 100, // 0x518
+	 100, // 0x519
+	 100, // 0x51a
+	 100, // 0x51b
+	 100, // 0x51c
+	 100, // 0x51d
+	 100, // 0x51e
+	 100, // 0x51f
+	 100, // 0x520
+	 100, // 0x521
+	 100, // 0x522
+	 100, // 0x523
+	 100, // 0x524
+	 100, // 0x525
+	 100, // 0x526
+	 100, // 0x527
+	 100, // 0x528
+	 100, // 0x529
+	 100, // 0x52a
+	 100, // 0x52b
+	 100, // 0x52c
+	 100, // 0x52d
+	 100, // 0x52e
+	 100, // 0x52f
+	 100, // 0x530
+	 100, // 0x531
+	 100, // 0x532
+	 100, // 0x533
+	 100, // 0x534
+	 100, // 0x535
+	 100, // 0x536
+	 100, // 0x537
+	 100, // 0x538
+	 100, // 0x539
+	 100, // 0x53a
+	 100, // 0x53b
+	 100, // 0x53c
+	 100, // 0x53d
+	 100, // 0x53e
+	 100, // 0x53f
+	 100, // 0x540
+	 100, // 0x541
+	 100, // 0x542
+	 100, // 0x543
+	 100, // 0x544
+	 100, // 0x545
+	 100, // 0x546
+	 100, // 0x547
+	 100, // 0x548
+	 100, // 0x549
+	 100, // 0x54a
+	 100, // 0x54b
+	 100, // 0x54c
+	 100, // 0x54d
+	 100, // 0x54e
+	 100, // 0x54f
+	 100, // 0x550
+	 100, // 0x551
+	 100, // 0x552
+	 100, // 0x553
+	 100, // 0x554
+	 100, // 0x555
+	 100, // 0x556
+	 100, // 0x557
+	 100, // 0x558
+	 100, // 0x559
+	 100, // 0x55a
+	 100, // 0x55b
+	 100, // 0x55c
+	 100, // 0x55d
+	 100, // 0x55e
+	 100, // 0x55f
+	 100, // 0x560
+	 100, // 0x561
+	 100, // 0x562
+	 100, // 0x563
+	 100, // 0x564
+	 100, // 0x565
+	 100, // 0x566
+	 100, // 0x567
+	 100, // 0x568
+	 100, // 0x569
+	 100, // 0x56a
+	 100, // 0x56b
+	 100, // 0x56c
+	 100, // 0x56d
+	 100, // 0x56e
+	 100, // 0x56f
+	 100, // 0x570
+	 100, // 0x571
+	 100, // 0x572
+	 100, // 0x573
+	 100, // 0x574
+	 100, // 0x575
+	 100, // 0x576
+	 100, // 0x577
+	 100, // 0x578
+	 100, // 0x579
+	 100, // 0x57a
+	 100, // 0x57b
+	 100, // 0x57c
+	 100, // 0x57d
+	 100, // 0x57e
+	 100, // 0x57f
+	 100, // 0x580
+	 100, // 0x581
+	 100, // 0x582
+	 100, // 0x583
+	 100, // 0x584
+	 100, // 0x585
+	 100, // 0x586
+	 100, // 0x587
+	 100, // 0x588
+	 100, // 0x589
+	 100, // 0x58a
+	 100, // 0x58b
+	 100, // 0x58c
+	 100, // 0x58d
+	 100, // 0x58e
+	 100, // 0x58f
+	 100, // 0x590
+	 100, // 0x591
+	 100, // 0x592
+	 100, // 0x593
+	 100, // 0x594
+	 100, // 0x595
+	 100, // 0x596
+	 100, // 0x597
+	 100, // 0x598
+	 100, // 0x599
+	 100, // 0x59a
+	 100, // 0x59b
+	 100, // 0x59c
+	 100, // 0x59d
+	 100, // 0x59e
+	 100, // 0x59f
+	 100, // 0x5a0
+	 100, // 0x5a1
+	 100, // 0x5a2
+	 100, // 0x5a3
+	 100, // 0x5a4
+	 100, // 0x5a5
+	 100, // 0x5a6
+	 100, // 0x5a7
+	 100, // 0x5a8
+	 100, // 0x5a9
+	 100, // 0x5aa
+	 100, // 0x5ab
+	 100, // 0x5ac
+	 100, // 0x5ad
+	 100, // 0x5ae
+	 100, // 0x5af
+	 100, // 0x5b0
+	 100, // 0x5b1
+	 100, // 0x5b2
+	 100, // 0x5b3
+	 100, // 0x5b4
+	 100, // 0x5b5
+	 100, // 0x5b6
+	 100, // 0x5b7
+	 100, // 0x5b8
+	 100, // 0x5b9
+	 100, // 0x5ba
+	 100, // 0x5bb
+	 100, // 0x5bc
+	 100, // 0x5bd
+	 100, // 0x5be
+	 100, // 0x5bf
+	 100, // 0x5c0
+	 100, // 0x5c1
+	 100, // 0x5c2
+	 100, // 0x5c3
+	 100, // 0x5c4
+	 100, // 0x5c5
+	 100, // 0x5c6
+	 100, // 0x5c7
+	 100, // 0x5c8
+	 100, // 0x5c9
+	 100, // 0x5ca
+	 100, // 0x5cb
+	 100, // 0x5cc
+	 100, // 0x5cd
+	 100, // 0x5ce
+	 100, // 0x5cf
+	 100, // 0x5d0
+	 100, // 0x5d1
+	 100, // 0x5d2
+	 100, // 0x5d3
+	 100, // 0x5d4
+	 100, // 0x5d5
+	 100, // 0x5d6
+	 100, // 0x5d7
+	 100, // 0x5d8
+	 100, // 0x5d9
+	 100, // 0x5da
+	 100, // 0x5db
+	 100, // 0x5dc
+	 100, // 0x5dd
+	 100, // 0x5de
+	 100, // 0x5df
+	 100, // 0x5e0
+	 100, // 0x5e1
+	 100, // 0x5e2
+	 100, // 0x5e3
+	 100, // 0x5e4
+	 100, // 0x5e5
+	 100, // 0x5e6
+	 100, // 0x5e7
+	 100, // 0x5e8
+	 100, // 0x5e9
+	 100, // 0x5ea
+	 100, // 0x5eb
+	 100, // 0x5ec
+	 100, // 0x5ed
+	 100, // 0x5ee
+	 100, // 0x5ef
+	 100, // 0x5f0
+	 100, // 0x5f1
+	 100, // 0x5f2
+	 100, // 0x5f3
+	 100, // 0x5f4
+	 100, // 0x5f5
+	 100, // 0x5f6
+	 100, // 0x5f7
+	 100, // 0x5f8
+	 100, // 0x5f9
+	 100, // 0x5fa
+	 100, // 0x5fb
+	 100, // 0x5fc
+	 100, // 0x5fd
+	 100, // 0x5fe
+	 100, // 0x5ff
+	 100, // 0x600
+	 100, // 0x601
+	 100, // 0x602
+	 100, // 0x603
+	 100, // 0x604
+	 100, // 0x605
+	 100, // 0x606
+	 100, // 0x607
+	 100, // 0x608
+	 100, // 0x609
+	 100, // 0x60a
+	 100, // 0x60b
+	 100, // 0x60c
+	 100, // 0x60d
+	 100, // 0x60e
+	 100, // 0x60f
+	 100, // 0x610
+	 100, // 0x611
+	 100, // 0x612
+	 100, // 0x613
+	 100, // 0x614
+	 100, // 0x615
+	 100, // 0x616
+	 100, // 0x617
+	 100, // 0x618
+	 100, // 0x619
+	 100, // 0x61a
+	 100, // 0x61b
+	 100, // 0x61c
+	 100, // 0x61d
+	 100, // 0x61e
+	 100, // 0x61f
+	 100, // 0x620
+	 100, // 0x621
+	 100, // 0x622
+	 100, // 0x623
+	 100, // 0x624
+	 100, // 0x625
+	 100, // 0x626
+	 100, // 0x627
+	 100, // 0x628
+	 100, // 0x629
+	 100, // 0x62a
+	 100, // 0x62b
+	 100, // 0x62c
+	 100, // 0x62d
+	 100, // 0x62e
+	 100, // 0x62f
+	 100, // 0x630
+	 100, // 0x631
+	 100, // 0x632
+	 100, // 0x633
+	 100, // 0x634
+	 100, // 0x635
+	 100, // 0x636
+	 100, // 0x637
+	 100, // 0x638
+	 100, // 0x639
+	 100, // 0x63a
+	 100, // 0x63b
+	 100, // 0x63c
+	 100, // 0x63d
+	 100, // 0x63e
+	 100, // 0x63f
+	 100, // 0x640
+	 100, // 0x641
+	 100, // 0x642
+	 100, // 0x643
+	 100, // 0x644
+	 100, // 0x645
+	 100, // 0x646
+	 100, // 0x647
+	 100, // 0x648
+	 100, // 0x649
+	 100, // 0x64a
+	 100, // 0x64b
+	 100, // 0x64c
+	 100, // 0x64d
+	 100, // 0x64e
+	 100, // 0x64f
+	 100, // 0x650
+	 100, // 0x651
+	 100, // 0x652
+	 100, // 0x653
+	 100, // 0x654
+	 100, // 0x655
+	 100, // 0x656
+	 100, // 0x657
+	 100, // 0x658
+	 100, // 0x659
+	 100, // 0x65a
+	 100, // 0x65b
+	 100, // 0x65c
+	 100, // 0x65d
+	 100, // 0x65e
+	 100, // 0x65f
+	 100, // 0x660
+	 100, // 0x661
+	 100, // 0x662
+	 100, // 0x663
+	 100, // 0x664
+	 100, // 0x665
+	 100, // 0x666
+	 100, // 0x667
+	 100, // 0x668
+	 100, // 0x669
+	 100, // 0x66a
+	 100, // 0x66b
+	 100, // 0x66c
+	 100, // 0x66d
+	 100, // 0x66e
+	 100, // 0x66f
+	 100, // 0x670
+	 100, // 0x671
+	 100, // 0x672
+	 100, // 0x673
+	 100, // 0x674
+	 100, // 0x675
+	 100, // 0x676
+	 100, // 0x677
+	 100, // 0x678
+	 100, // 0x679
+	 100, // 0x67a
+	 100, // 0x67b
+	 100, // 0x67c
+	 100, // 0x67d
+	 100, // 0x67e
+	 100, // 0x67f
+	 100, // 0x680
+	 100, // 0x681
+	 100, // 0x682
+	 100, // 0x683
+	 100, // 0x684
+	 100, // 0x685
+	 100, // 0x686
+	 100, // 0x687
+	 100, // 0x688
+	 100, // 0x689
+	 100, // 0x68a
+	 100, // 0x68b
+	 100, // 0x68c
+	 100, // 0x68d
+	 100, // 0x68e
+	 100, // 0x68f
+	 100, // 0x690
+	 100, // 0x691
+	 100, // 0x692
+	 100, // 0x693
+	 100, // 0x694
+	 100, // 0x695
+	 100, // 0x696
+	 100, // 0x697
+	 100, // 0x698
+	 100, // 0x699
+	 100, // 0x69a
+	 100, // 0x69b
+	 100, // 0x69c
+	 100, // 0x69d
+	 100, // 0x69e
+	 100, // 0x69f
+	 100, // 0x6a0
+	 100, // 0x6a1
+	 100, // 0x6a2
+	 100, // 0x6a3
+	 100, // 0x6a4
+	 100, // 0x6a5
+	 100, // 0x6a6
+	 100, // 0x6a7
+	 100, // 0x6a8
+	 100, // 0x6a9
+	 100, // 0x6aa
+	 100, // 0x6ab
+	 100, // 0x6ac
+	 100, // 0x6ad
+	 100, // 0x6ae
+	 100, // 0x6af
+	 100, // 0x6b0
+	 100, // 0x6b1
+	 100, // 0x6b2
+	 100, // 0x6b3
+	 100, // 0x6b4
+	 100, // 0x6b5
+	 100, // 0x6b6
+	 100, // 0x6b7
+	 100, // 0x6b8
+	 100, // 0x6b9
+	 100, // 0x6ba
+	 100, // 0x6bb
+	 100, // 0x6bc
+	 100, // 0x6bd
+	 100, // 0x6be
+	 100, // 0x6bf
+	 100, // 0x6c0
+	 100, // 0x6c1
+	 100, // 0x6c2
+	 100, // 0x6c3
+	 100, // 0x6c4
+	 100, // 0x6c5
+	 100, // 0x6c6
+	 100, // 0x6c7
+	 100, // 0x6c8
+	 100, // 0x6c9
+	 100, // 0x6ca
+	 100, // 0x6cb
+	 100, // 0x6cc
+	 100, // 0x6cd
+	 100, // 0x6ce
+	 100, // 0x6cf
+	 100, // 0x6d0
+	 100, // 0x6d1
+	 100, // 0x6d2
+	 100, // 0x6d3
+	 100, // 0x6d4
+	 100, // 0x6d5
+	 100, // 0x6d6
+	 100, // 0x6d7
+	 100, // 0x6d8
+	 100, // 0x6d9
+	 100, // 0x6da
+	 100, // 0x6db
+	 100, // 0x6dc
+	 100, // 0x6dd
+	 100, // 0x6de
+	 100, // 0x6df
+	 100, // 0x6e0
+	 100, // 0x6e1
+	 100, // 0x6e2
+	 100, // 0x6e3
+	 100, // 0x6e4
+	 100, // 0x6e5
+	 100, // 0x6e6
+	 100, // 0x6e7
+	 100, // 0x6e8
+	 100, // 0x6e9
+	 100, // 0x6ea
+	 100, // 0x6eb
+	 100, // 0x6ec
+	 100, // 0x6ed
+	 100, // 0x6ee
+	 100, // 0x6ef
+	 100, // 0x6f0
+	 100, // 0x6f1
+	 100, // 0x6f2
+	 100, // 0x6f3
+	 100, // 0x6f4
+	 100, // 0x6f5
+	 100, // 0x6f6
+	 100, // 0x6f7
+	 100, // 0x6f8
+	 100, // 0x6f9
+	 100, // 0x6fa
+	 100, // 0x6fb
+	 100, // 0x6fc
+	 100, // 0x6fd
+	 100, // 0x6fe
+	 100, // 0x6ff
+	 100, // 0x700
+	 100, // 0x701
+	 100, // 0x702
+	 100, // 0x703
+	 100, // 0x704
+	 100, // 0x705
+	 100, // 0x706
+	 100, // 0x707
+	 100, // 0x708
+	 100, // 0x709
+	 100, // 0x70a
+	 100, // 0x70b
+	 100, // 0x70c
+	 100, // 0x70d
+	 100, // 0x70e
+	 100, // 0x70f
+	 100, // 0x710
+	 100, // 0x711
+	 100, // 0x712
+	 100, // 0x713
+	 100, // 0x714
+	 100, // 0x715
+	 100, // 0x716
+	 100, // 0x717
+	 100, // 0x718
+	 100, // 0x719
+	 100, // 0x71a
+	 100, // 0x71b
+	 100, // 0x71c
+	 100, // 0x71d
+	 100, // 0x71e
+	 100, // 0x71f
+	 100, // 0x720
+	 100, // 0x721
+	 100, // 0x722
+	 100, // 0x723
+	 100, // 0x724
+	 100, // 0x725
+	 100, // 0x726
+	 100, // 0x727
+	 100, // 0x728
+	 100, // 0x729
+	 100, // 0x72a
+	 100, // 0x72b
+	 100, // 0x72c
+	 100, // 0x72d
+	 100, // 0x72e
+	 100, // 0x72f
+	 100, // 0x730
+	 100, // 0x731
+	 100, // 0x732
+	 100, // 0x733
+	 100, // 0x734
+	 100, // 0x735
+	 100, // 0x736
+	 100, // 0x737
+	 100, // 0x738
+	 100, // 0x739
+	 100, // 0x73a
+	 100, // 0x73b
+	 100, // 0x73c
+	 100, // 0x73d
+	 100, // 0x73e
+	 100, // 0x73f
+	 100, // 0x740
+	 100, // 0x741
+	 100, // 0x742
+	 100, // 0x743
+	 100, // 0x744
+	 100, // 0x745
+	 100, // 0x746
+	 100, // 0x747
+	 100, // 0x748
+	 100, // 0x749
+	 100, // 0x74a
+	 100, // 0x74b
+	 100, // 0x74c
+	 100, // 0x74d
+	 100, // 0x74e
+	 100, // 0x74f
+	 100, // 0x750
+	 100, // 0x751
+	 100, // 0x752
+	 100, // 0x753
+	 100, // 0x754
+	 100, // 0x755
+	 100, // 0x756
+	 100, // 0x757
+	 100, // 0x758
+	 100, // 0x759
+	 100, // 0x75a
+	 100, // 0x75b
+	 100, // 0x75c
+	 100, // 0x75d
+	 100, // 0x75e
+	 100, // 0x75f
+	 100, // 0x760
+	 100, // 0x761
+	 100, // 0x762
+	 100, // 0x763
+	 100, // 0x764
+	 100, // 0x765
+	 100, // 0x766
+	 100, // 0x767
+	 100, // 0x768
+	 100, // 0x769
+	 100, // 0x76a
+	 100, // 0x76b
+	 100, // 0x76c
+	 100, // 0x76d
+	 100, // 0x76e
+	 100, // 0x76f
+	 100, // 0x770
+	 100, // 0x771
+	 100, // 0x772
+	 100, // 0x773
+	 100, // 0x774
+	 100, // 0x775
+	 100, // 0x776
+	 100, // 0x777
+	 100, // 0x778
+	 100, // 0x779
+	 100, // 0x77a
+	 100, // 0x77b
+	 100, // 0x77c
+	 100, // 0x77d
+	 100, // 0x77e
+	 100, // 0x77f
+	 100, // 0x780
+	 100, // 0x781
+	 100, // 0x782
+	 100, // 0x783
+	 100, // 0x784
+	 100, // 0x785
+	 100, // 0x786
+	 100, // 0x787
+	 100, // 0x788
+	 100, // 0x789
+	 100, // 0x78a
+	 100, // 0x78b
+	 100, // 0x78c
+	 100, // 0x78d
+	 100, // 0x78e
+	 100, // 0x78f
+	 100, // 0x790
+	 100, // 0x791
+	 100, // 0x792
+	 100, // 0x793
+	 100, // 0x794
+	 100, // 0x795
+	 100, // 0x796
+	 100, // 0x797
+	 100, // 0x798
+	 100, // 0x799
+	 100, // 0x79a
+	 100, // 0x79b
+	 100, // 0x79c
+	 100, // 0x79d
+	 100, // 0x79e
+	 100, // 0x79f
+	 100, // 0x7a0
+	 100, // 0x7a1
+	 100, // 0x7a2
+	 100, // 0x7a3
+	 100, // 0x7a4
+	 100, // 0x7a5
+	 100, // 0x7a6
+	 100, // 0x7a7
+	 100, // 0x7a8
+	 100, // 0x7a9
+	 100, // 0x7aa
+	 100, // 0x7ab
+	 100, // 0x7ac
+	 100, // 0x7ad
+	 100, // 0x7ae
+	 100, // 0x7af
+	 100, // 0x7b0
+	 100, // 0x7b1
+	 100, // 0x7b2
+	 100, // 0x7b3
+	 100, // 0x7b4
+	 100, // 0x7b5
+	 100, // 0x7b6
+	 100, // 0x7b7
+	 100, // 0x7b8
+	 100, // 0x7b9
+	 100, // 0x7ba
+	 100, // 0x7bb
+	 100, // 0x7bc
+	 100, // 0x7bd
+	 100, // 0x7be
+	 100, // 0x7bf
+	 100, // 0x7c0
+	 100, // 0x7c1
+	 100, // 0x7c2
+	 100, // 0x7c3
+	 100, // 0x7c4
+	 100, // 0x7c5
+	 100, // 0x7c6
+	 100, // 0x7c7
+	 100, // 0x7c8
+	 100, // 0x7c9
+	 100, // 0x7ca
+	 100, // 0x7cb
+	 100, // 0x7cc
+	 100, // 0x7cd
+	 100, // 0x7ce
+	 100, // 0x7cf
+	 100, // 0x7d0
+	 100, // 0x7d1
+	 100, // 0x7d2
+	 100, // 0x7d3
+	 100, // 0x7d4
+	 100, // 0x7d5
+	 100, // 0x7d6
+	 100, // 0x7d7
+	 100, // 0x7d8
+	 100, // 0x7d9
+	 100, // 0x7da
+	 100, // 0x7db
+	 100, // 0x7dc
+	 100, // 0x7dd
+	 100, // 0x7de
+	 100, // 0x7df
+	 100, // 0x7e0
+	 100, // 0x7e1
+	 100, // 0x7e2
+	 100, // 0x7e3
+	 100, // 0x7e4
+	 100, // 0x7e5
+	 100, // 0x7e6
+	 100, // 0x7e7
+	 100, // 0x7e8
+	 100, // 0x7e9
+	 100, // 0x7ea
+	 100, // 0x7eb
+	 100, // 0x7ec
+	 100, // 0x7ed
+	 100, // 0x7ee
+	 100, // 0x7ef
+	 100, // 0x7f0
+	 100, // 0x7f1
+	 100, // 0x7f2
+	 100, // 0x7f3
+	 100, // 0x7f4
+	 100, // 0x7f5
+	 100, // 0x7f6
+	 100, // 0x7f7
+	 100, // 0x7f8
+	 100, // 0x7f9
+	 100, // 0x7fa
+	 100, // 0x7fb
+	 100, // 0x7fc
+	 100, // 0x7fd
+	 100, // 0x7fe
+	 100, // 0x7ff
+	 100, // 0x800
+	 100, // 0x801
+	 100, // 0x802
+	 100, // 0x803
+	 100, // 0x804
+	 100, // 0x805
+	 100, // 0x806
+	 100, // 0x807
+	 100, // 0x808
+	 100, // 0x809
+	 100, // 0x80a
+	 100, // 0x80b
+	 100, // 0x80c
+	 100, // 0x80d
+	 100, // 0x80e
+	 100, // 0x80f
+	 100, // 0x810
+	 100, // 0x811
+	 100, // 0x812
+	 100, // 0x813
+	 100, // 0x814
+	 100, // 0x815
+	 100, // 0x816
+	 100, // 0x817
+	 100, // 0x818
+	 100, // 0x819
+	 100, // 0x81a
+	 100, // 0x81b
+	 100, // 0x81c
+	 100, // 0x81d
+	 100, // 0x81e
+	 100, // 0x81f
+	 100, // 0x820
+	 100, // 0x821
+	 100, // 0x822
+	 100, // 0x823
+	 100, // 0x824
+	 100, // 0x825
+	 100, // 0x826
+	 100, // 0x827
+	 100, // 0x828
+	 100, // 0x829
+	 100, // 0x82a
+	 100, // 0x82b
+	 100, // 0x82c
+	 100, // 0x82d
+	 100, // 0x82e
+	 100, // 0x82f
+	 100, // 0x830
+	 100, // 0x831
+	 100, // 0x832
+	 100, // 0x833
+	 100, // 0x834
+	 100, // 0x835
+	 100, // 0x836
+	 100, // 0x837
+	 100, // 0x838
+	 100, // 0x839
+	 100, // 0x83a
+	 100, // 0x83b
+	 100, // 0x83c
+	 100, // 0x83d
+	 100, // 0x83e
+	 100, // 0x83f
+	 100, // 0x840
+	 100, // 0x841
+	 100, // 0x842
+	 100, // 0x843
+	 100, // 0x844
+	 100, // 0x845
+	 100, // 0x846
+	 100, // 0x847
+	 100, // 0x848
+	 100, // 0x849
+	 100, // 0x84a
+	 100, // 0x84b
+	 100, // 0x84c
+	 100, // 0x84d
+	 100, // 0x84e
+	 100, // 0x84f
+	 100, // 0x850
+	 100, // 0x851
+	 100, // 0x852
+	 100, // 0x853
+	 100, // 0x854
+	 100, // 0x855
+	 100, // 0x856
+	 100, // 0x857
+	 100, // 0x858
+	 100, // 0x859
+	 100, // 0x85a
+	 100, // 0x85b
+	 100, // 0x85c
+	 100, // 0x85d
+	 100, // 0x85e
+	 100, // 0x85f
+	 100, // 0x860
+	 100, // 0x861
+	 100, // 0x862
+	 100, // 0x863
+	 100, // 0x864
+	 100, // 0x865
+	 100, // 0x866
+	 100, // 0x867
+	 100, // 0x868
+	 100, // 0x869
+	 100, // 0x86a
+	 100, // 0x86b
+	 100, // 0x86c
+	 100, // 0x86d
+	 100, // 0x86e
+	 100, // 0x86f
+	 100, // 0x870
+	 100, // 0x871
+	 100, // 0x872
+	 100, // 0x873
+	 100, // 0x874
+	 100, // 0x875
+	 100, // 0x876
+	 100, // 0x877
+	 100, // 0x878
+	 100, // 0x879
+	 100, // 0x87a
+	 100, // 0x87b
+	 100, // 0x87c
+	 100, // 0x87d
+	 100, // 0x87e
+	 100, // 0x87f
+	 100, // 0x880
+	 100, // 0x881
+	 100, // 0x882
+	 100, // 0x883
+	 100, // 0x884
+	 100, // 0x885
+	 100, // 0x886
+	 100, // 0x887
+	 100, // 0x888
+	 100, // 0x889
+	 100, // 0x88a
+	 100, // 0x88b
+	 100, // 0x88c
+	 100, // 0x88d
+	 100, // 0x88e
+	 100, // 0x88f
+	 100, // 0x890
+	 100, // 0x891
+	 100, // 0x892
+	 100, // 0x893
+	 100, // 0x894
+	 100, // 0x895
+	 100, // 0x896
+	 100, // 0x897
+	 100, // 0x898
+	 100, // 0x899
+	 100, // 0x89a
+	 100, // 0x89b
+	 100, // 0x89c
+	 100, // 0x89d
+	 100, // 0x89e
+	 100, // 0x89f
+	 100, // 0x8a0
+	 100, // 0x8a1
+	 100, // 0x8a2
+	 100, // 0x8a3
+	 100, // 0x8a4
+	 100, // 0x8a5
+	 100, // 0x8a6
+	 100, // 0x8a7
+	 100, // 0x8a8
+	 100, // 0x8a9
+	 100, // 0x8aa
+	 100, // 0x8ab
+	 100, // 0x8ac
+	 100, // 0x8ad
+	 100, // 0x8ae
+	 100, // 0x8af
+	 100, // 0x8b0
+	 100, // 0x8b1
+	 100, // 0x8b2
+	 100, // 0x8b3
+	 100, // 0x8b4
+	 100, // 0x8b5
+	 100, // 0x8b6
+	 100, // 0x8b7
+	 100, // 0x8b8
+	 100, // 0x8b9
+	 100, // 0x8ba
+	 100, // 0x8bb
+	 100, // 0x8bc
+	 100, // 0x8bd
+	 100, // 0x8be
+	 100, // 0x8bf
+	 100, // 0x8c0
+	 100, // 0x8c1
+	 100, // 0x8c2
+	 100, // 0x8c3
+	 100, // 0x8c4
+	 100, // 0x8c5
+	 100, // 0x8c6
+	 100, // 0x8c7
+	 100, // 0x8c8
+	 100, // 0x8c9
+	 100, // 0x8ca
+	 100, // 0x8cb
+	 100, // 0x8cc
+	 100, // 0x8cd
+	 100, // 0x8ce
+	 100, // 0x8cf
+	 100, // 0x8d0
+	 100, // 0x8d1
+	 100, // 0x8d2
+	 100, // 0x8d3
+	 100, // 0x8d4
+	 100, // 0x8d5
+	 100, // 0x8d6
+	 100, // 0x8d7
+	 100, // 0x8d8
+	 100, // 0x8d9
+	 100, // 0x8da
+	 100, // 0x8db
+	 100, // 0x8dc
+	 100, // 0x8dd
+	 100, // 0x8de
+	 100, // 0x8df
+	 100, // 0x8e0
+	 100, // 0x8e1
+	 100, // 0x8e2
+	 100, // 0x8e3
+	 100, // 0x8e4
+	 100, // 0x8e5
+	 100, // 0x8e6
+	 100, // 0x8e7
+	 100, // 0x8e8
+	 100, // 0x8e9
+	 100, // 0x8ea
+	 100, // 0x8eb
+	 100, // 0x8ec
+	 100, // 0x8ed
+	 100, // 0x8ee
+	 100, // 0x8ef
+	 100, // 0x8f0
+	 100, // 0x8f1
+	 100, // 0x8f2
+	 100, // 0x8f3
+	 100, // 0x8f4
+	 100, // 0x8f5
+	 100, // 0x8f6
+	 100, // 0x8f7
+	 100, // 0x8f8
+	 100, // 0x8f9
+	 100, // 0x8fa
+	 100, // 0x8fb
+	 100, // 0x8fc
+	 100, // 0x8fd
+	 100, // 0x8fe
+	 100, // 0x8ff
+	 100, // 0x900
+	 100, // 0x901
+	 100, // 0x902
+	 100, // 0x903
+	 100, // 0x904
+	 100, // 0x905
+	 100, // 0x906
+	 100, // 0x907
+	 100, // 0x908
+	 100, // 0x909
+	 100, // 0x90a
+	 100, // 0x90b
+	 100, // 0x90c
+	 100, // 0x90d
+	 100, // 0x90e
+	 100, // 0x90f
+	 100, // 0x910
+	 100, // 0x911
+	 100, // 0x912
+	 100, // 0x913
+	 100, // 0x914
+	 100, // 0x915
+	 100, // 0x916
+	 100, // 0x917
+	 100, // 0x918
+	 100, // 0x919
+	 100, // 0x91a
+	 100, // 0x91b
+	 100, // 0x91c
+	 100, // 0x91d
+	 100, // 0x91e
+	 100, // 0x91f
+	 100, // 0x920
+	 100, // 0x921
+	 100, // 0x922
+	 100, // 0x923
+	 100, // 0x924
+	 100, // 0x925
+	 100, // 0x926
+	 100, // 0x927
+	 100, // 0x928
+	 100, // 0x929
+	 100, // 0x92a
+	 100, // 0x92b
+	 100, // 0x92c
+	 100, // 0x92d
+	 100, // 0x92e
+	 100, // 0x92f
+	 100, // 0x930
+	 100, // 0x931
+	 100, // 0x932
+	 100, // 0x933
+	 100, // 0x934
+	 100, // 0x935
+	 100, // 0x936
+	 100, // 0x937
+	 100, // 0x938
+	 100, // 0x939
+	 100, // 0x93a
+	 100, // 0x93b
+	 100, // 0x93c
+	 100, // 0x93d
+	 100, // 0x93e
+	 100, // 0x93f
+	 100, // 0x940
+	 100, // 0x941
+	 100, // 0x942
+	 100, // 0x943
+	 100, // 0x944
+	 100, // 0x945
+	 100, // 0x946
+	 100, // 0x947
+	 100, // 0x948
+	 100, // 0x949
+	 100, // 0x94a
+	 100, // 0x94b
+	 100, // 0x94c
+	 100, // 0x94d
+	 100, // 0x94e
+	 100, // 0x94f
+	 100, // 0x950
+	 100, // 0x951
+	 100, // 0x952
+	 100, // 0x953
+	 100, // 0x954
+	 100, // 0x955
+	 100, // 0x956
+	 100, // 0x957
+	 100, // 0x958
+	 100, // 0x959
+	 100, // 0x95a
+	 100, // 0x95b
+	 100, // 0x95c
+	 100, // 0x95d
+	 100, // 0x95e
+	 100, // 0x95f
+	 100, // 0x960
+	 100, // 0x961
+	 100, // 0x962
+	 100, // 0x963
+	 100, // 0x964
+	 100, // 0x965
+	 100, // 0x966
+	 100, // 0x967
+	 100, // 0x968
+	 100, // 0x969
+	 100, // 0x96a
+	 100, // 0x96b
+	 100, // 0x96c
+	 100, // 0x96d
+	 100, // 0x96e
+	 100, // 0x96f
+	 100, // 0x970
+	 100, // 0x971
+	 100, // 0x972
+	 100, // 0x973
+	 100, // 0x974
+	 100, // 0x975
+	 100, // 0x976
+	 100, // 0x977
+	 100, // 0x978
+	 100, // 0x979
+	 100, // 0x97a
+	 100, // 0x97b
+	 100, // 0x97c
+	 100, // 0x97d
+	 100, // 0x97e
+	 100, // 0x97f
+	 100, // 0x980
+	 100, // 0x981
+	 100, // 0x982
+	 100, // 0x983
+	 100, // 0x984
+	 100, // 0x985
+	 100, // 0x986
+	 100, // 0x987
+	 100, // 0x988
+	 100, // 0x989
+	 100, // 0x98a
+	 100, // 0x98b
+	 100, // 0x98c
+	 100, // 0x98d
+	 100, // 0x98e
+	 100, // 0x98f
+	 100, // 0x990
+	 100, // 0x991
+	 100, // 0x992
+	 100, // 0x993
+	 100, // 0x994
+	 100, // 0x995
+	 100, // 0x996
+	 100, // 0x997
+	 100, // 0x998
+	 100, // 0x999
+	 100, // 0x99a
+	 100, // 0x99b
+	 100, // 0x99c
+	 100, // 0x99d
+	 100, // 0x99e
+	 100, // 0x99f
+	 100, // 0x9a0
+	 100, // 0x9a1
+	 100, // 0x9a2
+	 100, // 0x9a3
+	 100, // 0x9a4
+	 100, // 0x9a5
+	 100, // 0x9a6
+	 100, // 0x9a7
+	 100, // 0x9a8
+	 100, // 0x9a9
+	 100, // 0x9aa
+	 100, // 0x9ab
+	 100, // 0x9ac
+	 100, // 0x9ad
+	 100, // 0x9ae
+	 100, // 0x9af
+	 100, // 0x9b0
+	 100, // 0x9b1
+	 100, // 0x9b2
+	 100, // 0x9b3
+	 100, // 0x9b4
+	 100, // 0x9b5
+	 100, // 0x9b6
+	 100, // 0x9b7
+	 100, // 0x9b8
+	 100, // 0x9b9
+	 100, // 0x9ba
+	 100, // 0x9bb
+	 100, // 0x9bc
+	 100, // 0x9bd
+	 100, // 0x9be
+	 100, // 0x9bf
+	 100, // 0x9c0
+	 100, // 0x9c1
+	 100, // 0x9c2
+	 100, // 0x9c3
+	 100, // 0x9c4
+	 100, // 0x9c5
+	 100, // 0x9c6
+	 100, // 0x9c7
+	 100, // 0x9c8
+	 100, // 0x9c9
+	 100, // 0x9ca
+	 100, // 0x9cb
+	 100, // 0x9cc
+	 100, // 0x9cd
+	 100, // 0x9ce
+	 100, // 0x9cf
+	 100, // 0x9d0
+	 100, // 0x9d1
+	 100, // 0x9d2
+	 100, // 0x9d3
+	 100, // 0x9d4
+	 100, // 0x9d5
+	 100, // 0x9d6
+	 100, // 0x9d7
+	 100, // 0x9d8
+	 100, // 0x9d9
+	 100, // 0x9da
+	 100, // 0x9db
+	 100, // 0x9dc
+	 100, // 0x9dd
+	 100, // 0x9de
+	 100, // 0x9df
+	 100, // 0x9e0
+	 100, // 0x9e1
+	 100, // 0x9e2
+	 100, // 0x9e3
+	 100, // 0x9e4
+	 100, // 0x9e5
+	 100, // 0x9e6
+	 100, // 0x9e7
+	 100, // 0x9e8
+	 100, // 0x9e9
+	 100, // 0x9ea
+	 100, // 0x9eb
+	 100, // 0x9ec
+	 100, // 0x9ed
+	 100, // 0x9ee
+	 100, // 0x9ef
+	 100, // 0x9f0
+	 100, // 0x9f1
+	 100, // 0x9f2
+	 100, // 0x9f3
+	 100, // 0x9f4
+	 100, // 0x9f5
+	 100, // 0x9f6
+	 100, // 0x9f7
+	 100, // 0x9f8
+	 100, // 0x9f9
+	 100, // 0x9fa
+	 100, // 0x9fb
+	 100, // 0x9fc
+	 100, // 0x9fd
+	 100, // 0x9fe
+	 100, // 0x9ff
+	 100, // 0xa00
+	 100, // 0xa01
+	 100, // 0xa02
+	 100, // 0xa03
+	 100, // 0xa04
+	 100, // 0xa05
+	 100, // 0xa06
+	 100, // 0xa07
+	 100, // 0xa08
+	 100, // 0xa09
+	 100, // 0xa0a
+	 100, // 0xa0b
+	 100, // 0xa0c
+	 100, // 0xa0d
+	 100, // 0xa0e
+	 100, // 0xa0f
+	 100, // 0xa10
+	 100, // 0xa11
+	 100, // 0xa12
+	 100, // 0xa13
+	 100, // 0xa14
+	 100, // 0xa15
+	 100, // 0xa16
+	 100, // 0xa17
+	 100, // 0xa18
+	 100, // 0xa19
+	 100, // 0xa1a
+	 100, // 0xa1b
+	 100, // 0xa1c
+	 100, // 0xa1d
+	 100, // 0xa1e
+	 100, // 0xa1f
+	 100, // 0xa20
+	 100, // 0xa21
+	 100, // 0xa22
+	 100, // 0xa23
+	 100, // 0xa24
+	 100, // 0xa25
+	 100, // 0xa26
+	 100, // 0xa27
+	 100, // 0xa28
+	 100, // 0xa29
+	 100, // 0xa2a
+	 100, // 0xa2b
+	 100, // 0xa2c
+	 100, // 0xa2d
+	 100, // 0xa2e
+	 100, // 0xa2f
+	 100, // 0xa30
+	 100, // 0xa31
+	 100, // 0xa32
+	 100, // 0xa33
+	 100, // 0xa34
+	 100, // 0xa35
+	 100, // 0xa36
+	 100, // 0xa37
+	 100, // 0xa38
+	 100, // 0xa39
+	 100, // 0xa3a
+	 100, // 0xa3b
+	 100, // 0xa3c
+	 100, // 0xa3d
+	 100, // 0xa3e
+	 100, // 0xa3f
+	 100, // 0xa40
+	 100, // 0xa41
+	 100, // 0xa42
+	 100, // 0xa43
+	 100, // 0xa44
+	 100, // 0xa45
+	 100, // 0xa46
+	 100, // 0xa47
+	 100, // 0xa48
+	 100, // 0xa49
+	 100, // 0xa4a
+	 100, // 0xa4b
+	 100, // 0xa4c
+	 100, // 0xa4d
+	 100, // 0xa4e
+	 100, // 0xa4f
+	 100, // 0xa50
+	 100, // 0xa51
+	 100, // 0xa52
+	 100, // 0xa53
+	 100, // 0xa54
+	 100, // 0xa55
+	 100, // 0xa56
+	 100, // 0xa57
+	 100, // 0xa58
+	 100, // 0xa59
+	 100, // 0xa5a
+	 100, // 0xa5b
+	 100, // 0xa5c
+	 100, // 0xa5d
+	 100, // 0xa5e
+	 100, // 0xa5f
+	 100, // 0xa60
+	 100, // 0xa61
+	 100, // 0xa62
+	 100, // 0xa63
+	 100, // 0xa64
+	 100, // 0xa65
+	 100, // 0xa66
+	 100, // 0xa67
+	 100, // 0xa68
+	 100, // 0xa69
+	 100, // 0xa6a
+	 100, // 0xa6b
+	 100, // 0xa6c
+	 100, // 0xa6d
+	 100, // 0xa6e
+	 100, // 0xa6f
+	 100, // 0xa70
+	 100, // 0xa71
+	 100, // 0xa72
+	 100, // 0xa73
+	 100, // 0xa74
+	 100, // 0xa75
+	 100, // 0xa76
+	 100, // 0xa77
+	 100, // 0xa78
+	 100, // 0xa79
+	 100, // 0xa7a
+	 100, // 0xa7b
+	 100, // 0xa7c
+	 100, // 0xa7d
+	 100, // 0xa7e
+	 100, // 0xa7f
+	 100, // 0xa80
+	 100, // 0xa81
+	 100, // 0xa82
+	 100, // 0xa83
+	 100, // 0xa84
+	 100, // 0xa85
+	 100, // 0xa86
+	 100, // 0xa87
+	 100, // 0xa88
+	 100, // 0xa89
+	 100, // 0xa8a
+	 100, // 0xa8b
+	 100, // 0xa8c
+	 100, // 0xa8d
+	 100, // 0xa8e
+	 100, // 0xa8f
+	 100, // 0xa90
+	 100, // 0xa91
+	 100, // 0xa92
+	 100, // 0xa93
+	 100, // 0xa94
+	 100, // 0xa95
+	 100, // 0xa96
+	 100, // 0xa97
+	 100, // 0xa98
+	 100, // 0xa99
+	 100, // 0xa9a
+	 100, // 0xa9b
+	 100, // 0xa9c
+	 100, // 0xa9d
+	 100, // 0xa9e
+	 100, // 0xa9f
+	 100, // 0xaa0
+	 100, // 0xaa1
+	 100, // 0xaa2
+	 100, // 0xaa3
+	 100, // 0xaa4
+	 100, // 0xaa5
+	 100, // 0xaa6
+	 100, // 0xaa7
+	 100, // 0xaa8
+	 100, // 0xaa9
+	 100, // 0xaaa
+	 100, // 0xaab
+	 100, // 0xaac
+	 100, // 0xaad
+	 100, // 0xaae
+	 100, // 0xaaf
+	 100, // 0xab0
+	 100, // 0xab1
+	 100, // 0xab2
+	 100, // 0xab3
+	 100, // 0xab4
+	 100, // 0xab5
+	 100, // 0xab6
+	 100, // 0xab7
+	 100, // 0xab8
+	 100, // 0xab9
+	 100, // 0xaba
+	 100, // 0xabb
+	 100, // 0xabc
+	 100, // 0xabd
+	 100, // 0xabe
+	 100, // 0xabf
+	 100, // 0xac0
+	 100, // 0xac1
+	 100, // 0xac2
+	 100, // 0xac3
+	 100, // 0xac4
+	 100, // 0xac5
+	 100, // 0xac6
+	 100, // 0xac7
+	 100, // 0xac8
+	 100, // 0xac9
+	 100, // 0xaca
+	 100, // 0xacb
+	 100, // 0xacc
+	 100, // 0xacd
+	 100, // 0xace
+	 100, // 0xacf
+	 100, // 0xad0
+	 100, // 0xad1
+	 100, // 0xad2
+	 100, // 0xad3
+	 100, // 0xad4
+	 100, // 0xad5
+	 100, // 0xad6
+	 100, // 0xad7
+	 100, // 0xad8
+	 100, // 0xad9
+	 100, // 0xada
+	 100, // 0xadb
+	 100, // 0xadc
+	 100, // 0xadd
+	 100, // 0xade
+	 100, // 0xadf
+	 100, // 0xae0
+	 100, // 0xae1
+	 100, // 0xae2
+	 100, // 0xae3
+	 100, // 0xae4
+	 100, // 0xae5
+	 100, // 0xae6
+	 100, // 0xae7
+	 100, // 0xae8
+	 100, // 0xae9
+	 100, // 0xaea
+	 100, // 0xaeb
+	 100, // 0xaec
+	 100, // 0xaed
+	 100, // 0xaee
+	 100, // 0xaef
+	 100, // 0xaf0
+	 100, // 0xaf1
+	 100, // 0xaf2
+	 100, // 0xaf3
+	 100, // 0xaf4
+	 100, // 0xaf5
+	 100, // 0xaf6
+	 100, // 0xaf7
+	 100, // 0xaf8
+	 100, // 0xaf9
+	 100, // 0xafa
+	 100, // 0xafb
+	 100, // 0xafc
+	 100, // 0xafd
+	 100, // 0xafe
+	 100, // 0xaff
+	 100, // 0xb00
+	 100, // 0xb01
+	 100, // 0xb02
+	 100, // 0xb03
+	 100, // 0xb04
+	 100, // 0xb05
+	 100, // 0xb06
+	 100, // 0xb07
+	 100, // 0xb08
+	 100, // 0xb09
+	 100, // 0xb0a
+	 100, // 0xb0b
+	 100, // 0xb0c
+	 100, // 0xb0d
+	 100, // 0xb0e
+	 100, // 0xb0f
+	 100, // 0xb10
+	 100, // 0xb11
+	 100, // 0xb12
+	 100, // 0xb13
+	 100, // 0xb14
+	 100, // 0xb15
+	 100, // 0xb16
+	 100, // 0xb17
+	 100, // 0xb18
+	 100, // 0xb19
+	 100, // 0xb1a
+	 100, // 0xb1b
+	 100, // 0xb1c
+	 100, // 0xb1d
+	 100, // 0xb1e
+	 100, // 0xb1f
+	 100, // 0xb20
+	 100, // 0xb21
+	 100, // 0xb22
+	 100, // 0xb23
+	 100, // 0xb24
+	 100, // 0xb25
+	 100, // 0xb26
+	 100, // 0xb27
+	 100, // 0xb28
+	 100, // 0xb29
+	 100, // 0xb2a
+	 100, // 0xb2b
+	 100, // 0xb2c
+	 100, // 0xb2d
+	 100, // 0xb2e
+	 100, // 0xb2f
+	 100, // 0xb30
+	 100, // 0xb31
+	 100, // 0xb32
+	 100, // 0xb33
+	 100, // 0xb34
+	 100, // 0xb35
+	 100, // 0xb36
+	 100, // 0xb37
+	 100, // 0xb38
+	 100, // 0xb39
+	 100, // 0xb3a
+	 100, // 0xb3b
+	 100, // 0xb3c
+	 100, // 0xb3d
+	 100, // 0xb3e
+	 100, // 0xb3f
+	 100, // 0xb40
+	 100, // 0xb41
+	 100, // 0xb42
+	 100, // 0xb43
+	 100, // 0xb44
+	 100, // 0xb45
+	 100, // 0xb46
+	 100, // 0xb47
+	 100, // 0xb48
+	 100, // 0xb49
+	 100, // 0xb4a
+	 100, // 0xb4b
+	 100, // 0xb4c
+	 100, // 0xb4d
+	 100, // 0xb4e
+	 100, // 0xb4f
+	 100, // 0xb50
+	 100, // 0xb51
+	 100, // 0xb52
+	 100, // 0xb53
+	 100, // 0xb54
+	 100, // 0xb55
+	 100, // 0xb56
+	 100, // 0xb57
+	 100, // 0xb58
+	 100, // 0xb59
+	 100, // 0xb5a
+	 100, // 0xb5b
+	 100, // 0xb5c
+	 100, // 0xb5d
+	 100, // 0xb5e
+	 100, // 0xb5f
+	 100, // 0xb60
+	 100, // 0xb61
+	 100, // 0xb62
+	 100, // 0xb63
+	 100, // 0xb64
+	 100, // 0xb65
+	 100, // 0xb66
+	 100, // 0xb67
+	 100, // 0xb68
+	 100, // 0xb69
+	 100, // 0xb6a
+	 100, // 0xb6b
+	 100, // 0xb6c
+	 100, // 0xb6d
+	 100, // 0xb6e
+	 100, // 0xb6f
+	 100, // 0xb70
+	 100, // 0xb71
+	 100, // 0xb72
+	 100, // 0xb73
+	 100, // 0xb74
+	 100, // 0xb75
+	 100, // 0xb76
+	 100, // 0xb77
+	 100, // 0xb78
+	 100, // 0xb79
+	 100, // 0xb7a
+	 100, // 0xb7b
+	 100, // 0xb7c
+	 100, // 0xb7d
+	 100, // 0xb7e
+	 100, // 0xb7f
+	 100, // 0xb80
+	 100, // 0xb81
+	 100, // 0xb82
+	 100, // 0xb83
+	 100, // 0xb84
+	 100, // 0xb85
+	 100, // 0xb86
+	 100, // 0xb87
+	 100, // 0xb88
+	 100, // 0xb89
+	 100, // 0xb8a
+	 100, // 0xb8b
+	 100, // 0xb8c
+	 100, // 0xb8d
+	 100, // 0xb8e
+	 100, // 0xb8f
+	 100, // 0xb90
+	 100, // 0xb91
+	 100, // 0xb92
+	 100, // 0xb93
+	 100, // 0xb94
+	 100, // 0xb95
+	 100, // 0xb96
+	 100, // 0xb97
+	 100, // 0xb98
+	 100, // 0xb99
+	 100, // 0xb9a
+	 100, // 0xb9b
+	 100, // 0xb9c
+	 100, // 0xb9d
+	 100, // 0xb9e
+	 100, // 0xb9f
+	 100, // 0xba0
+	 100, // 0xba1
+	 100, // 0xba2
+	 100, // 0xba3
+	 100, // 0xba4
+	 100, // 0xba5
+	 100, // 0xba6
+	 100, // 0xba7
+	 100, // 0xba8
+	 100, // 0xba9
+	 100, // 0xbaa
+	 100, // 0xbab
+	 100, // 0xbac
+	 100, // 0xbad
+	 100, // 0xbae
+	 100, // 0xbaf
+	 100, // 0xbb0
+	 100, // 0xbb1
+	 100, // 0xbb2
+	 100, // 0xbb3
+	 100, // 0xbb4
+	 100, // 0xbb5
+	 100, // 0xbb6
+	 100, // 0xbb7
+	 100, // 0xbb8
+	 100, // 0xbb9
+	 100, // 0xbba
+	 100, // 0xbbb
+	 100, // 0xbbc
+	 100, // 0xbbd
+	 100, // 0xbbe
+	 100, // 0xbbf
+	 100, // 0xbc0
+	 100, // 0xbc1
+	 100, // 0xbc2
+	 100, // 0xbc3
+	 100, // 0xbc4
+	 100, // 0xbc5
+	 100, // 0xbc6
+	 100, // 0xbc7
+	 100, // 0xbc8
+	 100, // 0xbc9
+	 100, // 0xbca
+	 100, // 0xbcb
+	 100, // 0xbcc
+	 100, // 0xbcd
+	 100, // 0xbce
+	 100, // 0xbcf
+	 100, // 0xbd0
+	 100, // 0xbd1
+	 100, // 0xbd2
+	 100, // 0xbd3
+	 100, // 0xbd4
+	 100, // 0xbd5
+	 100, // 0xbd6
+	 100, // 0xbd7
+	 100, // 0xbd8
+	 100, // 0xbd9
+	 100, // 0xbda
+	 100, // 0xbdb
+	 100, // 0xbdc
+	 100, // 0xbdd
+	 100, // 0xbde
+	 100, // 0xbdf
+	 100, // 0xbe0
+	 100, // 0xbe1
+	 100, // 0xbe2
+	 100, // 0xbe3
+	 100, // 0xbe4
+	 100, // 0xbe5
+	 100, // 0xbe6
+	 100, // 0xbe7
+	 100, // 0xbe8
+	 100, // 0xbe9
+	 100, // 0xbea
+	 100, // 0xbeb
+	 100, // 0xbec
+	 100, // 0xbed
+	 100, // 0xbee
+	 100, // 0xbef
+	 100, // 0xbf0
+	 100, // 0xbf1
+	 100, // 0xbf2
+	 100, // 0xbf3
+	 100, // 0xbf4
+	 100, // 0xbf5
+	 100, // 0xbf6
+	 100, // 0xbf7
+	 100, // 0xbf8
+	 100, // 0xbf9
+	 100, // 0xbfa
+	 100, // 0xbfb
+	 100, // 0xbfc
+	 100, // 0xbfd
+	 100, // 0xbfe
+	 100, // 0xbff
+	 100, // 0xc00
+	 100, // 0xc01
+	 100, // 0xc02
+	 100, // 0xc03
+	 100, // 0xc04
+	 100, // 0xc05
+	 100, // 0xc06
+	 100, // 0xc07
+	 100, // 0xc08
+	 100, // 0xc09
+	 100, // 0xc0a
+	 100, // 0xc0b
+	 100, // 0xc0c
+	 100, // 0xc0d
+	 100, // 0xc0e
+	 100, // 0xc0f
+	 100, // 0xc10
+	 100, // 0xc11
+	 100, // 0xc12
+	 100, // 0xc13
+	 100, // 0xc14
+	 100, // 0xc15
+	 100, // 0xc16
+	 100, // 0xc17
+	 100, // 0xc18
+	 100, // 0xc19
+	 100, // 0xc1a
+	 100, // 0xc1b
+	 100, // 0xc1c
+	 100, // 0xc1d
+	 100, // 0xc1e
+	 100, // 0xc1f
+	 100, // 0xc20
+	 100, // 0xc21
+	 100, // 0xc22
+	 100, // 0xc23
+	 100, // 0xc24
+	 100, // 0xc25
+	 100, // 0xc26
+	 100, // 0xc27
+	 100, // 0xc28
+	 100, // 0xc29
+	 100, // 0xc2a
+	 100, // 0xc2b
+	 100, // 0xc2c
+	 100, // 0xc2d
+	 100, // 0xc2e
+	 100, // 0xc2f
+	 100, // 0xc30
+	 100, // 0xc31
+	 100, // 0xc32
+	 100, // 0xc33
+	 100, // 0xc34
+	 100, // 0xc35
+	 100, // 0xc36
+	 100, // 0xc37
+	 100, // 0xc38
+	 100, // 0xc39
+	 100, // 0xc3a
+	 100, // 0xc3b
+	 100, // 0xc3c
+	 100, // 0xc3d
+	 100, // 0xc3e
+	 100, // 0xc3f
+	 100, // 0xc40
+	 100, // 0xc41
+	 100, // 0xc42
+	 100, // 0xc43
+	 100, // 0xc44
+	 100, // 0xc45
+	 100, // 0xc46
+	 100, // 0xc47
+	 100, // 0xc48
+	 100, // 0xc49
+	 100, // 0xc4a
+	 100, // 0xc4b
+	 100, // 0xc4c
+	 100, // 0xc4d
+	 100, // 0xc4e
+	 100, // 0xc4f
+	 100, // 0xc50
+	 100, // 0xc51
+	 100, // 0xc52
+	 100, // 0xc53
+	 100, // 0xc54
+	 100, // 0xc55
+	 100, // 0xc56
+	 100, // 0xc57
+	 100, // 0xc58
+	 100, // 0xc59
+	 100, // 0xc5a
+	 100, // 0xc5b
+	 100, // 0xc5c
+	 100, // 0xc5d
+	 100, // 0xc5e
+	 100, // 0xc5f
+	 100, // 0xc60
+	 100, // 0xc61
+	 100, // 0xc62
+	 100, // 0xc63
+	 100, // 0xc64
+	 100, // 0xc65
+	 100, // 0xc66
+	 100, // 0xc67
+	 100, // 0xc68
+	 100, // 0xc69
+	 100, // 0xc6a
+	 100, // 0xc6b
+	 100, // 0xc6c
+	 100, // 0xc6d
+	 100, // 0xc6e
+	 100, // 0xc6f
+	 100, // 0xc70
+	 100, // 0xc71
+	 100, // 0xc72
+	 100, // 0xc73
+	 100, // 0xc74
+	 100, // 0xc75
+	 100, // 0xc76
+	 100, // 0xc77
+	 100, // 0xc78
+	 100, // 0xc79
+	 100, // 0xc7a
+	 100, // 0xc7b
+	 100, // 0xc7c
+	 100, // 0xc7d
+	 100, // 0xc7e
+	 100, // 0xc7f
+	 100, // 0xc80
+	 100, // 0xc81
+	 100, // 0xc82
+	 100, // 0xc83
+	 100, // 0xc84
+	 100, // 0xc85
+	 100, // 0xc86
+	 100, // 0xc87
+	 100, // 0xc88
+	 100, // 0xc89
+	 100, // 0xc8a
+	 100, // 0xc8b
+	 100, // 0xc8c
+	 100, // 0xc8d
+	 100, // 0xc8e
+	 100, // 0xc8f
+	 100, // 0xc90
+	 100, // 0xc91
+	 100, // 0xc92
+	 100, // 0xc93
+	 100, // 0xc94
+	 100, // 0xc95
+	 100, // 0xc96
+	 100, // 0xc97
+	 100, // 0xc98
+	 100, // 0xc99
+	 100, // 0xc9a
+	 100, // 0xc9b
+	 100, // 0xc9c
+	 100, // 0xc9d
+	 100, // 0xc9e
+	 100, // 0xc9f
+	 100, // 0xca0
+	 100, // 0xca1
+	 100, // 0xca2
+	 100, // 0xca3
+	 100, // 0xca4
+	 100, // 0xca5
+	 100, // 0xca6
+	 100, // 0xca7
+	 100, // 0xca8
+	 100, // 0xca9
+	 100, // 0xcaa
+	 100, // 0xcab
+	 100, // 0xcac
+	 100, // 0xcad
+	 100, // 0xcae
+	 100, // 0xcaf
+	 100, // 0xcb0
+	 100, // 0xcb1
+	 100, // 0xcb2
+	 100, // 0xcb3
+	 100, // 0xcb4
+	 100, // 0xcb5
+	 100, // 0xcb6
+	 100, // 0xcb7
+	 100, // 0xcb8
+	 100, // 0xcb9
+	 100, // 0xcba
+	 100, // 0xcbb
+	 100, // 0xcbc
+	 100, // 0xcbd
+	 100, // 0xcbe
+	 100, // 0xcbf
+	 100, // 0xcc0
+	 100, // 0xcc1
+	 100, // 0xcc2
+	 100, // 0xcc3
+	 100, // 0xcc4
+	 100, // 0xcc5
+	 100, // 0xcc6
+	 100, // 0xcc7
+	 100, // 0xcc8
+	 100, // 0xcc9
+	 100, // 0xcca
+	 100, // 0xccb
+	 100, // 0xccc
+	 100, // 0xccd
+	 100, // 0xcce
+	 100, // 0xccf
+	 100, // 0xcd0
+	 100, // 0xcd1
+	 100, // 0xcd2
+	 100, // 0xcd3
+	 100, // 0xcd4
+	 100, // 0xcd5
+	 100, // 0xcd6
+	 100, // 0xcd7
+	 100, // 0xcd8
+	 100, // 0xcd9
+	 100, // 0xcda
+	 100, // 0xcdb
+	 100, // 0xcdc
+	 100, // 0xcdd
+	 100, // 0xcde
+	 100, // 0xcdf
+	 100, // 0xce0
+	 100, // 0xce1
+	 100, // 0xce2
+	 100, // 0xce3
+	 100, // 0xce4
+	 100, // 0xce5
+	 100, // 0xce6
+	 100, // 0xce7
+	 100, // 0xce8
+	 100, // 0xce9
+	 100, // 0xcea
+	 100, // 0xceb
+	 100, // 0xcec
+	 100, // 0xced
+	 100, // 0xcee
+	 100, // 0xcef
+	 100, // 0xcf0
+	 100, // 0xcf1
+	 100, // 0xcf2
+	 100, // 0xcf3
+	 100, // 0xcf4
+	 100, // 0xcf5
+	 100, // 0xcf6
+	 100, // 0xcf7
+	 100, // 0xcf8
+	 100, // 0xcf9
+	 100, // 0xcfa
+	 100, // 0xcfb
+	 100, // 0xcfc
+	 100, // 0xcfd
+	 100, // 0xcfe
+	 100, // 0xcff
+	 100, // 0xd00
+	 100, // 0xd01
+	 100, // 0xd02
+	 100, // 0xd03
+	 100, // 0xd04
+	 100, // 0xd05
+	 100, // 0xd06
+	 100, // 0xd07
+	 100, // 0xd08
+	 100, // 0xd09
+	 100, // 0xd0a
+	 100, // 0xd0b
+	 100, // 0xd0c
+	 100, // 0xd0d
+	 100, // 0xd0e
+	 100, // 0xd0f
+	 100, // 0xd10
+	 100, // 0xd11
+	 100, // 0xd12
+	 100, // 0xd13
+	 100, // 0xd14
+	 100, // 0xd15
+	 100, // 0xd16
+	 100, // 0xd17
+	 100, // 0xd18
+	 100, // 0xd19
+	 100, // 0xd1a
+	 100, // 0xd1b
+	 100, // 0xd1c
+	 100, // 0xd1d
+	 100, // 0xd1e
+	 100, // 0xd1f
+	 100, // 0xd20
+	 100, // 0xd21
+	 100, // 0xd22
+	 100, // 0xd23
+	 100, // 0xd24
+	 100, // 0xd25
+	 100, // 0xd26
+	 100, // 0xd27
+	 100, // 0xd28
+	 100, // 0xd29
+	 100, // 0xd2a
+	 100, // 0xd2b
+	 100, // 0xd2c
+	 100, // 0xd2d
+	 100, // 0xd2e
+	 100, // 0xd2f
+	 100, // 0xd30
+	 100, // 0xd31
+	 100, // 0xd32
+	 100, // 0xd33
+	 100, // 0xd34
+	 100, // 0xd35
+	 100, // 0xd36
+	 100, // 0xd37
+	 100, // 0xd38
+	 100, // 0xd39
+	 100, // 0xd3a
+	 100, // 0xd3b
+	 100, // 0xd3c
+	 100, // 0xd3d
+	 100, // 0xd3e
+	 100, // 0xd3f
+	 100, // 0xd40
+	 100, // 0xd41
+	 100, // 0xd42
+	 100, // 0xd43
+	 100, // 0xd44
+	 100, // 0xd45
+	 100, // 0xd46
+	 100, // 0xd47
+	 100, // 0xd48
+	 100, // 0xd49
+	 100, // 0xd4a
+	 100, // 0xd4b
+	 100, // 0xd4c
+	 100, // 0xd4d
+	 100, // 0xd4e
+	 100, // 0xd4f
+	 100, // 0xd50
+	 100, // 0xd51
+	 100, // 0xd52
+	 100, // 0xd53
+	 100, // 0xd54
+	 100, // 0xd55
+	 100, // 0xd56
+	 100, // 0xd57
+	 100, // 0xd58
+	 100, // 0xd59
+	 100, // 0xd5a
+	 100, // 0xd5b
+	 100, // 0xd5c
+	 100, // 0xd5d
+	 100, // 0xd5e
+	 100, // 0xd5f
+	 100, // 0xd60
+	 100, // 0xd61
+	 100, // 0xd62
+	 100, // 0xd63
+	 100, // 0xd64
+	 100, // 0xd65
+	 100, // 0xd66
+	 100, // 0xd67
+	 100, // 0xd68
+	 100, // 0xd69
+	 100, // 0xd6a
+	 100, // 0xd6b
+	 100, // 0xd6c
+	 100, // 0xd6d
+	 100, // 0xd6e
+	 100, // 0xd6f
+	 100, // 0xd70
+	 100, // 0xd71
+	 100, // 0xd72
+	 100, // 0xd73
+	 100, // 0xd74
+	 100, // 0xd75
+	 100, // 0xd76
+	 100, // 0xd77
+	 100, // 0xd78
+	 100, // 0xd79
+	 100, // 0xd7a
+	 100, // 0xd7b
+	 100, // 0xd7c
+	 100, // 0xd7d
+	 100, // 0xd7e
+	 100, // 0xd7f
+	 100, // 0xd80
+	 100, // 0xd81
+	 100, // 0xd82
+	 100, // 0xd83
+	 100, // 0xd84
+	 100, // 0xd85
+	 100, // 0xd86
+	 100, // 0xd87
+	 100, // 0xd88
+	 100, // 0xd89
+	 100, // 0xd8a
+	 100, // 0xd8b
+	 100, // 0xd8c
+	 100, // 0xd8d
+	 100, // 0xd8e
+	 100, // 0xd8f
+	 100, // 0xd90
+	 100, // 0xd91
+	 100, // 0xd92
+	 100, // 0xd93
+	 100, // 0xd94
+	 100, // 0xd95
+	 100, // 0xd96
+	 100, // 0xd97
+	 100, // 0xd98
+	 100, // 0xd99
+	 100, // 0xd9a
+	 100, // 0xd9b
+	 100, // 0xd9c
+	 100, // 0xd9d
+	 100, // 0xd9e
+	 100, // 0xd9f
+	 100, // 0xda0
+	 100, // 0xda1
+	 100, // 0xda2
+	 100, // 0xda3
+	 100, // 0xda4
+	 100, // 0xda5
+	 100, // 0xda6
+	 100, // 0xda7
+	 100, // 0xda8
+	 100, // 0xda9
+	 100, // 0xdaa
+	 100, // 0xdab
+	 100, // 0xdac
+	 100, // 0xdad
+	 100, // 0xdae
+	 100, // 0xdaf
+	 100, // 0xdb0
+	 100, // 0xdb1
+	 100, // 0xdb2
+	 100, // 0xdb3
+	 100, // 0xdb4
+	 100, // 0xdb5
+	 100, // 0xdb6
+	 100, // 0xdb7
+	 100, // 0xdb8
+	 100, // 0xdb9
+	 100, // 0xdba
+	 100, // 0xdbb
+	 100, // 0xdbc
+	 100, // 0xdbd
+	 100, // 0xdbe
+	 100, // 0xdbf
+	 100, // 0xdc0
+	 100, // 0xdc1
+	 100, // 0xdc2
+	 100, // 0xdc3
+	 100, // 0xdc4
+	 100, // 0xdc5
+	 100, // 0xdc6
+	 100, // 0xdc7
+	 100, // 0xdc8
+	 100, // 0xdc9
+	 100, // 0xdca
+	 100, // 0xdcb
+	 100, // 0xdcc
+	 100, // 0xdcd
+	 100, // 0xdce
+	 100, // 0xdcf
+	 100, // 0xdd0
+	 100, // 0xdd1
+	 100, // 0xdd2
+	 100, // 0xdd3
+	 100, // 0xdd4
+	 100, // 0xdd5
+	 100, // 0xdd6
+	 100, // 0xdd7
+	 100, // 0xdd8
+	 100, // 0xdd9
+	 100, // 0xdda
+	 100, // 0xddb
+	 100, // 0xddc
+	 100, // 0xddd
+	 100, // 0xdde
+	 100, // 0xddf
+	 100, // 0xde0
+	 100, // 0xde1
+	 100, // 0xde2
+	 100, // 0xde3
+	 100, // 0xde4
+	 100, // 0xde5
+	 100, // 0xde6
+	 100, // 0xde7
+	 100, // 0xde8
+	 100, // 0xde9
+	 100, // 0xdea
+	 100, // 0xdeb
+	 100, // 0xdec
+	 100, // 0xded
+	 100, // 0xdee
+	 100, // 0xdef
+	 100, // 0xdf0
+	 100, // 0xdf1
+	 100, // 0xdf2
+	 100, // 0xdf3
+	 100, // 0xdf4
+	 100, // 0xdf5
+	 100, // 0xdf6
+	 100, // 0xdf7
+	 100, // 0xdf8
+	 100, // 0xdf9
+	 100, // 0xdfa
+	 100, // 0xdfb
+	 100, // 0xdfc
+	 100, // 0xdfd
+	 100, // 0xdfe
+	 100, // 0xdff
+	 153, // 0xe00
+	 154, // 0xe01
+	 100, // 0xe02
+	 100, // 0xe03
+	 100, // 0xe04
+	 100, // 0xe05
+	 100, // 0xe06
+	 100, // 0xe07
+	 100, // 0xe08
+	 100, // 0xe09
+	 100, // 0xe0a
+	 100, // 0xe0b
+	 100, // 0xe0c
+	 100, // 0xe0d
+	 100, // 0xe0e
+	 100, // 0xe0f
+	 100, // 0xe10
+	 100, // 0xe11
+	 100, // 0xe12
+	 100, // 0xe13
+	 100, // 0xe14
+	 100, // 0xe15
+	 100, // 0xe16
+	 100, // 0xe17
+	 100, // 0xe18
+	 100, // 0xe19
+	 100, // 0xe1a
+	 100, // 0xe1b
+	 100, // 0xe1c
+	 100, // 0xe1d
+	 100, // 0xe1e
+	 100, // 0xe1f
+	 100, // 0xe20
+	 100, // 0xe21
+	 100, // 0xe22
+	 100, // 0xe23
+	 100, // 0xe24
+	 100, // 0xe25
+	 100, // 0xe26
+	 100, // 0xe27
+	 100, // 0xe28
+	 100, // 0xe29
+	 100, // 0xe2a
+	 100, // 0xe2b
+	 100, // 0xe2c
+	 100, // 0xe2d
+	 100, // 0xe2e
+	 100, // 0xe2f
+	 100, // 0xe30
+	 100, // 0xe31
+	 100, // 0xe32
+	 100, // 0xe33
+	 100, // 0xe34
+	 100, // 0xe35
+	 100, // 0xe36
+	 100, // 0xe37
+	 100, // 0xe38
+	 100, // 0xe39
+	 100, // 0xe3a
+	 100, // 0xe3b
+	 100, // 0xe3c
+	 100, // 0xe3d
+	 100, // 0xe3e
+	 100, // 0xe3f
+	 100, // 0xe40
+	 100, // 0xe41
+	 100, // 0xe42
+	 100, // 0xe43
+	 100, // 0xe44
+	 100, // 0xe45
+	 100, // 0xe46
+	 100, // 0xe47
+	 100, // 0xe48
+	 100, // 0xe49
+	 100, // 0xe4a
+	 100, // 0xe4b
+	 100, // 0xe4c
+	 100, // 0xe4d
+	 100, // 0xe4e
+	 100, // 0xe4f
+	 100, // 0xe50
+	 100, // 0xe51
+	 100, // 0xe52
+	 100, // 0xe53
+	 100, // 0xe54
+	 100, // 0xe55
+	 100, // 0xe56
+	 100, // 0xe57
+	 100, // 0xe58
+	 100, // 0xe59
+	 100, // 0xe5a
+	 100, // 0xe5b
+	 100, // 0xe5c
+	 100, // 0xe5d
+	 100, // 0xe5e
+	 100, // 0xe5f
+	 100, // 0xe60
+	 100, // 0xe61
+	 100, // 0xe62
+	 100, // 0xe63
+	 100, // 0xe64
+	 100, // 0xe65
+	 100, // 0xe66
+	 100, // 0xe67
+	 100, // 0xe68
+	 100, // 0xe69
+	 100, // 0xe6a
+	 100, // 0xe6b
+	 100, // 0xe6c
+	 100, // 0xe6d
+	 100, // 0xe6e
+	 100, // 0xe6f
+	 100, // 0xe70
+	 100, // 0xe71
+	 100, // 0xe72
+	 100, // 0xe73
+	 100, // 0xe74
+	 100, // 0xe75
+	 100, // 0xe76
+	 100, // 0xe77
+	 100, // 0xe78
+	 100, // 0xe79
+	 100, // 0xe7a
+	 100, // 0xe7b
+	 100, // 0xe7c
+	 100, // 0xe7d
+	 100, // 0xe7e
+	 100, // 0xe7f
+	 100, // 0xe80
+	 100, // 0xe81
+	 100, // 0xe82
+	 100, // 0xe83
+	 100, // 0xe84
+	 100, // 0xe85
+	 100, // 0xe86
+	 100, // 0xe87
+	 100, // 0xe88
+	 100, // 0xe89
+	 100, // 0xe8a
+	 100, // 0xe8b
+	 100, // 0xe8c
+	 100, // 0xe8d
+	 100, // 0xe8e
+	 100, // 0xe8f
+	 100, // 0xe90
+	 100, // 0xe91
+	 100, // 0xe92
+	 100, // 0xe93
+	 100, // 0xe94
+	 100, // 0xe95
+	 100, // 0xe96
+	 100, // 0xe97
+	 100, // 0xe98
+	 100, // 0xe99
+	 100, // 0xe9a
+	 100, // 0xe9b
+	 100, // 0xe9c
+	 100, // 0xe9d
+	 100, // 0xe9e
+	 100, // 0xe9f
+	 100, // 0xea0
+	 100, // 0xea1
+	 100, // 0xea2
+	 100, // 0xea3
+	 100, // 0xea4
+	 100, // 0xea5
+	 100, // 0xea6
+	 100, // 0xea7
+	 100, // 0xea8
+	 100, // 0xea9
+	 100, // 0xeaa
+	 100, // 0xeab
+	 100, // 0xeac
+	 100, // 0xead
+	 100, // 0xeae
+	 100, // 0xeaf
+	 100, // 0xeb0
+	 100, // 0xeb1
+	 100, // 0xeb2
+	 100, // 0xeb3
+	 100, // 0xeb4
+	 100, // 0xeb5
+	 100, // 0xeb6
+	 100, // 0xeb7
+	 100, // 0xeb8
+	 100, // 0xeb9
+	 100, // 0xeba
+	 100, // 0xebb
+	 100, // 0xebc
+	 100, // 0xebd
+	 100, // 0xebe
+	 100, // 0xebf
+	 100, // 0xec0
+	 100, // 0xec1
+	 100, // 0xec2
+	 100, // 0xec3
+	 100, // 0xec4
+	 100, // 0xec5
+	 100, // 0xec6
+	 100, // 0xec7
+	 100, // 0xec8
+	 100, // 0xec9
+	 100, // 0xeca
+	 100, // 0xecb
+	 100, // 0xecc
+	 100, // 0xecd
+	 100, // 0xece
+	 100, // 0xecf
+	 100, // 0xed0
+	 100, // 0xed1
+	 100, // 0xed2
+	 100, // 0xed3
+	 100, // 0xed4
+	 100, // 0xed5
+	 100, // 0xed6
+	 100, // 0xed7
+	 100, // 0xed8
+	 100, // 0xed9
+	 100, // 0xeda
+	 100, // 0xedb
+	 100, // 0xedc
+	 100, // 0xedd
+	 100, // 0xede
+	 100, // 0xedf
+	 100, // 0xee0
+	 100, // 0xee1
+	 100, // 0xee2
+	 100, // 0xee3
+	 100, // 0xee4
+	 100, // 0xee5
+	 100, // 0xee6
+	 100, // 0xee7
+	 100, // 0xee8
+	 100, // 0xee9
+	 100, // 0xeea
+	 100, // 0xeeb
+	 100, // 0xeec
+	 100, // 0xeed
+	 100, // 0xeee
+	 100, // 0xeef
+	 100, // 0xef0
+	 100, // 0xef1
+	 100, // 0xef2
+	 100, // 0xef3
+	 100, // 0xef4
+	 100, // 0xef5
+	 100, // 0xef6
+	 100, // 0xef7
+	 100, // 0xef8
+	 100, // 0xef9
+	 100, // 0xefa
+	 100, // 0xefb
+	 100, // 0xefc
+	 100, // 0xefd
+	 100, // 0xefe
+	 100, // 0xeff
+	 60, // 0xf00
+	 60, // 0xf01
+	 60, // 0xf02
+	 60, // 0xf03
+	 60, // 0xf04
+	 60, // 0xf05
+	 60, // 0xf06
+	 60, // 0xf07
+	 60, // 0xf08
+	 60, // 0xf09
+	 60, // 0xf0a
+	 60, // 0xf0b
+	 60, // 0xf0c
+	 60, // 0xf0d
+	 60, // 0xf0e
+	 60, // 0xf0f
+	 60, // 0xf10
+	 60, // 0xf11
+	 60, // 0xf12
+	 60, // 0xf13
+	 60, // 0xf14
+	 60, // 0xf15
+	 60, // 0xf16
+	 60, // 0xf17
+	 60, // 0xf18
+	 60, // 0xf19
+	 60, // 0xf1a
+	 60, // 0xf1b
+	 60, // 0xf1c
+	 60, // 0xf1d
+	 60, // 0xf1e
+	 60, // 0xf1f
+	 60, // 0xf20
+	 60, // 0xf21
+	 60, // 0xf22
+	 60, // 0xf23
+	 60, // 0xf24
+	 60, // 0xf25
+	 60, // 0xf26
+	 60, // 0xf27
+	 60, // 0xf28
+	 60, // 0xf29
+	 60, // 0xf2a
+	 60, // 0xf2b
+	 60, // 0xf2c
+	 60, // 0xf2d
+	 60, // 0xf2e
+	 60, // 0xf2f
+	 60, // 0xf30
+	 60, // 0xf31
+	 60, // 0xf32
+	 60, // 0xf33
+	 60, // 0xf34
+	 60, // 0xf35
+	 60, // 0xf36
+	 60, // 0xf37
+	 60, // 0xf38
+	 60, // 0xf39
+	 60, // 0xf3a
+	 60, // 0xf3b
+	 60, // 0xf3c
+	 60, // 0xf3d
+	 60, // 0xf3e
+	 60, // 0xf3f
+	 60, // 0xf40
+	 60, // 0xf41
+	 60, // 0xf42
+	 60, // 0xf43
+	 60, // 0xf44
+	 60, // 0xf45
+	 60, // 0xf46
+	 60, // 0xf47
+	 60, // 0xf48
+	 60, // 0xf49
+	 60, // 0xf4a
+	 60, // 0xf4b
+	 60, // 0xf4c
+	 60, // 0xf4d
+	 60, // 0xf4e
+	 60, // 0xf4f
+	 60, // 0xf50
+	 60, // 0xf51
+	 60, // 0xf52
+	 60, // 0xf53
+	 60, // 0xf54
+	 60, // 0xf55
+	 60, // 0xf56
+	 60, // 0xf57
+	 60, // 0xf58
+	 60, // 0xf59
+	 60, // 0xf5a
+	 60, // 0xf5b
+	 60, // 0xf5c
+	 60, // 0xf5d
+	 60, // 0xf5e
+	 60, // 0xf5f
+	 60, // 0xf60
+	 60, // 0xf61
+	 60, // 0xf62
+	 60, // 0xf63
+	 60, // 0xf64
+	 60, // 0xf65
+	 60, // 0xf66
+	 60, // 0xf67
+	 60, // 0xf68
+	 60, // 0xf69
+	 60, // 0xf6a
+	 60, // 0xf6b
+	 60, // 0xf6c
+	 60, // 0xf6d
+	 60, // 0xf6e
+	 60, // 0xf6f
+	 60, // 0xf70
+	 60, // 0xf71
+	 60, // 0xf72
+	 60, // 0xf73
+	 60, // 0xf74
+	 60, // 0xf75
+	 60, // 0xf76
+	 60, // 0xf77
+	 60, // 0xf78
+	 60, // 0xf79
+	 60, // 0xf7a
+	 60, // 0xf7b
+	 60, // 0xf7c
+	 60, // 0xf7d
+	 60, // 0xf7e
+	 60, // 0xf7f
+	 60, // 0xf80
+	 60, // 0xf81
+	 60, // 0xf82
+	 60, // 0xf83
+	 60, // 0xf84
+	 60, // 0xf85
+	 60, // 0xf86
+	 60, // 0xf87
+	 60, // 0xf88
+	 60, // 0xf89
+	 60, // 0xf8a
+	 60, // 0xf8b
+	 60, // 0xf8c
+	 60, // 0xf8d
+	 60, // 0xf8e
+	 60, // 0xf8f
+	 60, // 0xf90
+	 60, // 0xf91
+	 60, // 0xf92
+	 60, // 0xf93
+	 60, // 0xf94
+	 60, // 0xf95
+	 60, // 0xf96
+	 60, // 0xf97
+	 60, // 0xf98
+	 60, // 0xf99
+	 60, // 0xf9a
+	 60, // 0xf9b
+	 60, // 0xf9c
+	 60, // 0xf9d
+	 60, // 0xf9e
+	 60, // 0xf9f
+	 60, // 0xfa0
+	 60, // 0xfa1
+	 60, // 0xfa2
+	 60, // 0xfa3
+	 60, // 0xfa4
+	 60, // 0xfa5
+	 60, // 0xfa6
+	 60, // 0xfa7
+	 60, // 0xfa8
+	 60, // 0xfa9
+	 60, // 0xfaa
+	 60, // 0xfab
+	 60, // 0xfac
+	 60, // 0xfad
+	 60, // 0xfae
+	 60, // 0xfaf
+	 60, // 0xfb0
+	 60, // 0xfb1
+	 60, // 0xfb2
+	 60, // 0xfb3
+	 60, // 0xfb4
+	 60, // 0xfb5
+	 60, // 0xfb6
+	 60, // 0xfb7
+	 60, // 0xfb8
+	 60, // 0xfb9
+	 60, // 0xfba
+	 60, // 0xfbb
+	 60, // 0xfbc
+	 60, // 0xfbd
+	 60, // 0xfbe
+	 60, // 0xfbf
+	 60, // 0xfc0
+	 60, // 0xfc1
+	 60, // 0xfc2
+	 60, // 0xfc3
+	 60, // 0xfc4
+	 60, // 0xfc5
+	 60, // 0xfc6
+	 60, // 0xfc7
+	 60, // 0xfc8
+	 60, // 0xfc9
+	 60, // 0xfca
+	 60, // 0xfcb
+	 60, // 0xfcc
+	 60, // 0xfcd
+	 60, // 0xfce
+	 60, // 0xfcf
+	 60, // 0xfd0
+	 60, // 0xfd1
+	 60, // 0xfd2
+	 60, // 0xfd3
+	 60, // 0xfd4
+	 60, // 0xfd5
+	 60, // 0xfd6
+	 60, // 0xfd7
+	 60, // 0xfd8
+	 60, // 0xfd9
+	 60, // 0xfda
+	 60, // 0xfdb
+	 60, // 0xfdc
+	 60, // 0xfdd
+	 60, // 0xfde
+	 60, // 0xfdf
+	 60, // 0xfe0
+	 60, // 0xfe1
+	 60, // 0xfe2
+	 60, // 0xfe3
+	 60, // 0xfe4
+	 60, // 0xfe5
+	 60, // 0xfe6
+	 60, // 0xfe7
+	 60, // 0xfe8
+	 60, // 0xfe9
+	 60, // 0xfea
+	 60, // 0xfeb
+	 60, // 0xfec
+	 60, // 0xfed
+	 60, // 0xfee
+	 60, // 0xfef
+	 60, // 0xff0
+	 60, // 0xff1
+	 60, // 0xff2
+	 60, // 0xff3
+	 60, // 0xff4
+	 60, // 0xff5
+	 60, // 0xff6
+	 60, // 0xff7
+	 60, // 0xff8
+	 60, // 0xff9
+	 60, // 0xffa
+	 60, // 0xffb
+	 60, // 0xffc
+	 60, // 0xffd
+	 60, // 0xffe
+	 155, // 0xfff
+	 60, // 0x1000
+	 60, // 0x1001
+	 60, // 0x1002
+	 60, // 0x1003
+	 60, // 0x1004
+	 60, // 0x1005
+	 60, // 0x1006
+	 60, // 0x1007
+	 60, // 0x1008
+	 60, // 0x1009
+	 60, // 0x100a
+	 60, // 0x100b
+	 60, // 0x100c
+	 60, // 0x100d
+	 60, // 0x100e
+	 60, // 0x100f
+	 60, // 0x1010
+	 60, // 0x1011
+	 60, // 0x1012
+	 60, // 0x1013
+	 60, // 0x1014
+	 60, // 0x1015
+	 60, // 0x1016
+	 60, // 0x1017
+	 60, // 0x1018
+	 60, // 0x1019
+	 60, // 0x101a
+	 60, // 0x101b
+	 60, // 0x101c
+	 60, // 0x101d
+	 60, // 0x101e
+	 60, // 0x101f
+	 60, // 0x1020
+	 60, // 0x1021
+	 60, // 0x1022
+	 60, // 0x1023
+	 60, // 0x1024
+	 60, // 0x1025
+	 60, // 0x1026
+	 60, // 0x1027
+	 60, // 0x1028
+	 60, // 0x1029
+	 60, // 0x102a
+	 60, // 0x102b
+	 60, // 0x102c
+	 60, // 0x102d
+	 60, // 0x102e
+	 60, // 0x102f
+	 60, // 0x1030
+	 60, // 0x1031
+	 60, // 0x1032
+	 60, // 0x1033
+	 60, // 0x1034
+	 60, // 0x1035
+	 60, // 0x1036
+	 60, // 0x1037
+	 60, // 0x1038
+	 60, // 0x1039
+	 60, // 0x103a
+	 60, // 0x103b
+	 60, // 0x103c
+	 60, // 0x103d
+	 60, // 0x103e
+	 60, // 0x103f
+	 60, // 0x1040
+	 60, // 0x1041
+	 60, // 0x1042
+	 60, // 0x1043
+	 60, // 0x1044
+	 60, // 0x1045
+	 60, // 0x1046
+	 60, // 0x1047
+	 60, // 0x1048
+	 60, // 0x1049
+	 60, // 0x104a
+	 60, // 0x104b
+	 60, // 0x104c
+	 60, // 0x104d
+	 60, // 0x104e
+	 60, // 0x104f
+	 60, // 0x1050
+	 60, // 0x1051
+	 60, // 0x1052
+	 60, // 0x1053
+	 60, // 0x1054
+	 60, // 0x1055
+	 60, // 0x1056
+	 60, // 0x1057
+	 60, // 0x1058
+	 60, // 0x1059
+	 60, // 0x105a
+	 60, // 0x105b
+	 60, // 0x105c
+	 60, // 0x105d
+	 60, // 0x105e
+	 60, // 0x105f
+	 60, // 0x1060
+	 60, // 0x1061
+	 60, // 0x1062
+	 60, // 0x1063
+	 60, // 0x1064
+	 60, // 0x1065
+	 60, // 0x1066
+	 60, // 0x1067
+	 60, // 0x1068
+	 60, // 0x1069
+	 60, // 0x106a
+	 60, // 0x106b
+	 60, // 0x106c
+	 60, // 0x106d
+	 60, // 0x106e
+	 60, // 0x106f
+	 60, // 0x1070
+	 60, // 0x1071
+	 60, // 0x1072
+	 60, // 0x1073
+	 60, // 0x1074
+	 60, // 0x1075
+	 60, // 0x1076
+	 60, // 0x1077
+	 60, // 0x1078
+	 60, // 0x1079
+	 60, // 0x107a
+	 60, // 0x107b
+	 60, // 0x107c
+	 60, // 0x107d
+	 60, // 0x107e
+	 60, // 0x107f
+	 60, // 0x1080
+	 60, // 0x1081
+	 60, // 0x1082
+	 60, // 0x1083
+	 60, // 0x1084
+	 60, // 0x1085
+	 60, // 0x1086
+	 60, // 0x1087
+	 60, // 0x1088
+	 60, // 0x1089
+	 60, // 0x108a
+	 60, // 0x108b
+	 60, // 0x108c
+	 60, // 0x108d
+	 60, // 0x108e
+	 60, // 0x108f
+	 60, // 0x1090
+	 60, // 0x1091
+	 60, // 0x1092
+	 60, // 0x1093
+	 60, // 0x1094
+	 60, // 0x1095
+	 60, // 0x1096
+	 60, // 0x1097
+	 60, // 0x1098
+	 60, // 0x1099
+	 60, // 0x109a
+	 60, // 0x109b
+	 60, // 0x109c
+	 60, // 0x109d
+	 60, // 0x109e
+	 60, // 0x109f
+	 60, // 0x10a0
+	 60, // 0x10a1
+	 60, // 0x10a2
+	 60, // 0x10a3
+	 60, // 0x10a4
+	 60, // 0x10a5
+	 60, // 0x10a6
+	 60, // 0x10a7
+	 60, // 0x10a8
+	 60, // 0x10a9
+	 60, // 0x10aa
+	 60, // 0x10ab
+	 60, // 0x10ac
+	 60, // 0x10ad
+	 60, // 0x10ae
+	 60, // 0x10af
+	 60, // 0x10b0
+	 60, // 0x10b1
+	 60, // 0x10b2
+	 60, // 0x10b3
+	 60, // 0x10b4
+	 60, // 0x10b5
+	 60, // 0x10b6
+	 60, // 0x10b7
+	 60, // 0x10b8
+	 60, // 0x10b9
+	 60, // 0x10ba
+	 60, // 0x10bb
+	 60, // 0x10bc
+	 60, // 0x10bd
+	 60, // 0x10be
+	 60, // 0x10bf
+	 60, // 0x10c0
+	 60, // 0x10c1
+	 60, // 0x10c2
+	 60, // 0x10c3
+	 60, // 0x10c4
+	 60, // 0x10c5
+	 60, // 0x10c6
+	 60, // 0x10c7
+	 60, // 0x10c8
+	 60, // 0x10c9
+	 60, // 0x10ca
+	 60, // 0x10cb
+	 60, // 0x10cc
+	 60, // 0x10cd
+	 60, // 0x10ce
+	 60, // 0x10cf
+	 60, // 0x10d0
+	 60, // 0x10d1
+	 60, // 0x10d2
+	 60, // 0x10d3
+	 60, // 0x10d4
+	 60, // 0x10d5
+	 60, // 0x10d6
+	 60, // 0x10d7
+	 60, // 0x10d8
+	 60, // 0x10d9
+	 60, // 0x10da
+	 60, // 0x10db
+	 60, // 0x10dc
+	 60, // 0x10dd
+	 60, // 0x10de
+	 60, // 0x10df
+	 60, // 0x10e0
+	 60, // 0x10e1
+	 60, // 0x10e2
+	 60, // 0x10e3
+	 60, // 0x10e4
+	 60, // 0x10e5
+	 60, // 0x10e6
+	 60, // 0x10e7
+	 60, // 0x10e8
+	 60, // 0x10e9
+	 60, // 0x10ea
+	 60, // 0x10eb
+	 60, // 0x10ec
+	 60, // 0x10ed
+	 60, // 0x10ee
+	 60, // 0x10ef
+	 60, // 0x10f0
+	 60, // 0x10f1
+	 60, // 0x10f2
+	 60, // 0x10f3
+	 60, // 0x10f4
+	 60, // 0x10f5
+	 60, // 0x10f6
+	 60, // 0x10f7
+	 60, // 0x10f8
+	 60, // 0x10f9
+	 60, // 0x10fa
+	 60, // 0x10fb
+	 60, // 0x10fc
+	 60, // 0x10fd
+	 60, // 0x10fe
+	 155, // 0x10ff
+];
+pub const CAT_PAGES: [[u8;256];156] = [
+    [
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x50,
+        0x36,
+        0x36,
+        0x36,
+        0x41,
+        0x36,
+        0x36,
+        0x36,
+        0x32,
+        0x33,
+        0x36,
+        0x40,
+        0x36,
+        0x31,
+        0x36,
+        0x36,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x36,
+        0x36,
+        0x40,
+        0x40,
+        0x40,
+        0x36,
+        0x36,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x32,
+        0x36,
+        0x33,
+        0x42,
+        0x30,
+        0x42,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x32,
+        0x40,
+        0x33,
+        0x40,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x61,
+        0x50,
+        0x36,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x43,
+        0x36,
+        0x42,
+        0x43,
+        0x84,
+        0x34,
+        0x40,
+        0x62,
+        0x43,
+        0x42,
+        0x43,
+        0x40,
+        0x22,
+        0x22,
+        0x42,
+        0x91,
+        0x36,
+        0x36,
+        0x42,
+        0x22,
+        0x84,
+        0x35,
+        0x22,
+        0x22,
+        0x22,
+        0x36,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x40,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x40,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+    ],
+    [
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x84,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x90,
+        0x92,
+        0x91,
+        0x90,
+        0x92,
+        0x91,
+        0x90,
+        0x92,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x91,
+        0x90,
+        0x92,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+    ],
+    [
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x84,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x83,
+        0x42,
+        0x83,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x83,
+        0x42,
+        0x90,
+        0x91,
+        0x60,
+        0x60,
+        0x83,
+        0x91,
+        0x91,
+        0x91,
+        0x36,
+        0x90,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x42,
+        0x42,
+        0x90,
+        0x36,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x90,
+        0x60,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x91,
+        0x40,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+    ],
+    [
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x43,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+    ],
+    [
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x60,
+        0x83,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x36,
+        0x31,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x41,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x31,
+        0x10,
+        0x36,
+        0x10,
+        0x10,
+        0x36,
+        0x10,
+        0x10,
+        0x36,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x40,
+        0x40,
+        0x40,
+        0x36,
+        0x36,
+        0x41,
+        0x36,
+        0x36,
+        0x43,
+        0x43,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x36,
+        0x62,
+        0x36,
+        0x36,
+        0x36,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x83,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x84,
+        0x84,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x36,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x62,
+        0x43,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x83,
+        0x83,
+        0x10,
+        0x10,
+        0x43,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x84,
+        0x84,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x84,
+        0x84,
+        0x84,
+        0x43,
+        0x43,
+        0x84,
+    ],
+    [
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x62,
+        0x84,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x83,
+        0x83,
+        0x43,
+        0x36,
+        0x36,
+        0x36,
+        0x83,
+        0x60,
+        0x60,
+        0x10,
+        0x41,
+        0x41,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x83,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x83,
+        0x10,
+        0x10,
+        0x10,
+        0x83,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x36,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x42,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x62,
+        0x62,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x83,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x62,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x11,
+        0x10,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x36,
+        0x36,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x36,
+        0x83,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x11,
+        0x11,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x10,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x11,
+        0x11,
+        0x60,
+        0x60,
+        0x11,
+        0x11,
+        0x10,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x11,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x84,
+        0x84,
+        0x41,
+        0x41,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x43,
+        0x41,
+        0x84,
+        0x36,
+        0x10,
+        0x60,
+    ],
+    [
+        0x60,
+        0x10,
+        0x10,
+        0x11,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x10,
+        0x60,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x10,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x11,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x10,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x10,
+        0x10,
+        0x11,
+        0x60,
+        0x11,
+        0x11,
+        0x10,
+        0x60,
+        0x60,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x36,
+        0x41,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x60,
+        0x10,
+        0x11,
+        0x11,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x10,
+        0x84,
+        0x11,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x11,
+        0x11,
+        0x60,
+        0x60,
+        0x11,
+        0x11,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x11,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x43,
+        0x84,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x60,
+        0x60,
+        0x60,
+        0x11,
+        0x11,
+        0x11,
+        0x60,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x60,
+        0x60,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x11,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x22,
+        0x22,
+        0x22,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x41,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x10,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x43,
+        0x84,
+        0x10,
+        0x11,
+        0x11,
+        0x36,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x10,
+        0x84,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x60,
+        0x10,
+        0x11,
+        0x11,
+        0x60,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x11,
+        0x11,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x84,
+        0x84,
+        0x11,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x11,
+        0x11,
+        0x11,
+        0x60,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x84,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x43,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x10,
+        0x11,
+        0x11,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x10,
+        0x60,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x11,
+        0x11,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x41,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x83,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x36,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x83,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x43,
+        0x43,
+        0x43,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x43,
+        0x36,
+        0x43,
+        0x43,
+        0x43,
+        0x10,
+        0x10,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x43,
+        0x10,
+        0x43,
+        0x10,
+        0x43,
+        0x10,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x11,
+        0x11,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x36,
+        0x10,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x10,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x43,
+        0x43,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x84,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x84,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x84,
+        0x11,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x43,
+        0x43,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x90,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x90,
+        0x60,
+        0x60,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x36,
+        0x83,
+        0x91,
+        0x91,
+        0x91,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x60,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x60,
+    ],
+    [
+        0x31,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x43,
+        0x36,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x50,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x32,
+        0x33,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x36,
+        0x36,
+        0x36,
+        0x21,
+        0x21,
+        0x21,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x11,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x36,
+        0x36,
+        0x36,
+        0x83,
+        0x36,
+        0x36,
+        0x36,
+        0x41,
+        0x84,
+        0x10,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x31,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x10,
+        0x10,
+        0x10,
+        0x62,
+        0x10,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x83,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+        0x36,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x60,
+        0x60,
+        0x36,
+        0x36,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x10,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x10,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x83,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x36,
+        0x36,
+        0x60,
+        0x10,
+        0x10,
+        0x11,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x84,
+        0x84,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x36,
+        0x36,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x36,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x84,
+        0x84,
+        0x11,
+        0x10,
+        0x10,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x83,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+    ],
+    [
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x60,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x60,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x90,
+        0x60,
+        0x90,
+        0x60,
+        0x90,
+        0x60,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x60,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x92,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x92,
+        0x42,
+        0x91,
+        0x42,
+        0x42,
+        0x42,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x92,
+        0x42,
+        0x42,
+        0x42,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x60,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x42,
+        0x42,
+        0x42,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x42,
+        0x42,
+        0x42,
+        0x60,
+        0x60,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x92,
+        0x42,
+        0x42,
+        0x60,
+    ],
+    [
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x50,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x31,
+        0x36,
+        0x36,
+        0x34,
+        0x35,
+        0x32,
+        0x34,
+        0x34,
+        0x35,
+        0x32,
+        0x34,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x51,
+        0x52,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x50,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x34,
+        0x35,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x30,
+        0x30,
+        0x36,
+        0x36,
+        0x36,
+        0x40,
+        0x32,
+        0x33,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x40,
+        0x36,
+        0x30,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x50,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x60,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x22,
+        0x83,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x40,
+        0x40,
+        0x40,
+        0x32,
+        0x33,
+        0x83,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x40,
+        0x40,
+        0x40,
+        0x32,
+        0x33,
+        0x60,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x60,
+        0x60,
+        0x60,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x43,
+        0x43,
+        0x90,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x90,
+        0x43,
+        0x43,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x43,
+        0x90,
+        0x43,
+        0x43,
+        0x40,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x90,
+        0x43,
+        0x90,
+        0x43,
+        0x90,
+        0x43,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x43,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x91,
+        0x43,
+        0x43,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x43,
+        0x40,
+        0x43,
+        0x43,
+        0x91,
+        0x43,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x90,
+        0x91,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x22,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x40,
+        0x40,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x40,
+        0x43,
+        0x43,
+        0x40,
+        0x43,
+        0x43,
+        0x40,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x40,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x40,
+        0x40,
+        0x43,
+        0x43,
+        0x40,
+        0x43,
+        0x40,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+    ],
+    [
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x40,
+        0x40,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x32,
+        0x33,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x40,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x40,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x40,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x40,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x32,
+        0x33,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+    ],
+    [
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x32,
+        0x33,
+        0x40,
+        0x40,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x43,
+        0x43,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+    ],
+    [
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x91,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x83,
+        0x83,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x91,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x10,
+        0x10,
+        0x10,
+        0x90,
+        0x91,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x22,
+        0x36,
+        0x36,
+    ],
+    [
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x91,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x91,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x83,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x36,
+        0x36,
+        0x34,
+        0x35,
+        0x34,
+        0x35,
+        0x36,
+        0x36,
+        0x36,
+        0x34,
+        0x35,
+        0x36,
+        0x34,
+        0x35,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x31,
+        0x36,
+        0x36,
+        0x31,
+        0x36,
+        0x34,
+        0x35,
+        0x36,
+        0x36,
+        0x34,
+        0x35,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x83,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x31,
+        0x31,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x31,
+        0x36,
+        0x32,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x43,
+        0x43,
+        0x36,
+        0x36,
+        0x36,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x31,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x50,
+        0x36,
+        0x36,
+        0x36,
+        0x43,
+        0x83,
+        0x84,
+        0x21,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x43,
+        0x43,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x31,
+        0x32,
+        0x33,
+        0x33,
+        0x43,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x31,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x43,
+        0x43,
+        0x21,
+        0x21,
+        0x21,
+        0x83,
+        0x84,
+        0x36,
+        0x43,
+        0x43,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x42,
+        0x42,
+        0x83,
+        0x83,
+        0x84,
+        0x31,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x36,
+        0x83,
+        0x83,
+        0x83,
+        0x84,
+    ],
+    [
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x43,
+        0x43,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x43,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x83,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x36,
+        0x36,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x83,
+        0x36,
+        0x36,
+        0x36,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x84,
+        0x10,
+        0x12,
+        0x12,
+        0x12,
+        0x36,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x36,
+        0x83,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x83,
+        0x83,
+        0x10,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x10,
+        0x10,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x42,
+        0x42,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x83,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x83,
+        0x42,
+        0x42,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x84,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x90,
+        0x91,
+        0x60,
+        0x91,
+        0x60,
+        0x91,
+        0x90,
+        0x91,
+        0x90,
+        0x91,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x83,
+        0x83,
+        0x83,
+        0x90,
+        0x91,
+        0x84,
+        0x83,
+        0x83,
+        0x91,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+    ],
+    [
+        0x84,
+        0x84,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x43,
+        0x43,
+        0x41,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x11,
+        0x11,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+        0x36,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x36,
+        0x36,
+        0x36,
+        0x84,
+        0x36,
+        0x84,
+        0x84,
+        0x10,
+    ],
+    [
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x36,
+        0x36,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x83,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+        0x36,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x83,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x11,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x83,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x43,
+        0x43,
+        0x43,
+        0x84,
+        0x11,
+        0x10,
+        0x11,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x84,
+        0x10,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x83,
+        0x36,
+        0x36,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x36,
+        0x36,
+        0x84,
+        0x83,
+        0x83,
+        0x11,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x42,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x83,
+        0x42,
+        0x42,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x36,
+        0x11,
+        0x10,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+        0x63,
+    ],
+    [
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x40,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x33,
+        0x32,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x41,
+        0x43,
+        0x43,
+        0x43,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x32,
+        0x33,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x36,
+        0x31,
+        0x31,
+        0x30,
+        0x30,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x36,
+        0x36,
+        0x32,
+        0x33,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x30,
+        0x30,
+        0x30,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x31,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x32,
+        0x33,
+        0x36,
+        0x36,
+        0x36,
+        0x40,
+        0x31,
+        0x40,
+        0x40,
+        0x40,
+        0x60,
+        0x36,
+        0x41,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x62,
+    ],
+    [
+        0x60,
+        0x36,
+        0x36,
+        0x36,
+        0x41,
+        0x36,
+        0x36,
+        0x36,
+        0x32,
+        0x33,
+        0x36,
+        0x40,
+        0x36,
+        0x31,
+        0x36,
+        0x36,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x36,
+        0x36,
+        0x40,
+        0x40,
+        0x40,
+        0x36,
+        0x36,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x32,
+        0x36,
+        0x33,
+        0x42,
+        0x30,
+        0x42,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x32,
+        0x40,
+        0x33,
+        0x40,
+        0x32,
+        0x33,
+        0x36,
+        0x32,
+        0x33,
+        0x36,
+        0x36,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x83,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x83,
+        0x83,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x41,
+        0x41,
+        0x40,
+        0x42,
+        0x43,
+        0x41,
+        0x41,
+        0x60,
+        0x43,
+        0x40,
+        0x40,
+        0x40,
+        0x40,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x62,
+        0x62,
+        0x62,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x22,
+        0x22,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x10,
+        0x60,
+        0x60,
+    ],
+    [
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x21,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x21,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x36,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x36,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x90,
+        0x90,
+        0x60,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x91,
+        0x91,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x60,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x60,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x36,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x43,
+        0x43,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x84,
+        0x84,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+    ],
+    [
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x22,
+        0x22,
+        0x36,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x43,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x10,
+        0x10,
+        0x31,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x11,
+        0x10,
+        0x11,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x10,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x36,
+        0x36,
+        0x62,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x62,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x84,
+        0x11,
+        0x11,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x36,
+        0x36,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x11,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x36,
+        0x11,
+        0x10,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x84,
+        0x36,
+        0x84,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x10,
+        0x84,
+        0x84,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x10,
+        0x10,
+        0x84,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x60,
+        0x60,
+        0x11,
+        0x11,
+        0x60,
+        0x60,
+        0x11,
+        0x11,
+        0x11,
+        0x60,
+        0x60,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x11,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x11,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x36,
+        0x36,
+        0x60,
+        0x36,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x84,
+        0x84,
+        0x36,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x36,
+        0x36,
+        0x36,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x11,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x84,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x22,
+        0x22,
+        0x36,
+        0x36,
+        0x36,
+        0x43,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x60,
+        0x11,
+        0x11,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x84,
+        0x11,
+        0x84,
+        0x11,
+        0x10,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x10,
+        0x84,
+        0x36,
+        0x84,
+        0x11,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x36,
+        0x36,
+        0x36,
+        0x84,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x84,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+        0x36,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x11,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x60,
+        0x10,
+        0x10,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x84,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x60,
+        0x10,
+        0x10,
+        0x60,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x10,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x11,
+        0x11,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x10,
+        0x10,
+        0x84,
+        0x11,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x11,
+        0x11,
+        0x10,
+        0x11,
+        0x10,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x41,
+        0x41,
+        0x41,
+        0x41,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x21,
+        0x60,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x10,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+        0x36,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x36,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x84,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x83,
+        0x83,
+        0x36,
+        0x83,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x11,
+        0x11,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x60,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x60,
+        0x83,
+        0x83,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x43,
+        0x10,
+        0x10,
+        0x36,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x11,
+        0x11,
+        0x10,
+        0x10,
+        0x10,
+        0x43,
+        0x43,
+        0x43,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x11,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x43,
+        0x43,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x10,
+        0x10,
+        0x10,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x60,
+        0x90,
+        0x90,
+        0x60,
+        0x60,
+        0x90,
+        0x60,
+        0x60,
+        0x90,
+        0x90,
+        0x60,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x91,
+        0x60,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+    ],
+    [
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x90,
+        0x60,
+        0x60,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x60,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+    ],
+    [
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x60,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x40,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x40,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x40,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+    ],
+    [
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x40,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x40,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x40,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x40,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x40,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x40,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x40,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x90,
+        0x91,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x10,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x10,
+        0x43,
+        0x43,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x84,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x10,
+        0x10,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x83,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x41,
+    ],
+    [
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x83,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x90,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x91,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x83,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x36,
+        0x36,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x43,
+        0x22,
+        0x22,
+        0x22,
+        0x41,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x60,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x43,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x40,
+        0x40,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x22,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+        0x42,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x43,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x20,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+    ],
+    [
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x84,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x60,
+        0x62,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x62,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x10,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+        0x60,
+    ],
+    [
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x64,
+        0x60,
+        0x60,
+    ],
+];
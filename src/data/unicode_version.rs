@@ -0,0 +1,2 @@
+// GENERATED CODE DO NOT MANUALLY EDIT
+pub const UNICODE_VERSION: (u8, u8, u8) = (16, 0, 0);
@@ -0,0 +1,7311 @@
+// GENERATED CODE DO NOT MANUALLY EDIT
+
+use crate::word_boundaries::tests::word_test;
+
+#[test]
+fn standard_word_test() {
+	word_test("\u{0001}\u{0001}",
+		&["\u{0001}", "\u{0001}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0308}\u{0001}",
+		&["\u{0001}\u{0308}", "\u{0001}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{000D}",
+		&["\u{0001}", "\u{000D}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0308}\u{000D}",
+		&["\u{0001}\u{0308}", "\u{000D}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{000A}",
+		&["\u{0001}", "\u{000A}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0308}\u{000A}",
+		&["\u{0001}\u{0308}", "\u{000A}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{000B}",
+		&["\u{0001}", "\u{000B}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0308}\u{000B}",
+		&["\u{0001}\u{0308}", "\u{000B}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{3031}",
+		&["\u{0001}", "\u{3031}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0308}\u{3031}",
+		&["\u{0001}\u{0308}", "\u{3031}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0041}",
+		&["\u{0001}", "\u{0041}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) ÷ [999.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0308}\u{0041}",
+		&["\u{0001}\u{0308}", "\u{0041}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{003A}",
+		&["\u{0001}", "\u{003A}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0308}\u{003A}",
+		&["\u{0001}\u{0308}", "\u{003A}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{002C}",
+		&["\u{0001}", "\u{002C}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0308}\u{002C}",
+		&["\u{0001}\u{0308}", "\u{002C}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{002E}",
+		&["\u{0001}", "\u{002E}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0308}\u{002E}",
+		&["\u{0001}\u{0308}", "\u{002E}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0030}",
+		&["\u{0001}", "\u{0030}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) ÷ [999.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0308}\u{0030}",
+		&["\u{0001}\u{0308}", "\u{0030}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{005F}",
+		&["\u{0001}", "\u{005F}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0308}\u{005F}",
+		&["\u{0001}\u{0308}", "\u{005F}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{1F1E6}",
+		&["\u{0001}", "\u{1F1E6}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0308}\u{1F1E6}",
+		&["\u{0001}\u{0308}", "\u{1F1E6}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{05D0}",
+		&["\u{0001}", "\u{05D0}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) ÷ [999.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0308}\u{05D0}",
+		&["\u{0001}\u{0308}", "\u{05D0}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0022}",
+		&["\u{0001}", "\u{0022}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0308}\u{0022}",
+		&["\u{0001}\u{0308}", "\u{0022}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0027}",
+		&["\u{0001}", "\u{0027}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0308}\u{0027}",
+		&["\u{0001}\u{0308}", "\u{0027}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{231A}",
+		&["\u{0001}", "\u{231A}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0308}\u{231A}",
+		&["\u{0001}\u{0308}", "\u{231A}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0020}",
+		&["\u{0001}", "\u{0020}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0308}\u{0020}",
+		&["\u{0001}\u{0308}", "\u{0020}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{00AD}",
+		&["\u{0001}\u{00AD}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0308}\u{00AD}",
+		&["\u{0001}\u{0308}\u{00AD}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0300}",
+		&["\u{0001}\u{0300}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0308}\u{0300}",
+		&["\u{0001}\u{0308}\u{0300}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{200D}",
+		&["\u{0001}\u{200D}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0308}\u{200D}",
+		&["\u{0001}\u{0308}\u{200D}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0061}\u{2060}",
+		&["\u{0001}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0308}\u{0061}\u{2060}",
+		&["\u{0001}\u{0308}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0061}\u{003A}",
+		&["\u{0001}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0308}\u{0061}\u{003A}",
+		&["\u{0001}\u{0308}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0061}\u{0027}",
+		&["\u{0001}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0308}\u{0061}\u{0027}",
+		&["\u{0001}\u{0308}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0061}\u{0027}\u{2060}",
+		&["\u{0001}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0308}\u{0061}\u{0027}\u{2060}",
+		&["\u{0001}\u{0308}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0061}\u{002C}",
+		&["\u{0001}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0308}\u{0061}\u{002C}",
+		&["\u{0001}\u{0308}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0031}\u{003A}",
+		&["\u{0001}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0308}\u{0031}\u{003A}",
+		&["\u{0001}\u{0308}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0031}\u{0027}",
+		&["\u{0001}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0308}\u{0031}\u{0027}",
+		&["\u{0001}\u{0308}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0031}\u{002C}",
+		&["\u{0001}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0308}\u{0031}\u{002C}",
+		&["\u{0001}\u{0308}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0031}\u{002E}\u{2060}",
+		&["\u{0001}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0001}\u{0308}\u{0031}\u{002E}\u{2060}",
+		&["\u{0001}\u{0308}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] <START OF HEADING> (XXmExtPict) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0001}",
+		&["\u{000D}", "\u{0001}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0308}\u{0001}",
+		&["\u{000D}", "\u{0308}", "\u{0001}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{000D}",
+		&["\u{000D}", "\u{000D}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0308}\u{000D}",
+		&["\u{000D}", "\u{0308}", "\u{000D}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{000A}",
+		&["\u{000D}\u{000A}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) × [3.0] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0308}\u{000A}",
+		&["\u{000D}", "\u{0308}", "\u{000A}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{000B}",
+		&["\u{000D}", "\u{000B}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0308}\u{000B}",
+		&["\u{000D}", "\u{0308}", "\u{000B}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{3031}",
+		&["\u{000D}", "\u{3031}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0308}\u{3031}",
+		&["\u{000D}", "\u{0308}", "\u{3031}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0041}",
+		&["\u{000D}", "\u{0041}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0308}\u{0041}",
+		&["\u{000D}", "\u{0308}", "\u{0041}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{003A}",
+		&["\u{000D}", "\u{003A}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0308}\u{003A}",
+		&["\u{000D}", "\u{0308}", "\u{003A}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{002C}",
+		&["\u{000D}", "\u{002C}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0308}\u{002C}",
+		&["\u{000D}", "\u{0308}", "\u{002C}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{002E}",
+		&["\u{000D}", "\u{002E}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0308}\u{002E}",
+		&["\u{000D}", "\u{0308}", "\u{002E}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0030}",
+		&["\u{000D}", "\u{0030}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0308}\u{0030}",
+		&["\u{000D}", "\u{0308}", "\u{0030}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{005F}",
+		&["\u{000D}", "\u{005F}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0308}\u{005F}",
+		&["\u{000D}", "\u{0308}", "\u{005F}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{1F1E6}",
+		&["\u{000D}", "\u{1F1E6}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0308}\u{1F1E6}",
+		&["\u{000D}", "\u{0308}", "\u{1F1E6}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{05D0}",
+		&["\u{000D}", "\u{05D0}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0308}\u{05D0}",
+		&["\u{000D}", "\u{0308}", "\u{05D0}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0022}",
+		&["\u{000D}", "\u{0022}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0308}\u{0022}",
+		&["\u{000D}", "\u{0308}", "\u{0022}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0027}",
+		&["\u{000D}", "\u{0027}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0308}\u{0027}",
+		&["\u{000D}", "\u{0308}", "\u{0027}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{231A}",
+		&["\u{000D}", "\u{231A}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0308}\u{231A}",
+		&["\u{000D}", "\u{0308}", "\u{231A}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0020}",
+		&["\u{000D}", "\u{0020}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0308}\u{0020}",
+		&["\u{000D}", "\u{0308}", "\u{0020}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{00AD}",
+		&["\u{000D}", "\u{00AD}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0308}\u{00AD}",
+		&["\u{000D}", "\u{0308}\u{00AD}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] COMBINING DIAERESIS (Extend) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0300}",
+		&["\u{000D}", "\u{0300}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0308}\u{0300}",
+		&["\u{000D}", "\u{0308}\u{0300}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] COMBINING DIAERESIS (Extend) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{200D}",
+		&["\u{000D}", "\u{200D}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0308}\u{200D}",
+		&["\u{000D}", "\u{0308}\u{200D}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] COMBINING DIAERESIS (Extend) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0061}\u{2060}",
+		&["\u{000D}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0308}\u{0061}\u{2060}",
+		&["\u{000D}", "\u{0308}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0061}\u{003A}",
+		&["\u{000D}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0308}\u{0061}\u{003A}",
+		&["\u{000D}", "\u{0308}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0061}\u{0027}",
+		&["\u{000D}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0308}\u{0061}\u{0027}",
+		&["\u{000D}", "\u{0308}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0061}\u{0027}\u{2060}",
+		&["\u{000D}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0308}\u{0061}\u{0027}\u{2060}",
+		&["\u{000D}", "\u{0308}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0061}\u{002C}",
+		&["\u{000D}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0308}\u{0061}\u{002C}",
+		&["\u{000D}", "\u{0308}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0031}\u{003A}",
+		&["\u{000D}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0308}\u{0031}\u{003A}",
+		&["\u{000D}", "\u{0308}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0031}\u{0027}",
+		&["\u{000D}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0308}\u{0031}\u{0027}",
+		&["\u{000D}", "\u{0308}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0031}\u{002C}",
+		&["\u{000D}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0308}\u{0031}\u{002C}",
+		&["\u{000D}", "\u{0308}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0031}\u{002E}\u{2060}",
+		&["\u{000D}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{0308}\u{0031}\u{002E}\u{2060}",
+		&["\u{000D}", "\u{0308}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0001}",
+		&["\u{000A}", "\u{0001}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0308}\u{0001}",
+		&["\u{000A}", "\u{0308}", "\u{0001}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{000D}",
+		&["\u{000A}", "\u{000D}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0308}\u{000D}",
+		&["\u{000A}", "\u{0308}", "\u{000D}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{000A}",
+		&["\u{000A}", "\u{000A}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0308}\u{000A}",
+		&["\u{000A}", "\u{0308}", "\u{000A}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{000B}",
+		&["\u{000A}", "\u{000B}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0308}\u{000B}",
+		&["\u{000A}", "\u{0308}", "\u{000B}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{3031}",
+		&["\u{000A}", "\u{3031}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0308}\u{3031}",
+		&["\u{000A}", "\u{0308}", "\u{3031}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0041}",
+		&["\u{000A}", "\u{0041}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0308}\u{0041}",
+		&["\u{000A}", "\u{0308}", "\u{0041}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{003A}",
+		&["\u{000A}", "\u{003A}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0308}\u{003A}",
+		&["\u{000A}", "\u{0308}", "\u{003A}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{002C}",
+		&["\u{000A}", "\u{002C}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0308}\u{002C}",
+		&["\u{000A}", "\u{0308}", "\u{002C}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{002E}",
+		&["\u{000A}", "\u{002E}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0308}\u{002E}",
+		&["\u{000A}", "\u{0308}", "\u{002E}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0030}",
+		&["\u{000A}", "\u{0030}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0308}\u{0030}",
+		&["\u{000A}", "\u{0308}", "\u{0030}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{005F}",
+		&["\u{000A}", "\u{005F}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0308}\u{005F}",
+		&["\u{000A}", "\u{0308}", "\u{005F}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{1F1E6}",
+		&["\u{000A}", "\u{1F1E6}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0308}\u{1F1E6}",
+		&["\u{000A}", "\u{0308}", "\u{1F1E6}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{05D0}",
+		&["\u{000A}", "\u{05D0}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0308}\u{05D0}",
+		&["\u{000A}", "\u{0308}", "\u{05D0}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0022}",
+		&["\u{000A}", "\u{0022}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0308}\u{0022}",
+		&["\u{000A}", "\u{0308}", "\u{0022}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0027}",
+		&["\u{000A}", "\u{0027}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0308}\u{0027}",
+		&["\u{000A}", "\u{0308}", "\u{0027}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{231A}",
+		&["\u{000A}", "\u{231A}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0308}\u{231A}",
+		&["\u{000A}", "\u{0308}", "\u{231A}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0020}",
+		&["\u{000A}", "\u{0020}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0308}\u{0020}",
+		&["\u{000A}", "\u{0308}", "\u{0020}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{00AD}",
+		&["\u{000A}", "\u{00AD}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0308}\u{00AD}",
+		&["\u{000A}", "\u{0308}\u{00AD}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] COMBINING DIAERESIS (Extend) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0300}",
+		&["\u{000A}", "\u{0300}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0308}\u{0300}",
+		&["\u{000A}", "\u{0308}\u{0300}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] COMBINING DIAERESIS (Extend) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{200D}",
+		&["\u{000A}", "\u{200D}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0308}\u{200D}",
+		&["\u{000A}", "\u{0308}\u{200D}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] COMBINING DIAERESIS (Extend) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0061}\u{2060}",
+		&["\u{000A}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0308}\u{0061}\u{2060}",
+		&["\u{000A}", "\u{0308}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0061}\u{003A}",
+		&["\u{000A}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0308}\u{0061}\u{003A}",
+		&["\u{000A}", "\u{0308}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0061}\u{0027}",
+		&["\u{000A}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0308}\u{0061}\u{0027}",
+		&["\u{000A}", "\u{0308}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0061}\u{0027}\u{2060}",
+		&["\u{000A}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0308}\u{0061}\u{0027}\u{2060}",
+		&["\u{000A}", "\u{0308}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0061}\u{002C}",
+		&["\u{000A}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0308}\u{0061}\u{002C}",
+		&["\u{000A}", "\u{0308}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0031}\u{003A}",
+		&["\u{000A}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0308}\u{0031}\u{003A}",
+		&["\u{000A}", "\u{0308}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0031}\u{0027}",
+		&["\u{000A}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0308}\u{0031}\u{0027}",
+		&["\u{000A}", "\u{0308}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0031}\u{002C}",
+		&["\u{000A}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0308}\u{0031}\u{002C}",
+		&["\u{000A}", "\u{0308}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0031}\u{002E}\u{2060}",
+		&["\u{000A}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{000A}\u{0308}\u{0031}\u{002E}\u{2060}",
+		&["\u{000A}", "\u{0308}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] <LINE FEED (LF)> (LF) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0001}",
+		&["\u{000B}", "\u{0001}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0308}\u{0001}",
+		&["\u{000B}", "\u{0308}", "\u{0001}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{000D}",
+		&["\u{000B}", "\u{000D}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0308}\u{000D}",
+		&["\u{000B}", "\u{0308}", "\u{000D}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{000A}",
+		&["\u{000B}", "\u{000A}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0308}\u{000A}",
+		&["\u{000B}", "\u{0308}", "\u{000A}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{000B}",
+		&["\u{000B}", "\u{000B}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0308}\u{000B}",
+		&["\u{000B}", "\u{0308}", "\u{000B}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{3031}",
+		&["\u{000B}", "\u{3031}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0308}\u{3031}",
+		&["\u{000B}", "\u{0308}", "\u{3031}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0041}",
+		&["\u{000B}", "\u{0041}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0308}\u{0041}",
+		&["\u{000B}", "\u{0308}", "\u{0041}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{003A}",
+		&["\u{000B}", "\u{003A}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0308}\u{003A}",
+		&["\u{000B}", "\u{0308}", "\u{003A}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{002C}",
+		&["\u{000B}", "\u{002C}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0308}\u{002C}",
+		&["\u{000B}", "\u{0308}", "\u{002C}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{002E}",
+		&["\u{000B}", "\u{002E}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0308}\u{002E}",
+		&["\u{000B}", "\u{0308}", "\u{002E}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0030}",
+		&["\u{000B}", "\u{0030}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0308}\u{0030}",
+		&["\u{000B}", "\u{0308}", "\u{0030}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{005F}",
+		&["\u{000B}", "\u{005F}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0308}\u{005F}",
+		&["\u{000B}", "\u{0308}", "\u{005F}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{1F1E6}",
+		&["\u{000B}", "\u{1F1E6}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0308}\u{1F1E6}",
+		&["\u{000B}", "\u{0308}", "\u{1F1E6}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{05D0}",
+		&["\u{000B}", "\u{05D0}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0308}\u{05D0}",
+		&["\u{000B}", "\u{0308}", "\u{05D0}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0022}",
+		&["\u{000B}", "\u{0022}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0308}\u{0022}",
+		&["\u{000B}", "\u{0308}", "\u{0022}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0027}",
+		&["\u{000B}", "\u{0027}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0308}\u{0027}",
+		&["\u{000B}", "\u{0308}", "\u{0027}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{231A}",
+		&["\u{000B}", "\u{231A}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0308}\u{231A}",
+		&["\u{000B}", "\u{0308}", "\u{231A}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0020}",
+		&["\u{000B}", "\u{0020}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0308}\u{0020}",
+		&["\u{000B}", "\u{0308}", "\u{0020}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{00AD}",
+		&["\u{000B}", "\u{00AD}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0308}\u{00AD}",
+		&["\u{000B}", "\u{0308}\u{00AD}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] COMBINING DIAERESIS (Extend) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0300}",
+		&["\u{000B}", "\u{0300}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0308}\u{0300}",
+		&["\u{000B}", "\u{0308}\u{0300}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] COMBINING DIAERESIS (Extend) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{200D}",
+		&["\u{000B}", "\u{200D}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0308}\u{200D}",
+		&["\u{000B}", "\u{0308}\u{200D}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] COMBINING DIAERESIS (Extend) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0061}\u{2060}",
+		&["\u{000B}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0308}\u{0061}\u{2060}",
+		&["\u{000B}", "\u{0308}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0061}\u{003A}",
+		&["\u{000B}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0308}\u{0061}\u{003A}",
+		&["\u{000B}", "\u{0308}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0061}\u{0027}",
+		&["\u{000B}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0308}\u{0061}\u{0027}",
+		&["\u{000B}", "\u{0308}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0061}\u{0027}\u{2060}",
+		&["\u{000B}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0308}\u{0061}\u{0027}\u{2060}",
+		&["\u{000B}", "\u{0308}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0061}\u{002C}",
+		&["\u{000B}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0308}\u{0061}\u{002C}",
+		&["\u{000B}", "\u{0308}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0031}\u{003A}",
+		&["\u{000B}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0308}\u{0031}\u{003A}",
+		&["\u{000B}", "\u{0308}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0031}\u{0027}",
+		&["\u{000B}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0308}\u{0031}\u{0027}",
+		&["\u{000B}", "\u{0308}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0031}\u{002C}",
+		&["\u{000B}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0308}\u{0031}\u{002C}",
+		&["\u{000B}", "\u{0308}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0031}\u{002E}\u{2060}",
+		&["\u{000B}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{000B}\u{0308}\u{0031}\u{002E}\u{2060}",
+		&["\u{000B}", "\u{0308}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] <LINE TABULATION> (Newline) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0001}",
+		&["\u{3031}", "\u{0001}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0308}\u{0001}",
+		&["\u{3031}\u{0308}", "\u{0001}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{000D}",
+		&["\u{3031}", "\u{000D}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0308}\u{000D}",
+		&["\u{3031}\u{0308}", "\u{000D}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{000A}",
+		&["\u{3031}", "\u{000A}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0308}\u{000A}",
+		&["\u{3031}\u{0308}", "\u{000A}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{000B}",
+		&["\u{3031}", "\u{000B}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0308}\u{000B}",
+		&["\u{3031}\u{0308}", "\u{000B}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{3031}",
+		&["\u{3031}\u{3031}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) × [13.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0308}\u{3031}",
+		&["\u{3031}\u{0308}\u{3031}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) × [4.0] COMBINING DIAERESIS (Extend) × [13.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0041}",
+		&["\u{3031}", "\u{0041}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) ÷ [999.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0308}\u{0041}",
+		&["\u{3031}\u{0308}", "\u{0041}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{003A}",
+		&["\u{3031}", "\u{003A}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0308}\u{003A}",
+		&["\u{3031}\u{0308}", "\u{003A}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{002C}",
+		&["\u{3031}", "\u{002C}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0308}\u{002C}",
+		&["\u{3031}\u{0308}", "\u{002C}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{002E}",
+		&["\u{3031}", "\u{002E}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0308}\u{002E}",
+		&["\u{3031}\u{0308}", "\u{002E}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0030}",
+		&["\u{3031}", "\u{0030}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) ÷ [999.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0308}\u{0030}",
+		&["\u{3031}\u{0308}", "\u{0030}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{005F}",
+		&["\u{3031}\u{005F}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) × [13.1] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0308}\u{005F}",
+		&["\u{3031}\u{0308}\u{005F}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) × [4.0] COMBINING DIAERESIS (Extend) × [13.1] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{1F1E6}",
+		&["\u{3031}", "\u{1F1E6}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0308}\u{1F1E6}",
+		&["\u{3031}\u{0308}", "\u{1F1E6}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{05D0}",
+		&["\u{3031}", "\u{05D0}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) ÷ [999.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0308}\u{05D0}",
+		&["\u{3031}\u{0308}", "\u{05D0}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0022}",
+		&["\u{3031}", "\u{0022}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0308}\u{0022}",
+		&["\u{3031}\u{0308}", "\u{0022}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0027}",
+		&["\u{3031}", "\u{0027}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0308}\u{0027}",
+		&["\u{3031}\u{0308}", "\u{0027}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{231A}",
+		&["\u{3031}", "\u{231A}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0308}\u{231A}",
+		&["\u{3031}\u{0308}", "\u{231A}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0020}",
+		&["\u{3031}", "\u{0020}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0308}\u{0020}",
+		&["\u{3031}\u{0308}", "\u{0020}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{00AD}",
+		&["\u{3031}\u{00AD}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0308}\u{00AD}",
+		&["\u{3031}\u{0308}\u{00AD}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0300}",
+		&["\u{3031}\u{0300}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0308}\u{0300}",
+		&["\u{3031}\u{0308}\u{0300}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{200D}",
+		&["\u{3031}\u{200D}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0308}\u{200D}",
+		&["\u{3031}\u{0308}\u{200D}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0061}\u{2060}",
+		&["\u{3031}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0308}\u{0061}\u{2060}",
+		&["\u{3031}\u{0308}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0061}\u{003A}",
+		&["\u{3031}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0308}\u{0061}\u{003A}",
+		&["\u{3031}\u{0308}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0061}\u{0027}",
+		&["\u{3031}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0308}\u{0061}\u{0027}",
+		&["\u{3031}\u{0308}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0061}\u{0027}\u{2060}",
+		&["\u{3031}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0308}\u{0061}\u{0027}\u{2060}",
+		&["\u{3031}\u{0308}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0061}\u{002C}",
+		&["\u{3031}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0308}\u{0061}\u{002C}",
+		&["\u{3031}\u{0308}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0031}\u{003A}",
+		&["\u{3031}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0308}\u{0031}\u{003A}",
+		&["\u{3031}\u{0308}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0031}\u{0027}",
+		&["\u{3031}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0308}\u{0031}\u{0027}",
+		&["\u{3031}\u{0308}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0031}\u{002C}",
+		&["\u{3031}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0308}\u{0031}\u{002C}",
+		&["\u{3031}\u{0308}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0031}\u{002E}\u{2060}",
+		&["\u{3031}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{0308}\u{0031}\u{002E}\u{2060}",
+		&["\u{3031}\u{0308}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0001}",
+		&["\u{0041}", "\u{0001}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0308}\u{0001}",
+		&["\u{0041}\u{0308}", "\u{0001}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{000D}",
+		&["\u{0041}", "\u{000D}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0308}\u{000D}",
+		&["\u{0041}\u{0308}", "\u{000D}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{000A}",
+		&["\u{0041}", "\u{000A}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0308}\u{000A}",
+		&["\u{0041}\u{0308}", "\u{000A}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{000B}",
+		&["\u{0041}", "\u{000B}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0308}\u{000B}",
+		&["\u{0041}\u{0308}", "\u{000B}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{3031}",
+		&["\u{0041}", "\u{3031}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0308}\u{3031}",
+		&["\u{0041}\u{0308}", "\u{3031}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0041}",
+		&["\u{0041}\u{0041}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [5.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0308}\u{0041}",
+		&["\u{0041}\u{0308}\u{0041}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [4.0] COMBINING DIAERESIS (Extend) × [5.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{003A}",
+		&["\u{0041}", "\u{003A}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0308}\u{003A}",
+		&["\u{0041}\u{0308}", "\u{003A}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{002C}",
+		&["\u{0041}", "\u{002C}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0308}\u{002C}",
+		&["\u{0041}\u{0308}", "\u{002C}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{002E}",
+		&["\u{0041}", "\u{002E}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0308}\u{002E}",
+		&["\u{0041}\u{0308}", "\u{002E}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0030}",
+		&["\u{0041}\u{0030}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [9.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0308}\u{0030}",
+		&["\u{0041}\u{0308}\u{0030}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [4.0] COMBINING DIAERESIS (Extend) × [9.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{005F}",
+		&["\u{0041}\u{005F}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [13.1] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0308}\u{005F}",
+		&["\u{0041}\u{0308}\u{005F}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [4.0] COMBINING DIAERESIS (Extend) × [13.1] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{1F1E6}",
+		&["\u{0041}", "\u{1F1E6}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0308}\u{1F1E6}",
+		&["\u{0041}\u{0308}", "\u{1F1E6}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{05D0}",
+		&["\u{0041}\u{05D0}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [5.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0308}\u{05D0}",
+		&["\u{0041}\u{0308}\u{05D0}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [4.0] COMBINING DIAERESIS (Extend) × [5.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0022}",
+		&["\u{0041}", "\u{0022}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0308}\u{0022}",
+		&["\u{0041}\u{0308}", "\u{0022}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0027}",
+		&["\u{0041}", "\u{0027}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0308}\u{0027}",
+		&["\u{0041}\u{0308}", "\u{0027}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{231A}",
+		&["\u{0041}", "\u{231A}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0308}\u{231A}",
+		&["\u{0041}\u{0308}", "\u{231A}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0020}",
+		&["\u{0041}", "\u{0020}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0308}\u{0020}",
+		&["\u{0041}\u{0308}", "\u{0020}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{00AD}",
+		&["\u{0041}\u{00AD}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0308}\u{00AD}",
+		&["\u{0041}\u{0308}\u{00AD}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0300}",
+		&["\u{0041}\u{0300}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0308}\u{0300}",
+		&["\u{0041}\u{0308}\u{0300}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{200D}",
+		&["\u{0041}\u{200D}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0308}\u{200D}",
+		&["\u{0041}\u{0308}\u{200D}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0061}\u{2060}",
+		&["\u{0041}\u{0061}\u{2060}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [5.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0308}\u{0061}\u{2060}",
+		&["\u{0041}\u{0308}\u{0061}\u{2060}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [4.0] COMBINING DIAERESIS (Extend) × [5.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0061}\u{003A}",
+		&["\u{0041}\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [5.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0308}\u{0061}\u{003A}",
+		&["\u{0041}\u{0308}\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [4.0] COMBINING DIAERESIS (Extend) × [5.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0061}\u{0027}",
+		&["\u{0041}\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [5.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0308}\u{0061}\u{0027}",
+		&["\u{0041}\u{0308}\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [4.0] COMBINING DIAERESIS (Extend) × [5.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0061}\u{0027}\u{2060}",
+		&["\u{0041}\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [5.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0308}\u{0061}\u{0027}\u{2060}",
+		&["\u{0041}\u{0308}\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [4.0] COMBINING DIAERESIS (Extend) × [5.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0061}\u{002C}",
+		&["\u{0041}\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [5.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0308}\u{0061}\u{002C}",
+		&["\u{0041}\u{0308}\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [4.0] COMBINING DIAERESIS (Extend) × [5.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0031}\u{003A}",
+		&["\u{0041}\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [9.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0308}\u{0031}\u{003A}",
+		&["\u{0041}\u{0308}\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [4.0] COMBINING DIAERESIS (Extend) × [9.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0031}\u{0027}",
+		&["\u{0041}\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [9.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0308}\u{0031}\u{0027}",
+		&["\u{0041}\u{0308}\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [4.0] COMBINING DIAERESIS (Extend) × [9.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0031}\u{002C}",
+		&["\u{0041}\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [9.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0308}\u{0031}\u{002C}",
+		&["\u{0041}\u{0308}\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [4.0] COMBINING DIAERESIS (Extend) × [9.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0031}\u{002E}\u{2060}",
+		&["\u{0041}\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [9.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0308}\u{0031}\u{002E}\u{2060}",
+		&["\u{0041}\u{0308}\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [4.0] COMBINING DIAERESIS (Extend) × [9.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0001}",
+		&["\u{003A}", "\u{0001}"],
+		"  ÷ [0.2] COLON (MidLetter) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0308}\u{0001}",
+		&["\u{003A}\u{0308}", "\u{0001}"],
+		"  ÷ [0.2] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{000D}",
+		&["\u{003A}", "\u{000D}"],
+		"  ÷ [0.2] COLON (MidLetter) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0308}\u{000D}",
+		&["\u{003A}\u{0308}", "\u{000D}"],
+		"  ÷ [0.2] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{000A}",
+		&["\u{003A}", "\u{000A}"],
+		"  ÷ [0.2] COLON (MidLetter) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0308}\u{000A}",
+		&["\u{003A}\u{0308}", "\u{000A}"],
+		"  ÷ [0.2] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{000B}",
+		&["\u{003A}", "\u{000B}"],
+		"  ÷ [0.2] COLON (MidLetter) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0308}\u{000B}",
+		&["\u{003A}\u{0308}", "\u{000B}"],
+		"  ÷ [0.2] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{3031}",
+		&["\u{003A}", "\u{3031}"],
+		"  ÷ [0.2] COLON (MidLetter) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0308}\u{3031}",
+		&["\u{003A}\u{0308}", "\u{3031}"],
+		"  ÷ [0.2] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0041}",
+		&["\u{003A}", "\u{0041}"],
+		"  ÷ [0.2] COLON (MidLetter) ÷ [999.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0308}\u{0041}",
+		&["\u{003A}\u{0308}", "\u{0041}"],
+		"  ÷ [0.2] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{003A}",
+		&["\u{003A}", "\u{003A}"],
+		"  ÷ [0.2] COLON (MidLetter) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0308}\u{003A}",
+		&["\u{003A}\u{0308}", "\u{003A}"],
+		"  ÷ [0.2] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{002C}",
+		&["\u{003A}", "\u{002C}"],
+		"  ÷ [0.2] COLON (MidLetter) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0308}\u{002C}",
+		&["\u{003A}\u{0308}", "\u{002C}"],
+		"  ÷ [0.2] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{002E}",
+		&["\u{003A}", "\u{002E}"],
+		"  ÷ [0.2] COLON (MidLetter) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0308}\u{002E}",
+		&["\u{003A}\u{0308}", "\u{002E}"],
+		"  ÷ [0.2] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0030}",
+		&["\u{003A}", "\u{0030}"],
+		"  ÷ [0.2] COLON (MidLetter) ÷ [999.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0308}\u{0030}",
+		&["\u{003A}\u{0308}", "\u{0030}"],
+		"  ÷ [0.2] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{005F}",
+		&["\u{003A}", "\u{005F}"],
+		"  ÷ [0.2] COLON (MidLetter) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0308}\u{005F}",
+		&["\u{003A}\u{0308}", "\u{005F}"],
+		"  ÷ [0.2] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{1F1E6}",
+		&["\u{003A}", "\u{1F1E6}"],
+		"  ÷ [0.2] COLON (MidLetter) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0308}\u{1F1E6}",
+		&["\u{003A}\u{0308}", "\u{1F1E6}"],
+		"  ÷ [0.2] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{05D0}",
+		&["\u{003A}", "\u{05D0}"],
+		"  ÷ [0.2] COLON (MidLetter) ÷ [999.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0308}\u{05D0}",
+		&["\u{003A}\u{0308}", "\u{05D0}"],
+		"  ÷ [0.2] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0022}",
+		&["\u{003A}", "\u{0022}"],
+		"  ÷ [0.2] COLON (MidLetter) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0308}\u{0022}",
+		&["\u{003A}\u{0308}", "\u{0022}"],
+		"  ÷ [0.2] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0027}",
+		&["\u{003A}", "\u{0027}"],
+		"  ÷ [0.2] COLON (MidLetter) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0308}\u{0027}",
+		&["\u{003A}\u{0308}", "\u{0027}"],
+		"  ÷ [0.2] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{231A}",
+		&["\u{003A}", "\u{231A}"],
+		"  ÷ [0.2] COLON (MidLetter) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0308}\u{231A}",
+		&["\u{003A}\u{0308}", "\u{231A}"],
+		"  ÷ [0.2] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0020}",
+		&["\u{003A}", "\u{0020}"],
+		"  ÷ [0.2] COLON (MidLetter) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0308}\u{0020}",
+		&["\u{003A}\u{0308}", "\u{0020}"],
+		"  ÷ [0.2] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{00AD}",
+		&["\u{003A}\u{00AD}"],
+		"  ÷ [0.2] COLON (MidLetter) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0308}\u{00AD}",
+		&["\u{003A}\u{0308}\u{00AD}"],
+		"  ÷ [0.2] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0300}",
+		&["\u{003A}\u{0300}"],
+		"  ÷ [0.2] COLON (MidLetter) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0308}\u{0300}",
+		&["\u{003A}\u{0308}\u{0300}"],
+		"  ÷ [0.2] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{200D}",
+		&["\u{003A}\u{200D}"],
+		"  ÷ [0.2] COLON (MidLetter) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0308}\u{200D}",
+		&["\u{003A}\u{0308}\u{200D}"],
+		"  ÷ [0.2] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0061}\u{2060}",
+		&["\u{003A}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] COLON (MidLetter) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0308}\u{0061}\u{2060}",
+		&["\u{003A}\u{0308}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0061}\u{003A}",
+		&["\u{003A}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] COLON (MidLetter) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0308}\u{0061}\u{003A}",
+		&["\u{003A}\u{0308}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0061}\u{0027}",
+		&["\u{003A}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] COLON (MidLetter) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0308}\u{0061}\u{0027}",
+		&["\u{003A}\u{0308}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0061}\u{0027}\u{2060}",
+		&["\u{003A}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] COLON (MidLetter) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0308}\u{0061}\u{0027}\u{2060}",
+		&["\u{003A}\u{0308}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0061}\u{002C}",
+		&["\u{003A}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] COLON (MidLetter) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0308}\u{0061}\u{002C}",
+		&["\u{003A}\u{0308}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0031}\u{003A}",
+		&["\u{003A}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] COLON (MidLetter) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0308}\u{0031}\u{003A}",
+		&["\u{003A}\u{0308}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0031}\u{0027}",
+		&["\u{003A}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] COLON (MidLetter) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0308}\u{0031}\u{0027}",
+		&["\u{003A}\u{0308}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0031}\u{002C}",
+		&["\u{003A}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] COLON (MidLetter) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0308}\u{0031}\u{002C}",
+		&["\u{003A}\u{0308}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0031}\u{002E}\u{2060}",
+		&["\u{003A}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] COLON (MidLetter) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{003A}\u{0308}\u{0031}\u{002E}\u{2060}",
+		&["\u{003A}\u{0308}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0001}",
+		&["\u{002C}", "\u{0001}"],
+		"  ÷ [0.2] COMMA (MidNum) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0308}\u{0001}",
+		&["\u{002C}\u{0308}", "\u{0001}"],
+		"  ÷ [0.2] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{000D}",
+		&["\u{002C}", "\u{000D}"],
+		"  ÷ [0.2] COMMA (MidNum) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0308}\u{000D}",
+		&["\u{002C}\u{0308}", "\u{000D}"],
+		"  ÷ [0.2] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{000A}",
+		&["\u{002C}", "\u{000A}"],
+		"  ÷ [0.2] COMMA (MidNum) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0308}\u{000A}",
+		&["\u{002C}\u{0308}", "\u{000A}"],
+		"  ÷ [0.2] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{000B}",
+		&["\u{002C}", "\u{000B}"],
+		"  ÷ [0.2] COMMA (MidNum) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0308}\u{000B}",
+		&["\u{002C}\u{0308}", "\u{000B}"],
+		"  ÷ [0.2] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{3031}",
+		&["\u{002C}", "\u{3031}"],
+		"  ÷ [0.2] COMMA (MidNum) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0308}\u{3031}",
+		&["\u{002C}\u{0308}", "\u{3031}"],
+		"  ÷ [0.2] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0041}",
+		&["\u{002C}", "\u{0041}"],
+		"  ÷ [0.2] COMMA (MidNum) ÷ [999.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0308}\u{0041}",
+		&["\u{002C}\u{0308}", "\u{0041}"],
+		"  ÷ [0.2] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{003A}",
+		&["\u{002C}", "\u{003A}"],
+		"  ÷ [0.2] COMMA (MidNum) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0308}\u{003A}",
+		&["\u{002C}\u{0308}", "\u{003A}"],
+		"  ÷ [0.2] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{002C}",
+		&["\u{002C}", "\u{002C}"],
+		"  ÷ [0.2] COMMA (MidNum) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0308}\u{002C}",
+		&["\u{002C}\u{0308}", "\u{002C}"],
+		"  ÷ [0.2] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{002E}",
+		&["\u{002C}", "\u{002E}"],
+		"  ÷ [0.2] COMMA (MidNum) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0308}\u{002E}",
+		&["\u{002C}\u{0308}", "\u{002E}"],
+		"  ÷ [0.2] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0030}",
+		&["\u{002C}", "\u{0030}"],
+		"  ÷ [0.2] COMMA (MidNum) ÷ [999.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0308}\u{0030}",
+		&["\u{002C}\u{0308}", "\u{0030}"],
+		"  ÷ [0.2] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{005F}",
+		&["\u{002C}", "\u{005F}"],
+		"  ÷ [0.2] COMMA (MidNum) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0308}\u{005F}",
+		&["\u{002C}\u{0308}", "\u{005F}"],
+		"  ÷ [0.2] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{1F1E6}",
+		&["\u{002C}", "\u{1F1E6}"],
+		"  ÷ [0.2] COMMA (MidNum) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0308}\u{1F1E6}",
+		&["\u{002C}\u{0308}", "\u{1F1E6}"],
+		"  ÷ [0.2] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{05D0}",
+		&["\u{002C}", "\u{05D0}"],
+		"  ÷ [0.2] COMMA (MidNum) ÷ [999.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0308}\u{05D0}",
+		&["\u{002C}\u{0308}", "\u{05D0}"],
+		"  ÷ [0.2] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0022}",
+		&["\u{002C}", "\u{0022}"],
+		"  ÷ [0.2] COMMA (MidNum) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0308}\u{0022}",
+		&["\u{002C}\u{0308}", "\u{0022}"],
+		"  ÷ [0.2] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0027}",
+		&["\u{002C}", "\u{0027}"],
+		"  ÷ [0.2] COMMA (MidNum) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0308}\u{0027}",
+		&["\u{002C}\u{0308}", "\u{0027}"],
+		"  ÷ [0.2] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{231A}",
+		&["\u{002C}", "\u{231A}"],
+		"  ÷ [0.2] COMMA (MidNum) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0308}\u{231A}",
+		&["\u{002C}\u{0308}", "\u{231A}"],
+		"  ÷ [0.2] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0020}",
+		&["\u{002C}", "\u{0020}"],
+		"  ÷ [0.2] COMMA (MidNum) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0308}\u{0020}",
+		&["\u{002C}\u{0308}", "\u{0020}"],
+		"  ÷ [0.2] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{00AD}",
+		&["\u{002C}\u{00AD}"],
+		"  ÷ [0.2] COMMA (MidNum) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0308}\u{00AD}",
+		&["\u{002C}\u{0308}\u{00AD}"],
+		"  ÷ [0.2] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0300}",
+		&["\u{002C}\u{0300}"],
+		"  ÷ [0.2] COMMA (MidNum) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0308}\u{0300}",
+		&["\u{002C}\u{0308}\u{0300}"],
+		"  ÷ [0.2] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{200D}",
+		&["\u{002C}\u{200D}"],
+		"  ÷ [0.2] COMMA (MidNum) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0308}\u{200D}",
+		&["\u{002C}\u{0308}\u{200D}"],
+		"  ÷ [0.2] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0061}\u{2060}",
+		&["\u{002C}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] COMMA (MidNum) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0308}\u{0061}\u{2060}",
+		&["\u{002C}\u{0308}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0061}\u{003A}",
+		&["\u{002C}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] COMMA (MidNum) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0308}\u{0061}\u{003A}",
+		&["\u{002C}\u{0308}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0061}\u{0027}",
+		&["\u{002C}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] COMMA (MidNum) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0308}\u{0061}\u{0027}",
+		&["\u{002C}\u{0308}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0061}\u{0027}\u{2060}",
+		&["\u{002C}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] COMMA (MidNum) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0308}\u{0061}\u{0027}\u{2060}",
+		&["\u{002C}\u{0308}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0061}\u{002C}",
+		&["\u{002C}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] COMMA (MidNum) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0308}\u{0061}\u{002C}",
+		&["\u{002C}\u{0308}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0031}\u{003A}",
+		&["\u{002C}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] COMMA (MidNum) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0308}\u{0031}\u{003A}",
+		&["\u{002C}\u{0308}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0031}\u{0027}",
+		&["\u{002C}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] COMMA (MidNum) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0308}\u{0031}\u{0027}",
+		&["\u{002C}\u{0308}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0031}\u{002C}",
+		&["\u{002C}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] COMMA (MidNum) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0308}\u{0031}\u{002C}",
+		&["\u{002C}\u{0308}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0031}\u{002E}\u{2060}",
+		&["\u{002C}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] COMMA (MidNum) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{002C}\u{0308}\u{0031}\u{002E}\u{2060}",
+		&["\u{002C}\u{0308}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0001}",
+		&["\u{002E}", "\u{0001}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0308}\u{0001}",
+		&["\u{002E}\u{0308}", "\u{0001}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{000D}",
+		&["\u{002E}", "\u{000D}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0308}\u{000D}",
+		&["\u{002E}\u{0308}", "\u{000D}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{000A}",
+		&["\u{002E}", "\u{000A}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0308}\u{000A}",
+		&["\u{002E}\u{0308}", "\u{000A}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{000B}",
+		&["\u{002E}", "\u{000B}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0308}\u{000B}",
+		&["\u{002E}\u{0308}", "\u{000B}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{3031}",
+		&["\u{002E}", "\u{3031}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0308}\u{3031}",
+		&["\u{002E}\u{0308}", "\u{3031}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0041}",
+		&["\u{002E}", "\u{0041}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) ÷ [999.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0308}\u{0041}",
+		&["\u{002E}\u{0308}", "\u{0041}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{003A}",
+		&["\u{002E}", "\u{003A}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0308}\u{003A}",
+		&["\u{002E}\u{0308}", "\u{003A}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{002C}",
+		&["\u{002E}", "\u{002C}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0308}\u{002C}",
+		&["\u{002E}\u{0308}", "\u{002C}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{002E}",
+		&["\u{002E}", "\u{002E}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0308}\u{002E}",
+		&["\u{002E}\u{0308}", "\u{002E}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0030}",
+		&["\u{002E}", "\u{0030}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) ÷ [999.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0308}\u{0030}",
+		&["\u{002E}\u{0308}", "\u{0030}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{005F}",
+		&["\u{002E}", "\u{005F}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0308}\u{005F}",
+		&["\u{002E}\u{0308}", "\u{005F}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{1F1E6}",
+		&["\u{002E}", "\u{1F1E6}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0308}\u{1F1E6}",
+		&["\u{002E}\u{0308}", "\u{1F1E6}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{05D0}",
+		&["\u{002E}", "\u{05D0}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) ÷ [999.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0308}\u{05D0}",
+		&["\u{002E}\u{0308}", "\u{05D0}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0022}",
+		&["\u{002E}", "\u{0022}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0308}\u{0022}",
+		&["\u{002E}\u{0308}", "\u{0022}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0027}",
+		&["\u{002E}", "\u{0027}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0308}\u{0027}",
+		&["\u{002E}\u{0308}", "\u{0027}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{231A}",
+		&["\u{002E}", "\u{231A}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0308}\u{231A}",
+		&["\u{002E}\u{0308}", "\u{231A}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0020}",
+		&["\u{002E}", "\u{0020}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0308}\u{0020}",
+		&["\u{002E}\u{0308}", "\u{0020}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{00AD}",
+		&["\u{002E}\u{00AD}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0308}\u{00AD}",
+		&["\u{002E}\u{0308}\u{00AD}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0300}",
+		&["\u{002E}\u{0300}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0308}\u{0300}",
+		&["\u{002E}\u{0308}\u{0300}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{200D}",
+		&["\u{002E}\u{200D}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0308}\u{200D}",
+		&["\u{002E}\u{0308}\u{200D}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0061}\u{2060}",
+		&["\u{002E}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0308}\u{0061}\u{2060}",
+		&["\u{002E}\u{0308}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0061}\u{003A}",
+		&["\u{002E}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0308}\u{0061}\u{003A}",
+		&["\u{002E}\u{0308}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0061}\u{0027}",
+		&["\u{002E}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0308}\u{0061}\u{0027}",
+		&["\u{002E}\u{0308}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0061}\u{0027}\u{2060}",
+		&["\u{002E}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0308}\u{0061}\u{0027}\u{2060}",
+		&["\u{002E}\u{0308}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0061}\u{002C}",
+		&["\u{002E}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0308}\u{0061}\u{002C}",
+		&["\u{002E}\u{0308}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0031}\u{003A}",
+		&["\u{002E}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0308}\u{0031}\u{003A}",
+		&["\u{002E}\u{0308}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0031}\u{0027}",
+		&["\u{002E}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0308}\u{0031}\u{0027}",
+		&["\u{002E}\u{0308}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0031}\u{002C}",
+		&["\u{002E}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0308}\u{0031}\u{002C}",
+		&["\u{002E}\u{0308}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0031}\u{002E}\u{2060}",
+		&["\u{002E}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{002E}\u{0308}\u{0031}\u{002E}\u{2060}",
+		&["\u{002E}\u{0308}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] FULL STOP (MidNumLet) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0001}",
+		&["\u{0030}", "\u{0001}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0308}\u{0001}",
+		&["\u{0030}\u{0308}", "\u{0001}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{000D}",
+		&["\u{0030}", "\u{000D}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0308}\u{000D}",
+		&["\u{0030}\u{0308}", "\u{000D}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{000A}",
+		&["\u{0030}", "\u{000A}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0308}\u{000A}",
+		&["\u{0030}\u{0308}", "\u{000A}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{000B}",
+		&["\u{0030}", "\u{000B}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0308}\u{000B}",
+		&["\u{0030}\u{0308}", "\u{000B}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{3031}",
+		&["\u{0030}", "\u{3031}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0308}\u{3031}",
+		&["\u{0030}\u{0308}", "\u{3031}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0041}",
+		&["\u{0030}\u{0041}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [10.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0308}\u{0041}",
+		&["\u{0030}\u{0308}\u{0041}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [4.0] COMBINING DIAERESIS (Extend) × [10.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{003A}",
+		&["\u{0030}", "\u{003A}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0308}\u{003A}",
+		&["\u{0030}\u{0308}", "\u{003A}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{002C}",
+		&["\u{0030}", "\u{002C}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0308}\u{002C}",
+		&["\u{0030}\u{0308}", "\u{002C}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{002E}",
+		&["\u{0030}", "\u{002E}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0308}\u{002E}",
+		&["\u{0030}\u{0308}", "\u{002E}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0030}",
+		&["\u{0030}\u{0030}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [8.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0308}\u{0030}",
+		&["\u{0030}\u{0308}\u{0030}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [4.0] COMBINING DIAERESIS (Extend) × [8.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{005F}",
+		&["\u{0030}\u{005F}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [13.1] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0308}\u{005F}",
+		&["\u{0030}\u{0308}\u{005F}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [4.0] COMBINING DIAERESIS (Extend) × [13.1] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{1F1E6}",
+		&["\u{0030}", "\u{1F1E6}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0308}\u{1F1E6}",
+		&["\u{0030}\u{0308}", "\u{1F1E6}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{05D0}",
+		&["\u{0030}\u{05D0}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [10.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0308}\u{05D0}",
+		&["\u{0030}\u{0308}\u{05D0}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [4.0] COMBINING DIAERESIS (Extend) × [10.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0022}",
+		&["\u{0030}", "\u{0022}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0308}\u{0022}",
+		&["\u{0030}\u{0308}", "\u{0022}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0027}",
+		&["\u{0030}", "\u{0027}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0308}\u{0027}",
+		&["\u{0030}\u{0308}", "\u{0027}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{231A}",
+		&["\u{0030}", "\u{231A}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0308}\u{231A}",
+		&["\u{0030}\u{0308}", "\u{231A}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0020}",
+		&["\u{0030}", "\u{0020}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0308}\u{0020}",
+		&["\u{0030}\u{0308}", "\u{0020}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{00AD}",
+		&["\u{0030}\u{00AD}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0308}\u{00AD}",
+		&["\u{0030}\u{0308}\u{00AD}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0300}",
+		&["\u{0030}\u{0300}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0308}\u{0300}",
+		&["\u{0030}\u{0308}\u{0300}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{200D}",
+		&["\u{0030}\u{200D}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0308}\u{200D}",
+		&["\u{0030}\u{0308}\u{200D}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0061}\u{2060}",
+		&["\u{0030}\u{0061}\u{2060}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [10.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0308}\u{0061}\u{2060}",
+		&["\u{0030}\u{0308}\u{0061}\u{2060}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [4.0] COMBINING DIAERESIS (Extend) × [10.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0061}\u{003A}",
+		&["\u{0030}\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [10.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0308}\u{0061}\u{003A}",
+		&["\u{0030}\u{0308}\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [4.0] COMBINING DIAERESIS (Extend) × [10.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0061}\u{0027}",
+		&["\u{0030}\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [10.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0308}\u{0061}\u{0027}",
+		&["\u{0030}\u{0308}\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [4.0] COMBINING DIAERESIS (Extend) × [10.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0061}\u{0027}\u{2060}",
+		&["\u{0030}\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [10.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0308}\u{0061}\u{0027}\u{2060}",
+		&["\u{0030}\u{0308}\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [4.0] COMBINING DIAERESIS (Extend) × [10.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0061}\u{002C}",
+		&["\u{0030}\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [10.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0308}\u{0061}\u{002C}",
+		&["\u{0030}\u{0308}\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [4.0] COMBINING DIAERESIS (Extend) × [10.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0031}\u{003A}",
+		&["\u{0030}\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [8.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0308}\u{0031}\u{003A}",
+		&["\u{0030}\u{0308}\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [4.0] COMBINING DIAERESIS (Extend) × [8.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0031}\u{0027}",
+		&["\u{0030}\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [8.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0308}\u{0031}\u{0027}",
+		&["\u{0030}\u{0308}\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [4.0] COMBINING DIAERESIS (Extend) × [8.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0031}\u{002C}",
+		&["\u{0030}\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [8.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0308}\u{0031}\u{002C}",
+		&["\u{0030}\u{0308}\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [4.0] COMBINING DIAERESIS (Extend) × [8.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0031}\u{002E}\u{2060}",
+		&["\u{0030}\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [8.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{0308}\u{0031}\u{002E}\u{2060}",
+		&["\u{0030}\u{0308}\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [4.0] COMBINING DIAERESIS (Extend) × [8.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0001}",
+		&["\u{005F}", "\u{0001}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0308}\u{0001}",
+		&["\u{005F}\u{0308}", "\u{0001}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{000D}",
+		&["\u{005F}", "\u{000D}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0308}\u{000D}",
+		&["\u{005F}\u{0308}", "\u{000D}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{000A}",
+		&["\u{005F}", "\u{000A}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0308}\u{000A}",
+		&["\u{005F}\u{0308}", "\u{000A}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{000B}",
+		&["\u{005F}", "\u{000B}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0308}\u{000B}",
+		&["\u{005F}\u{0308}", "\u{000B}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{3031}",
+		&["\u{005F}\u{3031}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [13.2] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0308}\u{3031}",
+		&["\u{005F}\u{0308}\u{3031}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [4.0] COMBINING DIAERESIS (Extend) × [13.2] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0041}",
+		&["\u{005F}\u{0041}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [13.2] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0308}\u{0041}",
+		&["\u{005F}\u{0308}\u{0041}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [4.0] COMBINING DIAERESIS (Extend) × [13.2] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{003A}",
+		&["\u{005F}", "\u{003A}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0308}\u{003A}",
+		&["\u{005F}\u{0308}", "\u{003A}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{002C}",
+		&["\u{005F}", "\u{002C}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0308}\u{002C}",
+		&["\u{005F}\u{0308}", "\u{002C}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{002E}",
+		&["\u{005F}", "\u{002E}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0308}\u{002E}",
+		&["\u{005F}\u{0308}", "\u{002E}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0030}",
+		&["\u{005F}\u{0030}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [13.2] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0308}\u{0030}",
+		&["\u{005F}\u{0308}\u{0030}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [4.0] COMBINING DIAERESIS (Extend) × [13.2] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{005F}",
+		&["\u{005F}\u{005F}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [13.1] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0308}\u{005F}",
+		&["\u{005F}\u{0308}\u{005F}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [4.0] COMBINING DIAERESIS (Extend) × [13.1] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{1F1E6}",
+		&["\u{005F}", "\u{1F1E6}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0308}\u{1F1E6}",
+		&["\u{005F}\u{0308}", "\u{1F1E6}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{05D0}",
+		&["\u{005F}\u{05D0}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [13.2] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0308}\u{05D0}",
+		&["\u{005F}\u{0308}\u{05D0}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [4.0] COMBINING DIAERESIS (Extend) × [13.2] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0022}",
+		&["\u{005F}", "\u{0022}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0308}\u{0022}",
+		&["\u{005F}\u{0308}", "\u{0022}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0027}",
+		&["\u{005F}", "\u{0027}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0308}\u{0027}",
+		&["\u{005F}\u{0308}", "\u{0027}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{231A}",
+		&["\u{005F}", "\u{231A}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0308}\u{231A}",
+		&["\u{005F}\u{0308}", "\u{231A}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0020}",
+		&["\u{005F}", "\u{0020}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0308}\u{0020}",
+		&["\u{005F}\u{0308}", "\u{0020}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{00AD}",
+		&["\u{005F}\u{00AD}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0308}\u{00AD}",
+		&["\u{005F}\u{0308}\u{00AD}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0300}",
+		&["\u{005F}\u{0300}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0308}\u{0300}",
+		&["\u{005F}\u{0308}\u{0300}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{200D}",
+		&["\u{005F}\u{200D}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0308}\u{200D}",
+		&["\u{005F}\u{0308}\u{200D}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0061}\u{2060}",
+		&["\u{005F}\u{0061}\u{2060}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0308}\u{0061}\u{2060}",
+		&["\u{005F}\u{0308}\u{0061}\u{2060}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [4.0] COMBINING DIAERESIS (Extend) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0061}\u{003A}",
+		&["\u{005F}\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0308}\u{0061}\u{003A}",
+		&["\u{005F}\u{0308}\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [4.0] COMBINING DIAERESIS (Extend) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0061}\u{0027}",
+		&["\u{005F}\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0308}\u{0061}\u{0027}",
+		&["\u{005F}\u{0308}\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [4.0] COMBINING DIAERESIS (Extend) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0061}\u{0027}\u{2060}",
+		&["\u{005F}\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0308}\u{0061}\u{0027}\u{2060}",
+		&["\u{005F}\u{0308}\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [4.0] COMBINING DIAERESIS (Extend) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0061}\u{002C}",
+		&["\u{005F}\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0308}\u{0061}\u{002C}",
+		&["\u{005F}\u{0308}\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [4.0] COMBINING DIAERESIS (Extend) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0031}\u{003A}",
+		&["\u{005F}\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0308}\u{0031}\u{003A}",
+		&["\u{005F}\u{0308}\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [4.0] COMBINING DIAERESIS (Extend) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0031}\u{0027}",
+		&["\u{005F}\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0308}\u{0031}\u{0027}",
+		&["\u{005F}\u{0308}\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [4.0] COMBINING DIAERESIS (Extend) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0031}\u{002C}",
+		&["\u{005F}\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0308}\u{0031}\u{002C}",
+		&["\u{005F}\u{0308}\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [4.0] COMBINING DIAERESIS (Extend) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0031}\u{002E}\u{2060}",
+		&["\u{005F}\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{005F}\u{0308}\u{0031}\u{002E}\u{2060}",
+		&["\u{005F}\u{0308}\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] LOW LINE (ExtendNumLet) × [4.0] COMBINING DIAERESIS (Extend) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0001}",
+		&["\u{1F1E6}", "\u{0001}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0308}\u{0001}",
+		&["\u{1F1E6}\u{0308}", "\u{0001}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{000D}",
+		&["\u{1F1E6}", "\u{000D}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0308}\u{000D}",
+		&["\u{1F1E6}\u{0308}", "\u{000D}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{000A}",
+		&["\u{1F1E6}", "\u{000A}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0308}\u{000A}",
+		&["\u{1F1E6}\u{0308}", "\u{000A}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{000B}",
+		&["\u{1F1E6}", "\u{000B}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0308}\u{000B}",
+		&["\u{1F1E6}\u{0308}", "\u{000B}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{3031}",
+		&["\u{1F1E6}", "\u{3031}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0308}\u{3031}",
+		&["\u{1F1E6}\u{0308}", "\u{3031}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0041}",
+		&["\u{1F1E6}", "\u{0041}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [999.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0308}\u{0041}",
+		&["\u{1F1E6}\u{0308}", "\u{0041}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{003A}",
+		&["\u{1F1E6}", "\u{003A}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0308}\u{003A}",
+		&["\u{1F1E6}\u{0308}", "\u{003A}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{002C}",
+		&["\u{1F1E6}", "\u{002C}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0308}\u{002C}",
+		&["\u{1F1E6}\u{0308}", "\u{002C}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{002E}",
+		&["\u{1F1E6}", "\u{002E}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0308}\u{002E}",
+		&["\u{1F1E6}\u{0308}", "\u{002E}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0030}",
+		&["\u{1F1E6}", "\u{0030}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [999.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0308}\u{0030}",
+		&["\u{1F1E6}\u{0308}", "\u{0030}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{005F}",
+		&["\u{1F1E6}", "\u{005F}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0308}\u{005F}",
+		&["\u{1F1E6}\u{0308}", "\u{005F}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{1F1E6}",
+		&["\u{1F1E6}\u{1F1E6}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [15.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0308}\u{1F1E6}",
+		&["\u{1F1E6}\u{0308}\u{1F1E6}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [4.0] COMBINING DIAERESIS (Extend) × [15.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{05D0}",
+		&["\u{1F1E6}", "\u{05D0}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [999.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0308}\u{05D0}",
+		&["\u{1F1E6}\u{0308}", "\u{05D0}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0022}",
+		&["\u{1F1E6}", "\u{0022}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0308}\u{0022}",
+		&["\u{1F1E6}\u{0308}", "\u{0022}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0027}",
+		&["\u{1F1E6}", "\u{0027}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0308}\u{0027}",
+		&["\u{1F1E6}\u{0308}", "\u{0027}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{231A}",
+		&["\u{1F1E6}", "\u{231A}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0308}\u{231A}",
+		&["\u{1F1E6}\u{0308}", "\u{231A}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0020}",
+		&["\u{1F1E6}", "\u{0020}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0308}\u{0020}",
+		&["\u{1F1E6}\u{0308}", "\u{0020}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{00AD}",
+		&["\u{1F1E6}\u{00AD}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0308}\u{00AD}",
+		&["\u{1F1E6}\u{0308}\u{00AD}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0300}",
+		&["\u{1F1E6}\u{0300}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0308}\u{0300}",
+		&["\u{1F1E6}\u{0308}\u{0300}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{200D}",
+		&["\u{1F1E6}\u{200D}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0308}\u{200D}",
+		&["\u{1F1E6}\u{0308}\u{200D}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0061}\u{2060}",
+		&["\u{1F1E6}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0308}\u{0061}\u{2060}",
+		&["\u{1F1E6}\u{0308}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0061}\u{003A}",
+		&["\u{1F1E6}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0308}\u{0061}\u{003A}",
+		&["\u{1F1E6}\u{0308}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0061}\u{0027}",
+		&["\u{1F1E6}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0308}\u{0061}\u{0027}",
+		&["\u{1F1E6}\u{0308}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0061}\u{0027}\u{2060}",
+		&["\u{1F1E6}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0308}\u{0061}\u{0027}\u{2060}",
+		&["\u{1F1E6}\u{0308}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0061}\u{002C}",
+		&["\u{1F1E6}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0308}\u{0061}\u{002C}",
+		&["\u{1F1E6}\u{0308}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0031}\u{003A}",
+		&["\u{1F1E6}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0308}\u{0031}\u{003A}",
+		&["\u{1F1E6}\u{0308}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0031}\u{0027}",
+		&["\u{1F1E6}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0308}\u{0031}\u{0027}",
+		&["\u{1F1E6}\u{0308}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0031}\u{002C}",
+		&["\u{1F1E6}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0308}\u{0031}\u{002C}",
+		&["\u{1F1E6}\u{0308}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0031}\u{002E}\u{2060}",
+		&["\u{1F1E6}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{0308}\u{0031}\u{002E}\u{2060}",
+		&["\u{1F1E6}\u{0308}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0001}",
+		&["\u{05D0}", "\u{0001}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0308}\u{0001}",
+		&["\u{05D0}\u{0308}", "\u{0001}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{000D}",
+		&["\u{05D0}", "\u{000D}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0308}\u{000D}",
+		&["\u{05D0}\u{0308}", "\u{000D}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{000A}",
+		&["\u{05D0}", "\u{000A}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0308}\u{000A}",
+		&["\u{05D0}\u{0308}", "\u{000A}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{000B}",
+		&["\u{05D0}", "\u{000B}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0308}\u{000B}",
+		&["\u{05D0}\u{0308}", "\u{000B}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{3031}",
+		&["\u{05D0}", "\u{3031}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0308}\u{3031}",
+		&["\u{05D0}\u{0308}", "\u{3031}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0041}",
+		&["\u{05D0}\u{0041}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [5.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0308}\u{0041}",
+		&["\u{05D0}\u{0308}\u{0041}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [4.0] COMBINING DIAERESIS (Extend) × [5.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{003A}",
+		&["\u{05D0}", "\u{003A}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0308}\u{003A}",
+		&["\u{05D0}\u{0308}", "\u{003A}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{002C}",
+		&["\u{05D0}", "\u{002C}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0308}\u{002C}",
+		&["\u{05D0}\u{0308}", "\u{002C}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{002E}",
+		&["\u{05D0}", "\u{002E}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0308}\u{002E}",
+		&["\u{05D0}\u{0308}", "\u{002E}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0030}",
+		&["\u{05D0}\u{0030}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [9.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0308}\u{0030}",
+		&["\u{05D0}\u{0308}\u{0030}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [4.0] COMBINING DIAERESIS (Extend) × [9.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{005F}",
+		&["\u{05D0}\u{005F}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [13.1] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0308}\u{005F}",
+		&["\u{05D0}\u{0308}\u{005F}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [4.0] COMBINING DIAERESIS (Extend) × [13.1] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{1F1E6}",
+		&["\u{05D0}", "\u{1F1E6}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0308}\u{1F1E6}",
+		&["\u{05D0}\u{0308}", "\u{1F1E6}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{05D0}",
+		&["\u{05D0}\u{05D0}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [5.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0308}\u{05D0}",
+		&["\u{05D0}\u{0308}\u{05D0}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [4.0] COMBINING DIAERESIS (Extend) × [5.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0022}",
+		&["\u{05D0}", "\u{0022}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0308}\u{0022}",
+		&["\u{05D0}\u{0308}", "\u{0022}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0027}",
+		&["\u{05D0}\u{0027}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [7.1] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0308}\u{0027}",
+		&["\u{05D0}\u{0308}\u{0027}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [4.0] COMBINING DIAERESIS (Extend) × [7.1] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{231A}",
+		&["\u{05D0}", "\u{231A}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0308}\u{231A}",
+		&["\u{05D0}\u{0308}", "\u{231A}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0020}",
+		&["\u{05D0}", "\u{0020}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0308}\u{0020}",
+		&["\u{05D0}\u{0308}", "\u{0020}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{00AD}",
+		&["\u{05D0}\u{00AD}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0308}\u{00AD}",
+		&["\u{05D0}\u{0308}\u{00AD}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0300}",
+		&["\u{05D0}\u{0300}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0308}\u{0300}",
+		&["\u{05D0}\u{0308}\u{0300}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{200D}",
+		&["\u{05D0}\u{200D}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0308}\u{200D}",
+		&["\u{05D0}\u{0308}\u{200D}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0061}\u{2060}",
+		&["\u{05D0}\u{0061}\u{2060}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [5.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0308}\u{0061}\u{2060}",
+		&["\u{05D0}\u{0308}\u{0061}\u{2060}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [4.0] COMBINING DIAERESIS (Extend) × [5.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0061}\u{003A}",
+		&["\u{05D0}\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [5.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0308}\u{0061}\u{003A}",
+		&["\u{05D0}\u{0308}\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [4.0] COMBINING DIAERESIS (Extend) × [5.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0061}\u{0027}",
+		&["\u{05D0}\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [5.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0308}\u{0061}\u{0027}",
+		&["\u{05D0}\u{0308}\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [4.0] COMBINING DIAERESIS (Extend) × [5.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0061}\u{0027}\u{2060}",
+		&["\u{05D0}\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [5.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0308}\u{0061}\u{0027}\u{2060}",
+		&["\u{05D0}\u{0308}\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [4.0] COMBINING DIAERESIS (Extend) × [5.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0061}\u{002C}",
+		&["\u{05D0}\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [5.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0308}\u{0061}\u{002C}",
+		&["\u{05D0}\u{0308}\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [4.0] COMBINING DIAERESIS (Extend) × [5.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0031}\u{003A}",
+		&["\u{05D0}\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [9.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0308}\u{0031}\u{003A}",
+		&["\u{05D0}\u{0308}\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [4.0] COMBINING DIAERESIS (Extend) × [9.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0031}\u{0027}",
+		&["\u{05D0}\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [9.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0308}\u{0031}\u{0027}",
+		&["\u{05D0}\u{0308}\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [4.0] COMBINING DIAERESIS (Extend) × [9.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0031}\u{002C}",
+		&["\u{05D0}\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [9.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0308}\u{0031}\u{002C}",
+		&["\u{05D0}\u{0308}\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [4.0] COMBINING DIAERESIS (Extend) × [9.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0031}\u{002E}\u{2060}",
+		&["\u{05D0}\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [9.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0308}\u{0031}\u{002E}\u{2060}",
+		&["\u{05D0}\u{0308}\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [4.0] COMBINING DIAERESIS (Extend) × [9.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0001}",
+		&["\u{0022}", "\u{0001}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0308}\u{0001}",
+		&["\u{0022}\u{0308}", "\u{0001}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{000D}",
+		&["\u{0022}", "\u{000D}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0308}\u{000D}",
+		&["\u{0022}\u{0308}", "\u{000D}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{000A}",
+		&["\u{0022}", "\u{000A}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0308}\u{000A}",
+		&["\u{0022}\u{0308}", "\u{000A}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{000B}",
+		&["\u{0022}", "\u{000B}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0308}\u{000B}",
+		&["\u{0022}\u{0308}", "\u{000B}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{3031}",
+		&["\u{0022}", "\u{3031}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0308}\u{3031}",
+		&["\u{0022}\u{0308}", "\u{3031}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0041}",
+		&["\u{0022}", "\u{0041}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) ÷ [999.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0308}\u{0041}",
+		&["\u{0022}\u{0308}", "\u{0041}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{003A}",
+		&["\u{0022}", "\u{003A}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0308}\u{003A}",
+		&["\u{0022}\u{0308}", "\u{003A}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{002C}",
+		&["\u{0022}", "\u{002C}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0308}\u{002C}",
+		&["\u{0022}\u{0308}", "\u{002C}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{002E}",
+		&["\u{0022}", "\u{002E}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0308}\u{002E}",
+		&["\u{0022}\u{0308}", "\u{002E}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0030}",
+		&["\u{0022}", "\u{0030}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) ÷ [999.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0308}\u{0030}",
+		&["\u{0022}\u{0308}", "\u{0030}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{005F}",
+		&["\u{0022}", "\u{005F}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0308}\u{005F}",
+		&["\u{0022}\u{0308}", "\u{005F}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{1F1E6}",
+		&["\u{0022}", "\u{1F1E6}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0308}\u{1F1E6}",
+		&["\u{0022}\u{0308}", "\u{1F1E6}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{05D0}",
+		&["\u{0022}", "\u{05D0}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) ÷ [999.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0308}\u{05D0}",
+		&["\u{0022}\u{0308}", "\u{05D0}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0022}",
+		&["\u{0022}", "\u{0022}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0308}\u{0022}",
+		&["\u{0022}\u{0308}", "\u{0022}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0027}",
+		&["\u{0022}", "\u{0027}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0308}\u{0027}",
+		&["\u{0022}\u{0308}", "\u{0027}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{231A}",
+		&["\u{0022}", "\u{231A}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0308}\u{231A}",
+		&["\u{0022}\u{0308}", "\u{231A}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0020}",
+		&["\u{0022}", "\u{0020}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0308}\u{0020}",
+		&["\u{0022}\u{0308}", "\u{0020}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{00AD}",
+		&["\u{0022}\u{00AD}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0308}\u{00AD}",
+		&["\u{0022}\u{0308}\u{00AD}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0300}",
+		&["\u{0022}\u{0300}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0308}\u{0300}",
+		&["\u{0022}\u{0308}\u{0300}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{200D}",
+		&["\u{0022}\u{200D}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0308}\u{200D}",
+		&["\u{0022}\u{0308}\u{200D}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0061}\u{2060}",
+		&["\u{0022}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0308}\u{0061}\u{2060}",
+		&["\u{0022}\u{0308}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0061}\u{003A}",
+		&["\u{0022}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0308}\u{0061}\u{003A}",
+		&["\u{0022}\u{0308}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0061}\u{0027}",
+		&["\u{0022}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0308}\u{0061}\u{0027}",
+		&["\u{0022}\u{0308}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0061}\u{0027}\u{2060}",
+		&["\u{0022}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0308}\u{0061}\u{0027}\u{2060}",
+		&["\u{0022}\u{0308}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0061}\u{002C}",
+		&["\u{0022}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0308}\u{0061}\u{002C}",
+		&["\u{0022}\u{0308}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0031}\u{003A}",
+		&["\u{0022}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0308}\u{0031}\u{003A}",
+		&["\u{0022}\u{0308}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0031}\u{0027}",
+		&["\u{0022}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0308}\u{0031}\u{0027}",
+		&["\u{0022}\u{0308}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0031}\u{002C}",
+		&["\u{0022}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0308}\u{0031}\u{002C}",
+		&["\u{0022}\u{0308}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0031}\u{002E}\u{2060}",
+		&["\u{0022}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0022}\u{0308}\u{0031}\u{002E}\u{2060}",
+		&["\u{0022}\u{0308}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] QUOTATION MARK (Double_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0001}",
+		&["\u{0027}", "\u{0001}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0308}\u{0001}",
+		&["\u{0027}\u{0308}", "\u{0001}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{000D}",
+		&["\u{0027}", "\u{000D}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0308}\u{000D}",
+		&["\u{0027}\u{0308}", "\u{000D}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{000A}",
+		&["\u{0027}", "\u{000A}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0308}\u{000A}",
+		&["\u{0027}\u{0308}", "\u{000A}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{000B}",
+		&["\u{0027}", "\u{000B}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0308}\u{000B}",
+		&["\u{0027}\u{0308}", "\u{000B}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{3031}",
+		&["\u{0027}", "\u{3031}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0308}\u{3031}",
+		&["\u{0027}\u{0308}", "\u{3031}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0041}",
+		&["\u{0027}", "\u{0041}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) ÷ [999.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0308}\u{0041}",
+		&["\u{0027}\u{0308}", "\u{0041}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{003A}",
+		&["\u{0027}", "\u{003A}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0308}\u{003A}",
+		&["\u{0027}\u{0308}", "\u{003A}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{002C}",
+		&["\u{0027}", "\u{002C}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0308}\u{002C}",
+		&["\u{0027}\u{0308}", "\u{002C}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{002E}",
+		&["\u{0027}", "\u{002E}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0308}\u{002E}",
+		&["\u{0027}\u{0308}", "\u{002E}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0030}",
+		&["\u{0027}", "\u{0030}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) ÷ [999.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0308}\u{0030}",
+		&["\u{0027}\u{0308}", "\u{0030}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{005F}",
+		&["\u{0027}", "\u{005F}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0308}\u{005F}",
+		&["\u{0027}\u{0308}", "\u{005F}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{1F1E6}",
+		&["\u{0027}", "\u{1F1E6}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0308}\u{1F1E6}",
+		&["\u{0027}\u{0308}", "\u{1F1E6}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{05D0}",
+		&["\u{0027}", "\u{05D0}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) ÷ [999.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0308}\u{05D0}",
+		&["\u{0027}\u{0308}", "\u{05D0}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0022}",
+		&["\u{0027}", "\u{0022}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0308}\u{0022}",
+		&["\u{0027}\u{0308}", "\u{0022}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0027}",
+		&["\u{0027}", "\u{0027}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0308}\u{0027}",
+		&["\u{0027}\u{0308}", "\u{0027}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{231A}",
+		&["\u{0027}", "\u{231A}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0308}\u{231A}",
+		&["\u{0027}\u{0308}", "\u{231A}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0020}",
+		&["\u{0027}", "\u{0020}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0308}\u{0020}",
+		&["\u{0027}\u{0308}", "\u{0020}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{00AD}",
+		&["\u{0027}\u{00AD}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0308}\u{00AD}",
+		&["\u{0027}\u{0308}\u{00AD}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0300}",
+		&["\u{0027}\u{0300}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0308}\u{0300}",
+		&["\u{0027}\u{0308}\u{0300}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{200D}",
+		&["\u{0027}\u{200D}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0308}\u{200D}",
+		&["\u{0027}\u{0308}\u{200D}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0061}\u{2060}",
+		&["\u{0027}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0308}\u{0061}\u{2060}",
+		&["\u{0027}\u{0308}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0061}\u{003A}",
+		&["\u{0027}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0308}\u{0061}\u{003A}",
+		&["\u{0027}\u{0308}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0061}\u{0027}",
+		&["\u{0027}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0308}\u{0061}\u{0027}",
+		&["\u{0027}\u{0308}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0061}\u{0027}\u{2060}",
+		&["\u{0027}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0308}\u{0061}\u{0027}\u{2060}",
+		&["\u{0027}\u{0308}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0061}\u{002C}",
+		&["\u{0027}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0308}\u{0061}\u{002C}",
+		&["\u{0027}\u{0308}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0031}\u{003A}",
+		&["\u{0027}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0308}\u{0031}\u{003A}",
+		&["\u{0027}\u{0308}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0031}\u{0027}",
+		&["\u{0027}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0308}\u{0031}\u{0027}",
+		&["\u{0027}\u{0308}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0031}\u{002C}",
+		&["\u{0027}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0308}\u{0031}\u{002C}",
+		&["\u{0027}\u{0308}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0031}\u{002E}\u{2060}",
+		&["\u{0027}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0027}\u{0308}\u{0031}\u{002E}\u{2060}",
+		&["\u{0027}\u{0308}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0001}",
+		&["\u{231A}", "\u{0001}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0308}\u{0001}",
+		&["\u{231A}\u{0308}", "\u{0001}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{000D}",
+		&["\u{231A}", "\u{000D}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0308}\u{000D}",
+		&["\u{231A}\u{0308}", "\u{000D}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{000A}",
+		&["\u{231A}", "\u{000A}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0308}\u{000A}",
+		&["\u{231A}\u{0308}", "\u{000A}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{000B}",
+		&["\u{231A}", "\u{000B}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0308}\u{000B}",
+		&["\u{231A}\u{0308}", "\u{000B}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{3031}",
+		&["\u{231A}", "\u{3031}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0308}\u{3031}",
+		&["\u{231A}\u{0308}", "\u{3031}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0041}",
+		&["\u{231A}", "\u{0041}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) ÷ [999.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0308}\u{0041}",
+		&["\u{231A}\u{0308}", "\u{0041}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{003A}",
+		&["\u{231A}", "\u{003A}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0308}\u{003A}",
+		&["\u{231A}\u{0308}", "\u{003A}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{002C}",
+		&["\u{231A}", "\u{002C}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0308}\u{002C}",
+		&["\u{231A}\u{0308}", "\u{002C}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{002E}",
+		&["\u{231A}", "\u{002E}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0308}\u{002E}",
+		&["\u{231A}\u{0308}", "\u{002E}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0030}",
+		&["\u{231A}", "\u{0030}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) ÷ [999.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0308}\u{0030}",
+		&["\u{231A}\u{0308}", "\u{0030}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{005F}",
+		&["\u{231A}", "\u{005F}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0308}\u{005F}",
+		&["\u{231A}\u{0308}", "\u{005F}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{1F1E6}",
+		&["\u{231A}", "\u{1F1E6}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0308}\u{1F1E6}",
+		&["\u{231A}\u{0308}", "\u{1F1E6}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{05D0}",
+		&["\u{231A}", "\u{05D0}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) ÷ [999.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0308}\u{05D0}",
+		&["\u{231A}\u{0308}", "\u{05D0}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0022}",
+		&["\u{231A}", "\u{0022}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0308}\u{0022}",
+		&["\u{231A}\u{0308}", "\u{0022}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0027}",
+		&["\u{231A}", "\u{0027}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0308}\u{0027}",
+		&["\u{231A}\u{0308}", "\u{0027}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{231A}",
+		&["\u{231A}", "\u{231A}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0308}\u{231A}",
+		&["\u{231A}\u{0308}", "\u{231A}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0020}",
+		&["\u{231A}", "\u{0020}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0308}\u{0020}",
+		&["\u{231A}\u{0308}", "\u{0020}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{00AD}",
+		&["\u{231A}\u{00AD}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0308}\u{00AD}",
+		&["\u{231A}\u{0308}\u{00AD}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0300}",
+		&["\u{231A}\u{0300}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0308}\u{0300}",
+		&["\u{231A}\u{0308}\u{0300}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{200D}",
+		&["\u{231A}\u{200D}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0308}\u{200D}",
+		&["\u{231A}\u{0308}\u{200D}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0061}\u{2060}",
+		&["\u{231A}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0308}\u{0061}\u{2060}",
+		&["\u{231A}\u{0308}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0061}\u{003A}",
+		&["\u{231A}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0308}\u{0061}\u{003A}",
+		&["\u{231A}\u{0308}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0061}\u{0027}",
+		&["\u{231A}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0308}\u{0061}\u{0027}",
+		&["\u{231A}\u{0308}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0061}\u{0027}\u{2060}",
+		&["\u{231A}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0308}\u{0061}\u{0027}\u{2060}",
+		&["\u{231A}\u{0308}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0061}\u{002C}",
+		&["\u{231A}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0308}\u{0061}\u{002C}",
+		&["\u{231A}\u{0308}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0031}\u{003A}",
+		&["\u{231A}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0308}\u{0031}\u{003A}",
+		&["\u{231A}\u{0308}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0031}\u{0027}",
+		&["\u{231A}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0308}\u{0031}\u{0027}",
+		&["\u{231A}\u{0308}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0031}\u{002C}",
+		&["\u{231A}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0308}\u{0031}\u{002C}",
+		&["\u{231A}\u{0308}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0031}\u{002E}\u{2060}",
+		&["\u{231A}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{231A}\u{0308}\u{0031}\u{002E}\u{2060}",
+		&["\u{231A}\u{0308}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] WATCH (ExtPictmALetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0001}",
+		&["\u{0020}", "\u{0001}"],
+		"  ÷ [0.2] SPACE (WSegSpace) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0308}\u{0001}",
+		&["\u{0020}\u{0308}", "\u{0001}"],
+		"  ÷ [0.2] SPACE (WSegSpace) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{000D}",
+		&["\u{0020}", "\u{000D}"],
+		"  ÷ [0.2] SPACE (WSegSpace) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0308}\u{000D}",
+		&["\u{0020}\u{0308}", "\u{000D}"],
+		"  ÷ [0.2] SPACE (WSegSpace) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{000A}",
+		&["\u{0020}", "\u{000A}"],
+		"  ÷ [0.2] SPACE (WSegSpace) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0308}\u{000A}",
+		&["\u{0020}\u{0308}", "\u{000A}"],
+		"  ÷ [0.2] SPACE (WSegSpace) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{000B}",
+		&["\u{0020}", "\u{000B}"],
+		"  ÷ [0.2] SPACE (WSegSpace) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0308}\u{000B}",
+		&["\u{0020}\u{0308}", "\u{000B}"],
+		"  ÷ [0.2] SPACE (WSegSpace) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{3031}",
+		&["\u{0020}", "\u{3031}"],
+		"  ÷ [0.2] SPACE (WSegSpace) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0308}\u{3031}",
+		&["\u{0020}\u{0308}", "\u{3031}"],
+		"  ÷ [0.2] SPACE (WSegSpace) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0041}",
+		&["\u{0020}", "\u{0041}"],
+		"  ÷ [0.2] SPACE (WSegSpace) ÷ [999.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0308}\u{0041}",
+		&["\u{0020}\u{0308}", "\u{0041}"],
+		"  ÷ [0.2] SPACE (WSegSpace) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{003A}",
+		&["\u{0020}", "\u{003A}"],
+		"  ÷ [0.2] SPACE (WSegSpace) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0308}\u{003A}",
+		&["\u{0020}\u{0308}", "\u{003A}"],
+		"  ÷ [0.2] SPACE (WSegSpace) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{002C}",
+		&["\u{0020}", "\u{002C}"],
+		"  ÷ [0.2] SPACE (WSegSpace) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0308}\u{002C}",
+		&["\u{0020}\u{0308}", "\u{002C}"],
+		"  ÷ [0.2] SPACE (WSegSpace) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{002E}",
+		&["\u{0020}", "\u{002E}"],
+		"  ÷ [0.2] SPACE (WSegSpace) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0308}\u{002E}",
+		&["\u{0020}\u{0308}", "\u{002E}"],
+		"  ÷ [0.2] SPACE (WSegSpace) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0030}",
+		&["\u{0020}", "\u{0030}"],
+		"  ÷ [0.2] SPACE (WSegSpace) ÷ [999.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0308}\u{0030}",
+		&["\u{0020}\u{0308}", "\u{0030}"],
+		"  ÷ [0.2] SPACE (WSegSpace) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{005F}",
+		&["\u{0020}", "\u{005F}"],
+		"  ÷ [0.2] SPACE (WSegSpace) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0308}\u{005F}",
+		&["\u{0020}\u{0308}", "\u{005F}"],
+		"  ÷ [0.2] SPACE (WSegSpace) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{1F1E6}",
+		&["\u{0020}", "\u{1F1E6}"],
+		"  ÷ [0.2] SPACE (WSegSpace) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0308}\u{1F1E6}",
+		&["\u{0020}\u{0308}", "\u{1F1E6}"],
+		"  ÷ [0.2] SPACE (WSegSpace) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{05D0}",
+		&["\u{0020}", "\u{05D0}"],
+		"  ÷ [0.2] SPACE (WSegSpace) ÷ [999.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0308}\u{05D0}",
+		&["\u{0020}\u{0308}", "\u{05D0}"],
+		"  ÷ [0.2] SPACE (WSegSpace) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0022}",
+		&["\u{0020}", "\u{0022}"],
+		"  ÷ [0.2] SPACE (WSegSpace) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0308}\u{0022}",
+		&["\u{0020}\u{0308}", "\u{0022}"],
+		"  ÷ [0.2] SPACE (WSegSpace) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0027}",
+		&["\u{0020}", "\u{0027}"],
+		"  ÷ [0.2] SPACE (WSegSpace) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0308}\u{0027}",
+		&["\u{0020}\u{0308}", "\u{0027}"],
+		"  ÷ [0.2] SPACE (WSegSpace) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{231A}",
+		&["\u{0020}", "\u{231A}"],
+		"  ÷ [0.2] SPACE (WSegSpace) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0308}\u{231A}",
+		&["\u{0020}\u{0308}", "\u{231A}"],
+		"  ÷ [0.2] SPACE (WSegSpace) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0020}",
+		&["\u{0020}\u{0020}"],
+		"  ÷ [0.2] SPACE (WSegSpace) × [3.4] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0308}\u{0020}",
+		&["\u{0020}\u{0308}", "\u{0020}"],
+		"  ÷ [0.2] SPACE (WSegSpace) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{00AD}",
+		&["\u{0020}\u{00AD}"],
+		"  ÷ [0.2] SPACE (WSegSpace) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0308}\u{00AD}",
+		&["\u{0020}\u{0308}\u{00AD}"],
+		"  ÷ [0.2] SPACE (WSegSpace) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0300}",
+		&["\u{0020}\u{0300}"],
+		"  ÷ [0.2] SPACE (WSegSpace) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0308}\u{0300}",
+		&["\u{0020}\u{0308}\u{0300}"],
+		"  ÷ [0.2] SPACE (WSegSpace) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{200D}",
+		&["\u{0020}\u{200D}"],
+		"  ÷ [0.2] SPACE (WSegSpace) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0308}\u{200D}",
+		&["\u{0020}\u{0308}\u{200D}"],
+		"  ÷ [0.2] SPACE (WSegSpace) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0061}\u{2060}",
+		&["\u{0020}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] SPACE (WSegSpace) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0308}\u{0061}\u{2060}",
+		&["\u{0020}\u{0308}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] SPACE (WSegSpace) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0061}\u{003A}",
+		&["\u{0020}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] SPACE (WSegSpace) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0308}\u{0061}\u{003A}",
+		&["\u{0020}\u{0308}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] SPACE (WSegSpace) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0061}\u{0027}",
+		&["\u{0020}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] SPACE (WSegSpace) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0308}\u{0061}\u{0027}",
+		&["\u{0020}\u{0308}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] SPACE (WSegSpace) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0061}\u{0027}\u{2060}",
+		&["\u{0020}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] SPACE (WSegSpace) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0308}\u{0061}\u{0027}\u{2060}",
+		&["\u{0020}\u{0308}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] SPACE (WSegSpace) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0061}\u{002C}",
+		&["\u{0020}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] SPACE (WSegSpace) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0308}\u{0061}\u{002C}",
+		&["\u{0020}\u{0308}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] SPACE (WSegSpace) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0031}\u{003A}",
+		&["\u{0020}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] SPACE (WSegSpace) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0308}\u{0031}\u{003A}",
+		&["\u{0020}\u{0308}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] SPACE (WSegSpace) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0031}\u{0027}",
+		&["\u{0020}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] SPACE (WSegSpace) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0308}\u{0031}\u{0027}",
+		&["\u{0020}\u{0308}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] SPACE (WSegSpace) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0031}\u{002C}",
+		&["\u{0020}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] SPACE (WSegSpace) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0308}\u{0031}\u{002C}",
+		&["\u{0020}\u{0308}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] SPACE (WSegSpace) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0031}\u{002E}\u{2060}",
+		&["\u{0020}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] SPACE (WSegSpace) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{0308}\u{0031}\u{002E}\u{2060}",
+		&["\u{0020}\u{0308}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] SPACE (WSegSpace) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0001}",
+		&["\u{00AD}", "\u{0001}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0308}\u{0001}",
+		&["\u{00AD}\u{0308}", "\u{0001}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{000D}",
+		&["\u{00AD}", "\u{000D}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0308}\u{000D}",
+		&["\u{00AD}\u{0308}", "\u{000D}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{000A}",
+		&["\u{00AD}", "\u{000A}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0308}\u{000A}",
+		&["\u{00AD}\u{0308}", "\u{000A}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{000B}",
+		&["\u{00AD}", "\u{000B}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0308}\u{000B}",
+		&["\u{00AD}\u{0308}", "\u{000B}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{3031}",
+		&["\u{00AD}", "\u{3031}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0308}\u{3031}",
+		&["\u{00AD}\u{0308}", "\u{3031}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0041}",
+		&["\u{00AD}", "\u{0041}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) ÷ [999.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0308}\u{0041}",
+		&["\u{00AD}\u{0308}", "\u{0041}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{003A}",
+		&["\u{00AD}", "\u{003A}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0308}\u{003A}",
+		&["\u{00AD}\u{0308}", "\u{003A}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{002C}",
+		&["\u{00AD}", "\u{002C}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0308}\u{002C}",
+		&["\u{00AD}\u{0308}", "\u{002C}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{002E}",
+		&["\u{00AD}", "\u{002E}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0308}\u{002E}",
+		&["\u{00AD}\u{0308}", "\u{002E}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0030}",
+		&["\u{00AD}", "\u{0030}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) ÷ [999.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0308}\u{0030}",
+		&["\u{00AD}\u{0308}", "\u{0030}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{005F}",
+		&["\u{00AD}", "\u{005F}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0308}\u{005F}",
+		&["\u{00AD}\u{0308}", "\u{005F}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{1F1E6}",
+		&["\u{00AD}", "\u{1F1E6}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0308}\u{1F1E6}",
+		&["\u{00AD}\u{0308}", "\u{1F1E6}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{05D0}",
+		&["\u{00AD}", "\u{05D0}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) ÷ [999.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0308}\u{05D0}",
+		&["\u{00AD}\u{0308}", "\u{05D0}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0022}",
+		&["\u{00AD}", "\u{0022}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0308}\u{0022}",
+		&["\u{00AD}\u{0308}", "\u{0022}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0027}",
+		&["\u{00AD}", "\u{0027}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0308}\u{0027}",
+		&["\u{00AD}\u{0308}", "\u{0027}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{231A}",
+		&["\u{00AD}", "\u{231A}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0308}\u{231A}",
+		&["\u{00AD}\u{0308}", "\u{231A}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0020}",
+		&["\u{00AD}", "\u{0020}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0308}\u{0020}",
+		&["\u{00AD}\u{0308}", "\u{0020}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{00AD}",
+		&["\u{00AD}\u{00AD}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0308}\u{00AD}",
+		&["\u{00AD}\u{0308}\u{00AD}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0300}",
+		&["\u{00AD}\u{0300}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0308}\u{0300}",
+		&["\u{00AD}\u{0308}\u{0300}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{200D}",
+		&["\u{00AD}\u{200D}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0308}\u{200D}",
+		&["\u{00AD}\u{0308}\u{200D}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0061}\u{2060}",
+		&["\u{00AD}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0308}\u{0061}\u{2060}",
+		&["\u{00AD}\u{0308}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0061}\u{003A}",
+		&["\u{00AD}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0308}\u{0061}\u{003A}",
+		&["\u{00AD}\u{0308}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0061}\u{0027}",
+		&["\u{00AD}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0308}\u{0061}\u{0027}",
+		&["\u{00AD}\u{0308}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0061}\u{0027}\u{2060}",
+		&["\u{00AD}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0308}\u{0061}\u{0027}\u{2060}",
+		&["\u{00AD}\u{0308}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0061}\u{002C}",
+		&["\u{00AD}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0308}\u{0061}\u{002C}",
+		&["\u{00AD}\u{0308}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0031}\u{003A}",
+		&["\u{00AD}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0308}\u{0031}\u{003A}",
+		&["\u{00AD}\u{0308}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0031}\u{0027}",
+		&["\u{00AD}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0308}\u{0031}\u{0027}",
+		&["\u{00AD}\u{0308}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0031}\u{002C}",
+		&["\u{00AD}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0308}\u{0031}\u{002C}",
+		&["\u{00AD}\u{0308}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0031}\u{002E}\u{2060}",
+		&["\u{00AD}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{00AD}\u{0308}\u{0031}\u{002E}\u{2060}",
+		&["\u{00AD}\u{0308}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] SOFT HYPHEN (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0001}",
+		&["\u{0300}", "\u{0001}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0308}\u{0001}",
+		&["\u{0300}\u{0308}", "\u{0001}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{000D}",
+		&["\u{0300}", "\u{000D}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0308}\u{000D}",
+		&["\u{0300}\u{0308}", "\u{000D}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{000A}",
+		&["\u{0300}", "\u{000A}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0308}\u{000A}",
+		&["\u{0300}\u{0308}", "\u{000A}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{000B}",
+		&["\u{0300}", "\u{000B}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0308}\u{000B}",
+		&["\u{0300}\u{0308}", "\u{000B}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{3031}",
+		&["\u{0300}", "\u{3031}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0308}\u{3031}",
+		&["\u{0300}\u{0308}", "\u{3031}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0041}",
+		&["\u{0300}", "\u{0041}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) ÷ [999.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0308}\u{0041}",
+		&["\u{0300}\u{0308}", "\u{0041}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{003A}",
+		&["\u{0300}", "\u{003A}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0308}\u{003A}",
+		&["\u{0300}\u{0308}", "\u{003A}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{002C}",
+		&["\u{0300}", "\u{002C}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0308}\u{002C}",
+		&["\u{0300}\u{0308}", "\u{002C}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{002E}",
+		&["\u{0300}", "\u{002E}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0308}\u{002E}",
+		&["\u{0300}\u{0308}", "\u{002E}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0030}",
+		&["\u{0300}", "\u{0030}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) ÷ [999.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0308}\u{0030}",
+		&["\u{0300}\u{0308}", "\u{0030}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{005F}",
+		&["\u{0300}", "\u{005F}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0308}\u{005F}",
+		&["\u{0300}\u{0308}", "\u{005F}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{1F1E6}",
+		&["\u{0300}", "\u{1F1E6}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0308}\u{1F1E6}",
+		&["\u{0300}\u{0308}", "\u{1F1E6}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{05D0}",
+		&["\u{0300}", "\u{05D0}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) ÷ [999.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0308}\u{05D0}",
+		&["\u{0300}\u{0308}", "\u{05D0}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0022}",
+		&["\u{0300}", "\u{0022}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0308}\u{0022}",
+		&["\u{0300}\u{0308}", "\u{0022}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0027}",
+		&["\u{0300}", "\u{0027}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0308}\u{0027}",
+		&["\u{0300}\u{0308}", "\u{0027}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{231A}",
+		&["\u{0300}", "\u{231A}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0308}\u{231A}",
+		&["\u{0300}\u{0308}", "\u{231A}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0020}",
+		&["\u{0300}", "\u{0020}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0308}\u{0020}",
+		&["\u{0300}\u{0308}", "\u{0020}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{00AD}",
+		&["\u{0300}\u{00AD}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0308}\u{00AD}",
+		&["\u{0300}\u{0308}\u{00AD}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0300}",
+		&["\u{0300}\u{0300}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0308}\u{0300}",
+		&["\u{0300}\u{0308}\u{0300}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{200D}",
+		&["\u{0300}\u{200D}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0308}\u{200D}",
+		&["\u{0300}\u{0308}\u{200D}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0061}\u{2060}",
+		&["\u{0300}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0308}\u{0061}\u{2060}",
+		&["\u{0300}\u{0308}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0061}\u{003A}",
+		&["\u{0300}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0308}\u{0061}\u{003A}",
+		&["\u{0300}\u{0308}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0061}\u{0027}",
+		&["\u{0300}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0308}\u{0061}\u{0027}",
+		&["\u{0300}\u{0308}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0061}\u{0027}\u{2060}",
+		&["\u{0300}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0308}\u{0061}\u{0027}\u{2060}",
+		&["\u{0300}\u{0308}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0061}\u{002C}",
+		&["\u{0300}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0308}\u{0061}\u{002C}",
+		&["\u{0300}\u{0308}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0031}\u{003A}",
+		&["\u{0300}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0308}\u{0031}\u{003A}",
+		&["\u{0300}\u{0308}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0031}\u{0027}",
+		&["\u{0300}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0308}\u{0031}\u{0027}",
+		&["\u{0300}\u{0308}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0031}\u{002C}",
+		&["\u{0300}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0308}\u{0031}\u{002C}",
+		&["\u{0300}\u{0308}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0031}\u{002E}\u{2060}",
+		&["\u{0300}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0300}\u{0308}\u{0031}\u{002E}\u{2060}",
+		&["\u{0300}\u{0308}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] COMBINING GRAVE ACCENT (Extend) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0001}",
+		&["\u{200D}", "\u{0001}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0308}\u{0001}",
+		&["\u{200D}\u{0308}", "\u{0001}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{000D}",
+		&["\u{200D}", "\u{000D}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0308}\u{000D}",
+		&["\u{200D}\u{0308}", "\u{000D}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{000A}",
+		&["\u{200D}", "\u{000A}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0308}\u{000A}",
+		&["\u{200D}\u{0308}", "\u{000A}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{000B}",
+		&["\u{200D}", "\u{000B}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0308}\u{000B}",
+		&["\u{200D}\u{0308}", "\u{000B}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{3031}",
+		&["\u{200D}", "\u{3031}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0308}\u{3031}",
+		&["\u{200D}\u{0308}", "\u{3031}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0041}",
+		&["\u{200D}", "\u{0041}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) ÷ [999.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0308}\u{0041}",
+		&["\u{200D}\u{0308}", "\u{0041}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{003A}",
+		&["\u{200D}", "\u{003A}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0308}\u{003A}",
+		&["\u{200D}\u{0308}", "\u{003A}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{002C}",
+		&["\u{200D}", "\u{002C}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0308}\u{002C}",
+		&["\u{200D}\u{0308}", "\u{002C}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{002E}",
+		&["\u{200D}", "\u{002E}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0308}\u{002E}",
+		&["\u{200D}\u{0308}", "\u{002E}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0030}",
+		&["\u{200D}", "\u{0030}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) ÷ [999.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0308}\u{0030}",
+		&["\u{200D}\u{0308}", "\u{0030}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{005F}",
+		&["\u{200D}", "\u{005F}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0308}\u{005F}",
+		&["\u{200D}\u{0308}", "\u{005F}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{1F1E6}",
+		&["\u{200D}", "\u{1F1E6}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0308}\u{1F1E6}",
+		&["\u{200D}\u{0308}", "\u{1F1E6}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{05D0}",
+		&["\u{200D}", "\u{05D0}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) ÷ [999.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0308}\u{05D0}",
+		&["\u{200D}\u{0308}", "\u{05D0}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0022}",
+		&["\u{200D}", "\u{0022}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0308}\u{0022}",
+		&["\u{200D}\u{0308}", "\u{0022}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0027}",
+		&["\u{200D}", "\u{0027}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0308}\u{0027}",
+		&["\u{200D}\u{0308}", "\u{0027}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{231A}",
+		&["\u{200D}\u{231A}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) × [3.3] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0308}\u{231A}",
+		&["\u{200D}\u{0308}", "\u{231A}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0020}",
+		&["\u{200D}", "\u{0020}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0308}\u{0020}",
+		&["\u{200D}\u{0308}", "\u{0020}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{00AD}",
+		&["\u{200D}\u{00AD}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0308}\u{00AD}",
+		&["\u{200D}\u{0308}\u{00AD}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0300}",
+		&["\u{200D}\u{0300}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0308}\u{0300}",
+		&["\u{200D}\u{0308}\u{0300}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{200D}",
+		&["\u{200D}\u{200D}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0308}\u{200D}",
+		&["\u{200D}\u{0308}\u{200D}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0061}\u{2060}",
+		&["\u{200D}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0308}\u{0061}\u{2060}",
+		&["\u{200D}\u{0308}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0061}\u{003A}",
+		&["\u{200D}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0308}\u{0061}\u{003A}",
+		&["\u{200D}\u{0308}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0061}\u{0027}",
+		&["\u{200D}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0308}\u{0061}\u{0027}",
+		&["\u{200D}\u{0308}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0061}\u{0027}\u{2060}",
+		&["\u{200D}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0308}\u{0061}\u{0027}\u{2060}",
+		&["\u{200D}\u{0308}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0061}\u{002C}",
+		&["\u{200D}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0308}\u{0061}\u{002C}",
+		&["\u{200D}\u{0308}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0031}\u{003A}",
+		&["\u{200D}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0308}\u{0031}\u{003A}",
+		&["\u{200D}\u{0308}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0031}\u{0027}",
+		&["\u{200D}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0308}\u{0031}\u{0027}",
+		&["\u{200D}\u{0308}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0031}\u{002C}",
+		&["\u{200D}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0308}\u{0031}\u{002C}",
+		&["\u{200D}\u{0308}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0031}\u{002E}\u{2060}",
+		&["\u{200D}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{0308}\u{0031}\u{002E}\u{2060}",
+		&["\u{200D}\u{0308}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0001}",
+		&["\u{0061}\u{2060}", "\u{0001}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0308}\u{0001}",
+		&["\u{0061}\u{2060}\u{0308}", "\u{0001}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{000D}",
+		&["\u{0061}\u{2060}", "\u{000D}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0308}\u{000D}",
+		&["\u{0061}\u{2060}\u{0308}", "\u{000D}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{000A}",
+		&["\u{0061}\u{2060}", "\u{000A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0308}\u{000A}",
+		&["\u{0061}\u{2060}\u{0308}", "\u{000A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{000B}",
+		&["\u{0061}\u{2060}", "\u{000B}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0308}\u{000B}",
+		&["\u{0061}\u{2060}\u{0308}", "\u{000B}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{3031}",
+		&["\u{0061}\u{2060}", "\u{3031}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0308}\u{3031}",
+		&["\u{0061}\u{2060}\u{0308}", "\u{3031}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0041}",
+		&["\u{0061}\u{2060}\u{0041}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [5.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0308}\u{0041}",
+		&["\u{0061}\u{2060}\u{0308}\u{0041}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) × [5.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{003A}",
+		&["\u{0061}\u{2060}", "\u{003A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0308}\u{003A}",
+		&["\u{0061}\u{2060}\u{0308}", "\u{003A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{002C}",
+		&["\u{0061}\u{2060}", "\u{002C}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0308}\u{002C}",
+		&["\u{0061}\u{2060}\u{0308}", "\u{002C}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{002E}",
+		&["\u{0061}\u{2060}", "\u{002E}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0308}\u{002E}",
+		&["\u{0061}\u{2060}\u{0308}", "\u{002E}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0030}",
+		&["\u{0061}\u{2060}\u{0030}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [9.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0308}\u{0030}",
+		&["\u{0061}\u{2060}\u{0308}\u{0030}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) × [9.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{005F}",
+		&["\u{0061}\u{2060}\u{005F}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [13.1] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0308}\u{005F}",
+		&["\u{0061}\u{2060}\u{0308}\u{005F}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) × [13.1] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{1F1E6}",
+		&["\u{0061}\u{2060}", "\u{1F1E6}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0308}\u{1F1E6}",
+		&["\u{0061}\u{2060}\u{0308}", "\u{1F1E6}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{05D0}",
+		&["\u{0061}\u{2060}\u{05D0}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [5.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0308}\u{05D0}",
+		&["\u{0061}\u{2060}\u{0308}\u{05D0}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) × [5.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0022}",
+		&["\u{0061}\u{2060}", "\u{0022}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0308}\u{0022}",
+		&["\u{0061}\u{2060}\u{0308}", "\u{0022}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0027}",
+		&["\u{0061}\u{2060}", "\u{0027}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0308}\u{0027}",
+		&["\u{0061}\u{2060}\u{0308}", "\u{0027}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{231A}",
+		&["\u{0061}\u{2060}", "\u{231A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0308}\u{231A}",
+		&["\u{0061}\u{2060}\u{0308}", "\u{231A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0020}",
+		&["\u{0061}\u{2060}", "\u{0020}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0308}\u{0020}",
+		&["\u{0061}\u{2060}\u{0308}", "\u{0020}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{00AD}",
+		&["\u{0061}\u{2060}\u{00AD}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0308}\u{00AD}",
+		&["\u{0061}\u{2060}\u{0308}\u{00AD}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0300}",
+		&["\u{0061}\u{2060}\u{0300}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0308}\u{0300}",
+		&["\u{0061}\u{2060}\u{0308}\u{0300}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{200D}",
+		&["\u{0061}\u{2060}\u{200D}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0308}\u{200D}",
+		&["\u{0061}\u{2060}\u{0308}\u{200D}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0061}\u{2060}",
+		&["\u{0061}\u{2060}\u{0061}\u{2060}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [5.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0308}\u{0061}\u{2060}",
+		&["\u{0061}\u{2060}\u{0308}\u{0061}\u{2060}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) × [5.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0061}\u{003A}",
+		&["\u{0061}\u{2060}\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [5.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0308}\u{0061}\u{003A}",
+		&["\u{0061}\u{2060}\u{0308}\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) × [5.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0061}\u{0027}",
+		&["\u{0061}\u{2060}\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [5.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0308}\u{0061}\u{0027}",
+		&["\u{0061}\u{2060}\u{0308}\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) × [5.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0061}\u{0027}\u{2060}",
+		&["\u{0061}\u{2060}\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [5.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0308}\u{0061}\u{0027}\u{2060}",
+		&["\u{0061}\u{2060}\u{0308}\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) × [5.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0061}\u{002C}",
+		&["\u{0061}\u{2060}\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [5.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0308}\u{0061}\u{002C}",
+		&["\u{0061}\u{2060}\u{0308}\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) × [5.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0031}\u{003A}",
+		&["\u{0061}\u{2060}\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [9.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0308}\u{0031}\u{003A}",
+		&["\u{0061}\u{2060}\u{0308}\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) × [9.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0031}\u{0027}",
+		&["\u{0061}\u{2060}\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [9.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0308}\u{0031}\u{0027}",
+		&["\u{0061}\u{2060}\u{0308}\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) × [9.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0031}\u{002C}",
+		&["\u{0061}\u{2060}\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [9.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0308}\u{0031}\u{002C}",
+		&["\u{0061}\u{2060}\u{0308}\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) × [9.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0031}\u{002E}\u{2060}",
+		&["\u{0061}\u{2060}\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [9.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{2060}\u{0308}\u{0031}\u{002E}\u{2060}",
+		&["\u{0061}\u{2060}\u{0308}\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) × [9.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0001}",
+		&["\u{0061}", "\u{003A}", "\u{0001}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0308}\u{0001}",
+		&["\u{0061}", "\u{003A}\u{0308}", "\u{0001}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{000D}",
+		&["\u{0061}", "\u{003A}", "\u{000D}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0308}\u{000D}",
+		&["\u{0061}", "\u{003A}\u{0308}", "\u{000D}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{000A}",
+		&["\u{0061}", "\u{003A}", "\u{000A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0308}\u{000A}",
+		&["\u{0061}", "\u{003A}\u{0308}", "\u{000A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{000B}",
+		&["\u{0061}", "\u{003A}", "\u{000B}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0308}\u{000B}",
+		&["\u{0061}", "\u{003A}\u{0308}", "\u{000B}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{3031}",
+		&["\u{0061}", "\u{003A}", "\u{3031}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0308}\u{3031}",
+		&["\u{0061}", "\u{003A}\u{0308}", "\u{3031}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0041}",
+		&["\u{0061}\u{003A}\u{0041}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] COLON (MidLetter) × [7.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0308}\u{0041}",
+		&["\u{0061}\u{003A}\u{0308}\u{0041}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) × [7.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{003A}",
+		&["\u{0061}", "\u{003A}", "\u{003A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0308}\u{003A}",
+		&["\u{0061}", "\u{003A}\u{0308}", "\u{003A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{002C}",
+		&["\u{0061}", "\u{003A}", "\u{002C}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0308}\u{002C}",
+		&["\u{0061}", "\u{003A}\u{0308}", "\u{002C}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{002E}",
+		&["\u{0061}", "\u{003A}", "\u{002E}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0308}\u{002E}",
+		&["\u{0061}", "\u{003A}\u{0308}", "\u{002E}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0030}",
+		&["\u{0061}", "\u{003A}", "\u{0030}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [999.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0308}\u{0030}",
+		&["\u{0061}", "\u{003A}\u{0308}", "\u{0030}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{005F}",
+		&["\u{0061}", "\u{003A}", "\u{005F}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0308}\u{005F}",
+		&["\u{0061}", "\u{003A}\u{0308}", "\u{005F}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{1F1E6}",
+		&["\u{0061}", "\u{003A}", "\u{1F1E6}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0308}\u{1F1E6}",
+		&["\u{0061}", "\u{003A}\u{0308}", "\u{1F1E6}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{05D0}",
+		&["\u{0061}\u{003A}\u{05D0}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] COLON (MidLetter) × [7.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0308}\u{05D0}",
+		&["\u{0061}\u{003A}\u{0308}\u{05D0}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) × [7.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0022}",
+		&["\u{0061}", "\u{003A}", "\u{0022}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0308}\u{0022}",
+		&["\u{0061}", "\u{003A}\u{0308}", "\u{0022}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0027}",
+		&["\u{0061}", "\u{003A}", "\u{0027}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0308}\u{0027}",
+		&["\u{0061}", "\u{003A}\u{0308}", "\u{0027}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{231A}",
+		&["\u{0061}", "\u{003A}", "\u{231A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0308}\u{231A}",
+		&["\u{0061}", "\u{003A}\u{0308}", "\u{231A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0020}",
+		&["\u{0061}", "\u{003A}", "\u{0020}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0308}\u{0020}",
+		&["\u{0061}", "\u{003A}\u{0308}", "\u{0020}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{00AD}",
+		&["\u{0061}", "\u{003A}\u{00AD}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0308}\u{00AD}",
+		&["\u{0061}", "\u{003A}\u{0308}\u{00AD}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0300}",
+		&["\u{0061}", "\u{003A}\u{0300}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0308}\u{0300}",
+		&["\u{0061}", "\u{003A}\u{0308}\u{0300}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{200D}",
+		&["\u{0061}", "\u{003A}\u{200D}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0308}\u{200D}",
+		&["\u{0061}", "\u{003A}\u{0308}\u{200D}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0061}\u{2060}",
+		&["\u{0061}\u{003A}\u{0061}\u{2060}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] COLON (MidLetter) × [7.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0308}\u{0061}\u{2060}",
+		&["\u{0061}\u{003A}\u{0308}\u{0061}\u{2060}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) × [7.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0061}\u{003A}",
+		&["\u{0061}\u{003A}\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] COLON (MidLetter) × [7.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0308}\u{0061}\u{003A}",
+		&["\u{0061}\u{003A}\u{0308}\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) × [7.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0061}\u{0027}",
+		&["\u{0061}\u{003A}\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] COLON (MidLetter) × [7.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0308}\u{0061}\u{0027}",
+		&["\u{0061}\u{003A}\u{0308}\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) × [7.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0061}\u{0027}\u{2060}",
+		&["\u{0061}\u{003A}\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] COLON (MidLetter) × [7.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0308}\u{0061}\u{0027}\u{2060}",
+		&["\u{0061}\u{003A}\u{0308}\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) × [7.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0061}\u{002C}",
+		&["\u{0061}\u{003A}\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] COLON (MidLetter) × [7.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0308}\u{0061}\u{002C}",
+		&["\u{0061}\u{003A}\u{0308}\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) × [7.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0031}\u{003A}",
+		&["\u{0061}", "\u{003A}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0308}\u{0031}\u{003A}",
+		&["\u{0061}", "\u{003A}\u{0308}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0031}\u{0027}",
+		&["\u{0061}", "\u{003A}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0308}\u{0031}\u{0027}",
+		&["\u{0061}", "\u{003A}\u{0308}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0031}\u{002C}",
+		&["\u{0061}", "\u{003A}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0308}\u{0031}\u{002C}",
+		&["\u{0061}", "\u{003A}\u{0308}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0031}\u{002E}\u{2060}",
+		&["\u{0061}", "\u{003A}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{0308}\u{0031}\u{002E}\u{2060}",
+		&["\u{0061}", "\u{003A}\u{0308}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0001}",
+		&["\u{0061}", "\u{0027}", "\u{0001}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0308}\u{0001}",
+		&["\u{0061}", "\u{0027}\u{0308}", "\u{0001}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{000D}",
+		&["\u{0061}", "\u{0027}", "\u{000D}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0308}\u{000D}",
+		&["\u{0061}", "\u{0027}\u{0308}", "\u{000D}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{000A}",
+		&["\u{0061}", "\u{0027}", "\u{000A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0308}\u{000A}",
+		&["\u{0061}", "\u{0027}\u{0308}", "\u{000A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{000B}",
+		&["\u{0061}", "\u{0027}", "\u{000B}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0308}\u{000B}",
+		&["\u{0061}", "\u{0027}\u{0308}", "\u{000B}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{3031}",
+		&["\u{0061}", "\u{0027}", "\u{3031}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0308}\u{3031}",
+		&["\u{0061}", "\u{0027}\u{0308}", "\u{3031}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0041}",
+		&["\u{0061}\u{0027}\u{0041}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] APOSTROPHE (Single_Quote) × [7.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0308}\u{0041}",
+		&["\u{0061}\u{0027}\u{0308}\u{0041}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) × [7.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{003A}",
+		&["\u{0061}", "\u{0027}", "\u{003A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0308}\u{003A}",
+		&["\u{0061}", "\u{0027}\u{0308}", "\u{003A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{002C}",
+		&["\u{0061}", "\u{0027}", "\u{002C}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0308}\u{002C}",
+		&["\u{0061}", "\u{0027}\u{0308}", "\u{002C}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{002E}",
+		&["\u{0061}", "\u{0027}", "\u{002E}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0308}\u{002E}",
+		&["\u{0061}", "\u{0027}\u{0308}", "\u{002E}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0030}",
+		&["\u{0061}", "\u{0027}", "\u{0030}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [999.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0308}\u{0030}",
+		&["\u{0061}", "\u{0027}\u{0308}", "\u{0030}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{005F}",
+		&["\u{0061}", "\u{0027}", "\u{005F}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0308}\u{005F}",
+		&["\u{0061}", "\u{0027}\u{0308}", "\u{005F}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{1F1E6}",
+		&["\u{0061}", "\u{0027}", "\u{1F1E6}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0308}\u{1F1E6}",
+		&["\u{0061}", "\u{0027}\u{0308}", "\u{1F1E6}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{05D0}",
+		&["\u{0061}\u{0027}\u{05D0}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] APOSTROPHE (Single_Quote) × [7.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0308}\u{05D0}",
+		&["\u{0061}\u{0027}\u{0308}\u{05D0}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) × [7.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0022}",
+		&["\u{0061}", "\u{0027}", "\u{0022}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0308}\u{0022}",
+		&["\u{0061}", "\u{0027}\u{0308}", "\u{0022}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0027}",
+		&["\u{0061}", "\u{0027}", "\u{0027}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0308}\u{0027}",
+		&["\u{0061}", "\u{0027}\u{0308}", "\u{0027}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{231A}",
+		&["\u{0061}", "\u{0027}", "\u{231A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0308}\u{231A}",
+		&["\u{0061}", "\u{0027}\u{0308}", "\u{231A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0020}",
+		&["\u{0061}", "\u{0027}", "\u{0020}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0308}\u{0020}",
+		&["\u{0061}", "\u{0027}\u{0308}", "\u{0020}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{00AD}",
+		&["\u{0061}", "\u{0027}\u{00AD}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0308}\u{00AD}",
+		&["\u{0061}", "\u{0027}\u{0308}\u{00AD}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0300}",
+		&["\u{0061}", "\u{0027}\u{0300}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0308}\u{0300}",
+		&["\u{0061}", "\u{0027}\u{0308}\u{0300}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{200D}",
+		&["\u{0061}", "\u{0027}\u{200D}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0308}\u{200D}",
+		&["\u{0061}", "\u{0027}\u{0308}\u{200D}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0061}\u{2060}",
+		&["\u{0061}\u{0027}\u{0061}\u{2060}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] APOSTROPHE (Single_Quote) × [7.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0308}\u{0061}\u{2060}",
+		&["\u{0061}\u{0027}\u{0308}\u{0061}\u{2060}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) × [7.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0061}\u{003A}",
+		&["\u{0061}\u{0027}\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] APOSTROPHE (Single_Quote) × [7.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0308}\u{0061}\u{003A}",
+		&["\u{0061}\u{0027}\u{0308}\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) × [7.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0061}\u{0027}",
+		&["\u{0061}\u{0027}\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] APOSTROPHE (Single_Quote) × [7.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0308}\u{0061}\u{0027}",
+		&["\u{0061}\u{0027}\u{0308}\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) × [7.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0061}\u{0027}\u{2060}",
+		&["\u{0061}\u{0027}\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] APOSTROPHE (Single_Quote) × [7.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0308}\u{0061}\u{0027}\u{2060}",
+		&["\u{0061}\u{0027}\u{0308}\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) × [7.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0061}\u{002C}",
+		&["\u{0061}\u{0027}\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] APOSTROPHE (Single_Quote) × [7.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0308}\u{0061}\u{002C}",
+		&["\u{0061}\u{0027}\u{0308}\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) × [7.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0031}\u{003A}",
+		&["\u{0061}", "\u{0027}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0308}\u{0031}\u{003A}",
+		&["\u{0061}", "\u{0027}\u{0308}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0031}\u{0027}",
+		&["\u{0061}", "\u{0027}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0308}\u{0031}\u{0027}",
+		&["\u{0061}", "\u{0027}\u{0308}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0031}\u{002C}",
+		&["\u{0061}", "\u{0027}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0308}\u{0031}\u{002C}",
+		&["\u{0061}", "\u{0027}\u{0308}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0031}\u{002E}\u{2060}",
+		&["\u{0061}", "\u{0027}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{0308}\u{0031}\u{002E}\u{2060}",
+		&["\u{0061}", "\u{0027}\u{0308}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0001}",
+		&["\u{0061}", "\u{0027}\u{2060}", "\u{0001}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0308}\u{0001}",
+		&["\u{0061}", "\u{0027}\u{2060}\u{0308}", "\u{0001}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{000D}",
+		&["\u{0061}", "\u{0027}\u{2060}", "\u{000D}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0308}\u{000D}",
+		&["\u{0061}", "\u{0027}\u{2060}\u{0308}", "\u{000D}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{000A}",
+		&["\u{0061}", "\u{0027}\u{2060}", "\u{000A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0308}\u{000A}",
+		&["\u{0061}", "\u{0027}\u{2060}\u{0308}", "\u{000A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{000B}",
+		&["\u{0061}", "\u{0027}\u{2060}", "\u{000B}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0308}\u{000B}",
+		&["\u{0061}", "\u{0027}\u{2060}\u{0308}", "\u{000B}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{3031}",
+		&["\u{0061}", "\u{0027}\u{2060}", "\u{3031}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0308}\u{3031}",
+		&["\u{0061}", "\u{0027}\u{2060}\u{0308}", "\u{3031}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0041}",
+		&["\u{0061}\u{0027}\u{2060}\u{0041}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) × [7.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0308}\u{0041}",
+		&["\u{0061}\u{0027}\u{2060}\u{0308}\u{0041}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) × [7.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{003A}",
+		&["\u{0061}", "\u{0027}\u{2060}", "\u{003A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0308}\u{003A}",
+		&["\u{0061}", "\u{0027}\u{2060}\u{0308}", "\u{003A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{002C}",
+		&["\u{0061}", "\u{0027}\u{2060}", "\u{002C}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0308}\u{002C}",
+		&["\u{0061}", "\u{0027}\u{2060}\u{0308}", "\u{002C}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{002E}",
+		&["\u{0061}", "\u{0027}\u{2060}", "\u{002E}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0308}\u{002E}",
+		&["\u{0061}", "\u{0027}\u{2060}\u{0308}", "\u{002E}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0030}",
+		&["\u{0061}", "\u{0027}\u{2060}", "\u{0030}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [999.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0308}\u{0030}",
+		&["\u{0061}", "\u{0027}\u{2060}\u{0308}", "\u{0030}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{005F}",
+		&["\u{0061}", "\u{0027}\u{2060}", "\u{005F}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0308}\u{005F}",
+		&["\u{0061}", "\u{0027}\u{2060}\u{0308}", "\u{005F}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{1F1E6}",
+		&["\u{0061}", "\u{0027}\u{2060}", "\u{1F1E6}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0308}\u{1F1E6}",
+		&["\u{0061}", "\u{0027}\u{2060}\u{0308}", "\u{1F1E6}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{05D0}",
+		&["\u{0061}\u{0027}\u{2060}\u{05D0}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) × [7.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0308}\u{05D0}",
+		&["\u{0061}\u{0027}\u{2060}\u{0308}\u{05D0}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) × [7.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0022}",
+		&["\u{0061}", "\u{0027}\u{2060}", "\u{0022}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0308}\u{0022}",
+		&["\u{0061}", "\u{0027}\u{2060}\u{0308}", "\u{0022}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0027}",
+		&["\u{0061}", "\u{0027}\u{2060}", "\u{0027}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0308}\u{0027}",
+		&["\u{0061}", "\u{0027}\u{2060}\u{0308}", "\u{0027}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{231A}",
+		&["\u{0061}", "\u{0027}\u{2060}", "\u{231A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0308}\u{231A}",
+		&["\u{0061}", "\u{0027}\u{2060}\u{0308}", "\u{231A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0020}",
+		&["\u{0061}", "\u{0027}\u{2060}", "\u{0020}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0308}\u{0020}",
+		&["\u{0061}", "\u{0027}\u{2060}\u{0308}", "\u{0020}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{00AD}",
+		&["\u{0061}", "\u{0027}\u{2060}\u{00AD}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0308}\u{00AD}",
+		&["\u{0061}", "\u{0027}\u{2060}\u{0308}\u{00AD}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0300}",
+		&["\u{0061}", "\u{0027}\u{2060}\u{0300}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0308}\u{0300}",
+		&["\u{0061}", "\u{0027}\u{2060}\u{0308}\u{0300}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{200D}",
+		&["\u{0061}", "\u{0027}\u{2060}\u{200D}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0308}\u{200D}",
+		&["\u{0061}", "\u{0027}\u{2060}\u{0308}\u{200D}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0061}\u{2060}",
+		&["\u{0061}\u{0027}\u{2060}\u{0061}\u{2060}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) × [7.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0308}\u{0061}\u{2060}",
+		&["\u{0061}\u{0027}\u{2060}\u{0308}\u{0061}\u{2060}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) × [7.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0061}\u{003A}",
+		&["\u{0061}\u{0027}\u{2060}\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) × [7.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0308}\u{0061}\u{003A}",
+		&["\u{0061}\u{0027}\u{2060}\u{0308}\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) × [7.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0061}\u{0027}",
+		&["\u{0061}\u{0027}\u{2060}\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) × [7.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0308}\u{0061}\u{0027}",
+		&["\u{0061}\u{0027}\u{2060}\u{0308}\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) × [7.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0061}\u{0027}\u{2060}",
+		&["\u{0061}\u{0027}\u{2060}\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) × [7.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0308}\u{0061}\u{0027}\u{2060}",
+		&["\u{0061}\u{0027}\u{2060}\u{0308}\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) × [7.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0061}\u{002C}",
+		&["\u{0061}\u{0027}\u{2060}\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) × [7.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0308}\u{0061}\u{002C}",
+		&["\u{0061}\u{0027}\u{2060}\u{0308}\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [6.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) × [7.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0031}\u{003A}",
+		&["\u{0061}", "\u{0027}\u{2060}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0308}\u{0031}\u{003A}",
+		&["\u{0061}", "\u{0027}\u{2060}\u{0308}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0031}\u{0027}",
+		&["\u{0061}", "\u{0027}\u{2060}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0308}\u{0031}\u{0027}",
+		&["\u{0061}", "\u{0027}\u{2060}\u{0308}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0031}\u{002C}",
+		&["\u{0061}", "\u{0027}\u{2060}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0308}\u{0031}\u{002C}",
+		&["\u{0061}", "\u{0027}\u{2060}\u{0308}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0031}\u{002E}\u{2060}",
+		&["\u{0061}", "\u{0027}\u{2060}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0027}\u{2060}\u{0308}\u{0031}\u{002E}\u{2060}",
+		&["\u{0061}", "\u{0027}\u{2060}\u{0308}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0001}",
+		&["\u{0061}", "\u{002C}", "\u{0001}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0308}\u{0001}",
+		&["\u{0061}", "\u{002C}\u{0308}", "\u{0001}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{000D}",
+		&["\u{0061}", "\u{002C}", "\u{000D}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0308}\u{000D}",
+		&["\u{0061}", "\u{002C}\u{0308}", "\u{000D}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{000A}",
+		&["\u{0061}", "\u{002C}", "\u{000A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0308}\u{000A}",
+		&["\u{0061}", "\u{002C}\u{0308}", "\u{000A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{000B}",
+		&["\u{0061}", "\u{002C}", "\u{000B}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0308}\u{000B}",
+		&["\u{0061}", "\u{002C}\u{0308}", "\u{000B}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{3031}",
+		&["\u{0061}", "\u{002C}", "\u{3031}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0308}\u{3031}",
+		&["\u{0061}", "\u{002C}\u{0308}", "\u{3031}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0041}",
+		&["\u{0061}", "\u{002C}", "\u{0041}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0308}\u{0041}",
+		&["\u{0061}", "\u{002C}\u{0308}", "\u{0041}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{003A}",
+		&["\u{0061}", "\u{002C}", "\u{003A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0308}\u{003A}",
+		&["\u{0061}", "\u{002C}\u{0308}", "\u{003A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{002C}",
+		&["\u{0061}", "\u{002C}", "\u{002C}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0308}\u{002C}",
+		&["\u{0061}", "\u{002C}\u{0308}", "\u{002C}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{002E}",
+		&["\u{0061}", "\u{002C}", "\u{002E}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0308}\u{002E}",
+		&["\u{0061}", "\u{002C}\u{0308}", "\u{002E}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0030}",
+		&["\u{0061}", "\u{002C}", "\u{0030}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0308}\u{0030}",
+		&["\u{0061}", "\u{002C}\u{0308}", "\u{0030}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{005F}",
+		&["\u{0061}", "\u{002C}", "\u{005F}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0308}\u{005F}",
+		&["\u{0061}", "\u{002C}\u{0308}", "\u{005F}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{1F1E6}",
+		&["\u{0061}", "\u{002C}", "\u{1F1E6}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0308}\u{1F1E6}",
+		&["\u{0061}", "\u{002C}\u{0308}", "\u{1F1E6}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{05D0}",
+		&["\u{0061}", "\u{002C}", "\u{05D0}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0308}\u{05D0}",
+		&["\u{0061}", "\u{002C}\u{0308}", "\u{05D0}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0022}",
+		&["\u{0061}", "\u{002C}", "\u{0022}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0308}\u{0022}",
+		&["\u{0061}", "\u{002C}\u{0308}", "\u{0022}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0027}",
+		&["\u{0061}", "\u{002C}", "\u{0027}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0308}\u{0027}",
+		&["\u{0061}", "\u{002C}\u{0308}", "\u{0027}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{231A}",
+		&["\u{0061}", "\u{002C}", "\u{231A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0308}\u{231A}",
+		&["\u{0061}", "\u{002C}\u{0308}", "\u{231A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0020}",
+		&["\u{0061}", "\u{002C}", "\u{0020}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0308}\u{0020}",
+		&["\u{0061}", "\u{002C}\u{0308}", "\u{0020}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{00AD}",
+		&["\u{0061}", "\u{002C}\u{00AD}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0308}\u{00AD}",
+		&["\u{0061}", "\u{002C}\u{0308}\u{00AD}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0300}",
+		&["\u{0061}", "\u{002C}\u{0300}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0308}\u{0300}",
+		&["\u{0061}", "\u{002C}\u{0308}\u{0300}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{200D}",
+		&["\u{0061}", "\u{002C}\u{200D}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0308}\u{200D}",
+		&["\u{0061}", "\u{002C}\u{0308}\u{200D}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0061}\u{2060}",
+		&["\u{0061}", "\u{002C}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0308}\u{0061}\u{2060}",
+		&["\u{0061}", "\u{002C}\u{0308}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0061}\u{003A}",
+		&["\u{0061}", "\u{002C}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0308}\u{0061}\u{003A}",
+		&["\u{0061}", "\u{002C}\u{0308}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0061}\u{0027}",
+		&["\u{0061}", "\u{002C}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0308}\u{0061}\u{0027}",
+		&["\u{0061}", "\u{002C}\u{0308}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0061}\u{0027}\u{2060}",
+		&["\u{0061}", "\u{002C}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0308}\u{0061}\u{0027}\u{2060}",
+		&["\u{0061}", "\u{002C}\u{0308}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0061}\u{002C}",
+		&["\u{0061}", "\u{002C}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0308}\u{0061}\u{002C}",
+		&["\u{0061}", "\u{002C}\u{0308}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0031}\u{003A}",
+		&["\u{0061}", "\u{002C}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0308}\u{0031}\u{003A}",
+		&["\u{0061}", "\u{002C}\u{0308}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0031}\u{0027}",
+		&["\u{0061}", "\u{002C}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0308}\u{0031}\u{0027}",
+		&["\u{0061}", "\u{002C}\u{0308}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0031}\u{002C}",
+		&["\u{0061}", "\u{002C}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0308}\u{0031}\u{002C}",
+		&["\u{0061}", "\u{002C}\u{0308}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0031}\u{002E}\u{2060}",
+		&["\u{0061}", "\u{002C}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{0308}\u{0031}\u{002E}\u{2060}",
+		&["\u{0061}", "\u{002C}\u{0308}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0001}",
+		&["\u{0031}", "\u{003A}", "\u{0001}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0308}\u{0001}",
+		&["\u{0031}", "\u{003A}\u{0308}", "\u{0001}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{000D}",
+		&["\u{0031}", "\u{003A}", "\u{000D}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0308}\u{000D}",
+		&["\u{0031}", "\u{003A}\u{0308}", "\u{000D}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{000A}",
+		&["\u{0031}", "\u{003A}", "\u{000A}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0308}\u{000A}",
+		&["\u{0031}", "\u{003A}\u{0308}", "\u{000A}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{000B}",
+		&["\u{0031}", "\u{003A}", "\u{000B}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0308}\u{000B}",
+		&["\u{0031}", "\u{003A}\u{0308}", "\u{000B}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{3031}",
+		&["\u{0031}", "\u{003A}", "\u{3031}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0308}\u{3031}",
+		&["\u{0031}", "\u{003A}\u{0308}", "\u{3031}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0041}",
+		&["\u{0031}", "\u{003A}", "\u{0041}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0308}\u{0041}",
+		&["\u{0031}", "\u{003A}\u{0308}", "\u{0041}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{003A}",
+		&["\u{0031}", "\u{003A}", "\u{003A}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0308}\u{003A}",
+		&["\u{0031}", "\u{003A}\u{0308}", "\u{003A}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{002C}",
+		&["\u{0031}", "\u{003A}", "\u{002C}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0308}\u{002C}",
+		&["\u{0031}", "\u{003A}\u{0308}", "\u{002C}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{002E}",
+		&["\u{0031}", "\u{003A}", "\u{002E}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0308}\u{002E}",
+		&["\u{0031}", "\u{003A}\u{0308}", "\u{002E}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0030}",
+		&["\u{0031}", "\u{003A}", "\u{0030}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0308}\u{0030}",
+		&["\u{0031}", "\u{003A}\u{0308}", "\u{0030}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{005F}",
+		&["\u{0031}", "\u{003A}", "\u{005F}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0308}\u{005F}",
+		&["\u{0031}", "\u{003A}\u{0308}", "\u{005F}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{1F1E6}",
+		&["\u{0031}", "\u{003A}", "\u{1F1E6}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0308}\u{1F1E6}",
+		&["\u{0031}", "\u{003A}\u{0308}", "\u{1F1E6}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{05D0}",
+		&["\u{0031}", "\u{003A}", "\u{05D0}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0308}\u{05D0}",
+		&["\u{0031}", "\u{003A}\u{0308}", "\u{05D0}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0022}",
+		&["\u{0031}", "\u{003A}", "\u{0022}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0308}\u{0022}",
+		&["\u{0031}", "\u{003A}\u{0308}", "\u{0022}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0027}",
+		&["\u{0031}", "\u{003A}", "\u{0027}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0308}\u{0027}",
+		&["\u{0031}", "\u{003A}\u{0308}", "\u{0027}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{231A}",
+		&["\u{0031}", "\u{003A}", "\u{231A}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0308}\u{231A}",
+		&["\u{0031}", "\u{003A}\u{0308}", "\u{231A}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0020}",
+		&["\u{0031}", "\u{003A}", "\u{0020}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0308}\u{0020}",
+		&["\u{0031}", "\u{003A}\u{0308}", "\u{0020}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{00AD}",
+		&["\u{0031}", "\u{003A}\u{00AD}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0308}\u{00AD}",
+		&["\u{0031}", "\u{003A}\u{0308}\u{00AD}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0300}",
+		&["\u{0031}", "\u{003A}\u{0300}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0308}\u{0300}",
+		&["\u{0031}", "\u{003A}\u{0308}\u{0300}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{200D}",
+		&["\u{0031}", "\u{003A}\u{200D}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0308}\u{200D}",
+		&["\u{0031}", "\u{003A}\u{0308}\u{200D}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0061}\u{2060}",
+		&["\u{0031}", "\u{003A}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0308}\u{0061}\u{2060}",
+		&["\u{0031}", "\u{003A}\u{0308}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0061}\u{003A}",
+		&["\u{0031}", "\u{003A}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0308}\u{0061}\u{003A}",
+		&["\u{0031}", "\u{003A}\u{0308}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0061}\u{0027}",
+		&["\u{0031}", "\u{003A}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0308}\u{0061}\u{0027}",
+		&["\u{0031}", "\u{003A}\u{0308}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0061}\u{0027}\u{2060}",
+		&["\u{0031}", "\u{003A}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0308}\u{0061}\u{0027}\u{2060}",
+		&["\u{0031}", "\u{003A}\u{0308}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0061}\u{002C}",
+		&["\u{0031}", "\u{003A}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0308}\u{0061}\u{002C}",
+		&["\u{0031}", "\u{003A}\u{0308}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0031}\u{003A}",
+		&["\u{0031}", "\u{003A}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0308}\u{0031}\u{003A}",
+		&["\u{0031}", "\u{003A}\u{0308}", "\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0031}\u{0027}",
+		&["\u{0031}", "\u{003A}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0308}\u{0031}\u{0027}",
+		&["\u{0031}", "\u{003A}\u{0308}", "\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0031}\u{002C}",
+		&["\u{0031}", "\u{003A}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0308}\u{0031}\u{002C}",
+		&["\u{0031}", "\u{003A}\u{0308}", "\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0031}\u{002E}\u{2060}",
+		&["\u{0031}", "\u{003A}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{0308}\u{0031}\u{002E}\u{2060}",
+		&["\u{0031}", "\u{003A}\u{0308}", "\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0001}",
+		&["\u{0031}", "\u{0027}", "\u{0001}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0308}\u{0001}",
+		&["\u{0031}", "\u{0027}\u{0308}", "\u{0001}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{000D}",
+		&["\u{0031}", "\u{0027}", "\u{000D}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0308}\u{000D}",
+		&["\u{0031}", "\u{0027}\u{0308}", "\u{000D}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{000A}",
+		&["\u{0031}", "\u{0027}", "\u{000A}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0308}\u{000A}",
+		&["\u{0031}", "\u{0027}\u{0308}", "\u{000A}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{000B}",
+		&["\u{0031}", "\u{0027}", "\u{000B}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0308}\u{000B}",
+		&["\u{0031}", "\u{0027}\u{0308}", "\u{000B}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{3031}",
+		&["\u{0031}", "\u{0027}", "\u{3031}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0308}\u{3031}",
+		&["\u{0031}", "\u{0027}\u{0308}", "\u{3031}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0041}",
+		&["\u{0031}", "\u{0027}", "\u{0041}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [999.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0308}\u{0041}",
+		&["\u{0031}", "\u{0027}\u{0308}", "\u{0041}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{003A}",
+		&["\u{0031}", "\u{0027}", "\u{003A}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0308}\u{003A}",
+		&["\u{0031}", "\u{0027}\u{0308}", "\u{003A}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{002C}",
+		&["\u{0031}", "\u{0027}", "\u{002C}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0308}\u{002C}",
+		&["\u{0031}", "\u{0027}\u{0308}", "\u{002C}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{002E}",
+		&["\u{0031}", "\u{0027}", "\u{002E}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0308}\u{002E}",
+		&["\u{0031}", "\u{0027}\u{0308}", "\u{002E}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0030}",
+		&["\u{0031}\u{0027}\u{0030}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [12.0] APOSTROPHE (Single_Quote) × [11.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0308}\u{0030}",
+		&["\u{0031}\u{0027}\u{0308}\u{0030}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [12.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) × [11.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{005F}",
+		&["\u{0031}", "\u{0027}", "\u{005F}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0308}\u{005F}",
+		&["\u{0031}", "\u{0027}\u{0308}", "\u{005F}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{1F1E6}",
+		&["\u{0031}", "\u{0027}", "\u{1F1E6}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0308}\u{1F1E6}",
+		&["\u{0031}", "\u{0027}\u{0308}", "\u{1F1E6}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{05D0}",
+		&["\u{0031}", "\u{0027}", "\u{05D0}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [999.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0308}\u{05D0}",
+		&["\u{0031}", "\u{0027}\u{0308}", "\u{05D0}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0022}",
+		&["\u{0031}", "\u{0027}", "\u{0022}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0308}\u{0022}",
+		&["\u{0031}", "\u{0027}\u{0308}", "\u{0022}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0027}",
+		&["\u{0031}", "\u{0027}", "\u{0027}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0308}\u{0027}",
+		&["\u{0031}", "\u{0027}\u{0308}", "\u{0027}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{231A}",
+		&["\u{0031}", "\u{0027}", "\u{231A}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0308}\u{231A}",
+		&["\u{0031}", "\u{0027}\u{0308}", "\u{231A}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0020}",
+		&["\u{0031}", "\u{0027}", "\u{0020}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0308}\u{0020}",
+		&["\u{0031}", "\u{0027}\u{0308}", "\u{0020}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{00AD}",
+		&["\u{0031}", "\u{0027}\u{00AD}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0308}\u{00AD}",
+		&["\u{0031}", "\u{0027}\u{0308}\u{00AD}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0300}",
+		&["\u{0031}", "\u{0027}\u{0300}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0308}\u{0300}",
+		&["\u{0031}", "\u{0027}\u{0308}\u{0300}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{200D}",
+		&["\u{0031}", "\u{0027}\u{200D}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0308}\u{200D}",
+		&["\u{0031}", "\u{0027}\u{0308}\u{200D}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0061}\u{2060}",
+		&["\u{0031}", "\u{0027}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0308}\u{0061}\u{2060}",
+		&["\u{0031}", "\u{0027}\u{0308}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0061}\u{003A}",
+		&["\u{0031}", "\u{0027}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0308}\u{0061}\u{003A}",
+		&["\u{0031}", "\u{0027}\u{0308}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0061}\u{0027}",
+		&["\u{0031}", "\u{0027}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0308}\u{0061}\u{0027}",
+		&["\u{0031}", "\u{0027}\u{0308}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0061}\u{0027}\u{2060}",
+		&["\u{0031}", "\u{0027}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0308}\u{0061}\u{0027}\u{2060}",
+		&["\u{0031}", "\u{0027}\u{0308}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0061}\u{002C}",
+		&["\u{0031}", "\u{0027}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0308}\u{0061}\u{002C}",
+		&["\u{0031}", "\u{0027}\u{0308}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0031}\u{003A}",
+		&["\u{0031}\u{0027}\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [12.0] APOSTROPHE (Single_Quote) × [11.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0308}\u{0031}\u{003A}",
+		&["\u{0031}\u{0027}\u{0308}\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [12.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) × [11.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0031}\u{0027}",
+		&["\u{0031}\u{0027}\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [12.0] APOSTROPHE (Single_Quote) × [11.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0308}\u{0031}\u{0027}",
+		&["\u{0031}\u{0027}\u{0308}\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [12.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) × [11.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0031}\u{002C}",
+		&["\u{0031}\u{0027}\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [12.0] APOSTROPHE (Single_Quote) × [11.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0308}\u{0031}\u{002C}",
+		&["\u{0031}\u{0027}\u{0308}\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [12.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) × [11.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0031}\u{002E}\u{2060}",
+		&["\u{0031}\u{0027}\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [12.0] APOSTROPHE (Single_Quote) × [11.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{0027}\u{0308}\u{0031}\u{002E}\u{2060}",
+		&["\u{0031}\u{0027}\u{0308}\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [12.0] APOSTROPHE (Single_Quote) × [4.0] COMBINING DIAERESIS (Extend) × [11.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0001}",
+		&["\u{0031}", "\u{002C}", "\u{0001}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0308}\u{0001}",
+		&["\u{0031}", "\u{002C}\u{0308}", "\u{0001}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{000D}",
+		&["\u{0031}", "\u{002C}", "\u{000D}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0308}\u{000D}",
+		&["\u{0031}", "\u{002C}\u{0308}", "\u{000D}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{000A}",
+		&["\u{0031}", "\u{002C}", "\u{000A}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0308}\u{000A}",
+		&["\u{0031}", "\u{002C}\u{0308}", "\u{000A}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{000B}",
+		&["\u{0031}", "\u{002C}", "\u{000B}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0308}\u{000B}",
+		&["\u{0031}", "\u{002C}\u{0308}", "\u{000B}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{3031}",
+		&["\u{0031}", "\u{002C}", "\u{3031}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0308}\u{3031}",
+		&["\u{0031}", "\u{002C}\u{0308}", "\u{3031}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0041}",
+		&["\u{0031}", "\u{002C}", "\u{0041}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [999.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0308}\u{0041}",
+		&["\u{0031}", "\u{002C}\u{0308}", "\u{0041}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{003A}",
+		&["\u{0031}", "\u{002C}", "\u{003A}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0308}\u{003A}",
+		&["\u{0031}", "\u{002C}\u{0308}", "\u{003A}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{002C}",
+		&["\u{0031}", "\u{002C}", "\u{002C}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0308}\u{002C}",
+		&["\u{0031}", "\u{002C}\u{0308}", "\u{002C}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{002E}",
+		&["\u{0031}", "\u{002C}", "\u{002E}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0308}\u{002E}",
+		&["\u{0031}", "\u{002C}\u{0308}", "\u{002E}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0030}",
+		&["\u{0031}\u{002C}\u{0030}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [12.0] COMMA (MidNum) × [11.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0308}\u{0030}",
+		&["\u{0031}\u{002C}\u{0308}\u{0030}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [12.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) × [11.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{005F}",
+		&["\u{0031}", "\u{002C}", "\u{005F}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0308}\u{005F}",
+		&["\u{0031}", "\u{002C}\u{0308}", "\u{005F}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{1F1E6}",
+		&["\u{0031}", "\u{002C}", "\u{1F1E6}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0308}\u{1F1E6}",
+		&["\u{0031}", "\u{002C}\u{0308}", "\u{1F1E6}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{05D0}",
+		&["\u{0031}", "\u{002C}", "\u{05D0}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [999.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0308}\u{05D0}",
+		&["\u{0031}", "\u{002C}\u{0308}", "\u{05D0}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0022}",
+		&["\u{0031}", "\u{002C}", "\u{0022}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0308}\u{0022}",
+		&["\u{0031}", "\u{002C}\u{0308}", "\u{0022}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0027}",
+		&["\u{0031}", "\u{002C}", "\u{0027}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0308}\u{0027}",
+		&["\u{0031}", "\u{002C}\u{0308}", "\u{0027}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{231A}",
+		&["\u{0031}", "\u{002C}", "\u{231A}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0308}\u{231A}",
+		&["\u{0031}", "\u{002C}\u{0308}", "\u{231A}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0020}",
+		&["\u{0031}", "\u{002C}", "\u{0020}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0308}\u{0020}",
+		&["\u{0031}", "\u{002C}\u{0308}", "\u{0020}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{00AD}",
+		&["\u{0031}", "\u{002C}\u{00AD}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0308}\u{00AD}",
+		&["\u{0031}", "\u{002C}\u{0308}\u{00AD}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0300}",
+		&["\u{0031}", "\u{002C}\u{0300}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0308}\u{0300}",
+		&["\u{0031}", "\u{002C}\u{0308}\u{0300}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{200D}",
+		&["\u{0031}", "\u{002C}\u{200D}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0308}\u{200D}",
+		&["\u{0031}", "\u{002C}\u{0308}\u{200D}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0061}\u{2060}",
+		&["\u{0031}", "\u{002C}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0308}\u{0061}\u{2060}",
+		&["\u{0031}", "\u{002C}\u{0308}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0061}\u{003A}",
+		&["\u{0031}", "\u{002C}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0308}\u{0061}\u{003A}",
+		&["\u{0031}", "\u{002C}\u{0308}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0061}\u{0027}",
+		&["\u{0031}", "\u{002C}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0308}\u{0061}\u{0027}",
+		&["\u{0031}", "\u{002C}\u{0308}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0061}\u{0027}\u{2060}",
+		&["\u{0031}", "\u{002C}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0308}\u{0061}\u{0027}\u{2060}",
+		&["\u{0031}", "\u{002C}\u{0308}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0061}\u{002C}",
+		&["\u{0031}", "\u{002C}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0308}\u{0061}\u{002C}",
+		&["\u{0031}", "\u{002C}\u{0308}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0031}\u{003A}",
+		&["\u{0031}\u{002C}\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [12.0] COMMA (MidNum) × [11.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0308}\u{0031}\u{003A}",
+		&["\u{0031}\u{002C}\u{0308}\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [12.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) × [11.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0031}\u{0027}",
+		&["\u{0031}\u{002C}\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [12.0] COMMA (MidNum) × [11.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0308}\u{0031}\u{0027}",
+		&["\u{0031}\u{002C}\u{0308}\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [12.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) × [11.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0031}\u{002C}",
+		&["\u{0031}\u{002C}\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [12.0] COMMA (MidNum) × [11.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0308}\u{0031}\u{002C}",
+		&["\u{0031}\u{002C}\u{0308}\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [12.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) × [11.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0031}\u{002E}\u{2060}",
+		&["\u{0031}\u{002C}\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [12.0] COMMA (MidNum) × [11.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{0308}\u{0031}\u{002E}\u{2060}",
+		&["\u{0031}\u{002C}\u{0308}\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [12.0] COMMA (MidNum) × [4.0] COMBINING DIAERESIS (Extend) × [11.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0001}",
+		&["\u{0031}", "\u{002E}\u{2060}", "\u{0001}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0308}\u{0001}",
+		&["\u{0031}", "\u{002E}\u{2060}\u{0308}", "\u{0001}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] <START OF HEADING> (XXmExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{000D}",
+		&["\u{0031}", "\u{002E}\u{2060}", "\u{000D}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0308}\u{000D}",
+		&["\u{0031}", "\u{002E}\u{2060}\u{0308}", "\u{000D}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <CARRIAGE RETURN (CR)> (CR) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{000A}",
+		&["\u{0031}", "\u{002E}\u{2060}", "\u{000A}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0308}\u{000A}",
+		&["\u{0031}", "\u{002E}\u{2060}\u{0308}", "\u{000A}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{000B}",
+		&["\u{0031}", "\u{002E}\u{2060}", "\u{000B}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0308}\u{000B}",
+		&["\u{0031}", "\u{002E}\u{2060}\u{0308}", "\u{000B}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [3.2] <LINE TABULATION> (Newline) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{3031}",
+		&["\u{0031}", "\u{002E}\u{2060}", "\u{3031}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0308}\u{3031}",
+		&["\u{0031}", "\u{002E}\u{2060}\u{0308}", "\u{3031}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0041}",
+		&["\u{0031}", "\u{002E}\u{2060}", "\u{0041}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [999.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0308}\u{0041}",
+		&["\u{0031}", "\u{002E}\u{2060}\u{0308}", "\u{0041}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{003A}",
+		&["\u{0031}", "\u{002E}\u{2060}", "\u{003A}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0308}\u{003A}",
+		&["\u{0031}", "\u{002E}\u{2060}\u{0308}", "\u{003A}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{002C}",
+		&["\u{0031}", "\u{002E}\u{2060}", "\u{002C}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0308}\u{002C}",
+		&["\u{0031}", "\u{002E}\u{2060}\u{0308}", "\u{002C}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{002E}",
+		&["\u{0031}", "\u{002E}\u{2060}", "\u{002E}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0308}\u{002E}",
+		&["\u{0031}", "\u{002E}\u{2060}\u{0308}", "\u{002E}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] FULL STOP (MidNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0030}",
+		&["\u{0031}\u{002E}\u{2060}\u{0030}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [12.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) × [11.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0308}\u{0030}",
+		&["\u{0031}\u{002E}\u{2060}\u{0308}\u{0030}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [12.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) × [11.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{005F}",
+		&["\u{0031}", "\u{002E}\u{2060}", "\u{005F}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0308}\u{005F}",
+		&["\u{0031}", "\u{002E}\u{2060}\u{0308}", "\u{005F}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{1F1E6}",
+		&["\u{0031}", "\u{002E}\u{2060}", "\u{1F1E6}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0308}\u{1F1E6}",
+		&["\u{0031}", "\u{002E}\u{2060}\u{0308}", "\u{1F1E6}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{05D0}",
+		&["\u{0031}", "\u{002E}\u{2060}", "\u{05D0}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [999.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0308}\u{05D0}",
+		&["\u{0031}", "\u{002E}\u{2060}\u{0308}", "\u{05D0}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0022}",
+		&["\u{0031}", "\u{002E}\u{2060}", "\u{0022}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0308}\u{0022}",
+		&["\u{0031}", "\u{002E}\u{2060}\u{0308}", "\u{0022}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] QUOTATION MARK (Double_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0027}",
+		&["\u{0031}", "\u{002E}\u{2060}", "\u{0027}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0308}\u{0027}",
+		&["\u{0031}", "\u{002E}\u{2060}\u{0308}", "\u{0027}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{231A}",
+		&["\u{0031}", "\u{002E}\u{2060}", "\u{231A}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0308}\u{231A}",
+		&["\u{0031}", "\u{002E}\u{2060}\u{0308}", "\u{231A}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] WATCH (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0020}",
+		&["\u{0031}", "\u{002E}\u{2060}", "\u{0020}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0308}\u{0020}",
+		&["\u{0031}", "\u{002E}\u{2060}\u{0308}", "\u{0020}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{00AD}",
+		&["\u{0031}", "\u{002E}\u{2060}\u{00AD}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0308}\u{00AD}",
+		&["\u{0031}", "\u{002E}\u{2060}\u{0308}\u{00AD}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] SOFT HYPHEN (Format) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0300}",
+		&["\u{0031}", "\u{002E}\u{2060}\u{0300}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0308}\u{0300}",
+		&["\u{0031}", "\u{002E}\u{2060}\u{0308}\u{0300}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] COMBINING GRAVE ACCENT (Extend) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{200D}",
+		&["\u{0031}", "\u{002E}\u{2060}\u{200D}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0308}\u{200D}",
+		&["\u{0031}", "\u{002E}\u{2060}\u{0308}\u{200D}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0061}\u{2060}",
+		&["\u{0031}", "\u{002E}\u{2060}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0308}\u{0061}\u{2060}",
+		&["\u{0031}", "\u{002E}\u{2060}\u{0308}", "\u{0061}\u{2060}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0061}\u{003A}",
+		&["\u{0031}", "\u{002E}\u{2060}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0308}\u{0061}\u{003A}",
+		&["\u{0031}", "\u{002E}\u{2060}\u{0308}", "\u{0061}", "\u{003A}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0061}\u{0027}",
+		&["\u{0031}", "\u{002E}\u{2060}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0308}\u{0061}\u{0027}",
+		&["\u{0031}", "\u{002E}\u{2060}\u{0308}", "\u{0061}", "\u{0027}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0061}\u{0027}\u{2060}",
+		&["\u{0031}", "\u{002E}\u{2060}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0308}\u{0061}\u{0027}\u{2060}",
+		&["\u{0031}", "\u{002E}\u{2060}\u{0308}", "\u{0061}", "\u{0027}\u{2060}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] APOSTROPHE (Single_Quote) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0061}\u{002C}",
+		&["\u{0031}", "\u{002E}\u{2060}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0308}\u{0061}\u{002C}",
+		&["\u{0031}", "\u{002E}\u{2060}\u{0308}", "\u{0061}", "\u{002C}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0031}\u{003A}",
+		&["\u{0031}\u{002E}\u{2060}\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [12.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) × [11.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0308}\u{0031}\u{003A}",
+		&["\u{0031}\u{002E}\u{2060}\u{0308}\u{0031}", "\u{003A}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [12.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) × [11.0] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0031}\u{0027}",
+		&["\u{0031}\u{002E}\u{2060}\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [12.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) × [11.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0308}\u{0031}\u{0027}",
+		&["\u{0031}\u{002E}\u{2060}\u{0308}\u{0031}", "\u{0027}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [12.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) × [11.0] DIGIT ONE (Numeric) ÷ [999.0] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0031}\u{002C}",
+		&["\u{0031}\u{002E}\u{2060}\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [12.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) × [11.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0308}\u{0031}\u{002C}",
+		&["\u{0031}\u{002E}\u{2060}\u{0308}\u{0031}", "\u{002C}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [12.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) × [11.0] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0031}\u{002E}\u{2060}",
+		&["\u{0031}\u{002E}\u{2060}\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [12.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) × [11.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{2060}\u{0308}\u{0031}\u{002E}\u{2060}",
+		&["\u{0031}\u{002E}\u{2060}\u{0308}\u{0031}", "\u{002E}\u{2060}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [12.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) × [4.0] COMBINING DIAERESIS (Extend) × [11.0] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) × [4.0] WORD JOINER (Format) ÷ [0.3]"
+	);
+	word_test("\u{000D}\u{000A}\u{0061}\u{000A}\u{0308}",
+		&["\u{000D}\u{000A}", "\u{0061}", "\u{000A}", "\u{0308}"],
+		"  ÷ [0.2] <CARRIAGE RETURN (CR)> (CR) × [3.0] <LINE FEED (LF)> (LF) ÷ [3.1] LATIN SMALL LETTER A (ALettermExtPict) ÷ [3.2] <LINE FEED (LF)> (LF) ÷ [3.1] COMBINING DIAERESIS (Extend) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0308}",
+		&["\u{0061}\u{0308}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] COMBINING DIAERESIS (Extend) ÷ [0.3]"
+	);
+	word_test("\u{0020}\u{200D}\u{0646}",
+		&["\u{0020}\u{200D}", "\u{0646}"],
+		"  ÷ [0.2] SPACE (WSegSpace) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [999.0] ARABIC LETTER NOON (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0646}\u{200D}\u{0020}",
+		&["\u{0646}\u{200D}", "\u{0020}"],
+		"  ÷ [0.2] ARABIC LETTER NOON (ALettermExtPict) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+	);
+	word_test("\u{0671}\u{0644}\u{0631}\u{064E}\u{0651}\u{062D}\u{0650}\u{064A}\u{0645}\u{0650}\u{0020}\u{06DD}\u{0661}",
+		&["\u{0671}\u{0644}\u{0631}\u{064E}\u{0651}\u{062D}\u{0650}\u{064A}\u{0645}\u{0650}", "\u{0020}", "\u{06DD}\u{0661}"],
+		"  ÷ [0.2] ARABIC LETTER ALEF WASLA (ALettermExtPict) × [5.0] ARABIC LETTER LAM (ALettermExtPict) × [5.0] ARABIC LETTER REH (ALettermExtPict) × [4.0] ARABIC FATHA (Extend) × [4.0] ARABIC SHADDA (Extend) × [5.0] ARABIC LETTER HAH (ALettermExtPict) × [4.0] ARABIC KASRA (Extend) × [5.0] ARABIC LETTER YEH (ALettermExtPict) × [5.0] ARABIC LETTER MEEM (ALettermExtPict) × [4.0] ARABIC KASRA (Extend) ÷ [999.0] SPACE (WSegSpace) ÷ [999.0] ARABIC END OF AYAH (Numeric) × [8.0] ARABIC-INDIC DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0721}\u{0719}\u{0721}\u{0718}\u{072A}\u{0710}\u{0020}\u{070F}\u{071D}\u{0717}",
+		&["\u{0721}\u{0719}\u{0721}\u{0718}\u{072A}\u{0710}", "\u{0020}", "\u{070F}\u{071D}\u{0717}"],
+		"  ÷ [0.2] SYRIAC LETTER MIM (ALettermExtPict) × [5.0] SYRIAC LETTER ZAIN (ALettermExtPict) × [5.0] SYRIAC LETTER MIM (ALettermExtPict) × [5.0] SYRIAC LETTER WAW (ALettermExtPict) × [5.0] SYRIAC LETTER RISH (ALettermExtPict) × [5.0] SYRIAC LETTER ALAPH (ALettermExtPict) ÷ [999.0] SPACE (WSegSpace) ÷ [999.0] SYRIAC ABBREVIATION MARK (ALettermExtPict) × [5.0] SYRIAC LETTER YUDH (ALettermExtPict) × [5.0] SYRIAC LETTER HE (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{072C}\u{070F}\u{072B}\u{0712}\u{0718}",
+		&["\u{072C}\u{070F}\u{072B}\u{0712}\u{0718}"],
+		"  ÷ [0.2] SYRIAC LETTER TAW (ALettermExtPict) × [5.0] SYRIAC ABBREVIATION MARK (ALettermExtPict) × [5.0] SYRIAC LETTER SHIN (ALettermExtPict) × [5.0] SYRIAC LETTER BETH (ALettermExtPict) × [5.0] SYRIAC LETTER WAW (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0041}\u{0041}",
+		&["\u{0041}\u{0041}\u{0041}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [5.0] LATIN CAPITAL LETTER A (ALettermExtPict) × [5.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{003A}\u{0041}",
+		&["\u{0041}\u{003A}\u{0041}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [6.0] COLON (MidLetter) × [7.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{003A}\u{003A}\u{0041}",
+		&["\u{0041}", "\u{003A}", "\u{003A}", "\u{0041}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [999.0] COLON (MidLetter) ÷ [999.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0027}",
+		&["\u{05D0}\u{0027}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [7.1] APOSTROPHE (Single_Quote) ÷ [0.3]"
+	);
+	word_test("\u{05D0}\u{0022}\u{05D0}",
+		&["\u{05D0}\u{0022}\u{05D0}"],
+		"  ÷ [0.2] HEBREW LETTER ALEF (Hebrew_Letter) × [7.2] QUOTATION MARK (Double_Quote) × [7.3] HEBREW LETTER ALEF (Hebrew_Letter) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{0030}\u{0030}\u{0041}",
+		&["\u{0041}\u{0030}\u{0030}\u{0041}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [9.0] DIGIT ZERO (Numeric) × [8.0] DIGIT ZERO (Numeric) × [10.0] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{002C}\u{0030}",
+		&["\u{0030}\u{002C}\u{0030}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) × [12.0] COMMA (MidNum) × [11.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0030}\u{002C}\u{002C}\u{0030}",
+		&["\u{0030}", "\u{002C}", "\u{002C}", "\u{0030}"],
+		"  ÷ [0.2] DIGIT ZERO (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [999.0] COMMA (MidNum) ÷ [999.0] DIGIT ZERO (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{3031}\u{3031}",
+		&["\u{3031}\u{3031}"],
+		"  ÷ [0.2] VERTICAL KANA REPEAT MARK (Katakana) × [13.0] VERTICAL KANA REPEAT MARK (Katakana) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{005F}\u{0030}\u{005F}\u{3031}\u{005F}",
+		&["\u{0041}\u{005F}\u{0030}\u{005F}\u{3031}\u{005F}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [13.1] LOW LINE (ExtendNumLet) × [13.2] DIGIT ZERO (Numeric) × [13.1] LOW LINE (ExtendNumLet) × [13.2] VERTICAL KANA REPEAT MARK (Katakana) × [13.1] LOW LINE (ExtendNumLet) ÷ [0.3]"
+	);
+	word_test("\u{0041}\u{005F}\u{005F}\u{0041}",
+		&["\u{0041}\u{005F}\u{005F}\u{0041}"],
+		"  ÷ [0.2] LATIN CAPITAL LETTER A (ALettermExtPict) × [13.1] LOW LINE (ExtendNumLet) × [13.1] LOW LINE (ExtendNumLet) × [13.2] LATIN CAPITAL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{1F1E6}\u{1F1E7}\u{1F1E8}\u{0062}",
+		&["\u{1F1E6}\u{1F1E7}", "\u{1F1E8}", "\u{0062}"],
+		"  ÷ [0.2] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [15.0] REGIONAL INDICATOR SYMBOL LETTER B (RI) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER C (RI) ÷ [999.0] LATIN SMALL LETTER B (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{1F1E6}\u{1F1E7}\u{1F1E8}\u{0062}",
+		&["\u{0061}", "\u{1F1E6}\u{1F1E7}", "\u{1F1E8}", "\u{0062}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [16.0] REGIONAL INDICATOR SYMBOL LETTER B (RI) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER C (RI) ÷ [999.0] LATIN SMALL LETTER B (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{1F1E6}\u{1F1E7}\u{200D}\u{1F1E8}\u{0062}",
+		&["\u{0061}", "\u{1F1E6}\u{1F1E7}\u{200D}", "\u{1F1E8}", "\u{0062}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [16.0] REGIONAL INDICATOR SYMBOL LETTER B (RI) × [4.0] ZERO WIDTH JOINER (ZWJ) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER C (RI) ÷ [999.0] LATIN SMALL LETTER B (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{1F1E6}\u{200D}\u{1F1E7}\u{1F1E8}\u{0062}",
+		&["\u{0061}", "\u{1F1E6}\u{200D}\u{1F1E7}", "\u{1F1E8}", "\u{0062}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [4.0] ZERO WIDTH JOINER (ZWJ) × [16.0] REGIONAL INDICATOR SYMBOL LETTER B (RI) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER C (RI) ÷ [999.0] LATIN SMALL LETTER B (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{1F1E6}\u{1F1E7}\u{1F1E8}\u{1F1E9}\u{0062}",
+		&["\u{0061}", "\u{1F1E6}\u{1F1E7}", "\u{1F1E8}\u{1F1E9}", "\u{0062}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER A (RI) × [16.0] REGIONAL INDICATOR SYMBOL LETTER B (RI) ÷ [999.0] REGIONAL INDICATOR SYMBOL LETTER C (RI) × [16.0] REGIONAL INDICATOR SYMBOL LETTER D (RI) ÷ [999.0] LATIN SMALL LETTER B (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{1F476}\u{1F3FF}\u{1F476}",
+		&["\u{1F476}\u{1F3FF}", "\u{1F476}"],
+		"  ÷ [0.2] BABY (ExtPictmALetter) × [4.0] EMOJI MODIFIER FITZPATRICK TYPE-6 (Extend) ÷ [999.0] BABY (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{1F6D1}\u{200D}\u{1F6D1}",
+		&["\u{1F6D1}\u{200D}\u{1F6D1}"],
+		"  ÷ [0.2] OCTAGONAL SIGN (ExtPictmALetter) × [4.0] ZERO WIDTH JOINER (ZWJ) × [3.3] OCTAGONAL SIGN (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{200D}\u{1F6D1}",
+		&["\u{0061}\u{200D}\u{1F6D1}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] ZERO WIDTH JOINER (ZWJ) × [3.3] OCTAGONAL SIGN (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{2701}\u{200D}\u{2701}",
+		&["\u{2701}\u{200D}\u{2701}"],
+		"  ÷ [0.2] UPPER BLADE SCISSORS (ExtPictmALetter) × [4.0] ZERO WIDTH JOINER (ZWJ) × [3.3] UPPER BLADE SCISSORS (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{200D}\u{2701}",
+		&["\u{0061}\u{200D}\u{2701}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] ZERO WIDTH JOINER (ZWJ) × [3.3] UPPER BLADE SCISSORS (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{1F476}\u{1F3FF}\u{0308}\u{200D}\u{1F476}\u{1F3FF}",
+		&["\u{1F476}\u{1F3FF}\u{0308}\u{200D}\u{1F476}\u{1F3FF}"],
+		"  ÷ [0.2] BABY (ExtPictmALetter) × [4.0] EMOJI MODIFIER FITZPATRICK TYPE-6 (Extend) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] ZERO WIDTH JOINER (ZWJ) × [3.3] BABY (ExtPictmALetter) × [4.0] EMOJI MODIFIER FITZPATRICK TYPE-6 (Extend) ÷ [0.3]"
+	);
+	word_test("\u{1F6D1}\u{1F3FF}",
+		&["\u{1F6D1}\u{1F3FF}"],
+		"  ÷ [0.2] OCTAGONAL SIGN (ExtPictmALetter) × [4.0] EMOJI MODIFIER FITZPATRICK TYPE-6 (Extend) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{1F6D1}\u{1F3FF}",
+		&["\u{200D}\u{1F6D1}\u{1F3FF}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) × [3.3] OCTAGONAL SIGN (ExtPictmALetter) × [4.0] EMOJI MODIFIER FITZPATRICK TYPE-6 (Extend) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{1F6D1}",
+		&["\u{200D}\u{1F6D1}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) × [3.3] OCTAGONAL SIGN (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{200D}\u{1F6D1}",
+		&["\u{200D}\u{1F6D1}"],
+		"  ÷ [0.2] ZERO WIDTH JOINER (ZWJ) × [3.3] OCTAGONAL SIGN (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{1F6D1}\u{1F6D1}",
+		&["\u{1F6D1}", "\u{1F6D1}"],
+		"  ÷ [0.2] OCTAGONAL SIGN (ExtPictmALetter) ÷ [999.0] OCTAGONAL SIGN (ExtPictmALetter) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0308}\u{200D}\u{0308}\u{0062}",
+		&["\u{0061}\u{0308}\u{200D}\u{0308}\u{0062}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [4.0] COMBINING DIAERESIS (Extend) × [4.0] ZERO WIDTH JOINER (ZWJ) × [4.0] COMBINING DIAERESIS (Extend) × [5.0] LATIN SMALL LETTER B (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{0020}\u{0020}\u{0062}",
+		&["\u{0061}", "\u{0020}\u{0020}", "\u{0062}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] SPACE (WSegSpace) × [3.4] SPACE (WSegSpace) ÷ [999.0] LATIN SMALL LETTER B (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{003A}\u{0031}",
+		&["\u{0031}", "\u{003A}", "\u{003A}", "\u{0031}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] COLON (MidLetter) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{005F}\u{0031}\u{003A}\u{003A}\u{0031}",
+		&["\u{0031}\u{005F}\u{0031}", "\u{003A}", "\u{003A}", "\u{0031}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [13.1] LOW LINE (ExtendNumLet) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] COLON (MidLetter) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{005F}\u{0061}\u{003A}\u{003A}\u{0031}",
+		&["\u{0031}\u{005F}\u{0061}", "\u{003A}", "\u{003A}", "\u{0031}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [13.1] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [999.0] COLON (MidLetter) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{003A}\u{0061}",
+		&["\u{0031}", "\u{003A}", "\u{003A}", "\u{0061}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] COLON (MidLetter) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{005F}\u{0031}\u{003A}\u{003A}\u{0061}",
+		&["\u{0031}\u{005F}\u{0031}", "\u{003A}", "\u{003A}", "\u{0061}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [13.1] LOW LINE (ExtendNumLet) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] COLON (MidLetter) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{005F}\u{0061}\u{003A}\u{003A}\u{0061}",
+		&["\u{0031}\u{005F}\u{0061}", "\u{003A}", "\u{003A}", "\u{0061}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [13.1] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [999.0] COLON (MidLetter) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{002E}\u{0031}",
+		&["\u{0031}", "\u{003A}", "\u{002E}", "\u{0031}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{005F}\u{0031}\u{003A}\u{002E}\u{0031}",
+		&["\u{0031}\u{005F}\u{0031}", "\u{003A}", "\u{002E}", "\u{0031}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [13.1] LOW LINE (ExtendNumLet) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{005F}\u{0061}\u{003A}\u{002E}\u{0031}",
+		&["\u{0031}\u{005F}\u{0061}", "\u{003A}", "\u{002E}", "\u{0031}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [13.1] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{002E}\u{0061}",
+		&["\u{0031}", "\u{003A}", "\u{002E}", "\u{0061}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{005F}\u{0031}\u{003A}\u{002E}\u{0061}",
+		&["\u{0031}\u{005F}\u{0031}", "\u{003A}", "\u{002E}", "\u{0061}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [13.1] LOW LINE (ExtendNumLet) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{005F}\u{0061}\u{003A}\u{002E}\u{0061}",
+		&["\u{0031}\u{005F}\u{0061}", "\u{003A}", "\u{002E}", "\u{0061}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [13.1] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{002C}\u{0031}",
+		&["\u{0031}", "\u{003A}", "\u{002C}", "\u{0031}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] COMMA (MidNum) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{005F}\u{0031}\u{003A}\u{002C}\u{0031}",
+		&["\u{0031}\u{005F}\u{0031}", "\u{003A}", "\u{002C}", "\u{0031}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [13.1] LOW LINE (ExtendNumLet) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] COMMA (MidNum) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{005F}\u{0061}\u{003A}\u{002C}\u{0031}",
+		&["\u{0031}\u{005F}\u{0061}", "\u{003A}", "\u{002C}", "\u{0031}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [13.1] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [999.0] COMMA (MidNum) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{003A}\u{002C}\u{0061}",
+		&["\u{0031}", "\u{003A}", "\u{002C}", "\u{0061}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] COMMA (MidNum) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{005F}\u{0031}\u{003A}\u{002C}\u{0061}",
+		&["\u{0031}\u{005F}\u{0031}", "\u{003A}", "\u{002C}", "\u{0061}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [13.1] LOW LINE (ExtendNumLet) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] COMMA (MidNum) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{005F}\u{0061}\u{003A}\u{002C}\u{0061}",
+		&["\u{0031}\u{005F}\u{0061}", "\u{003A}", "\u{002C}", "\u{0061}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [13.1] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [999.0] COMMA (MidNum) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{003A}\u{0031}",
+		&["\u{0031}", "\u{002E}", "\u{003A}", "\u{0031}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] COLON (MidLetter) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{005F}\u{0031}\u{002E}\u{003A}\u{0031}",
+		&["\u{0031}\u{005F}\u{0031}", "\u{002E}", "\u{003A}", "\u{0031}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [13.1] LOW LINE (ExtendNumLet) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] COLON (MidLetter) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{005F}\u{0061}\u{002E}\u{003A}\u{0031}",
+		&["\u{0031}\u{005F}\u{0061}", "\u{002E}", "\u{003A}", "\u{0031}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [13.1] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] COLON (MidLetter) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{003A}\u{0061}",
+		&["\u{0031}", "\u{002E}", "\u{003A}", "\u{0061}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] COLON (MidLetter) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{005F}\u{0031}\u{002E}\u{003A}\u{0061}",
+		&["\u{0031}\u{005F}\u{0031}", "\u{002E}", "\u{003A}", "\u{0061}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [13.1] LOW LINE (ExtendNumLet) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] COLON (MidLetter) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{005F}\u{0061}\u{002E}\u{003A}\u{0061}",
+		&["\u{0031}\u{005F}\u{0061}", "\u{002E}", "\u{003A}", "\u{0061}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [13.1] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] COLON (MidLetter) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{002E}\u{0031}",
+		&["\u{0031}", "\u{002E}", "\u{002E}", "\u{0031}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{005F}\u{0031}\u{002E}\u{002E}\u{0031}",
+		&["\u{0031}\u{005F}\u{0031}", "\u{002E}", "\u{002E}", "\u{0031}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [13.1] LOW LINE (ExtendNumLet) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{005F}\u{0061}\u{002E}\u{002E}\u{0031}",
+		&["\u{0031}\u{005F}\u{0061}", "\u{002E}", "\u{002E}", "\u{0031}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [13.1] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{002E}\u{0061}",
+		&["\u{0031}", "\u{002E}", "\u{002E}", "\u{0061}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{005F}\u{0031}\u{002E}\u{002E}\u{0061}",
+		&["\u{0031}\u{005F}\u{0031}", "\u{002E}", "\u{002E}", "\u{0061}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [13.1] LOW LINE (ExtendNumLet) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{005F}\u{0061}\u{002E}\u{002E}\u{0061}",
+		&["\u{0031}\u{005F}\u{0061}", "\u{002E}", "\u{002E}", "\u{0061}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [13.1] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{002C}\u{0031}",
+		&["\u{0031}", "\u{002E}", "\u{002C}", "\u{0031}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] COMMA (MidNum) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{005F}\u{0031}\u{002E}\u{002C}\u{0031}",
+		&["\u{0031}\u{005F}\u{0031}", "\u{002E}", "\u{002C}", "\u{0031}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [13.1] LOW LINE (ExtendNumLet) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] COMMA (MidNum) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{005F}\u{0061}\u{002E}\u{002C}\u{0031}",
+		&["\u{0031}\u{005F}\u{0061}", "\u{002E}", "\u{002C}", "\u{0031}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [13.1] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] COMMA (MidNum) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002E}\u{002C}\u{0061}",
+		&["\u{0031}", "\u{002E}", "\u{002C}", "\u{0061}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] COMMA (MidNum) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{005F}\u{0031}\u{002E}\u{002C}\u{0061}",
+		&["\u{0031}\u{005F}\u{0031}", "\u{002E}", "\u{002C}", "\u{0061}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [13.1] LOW LINE (ExtendNumLet) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] COMMA (MidNum) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{005F}\u{0061}\u{002E}\u{002C}\u{0061}",
+		&["\u{0031}\u{005F}\u{0061}", "\u{002E}", "\u{002C}", "\u{0061}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [13.1] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] COMMA (MidNum) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{003A}\u{0031}",
+		&["\u{0031}", "\u{002C}", "\u{003A}", "\u{0031}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [999.0] COLON (MidLetter) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{005F}\u{0031}\u{002C}\u{003A}\u{0031}",
+		&["\u{0031}\u{005F}\u{0031}", "\u{002C}", "\u{003A}", "\u{0031}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [13.1] LOW LINE (ExtendNumLet) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [999.0] COLON (MidLetter) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{005F}\u{0061}\u{002C}\u{003A}\u{0031}",
+		&["\u{0031}\u{005F}\u{0061}", "\u{002C}", "\u{003A}", "\u{0031}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [13.1] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] COLON (MidLetter) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{003A}\u{0061}",
+		&["\u{0031}", "\u{002C}", "\u{003A}", "\u{0061}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [999.0] COLON (MidLetter) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{005F}\u{0031}\u{002C}\u{003A}\u{0061}",
+		&["\u{0031}\u{005F}\u{0031}", "\u{002C}", "\u{003A}", "\u{0061}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [13.1] LOW LINE (ExtendNumLet) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [999.0] COLON (MidLetter) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{005F}\u{0061}\u{002C}\u{003A}\u{0061}",
+		&["\u{0031}\u{005F}\u{0061}", "\u{002C}", "\u{003A}", "\u{0061}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [13.1] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] COLON (MidLetter) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{002E}\u{0031}",
+		&["\u{0031}", "\u{002C}", "\u{002E}", "\u{0031}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{005F}\u{0031}\u{002C}\u{002E}\u{0031}",
+		&["\u{0031}\u{005F}\u{0031}", "\u{002C}", "\u{002E}", "\u{0031}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [13.1] LOW LINE (ExtendNumLet) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{005F}\u{0061}\u{002C}\u{002E}\u{0031}",
+		&["\u{0031}\u{005F}\u{0061}", "\u{002C}", "\u{002E}", "\u{0031}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [13.1] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{002E}\u{0061}",
+		&["\u{0031}", "\u{002C}", "\u{002E}", "\u{0061}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{005F}\u{0031}\u{002C}\u{002E}\u{0061}",
+		&["\u{0031}\u{005F}\u{0031}", "\u{002C}", "\u{002E}", "\u{0061}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [13.1] LOW LINE (ExtendNumLet) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{005F}\u{0061}\u{002C}\u{002E}\u{0061}",
+		&["\u{0031}\u{005F}\u{0061}", "\u{002C}", "\u{002E}", "\u{0061}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [13.1] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{002C}\u{0031}",
+		&["\u{0031}", "\u{002C}", "\u{002C}", "\u{0031}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [999.0] COMMA (MidNum) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{005F}\u{0031}\u{002C}\u{002C}\u{0031}",
+		&["\u{0031}\u{005F}\u{0031}", "\u{002C}", "\u{002C}", "\u{0031}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [13.1] LOW LINE (ExtendNumLet) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [999.0] COMMA (MidNum) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{005F}\u{0061}\u{002C}\u{002C}\u{0031}",
+		&["\u{0031}\u{005F}\u{0061}", "\u{002C}", "\u{002C}", "\u{0031}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [13.1] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] COMMA (MidNum) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{002C}\u{002C}\u{0061}",
+		&["\u{0031}", "\u{002C}", "\u{002C}", "\u{0061}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [999.0] COMMA (MidNum) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{005F}\u{0031}\u{002C}\u{002C}\u{0061}",
+		&["\u{0031}\u{005F}\u{0031}", "\u{002C}", "\u{002C}", "\u{0061}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [13.1] LOW LINE (ExtendNumLet) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [999.0] COMMA (MidNum) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0031}\u{005F}\u{0061}\u{002C}\u{002C}\u{0061}",
+		&["\u{0031}\u{005F}\u{0061}", "\u{002C}", "\u{002C}", "\u{0061}"],
+		"  ÷ [0.2] DIGIT ONE (Numeric) × [13.1] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] COMMA (MidNum) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{003A}\u{0031}",
+		&["\u{0061}", "\u{003A}", "\u{003A}", "\u{0031}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [999.0] COLON (MidLetter) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{005F}\u{0031}\u{003A}\u{003A}\u{0031}",
+		&["\u{0061}\u{005F}\u{0031}", "\u{003A}", "\u{003A}", "\u{0031}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [13.1] LOW LINE (ExtendNumLet) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] COLON (MidLetter) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{005F}\u{0061}\u{003A}\u{003A}\u{0031}",
+		&["\u{0061}\u{005F}\u{0061}", "\u{003A}", "\u{003A}", "\u{0031}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [13.1] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [999.0] COLON (MidLetter) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{003A}\u{0061}",
+		&["\u{0061}", "\u{003A}", "\u{003A}", "\u{0061}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [999.0] COLON (MidLetter) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{005F}\u{0031}\u{003A}\u{003A}\u{0061}",
+		&["\u{0061}\u{005F}\u{0031}", "\u{003A}", "\u{003A}", "\u{0061}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [13.1] LOW LINE (ExtendNumLet) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] COLON (MidLetter) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{005F}\u{0061}\u{003A}\u{003A}\u{0061}",
+		&["\u{0061}\u{005F}\u{0061}", "\u{003A}", "\u{003A}", "\u{0061}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [13.1] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [999.0] COLON (MidLetter) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{002E}\u{0031}",
+		&["\u{0061}", "\u{003A}", "\u{002E}", "\u{0031}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{005F}\u{0031}\u{003A}\u{002E}\u{0031}",
+		&["\u{0061}\u{005F}\u{0031}", "\u{003A}", "\u{002E}", "\u{0031}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [13.1] LOW LINE (ExtendNumLet) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{005F}\u{0061}\u{003A}\u{002E}\u{0031}",
+		&["\u{0061}\u{005F}\u{0061}", "\u{003A}", "\u{002E}", "\u{0031}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [13.1] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{002E}\u{0061}",
+		&["\u{0061}", "\u{003A}", "\u{002E}", "\u{0061}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{005F}\u{0031}\u{003A}\u{002E}\u{0061}",
+		&["\u{0061}\u{005F}\u{0031}", "\u{003A}", "\u{002E}", "\u{0061}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [13.1] LOW LINE (ExtendNumLet) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{005F}\u{0061}\u{003A}\u{002E}\u{0061}",
+		&["\u{0061}\u{005F}\u{0061}", "\u{003A}", "\u{002E}", "\u{0061}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [13.1] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{002C}\u{0031}",
+		&["\u{0061}", "\u{003A}", "\u{002C}", "\u{0031}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [999.0] COMMA (MidNum) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{005F}\u{0031}\u{003A}\u{002C}\u{0031}",
+		&["\u{0061}\u{005F}\u{0031}", "\u{003A}", "\u{002C}", "\u{0031}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [13.1] LOW LINE (ExtendNumLet) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] COMMA (MidNum) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{005F}\u{0061}\u{003A}\u{002C}\u{0031}",
+		&["\u{0061}\u{005F}\u{0061}", "\u{003A}", "\u{002C}", "\u{0031}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [13.1] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [999.0] COMMA (MidNum) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{003A}\u{002C}\u{0061}",
+		&["\u{0061}", "\u{003A}", "\u{002C}", "\u{0061}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [999.0] COMMA (MidNum) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{005F}\u{0031}\u{003A}\u{002C}\u{0061}",
+		&["\u{0061}\u{005F}\u{0031}", "\u{003A}", "\u{002C}", "\u{0061}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [13.1] LOW LINE (ExtendNumLet) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] COLON (MidLetter) ÷ [999.0] COMMA (MidNum) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{005F}\u{0061}\u{003A}\u{002C}\u{0061}",
+		&["\u{0061}\u{005F}\u{0061}", "\u{003A}", "\u{002C}", "\u{0061}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [13.1] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COLON (MidLetter) ÷ [999.0] COMMA (MidNum) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002E}\u{003A}\u{0031}",
+		&["\u{0061}", "\u{002E}", "\u{003A}", "\u{0031}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] COLON (MidLetter) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{005F}\u{0031}\u{002E}\u{003A}\u{0031}",
+		&["\u{0061}\u{005F}\u{0031}", "\u{002E}", "\u{003A}", "\u{0031}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [13.1] LOW LINE (ExtendNumLet) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] COLON (MidLetter) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{005F}\u{0061}\u{002E}\u{003A}\u{0031}",
+		&["\u{0061}\u{005F}\u{0061}", "\u{002E}", "\u{003A}", "\u{0031}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [13.1] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] COLON (MidLetter) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002E}\u{003A}\u{0061}",
+		&["\u{0061}", "\u{002E}", "\u{003A}", "\u{0061}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] COLON (MidLetter) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{005F}\u{0031}\u{002E}\u{003A}\u{0061}",
+		&["\u{0061}\u{005F}\u{0031}", "\u{002E}", "\u{003A}", "\u{0061}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [13.1] LOW LINE (ExtendNumLet) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] COLON (MidLetter) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{005F}\u{0061}\u{002E}\u{003A}\u{0061}",
+		&["\u{0061}\u{005F}\u{0061}", "\u{002E}", "\u{003A}", "\u{0061}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [13.1] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] COLON (MidLetter) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002E}\u{002E}\u{0031}",
+		&["\u{0061}", "\u{002E}", "\u{002E}", "\u{0031}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{005F}\u{0031}\u{002E}\u{002E}\u{0031}",
+		&["\u{0061}\u{005F}\u{0031}", "\u{002E}", "\u{002E}", "\u{0031}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [13.1] LOW LINE (ExtendNumLet) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{005F}\u{0061}\u{002E}\u{002E}\u{0031}",
+		&["\u{0061}\u{005F}\u{0061}", "\u{002E}", "\u{002E}", "\u{0031}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [13.1] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002E}\u{002E}\u{0061}",
+		&["\u{0061}", "\u{002E}", "\u{002E}", "\u{0061}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{005F}\u{0031}\u{002E}\u{002E}\u{0061}",
+		&["\u{0061}\u{005F}\u{0031}", "\u{002E}", "\u{002E}", "\u{0061}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [13.1] LOW LINE (ExtendNumLet) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{005F}\u{0061}\u{002E}\u{002E}\u{0061}",
+		&["\u{0061}\u{005F}\u{0061}", "\u{002E}", "\u{002E}", "\u{0061}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [13.1] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002E}\u{002C}\u{0031}",
+		&["\u{0061}", "\u{002E}", "\u{002C}", "\u{0031}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] COMMA (MidNum) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{005F}\u{0031}\u{002E}\u{002C}\u{0031}",
+		&["\u{0061}\u{005F}\u{0031}", "\u{002E}", "\u{002C}", "\u{0031}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [13.1] LOW LINE (ExtendNumLet) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] COMMA (MidNum) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{005F}\u{0061}\u{002E}\u{002C}\u{0031}",
+		&["\u{0061}\u{005F}\u{0061}", "\u{002E}", "\u{002C}", "\u{0031}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [13.1] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] COMMA (MidNum) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002E}\u{002C}\u{0061}",
+		&["\u{0061}", "\u{002E}", "\u{002C}", "\u{0061}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] COMMA (MidNum) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{005F}\u{0031}\u{002E}\u{002C}\u{0061}",
+		&["\u{0061}\u{005F}\u{0031}", "\u{002E}", "\u{002C}", "\u{0061}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [13.1] LOW LINE (ExtendNumLet) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] COMMA (MidNum) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{005F}\u{0061}\u{002E}\u{002C}\u{0061}",
+		&["\u{0061}\u{005F}\u{0061}", "\u{002E}", "\u{002C}", "\u{0061}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [13.1] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] COMMA (MidNum) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{003A}\u{0031}",
+		&["\u{0061}", "\u{002C}", "\u{003A}", "\u{0031}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] COLON (MidLetter) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{005F}\u{0031}\u{002C}\u{003A}\u{0031}",
+		&["\u{0061}\u{005F}\u{0031}", "\u{002C}", "\u{003A}", "\u{0031}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [13.1] LOW LINE (ExtendNumLet) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [999.0] COLON (MidLetter) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{005F}\u{0061}\u{002C}\u{003A}\u{0031}",
+		&["\u{0061}\u{005F}\u{0061}", "\u{002C}", "\u{003A}", "\u{0031}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [13.1] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] COLON (MidLetter) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{003A}\u{0061}",
+		&["\u{0061}", "\u{002C}", "\u{003A}", "\u{0061}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] COLON (MidLetter) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{005F}\u{0031}\u{002C}\u{003A}\u{0061}",
+		&["\u{0061}\u{005F}\u{0031}", "\u{002C}", "\u{003A}", "\u{0061}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [13.1] LOW LINE (ExtendNumLet) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [999.0] COLON (MidLetter) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{005F}\u{0061}\u{002C}\u{003A}\u{0061}",
+		&["\u{0061}\u{005F}\u{0061}", "\u{002C}", "\u{003A}", "\u{0061}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [13.1] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] COLON (MidLetter) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{002E}\u{0031}",
+		&["\u{0061}", "\u{002C}", "\u{002E}", "\u{0031}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{005F}\u{0031}\u{002C}\u{002E}\u{0031}",
+		&["\u{0061}\u{005F}\u{0031}", "\u{002C}", "\u{002E}", "\u{0031}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [13.1] LOW LINE (ExtendNumLet) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{005F}\u{0061}\u{002C}\u{002E}\u{0031}",
+		&["\u{0061}\u{005F}\u{0061}", "\u{002C}", "\u{002E}", "\u{0031}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [13.1] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{002E}\u{0061}",
+		&["\u{0061}", "\u{002C}", "\u{002E}", "\u{0061}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{005F}\u{0031}\u{002C}\u{002E}\u{0061}",
+		&["\u{0061}\u{005F}\u{0031}", "\u{002C}", "\u{002E}", "\u{0061}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [13.1] LOW LINE (ExtendNumLet) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{005F}\u{0061}\u{002C}\u{002E}\u{0061}",
+		&["\u{0061}\u{005F}\u{0061}", "\u{002C}", "\u{002E}", "\u{0061}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [13.1] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] FULL STOP (MidNumLet) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{002C}\u{0031}",
+		&["\u{0061}", "\u{002C}", "\u{002C}", "\u{0031}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] COMMA (MidNum) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{005F}\u{0031}\u{002C}\u{002C}\u{0031}",
+		&["\u{0061}\u{005F}\u{0031}", "\u{002C}", "\u{002C}", "\u{0031}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [13.1] LOW LINE (ExtendNumLet) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [999.0] COMMA (MidNum) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{005F}\u{0061}\u{002C}\u{002C}\u{0031}",
+		&["\u{0061}\u{005F}\u{0061}", "\u{002C}", "\u{002C}", "\u{0031}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [13.1] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] COMMA (MidNum) ÷ [999.0] DIGIT ONE (Numeric) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{002C}\u{002C}\u{0061}",
+		&["\u{0061}", "\u{002C}", "\u{002C}", "\u{0061}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] COMMA (MidNum) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{005F}\u{0031}\u{002C}\u{002C}\u{0061}",
+		&["\u{0061}\u{005F}\u{0031}", "\u{002C}", "\u{002C}", "\u{0061}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [13.1] LOW LINE (ExtendNumLet) × [13.2] DIGIT ONE (Numeric) ÷ [999.0] COMMA (MidNum) ÷ [999.0] COMMA (MidNum) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+	word_test("\u{0061}\u{005F}\u{0061}\u{002C}\u{002C}\u{0061}",
+		&["\u{0061}\u{005F}\u{0061}", "\u{002C}", "\u{002C}", "\u{0061}"],
+		"  ÷ [0.2] LATIN SMALL LETTER A (ALettermExtPict) × [13.1] LOW LINE (ExtendNumLet) × [13.2] LATIN SMALL LETTER A (ALettermExtPict) ÷ [999.0] COMMA (MidNum) ÷ [999.0] COMMA (MidNum) ÷ [999.0] LATIN SMALL LETTER A (ALettermExtPict) ÷ [0.3]"
+	);
+}
@@ -0,0 +1,159 @@
+//! This module provides the East_Asian_Width property described in
+//! [UAX #11](https://www.unicode.org/reports/tr11/) and a `display_width` function which uses it
+//! to find the number of columns a string takes up in a terminal.
+//! ```
+//! # use crate::finl_unicode::east_asian_width::{CharacterEastAsianWidth, EastAsianWidth};
+//! assert_eq!('a'.east_asian_width(), EastAsianWidth::Narrow);
+//! assert_eq!('漢'.east_asian_width(), EastAsianWidth::Wide);
+//! assert_eq!('Ａ'.east_asian_width(), EastAsianWidth::Fullwidth);
+//! assert_eq!('ｱ'.east_asian_width(), EastAsianWidth::Halfwidth);
+//! assert_eq!('±'.east_asian_width(), EastAsianWidth::Ambiguous);
+//! assert_eq!('ب'.east_asian_width(), EastAsianWidth::Neutral);
+//! ```
+//!
+//! `display_width` works on grapheme clusters rather than `char`s, so a cluster made up of several
+//! characters like an emoji ZWJ sequence, a flag or a letter with combining marks is counted once.
+//! ```
+//! # use crate::finl_unicode::east_asian_width::display_width;
+//! assert_eq!(display_width("abc"), 3);
+//! assert_eq!(display_width("日本語"), 6);
+//! assert_eq!(display_width("e\u{301}"), 1);
+//! assert_eq!(display_width("👩‍👩‍👧"), 2);
+//! assert_eq!(display_width("🇦🇹"), 2);
+//! ```
+
+use crate::categories::is_zero_width_category;
+use crate::data::east_asian_width::{EAW_PAGES, EAW_TABLE};
+use crate::grapheme_clusters::Graphemes;
+
+/// Trait to look up the East_Asian_Width of a character. Importing the trait will provide the
+/// method on the `char` type.
+pub trait CharacterEastAsianWidth {
+    /// Returns the East_Asian_Width of a character. Unassigned code points get the default value
+    /// for where they fall, e.g., `Wide` for unassigned code points in the CJK ideograph blocks.
+    fn east_asian_width(self) -> EastAsianWidth;
+}
+
+/// enum for the East_Asian_Width values of characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EastAsianWidth {
+    /// Characters which don't occur in East Asian legacy character sets (N)
+    Neutral,
+    /// Characters which are narrow or wide depending on context (A)
+    Ambiguous,
+    /// Halfwidth compatibility characters, e.g., halfwidth katakana (H)
+    Halfwidth,
+    /// Characters which are always wide, e.g., ideographs (W)
+    Wide,
+    /// Characters with a halfwidth equivalent, e.g., fullwidth Latin letters (F)
+    Fullwidth,
+    /// Characters with a fullwidth equivalent, e.g., ASCII (Na)
+    Narrow,
+}
+
+impl CharacterEastAsianWidth for char {
+    #[inline]
+    fn east_asian_width(self) -> EastAsianWidth {
+        match EAW_PAGES[usize::from(EAW_TABLE[(self as usize) >> 8])][(self as usize) & 0xff] {
+            0x00 => EastAsianWidth::Neutral,
+            0x01 => EastAsianWidth::Ambiguous,
+            0x02 => EastAsianWidth::Halfwidth,
+            0x03 => EastAsianWidth::Wide,
+            0x04 => EastAsianWidth::Fullwidth,
+            0x05 => EastAsianWidth::Narrow,
+            _ => {
+                panic!("Corrupt east asian width data")
+            }
+        }
+    }
+}
+
+/// Returns the number of columns that a string takes up when displayed in a terminal. Each
+/// grapheme cluster is two columns wide if it begins with a wide or fullwidth character, is a pair
+/// of regional indicators or is given emoji presentation with U+FE0F. Clusters which are only
+/// control characters, format characters (other than U+00AD SOFT HYPHEN) or marks take up no
+/// columns and all others take up one.
+///
+/// Ambiguous characters are counted as narrow, which is what most terminals do outside of East
+/// Asian locales.
+pub fn display_width(s: &str) -> usize {
+    Graphemes::new(s).map(cluster_width).sum()
+}
+
+fn cluster_width(cluster: &str) -> usize {
+    let mut chars = cluster.chars();
+    let Some(first) = chars.next() else {
+        return 0;
+    };
+    if matches!(first.east_asian_width(), EastAsianWidth::Wide | EastAsianWidth::Fullwidth)
+        || (is_regional_indicator(first) && chars.clone().next().is_some_and(is_regional_indicator))
+        || chars.any(|c| c == '\u{fe0f}') {
+        2
+    } else if cluster.chars().all(is_zero_width) {
+        0
+    } else {
+        1
+    }
+}
+
+#[inline]
+fn is_regional_indicator(c: char) -> bool {
+    ('\u{1f1e6}'..='\u{1f1ff}').contains(&c)
+}
+
+// Nonspacing and enclosing marks along with control and format characters, except for U+00AD
+// SOFT HYPHEN, which terminals show as a hyphen. Hangul medial vowels and final consonants are
+// also zero width since they are drawn as part of the preceding syllable.
+#[inline]
+fn is_zero_width(c: char) -> bool {
+    (is_zero_width_category(c) && c != '\u{ad}')
+        || matches!(c, '\u{1160}'..='\u{11ff}' | '\u{d7b0}'..='\u{d7ff}')
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::east_asian_width::*;
+
+    #[test]
+    fn can_get_east_asian_width() {
+        assert_eq!('A'.east_asian_width(), EastAsianWidth::Narrow);
+        assert_eq!('\u{7f}'.east_asian_width(), EastAsianWidth::Neutral);
+        assert_eq!('あ'.east_asian_width(), EastAsianWidth::Wide);
+        assert_eq!('😀'.east_asian_width(), EastAsianWidth::Wide);
+        assert_eq!('☺'.east_asian_width(), EastAsianWidth::Neutral);
+        assert_eq!('￦'.east_asian_width(), EastAsianWidth::Fullwidth);
+        assert_eq!('\u{e000}'.east_asian_width(), EastAsianWidth::Ambiguous);
+        // Unassigned code points in CJK ranges default to Wide
+        assert_eq!('\u{2fffd}'.east_asian_width(), EastAsianWidth::Wide);
+        assert_eq!('\u{378}'.east_asian_width(), EastAsianWidth::Neutral);
+    }
+
+    #[test]
+    fn can_find_display_width() {
+        assert_eq!(display_width(""), 0);
+        assert_eq!(display_width("Hello, world!"), 13);
+        assert_eq!(display_width("ｺﾝﾆﾁﾊ"), 5);
+        assert_eq!(display_width("한국어"), 6);
+        // Conjoining jamo make up a single syllable
+        assert_eq!(display_width("\u{1100}\u{1161}\u{11a8}"), 2);
+        assert_eq!(display_width("a\u{301}\u{302}b"), 2);
+        assert_eq!(display_width("\u{301}"), 0);
+        assert_eq!(display_width("a\u{200b}b"), 2);
+        assert_eq!(display_width("\r\n"), 0);
+        // Emoji presentation, modifiers, keycaps, flags and ZWJ sequences
+        assert_eq!(display_width("☺\u{fe0f}"), 2);
+        assert_eq!(display_width("✋🏽"), 2);
+        assert_eq!(display_width("1\u{fe0f}\u{20e3}"), 2);
+        assert_eq!(display_width("🇯🇵🇫🇷"), 4);
+        assert_eq!(display_width("🏳\u{fe0f}\u{200d}🌈"), 2);
+        assert_eq!(display_width("👨‍👩‍👧‍👦 family"), 9);
+    }
+
+    #[test]
+    fn soft_hyphen_is_one_column() {
+        assert_eq!(display_width("\u{ad}"), 1);
+        assert_eq!(display_width("co\u{ad}op"), 5);
+        // Other format characters still take up no columns
+        assert_eq!(display_width("\u{2060}\u{feff}"), 0);
+    }
+}
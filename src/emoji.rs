@@ -0,0 +1,189 @@
+//! This module provides the emoji properties from `emoji-data.txt` described in
+//! [UTS #51](https://www.unicode.org/reports/tr51/).
+//! ```
+//! # use crate::finl_unicode::emoji::CharacterEmoji;
+//! assert!('😀'.is_emoji());
+//! assert!('😀'.is_emoji_presentation());
+//! assert!('©'.is_emoji());
+//! assert!(!'©'.is_emoji_presentation());
+//! assert!('👍'.is_emoji_modifier_base());
+//! assert!('\u{1f3fd}'.is_emoji_modifier());
+//! assert!('#'.is_emoji_component());
+//! assert!('❤'.is_extended_pictographic());
+//! ```
+//!
+//! A grapheme cluster can be classified as one of the recommended-for-general-interchange (RGI)
+//! emoji sequences listed in `emoji-sequences.txt` and `emoji-zwj-sequences.txt`:
+//! ```
+//! # use crate::finl_unicode::emoji::{emoji_sequence_type, EmojiSequenceType};
+//! assert_eq!(emoji_sequence_type("😀"), Some(EmojiSequenceType::Basic));
+//! assert_eq!(emoji_sequence_type("1\u{fe0f}\u{20e3}"), Some(EmojiSequenceType::Keycap));
+//! assert_eq!(emoji_sequence_type("🇫🇷"), Some(EmojiSequenceType::Flag));
+//! assert_eq!(emoji_sequence_type("👋🏾"), Some(EmojiSequenceType::Modifier));
+//! assert_eq!(emoji_sequence_type("👩\u{200d}🚀"), Some(EmojiSequenceType::Zwj));
+//! assert_eq!(emoji_sequence_type("a"), None);
+//! ```
+
+use crate::data::emoji_property::{EMOJI_PAGES, EMOJI_TABLE};
+use crate::data::emoji_sequences::EMOJI_SEQUENCES;
+
+/// Trait to look up the emoji properties of a character. Importing the trait will provide the
+/// methods on the `char` type.
+#[allow(clippy::wrong_self_convention)]
+pub trait CharacterEmoji {
+    /// Determines whether a character is an emoji (Emoji). Note that this includes the ASCII
+    /// digits, `#` and `*` since they can begin keycap sequences.
+    fn is_emoji(self) -> bool;
+    /// Determines whether a character is displayed as an emoji by default rather than as text
+    /// (Emoji_Presentation).
+    fn is_emoji_presentation(self) -> bool;
+    /// Determines whether a character is one of the skin tone modifiers (Emoji_Modifier).
+    fn is_emoji_modifier(self) -> bool;
+    /// Determines whether a character can be followed by a skin tone modifier
+    /// (Emoji_Modifier_Base).
+    fn is_emoji_modifier_base(self) -> bool;
+    /// Determines whether a character can appear as a part of an emoji sequence without being an
+    /// emoji itself, such as the regional indicators, tags and ZWJ (Emoji_Component).
+    fn is_emoji_component(self) -> bool;
+    /// Determines whether a character is pictographic or reserved for future pictographs
+    /// (Extended_Pictographic). This is the property used for grapheme cluster boundaries.
+    fn is_extended_pictographic(self) -> bool;
+}
+
+impl CharacterEmoji for char {
+    #[inline]
+    fn is_emoji(self) -> bool {
+        get_emoji_property(self) & EMOJI != 0
+    }
+
+    #[inline]
+    fn is_emoji_presentation(self) -> bool {
+        get_emoji_property(self) & EMOJI_PRESENTATION != 0
+    }
+
+    #[inline]
+    fn is_emoji_modifier(self) -> bool {
+        get_emoji_property(self) & EMOJI_MODIFIER != 0
+    }
+
+    #[inline]
+    fn is_emoji_modifier_base(self) -> bool {
+        get_emoji_property(self) & EMOJI_MODIFIER_BASE != 0
+    }
+
+    #[inline]
+    fn is_emoji_component(self) -> bool {
+        get_emoji_property(self) & EMOJI_COMPONENT != 0
+    }
+
+    #[inline]
+    fn is_extended_pictographic(self) -> bool {
+        get_emoji_property(self) & EXTENDED_PICTOGRAPHIC != 0
+    }
+}
+
+/// The types of RGI emoji sequences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EmojiSequenceType {
+    /// A single emoji character, or a character followed by the emoji presentation selector
+    /// U+FE0F (Basic_Emoji)
+    Basic,
+    /// A digit, `#` or `*` followed by U+FE0F and the combining enclosing keycap U+20E3
+    /// (Emoji_Keycap_Sequence)
+    Keycap,
+    /// A pair of regional indicators for a country or region flag (RGI_Emoji_Flag_Sequence)
+    Flag,
+    /// An emoji modifier base followed by a skin tone modifier (RGI_Emoji_Modifier_Sequence)
+    Modifier,
+    /// Emoji joined by the zero width joiner U+200D (RGI_Emoji_ZWJ_Sequence)
+    Zwj,
+    /// An emoji followed by tag characters and a cancel tag for subdivision flags
+    /// (RGI_Emoji_Tag_Sequence)
+    Tag,
+}
+
+/// Classifies a grapheme cluster as one of the RGI emoji sequences, returning `None` if the
+/// cluster is not an RGI emoji. Only the fully-qualified forms of the sequences are matched, so,
+/// e.g., `"❤\u{fe0f}"` is a basic emoji, but `"❤"` without the presentation selector is not.
+pub fn emoji_sequence_type(cluster: &str) -> Option<EmojiSequenceType> {
+    let index = EMOJI_SEQUENCES.binary_search_by(|&(sequence, _)| sequence.cmp(cluster)).ok()?;
+    match EMOJI_SEQUENCES[index].1 {
+        BASIC_EMOJI => Some(EmojiSequenceType::Basic),
+        EMOJI_KEYCAP_SEQUENCE => Some(EmojiSequenceType::Keycap),
+        EMOJI_FLAG_SEQUENCE => Some(EmojiSequenceType::Flag),
+        EMOJI_MODIFIER_SEQUENCE => Some(EmojiSequenceType::Modifier),
+        EMOJI_ZWJ_SEQUENCE => Some(EmojiSequenceType::Zwj),
+        EMOJI_TAG_SEQUENCE => Some(EmojiSequenceType::Tag),
+        _ => None,
+    }
+}
+
+#[inline]
+fn get_emoji_property(c: char) -> u8 {
+    EMOJI_PAGES[usize::from(EMOJI_TABLE[(c as usize) >> 8])][(c as usize) & 0xff]
+}
+
+// Symbolic names for the emoji flags in data tables
+const EMOJI: u8 = 0x01;
+const EMOJI_PRESENTATION: u8 = 0x02;
+const EMOJI_MODIFIER: u8 = 0x04;
+const EMOJI_MODIFIER_BASE: u8 = 0x08;
+const EMOJI_COMPONENT: u8 = 0x10;
+const EXTENDED_PICTOGRAPHIC: u8 = 0x20;
+
+// Symbolic names for the sequence types in the emoji sequence list
+const BASIC_EMOJI: u8 = 0x01;
+const EMOJI_KEYCAP_SEQUENCE: u8 = 0x02;
+const EMOJI_FLAG_SEQUENCE: u8 = 0x03;
+const EMOJI_MODIFIER_SEQUENCE: u8 = 0x04;
+const EMOJI_ZWJ_SEQUENCE: u8 = 0x05;
+const EMOJI_TAG_SEQUENCE: u8 = 0x06;
+
+#[cfg(test)]
+mod tests {
+    use crate::emoji::*;
+
+    #[test]
+    fn can_get_emoji_properties() {
+        assert!('🦀'.is_emoji());
+        assert!('🦀'.is_emoji_presentation());
+        assert!('🦀'.is_extended_pictographic());
+        assert!('1'.is_emoji());
+        assert!(!'1'.is_emoji_presentation());
+        assert!(!'1'.is_extended_pictographic());
+        assert!(!'a'.is_emoji());
+        assert!('™'.is_emoji());
+        assert!(!'™'.is_emoji_presentation());
+        assert!('\u{1f3fb}'.is_emoji_modifier());
+        assert!(!'\u{1f3fb}'.is_emoji_modifier_base());
+        assert!('✋'.is_emoji_modifier_base());
+        assert!('\u{200d}'.is_emoji_component());
+        assert!('\u{1f1e6}'.is_emoji_component());
+        assert!('\u{e0067}'.is_emoji_component());
+        assert!(!'😀'.is_emoji_component());
+        // Unassigned code points in the pictographic blocks are reserved for future emoji
+        assert!('\u{1fffd}'.is_extended_pictographic());
+        assert!(!'\u{1fffd}'.is_emoji());
+    }
+
+    #[test]
+    fn can_classify_emoji_sequences() {
+        assert_eq!(emoji_sequence_type("⌚"), Some(EmojiSequenceType::Basic));
+        assert_eq!(emoji_sequence_type("☺\u{fe0f}"), Some(EmojiSequenceType::Basic));
+        assert_eq!(emoji_sequence_type("\u{1f3ff}"), Some(EmojiSequenceType::Basic));
+        assert_eq!(emoji_sequence_type("☺"), None);
+        assert_eq!(emoji_sequence_type("#\u{fe0f}\u{20e3}"), Some(EmojiSequenceType::Keycap));
+        assert_eq!(emoji_sequence_type("#\u{20e3}"), None);
+        assert_eq!(emoji_sequence_type("🇯🇵"), Some(EmojiSequenceType::Flag));
+        assert_eq!(emoji_sequence_type("🇦🇦"), None);
+        assert_eq!(emoji_sequence_type("🇯"), None);
+        assert_eq!(emoji_sequence_type("🧑🏻"), Some(EmojiSequenceType::Modifier));
+        assert_eq!(emoji_sequence_type("🦀🏻"), None);
+        assert_eq!(emoji_sequence_type("👨\u{200d}👩\u{200d}👧"), Some(EmojiSequenceType::Zwj));
+        assert_eq!(emoji_sequence_type("🏳\u{fe0f}\u{200d}🌈"), Some(EmojiSequenceType::Zwj));
+        assert_eq!(emoji_sequence_type("🏴\u{e0067}\u{e0062}\u{e0065}\u{e006e}\u{e0067}\u{e007f}"),
+                   Some(EmojiSequenceType::Tag));
+        assert_eq!(emoji_sequence_type(""), None);
+        assert_eq!(emoji_sequence_type("😀😀"), None);
+    }
+}
@@ -22,10 +22,47 @@
 //! let graphemes = Graphemes::new("A\u{301}✋🏽🇦🇹!");
 //! assert_eq!(graphemes.collect::<Vec<&str>>(), ["A\u{301}", "✋🏽", "🇦🇹", "!"])
 //! ```
+//!
+//! `Graphemes` is also a `DoubleEndedIterator`, so clusters can be read from the end of the string
+//! (e.g., to find the cluster before a cursor position) without segmenting the text that precedes
+//! it. Forward and reverse iteration can be mixed on the same iterator.
+//! ```
+//! # use crate::finl_unicode::grapheme_clusters::Graphemes;
+//! let graphemes = Graphemes::new("A\u{301}✋🏽🇦🇹!");
+//! assert_eq!(graphemes.rev().collect::<Vec<&str>>(), ["!", "🇦🇹", "✋🏽", "A\u{301}"])
+//! ```
+//!
+//! Text which is mostly, but not necessarily, valid UTF-8 can be segmented without converting it
+//! to a `&str` first with `ByteGraphemes`, which returns byte slices. Each invalid sequence (as
+//! would be replaced by a single U+FFFD in a lossy conversion) is a cluster by itself.
+//! ```
+//! # use crate::finl_unicode::grapheme_clusters::ByteGraphemes;
+//! let graphemes = ByteGraphemes::new(b"A\xcc\x81\xff!\xe2\x9c");
+//! assert_eq!(graphemes.collect::<Vec<&[u8]>>(), [&b"A\xcc\x81"[..], b"\xff", b"!", b"\xe2\x9c"])
+//! ```
+//!
+//! Finally, `GraphemeCursor` finds the cluster boundaries around an arbitrary byte offset, looking
+//! at no more of the text than the segmentation rules require. It can work on text that is split
+//! into chunks (e.g., a rope) and will tell the caller when it needs text outside the current chunk.
+//!
+//! For text which arrives one `char` at a time (e.g., from a socket or a TeX input stack),
+//! `GraphemeSegmenter` is pushed each character in turn and reports where the clusters end without
+//! needing to look ahead or buffer anything.
+//! ```
+//! # use crate::finl_unicode::grapheme_clusters::{Boundary, GraphemeSegmenter};
+//! let mut segmenter = GraphemeSegmenter::new();
+//! assert_eq!(segmenter.push('e'), Boundary::None);
+//! assert_eq!(segmenter.push('\u{301}'), Boundary::None);
+//! assert_eq!(segmenter.push('\r'), Boundary::Before);
+//! assert_eq!(segmenter.push('\n'), Boundary::After);
+//! assert_eq!(segmenter.push('!'), Boundary::None);
+//! assert!(segmenter.finish());
+//! ```
 
-use std::iter::Peekable;
-use std::str::CharIndices;
-use std::str::Chars;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::iter::Peekable;
+use core::str::CharIndices;
 use crate::data::grapheme_property::{GP_PAGES,GP_TABLE};
 
 
@@ -33,6 +70,10 @@ use crate::data::grapheme_property::{GP_PAGES,GP_TABLE};
 pub struct Graphemes<'a> {
     input: &'a str,
     iter: Peekable<CharIndices<'a>>,
+    end: usize,
+    /// The number of regional indicators immediately before `end`, once `next_back` has counted
+    /// them, so that a long run of flags is only scanned once.
+    regional_indicators: Option<usize>,
 }
 
 impl<'a> Graphemes<'a> {
@@ -45,7 +86,9 @@ impl<'a> Graphemes<'a> {
         let iter = input.char_indices().peekable();
         Graphemes {
             input,
-            iter
+            iter,
+            end: input.len(),
+            regional_indicators: None,
         }
     }
 }
@@ -72,13 +115,13 @@ impl<'a> Iterator for Graphemes<'a> {
                                 if let Some(&(curr_loc, _)) = self.iter.peek() {
                                     &self.input[start..curr_loc]
                                 } else {
-                                    &self.input[start..]
+                                    &self.input[start..self.end]
                                 });
                         }
                     }
                 }
                 else {
-                    return Some(&self.input[start..]);
+                    return Some(&self.input[start..self.end]);
                 }
             }
         } else {
@@ -87,14 +130,118 @@ impl<'a> Iterator for Graphemes<'a> {
     }
 }
 
+impl<'a> DoubleEndedIterator for Graphemes<'a> {
+    #[inline]
+    /// Return a slice of the underlying string corresponding to the last cluster which has not
+    /// yet been returned by either `next` or `next_back`, or `None` if there are no clusters left.
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let end = self.end;
+        let mut cluster_machine = ReverseClusterMachine::new(self.regional_indicators);
+        // Cloning the iterator is cheap and lets us look at the last remaining character without
+        // consuming it. Anything the machine needs to know about the text before that character
+        // comes from `input` directly since it may already have been consumed by `next`.
+        let cluster = loop {
+            let Some((curr_loc, ch)) = self.iter.clone().next_back() else {
+                break if self.end < end {
+                    Some(&self.input[self.end..end])
+                } else {
+                    None
+                };
+            };
+            match cluster_machine.find_cluster(ch, self.input[..curr_loc].chars().rev()) {
+                Break::None => {
+                    self.iter.next_back();
+                    self.end = curr_loc;
+                }
+                Break::Before => {
+                    break Some(&self.input[self.end..end]);
+                }
+                Break::After => {
+                    self.iter.next_back();
+                    self.end = curr_loc;
+                    break Some(&self.input[curr_loc..end]);
+                }
+            }
+        };
+        self.regional_indicators = cluster_machine.regional_indicators;
+        cluster
+    }
+}
+
+/// `ByteGraphemes` provides an iterator over the grapheme clusters of a byte string which is
+/// expected to be UTF-8 but may contain invalid sequences. The clusters are the same as those
+/// returned by `Graphemes` for valid text. Each invalid sequence is returned as a cluster by itself,
+/// using the same maximal subparts that `String::from_utf8_lossy` replaces with U+FFFD.
+pub struct ByteGraphemes<'a> {
+    input: &'a [u8],
+    position: usize,
+}
+
+impl<'a> ByteGraphemes<'a> {
+    /// A new instance of `ByteGraphemes` can be constructed from a byte slice using `ByteGraphemes::new`
+    /// ```
+    /// # use crate::finl_unicode::grapheme_clusters::ByteGraphemes;
+    /// let graphemes = ByteGraphemes::new(b"some bytes");
+    /// ```
+    pub fn new(input: &'a [u8]) -> ByteGraphemes<'a> {
+        ByteGraphemes {
+            input,
+            position: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for ByteGraphemes<'a> {
+    type Item = &'a [u8];
+    #[inline]
+    /// Return a slice of the underlying bytes corresponding to the next cluster if one exists, or
+    /// `None` if the end of the input has been reached.
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.position;
+        if start == self.input.len() {
+            return None;
+        }
+        let (mut ch, mut len) = match decode_utf8(&self.input[start..]) {
+            Ok(decoded) => decoded,
+            Err(invalid_len) => {
+                self.position += invalid_len;
+                return Some(&self.input[start..self.position]);
+            }
+        };
+        let mut cluster_machine = ClusterMachine::new();
+        loop {
+            match cluster_machine.find_cluster(ch) {
+                Break::None => { self.position += len; }
+                Break::Before => {
+                    return Some(&self.input[start..self.position]);
+                }
+                Break::After => {
+                    self.position += len;
+                    return Some(&self.input[start..self.position]);
+                }
+            }
+            // The cluster also ends at the end of the input or before an invalid sequence
+            if self.position == self.input.len() {
+                return Some(&self.input[start..self.position]);
+            }
+            match decode_utf8(&self.input[self.position..]) {
+                Ok(decoded) => (ch, len) = decoded,
+                Err(_) => return Some(&self.input[start..self.position]),
+            }
+        }
+    }
+}
+
 /// Get the next grapheme cluster from a stream of characters or char indices
 /// This trait is implemented for any `Peekable` iterator over either `char` or `(usize, char)` (so
 /// it will work on `Peekable<Chars>` and `Peekable<CharIndices>` as well as any other peekable iterator
-/// which meets this requirement.
+/// which meets this requirement. This needs the `alloc` feature.
+#[cfg(feature = "alloc")]
 pub trait GraphemeCluster<T> {
     fn next_cluster(&mut self) -> Option<String>;
 }
 
+#[cfg(feature = "alloc")]
 impl<T> GraphemeCluster<T> for T where T: PeekChar {
     /// Returns the next cluster if there is one in an `Option<String>`. Since this has a heap allocation
     /// it is *not* recommended for iterating over all the clusters in a string. In that case, use
@@ -104,23 +251,19 @@ impl<T> GraphemeCluster<T> for T where T: PeekChar {
         if self.has_next() {
             let mut cluster_machine = ClusterMachine::new();
             let mut rv = String::new();
-            loop {
-                if let Some(ch) = self.peek_char() {
-                    let state = cluster_machine.find_cluster(ch);
-                    match state {
-                        Break::None => {
-                            rv.push(ch);
-                            self.next();
-                        }
-                        Break::Before => { return Some(rv); }
-                        Break::After => {
-                            rv.push(ch);
-                            self.next();
-                            return Some(rv);
-                        }
+            while let Some(ch) = self.peek_char() {
+                let state = cluster_machine.find_cluster(ch);
+                match state {
+                    Break::None => {
+                        rv.push(ch);
+                        self.next();
+                    }
+                    Break::Before => { return Some(rv); }
+                    Break::After => {
+                        rv.push(ch);
+                        self.next();
+                        return Some(rv);
                     }
-                } else {
-                    break;
                 }
             }
             Some(rv)
@@ -171,6 +314,308 @@ where CharIter: Iterator<Item = C>
     }
 }
 
+/// `GraphemeCursor` answers questions about grapheme cluster boundaries at arbitrary byte offsets
+/// in a piece of text without segmenting the text from its start. Only the characters around the
+/// offset are examined, along with as much of the preceding text as the emoji ZWJ and regional
+/// indicator rules need.
+///
+/// The text does not need to be in a single `&str`. Each method takes a `chunk` of the text along
+/// with `chunk_start`, the offset of that chunk within the complete text. If the cursor needs text
+/// that is not in the chunk, it returns a `GraphemeIncomplete` describing what it needs so that the
+/// caller can retry with the appropriate chunk (or supply preceding text with `provide_context`).
+/// If the whole text is available in one `&str`, pass it with a `chunk_start` of `0` and the
+/// methods will only fail for invalid offsets.
+/// ```
+/// # use crate::finl_unicode::grapheme_clusters::GraphemeCursor;
+/// let text = "A\u{301}✋🏽🇦🇹!";
+/// let mut cursor = GraphemeCursor::new(6, text.len());
+/// assert_eq!(cursor.is_boundary(text, 0), Ok(false));
+/// assert_eq!(cursor.next_boundary(text, 0), Ok(Some(10)));
+/// assert_eq!(cursor.prev_boundary(text, 0), Ok(Some(3)));
+/// assert_eq!(cursor.prev_boundary(text, 0), Ok(Some(0)));
+/// assert_eq!(cursor.prev_boundary(text, 0), Ok(None));
+/// ```
+pub struct GraphemeCursor {
+    offset: usize,
+    len: usize,
+    boundary: Option<bool>,
+    pending: Option<Pending>,
+    resuming: bool,
+}
+
+/// The reasons a `GraphemeCursor` could not answer a query from the chunk it was given.
+#[derive(PartialEq, Eq, Debug)]
+pub enum GraphemeIncomplete {
+    /// Text ending at the given offset is needed. Pass the chunk which ends there to
+    /// `provide_context` and then repeat the query.
+    PreContext(usize),
+    /// The cursor moved to the start of the chunk. Repeat the query with the preceding chunk.
+    PrevChunk,
+    /// The cursor moved to the end of the chunk. Repeat the query with the following chunk.
+    NextChunk,
+    /// The cursor is not inside the chunk or is not on a character boundary.
+    InvalidOffset,
+}
+
+impl GraphemeCursor {
+    /// Create a new cursor at byte `offset` in a text which is `len` bytes long.
+    pub fn new(offset: usize, len: usize) -> GraphemeCursor {
+        GraphemeCursor {
+            offset,
+            len,
+            boundary: None,
+            pending: None,
+            resuming: false,
+        }
+    }
+
+    /// The current byte offset of the cursor.
+    pub fn cur_cursor(&self) -> usize {
+        self.offset
+    }
+
+    /// Move the cursor to byte `offset`.
+    pub fn set_cursor(&mut self, offset: usize) {
+        if offset != self.offset {
+            self.offset = offset;
+            self.reset();
+        }
+    }
+
+    /// Returns `true` if there is a grapheme cluster boundary at the cursor. The start and end of
+    /// the text are always boundaries. The chunk must contain the cursor, and unless the cursor is at
+    /// the start or end of the text, the character following it.
+    pub fn is_boundary(&mut self, chunk: &str, chunk_start: usize) -> Result<bool, GraphemeIncomplete> {
+        if self.offset == 0 || self.offset == self.len {
+            return Ok(true);
+        }
+        if let Some(boundary) = self.boundary {
+            return Ok(boundary);
+        }
+        // Unless this chunk reaches further back than the text we're waiting for, we can't do
+        // any better than last time.
+        if let Some(pending) = &self.pending {
+            if chunk_start >= pending.context_end {
+                return Err(GraphemeIncomplete::PreContext(pending.context_end));
+            }
+        }
+        let pos = self.chunk_offset(chunk, chunk_start)?;
+        let following = chunk[pos..].chars().next().ok_or(GraphemeIncomplete::NextChunk)?;
+        self.decide(following, &chunk[..pos], chunk_start)
+    }
+
+    /// Supply the text preceding the chunk most recently passed to the cursor after a
+    /// `GraphemeIncomplete::PreContext` error. `chunk` must end at the offset given in the error.
+    pub fn provide_context(&mut self, chunk: &str, chunk_start: usize) {
+        if let Some(pending) = self.pending.take() {
+            assert_eq!(chunk_start + chunk.len(), pending.context_end, "Context chunk does not end where it was requested");
+            let _ = match pending.scan {
+                PendingScan::Preceding(following) => self.decide(following, chunk, chunk_start),
+                PendingScan::Lookbehind(lookbehind) => self.resume(lookbehind, chunk.chars().rev(), chunk_start),
+            };
+        }
+    }
+
+    /// Move the cursor to the next boundary and return its offset, or `None` if the cursor is
+    /// already at the end of the text.
+    pub fn next_boundary(&mut self, chunk: &str, chunk_start: usize) -> Result<Option<usize>, GraphemeIncomplete> {
+        if self.offset == self.len {
+            return Ok(None);
+        }
+        loop {
+            if !self.resuming {
+                let pos = self.chunk_offset(chunk, chunk_start)?;
+                let ch = chunk[pos..].chars().next().ok_or(GraphemeIncomplete::NextChunk)?;
+                self.offset += ch.len_utf8();
+                self.reset();
+                self.resuming = true;
+            }
+            if self.is_boundary(chunk, chunk_start)? {
+                self.resuming = false;
+                return Ok(Some(self.offset));
+            }
+            self.resuming = false;
+        }
+    }
+
+    /// Move the cursor to the previous boundary and return its offset, or `None` if the cursor is
+    /// already at the start of the text.
+    pub fn prev_boundary(&mut self, chunk: &str, chunk_start: usize) -> Result<Option<usize>, GraphemeIncomplete> {
+        if self.offset == 0 {
+            return Ok(None);
+        }
+        loop {
+            if !self.resuming {
+                let pos = self.chunk_offset(chunk, chunk_start)?;
+                let ch = chunk[..pos].chars().next_back().ok_or(GraphemeIncomplete::PrevChunk)?;
+                self.offset -= ch.len_utf8();
+                self.reset();
+                self.resuming = true;
+            }
+            if self.is_boundary(chunk, chunk_start)? {
+                self.resuming = false;
+                return Ok(Some(self.offset));
+            }
+            self.resuming = false;
+        }
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.boundary = None;
+        self.pending = None;
+    }
+
+    /// The position of the cursor within `chunk`
+    #[inline]
+    fn chunk_offset(&self, chunk: &str, chunk_start: usize) -> Result<usize, GraphemeIncomplete> {
+        match self.offset.checked_sub(chunk_start) {
+            Some(pos) if chunk.is_char_boundary(pos) => Ok(pos),
+            _ => Err(GraphemeIncomplete::InvalidOffset)
+        }
+    }
+
+    /// Decide whether there is a boundary before `following` given the text `preceding` which
+    /// ends at the cursor and starts at `chunk_start`.
+    fn decide(&mut self, following: char, preceding: &str, chunk_start: usize) -> Result<bool, GraphemeIncomplete> {
+        let mut preceding_chars = preceding.chars().rev();
+        match preceding_chars.next() {
+            None => {
+                self.pending = Some(Pending {
+                    scan: PendingScan::Preceding(following),
+                    context_end: chunk_start,
+                });
+                Err(GraphemeIncomplete::PreContext(chunk_start))
+            }
+            Some(c) => match boundary_rule(c, following) {
+                Ok(boundary) => {
+                    self.boundary = Some(boundary);
+                    Ok(boundary)
+                }
+                Err(lookbehind) => self.resume(lookbehind, preceding_chars, chunk_start),
+            }
+        }
+    }
+
+    fn resume<I: Iterator<Item = char>>(&mut self, lookbehind: Lookbehind, preceding: I, chunk_start: usize) -> Result<bool, GraphemeIncomplete> {
+        let joins = match lookbehind.scan(preceding) {
+            Ok(joins) => joins,
+            Err(lookbehind) if chunk_start == 0 => lookbehind.at_start(),
+            Err(lookbehind) => {
+                self.pending = Some(Pending {
+                    scan: PendingScan::Lookbehind(lookbehind),
+                    context_end: chunk_start,
+                });
+                return Err(GraphemeIncomplete::PreContext(chunk_start));
+            }
+        };
+        self.boundary = Some(!joins);
+        Ok(!joins)
+    }
+}
+
+/// A push-based interface to grapheme cluster segmentation. Each character of the text is passed
+/// to `push` in turn, and the returned `Boundary` says where the current cluster ends:
+///
+/// * `Boundary::None`: the character is part of the current cluster.
+/// * `Boundary::Before`: there is a boundary before the character, which begins a new cluster.
+/// * `Boundary::After`: the character is the last one in the current cluster, so the next character
+///   will begin a new one.
+///
+/// Each boundary is reported exactly once. A character which is a cluster by itself and follows
+/// another cluster (e.g., a control character after a letter) returns `Boundary::Before` and the
+/// boundary after it is reported by the next call to `push` (as `Boundary::Before`) or by `finish`.
+///
+/// The segmenter never needs to see a character before it is pushed, so it can be used with any
+/// source of characters without buffering. When the text ends, call `finish` to close off the
+/// last cluster.
+pub struct GraphemeSegmenter {
+    machine: ClusterMachine,
+    state: SegmenterState,
+}
+
+impl GraphemeSegmenter {
+    /// Create a segmenter at the start of the text.
+    #[inline]
+    pub fn new() -> GraphemeSegmenter {
+        GraphemeSegmenter {
+            machine: ClusterMachine::new(),
+            state: SegmenterState::Start,
+        }
+    }
+
+    /// Feed the next character of the text to the segmenter and find out whether there is a cluster
+    /// boundary before or after it.
+    #[inline]
+    pub fn push(&mut self, c: char) -> Boundary {
+        match self.state {
+            SegmenterState::Start => {
+                if self.start_cluster(c) {
+                    self.state = SegmenterState::Start;
+                    Boundary::After
+                } else {
+                    Boundary::None
+                }
+            }
+            SegmenterState::Unreported => {
+                self.start_cluster(c);
+                Boundary::Before
+            }
+            SegmenterState::InCluster => match self.machine.find_cluster(c) {
+                Break::None => Boundary::None,
+                Break::Before => {
+                    self.start_cluster(c);
+                    Boundary::Before
+                }
+                Break::After => {
+                    self.state = SegmenterState::Start;
+                    Boundary::After
+                }
+            }
+        }
+    }
+
+    /// Mark the end of the text. Returns `true` if there is a boundary at the end of the text which
+    /// has not already been reported by `push`, i.e., if any characters were pushed since the last
+    /// boundary. The segmenter is reset, so it can be used again from the start of a new text.
+    #[inline]
+    pub fn finish(&mut self) -> bool {
+        let unreported = self.state != SegmenterState::Start;
+        *self = GraphemeSegmenter::new();
+        unreported
+    }
+
+    /// Begin a new cluster with `c`, returning `true` if `c` is a cluster by itself.
+    #[inline]
+    fn start_cluster(&mut self, c: char) -> bool {
+        self.machine = ClusterMachine::new();
+        if self.machine.find_cluster(c) == Break::After {
+            self.state = SegmenterState::Unreported;
+            true
+        } else {
+            self.state = SegmenterState::InCluster;
+            false
+        }
+    }
+}
+
+impl Default for GraphemeSegmenter {
+    fn default() -> Self {
+        GraphemeSegmenter::new()
+    }
+}
+
+/// Where a character pushed to a `GraphemeSegmenter` falls relative to the cluster boundaries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Boundary {
+    /// The character is part of the current cluster
+    None,
+    /// There is a boundary before the character, which begins a new cluster
+    Before,
+    /// The character is the last one in the current cluster
+    After,
+}
+
 // ------------------------
 // Private implementation details follow
 
@@ -190,15 +635,56 @@ enum ClusterMachineState {
     Other,
 }
 
-#[derive(Debug, PartialEq)]
-enum Break {
+/// Tracks the Indic conjunct sequence (GB9c) which ends at the current character.
+#[derive(PartialEq, Clone, Copy)]
+enum ConjunctState {
+    /// Not in a conjunct sequence
+    Outside,
+    /// We have seen a consonant and possibly some `InCB=Extend` characters
+    Consonant,
+    /// We have seen a consonant followed by at least one linker, so another consonant will join
+    Linked,
+}
+
+impl ConjunctState {
+    /// The state after a character with the given property
+    #[inline]
+    fn advance(self, property: u8) -> ConjunctState {
+        match property & GraphemeProperty::CONJUNCT_MASK {
+            GraphemeProperty::INCB_CONSONANT => ConjunctState::Consonant,
+            GraphemeProperty::INCB_LINKER if self != ConjunctState::Outside => ConjunctState::Linked,
+            GraphemeProperty::INCB_EXTEND => self,
+            _ => ConjunctState::Outside,
+        }
+    }
+}
+
+/// The result of feeding a character to one of the segmentation state machines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Break {
+    /// The character is part of the current segment
     None,
+    /// The current segment ends before the character
     Before,
+    /// The character is the last one in the current segment (or the first one when running
+    /// backwards)
     After,
 }
 
+/// Where a `GraphemeSegmenter` is relative to the clusters it has reported
+#[derive(PartialEq, Clone, Copy)]
+enum SegmenterState {
+    /// At the start of the text or just after a reported boundary
+    Start,
+    /// Inside a cluster which has not ended yet
+    InCluster,
+    /// The previous character ended a cluster, but the boundary after it has not been reported
+    Unreported,
+}
+
 struct ClusterMachine {
     state: ClusterMachineState,
+    conjunct: ConjunctState,
 }
 
 impl ClusterMachine {
@@ -206,6 +692,7 @@ impl ClusterMachine {
     pub fn new() -> ClusterMachine {
         ClusterMachine {
             state: ClusterMachineState::Start,
+            conjunct: ConjunctState::Outside,
         }
     }
 
@@ -213,10 +700,15 @@ impl ClusterMachine {
     /// If the `bool` is true, it means that we are also consuming the character  in the cluster.
     #[inline]
     pub fn find_cluster(&mut self, c: char) -> Break {
+        let properties = get_properties(c);
+        let conjunct = self.conjunct;
+        // Once we break, the machine is only used to start a new cluster, so the conjunct state
+        // can be updated up front.
+        self.conjunct = conjunct.advance(properties);
         if self.state == ClusterMachineState::Start {
             return self.first_character(c);
         }
-        let property = get_property(c);
+        let property = properties & GraphemeProperty::BASE_MASK;
 
         if property == GraphemeProperty::CONTROL {
             return if self.state == ClusterMachineState::CrLf && c == '\n' {
@@ -232,6 +724,13 @@ impl ClusterMachine {
             }
         }
 
+        // GB9c: a consonant joins a preceding consonant and linker. The consonant is `Other` for
+        // the rest of the rules, so the cluster continues as if it had started with it.
+        if conjunct == ConjunctState::Linked && properties & GraphemeProperty::INCB_CONSONANT != 0 {
+            self.state = ClusterMachineState::Other;
+            return Break::None;
+        }
+
         match self.state {
             ClusterMachineState::Start => self.first_character(c),
             ClusterMachineState::Precore => {
@@ -323,10 +822,15 @@ impl ClusterMachine {
                         self.state = ClusterMachineState::EmojiZWJ;
                         Break::None
                     }
-                    GraphemeProperty::EXTEND | GraphemeProperty::SPACING_MARK => {
+                    GraphemeProperty::EXTEND => {
                         self.state = ClusterMachineState::Emoji;
                         Break::None
                     }
+                    GraphemeProperty::SPACING_MARK => {
+                        // GB11 only allows Extend between the pictograph and the ZWJ
+                        self.state = ClusterMachineState::Other;
+                        Break::None
+                    }
                     _ => {
                         self.first_character(c);
                         Break::Before
@@ -337,7 +841,11 @@ impl ClusterMachine {
                 if property == GraphemeProperty::EXTENDED_GRAPHEME {
                     self.state = ClusterMachineState::Emoji;
                     Break::None
+                } else if is_continuation(property) {
+                    self.state = ClusterMachineState::Other;
+                    Break::None
                 } else {
+                    self.first_character(c);
                     Break::Before
                 }
             }
@@ -402,6 +910,239 @@ impl ClusterMachine {
     }
 }
 
+#[derive(PartialEq)]
+enum ReverseClusterMachineState {
+    Start,
+    Lf,
+    Cluster,
+    Flag,
+}
+
+/// The backward-running counterpart of `ClusterMachine`. Characters are fed from the end of the
+/// text towards the start and for each one we decide whether it belongs to the cluster collected
+/// so far. Most rules only depend on the character and the one following it, but GB9c (Indic
+/// conjuncts), GB11 (emoji ZWJ sequences) and GB12/GB13 (regional indicator pairs) depend on what
+/// comes *before* the character, so `find_cluster` also receives the preceding characters in
+/// reverse order. These are only read when one of those rules applies.
+struct ReverseClusterMachine {
+    state: ReverseClusterMachineState,
+    following: u8,
+    /// The number of regional indicators immediately before the first character of the cluster
+    /// so far, if it is known. Within a run of regional indicators this lets each character's
+    /// count be worked out from the one after it rather than scanning the run again.
+    regional_indicators: Option<usize>,
+}
+
+impl ReverseClusterMachine {
+    /// Start a new cluster. `regional_indicators` is the count left by the machine which found the
+    /// cluster following this one, if any.
+    #[inline]
+    pub fn new(regional_indicators: Option<usize>) -> ReverseClusterMachine {
+        ReverseClusterMachine {
+            state: ReverseClusterMachineState::Start,
+            following: 0,
+            regional_indicators,
+        }
+    }
+
+    /// `Break::None` means that `c` is part of the cluster, `Break::Before` means that the cluster
+    /// starts after `c` (so `c` is not part of it) and `Break::After` means that `c` is part of the
+    /// cluster and is also its first character.
+    #[inline]
+    pub fn find_cluster<I: Iterator<Item = char>>(&mut self, c: char, preceding: I) -> Break {
+        let property = get_properties(c);
+        // The number of regional indicators before `c` if `c` is one of the run we have counted
+        let mut regional_indicators = if property & GraphemeProperty::BASE_MASK == GraphemeProperty::REGIONAL_INDICATOR {
+            self.regional_indicators.and_then(|count| count.checked_sub(1))
+        } else {
+            None
+        };
+        match self.state {
+            ReverseClusterMachineState::Start => {
+                self.regional_indicators = regional_indicators;
+                if property == GraphemeProperty::CONTROL {
+                    if c == '\n' {
+                        self.state = ReverseClusterMachineState::Lf;
+                        return Break::None;
+                    }
+                    return Break::After;
+                }
+                self.state = ReverseClusterMachineState::Cluster;
+                self.following = property;
+                return Break::None;
+            }
+            ReverseClusterMachineState::Lf => {
+                return if c == '\r' {
+                    self.regional_indicators = None;
+                    Break::After
+                } else {
+                    Break::Before
+                };
+            }
+            _ => {}
+        }
+        if property == GraphemeProperty::CONTROL {
+            return Break::Before;
+        }
+        let (joins, state) = match join_rule(property, self.following) {
+            Join::Yes => (true, ReverseClusterMachineState::Cluster),
+            Join::No => (false, ReverseClusterMachineState::Cluster),
+            Join::IfConjunct(linked) => (Lookbehind::Conjunct(linked).resolve(preceding), ReverseClusterMachineState::Cluster),
+            Join::IfPictographic => (Lookbehind::Pictographic.resolve(preceding), ReverseClusterMachineState::Cluster),
+            // If the following indicator is already paired, it can't pair with this one
+            Join::IfEvenRegionalIndicators if self.state == ReverseClusterMachineState::Flag => (false, ReverseClusterMachineState::Flag),
+            Join::IfEvenRegionalIndicators => {
+                let count = *regional_indicators.get_or_insert_with(|| preceding
+                    .take_while(|&c| get_property(c) == GraphemeProperty::REGIONAL_INDICATOR)
+                    .count());
+                (count & 1 == 0, ReverseClusterMachineState::Flag)
+            }
+        };
+        if joins {
+            self.state = state;
+            self.following = property;
+            self.regional_indicators = regional_indicators;
+            Break::None
+        } else {
+            Break::Before
+        }
+    }
+}
+
+/// How two adjacent characters, neither of which is a control character, combine.
+#[derive(Debug, PartialEq)]
+enum Join {
+    Yes,
+    No,
+    /// GB9c: join if the first character (a linker or `InCB=Extend`) is preceded by a consonant and
+    /// any number of linkers and `InCB=Extend` characters with at least one linker among them. The
+    /// flag records whether the first character is itself a linker.
+    IfConjunct(bool),
+    /// GB11: join if the first character (a ZWJ) is preceded by an extended pictograph and any
+    /// number of `Extend` characters.
+    IfPictographic,
+    /// GB12/GB13: join if the first character (a regional indicator) is preceded by an even number
+    /// of regional indicators.
+    IfEvenRegionalIndicators,
+}
+
+/// `property` and `following` are the full values from the data tables, including the Indic
+/// conjunct break bits.
+#[inline]
+fn join_rule(property: u8, following: u8) -> Join {
+    if following & GraphemeProperty::INCB_CONSONANT != 0
+        && property & (GraphemeProperty::INCB_LINKER | GraphemeProperty::INCB_EXTEND) != 0 {
+        return Join::IfConjunct(property & GraphemeProperty::INCB_LINKER != 0);
+    }
+    match (property & GraphemeProperty::BASE_MASK, following & GraphemeProperty::BASE_MASK) {
+        (_, following) if is_continuation(following) => Join::Yes,
+        (GraphemeProperty::PREPEND, _) => Join::Yes,
+        (GraphemeProperty::L, GraphemeProperty::L | GraphemeProperty::V | GraphemeProperty::LV | GraphemeProperty::LVT) => Join::Yes,
+        (GraphemeProperty::V | GraphemeProperty::LV, GraphemeProperty::V | GraphemeProperty::T) => Join::Yes,
+        (GraphemeProperty::T | GraphemeProperty::LVT, GraphemeProperty::T) => Join::Yes,
+        (GraphemeProperty::ZWJ, GraphemeProperty::EXTENDED_GRAPHEME) => Join::IfPictographic,
+        (GraphemeProperty::REGIONAL_INDICATOR, GraphemeProperty::REGIONAL_INDICATOR) => Join::IfEvenRegionalIndicators,
+        _ => Join::No,
+    }
+}
+
+/// Decides whether there is a boundary between `c` and `following`. If that depends on the
+/// text before `c`, we return the `Lookbehind` that will decide it.
+#[inline]
+fn boundary_rule(c: char, following: char) -> Result<bool, Lookbehind> {
+    let property = get_properties(c);
+    if get_property(following) == GraphemeProperty::CONTROL {
+        return Ok(!(c == '\r' && following == '\n'));
+    }
+    if property == GraphemeProperty::CONTROL {
+        return Ok(true);
+    }
+    match join_rule(property, get_properties(following)) {
+        Join::Yes => Ok(false),
+        Join::No => Ok(true),
+        Join::IfConjunct(linked) => Err(Lookbehind::Conjunct(linked)),
+        Join::IfPictographic => Err(Lookbehind::Pictographic),
+        Join::IfEvenRegionalIndicators => Err(Lookbehind::RegionalIndicators(0)),
+    }
+}
+
+/// A decision by `GraphemeCursor` that is waiting for the text which ends at `context_end`.
+struct Pending {
+    scan: PendingScan,
+    context_end: usize,
+}
+
+enum PendingScan {
+    /// We need the character before the cursor. The character after it is kept.
+    Preceding(char),
+    Lookbehind(Lookbehind),
+}
+
+/// A scan backwards through the text for one of the context-dependent rules. The scan can be
+/// suspended when we run out of text and resumed when more is provided.
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Lookbehind {
+    /// Whether a linker has been seen so far
+    Conjunct(bool),
+    Pictographic,
+    RegionalIndicators(usize),
+}
+
+impl Lookbehind {
+    /// Continue the scan over `preceding` (in reverse order). Returns whether the characters on
+    /// either side of the position being tested join, or the updated scan if `preceding` ran out
+    /// before that could be decided.
+    #[inline]
+    fn scan<I: Iterator<Item = char>>(self, preceding: I) -> Result<bool, Lookbehind> {
+        match self {
+            Lookbehind::Conjunct(mut linked) => {
+                for c in preceding {
+                    match get_properties(c) & GraphemeProperty::CONJUNCT_MASK {
+                        GraphemeProperty::INCB_LINKER => linked = true,
+                        GraphemeProperty::INCB_EXTEND => {}
+                        GraphemeProperty::INCB_CONSONANT => return Ok(linked),
+                        _ => return Ok(false),
+                    }
+                }
+                Err(Lookbehind::Conjunct(linked))
+            }
+            Lookbehind::Pictographic => {
+                for c in preceding {
+                    let property = get_property(c);
+                    if property != GraphemeProperty::EXTEND {
+                        return Ok(property == GraphemeProperty::EXTENDED_GRAPHEME);
+                    }
+                }
+                Err(self)
+            }
+            Lookbehind::RegionalIndicators(mut count) => {
+                for c in preceding {
+                    if get_property(c) != GraphemeProperty::REGIONAL_INDICATOR {
+                        return Ok(count & 1 == 0);
+                    }
+                    count += 1;
+                }
+                Err(Lookbehind::RegionalIndicators(count))
+            }
+        }
+    }
+
+    /// Finish the scan at the start of the text.
+    #[inline]
+    fn at_start(self) -> bool {
+        match self {
+            Lookbehind::Conjunct(_) | Lookbehind::Pictographic => false,
+            Lookbehind::RegionalIndicators(count) => count & 1 == 0,
+        }
+    }
+
+    /// Scan `preceding`, treating its end as the start of the text.
+    #[inline]
+    fn resolve<I: Iterator<Item = char>>(self, preceding: I) -> bool {
+        self.scan(preceding).unwrap_or_else(Lookbehind::at_start)
+    }
+}
+
 #[inline]
 fn is_continuation(property: u8) -> bool {
     property != 0 && property & 0xc == 0
@@ -423,11 +1164,58 @@ impl GraphemeProperty {
     const T: u8 = 0x09;
     const LV: u8 = 0x0d;
     const LVT: u8 = 0x0e;
+    // The Grapheme_Cluster_Break value is in the low nibble
+    const BASE_MASK: u8 = 0x0f;
+    // Indic_Conjunct_Break values are in the high nibble
+    const CONJUNCT_MASK: u8 = 0xf0;
+    const INCB_CONSONANT: u8 = 0x10;
+    const INCB_LINKER: u8 = 0x20;
+    const INCB_EXTEND: u8 = 0x40;
 }
 
 
+/// Decode the UTF-8 sequence at the start of `bytes` (which must not be empty), returning the
+/// character and its length in bytes. An invalid sequence, including one which is cut off by the
+/// end of `bytes`, gives `Err` with the length of its maximal subpart: the lead byte and any
+/// continuation bytes which could still have formed a valid sequence with it. This is always at
+/// least 1 and is what `String::from_utf8_lossy` replaces with a single U+FFFD.
+#[inline]
+fn decode_utf8(bytes: &[u8]) -> Result<(char, usize), usize> {
+    let first = bytes[0];
+    // The allowed range of the second byte excludes overlong encodings, surrogates and values
+    // beyond U+10FFFF.
+    let (len, second) = match first {
+        0x00..=0x7f => return Ok((char::from(first), 1)),
+        0xc2..=0xdf => (2, 0x80..=0xbf),
+        0xe0 => (3, 0xa0..=0xbf),
+        0xe1..=0xec | 0xee..=0xef => (3, 0x80..=0xbf),
+        0xed => (3, 0x80..=0x9f),
+        0xf0 => (4, 0x90..=0xbf),
+        0xf1..=0xf3 => (4, 0x80..=0xbf),
+        0xf4 => (4, 0x80..=0x8f),
+        _ => return Err(1),
+    };
+    let mut code = u32::from(first) & (0x7f >> len);
+    for index in 1..len {
+        match bytes.get(index) {
+            Some(&byte) if index == 1 && second.contains(&byte) || index > 1 && byte & 0xc0 == 0x80 => {
+                code = (code << 6) | u32::from(byte & 0x3f);
+            }
+            _ => return Err(index),
+        }
+    }
+    char::from_u32(code).map(|c| (c, len)).ok_or(len)
+}
+
+/// The Grapheme_Cluster_Break property of `c`
 #[inline]
 fn get_property(c: char) -> u8 {
+    get_properties(c) & GraphemeProperty::BASE_MASK
+}
+
+/// The full value from the data tables including the Indic_Conjunct_Break bits
+#[inline]
+fn get_properties(c: char) -> u8 {
     GP_PAGES[usize::from(GP_TABLE[(c as usize) >> 8])][(c as usize) & 0xff]
 }
 
@@ -446,6 +1234,139 @@ pub (crate) mod tests {
         assert_eq!(machine.find_cluster('\n'), Break::After);
     }
 
+    #[test]
+    fn segmenter_reports_end_of_text() {
+        let mut segmenter = GraphemeSegmenter::new();
+        assert!(!segmenter.finish());
+        assert_eq!(segmenter.push('a'), Boundary::None);
+        assert_eq!(segmenter.push('\u{1f1e6}'), Boundary::Before);
+        assert_eq!(segmenter.push('\u{1f1f9}'), Boundary::None);
+        assert_eq!(segmenter.push('\u{1f1e9}'), Boundary::Before);
+        assert_eq!(segmenter.push('\n'), Boundary::Before);
+        assert!(segmenter.finish());
+        assert_eq!(segmenter.push('\n'), Boundary::After);
+        assert!(!segmenter.finish());
+        assert_eq!(segmenter.push('a'), Boundary::None);
+        assert_eq!(segmenter.push('\t'), Boundary::Before);
+        assert_eq!(segmenter.push('\u{301}'), Boundary::Before);
+        assert!(segmenter.finish());
+    }
+
+    #[test]
+    fn marks_continue_after_emoji_zwj() {
+        // GB9 and GB9a apply after a ZWJ even when it follows a pictograph
+        let clusters = Graphemes::new("\u{1f600}\u{200d}\u{301}\u{1f600}\u{200d}\u{200d}a").collect::<Vec<&str>>();
+        assert_eq!(clusters, ["\u{1f600}\u{200d}\u{301}", "\u{1f600}\u{200d}\u{200d}", "a"]);
+        // The character which breaks the sequence starts the next cluster
+        let mut machine = ClusterMachine::new();
+        assert_eq!(machine.find_cluster('\u{1f600}'), Break::None);
+        assert_eq!(machine.find_cluster('\u{200d}'), Break::None);
+        assert_eq!(machine.find_cluster('a'), Break::Before);
+        assert_eq!(machine.find_cluster('\u{1f600}'), Break::Before);
+    }
+
+    #[test]
+    fn spacing_mark_ends_emoji_zwj_sequence() {
+        // The spacing mark stays in the cluster (GB9a) but a ZWJ after it does not join the
+        // following pictograph (GB11)
+        let clusters = Graphemes::new("\u{1f600}\u{903}\u{200d}\u{1f600}").collect::<Vec<&str>>();
+        assert_eq!(clusters, ["\u{1f600}\u{903}\u{200d}", "\u{1f600}"]);
+    }
+
+    #[test]
+    fn reverse_low_level_interface_test() {
+        let mut machine = ReverseClusterMachine::new(None);
+        assert_eq!(machine.find_cluster('\n', "\r".chars().rev()), Break::None);
+        assert_eq!(machine.find_cluster('\r', "".chars().rev()), Break::After);
+        let mut machine = ReverseClusterMachine::new(None);
+        assert_eq!(machine.find_cluster('\u{301}', "a".chars().rev()), Break::None);
+        assert_eq!(machine.find_cluster('a', "\r".chars().rev()), Break::None);
+        assert_eq!(machine.find_cluster('\r', "".chars().rev()), Break::Before);
+    }
+
+    #[test]
+    fn can_get_clusters_from_both_ends() {
+        let mut graphemes = Graphemes::new("\r\ne\u{301}🇦🇹🇩🇪👩\u{200d}👩\u{200d}👧f");
+        assert_eq!(graphemes.next_back(), Some("f"));
+        assert_eq!(graphemes.next(), Some("\r\n"));
+        assert_eq!(graphemes.next_back(), Some("👩\u{200d}👩\u{200d}👧"));
+        assert_eq!(graphemes.next_back(), Some("🇩🇪"));
+        assert_eq!(graphemes.next(), Some("e\u{301}"));
+        assert_eq!(graphemes.next_back(), Some("🇦🇹"));
+        assert_eq!(graphemes.next(), None);
+        assert_eq!(graphemes.next_back(), None);
+    }
+
+    #[test]
+    fn long_flag_runs_match_in_both_directions() {
+        for count in [1000, 1001] {
+            let input = "a\u{301}".to_string() + &"\u{1f1e6}".repeat(count) + "\u{301}b";
+            let forward = Graphemes::new(&input).collect::<Vec<&str>>();
+            let mut backward = Graphemes::new(&input).rev().collect::<Vec<&str>>();
+            backward.reverse();
+            assert_eq!(forward, backward);
+            assert_eq!(forward.len(), count / 2 + count % 2 + 2);
+        }
+        let mut graphemes = Graphemes::new("\u{1f1e6}\u{1f1f9}\u{1f1e9}\u{1f1ea}\u{1f1eb}");
+        assert_eq!(graphemes.next_back(), Some("\u{1f1eb}"));
+        assert_eq!(graphemes.next(), Some("\u{1f1e6}\u{1f1f9}"));
+        assert_eq!(graphemes.next_back(), Some("\u{1f1e9}\u{1f1ea}"));
+        assert_eq!(graphemes.next_back(), None);
+    }
+
+    #[test]
+    #[cfg(not(any(feature = "unicode_14", feature = "unicode_15")))]
+    fn can_get_conjunct_clusters() {
+        // क्‍ष त्त क्ष with a ZWJ after the first virama
+        let input = "\u{915}\u{94d}\u{200d}\u{937}\u{924}\u{94d}\u{924}\u{915}\u{94d}";
+        let expected = ["\u{915}\u{94d}\u{200d}\u{937}", "\u{924}\u{94d}\u{924}", "\u{915}\u{94d}"];
+        assert_eq!(Graphemes::new(input).collect::<Vec<&str>>(), expected);
+        assert_eq!(Graphemes::new(input).rev().collect::<Vec<&str>>(), expected.iter().rev().copied().collect::<Vec<&str>>());
+        let mut cursor = GraphemeCursor::new(9, input.len());
+        assert_eq!(cursor.is_boundary(&input[9..], 9), Err(GraphemeIncomplete::PreContext(9)));
+        cursor.provide_context(&input[..9], 0);
+        assert_eq!(cursor.is_boundary(&input[9..], 9), Ok(false));
+    }
+
+    #[test]
+    fn conjuncts_follow_the_unicode_version() {
+        // GB9c was added in Unicode 15.1
+        let clusters = Graphemes::new("\u{915}\u{94d}\u{937}").collect::<Vec<&str>>();
+        if cfg!(any(feature = "unicode_14", feature = "unicode_15")) {
+            assert_eq!(clusters, ["\u{915}\u{94d}", "\u{937}"]);
+        } else {
+            assert_eq!(clusters, ["\u{915}\u{94d}\u{937}"]);
+        }
+    }
+
+    #[test]
+    fn invalid_utf8_sequences_are_clusters() {
+        let input = b"e\xcc\x81\xcc!\xed\xa0\x80\xf0\x9f\x87\xa6\xf0\x9f\x87\xb9\x80\xc0\xaf\xf4\x90\x80\x80a\r\n\xf0\x9f\x98";
+        let clusters = ByteGraphemes::new(input).collect::<Vec<&[u8]>>();
+        assert_eq!(clusters, [&b"e\xcc\x81"[..], b"\xcc", b"!", b"\xed", b"\xa0", b"\x80",
+            b"\xf0\x9f\x87\xa6\xf0\x9f\x87\xb9", b"\x80", b"\xc0", b"\xaf",
+            b"\xf4", b"\x90", b"\x80", b"\x80", b"a", b"\r\n", b"\xf0\x9f\x98"]);
+        // Every invalid sequence is one replacement character in a lossy conversion
+        let invalid = clusters.iter().filter(|cluster| core::str::from_utf8(cluster).is_err()).count();
+        assert_eq!(invalid, String::from_utf8_lossy(input).matches('\u{fffd}').count());
+        assert_eq!(ByteGraphemes::new(b"").next(), None);
+    }
+
+    #[test]
+    fn cursor_asks_for_context() {
+        let mut cursor = GraphemeCursor::new(8, 16);
+        assert_eq!(cursor.is_boundary("🇦🇹", 8), Err(GraphemeIncomplete::PreContext(8)));
+        cursor.provide_context("🇩", 4);
+        assert_eq!(cursor.is_boundary("🇦🇹", 8), Err(GraphemeIncomplete::PreContext(4)));
+        cursor.provide_context("🇦", 0);
+        assert_eq!(cursor.is_boundary("🇦🇹", 8), Ok(true));
+        assert_eq!(cursor.next_boundary("🇦🇹", 8), Err(GraphemeIncomplete::PreContext(8)));
+        assert_eq!(cursor.next_boundary("🇦🇩🇦🇹", 0), Ok(Some(16)));
+        assert_eq!(cursor.next_boundary("🇦🇩🇦🇹", 0), Ok(None));
+        cursor.set_cursor(5);
+        assert_eq!(cursor.is_boundary("🇦🇹", 8), Err(GraphemeIncomplete::InvalidOffset));
+    }
+
     #[test]
     fn can_get_clusters() {
         let mut peekable_index = "\r\ne\u{301}f".char_indices().peekable();
@@ -461,14 +1382,92 @@ pub (crate) mod tests {
             clusters.push(cluster);
         }
         assert_eq!(clusters.len(), expected_output.len(), "Lengths did not match on Grapheme Cluster\n\t{message}\n\tOutput: {clusters:?}\n\tExpected: {expected_output:?}");
-        clusters.iter().zip(expected_output.into_iter())
+        clusters.iter().zip(expected_output.iter())
             .for_each(|(actual, &expected)| assert_eq!(actual.as_str(), expected, "GraphemeCluster mismatch: {message}"));
 
         let iter = Graphemes::new(input);
         let clusters = iter.collect::<Vec<&str>>();
         assert_eq!(clusters.len(), expected_output.len(), "Lengths did not match on Grapheme Cluster Indices\n\t{message}\n\tOutput: {clusters:?}\n\tExpected: {expected_output:?}");
-        clusters.iter().zip(expected_output.into_iter())
+        clusters.iter().zip(expected_output.iter())
             .for_each(|(actual, &expected)| assert_eq!(*actual, expected, "Grapheme cluster indices mismatch: {message}\n{} ≠ {}", actual.escape_unicode(), expected.escape_unicode()));
+
+        let clusters = ByteGraphemes::new(input.as_bytes()).collect::<Vec<&[u8]>>();
+        assert_eq!(clusters, expected_output.iter().map(|expected| expected.as_bytes()).collect::<Vec<&[u8]>>(), "Byte grapheme cluster mismatch: {message}");
+
+        let iter = Graphemes::new(input).rev();
+        let clusters = iter.collect::<Vec<&str>>();
+        assert_eq!(clusters.len(), expected_output.len(), "Lengths did not match on reversed Grapheme Cluster Indices\n\t{message}\n\tOutput: {clusters:?}\n\tExpected: {expected_output:?}");
+        clusters.iter().zip(expected_output.iter().rev())
+            .for_each(|(actual, &expected)| assert_eq!(*actual, expected, "Reversed grapheme cluster indices mismatch: {message}\n{} ≠ {}", actual.escape_unicode(), expected.escape_unicode()));
+
+        let expected_boundaries = expected_output.iter()
+            .scan(0, |offset, cluster| {
+                *offset += cluster.len();
+                Some(*offset)
+            })
+            .collect::<Vec<usize>>();
+        let mut cursor = GraphemeCursor::new(0, input.len());
+        let mut boundaries = vec!();
+        while let Some(boundary) = cursor.next_boundary(input, 0).unwrap() {
+            boundaries.push(boundary);
+        }
+        assert_eq!(boundaries, expected_boundaries, "GraphemeCursor mismatch: {message}");
+        assert_eq!(segmenter_boundaries(input), expected_boundaries, "GraphemeSegmenter mismatch: {message}");
+        assert_eq!(chunked_boundaries(input, true), expected_boundaries, "Chunked GraphemeCursor mismatch: {message}");
+        let mut expected_boundaries = expected_boundaries;
+        expected_boundaries.pop();
+        expected_boundaries.reverse();
+        expected_boundaries.push(0);
+        assert_eq!(chunked_boundaries(input, false), expected_boundaries, "Reversed chunked GraphemeCursor mismatch: {message}");
+    }
+
+    /// Collect the boundaries of `input` reported by a `GraphemeSegmenter`.
+    fn segmenter_boundaries(input: &str) -> Vec<usize> {
+        let mut segmenter = GraphemeSegmenter::new();
+        let mut boundaries = vec!();
+        for (offset, c) in input.char_indices() {
+            match segmenter.push(c) {
+                Boundary::None => {}
+                Boundary::Before => boundaries.push(offset),
+                Boundary::After => boundaries.push(offset + c.len_utf8()),
+            }
+        }
+        if segmenter.finish() {
+            boundaries.push(input.len());
+        }
+        boundaries
+    }
+
+    /// Walk through the boundaries of `input` with a `GraphemeCursor` which only ever gets to see
+    /// one character at a time.
+    fn chunked_boundaries(input: &str, forward: bool) -> Vec<usize> {
+        let chunks = input.char_indices()
+            .map(|(start, c)| (start, &input[start..start + c.len_utf8()]))
+            .collect::<Vec<(usize, &str)>>();
+        let mut chunk = if forward { 0 } else { chunks.len() - 1 };
+        let mut cursor = GraphemeCursor::new(if forward { 0 } else { input.len() }, input.len());
+        let mut boundaries = vec!();
+        loop {
+            let (chunk_start, text) = chunks[chunk];
+            let result = if forward {
+                cursor.next_boundary(text, chunk_start)
+            } else {
+                cursor.prev_boundary(text, chunk_start)
+            };
+            match result {
+                Ok(Some(boundary)) => boundaries.push(boundary),
+                Ok(None) => return boundaries,
+                Err(GraphemeIncomplete::NextChunk) => chunk += 1,
+                Err(GraphemeIncomplete::PrevChunk) => chunk -= 1,
+                Err(GraphemeIncomplete::PreContext(end)) => {
+                    let (context_start, context) = chunks.iter()
+                        .find(|(start, text)| start + text.len() == end)
+                        .unwrap();
+                    cursor.provide_context(context, *context_start);
+                }
+                Err(GraphemeIncomplete::InvalidOffset) => panic!("Invalid offset {}", cursor.cur_cursor()),
+            }
+        }
     }
 
 }
@@ -0,0 +1,146 @@
+//! This module provides the properties used for identifiers and pattern syntax as described in
+//! [UAX #31](https://www.unicode.org/reports/tr31/).
+//! ```
+//! # use crate::finl_unicode::identifiers::CharacterIdentifier;
+//! assert!('a'.is_xid_start());
+//! assert!(!'1'.is_xid_start());
+//! assert!('1'.is_xid_continue());
+//! assert!('{'.is_pattern_syntax());
+//! assert!('\u{200e}'.is_pattern_white_space());
+//! ```
+//!
+//! `is_identifier` checks a whole string against the default identifier syntax. With the
+//! `normalization` feature, `is_nfc_identifier` also requires identifiers to be in NFC so that
+//! identifiers which look the same are the same.
+//! ```
+//! # use crate::finl_unicode::identifiers::is_identifier;
+//! assert!(is_identifier("größe"));
+//! assert!(is_identifier("переменная_1"));
+//! assert!(!is_identifier("1st"));
+//! assert!(!is_identifier("a-b"));
+//! ```
+
+use crate::data::identifier_property::{ID_PAGES, ID_TABLE};
+
+/// Trait to look up the identifier and pattern properties of a character. Importing the trait
+/// will provide the methods on the `char` type.
+#[allow(clippy::wrong_self_convention)]
+pub trait CharacterIdentifier {
+    /// Determines whether a character can begin an identifier (XID_Start). These are the letters
+    /// and letter numbers, adjusted so that the property is closed under NFKC normalization.
+    fn is_xid_start(self) -> bool;
+    /// Determines whether a character can appear after the first character of an identifier
+    /// (XID_Continue). These are the characters which can begin an identifier along with marks,
+    /// digits and connector punctuation like `_`.
+    fn is_xid_continue(self) -> bool;
+    /// Determines whether a character is reserved for use as syntax in patterns
+    /// (Pattern_Syntax). This is a stable set which includes ASCII punctuation and symbols and
+    /// some unassigned code points, so it will not change in future versions of Unicode.
+    fn is_pattern_syntax(self) -> bool;
+    /// Determines whether a character is treated as whitespace in patterns
+    /// (Pattern_White_Space). Like Pattern_Syntax, this is a stable set.
+    fn is_pattern_white_space(self) -> bool;
+}
+
+impl CharacterIdentifier for char {
+    #[inline]
+    fn is_xid_start(self) -> bool {
+        get_identifier_property(self) & XID_START != 0
+    }
+
+    #[inline]
+    fn is_xid_continue(self) -> bool {
+        get_identifier_property(self) & XID_CONTINUE != 0
+    }
+
+    #[inline]
+    fn is_pattern_syntax(self) -> bool {
+        get_identifier_property(self) & PATTERN_SYNTAX != 0
+    }
+
+    #[inline]
+    fn is_pattern_white_space(self) -> bool {
+        get_identifier_property(self) & PATTERN_WHITE_SPACE != 0
+    }
+}
+
+/// Determines whether a string is an identifier under the default identifier syntax of UAX #31
+/// (UAX31-D1): an XID_Start character followed by any number of XID_Continue characters. The
+/// string is not checked for normalization; see `is_nfc_identifier`.
+pub fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    chars.next().is_some_and(char::is_xid_start) && chars.all(char::is_xid_continue)
+}
+
+/// Determines whether a string is an identifier, as with `is_identifier`, which is also in NFC
+/// (UAX31-R4), so that two identifiers which are canonically equivalent can't both be valid.
+/// ```
+/// # use crate::finl_unicode::identifiers::is_nfc_identifier;
+/// assert!(is_nfc_identifier("caf\u{e9}"));
+/// assert!(!is_nfc_identifier("cafe\u{301}"));
+/// ```
+#[cfg(feature = "normalization")]
+pub fn is_nfc_identifier(s: &str) -> bool {
+    is_identifier(s) && crate::normalization::is_nfc(s)
+}
+
+#[inline]
+fn get_identifier_property(c: char) -> u8 {
+    ID_PAGES[usize::from(ID_TABLE[(c as usize) >> 8])][(c as usize) & 0xff]
+}
+
+// Symbolic names for the identifier flags in data tables
+const XID_START: u8 = 0x01;
+const XID_CONTINUE: u8 = 0x02;
+const PATTERN_SYNTAX: u8 = 0x04;
+const PATTERN_WHITE_SPACE: u8 = 0x08;
+
+#[cfg(test)]
+mod tests {
+    use crate::identifiers::*;
+
+    #[test]
+    fn can_get_identifier_properties() {
+        assert!('Z'.is_xid_start());
+        assert!('ß'.is_xid_start());
+        assert!('漢'.is_xid_start());
+        assert!('Ⅻ'.is_xid_start());
+        assert!(!'_'.is_xid_start());
+        assert!('_'.is_xid_continue());
+        assert!('\u{301}'.is_xid_continue());
+        assert!('٣'.is_xid_continue());
+        assert!(!' '.is_xid_continue());
+        // U+037A is not XID_Start since its NFKC form begins with a space, though it is ID_Start
+        assert!(!'\u{37a}'.is_xid_start());
+
+        assert!('#'.is_pattern_syntax());
+        assert!('→'.is_pattern_syntax());
+        assert!(!'a'.is_pattern_syntax());
+        // Unassigned code points in the Supplemental Punctuation block are reserved for syntax
+        assert!('\u{2e7f}'.is_pattern_syntax());
+        assert!(' '.is_pattern_white_space());
+        assert!('\u{2029}'.is_pattern_white_space());
+        assert!(!'\u{a0}'.is_pattern_white_space());
+    }
+
+    #[test]
+    fn can_check_identifiers() {
+        assert!(is_identifier("x"));
+        assert!(is_identifier("ΑΒΓ"));
+        assert!(is_identifier("日本語"));
+        assert!(!is_identifier(""));
+        assert!(!is_identifier("_x"));
+        assert!(!is_identifier("a b"));
+        assert!(!is_identifier("\u{301}a"));
+        // Identifiers aren't checked for NFC unless asked
+        assert!(is_identifier("e\u{301}"));
+    }
+
+    #[cfg(feature = "normalization")]
+    #[test]
+    fn can_check_nfc_identifiers() {
+        assert!(!is_nfc_identifier("e\u{301}"));
+        assert!(is_nfc_identifier("\u{e9}"));
+        assert!(!is_nfc_identifier("1\u{e9}"));
+    }
+}
@@ -1,18 +1,93 @@
 //! `finl_unicode` is a crate to provide Unicode support for the finl project. This is not necessarily
 //! meant to be a comoprehensive Unicode support, although I will consider adding additional use cases
-//! as necessary. Unicode 14.0.0 is implemented in the current version.
+//! as necessary. Unicode 16.0.0 is implemented in the current version.
 //!
-//! Two features are currently supported:
+//! The version of Unicode and the data tables which were compiled into the crate can be checked
+//! with `UNICODE_VERSION`, `COMPILED_TABLES` and `compiled_table`, e.g., to assert at build time
+//! that the version is the one a crate was written for:
+//! ```
+//! const _: () = assert!(finl_unicode::UNICODE_VERSION.0 >= 15);
+//! const _: () = assert!(finl_unicode::compiled_table("grapheme_clusters").is_some());
+//! assert_eq!(finl_unicode::UNICODE_VERSION, (16, 0, 0));
+//! assert!(finl_unicode::COMPILED_TABLES.iter().any(|table| table.feature == "grapheme_clusters"));
+//! ```
+//!
+//! The following features are currently supported:
 //! - **Unicode segmentation**. (Specify `clusters` as a feature when importing the crate.) For a peekable iterator of `CharIndices`, we extend that iterator to
 //!   include a `next_cluster` method which returns `Option<String>` which will contain the next
 //!   grapheme cluster if there is one or `None` if there isn't.
 //! - **Character category**. (Specify `categories` as a feature when importing the crate.) Extends the `char` class with methods for testing the
 //!   category of the character.
+//! - **Word boundaries**. (Specify `word_boundaries` as a feature when importing the crate.) Works the same way as
+//!   grapheme clusters, with a `next_word` method for peekable iterators and a `Words` iterator over `&str`.
+//! - **Sentences**. (Specify `sentences` as a feature when importing the crate.) Provides a `next_sentence` method
+//!   for peekable iterators and a `Sentences` iterator over `&str`.
+//! - **Line breaking**. (Specify `line_break` as a feature when importing the crate.) Provides `LineBreaks`
+//!   and `CharLineBreaks` iterators over the line break opportunities in a `&str` or peekable iterator.
+//! - **Normalization**. (Specify `normalization` as a feature when importing the crate.) Provides `nfc`, `nfd`,
+//!   `nfkc` and `nfkd` methods on iterators over `char`, along with quick-check functions and canonical combining classes.
+//! - **Character properties**. (Specify `properties` as a feature when importing the crate.) Extends the `char` class
+//!   with methods for the canonical combining class, bidi class, decomposition type, numeric value and mirroring
+//!   of the character.
+//! - **Scripts**. (Specify `scripts` as a feature when importing the crate.) Extends the `char` class with methods
+//!   for the Script and Script_Extensions properties and provides a `ScriptRuns` iterator over the script runs of a `&str`.
+//! - **Blocks**. (Specify `blocks` as a feature when importing the crate.) Extends the `char` class with a method
+//!   for the block of the character and provides the names and ranges of all of the blocks.
+//! - **East Asian width**. (Specify `east_asian_width` as a feature when importing the crate.) Extends the `char`
+//!   class with a method for the East_Asian_Width of the character and provides a `display_width` function
+//!   giving the number of terminal columns taken up by a `&str`.
+//! - **Case mapping**. (Specify `case` as a feature when importing the crate.) Extends the `char` class with methods
+//!   for the simple and full case mappings and case folding of the character and provides functions to convert the
+//!   case of a `&str` (including title case), with the Turkish, Azeri and Lithuanian mappings.
+//! - **Bidirectional text**. (Specify `bidi` as a feature when importing the crate.) Provides `BidiParagraph`
+//!   which resolves the embedding levels of a paragraph and reorders its lines for display, and extends the `char`
+//!   class with methods for the paired bracket and mirroring glyph of the character.
+//! - **Identifiers**. (Specify `identifiers` as a feature when importing the crate.) Extends the `char` class with
+//!   methods for the XID_Start, XID_Continue, Pattern_Syntax and Pattern_White_Space properties and provides an
+//!   `is_identifier` function to check a `&str` against the default identifier syntax (and `is_nfc_identifier`
+//!   with the `normalization` feature).
+//! - **Binary properties**. (Specify `binary_properties` as a feature when importing the crate.) Extends the `char`
+//!   class with a `has_property` method for the binary properties in `PropList.txt` and `DerivedCoreProperties.txt`
+//!   such as White_Space, Alphabetic and Math.
+//! - **Emoji**. (Specify `emoji` as a feature when importing the crate.) Extends the `char` class with methods for
+//!   the emoji properties of the character and provides an `emoji_sequence_type` function to classify a grapheme
+//!   cluster as one of the RGI emoji sequences.
+//! - **Character names**. (Specify `names` as a feature when importing the crate.) Extends the `char` class with
+//!   methods for the name and name aliases of the character and provides a `char_from_name` function to look up a
+//!   character by its name with loose matching.
+//!
+//! The default is to compile the `categories` and `grapheme_clusters` features (along with
+//! `alloc`); the other modules each need their feature to be enabled. Note that the Rust
+//! compiler/linker will not automatically link unused code, so you most of the time, there will be
+//! no need to remove features.
 //!
-//! The default is to compile all features. Note that the Rust compiler/linker will not automatically
-//! link unused code, so you most of the time, there will be no need to remove features.
+//! Documents which must be segmented the same way years later can pin the character categories and
+//! grapheme cluster boundaries to an older version of Unicode with the `unicode_14` or `unicode_15`
+//! feature (for Unicode 14.0.0 and 15.0.0). The features are additive: if both are enabled (e.g.,
+//! by two crates in the same build, or with `--all-features`), the older version, Unicode 14.0.0,
+//! wins. Only the `categories` and `grapheme_clusters` tables are pinned: the other features keep
+//! their Unicode 16.0.0 data, so where they use the general category or grapheme clusters (the
+//! zero-width check in `display_width`, say, or the clusters which word and sentence boundaries
+//! are built from), the results mix the pinned and current versions and may match neither.
 //!
-//! Building the crate runs a build script which connects to unicode.org to download the data files.
+//! The crate is `no_std`. Everything which allocates (the `next_cluster`, `next_word` and
+//! `next_sentence` methods which return a `String`, along with normalization, case mapping, bidi
+//! and names) needs the `alloc` feature, which is on by default. Without it, `Graphemes`,
+//! `GraphemeCursor`, the other segmentation iterators, script runs and the character properties
+//! work with no allocator. The `std` feature, which is off by default, implements
+//! `std::error::Error` for `InvalidCodePoint`.
+//!
+//! The data tables are generated from the Unicode character database by the `generate-sources`
+//! program in the repository, which can download the data files from unicode.org or read them from
+//! a local copy checked against a checksum manifest.
+
+#![cfg_attr(not(test), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+extern crate std;
 
 #[cfg(feature = "categories")]
 pub mod categories;
@@ -20,4 +95,106 @@ pub mod categories;
 #[cfg(feature = "grapheme_clusters")]
 pub mod grapheme_clusters;
 
-mod data;
\ No newline at end of file
+#[cfg(feature = "word_boundaries")]
+pub mod word_boundaries;
+
+#[cfg(feature = "sentences")]
+pub mod sentences;
+
+#[cfg(feature = "line_break")]
+pub mod line_break;
+
+#[cfg(feature = "normalization")]
+pub mod normalization;
+
+#[cfg(feature = "properties")]
+pub mod properties;
+
+#[cfg(feature = "scripts")]
+pub mod scripts;
+
+#[cfg(feature = "blocks")]
+pub mod blocks;
+
+#[cfg(feature = "east_asian_width")]
+pub mod east_asian_width;
+
+#[cfg(feature = "case")]
+pub mod case;
+
+#[cfg(feature = "bidi")]
+pub mod bidi;
+
+#[cfg(feature = "identifiers")]
+pub mod identifiers;
+
+#[cfg(feature = "binary_properties")]
+pub mod binary_properties;
+
+#[cfg(feature = "emoji")]
+pub mod emoji;
+
+#[cfg(feature = "names")]
+pub mod names;
+
+mod data;
+
+/// The version of Unicode the data tables were generated from. With the `unicode_14` or
+/// `unicode_15` feature, the category and grapheme cluster tables are from an older version, which
+/// is given by `COMPILED_TABLES`.
+pub const UNICODE_VERSION: (u8, u8, u8) = data::unicode_version::UNICODE_VERSION;
+
+/// A set of data tables which is compiled into the crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DataTable {
+    /// The cargo feature which includes the tables, e.g., `"categories"`
+    pub feature: &'static str,
+    /// The version of Unicode the tables were generated from
+    pub unicode_version: (u8, u8, u8),
+}
+
+/// The data tables which are compiled into the crate, i.e., the features which have been enabled
+/// that come with Unicode data, along with the version of Unicode each was generated from. The
+/// list is written by the generator along with the tables.
+pub const COMPILED_TABLES: &[DataTable] = data::COMPILED_TABLES;
+
+/// Returns the data tables which are compiled into the crate. This is the same as iterating over
+/// `COMPILED_TABLES`.
+pub fn compiled_tables() -> impl Iterator<Item = DataTable> {
+    COMPILED_TABLES.iter().copied()
+}
+
+/// Returns the compiled-in data table for the cargo feature `feature`, if the feature is enabled
+/// and comes with Unicode data. As a `const fn`, this can be used in a const assertion:
+/// ```
+/// use finl_unicode::{compiled_table, DataTable};
+/// const _: () = assert!(matches!(
+///     compiled_table("categories"),
+///     Some(DataTable { unicode_version: (14..=16, _, _), .. })
+/// ));
+/// ```
+pub const fn compiled_table(feature: &str) -> Option<DataTable> {
+    let mut index = 0;
+    while index < COMPILED_TABLES.len() {
+        if str_eq(COMPILED_TABLES[index].feature, feature) {
+            return Some(COMPILED_TABLES[index]);
+        }
+        index += 1;
+    }
+    None
+}
+
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut index = 0;
+    while index < a.len() {
+        if a[index] != b[index] {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
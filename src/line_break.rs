@@ -0,0 +1,599 @@
+//! This module finds line break opportunities as described in [UAX #14](https://www.unicode.org/reports/tr14/).
+//! Unlike the segmentation modules, the result is not a string but the byte offset at which the
+//! next line would begin together with a `BreakOpportunity` saying whether the break is
+//! mandatory (as after a line feed or at the end of the text) or merely allowed.
+//! ```
+//! # use crate::finl_unicode::line_break::{BreakOpportunity, LineBreaks};
+//! let breaks = LineBreaks::new("Hello, world!\nNew line");
+//! assert_eq!(breaks.collect::<Vec<_>>(), [
+//!     (7, BreakOpportunity::Allowed),
+//!     (14, BreakOpportunity::Mandatory),
+//!     (18, BreakOpportunity::Allowed),
+//!     (22, BreakOpportunity::Mandatory),
+//! ]);
+//! ```
+//!
+//! For a stream of characters there is `CharLineBreaks` which can be constructed from any
+//! `Peekable` iterator over either `char` or `(usize, char)` which can be cloned. The byte offsets
+//! are counted from the character at the front of the iterator when `CharLineBreaks` is created.
+//! ```
+//! # use crate::finl_unicode::line_break::{BreakOpportunity, CharLineBreaks};
+//! let breaks = CharLineBreaks::new("“Yes,” 約束します。".chars().peekable());
+//! assert_eq!(breaks.collect::<Vec<_>>(), [
+//!     (11, BreakOpportunity::Allowed),
+//!     (14, BreakOpportunity::Allowed),
+//!     (17, BreakOpportunity::Allowed),
+//!     (20, BreakOpportunity::Allowed),
+//!     (23, BreakOpportunity::Allowed),
+//!     (29, BreakOpportunity::Mandatory),
+//! ]);
+//! ```
+//!
+//! Only the default rules are implemented. In particular, there is no dictionary for finding the
+//! breaks within Thai, Lao, Khmer or Myanmar words, so the text of those scripts is treated as
+//! though it were alphabetic and only broken at spaces and punctuation (LB1).
+
+use core::iter::Peekable;
+use core::str::CharIndices;
+use crate::data::line_property::{LB_PAGES, LB_TABLE};
+use crate::grapheme_clusters::PeekChar;
+
+/// Whether a line break opportunity must be taken.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BreakOpportunity {
+    /// The line must be broken here, as after a line feed or paragraph separator or at the end
+    /// of the text
+    Mandatory,
+    /// The line may be broken here
+    Allowed,
+}
+
+/// `LineBreaks` provides an iterator over the line break opportunities in a string. Each
+/// opportunity is given as the byte offset at which the next line would begin along with its
+/// `BreakOpportunity`. The last item is always a mandatory break at the end of the string.
+pub struct LineBreaks<'a> {
+    input: &'a str,
+    iter: Peekable<CharIndices<'a>>,
+    line_break_machine: LineBreakMachine,
+}
+
+impl<'a> LineBreaks<'a> {
+    /// A new instance of line breaks can be constructed from a string using `LineBreaks::new`
+    /// ```
+    /// # use crate::finl_unicode::line_break::LineBreaks;
+    /// let line_breaks = LineBreaks::new("some string");
+    /// ```
+    pub fn new(input: &'a str) -> LineBreaks<'a> {
+        LineBreaks {
+            input,
+            iter: input.char_indices().peekable(),
+            line_break_machine: LineBreakMachine::new(),
+        }
+    }
+}
+
+impl<'a> Iterator for LineBreaks<'a> {
+    type Item = (usize, BreakOpportunity);
+    #[inline]
+    /// Return the next line break opportunity, or `None` if the end of the string has been
+    /// reached.
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((curr_loc, ch)) = self.iter.next() {
+            let following = || self.input[curr_loc + ch.len_utf8()..].chars();
+            if let Some(opportunity) = self.line_break_machine.find_boundary(ch, following) {
+                return Some((curr_loc, opportunity));
+            }
+        }
+        self.line_break_machine.finish().map(|opportunity| (self.input.len(), opportunity))
+    }
+}
+
+/// `CharLineBreaks` provides an iterator over the line break opportunities in a stream of
+/// characters or char indices. It accepts any `Peekable` iterator over either `char` or
+/// `(usize, char)` which can be cloned (which includes `Peekable<Chars>` and
+/// `Peekable<CharIndices>`). Some of the rules need to look at the characters after a possible
+/// break, which is done on a clone of the iterator.
+pub struct CharLineBreaks<T> {
+    iter: T,
+    offset: usize,
+    line_break_machine: LineBreakMachine,
+}
+
+impl<T> CharLineBreaks<T> where T: PeekChar + Clone {
+    /// A new instance can be constructed from a peekable iterator using `CharLineBreaks::new`.
+    /// The byte offsets returned are relative to the current position of the iterator.
+    /// ```
+    /// # use crate::finl_unicode::line_break::CharLineBreaks;
+    /// let line_breaks = CharLineBreaks::new("some string".char_indices().peekable());
+    /// ```
+    pub fn new(iter: T) -> CharLineBreaks<T> {
+        CharLineBreaks {
+            iter,
+            offset: 0,
+            line_break_machine: LineBreakMachine::new(),
+        }
+    }
+}
+
+impl<T> Iterator for CharLineBreaks<T> where T: PeekChar + Clone {
+    type Item = (usize, BreakOpportunity);
+    #[inline]
+    /// Return the next line break opportunity, or `None` if the end of the stream has been
+    /// reached.
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(ch) = self.iter.peek_char() {
+            self.iter.next();
+            let curr_loc = self.offset;
+            self.offset += ch.len_utf8();
+            let following = || {
+                let mut following = self.iter.clone();
+                core::iter::from_fn(move || {
+                    let c = following.peek_char();
+                    following.next();
+                    c
+                })
+            };
+            if let Some(opportunity) = self.line_break_machine.find_boundary(ch, following) {
+                return Some((curr_loc, opportunity));
+            }
+        }
+        self.line_break_machine.finish().map(|opportunity| (self.offset, opportunity))
+    }
+}
+
+// ------------------------
+// Private implementation details follow
+
+/// A character together with any combining marks which follow it. Under LB9, these are treated
+/// as the character alone, so most of the rules only need to look at the first character.
+#[derive(Clone, Copy)]
+struct Unit {
+    property: u8,
+    c: char,
+}
+
+impl Unit {
+    #[inline]
+    fn new(property: u8, c: char) -> Unit {
+        // LB10
+        if is_combining(property & LineProperty::CLASS_MASK) {
+            Unit { property: LineProperty::AL, c }
+        } else {
+            Unit { property, c }
+        }
+    }
+
+    #[inline]
+    fn class(&self) -> u8 {
+        self.property & LineProperty::CLASS_MASK
+    }
+
+    #[inline]
+    fn is_east_asian(&self) -> bool {
+        self.property & LineProperty::EAST_ASIAN != 0
+    }
+
+    /// The characters which LB28a applies to
+    #[inline]
+    fn is_aksara(&self) -> bool {
+        matches!(self.class(), LineProperty::AK | LineProperty::AS) || self.c == '\u{25CC}'
+    }
+
+    /// Whether combining marks following this unit are attached to it under LB9
+    #[inline]
+    fn takes_combining_marks(&self) -> bool {
+        !matches!(self.class(), LineProperty::BK | LineProperty::CR | LineProperty::LF | LineProperty::NL | LineProperty::SP | LineProperty::ZW)
+    }
+}
+
+/// Where we are in a number for LB25
+#[derive(PartialEq)]
+enum NumericState {
+    Other,
+    /// NU (SY | IS)*
+    Numeric,
+    /// NU (SY | IS)* (CL | CP)
+    Closed,
+}
+
+/// The state machine for finding line break opportunities. Each call to `find_boundary` decides
+/// whether there is a break opportunity *before* the character passed to it. A few of the rules
+/// depend on the characters after that one, so `find_boundary` also takes a function which
+/// returns the following characters. It is only called when one of those rules might apply.
+struct LineBreakMachine {
+    /// The class of the last character, before LB9 and LB10 have been applied. This is `None`
+    /// at the start of the text.
+    previous: Option<u8>,
+    /// The last character along with any combining marks following it
+    left: Unit,
+    /// The unit before `left` or `None` if `left` is at the start of the text
+    before_left: Option<Unit>,
+    /// If `left` is a space, the class of the last unit before the spaces
+    before_spaces: Option<u8>,
+    /// Whether the text ends in ZW SP* (LB8)
+    after_zero_width_space: bool,
+    /// Whether the text ends in an initial quotation mark which LB15a applies to followed by
+    /// any number of spaces
+    after_initial_quote: bool,
+    numeric: NumericState,
+    /// Whether the text ends in an odd number of regional indicators (LB30a)
+    odd_regional_indicators: bool,
+}
+
+impl LineBreakMachine {
+    #[inline]
+    pub fn new() -> LineBreakMachine {
+        LineBreakMachine {
+            previous: None,
+            left: Unit { property: LineProperty::AL, c: ' ' },
+            before_left: None,
+            before_spaces: None,
+            after_zero_width_space: false,
+            after_initial_quote: false,
+            numeric: NumericState::Other,
+            odd_regional_indicators: false,
+        }
+    }
+
+    #[inline]
+    pub fn find_boundary<F, I>(&mut self, c: char, following: F) -> Option<BreakOpportunity>
+    where F: FnOnce() -> I, I: Iterator<Item = char>
+    {
+        let property = get_property(c);
+        let class = property & LineProperty::CLASS_MASK;
+        let opportunity = match self.previous {
+            // LB2
+            None => None,
+            Some(previous) => self.opportunity(previous, property, c, following),
+        };
+        // LB9
+        if self.previous.is_none() || !is_combining(class) || !self.left.takes_combining_marks() {
+            self.push(Unit::new(property, c));
+        }
+        self.after_zero_width_space = class == LineProperty::ZW || (class == LineProperty::SP && self.after_zero_width_space);
+        self.previous = Some(class);
+        opportunity
+    }
+
+    /// LB3: there is always a mandatory break at the end of the text, unless the text is empty.
+    /// The machine is reset so that further calls return `None`.
+    #[inline]
+    pub fn finish(&mut self) -> Option<BreakOpportunity> {
+        self.previous.take().map(|_| BreakOpportunity::Mandatory)
+    }
+
+    /// Find the break opportunity between the text seen so far and `c`.
+    #[inline]
+    fn opportunity<F, I>(&self, previous: u8, property: u8, c: char, following: F) -> Option<BreakOpportunity>
+    where F: FnOnce() -> I, I: Iterator<Item = char>
+    {
+        let class = property & LineProperty::CLASS_MASK;
+        // LB4, LB5
+        match previous {
+            LineProperty::BK | LineProperty::LF | LineProperty::NL => return Some(BreakOpportunity::Mandatory),
+            LineProperty::CR if class != LineProperty::LF => return Some(BreakOpportunity::Mandatory),
+            _ => {}
+        }
+        // LB5, LB6, LB7
+        if matches!(class, LineProperty::BK | LineProperty::CR | LineProperty::LF | LineProperty::NL | LineProperty::SP | LineProperty::ZW) {
+            return None;
+        }
+        // LB8
+        if self.after_zero_width_space {
+            return Some(BreakOpportunity::Allowed);
+        }
+        // LB8a, LB9
+        if previous == LineProperty::ZWJ || (is_combining(class) && self.left.takes_combining_marks()) {
+            return None;
+        }
+
+        let left = self.left;
+        let right = Unit::new(property, c);
+        let (lc, rc) = (left.class(), right.class());
+        let before_spaces = if lc == LineProperty::SP { self.before_spaces } else { Some(lc) };
+        let mut following = Some(following);
+        let mut lookahead = None;
+        let mut next = |n: usize| -> Option<Unit> {
+            lookahead.get_or_insert_with(|| following_units(following.take().unwrap()()))[n]
+        };
+
+        // LB11, LB12
+        let no_break = rc == LineProperty::WJ || lc == LineProperty::WJ || lc == LineProperty::GL
+            // LB12a
+            || (rc == LineProperty::GL && !matches!(lc, LineProperty::SP | LineProperty::BA | LineProperty::HY))
+            // LB13
+            || matches!(rc, LineProperty::CL | LineProperty::CP | LineProperty::EX | LineProperty::SY)
+            // LB14
+            || before_spaces == Some(LineProperty::OP)
+            // LB15a
+            || self.after_initial_quote
+            // LB15b
+            || (rc == LineProperty::QU_PF && matches!(next(0).map(|unit| unit.class()),
+                None | Some(LineProperty::SP | LineProperty::GL | LineProperty::WJ | LineProperty::CL | LineProperty::QU
+                | LineProperty::QU_PI | LineProperty::QU_PF | LineProperty::CP | LineProperty::EX | LineProperty::IS
+                | LineProperty::SY | LineProperty::BK | LineProperty::CR | LineProperty::LF | LineProperty::NL
+                | LineProperty::ZW)));
+        if no_break {
+            return None;
+        }
+        // LB15c
+        if lc == LineProperty::SP && rc == LineProperty::IS && next(0).map(|unit| unit.class()) == Some(LineProperty::NU) {
+            return Some(BreakOpportunity::Allowed);
+        }
+        // LB15d
+        let no_break = rc == LineProperty::IS
+            // LB16
+            || (rc == LineProperty::NS && matches!(before_spaces, Some(LineProperty::CL | LineProperty::CP)))
+            // LB17
+            || (rc == LineProperty::B2 && before_spaces == Some(LineProperty::B2));
+        if no_break {
+            return None;
+        }
+        // LB18
+        if lc == LineProperty::SP {
+            return Some(BreakOpportunity::Allowed);
+        }
+        // LB19
+        let no_break = matches!(rc, LineProperty::QU | LineProperty::QU_PF)
+            || matches!(lc, LineProperty::QU | LineProperty::QU_PI)
+            // LB19a
+            || (is_quote(rc) && (!left.is_east_asian() || !next(0).is_some_and(|unit| unit.is_east_asian())))
+            || (is_quote(lc) && (!right.is_east_asian() || !self.before_left.is_some_and(|unit| unit.is_east_asian())));
+        if no_break {
+            return None;
+        }
+        // LB20
+        if rc == LineProperty::CB || lc == LineProperty::CB {
+            return Some(BreakOpportunity::Allowed);
+        }
+        let before_left = self.before_left.map(|unit| unit.class());
+        // LB20a
+        let no_break = ((lc == LineProperty::HY || left.c == '\u{2010}')
+                && is_alphabetic(rc)
+                && matches!(before_left,
+                    None | Some(LineProperty::BK | LineProperty::CR | LineProperty::LF | LineProperty::NL | LineProperty::SP
+                    | LineProperty::ZW | LineProperty::CB | LineProperty::GL)))
+            // LB21
+            || matches!(rc, LineProperty::BA | LineProperty::HY | LineProperty::NS)
+            || lc == LineProperty::BB
+            // LB21a
+            || ((lc == LineProperty::HY || (lc == LineProperty::BA && !left.is_east_asian()))
+                && before_left == Some(LineProperty::HL)
+                && rc != LineProperty::HL)
+            // LB21b
+            || (lc == LineProperty::SY && rc == LineProperty::HL)
+            // LB22
+            || rc == LineProperty::IN
+            // LB23
+            || (is_alphabetic(lc) && rc == LineProperty::NU)
+            || (lc == LineProperty::NU && is_alphabetic(rc))
+            // LB23a
+            || (lc == LineProperty::PR && matches!(rc, LineProperty::ID | LineProperty::EB | LineProperty::EM))
+            || (matches!(lc, LineProperty::ID | LineProperty::EB | LineProperty::EM) && rc == LineProperty::PO)
+            // LB24
+            || (matches!(lc, LineProperty::PR | LineProperty::PO) && is_alphabetic(rc))
+            || (is_alphabetic(lc) && matches!(rc, LineProperty::PR | LineProperty::PO))
+            // LB25
+            || self.continues_number(lc, rc, &mut next)
+            // LB26
+            || (lc == LineProperty::JL && matches!(rc, LineProperty::JL | LineProperty::JV | LineProperty::H2 | LineProperty::H3))
+            || (matches!(lc, LineProperty::JV | LineProperty::H2) && matches!(rc, LineProperty::JV | LineProperty::JT))
+            || (matches!(lc, LineProperty::JT | LineProperty::H3) && rc == LineProperty::JT)
+            // LB27
+            || (is_korean(lc) && rc == LineProperty::PO)
+            || (lc == LineProperty::PR && is_korean(rc))
+            // LB28
+            || (is_alphabetic(lc) && is_alphabetic(rc))
+            // LB28a
+            || (lc == LineProperty::AP && right.is_aksara())
+            || (left.is_aksara() && matches!(rc, LineProperty::VF | LineProperty::VI))
+            || (lc == LineProperty::VI
+                && self.before_left.is_some_and(|unit| unit.is_aksara())
+                && (rc == LineProperty::AK || right.c == '\u{25CC}'))
+            || (left.is_aksara() && right.is_aksara() && next(0).map(|unit| unit.class()) == Some(LineProperty::VF))
+            // LB29
+            || (lc == LineProperty::IS && is_alphabetic(rc))
+            // LB30
+            || ((is_alphabetic(lc) || lc == LineProperty::NU) && rc == LineProperty::OP && !right.is_east_asian())
+            || (lc == LineProperty::CP && !left.is_east_asian() && (is_alphabetic(rc) || rc == LineProperty::NU))
+            // LB30a
+            || (rc == LineProperty::RI && self.odd_regional_indicators)
+            // LB30b
+            || (rc == LineProperty::EM && (lc == LineProperty::EB || left.property & LineProperty::EXT_PICT_UNASSIGNED != 0));
+        if no_break {
+            None
+        } else {
+            // LB31
+            Some(BreakOpportunity::Allowed)
+        }
+    }
+
+    /// LB25: don't break within numbers, including any prefix and postfix
+    #[inline]
+    fn continues_number(&self, lc: u8, rc: u8, next: &mut impl FnMut(usize) -> Option<Unit>) -> bool {
+        match rc {
+            LineProperty::PO | LineProperty::PR => self.numeric != NumericState::Other,
+            LineProperty::NU => self.numeric == NumericState::Numeric
+                || matches!(lc, LineProperty::PO | LineProperty::PR | LineProperty::HY | LineProperty::IS),
+            LineProperty::OP if matches!(lc, LineProperty::PO | LineProperty::PR) => {
+                match next(0).map(|unit| unit.class()) {
+                    Some(LineProperty::NU) => true,
+                    Some(LineProperty::IS) => next(1).map(|unit| unit.class()) == Some(LineProperty::NU),
+                    _ => false,
+                }
+            }
+            _ => false,
+        }
+    }
+
+    /// Make `unit` the last unit of the text, updating the state which depends on the units
+    /// before it.
+    #[inline]
+    fn push(&mut self, unit: Unit) {
+        let class = unit.class();
+        let previous = self.previous.map(|_| self.left);
+        self.numeric = match class {
+            LineProperty::NU => NumericState::Numeric,
+            LineProperty::SY | LineProperty::IS if self.numeric == NumericState::Numeric => NumericState::Numeric,
+            LineProperty::CL | LineProperty::CP if self.numeric == NumericState::Numeric => NumericState::Closed,
+            _ => NumericState::Other,
+        };
+        self.odd_regional_indicators = class == LineProperty::RI && !self.odd_regional_indicators;
+        self.after_initial_quote = match class {
+            LineProperty::QU_PI => matches!(previous.map(|unit| unit.class()),
+                None | Some(LineProperty::BK | LineProperty::CR | LineProperty::LF | LineProperty::NL | LineProperty::OP
+                | LineProperty::QU | LineProperty::QU_PI | LineProperty::QU_PF | LineProperty::GL
+                | LineProperty::SP | LineProperty::ZW)),
+            LineProperty::SP => self.after_initial_quote,
+            _ => false,
+        };
+        if class == LineProperty::SP && previous.map(|unit| unit.class()) != Some(LineProperty::SP) {
+            self.before_spaces = previous.map(|unit| unit.class());
+        }
+        self.before_left = previous;
+        self.left = unit;
+    }
+}
+
+/// Find the two units after the character being examined, skipping over the combining marks
+/// attached to it.
+#[inline]
+fn following_units<I: Iterator<Item = char>>(following: I) -> [Option<Unit>; 2] {
+    let mut units = [None; 2];
+    let mut count = 0;
+    let mut takes_combining_marks = true;
+    for c in following {
+        let property = get_property(c);
+        if is_combining(property & LineProperty::CLASS_MASK) && takes_combining_marks {
+            continue;
+        }
+        let unit = Unit::new(property, c);
+        takes_combining_marks = unit.takes_combining_marks();
+        units[count] = Some(unit);
+        count += 1;
+        if count == units.len() {
+            break;
+        }
+    }
+    units
+}
+
+#[inline]
+fn is_combining(class: u8) -> bool {
+    matches!(class, LineProperty::CM | LineProperty::ZWJ)
+}
+
+#[inline]
+fn is_alphabetic(class: u8) -> bool {
+    matches!(class, LineProperty::AL | LineProperty::HL)
+}
+
+#[inline]
+fn is_quote(class: u8) -> bool {
+    matches!(class, LineProperty::QU | LineProperty::QU_PI | LineProperty::QU_PF)
+}
+
+#[inline]
+fn is_korean(class: u8) -> bool {
+    matches!(class, LineProperty::JL | LineProperty::JV | LineProperty::JT | LineProperty::H2 | LineProperty::H3)
+}
+
+// Symbolic names for properties in data tables. AI, SG, SA, XX and CJ do not appear since they
+// are resolved to other values by LB1 when the tables are generated.
+struct LineProperty {}
+impl LineProperty {
+    const AL: u8 = 0x00;
+    const BK: u8 = 0x01;
+    const CR: u8 = 0x02;
+    const LF: u8 = 0x03;
+    const NL: u8 = 0x04;
+    const SP: u8 = 0x05;
+    const ZW: u8 = 0x06;
+    const ZWJ: u8 = 0x07;
+    const CM: u8 = 0x08;
+    const WJ: u8 = 0x09;
+    const GL: u8 = 0x0a;
+    const BA: u8 = 0x0b;
+    const HY: u8 = 0x0c;
+    const BB: u8 = 0x0d;
+    const B2: u8 = 0x0e;
+    const CB: u8 = 0x0f;
+    const CL: u8 = 0x10;
+    const CP: u8 = 0x11;
+    const EX: u8 = 0x12;
+    const IN: u8 = 0x13;
+    const IS: u8 = 0x14;
+    const NS: u8 = 0x15;
+    const OP: u8 = 0x16;
+    const PO: u8 = 0x17;
+    const PR: u8 = 0x18;
+    const QU: u8 = 0x19;
+    /// A quotation mark which is also initial punctuation (`Pi`)
+    const QU_PI: u8 = 0x1a;
+    /// A quotation mark which is also final punctuation (`Pf`)
+    const QU_PF: u8 = 0x1b;
+    const SY: u8 = 0x1c;
+    const HL: u8 = 0x1d;
+    const ID: u8 = 0x1e;
+    const EB: u8 = 0x1f;
+    const EM: u8 = 0x20;
+    const H2: u8 = 0x21;
+    const H3: u8 = 0x22;
+    const JL: u8 = 0x23;
+    const JV: u8 = 0x24;
+    const JT: u8 = 0x25;
+    const RI: u8 = 0x26;
+    const NU: u8 = 0x27;
+    const AK: u8 = 0x28;
+    const AP: u8 = 0x29;
+    const AS: u8 = 0x2a;
+    const VF: u8 = 0x2b;
+    const VI: u8 = 0x2c;
+
+    const CLASS_MASK: u8 = 0x3f;
+    /// East_Asian_Width is F, W or H
+    const EAST_ASIAN: u8 = 0x40;
+    /// An unassigned code point which is Extended_Pictographic
+    const EXT_PICT_UNASSIGNED: u8 = 0x80;
+}
+
+#[inline]
+fn get_property(c: char) -> u8 {
+    LB_PAGES[usize::from(LB_TABLE[(c as usize) >> 8])][(c as usize) & 0xff]
+}
+
+#[cfg(test)]
+pub (crate) mod tests {
+    use crate::line_break::*;
+
+    #[test]
+    fn can_get_line_breaks() {
+        let breaks = CharLineBreaks::new("A (big) $5.00 dog—\r\nis 😀.".char_indices().peekable())
+            .collect::<Vec<_>>();
+        assert_eq!(breaks, [
+            (2, BreakOpportunity::Allowed),
+            (8, BreakOpportunity::Allowed),
+            (14, BreakOpportunity::Allowed),
+            (17, BreakOpportunity::Allowed),
+            (22, BreakOpportunity::Mandatory),
+            (25, BreakOpportunity::Allowed),
+            (30, BreakOpportunity::Mandatory),
+        ]);
+        assert_eq!(LineBreaks::new("").next(), None);
+    }
+
+    pub (crate) fn line_test(input: &str, expected_output: &[&str], message: &str) {
+        let mut lines = vec!();
+        let mut start = 0;
+        for (offset, opportunity) in LineBreaks::new(input) {
+            assert_eq!(opportunity == BreakOpportunity::Mandatory, offset == input.len() || matches!(input[..offset].chars().last(), Some('\n' | '\r' | '\u{0b}' | '\u{0c}' | '\u{85}' | '\u{2028}' | '\u{2029}')),
+                "Mandatory break mismatch at {offset}: {message}");
+            lines.push(&input[start..offset]);
+            start = offset;
+        }
+        assert_eq!(lines, expected_output, "Line break mismatch: {message}");
+
+        let offsets = CharLineBreaks::new(input.chars().peekable()).collect::<Vec<_>>();
+        assert_eq!(offsets, LineBreaks::new(input).collect::<Vec<_>>(), "Line break stream mismatch: {message}");
+    }
+}
@@ -0,0 +1,377 @@
+//! This module provides the names of characters from `UnicodeData.txt` and `NameAliases.txt`,
+//! along with lookup of a character by its name.
+//! ```
+//! # use crate::finl_unicode::names::{char_from_name, CharacterName};
+//! assert_eq!('\u{301}'.name().as_deref(), Some("COMBINING ACUTE ACCENT"));
+//! assert_eq!('한'.name().as_deref(), Some("HANGUL SYLLABLE HAN"));
+//! assert_eq!('\n'.name(), None);
+//! assert_eq!(char_from_name("COMBINING ACUTE ACCENT"), Some('\u{301}'));
+//! assert_eq!(char_from_name("combining_acute_accent"), Some('\u{301}'));
+//! assert_eq!(char_from_name("LINE FEED"), Some('\n'));
+//! assert_eq!(char_from_name("CJK UNIFIED IDEOGRAPH-4E00"), Some('一'));
+//! ```
+//!
+//! Names are matched loosely following UAX44-LM2: case, whitespace, underscores and hyphens
+//! between letters or digits are ignored, so `"zero width space"` and `"ZERO-WIDTH-SPACE"` both give
+//! U+200B. Control characters have no name, but they can be found by their aliases, which are
+//! given by `name_aliases`.
+
+use alloc::format;
+use alloc::string::String;
+use core::iter::Peekable;
+use crate::data::names::{ALGORITHMIC_NAMES, NAME_ALIASES, NAME_ALIASES_BY_LOOSE_NAME, NAME_DATA, NAME_INDEX, NAME_WORDS, NAME_WORD_OFFSETS, NAMES_BY_LOOSE_NAME, NAMES_PER_BLOCK};
+
+/// Trait to look up the name of a character. Importing the trait will provide the methods on
+/// the `char` type.
+pub trait CharacterName {
+    /// Returns the name of the character, or `None` if it has no name. Controls, private use
+    /// characters, surrogates, noncharacters and unassigned code points have no name.
+    fn name(self) -> Option<String>;
+    /// Returns an iterator over the formal aliases of the character in `NameAliases.txt`. These
+    /// give corrections for names with errors, which cannot be changed, as well as names and
+    /// abbreviations for controls and format characters.
+    fn name_aliases(self) -> NameAliases;
+}
+
+/// The types of name aliases.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NameAliasType {
+    /// A correction of a name with a serious error
+    Correction,
+    /// An ISO 6429 name for a control
+    Control,
+    /// A widely used alternate name for a format character
+    Alternate,
+    /// A name which was documented but never in any standard
+    Figment,
+    /// An abbreviation or acronym for a control, format character or variation selector
+    Abbreviation,
+}
+
+/// Iterator over the aliases of a character as `(alias, type)` pairs, returned by
+/// `CharacterName::name_aliases`.
+#[derive(Debug, Clone)]
+pub struct NameAliases {
+    aliases: core::slice::Iter<'static, (char, &'static str, u8)>,
+}
+
+impl Iterator for NameAliases {
+    type Item = (&'static str, NameAliasType);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.aliases.next().map(|&(_, alias, alias_type)| (alias, decode_alias_type(alias_type)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.aliases.size_hint()
+    }
+}
+
+impl ExactSizeIterator for NameAliases {}
+
+impl CharacterName for char {
+    fn name(self) -> Option<String> {
+        if HANGUL_SYLLABLES.contains(&self) {
+            return Some(hangul_syllable_name(self));
+        }
+        if let Some(&(_, _, prefix)) = ALGORITHMIC_NAMES.iter().find(|(start, end, _)| (*start..=*end).contains(&self)) {
+            return Some(format!("{prefix}{:04X}", self as u32));
+        }
+        let block = NAME_INDEX.partition_point(|&(start, _)| start <= self).checked_sub(1)?;
+        let mut records = NameRecords::new(block);
+        for _ in 0 .. NAMES_PER_BLOCK {
+            match records.next_char()? {
+                c if c == self => return Some(records.read_name()),
+                c if c > self => return None,
+                _ => records.skip_name()
+            }
+        }
+        None
+    }
+
+    fn name_aliases(self) -> NameAliases {
+        let start = NAME_ALIASES.partition_point(|&(c, _, _)| c < self);
+        let end = NAME_ALIASES.partition_point(|&(c, _, _)| c <= self);
+        NameAliases { aliases: NAME_ALIASES[start..end].iter() }
+    }
+}
+
+/// Looks up a character by its name or one of its aliases using the loose matching rule
+/// UAX44-LM2. Returns `None` if no character has the name.
+pub fn char_from_name(name: &str) -> Option<char> {
+    // U+1180 HANGUL JUNGSEONG O-E is distinguished from U+116C HANGUL JUNGSEONG OE by its hyphen,
+    // which is the one medial hyphen which isn't ignored
+    if name.chars().filter(|&c| !c.is_whitespace() && c != '_').map(|c| c.to_ascii_uppercase()).eq("HANGULJUNGSEONGO-E".chars()) {
+        return Some('\u{1180}');
+    }
+    let name = loose_chars(name.chars()).collect::<String>();
+    if let Some(c) = hangul_syllable_from_name(&name) {
+        return Some(c);
+    }
+    for &(start, end, prefix) in &ALGORITHMIC_NAMES {
+        if let Some(hex) = strip_loose_prefix(&name, prefix.trim_end_matches('-')) {
+            if let Some(c) = u32::from_str_radix(hex, 16).ok().and_then(char::from_u32) {
+                if (start..=end).contains(&c) && hex == format!("{:04X}", c as u32) {
+                    return Some(c);
+                }
+            }
+        }
+    }
+    // Both tables are sorted by loose name, so the stored names can be compared as they're decoded
+    if let Ok(index) = NAMES_BY_LOOSE_NAME.binary_search_by(|&record| loose_chars(record_name_chars(usize::from(record))).cmp(name.chars())) {
+        return Some(record_char(usize::from(NAMES_BY_LOOSE_NAME[index])));
+    }
+    NAME_ALIASES_BY_LOOSE_NAME.binary_search_by(|&(alias, _)| alias.cmp(&name))
+        .ok()
+        .map(|index| NAME_ALIASES_BY_LOOSE_NAME[index].1)
+}
+
+// Reads the records of names in a block of NAME_DATA, as described in generate-sources. Each
+// record is read by calling `next_char` and then either `read_name` or `skip_name`.
+struct NameRecords {
+    offset: usize,
+    char_code: u32,
+}
+
+impl NameRecords {
+    fn new(block: usize) -> NameRecords {
+        let (char_code, offset) = NAME_INDEX[block];
+        NameRecords { offset: offset as usize, char_code: char_code as u32 }
+    }
+
+    fn next_char(&mut self) -> Option<char> {
+        let delta = *NAME_DATA.get(self.offset)?;
+        self.offset += 1;
+        if delta == 0xff {
+            self.char_code = NAME_DATA[self.offset .. self.offset + 3].iter().fold(0, |code, &byte| (code << 8) | u32::from(byte));
+            self.offset += 3;
+        }
+        else {
+            self.char_code += u32::from(delta);
+        }
+        char::from_u32(self.char_code)
+    }
+
+    fn read_name(self) -> String {
+        self.name_chars().collect()
+    }
+
+    // The characters of the name, with a space between each word
+    fn name_chars(mut self) -> impl Iterator<Item = char> {
+        let word_count = self.next_byte();
+        (0 .. word_count).flat_map(move |i| {
+            let index = self.next_word_index();
+            let word = &NAME_WORDS[NAME_WORD_OFFSETS[index] as usize .. NAME_WORD_OFFSETS[index + 1] as usize - 1];
+            (i > 0).then_some(' ').into_iter().chain(word.chars())
+        })
+    }
+
+    fn skip_name(&mut self) {
+        for _ in 0 .. self.next_byte() {
+            self.next_word_index();
+        }
+    }
+
+    fn next_word_index(&mut self) -> usize {
+        let index = usize::from(self.next_byte());
+        if index < 0xc0 {
+            index
+        }
+        else {
+            0xc0 + ((index - 0xc0) << 8) + usize::from(self.next_byte())
+        }
+    }
+
+    fn next_byte(&mut self) -> u8 {
+        self.offset += 1;
+        NAME_DATA[self.offset - 1]
+    }
+}
+
+// The character of the `record`th name in NAME_DATA, with the records positioned at its name
+fn seek_record(record: usize) -> (char, NameRecords) {
+    let mut records = NameRecords::new(record / NAMES_PER_BLOCK);
+    for _ in 0 .. record % NAMES_PER_BLOCK {
+        records.next_char();
+        records.skip_name();
+    }
+    let c = records.next_char().unwrap();
+    (c, records)
+}
+
+fn record_char(record: usize) -> char {
+    seek_record(record).0
+}
+
+fn record_name_chars(record: usize) -> impl Iterator<Item = char> {
+    seek_record(record).1.name_chars()
+}
+
+// Applies the loose matching rule UAX44-LM2 to the characters of a name: case, whitespace,
+// underscores and hyphens between two letters or digits are ignored.
+fn loose_chars<I: Iterator<Item = char>>(chars: I) -> LooseChars<I> {
+    LooseChars { chars: chars.peekable(), previous: ' ' }
+}
+
+struct LooseChars<I: Iterator<Item = char>> {
+    chars: Peekable<I>,
+    previous: char,
+}
+
+impl<I: Iterator<Item = char>> Iterator for LooseChars<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        loop {
+            let c = self.chars.next()?;
+            let medial_hyphen = c == '-' && self.previous.is_ascii_alphanumeric() && self.chars.peek().is_some_and(char::is_ascii_alphanumeric);
+            self.previous = c;
+            if !c.is_whitespace() && c != '_' && !medial_hyphen {
+                return Some(c.to_ascii_uppercase());
+            }
+        }
+    }
+}
+
+// Strips `prefix`, compared loosely, from `name`, which is already in loose form
+fn strip_loose_prefix<'a>(name: &'a str, prefix: &str) -> Option<&'a str> {
+    let mut rest = name.chars();
+    for c in loose_chars(prefix.chars()) {
+        if rest.next() != Some(c) {
+            return None;
+        }
+    }
+    Some(rest.as_str())
+}
+
+fn decode_alias_type(alias_type: u8) -> NameAliasType {
+    match alias_type {
+        CORRECTION => NameAliasType::Correction,
+        CONTROL => NameAliasType::Control,
+        ALTERNATE => NameAliasType::Alternate,
+        FIGMENT => NameAliasType::Figment,
+        _ => NameAliasType::Abbreviation,
+    }
+}
+
+// Symbolic names for the alias types in the data tables
+const CORRECTION: u8 = 0x01;
+const CONTROL: u8 = 0x02;
+const ALTERNATE: u8 = 0x03;
+const FIGMENT: u8 = 0x04;
+
+// Hangul syllable names are made up of the short names of their jamo in Jamo.txt
+const HANGUL_SYLLABLES: core::ops::RangeInclusive<char> = '\u{ac00}'..='\u{d7a3}';
+const JAMO_L: [&str; 19] = ["G", "GG", "N", "D", "DD", "R", "M", "B", "BB", "S", "SS", "", "J", "JJ", "C", "K", "T", "P", "H"];
+const JAMO_V: [&str; 21] = ["A", "AE", "YA", "YAE", "EO", "E", "YEO", "YE", "O", "WA", "WAE", "OE", "YO", "U", "WEO", "WE", "WI", "YU", "EU", "YI", "I"];
+const JAMO_T: [&str; 28] = ["", "G", "GG", "GS", "N", "NJ", "NH", "D", "L", "LG", "LM", "LB", "LS", "LT", "LP", "LH", "M", "B", "BS", "S", "SS", "NG", "J", "C", "K", "T", "P", "H"];
+
+fn hangul_syllable_name(c: char) -> String {
+    let index = c as usize - 0xac00;
+    let (l, v, t) = (index / (21 * 28), (index % (21 * 28)) / 28, index % 28);
+    format!("HANGUL SYLLABLE {}{}{}", JAMO_L[l], JAMO_V[v], JAMO_T[t])
+}
+
+fn hangul_syllable_from_name(name: &str) -> Option<char> {
+    let jamo = name.strip_prefix("HANGULSYLLABLE")?;
+    // The short names are ambiguous on their own (e.g., GAG could be G+A+G or GA+G), so try each
+    // initial consonant and vowel which matches and check that the rest is a final consonant.
+    for (l, l_name) in JAMO_L.iter().enumerate() {
+        let Some(rest) = jamo.strip_prefix(l_name) else { continue };
+        for (v, v_name) in JAMO_V.iter().enumerate() {
+            let Some(rest) = rest.strip_prefix(v_name) else { continue };
+            if let Some(t) = JAMO_T.iter().position(|t_name| *t_name == rest) {
+                return char::from_u32((0xac00 + (l * 21 + v) * 28 + t) as u32);
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::names::*;
+
+    #[test]
+    fn can_get_names() {
+        assert_eq!(' '.name().as_deref(), Some("SPACE"));
+        assert_eq!('A'.name().as_deref(), Some("LATIN CAPITAL LETTER A"));
+        assert_eq!('\u{1180}'.name().as_deref(), Some("HANGUL JUNGSEONG O-E"));
+        assert_eq!('\u{f39}'.name().as_deref(), Some("TIBETAN MARK TSA -PHRU"));
+        assert_eq!('\u{ac00}'.name().as_deref(), Some("HANGUL SYLLABLE GA"));
+        assert_eq!('\u{d7a3}'.name().as_deref(), Some("HANGUL SYLLABLE HIH"));
+        assert_eq!('\u{20000}'.name().as_deref(), Some("CJK UNIFIED IDEOGRAPH-20000"));
+        assert_eq!('\u{f900}'.name().as_deref(), Some("CJK COMPATIBILITY IDEOGRAPH-F900"));
+        assert_eq!('\u{17000}'.name().as_deref(), Some("TANGUT IDEOGRAPH-17000"));
+        assert_eq!('\u{1f980}'.name().as_deref(), Some("CRAB"));
+        assert_eq!('\u{e01ef}'.name().as_deref(), Some("VARIATION SELECTOR-256"));
+        assert_eq!('\u{10fffd}'.name(), None);
+        assert_eq!('\u{7f}'.name(), None);
+        assert_eq!('\u{e000}'.name(), None);
+        assert_eq!('\u{378}'.name(), None);
+        assert_eq!('\u{10ffff}'.name(), None);
+    }
+
+    #[test]
+    fn can_get_name_aliases() {
+        assert_eq!('\u{1a2}'.name_aliases().collect::<Vec<_>>(), vec![("LATIN CAPITAL LETTER GHA", NameAliasType::Correction)]);
+        assert_eq!('\u{feff}'.name_aliases().collect::<Vec<_>>(), vec![
+            ("BYTE ORDER MARK", NameAliasType::Alternate),
+            ("BOM", NameAliasType::Abbreviation),
+            ("ZWNBSP", NameAliasType::Abbreviation)]);
+        assert_eq!('\u{80}'.name_aliases().next(), Some(("PADDING CHARACTER", NameAliasType::Figment)));
+        assert_eq!('\0'.name_aliases().next(), Some(("NULL", NameAliasType::Control)));
+        assert_eq!('a'.name_aliases().len(), 0);
+    }
+
+    #[test]
+    fn can_look_up_names() {
+        assert_eq!(char_from_name("SPACE"), Some(' '));
+        assert_eq!(char_from_name("latin small letter sharp s"), Some('ß'));
+        assert_eq!(char_from_name("Latin_Small_Letter_Sharp_S"), Some('ß'));
+        assert_eq!(char_from_name("ZERO-WIDTH-SPACE"), Some('\u{200b}'));
+        assert_eq!(char_from_name("HANGUL JUNGSEONG OE"), Some('\u{116c}'));
+        assert_eq!(char_from_name("hangul jungseong o-e"), Some('\u{1180}'));
+        assert_eq!(char_from_name("TIBETAN MARK TSA -PHRU"), Some('\u{f39}'));
+        assert_eq!(char_from_name("TIBETAN MARK TSA PHRU"), None);
+        assert_eq!(char_from_name("Hangul Syllable Gag"), Some('각'));
+        assert_eq!(char_from_name("HANGUL SYLLABLE HIH"), Some('\u{d7a3}'));
+        assert_eq!(char_from_name("HANGUL SYLLABLE Q"), None);
+        assert_eq!(char_from_name("cjk unified ideograph-20000"), Some('\u{20000}'));
+        assert_eq!(char_from_name("CJK UNIFIED IDEOGRAPH-04E00"), None);
+        assert_eq!(char_from_name("CJK UNIFIED IDEOGRAPH-E000"), None);
+        assert_eq!(char_from_name("NUSHU CHARACTER-1B170"), Some('\u{1b170}'));
+        assert_eq!(char_from_name("LATIN CAPITAL LETTER GHA"), Some('\u{1a2}'));
+        assert_eq!(char_from_name("LATIN CAPITAL LETTER OI"), Some('\u{1a2}'));
+        assert_eq!(char_from_name("bom"), Some('\u{feff}'));
+        assert_eq!(char_from_name("VS17"), Some('\u{e0100}'));
+        assert_eq!(char_from_name("NOT A CHARACTER NAME"), None);
+        assert_eq!(char_from_name(""), None);
+    }
+
+    #[test]
+    fn loose_matching_ignores_all_whitespace() {
+        assert_eq!(char_from_name("LATIN\tSMALL LETTER A"), Some('a'));
+        assert_eq!(char_from_name("latin\nsmall\u{a0}letter\u{3000}a"), Some('a'));
+        assert_eq!(char_from_name(" ZERO\tWIDTH\r\nSPACE "), Some('\u{200b}'));
+        assert_eq!(char_from_name("byte\torder\nmark"), Some('\u{feff}'));
+        assert_eq!(char_from_name("CJK\tUNIFIED\tIDEOGRAPH-4E00"), Some('\u{4e00}'));
+        assert_eq!(char_from_name("hangul\tjungseong\to-e"), Some('\u{1180}'));
+    }
+
+    #[test]
+    fn aliases_round_trip() {
+        for &(c, alias, _) in &NAME_ALIASES {
+            assert_eq!(char_from_name(alias), Some(c), "{alias}");
+        }
+    }
+
+    #[test]
+    fn names_round_trip() {
+        for c in ('\0'..='\u{3ffff}').chain('\u{e0000}'..='\u{e01ef}') {
+            if let Some(name) = c.name() {
+                assert_eq!(char_from_name(&name), Some(c), "{name}");
+            }
+        }
+    }
+}
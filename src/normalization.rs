@@ -0,0 +1,467 @@
+//! This module implements the Unicode normalization forms described in
+//! [UAX #15](https://www.unicode.org/reports/tr15/). The `Normalization` trait extends any iterator
+//! over `char` with `nfd`, `nfkd`, `nfc` and `nfkc` methods which return streaming iterators over
+//! the normalized characters.
+//! ```
+//! # use crate::finl_unicode::normalization::Normalization;
+//! assert_eq!("e\u{301}".chars().nfc().collect::<String>(), "é");
+//! assert_eq!("é".chars().nfd().collect::<String>(), "e\u{301}");
+//! assert_eq!("ﬁ".chars().nfkc().collect::<String>(), "fi");
+//! ```
+//!
+//! The normalizing iterators can themselves be made `Peekable`, so they can be combined with the
+//! segmentation traits which work on `PeekChar` iterators.
+//! ```
+//! # use crate::finl_unicode::normalization::Normalization;
+//! # use crate::finl_unicode::grapheme_clusters::GraphemeCluster;
+//! let mut normalized = "a\u{308}\u{301}b".chars().nfc().peekable();
+//! assert_eq!(normalized.next_cluster(), Some("ä\u{301}".to_string()));
+//! assert_eq!(normalized.next_cluster(), Some("b".to_string()));
+//! ```
+//!
+//! There are also quick-check functions which can often determine whether a string is normalized
+//! without normalizing it, and `canonical_combining_class` to look up the combining class of a
+//! character.
+
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use crate::data::combining_class::{CCC_PAGES, CCC_TABLE};
+use crate::data::decompositions::{CANONICAL_DECOMPOSITIONS, COMPATIBILITY_DECOMPOSITIONS, COMPOSITIONS};
+use crate::data::normalization_property::{NORM_PAGES, NORM_TABLE};
+
+/// Normalize a stream of characters. This trait is implemented for every iterator over `char`,
+/// including `Chars` and `Peekable<Chars>`.
+pub trait Normalization: Iterator<Item = char> + Sized {
+    /// Returns an iterator over the canonical decomposition (NFD) of the characters.
+    fn nfd(self) -> Decompositions<Self>;
+    /// Returns an iterator over the compatibility decomposition (NFKD) of the characters.
+    fn nfkd(self) -> Decompositions<Self>;
+    /// Returns an iterator over the canonical composition (NFC) of the characters.
+    fn nfc(self) -> Recompositions<Self>;
+    /// Returns an iterator over the compatibility composition (NFKC) of the characters.
+    fn nfkc(self) -> Recompositions<Self>;
+}
+
+impl<I> Normalization for I where I: Iterator<Item = char> {
+    #[inline]
+    fn nfd(self) -> Decompositions<Self> {
+        Decompositions::new(self, false)
+    }
+
+    #[inline]
+    fn nfkd(self) -> Decompositions<Self> {
+        Decompositions::new(self, true)
+    }
+
+    #[inline]
+    fn nfc(self) -> Recompositions<Self> {
+        Recompositions::new(Decompositions::new(self, false))
+    }
+
+    #[inline]
+    fn nfkc(self) -> Recompositions<Self> {
+        Recompositions::new(Decompositions::new(self, true))
+    }
+}
+
+/// An iterator over the NFD or NFKD form of a stream of characters. Characters are returned as
+/// soon as the next starter has been seen, so only a single run of combining marks is buffered.
+#[derive(Clone)]
+pub struct Decompositions<I> {
+    iter: I,
+    compatibility: bool,
+    /// Decomposed characters along with their combining classes
+    buffer: Vec<(u8, char)>,
+    /// Characters before this index are in canonical order and can be returned
+    ready: usize,
+    /// The index of the next character to return
+    position: usize,
+}
+
+impl<I> Decompositions<I> where I: Iterator<Item = char> {
+    fn new(iter: I, compatibility: bool) -> Decompositions<I> {
+        Decompositions {
+            iter,
+            compatibility,
+            buffer: Vec::new(),
+            ready: 0,
+            position: 0,
+        }
+    }
+
+    /// Append the full decomposition of `c` to the buffer.
+    #[inline]
+    fn decompose(&mut self, c: char) {
+        let quick_check = if self.compatibility { NormalizationProperty::NFKD_NO } else { NormalizationProperty::NFD_NO };
+        if get_property(c) & quick_check == 0 {
+            self.push(c);
+        }
+        else if HANGUL_SYLLABLES.contains(&c) {
+            let s_index = c as u32 - S_BASE;
+            self.push(from_u32(L_BASE + s_index / N_COUNT));
+            self.push(from_u32(V_BASE + (s_index % N_COUNT) / T_COUNT));
+            let t_index = s_index % T_COUNT;
+            if t_index != 0 {
+                self.push(from_u32(T_BASE + t_index));
+            }
+        }
+        else {
+            let decomposition = self.compatibility
+                .then(|| lookup(&COMPATIBILITY_DECOMPOSITIONS, c))
+                .flatten()
+                .or_else(|| lookup(&CANONICAL_DECOMPOSITIONS, c))
+                .unwrap_or_default();
+            for &d in decomposition {
+                self.push(d);
+            }
+        }
+    }
+
+    /// Add a single character to the buffer. A starter puts everything before it in canonical
+    /// order and makes it ready to return.
+    #[inline]
+    fn push(&mut self, c: char) {
+        let class = canonical_combining_class(c);
+        if class == 0 {
+            self.sort_pending();
+            self.buffer.push((class, c));
+            self.ready = self.buffer.len();
+        }
+        else {
+            self.buffer.push((class, c));
+        }
+    }
+
+    #[inline]
+    fn sort_pending(&mut self) {
+        // The sort is stable, so marks with the same combining class keep their order
+        self.buffer[self.ready..].sort_by_key(|&(class, _)| class);
+    }
+}
+
+impl<I> Iterator for Decompositions<I> where I: Iterator<Item = char> {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        loop {
+            if self.position < self.ready {
+                let (_, c) = self.buffer[self.position];
+                self.position += 1;
+                return Some(c);
+            }
+            self.buffer.drain(..self.position);
+            self.ready = 0;
+            self.position = 0;
+            match self.iter.next() {
+                Some(c) => self.decompose(c),
+                None if self.buffer.is_empty() => return None,
+                None => {
+                    self.sort_pending();
+                    self.ready = self.buffer.len();
+                }
+            }
+        }
+    }
+}
+
+/// An iterator over the NFC or NFKC form of a stream of characters. Characters are returned once
+/// it is no longer possible for them to combine with anything which follows.
+#[derive(Clone)]
+pub struct Recompositions<I> {
+    iter: Decompositions<I>,
+    /// The last starter seen, which may still combine with following characters
+    starter: Option<char>,
+    /// Characters after `starter` which did not combine with it
+    pending: Vec<char>,
+    /// The combining class of the last character in `pending`
+    last_class: Option<u8>,
+    output: VecDeque<char>,
+}
+
+impl<I> Recompositions<I> where I: Iterator<Item = char> {
+    fn new(iter: Decompositions<I>) -> Recompositions<I> {
+        Recompositions {
+            iter,
+            starter: None,
+            pending: Vec::new(),
+            last_class: None,
+            output: VecDeque::new(),
+        }
+    }
+
+    #[inline]
+    fn recompose(&mut self, c: char) {
+        let class = canonical_combining_class(c);
+        if let Some(starter) = self.starter {
+            // A character is blocked from the starter if there is a character between them with
+            // the same or higher combining class.
+            let blocked = matches!(self.last_class, Some(last) if last >= class);
+            if !blocked {
+                if let Some(composite) = compose(starter, c) {
+                    self.starter = Some(composite);
+                    return;
+                }
+            }
+        }
+        if class == 0 {
+            self.flush();
+            self.starter = Some(c);
+        }
+        else if self.starter.is_none() {
+            self.output.push_back(c);
+        }
+        else {
+            self.pending.push(c);
+            self.last_class = Some(class);
+        }
+    }
+
+    #[inline]
+    fn flush(&mut self) {
+        self.output.extend(self.starter.take());
+        self.output.extend(self.pending.drain(..));
+        self.last_class = None;
+    }
+}
+
+impl<I> Iterator for Recompositions<I> where I: Iterator<Item = char> {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        loop {
+            if let Some(c) = self.output.pop_front() {
+                return Some(c);
+            }
+            match self.iter.next() {
+                Some(c) => self.recompose(c),
+                None => {
+                    self.flush();
+                    return self.output.pop_front();
+                }
+            }
+        }
+    }
+}
+
+/// The result of a quick check for whether a string is normalized. `Maybe` means that the string
+/// has to be normalized to find out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IsNormalized {
+    Yes,
+    No,
+    Maybe,
+}
+
+/// Quick check whether the characters are in NFC.
+/// ```
+/// # use crate::finl_unicode::normalization::{is_nfc_quick, IsNormalized};
+/// assert_eq!(is_nfc_quick("é".chars()), IsNormalized::Yes);
+/// assert_eq!(is_nfc_quick("\u{212b}".chars()), IsNormalized::No);
+/// assert_eq!(is_nfc_quick("e\u{301}".chars()), IsNormalized::Maybe);
+/// ```
+#[inline]
+pub fn is_nfc_quick<I: IntoIterator<Item = char>>(chars: I) -> IsNormalized {
+    quick_check(chars, NormalizationProperty::NFC_NO, NormalizationProperty::NFC_MAYBE)
+}
+
+/// Quick check whether the characters are in NFD. The answer is never `Maybe`.
+#[inline]
+pub fn is_nfd_quick<I: IntoIterator<Item = char>>(chars: I) -> IsNormalized {
+    quick_check(chars, NormalizationProperty::NFD_NO, 0)
+}
+
+/// Quick check whether the characters are in NFKC.
+#[inline]
+pub fn is_nfkc_quick<I: IntoIterator<Item = char>>(chars: I) -> IsNormalized {
+    quick_check(chars, NormalizationProperty::NFKC_NO, NormalizationProperty::NFKC_MAYBE)
+}
+
+/// Quick check whether the characters are in NFKD. The answer is never `Maybe`.
+#[inline]
+pub fn is_nfkd_quick<I: IntoIterator<Item = char>>(chars: I) -> IsNormalized {
+    quick_check(chars, NormalizationProperty::NFKD_NO, 0)
+}
+
+/// Whether a string is in NFC. This only normalizes the string if the quick check can't decide.
+/// ```
+/// # use crate::finl_unicode::normalization::is_nfc;
+/// assert!(is_nfc("é"));
+/// assert!(!is_nfc("e\u{301}"));
+/// ```
+#[inline]
+pub fn is_nfc(s: &str) -> bool {
+    match is_nfc_quick(s.chars()) {
+        IsNormalized::Yes => true,
+        IsNormalized::No => false,
+        IsNormalized::Maybe => s.chars().nfc().eq(s.chars()),
+    }
+}
+
+/// Whether a string is in NFD.
+#[inline]
+pub fn is_nfd(s: &str) -> bool {
+    is_nfd_quick(s.chars()) == IsNormalized::Yes
+}
+
+/// Whether a string is in NFKC. This only normalizes the string if the quick check can't decide.
+#[inline]
+pub fn is_nfkc(s: &str) -> bool {
+    match is_nfkc_quick(s.chars()) {
+        IsNormalized::Yes => true,
+        IsNormalized::No => false,
+        IsNormalized::Maybe => s.chars().nfkc().eq(s.chars()),
+    }
+}
+
+/// Whether a string is in NFKD.
+#[inline]
+pub fn is_nfkd(s: &str) -> bool {
+    is_nfkd_quick(s.chars()) == IsNormalized::Yes
+}
+
+/// Returns the canonical combining class of a character. Starters have a class of 0.
+/// ```
+/// # use crate::finl_unicode::normalization::canonical_combining_class;
+/// assert_eq!(canonical_combining_class('a'), 0);
+/// assert_eq!(canonical_combining_class('\u{301}'), 230);
+/// assert_eq!(canonical_combining_class('\u{323}'), 220);
+/// ```
+#[inline]
+pub fn canonical_combining_class(c: char) -> u8 {
+    CCC_PAGES[usize::from(CCC_TABLE[(c as usize) >> 8])][(c as usize) & 0xff]
+}
+
+// ------------------------
+// Private implementation details follow
+
+const S_BASE: u32 = 0xac00;
+const L_BASE: u32 = 0x1100;
+const V_BASE: u32 = 0x1161;
+const T_BASE: u32 = 0x11a7;
+const L_COUNT: u32 = 19;
+const V_COUNT: u32 = 21;
+const T_COUNT: u32 = 28;
+const N_COUNT: u32 = V_COUNT * T_COUNT;
+const HANGUL_SYLLABLES: core::ops::RangeInclusive<char> = '\u{ac00}'..='\u{d7a3}';
+
+/// The quick check algorithm from UAX #15. The NFD and NFKD checks pass a `maybe` of 0 since those
+/// forms have no `Maybe` values.
+#[inline]
+fn quick_check<I: IntoIterator<Item = char>>(chars: I, no: u8, maybe: u8) -> IsNormalized {
+    let mut last_class = 0;
+    let mut result = IsNormalized::Yes;
+    for c in chars {
+        let class = canonical_combining_class(c);
+        if class != 0 && last_class > class {
+            return IsNormalized::No;
+        }
+        let property = get_property(c);
+        if property & no != 0 {
+            return IsNormalized::No;
+        }
+        if property & maybe != 0 {
+            result = IsNormalized::Maybe;
+        }
+        last_class = class;
+    }
+    result
+}
+
+/// Find the primary composite of two characters, if there is one.
+#[inline]
+fn compose(first: char, second: char) -> Option<char> {
+    let (first, second) = (first as u32, second as u32);
+    // Hangul LV syllables
+    if (L_BASE..L_BASE + L_COUNT).contains(&first) && (V_BASE..V_BASE + V_COUNT).contains(&second) {
+        return Some(from_u32(S_BASE + ((first - L_BASE) * V_COUNT + second - V_BASE) * T_COUNT));
+    }
+    // Hangul LVT syllables
+    if (S_BASE..S_BASE + L_COUNT * N_COUNT).contains(&first) && (T_BASE + 1..T_BASE + T_COUNT).contains(&second) {
+        let t_index = (first - S_BASE) % T_COUNT;
+        if t_index == 0 {
+            return Some(from_u32(first + second - T_BASE));
+        }
+    }
+    let (first, second) = (from_u32(first), from_u32(second));
+    COMPOSITIONS.binary_search_by_key(&(first, second), |&(pair, _)| pair)
+        .ok()
+        .map(|index| COMPOSITIONS[index].1)
+}
+
+#[inline]
+fn lookup(table: &'static [(char, &'static [char])], c: char) -> Option<&'static [char]> {
+    table.binary_search_by_key(&c, |&(key, _)| key)
+        .ok()
+        .map(|index| table[index].1)
+}
+
+/// Only used for code points computed by the Hangul algorithms, which are always valid.
+#[inline]
+fn from_u32(code: u32) -> char {
+    char::from_u32(code).unwrap_or_default()
+}
+
+// Symbolic names for the quick check flags in data tables
+struct NormalizationProperty {}
+impl NormalizationProperty {
+    const NFD_NO: u8 = 0x01;
+    const NFKD_NO: u8 = 0x02;
+    const NFC_NO: u8 = 0x04;
+    const NFC_MAYBE: u8 = 0x08;
+    const NFKC_NO: u8 = 0x10;
+    const NFKC_MAYBE: u8 = 0x20;
+}
+
+#[inline]
+fn get_property(c: char) -> u8 {
+    NORM_PAGES[usize::from(NORM_TABLE[(c as usize) >> 8])][(c as usize) & 0xff]
+}
+
+#[cfg(test)]
+pub (crate) mod tests {
+    use crate::normalization::*;
+
+    #[test]
+    fn can_normalize() {
+        assert_eq!("Å\u{323}".chars().nfd().collect::<String>(), "A\u{323}\u{30a}");
+        assert_eq!("A\u{30a}\u{323}".chars().nfc().collect::<String>(), "Ạ\u{30a}");
+        assert_eq!("한글".chars().nfd().count(), 6);
+        assert_eq!("한글".chars().nfd().nfc().collect::<String>(), "한글");
+        assert_eq!("x²".chars().nfkd().collect::<String>(), "x2");
+        assert_eq!("\u{301}e".chars().nfc().collect::<String>(), "\u{301}e");
+        assert_eq!(is_nfd_quick("a\u{323}\u{301}".chars()), IsNormalized::Yes);
+        assert_eq!(is_nfd_quick("a\u{301}\u{323}".chars()), IsNormalized::No);
+        assert!(is_nfkc("fi"));
+        assert!(!is_nfkc("ﬁ"));
+    }
+
+    pub (crate) fn normalization_test(source: &str, nfc: &str, nfd: &str, nfkc: &str, nfkd: &str, message: &str) {
+        assert_eq!(source.chars().nfc().collect::<String>(), nfc, "NFC mismatch: {message}");
+        assert_eq!(source.chars().nfd().collect::<String>(), nfd, "NFD mismatch: {message}");
+        assert_eq!(source.chars().nfkc().collect::<String>(), nfkc, "NFKC mismatch: {message}");
+        assert_eq!(source.chars().nfkd().collect::<String>(), nfkd, "NFKD mismatch: {message}");
+
+        assert!(is_nfc(nfc), "NFC check mismatch: {message}");
+        assert!(is_nfd(nfd), "NFD check mismatch: {message}");
+        assert!(is_nfkc(nfkc), "NFKC check mismatch: {message}");
+        assert!(is_nfkd(nfkd), "NFKD check mismatch: {message}");
+        assert_eq!(is_nfc(source), source == nfc, "NFC source check mismatch: {message}");
+        assert_eq!(is_nfkd(source), source == nfkd, "NFKD source check mismatch: {message}");
+    }
+
+    /// Every character which isn't listed in part 1 of the normalization test must be unchanged
+    /// by all of the normalization forms.
+    pub (crate) fn unchanged_test(part_one: &[char]) {
+        for c in (0..=0x10ffff).filter_map(char::from_u32) {
+            if part_one.binary_search(&c).is_err() {
+                let s = c.to_string();
+                assert!(s.chars().nfc().eq(s.chars()), "NFC changed {c:?}");
+                assert!(s.chars().nfd().eq(s.chars()), "NFD changed {c:?}");
+                assert!(s.chars().nfkc().eq(s.chars()), "NFKC changed {c:?}");
+                assert!(s.chars().nfkd().eq(s.chars()), "NFKD changed {c:?}");
+            }
+        }
+    }
+}
@@ -0,0 +1,300 @@
+//! This module provides a trait implemented on `char` for looking up the character properties
+//! recorded in `UnicodeData.txt` other than the general category (which is provided by the
+//! `categories` module) and the character name.
+//! ```
+//! # use crate::finl_unicode::properties::{BidiClass, CharacterProperties, DecompositionType};
+//! assert_eq!('\u{301}'.canonical_combining_class(), 230);
+//! assert_eq!('א'.bidi_class(), BidiClass::R);
+//! assert_eq!('½'.decomposition_type(), Some(DecompositionType::Fraction));
+//! assert_eq!('½'.numeric_value().map(|value| value.to_f64()), Some(0.5));
+//! assert!('('.is_mirrored());
+//! ```
+//!
+//! Like the categories, the properties are looked up in multistage tables, so each lookup takes
+//! constant time.
+
+use crate::data::bidi_class::{BIDI_PAGES, BIDI_TABLE};
+use crate::data::combining_class::{CCC_PAGES, CCC_TABLE};
+use crate::data::decomposition_type::{DT_PAGES, DT_TABLE};
+use crate::data::numeric_value::{NUMERIC_VALUES, NV_PAGES, NV_TABLE};
+
+/// Trait to look up the properties in `UnicodeData.txt` for a character. Importing the trait will
+/// provide the methods on the `char` type.
+#[allow(clippy::wrong_self_convention)]
+pub trait CharacterProperties {
+    /// Returns the canonical combining class of a character. This is 0 for starters and otherwise
+    /// determines the order of combining marks in canonically ordered text.
+    fn canonical_combining_class(self) -> u8;
+    /// Returns the bidirectional class of a character as used by the Unicode bidirectional
+    /// algorithm (UAX #9). Unassigned code points get the default value for the block they fall in,
+    /// e.g., R for unassigned code points in the Hebrew block.
+    fn bidi_class(self) -> BidiClass;
+    /// Returns the type of the decomposition mapping of a character, or `None` if it has no
+    /// decomposition mapping. Hangul syllables have canonical decompositions even though they are
+    /// not listed in `UnicodeData.txt`.
+    fn decomposition_type(self) -> Option<DecompositionType>;
+    /// Returns the numeric type and value of a character, or `None` if it is not numeric. Only the
+    /// values in `UnicodeData.txt` are included, so CJK ideographs which are used as numbers
+    /// (e.g., 三) will return `None`.
+    fn numeric_value(self) -> Option<NumericValue>;
+    /// Determines whether a character is mirrored in right-to-left text (e.g., parentheses and
+    /// brackets).
+    fn is_mirrored(self) -> bool;
+}
+
+/// enum for the bidirectional classes of characters. The names are the abbreviations used in
+/// `UnicodeData.txt`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BidiClass {
+    /// Left-to-right
+    L,
+    /// Right-to-left
+    R,
+    /// Right-to-left Arabic
+    AL,
+    /// European number
+    EN,
+    /// European number separator
+    ES,
+    /// European number terminator
+    ET,
+    /// Arabic number
+    AN,
+    /// Common number separator
+    CS,
+    /// Nonspacing mark
+    NSM,
+    /// Boundary neutral
+    BN,
+    /// Paragraph separator
+    B,
+    /// Segment separator
+    S,
+    /// Whitespace
+    WS,
+    /// Other neutrals
+    ON,
+    /// Left-to-right embedding
+    LRE,
+    /// Left-to-right override
+    LRO,
+    /// Right-to-left embedding
+    RLE,
+    /// Right-to-left override
+    RLO,
+    /// Pop directional format
+    PDF,
+    /// Left-to-right isolate
+    LRI,
+    /// Right-to-left isolate
+    RLI,
+    /// First strong isolate
+    FSI,
+    /// Pop directional isolate
+    PDI,
+}
+
+/// enum for the types of decomposition mappings. All but `Canonical` are compatibility
+/// decompositions, with the type given by the tag in `UnicodeData.txt`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DecompositionType {
+    /// Canonical decomposition
+    Canonical,
+    /// Font variant
+    Font,
+    /// No-break version of a space or hyphen
+    NoBreak,
+    /// Initial presentation form (Arabic)
+    Initial,
+    /// Medial presentation form (Arabic)
+    Medial,
+    /// Final presentation form (Arabic)
+    Final,
+    /// Isolated presentation form (Arabic)
+    Isolated,
+    /// Encircled form
+    Circle,
+    /// Superscript form
+    Super,
+    /// Subscript form
+    Sub,
+    /// Vertical layout presentation form
+    Vertical,
+    /// Wide (zenkaku) compatibility character
+    Wide,
+    /// Narrow (hankaku) compatibility character
+    Narrow,
+    /// Small variant form (CNS compatibility)
+    Small,
+    /// CJK squared font variant
+    Square,
+    /// Vulgar fraction form
+    Fraction,
+    /// Otherwise unspecified compatibility character
+    Compat,
+}
+
+/// enum for the numeric types of characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NumericType {
+    /// A decimal digit which is part of a contiguous 0–9 sequence
+    Decimal,
+    /// A digit which isn't used in decimal notation, such as a superscript or circled digit
+    Digit,
+    /// Any other numeric character, such as a fraction or Roman numeral
+    Numeric,
+}
+
+/// The numeric value of a character. Values are kept as fractions since some characters represent
+/// fractions like ⅓ which can't be represented exactly as a floating point value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NumericValue {
+    pub numeric_type: NumericType,
+    pub numerator: i64,
+    pub denominator: u32,
+}
+
+impl NumericValue {
+    /// Returns the value as a floating point number.
+    /// ```
+    /// # use crate::finl_unicode::properties::CharacterProperties;
+    /// assert_eq!('Ⅻ'.numeric_value().unwrap().to_f64(), 12.0);
+    /// ```
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        self.numerator as f64 / f64::from(self.denominator)
+    }
+}
+
+impl CharacterProperties for char {
+    #[inline]
+    fn canonical_combining_class(self) -> u8 {
+        CCC_PAGES[usize::from(CCC_TABLE[(self as usize) >> 8])][(self as usize) & 0xff]
+    }
+
+    #[inline]
+    fn bidi_class(self) -> BidiClass {
+        match get_bidi_property(self) & 0x1f {
+            0x00 => BidiClass::L,
+            0x01 => BidiClass::R,
+            0x02 => BidiClass::AL,
+            0x03 => BidiClass::EN,
+            0x04 => BidiClass::ES,
+            0x05 => BidiClass::ET,
+            0x06 => BidiClass::AN,
+            0x07 => BidiClass::CS,
+            0x08 => BidiClass::NSM,
+            0x09 => BidiClass::BN,
+            0x0a => BidiClass::B,
+            0x0b => BidiClass::S,
+            0x0c => BidiClass::WS,
+            0x0d => BidiClass::ON,
+            0x0e => BidiClass::LRE,
+            0x0f => BidiClass::LRO,
+            0x10 => BidiClass::RLE,
+            0x11 => BidiClass::RLO,
+            0x12 => BidiClass::PDF,
+            0x13 => BidiClass::LRI,
+            0x14 => BidiClass::RLI,
+            0x15 => BidiClass::FSI,
+            0x16 => BidiClass::PDI,
+            _ => {
+                panic!("Corrupt bidi class data")
+            }
+        }
+    }
+
+    #[inline]
+    fn decomposition_type(self) -> Option<DecompositionType> {
+        match DT_PAGES[usize::from(DT_TABLE[(self as usize) >> 8])][(self as usize) & 0xff] {
+            0x00 => None,
+            0x01 => Some(DecompositionType::Canonical),
+            0x02 => Some(DecompositionType::Font),
+            0x03 => Some(DecompositionType::NoBreak),
+            0x04 => Some(DecompositionType::Initial),
+            0x05 => Some(DecompositionType::Medial),
+            0x06 => Some(DecompositionType::Final),
+            0x07 => Some(DecompositionType::Isolated),
+            0x08 => Some(DecompositionType::Circle),
+            0x09 => Some(DecompositionType::Super),
+            0x0a => Some(DecompositionType::Sub),
+            0x0b => Some(DecompositionType::Vertical),
+            0x0c => Some(DecompositionType::Wide),
+            0x0d => Some(DecompositionType::Narrow),
+            0x0e => Some(DecompositionType::Small),
+            0x0f => Some(DecompositionType::Square),
+            0x10 => Some(DecompositionType::Fraction),
+            0x11 => Some(DecompositionType::Compat),
+            _ => {
+                panic!("Corrupt decomposition type data")
+            }
+        }
+    }
+
+    #[inline]
+    fn numeric_value(self) -> Option<NumericValue> {
+        let index = NV_PAGES[usize::from(NV_TABLE[(self as usize) >> 8])][(self as usize) & 0xff];
+        let (numeric_type, numerator, denominator) = NUMERIC_VALUES[usize::from(index).checked_sub(1)?];
+        let numeric_type = match numeric_type {
+            1 => NumericType::Decimal,
+            2 => NumericType::Digit,
+            _ => NumericType::Numeric,
+        };
+        Some(NumericValue { numeric_type, numerator, denominator })
+    }
+
+    #[inline]
+    fn is_mirrored(self) -> bool {
+        get_bidi_property(self) & 0x80 != 0
+    }
+}
+
+#[inline]
+fn get_bidi_property(c: char) -> u8 {
+    BIDI_PAGES[usize::from(BIDI_TABLE[(c as usize) >> 8])][(c as usize) & 0xff]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::properties::*;
+
+    #[test]
+    fn character_properties() {
+        assert_eq!('a'.canonical_combining_class(), 0);
+        assert_eq!('\u{323}'.canonical_combining_class(), 220);
+        assert_eq!('\u{94d}'.canonical_combining_class(), 9);
+
+        assert_eq!('a'.bidi_class(), BidiClass::L);
+        assert_eq!('ب'.bidi_class(), BidiClass::AL);
+        assert_eq!('5'.bidi_class(), BidiClass::EN);
+        assert_eq!('٥'.bidi_class(), BidiClass::AN);
+        assert_eq!('\u{301}'.bidi_class(), BidiClass::NSM);
+        assert_eq!(' '.bidi_class(), BidiClass::WS);
+        assert_eq!('\u{2029}'.bidi_class(), BidiClass::B);
+        assert_eq!('\u{2067}'.bidi_class(), BidiClass::RLI);
+        // Unassigned code points in right-to-left blocks
+        assert_eq!('\u{5ff}'.bidi_class(), BidiClass::R);
+        assert_eq!('\u{7bf}'.bidi_class(), BidiClass::AL);
+        assert_eq!('\u{20cf}'.bidi_class(), BidiClass::ET);
+
+        assert_eq!('a'.decomposition_type(), None);
+        assert_eq!('é'.decomposition_type(), Some(DecompositionType::Canonical));
+        assert_eq!('한'.decomposition_type(), Some(DecompositionType::Canonical));
+        assert_eq!('\u{a0}'.decomposition_type(), Some(DecompositionType::NoBreak));
+        assert_eq!('²'.decomposition_type(), Some(DecompositionType::Super));
+        assert_eq!('ﬁ'.decomposition_type(), Some(DecompositionType::Compat));
+        assert_eq!('ｱ'.decomposition_type(), Some(DecompositionType::Narrow));
+
+        assert_eq!('a'.numeric_value(), None);
+        assert_eq!('7'.numeric_value(), Some(NumericValue { numeric_type: NumericType::Decimal, numerator: 7, denominator: 1 }));
+        assert_eq!('²'.numeric_value(), Some(NumericValue { numeric_type: NumericType::Digit, numerator: 2, denominator: 1 }));
+        assert_eq!('⅓'.numeric_value(), Some(NumericValue { numeric_type: NumericType::Numeric, numerator: 1, denominator: 3 }));
+        assert_eq!('\u{f33}'.numeric_value().map(NumericValue::to_f64), Some(-0.5));
+        assert_eq!('万'.numeric_value(), None);
+
+        assert!('['.is_mirrored());
+        assert!('≤'.is_mirrored());
+        assert!(!'a'.is_mirrored());
+        assert!(!'-'.is_mirrored());
+    }
+}
@@ -0,0 +1,223 @@
+//! This module provides the Script and Script_Extensions properties described in
+//! [UAX #24](https://www.unicode.org/reports/tr24/) along with an iterator over the script runs
+//! of a string.
+//! ```
+//! # use crate::finl_unicode::scripts::{CharacterScript, Script};
+//! assert_eq!('a'.script(), Script::Latin);
+//! assert_eq!('ж'.script(), Script::Cyrillic);
+//! assert_eq!('1'.script(), Script::Common);
+//! assert_eq!('\u{964}'.script_extensions()[..2], [Script::Bengali, Script::Devanagari]);
+//! ```
+//!
+//! `ScriptRuns` divides a string into runs of text in a single script. Characters which are used
+//! by more than one script, like spaces, punctuation and combining marks, become part of the run
+//! of the text around them.
+//! ```
+//! # use crate::finl_unicode::scripts::{Script, ScriptRuns};
+//! let runs = ScriptRuns::new("Hello, Привет (мир)!");
+//! assert_eq!(runs.collect::<Vec<_>>(), [
+//!     ("Hello, ", Script::Latin),
+//!     ("Привет (мир)!", Script::Cyrillic),
+//! ]);
+//! ```
+
+use core::slice;
+use crate::data::script_extensions::{SCX_PAGES, SCX_TABLE};
+use crate::data::script_property::{SC_PAGES, SC_TABLE};
+use crate::data::scripts::{SCRIPTS, SCRIPT_CODES, SCRIPT_EXTENSIONS, SCRIPT_NAMES};
+pub use crate::data::scripts::Script;
+
+/// Trait to look up the script of a character. Importing the trait will provide the methods on
+/// the `char` type.
+pub trait CharacterScript {
+    /// Returns the script of a character. Characters which are used with more than one script are
+    /// `Common` (e.g., spaces, digits and most punctuation) or `Inherited` (e.g., combining marks,
+    /// which take the script of the base character) and unassigned code points are `Unknown`.
+    fn script(self) -> Script;
+    /// Returns the scripts that a character is used with. For most characters this is just the
+    /// script of the character, but, e.g., the Devanagari danda (।) is `Common` but used with
+    /// Bengali, Devanagari and other Indic scripts which will be given here.
+    fn script_extensions(self) -> &'static [Script];
+}
+
+impl CharacterScript for char {
+    #[inline]
+    fn script(self) -> Script {
+        SCRIPTS[get_script_index(self)]
+    }
+
+    #[inline]
+    fn script_extensions(self) -> &'static [Script] {
+        let index = SCX_PAGES[usize::from(SCX_TABLE[(self as usize) >> 8])][(self as usize) & 0xff];
+        match usize::from(index).checked_sub(1) {
+            Some(index) => SCRIPT_EXTENSIONS[index],
+            None => slice::from_ref(&SCRIPTS[get_script_index(self)]),
+        }
+    }
+}
+
+impl Script {
+    /// Returns the four letter ISO 15924 code for the script, e.g., `Latn` for `Script::Latin`.
+    /// ```
+    /// # use crate::finl_unicode::scripts::Script;
+    /// assert_eq!(Script::Latin.code(), "Latn");
+    /// assert_eq!(Script::Inherited.code(), "Zinh");
+    /// ```
+    #[inline]
+    pub fn code(self) -> &'static str {
+        SCRIPT_CODES[self as usize]
+    }
+
+    /// Returns the name of the script as given in the Unicode character database, e.g.,
+    /// `Old_Italic` for `Script::OldItalic`.
+    #[inline]
+    pub fn name(self) -> &'static str {
+        SCRIPT_NAMES[self as usize]
+    }
+}
+
+/// `ScriptRuns` provides an iterator over the runs of a string which are in a single script. Each
+/// run is given as a string slice along with its script.
+///
+/// A character with a `Common` or `Inherited` script continues the current run. If it has script
+/// extensions, it can only continue a run in one of those scripts. If a run is made up only of
+/// such characters, its script is the script of its first character, e.g., `Common` for a string
+/// which is only spaces and digits.
+pub struct ScriptRuns<'a> {
+    input: &'a str,
+    offset: usize,
+}
+
+impl<'a> ScriptRuns<'a> {
+    /// A new instance of script runs can be constructed from a string using `ScriptRuns::new`
+    /// ```
+    /// # use crate::finl_unicode::scripts::ScriptRuns;
+    /// let script_runs = ScriptRuns::new("some string");
+    /// ```
+    pub fn new(input: &'a str) -> ScriptRuns<'a> {
+        ScriptRuns {
+            input,
+            offset: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for ScriptRuns<'a> {
+    type Item = (&'a str, Script);
+
+    #[inline]
+    /// Return the next script run, or `None` if the end of the string has been reached.
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = &self.input[self.offset..];
+        let first = rest.chars().next()?;
+        // The scripts which every character so far could be in. `None` means that we haven't yet
+        // seen a character which limits the possible scripts.
+        let mut candidates: Option<ScriptSet> = None;
+        let mut end = 0;
+        for c in rest.chars() {
+            let scripts = c.script_extensions();
+            if !matches!(scripts, [Script::Common] | [Script::Inherited]) {
+                let scripts = ScriptSet::new(scripts);
+                let remaining = match candidates {
+                    None => scripts,
+                    Some(candidates) => candidates.intersection(scripts),
+                };
+                if remaining.is_empty() {
+                    break;
+                }
+                candidates = Some(remaining);
+            }
+            end += c.len_utf8();
+        }
+        self.offset += end;
+        let script = candidates.and_then(ScriptSet::only).unwrap_or_else(|| first.script());
+        Some((&rest[..end], script))
+    }
+}
+
+// A set of scripts with a bit for each one, so that the scripts a run could be in can be narrowed
+// down without allocating.
+#[derive(Clone, Copy)]
+struct ScriptSet([u64; SCRIPT_SET_WORDS]);
+
+const SCRIPT_SET_WORDS: usize = SCRIPT_CODES.len().div_ceil(64);
+
+impl ScriptSet {
+    fn new(scripts: &[Script]) -> ScriptSet {
+        let mut words = [0; SCRIPT_SET_WORDS];
+        for &script in scripts {
+            words[script as usize / 64] |= 1 << (script as usize % 64);
+        }
+        ScriptSet(words)
+    }
+
+    fn intersection(self, other: ScriptSet) -> ScriptSet {
+        ScriptSet(core::array::from_fn(|index| self.0[index] & other.0[index]))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.iter().all(|&word| word == 0)
+    }
+
+    // The script in the set if there is exactly one
+    fn only(self) -> Option<Script> {
+        if self.0.iter().map(|word| word.count_ones()).sum::<u32>() != 1 {
+            return None;
+        }
+        let (index, word) = self.0.iter().enumerate().find(|(_, &word)| word != 0)?;
+        Some(SCRIPTS[index * 64 + word.trailing_zeros() as usize])
+    }
+}
+
+#[inline]
+fn get_script_index(c: char) -> usize {
+    usize::from(SC_PAGES[usize::from(SC_TABLE[(c as usize) >> 8])][(c as usize) & 0xff])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::scripts::*;
+
+    #[test]
+    fn can_get_scripts() {
+        assert_eq!('Z'.script(), Script::Latin);
+        assert_eq!('λ'.script(), Script::Greek);
+        assert_eq!('ب'.script(), Script::Arabic);
+        assert_eq!('あ'.script(), Script::Hiragana);
+        assert_eq!('漢'.script(), Script::Han);
+        assert_eq!('\u{301}'.script(), Script::Inherited);
+        assert_eq!(' '.script(), Script::Common);
+        assert_eq!('\u{378}'.script(), Script::Unknown);
+
+        assert_eq!('Z'.script_extensions(), [Script::Latin]);
+        assert_eq!(' '.script_extensions(), [Script::Common]);
+        assert!('ー'.script_extensions().contains(&Script::Hiragana));
+        assert!('ー'.script_extensions().contains(&Script::Katakana));
+        assert!(!'\u{964}'.script_extensions().contains(&Script::Common));
+
+        assert_eq!(Script::OldItalic.code(), "Ital");
+        assert_eq!(Script::OldItalic.name(), "Old_Italic");
+    }
+
+    #[test]
+    fn can_find_script_runs() {
+        assert_eq!(ScriptRuns::new("").next(), None);
+        assert_eq!(ScriptRuns::new("123 !").collect::<Vec<_>>(), [("123 !", Script::Common)]);
+        assert_eq!(ScriptRuns::new("\u{301}\u{20dd}").collect::<Vec<_>>(), [("\u{301}\u{20dd}", Script::Inherited)]);
+        // Leading common characters and combining marks take the script of the following text
+        assert_eq!(ScriptRuns::new("“ά” שָׁלוֹם").collect::<Vec<_>>(), [
+            ("“ά” ", Script::Greek),
+            ("שָׁלוֹם", Script::Hebrew),
+        ]);
+        // The danda can be used with Devanagari but not Latin, so it begins a new run
+        assert_eq!(ScriptRuns::new("abc।नमस्ते।").collect::<Vec<_>>(), [
+            ("abc", Script::Latin),
+            ("।नमस्ते।", Script::Devanagari),
+        ]);
+        assert_eq!(ScriptRuns::new("日本語のテキスト").collect::<Vec<_>>(), [
+            ("日本語", Script::Han),
+            ("の", Script::Hiragana),
+            ("テキスト", Script::Katakana),
+        ]);
+    }
+}
@@ -0,0 +1,350 @@
+//! This module finds sentence boundaries as described in [UAX #29](https://www.unicode.org/reports/tr29/#Sentence_Boundaries).
+//! As with `grapheme_clusters` and `word_boundaries`, there are two interfaces. The
+//! `SentenceBoundary` trait extends `Peekable` iterators over `Chars` or `CharIndices` with a
+//! `next_sentence` method which returns `Option<String>` with the next sentence.
+//! ```
+//! # use crate::finl_unicode::sentences::SentenceBoundary;
+//! let mut char_iterator = "Mr. Smith arrived. He said “Hello!” Then he left.".chars().peekable();
+//! assert_eq!(char_iterator.next_sentence(), Some("Mr. ".to_string()));
+//! assert_eq!(char_iterator.next_sentence(), Some("Smith arrived. ".to_string()));
+//! assert_eq!(char_iterator.next_sentence(), Some("He said “Hello!” ".to_string()));
+//! assert_eq!(char_iterator.next_sentence(), Some("Then he left.".to_string()));
+//! assert_eq!(char_iterator.next_sentence(), None);
+//! ```
+//!
+//! Note that the default rules don't know anything about abbreviations, so “Mr.” followed by a
+//! capitalized word ends a sentence. A period followed by a lowercase word does not.
+//!
+//! For iterating over all the sentences in a `&str` there is a struct `Sentences` which returns
+//! references to substrings of the original `&str`.
+//! ```
+//! # use crate::finl_unicode::sentences::Sentences;
+//! let sentences = Sentences::new("See p. 5 for details. ¿Qué?\nNo.");
+//! assert_eq!(sentences.collect::<Vec<&str>>(), ["See p. 5 for details. ", "¿Qué?\n", "No."]);
+//! ```
+//!
+//! Any spaces and paragraph separator after the end of a sentence are included in the sentence.
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::iter::Peekable;
+use core::str::CharIndices;
+use crate::data::sentence_property::{SB_PAGES, SB_TABLE};
+use crate::grapheme_clusters::Break;
+#[cfg(feature = "alloc")]
+use crate::grapheme_clusters::PeekChar;
+
+/// `Sentences` provides an iterator over the sentences in a string.
+pub struct Sentences<'a> {
+    input: &'a str,
+    iter: Peekable<CharIndices<'a>>,
+}
+
+impl<'a> Sentences<'a> {
+    /// A new instance of sentences can be constructed from a string using `Sentences::new`
+    /// ```
+    /// # use crate::finl_unicode::sentences::Sentences;
+    /// let sentences = Sentences::new("some string");
+    /// ```
+    pub fn new(input: &'a str) -> Sentences<'a> {
+        Sentences {
+            input,
+            iter: input.char_indices().peekable(),
+        }
+    }
+}
+
+impl<'a> Iterator for Sentences<'a> {
+    type Item = &'a str;
+    #[inline]
+    /// Return a slice of the underlying string corresponding to the next sentence, or `None` if
+    /// the end of the string has been reached.
+    fn next(&mut self) -> Option<Self::Item> {
+        let &(start, _) = self.iter.peek()?;
+        let mut sentence_machine = SentenceMachine::new();
+        while let Some(&(curr_loc, ch)) = self.iter.peek() {
+            let following = || self.input[curr_loc + ch.len_utf8()..].chars();
+            match sentence_machine.find_boundary(ch, following) {
+                Break::None => { self.iter.next(); }
+                Break::Before => {
+                    return Some(&self.input[start..curr_loc]);
+                }
+                Break::After => {
+                    self.iter.next();
+                    return Some(&self.input[start..curr_loc + ch.len_utf8()]);
+                }
+            }
+        }
+        Some(&self.input[start..])
+    }
+}
+
+/// Get the next sentence from a stream of characters or char indices. This trait is implemented
+/// for any `Peekable` iterator over either `char` or `(usize, char)` which can be cloned (which
+/// includes `Peekable<Chars>` and `Peekable<CharIndices>`). Deciding whether a full stop ends a
+/// sentence can require looking ahead an arbitrary distance for a lowercase letter, which is done
+/// on a clone of the iterator.
+#[cfg(feature = "alloc")]
+pub trait SentenceBoundary<T> {
+    fn next_sentence(&mut self) -> Option<String>;
+}
+
+#[cfg(feature = "alloc")]
+impl<T> SentenceBoundary<T> for T where T: PeekChar + Clone {
+    /// Returns the next sentence if there is one in an `Option<String>`. Since this has a heap
+    /// allocation it is *not* recommended for iterating over all the sentences in a string. In
+    /// that case, use `Sentences` instead.
+    #[inline]
+    fn next_sentence(&mut self) -> Option<String> {
+        if self.has_next() {
+            let mut sentence_machine = SentenceMachine::new();
+            let mut rv = String::new();
+            while let Some(ch) = self.peek_char() {
+                let following = || {
+                    let mut following = self.clone();
+                    following.next();
+                    core::iter::from_fn(move || {
+                        let c = following.peek_char();
+                        following.next();
+                        c
+                    })
+                };
+                match sentence_machine.find_boundary(ch, following) {
+                    Break::None => {
+                        rv.push(ch);
+                        self.next();
+                    }
+                    Break::Before => { return Some(rv); }
+                    Break::After => {
+                        rv.push(ch);
+                        self.next();
+                        return Some(rv);
+                    }
+                }
+            }
+            Some(rv)
+        } else {
+            None
+        }
+    }
+}
+
+// ------------------------
+// Private implementation details follow
+
+#[derive(PartialEq)]
+enum SentenceMachineState {
+    Start,
+    Sentence,
+    /// A carriage return, which is only followed by a line feed in the same sentence
+    Cr,
+    /// A full stop or other terminator possibly followed by closing punctuation
+    Terminator,
+    /// A terminator followed by spaces
+    Space,
+}
+
+/// The state machine for finding sentence boundaries. Each call to `find_boundary` decides
+/// whether the character belongs to the sentence seen so far. SB8 depends on the characters
+/// *after* the one being examined, so `find_boundary` also takes a function which returns the
+/// following characters. It is only called when that rule applies.
+struct SentenceMachine {
+    state: SentenceMachineState,
+    /// The property of the last character which was not ignored under SB5
+    last: u8,
+    /// The property of the character before `last`, also ignoring SB5 characters
+    before_last: u8,
+    /// Whether the terminator for the `Terminator` and `Space` states is an `ATerm`
+    aterm: bool,
+}
+
+impl SentenceMachine {
+    #[inline]
+    pub fn new() -> SentenceMachine {
+        SentenceMachine {
+            state: SentenceMachineState::Start,
+            last: SentenceProperty::OTHER,
+            before_last: SentenceProperty::OTHER,
+            aterm: false,
+        }
+    }
+
+    #[inline]
+    pub fn find_boundary<F, I>(&mut self, c: char, following: F) -> Break
+    where F: FnOnce() -> I, I: Iterator<Item = char>
+    {
+        let property = get_property(c);
+        match self.state {
+            SentenceMachineState::Start => {
+                self.last = property;
+                return self.accept(property, SentenceMachineState::Sentence);
+            }
+            // SB3, SB4
+            SentenceMachineState::Cr => {
+                return if property == SentenceProperty::LF {
+                    Break::After
+                } else {
+                    Break::Before
+                };
+            }
+            _ => {}
+        }
+
+        // SB5
+        if property == SentenceProperty::EXTEND || property == SentenceProperty::FORMAT {
+            return Break::None;
+        }
+
+        let state = match self.state {
+            SentenceMachineState::Terminator | SentenceMachineState::Space => match self.after_terminator(property, following) {
+                Some(state) => state,
+                // SB11
+                None => return Break::Before,
+            },
+            // SB998
+            _ => SentenceMachineState::Sentence,
+        };
+        self.before_last = self.last;
+        self.last = property;
+        self.accept(property, state)
+    }
+
+    /// Apply SB6 through SB10 to a character following a terminator. If the character continues
+    /// the sentence, we return the state to move to.
+    #[inline]
+    fn after_terminator<F, I>(&self, property: u8, following: F) -> Option<SentenceMachineState>
+    where F: FnOnce() -> I, I: Iterator<Item = char>
+    {
+        let after_aterm = self.state == SentenceMachineState::Terminator && self.last == SentenceProperty::ATERM;
+        // SB6
+        let continues = (after_aterm && property == SentenceProperty::NUMERIC)
+            // SB7
+            || (after_aterm && property == SentenceProperty::UPPER && is_cased(self.before_last))
+            // SB8
+            || (self.aterm && (property == SentenceProperty::LOWER || (!is_sb8_stop(property) && is_followed_by_lower(following))))
+            // SB8a
+            || property == SentenceProperty::SCONTINUE;
+        if continues {
+            return Some(SentenceMachineState::Sentence);
+        }
+        match property {
+            // SB9
+            SentenceProperty::CLOSE if self.state == SentenceMachineState::Terminator => Some(SentenceMachineState::Terminator),
+            // SB9, SB10
+            SentenceProperty::SP => Some(SentenceMachineState::Space),
+            // SB8a, and SB9 and SB10 for paragraph separators
+            _ if is_terminator(property) || is_paragraph_separator(property) => Some(SentenceMachineState::Sentence),
+            _ => None,
+        }
+    }
+
+    /// Add a character to the sentence, moving to `state` unless the character is a terminator
+    /// or paragraph separator.
+    #[inline]
+    fn accept(&mut self, property: u8, state: SentenceMachineState) -> Break {
+        match property {
+            SentenceProperty::CR => {
+                self.state = SentenceMachineState::Cr;
+                Break::None
+            }
+            // SB4
+            SentenceProperty::LF | SentenceProperty::SEP => Break::After,
+            SentenceProperty::ATERM | SentenceProperty::STERM => {
+                self.state = SentenceMachineState::Terminator;
+                self.aterm = property == SentenceProperty::ATERM;
+                Break::None
+            }
+            _ => {
+                self.state = state;
+                Break::None
+            }
+        }
+    }
+}
+
+/// Whether the first character in `following` which could stop SB8 applying is a lowercase
+/// letter.
+#[inline]
+fn is_followed_by_lower<F, I>(following: F) -> bool
+where F: FnOnce() -> I, I: Iterator<Item = char>
+{
+    following()
+        .map(get_property)
+        .find(|&property| is_sb8_stop(property))
+        == Some(SentenceProperty::LOWER)
+}
+
+/// The characters which are not skipped over when SB8 looks for a lowercase letter
+#[inline]
+fn is_sb8_stop(property: u8) -> bool {
+    matches!(property, SentenceProperty::OLETTER | SentenceProperty::UPPER | SentenceProperty::LOWER)
+        || is_paragraph_separator(property)
+        || is_terminator(property)
+}
+
+#[inline]
+fn is_cased(property: u8) -> bool {
+    matches!(property, SentenceProperty::UPPER | SentenceProperty::LOWER)
+}
+
+#[inline]
+fn is_terminator(property: u8) -> bool {
+    matches!(property, SentenceProperty::ATERM | SentenceProperty::STERM)
+}
+
+#[inline]
+fn is_paragraph_separator(property: u8) -> bool {
+    matches!(property, SentenceProperty::CR | SentenceProperty::LF | SentenceProperty::SEP)
+}
+
+// Symbolic names for properties in data tables
+struct SentenceProperty {}
+impl SentenceProperty {
+    const OTHER: u8 = 0x00;
+    const CR: u8 = 0x01;
+    const LF: u8 = 0x02;
+    const EXTEND: u8 = 0x03;
+    const SEP: u8 = 0x04;
+    const FORMAT: u8 = 0x05;
+    const SP: u8 = 0x06;
+    const LOWER: u8 = 0x07;
+    const UPPER: u8 = 0x08;
+    const OLETTER: u8 = 0x09;
+    const NUMERIC: u8 = 0x0a;
+    const ATERM: u8 = 0x0b;
+    const SCONTINUE: u8 = 0x0c;
+    const STERM: u8 = 0x0d;
+    const CLOSE: u8 = 0x0e;
+}
+
+#[inline]
+fn get_property(c: char) -> u8 {
+    SB_PAGES[usize::from(SB_TABLE[(c as usize) >> 8])][(c as usize) & 0xff]
+}
+
+#[cfg(test)]
+pub (crate) mod tests {
+    use crate::sentences::*;
+
+    #[test]
+    fn can_get_sentences() {
+        let mut peekable_index = "Go.\r\nU.S.A. is big. (It is.) Then etc. and more".char_indices().peekable();
+        assert_eq!(Some("Go.\r\n".to_string()), peekable_index.next_sentence());
+        assert_eq!(Some("U.S.A. is big. ".to_string()), peekable_index.next_sentence());
+        assert_eq!(Some("(It is.) ".to_string()), peekable_index.next_sentence());
+        assert_eq!(Some("Then etc. and more".to_string()), peekable_index.next_sentence());
+        assert_eq!(None, peekable_index.next_sentence());
+    }
+
+    pub (crate) fn sentence_test(input: &str, expected_output: &[&str], message: &str) {
+        let mut iter = input.chars().peekable();
+        let mut sentences = vec!();
+        while let Some(sentence) = iter.next_sentence() {
+            sentences.push(sentence);
+        }
+        assert_eq!(sentences, expected_output, "Sentence mismatch: {message}");
+
+        let sentences = Sentences::new(input).collect::<Vec<&str>>();
+        assert_eq!(sentences, expected_output, "Sentence slice mismatch: {message}");
+    }
+}
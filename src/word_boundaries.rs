@@ -0,0 +1,382 @@
+//! This module finds word boundaries as described in [UAX #29](https://www.unicode.org/reports/tr29/#Word_Boundaries).
+//! As with `grapheme_clusters`, there are two interfaces. The `WordBoundary` trait extends
+//! `Peekable` iterators over `Chars` or `CharIndices` with a `next_word` method which returns
+//! `Option<String>` with the text up to the next word boundary.
+//! ```
+//! # use crate::finl_unicode::word_boundaries::WordBoundary;
+//! let mut char_iterator = "Can't stop 3.14".chars().peekable();
+//! assert_eq!(char_iterator.next_word(), Some("Can't".to_string()));
+//! assert_eq!(char_iterator.next_word(), Some(" ".to_string()));
+//! assert_eq!(char_iterator.next_word(), Some("stop".to_string()));
+//! assert_eq!(char_iterator.next_word(), Some(" ".to_string()));
+//! assert_eq!(char_iterator.next_word(), Some("3.14".to_string()));
+//! assert_eq!(char_iterator.next_word(), None);
+//! ```
+//!
+//! For iterating over all the words in a `&str` there is a struct `Words` which returns
+//! references to substrings of the original `&str`.
+//! ```
+//! # use crate::finl_unicode::word_boundaries::Words;
+//! let words = Words::new("The quick (“brown”) fox");
+//! assert_eq!(words.collect::<Vec<&str>>(), ["The", " ", "quick", " ", "(", "“", "brown", "”", ")", " ", "fox"]);
+//! ```
+//!
+//! Note that, as in the example above, everything between two word boundaries is returned,
+//! including spaces and punctuation. Filter the results (e.g., on whether they contain any
+//! alphanumeric characters) if you only want the words themselves.
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::iter::Peekable;
+use core::str::CharIndices;
+use crate::data::word_property::{WB_PAGES, WB_TABLE};
+use crate::grapheme_clusters::Break;
+#[cfg(feature = "alloc")]
+use crate::grapheme_clusters::PeekChar;
+
+/// `Words` provides an iterator over the text between word boundaries in a string.
+pub struct Words<'a> {
+    input: &'a str,
+    iter: Peekable<CharIndices<'a>>,
+}
+
+impl<'a> Words<'a> {
+    /// A new instance of words can be constructed from a string using `Words::new`
+    /// ```
+    /// # use crate::finl_unicode::word_boundaries::Words;
+    /// let words = Words::new("some string");
+    /// ```
+    pub fn new(input: &'a str) -> Words<'a> {
+        Words {
+            input,
+            iter: input.char_indices().peekable(),
+        }
+    }
+}
+
+impl<'a> Iterator for Words<'a> {
+    type Item = &'a str;
+    #[inline]
+    /// Return a slice of the underlying string up to the next word boundary, or `None` if the
+    /// end of the string has been reached.
+    fn next(&mut self) -> Option<Self::Item> {
+        let &(start, _) = self.iter.peek()?;
+        let mut word_machine = WordMachine::new();
+        while let Some(&(curr_loc, ch)) = self.iter.peek() {
+            let following = || self.input[curr_loc + ch.len_utf8()..].chars();
+            match word_machine.find_boundary(ch, following) {
+                Break::None => { self.iter.next(); }
+                Break::Before => {
+                    return Some(&self.input[start..curr_loc]);
+                }
+                Break::After => {
+                    self.iter.next();
+                    return Some(&self.input[start..curr_loc + ch.len_utf8()]);
+                }
+            }
+        }
+        Some(&self.input[start..])
+    }
+}
+
+/// Get the text up to the next word boundary from a stream of characters or char indices.
+/// This trait is implemented for any `Peekable` iterator over either `char` or `(usize, char)`
+/// which can be cloned (which includes `Peekable<Chars>` and `Peekable<CharIndices>`). Some of the
+/// word boundary rules need to look more than one character ahead, which is done on a clone of the
+/// iterator.
+#[cfg(feature = "alloc")]
+pub trait WordBoundary<T> {
+    fn next_word(&mut self) -> Option<String>;
+}
+
+#[cfg(feature = "alloc")]
+impl<T> WordBoundary<T> for T where T: PeekChar + Clone {
+    /// Returns the text up to the next word boundary if there is any in an `Option<String>`.
+    /// Since this has a heap allocation it is *not* recommended for iterating over all the words
+    /// in a string. In that case, use `Words` instead.
+    #[inline]
+    fn next_word(&mut self) -> Option<String> {
+        if self.has_next() {
+            let mut word_machine = WordMachine::new();
+            let mut rv = String::new();
+            while let Some(ch) = self.peek_char() {
+                let following = || {
+                    let mut following = self.clone();
+                    following.next();
+                    core::iter::from_fn(move || {
+                        let c = following.peek_char();
+                        following.next();
+                        c
+                    })
+                };
+                match word_machine.find_boundary(ch, following) {
+                    Break::None => {
+                        rv.push(ch);
+                        self.next();
+                    }
+                    Break::Before => { return Some(rv); }
+                    Break::After => {
+                        rv.push(ch);
+                        self.next();
+                        return Some(rv);
+                    }
+                }
+            }
+            Some(rv)
+        } else {
+            None
+        }
+    }
+}
+
+// ------------------------
+// Private implementation details follow
+
+#[derive(PartialEq)]
+enum WordMachineState {
+    Start,
+    Word,
+    /// A carriage return, which is only followed by a line feed in the same segment
+    Cr,
+}
+
+/// The state machine for finding word boundaries. Each call to `find_boundary` decides whether
+/// the character goes with the text seen so far. WB6, WB7b and WB12 depend on the character
+/// *after* the one being examined, so `find_boundary` also takes a function which returns the
+/// following characters. It is only called when one of those rules applies.
+struct WordMachine {
+    state: WordMachineState,
+    /// The property of the last character which was not ignored under WB4
+    last: u8,
+    /// The property of the character immediately before this one
+    previous: u8,
+    /// The number of regional indicators at the end of the text so far (WB15/WB16)
+    regional_indicators: usize,
+    /// Whether we have just joined a `MidLetter`, `MidNum` or similar under WB6, WB7b or WB12,
+    /// in which case the next character joins under WB7, WB7c or WB11
+    joined_middle: bool,
+}
+
+impl WordMachine {
+    #[inline]
+    pub fn new() -> WordMachine {
+        WordMachine {
+            state: WordMachineState::Start,
+            last: WordProperty::OTHER,
+            previous: WordProperty::OTHER,
+            regional_indicators: 0,
+            joined_middle: false,
+        }
+    }
+
+    #[inline]
+    pub fn find_boundary<F, I>(&mut self, c: char, following: F) -> Break
+    where F: FnOnce() -> I, I: Iterator<Item = char>
+    {
+        let properties = get_properties(c);
+        let property = properties & WordProperty::BASE_MASK;
+        let previous = self.previous;
+        self.previous = property;
+        match self.state {
+            WordMachineState::Start => {
+                self.last = property;
+                self.regional_indicators = usize::from(property == WordProperty::REGIONAL_INDICATOR);
+                return match property {
+                    WordProperty::CR => {
+                        self.state = WordMachineState::Cr;
+                        Break::None
+                    }
+                    // WB3a
+                    WordProperty::LF | WordProperty::NEWLINE => Break::After,
+                    _ => {
+                        self.state = WordMachineState::Word;
+                        Break::None
+                    }
+                };
+            }
+            // WB3, WB3a
+            WordMachineState::Cr => {
+                return if property == WordProperty::LF {
+                    Break::After
+                } else {
+                    Break::Before
+                };
+            }
+            WordMachineState::Word => {}
+        }
+
+        // WB3b
+        if is_newline(property) {
+            return Break::Before;
+        }
+        // WB4
+        if is_ignorable(property) {
+            return Break::None;
+        }
+        let mut joined_middle = false;
+        // WB3c
+        let joins = (previous == WordProperty::ZWJ && properties & WordProperty::EXTENDED_PICTOGRAPHIC != 0)
+            // WB3d
+            || (previous == WordProperty::WSEG_SPACE && property == WordProperty::WSEG_SPACE)
+            // WB7, WB7c, WB11
+            || self.joined_middle
+            || self.joins_last(property, following, &mut joined_middle);
+
+        if joins {
+            self.regional_indicators = if property != WordProperty::REGIONAL_INDICATOR {
+                0
+            } else if self.last == WordProperty::REGIONAL_INDICATOR {
+                self.regional_indicators + 1
+            } else {
+                1
+            };
+            self.last = property;
+            self.joined_middle = joined_middle;
+            Break::None
+        } else {
+            Break::Before
+        }
+    }
+
+    /// Apply WB5 through WB16 (other than WB7, WB7c and WB11) to the last character which was not
+    /// ignored and `property`. If we join a middle character which must be followed by a letter
+    /// or number, `joined_middle` is set.
+    #[inline]
+    fn joins_last<F, I>(&self, property: u8, following: F, joined_middle: &mut bool) -> bool
+    where F: FnOnce() -> I, I: Iterator<Item = char>
+    {
+        match (self.last, property) {
+            // WB5
+            (WordProperty::ALETTER | WordProperty::HEBREW_LETTER, WordProperty::ALETTER | WordProperty::HEBREW_LETTER) => true,
+            // WB6, WB7a
+            (WordProperty::HEBREW_LETTER, WordProperty::SINGLE_QUOTE) => {
+                *joined_middle = is_followed_by(following, is_ahletter);
+                true
+            }
+            // WB6
+            (WordProperty::ALETTER | WordProperty::HEBREW_LETTER, WordProperty::MID_LETTER | WordProperty::MID_NUM_LET | WordProperty::SINGLE_QUOTE) => {
+                *joined_middle = is_followed_by(following, is_ahletter);
+                *joined_middle
+            }
+            // WB7b
+            (WordProperty::HEBREW_LETTER, WordProperty::DOUBLE_QUOTE) => {
+                *joined_middle = is_followed_by(following, |property| property == WordProperty::HEBREW_LETTER);
+                *joined_middle
+            }
+            // WB8, WB9, WB10
+            (WordProperty::ALETTER | WordProperty::HEBREW_LETTER | WordProperty::NUMERIC, WordProperty::ALETTER | WordProperty::HEBREW_LETTER | WordProperty::NUMERIC) => true,
+            // WB12
+            (WordProperty::NUMERIC, WordProperty::MID_NUM | WordProperty::MID_NUM_LET | WordProperty::SINGLE_QUOTE) => {
+                *joined_middle = is_followed_by(following, |property| property == WordProperty::NUMERIC);
+                *joined_middle
+            }
+            // WB13
+            (WordProperty::KATAKANA, WordProperty::KATAKANA) => true,
+            // WB13a
+            (WordProperty::ALETTER | WordProperty::HEBREW_LETTER | WordProperty::NUMERIC | WordProperty::KATAKANA | WordProperty::EXTEND_NUM_LET, WordProperty::EXTEND_NUM_LET) => true,
+            // WB13b
+            (WordProperty::EXTEND_NUM_LET, WordProperty::ALETTER | WordProperty::HEBREW_LETTER | WordProperty::NUMERIC | WordProperty::KATAKANA) => true,
+            // WB15, WB16
+            (WordProperty::REGIONAL_INDICATOR, WordProperty::REGIONAL_INDICATOR) => self.regional_indicators & 1 == 1,
+            // WB999
+            _ => false,
+        }
+    }
+}
+
+/// Whether the first character in `following` which is not ignored under WB4 satisfies `test`
+#[inline]
+fn is_followed_by<F, I>(following: F, test: fn(u8) -> bool) -> bool
+where F: FnOnce() -> I, I: Iterator<Item = char>
+{
+    following()
+        .map(get_property)
+        .find(|&property| !is_ignorable(property))
+        .is_some_and(test)
+}
+
+#[inline]
+fn is_ignorable(property: u8) -> bool {
+    matches!(property, WordProperty::EXTEND | WordProperty::FORMAT | WordProperty::ZWJ)
+}
+
+#[inline]
+fn is_newline(property: u8) -> bool {
+    matches!(property, WordProperty::CR | WordProperty::LF | WordProperty::NEWLINE)
+}
+
+#[inline]
+fn is_ahletter(property: u8) -> bool {
+    matches!(property, WordProperty::ALETTER | WordProperty::HEBREW_LETTER)
+}
+
+// Symbolic names for properties in data tables
+struct WordProperty {}
+impl WordProperty {
+    const OTHER: u8 = 0x00;
+    const CR: u8 = 0x01;
+    const LF: u8 = 0x02;
+    const NEWLINE: u8 = 0x03;
+    const EXTEND: u8 = 0x04;
+    const ZWJ: u8 = 0x05;
+    const REGIONAL_INDICATOR: u8 = 0x06;
+    const FORMAT: u8 = 0x07;
+    const KATAKANA: u8 = 0x08;
+    const HEBREW_LETTER: u8 = 0x09;
+    const ALETTER: u8 = 0x0a;
+    const SINGLE_QUOTE: u8 = 0x0b;
+    const DOUBLE_QUOTE: u8 = 0x0c;
+    const MID_NUM_LET: u8 = 0x0d;
+    const MID_LETTER: u8 = 0x0e;
+    const MID_NUM: u8 = 0x0f;
+    const NUMERIC: u8 = 0x10;
+    const EXTEND_NUM_LET: u8 = 0x11;
+    const WSEG_SPACE: u8 = 0x12;
+    // The Word_Break value is in the low five bits
+    const BASE_MASK: u8 = 0x1f;
+    // Extended_Pictographic (for WB3c) is the high bit
+    const EXTENDED_PICTOGRAPHIC: u8 = 0x80;
+}
+
+/// The Word_Break property of `c`
+#[inline]
+fn get_property(c: char) -> u8 {
+    get_properties(c) & WordProperty::BASE_MASK
+}
+
+/// The full value from the data tables including the Extended_Pictographic bit
+#[inline]
+fn get_properties(c: char) -> u8 {
+    WB_PAGES[usize::from(WB_TABLE[(c as usize) >> 8])][(c as usize) & 0xff]
+}
+
+#[cfg(test)]
+pub (crate) mod tests {
+    use crate::word_boundaries::*;
+
+    #[test]
+    fn can_get_words() {
+        let mut peekable_index = "\r\nשב״ק a.b 1,000.5\u{200d}👍🇦🇹🇩".char_indices().peekable();
+        assert_eq!(Some("\r\n".to_string()), peekable_index.next_word());
+        assert_eq!(Some("שב״ק".to_string()), peekable_index.next_word());
+        assert_eq!(Some(" ".to_string()), peekable_index.next_word());
+        assert_eq!(Some("a.b".to_string()), peekable_index.next_word());
+        assert_eq!(Some(" ".to_string()), peekable_index.next_word());
+        assert_eq!(Some("1,000.5\u{200d}👍".to_string()), peekable_index.next_word());
+        assert_eq!(Some("🇦🇹".to_string()), peekable_index.next_word());
+        assert_eq!(Some("🇩".to_string()), peekable_index.next_word());
+        assert_eq!(None, peekable_index.next_word());
+    }
+
+    pub (crate) fn word_test(input: &str, expected_output: &[&str], message: &str) {
+        let mut iter = input.chars().peekable();
+        let mut words = vec!();
+        while let Some(word) = iter.next_word() {
+            words.push(word);
+        }
+        assert_eq!(words, expected_output, "Word mismatch: {message}");
+
+        let words = Words::new(input).collect::<Vec<&str>>();
+        assert_eq!(words, expected_output, "Word slice mismatch: {message}");
+    }
+}